@@ -0,0 +1,70 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measures the cost of [`dispatch!`] for a very small amount of work.
+//!
+//! Selecting the SIMD version is a short chain of comparisons on the [`Level`], tested from the
+//! strongest level down. The level doesn't change between calls, so these branches are always
+//! predicted correctly, and the remaining overhead is mostly the call into the selected version,
+//! which can't be inlined because it's compiled with different target features.
+//!
+//! Run this in release mode to compare a dispatch per call with a single dispatch around the
+//! whole loop:
+//!
+//! ```sh
+//! cargo run --release --example dispatch_overhead
+//! ```
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use fearless_simd::{Level, dispatch, f32x4, prelude::*};
+
+const ITERATIONS: u32 = 10_000_000;
+
+#[inline(always)]
+fn scale<S: Simd>(simd: S, x: [f32; 4], gain: f32) -> [f32; 4] {
+    (f32x4::from_slice(simd, &x) * gain).into()
+}
+
+/// Dispatch separately for every call, as a latency-sensitive callback would.
+fn dispatch_per_call(level: Level) -> Duration {
+    let start = Instant::now();
+    let mut x = [1.0, 2.0, 3.0, 4.0];
+    for _ in 0..ITERATIONS {
+        let level = black_box(level);
+        x = dispatch!(level, simd => scale(simd, black_box(x), 0.5));
+    }
+    black_box(x);
+    start.elapsed()
+}
+
+/// Dispatch once, and run the whole loop with the selected level.
+fn dispatch_once(level: Level) -> Duration {
+    #[inline(always)]
+    fn run<S: Simd>(simd: S) -> Duration {
+        let start = Instant::now();
+        let mut x = [1.0, 2.0, 3.0, 4.0];
+        for _ in 0..ITERATIONS {
+            x = scale(simd, black_box(x), 0.5);
+        }
+        black_box(x);
+        start.elapsed()
+    }
+
+    dispatch!(level, simd => run(simd))
+}
+
+fn main() {
+    let level = Level::new();
+    println!("level = {level:?}");
+    let per_call = dispatch_per_call(level);
+    let once = dispatch_once(level);
+    let nanos = |duration: Duration| duration.as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+    println!("dispatch per call: {:.2} ns/iteration", nanos(per_call));
+    println!("dispatch once:     {:.2} ns/iteration", nanos(once));
+    println!(
+        "overhead:          {:.2} ns/dispatch",
+        nanos(per_call) - nanos(once)
+    );
+}
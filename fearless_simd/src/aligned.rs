@@ -0,0 +1,371 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Growable buffers which are aligned and padded for whole vectors.
+//!
+//! An [`AlignedVec`] is like a [`Vec`](std::vec::Vec), whose allocation starts at a multiple of `ALIGN` bytes and
+//! is padded with zeros to a multiple of `ALIGN` bytes. With an `ALIGN` of at least the width of the vectors, every
+//! vector of [`padded`](AlignedVec::padded) is aligned, and the last one can be loaded and stored whole, without
+//! handling a tail separately. [`NativeAlignedVec`] is aligned for the widest vectors of every level.
+//!
+//! This is only available with the `std` feature.
+//!
+//! ```rust
+//! use fearless_simd::aligned::NativeAlignedVec;
+//! use fearless_simd::{Level, Simd, dispatch, prelude::*};
+//!
+//! /// Scale every sample, including the padding, which stays zero.
+//! #[inline(always)]
+//! fn scale<S: Simd>(simd: S, samples: &mut NativeAlignedVec<f32>, gain: f32) {
+//!     for chunk in samples.padded_mut().chunks_exact_mut(S::f32s::N) {
+//!         (S::f32s::from_slice(simd, chunk) * gain).store_slice(chunk);
+//!     }
+//! }
+//!
+//! let mut samples = NativeAlignedVec::from_slice(&[0.5, -1.0, 2.0]);
+//! dispatch!(Level::new(), simd => scale(simd, &mut samples, 2.0));
+//! assert_eq!(*samples, [1.0, -2.0, 4.0]);
+//! assert_eq!(samples.padded().len(), 16);
+//! assert_eq!(samples.as_ptr().addr() % 64, 0);
+//! ```
+
+use core::fmt;
+use core::mem::size_of;
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+use std::alloc::{self, Layout};
+
+use crate::slice::load_tail;
+use crate::{Simd, SimdBase, SimdElement, SimdMask};
+
+/// The alignment of [`NativeAlignedVec`], which is the width of the widest vectors of any level, in bytes.
+pub const NATIVE_ALIGN: usize = 64;
+
+/// An [`AlignedVec`] aligned and padded for the widest vectors of any level, which are 512 bits wide.
+pub type NativeAlignedVec<T> = AlignedVec<T, NATIVE_ALIGN>;
+
+/// A growable buffer of `T`, whose allocation is aligned to `ALIGN` bytes and padded with zeros to a multiple of
+/// `ALIGN` bytes.
+///
+/// This dereferences to a slice of its elements, like a [`Vec`](std::vec::Vec). The padding after them is
+/// accessed with [`padded`](Self::padded) and [`padded_mut`](Self::padded_mut), which have a length that's a
+/// multiple of `ALIGN / size_of::<T>()`. The padding is zero when elements are added, and elements which are
+/// removed are set back to zero, but code which writes to [`padded_mut`](Self::padded_mut) can leave other values
+/// in it.
+///
+/// `ALIGN` must be a power of two, and at least the size of `T`, which is checked at compile time.
+pub struct AlignedVec<T: SimdElement + Copy, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    /// The number of elements which are allocated, which is a multiple of [`Self::LANES`].
+    capacity: usize,
+}
+
+// Safety: `AlignedVec` owns its elements, which are plain numbers.
+unsafe impl<T: SimdElement + Copy + Send, const ALIGN: usize> Send for AlignedVec<T, ALIGN> {}
+// Safety: `AlignedVec` only gives out shared references to its elements from shared references to itself.
+unsafe impl<T: SimdElement + Copy + Sync, const ALIGN: usize> Sync for AlignedVec<T, ALIGN> {}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> AlignedVec<T, ALIGN> {
+    /// The number of elements in `ALIGN` bytes, which the padded length is a multiple of.
+    const LANES: usize = {
+        assert!(ALIGN.is_power_of_two(), "`ALIGN` must be a power of two");
+        assert!(
+            ALIGN >= size_of::<T>(),
+            "`ALIGN` must be at least the size of an element"
+        );
+        ALIGN / size_of::<T>()
+    };
+
+    /// Create an empty buffer, without allocating.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            ptr: Self::dangling(),
+            len: 0,
+            capacity: 0,
+        }
+    }
+
+    /// Create an empty buffer, with room for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+
+    /// Create a buffer of `len` zeros.
+    pub fn zeroed(len: usize) -> Self {
+        let mut vec = Self::with_capacity(len);
+        // The allocation is zeroed.
+        vec.len = len;
+        vec
+    }
+
+    /// Create a buffer with a copy of `values`.
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut vec = Self::with_capacity(values.len());
+        vec.extend_from_slice(values);
+        vec
+    }
+
+    /// The number of elements, not counting the padding.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of elements which can be held without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// A pointer to the first element, which is aligned to `ALIGN` bytes.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// A mutable pointer to the first element, which is aligned to `ALIGN` bytes.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// The elements, followed by the padding up to the next multiple of `ALIGN` bytes.
+    #[inline]
+    pub fn padded(&self) -> &[T] {
+        // Safety: the first `capacity` elements are allocated and initialized, and the padded length is at most
+        // `capacity`, as that's a multiple of `LANES`.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.padded_len()) }
+    }
+
+    /// The elements, followed by the padding up to the next multiple of `ALIGN` bytes, which may be changed.
+    #[inline]
+    pub fn padded_mut(&mut self) -> &mut [T] {
+        // Safety: as in `padded`, and every value is a valid `T`.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.padded_len()) }
+    }
+
+    /// Reserve room for at least `additional` more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the allocation would be more than `isize::MAX` bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.capacity {
+            return;
+        }
+        let capacity = required
+            .max(self.capacity * 2)
+            .checked_next_multiple_of(Self::LANES)
+            .expect("capacity overflow");
+        let layout = Self::layout(capacity);
+        // Safety: `layout` has a nonzero size, as `capacity` is at least `required`, which is nonzero.
+        let new = unsafe { alloc::alloc_zeroed(layout) }.cast::<T>();
+        let Some(new) = NonNull::new(new) else {
+            alloc::handle_alloc_error(layout);
+        };
+        if self.capacity != 0 {
+            // Safety: the old allocation has `self.capacity` elements, which fit in the new one, and the two don't
+            // overlap. It was allocated with the layout for `self.capacity`.
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new.as_ptr(), self.capacity);
+                alloc::dealloc(self.ptr.as_ptr().cast(), Self::layout(self.capacity));
+            }
+        }
+        self.ptr = new;
+        self.capacity = capacity;
+    }
+
+    /// Append `value`.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        let len = self.len;
+        self.len += 1;
+        self[len] = value;
+    }
+
+    /// Remove the last element and return it, or `None` if there are none.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let value = *self.last()?;
+        self.truncate(self.len - 1);
+        Some(value)
+    }
+
+    /// Append a copy of `values`.
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.reserve(values.len());
+        let len = self.len;
+        self.len += values.len();
+        self[len..].copy_from_slice(values);
+    }
+
+    /// Change the length to `len`, appending copies of `value` or removing elements from the end.
+    pub fn resize(&mut self, len: usize, value: T) {
+        if len <= self.len {
+            self.truncate(len);
+        } else {
+            self.reserve(len - self.len);
+            let old_len = self.len;
+            self.len = len;
+            self[old_len..].fill(value);
+        }
+    }
+
+    /// Remove the elements from `len` onwards, setting them back to zero. This does nothing if there are at most
+    /// `len` elements.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        // Zero the whole padded tail, in case it was changed through `padded_mut`.
+        let end = self.padded_len();
+        self.len = len;
+        // Safety: the first `capacity` elements are allocated, and `end` is at most `capacity`. Every element is a
+        // plain number, for which zero bits are valid.
+        unsafe { ptr::write_bytes(self.ptr.as_ptr().add(len), 0, end - len) };
+    }
+
+    /// Remove every element, setting them back to zero.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Call `f` with each vector of [`padded`](Self::padded), and a mask of which of its lanes hold elements.
+    ///
+    /// This is [`for_each_vector_with_tail`](crate::slice::for_each_vector_with_tail) for an aligned buffer. If a
+    /// vector is at most `ALIGN` bytes, every vector is loaded from an aligned address, including the last one,
+    /// which is loaded whole rather than copied. Its lanes beyond [`len`](Self::len) are the padding, which is
+    /// zero unless it was changed through [`padded_mut`](Self::padded_mut).
+    #[inline(always)]
+    pub fn for_each_vector<S: Simd, V: SimdBase<S, Element = T>>(
+        &self,
+        simd: S,
+        mut f: impl FnMut(V, V::Mask),
+    ) {
+        let all = V::Mask::splat(simd, true);
+        for (i, chunk) in self.padded().chunks(V::N).enumerate() {
+            let remaining = self.len.saturating_sub(i * V::N);
+            if remaining == 0 {
+                break;
+            }
+            let v = if chunk.len() == V::N {
+                V::from_slice(simd, chunk)
+            } else {
+                // Only vectors which are wider than `ALIGN` can be partly outside of the allocation.
+                load_tail(simd, chunk, V::ADD_IDENTITY)
+            };
+            if remaining >= V::N {
+                f(v, all);
+            } else {
+                // The remainder is shorter than a vector, which has at most 64 lanes, so this doesn't overflow.
+                f(v, V::Mask::from_bitmask(simd, (1 << remaining) - 1));
+            }
+        }
+    }
+
+    fn padded_len(&self) -> usize {
+        // `capacity` is a multiple of `LANES`, so this is at most `capacity`.
+        self.len.next_multiple_of(Self::LANES)
+    }
+
+    /// The layout of an allocation of `capacity` elements.
+    fn layout(capacity: usize) -> Layout {
+        capacity
+            .checked_mul(size_of::<T>())
+            .and_then(|size| Layout::from_size_align(size, ALIGN).ok())
+            .expect("capacity overflow")
+    }
+
+    /// A pointer for an empty buffer, which is aligned but doesn't point to an allocation.
+    fn dangling() -> NonNull<T> {
+        // Evaluating `LANES` checks `ALIGN`, so that this is a valid alignment for `T`.
+        let _ = Self::LANES;
+        NonNull::new(ptr::without_provenance_mut(ALIGN)).expect("`ALIGN` isn't zero")
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> Drop for AlignedVec<T, ALIGN> {
+    fn drop(&mut self) {
+        if self.capacity != 0 {
+            // Safety: the allocation was made with the layout for `self.capacity`.
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), Self::layout(self.capacity)) };
+        }
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> Deref for AlignedVec<T, ALIGN> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.padded()[..self.len]
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> DerefMut for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        let len = self.len;
+        &mut self.padded_mut()[..len]
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> Default for AlignedVec<T, ALIGN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> Clone for AlignedVec<T, ALIGN> {
+    fn clone(&self) -> Self {
+        Self::from_slice(self)
+    }
+}
+
+impl<T: SimdElement + Copy + fmt::Debug, const ALIGN: usize> fmt::Debug for AlignedVec<T, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: SimdElement + Copy + PartialEq, const ALIGN: usize> PartialEq for AlignedVec<T, ALIGN> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> From<&[T]> for AlignedVec<T, ALIGN> {
+    fn from(values: &[T]) -> Self {
+        Self::from_slice(values)
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> Extend<T> for AlignedVec<T, ALIGN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: SimdElement + Copy, const ALIGN: usize> FromIterator<T> for AlignedVec<T, ALIGN> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
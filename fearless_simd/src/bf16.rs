@@ -0,0 +1,166 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Storage of `bfloat16` values, which are converted to `f32` for arithmetic.
+
+use core::fmt;
+
+use crate::{
+    Bytes, Select, Simd, SimdBase, SimdFloat, SimdInt, SimdSplit, f32x8, u16x8, u16x16, u32x8,
+};
+
+/// A SIMD vector of 8 `bfloat16` values.
+///
+/// A `bfloat16` is the upper half of the bits of an [`f32`]: it has the same sign and exponent,
+/// and 7 bits of mantissa. It is common in machine learning, where halving the size of weights
+/// matters more than their precision.
+///
+/// This is a storage type only. It has no arithmetic, so values are converted to [`f32x8`] with
+/// [`to_f32`](Self::to_f32), computed on (typically with [`mul_add`](SimdFloat::mul_add)), and
+/// converted back with [`from_f32`](Self::from_f32) if needed. In memory, each value is a `u16`
+/// holding its bits.
+///
+/// The conversions are done with integer operations on every level, so their results are the
+/// same everywhere. Widening is a single shift. The instructions for narrowing (from AVX-512 BF16
+/// and Arm's BF16 extension) are not part of any level, and AVX-512 BF16 flushes subnormal values
+/// to zero.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, bf16x8, prelude::*};
+///
+/// let simd = Fallback::new();
+/// let weights = bf16x8::from_slice(simd, &[0x3F80, 0x4000, 0xBF80, 0x3F00, 0, 0, 0, 0]);
+/// let weights = weights.to_f32();
+/// assert_eq!(*weights, [1.0, 2.0, -1.0, 0.5, 0.0, 0.0, 0.0, 0.0]);
+/// assert_eq!(*bf16x8::from_f32(weights).to_bits(), [0x3F80, 0x4000, 0xBF80, 0x3F00, 0, 0, 0, 0]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct bf16x8<S: Simd> {
+    bits: u16x8<S>,
+}
+
+impl<S: Simd> bf16x8<S> {
+    /// Create a vector from the bits of each value.
+    #[inline(always)]
+    pub fn from_bits(bits: u16x8<S>) -> Self {
+        Self { bits }
+    }
+
+    /// Return the bits of each value.
+    #[inline(always)]
+    pub fn to_bits(self) -> u16x8<S> {
+        self.bits
+    }
+
+    /// Load the bits of 8 values from a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice doesn't have exactly 8 elements.
+    #[inline(always)]
+    pub fn from_slice(simd: S, slice: &[u16]) -> Self {
+        Self::from_bits(u16x8::from_slice(simd, slice))
+    }
+
+    /// Store the bits of each value to a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice doesn't have exactly 8 elements.
+    #[inline(always)]
+    pub fn store_slice(self, slice: &mut [u16]) {
+        self.bits.store_slice(slice);
+    }
+
+    /// Convert each value to an `f32`.
+    ///
+    /// This is exact, as every `bfloat16` value is also an `f32` value.
+    #[inline(always)]
+    pub fn to_f32(self) -> f32x8<S> {
+        let simd = self.bits.simd;
+        (simd.widen_u16x8(self.bits) << 16).bitcast()
+    }
+
+    /// Convert each element of an `f32` vector to a `bfloat16`, rounding to the nearest value,
+    /// with ties to even.
+    ///
+    /// Values too large for a `bfloat16` become infinity, like when converting an `f64` to an
+    /// `f32`. Signaling NaNs become quiet NaNs, keeping the upper bits of their payload.
+    #[inline(always)]
+    pub fn from_f32(a: f32x8<S>) -> Self {
+        let bits: u32x8<S> = a.bitcast();
+        // Adding just under half of the discarded range rounds up anything above half. Exactly
+        // half is rounded up if the lowest kept bit is set, which rounds ties to even.
+        let rounded = bits + ((bits >> 16) & 1) + 0x7FFF;
+        // Rounding a NaN could carry into its exponent, so NaNs are truncated instead, setting the
+        // quiet bit to ensure that the result is still a NaN.
+        let rounded = a.simd_eq(a).select(rounded, bits | 0x0040_0000);
+        let (lo, hi) = rounded.bitcast::<u16x16<S>>().split();
+        // Keep the upper half of each `u32`, which is the second `u16` of it on little-endian
+        // targets.
+        let bits = if cfg!(target_endian = "big") {
+            lo.unzip_low(hi)
+        } else {
+            lo.unzip_high(hi)
+        };
+        Self::from_bits(bits)
+    }
+}
+
+impl<S: Simd + fmt::Debug> fmt::Debug for bf16x8<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("bf16x8").field("bits", &self.bits).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bf16x8;
+    use crate::{Fallback, SimdBase, f32x8};
+
+    #[test]
+    fn from_f32_rounds_ties_to_even() {
+        let simd = Fallback::new();
+        let inputs = [
+            // Exactly halfway, with the lowest kept bit clear, then set.
+            f32::from_bits(0x3F80_8000),
+            f32::from_bits(0x3F81_8000),
+            // Just above and below halfway.
+            f32::from_bits(0x3F80_8001),
+            f32::from_bits(0x3F80_7FFF),
+            // The largest finite `f32` rounds up to infinity.
+            f32::MAX,
+            f32::NEG_INFINITY,
+            // A signaling NaN, whose payload is only in the discarded bits.
+            f32::from_bits(0x7F80_0001),
+            -0.0,
+        ];
+        let result = bf16x8::from_f32(f32x8::from_slice(simd, &inputs));
+        assert_eq!(
+            *result.to_bits(),
+            [
+                0x3F80, 0x3F82, 0x3F81, 0x3F80, 0x7F80, 0xFF80, 0x7FC0, 0x8000
+            ],
+            "conversion should round to nearest, ties to even"
+        );
+    }
+
+    #[test]
+    fn to_f32_is_exact() {
+        let simd = Fallback::new();
+        let bits = [
+            0x0001, 0x3F80, 0x7F7F, 0x8080, 0x7F80, 0xFFC1, 0x4049, 0xC2F7,
+        ];
+        let result = bf16x8::from_slice(simd, &bits).to_f32();
+        for (value, bits) in result.iter().zip(bits) {
+            assert_eq!(
+                value.to_bits(),
+                u32::from(bits) << 16,
+                "widening should only shift the bits"
+            );
+        }
+        let mut stored = [0; 8];
+        bf16x8::from_f32(result).store_slice(&mut stored);
+        assert_eq!(stored, bits, "every bfloat16 should round trip");
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Packing and unpacking integers of a fixed number of bits, such as the 10-, 12- and 14-bit samples of camera RAW
+//! images.
+//!
+//! The packed format is a little-endian bit stream: value `i` of width `WIDTH` is bits `i * WIDTH` to
+//! `(i + 1) * WIDTH - 1` of the stream, and bit `n` of the stream is bit `n % 8` of byte `n / 8`. This is the layout
+//! of Parquet's bit packing and of many RAW formats; formats which put the most significant bits first, or which
+//! split each group of samples into high and low parts (like MIPI's RAW10), need their bytes or bits reordered
+//! first. Samples wider than 16 bits, like 24-bit audio, don't fit in the `u16` lanes used here.
+//!
+//! 8 values of `WIDTH` bits take exactly `WIDTH` bytes, so [`unpack_bits`] and [`pack_bits`] convert between a
+//! `[u8; WIDTH]` and a `u16x8`. [`unpack_bits_slice`] and [`pack_bits_slice`] do the same for whole slices.
+//!
+//! ```rust
+//! use fearless_simd::bitpack::{pack_bits, unpack_bits};
+//! use fearless_simd::{Level, SimdBase, dispatch, u16x8};
+//!
+//! // Eight 10-bit samples in 10 bytes.
+//! let samples = [0, 1, 2, 3, 1020, 1021, 1022, 1023];
+//! let (packed, unpacked) = dispatch!(Level::new(), simd => {
+//!     let packed = pack_bits::<_, 10>(u16x8::from_slice(simd, &samples));
+//!     (packed, *unpack_bits::<_, 10>(simd, &packed))
+//! });
+//! assert_eq!(packed, [0x00, 0x04, 0x20, 0xc0, 0x00, 0xfc, 0xf7, 0xef, 0xff, 0xff]);
+//! assert_eq!(unpacked, samples);
+//! ```
+
+use crate::{
+    Bytes, Simd, SimdBase, SimdCombine, SimdInt, SimdSplit, u8x16, u8x32, u16x8, u16x16, u32x8,
+};
+
+/// The byte shuffle and shifts which move each value of `WIDTH` bits into its own `u32` lane.
+struct Layout<const WIDTH: usize>;
+
+impl<const WIDTH: usize> Layout<WIDTH> {
+    /// For the 4 bytes of each `u32` lane, the index of the byte of the stream which it takes.
+    ///
+    /// A value starts in byte `i * WIDTH / 8` and takes up to 3 bytes. Indices past the end of the 16 loaded bytes
+    /// are only for bits which are masked off, so they are clamped to stay in range.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "The indices are at most 15."
+    )]
+    const INDICES: [u8; 32] = {
+        let mut indices = [0; 32];
+        let mut i = 0;
+        while i < 8 {
+            let mut k = 0;
+            while k < 4 {
+                let index = i * WIDTH / 8 + k;
+                let lane_byte = if cfg!(target_endian = "big") {
+                    3 - k
+                } else {
+                    k
+                };
+                indices[4 * i + lane_byte] = if index > 15 { 15 } else { index as u8 };
+                k += 1;
+            }
+            i += 1;
+        }
+        indices
+    };
+
+    /// The position of the first bit of each value within the first byte which it takes.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "The shifts are less than 8."
+    )]
+    const SHIFTS: [u32; 8] = {
+        let mut shifts = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            shifts[i] = (i * WIDTH % 8) as u32;
+            i += 1;
+        }
+        shifts
+    };
+
+    /// The bits of a value.
+    const MASK: u16 = u16::MAX >> (16 - WIDTH);
+}
+
+/// Unpack 8 values of `WIDTH` bits from `bytes`, from the least significant bits of the first byte.
+///
+/// `WIDTH` must be from 1 to 16.
+///
+/// The bytes are copied into both 16-byte halves of a vector, and one byte shuffle gathers the bytes of each value
+/// into its own `u32` lane. A shift by the position of each value in its first byte and a mask then leave only its
+/// bits.
+#[inline(always)]
+pub fn unpack_bits<S: Simd, const WIDTH: usize>(simd: S, bytes: &[u8; WIDTH]) -> u16x8<S> {
+    const {
+        assert!(
+            WIDTH >= 1 && WIDTH <= 16,
+            "the width should be from 1 to 16 bits"
+        );
+    };
+    let mut padded = [0; 16];
+    padded[..WIDTH].copy_from_slice(bytes);
+    let bytes = u8x16::from_slice(simd, &padded);
+    let gathered = bytes
+        .combine(bytes)
+        .swizzle_dyn_within_blocks(u8x32::from_slice(simd, &Layout::<WIDTH>::INDICES));
+    let values = simd.shrv_u32x8(
+        gathered.bitcast::<u32x8<S>>(),
+        u32x8::from_slice(simd, &Layout::<WIDTH>::SHIFTS),
+    ) & u32::from(Layout::<WIDTH>::MASK);
+    // Each value is the lower half of its `u32`, which is the first on little-endian targets.
+    let (lo, hi) = values.bitcast::<u16x16<S>>().split();
+    if cfg!(target_endian = "big") {
+        lo.unzip_high(hi)
+    } else {
+        lo.unzip_low(hi)
+    }
+}
+
+/// Pack the low `WIDTH` bits of each of the 8 `values` into `WIDTH` bytes, ignoring their higher bits.
+///
+/// `WIDTH` must be from 1 to 16.
+///
+/// Neighboring values are merged in pairs, into 4 `u32` lanes of `2 * WIDTH` bits, and those are merged into the
+/// bytes of the result.
+#[inline(always)]
+pub fn pack_bits<S: Simd, const WIDTH: usize>(values: u16x8<S>) -> [u8; WIDTH] {
+    const {
+        assert!(
+            WIDTH >= 1 && WIDTH <= 16,
+            "the width should be from 1 to 16 bits"
+        );
+    };
+    let simd = values.simd;
+    let values = simd.widen_u16x8(values & Layout::<WIDTH>::MASK);
+    let (even, _) = values.unzip_low(values).split();
+    let (odd, _) = values.unzip_high(values).split();
+    let pairs = even | odd << u32::try_from(WIDTH).unwrap();
+    let mut stream = 0_u128;
+    for (i, &pair) in pairs.as_slice().iter().enumerate() {
+        stream |= u128::from(pair) << (2 * WIDTH * i);
+    }
+    let mut bytes = [0; WIDTH];
+    bytes.copy_from_slice(&stream.to_le_bytes()[..WIDTH]);
+    bytes
+}
+
+/// Unpack `values.len()` values of `WIDTH` bits from `bytes`.
+///
+/// `WIDTH` must be from 1 to 16.
+///
+/// # Panics
+///
+/// Panics if `bytes` isn't exactly the `(values.len() * WIDTH).div_ceil(8)` bytes which hold the values.
+#[inline(always)]
+pub fn unpack_bits_slice<S: Simd, const WIDTH: usize>(simd: S, bytes: &[u8], values: &mut [u16]) {
+    assert_eq!(
+        bytes.len(),
+        (values.len() * WIDTH).div_ceil(8),
+        "`bytes` should hold exactly {} values of {WIDTH} bits",
+        values.len()
+    );
+    // A partial last group can take as many bytes as a whole one, so the groups are split off by their values.
+    let (whole, values) = values.split_at_mut(values.len() / 8 * 8);
+    let (whole_bytes, bytes) = bytes.split_at(whole.len() / 8 * WIDTH);
+    for (values, bytes) in whole
+        .chunks_exact_mut(8)
+        .zip(whole_bytes.chunks_exact(WIDTH))
+    {
+        unpack_bits::<S, WIDTH>(simd, bytes.try_into().unwrap()).store_slice(values);
+    }
+    if !values.is_empty() {
+        // The last group is unpacked from a copy, with the values past the end left as zeros.
+        let mut padded = [0; WIDTH];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        let unpacked = unpack_bits::<S, WIDTH>(simd, &padded);
+        values.copy_from_slice(&unpacked.as_slice()[..values.len()]);
+    }
+}
+
+/// Pack the low `WIDTH` bits of each of `values` into `bytes`, ignoring their higher bits.
+///
+/// `WIDTH` must be from 1 to 16. The bits of the last byte which follow the last value are zero.
+///
+/// # Panics
+///
+/// Panics if `bytes` isn't exactly the `(values.len() * WIDTH).div_ceil(8)` bytes which hold the values.
+#[inline(always)]
+pub fn pack_bits_slice<S: Simd, const WIDTH: usize>(simd: S, values: &[u16], bytes: &mut [u8]) {
+    assert_eq!(
+        bytes.len(),
+        (values.len() * WIDTH).div_ceil(8),
+        "`bytes` should hold exactly {} values of {WIDTH} bits",
+        values.len()
+    );
+    let (whole, values) = values.split_at(values.len() / 8 * 8);
+    let (whole_bytes, bytes) = bytes.split_at_mut(whole.len() / 8 * WIDTH);
+    for (values, bytes) in whole
+        .chunks_exact(8)
+        .zip(whole_bytes.chunks_exact_mut(WIDTH))
+    {
+        bytes.copy_from_slice(&pack_bits::<S, WIDTH>(u16x8::from_slice(simd, values)));
+    }
+    if !values.is_empty() {
+        let mut padded = [0; 8];
+        padded[..values.len()].copy_from_slice(values);
+        let packed = pack_bits::<S, WIDTH>(u16x8::from_slice(simd, &padded));
+        bytes.copy_from_slice(&packed[..bytes.len()]);
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! SIMD levels implemented outside of this crate.
+//!
+//! The [`Simd`] trait is sealed, so that new methods can be added to it without breaking
+//! other crates. With the `custom_level` feature, other crates can instead implement [`CustomLevel`],
+//! which has a default implementation of every method, and get an implementation of `Simd` for free.
+//! This allows experimenting with new backends, such as an emulator or a shim which offloads the work,
+//! without forking this crate. Note that new methods of `Simd` will also be added to `CustomLevel`,
+//! with default implementations, in minor releases.
+//!
+//! [`assert_conforms`] checks that a custom level agrees with its host, and [`Level::custom`] carries
+//! one in a [`Level`].
+//!
+//! ```rust
+//! use core::sync::atomic::{AtomicUsize, Ordering};
+//! use fearless_simd::{Fallback, Level, Simd, SimdBase, f32x4};
+//! use fearless_simd::custom::{self, CustomLevel};
+//!
+//! static ADDS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! /// Runs on the fallback level, but counts the `f32x4` additions.
+//! #[derive(Clone, Copy, Debug)]
+//! struct Counting;
+//!
+//! impl CustomLevel for Counting {
+//!     type Host = Fallback;
+//!
+//!     fn host(self) -> Fallback {
+//!         Fallback::new()
+//!     }
+//!
+//!     fn level(self) -> Level {
+//!         Level::custom(&Counting)
+//!     }
+//!
+//!     fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+//!         ADDS.fetch_add(1, Ordering::Relaxed);
+//!         f32x4::from_fn(self, |i| a[i] + b[i])
+//!     }
+//! }
+//!
+//! let a = f32x4::splat(Counting, 1.5);
+//! assert_eq!(*(a + a), [3.0; 4]);
+//! assert_eq!(ADDS.load(Ordering::Relaxed), 1);
+//!
+//! assert!(Simd::level(Counting).as_custom::<Counting>().is_some());
+//! custom::assert_conforms(Counting, &[]);
+//! ```
+
+use core::any::Any;
+use core::fmt;
+
+use crate::{Level, Simd};
+
+pub use crate::generated::custom_level::CustomLevel;
+
+/// A custom level carried by a [`Level`], which is created by [`Level::custom`].
+///
+/// The type of the level is erased, so that `Level` doesn't need to be generic. It can be recovered
+/// with [`Level::as_custom`].
+#[derive(Clone, Copy)]
+pub struct CustomToken {
+    token: &'static (dyn Any + Send + Sync),
+    name: &'static str,
+    host: fn(&'static (dyn Any + Send + Sync)) -> Level,
+}
+
+impl CustomToken {
+    pub(crate) fn new<L: CustomLevel>(token: &'static L) -> Self {
+        fn host<L: CustomLevel>(token: &'static (dyn Any + Send + Sync)) -> Level {
+            let token = token
+                .downcast_ref::<L>()
+                .expect("the token should have the type it was created with");
+            Simd::level(token.host())
+        }
+        Self {
+            token,
+            name: core::any::type_name::<L>(),
+            host: host::<L>,
+        }
+    }
+
+    /// The name of the type of the custom level.
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// The level of the custom level's [host](CustomLevel::Host).
+    ///
+    /// This is the level which [`dispatch`](crate::dispatch) runs code with.
+    pub fn host(self) -> Level {
+        (self.host)(self.token)
+    }
+
+    /// The custom level, if it has the type `L`.
+    pub fn downcast<L: CustomLevel>(self) -> Option<L> {
+        self.token.downcast_ref::<L>().copied()
+    }
+}
+
+impl fmt::Debug for CustomToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomToken").field(&self.name).finish()
+    }
+}
+
+/// Check that the lane-wise operations of a custom level give the same results as its host.
+///
+/// This runs each operation which has a function in the [`reference`](crate::reference) module on
+/// inputs including zeros, infinities, NaN and the extreme values of each type, on both `level`
+/// and its [host](CustomLevel::Host), and panics if any lane differs. Floating point lanes must have
+/// the same bits, except that any two NaNs are the same.
+///
+/// Operations whose results are implementation-defined, such as `approximate_recip` or `min` with a
+/// NaN operand, are compared exactly too, as a custom level which forwards to its host gets the same
+/// results. Operations which a custom level deliberately implements differently can be listed in
+/// `skip` by their `Simd` method name, like `"approximate_recip_f32x4"`, and aren't run.
+///
+/// # Panics
+///
+/// If an operation gives a different result, or `skip` contains a name which isn't a method of
+/// [`Simd`].
+#[track_caller]
+pub fn assert_conforms<L: CustomLevel>(level: L, skip: &[&str]) {
+    for method in skip {
+        assert!(
+            crate::lowering::find(method).is_some(),
+            "`{method}` is not a method of `Simd`"
+        );
+    }
+    let conformance = Conformance {
+        level: core::any::type_name::<L>(),
+        skip,
+    };
+    crate::generated::custom_level::check_ops(level, &conformance);
+}
+
+/// The number of different inputs each operation is checked with.
+pub(crate) const ROUNDS: usize = 16;
+
+/// The state of [`assert_conforms`], which the generated checks report to.
+pub(crate) struct Conformance<'a> {
+    level: &'static str,
+    skip: &'a [&'a str],
+}
+
+impl Conformance<'_> {
+    /// Compare the results of the method `method` on the custom level and its host, unless it's skipped.
+    #[track_caller]
+    pub(crate) fn check<T: Lanes>(
+        &self,
+        method: &str,
+        actual: impl FnOnce() -> T,
+        expected: impl FnOnce() -> T,
+    ) {
+        if self.skip.contains(&method) {
+            return;
+        }
+        let (actual, expected) = (actual(), expected());
+        if let Some(lane) = actual.differing_lane(&expected) {
+            panic!(
+                "`{method}` on `{}` differs from its host in lane {lane}\n  custom: {actual:?}\n    host: {expected:?}",
+                self.level
+            );
+        }
+    }
+}
+
+/// `N` lanes of the test values of `T`, starting from the value at `offset`.
+pub(crate) fn lanes<T: Lane, const N: usize>(offset: usize) -> [T; N] {
+    core::array::from_fn(|i| T::VALUES[(i + offset) % T::VALUES.len()])
+}
+
+/// `N` shift amounts between -7 and 7, which are valid for every element width.
+pub(crate) fn shift_amounts<T: From<i8>, const N: usize>(offset: usize) -> [T; N] {
+    const AMOUNTS: [i8; 15] = [-7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 7];
+    core::array::from_fn(|i| T::from(AMOUNTS[(i + offset) % AMOUNTS.len()]))
+}
+
+/// An element type of the vectors checked by [`assert_conforms`].
+pub(crate) trait Lane: Copy + fmt::Debug + 'static {
+    /// Values to test with, including edge cases.
+    const VALUES: &'static [Self];
+
+    /// Whether two results are the same.
+    fn same(self, other: Self) -> bool;
+}
+
+macro_rules! impl_lane_float {
+    ($($ty:ident),*) => {
+        $(
+            impl Lane for $ty {
+                const VALUES: &'static [Self] = &[
+                    0.0,
+                    -0.0,
+                    1.0,
+                    -1.0,
+                    0.5,
+                    -2.5,
+                    3.75,
+                    1e-3,
+                    -1e6,
+                    $ty::MAX,
+                    $ty::MIN,
+                    $ty::MIN_POSITIVE,
+                    $ty::from_bits(1),
+                    $ty::INFINITY,
+                    $ty::NEG_INFINITY,
+                    $ty::NAN,
+                ];
+
+                fn same(self, other: Self) -> bool {
+                    self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lane_int {
+    ($($ty:ident),*) => {
+        $(
+            impl Lane for $ty {
+                const VALUES: &'static [Self] = &[
+                    0,
+                    1,
+                    2,
+                    3,
+                    7,
+                    8,
+                    42,
+                    100,
+                    $ty::MAX,
+                    $ty::MAX - 1,
+                    $ty::MAX / 3,
+                    $ty::MIN,
+                    $ty::MIN.wrapping_add(1),
+                    $ty::MIN.wrapping_sub(5),
+                    $ty::wrapping_sub(0, 1),
+                    $ty::wrapping_sub(0, 100),
+                ];
+
+                fn same(self, other: Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_float!(f32, f64);
+impl_lane_int!(i8, u8, i16, u16, i32, u32, i64, u64);
+
+/// The result of an operation checked by [`assert_conforms`].
+pub(crate) trait Lanes: fmt::Debug {
+    /// The index of the first lane which differs between `self` and `other`.
+    fn differing_lane(&self, other: &Self) -> Option<usize>;
+}
+
+impl<T: Lane, const N: usize> Lanes for [T; N] {
+    fn differing_lane(&self, other: &Self) -> Option<usize> {
+        self.iter().zip(other).position(|(a, b)| !a.same(*b))
+    }
+}
+
+impl<T: Lane, const N: usize> Lanes for ([T; N], [T; N]) {
+    fn differing_lane(&self, other: &Self) -> Option<usize> {
+        self.0
+            .differing_lane(&other.0)
+            .or_else(|| Some(N + self.1.differing_lane(&other.1)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomLevel, assert_conforms};
+    use crate::{Fallback, Level, Simd, SimdBase, SimdInto, SimdMask, f32x4, mask32x4, u8x16};
+
+    /// Forwards everything to the fallback level.
+    #[derive(Clone, Copy, Debug)]
+    struct Forwarding;
+
+    impl CustomLevel for Forwarding {
+        type Host = Fallback;
+
+        fn host(self) -> Fallback {
+            Fallback::new()
+        }
+
+        fn level(self) -> Level {
+            Level::custom(&Self)
+        }
+    }
+
+    /// Computes `max` incorrectly.
+    #[derive(Clone, Copy, Debug)]
+    struct Broken;
+
+    impl CustomLevel for Broken {
+        type Host = Fallback;
+
+        fn host(self) -> Fallback {
+            Fallback::new()
+        }
+
+        fn max_u8x16(self, a: u8x16<Self>, _b: u8x16<Self>) -> u8x16<Self> {
+            a
+        }
+    }
+
+    #[test]
+    fn forwarding_level_conforms() {
+        assert_conforms(Forwarding, &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`max_u8x16` on `fearless_simd::custom::tests::Broken` differs")]
+    fn broken_level_does_not_conform() {
+        assert_conforms(Broken, &[]);
+    }
+
+    #[test]
+    fn skipped_ops_are_not_checked() {
+        assert_conforms(Broken, &["max_u8x16"]);
+    }
+
+    #[test]
+    fn references_and_mutation_forward() {
+        let simd = Forwarding;
+        let mut v: f32x4<_> = [1.0, 2.0, 3.0, 4.0].simd_into(simd);
+        v.as_mut_slice()[2] = 5.0;
+        assert_eq!(*v, [1.0, 2.0, 5.0, 4.0], "writes should reach the vector");
+
+        let mut mask = mask32x4::splat(simd, false);
+        mask.set(1, true);
+        assert_eq!(
+            Simd::as_array_mask32x4(simd, mask),
+            [0, -1, 0, 0],
+            "`set` should write back to the mask"
+        );
+    }
+
+    #[test]
+    fn level_round_trips() {
+        let level = Simd::level(Forwarding);
+        assert!(level.as_custom::<Forwarding>().is_some());
+        assert!(level.as_custom::<Broken>().is_none());
+        let Level::Custom(token) = level else {
+            panic!("the level should be custom");
+        };
+        assert!(token.name().ends_with("Forwarding"));
+        assert!(
+            token.host().as_custom::<Forwarding>().is_none(),
+            "the host should be the fallback level"
+        );
+        // `dispatch` runs on the host.
+        let lanes = crate::dispatch!(level, simd => *f32x4::splat(simd, 2.0));
+        assert_eq!(lanes, [2.0; 4]);
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The 8x8 discrete cosine transforms of JPEG, in integer arithmetic.
+//!
+//! [`forward_dct_8x8`] and [`inverse_dct_8x8`] are the "slow integer" (`islow`) transforms of the Independent JPEG
+//! Group's libjpeg, which use the factorization of Loeffler, Ligtenberg and Moschytz with 13-bit fixed-point
+//! constants. They give exactly the same results as libjpeg's `jfdctint.c` and `jidctint.c`, on every level, so
+//! they can replace those in a codec without changing its output, and libjpeg is a reference for testing them.
+//!
+//! Each pass of the transform computes the 1-D DCT of all 8 rows or columns at once, with one `i32x8` for each of
+//! the 8 inputs. The block is transposed between the passes, by interleaving pairs of vectors.
+//!
+//! ```rust
+//! use fearless_simd::dct::{forward_dct_8x8, inverse_dct_8x8};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! // A flat block only has a DC coefficient, which is 8 times the JPEG coefficient: 8 * 8 * (138 - 128).
+//! let flat = [138; 64];
+//! let coeffs = dispatch!(Level::new(), simd => forward_dct_8x8(simd, &flat));
+//! assert_eq!(coeffs[0], 640);
+//! assert!(coeffs[1..].iter().all(|&c| c == 0));
+//!
+//! // The inverse transform takes JPEG coefficients, without the extra factor of 8.
+//! let mut jpeg = [0; 64];
+//! jpeg[0] = 80;
+//! let samples = dispatch!(Level::new(), simd => inverse_dct_8x8(simd, &jpeg));
+//! assert_eq!(samples, flat);
+//! ```
+
+use crate::{Bytes, Simd, SimdBase, SimdCombine, SimdInt, SimdSplit, i16x8, i16x32, i32x8, u8x16};
+
+/// The number of fractional bits of the constants.
+const CONST_BITS: u32 = 13;
+
+/// The number of extra bits of precision which are kept between the two passes.
+const PASS1_BITS: u32 = 2;
+
+/// The sample value which is subtracted before the forward transform, and added after the inverse.
+const CENTER: i32 = 128;
+
+const FIX_0_298631336: i32 = 2446;
+const FIX_0_390180644: i32 = 3196;
+const FIX_0_541196100: i32 = 4433;
+const FIX_0_765366865: i32 = 6270;
+const FIX_0_899976223: i32 = 7373;
+const FIX_1_175875602: i32 = 9633;
+const FIX_1_501321110: i32 = 12299;
+const FIX_1_847759065: i32 = 15137;
+const FIX_1_961570560: i32 = 16069;
+const FIX_2_053119869: i32 = 16819;
+const FIX_2_562915447: i32 = 20995;
+const FIX_3_072711026: i32 = 25172;
+
+/// Compute the forward DCT of a block of 8x8 samples, in row-major order.
+///
+/// The samples are centered on zero first, as JPEG does. Like libjpeg's, the coefficients are 8 times those of the
+/// JPEG standard, so quantization should divide them by 8 times the quantizer.
+#[inline(always)]
+pub fn forward_dct_8x8<S: Simd>(simd: S, samples: &[u8; 64]) -> [i16; 64] {
+    let rows: [i32x8<S>; 8] = core::array::from_fn(|r| {
+        let (lo, hi) = simd
+            .widen_u8x16(u8x16::from_slice(simd, &samples[r / 2 * 16..][..16]))
+            .split();
+        let row = if r % 2 == 0 { lo } else { hi };
+        simd.widen_u16x8(row).as_signed() - CENTER
+    });
+    // The first pass is over the rows, so each vector needs to hold a column.
+    let columns = forward_pass(transpose(rows), true);
+    let coeffs = forward_pass(transpose(columns), false);
+    let mut out = [0; 64];
+    for (out, rows) in out.chunks_exact_mut(16).zip(coeffs.chunks_exact(2)) {
+        narrow(rows[0], rows[1]).store_slice(out);
+    }
+    out
+}
+
+/// Compute the inverse DCT of a block of 8x8 JPEG coefficients, in row-major order.
+///
+/// The coefficients should already be multiplied by their quantizers. The samples are rounded and clamped to the
+/// range of a `u8`. The intermediate sums wrap like libjpeg's, which only happens for coefficients far outside the
+/// range which JPEG can encode.
+#[inline(always)]
+pub fn inverse_dct_8x8<S: Simd>(simd: S, coeffs: &[i16; 64]) -> [u8; 64] {
+    let rows: [i32x8<S>; 8] =
+        core::array::from_fn(|r| simd.widen_i16x8(i16x8::from_slice(simd, &coeffs[r * 8..][..8])));
+    // The first pass is over the columns, which the vectors of rows already hold.
+    let pass1 = inverse_pass(rows, CONST_BITS - PASS1_BITS);
+    let pass2 = inverse_pass(transpose(pass1), CONST_BITS + PASS1_BITS + 3);
+    let samples = transpose(pass2).map(|row| (row + CENTER).max(0).min(255));
+    let mut out = [0; 64];
+    for (out, rows) in out.chunks_exact_mut(16).zip(samples.chunks_exact(2)) {
+        simd.pack_saturate_u8_i16x16(narrow(rows[0], rows[1]))
+            .store_slice(out);
+    }
+    out
+}
+
+/// One pass of libjpeg's `jpeg_fdct_islow`, over the 8 inputs in `d`.
+///
+/// The first pass keeps [`PASS1_BITS`] extra bits, which the second removes.
+#[inline(always)]
+fn forward_pass<S: Simd>(d: [i32x8<S>; 8], first: bool) -> [i32x8<S>; 8] {
+    let shift = if first {
+        CONST_BITS - PASS1_BITS
+    } else {
+        CONST_BITS + PASS1_BITS
+    };
+
+    let tmp0 = d[0] + d[7];
+    let tmp7 = d[0] - d[7];
+    let tmp1 = d[1] + d[6];
+    let tmp6 = d[1] - d[6];
+    let tmp2 = d[2] + d[5];
+    let tmp5 = d[2] - d[5];
+    let tmp3 = d[3] + d[4];
+    let tmp4 = d[3] - d[4];
+
+    // The even part.
+    let tmp10 = tmp0 + tmp3;
+    let tmp13 = tmp0 - tmp3;
+    let tmp11 = tmp1 + tmp2;
+    let tmp12 = tmp1 - tmp2;
+    let (out0, out4) = if first {
+        ((tmp10 + tmp11) << PASS1_BITS, (tmp10 - tmp11) << PASS1_BITS)
+    } else {
+        (
+            descale(tmp10 + tmp11, PASS1_BITS),
+            descale(tmp10 - tmp11, PASS1_BITS),
+        )
+    };
+    let z1 = (tmp12 + tmp13) * FIX_0_541196100;
+    let out2 = descale(z1 + tmp13 * FIX_0_765366865, shift);
+    let out6 = descale(z1 + tmp12 * -FIX_1_847759065, shift);
+
+    // The odd part.
+    let z1 = tmp4 + tmp7;
+    let z2 = tmp5 + tmp6;
+    let z3 = tmp4 + tmp6;
+    let z4 = tmp5 + tmp7;
+    let z5 = (z3 + z4) * FIX_1_175875602;
+    let tmp4 = tmp4 * FIX_0_298631336;
+    let tmp5 = tmp5 * FIX_2_053119869;
+    let tmp6 = tmp6 * FIX_3_072711026;
+    let tmp7 = tmp7 * FIX_1_501321110;
+    let z1 = z1 * -FIX_0_899976223;
+    let z2 = z2 * -FIX_2_562915447;
+    let z3 = z3 * -FIX_1_961570560 + z5;
+    let z4 = z4 * -FIX_0_390180644 + z5;
+    let out7 = descale(tmp4 + z1 + z3, shift);
+    let out5 = descale(tmp5 + z2 + z4, shift);
+    let out3 = descale(tmp6 + z2 + z3, shift);
+    let out1 = descale(tmp7 + z1 + z4, shift);
+
+    [out0, out1, out2, out3, out4, out5, out6, out7]
+}
+
+/// One pass of libjpeg's `jpeg_idct_islow`, over the 8 inputs in `d`, removing `shift` bits from the results.
+#[inline(always)]
+fn inverse_pass<S: Simd>(d: [i32x8<S>; 8], shift: u32) -> [i32x8<S>; 8] {
+    // The even part.
+    let z1 = (d[2] + d[6]) * FIX_0_541196100;
+    let tmp2 = z1 + d[6] * -FIX_1_847759065;
+    let tmp3 = z1 + d[2] * FIX_0_765366865;
+    let tmp0 = (d[0] + d[4]) << CONST_BITS;
+    let tmp1 = (d[0] - d[4]) << CONST_BITS;
+    let tmp10 = tmp0 + tmp3;
+    let tmp13 = tmp0 - tmp3;
+    let tmp11 = tmp1 + tmp2;
+    let tmp12 = tmp1 - tmp2;
+
+    // The odd part.
+    let (tmp0, tmp1, tmp2, tmp3) = (d[7], d[5], d[3], d[1]);
+    let z1 = tmp0 + tmp3;
+    let z2 = tmp1 + tmp2;
+    let z3 = tmp0 + tmp2;
+    let z4 = tmp1 + tmp3;
+    let z5 = (z3 + z4) * FIX_1_175875602;
+    let z1 = z1 * -FIX_0_899976223;
+    let z2 = z2 * -FIX_2_562915447;
+    let z3 = z3 * -FIX_1_961570560 + z5;
+    let z4 = z4 * -FIX_0_390180644 + z5;
+    let tmp0 = tmp0 * FIX_0_298631336 + z1 + z3;
+    let tmp1 = tmp1 * FIX_2_053119869 + z2 + z4;
+    let tmp2 = tmp2 * FIX_3_072711026 + z2 + z3;
+    let tmp3 = tmp3 * FIX_1_501321110 + z1 + z4;
+
+    [
+        descale(tmp10 + tmp3, shift),
+        descale(tmp11 + tmp2, shift),
+        descale(tmp12 + tmp1, shift),
+        descale(tmp13 + tmp0, shift),
+        descale(tmp13 - tmp0, shift),
+        descale(tmp12 - tmp1, shift),
+        descale(tmp11 - tmp2, shift),
+        descale(tmp10 - tmp3, shift),
+    ]
+}
+
+/// Divide by 2<sup>`n`</sup>, rounding to the nearest integer, like libjpeg's `DESCALE`.
+#[inline(always)]
+fn descale<S: Simd>(x: i32x8<S>, n: u32) -> i32x8<S> {
+    (x + (1 << (n - 1))) >> n
+}
+
+/// Transpose an 8x8 block of vectors.
+///
+/// Interleaving each vector with the one 4 after it moves the bits of each index one place, so three rounds swap
+/// the row and column.
+#[inline(always)]
+fn transpose<S: Simd>(mut v: [i32x8<S>; 8]) -> [i32x8<S>; 8] {
+    for _ in 0..3 {
+        let mut next = v;
+        for i in 0..4 {
+            (next[2 * i], next[2 * i + 1]) = v[i].interleave(v[i + 4]);
+        }
+        v = next;
+    }
+    v
+}
+
+/// Narrow two vectors of values which fit in an `i16` into one vector.
+#[inline(always)]
+fn narrow<S: Simd>(a: i32x8<S>, b: i32x8<S>) -> crate::i16x16<S> {
+    // Each value is the lower half of its `i32`, which is the first on little-endian targets.
+    let (lo, hi) = a.combine(b).bitcast::<i16x32<S>>().split();
+    if cfg!(target_endian = "big") {
+        lo.unzip_high(hi)
+    } else {
+        lo.unzip_low(hi)
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support code for the [`dump_lanes`](crate::dump_lanes) debugging macro.
+
+use std::fmt::Write as _;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use crate::{Simd, SimdBase, SimdMask};
+use crate::{
+    f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4,
+    i32x8, i32x16, i64x2, i64x4, i64x8, mask8x16, mask8x32, mask8x64, mask16x8, mask16x16,
+    mask16x32, mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x16, u8x32, u8x64,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
+};
+
+/// The maximum number of lanes printed on a single row before wrapping.
+const LANES_PER_ROW: usize = 16;
+
+/// Implementation detail of [`dump_lanes`](crate::dump_lanes); this is not public API.
+///
+/// Implemented for every vector and mask type.
+#[doc(hidden)]
+pub trait DumpLanes {
+    /// The short name of the type, e.g. `f32x4`.
+    fn type_name(&self) -> &'static str;
+
+    /// The name of the [`Simd`] implementation this value was created with, e.g. `Avx2`.
+    fn simd_name(&self) -> &'static str;
+
+    /// Format each lane as a string.
+    ///
+    /// Vector lanes use their `Debug` representation, and mask lanes are shown as `true`/`false`.
+    fn lanes(&self) -> Vec<String>;
+}
+
+macro_rules! impl_dump_lanes_vector {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<S: Simd> DumpLanes for $ty<S> {
+                fn type_name(&self) -> &'static str {
+                    stringify!($ty)
+                }
+
+                fn simd_name(&self) -> &'static str {
+                    simd_name::<S>()
+                }
+
+                fn lanes(&self) -> Vec<String> {
+                    self.as_slice().iter().map(|lane| std::format!("{lane:?}")).collect()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_dump_lanes_mask {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<S: Simd> DumpLanes for $ty<S> {
+                fn type_name(&self) -> &'static str {
+                    stringify!($ty)
+                }
+
+                fn simd_name(&self) -> &'static str {
+                    simd_name::<S>()
+                }
+
+                fn lanes(&self) -> Vec<String> {
+                    (0..Self::N).map(|i| self.test(i).to_string()).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_dump_lanes_vector!(
+    f32x4, i8x16, u8x16, i16x8, u16x8, i32x4, u32x4, f64x2, i64x2, u64x2, f32x8, i8x32, u8x32,
+    i16x16, u16x16, i32x8, u32x8, f64x4, i64x4, u64x4, f32x16, i8x64, u8x64, i16x32, u16x32,
+    i32x16, u32x16, f64x8, i64x8, u64x8,
+);
+
+impl_dump_lanes_mask!(
+    mask8x16, mask16x8, mask32x4, mask64x2, mask8x32, mask16x16, mask32x8, mask64x4, mask8x64,
+    mask16x32, mask32x16, mask64x8,
+);
+
+/// The name of the token type, without its module path.
+///
+/// This names the backend the code actually runs on, which can differ from [`Simd::level`]
+/// (for example, [`Fallback`](crate::Fallback) reports the baseline level).
+fn simd_name<S: Simd>() -> &'static str {
+    let name = core::any::type_name::<S>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Lay out the lanes as a table, with lane indices above the values they belong to.
+fn format_lanes(
+    location: &str,
+    label: &str,
+    type_name: &str,
+    simd: &str,
+    lanes: &[String],
+) -> String {
+    let width = lanes
+        .iter()
+        .map(String::len)
+        .chain(core::iter::once(
+            lanes.len().saturating_sub(1).to_string().len(),
+        ))
+        .max()
+        .unwrap_or(1);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "[{location}] {label}: {type_name} ({simd})");
+    for (row, chunk) in lanes.chunks(LANES_PER_ROW).enumerate() {
+        let first = row * LANES_PER_ROW;
+        out.push_str("  lane |");
+        for i in first..first + chunk.len() {
+            let _ = write!(out, " {i:>width$}");
+        }
+        out.push_str("\n       |");
+        for lane in chunk {
+            let _ = write!(out, " {lane:>width$}");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Implementation detail of [`dump_lanes`](crate::dump_lanes); this is not public API.
+#[doc(hidden)]
+#[inline(never)]
+#[expect(
+    clippy::print_stderr,
+    reason = "Printing to stderr is the purpose of `dump_lanes`, like `dbg!`."
+)]
+pub fn dump_lanes_impl(location: &str, label: &str, value: &dyn DumpLanes) {
+    let lanes = value.lanes();
+    std::eprint!(
+        "{}",
+        format_lanes(
+            location,
+            label,
+            value.type_name(),
+            value.simd_name(),
+            &lanes
+        )
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DumpLanes, format_lanes};
+    use crate::{Fallback, SimdBase, SimdMask, f32x4, mask32x4, u8x32};
+    use std::string::String;
+
+    fn dump(value: &dyn DumpLanes) -> String {
+        format_lanes(
+            "src/lib.rs:1",
+            "v",
+            value.type_name(),
+            value.simd_name(),
+            &value.lanes(),
+        )
+    }
+
+    #[test]
+    fn dump_vector_columns_are_aligned() {
+        let simd = Fallback::new();
+        let v = f32x4::from_slice(simd, &[1.0, -2.5, 10.0, 0.0]);
+        assert_eq!(
+            dump(&v),
+            concat!(
+                "[src/lib.rs:1] v: f32x4 (Fallback)\n",
+                "  lane |    0    1    2    3\n",
+                "       |  1.0 -2.5 10.0  0.0\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn dump_mask_shows_booleans() {
+        let simd = Fallback::new();
+        let mut m = mask32x4::splat(simd, false);
+        m.set(1, true);
+        assert_eq!(
+            dump(&m),
+            concat!(
+                "[src/lib.rs:1] v: mask32x4 (Fallback)\n",
+                "  lane |     0     1     2     3\n",
+                "       | false  true false false\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn dump_wide_vectors_wrap_rows() {
+        let simd = Fallback::new();
+        let v = u8x32::from_fn(simd, |i| u8::try_from(i).unwrap());
+        let out = dump(&v);
+        assert_eq!(
+            out.lines().count(),
+            5,
+            "expected a header and two rows of two lines: {out}"
+        );
+        assert!(
+            out.lines().nth(3).unwrap().starts_with("  lane | 16 17"),
+            "second row should start at lane 16: {out}"
+        );
+    }
+}
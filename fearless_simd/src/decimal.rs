@@ -0,0 +1,110 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Scaling fixed-point decimals by powers of ten, such as when converting prices between numbers of decimal places.
+//!
+//! A fixed-point decimal is stored as an `i64` number of its smallest unit: a price of 12.34 with 4 decimal places
+//! is `123_400`. Columns of these are added as integers, and converted to another number of decimal places by
+//! multiplying or dividing by a power of ten, which these functions do for each element of a vector of `i64`. The
+//! power is a const parameter `K`, from 0 to 18, which are the powers of ten which fit in an `i64`.
+//!
+//! Every result is exact, unless it overflows, and the same on every level:
+//!
+//! - [`mul_pow10`] wraps on overflow, like [`i64::wrapping_mul`], and [`mul_pow10_saturating`] saturates to
+//!   [`i64::MIN`] or [`i64::MAX`]. [`mul_pow10_overflows`] returns which lanes overflow, to reject them instead.
+//! - [`div_pow10`] rounds towards zero, like integer division, and [`div_pow10_round`] rounds to the nearest integer,
+//!   with halves rounded away from zero, as is usual for amounts of money. Neither can overflow.
+//!
+//! No level has a 64-bit multiply-high instruction, which vector division by a constant would use, so the divisions
+//! are computed for each lane, where the compiler replaces them with a multiplication by the reciprocal. The
+//! multiplications are vector operations.
+//!
+//! ```rust
+//! use fearless_simd::decimal::{div_pow10_round, mul_pow10_saturating};
+//! use fearless_simd::{Level, Simd, dispatch, i64x4, prelude::*};
+//!
+//! /// Converts prices with 4 decimal places to 2, and then to 6.
+//! #[inline(always)]
+//! fn rescale<S: Simd>(simd: S, prices: [i64; 4]) -> ([i64; 4], [i64; 4]) {
+//!     let prices = i64x4::from_slice(simd, &prices);
+//!     let cents = div_pow10_round::<2, _, _>(prices);
+//!     let micros = mul_pow10_saturating::<2, _, _>(prices);
+//!     (*cents, *micros)
+//! }
+//!
+//! let prices = [123_450, -123_450, 99, i64::MAX];
+//! let (cents, micros) = dispatch!(Level::new(), simd => rescale(simd, prices));
+//! assert_eq!(cents, [1_235, -1_235, 1, i64::MAX / 100]);
+//! assert_eq!(micros, [12_345_000, -12_345_000, 9_900, i64::MAX]);
+//! ```
+
+use crate::{Select, Simd, SimdInt};
+
+/// `10^K`, checking that it fits in an `i64`.
+const fn pow10<const K: u32>() -> i64 {
+    assert!(K <= 18, "10^K only fits in an `i64` for K up to 18");
+    10_i64.pow(K)
+}
+
+/// Multiply each element by `10^K`, wrapping on overflow.
+///
+/// This is a single multiplication, whose result is exact if it doesn't overflow. Use [`mul_pow10_overflows`] to
+/// check which lanes overflow, or [`mul_pow10_saturating`] to clamp them.
+#[inline(always)]
+pub fn mul_pow10<const K: u32, S: Simd, V: SimdInt<S, Element = i64>>(a: V) -> V {
+    a * const { pow10::<K>() }
+}
+
+/// Multiply each element by `10^K`, saturating to [`i64::MIN`] or [`i64::MAX`] on overflow.
+#[inline(always)]
+pub fn mul_pow10_saturating<const K: u32, S: Simd, V: SimdInt<S, Element = i64>>(a: V) -> V
+where
+    V::Mask: Select<V>,
+{
+    let simd = a.witness();
+    let (min, max) = const { (i64::MIN / pow10::<K>(), i64::MAX / pow10::<K>()) };
+    let product = mul_pow10::<K, S, V>(a);
+    let product = a.simd_gt(max).select(V::splat(simd, i64::MAX), product);
+    a.simd_lt(min).select(V::splat(simd, i64::MIN), product)
+}
+
+/// Returns a mask of the elements which overflow when multiplied by `10^K`, as with [`mul_pow10`].
+///
+/// A column can be checked with this before it's scaled, to reject values which are out of range rather than
+/// clamping or wrapping them.
+#[inline(always)]
+pub fn mul_pow10_overflows<const K: u32, S: Simd, V: SimdInt<S, Element = i64>>(a: V) -> V::Mask {
+    // Division rounds towards zero, so these are the smallest and largest elements whose products fit.
+    let (min, max) = const { (i64::MIN / pow10::<K>(), i64::MAX / pow10::<K>()) };
+    a.simd_gt(max) | a.simd_lt(min)
+}
+
+/// Divide each element by `10^K`, rounding towards zero.
+///
+/// This is the same as dividing each element with `/`, and can't overflow.
+#[inline(always)]
+pub fn div_pow10<const K: u32, S: Simd, V: SimdInt<S, Element = i64>>(a: V) -> V {
+    let divisor = const { pow10::<K>() };
+    V::from_fn(a.witness(), |i| a[i] / divisor)
+}
+
+/// Divide each element by `10^K`, rounding to the nearest integer, with halves rounded away from zero.
+///
+/// This is how amounts of money are usually rounded, so that `0.5` cents becomes one cent, and `-0.5` cents minus
+/// one cent. It can't overflow.
+#[inline(always)]
+pub fn div_pow10_round<const K: u32, S: Simd, V: SimdInt<S, Element = i64>>(a: V) -> V {
+    let divisor = const { pow10::<K>() };
+    V::from_fn(a.witness(), |i| {
+        let (quotient, remainder) = (a[i] / divisor, a[i] % divisor);
+        // The remainder has the sign of the dividend, and is rounded away from zero if it's at least half of the
+        // divisor. Comparing it with the rest of the divisor avoids doubling it, which could overflow.
+        if remainder >= divisor - remainder {
+            quotient + 1
+        } else if -remainder >= divisor + remainder {
+            quotient - 1
+        } else {
+            quotient
+        }
+    })
+}
@@ -0,0 +1,296 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Delta and delta-of-delta encoding of integers, which turn slowly changing series into small numbers.
+//!
+//! Timestamps taken at a regular interval, counters, and sorted IDs differ little from one element to the next.
+//! Storing the differences ([`encode_u32`]) instead of the values leaves small numbers, which compress well with
+//! varints or bit packing. Storing the differences of the differences ([`encode_delta_of_delta_u32`]) leaves zeros
+//! for the parts of a series which change at a constant rate, which is how time-series databases store timestamps.
+//!
+//! Every function works in place, with wrapping arithmetic, so decoding gives back exactly the encoded values even if
+//! a difference overflows. A series can be encoded or decoded in parts: each function takes the state before the
+//! first element, and returns the state after the last, to be passed to the call for the next part.
+//!
+//! ```rust
+//! use fearless_simd::delta::{Previous, decode_delta_of_delta_i64, encode_delta_of_delta_i64};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! // Timestamps every 15 seconds, with one sample a second late.
+//! let timestamps = [1_000, 1_015, 1_030, 1_046, 1_060, 1_075];
+//! let mut values = timestamps;
+//! let level = Level::new();
+//! dispatch!(level, simd => encode_delta_of_delta_i64(simd, &mut values, Previous::default()));
+//! // The first value, then the first difference, and then only the changes to the difference.
+//! assert_eq!(values, [1_000, -985, 0, 1, -2, 1]);
+//!
+//! dispatch!(level, simd => decode_delta_of_delta_i64(simd, &mut values, Previous::default()));
+//! assert_eq!(values, timestamps);
+//! ```
+
+use crate::slice::{inclusive_scan_vector, load_tail};
+use crate::{Simd, SimdBase, SimdInt};
+
+/// The state of a series before an element: the element before it, and the difference between that element and the
+/// one before it.
+///
+/// The default state is zero for both, so that the first element of a series is encoded as itself.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Previous<T> {
+    /// The previous element.
+    pub value: T,
+    /// The difference between the previous element and the one before it.
+    pub delta: T,
+}
+
+/// Replaces each element of `values` with its difference from the element before it, and returns the last element.
+///
+/// The first element is replaced with its difference from `previous`. The differences wrap on overflow.
+///
+/// Each difference is found by subtracting a copy of the vector which is shifted by one lane, with the last element
+/// of the previous vector shifted in.
+#[inline(always)]
+pub fn encode_u32<S: Simd>(simd: S, values: &mut [u32], previous: u32) -> u32 {
+    encode::<S, S::u32s>(simd, values, previous)
+}
+
+/// Replaces each element of `values` with the sum of `previous` and all of the elements up to and including it, and
+/// returns the last sum.
+///
+/// This reverses [`encode_u32`], with the same `previous`. The sums wrap on overflow.
+#[inline(always)]
+pub fn decode_u32<S: Simd>(simd: S, values: &mut [u32], previous: u32) -> u32 {
+    decode::<S, S::u32s>(simd, values, previous)
+}
+
+/// Replaces each element of `values` with the difference between its own difference from the element before it
+/// and that of the element before it, and returns the state after the last element.
+///
+/// The first element uses `previous` for the element and the difference before it. The differences wrap on
+/// overflow.
+#[inline(always)]
+pub fn encode_delta_of_delta_u32<S: Simd>(
+    simd: S,
+    values: &mut [u32],
+    previous: Previous<u32>,
+) -> Previous<u32> {
+    encode_delta_of_delta::<S, S::u32s>(simd, values, previous)
+}
+
+/// Reverses [`encode_delta_of_delta_u32`], with the same `previous`, and returns the state after the last element.
+///
+/// This is two prefix sums of each vector: the first gives back the differences, and the second the elements.
+#[inline(always)]
+pub fn decode_delta_of_delta_u32<S: Simd>(
+    simd: S,
+    values: &mut [u32],
+    previous: Previous<u32>,
+) -> Previous<u32> {
+    decode_delta_of_delta::<S, S::u32s>(simd, values, previous)
+}
+
+/// The `i32` version of [`encode_u32`].
+#[inline(always)]
+pub fn encode_i32<S: Simd>(simd: S, values: &mut [i32], previous: i32) -> i32 {
+    encode::<S, S::i32s>(simd, values, previous)
+}
+
+/// The `i32` version of [`decode_u32`].
+#[inline(always)]
+pub fn decode_i32<S: Simd>(simd: S, values: &mut [i32], previous: i32) -> i32 {
+    decode::<S, S::i32s>(simd, values, previous)
+}
+
+/// The `i32` version of [`encode_delta_of_delta_u32`].
+#[inline(always)]
+pub fn encode_delta_of_delta_i32<S: Simd>(
+    simd: S,
+    values: &mut [i32],
+    previous: Previous<i32>,
+) -> Previous<i32> {
+    encode_delta_of_delta::<S, S::i32s>(simd, values, previous)
+}
+
+/// The `i32` version of [`decode_delta_of_delta_u32`].
+#[inline(always)]
+pub fn decode_delta_of_delta_i32<S: Simd>(
+    simd: S,
+    values: &mut [i32],
+    previous: Previous<i32>,
+) -> Previous<i32> {
+    decode_delta_of_delta::<S, S::i32s>(simd, values, previous)
+}
+
+/// The `u64` version of [`encode_u32`].
+#[inline(always)]
+pub fn encode_u64<S: Simd>(simd: S, values: &mut [u64], previous: u64) -> u64 {
+    encode::<S, S::u64s>(simd, values, previous)
+}
+
+/// The `u64` version of [`decode_u32`].
+#[inline(always)]
+pub fn decode_u64<S: Simd>(simd: S, values: &mut [u64], previous: u64) -> u64 {
+    decode::<S, S::u64s>(simd, values, previous)
+}
+
+/// The `u64` version of [`encode_delta_of_delta_u32`].
+#[inline(always)]
+pub fn encode_delta_of_delta_u64<S: Simd>(
+    simd: S,
+    values: &mut [u64],
+    previous: Previous<u64>,
+) -> Previous<u64> {
+    encode_delta_of_delta::<S, S::u64s>(simd, values, previous)
+}
+
+/// The `u64` version of [`decode_delta_of_delta_u32`].
+#[inline(always)]
+pub fn decode_delta_of_delta_u64<S: Simd>(
+    simd: S,
+    values: &mut [u64],
+    previous: Previous<u64>,
+) -> Previous<u64> {
+    decode_delta_of_delta::<S, S::u64s>(simd, values, previous)
+}
+
+/// The `i64` version of [`encode_u32`].
+#[inline(always)]
+pub fn encode_i64<S: Simd>(simd: S, values: &mut [i64], previous: i64) -> i64 {
+    encode::<S, S::i64s>(simd, values, previous)
+}
+
+/// The `i64` version of [`decode_u32`].
+#[inline(always)]
+pub fn decode_i64<S: Simd>(simd: S, values: &mut [i64], previous: i64) -> i64 {
+    decode::<S, S::i64s>(simd, values, previous)
+}
+
+/// The `i64` version of [`encode_delta_of_delta_u32`].
+#[inline(always)]
+pub fn encode_delta_of_delta_i64<S: Simd>(
+    simd: S,
+    values: &mut [i64],
+    previous: Previous<i64>,
+) -> Previous<i64> {
+    encode_delta_of_delta::<S, S::i64s>(simd, values, previous)
+}
+
+/// The `i64` version of [`decode_delta_of_delta_u32`].
+#[inline(always)]
+pub fn decode_delta_of_delta_i64<S: Simd>(
+    simd: S,
+    values: &mut [i64],
+    previous: Previous<i64>,
+) -> Previous<i64> {
+    decode_delta_of_delta::<S, S::i64s>(simd, values, previous)
+}
+
+/// The differences of the lanes of `v` from the lanes before them, with `previous` before the first lane.
+#[inline(always)]
+fn differences<S: Simd, V: SimdInt<S>>(v: V, previous: V::Element) -> V {
+    v - v.shift_elements_right::<1>(previous)
+}
+
+#[inline(always)]
+fn encode<S: Simd, V: SimdInt<S>>(
+    simd: S,
+    values: &mut [V::Element],
+    previous: V::Element,
+) -> V::Element
+where
+    V::Element: Copy,
+{
+    let mut previous = previous;
+    map_vectors(simd, values, |v: V, len| {
+        let deltas = differences(v, previous);
+        previous = v[len - 1];
+        deltas
+    });
+    previous
+}
+
+#[inline(always)]
+fn decode<S: Simd, V: SimdInt<S>>(
+    simd: S,
+    values: &mut [V::Element],
+    previous: V::Element,
+) -> V::Element
+where
+    V::Element: Copy,
+{
+    let mut previous = previous;
+    map_vectors(simd, values, |deltas: V, len| {
+        let v = inclusive_scan_vector(deltas, previous);
+        previous = v[len - 1];
+        v
+    });
+    previous
+}
+
+#[inline(always)]
+fn encode_delta_of_delta<S: Simd, V: SimdInt<S>>(
+    simd: S,
+    values: &mut [V::Element],
+    previous: Previous<V::Element>,
+) -> Previous<V::Element>
+where
+    V::Element: Copy,
+{
+    let mut previous = previous;
+    map_vectors(simd, values, |v: V, len| {
+        let deltas = differences(v, previous.value);
+        let deltas_of_deltas = differences(deltas, previous.delta);
+        previous = Previous {
+            value: v[len - 1],
+            delta: deltas[len - 1],
+        };
+        deltas_of_deltas
+    });
+    previous
+}
+
+#[inline(always)]
+fn decode_delta_of_delta<S: Simd, V: SimdInt<S>>(
+    simd: S,
+    values: &mut [V::Element],
+    previous: Previous<V::Element>,
+) -> Previous<V::Element>
+where
+    V::Element: Copy,
+{
+    let mut previous = previous;
+    map_vectors(simd, values, |deltas_of_deltas: V, len| {
+        let deltas = inclusive_scan_vector(deltas_of_deltas, previous.delta);
+        let v = inclusive_scan_vector(deltas, previous.value);
+        previous = Previous {
+            value: v[len - 1],
+            delta: deltas[len - 1],
+        };
+        v
+    });
+    previous
+}
+
+/// Replaces each vector of `values` with the result of `f`, which is also passed the number of lanes which hold
+/// elements.
+///
+/// The last vector is padded with zeros. Since every function here only carries state forwards through the lanes,
+/// the padding doesn't change the lanes before it.
+#[inline(always)]
+fn map_vectors<S: Simd, V: SimdBase<S>>(
+    simd: S,
+    values: &mut [V::Element],
+    mut f: impl FnMut(V, usize) -> V,
+) where
+    V::Element: Copy,
+{
+    let mut chunks = values.chunks_exact_mut(V::N);
+    for chunk in &mut chunks {
+        f(V::from_slice(simd, chunk), V::N).store_slice(chunk);
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        let v = f(load_tail(simd, tail, V::ADD_IDENTITY), tail.len());
+        tail.copy_from_slice(&v.as_slice()[..tail.len()]);
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Flushing subnormal floats to zero while running some code.
+//!
+//! Subnormal (or denormal) floats are those closer to zero than [`f32::MIN_POSITIVE`]. Most CPUs handle them
+//! much more slowly than other floats: on x86, an operation with a subnormal input or result can take around a
+//! hundred times longer. They're common in audio processing, where the output of a filter or a reverb decays
+//! towards zero after its input goes silent, and can then make a real-time callback miss its deadline.
+//!
+//! The CPU can instead be told to replace subnormals with zero, which is what [`SimdFlushToZero`] does while
+//! running a closure. The mode is restored afterwards, even if the closure panics, so that code which expects
+//! subnormals (including other code on the same thread) isn't affected.
+//!
+//! - On x86 and x86-64, this sets the flush-to-zero (FTZ) and denormals-are-zero (DAZ) bits of the `MXCSR`
+//!   register, so that subnormal results and inputs are both zero. On 32 bit x86, DAZ is only set if the level is
+//!   [`Sse4_2`](crate::Sse4_2) or better, as some earlier CPUs don't support it, and nothing is changed if the
+//!   level isn't [`Sse2`](crate::Sse2) or better and the target doesn't enable SSE.
+//! - On 64 bit ARM, this sets the FZ bit of the `FPCR` register, which flushes both subnormal results and inputs.
+//!   Subnormal `f16` values aren't flushed.
+//! - On other targets, including WebAssembly (which has no such mode) and `arm64ec`, the closure is run unchanged.
+//!   [`SimdFlushToZero::supports_flush_to_zero`] returns whether the mode is changed.
+//! - With the `soft_float` feature, the [`Fallback`](crate::Fallback) level computes floats with integer
+//!   arithmetic, which the mode doesn't affect, so nothing is changed on that level.
+//!
+//! The mode only applies to the current thread, so it doesn't affect threads which are spawned by the closure.
+//!
+//! The compiler assumes that subnormals aren't flushed, so operations which it evaluates at compile time aren't
+//! flushed either, and operations inside the closure could in principle be moved to before or after it. This only
+//! changes whether a subnormal is replaced by zero, so this is suitable for avoiding the slowdown, but not for
+//! code which relies on subnormals being flushed for its results.
+//!
+//! ```rust
+//! use fearless_simd::{Level, SimdFlushToZero, dispatch, prelude::*};
+//!
+//! /// A one-pole low-pass filter, whose output decays towards zero once the input is silent.
+//! #[inline(always)]
+//! fn low_pass<S: Simd>(simd: S, input: &[f32], output: &mut [f32]) {
+//!     let n = S::f32s::N;
+//!     simd.with_flush_to_zero(|| {
+//!         let mut state = S::f32s::splat(simd, 1.0);
+//!         for (input, output) in input.chunks_exact(n).zip(output.chunks_exact_mut(n)) {
+//!             state = (S::f32s::from_slice(simd, input) - state).mul_add(0.5, state);
+//!             state.store_slice(output);
+//!         }
+//!     });
+//! }
+//!
+//! let input = [0.0; 4096];
+//! let mut output = [1.0; 4096];
+//! dispatch!(Level::new(), simd => low_pass(simd, &input, &mut output));
+//! assert_eq!(output[4095], 0.0);
+//! ```
+
+use crate::{Level, Simd};
+
+/// Flushing subnormal floats to zero while running a closure.
+///
+/// This is implemented for every [`Simd`] level. See the [module documentation](crate::denormal) for what this
+/// changes on each target.
+pub trait SimdFlushToZero: Simd {
+    /// Run `f` with subnormal results and inputs of float operations replaced by zero.
+    ///
+    /// The previous mode is restored when `f` returns or panics, so this can be nested.
+    fn with_flush_to_zero<R>(self, f: impl FnOnce() -> R) -> R;
+
+    /// Whether [`with_flush_to_zero`](Self::with_flush_to_zero) changes the mode on this level and target.
+    fn supports_flush_to_zero(self) -> bool;
+}
+
+impl<S: Simd> SimdFlushToZero for S {
+    #[inline]
+    fn with_flush_to_zero<R>(self, f: impl FnOnce() -> R) -> R {
+        let bits = flush_bits::<S>(self.level());
+        if bits == 0 {
+            return f();
+        }
+        let _restore = Restore::set(bits);
+        f()
+    }
+
+    #[inline]
+    fn supports_flush_to_zero(self) -> bool {
+        flush_bits::<S>(self.level()) != 0
+    }
+}
+
+/// Restores the control register to its previous value when dropped.
+struct Restore {
+    previous: Control,
+}
+
+impl Restore {
+    /// Set `bits` in the control register, until the returned value is dropped.
+    #[inline(always)]
+    fn set(bits: Control) -> Self {
+        let previous = read_control();
+        write_control(previous | bits);
+        Self { previous }
+    }
+}
+
+impl Drop for Restore {
+    #[inline(always)]
+    fn drop(&mut self) {
+        write_control(self.previous);
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod arch {
+    use core::arch::asm;
+
+    use crate::Level;
+
+    /// The type of `MXCSR`.
+    pub(super) type Control = u32;
+
+    /// The flush-to-zero bit of `MXCSR`.
+    const FTZ: u32 = 1 << 15;
+
+    /// The denormals-are-zero bit of `MXCSR`.
+    const DAZ: u32 = 1 << 6;
+
+    /// The bits of `MXCSR` which flush subnormals on `level`, or zero if it doesn't have `MXCSR`.
+    pub(super) fn flush_bits(level: Level) -> u32 {
+        // Every x86-64 CPU supports DAZ, as does every 32 bit CPU with SSE4.2.
+        if cfg!(target_arch = "x86_64") || level.as_sse4_2().is_some() {
+            FTZ | DAZ
+        } else if cfg!(target_feature = "sse") || level.as_sse2().is_some() {
+            FTZ
+        } else {
+            0
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn read_control() -> u32 {
+        let mut mxcsr = 0_u32;
+        // Safety: `flush_bits` only returns bits to set if SSE is available, so `stmxcsr` is too. It writes 4
+        // bytes to `mxcsr`.
+        unsafe {
+            asm!("stmxcsr [{}]", in(reg) &mut mxcsr, options(nostack, preserves_flags));
+        }
+        mxcsr
+    }
+
+    #[inline(always)]
+    pub(super) fn write_control(mxcsr: u32) {
+        // Safety: `ldmxcsr` is available as in `read_control`, and `mxcsr` only differs from the value which was
+        // read in bits which `flush_bits` checked are supported. It reads 4 bytes from `mxcsr`.
+        unsafe {
+            asm!("ldmxcsr [{}]", in(reg) &mxcsr, options(nostack, preserves_flags, readonly));
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    use core::arch::asm;
+
+    use crate::Level;
+
+    /// The type of `FPCR`.
+    pub(super) type Control = u64;
+
+    /// The flush-to-zero bit of `FPCR`.
+    const FZ: u64 = 1 << 24;
+
+    /// The bits of `FPCR` which flush subnormals, which every 64 bit ARM CPU supports.
+    pub(super) fn flush_bits(_level: Level) -> u64 {
+        FZ
+    }
+
+    #[inline(always)]
+    pub(super) fn read_control() -> u64 {
+        let fpcr: u64;
+        // Safety: `FPCR` can always be read.
+        unsafe {
+            asm!("mrs {}, fpcr", out(reg) fpcr, options(nomem, nostack, preserves_flags));
+        }
+        fpcr
+    }
+
+    #[inline(always)]
+    pub(super) fn write_control(fpcr: u64) {
+        // Safety: `fpcr` only differs from the value which was read in the FZ bit, which only changes how
+        // subnormals are handled.
+        unsafe {
+            asm!("msr fpcr, {}", in(reg) fpcr, options(nostack, preserves_flags));
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+mod arch {
+    use crate::Level;
+
+    /// There's no control register to change.
+    pub(super) type Control = u8;
+
+    pub(super) fn flush_bits(_level: Level) -> u8 {
+        0
+    }
+
+    pub(super) fn read_control() -> u8 {
+        0
+    }
+
+    pub(super) fn write_control(_control: u8) {}
+}
+
+use arch::{Control, read_control, write_control};
+
+/// The bits of the control register which flush subnormals for the level `S`, whose [`Level`] is `level`, or zero if
+/// that isn't supported.
+#[inline]
+fn flush_bits<S: Simd>(level: Level) -> Control {
+    // The fallback level's float operations don't use the float unit with `soft_float`. This depends on the token
+    // type, as the fallback level's `Level` is the baseline level unless `force_support_fallback` is enabled.
+    if S::SOFT_FLOAT {
+        return 0;
+    }
+    host_flush_bits(level)
+}
+
+/// The bits of the control register which flush subnormals on `level`, or zero if that isn't supported.
+#[inline]
+fn host_flush_bits(level: Level) -> Control {
+    // Custom levels aren't proof of any instruction set, but their host is.
+    #[cfg(feature = "custom_level")]
+    if let Level::Custom(custom) = level {
+        return host_flush_bits(custom.host());
+    }
+    arch::flush_bits(level)
+}
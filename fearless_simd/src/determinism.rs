@@ -0,0 +1,300 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Choosing how reproducible float results are, for a whole kernel at once.
+//!
+//! Several float operations have a fast variant, whose results can differ between levels, and a precise one:
+//! [`min`](SimdFloat::min) and [`min_precise`](SimdFloat::min_precise), [`to_int`](SimdFloat::to_int) and
+//! [`to_int_precise`](SimdFloat::to_int_precise), [`approximate_recip`](SimdFloat::approximate_recip) and
+//! division, and [`mul_add`](SimdFloat::mul_add), which is fused on some levels and not others. Rather than choosing
+//! between them at every call, a kernel can take a [`DeterminismLevel`] type parameter, and call its methods, so that
+//! its caller chooses for all of them with one of the tiers:
+//!
+//! - [`BitExact`]: The results are the same on every level and target. Apart from these methods, the arithmetic
+//!   operators, [`sqrt`](SimdFloat::sqrt), rounding, comparisons and lane movement are all exact, so a kernel
+//!   built from them gives the same bits everywhere. The exceptions are the sign and payload of NaN results, which
+//!   depend on the hardware, and the order of reductions across lanes, which depends on the vector width. Use
+//!   fixed-width types such as [`f32x8`](crate::f32x8), rather than [`Simd::f32s`], if a kernel reduces lanes.
+//! - [`UlpBounded`]: Each result is within a known bound of the exact result, but may differ between levels.
+//!   [`mul_add`](DeterminismLevel::mul_add) rounds once or twice, depending on the level, so its error is at most
+//!   half an ULP of the product plus half an ULP of the result.
+//! - [`Fast`]: The fastest variant on each level, with the results of edge cases left to the hardware, as
+//!   described by each method.
+//!
+//! Subnormals are flushed to zero by none of these, unless the kernel is run inside
+//! [`with_flush_to_zero`](crate::SimdFlushToZero::with_flush_to_zero).
+//!
+//! ```rust
+//! use fearless_simd::determinism::{BitExact, DeterminismLevel, Fast};
+//! use fearless_simd::{Level, dispatch, f32x4, prelude::*};
+//!
+//! /// Clamp `a * b + c` to `[0, 1]`, and scale it to a byte.
+//! #[inline(always)]
+//! fn blend<D: DeterminismLevel, S: Simd>(simd: S, a: [f32; 4], b: [f32; 4], c: [f32; 4]) -> [u32; 4] {
+//!     let [a, b, c] = [a, b, c].map(|v| f32x4::from_slice(simd, &v));
+//!     let value = D::mul_add(a, b, c);
+//!     let clamped = D::min(D::max(value, f32x4::splat(simd, 0.0)), f32x4::splat(simd, 1.0));
+//!     *D::to_uint(clamped * 255.0)
+//! }
+//!
+//! let level = Level::new();
+//! let a = [0.5, 2.0, f32::NAN, 0.1];
+//! let b = [0.5, 1.0, 1.0, 0.3];
+//! let c = [0.0, -0.5, 0.0, 0.2];
+//! // The same bits on every machine, with NaN clamped to zero.
+//! let exact = dispatch!(level, simd => blend::<BitExact, _>(simd, a, b, c));
+//! assert_eq!(exact, [63, 255, 0, 58]);
+//! // Possibly faster, with the lane which is NaN depending on the hardware.
+//! let fast = dispatch!(level, simd => blend::<Fast, _>(simd, a, b, c));
+//! assert_eq!([fast[0], fast[1], fast[3]], [63, 255, 58]);
+//! ```
+
+use core::fmt::Debug;
+
+use crate::seal::Seal;
+use crate::{Select, Simd, SimdCvtTruncate, SimdFloat};
+
+/// A tier of reproducibility for float results, which selects a variant of each of its methods.
+///
+/// This is implemented by [`BitExact`], [`UlpBounded`] and [`Fast`]. See the
+/// [module documentation](crate::determinism) for what each guarantees.
+pub trait DeterminismLevel: Copy + Debug + Default + Send + Sync + 'static + Seal {
+    /// Whether the results of kernels which only use these methods and the exact operations are the same on every
+    /// level, apart from the sign and payload of NaN.
+    const BIT_EXACT: bool;
+
+    /// Compute `a * b + c` for each element.
+    ///
+    /// This is [`mul_add`](SimdFloat::mul_add) for [`UlpBounded`], which is fused if [`Simd::FUSED_MUL_ADD`].
+    /// [`BitExact`] multiplies and then adds, rounding twice on every level, as fusing on levels without a fused
+    /// multiply-add instruction would be much slower. [`Fast`] uses
+    /// [`mul_add_relaxed`](SimdFloat::mul_add_relaxed), which is the same as `mul_add`, except on WebAssembly with the
+    /// `relaxed-simd` feature, where whether it's fused depends on the host.
+    fn mul_add<S: Simd, V: SimdFloat<S>>(a: V, b: V, c: V) -> V;
+
+    /// Compute the minimum of each element.
+    ///
+    /// For [`BitExact`], this is `b` if `a` isn't less than it, other than when `b` is NaN and `a` isn't. So if
+    /// only one of them is NaN, this is the other, and of positive and negative zero, this is `b`. For
+    /// [`UlpBounded`], this is [`min_precise`](SimdFloat::min_precise), which also returns the operand which isn't
+    /// NaN, but either zero. For [`Fast`], this is [`min`](SimdFloat::min), whose result for NaN depends on the
+    /// level.
+    fn min<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>;
+
+    /// Compute the maximum of each element.
+    ///
+    /// This is the same as [`min`](Self::min), choosing the greater operand.
+    fn max<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>;
+
+    /// Convert each element to an integer, rounding towards zero.
+    ///
+    /// For [`BitExact`] and [`UlpBounded`], this is [`to_int_precise`](SimdFloat::to_int_precise), which saturates
+    /// values out of range, and converts NaN to zero. For [`Fast`], this is [`to_int`](SimdFloat::to_int), whose
+    /// result for those depends on the level.
+    fn to_int<S: Simd, V: SimdFloat<S>, T: SimdCvtTruncate<V>>(a: V) -> T;
+
+    /// Convert each element to an unsigned integer, rounding towards zero.
+    ///
+    /// This is the same as [`to_int`](Self::to_int), with [`to_uint`](SimdFloat::to_uint) and
+    /// [`to_uint_precise`](SimdFloat::to_uint_precise).
+    fn to_uint<S: Simd, V: SimdFloat<S>>(a: V) -> V::Uint
+    where
+        V::Uint: SimdCvtTruncate<V>;
+
+    /// Compute the reciprocal (`1 / a`) of each element.
+    ///
+    /// For [`BitExact`] and [`UlpBounded`], this is a division, which is correctly rounded. For [`Fast`], this is
+    /// [`approximate_recip`](SimdFloat::approximate_recip), whose relative error can be as large as 2<sup>-8</sup>.
+    fn recip<S: Simd, V: SimdFloat<S>>(a: V) -> V
+    where
+        V::Element: From<f32>;
+
+    /// Select elements from `if_true` and `if_false` based on `mask`.
+    ///
+    /// For [`BitExact`] and [`UlpBounded`], this is [`select`](Select::select). For [`Fast`], this is
+    /// [`select_relaxed`](Select::select_relaxed), which is the same for masks whose lanes are all zeroes or all ones,
+    /// and otherwise depends on the host on WebAssembly with the `relaxed-simd` feature.
+    fn select<T: Seal, M: Select<T>>(mask: M, if_true: T, if_false: T) -> T;
+}
+
+/// The tier whose results are the same on every level and target.
+///
+/// See [`DeterminismLevel`] for the variant of each op which this selects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitExact;
+
+/// The tier whose results are within a known bound of the exact result, but may differ between levels.
+///
+/// See [`DeterminismLevel`] for the variant of each op which this selects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UlpBounded;
+
+/// The tier which uses the fastest variant of each op on each level.
+///
+/// See [`DeterminismLevel`] for the variant of each op which this selects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fast;
+
+impl Seal for BitExact {}
+impl Seal for UlpBounded {}
+impl Seal for Fast {}
+
+impl DeterminismLevel for BitExact {
+    const BIT_EXACT: bool = true;
+
+    #[inline(always)]
+    fn mul_add<S: Simd, V: SimdFloat<S>>(a: V, b: V, c: V) -> V {
+        a * b + c
+    }
+
+    #[inline(always)]
+    fn min<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>,
+    {
+        let lesser = a.simd_lt(b).select(a, b);
+        // `b` isn't equal to itself if it's NaN.
+        b.simd_eq(b).select(lesser, a)
+    }
+
+    #[inline(always)]
+    fn max<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>,
+    {
+        let greater = a.simd_gt(b).select(a, b);
+        b.simd_eq(b).select(greater, a)
+    }
+
+    #[inline(always)]
+    fn to_int<S: Simd, V: SimdFloat<S>, T: SimdCvtTruncate<V>>(a: V) -> T {
+        a.to_int_precise()
+    }
+
+    #[inline(always)]
+    fn to_uint<S: Simd, V: SimdFloat<S>>(a: V) -> V::Uint
+    where
+        V::Uint: SimdCvtTruncate<V>,
+    {
+        a.to_uint_precise()
+    }
+
+    #[inline(always)]
+    fn recip<S: Simd, V: SimdFloat<S>>(a: V) -> V
+    where
+        V::Element: From<f32>,
+    {
+        V::splat(a.witness(), 1.0.into()) / a
+    }
+
+    #[inline(always)]
+    fn select<T: Seal, M: Select<T>>(mask: M, if_true: T, if_false: T) -> T {
+        mask.select(if_true, if_false)
+    }
+}
+
+impl DeterminismLevel for UlpBounded {
+    const BIT_EXACT: bool = false;
+
+    #[inline(always)]
+    fn mul_add<S: Simd, V: SimdFloat<S>>(a: V, b: V, c: V) -> V {
+        a.mul_add(b, c)
+    }
+
+    #[inline(always)]
+    fn min<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>,
+    {
+        a.min_precise(b)
+    }
+
+    #[inline(always)]
+    fn max<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>,
+    {
+        a.max_precise(b)
+    }
+
+    #[inline(always)]
+    fn to_int<S: Simd, V: SimdFloat<S>, T: SimdCvtTruncate<V>>(a: V) -> T {
+        a.to_int_precise()
+    }
+
+    #[inline(always)]
+    fn to_uint<S: Simd, V: SimdFloat<S>>(a: V) -> V::Uint
+    where
+        V::Uint: SimdCvtTruncate<V>,
+    {
+        a.to_uint_precise()
+    }
+
+    #[inline(always)]
+    fn recip<S: Simd, V: SimdFloat<S>>(a: V) -> V
+    where
+        V::Element: From<f32>,
+    {
+        V::splat(a.witness(), 1.0.into()) / a
+    }
+
+    #[inline(always)]
+    fn select<T: Seal, M: Select<T>>(mask: M, if_true: T, if_false: T) -> T {
+        mask.select(if_true, if_false)
+    }
+}
+
+impl DeterminismLevel for Fast {
+    const BIT_EXACT: bool = false;
+
+    #[inline(always)]
+    fn mul_add<S: Simd, V: SimdFloat<S>>(a: V, b: V, c: V) -> V {
+        a.mul_add_relaxed(b, c)
+    }
+
+    #[inline(always)]
+    fn min<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>,
+    {
+        a.min(b)
+    }
+
+    #[inline(always)]
+    fn max<S: Simd, V: SimdFloat<S>>(a: V, b: V) -> V
+    where
+        V::Mask: Select<V>,
+    {
+        a.max(b)
+    }
+
+    #[inline(always)]
+    fn to_int<S: Simd, V: SimdFloat<S>, T: SimdCvtTruncate<V>>(a: V) -> T {
+        a.to_int()
+    }
+
+    #[inline(always)]
+    fn to_uint<S: Simd, V: SimdFloat<S>>(a: V) -> V::Uint
+    where
+        V::Uint: SimdCvtTruncate<V>,
+    {
+        a.to_uint()
+    }
+
+    #[inline(always)]
+    fn recip<S: Simd, V: SimdFloat<S>>(a: V) -> V
+    where
+        V::Element: From<f32>,
+    {
+        a.approximate_recip()
+    }
+
+    #[inline(always)]
+    fn select<T: Seal, M: Select<T>>(mask: M, if_true: T, if_false: T) -> T {
+        mask.select_relaxed(if_true, if_false)
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The scalar float operations which the fallback implementations compute each lane with.
+//!
+//! By default, these are the operators and methods of `f32` and `f64`, which come from [libm] without the standard
+//! library. With the `soft_float` feature, the `soft_float` module computes them with integer arithmetic instead.
+//!
+//! [libm]: https://crates.io/crates/libm
+
+/// Float operations which may differ between hosts, and so are computed the same way in every lane of the fallback
+/// level.
+///
+/// The generated code calls these as `FallbackFloat::add(a, b)`, which never conflicts with the operator traits or
+/// inherent methods of the same name.
+pub(crate) trait FallbackFloat: Copy {
+    fn add(self, rhs: Self) -> Self;
+    fn sub(self, rhs: Self) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+    fn div(self, rhs: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round_ties_even(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn max(self, rhs: Self) -> Self;
+    fn min(self, rhs: Self) -> Self;
+}
+
+#[cfg(not(feature = "soft_float"))]
+macro_rules! native_float {
+    ($float:ident, $($method:ident => $libm:ident),*) => {
+        impl FallbackFloat for $float {
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                self - rhs
+            }
+            #[inline(always)]
+            fn mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+            #[inline(always)]
+            fn div(self, rhs: Self) -> Self {
+                self / rhs
+            }
+            $(
+                #[inline(always)]
+                fn $method(self) -> Self {
+                    // Inherent methods take precedence over this trait, so this doesn't recurse.
+                    #[cfg(feature = "std")]
+                    {
+                        $float::$method(self)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$libm(self)
+                    }
+                }
+            )*
+            #[inline(always)]
+            fn fract(self) -> Self {
+                self - FallbackFloat::trunc(self)
+            }
+            #[inline(always)]
+            fn max(self, rhs: Self) -> Self {
+                $float::max(self, rhs)
+            }
+            #[inline(always)]
+            fn min(self, rhs: Self) -> Self {
+                $float::min(self, rhs)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "soft_float"))]
+native_float!(f32, sqrt => sqrtf, floor => floorf, ceil => ceilf, round_ties_even => rintf, trunc => truncf);
+#[cfg(not(feature = "soft_float"))]
+native_float!(f64, sqrt => sqrt, floor => floor, ceil => ceil, round_ties_even => rint, trunc => trunc);
@@ -0,0 +1,158 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Finite impulse response (FIR) filters, which convolve a stream of samples with a fixed set of coefficients.
+//!
+//! These are the low-pass, high-pass and equalization filters of audio processing, and the smoothing kernels of
+//! sensor data. [`FirFilter`] keeps the end of its input between calls, so a signal can be processed in blocks of
+//! any size, such as the buffers of an audio callback. A one-off convolution is a single call on a new filter.
+
+use core::fmt;
+
+use crate::{Simd, SimdBase, SimdFloat, f32x8};
+
+/// The number of outputs which are computed at once.
+const BLOCK: usize = 8;
+
+/// A finite impulse response filter with `TAPS` coefficients.
+///
+/// Each output sample is a weighted sum of the last `TAPS` input samples:
+/// `output[n] = coeffs[0] * input[n] + coeffs[1] * input[n - 1] + ...`. Samples from before the first call to
+/// [`process`](Self::process) are zero, and each call continues from where the previous one ended.
+///
+/// The coefficients are splatted into vectors once, when the filter is created. Each block of 8 outputs is then
+/// computed from 8-sample vectors of the input. These are offset for each coefficient with
+/// [`slide`](SimdBase::slide), rather than loaded again from memory, and accumulated with
+/// [`mul_add`](SimdFloat::mul_add). The results can differ in the last bits between levels with and without FMA
+/// (see [`Simd::FUSED_MUL_ADD`]).
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, fir::FirFilter};
+///
+/// /// A moving average of the last 4 samples, applied to a signal which arrives in two blocks.
+/// #[inline(always)]
+/// fn smooth<S: Simd>(simd: S, blocks: &[[f32; 6]; 2]) -> [[f32; 6]; 2] {
+///     let mut filter = FirFilter::new(simd, &[0.25; 4]);
+///     let mut smoothed = [[0.0; 6]; 2];
+///     for (input, output) in blocks.iter().zip(&mut smoothed) {
+///         filter.process(input, output);
+///     }
+///     smoothed
+/// }
+///
+/// let blocks = [[4.0, 4.0, 4.0, 4.0, 8.0, 8.0], [8.0, 8.0, 0.0, 0.0, 0.0, 0.0]];
+/// let smoothed = dispatch!(Level::new(), simd => smooth(simd, &blocks));
+/// assert_eq!(smoothed, [[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [7.0, 8.0, 6.0, 4.0, 2.0, 0.0]]);
+/// ```
+#[derive(Clone)]
+pub struct FirFilter<S: Simd, const TAPS: usize> {
+    /// Each coefficient, splatted to every lane.
+    coeffs: [f32x8<S>; TAPS],
+    /// The last `TAPS` input samples, oldest first.
+    history: [f32; TAPS],
+}
+
+impl<S: Simd, const TAPS: usize> FirFilter<S, TAPS> {
+    /// Create a filter with the given coefficients, where `coeffs[k]` is the weight of the input from `k` samples
+    /// earlier.
+    ///
+    /// The filter starts as if it had only been given zeros.
+    #[inline(always)]
+    pub fn new(simd: S, coeffs: &[f32; TAPS]) -> Self {
+        const { assert!(TAPS > 0, "a filter needs at least one coefficient") };
+        Self {
+            coeffs: coeffs.map(|c| f32x8::splat(simd, c)),
+            history: [0.0; TAPS],
+        }
+    }
+
+    /// Forget the previous input, as if the filter had only been given zeros.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.history = [0.0; TAPS];
+    }
+
+    /// Filter `input`, continuing from the end of the previous call, and write the result to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `output` have different lengths.
+    #[inline(always)]
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        assert_eq!(
+            input.len(),
+            output.len(),
+            "`input` and `output` should have the same length"
+        );
+        let simd = self.coeffs[0].witness();
+        // Positions are counted from the start of the history, which is directly before the input.
+        for (block, output) in output.chunks_mut(BLOCK).enumerate() {
+            let end = TAPS + block * BLOCK + BLOCK;
+            let mut acc = f32x8::splat(simd, 0.0);
+            let mut hi = self.load(simd, input, end);
+            for (q, coeffs) in self.coeffs.chunks(BLOCK).enumerate() {
+                // The samples for coefficient `k` of this chunk start `k` before those of `hi`.
+                let lo = self.load(simd, input, end - (q + 1) * BLOCK);
+                let windows = [
+                    hi,
+                    lo.slide::<7>(hi),
+                    lo.slide::<6>(hi),
+                    lo.slide::<5>(hi),
+                    lo.slide::<4>(hi),
+                    lo.slide::<3>(hi),
+                    lo.slide::<2>(hi),
+                    lo.slide::<1>(hi),
+                ];
+                for (&coeff, window) in coeffs.iter().zip(windows) {
+                    acc = window.mul_add(coeff, acc);
+                }
+                hi = lo;
+            }
+            if output.len() == BLOCK {
+                acc.store_slice(output);
+            } else {
+                output.copy_from_slice(&acc.as_slice()[..output.len()]);
+            }
+        }
+
+        if let Some(start) = input.len().checked_sub(TAPS) {
+            self.history.copy_from_slice(&input[start..]);
+        } else {
+            self.history.copy_within(input.len().., 0);
+            self.history[TAPS - input.len()..].copy_from_slice(input);
+        }
+    }
+
+    /// Load the 8 samples which end at `end`, counting from the start of the history.
+    ///
+    /// Samples before the history or after the input are zero.
+    #[inline(always)]
+    fn load(&self, simd: S, input: &[f32], end: usize) -> f32x8<S> {
+        if let Some(start) = end.checked_sub(BLOCK) {
+            if end <= TAPS {
+                return f32x8::from_slice(simd, &self.history[start..end]);
+            }
+            if let Some(samples) = start
+                .checked_sub(TAPS)
+                .and_then(|start| input.get(start..end - TAPS))
+            {
+                return f32x8::from_slice(simd, samples);
+            }
+        }
+        // Only the blocks near the start and end of the input straddle the history or the end.
+        f32x8::from_fn(simd, |i| match (end + i).checked_sub(BLOCK) {
+            Some(pos) if pos < TAPS => self.history[pos],
+            Some(pos) => input.get(pos - TAPS).copied().unwrap_or(0.0),
+            None => 0.0,
+        })
+    }
+}
+
+impl<S: Simd, const TAPS: usize> fmt::Debug for FirFilter<S, TAPS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FirFilter")
+            .field("coeffs", &self.coeffs.map(|c| c[0]))
+            .field("history", &self.history)
+            .finish()
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Formatting slices of `f32` as text, such as for exporting telemetry as CSV.
+//!
+//! Most of the time spent formatting a float goes to generating its decimal digits. For values which are
+//! integers, as counters and quantized samples usually are, the digits of a whole vector are generated at once
+//! by repeatedly dividing by ten. Other values are formatted one at a time by a [`FormatF32`] backend, which can
+//! be replaced to use another formatting library or a fixed number of decimals.
+//!
+//! This is only available with the `std` feature.
+//!
+//! ```rust
+//! use fearless_simd::format::{format_f32_slice, format_f32_slice_with};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! let values = [3.0, -42.0, 0.1, f32::NAN, 1.5e9];
+//! let level = Level::new();
+//!
+//! let mut csv = String::new();
+//! dispatch!(level, simd => format_f32_slice(simd, &values, &mut csv));
+//! assert_eq!(csv, "3,-42,0.1,NaN,1500000000");
+//!
+//! // A backend which rounds to two decimals, separated by tabs.
+//! let mut rounded = String::new();
+//! let mut two_decimals = |value: f32, out: &mut String| {
+//!     use std::fmt::Write;
+//!     write!(out, "{value:.2}").unwrap();
+//! };
+//! dispatch!(level, simd => {
+//!     format_f32_slice_with(simd, &values[..3], "\t", &mut two_decimals, &mut rounded)
+//! });
+//! assert_eq!(rounded, "3.00\t-42.00\t0.10");
+//! ```
+
+use std::string::String;
+
+use crate::slice::load_tail;
+use crate::{Simd, SimdBase, SimdFloat, SimdMask, f32x8};
+
+/// The number of values which are formatted at once.
+const BLOCK: usize = 8;
+
+/// Integers below this are exact in an `f32`, and so is dividing them by ten and rounding down.
+const PLAIN_LIMIT: f32 = 16_777_216.0;
+
+/// The most decimal digits of an integer below [`PLAIN_LIMIT`].
+const MAX_DIGITS: usize = 8;
+
+/// A scalar backend for [`format_f32_slice_with`], which formats one value at a time.
+///
+/// This is implemented for closures taking the value and the output, and by [`Shortest`].
+pub trait FormatF32 {
+    /// Whether an integer whose magnitude is below 2<sup>24</sup> is formatted as its decimal digits, with a `-`
+    /// before them if it's negative, including `-0` for negative zero.
+    ///
+    /// When this is true, [`format_f32_slice_with`] formats those values itself, with vectorized digit
+    /// generation, rather than calling [`format_f32`](Self::format_f32). This is false by default.
+    const PLAIN_INTEGERS: bool = false;
+
+    /// Append `value` to `out`.
+    fn format_f32(&mut self, value: f32, out: &mut String);
+}
+
+impl<F: FnMut(f32, &mut String)> FormatF32 for F {
+    #[inline]
+    fn format_f32(&mut self, value: f32, out: &mut String) {
+        self(value, out);
+    }
+}
+
+/// Formats values with their [`Display`](core::fmt::Display) implementation.
+///
+/// This is the shortest decimal representation which reads back as the same value, without an exponent, like
+/// `0.1`, `-3` or `1500000000`. Infinities are `inf` and `-inf`, and NaN is `NaN`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Shortest;
+
+impl FormatF32 for Shortest {
+    const PLAIN_INTEGERS: bool = true;
+
+    #[inline]
+    fn format_f32(&mut self, value: f32, out: &mut String) {
+        use core::fmt::Write;
+        write!(out, "{value}").expect("writing to a `String` doesn't fail");
+    }
+}
+
+/// Append `values` to `out`, separated by commas, as formatted by [`Shortest`].
+#[inline(always)]
+pub fn format_f32_slice<S: Simd>(simd: S, values: &[f32], out: &mut String) {
+    format_f32_slice_with(simd, values, ",", &mut Shortest, out);
+}
+
+/// Append `values` to `out`, separated by `separator`, as formatted by `backend`.
+///
+/// If the backend's [`PLAIN_INTEGERS`](FormatF32::PLAIN_INTEGERS) is true, the small integers in `values` are
+/// formatted by generating the digits of a vector of values at once, and the others are passed to the backend one
+/// at a time. Otherwise, every value is passed to the backend.
+#[inline(always)]
+pub fn format_f32_slice_with<S: Simd, F: FormatF32>(
+    simd: S,
+    values: &[f32],
+    separator: &str,
+    backend: &mut F,
+    out: &mut String,
+) {
+    // Enough for most values, so that `out` usually grows once.
+    out.reserve(values.len() * (separator.len() + MAX_DIGITS));
+    for (i, chunk) in values.chunks(BLOCK).enumerate() {
+        let block = load_chunk(simd, chunk);
+        let digits = if F::PLAIN_INTEGERS {
+            plain_digits(block)
+        } else {
+            None
+        };
+        for (lane, &value) in chunk.iter().enumerate() {
+            if i != 0 || lane != 0 {
+                out.push_str(separator);
+            }
+            match &digits {
+                Some((plain, digits)) if plain & (1 << lane) != 0 => {
+                    push_digits(value, digits, lane, out);
+                }
+                _ => backend.format_f32(value, out),
+            }
+        }
+    }
+}
+
+/// Load a chunk of up to [`BLOCK`] values, filling the missing lanes with zero.
+#[inline(always)]
+fn load_chunk<S: Simd>(simd: S, chunk: &[f32]) -> f32x8<S> {
+    if chunk.len() == BLOCK {
+        f32x8::from_slice(simd, chunk)
+    } else {
+        load_tail(simd, chunk, 0.0)
+    }
+}
+
+/// The bitmask of the lanes of `block` which are integers below [`PLAIN_LIMIT`] in magnitude, and their digits
+/// from the least significant, or `None` if there are no such lanes.
+#[inline(always)]
+fn plain_digits<S: Simd>(block: f32x8<S>) -> Option<(u64, [[u32; BLOCK]; MAX_DIGITS])> {
+    let magnitude = block.abs();
+    // NaN isn't equal to itself, so it isn't plain.
+    let plain =
+        (magnitude.trunc().simd_eq(magnitude) & magnitude.simd_lt(PLAIN_LIMIT)).to_bitmask();
+    if plain == 0 {
+        return None;
+    }
+    let mut rest = magnitude;
+    let mut digits = [[0; BLOCK]; MAX_DIGITS];
+    for digit in &mut digits {
+        let quotient = (rest / 10.0).floor();
+        *digit = *(rest - quotient * 10.0).to_uint();
+        rest = quotient;
+    }
+    Some((plain, digits))
+}
+
+/// Append the integer `value` from `lane` of the digits from [`plain_digits`].
+#[inline(always)]
+fn push_digits(value: f32, digits: &[[u32; BLOCK]; MAX_DIGITS], lane: usize, out: &mut String) {
+    if value.is_sign_negative() {
+        out.push('-');
+    }
+    let len = digits
+        .iter()
+        .rposition(|digit| digit[lane] != 0)
+        .map_or(1, |last| last + 1);
+    for digit in digits[..len].iter().rev() {
+        out.push(char::from_digit(digit[lane], 10).expect("a digit is below ten"));
+    }
+}
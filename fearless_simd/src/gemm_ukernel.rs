@@ -0,0 +1,129 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A fixed-size micro-kernel for 8-bit integer matrix multiplication.
+//!
+//! Quantized neural networks multiply matrices of 8-bit values, accumulating into 32-bit
+//! integers. Fast implementations split the matrices into tiles, and repack them so that the
+//! innermost loop, the "micro-kernel", only reads consecutive memory. This module provides that
+//! innermost loop, [`gemm_u8i8_4x16`], so the tiling and packing can be written once in portable
+//! code.
+//!
+//! The left-hand side is unsigned and the right-hand side is signed, which is what the 8-bit dot
+//! product instructions (`vpdpbusd` on x86 and `usdot` on Arm) multiply. This matches the usual
+//! quantization of activations as `u8` and weights as `i8`.
+//!
+//! ## Packing
+//!
+//! The kernel computes `C += A * B` for a [`MR`]x[`NR`] tile `C`, where `A` has [`MR`] rows and
+//! `B` has [`NR`] columns. Both are split along their shared dimension into blocks of [`KR`]:
+//!
+//! - Each block of `A` holds [`KR`] consecutive elements of each row in turn: `a[m * KR + k]` is
+//!   row `m`, column `k` of the block.
+//! - Each block of `B` holds [`KR`] consecutive elements of each column in turn:
+//!   `b[n * KR + k]` is row `k`, column `n` of the block.
+//!
+//! The depth of the product is [`KR`] times the number of blocks. Pad it with zeros if needed.
+//!
+//! ```rust
+//! use fearless_simd::gemm_ukernel::{KR, MR, NR, gemm_u8i8_4x16};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! // A 4x8 matrix times an 8x16 matrix, stored row-major.
+//! let a: [[u8; 8]; MR] = core::array::from_fn(|m| core::array::from_fn(|k| (m * 8 + k) as u8));
+//! let b: [[i8; NR]; 8] = core::array::from_fn(|k| core::array::from_fn(|n| n as i8 - k as i8));
+//!
+//! let packed_a: [[u8; MR * KR]; 2] =
+//!     core::array::from_fn(|block| core::array::from_fn(|i| a[i / KR][block * KR + i % KR]));
+//! let packed_b: [[i8; NR * KR]; 2] =
+//!     core::array::from_fn(|block| core::array::from_fn(|i| b[block * KR + i % KR][i / KR]));
+//!
+//! let mut c = [[0; NR]; MR];
+//! dispatch!(Level::new(), simd => gemm_u8i8_4x16(simd, &packed_a, &packed_b, &mut c));
+//! let expected = (0..8).map(|k| i32::from(a[1][k]) * i32::from(b[k][5])).sum::<i32>();
+//! assert_eq!(c[1][5], expected);
+//! ```
+//!
+//! ## Implementation
+//!
+//! With AVX-512, which includes VNNI, each block is one `vpdpbusd` for each row of `C`. Other
+//! levels widen the elements to 32 bits and multiply them. Rust doesn't yet have stable
+//! intrinsics for Arm's 8-bit dot product and matrix multiplication extensions, so Neon also
+//! uses the widening implementation.
+
+use crate::{Bytes, SimdBase, SimdSplit, i8x64, i32x16};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::transmute::{checked_transmute_copy, checked_transmute_store};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{__m512i, _mm512_dpbusd_epi32, _mm512_set1_epi32};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{__m512i, _mm512_dpbusd_epi32, _mm512_set1_epi32};
+
+/// The number of rows of `A` and `C` in a tile.
+pub const MR: usize = 4;
+
+/// The number of columns of `B` and `C` in a tile.
+pub const NR: usize = 16;
+
+/// The depth of each packed block of `A` and `B`.
+pub const KR: usize = 4;
+
+crate::simd_op! {
+    /// Add the product of the packed blocks of `a` and `b` to the tile `c`.
+    ///
+    /// See the [module documentation](self) for the layout of the blocks. The sums wrap on
+    /// overflow, which needs a depth of more than 33,000.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have a different number of blocks.
+    pub fn gemm_u8i8_4x16<S: Simd>(
+        simd: S,
+        a: &[[u8; MR * KR]],
+        b: &[[i8; NR * KR]],
+        c: &mut [[i32; NR]; MR]
+    ) {
+        Avx512(
+            avx512,
+            a: &[[u8; MR * KR]],
+            b: &[[i8; NR * KR]],
+            c: &mut [[i32; NR]; MR]
+        ) {
+            assert_eq!(a.len(), b.len(), "`a` and `b` should have the same depth");
+            let mut acc: [__m512i; MR] = core::array::from_fn(|m| checked_transmute_copy(&c[m]));
+            for (a, b) in a.iter().zip(b) {
+                let b: __m512i = checked_transmute_copy(b);
+                for (m, acc) in acc.iter_mut().enumerate() {
+                    let row = i32::from_le_bytes(a[m * KR..][..KR].try_into().unwrap());
+                    *acc = _mm512_dpbusd_epi32(*acc, _mm512_set1_epi32(row), b);
+                }
+            }
+            for (c, acc) in c.iter_mut().zip(acc) {
+                checked_transmute_store(acc, c);
+            }
+        }
+        _ => {
+            assert_eq!(a.len(), b.len(), "`a` and `b` should have the same depth");
+            let mut acc: [i32x16<S>; MR] = core::array::from_fn(|m| i32x16::from_slice(simd, &c[m]));
+            for (a, b) in a.iter().zip(b) {
+                // Separate the rows of the block of `b`, then widen them to 32 bits.
+                let b = simd
+                    .load_interleaved_128_u8x64(&b.map(i8::cast_unsigned))
+                    .bitcast::<i8x64<S>>();
+                let (b01, b23) = b.split();
+                let (b0, b1) = b01.split();
+                let (b2, b3) = b23.split();
+                let rows = [b0, b1, b2, b3].map(|row| simd.widen_i16x16(simd.widen_i8x16(row)));
+                for (m, acc) in acc.iter_mut().enumerate() {
+                    for (k, row) in rows.iter().enumerate() {
+                        *acc += *row * i32::from(a[m * KR + k]);
+                    }
+                }
+            }
+            for (c, acc) in c.iter_mut().zip(acc) {
+                acc.store_slice(c);
+            }
+        }
+    }
+}
@@ -43,10 +43,44 @@
 mod avx2;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod avx512;
+#[cfg(feature = "custom_level")]
+pub(crate) mod custom_level;
 mod fallback;
-#[cfg(target_arch = "aarch64")]
+/// The intrinsics which each level implements each operation with, for tools.
+///
+/// This is the same information as the `# Lowering` tables in the documentation of the [`Simd`] methods.
+/// It's worked out from the code generated for each level, so it covers every level whichever target this crate
+/// is compiled for. The intrinsics of the other methods which an implementation calls are included, and where a
+/// level chooses between implementations depending on the enabled target features, such as `relaxed-simd` on
+/// WebAssembly, the intrinsics of all of them are listed.
+///
+/// The intrinsics are named as in [`core::arch`]. Most of them compile to a single instruction.
+///
+/// ```rust
+/// use fearless_simd::lowering::{self, LoweringKind};
+///
+/// let mul_add = lowering::find("mul_add_f32x4").unwrap();
+/// assert_eq!(mul_add.level("Neon").unwrap().intrinsics, ["vfmaq_f32"]);
+/// assert_eq!(mul_add.level("Avx2").unwrap().intrinsics, ["_mm_fmadd_ps"]);
+/// assert_eq!(mul_add.level("Fallback").unwrap().kind, LoweringKind::Portable);
+/// ```
+pub mod lowering;
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 mod neon;
 mod ops;
+/// Reference implementations of the lane-wise operations, for verifying other SIMD code.
+///
+/// Each function corresponds to a method on the [`Simd`](crate::Simd) trait with the same name,
+/// but takes its vector arguments as arrays and returns arrays. The results are always computed
+/// by the [`Fallback`] level, whichever levels are available on the current CPU, so they
+/// follow the semantics this crate guarantees on every level.
+///
+/// ```rust
+/// use fearless_simd::reference;
+///
+/// assert_eq!(reference::cvt_u32_precise_f32x4(&[1.5, -1.0, 3e10, f32::NAN]), [1, 0, u32::MAX, 0]);
+/// ```
+pub mod reference;
 pub(crate) mod simd_trait;
 mod simd_types;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -61,7 +95,7 @@ pub use avx2::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use avx512::*;
 pub use fallback::*;
-#[cfg(target_arch = "aarch64")]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 pub use neon::*;
 pub use simd_trait::*;
 pub use simd_types::*;
@@ -0,0 +1,23175 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This file is autogenerated by fearless_simd_gen
+
+use super::Avx2;
+use crate::{Level, prelude::*};
+use crate::{
+    f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4,
+    i32x8, i32x16, i64x2, i64x4, i64x8, mask8x16, mask8x32, mask8x64, mask16x8, mask16x16,
+    mask16x32, mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x16, u8x32, u8x64,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
+};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::ops::*;
+impl Simd for Avx2 {
+    type f32s = f32x8<Self>;
+    type f64s = f64x4<Self>;
+    type u8s = u8x32<Self>;
+    type i8s = i8x32<Self>;
+    type u16s = u16x16<Self>;
+    type i16s = i16x16<Self>;
+    type u32s = u32x8<Self>;
+    type i32s = i32x8<Self>;
+    type u64s = u64x4<Self>;
+    type i64s = i64x4<Self>;
+    type mask8s = mask8x32<Self>;
+    type mask16s = mask16x16<Self>;
+    type mask32s = mask32x8<Self>;
+    type mask64s = mask64x4<Self>;
+    const NATIVE_BITS: usize = 256;
+    const FUSED_MUL_ADD: bool = true;
+    const SOFT_FLOAT: bool = false;
+    #[inline(always)]
+    fn level(self) -> Level {
+        Level::Avx2(self)
+    }
+    #[inline]
+    fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
+        #[target_feature(
+            enable = "fxsr,avx2,bmi1,bmi2,cmpxchg16b,f16c,fma,lzcnt,movbe,popcnt,xsave"
+        )]
+        fn vectorize_avx2<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+        unsafe { vectorize_avx2(f) }
+    }
+    #[inline(always)]
+    fn splat_f32x4(self, val: f32) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: f32) -> f32x4<Avx2> {
+                _mm_set1_ps(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_f32x4(self, val: [f32; 4usize]) -> f32x4<Self> {
+        f32x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_f32x4(self, val: &[f32; 4usize]) -> f32x4<Self> {
+        f32x4 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_f32x4(self, a: f32x4<Self>) -> [f32; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m128, [f32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_f32x4(self, a: &f32x4<Self>) -> &[f32; 4usize] {
+        crate::transmute::checked_cast_ref::<__m128, [f32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_f32x4(self, a: &mut f32x4<Self>) -> &mut [f32; 4usize] {
+        crate::transmute::checked_cast_mut::<__m128, [f32; 4usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_f32x4(self, a: f32x4<Self>, dest: &mut [f32; 4usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_f32x4(self, a: u8x16<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_f32x4(self, a: f32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_f32x4<const SHIFT: usize>(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_f32x4(b).val.0,
+            self.cvt_to_bytes_f32x4(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_f32x4(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_f32x4<const SHIFT: usize>(
+        self,
+        a: f32x4<Self>,
+        b: f32x4<Self>,
+    ) -> f32x4<Self> {
+        self.slide_f32x4::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_f32x4<const OFFSET: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_f32x4::<0>(a, a),
+            1 => self.slide_f32x4::<1>(a, a),
+            2 => self.slide_f32x4::<2>(a, a),
+            3 => self.slide_f32x4::<3>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_f32x4<const OFFSET: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_f32x4::<4>(a, a),
+            1 => self.slide_f32x4::<3>(a, a),
+            2 => self.slide_f32x4::<2>(a, a),
+            3 => self.slide_f32x4::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_f32x4<const OFFSET: usize>(
+        self,
+        a: f32x4<Self>,
+        padding: f32,
+    ) -> f32x4<Self> {
+        let padding = self.splat_f32x4(padding);
+        match OFFSET {
+            0 => self.slide_f32x4::<0>(a, padding),
+            1 => self.slide_f32x4::<1>(a, padding),
+            2 => self.slide_f32x4::<2>(a, padding),
+            3 => self.slide_f32x4::<3>(a, padding),
+            4 => self.slide_f32x4::<4>(a, padding),
+            _ => self.slide_f32x4::<4>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_f32x4<const OFFSET: usize>(
+        self,
+        a: f32x4<Self>,
+        padding: f32,
+    ) -> f32x4<Self> {
+        let padding = self.splat_f32x4(padding);
+        match OFFSET {
+            0 => self.slide_f32x4::<4>(padding, a),
+            1 => self.slide_f32x4::<3>(padding, a),
+            2 => self.slide_f32x4::<2>(padding, a),
+            3 => self.slide_f32x4::<1>(padding, a),
+            4 => self.slide_f32x4::<0>(padding, a),
+            _ => self.slide_f32x4::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_f32x4<const LANE: usize>(self, a: f32x4<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, lane: usize) -> f32 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_cvtss_f32(a),
+                    1 => _mm_cvtss_f32(_mm_shuffle_ps::<1>(a, a)),
+                    2 => _mm_cvtss_f32(_mm_shuffle_ps::<2>(a, a)),
+                    3 => _mm_cvtss_f32(_mm_shuffle_ps::<3>(a, a)),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_f32x4<const LANE: usize>(self, a: f32x4<Self>, value: f32) -> f32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, value: f32, lane: usize) -> f32x4<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_move_ss(a, _mm_set_ss(value)),
+                    1 => _mm_insert_ps::<16>(a, _mm_set_ss(value)),
+                    2 => _mm_insert_ps::<32>(a, _mm_set_ss(value)),
+                    3 => _mm_insert_ps::<48>(a, _mm_set_ss(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x4(self, a: f32x4<Self>, index: usize) -> f32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_f32x4(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_f32x4(self, a: f32x4<Self>, indices: u8x16<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, indices: u8x16<Avx2>) -> f32x4<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_f32x4(a).val.0, indices.into());
+                token.cvt_from_bytes_f32x4(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn abs_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_andnot_ps(_mm_set1_ps(-0.0), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_xor_ps(a.into(), _mm_set1_ps(-0.0)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_sqrt_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn approximate_recip_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_rcp_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_add_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_sub_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_mul_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_div_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn copysign_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                let mask = _mm_set1_ps(-0.0);
+                _mm_or_ps(_mm_and_ps(mask, b.into()), _mm_andnot_ps(mask, a.into()))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_eq_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_castps_si128(_mm_cmpeq_ps(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_castps_si128(_mm_cmplt_ps(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_castps_si128(_mm_cmple_ps(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_castps_si128(_mm_cmpge_ps(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_castps_si128(_mm_cmpgt_ps(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_unpacklo_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_unpackhi_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_shuffle_ps::<0b10_00_10_00>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_shuffle_ps::<0b11_01_11_01>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> (f32x4<Self>, f32x4<Self>) {
+        (self.zip_low_f32x4(a, b), self.zip_high_f32x4(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> (f32x4<Self>, f32x4<Self>) {
+        (self.unzip_low_f32x4(a, b), self.unzip_high_f32x4(a, b))
+    }
+    #[inline(always)]
+    fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_max_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_min_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                let intermediate = _mm_max_ps(a.into(), b.into());
+                let b_is_nan = _mm_cmpunord_ps(b.into(), b.into());
+                _mm_blendv_ps(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                let intermediate = _mm_min_ps(a.into(), b.into());
+                let b_is_nan = _mm_cmpunord_ps(b.into(), b.into());
+                _mm_blendv_ps(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>, c: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_fmadd_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>, c: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_fmsub_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>, c: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_fnmadd_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>, c: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_fnmsub_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.mul_add_f32x4(a, b, c)
+    }
+    #[inline(always)]
+    fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_round_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn ceil_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_round_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        self.sub_f32x4(a, self.trunc_f32x4(a))
+    }
+    #[inline(always)]
+    fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn select_f32x4(self, a: mask32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x4<Avx2>,
+                b: f32x4<Avx2>,
+                c: f32x4<Avx2>,
+            ) -> f32x4<Avx2> {
+                _mm_blendv_ps(c.into(), b.into(), _mm_castsi128_ps(a.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_f32x4(
+        self,
+        a: mask32x4<Self>,
+        b: f32x4<Self>,
+        c: f32x4<Self>,
+    ) -> f32x4<Self> {
+        self.select_f32x4(a, b, c)
+    }
+    #[inline(always)]
+    fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x8<Avx2> {
+                _mm256_setr_m128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x4(self, a: f32x4<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> f64x2<Avx2> {
+                _mm_castps_pd(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_castps_si128(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x4(self, a: f32x4<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> u8x16<Avx2> {
+                _mm_castps_si128(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_castps_si128(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> u32x4<Avx2> {
+                let mut converted = _mm_cvttps_epi32(a.into());
+                let in_range = _mm_cmplt_ps(a.into(), _mm_set1_ps(2147483648.0));
+                let all_in_range = _mm_movemask_ps(in_range) == 0b1111;
+                if !all_in_range {
+                    let excess = _mm_sub_ps(a.into(), _mm_set1_ps(2147483648.0));
+                    let excess_converted = _mm_cvttps_epi32(_mm_andnot_ps(in_range, excess));
+                    converted = _mm_add_epi32(converted, excess_converted);
+                }
+                converted.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_u32_precise_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> u32x4<Avx2> {
+                let a = _mm_max_ps(a.into(), _mm_setzero_ps());
+                let mut converted = _mm_cvttps_epi32(a);
+                let in_range = _mm_cmplt_ps(a, _mm_set1_ps(2147483648.0));
+                let all_in_range = _mm_movemask_ps(in_range) == 0b1111;
+                if !all_in_range {
+                    let exceeds_unsigned_range =
+                        _mm_castps_si128(_mm_cmplt_ps(_mm_set1_ps(4294967040.0), a));
+                    let excess = _mm_sub_ps(a, _mm_set1_ps(2147483648.0));
+                    let excess_converted = _mm_cvttps_epi32(_mm_andnot_ps(in_range, excess));
+                    converted = _mm_add_epi32(converted, excess_converted);
+                    converted = _mm_blendv_epi8(
+                        converted,
+                        _mm_set1_epi32(u32::MAX.cast_signed()),
+                        exceeds_unsigned_range,
+                    );
+                }
+                converted.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_cvttps_epi32(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_i32_precise_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>) -> i32x4<Avx2> {
+                let a = a.into();
+                let mut converted = _mm_cvttps_epi32(a);
+                let in_range = _mm_cmplt_ps(a, _mm_set1_ps(2147483648.0));
+                let all_in_range = _mm_movemask_ps(in_range) == 0b1111;
+                if !all_in_range {
+                    converted = _mm_blendv_epi8(
+                        _mm_set1_epi32(i32::MAX),
+                        converted,
+                        _mm_castps_si128(in_range),
+                    );
+                    let is_not_nan = _mm_castps_si128(_mm_cmpord_ps(a, a));
+                    converted = _mm_and_si128(converted, is_not_nan);
+                }
+                converted.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn dot2_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                let p = _mm_mul_ps(a.into(), b.into());
+                let pairs = _mm_add_ps(p, _mm_shuffle_ps::<0b10_11_00_01>(p, p));
+                pairs.simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn dot3_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_dp_ps::<0x7f>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn dot4_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x4<Avx2>, b: f32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_dp_ps::<0xff>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn splat_i8x16(self, val: i8) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i8) -> i8x16<Avx2> {
+                _mm_set1_epi8(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i8x16(self, val: [i8; 16usize]) -> i8x16<Self> {
+        i8x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i8x16(self, val: &[i8; 16usize]) -> i8x16<Self> {
+        i8x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i8x16(self, a: i8x16<Self>) -> [i8; 16usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i8; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i8x16(self, a: &i8x16<Self>) -> &[i8; 16usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [i8; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i8x16(self, a: &mut i8x16<Self>) -> &mut [i8; 16usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [i8; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i8x16(self, a: i8x16<Self>, dest: &mut [i8; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i8x16(self, a: u8x16<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i8x16<const SHIFT: usize>(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_i8x16(b).val.0,
+            self.cvt_to_bytes_i8x16(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_i8x16(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i8x16<const SHIFT: usize>(
+        self,
+        a: i8x16<Self>,
+        b: i8x16<Self>,
+    ) -> i8x16<Self> {
+        self.slide_i8x16::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i8x16<const OFFSET: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_i8x16::<0>(a, a),
+            1 => self.slide_i8x16::<1>(a, a),
+            2 => self.slide_i8x16::<2>(a, a),
+            3 => self.slide_i8x16::<3>(a, a),
+            4 => self.slide_i8x16::<4>(a, a),
+            5 => self.slide_i8x16::<5>(a, a),
+            6 => self.slide_i8x16::<6>(a, a),
+            7 => self.slide_i8x16::<7>(a, a),
+            8 => self.slide_i8x16::<8>(a, a),
+            9 => self.slide_i8x16::<9>(a, a),
+            10 => self.slide_i8x16::<10>(a, a),
+            11 => self.slide_i8x16::<11>(a, a),
+            12 => self.slide_i8x16::<12>(a, a),
+            13 => self.slide_i8x16::<13>(a, a),
+            14 => self.slide_i8x16::<14>(a, a),
+            15 => self.slide_i8x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i8x16<const OFFSET: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_i8x16::<16>(a, a),
+            1 => self.slide_i8x16::<15>(a, a),
+            2 => self.slide_i8x16::<14>(a, a),
+            3 => self.slide_i8x16::<13>(a, a),
+            4 => self.slide_i8x16::<12>(a, a),
+            5 => self.slide_i8x16::<11>(a, a),
+            6 => self.slide_i8x16::<10>(a, a),
+            7 => self.slide_i8x16::<9>(a, a),
+            8 => self.slide_i8x16::<8>(a, a),
+            9 => self.slide_i8x16::<7>(a, a),
+            10 => self.slide_i8x16::<6>(a, a),
+            11 => self.slide_i8x16::<5>(a, a),
+            12 => self.slide_i8x16::<4>(a, a),
+            13 => self.slide_i8x16::<3>(a, a),
+            14 => self.slide_i8x16::<2>(a, a),
+            15 => self.slide_i8x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i8x16<const OFFSET: usize>(
+        self,
+        a: i8x16<Self>,
+        padding: i8,
+    ) -> i8x16<Self> {
+        let padding = self.splat_i8x16(padding);
+        match OFFSET {
+            0 => self.slide_i8x16::<0>(a, padding),
+            1 => self.slide_i8x16::<1>(a, padding),
+            2 => self.slide_i8x16::<2>(a, padding),
+            3 => self.slide_i8x16::<3>(a, padding),
+            4 => self.slide_i8x16::<4>(a, padding),
+            5 => self.slide_i8x16::<5>(a, padding),
+            6 => self.slide_i8x16::<6>(a, padding),
+            7 => self.slide_i8x16::<7>(a, padding),
+            8 => self.slide_i8x16::<8>(a, padding),
+            9 => self.slide_i8x16::<9>(a, padding),
+            10 => self.slide_i8x16::<10>(a, padding),
+            11 => self.slide_i8x16::<11>(a, padding),
+            12 => self.slide_i8x16::<12>(a, padding),
+            13 => self.slide_i8x16::<13>(a, padding),
+            14 => self.slide_i8x16::<14>(a, padding),
+            15 => self.slide_i8x16::<15>(a, padding),
+            16 => self.slide_i8x16::<16>(a, padding),
+            _ => self.slide_i8x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i8x16<const OFFSET: usize>(
+        self,
+        a: i8x16<Self>,
+        padding: i8,
+    ) -> i8x16<Self> {
+        let padding = self.splat_i8x16(padding);
+        match OFFSET {
+            0 => self.slide_i8x16::<16>(padding, a),
+            1 => self.slide_i8x16::<15>(padding, a),
+            2 => self.slide_i8x16::<14>(padding, a),
+            3 => self.slide_i8x16::<13>(padding, a),
+            4 => self.slide_i8x16::<12>(padding, a),
+            5 => self.slide_i8x16::<11>(padding, a),
+            6 => self.slide_i8x16::<10>(padding, a),
+            7 => self.slide_i8x16::<9>(padding, a),
+            8 => self.slide_i8x16::<8>(padding, a),
+            9 => self.slide_i8x16::<7>(padding, a),
+            10 => self.slide_i8x16::<6>(padding, a),
+            11 => self.slide_i8x16::<5>(padding, a),
+            12 => self.slide_i8x16::<4>(padding, a),
+            13 => self.slide_i8x16::<3>(padding, a),
+            14 => self.slide_i8x16::<2>(padding, a),
+            15 => self.slide_i8x16::<1>(padding, a),
+            16 => self.slide_i8x16::<0>(padding, a),
+            _ => self.slide_i8x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i8x16<const LANE: usize>(self, a: i8x16<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the intrinsics zero-extend the lane to an `i32`"
+            )]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, lane: usize) -> i8 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_extract_epi8::<0>(a) as i8,
+                    1 => _mm_extract_epi8::<1>(a) as i8,
+                    2 => _mm_extract_epi8::<2>(a) as i8,
+                    3 => _mm_extract_epi8::<3>(a) as i8,
+                    4 => _mm_extract_epi8::<4>(a) as i8,
+                    5 => _mm_extract_epi8::<5>(a) as i8,
+                    6 => _mm_extract_epi8::<6>(a) as i8,
+                    7 => _mm_extract_epi8::<7>(a) as i8,
+                    8 => _mm_extract_epi8::<8>(a) as i8,
+                    9 => _mm_extract_epi8::<9>(a) as i8,
+                    10 => _mm_extract_epi8::<10>(a) as i8,
+                    11 => _mm_extract_epi8::<11>(a) as i8,
+                    12 => _mm_extract_epi8::<12>(a) as i8,
+                    13 => _mm_extract_epi8::<13>(a) as i8,
+                    14 => _mm_extract_epi8::<14>(a) as i8,
+                    15 => _mm_extract_epi8::<15>(a) as i8,
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i8x16<const LANE: usize>(self, a: i8x16<Self>, value: i8) -> i8x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, value: i8, lane: usize) -> i8x16<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_insert_epi8::<0>(a, i32::from(value)),
+                    1 => _mm_insert_epi8::<1>(a, i32::from(value)),
+                    2 => _mm_insert_epi8::<2>(a, i32::from(value)),
+                    3 => _mm_insert_epi8::<3>(a, i32::from(value)),
+                    4 => _mm_insert_epi8::<4>(a, i32::from(value)),
+                    5 => _mm_insert_epi8::<5>(a, i32::from(value)),
+                    6 => _mm_insert_epi8::<6>(a, i32::from(value)),
+                    7 => _mm_insert_epi8::<7>(a, i32::from(value)),
+                    8 => _mm_insert_epi8::<8>(a, i32::from(value)),
+                    9 => _mm_insert_epi8::<9>(a, i32::from(value)),
+                    10 => _mm_insert_epi8::<10>(a, i32::from(value)),
+                    11 => _mm_insert_epi8::<11>(a, i32::from(value)),
+                    12 => _mm_insert_epi8::<12>(a, i32::from(value)),
+                    13 => _mm_insert_epi8::<13>(a, i32::from(value)),
+                    14 => _mm_insert_epi8::<14>(a, i32::from(value)),
+                    15 => _mm_insert_epi8::<15>(a, i32::from(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x16(self, a: i8x16<Self>, index: usize) -> i8 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i8x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i8x16(self, a: i8x16<Self>, indices: u8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, indices: u8x16<Avx2>) -> i8x16<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_i8x16(a).val.0, indices.into());
+                token.cvt_from_bytes_i8x16(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_add_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_sub_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                let dst_even = _mm_mullo_epi16(a.into(), b.into());
+                let dst_odd =
+                    _mm_mullo_epi16(_mm_srli_epi16::<8>(a.into()), _mm_srli_epi16::<8>(b.into()));
+                _mm_or_si128(
+                    _mm_slli_epi16(dst_odd, 8),
+                    _mm_and_si128(dst_even, _mm_set1_epi16(0xFF)),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        self.xor_i8x16(a, self.splat_i8x16(!0))
+    }
+    #[inline(always)]
+    fn shl_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, shift: u32) -> i8x16<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 = _mm_unpacklo_epi8(val, _mm_cmpgt_epi8(_mm_setzero_si128(), val));
+                let hi_16 = _mm_unpackhi_epi8(val, _mm_cmpgt_epi8(_mm_setzero_si128(), val));
+                let lo_shifted = _mm_sll_epi16(lo_16, shift_count);
+                let hi_shifted = _mm_sll_epi16(hi_16, shift_count);
+                _mm_packs_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_shl(a[0usize], b[0usize] as u32),
+            i8::wrapping_shl(a[1usize], b[1usize] as u32),
+            i8::wrapping_shl(a[2usize], b[2usize] as u32),
+            i8::wrapping_shl(a[3usize], b[3usize] as u32),
+            i8::wrapping_shl(a[4usize], b[4usize] as u32),
+            i8::wrapping_shl(a[5usize], b[5usize] as u32),
+            i8::wrapping_shl(a[6usize], b[6usize] as u32),
+            i8::wrapping_shl(a[7usize], b[7usize] as u32),
+            i8::wrapping_shl(a[8usize], b[8usize] as u32),
+            i8::wrapping_shl(a[9usize], b[9usize] as u32),
+            i8::wrapping_shl(a[10usize], b[10usize] as u32),
+            i8::wrapping_shl(a[11usize], b[11usize] as u32),
+            i8::wrapping_shl(a[12usize], b[12usize] as u32),
+            i8::wrapping_shl(a[13usize], b[13usize] as u32),
+            i8::wrapping_shl(a[14usize], b[14usize] as u32),
+            i8::wrapping_shl(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, shift: u32) -> i8x16<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 = _mm_unpacklo_epi8(val, _mm_cmpgt_epi8(_mm_setzero_si128(), val));
+                let hi_16 = _mm_unpackhi_epi8(val, _mm_cmpgt_epi8(_mm_setzero_si128(), val));
+                let lo_shifted = _mm_sra_epi16(lo_16, shift_count);
+                let hi_shifted = _mm_sra_epi16(hi_16, shift_count);
+                _mm_packs_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_shr(a[0usize], b[0usize] as u32),
+            i8::wrapping_shr(a[1usize], b[1usize] as u32),
+            i8::wrapping_shr(a[2usize], b[2usize] as u32),
+            i8::wrapping_shr(a[3usize], b[3usize] as u32),
+            i8::wrapping_shr(a[4usize], b[4usize] as u32),
+            i8::wrapping_shr(a[5usize], b[5usize] as u32),
+            i8::wrapping_shr(a[6usize], b[6usize] as u32),
+            i8::wrapping_shr(a[7usize], b[7usize] as u32),
+            i8::wrapping_shr(a[8usize], b[8usize] as u32),
+            i8::wrapping_shr(a[9usize], b[9usize] as u32),
+            i8::wrapping_shr(a[10usize], b[10usize] as u32),
+            i8::wrapping_shr(a[11usize], b[11usize] as u32),
+            i8::wrapping_shr(a[12usize], b[12usize] as u32),
+            i8::wrapping_shr(a[13usize], b[13usize] as u32),
+            i8::wrapping_shr(a[14usize], b[14usize] as u32),
+            i8::wrapping_shr(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpgt_epi8(b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(_mm_min_epi8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(_mm_max_epi8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpgt_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_unpacklo_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_unpackhi_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                let mask = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpacklo_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                let mask = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpackhi_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> (i8x16<Self>, i8x16<Self>) {
+        (self.zip_low_i8x16(a, b), self.zip_high_i8x16(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> (i8x16<Self>, i8x16<Self>) {
+        (self.unzip_low_i8x16(a, b), self.unzip_high_i8x16(a, b))
+    }
+    #[inline(always)]
+    fn select_i8x16(self, a: mask8x16<Self>, b: i8x16<Self>, c: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask8x16<Avx2>,
+                b: i8x16<Avx2>,
+                c: i8x16<Avx2>,
+            ) -> i8x16<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i8x16(
+        self,
+        a: mask8x16<Self>,
+        b: i8x16<Self>,
+        c: i8x16<Self>,
+    ) -> i8x16<Self> {
+        self.select_i8x16(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_min_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_max_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_adds_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_subs_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>) -> i8x16<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        let a = self.or_i8x16(a, self.shr_i8x16(a, 1u32));
+        let a = self.or_i8x16(a, self.shr_i8x16(a, 2u32));
+        let a = self.or_i8x16(a, self.shr_i8x16(a, 4u32));
+        self.count_ones_i8x16(self.not_i8x16(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        self.count_ones_i8x16(
+            self.and_i8x16(self.not_i8x16(a), self.sub_i8x16(a, self.splat_i8x16(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>, b: i8x16<Avx2>) -> i8x32<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn neg_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>) -> i8x16<Avx2> {
+                _mm_sub_epi8(_mm_setzero_si128(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_cvtepi8_epi16(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x16(self, a: i8x16<Self>) -> i32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>) -> i32 {
+                let sums = _mm_sad_epu8(
+                    _mm_xor_si128(a.into(), _mm_set1_epi8(i8::MIN)),
+                    _mm_setzero_si128(),
+                );
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                let sum = _mm_cvtsi128_si32(sums);
+                sum - 2048
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x16(self, a: i8x16<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x16<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u8x16(self, val: u8) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u8) -> u8x16<Avx2> {
+                _mm_set1_epi8(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u8x16(self, val: [u8; 16usize]) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u8x16(self, val: &[u8; 16usize]) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u8x16(self, a: u8x16<Self>) -> [u8; 16usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [u8; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u8x16(self, a: &u8x16<Self>) -> &[u8; 16usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [u8; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u8x16(self, a: &mut u8x16<Self>) -> &mut [u8; 16usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [u8; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u8x16(self, a: u8x16<Self>, dest: &mut [u8; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u8x16<const SHIFT: usize>(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_u8x16(b).val.0,
+            self.cvt_to_bytes_u8x16(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_u8x16(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u8x16<const SHIFT: usize>(
+        self,
+        a: u8x16<Self>,
+        b: u8x16<Self>,
+    ) -> u8x16<Self> {
+        self.slide_u8x16::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u8x16<const OFFSET: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_u8x16::<0>(a, a),
+            1 => self.slide_u8x16::<1>(a, a),
+            2 => self.slide_u8x16::<2>(a, a),
+            3 => self.slide_u8x16::<3>(a, a),
+            4 => self.slide_u8x16::<4>(a, a),
+            5 => self.slide_u8x16::<5>(a, a),
+            6 => self.slide_u8x16::<6>(a, a),
+            7 => self.slide_u8x16::<7>(a, a),
+            8 => self.slide_u8x16::<8>(a, a),
+            9 => self.slide_u8x16::<9>(a, a),
+            10 => self.slide_u8x16::<10>(a, a),
+            11 => self.slide_u8x16::<11>(a, a),
+            12 => self.slide_u8x16::<12>(a, a),
+            13 => self.slide_u8x16::<13>(a, a),
+            14 => self.slide_u8x16::<14>(a, a),
+            15 => self.slide_u8x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u8x16<const OFFSET: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_u8x16::<16>(a, a),
+            1 => self.slide_u8x16::<15>(a, a),
+            2 => self.slide_u8x16::<14>(a, a),
+            3 => self.slide_u8x16::<13>(a, a),
+            4 => self.slide_u8x16::<12>(a, a),
+            5 => self.slide_u8x16::<11>(a, a),
+            6 => self.slide_u8x16::<10>(a, a),
+            7 => self.slide_u8x16::<9>(a, a),
+            8 => self.slide_u8x16::<8>(a, a),
+            9 => self.slide_u8x16::<7>(a, a),
+            10 => self.slide_u8x16::<6>(a, a),
+            11 => self.slide_u8x16::<5>(a, a),
+            12 => self.slide_u8x16::<4>(a, a),
+            13 => self.slide_u8x16::<3>(a, a),
+            14 => self.slide_u8x16::<2>(a, a),
+            15 => self.slide_u8x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u8x16<const OFFSET: usize>(
+        self,
+        a: u8x16<Self>,
+        padding: u8,
+    ) -> u8x16<Self> {
+        let padding = self.splat_u8x16(padding);
+        match OFFSET {
+            0 => self.slide_u8x16::<0>(a, padding),
+            1 => self.slide_u8x16::<1>(a, padding),
+            2 => self.slide_u8x16::<2>(a, padding),
+            3 => self.slide_u8x16::<3>(a, padding),
+            4 => self.slide_u8x16::<4>(a, padding),
+            5 => self.slide_u8x16::<5>(a, padding),
+            6 => self.slide_u8x16::<6>(a, padding),
+            7 => self.slide_u8x16::<7>(a, padding),
+            8 => self.slide_u8x16::<8>(a, padding),
+            9 => self.slide_u8x16::<9>(a, padding),
+            10 => self.slide_u8x16::<10>(a, padding),
+            11 => self.slide_u8x16::<11>(a, padding),
+            12 => self.slide_u8x16::<12>(a, padding),
+            13 => self.slide_u8x16::<13>(a, padding),
+            14 => self.slide_u8x16::<14>(a, padding),
+            15 => self.slide_u8x16::<15>(a, padding),
+            16 => self.slide_u8x16::<16>(a, padding),
+            _ => self.slide_u8x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u8x16<const OFFSET: usize>(
+        self,
+        a: u8x16<Self>,
+        padding: u8,
+    ) -> u8x16<Self> {
+        let padding = self.splat_u8x16(padding);
+        match OFFSET {
+            0 => self.slide_u8x16::<16>(padding, a),
+            1 => self.slide_u8x16::<15>(padding, a),
+            2 => self.slide_u8x16::<14>(padding, a),
+            3 => self.slide_u8x16::<13>(padding, a),
+            4 => self.slide_u8x16::<12>(padding, a),
+            5 => self.slide_u8x16::<11>(padding, a),
+            6 => self.slide_u8x16::<10>(padding, a),
+            7 => self.slide_u8x16::<9>(padding, a),
+            8 => self.slide_u8x16::<8>(padding, a),
+            9 => self.slide_u8x16::<7>(padding, a),
+            10 => self.slide_u8x16::<6>(padding, a),
+            11 => self.slide_u8x16::<5>(padding, a),
+            12 => self.slide_u8x16::<4>(padding, a),
+            13 => self.slide_u8x16::<3>(padding, a),
+            14 => self.slide_u8x16::<2>(padding, a),
+            15 => self.slide_u8x16::<1>(padding, a),
+            16 => self.slide_u8x16::<0>(padding, a),
+            _ => self.slide_u8x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u8x16<const LANE: usize>(self, a: u8x16<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the intrinsics zero-extend the lane to an `i32`"
+            )]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, lane: usize) -> u8 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_extract_epi8::<0>(a) as u8,
+                    1 => _mm_extract_epi8::<1>(a) as u8,
+                    2 => _mm_extract_epi8::<2>(a) as u8,
+                    3 => _mm_extract_epi8::<3>(a) as u8,
+                    4 => _mm_extract_epi8::<4>(a) as u8,
+                    5 => _mm_extract_epi8::<5>(a) as u8,
+                    6 => _mm_extract_epi8::<6>(a) as u8,
+                    7 => _mm_extract_epi8::<7>(a) as u8,
+                    8 => _mm_extract_epi8::<8>(a) as u8,
+                    9 => _mm_extract_epi8::<9>(a) as u8,
+                    10 => _mm_extract_epi8::<10>(a) as u8,
+                    11 => _mm_extract_epi8::<11>(a) as u8,
+                    12 => _mm_extract_epi8::<12>(a) as u8,
+                    13 => _mm_extract_epi8::<13>(a) as u8,
+                    14 => _mm_extract_epi8::<14>(a) as u8,
+                    15 => _mm_extract_epi8::<15>(a) as u8,
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u8x16<const LANE: usize>(self, a: u8x16<Self>, value: u8) -> u8x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, value: u8, lane: usize) -> u8x16<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_insert_epi8::<0>(a, i32::from(value)),
+                    1 => _mm_insert_epi8::<1>(a, i32::from(value)),
+                    2 => _mm_insert_epi8::<2>(a, i32::from(value)),
+                    3 => _mm_insert_epi8::<3>(a, i32::from(value)),
+                    4 => _mm_insert_epi8::<4>(a, i32::from(value)),
+                    5 => _mm_insert_epi8::<5>(a, i32::from(value)),
+                    6 => _mm_insert_epi8::<6>(a, i32::from(value)),
+                    7 => _mm_insert_epi8::<7>(a, i32::from(value)),
+                    8 => _mm_insert_epi8::<8>(a, i32::from(value)),
+                    9 => _mm_insert_epi8::<9>(a, i32::from(value)),
+                    10 => _mm_insert_epi8::<10>(a, i32::from(value)),
+                    11 => _mm_insert_epi8::<11>(a, i32::from(value)),
+                    12 => _mm_insert_epi8::<12>(a, i32::from(value)),
+                    13 => _mm_insert_epi8::<13>(a, i32::from(value)),
+                    14 => _mm_insert_epi8::<14>(a, i32::from(value)),
+                    15 => _mm_insert_epi8::<15>(a, i32::from(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x16(self, a: u8x16<Self>, index: usize) -> u8 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u8x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u8x16(self, a: u8x16<Self>, indices: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, indices: u8x16<Avx2>) -> u8x16<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_u8x16(a).val.0, indices.into());
+                token.cvt_from_bytes_u8x16(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_add_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_sub_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                let dst_even = _mm_mullo_epi16(a.into(), b.into());
+                let dst_odd =
+                    _mm_mullo_epi16(_mm_srli_epi16::<8>(a.into()), _mm_srli_epi16::<8>(b.into()));
+                _mm_or_si128(
+                    _mm_slli_epi16(dst_odd, 8),
+                    _mm_and_si128(dst_even, _mm_set1_epi16(0xFF)),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        self.xor_u8x16(a, self.splat_u8x16(!0))
+    }
+    #[inline(always)]
+    fn shl_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, shift: u32) -> u8x16<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 = _mm_unpacklo_epi8(val, _mm_setzero_si128());
+                let hi_16 = _mm_unpackhi_epi8(val, _mm_setzero_si128());
+                let lo_shifted = _mm_sll_epi16(lo_16, shift_count);
+                let hi_shifted = _mm_sll_epi16(hi_16, shift_count);
+                _mm_packus_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::wrapping_shl(a[0usize], b[0usize] as u32),
+            u8::wrapping_shl(a[1usize], b[1usize] as u32),
+            u8::wrapping_shl(a[2usize], b[2usize] as u32),
+            u8::wrapping_shl(a[3usize], b[3usize] as u32),
+            u8::wrapping_shl(a[4usize], b[4usize] as u32),
+            u8::wrapping_shl(a[5usize], b[5usize] as u32),
+            u8::wrapping_shl(a[6usize], b[6usize] as u32),
+            u8::wrapping_shl(a[7usize], b[7usize] as u32),
+            u8::wrapping_shl(a[8usize], b[8usize] as u32),
+            u8::wrapping_shl(a[9usize], b[9usize] as u32),
+            u8::wrapping_shl(a[10usize], b[10usize] as u32),
+            u8::wrapping_shl(a[11usize], b[11usize] as u32),
+            u8::wrapping_shl(a[12usize], b[12usize] as u32),
+            u8::wrapping_shl(a[13usize], b[13usize] as u32),
+            u8::wrapping_shl(a[14usize], b[14usize] as u32),
+            u8::wrapping_shl(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, shift: u32) -> u8x16<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 = _mm_unpacklo_epi8(val, _mm_setzero_si128());
+                let hi_16 = _mm_unpackhi_epi8(val, _mm_setzero_si128());
+                let lo_shifted = _mm_srl_epi16(lo_16, shift_count);
+                let hi_shifted = _mm_srl_epi16(hi_16, shift_count);
+                _mm_packus_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::wrapping_shr(a[0usize], b[0usize] as u32),
+            u8::wrapping_shr(a[1usize], b[1usize] as u32),
+            u8::wrapping_shr(a[2usize], b[2usize] as u32),
+            u8::wrapping_shr(a[3usize], b[3usize] as u32),
+            u8::wrapping_shr(a[4usize], b[4usize] as u32),
+            u8::wrapping_shr(a[5usize], b[5usize] as u32),
+            u8::wrapping_shr(a[6usize], b[6usize] as u32),
+            u8::wrapping_shr(a[7usize], b[7usize] as u32),
+            u8::wrapping_shr(a[8usize], b[8usize] as u32),
+            u8::wrapping_shr(a[9usize], b[9usize] as u32),
+            u8::wrapping_shr(a[10usize], b[10usize] as u32),
+            u8::wrapping_shr(a[11usize], b[11usize] as u32),
+            u8::wrapping_shr(a[12usize], b[12usize] as u32),
+            u8::wrapping_shr(a[13usize], b[13usize] as u32),
+            u8::wrapping_shr(a[14usize], b[14usize] as u32),
+            u8::wrapping_shr(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u8x16(self, a: u8x16<Self>, b: i8x16<Self>) -> u8x16<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> mask8x16<Avx2> {
+                {
+                    let sign_bit = _mm_set1_epi8(0x80u8.cast_signed());
+                    let lhs_signed = _mm_xor_si128(b.into(), sign_bit);
+                    let rhs_signed = _mm_xor_si128(a.into(), sign_bit);
+                    _mm_cmpgt_epi8(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(_mm_min_epu8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(_mm_max_epu8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> mask8x16<Avx2> {
+                {
+                    let sign_bit = _mm_set1_epi8(0x80u8.cast_signed());
+                    let lhs_signed = _mm_xor_si128(a.into(), sign_bit);
+                    let rhs_signed = _mm_xor_si128(b.into(), sign_bit);
+                    _mm_cmpgt_epi8(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_unpacklo_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_unpackhi_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                let mask = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpacklo_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                let mask = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpackhi_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> (u8x16<Self>, u8x16<Self>) {
+        (self.zip_low_u8x16(a, b), self.zip_high_u8x16(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> (u8x16<Self>, u8x16<Self>) {
+        (self.unzip_low_u8x16(a, b), self.unzip_high_u8x16(a, b))
+    }
+    #[inline(always)]
+    fn select_u8x16(self, a: mask8x16<Self>, b: u8x16<Self>, c: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask8x16<Avx2>,
+                b: u8x16<Avx2>,
+                c: u8x16<Avx2>,
+            ) -> u8x16<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u8x16(
+        self,
+        a: mask8x16<Self>,
+        b: u8x16<Self>,
+        c: u8x16<Self>,
+    ) -> u8x16<Self> {
+        self.select_u8x16(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_min_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_max_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_adds_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_subs_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>) -> u8x16<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        let a = self.or_u8x16(a, self.shr_u8x16(a, 1u32));
+        let a = self.or_u8x16(a, self.shr_u8x16(a, 2u32));
+        let a = self.or_u8x16(a, self.shr_u8x16(a, 4u32));
+        self.count_ones_u8x16(self.not_u8x16(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        self.count_ones_u8x16(
+            self.and_u8x16(self.not_u8x16(a), self.sub_u8x16(a, self.splat_u8x16(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x32<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn widen_u8x16(self, a: u8x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_cvtepu8_epi16(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u8x16(
+        self,
+        acc: u16x16<Self>,
+        a: u8x16<Self>,
+        b: u8x16<Self>,
+    ) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                acc: u16x16<Avx2>,
+                a: u8x16<Avx2>,
+                b: u8x16<Avx2>,
+            ) -> u16x16<Avx2> {
+                let (a, b) = (a.into(), b.into());
+                let abd = _mm_or_si128(_mm_subs_epu8(a, b), _mm_subs_epu8(b, a));
+                _mm256_add_epi16(acc.into(), _mm256_cvtepu8_epi16(abd)).simd_into(token)
+            }
+        );
+        kernel(self, acc, a, b)
+    }
+    #[inline(always)]
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                _mm_avg_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x16(self, a: u8x16<Self>) -> u32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>) -> u32 {
+                let sums = _mm_sad_epu8(a.into(), _mm_setzero_si128());
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                let sum = _mm_cvtsi128_si32(sums);
+                sum.cast_unsigned()
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn compress_bytes_u8x16(self, a: u8x16<Self>, mask: mask8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, mask: mask8x16<Avx2>) -> u8x16<Avx2> {
+                let (shuffle, merge) =
+                    crate::support::compress_bytes_indices(token.to_bitmask_mask8x16(mask));
+                let packed = _mm_shuffle_epi8(a.into(), token.load_array_u8x16(shuffle).into());
+                _mm_shuffle_epi8(packed, token.load_array_u8x16(merge).into()).simd_into(token)
+            }
+        );
+        kernel(self, a, mask)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x16(self, a: u8x16<Self>, mask: mask8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, mask: mask8x16<Avx2>) -> u8x16<Avx2> {
+                let indices = crate::support::expand_bytes_indices(token.to_bitmask_mask8x16(mask));
+                _mm_shuffle_epi8(a.into(), token.load_array_u8x16(indices).into()).simd_into(token)
+            }
+        );
+        kernel(self, a, mask)
+    }
+    #[inline(always)]
+    fn table_lookup_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x16<Avx2>, b: u8x16<Avx2>) -> u8x16<Avx2> {
+                let indices = _mm_adds_epu8(b.into(), _mm_set1_epi8(0x70));
+                _mm_shuffle_epi8(a.into(), indices).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x16<const FACTOR: u8>(self, a: u8x16<Self>) -> u8x16<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<16>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x16(
+            self.load_array_u8x16(low),
+            self.and_u8x16(a, self.splat_u8x16(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x16(self.load_array_u8x16(high), self.shr_u8x16(a, 4));
+        self.xor_u8x16(low, high)
+    }
+    #[inline(always)]
+    fn splat_mask8x16(self, val: bool) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask8x16<Avx2> {
+                let val: i8 = if val { !0 } else { 0 };
+                _mm_set1_epi8(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask8x16(self, val: [i8; 16usize]) -> mask8x16<Self> {
+        mask8x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask8x16(self, a: mask8x16<Self>) -> [i8; 16usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i8; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask8x16<Avx2> {
+                {
+                    let bit_bytes = _mm_cvtsi32_si128(bits as i32);
+                    let bit_bytes = _mm_shuffle_epi8(
+                        bit_bytes,
+                        _mm_setr_epi8(0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1),
+                    );
+                    let bit_mask =
+                        _mm_setr_epi8(1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128);
+                    _mm_cmpeq_epi8(_mm_and_si128(bit_bytes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x16(self, a: mask8x16<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>) -> u64 {
+                _mm_movemask_epi8(a.into()) as u32 as u64
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask8x16(self, a: &mut mask8x16<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 16usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        let mut lanes = self.as_array_mask8x16(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask8x16(lanes);
+    }
+    #[inline(always)]
+    fn and_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>, b: mask8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>, b: mask8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>, b: mask8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        self.xor_mask8x16(a, self.splat_mask8x16(true))
+    }
+    #[inline(always)]
+    fn select_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask8x16<Avx2>,
+                b: mask8x16<Avx2>,
+                c: mask8x16<Avx2>,
+            ) -> mask8x16<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        self.select_mask8x16(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>, b: mask8x16<Avx2>) -> mask8x16<Avx2> {
+                _mm_cmpeq_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask8x16(self, a: mask8x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask8x16(self, a: mask8x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 == 0xffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask8x16(self, a: mask8x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 != 0xffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask8x16(self, a: mask8x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x16<Avx2>, b: mask8x16<Avx2>) -> mask8x32<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn splat_i16x8(self, val: i16) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i16) -> i16x8<Avx2> {
+                _mm_set1_epi16(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i16x8(self, val: [i16; 8usize]) -> i16x8<Self> {
+        i16x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i16x8(self, val: &[i16; 8usize]) -> i16x8<Self> {
+        i16x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i16x8(self, a: i16x8<Self>) -> [i16; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i16; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i16x8(self, a: &i16x8<Self>) -> &[i16; 8usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [i16; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i16x8(self, a: &mut i16x8<Self>) -> &mut [i16; 8usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [i16; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i16x8(self, a: i16x8<Self>, dest: &mut [i16; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i16x8(self, a: u8x16<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i16x8<const SHIFT: usize>(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_i16x8(b).val.0,
+            self.cvt_to_bytes_i16x8(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_i16x8(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i16x8<const SHIFT: usize>(
+        self,
+        a: i16x8<Self>,
+        b: i16x8<Self>,
+    ) -> i16x8<Self> {
+        self.slide_i16x8::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i16x8<const OFFSET: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_i16x8::<0>(a, a),
+            1 => self.slide_i16x8::<1>(a, a),
+            2 => self.slide_i16x8::<2>(a, a),
+            3 => self.slide_i16x8::<3>(a, a),
+            4 => self.slide_i16x8::<4>(a, a),
+            5 => self.slide_i16x8::<5>(a, a),
+            6 => self.slide_i16x8::<6>(a, a),
+            7 => self.slide_i16x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i16x8<const OFFSET: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_i16x8::<8>(a, a),
+            1 => self.slide_i16x8::<7>(a, a),
+            2 => self.slide_i16x8::<6>(a, a),
+            3 => self.slide_i16x8::<5>(a, a),
+            4 => self.slide_i16x8::<4>(a, a),
+            5 => self.slide_i16x8::<3>(a, a),
+            6 => self.slide_i16x8::<2>(a, a),
+            7 => self.slide_i16x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i16x8<const OFFSET: usize>(
+        self,
+        a: i16x8<Self>,
+        padding: i16,
+    ) -> i16x8<Self> {
+        let padding = self.splat_i16x8(padding);
+        match OFFSET {
+            0 => self.slide_i16x8::<0>(a, padding),
+            1 => self.slide_i16x8::<1>(a, padding),
+            2 => self.slide_i16x8::<2>(a, padding),
+            3 => self.slide_i16x8::<3>(a, padding),
+            4 => self.slide_i16x8::<4>(a, padding),
+            5 => self.slide_i16x8::<5>(a, padding),
+            6 => self.slide_i16x8::<6>(a, padding),
+            7 => self.slide_i16x8::<7>(a, padding),
+            8 => self.slide_i16x8::<8>(a, padding),
+            _ => self.slide_i16x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i16x8<const OFFSET: usize>(
+        self,
+        a: i16x8<Self>,
+        padding: i16,
+    ) -> i16x8<Self> {
+        let padding = self.splat_i16x8(padding);
+        match OFFSET {
+            0 => self.slide_i16x8::<8>(padding, a),
+            1 => self.slide_i16x8::<7>(padding, a),
+            2 => self.slide_i16x8::<6>(padding, a),
+            3 => self.slide_i16x8::<5>(padding, a),
+            4 => self.slide_i16x8::<4>(padding, a),
+            5 => self.slide_i16x8::<3>(padding, a),
+            6 => self.slide_i16x8::<2>(padding, a),
+            7 => self.slide_i16x8::<1>(padding, a),
+            8 => self.slide_i16x8::<0>(padding, a),
+            _ => self.slide_i16x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i16x8<const LANE: usize>(self, a: i16x8<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the intrinsics zero-extend the lane to an `i32`"
+            )]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, lane: usize) -> i16 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_extract_epi16::<0>(a) as i16,
+                    1 => _mm_extract_epi16::<1>(a) as i16,
+                    2 => _mm_extract_epi16::<2>(a) as i16,
+                    3 => _mm_extract_epi16::<3>(a) as i16,
+                    4 => _mm_extract_epi16::<4>(a) as i16,
+                    5 => _mm_extract_epi16::<5>(a) as i16,
+                    6 => _mm_extract_epi16::<6>(a) as i16,
+                    7 => _mm_extract_epi16::<7>(a) as i16,
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i16x8<const LANE: usize>(self, a: i16x8<Self>, value: i16) -> i16x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, value: i16, lane: usize) -> i16x8<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_insert_epi16::<0>(a, i32::from(value)),
+                    1 => _mm_insert_epi16::<1>(a, i32::from(value)),
+                    2 => _mm_insert_epi16::<2>(a, i32::from(value)),
+                    3 => _mm_insert_epi16::<3>(a, i32::from(value)),
+                    4 => _mm_insert_epi16::<4>(a, i32::from(value)),
+                    5 => _mm_insert_epi16::<5>(a, i32::from(value)),
+                    6 => _mm_insert_epi16::<6>(a, i32::from(value)),
+                    7 => _mm_insert_epi16::<7>(a, i32::from(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x8(self, a: i16x8<Self>, index: usize) -> i16 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i16x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i16x8(self, a: i16x8<Self>, indices: u8x16<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, indices: u8x16<Avx2>) -> i16x8<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_i16x8(a).val.0, indices.into());
+                token.cvt_from_bytes_i16x8(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_add_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_sub_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_mullo_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        self.xor_i16x8(a, self.splat_i16x8(!0))
+    }
+    #[inline(always)]
+    fn shl_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, shift: u32) -> i16x8<Avx2> {
+                _mm_sll_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_shl(a[0usize], b[0usize] as u32),
+            i16::wrapping_shl(a[1usize], b[1usize] as u32),
+            i16::wrapping_shl(a[2usize], b[2usize] as u32),
+            i16::wrapping_shl(a[3usize], b[3usize] as u32),
+            i16::wrapping_shl(a[4usize], b[4usize] as u32),
+            i16::wrapping_shl(a[5usize], b[5usize] as u32),
+            i16::wrapping_shl(a[6usize], b[6usize] as u32),
+            i16::wrapping_shl(a[7usize], b[7usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, shift: u32) -> i16x8<Avx2> {
+                _mm_sra_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_shr(a[0usize], b[0usize] as u32),
+            i16::wrapping_shr(a[1usize], b[1usize] as u32),
+            i16::wrapping_shr(a[2usize], b[2usize] as u32),
+            i16::wrapping_shr(a[3usize], b[3usize] as u32),
+            i16::wrapping_shr(a[4usize], b[4usize] as u32),
+            i16::wrapping_shr(a[5usize], b[5usize] as u32),
+            i16::wrapping_shr(a[6usize], b[6usize] as u32),
+            i16::wrapping_shr(a[7usize], b[7usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpgt_epi16(b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(_mm_min_epi16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(_mm_max_epi16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpgt_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_unpacklo_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_unpackhi_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                let mask = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpacklo_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                let mask = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpackhi_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> (i16x8<Self>, i16x8<Self>) {
+        (self.zip_low_i16x8(a, b), self.zip_high_i16x8(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> (i16x8<Self>, i16x8<Self>) {
+        (self.unzip_low_i16x8(a, b), self.unzip_high_i16x8(a, b))
+    }
+    #[inline(always)]
+    fn select_i16x8(self, a: mask16x8<Self>, b: i16x8<Self>, c: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask16x8<Avx2>,
+                b: i16x8<Avx2>,
+                c: i16x8<Avx2>,
+            ) -> i16x8<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i16x8(
+        self,
+        a: mask16x8<Self>,
+        b: i16x8<Self>,
+        c: i16x8<Self>,
+    ) -> i16x8<Self> {
+        self.select_i16x8(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_min_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_max_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_adds_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_subs_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>) -> i16x8<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    let bytes = _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm_add_epi16(
+                        _mm_and_si128(bytes, _mm_set1_epi16(0xff)),
+                        _mm_srli_epi16::<8>(bytes),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        let a = self.or_i16x8(a, self.shr_i16x8(a, 1u32));
+        let a = self.or_i16x8(a, self.shr_i16x8(a, 2u32));
+        let a = self.or_i16x8(a, self.shr_i16x8(a, 4u32));
+        let a = self.or_i16x8(a, self.shr_i16x8(a, 8u32));
+        self.count_ones_i16x8(self.not_i16x8(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        self.count_ones_i16x8(
+            self.and_i16x8(self.not_i16x8(a), self.sub_i16x8(a, self.splat_i16x8(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>, b: i16x8<Avx2>) -> i16x16<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn neg_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>) -> i16x8<Avx2> {
+                _mm_sub_epi16(_mm_setzero_si128(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_cvtepi16_epi32(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x8(self, a: i16x8<Self>) -> i32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>) -> i32 {
+                let sums = _mm_madd_epi16(a.into(), _mm_set1_epi16(1));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                _mm_cvtsi128_si32(sums)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x8(self, a: i16x8<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x8<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u16x8(self, val: u16) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u16) -> u16x8<Avx2> {
+                _mm_set1_epi16(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u16x8(self, val: [u16; 8usize]) -> u16x8<Self> {
+        u16x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u16x8(self, val: &[u16; 8usize]) -> u16x8<Self> {
+        u16x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u16x8(self, a: u16x8<Self>) -> [u16; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [u16; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u16x8(self, a: &u16x8<Self>) -> &[u16; 8usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [u16; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u16x8(self, a: &mut u16x8<Self>) -> &mut [u16; 8usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [u16; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u16x8(self, a: u16x8<Self>, dest: &mut [u16; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u16x8(self, a: u8x16<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u16x8<const SHIFT: usize>(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_u16x8(b).val.0,
+            self.cvt_to_bytes_u16x8(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_u16x8(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u16x8<const SHIFT: usize>(
+        self,
+        a: u16x8<Self>,
+        b: u16x8<Self>,
+    ) -> u16x8<Self> {
+        self.slide_u16x8::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u16x8<const OFFSET: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_u16x8::<0>(a, a),
+            1 => self.slide_u16x8::<1>(a, a),
+            2 => self.slide_u16x8::<2>(a, a),
+            3 => self.slide_u16x8::<3>(a, a),
+            4 => self.slide_u16x8::<4>(a, a),
+            5 => self.slide_u16x8::<5>(a, a),
+            6 => self.slide_u16x8::<6>(a, a),
+            7 => self.slide_u16x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u16x8<const OFFSET: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_u16x8::<8>(a, a),
+            1 => self.slide_u16x8::<7>(a, a),
+            2 => self.slide_u16x8::<6>(a, a),
+            3 => self.slide_u16x8::<5>(a, a),
+            4 => self.slide_u16x8::<4>(a, a),
+            5 => self.slide_u16x8::<3>(a, a),
+            6 => self.slide_u16x8::<2>(a, a),
+            7 => self.slide_u16x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u16x8<const OFFSET: usize>(
+        self,
+        a: u16x8<Self>,
+        padding: u16,
+    ) -> u16x8<Self> {
+        let padding = self.splat_u16x8(padding);
+        match OFFSET {
+            0 => self.slide_u16x8::<0>(a, padding),
+            1 => self.slide_u16x8::<1>(a, padding),
+            2 => self.slide_u16x8::<2>(a, padding),
+            3 => self.slide_u16x8::<3>(a, padding),
+            4 => self.slide_u16x8::<4>(a, padding),
+            5 => self.slide_u16x8::<5>(a, padding),
+            6 => self.slide_u16x8::<6>(a, padding),
+            7 => self.slide_u16x8::<7>(a, padding),
+            8 => self.slide_u16x8::<8>(a, padding),
+            _ => self.slide_u16x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u16x8<const OFFSET: usize>(
+        self,
+        a: u16x8<Self>,
+        padding: u16,
+    ) -> u16x8<Self> {
+        let padding = self.splat_u16x8(padding);
+        match OFFSET {
+            0 => self.slide_u16x8::<8>(padding, a),
+            1 => self.slide_u16x8::<7>(padding, a),
+            2 => self.slide_u16x8::<6>(padding, a),
+            3 => self.slide_u16x8::<5>(padding, a),
+            4 => self.slide_u16x8::<4>(padding, a),
+            5 => self.slide_u16x8::<3>(padding, a),
+            6 => self.slide_u16x8::<2>(padding, a),
+            7 => self.slide_u16x8::<1>(padding, a),
+            8 => self.slide_u16x8::<0>(padding, a),
+            _ => self.slide_u16x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u16x8<const LANE: usize>(self, a: u16x8<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the intrinsics zero-extend the lane to an `i32`"
+            )]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, lane: usize) -> u16 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_extract_epi16::<0>(a) as u16,
+                    1 => _mm_extract_epi16::<1>(a) as u16,
+                    2 => _mm_extract_epi16::<2>(a) as u16,
+                    3 => _mm_extract_epi16::<3>(a) as u16,
+                    4 => _mm_extract_epi16::<4>(a) as u16,
+                    5 => _mm_extract_epi16::<5>(a) as u16,
+                    6 => _mm_extract_epi16::<6>(a) as u16,
+                    7 => _mm_extract_epi16::<7>(a) as u16,
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u16x8<const LANE: usize>(self, a: u16x8<Self>, value: u16) -> u16x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, value: u16, lane: usize) -> u16x8<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_insert_epi16::<0>(a, i32::from(value)),
+                    1 => _mm_insert_epi16::<1>(a, i32::from(value)),
+                    2 => _mm_insert_epi16::<2>(a, i32::from(value)),
+                    3 => _mm_insert_epi16::<3>(a, i32::from(value)),
+                    4 => _mm_insert_epi16::<4>(a, i32::from(value)),
+                    5 => _mm_insert_epi16::<5>(a, i32::from(value)),
+                    6 => _mm_insert_epi16::<6>(a, i32::from(value)),
+                    7 => _mm_insert_epi16::<7>(a, i32::from(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x8(self, a: u16x8<Self>, index: usize) -> u16 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u16x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u16x8(self, a: u16x8<Self>, indices: u8x16<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, indices: u8x16<Avx2>) -> u16x8<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_u16x8(a).val.0, indices.into());
+                token.cvt_from_bytes_u16x8(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_add_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_sub_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_mullo_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        self.xor_u16x8(a, self.splat_u16x8(!0))
+    }
+    #[inline(always)]
+    fn shl_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, shift: u32) -> u16x8<Avx2> {
+                _mm_sll_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::wrapping_shl(a[0usize], b[0usize] as u32),
+            u16::wrapping_shl(a[1usize], b[1usize] as u32),
+            u16::wrapping_shl(a[2usize], b[2usize] as u32),
+            u16::wrapping_shl(a[3usize], b[3usize] as u32),
+            u16::wrapping_shl(a[4usize], b[4usize] as u32),
+            u16::wrapping_shl(a[5usize], b[5usize] as u32),
+            u16::wrapping_shl(a[6usize], b[6usize] as u32),
+            u16::wrapping_shl(a[7usize], b[7usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, shift: u32) -> u16x8<Avx2> {
+                _mm_srl_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::wrapping_shr(a[0usize], b[0usize] as u32),
+            u16::wrapping_shr(a[1usize], b[1usize] as u32),
+            u16::wrapping_shr(a[2usize], b[2usize] as u32),
+            u16::wrapping_shr(a[3usize], b[3usize] as u32),
+            u16::wrapping_shr(a[4usize], b[4usize] as u32),
+            u16::wrapping_shr(a[5usize], b[5usize] as u32),
+            u16::wrapping_shr(a[6usize], b[6usize] as u32),
+            u16::wrapping_shr(a[7usize], b[7usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u16x8(self, a: u16x8<Self>, b: i16x8<Self>) -> u16x8<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> mask16x8<Avx2> {
+                {
+                    let sign_bit = _mm_set1_epi16(0x8000u16.cast_signed());
+                    let lhs_signed = _mm_xor_si128(b.into(), sign_bit);
+                    let rhs_signed = _mm_xor_si128(a.into(), sign_bit);
+                    _mm_cmpgt_epi16(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(_mm_min_epu16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(_mm_max_epu16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> mask16x8<Avx2> {
+                {
+                    let sign_bit = _mm_set1_epi16(0x8000u16.cast_signed());
+                    let lhs_signed = _mm_xor_si128(a.into(), sign_bit);
+                    let rhs_signed = _mm_xor_si128(b.into(), sign_bit);
+                    _mm_cmpgt_epi16(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_unpacklo_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_unpackhi_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                let mask = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpacklo_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                let mask = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                let t1 = _mm_shuffle_epi8(a.into(), mask);
+                let t2 = _mm_shuffle_epi8(b.into(), mask);
+                _mm_unpackhi_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> (u16x8<Self>, u16x8<Self>) {
+        (self.zip_low_u16x8(a, b), self.zip_high_u16x8(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> (u16x8<Self>, u16x8<Self>) {
+        (self.unzip_low_u16x8(a, b), self.unzip_high_u16x8(a, b))
+    }
+    #[inline(always)]
+    fn select_u16x8(self, a: mask16x8<Self>, b: u16x8<Self>, c: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask16x8<Avx2>,
+                b: u16x8<Avx2>,
+                c: u16x8<Avx2>,
+            ) -> u16x8<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u16x8(
+        self,
+        a: mask16x8<Self>,
+        b: u16x8<Self>,
+        c: u16x8<Self>,
+    ) -> u16x8<Self> {
+        self.select_u16x8(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_min_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_max_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_adds_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_subs_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>) -> u16x8<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    let bytes = _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm_add_epi16(
+                        _mm_and_si128(bytes, _mm_set1_epi16(0xff)),
+                        _mm_srli_epi16::<8>(bytes),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        let a = self.or_u16x8(a, self.shr_u16x8(a, 1u32));
+        let a = self.or_u16x8(a, self.shr_u16x8(a, 2u32));
+        let a = self.or_u16x8(a, self.shr_u16x8(a, 4u32));
+        let a = self.or_u16x8(a, self.shr_u16x8(a, 8u32));
+        self.count_ones_u16x8(self.not_u16x8(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        self.count_ones_u16x8(
+            self.and_u16x8(self.not_u16x8(a), self.sub_u16x8(a, self.splat_u16x8(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x16<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_cvtepu16_epi32(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u16x8(self, acc: u32x8<Self>, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                acc: u32x8<Avx2>,
+                a: u16x8<Avx2>,
+                b: u16x8<Avx2>,
+            ) -> u32x8<Avx2> {
+                let (a, b) = (a.into(), b.into());
+                let abd = _mm_or_si128(_mm_subs_epu16(a, b), _mm_subs_epu16(b, a));
+                _mm256_add_epi32(acc.into(), _mm256_cvtepu16_epi32(abd)).simd_into(token)
+            }
+        );
+        kernel(self, acc, a, b)
+    }
+    #[inline(always)]
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>, b: u16x8<Avx2>) -> u16x8<Avx2> {
+                _mm_avg_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x8(self, a: u16x8<Self>) -> u32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>) -> u32 {
+                let sums = _mm_madd_epi16(
+                    _mm_xor_si128(a.into(), _mm_set1_epi16(i16::MIN)),
+                    _mm_set1_epi16(1),
+                );
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                let sum = _mm_cvtsi128_si32(sums);
+                (sum + 262144).cast_unsigned()
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x8<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_mask16x8(self, val: bool) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask16x8<Avx2> {
+                let val: i16 = if val { !0 } else { 0 };
+                _mm_set1_epi16(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask16x8(self, val: [i16; 8usize]) -> mask16x8<Self> {
+        mask16x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask16x8(self, a: mask16x8<Self>) -> [i16; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i16; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask16x8<Avx2> {
+                {
+                    let bit_lanes = _mm_set1_epi16(bits as i16);
+                    let bit_mask = _mm_setr_epi16(1, 2, 4, 8, 16, 32, 64, 128);
+                    _mm_cmpeq_epi16(_mm_and_si128(bit_lanes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x8(self, a: mask16x8<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>) -> u64 {
+                {
+                    let packed = _mm_packs_epi16(a.into(), a.into());
+                    _mm_movemask_epi8(packed) as u8 as u64
+                }
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask16x8(self, a: &mut mask16x8<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 8usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        let mut lanes = self.as_array_mask16x8(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask16x8(lanes);
+    }
+    #[inline(always)]
+    fn and_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>, b: mask16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>, b: mask16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>, b: mask16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        self.xor_mask16x8(a, self.splat_mask16x8(true))
+    }
+    #[inline(always)]
+    fn select_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask16x8<Avx2>,
+                b: mask16x8<Avx2>,
+                c: mask16x8<Avx2>,
+            ) -> mask16x8<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        self.select_mask16x8(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>, b: mask16x8<Avx2>) -> mask16x8<Avx2> {
+                _mm_cmpeq_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask16x8(self, a: mask16x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask16x8(self, a: mask16x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 == 0xffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask16x8(self, a: mask16x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 != 0xffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask16x8(self, a: mask16x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>) -> bool {
+                _mm_movemask_epi8(a.into()) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x8<Avx2>, b: mask16x8<Avx2>) -> mask16x16<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn splat_i32x4(self, val: i32) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i32) -> i32x4<Avx2> {
+                _mm_set1_epi32(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i32x4(self, val: [i32; 4usize]) -> i32x4<Self> {
+        i32x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i32x4(self, val: &[i32; 4usize]) -> i32x4<Self> {
+        i32x4 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i32x4(self, a: i32x4<Self>) -> [i32; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i32x4(self, a: &i32x4<Self>) -> &[i32; 4usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [i32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i32x4(self, a: &mut i32x4<Self>) -> &mut [i32; 4usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [i32; 4usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i32x4(self, a: i32x4<Self>, dest: &mut [i32; 4usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i32x4(self, a: u8x16<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i32x4<const SHIFT: usize>(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_i32x4(b).val.0,
+            self.cvt_to_bytes_i32x4(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_i32x4(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i32x4<const SHIFT: usize>(
+        self,
+        a: i32x4<Self>,
+        b: i32x4<Self>,
+    ) -> i32x4<Self> {
+        self.slide_i32x4::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i32x4<const OFFSET: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_i32x4::<0>(a, a),
+            1 => self.slide_i32x4::<1>(a, a),
+            2 => self.slide_i32x4::<2>(a, a),
+            3 => self.slide_i32x4::<3>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i32x4<const OFFSET: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_i32x4::<4>(a, a),
+            1 => self.slide_i32x4::<3>(a, a),
+            2 => self.slide_i32x4::<2>(a, a),
+            3 => self.slide_i32x4::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i32x4<const OFFSET: usize>(
+        self,
+        a: i32x4<Self>,
+        padding: i32,
+    ) -> i32x4<Self> {
+        let padding = self.splat_i32x4(padding);
+        match OFFSET {
+            0 => self.slide_i32x4::<0>(a, padding),
+            1 => self.slide_i32x4::<1>(a, padding),
+            2 => self.slide_i32x4::<2>(a, padding),
+            3 => self.slide_i32x4::<3>(a, padding),
+            4 => self.slide_i32x4::<4>(a, padding),
+            _ => self.slide_i32x4::<4>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i32x4<const OFFSET: usize>(
+        self,
+        a: i32x4<Self>,
+        padding: i32,
+    ) -> i32x4<Self> {
+        let padding = self.splat_i32x4(padding);
+        match OFFSET {
+            0 => self.slide_i32x4::<4>(padding, a),
+            1 => self.slide_i32x4::<3>(padding, a),
+            2 => self.slide_i32x4::<2>(padding, a),
+            3 => self.slide_i32x4::<1>(padding, a),
+            4 => self.slide_i32x4::<0>(padding, a),
+            _ => self.slide_i32x4::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i32x4<const LANE: usize>(self, a: i32x4<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, lane: usize) -> i32 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_extract_epi32::<0>(a),
+                    1 => _mm_extract_epi32::<1>(a),
+                    2 => _mm_extract_epi32::<2>(a),
+                    3 => _mm_extract_epi32::<3>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i32x4<const LANE: usize>(self, a: i32x4<Self>, value: i32) -> i32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, value: i32, lane: usize) -> i32x4<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_insert_epi32::<0>(a, value),
+                    1 => _mm_insert_epi32::<1>(a, value),
+                    2 => _mm_insert_epi32::<2>(a, value),
+                    3 => _mm_insert_epi32::<3>(a, value),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x4(self, a: i32x4<Self>, index: usize) -> i32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_i32x4(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i32x4(self, a: i32x4<Self>, indices: u8x16<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, indices: u8x16<Avx2>) -> i32x4<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_i32x4(a).val.0, indices.into());
+                token.cvt_from_bytes_i32x4(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_add_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_sub_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_mullo_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        self.xor_i32x4(a, self.splat_i32x4(!0))
+    }
+    #[inline(always)]
+    fn shl_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, shift: u32) -> i32x4<Avx2> {
+                _mm_sll_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_sllv_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, shift: u32) -> i32x4<Avx2> {
+                _mm_sra_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_srav_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shift_signed_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let left = self.shlv_i32x4(a, b.bitcast());
+        let right = self.shrv_i32x4(a, self.neg_i32x4(b).bitcast());
+        self.select_i32x4(self.simd_lt_i32x4(b, self.splat_i32x4(0)), right, left)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpgt_epi32(b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(_mm_min_epi32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(_mm_max_epi32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpgt_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_unpacklo_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_unpackhi_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                let t1 = _mm_shuffle_epi32::<0b11_01_10_00>(a.into());
+                let t2 = _mm_shuffle_epi32::<0b11_01_10_00>(b.into());
+                _mm_unpacklo_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                let t1 = _mm_shuffle_epi32::<0b11_01_10_00>(a.into());
+                let t2 = _mm_shuffle_epi32::<0b11_01_10_00>(b.into());
+                _mm_unpackhi_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> (i32x4<Self>, i32x4<Self>) {
+        (self.zip_low_i32x4(a, b), self.zip_high_i32x4(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> (i32x4<Self>, i32x4<Self>) {
+        (self.unzip_low_i32x4(a, b), self.unzip_high_i32x4(a, b))
+    }
+    #[inline(always)]
+    fn select_i32x4(self, a: mask32x4<Self>, b: i32x4<Self>, c: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x4<Avx2>,
+                b: i32x4<Avx2>,
+                c: i32x4<Avx2>,
+            ) -> i32x4<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i32x4(
+        self,
+        a: mask32x4<Self>,
+        b: i32x4<Self>,
+        c: i32x4<Self>,
+    ) -> i32x4<Self> {
+        self.select_i32x4(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_min_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_max_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::saturating_add(a[0usize], b[0usize]),
+            i32::saturating_add(a[1usize], b[1usize]),
+            i32::saturating_add(a[2usize], b[2usize]),
+            i32::saturating_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::saturating_sub(a[0usize], b[0usize]),
+            i32::saturating_sub(a[1usize], b[1usize]),
+            i32::saturating_sub(a[2usize], b[2usize]),
+            i32::saturating_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>) -> i32x4<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    let bytes = _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm_madd_epi16(
+                        _mm_add_epi16(
+                            _mm_and_si128(bytes, _mm_set1_epi16(0xff)),
+                            _mm_srli_epi16::<8>(bytes),
+                        ),
+                        _mm_set1_epi16(1),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        let a = self.or_i32x4(a, self.shr_i32x4(a, 1u32));
+        let a = self.or_i32x4(a, self.shr_i32x4(a, 2u32));
+        let a = self.or_i32x4(a, self.shr_i32x4(a, 4u32));
+        let a = self.or_i32x4(a, self.shr_i32x4(a, 8u32));
+        let a = self.or_i32x4(a, self.shr_i32x4(a, 16u32));
+        self.count_ones_i32x4(self.not_i32x4(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        self.count_ones_i32x4(
+            self.and_i32x4(self.not_i32x4(a), self.sub_i32x4(a, self.splat_i32x4(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>, b: i32x4<Avx2>) -> i32x8<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>) -> i32x4<Avx2> {
+                _mm_sub_epi32(_mm_setzero_si128(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_cvtepi32_epi64(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x4(self, a: i32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x4<Avx2>) -> f32x4<Avx2> {
+                _mm_cvtepi32_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u32x4(self, val: u32) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u32) -> u32x4<Avx2> {
+                _mm_set1_epi32(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u32x4(self, val: [u32; 4usize]) -> u32x4<Self> {
+        u32x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u32x4(self, val: &[u32; 4usize]) -> u32x4<Self> {
+        u32x4 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u32x4(self, a: u32x4<Self>) -> [u32; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [u32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u32x4(self, a: &u32x4<Self>) -> &[u32; 4usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [u32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u32x4(self, a: &mut u32x4<Self>) -> &mut [u32; 4usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [u32; 4usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u32x4(self, a: u32x4<Self>, dest: &mut [u32; 4usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u32x4(self, a: u8x16<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u32x4<const SHIFT: usize>(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_u32x4(b).val.0,
+            self.cvt_to_bytes_u32x4(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_u32x4(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u32x4<const SHIFT: usize>(
+        self,
+        a: u32x4<Self>,
+        b: u32x4<Self>,
+    ) -> u32x4<Self> {
+        self.slide_u32x4::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u32x4<const OFFSET: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_u32x4::<0>(a, a),
+            1 => self.slide_u32x4::<1>(a, a),
+            2 => self.slide_u32x4::<2>(a, a),
+            3 => self.slide_u32x4::<3>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u32x4<const OFFSET: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_u32x4::<4>(a, a),
+            1 => self.slide_u32x4::<3>(a, a),
+            2 => self.slide_u32x4::<2>(a, a),
+            3 => self.slide_u32x4::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u32x4<const OFFSET: usize>(
+        self,
+        a: u32x4<Self>,
+        padding: u32,
+    ) -> u32x4<Self> {
+        let padding = self.splat_u32x4(padding);
+        match OFFSET {
+            0 => self.slide_u32x4::<0>(a, padding),
+            1 => self.slide_u32x4::<1>(a, padding),
+            2 => self.slide_u32x4::<2>(a, padding),
+            3 => self.slide_u32x4::<3>(a, padding),
+            4 => self.slide_u32x4::<4>(a, padding),
+            _ => self.slide_u32x4::<4>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u32x4<const OFFSET: usize>(
+        self,
+        a: u32x4<Self>,
+        padding: u32,
+    ) -> u32x4<Self> {
+        let padding = self.splat_u32x4(padding);
+        match OFFSET {
+            0 => self.slide_u32x4::<4>(padding, a),
+            1 => self.slide_u32x4::<3>(padding, a),
+            2 => self.slide_u32x4::<2>(padding, a),
+            3 => self.slide_u32x4::<1>(padding, a),
+            4 => self.slide_u32x4::<0>(padding, a),
+            _ => self.slide_u32x4::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u32x4<const LANE: usize>(self, a: u32x4<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, lane: usize) -> u32 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_extract_epi32::<0>(a).cast_unsigned(),
+                    1 => _mm_extract_epi32::<1>(a).cast_unsigned(),
+                    2 => _mm_extract_epi32::<2>(a).cast_unsigned(),
+                    3 => _mm_extract_epi32::<3>(a).cast_unsigned(),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u32x4<const LANE: usize>(self, a: u32x4<Self>, value: u32) -> u32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, value: u32, lane: usize) -> u32x4<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_insert_epi32::<0>(a, value.cast_signed()),
+                    1 => _mm_insert_epi32::<1>(a, value.cast_signed()),
+                    2 => _mm_insert_epi32::<2>(a, value.cast_signed()),
+                    3 => _mm_insert_epi32::<3>(a, value.cast_signed()),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x4(self, a: u32x4<Self>, index: usize) -> u32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_u32x4(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u32x4(self, a: u32x4<Self>, indices: u8x16<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, indices: u8x16<Avx2>) -> u32x4<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_u32x4(a).val.0, indices.into());
+                token.cvt_from_bytes_u32x4(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_add_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_sub_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_mullo_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        self.xor_u32x4(a, self.splat_u32x4(!0))
+    }
+    #[inline(always)]
+    fn shl_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, shift: u32) -> u32x4<Avx2> {
+                _mm_sll_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_sllv_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, shift: u32) -> u32x4<Avx2> {
+                _mm_srl_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_srlv_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shift_signed_u32x4(self, a: u32x4<Self>, b: i32x4<Self>) -> u32x4<Self> {
+        let left = self.shlv_u32x4(a, b.bitcast());
+        let right = self.shrv_u32x4(a, self.neg_i32x4(b).bitcast());
+        self.select_u32x4(self.simd_lt_i32x4(b, self.splat_i32x4(0)), right, left)
+    }
+    #[inline(always)]
+    fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> mask32x4<Avx2> {
+                {
+                    let sign_bit = _mm_set1_epi32(0x80000000u32.cast_signed());
+                    let lhs_signed = _mm_xor_si128(b.into(), sign_bit);
+                    let rhs_signed = _mm_xor_si128(a.into(), sign_bit);
+                    _mm_cmpgt_epi32(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(_mm_min_epu32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(_mm_max_epu32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> mask32x4<Avx2> {
+                {
+                    let sign_bit = _mm_set1_epi32(0x80000000u32.cast_signed());
+                    let lhs_signed = _mm_xor_si128(a.into(), sign_bit);
+                    let rhs_signed = _mm_xor_si128(b.into(), sign_bit);
+                    _mm_cmpgt_epi32(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_unpacklo_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_unpackhi_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                let t1 = _mm_shuffle_epi32::<0b11_01_10_00>(a.into());
+                let t2 = _mm_shuffle_epi32::<0b11_01_10_00>(b.into());
+                _mm_unpacklo_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                let t1 = _mm_shuffle_epi32::<0b11_01_10_00>(a.into());
+                let t2 = _mm_shuffle_epi32::<0b11_01_10_00>(b.into());
+                _mm_unpackhi_epi64(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> (u32x4<Self>, u32x4<Self>) {
+        (self.zip_low_u32x4(a, b), self.zip_high_u32x4(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> (u32x4<Self>, u32x4<Self>) {
+        (self.unzip_low_u32x4(a, b), self.unzip_high_u32x4(a, b))
+    }
+    #[inline(always)]
+    fn select_u32x4(self, a: mask32x4<Self>, b: u32x4<Self>, c: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x4<Avx2>,
+                b: u32x4<Avx2>,
+                c: u32x4<Avx2>,
+            ) -> u32x4<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u32x4(
+        self,
+        a: mask32x4<Self>,
+        b: u32x4<Self>,
+        c: u32x4<Self>,
+    ) -> u32x4<Self> {
+        self.select_u32x4(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_min_epu32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x4<Avx2> {
+                _mm_max_epu32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::saturating_add(a[0usize], b[0usize]),
+            u32::saturating_add(a[1usize], b[1usize]),
+            u32::saturating_add(a[2usize], b[2usize]),
+            u32::saturating_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::saturating_sub(a[0usize], b[0usize]),
+            u32::saturating_sub(a[1usize], b[1usize]),
+            u32::saturating_sub(a[2usize], b[2usize]),
+            u32::saturating_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>) -> u32x4<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    let bytes = _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm_madd_epi16(
+                        _mm_add_epi16(
+                            _mm_and_si128(bytes, _mm_set1_epi16(0xff)),
+                            _mm_srli_epi16::<8>(bytes),
+                        ),
+                        _mm_set1_epi16(1),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        let a = self.or_u32x4(a, self.shr_u32x4(a, 1u32));
+        let a = self.or_u32x4(a, self.shr_u32x4(a, 2u32));
+        let a = self.or_u32x4(a, self.shr_u32x4(a, 4u32));
+        let a = self.or_u32x4(a, self.shr_u32x4(a, 8u32));
+        let a = self.or_u32x4(a, self.shr_u32x4(a, 16u32));
+        self.count_ones_u32x4(self.not_u32x4(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        self.count_ones_u32x4(
+            self.and_u32x4(self.not_u32x4(a), self.sub_u32x4(a, self.splat_u32x4(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>, b: u32x4<Avx2>) -> u32x8<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_cvtepu32_epi64(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x4<Avx2>) -> f32x4<Avx2> {
+                let a = a.into();
+                let lo = _mm_blend_epi16::<0xAA>(a, _mm_set1_epi32(0x4B000000));
+                let hi =
+                    _mm_blend_epi16::<0xAA>(_mm_srli_epi32::<16>(a), _mm_set1_epi32(0x53000000));
+                let fhi = _mm_sub_ps(
+                    _mm_castsi128_ps(hi),
+                    _mm_set1_ps(f32::from_bits(0x53000080)),
+                );
+                let result = _mm_add_ps(_mm_castsi128_ps(lo), fhi);
+                result.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_mask32x4(self, val: bool) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask32x4<Avx2> {
+                let val: i32 = if val { !0 } else { 0 };
+                _mm_set1_epi32(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask32x4(self, val: [i32; 4usize]) -> mask32x4<Self> {
+        mask32x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask32x4(self, a: mask32x4<Self>) -> [i32; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i32; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask32x4<Avx2> {
+                {
+                    let bit_lanes = _mm_set1_epi32(bits as i32);
+                    let bit_mask = _mm_setr_epi32(1, 2, 4, 8);
+                    _mm_cmpeq_epi32(_mm_and_si128(bit_lanes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x4(self, a: mask32x4<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>) -> u64 {
+                _mm_movemask_ps(_mm_castsi128_ps(a.into())) as u32 as u64
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask32x4(self, a: &mut mask32x4<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 4usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        let mut lanes = self.as_array_mask32x4(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask32x4(lanes);
+    }
+    #[inline(always)]
+    fn and_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>, b: mask32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>, b: mask32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>, b: mask32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        self.xor_mask32x4(a, self.splat_mask32x4(true))
+    }
+    #[inline(always)]
+    fn select_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x4<Avx2>,
+                b: mask32x4<Avx2>,
+                c: mask32x4<Avx2>,
+            ) -> mask32x4<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        self.select_mask32x4(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>, b: mask32x4<Avx2>) -> mask32x4<Avx2> {
+                _mm_cmpeq_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask32x4(self, a: mask32x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>) -> bool {
+                _mm_movemask_ps(_mm_castsi128_ps(a.into())) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask32x4(self, a: mask32x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>) -> bool {
+                _mm_movemask_ps(_mm_castsi128_ps(a.into())) as u32 == 0b1111
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask32x4(self, a: mask32x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>) -> bool {
+                _mm_movemask_ps(_mm_castsi128_ps(a.into())) as u32 != 0b1111
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask32x4(self, a: mask32x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>) -> bool {
+                _mm_movemask_ps(_mm_castsi128_ps(a.into())) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x4<Avx2>, b: mask32x4<Avx2>) -> mask32x8<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn splat_f64x2(self, val: f64) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: f64) -> f64x2<Avx2> {
+                _mm_set1_pd(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_f64x2(self, val: [f64; 2usize]) -> f64x2<Self> {
+        f64x2 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_f64x2(self, val: &[f64; 2usize]) -> f64x2<Self> {
+        f64x2 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_f64x2(self, a: f64x2<Self>) -> [f64; 2usize] {
+        crate::transmute::checked_transmute_copy::<__m128d, [f64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_f64x2(self, a: &f64x2<Self>) -> &[f64; 2usize] {
+        crate::transmute::checked_cast_ref::<__m128d, [f64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_f64x2(self, a: &mut f64x2<Self>) -> &mut [f64; 2usize] {
+        crate::transmute::checked_cast_mut::<__m128d, [f64; 2usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_f64x2(self, a: f64x2<Self>, dest: &mut [f64; 2usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_f64x2(self, a: u8x16<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_f64x2(self, a: f64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_f64x2<const SHIFT: usize>(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_f64x2(b).val.0,
+            self.cvt_to_bytes_f64x2(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_f64x2(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_f64x2<const SHIFT: usize>(
+        self,
+        a: f64x2<Self>,
+        b: f64x2<Self>,
+    ) -> f64x2<Self> {
+        self.slide_f64x2::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_f64x2<const OFFSET: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        match OFFSET % 2 {
+            0 => self.slide_f64x2::<0>(a, a),
+            1 => self.slide_f64x2::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_f64x2<const OFFSET: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        match OFFSET % 2 {
+            0 => self.slide_f64x2::<2>(a, a),
+            1 => self.slide_f64x2::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_f64x2<const OFFSET: usize>(
+        self,
+        a: f64x2<Self>,
+        padding: f64,
+    ) -> f64x2<Self> {
+        let padding = self.splat_f64x2(padding);
+        match OFFSET {
+            0 => self.slide_f64x2::<0>(a, padding),
+            1 => self.slide_f64x2::<1>(a, padding),
+            2 => self.slide_f64x2::<2>(a, padding),
+            _ => self.slide_f64x2::<2>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_f64x2<const OFFSET: usize>(
+        self,
+        a: f64x2<Self>,
+        padding: f64,
+    ) -> f64x2<Self> {
+        let padding = self.splat_f64x2(padding);
+        match OFFSET {
+            0 => self.slide_f64x2::<2>(padding, a),
+            1 => self.slide_f64x2::<1>(padding, a),
+            2 => self.slide_f64x2::<0>(padding, a),
+            _ => self.slide_f64x2::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_f64x2<const LANE: usize>(self, a: f64x2<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, lane: usize) -> f64 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_cvtsd_f64(a),
+                    1 => _mm_cvtsd_f64(_mm_unpackhi_pd(a, a)),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_f64x2<const LANE: usize>(self, a: f64x2<Self>, value: f64) -> f64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, value: f64, lane: usize) -> f64x2<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_move_sd(a, _mm_set_sd(value)),
+                    1 => _mm_unpacklo_pd(a, _mm_set_sd(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x2(self, a: f64x2<Self>, index: usize) -> f64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_f64x2(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_f64x2(self, a: f64x2<Self>, indices: u8x16<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, indices: u8x16<Avx2>) -> f64x2<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_f64x2(a).val.0, indices.into());
+                token.cvt_from_bytes_f64x2(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn abs_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_andnot_pd(_mm_set1_pd(-0.0), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_xor_pd(a.into(), _mm_set1_pd(-0.0)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn sqrt_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_sqrt_pd(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn approximate_recip_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), a)
+    }
+    #[inline(always)]
+    fn add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_add_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_sub_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_mul_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn div_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_div_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn copysign_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                let mask = _mm_set1_pd(-0.0);
+                _mm_or_pd(_mm_and_pd(mask, b.into()), _mm_andnot_pd(mask, a.into()))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_eq_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_castpd_si128(_mm_cmpeq_pd(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_castpd_si128(_mm_cmplt_pd(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_castpd_si128(_mm_cmple_pd(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_castpd_si128(_mm_cmpge_pd(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_castpd_si128(_mm_cmpgt_pd(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_unpacklo_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_unpackhi_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_shuffle_pd::<0b00>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_shuffle_pd::<0b11>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> (f64x2<Self>, f64x2<Self>) {
+        (self.zip_low_f64x2(a, b), self.zip_high_f64x2(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> (f64x2<Self>, f64x2<Self>) {
+        (self.unzip_low_f64x2(a, b), self.unzip_high_f64x2(a, b))
+    }
+    #[inline(always)]
+    fn max_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_max_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_min_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                let intermediate = _mm_max_pd(a.into(), b.into());
+                let b_is_nan = _mm_cmpunord_pd(b.into(), b.into());
+                _mm_blendv_pd(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x2<Avx2> {
+                let intermediate = _mm_min_pd(a.into(), b.into());
+                let b_is_nan = _mm_cmpunord_pd(b.into(), b.into());
+                _mm_blendv_pd(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>, c: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_fmadd_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>, c: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_fmsub_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>, c: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_fnmadd_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>, c: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_fnmsub_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.mul_add_f64x2(a, b, c)
+    }
+    #[inline(always)]
+    fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_round_pd::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn ceil_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_round_pd::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_round_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn fract_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.sub_f64x2(a, self.trunc_f64x2(a))
+    }
+    #[inline(always)]
+    fn trunc_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f64x2<Avx2> {
+                _mm_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn select_f64x2(self, a: mask64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x2<Avx2>,
+                b: f64x2<Avx2>,
+                c: f64x2<Avx2>,
+            ) -> f64x2<Avx2> {
+                _mm_blendv_pd(c.into(), b.into(), _mm_castsi128_pd(a.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_f64x2(
+        self,
+        a: mask64x2<Self>,
+        b: f64x2<Self>,
+        c: f64x2<Self>,
+    ) -> f64x2<Self> {
+        self.select_f64x2(a, b, c)
+    }
+    #[inline(always)]
+    fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>, b: f64x2<Avx2>) -> f64x4<Avx2> {
+                _mm256_setr_m128d(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x2(self, a: f64x2<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x2<Avx2>) -> f32x4<Avx2> {
+                _mm_castpd_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_i64x2(self, val: i64) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i64) -> i64x2<Avx2> {
+                _mm_set1_epi64x(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i64x2(self, val: [i64; 2usize]) -> i64x2<Self> {
+        i64x2 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i64x2(self, val: &[i64; 2usize]) -> i64x2<Self> {
+        i64x2 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i64x2(self, a: i64x2<Self>) -> [i64; 2usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i64x2(self, a: &i64x2<Self>) -> &[i64; 2usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [i64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i64x2(self, a: &mut i64x2<Self>) -> &mut [i64; 2usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [i64; 2usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i64x2(self, a: i64x2<Self>, dest: &mut [i64; 2usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i64x2(self, a: u8x16<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i64x2(self, a: i64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i64x2<const SHIFT: usize>(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_i64x2(b).val.0,
+            self.cvt_to_bytes_i64x2(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_i64x2(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i64x2<const SHIFT: usize>(
+        self,
+        a: i64x2<Self>,
+        b: i64x2<Self>,
+    ) -> i64x2<Self> {
+        self.slide_i64x2::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i64x2<const OFFSET: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        match OFFSET % 2 {
+            0 => self.slide_i64x2::<0>(a, a),
+            1 => self.slide_i64x2::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i64x2<const OFFSET: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        match OFFSET % 2 {
+            0 => self.slide_i64x2::<2>(a, a),
+            1 => self.slide_i64x2::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i64x2<const OFFSET: usize>(
+        self,
+        a: i64x2<Self>,
+        padding: i64,
+    ) -> i64x2<Self> {
+        let padding = self.splat_i64x2(padding);
+        match OFFSET {
+            0 => self.slide_i64x2::<0>(a, padding),
+            1 => self.slide_i64x2::<1>(a, padding),
+            2 => self.slide_i64x2::<2>(a, padding),
+            _ => self.slide_i64x2::<2>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i64x2<const OFFSET: usize>(
+        self,
+        a: i64x2<Self>,
+        padding: i64,
+    ) -> i64x2<Self> {
+        let padding = self.splat_i64x2(padding);
+        match OFFSET {
+            0 => self.slide_i64x2::<2>(padding, a),
+            1 => self.slide_i64x2::<1>(padding, a),
+            2 => self.slide_i64x2::<0>(padding, a),
+            _ => self.slide_i64x2::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i64x2<const LANE: usize>(self, a: i64x2<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, lane: usize) -> i64 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_cvtsd_f64(_mm_castsi128_pd(a)).to_bits().cast_signed(),
+                    1 => _mm_cvtsd_f64(_mm_unpackhi_pd(_mm_castsi128_pd(a), _mm_castsi128_pd(a)))
+                        .to_bits()
+                        .cast_signed(),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i64x2<const LANE: usize>(self, a: i64x2<Self>, value: i64) -> i64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, value: i64, lane: usize) -> i64x2<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_unpackhi_epi64(_mm_set1_epi64x(value), a),
+                    1 => _mm_unpacklo_epi64(a, _mm_set1_epi64x(value)),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x2(self, a: i64x2<Self>, index: usize) -> i64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_i64x2(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i64x2(self, a: i64x2<Self>, indices: u8x16<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, indices: u8x16<Avx2>) -> i64x2<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_i64x2(a).val.0, indices.into());
+                token.cvt_from_bytes_i64x2(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_add_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_sub_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::wrapping_mul(a[0usize], b[0usize]),
+            i64::wrapping_mul(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        self.xor_i64x2(a, self.splat_i64x2(!0))
+    }
+    #[inline(always)]
+    fn shl_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, shift: u32) -> i64x2<Avx2> {
+                _mm_sll_epi64(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_sllv_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self> {
+        [
+            i64::wrapping_shr(a[0usize], shift),
+            i64::wrapping_shr(a[1usize], shift),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shrv_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::wrapping_shr(a[0usize], b[0usize] as u32),
+            i64::wrapping_shr(a[1usize], b[1usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_cmpeq_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::lt(&a[0usize], &b[0usize]) as i64),
+            -(i64::lt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::le(&a[0usize], &b[0usize]) as i64),
+            -(i64::le(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::ge(&a[0usize], &b[0usize]) as i64),
+            -(i64::ge(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::gt(&a[0usize], &b[0usize]) as i64),
+            -(i64::gt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_unpacklo_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_unpackhi_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_unpacklo_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_unpackhi_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> (i64x2<Self>, i64x2<Self>) {
+        (self.zip_low_i64x2(a, b), self.zip_high_i64x2(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> (i64x2<Self>, i64x2<Self>) {
+        (self.unzip_low_i64x2(a, b), self.unzip_high_i64x2(a, b))
+    }
+    #[inline(always)]
+    fn select_i64x2(self, a: mask64x2<Self>, b: i64x2<Self>, c: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x2<Avx2>,
+                b: i64x2<Avx2>,
+                c: i64x2<Avx2>,
+            ) -> i64x2<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i64x2(
+        self,
+        a: mask64x2<Self>,
+        b: i64x2<Self>,
+        c: i64x2<Self>,
+    ) -> i64x2<Self> {
+        self.select_i64x2(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::min(a[0usize], b[0usize]),
+            i64::min(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::max(a[0usize], b[0usize]),
+            i64::max(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::saturating_add(a[0usize], b[0usize]),
+            i64::saturating_add(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::saturating_sub(a[0usize], b[0usize]),
+            i64::saturating_sub(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>) -> i64x2<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    let bytes = _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm_sad_epu8(bytes, _mm_setzero_si128())
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 1u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 2u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 4u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 8u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 16u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 32u32));
+        self.count_ones_i64x2(self.not_i64x2(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        self.count_ones_i64x2(
+            self.and_i64x2(self.not_i64x2(a), self.sub_i64x2(a, self.splat_i64x2(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>, b: i64x2<Avx2>) -> i64x4<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn neg_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>) -> i64x2<Avx2> {
+                _mm_sub_epi64(_mm_setzero_si128(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x2(self, a: i64x2<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x2(self, a: i64x2<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x2<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u64x2(self, val: u64) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u64) -> u64x2<Avx2> {
+                _mm_set1_epi64x(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u64x2(self, val: [u64; 2usize]) -> u64x2<Self> {
+        u64x2 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u64x2(self, val: &[u64; 2usize]) -> u64x2<Self> {
+        u64x2 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u64x2(self, a: u64x2<Self>) -> [u64; 2usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [u64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u64x2(self, a: &u64x2<Self>) -> &[u64; 2usize] {
+        crate::transmute::checked_cast_ref::<__m128i, [u64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u64x2(self, a: &mut u64x2<Self>) -> &mut [u64; 2usize] {
+        crate::transmute::checked_cast_mut::<__m128i, [u64; 2usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u64x2(self, a: u64x2<Self>, dest: &mut [u64; 2usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u64x2(self, a: u8x16<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u64x2(self, a: u64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u64x2<const SHIFT: usize>(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
+        let result = dyn_alignr_128(
+            self,
+            self.cvt_to_bytes_u64x2(b).val.0,
+            self.cvt_to_bytes_u64x2(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_u64x2(u8x16 {
+            val: crate::support::Aligned128(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u64x2<const SHIFT: usize>(
+        self,
+        a: u64x2<Self>,
+        b: u64x2<Self>,
+    ) -> u64x2<Self> {
+        self.slide_u64x2::<SHIFT>(a, b)
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u64x2<const OFFSET: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        match OFFSET % 2 {
+            0 => self.slide_u64x2::<0>(a, a),
+            1 => self.slide_u64x2::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u64x2<const OFFSET: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        match OFFSET % 2 {
+            0 => self.slide_u64x2::<2>(a, a),
+            1 => self.slide_u64x2::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u64x2<const OFFSET: usize>(
+        self,
+        a: u64x2<Self>,
+        padding: u64,
+    ) -> u64x2<Self> {
+        let padding = self.splat_u64x2(padding);
+        match OFFSET {
+            0 => self.slide_u64x2::<0>(a, padding),
+            1 => self.slide_u64x2::<1>(a, padding),
+            2 => self.slide_u64x2::<2>(a, padding),
+            _ => self.slide_u64x2::<2>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u64x2<const OFFSET: usize>(
+        self,
+        a: u64x2<Self>,
+        padding: u64,
+    ) -> u64x2<Self> {
+        let padding = self.splat_u64x2(padding);
+        match OFFSET {
+            0 => self.slide_u64x2::<2>(padding, a),
+            1 => self.slide_u64x2::<1>(padding, a),
+            2 => self.slide_u64x2::<0>(padding, a),
+            _ => self.slide_u64x2::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u64x2<const LANE: usize>(self, a: u64x2<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, lane: usize) -> u64 {
+                let a = a.into();
+                match lane {
+                    0 => _mm_cvtsd_f64(_mm_castsi128_pd(a)).to_bits(),
+                    1 => _mm_cvtsd_f64(_mm_unpackhi_pd(_mm_castsi128_pd(a), _mm_castsi128_pd(a)))
+                        .to_bits(),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u64x2<const LANE: usize>(self, a: u64x2<Self>, value: u64) -> u64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, value: u64, lane: usize) -> u64x2<Avx2> {
+                let a = a.into();
+                match lane {
+                    0 => _mm_unpackhi_epi64(_mm_set1_epi64x(value.cast_signed()), a),
+                    1 => _mm_unpacklo_epi64(a, _mm_set1_epi64x(value.cast_signed())),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x2(self, a: u64x2<Self>, index: usize) -> u64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_u64x2(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u64x2(self, a: u64x2<Self>, indices: u8x16<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, indices: u8x16<Avx2>) -> u64x2<Avx2> {
+                let result = _mm_shuffle_epi8(token.cvt_to_bytes_u64x2(a).val.0, indices.into());
+                token.cvt_from_bytes_u64x2(u8x16 {
+                    val: crate::support::Aligned128(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_add_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_sub_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::wrapping_mul(a[0usize], b[0usize]),
+            u64::wrapping_mul(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        self.xor_u64x2(a, self.splat_u64x2(!0))
+    }
+    #[inline(always)]
+    fn shl_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, shift: u32) -> u64x2<Avx2> {
+                _mm_sll_epi64(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_sllv_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, shift: u32) -> u64x2<Avx2> {
+                _mm_srl_epi64(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_srlv_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shift_signed_u64x2(self, a: u64x2<Self>, b: i64x2<Self>) -> u64x2<Self> {
+        let left = self.shlv_u64x2(a, b.bitcast());
+        let right = self.shrv_u64x2(a, self.neg_i64x2(b).bitcast());
+        self.select_u64x2(self.simd_lt_i64x2(b, self.splat_i64x2(0)), right, left)
+    }
+    #[inline(always)]
+    fn simd_eq_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_cmpeq_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::lt(&a[0usize], &b[0usize]) as i64),
+            -(u64::lt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::le(&a[0usize], &b[0usize]) as i64),
+            -(u64::le(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::ge(&a[0usize], &b[0usize]) as i64),
+            -(u64::ge(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::gt(&a[0usize], &b[0usize]) as i64),
+            -(u64::gt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_unpacklo_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_unpackhi_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_unpacklo_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x2<Avx2> {
+                _mm_unpackhi_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> (u64x2<Self>, u64x2<Self>) {
+        (self.zip_low_u64x2(a, b), self.zip_high_u64x2(a, b))
+    }
+    #[inline(always)]
+    fn deinterleave_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> (u64x2<Self>, u64x2<Self>) {
+        (self.unzip_low_u64x2(a, b), self.unzip_high_u64x2(a, b))
+    }
+    #[inline(always)]
+    fn select_u64x2(self, a: mask64x2<Self>, b: u64x2<Self>, c: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x2<Avx2>,
+                b: u64x2<Avx2>,
+                c: u64x2<Avx2>,
+            ) -> u64x2<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u64x2(
+        self,
+        a: mask64x2<Self>,
+        b: u64x2<Self>,
+        c: u64x2<Self>,
+    ) -> u64x2<Self> {
+        self.select_u64x2(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::min(a[0usize], b[0usize]),
+            u64::min(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::max(a[0usize], b[0usize]),
+            u64::max(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::saturating_add(a[0usize], b[0usize]),
+            u64::saturating_add(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::saturating_sub(a[0usize], b[0usize]),
+            u64::saturating_sub(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>) -> u64x2<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4);
+                    let low_nibbles = _mm_and_si128(a, _mm_set1_epi8(0x0f));
+                    let high_nibbles = _mm_and_si128(_mm_srli_epi16::<4>(a), _mm_set1_epi8(0x0f));
+                    let bytes = _mm_add_epi8(
+                        _mm_shuffle_epi8(table, low_nibbles),
+                        _mm_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm_sad_epu8(bytes, _mm_setzero_si128())
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 1u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 2u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 4u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 8u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 16u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 32u32));
+        self.count_ones_u64x2(self.not_u64x2(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        self.count_ones_u64x2(
+            self.and_u64x2(self.not_u64x2(a), self.sub_u64x2(a, self.splat_u64x2(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>, b: u64x2<Avx2>) -> u64x4<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x2(self, a: u64x2<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>) -> u8x16<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x2(self, a: u64x2<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x2<Avx2>) -> u32x4<Avx2> {
+                __m128i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn transpose_bits_8x8_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 7)),
+            self.splat_u64x2(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 7));
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 14)),
+            self.splat_u64x2(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 14));
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 28)),
+            self.splat_u64x2(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 28))
+    }
+    #[inline(always)]
+    fn splat_mask64x2(self, val: bool) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask64x2<Avx2> {
+                let val: i64 = if val { !0 } else { 0 };
+                _mm_set1_epi64x(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask64x2(self, val: [i64; 2usize]) -> mask64x2<Self> {
+        mask64x2 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask64x2(self, a: mask64x2<Self>) -> [i64; 2usize] {
+        crate::transmute::checked_transmute_copy::<__m128i, [i64; 2usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask64x2<Avx2> {
+                {
+                    let bit_lanes = _mm_set1_epi64x(bits.cast_signed());
+                    let bit_mask = _mm_set_epi64x(2, 1);
+                    _mm_cmpeq_epi64(_mm_and_si128(bit_lanes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x2(self, a: mask64x2<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>) -> u64 {
+                _mm_movemask_pd(_mm_castsi128_pd(a.into())) as u32 as u64
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask64x2(self, a: &mut mask64x2<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 2usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        let mut lanes = self.as_array_mask64x2(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask64x2(lanes);
+    }
+    #[inline(always)]
+    fn and_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>, b: mask64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_and_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>, b: mask64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_or_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>, b: mask64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_xor_si128(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        self.xor_mask64x2(a, self.splat_mask64x2(true))
+    }
+    #[inline(always)]
+    fn select_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x2<Avx2>,
+                b: mask64x2<Avx2>,
+                c: mask64x2<Avx2>,
+            ) -> mask64x2<Avx2> {
+                _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        self.select_mask64x2(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>, b: mask64x2<Avx2>) -> mask64x2<Avx2> {
+                _mm_cmpeq_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask64x2(self, a: mask64x2<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>) -> bool {
+                _mm_movemask_pd(_mm_castsi128_pd(a.into())) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask64x2(self, a: mask64x2<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>) -> bool {
+                _mm_movemask_pd(_mm_castsi128_pd(a.into())) as u32 == 0b11
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask64x2(self, a: mask64x2<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>) -> bool {
+                _mm_movemask_pd(_mm_castsi128_pd(a.into())) as u32 != 0b11
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask64x2(self, a: mask64x2<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>) -> bool {
+                _mm_movemask_pd(_mm_castsi128_pd(a.into())) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x2<Avx2>, b: mask64x2<Avx2>) -> mask64x4<Avx2> {
+                _mm256_setr_m128i(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn splat_f32x8(self, val: f32) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: f32) -> f32x8<Avx2> {
+                _mm256_set1_ps(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_f32x8(self, val: [f32; 8usize]) -> f32x8<Self> {
+        f32x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_f32x8(self, val: &[f32; 8usize]) -> f32x8<Self> {
+        f32x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_f32x8(self, a: f32x8<Self>) -> [f32; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m256, [f32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_f32x8(self, a: &f32x8<Self>) -> &[f32; 8usize] {
+        crate::transmute::checked_cast_ref::<__m256, [f32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_f32x8(self, a: &mut f32x8<Self>) -> &mut [f32; 8usize] {
+        crate::transmute::checked_cast_mut::<__m256, [f32; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_f32x8(self, a: f32x8<Self>, dest: &mut [f32; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_f32x8(self, a: u8x32<Self>) -> f32x8<Self> {
+        f32x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_f32x8(self, a: f32x8<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_f32x8<const SHIFT: usize>(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_f32x8(b).val.0,
+            self.cvt_to_bytes_f32x8(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_f32x8(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_f32x8<const SHIFT: usize>(
+        self,
+        a: f32x8<Self>,
+        b: f32x8<Self>,
+    ) -> f32x8<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_f32x8(b).val.0,
+            self.cvt_to_bytes_f32x8(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_f32x8(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_f32x8<const OFFSET: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_f32x8::<0>(a, a),
+            1 => self.slide_f32x8::<1>(a, a),
+            2 => self.slide_f32x8::<2>(a, a),
+            3 => self.slide_f32x8::<3>(a, a),
+            4 => self.slide_f32x8::<4>(a, a),
+            5 => self.slide_f32x8::<5>(a, a),
+            6 => self.slide_f32x8::<6>(a, a),
+            7 => self.slide_f32x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_f32x8<const OFFSET: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_f32x8::<8>(a, a),
+            1 => self.slide_f32x8::<7>(a, a),
+            2 => self.slide_f32x8::<6>(a, a),
+            3 => self.slide_f32x8::<5>(a, a),
+            4 => self.slide_f32x8::<4>(a, a),
+            5 => self.slide_f32x8::<3>(a, a),
+            6 => self.slide_f32x8::<2>(a, a),
+            7 => self.slide_f32x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_f32x8<const OFFSET: usize>(
+        self,
+        a: f32x8<Self>,
+        padding: f32,
+    ) -> f32x8<Self> {
+        let padding = self.splat_f32x8(padding);
+        match OFFSET {
+            0 => self.slide_f32x8::<0>(a, padding),
+            1 => self.slide_f32x8::<1>(a, padding),
+            2 => self.slide_f32x8::<2>(a, padding),
+            3 => self.slide_f32x8::<3>(a, padding),
+            4 => self.slide_f32x8::<4>(a, padding),
+            5 => self.slide_f32x8::<5>(a, padding),
+            6 => self.slide_f32x8::<6>(a, padding),
+            7 => self.slide_f32x8::<7>(a, padding),
+            8 => self.slide_f32x8::<8>(a, padding),
+            _ => self.slide_f32x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_f32x8<const OFFSET: usize>(
+        self,
+        a: f32x8<Self>,
+        padding: f32,
+    ) -> f32x8<Self> {
+        let padding = self.splat_f32x8(padding);
+        match OFFSET {
+            0 => self.slide_f32x8::<8>(padding, a),
+            1 => self.slide_f32x8::<7>(padding, a),
+            2 => self.slide_f32x8::<6>(padding, a),
+            3 => self.slide_f32x8::<5>(padding, a),
+            4 => self.slide_f32x8::<4>(padding, a),
+            5 => self.slide_f32x8::<3>(padding, a),
+            6 => self.slide_f32x8::<2>(padding, a),
+            7 => self.slide_f32x8::<1>(padding, a),
+            8 => self.slide_f32x8::<0>(padding, a),
+            _ => self.slide_f32x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_f32x8<const LANE: usize>(self, a: f32x8<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x8(a);
+        match LANE {
+            0 => self.extract_f32x4::<0>(a0),
+            1 => self.extract_f32x4::<1>(a0),
+            2 => self.extract_f32x4::<2>(a0),
+            3 => self.extract_f32x4::<3>(a0),
+            4 => self.extract_f32x4::<0>(a1),
+            5 => self.extract_f32x4::<1>(a1),
+            6 => self.extract_f32x4::<2>(a1),
+            7 => self.extract_f32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f32x8<const LANE: usize>(self, a: f32x8<Self>, value: f32) -> f32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x8(a);
+        match LANE {
+            0 => self.combine_f32x4(self.insert_f32x4::<0>(a0, value), a1),
+            1 => self.combine_f32x4(self.insert_f32x4::<1>(a0, value), a1),
+            2 => self.combine_f32x4(self.insert_f32x4::<2>(a0, value), a1),
+            3 => self.combine_f32x4(self.insert_f32x4::<3>(a0, value), a1),
+            4 => self.combine_f32x4(a0, self.insert_f32x4::<0>(a1, value)),
+            5 => self.combine_f32x4(a0, self.insert_f32x4::<1>(a1, value)),
+            6 => self.combine_f32x4(a0, self.insert_f32x4::<2>(a1, value)),
+            7 => self.combine_f32x4(a0, self.insert_f32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x8(self, a: f32x8<Self>, index: usize) -> f32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_f32x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_f32x8(self, a: f32x8<Self>, indices: u8x32<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, indices: u8x32<Avx2>) -> f32x8<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_f32x8(a).val.0, indices.into());
+                token.cvt_from_bytes_f32x8(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn abs_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_andnot_ps(_mm256_set1_ps(-0.0), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_xor_ps(a.into(), _mm256_set1_ps(-0.0)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn sqrt_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_sqrt_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn approximate_recip_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_rcp_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_add_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_sub_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_mul_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn div_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_div_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn copysign_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let mask = _mm256_set1_ps(-0.0);
+                _mm256_or_ps(
+                    _mm256_and_ps(mask, b.into()),
+                    _mm256_andnot_ps(mask, a.into()),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_eq_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_castps_si256(_mm256_cmp_ps::<0i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_castps_si256(_mm256_cmp_ps::<17i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_castps_si256(_mm256_cmp_ps::<18i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_castps_si256(_mm256_cmp_ps::<29i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_castps_si256(_mm256_cmp_ps::<30i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let lo = _mm256_unpacklo_ps(a.into(), b.into());
+                let hi = _mm256_unpackhi_ps(a.into(), b.into());
+                _mm256_permute2f128_ps::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let lo = _mm256_unpacklo_ps(a.into(), b.into());
+                let hi = _mm256_unpackhi_ps(a.into(), b.into());
+                _mm256_permute2f128_ps::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let t1 =
+                    _mm256_permutevar8x32_ps(a.into(), _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7));
+                let t2 =
+                    _mm256_permutevar8x32_ps(b.into(), _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7));
+                _mm256_permute2f128_ps::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let t1 =
+                    _mm256_permutevar8x32_ps(a.into(), _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7));
+                let t2 =
+                    _mm256_permutevar8x32_ps(b.into(), _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7));
+                _mm256_permute2f128_ps::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> (f32x8<Self>, f32x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> (f32x8<Avx2>, f32x8<Avx2>) {
+                let lo = _mm256_unpacklo_ps(a.into(), b.into());
+                let hi = _mm256_unpackhi_ps(a.into(), b.into());
+                (
+                    _mm256_permute2f128_ps::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2f128_ps::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> (f32x8<Self>, f32x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> (f32x8<Avx2>, f32x8<Avx2>) {
+                let t1 =
+                    _mm256_permutevar8x32_ps(a.into(), _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7));
+                let t2 =
+                    _mm256_permutevar8x32_ps(b.into(), _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7));
+                (
+                    _mm256_permute2f128_ps::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2f128_ps::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_max_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_min_ps(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let intermediate = _mm256_max_ps(a.into(), b.into());
+                let b_is_nan = _mm256_cmp_ps::<3i32>(b.into(), b.into());
+                _mm256_blendv_ps(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let intermediate = _mm256_min_ps(a.into(), b.into());
+                let b_is_nan = _mm256_cmp_ps::<3i32>(b.into(), b.into());
+                _mm256_blendv_ps(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>, c: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_fmadd_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>, c: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_fmsub_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>, c: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_fnmadd_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>, c: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_fnmsub_ps(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        self.mul_add_f32x8(a, b, c)
+    }
+    #[inline(always)]
+    fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_round_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn ceil_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_round_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn fract_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        self.sub_f32x8(a, self.trunc_f32x8(a))
+    }
+    #[inline(always)]
+    fn trunc_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn select_f32x8(self, a: mask32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x8<Avx2>,
+                b: f32x8<Avx2>,
+                c: f32x8<Avx2>,
+            ) -> f32x8<Avx2> {
+                _mm256_blendv_ps(c.into(), b.into(), _mm256_castsi256_ps(a.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_f32x8(
+        self,
+        a: mask32x8<Self>,
+        b: f32x8<Self>,
+        c: f32x8<Self>,
+    ) -> f32x8<Self> {
+        self.select_f32x8(a, b, c)
+    }
+    #[inline(always)]
+    fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
+        f32x16 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_f32x8(self, a: f32x8<Self>) -> (f32x4<Self>, f32x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> (f32x4<Avx2>, f32x4<Avx2>) {
+                (
+                    _mm256_extractf128_ps::<0>(a.into()).simd_into(token),
+                    _mm256_extractf128_ps::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x8(self, a: f32x8<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> f64x4<Avx2> {
+                _mm256_castps_pd(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_castps_si256(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x8(self, a: f32x8<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> u8x32<Avx2> {
+                _mm256_castps_si256(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_castps_si256(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> u32x8<Avx2> {
+                let mut converted = _mm256_cvttps_epi32(a.into());
+                let in_range = _mm256_cmp_ps::<17i32>(a.into(), _mm256_set1_ps(2147483648.0));
+                let all_in_range = _mm256_movemask_ps(in_range) == 0b11111111;
+                if !all_in_range {
+                    let excess = _mm256_sub_ps(a.into(), _mm256_set1_ps(2147483648.0));
+                    let excess_converted = _mm256_cvttps_epi32(_mm256_andnot_ps(in_range, excess));
+                    converted = _mm256_add_epi32(converted, excess_converted);
+                }
+                converted.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_u32_precise_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> u32x8<Avx2> {
+                let a = _mm256_max_ps(a.into(), _mm256_setzero_ps());
+                let mut converted = _mm256_cvttps_epi32(a);
+                let in_range = _mm256_cmp_ps::<17i32>(a, _mm256_set1_ps(2147483648.0));
+                let all_in_range = _mm256_movemask_ps(in_range) == 0b11111111;
+                if !all_in_range {
+                    let exceeds_unsigned_range = _mm256_castps_si256(_mm256_cmp_ps::<17i32>(
+                        _mm256_set1_ps(4294967040.0),
+                        a,
+                    ));
+                    let excess = _mm256_sub_ps(a, _mm256_set1_ps(2147483648.0));
+                    let excess_converted = _mm256_cvttps_epi32(_mm256_andnot_ps(in_range, excess));
+                    converted = _mm256_add_epi32(converted, excess_converted);
+                    converted = _mm256_blendv_epi8(
+                        converted,
+                        _mm256_set1_epi32(u32::MAX.cast_signed()),
+                        exceeds_unsigned_range,
+                    );
+                }
+                converted.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_cvttps_epi32(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_i32_precise_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>) -> i32x8<Avx2> {
+                let a = a.into();
+                let mut converted = _mm256_cvttps_epi32(a);
+                let in_range = _mm256_cmp_ps::<17i32>(a, _mm256_set1_ps(2147483648.0));
+                let all_in_range = _mm256_movemask_ps(in_range) == 0b11111111;
+                if !all_in_range {
+                    converted = _mm256_blendv_epi8(
+                        _mm256_set1_epi32(i32::MAX),
+                        converted,
+                        _mm256_castps_si256(in_range),
+                    );
+                    let is_not_nan = _mm256_castps_si256(_mm256_cmp_ps::<7i32>(a, a));
+                    converted = _mm256_and_si256(converted, is_not_nan);
+                }
+                converted.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn dot2_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                let p = _mm256_mul_ps(a.into(), b.into());
+                let pairs = _mm256_add_ps(p, _mm256_shuffle_ps::<0b10_11_00_01>(p, p));
+                pairs.simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn dot3_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_dp_ps::<0x7f>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn dot4_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x8<Avx2>, b: f32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_dp_ps::<0xff>(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn splat_i8x32(self, val: i8) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i8) -> i8x32<Avx2> {
+                _mm256_set1_epi8(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i8x32(self, val: [i8; 32usize]) -> i8x32<Self> {
+        i8x32 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i8x32(self, val: &[i8; 32usize]) -> i8x32<Self> {
+        i8x32 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i8x32(self, a: i8x32<Self>) -> [i8; 32usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i8; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i8x32(self, a: &i8x32<Self>) -> &[i8; 32usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [i8; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i8x32(self, a: &mut i8x32<Self>) -> &mut [i8; 32usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [i8; 32usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i8x32(self, a: i8x32<Self>, dest: &mut [i8; 32usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i8x32(self, a: u8x32<Self>) -> i8x32<Self> {
+        i8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i8x32<const SHIFT: usize>(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_i8x32(b).val.0,
+            self.cvt_to_bytes_i8x32(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_i8x32(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i8x32<const SHIFT: usize>(
+        self,
+        a: i8x32<Self>,
+        b: i8x32<Self>,
+    ) -> i8x32<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_i8x32(b).val.0,
+            self.cvt_to_bytes_i8x32(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_i8x32(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i8x32<const OFFSET: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_i8x32::<0>(a, a),
+            1 => self.slide_i8x32::<1>(a, a),
+            2 => self.slide_i8x32::<2>(a, a),
+            3 => self.slide_i8x32::<3>(a, a),
+            4 => self.slide_i8x32::<4>(a, a),
+            5 => self.slide_i8x32::<5>(a, a),
+            6 => self.slide_i8x32::<6>(a, a),
+            7 => self.slide_i8x32::<7>(a, a),
+            8 => self.slide_i8x32::<8>(a, a),
+            9 => self.slide_i8x32::<9>(a, a),
+            10 => self.slide_i8x32::<10>(a, a),
+            11 => self.slide_i8x32::<11>(a, a),
+            12 => self.slide_i8x32::<12>(a, a),
+            13 => self.slide_i8x32::<13>(a, a),
+            14 => self.slide_i8x32::<14>(a, a),
+            15 => self.slide_i8x32::<15>(a, a),
+            16 => self.slide_i8x32::<16>(a, a),
+            17 => self.slide_i8x32::<17>(a, a),
+            18 => self.slide_i8x32::<18>(a, a),
+            19 => self.slide_i8x32::<19>(a, a),
+            20 => self.slide_i8x32::<20>(a, a),
+            21 => self.slide_i8x32::<21>(a, a),
+            22 => self.slide_i8x32::<22>(a, a),
+            23 => self.slide_i8x32::<23>(a, a),
+            24 => self.slide_i8x32::<24>(a, a),
+            25 => self.slide_i8x32::<25>(a, a),
+            26 => self.slide_i8x32::<26>(a, a),
+            27 => self.slide_i8x32::<27>(a, a),
+            28 => self.slide_i8x32::<28>(a, a),
+            29 => self.slide_i8x32::<29>(a, a),
+            30 => self.slide_i8x32::<30>(a, a),
+            31 => self.slide_i8x32::<31>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i8x32<const OFFSET: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_i8x32::<32>(a, a),
+            1 => self.slide_i8x32::<31>(a, a),
+            2 => self.slide_i8x32::<30>(a, a),
+            3 => self.slide_i8x32::<29>(a, a),
+            4 => self.slide_i8x32::<28>(a, a),
+            5 => self.slide_i8x32::<27>(a, a),
+            6 => self.slide_i8x32::<26>(a, a),
+            7 => self.slide_i8x32::<25>(a, a),
+            8 => self.slide_i8x32::<24>(a, a),
+            9 => self.slide_i8x32::<23>(a, a),
+            10 => self.slide_i8x32::<22>(a, a),
+            11 => self.slide_i8x32::<21>(a, a),
+            12 => self.slide_i8x32::<20>(a, a),
+            13 => self.slide_i8x32::<19>(a, a),
+            14 => self.slide_i8x32::<18>(a, a),
+            15 => self.slide_i8x32::<17>(a, a),
+            16 => self.slide_i8x32::<16>(a, a),
+            17 => self.slide_i8x32::<15>(a, a),
+            18 => self.slide_i8x32::<14>(a, a),
+            19 => self.slide_i8x32::<13>(a, a),
+            20 => self.slide_i8x32::<12>(a, a),
+            21 => self.slide_i8x32::<11>(a, a),
+            22 => self.slide_i8x32::<10>(a, a),
+            23 => self.slide_i8x32::<9>(a, a),
+            24 => self.slide_i8x32::<8>(a, a),
+            25 => self.slide_i8x32::<7>(a, a),
+            26 => self.slide_i8x32::<6>(a, a),
+            27 => self.slide_i8x32::<5>(a, a),
+            28 => self.slide_i8x32::<4>(a, a),
+            29 => self.slide_i8x32::<3>(a, a),
+            30 => self.slide_i8x32::<2>(a, a),
+            31 => self.slide_i8x32::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i8x32<const OFFSET: usize>(
+        self,
+        a: i8x32<Self>,
+        padding: i8,
+    ) -> i8x32<Self> {
+        let padding = self.splat_i8x32(padding);
+        match OFFSET {
+            0 => self.slide_i8x32::<0>(a, padding),
+            1 => self.slide_i8x32::<1>(a, padding),
+            2 => self.slide_i8x32::<2>(a, padding),
+            3 => self.slide_i8x32::<3>(a, padding),
+            4 => self.slide_i8x32::<4>(a, padding),
+            5 => self.slide_i8x32::<5>(a, padding),
+            6 => self.slide_i8x32::<6>(a, padding),
+            7 => self.slide_i8x32::<7>(a, padding),
+            8 => self.slide_i8x32::<8>(a, padding),
+            9 => self.slide_i8x32::<9>(a, padding),
+            10 => self.slide_i8x32::<10>(a, padding),
+            11 => self.slide_i8x32::<11>(a, padding),
+            12 => self.slide_i8x32::<12>(a, padding),
+            13 => self.slide_i8x32::<13>(a, padding),
+            14 => self.slide_i8x32::<14>(a, padding),
+            15 => self.slide_i8x32::<15>(a, padding),
+            16 => self.slide_i8x32::<16>(a, padding),
+            17 => self.slide_i8x32::<17>(a, padding),
+            18 => self.slide_i8x32::<18>(a, padding),
+            19 => self.slide_i8x32::<19>(a, padding),
+            20 => self.slide_i8x32::<20>(a, padding),
+            21 => self.slide_i8x32::<21>(a, padding),
+            22 => self.slide_i8x32::<22>(a, padding),
+            23 => self.slide_i8x32::<23>(a, padding),
+            24 => self.slide_i8x32::<24>(a, padding),
+            25 => self.slide_i8x32::<25>(a, padding),
+            26 => self.slide_i8x32::<26>(a, padding),
+            27 => self.slide_i8x32::<27>(a, padding),
+            28 => self.slide_i8x32::<28>(a, padding),
+            29 => self.slide_i8x32::<29>(a, padding),
+            30 => self.slide_i8x32::<30>(a, padding),
+            31 => self.slide_i8x32::<31>(a, padding),
+            32 => self.slide_i8x32::<32>(a, padding),
+            _ => self.slide_i8x32::<32>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i8x32<const OFFSET: usize>(
+        self,
+        a: i8x32<Self>,
+        padding: i8,
+    ) -> i8x32<Self> {
+        let padding = self.splat_i8x32(padding);
+        match OFFSET {
+            0 => self.slide_i8x32::<32>(padding, a),
+            1 => self.slide_i8x32::<31>(padding, a),
+            2 => self.slide_i8x32::<30>(padding, a),
+            3 => self.slide_i8x32::<29>(padding, a),
+            4 => self.slide_i8x32::<28>(padding, a),
+            5 => self.slide_i8x32::<27>(padding, a),
+            6 => self.slide_i8x32::<26>(padding, a),
+            7 => self.slide_i8x32::<25>(padding, a),
+            8 => self.slide_i8x32::<24>(padding, a),
+            9 => self.slide_i8x32::<23>(padding, a),
+            10 => self.slide_i8x32::<22>(padding, a),
+            11 => self.slide_i8x32::<21>(padding, a),
+            12 => self.slide_i8x32::<20>(padding, a),
+            13 => self.slide_i8x32::<19>(padding, a),
+            14 => self.slide_i8x32::<18>(padding, a),
+            15 => self.slide_i8x32::<17>(padding, a),
+            16 => self.slide_i8x32::<16>(padding, a),
+            17 => self.slide_i8x32::<15>(padding, a),
+            18 => self.slide_i8x32::<14>(padding, a),
+            19 => self.slide_i8x32::<13>(padding, a),
+            20 => self.slide_i8x32::<12>(padding, a),
+            21 => self.slide_i8x32::<11>(padding, a),
+            22 => self.slide_i8x32::<10>(padding, a),
+            23 => self.slide_i8x32::<9>(padding, a),
+            24 => self.slide_i8x32::<8>(padding, a),
+            25 => self.slide_i8x32::<7>(padding, a),
+            26 => self.slide_i8x32::<6>(padding, a),
+            27 => self.slide_i8x32::<5>(padding, a),
+            28 => self.slide_i8x32::<4>(padding, a),
+            29 => self.slide_i8x32::<3>(padding, a),
+            30 => self.slide_i8x32::<2>(padding, a),
+            31 => self.slide_i8x32::<1>(padding, a),
+            32 => self.slide_i8x32::<0>(padding, a),
+            _ => self.slide_i8x32::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i8x32<const LANE: usize>(self, a: i8x32<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x32(a);
+        match LANE {
+            0 => self.extract_i8x16::<0>(a0),
+            1 => self.extract_i8x16::<1>(a0),
+            2 => self.extract_i8x16::<2>(a0),
+            3 => self.extract_i8x16::<3>(a0),
+            4 => self.extract_i8x16::<4>(a0),
+            5 => self.extract_i8x16::<5>(a0),
+            6 => self.extract_i8x16::<6>(a0),
+            7 => self.extract_i8x16::<7>(a0),
+            8 => self.extract_i8x16::<8>(a0),
+            9 => self.extract_i8x16::<9>(a0),
+            10 => self.extract_i8x16::<10>(a0),
+            11 => self.extract_i8x16::<11>(a0),
+            12 => self.extract_i8x16::<12>(a0),
+            13 => self.extract_i8x16::<13>(a0),
+            14 => self.extract_i8x16::<14>(a0),
+            15 => self.extract_i8x16::<15>(a0),
+            16 => self.extract_i8x16::<0>(a1),
+            17 => self.extract_i8x16::<1>(a1),
+            18 => self.extract_i8x16::<2>(a1),
+            19 => self.extract_i8x16::<3>(a1),
+            20 => self.extract_i8x16::<4>(a1),
+            21 => self.extract_i8x16::<5>(a1),
+            22 => self.extract_i8x16::<6>(a1),
+            23 => self.extract_i8x16::<7>(a1),
+            24 => self.extract_i8x16::<8>(a1),
+            25 => self.extract_i8x16::<9>(a1),
+            26 => self.extract_i8x16::<10>(a1),
+            27 => self.extract_i8x16::<11>(a1),
+            28 => self.extract_i8x16::<12>(a1),
+            29 => self.extract_i8x16::<13>(a1),
+            30 => self.extract_i8x16::<14>(a1),
+            31 => self.extract_i8x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i8x32<const LANE: usize>(self, a: i8x32<Self>, value: i8) -> i8x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x32(a);
+        match LANE {
+            0 => self.combine_i8x16(self.insert_i8x16::<0>(a0, value), a1),
+            1 => self.combine_i8x16(self.insert_i8x16::<1>(a0, value), a1),
+            2 => self.combine_i8x16(self.insert_i8x16::<2>(a0, value), a1),
+            3 => self.combine_i8x16(self.insert_i8x16::<3>(a0, value), a1),
+            4 => self.combine_i8x16(self.insert_i8x16::<4>(a0, value), a1),
+            5 => self.combine_i8x16(self.insert_i8x16::<5>(a0, value), a1),
+            6 => self.combine_i8x16(self.insert_i8x16::<6>(a0, value), a1),
+            7 => self.combine_i8x16(self.insert_i8x16::<7>(a0, value), a1),
+            8 => self.combine_i8x16(self.insert_i8x16::<8>(a0, value), a1),
+            9 => self.combine_i8x16(self.insert_i8x16::<9>(a0, value), a1),
+            10 => self.combine_i8x16(self.insert_i8x16::<10>(a0, value), a1),
+            11 => self.combine_i8x16(self.insert_i8x16::<11>(a0, value), a1),
+            12 => self.combine_i8x16(self.insert_i8x16::<12>(a0, value), a1),
+            13 => self.combine_i8x16(self.insert_i8x16::<13>(a0, value), a1),
+            14 => self.combine_i8x16(self.insert_i8x16::<14>(a0, value), a1),
+            15 => self.combine_i8x16(self.insert_i8x16::<15>(a0, value), a1),
+            16 => self.combine_i8x16(a0, self.insert_i8x16::<0>(a1, value)),
+            17 => self.combine_i8x16(a0, self.insert_i8x16::<1>(a1, value)),
+            18 => self.combine_i8x16(a0, self.insert_i8x16::<2>(a1, value)),
+            19 => self.combine_i8x16(a0, self.insert_i8x16::<3>(a1, value)),
+            20 => self.combine_i8x16(a0, self.insert_i8x16::<4>(a1, value)),
+            21 => self.combine_i8x16(a0, self.insert_i8x16::<5>(a1, value)),
+            22 => self.combine_i8x16(a0, self.insert_i8x16::<6>(a1, value)),
+            23 => self.combine_i8x16(a0, self.insert_i8x16::<7>(a1, value)),
+            24 => self.combine_i8x16(a0, self.insert_i8x16::<8>(a1, value)),
+            25 => self.combine_i8x16(a0, self.insert_i8x16::<9>(a1, value)),
+            26 => self.combine_i8x16(a0, self.insert_i8x16::<10>(a1, value)),
+            27 => self.combine_i8x16(a0, self.insert_i8x16::<11>(a1, value)),
+            28 => self.combine_i8x16(a0, self.insert_i8x16::<12>(a1, value)),
+            29 => self.combine_i8x16(a0, self.insert_i8x16::<13>(a1, value)),
+            30 => self.combine_i8x16(a0, self.insert_i8x16::<14>(a1, value)),
+            31 => self.combine_i8x16(a0, self.insert_i8x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x32(self, a: i8x32<Self>, index: usize) -> i8 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_i8x32(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i8x32(self, a: i8x32<Self>, indices: u8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, indices: u8x32<Avx2>) -> i8x32<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_i8x32(a).val.0, indices.into());
+                token.cvt_from_bytes_i8x32(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_add_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_sub_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                let dst_even = _mm256_mullo_epi16(a.into(), b.into());
+                let dst_odd = _mm256_mullo_epi16(
+                    _mm256_srli_epi16::<8>(a.into()),
+                    _mm256_srli_epi16::<8>(b.into()),
+                );
+                _mm256_or_si256(
+                    _mm256_slli_epi16(dst_odd, 8),
+                    _mm256_and_si256(dst_even, _mm256_set1_epi16(0xFF)),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        self.xor_i8x32(a, self.splat_i8x32(!0))
+    }
+    #[inline(always)]
+    fn shl_i8x32(self, a: i8x32<Self>, shift: u32) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, shift: u32) -> i8x32<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 =
+                    _mm256_unpacklo_epi8(val, _mm256_cmpgt_epi8(_mm256_setzero_si256(), val));
+                let hi_16 =
+                    _mm256_unpackhi_epi8(val, _mm256_cmpgt_epi8(_mm256_setzero_si256(), val));
+                let lo_shifted = _mm256_sll_epi16(lo_16, shift_count);
+                let hi_shifted = _mm256_sll_epi16(hi_16, shift_count);
+                _mm256_packs_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        [
+            i8::wrapping_shl(a[0usize], b[0usize] as u32),
+            i8::wrapping_shl(a[1usize], b[1usize] as u32),
+            i8::wrapping_shl(a[2usize], b[2usize] as u32),
+            i8::wrapping_shl(a[3usize], b[3usize] as u32),
+            i8::wrapping_shl(a[4usize], b[4usize] as u32),
+            i8::wrapping_shl(a[5usize], b[5usize] as u32),
+            i8::wrapping_shl(a[6usize], b[6usize] as u32),
+            i8::wrapping_shl(a[7usize], b[7usize] as u32),
+            i8::wrapping_shl(a[8usize], b[8usize] as u32),
+            i8::wrapping_shl(a[9usize], b[9usize] as u32),
+            i8::wrapping_shl(a[10usize], b[10usize] as u32),
+            i8::wrapping_shl(a[11usize], b[11usize] as u32),
+            i8::wrapping_shl(a[12usize], b[12usize] as u32),
+            i8::wrapping_shl(a[13usize], b[13usize] as u32),
+            i8::wrapping_shl(a[14usize], b[14usize] as u32),
+            i8::wrapping_shl(a[15usize], b[15usize] as u32),
+            i8::wrapping_shl(a[16usize], b[16usize] as u32),
+            i8::wrapping_shl(a[17usize], b[17usize] as u32),
+            i8::wrapping_shl(a[18usize], b[18usize] as u32),
+            i8::wrapping_shl(a[19usize], b[19usize] as u32),
+            i8::wrapping_shl(a[20usize], b[20usize] as u32),
+            i8::wrapping_shl(a[21usize], b[21usize] as u32),
+            i8::wrapping_shl(a[22usize], b[22usize] as u32),
+            i8::wrapping_shl(a[23usize], b[23usize] as u32),
+            i8::wrapping_shl(a[24usize], b[24usize] as u32),
+            i8::wrapping_shl(a[25usize], b[25usize] as u32),
+            i8::wrapping_shl(a[26usize], b[26usize] as u32),
+            i8::wrapping_shl(a[27usize], b[27usize] as u32),
+            i8::wrapping_shl(a[28usize], b[28usize] as u32),
+            i8::wrapping_shl(a[29usize], b[29usize] as u32),
+            i8::wrapping_shl(a[30usize], b[30usize] as u32),
+            i8::wrapping_shl(a[31usize], b[31usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i8x32(self, a: i8x32<Self>, shift: u32) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, shift: u32) -> i8x32<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 =
+                    _mm256_unpacklo_epi8(val, _mm256_cmpgt_epi8(_mm256_setzero_si256(), val));
+                let hi_16 =
+                    _mm256_unpackhi_epi8(val, _mm256_cmpgt_epi8(_mm256_setzero_si256(), val));
+                let lo_shifted = _mm256_sra_epi16(lo_16, shift_count);
+                let hi_shifted = _mm256_sra_epi16(hi_16, shift_count);
+                _mm256_packs_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        [
+            i8::wrapping_shr(a[0usize], b[0usize] as u32),
+            i8::wrapping_shr(a[1usize], b[1usize] as u32),
+            i8::wrapping_shr(a[2usize], b[2usize] as u32),
+            i8::wrapping_shr(a[3usize], b[3usize] as u32),
+            i8::wrapping_shr(a[4usize], b[4usize] as u32),
+            i8::wrapping_shr(a[5usize], b[5usize] as u32),
+            i8::wrapping_shr(a[6usize], b[6usize] as u32),
+            i8::wrapping_shr(a[7usize], b[7usize] as u32),
+            i8::wrapping_shr(a[8usize], b[8usize] as u32),
+            i8::wrapping_shr(a[9usize], b[9usize] as u32),
+            i8::wrapping_shr(a[10usize], b[10usize] as u32),
+            i8::wrapping_shr(a[11usize], b[11usize] as u32),
+            i8::wrapping_shr(a[12usize], b[12usize] as u32),
+            i8::wrapping_shr(a[13usize], b[13usize] as u32),
+            i8::wrapping_shr(a[14usize], b[14usize] as u32),
+            i8::wrapping_shr(a[15usize], b[15usize] as u32),
+            i8::wrapping_shr(a[16usize], b[16usize] as u32),
+            i8::wrapping_shr(a[17usize], b[17usize] as u32),
+            i8::wrapping_shr(a[18usize], b[18usize] as u32),
+            i8::wrapping_shr(a[19usize], b[19usize] as u32),
+            i8::wrapping_shr(a[20usize], b[20usize] as u32),
+            i8::wrapping_shr(a[21usize], b[21usize] as u32),
+            i8::wrapping_shr(a[22usize], b[22usize] as u32),
+            i8::wrapping_shr(a[23usize], b[23usize] as u32),
+            i8::wrapping_shr(a[24usize], b[24usize] as u32),
+            i8::wrapping_shr(a[25usize], b[25usize] as u32),
+            i8::wrapping_shr(a[26usize], b[26usize] as u32),
+            i8::wrapping_shr(a[27usize], b[27usize] as u32),
+            i8::wrapping_shr(a[28usize], b[28usize] as u32),
+            i8::wrapping_shr(a[29usize], b[29usize] as u32),
+            i8::wrapping_shr(a[30usize], b[30usize] as u32),
+            i8::wrapping_shr(a[31usize], b[31usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
+            if b[16usize] >= 0 {
+                a[16usize].wrapping_shl(b[16usize] as u32)
+            } else {
+                a[16usize].wrapping_shr((b[16usize] as u32).wrapping_neg())
+            },
+            if b[17usize] >= 0 {
+                a[17usize].wrapping_shl(b[17usize] as u32)
+            } else {
+                a[17usize].wrapping_shr((b[17usize] as u32).wrapping_neg())
+            },
+            if b[18usize] >= 0 {
+                a[18usize].wrapping_shl(b[18usize] as u32)
+            } else {
+                a[18usize].wrapping_shr((b[18usize] as u32).wrapping_neg())
+            },
+            if b[19usize] >= 0 {
+                a[19usize].wrapping_shl(b[19usize] as u32)
+            } else {
+                a[19usize].wrapping_shr((b[19usize] as u32).wrapping_neg())
+            },
+            if b[20usize] >= 0 {
+                a[20usize].wrapping_shl(b[20usize] as u32)
+            } else {
+                a[20usize].wrapping_shr((b[20usize] as u32).wrapping_neg())
+            },
+            if b[21usize] >= 0 {
+                a[21usize].wrapping_shl(b[21usize] as u32)
+            } else {
+                a[21usize].wrapping_shr((b[21usize] as u32).wrapping_neg())
+            },
+            if b[22usize] >= 0 {
+                a[22usize].wrapping_shl(b[22usize] as u32)
+            } else {
+                a[22usize].wrapping_shr((b[22usize] as u32).wrapping_neg())
+            },
+            if b[23usize] >= 0 {
+                a[23usize].wrapping_shl(b[23usize] as u32)
+            } else {
+                a[23usize].wrapping_shr((b[23usize] as u32).wrapping_neg())
+            },
+            if b[24usize] >= 0 {
+                a[24usize].wrapping_shl(b[24usize] as u32)
+            } else {
+                a[24usize].wrapping_shr((b[24usize] as u32).wrapping_neg())
+            },
+            if b[25usize] >= 0 {
+                a[25usize].wrapping_shl(b[25usize] as u32)
+            } else {
+                a[25usize].wrapping_shr((b[25usize] as u32).wrapping_neg())
+            },
+            if b[26usize] >= 0 {
+                a[26usize].wrapping_shl(b[26usize] as u32)
+            } else {
+                a[26usize].wrapping_shr((b[26usize] as u32).wrapping_neg())
+            },
+            if b[27usize] >= 0 {
+                a[27usize].wrapping_shl(b[27usize] as u32)
+            } else {
+                a[27usize].wrapping_shr((b[27usize] as u32).wrapping_neg())
+            },
+            if b[28usize] >= 0 {
+                a[28usize].wrapping_shl(b[28usize] as u32)
+            } else {
+                a[28usize].wrapping_shr((b[28usize] as u32).wrapping_neg())
+            },
+            if b[29usize] >= 0 {
+                a[29usize].wrapping_shl(b[29usize] as u32)
+            } else {
+                a[29usize].wrapping_shr((b[29usize] as u32).wrapping_neg())
+            },
+            if b[30usize] >= 0 {
+                a[30usize].wrapping_shl(b[30usize] as u32)
+            } else {
+                a[30usize].wrapping_shr((b[30usize] as u32).wrapping_neg())
+            },
+            if b[31usize] >= 0 {
+                a[31usize].wrapping_shl(b[31usize] as u32)
+            } else {
+                a[31usize].wrapping_shr((b[31usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpgt_epi8(b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(_mm256_min_epi8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(_mm256_max_epi8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpgt_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                let lo = _mm256_unpacklo_epi8(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi8(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                let lo = _mm256_unpacklo_epi8(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi8(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> (i8x32<Self>, i8x32<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> (i8x32<Avx2>, i8x32<Avx2>) {
+                let lo = _mm256_unpacklo_epi8(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi8(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> (i8x32<Self>, i8x32<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> (i8x32<Avx2>, i8x32<Avx2>) {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_i8x32(self, a: mask8x32<Self>, b: i8x32<Self>, c: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask8x32<Avx2>,
+                b: i8x32<Avx2>,
+                c: i8x32<Avx2>,
+            ) -> i8x32<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i8x32(
+        self,
+        a: mask8x32<Self>,
+        b: i8x32<Self>,
+        c: i8x32<Self>,
+    ) -> i8x32<Self> {
+        self.select_i8x32(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_min_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_max_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_adds_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>, b: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_subs_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> i8x32<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let a = self.or_i8x32(a, self.shr_i8x32(a, 1u32));
+        let a = self.or_i8x32(a, self.shr_i8x32(a, 2u32));
+        let a = self.or_i8x32(a, self.shr_i8x32(a, 4u32));
+        self.count_ones_i8x32(self.not_i8x32(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        self.count_ones_i8x32(
+            self.and_i8x32(self.not_i8x32(a), self.sub_i8x32(a, self.splat_i8x32(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
+        i8x64 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_i8x32(self, a: i8x32<Self>) -> (i8x16<Self>, i8x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> (i8x16<Avx2>, i8x16<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> i8x32<Avx2> {
+                _mm256_sub_epi8(_mm256_setzero_si256(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> i16x32<Avx2> {
+                let (a0, a1) = token.split_i8x32(a);
+                let high = _mm256_cvtepi8_epi16(a0.into()).simd_into(token);
+                let low = _mm256_cvtepi8_epi16(a1.into()).simd_into(token);
+                token.combine_i16x16(high, low)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x32(self, a: i8x32<Self>) -> i32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> i32 {
+                let sums = _mm256_sad_epu8(
+                    _mm256_xor_si256(a.into(), _mm256_set1_epi8(i8::MIN)),
+                    _mm256_setzero_si256(),
+                );
+                let sums = _mm_add_epi32(
+                    _mm256_castsi256_si128(sums),
+                    _mm256_extracti128_si256::<1>(sums),
+                );
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                let sum = _mm_cvtsi128_si32(sums);
+                sum - 4096
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x32(self, a: i8x32<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i8x32<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u8x32(self, val: u8) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u8) -> u8x32<Avx2> {
+                _mm256_set1_epi8(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u8x32(self, val: [u8; 32usize]) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u8x32(self, val: &[u8; 32usize]) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u8x32(self, a: u8x32<Self>) -> [u8; 32usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [u8; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u8x32(self, a: &u8x32<Self>) -> &[u8; 32usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [u8; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u8x32(self, a: &mut u8x32<Self>) -> &mut [u8; 32usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [u8; 32usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u8x32(self, a: u8x32<Self>, dest: &mut [u8; 32usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u8x32<const SHIFT: usize>(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_u8x32(b).val.0,
+            self.cvt_to_bytes_u8x32(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_u8x32(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u8x32<const SHIFT: usize>(
+        self,
+        a: u8x32<Self>,
+        b: u8x32<Self>,
+    ) -> u8x32<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_u8x32(b).val.0,
+            self.cvt_to_bytes_u8x32(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_u8x32(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u8x32<const OFFSET: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_u8x32::<0>(a, a),
+            1 => self.slide_u8x32::<1>(a, a),
+            2 => self.slide_u8x32::<2>(a, a),
+            3 => self.slide_u8x32::<3>(a, a),
+            4 => self.slide_u8x32::<4>(a, a),
+            5 => self.slide_u8x32::<5>(a, a),
+            6 => self.slide_u8x32::<6>(a, a),
+            7 => self.slide_u8x32::<7>(a, a),
+            8 => self.slide_u8x32::<8>(a, a),
+            9 => self.slide_u8x32::<9>(a, a),
+            10 => self.slide_u8x32::<10>(a, a),
+            11 => self.slide_u8x32::<11>(a, a),
+            12 => self.slide_u8x32::<12>(a, a),
+            13 => self.slide_u8x32::<13>(a, a),
+            14 => self.slide_u8x32::<14>(a, a),
+            15 => self.slide_u8x32::<15>(a, a),
+            16 => self.slide_u8x32::<16>(a, a),
+            17 => self.slide_u8x32::<17>(a, a),
+            18 => self.slide_u8x32::<18>(a, a),
+            19 => self.slide_u8x32::<19>(a, a),
+            20 => self.slide_u8x32::<20>(a, a),
+            21 => self.slide_u8x32::<21>(a, a),
+            22 => self.slide_u8x32::<22>(a, a),
+            23 => self.slide_u8x32::<23>(a, a),
+            24 => self.slide_u8x32::<24>(a, a),
+            25 => self.slide_u8x32::<25>(a, a),
+            26 => self.slide_u8x32::<26>(a, a),
+            27 => self.slide_u8x32::<27>(a, a),
+            28 => self.slide_u8x32::<28>(a, a),
+            29 => self.slide_u8x32::<29>(a, a),
+            30 => self.slide_u8x32::<30>(a, a),
+            31 => self.slide_u8x32::<31>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u8x32<const OFFSET: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_u8x32::<32>(a, a),
+            1 => self.slide_u8x32::<31>(a, a),
+            2 => self.slide_u8x32::<30>(a, a),
+            3 => self.slide_u8x32::<29>(a, a),
+            4 => self.slide_u8x32::<28>(a, a),
+            5 => self.slide_u8x32::<27>(a, a),
+            6 => self.slide_u8x32::<26>(a, a),
+            7 => self.slide_u8x32::<25>(a, a),
+            8 => self.slide_u8x32::<24>(a, a),
+            9 => self.slide_u8x32::<23>(a, a),
+            10 => self.slide_u8x32::<22>(a, a),
+            11 => self.slide_u8x32::<21>(a, a),
+            12 => self.slide_u8x32::<20>(a, a),
+            13 => self.slide_u8x32::<19>(a, a),
+            14 => self.slide_u8x32::<18>(a, a),
+            15 => self.slide_u8x32::<17>(a, a),
+            16 => self.slide_u8x32::<16>(a, a),
+            17 => self.slide_u8x32::<15>(a, a),
+            18 => self.slide_u8x32::<14>(a, a),
+            19 => self.slide_u8x32::<13>(a, a),
+            20 => self.slide_u8x32::<12>(a, a),
+            21 => self.slide_u8x32::<11>(a, a),
+            22 => self.slide_u8x32::<10>(a, a),
+            23 => self.slide_u8x32::<9>(a, a),
+            24 => self.slide_u8x32::<8>(a, a),
+            25 => self.slide_u8x32::<7>(a, a),
+            26 => self.slide_u8x32::<6>(a, a),
+            27 => self.slide_u8x32::<5>(a, a),
+            28 => self.slide_u8x32::<4>(a, a),
+            29 => self.slide_u8x32::<3>(a, a),
+            30 => self.slide_u8x32::<2>(a, a),
+            31 => self.slide_u8x32::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u8x32<const OFFSET: usize>(
+        self,
+        a: u8x32<Self>,
+        padding: u8,
+    ) -> u8x32<Self> {
+        let padding = self.splat_u8x32(padding);
+        match OFFSET {
+            0 => self.slide_u8x32::<0>(a, padding),
+            1 => self.slide_u8x32::<1>(a, padding),
+            2 => self.slide_u8x32::<2>(a, padding),
+            3 => self.slide_u8x32::<3>(a, padding),
+            4 => self.slide_u8x32::<4>(a, padding),
+            5 => self.slide_u8x32::<5>(a, padding),
+            6 => self.slide_u8x32::<6>(a, padding),
+            7 => self.slide_u8x32::<7>(a, padding),
+            8 => self.slide_u8x32::<8>(a, padding),
+            9 => self.slide_u8x32::<9>(a, padding),
+            10 => self.slide_u8x32::<10>(a, padding),
+            11 => self.slide_u8x32::<11>(a, padding),
+            12 => self.slide_u8x32::<12>(a, padding),
+            13 => self.slide_u8x32::<13>(a, padding),
+            14 => self.slide_u8x32::<14>(a, padding),
+            15 => self.slide_u8x32::<15>(a, padding),
+            16 => self.slide_u8x32::<16>(a, padding),
+            17 => self.slide_u8x32::<17>(a, padding),
+            18 => self.slide_u8x32::<18>(a, padding),
+            19 => self.slide_u8x32::<19>(a, padding),
+            20 => self.slide_u8x32::<20>(a, padding),
+            21 => self.slide_u8x32::<21>(a, padding),
+            22 => self.slide_u8x32::<22>(a, padding),
+            23 => self.slide_u8x32::<23>(a, padding),
+            24 => self.slide_u8x32::<24>(a, padding),
+            25 => self.slide_u8x32::<25>(a, padding),
+            26 => self.slide_u8x32::<26>(a, padding),
+            27 => self.slide_u8x32::<27>(a, padding),
+            28 => self.slide_u8x32::<28>(a, padding),
+            29 => self.slide_u8x32::<29>(a, padding),
+            30 => self.slide_u8x32::<30>(a, padding),
+            31 => self.slide_u8x32::<31>(a, padding),
+            32 => self.slide_u8x32::<32>(a, padding),
+            _ => self.slide_u8x32::<32>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u8x32<const OFFSET: usize>(
+        self,
+        a: u8x32<Self>,
+        padding: u8,
+    ) -> u8x32<Self> {
+        let padding = self.splat_u8x32(padding);
+        match OFFSET {
+            0 => self.slide_u8x32::<32>(padding, a),
+            1 => self.slide_u8x32::<31>(padding, a),
+            2 => self.slide_u8x32::<30>(padding, a),
+            3 => self.slide_u8x32::<29>(padding, a),
+            4 => self.slide_u8x32::<28>(padding, a),
+            5 => self.slide_u8x32::<27>(padding, a),
+            6 => self.slide_u8x32::<26>(padding, a),
+            7 => self.slide_u8x32::<25>(padding, a),
+            8 => self.slide_u8x32::<24>(padding, a),
+            9 => self.slide_u8x32::<23>(padding, a),
+            10 => self.slide_u8x32::<22>(padding, a),
+            11 => self.slide_u8x32::<21>(padding, a),
+            12 => self.slide_u8x32::<20>(padding, a),
+            13 => self.slide_u8x32::<19>(padding, a),
+            14 => self.slide_u8x32::<18>(padding, a),
+            15 => self.slide_u8x32::<17>(padding, a),
+            16 => self.slide_u8x32::<16>(padding, a),
+            17 => self.slide_u8x32::<15>(padding, a),
+            18 => self.slide_u8x32::<14>(padding, a),
+            19 => self.slide_u8x32::<13>(padding, a),
+            20 => self.slide_u8x32::<12>(padding, a),
+            21 => self.slide_u8x32::<11>(padding, a),
+            22 => self.slide_u8x32::<10>(padding, a),
+            23 => self.slide_u8x32::<9>(padding, a),
+            24 => self.slide_u8x32::<8>(padding, a),
+            25 => self.slide_u8x32::<7>(padding, a),
+            26 => self.slide_u8x32::<6>(padding, a),
+            27 => self.slide_u8x32::<5>(padding, a),
+            28 => self.slide_u8x32::<4>(padding, a),
+            29 => self.slide_u8x32::<3>(padding, a),
+            30 => self.slide_u8x32::<2>(padding, a),
+            31 => self.slide_u8x32::<1>(padding, a),
+            32 => self.slide_u8x32::<0>(padding, a),
+            _ => self.slide_u8x32::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u8x32<const LANE: usize>(self, a: u8x32<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x32(a);
+        match LANE {
+            0 => self.extract_u8x16::<0>(a0),
+            1 => self.extract_u8x16::<1>(a0),
+            2 => self.extract_u8x16::<2>(a0),
+            3 => self.extract_u8x16::<3>(a0),
+            4 => self.extract_u8x16::<4>(a0),
+            5 => self.extract_u8x16::<5>(a0),
+            6 => self.extract_u8x16::<6>(a0),
+            7 => self.extract_u8x16::<7>(a0),
+            8 => self.extract_u8x16::<8>(a0),
+            9 => self.extract_u8x16::<9>(a0),
+            10 => self.extract_u8x16::<10>(a0),
+            11 => self.extract_u8x16::<11>(a0),
+            12 => self.extract_u8x16::<12>(a0),
+            13 => self.extract_u8x16::<13>(a0),
+            14 => self.extract_u8x16::<14>(a0),
+            15 => self.extract_u8x16::<15>(a0),
+            16 => self.extract_u8x16::<0>(a1),
+            17 => self.extract_u8x16::<1>(a1),
+            18 => self.extract_u8x16::<2>(a1),
+            19 => self.extract_u8x16::<3>(a1),
+            20 => self.extract_u8x16::<4>(a1),
+            21 => self.extract_u8x16::<5>(a1),
+            22 => self.extract_u8x16::<6>(a1),
+            23 => self.extract_u8x16::<7>(a1),
+            24 => self.extract_u8x16::<8>(a1),
+            25 => self.extract_u8x16::<9>(a1),
+            26 => self.extract_u8x16::<10>(a1),
+            27 => self.extract_u8x16::<11>(a1),
+            28 => self.extract_u8x16::<12>(a1),
+            29 => self.extract_u8x16::<13>(a1),
+            30 => self.extract_u8x16::<14>(a1),
+            31 => self.extract_u8x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u8x32<const LANE: usize>(self, a: u8x32<Self>, value: u8) -> u8x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x32(a);
+        match LANE {
+            0 => self.combine_u8x16(self.insert_u8x16::<0>(a0, value), a1),
+            1 => self.combine_u8x16(self.insert_u8x16::<1>(a0, value), a1),
+            2 => self.combine_u8x16(self.insert_u8x16::<2>(a0, value), a1),
+            3 => self.combine_u8x16(self.insert_u8x16::<3>(a0, value), a1),
+            4 => self.combine_u8x16(self.insert_u8x16::<4>(a0, value), a1),
+            5 => self.combine_u8x16(self.insert_u8x16::<5>(a0, value), a1),
+            6 => self.combine_u8x16(self.insert_u8x16::<6>(a0, value), a1),
+            7 => self.combine_u8x16(self.insert_u8x16::<7>(a0, value), a1),
+            8 => self.combine_u8x16(self.insert_u8x16::<8>(a0, value), a1),
+            9 => self.combine_u8x16(self.insert_u8x16::<9>(a0, value), a1),
+            10 => self.combine_u8x16(self.insert_u8x16::<10>(a0, value), a1),
+            11 => self.combine_u8x16(self.insert_u8x16::<11>(a0, value), a1),
+            12 => self.combine_u8x16(self.insert_u8x16::<12>(a0, value), a1),
+            13 => self.combine_u8x16(self.insert_u8x16::<13>(a0, value), a1),
+            14 => self.combine_u8x16(self.insert_u8x16::<14>(a0, value), a1),
+            15 => self.combine_u8x16(self.insert_u8x16::<15>(a0, value), a1),
+            16 => self.combine_u8x16(a0, self.insert_u8x16::<0>(a1, value)),
+            17 => self.combine_u8x16(a0, self.insert_u8x16::<1>(a1, value)),
+            18 => self.combine_u8x16(a0, self.insert_u8x16::<2>(a1, value)),
+            19 => self.combine_u8x16(a0, self.insert_u8x16::<3>(a1, value)),
+            20 => self.combine_u8x16(a0, self.insert_u8x16::<4>(a1, value)),
+            21 => self.combine_u8x16(a0, self.insert_u8x16::<5>(a1, value)),
+            22 => self.combine_u8x16(a0, self.insert_u8x16::<6>(a1, value)),
+            23 => self.combine_u8x16(a0, self.insert_u8x16::<7>(a1, value)),
+            24 => self.combine_u8x16(a0, self.insert_u8x16::<8>(a1, value)),
+            25 => self.combine_u8x16(a0, self.insert_u8x16::<9>(a1, value)),
+            26 => self.combine_u8x16(a0, self.insert_u8x16::<10>(a1, value)),
+            27 => self.combine_u8x16(a0, self.insert_u8x16::<11>(a1, value)),
+            28 => self.combine_u8x16(a0, self.insert_u8x16::<12>(a1, value)),
+            29 => self.combine_u8x16(a0, self.insert_u8x16::<13>(a1, value)),
+            30 => self.combine_u8x16(a0, self.insert_u8x16::<14>(a1, value)),
+            31 => self.combine_u8x16(a0, self.insert_u8x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x32(self, a: u8x32<Self>, index: usize) -> u8 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_u8x32(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u8x32(self, a: u8x32<Self>, indices: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, indices: u8x32<Avx2>) -> u8x32<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_u8x32(a).val.0, indices.into());
+                token.cvt_from_bytes_u8x32(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_add_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_sub_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                let dst_even = _mm256_mullo_epi16(a.into(), b.into());
+                let dst_odd = _mm256_mullo_epi16(
+                    _mm256_srli_epi16::<8>(a.into()),
+                    _mm256_srli_epi16::<8>(b.into()),
+                );
+                _mm256_or_si256(
+                    _mm256_slli_epi16(dst_odd, 8),
+                    _mm256_and_si256(dst_even, _mm256_set1_epi16(0xFF)),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        self.xor_u8x32(a, self.splat_u8x32(!0))
+    }
+    #[inline(always)]
+    fn shl_u8x32(self, a: u8x32<Self>, shift: u32) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, shift: u32) -> u8x32<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 = _mm256_unpacklo_epi8(val, _mm256_setzero_si256());
+                let hi_16 = _mm256_unpackhi_epi8(val, _mm256_setzero_si256());
+                let lo_shifted = _mm256_sll_epi16(lo_16, shift_count);
+                let hi_shifted = _mm256_sll_epi16(hi_16, shift_count);
+                _mm256_packus_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        [
+            u8::wrapping_shl(a[0usize], b[0usize] as u32),
+            u8::wrapping_shl(a[1usize], b[1usize] as u32),
+            u8::wrapping_shl(a[2usize], b[2usize] as u32),
+            u8::wrapping_shl(a[3usize], b[3usize] as u32),
+            u8::wrapping_shl(a[4usize], b[4usize] as u32),
+            u8::wrapping_shl(a[5usize], b[5usize] as u32),
+            u8::wrapping_shl(a[6usize], b[6usize] as u32),
+            u8::wrapping_shl(a[7usize], b[7usize] as u32),
+            u8::wrapping_shl(a[8usize], b[8usize] as u32),
+            u8::wrapping_shl(a[9usize], b[9usize] as u32),
+            u8::wrapping_shl(a[10usize], b[10usize] as u32),
+            u8::wrapping_shl(a[11usize], b[11usize] as u32),
+            u8::wrapping_shl(a[12usize], b[12usize] as u32),
+            u8::wrapping_shl(a[13usize], b[13usize] as u32),
+            u8::wrapping_shl(a[14usize], b[14usize] as u32),
+            u8::wrapping_shl(a[15usize], b[15usize] as u32),
+            u8::wrapping_shl(a[16usize], b[16usize] as u32),
+            u8::wrapping_shl(a[17usize], b[17usize] as u32),
+            u8::wrapping_shl(a[18usize], b[18usize] as u32),
+            u8::wrapping_shl(a[19usize], b[19usize] as u32),
+            u8::wrapping_shl(a[20usize], b[20usize] as u32),
+            u8::wrapping_shl(a[21usize], b[21usize] as u32),
+            u8::wrapping_shl(a[22usize], b[22usize] as u32),
+            u8::wrapping_shl(a[23usize], b[23usize] as u32),
+            u8::wrapping_shl(a[24usize], b[24usize] as u32),
+            u8::wrapping_shl(a[25usize], b[25usize] as u32),
+            u8::wrapping_shl(a[26usize], b[26usize] as u32),
+            u8::wrapping_shl(a[27usize], b[27usize] as u32),
+            u8::wrapping_shl(a[28usize], b[28usize] as u32),
+            u8::wrapping_shl(a[29usize], b[29usize] as u32),
+            u8::wrapping_shl(a[30usize], b[30usize] as u32),
+            u8::wrapping_shl(a[31usize], b[31usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u8x32(self, a: u8x32<Self>, shift: u32) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, shift: u32) -> u8x32<Avx2> {
+                let val = a.into();
+                let shift_count = _mm_cvtsi32_si128(shift.cast_signed());
+                let lo_16 = _mm256_unpacklo_epi8(val, _mm256_setzero_si256());
+                let hi_16 = _mm256_unpackhi_epi8(val, _mm256_setzero_si256());
+                let lo_shifted = _mm256_srl_epi16(lo_16, shift_count);
+                let hi_shifted = _mm256_srl_epi16(hi_16, shift_count);
+                _mm256_packus_epi16(lo_shifted, hi_shifted).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        [
+            u8::wrapping_shr(a[0usize], b[0usize] as u32),
+            u8::wrapping_shr(a[1usize], b[1usize] as u32),
+            u8::wrapping_shr(a[2usize], b[2usize] as u32),
+            u8::wrapping_shr(a[3usize], b[3usize] as u32),
+            u8::wrapping_shr(a[4usize], b[4usize] as u32),
+            u8::wrapping_shr(a[5usize], b[5usize] as u32),
+            u8::wrapping_shr(a[6usize], b[6usize] as u32),
+            u8::wrapping_shr(a[7usize], b[7usize] as u32),
+            u8::wrapping_shr(a[8usize], b[8usize] as u32),
+            u8::wrapping_shr(a[9usize], b[9usize] as u32),
+            u8::wrapping_shr(a[10usize], b[10usize] as u32),
+            u8::wrapping_shr(a[11usize], b[11usize] as u32),
+            u8::wrapping_shr(a[12usize], b[12usize] as u32),
+            u8::wrapping_shr(a[13usize], b[13usize] as u32),
+            u8::wrapping_shr(a[14usize], b[14usize] as u32),
+            u8::wrapping_shr(a[15usize], b[15usize] as u32),
+            u8::wrapping_shr(a[16usize], b[16usize] as u32),
+            u8::wrapping_shr(a[17usize], b[17usize] as u32),
+            u8::wrapping_shr(a[18usize], b[18usize] as u32),
+            u8::wrapping_shr(a[19usize], b[19usize] as u32),
+            u8::wrapping_shr(a[20usize], b[20usize] as u32),
+            u8::wrapping_shr(a[21usize], b[21usize] as u32),
+            u8::wrapping_shr(a[22usize], b[22usize] as u32),
+            u8::wrapping_shr(a[23usize], b[23usize] as u32),
+            u8::wrapping_shr(a[24usize], b[24usize] as u32),
+            u8::wrapping_shr(a[25usize], b[25usize] as u32),
+            u8::wrapping_shr(a[26usize], b[26usize] as u32),
+            u8::wrapping_shr(a[27usize], b[27usize] as u32),
+            u8::wrapping_shr(a[28usize], b[28usize] as u32),
+            u8::wrapping_shr(a[29usize], b[29usize] as u32),
+            u8::wrapping_shr(a[30usize], b[30usize] as u32),
+            u8::wrapping_shr(a[31usize], b[31usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u8x32(self, a: u8x32<Self>, b: i8x32<Self>) -> u8x32<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
+            if b[16usize] >= 0 {
+                a[16usize].wrapping_shl(b[16usize] as u32)
+            } else {
+                a[16usize].wrapping_shr((b[16usize] as u32).wrapping_neg())
+            },
+            if b[17usize] >= 0 {
+                a[17usize].wrapping_shl(b[17usize] as u32)
+            } else {
+                a[17usize].wrapping_shr((b[17usize] as u32).wrapping_neg())
+            },
+            if b[18usize] >= 0 {
+                a[18usize].wrapping_shl(b[18usize] as u32)
+            } else {
+                a[18usize].wrapping_shr((b[18usize] as u32).wrapping_neg())
+            },
+            if b[19usize] >= 0 {
+                a[19usize].wrapping_shl(b[19usize] as u32)
+            } else {
+                a[19usize].wrapping_shr((b[19usize] as u32).wrapping_neg())
+            },
+            if b[20usize] >= 0 {
+                a[20usize].wrapping_shl(b[20usize] as u32)
+            } else {
+                a[20usize].wrapping_shr((b[20usize] as u32).wrapping_neg())
+            },
+            if b[21usize] >= 0 {
+                a[21usize].wrapping_shl(b[21usize] as u32)
+            } else {
+                a[21usize].wrapping_shr((b[21usize] as u32).wrapping_neg())
+            },
+            if b[22usize] >= 0 {
+                a[22usize].wrapping_shl(b[22usize] as u32)
+            } else {
+                a[22usize].wrapping_shr((b[22usize] as u32).wrapping_neg())
+            },
+            if b[23usize] >= 0 {
+                a[23usize].wrapping_shl(b[23usize] as u32)
+            } else {
+                a[23usize].wrapping_shr((b[23usize] as u32).wrapping_neg())
+            },
+            if b[24usize] >= 0 {
+                a[24usize].wrapping_shl(b[24usize] as u32)
+            } else {
+                a[24usize].wrapping_shr((b[24usize] as u32).wrapping_neg())
+            },
+            if b[25usize] >= 0 {
+                a[25usize].wrapping_shl(b[25usize] as u32)
+            } else {
+                a[25usize].wrapping_shr((b[25usize] as u32).wrapping_neg())
+            },
+            if b[26usize] >= 0 {
+                a[26usize].wrapping_shl(b[26usize] as u32)
+            } else {
+                a[26usize].wrapping_shr((b[26usize] as u32).wrapping_neg())
+            },
+            if b[27usize] >= 0 {
+                a[27usize].wrapping_shl(b[27usize] as u32)
+            } else {
+                a[27usize].wrapping_shr((b[27usize] as u32).wrapping_neg())
+            },
+            if b[28usize] >= 0 {
+                a[28usize].wrapping_shl(b[28usize] as u32)
+            } else {
+                a[28usize].wrapping_shr((b[28usize] as u32).wrapping_neg())
+            },
+            if b[29usize] >= 0 {
+                a[29usize].wrapping_shl(b[29usize] as u32)
+            } else {
+                a[29usize].wrapping_shr((b[29usize] as u32).wrapping_neg())
+            },
+            if b[30usize] >= 0 {
+                a[30usize].wrapping_shl(b[30usize] as u32)
+            } else {
+                a[30usize].wrapping_shr((b[30usize] as u32).wrapping_neg())
+            },
+            if b[31usize] >= 0 {
+                a[31usize].wrapping_shl(b[31usize] as u32)
+            } else {
+                a[31usize].wrapping_shr((b[31usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> mask8x32<Avx2> {
+                {
+                    let sign_bit = _mm256_set1_epi8(0x80u8.cast_signed());
+                    let lhs_signed = _mm256_xor_si256(b.into(), sign_bit);
+                    let rhs_signed = _mm256_xor_si256(a.into(), sign_bit);
+                    _mm256_cmpgt_epi8(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(_mm256_min_epu8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(_mm256_max_epu8(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> mask8x32<Avx2> {
+                {
+                    let sign_bit = _mm256_set1_epi8(0x80u8.cast_signed());
+                    let lhs_signed = _mm256_xor_si256(a.into(), sign_bit);
+                    let rhs_signed = _mm256_xor_si256(b.into(), sign_bit);
+                    _mm256_cmpgt_epi8(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                let lo = _mm256_unpacklo_epi8(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi8(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                let lo = _mm256_unpacklo_epi8(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi8(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> (u8x32<Self>, u8x32<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> (u8x32<Avx2>, u8x32<Avx2>) {
+                let lo = _mm256_unpacklo_epi8(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi8(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> (u8x32<Self>, u8x32<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> (u8x32<Avx2>, u8x32<Avx2>) {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10,
+                        12, 14, 1, 3, 5, 7, 9, 11, 13, 15,
+                    ),
+                ));
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_u8x32(self, a: mask8x32<Self>, b: u8x32<Self>, c: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask8x32<Avx2>,
+                b: u8x32<Avx2>,
+                c: u8x32<Avx2>,
+            ) -> u8x32<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u8x32(
+        self,
+        a: mask8x32<Self>,
+        b: u8x32<Self>,
+        c: u8x32<Self>,
+    ) -> u8x32<Self> {
+        self.select_u8x32(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_min_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_max_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_adds_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_subs_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>) -> u8x32<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let a = self.or_u8x32(a, self.shr_u8x32(a, 1u32));
+        let a = self.or_u8x32(a, self.shr_u8x32(a, 2u32));
+        let a = self.or_u8x32(a, self.shr_u8x32(a, 4u32));
+        self.count_ones_u8x32(self.not_u8x32(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        self.count_ones_u8x32(
+            self.and_u8x32(self.not_u8x32(a), self.sub_u8x32(a, self.splat_u8x32(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_u8x32(self, a: u8x32<Self>) -> (u8x16<Self>, u8x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>) -> (u8x16<Avx2>, u8x16<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_u8x32(self, a: u8x32<Self>) -> u16x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>) -> u16x32<Avx2> {
+                let (a0, a1) = token.split_u8x32(a);
+                let high = _mm256_cvtepu8_epi16(a0.into()).simd_into(token);
+                let low = _mm256_cvtepu8_epi16(a1.into()).simd_into(token);
+                token.combine_u16x16(high, low)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u8x32(
+        self,
+        acc: u16x32<Self>,
+        a: u8x32<Self>,
+        b: u8x32<Self>,
+    ) -> u16x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                acc: u16x32<Avx2>,
+                a: u8x32<Avx2>,
+                b: u8x32<Avx2>,
+            ) -> u16x32<Avx2> {
+                let (a, b) = (a.into(), b.into());
+                let abd = _mm256_or_si256(_mm256_subs_epu8(a, b), _mm256_subs_epu8(b, a));
+                let (acc0, acc1) = token.split_u16x32(acc);
+                let lo = _mm256_add_epi16(
+                    acc0.into(),
+                    _mm256_cvtepu8_epi16(_mm256_castsi256_si128(abd)),
+                )
+                .simd_into(token);
+                let hi = _mm256_add_epi16(
+                    acc1.into(),
+                    _mm256_cvtepu8_epi16(_mm256_extracti128_si256::<1>(abd)),
+                )
+                .simd_into(token);
+                token.combine_u16x16(lo, hi)
+            }
+        );
+        kernel(self, acc, a, b)
+    }
+    #[inline(always)]
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                _mm256_avg_epu8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x32(self, a: u8x32<Self>) -> u32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>) -> u32 {
+                let sums = _mm256_sad_epu8(a.into(), _mm256_setzero_si256());
+                let sums = _mm_add_epi32(
+                    _mm256_castsi256_si128(sums),
+                    _mm256_extracti128_si256::<1>(sums),
+                );
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                let sum = _mm_cvtsi128_si32(sums);
+                sum.cast_unsigned()
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn compress_bytes_u8x32(self, a: u8x32<Self>, mask: mask8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (mask0, mask1) = self.split_mask8x32(mask);
+        let count = self.to_bitmask_mask8x16(mask0).count_ones() as usize;
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.as_array_u8x16(self.compress_bytes_u8x16(a0, mask0)));
+        result[count..count + 16]
+            .copy_from_slice(&self.as_array_u8x16(self.compress_bytes_u8x16(a1, mask1)));
+        self.load_array_u8x32(result)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x32(self, a: u8x32<Self>, mask: mask8x32<Self>) -> u8x32<Self> {
+        let (mask0, mask1) = self.split_mask8x32(mask);
+        let count = self.to_bitmask_mask8x16(mask0).count_ones() as usize;
+        let lanes = self.as_array_u8x32(a);
+        let (a0, _) = self.split_u8x32(a);
+        let a1 = self.load_array_u8x16(core::array::from_fn(|i| lanes[count + i]));
+        self.combine_u8x16(
+            self.expand_bytes_u8x16(a0, mask0),
+            self.expand_bytes_u8x16(a1, mask1),
+        )
+    }
+    #[inline(always)]
+    fn table_lookup_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x32<Avx2>, b: u8x32<Avx2>) -> u8x32<Avx2> {
+                let indices = _mm256_adds_epu8(b.into(), _mm256_set1_epi8(0x70));
+                _mm256_shuffle_epi8(a.into(), indices).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x32<const FACTOR: u8>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<32>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x32(
+            self.load_array_u8x32(low),
+            self.and_u8x32(a, self.splat_u8x32(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x32(self.load_array_u8x32(high), self.shr_u8x32(a, 4));
+        self.xor_u8x32(low, high)
+    }
+    #[inline(always)]
+    fn splat_mask8x32(self, val: bool) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask8x32<Avx2> {
+                let val: i8 = if val { !0 } else { 0 };
+                _mm256_set1_epi8(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask8x32(self, val: [i8; 32usize]) -> mask8x32<Self> {
+        mask8x32 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask8x32(self, a: mask8x32<Self>) -> [i8; 32usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i8; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self> {
+        i8x32 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask8x32<Avx2> {
+                {
+                    let bit_bytes = _mm256_broadcastsi128_si256(_mm_cvtsi32_si128(bits as i32));
+                    let bit_bytes = _mm256_shuffle_epi8(
+                        bit_bytes,
+                        _mm256_setr_epi8(
+                            0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2,
+                            3, 3, 3, 3, 3, 3, 3, 3,
+                        ),
+                    );
+                    let bit_mask = _mm256_setr_epi8(
+                        1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16,
+                        32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128,
+                    );
+                    _mm256_cmpeq_epi8(_mm256_and_si256(bit_bytes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x32(self, a: mask8x32<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>) -> u64 {
+                _mm256_movemask_epi8(a.into()) as u32 as u64
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask8x32(self, a: &mut mask8x32<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 32usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        let mut lanes = self.as_array_mask8x32(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask8x32(lanes);
+    }
+    #[inline(always)]
+    fn and_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>, b: mask8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>, b: mask8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>, b: mask8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        self.xor_mask8x32(a, self.splat_mask8x32(true))
+    }
+    #[inline(always)]
+    fn select_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask8x32<Avx2>,
+                b: mask8x32<Avx2>,
+                c: mask8x32<Avx2>,
+            ) -> mask8x32<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        self.select_mask8x32(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>, b: mask8x32<Avx2>) -> mask8x32<Avx2> {
+                _mm256_cmpeq_epi8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask8x32(self, a: mask8x32<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask8x32(self, a: mask8x32<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 == 0xffffffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask8x32(self, a: mask8x32<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 != 0xffffffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask8x32(self, a: mask8x32<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x64<Self> {
+        mask8x64 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_mask8x32(self, a: mask8x32<Self>) -> (mask8x16<Self>, mask8x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask8x32<Avx2>) -> (mask8x16<Avx2>, mask8x16<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_i16x16(self, val: i16) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i16) -> i16x16<Avx2> {
+                _mm256_set1_epi16(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i16x16(self, val: [i16; 16usize]) -> i16x16<Self> {
+        i16x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i16x16(self, val: &[i16; 16usize]) -> i16x16<Self> {
+        i16x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i16x16(self, a: i16x16<Self>) -> [i16; 16usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i16; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i16x16(self, a: &i16x16<Self>) -> &[i16; 16usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [i16; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i16x16(self, a: &mut i16x16<Self>) -> &mut [i16; 16usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [i16; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i16x16(self, a: i16x16<Self>, dest: &mut [i16; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i16x16(self, a: u8x32<Self>) -> i16x16<Self> {
+        i16x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i16x16<const SHIFT: usize>(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_i16x16(b).val.0,
+            self.cvt_to_bytes_i16x16(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_i16x16(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i16x16<const SHIFT: usize>(
+        self,
+        a: i16x16<Self>,
+        b: i16x16<Self>,
+    ) -> i16x16<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_i16x16(b).val.0,
+            self.cvt_to_bytes_i16x16(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_i16x16(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i16x16<const OFFSET: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_i16x16::<0>(a, a),
+            1 => self.slide_i16x16::<1>(a, a),
+            2 => self.slide_i16x16::<2>(a, a),
+            3 => self.slide_i16x16::<3>(a, a),
+            4 => self.slide_i16x16::<4>(a, a),
+            5 => self.slide_i16x16::<5>(a, a),
+            6 => self.slide_i16x16::<6>(a, a),
+            7 => self.slide_i16x16::<7>(a, a),
+            8 => self.slide_i16x16::<8>(a, a),
+            9 => self.slide_i16x16::<9>(a, a),
+            10 => self.slide_i16x16::<10>(a, a),
+            11 => self.slide_i16x16::<11>(a, a),
+            12 => self.slide_i16x16::<12>(a, a),
+            13 => self.slide_i16x16::<13>(a, a),
+            14 => self.slide_i16x16::<14>(a, a),
+            15 => self.slide_i16x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i16x16<const OFFSET: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_i16x16::<16>(a, a),
+            1 => self.slide_i16x16::<15>(a, a),
+            2 => self.slide_i16x16::<14>(a, a),
+            3 => self.slide_i16x16::<13>(a, a),
+            4 => self.slide_i16x16::<12>(a, a),
+            5 => self.slide_i16x16::<11>(a, a),
+            6 => self.slide_i16x16::<10>(a, a),
+            7 => self.slide_i16x16::<9>(a, a),
+            8 => self.slide_i16x16::<8>(a, a),
+            9 => self.slide_i16x16::<7>(a, a),
+            10 => self.slide_i16x16::<6>(a, a),
+            11 => self.slide_i16x16::<5>(a, a),
+            12 => self.slide_i16x16::<4>(a, a),
+            13 => self.slide_i16x16::<3>(a, a),
+            14 => self.slide_i16x16::<2>(a, a),
+            15 => self.slide_i16x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i16x16<const OFFSET: usize>(
+        self,
+        a: i16x16<Self>,
+        padding: i16,
+    ) -> i16x16<Self> {
+        let padding = self.splat_i16x16(padding);
+        match OFFSET {
+            0 => self.slide_i16x16::<0>(a, padding),
+            1 => self.slide_i16x16::<1>(a, padding),
+            2 => self.slide_i16x16::<2>(a, padding),
+            3 => self.slide_i16x16::<3>(a, padding),
+            4 => self.slide_i16x16::<4>(a, padding),
+            5 => self.slide_i16x16::<5>(a, padding),
+            6 => self.slide_i16x16::<6>(a, padding),
+            7 => self.slide_i16x16::<7>(a, padding),
+            8 => self.slide_i16x16::<8>(a, padding),
+            9 => self.slide_i16x16::<9>(a, padding),
+            10 => self.slide_i16x16::<10>(a, padding),
+            11 => self.slide_i16x16::<11>(a, padding),
+            12 => self.slide_i16x16::<12>(a, padding),
+            13 => self.slide_i16x16::<13>(a, padding),
+            14 => self.slide_i16x16::<14>(a, padding),
+            15 => self.slide_i16x16::<15>(a, padding),
+            16 => self.slide_i16x16::<16>(a, padding),
+            _ => self.slide_i16x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i16x16<const OFFSET: usize>(
+        self,
+        a: i16x16<Self>,
+        padding: i16,
+    ) -> i16x16<Self> {
+        let padding = self.splat_i16x16(padding);
+        match OFFSET {
+            0 => self.slide_i16x16::<16>(padding, a),
+            1 => self.slide_i16x16::<15>(padding, a),
+            2 => self.slide_i16x16::<14>(padding, a),
+            3 => self.slide_i16x16::<13>(padding, a),
+            4 => self.slide_i16x16::<12>(padding, a),
+            5 => self.slide_i16x16::<11>(padding, a),
+            6 => self.slide_i16x16::<10>(padding, a),
+            7 => self.slide_i16x16::<9>(padding, a),
+            8 => self.slide_i16x16::<8>(padding, a),
+            9 => self.slide_i16x16::<7>(padding, a),
+            10 => self.slide_i16x16::<6>(padding, a),
+            11 => self.slide_i16x16::<5>(padding, a),
+            12 => self.slide_i16x16::<4>(padding, a),
+            13 => self.slide_i16x16::<3>(padding, a),
+            14 => self.slide_i16x16::<2>(padding, a),
+            15 => self.slide_i16x16::<1>(padding, a),
+            16 => self.slide_i16x16::<0>(padding, a),
+            _ => self.slide_i16x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i16x16<const LANE: usize>(self, a: i16x16<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x16(a);
+        match LANE {
+            0 => self.extract_i16x8::<0>(a0),
+            1 => self.extract_i16x8::<1>(a0),
+            2 => self.extract_i16x8::<2>(a0),
+            3 => self.extract_i16x8::<3>(a0),
+            4 => self.extract_i16x8::<4>(a0),
+            5 => self.extract_i16x8::<5>(a0),
+            6 => self.extract_i16x8::<6>(a0),
+            7 => self.extract_i16x8::<7>(a0),
+            8 => self.extract_i16x8::<0>(a1),
+            9 => self.extract_i16x8::<1>(a1),
+            10 => self.extract_i16x8::<2>(a1),
+            11 => self.extract_i16x8::<3>(a1),
+            12 => self.extract_i16x8::<4>(a1),
+            13 => self.extract_i16x8::<5>(a1),
+            14 => self.extract_i16x8::<6>(a1),
+            15 => self.extract_i16x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i16x16<const LANE: usize>(self, a: i16x16<Self>, value: i16) -> i16x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x16(a);
+        match LANE {
+            0 => self.combine_i16x8(self.insert_i16x8::<0>(a0, value), a1),
+            1 => self.combine_i16x8(self.insert_i16x8::<1>(a0, value), a1),
+            2 => self.combine_i16x8(self.insert_i16x8::<2>(a0, value), a1),
+            3 => self.combine_i16x8(self.insert_i16x8::<3>(a0, value), a1),
+            4 => self.combine_i16x8(self.insert_i16x8::<4>(a0, value), a1),
+            5 => self.combine_i16x8(self.insert_i16x8::<5>(a0, value), a1),
+            6 => self.combine_i16x8(self.insert_i16x8::<6>(a0, value), a1),
+            7 => self.combine_i16x8(self.insert_i16x8::<7>(a0, value), a1),
+            8 => self.combine_i16x8(a0, self.insert_i16x8::<0>(a1, value)),
+            9 => self.combine_i16x8(a0, self.insert_i16x8::<1>(a1, value)),
+            10 => self.combine_i16x8(a0, self.insert_i16x8::<2>(a1, value)),
+            11 => self.combine_i16x8(a0, self.insert_i16x8::<3>(a1, value)),
+            12 => self.combine_i16x8(a0, self.insert_i16x8::<4>(a1, value)),
+            13 => self.combine_i16x8(a0, self.insert_i16x8::<5>(a1, value)),
+            14 => self.combine_i16x8(a0, self.insert_i16x8::<6>(a1, value)),
+            15 => self.combine_i16x8(a0, self.insert_i16x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x16(self, a: i16x16<Self>, index: usize) -> i16 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i16x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i16x16(
+        self,
+        a: i16x16<Self>,
+        indices: u8x32<Self>,
+    ) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, indices: u8x32<Avx2>) -> i16x16<Avx2> {
+                let result =
+                    _mm256_shuffle_epi8(token.cvt_to_bytes_i16x16(a).val.0, indices.into());
+                token.cvt_from_bytes_i16x16(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_add_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_sub_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_mullo_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        self.xor_i16x16(a, self.splat_i16x16(!0))
+    }
+    #[inline(always)]
+    fn shl_i16x16(self, a: i16x16<Self>, shift: u32) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, shift: u32) -> i16x16<Avx2> {
+                _mm256_sll_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        [
+            i16::wrapping_shl(a[0usize], b[0usize] as u32),
+            i16::wrapping_shl(a[1usize], b[1usize] as u32),
+            i16::wrapping_shl(a[2usize], b[2usize] as u32),
+            i16::wrapping_shl(a[3usize], b[3usize] as u32),
+            i16::wrapping_shl(a[4usize], b[4usize] as u32),
+            i16::wrapping_shl(a[5usize], b[5usize] as u32),
+            i16::wrapping_shl(a[6usize], b[6usize] as u32),
+            i16::wrapping_shl(a[7usize], b[7usize] as u32),
+            i16::wrapping_shl(a[8usize], b[8usize] as u32),
+            i16::wrapping_shl(a[9usize], b[9usize] as u32),
+            i16::wrapping_shl(a[10usize], b[10usize] as u32),
+            i16::wrapping_shl(a[11usize], b[11usize] as u32),
+            i16::wrapping_shl(a[12usize], b[12usize] as u32),
+            i16::wrapping_shl(a[13usize], b[13usize] as u32),
+            i16::wrapping_shl(a[14usize], b[14usize] as u32),
+            i16::wrapping_shl(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i16x16(self, a: i16x16<Self>, shift: u32) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, shift: u32) -> i16x16<Avx2> {
+                _mm256_sra_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        [
+            i16::wrapping_shr(a[0usize], b[0usize] as u32),
+            i16::wrapping_shr(a[1usize], b[1usize] as u32),
+            i16::wrapping_shr(a[2usize], b[2usize] as u32),
+            i16::wrapping_shr(a[3usize], b[3usize] as u32),
+            i16::wrapping_shr(a[4usize], b[4usize] as u32),
+            i16::wrapping_shr(a[5usize], b[5usize] as u32),
+            i16::wrapping_shr(a[6usize], b[6usize] as u32),
+            i16::wrapping_shr(a[7usize], b[7usize] as u32),
+            i16::wrapping_shr(a[8usize], b[8usize] as u32),
+            i16::wrapping_shr(a[9usize], b[9usize] as u32),
+            i16::wrapping_shr(a[10usize], b[10usize] as u32),
+            i16::wrapping_shr(a[11usize], b[11usize] as u32),
+            i16::wrapping_shr(a[12usize], b[12usize] as u32),
+            i16::wrapping_shr(a[13usize], b[13usize] as u32),
+            i16::wrapping_shr(a[14usize], b[14usize] as u32),
+            i16::wrapping_shr(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpgt_epi16(b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(_mm256_min_epi16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(_mm256_max_epi16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpgt_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                let lo = _mm256_unpacklo_epi16(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi16(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                let lo = _mm256_unpacklo_epi16(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi16(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> (i16x16<Self>, i16x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: i16x16<Avx2>,
+                b: i16x16<Avx2>,
+            ) -> (i16x16<Avx2>, i16x16<Avx2>) {
+                let lo = _mm256_unpacklo_epi16(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi16(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> (i16x16<Self>, i16x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: i16x16<Avx2>,
+                b: i16x16<Avx2>,
+            ) -> (i16x16<Avx2>, i16x16<Avx2>) {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_i16x16(self, a: mask16x16<Self>, b: i16x16<Self>, c: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask16x16<Avx2>,
+                b: i16x16<Avx2>,
+                c: i16x16<Avx2>,
+            ) -> i16x16<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i16x16(
+        self,
+        a: mask16x16<Self>,
+        b: i16x16<Self>,
+        c: i16x16<Self>,
+    ) -> i16x16<Self> {
+        self.select_i16x16(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_min_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_max_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_adds_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>, b: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_subs_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> i16x16<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    let bytes = _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm256_add_epi16(
+                        _mm256_and_si256(bytes, _mm256_set1_epi16(0xff)),
+                        _mm256_srli_epi16::<8>(bytes),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let a = self.or_i16x16(a, self.shr_i16x16(a, 1u32));
+        let a = self.or_i16x16(a, self.shr_i16x16(a, 2u32));
+        let a = self.or_i16x16(a, self.shr_i16x16(a, 4u32));
+        let a = self.or_i16x16(a, self.shr_i16x16(a, 8u32));
+        self.count_ones_i16x16(self.not_i16x16(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        self.count_ones_i16x16(
+            self.and_i16x16(self.not_i16x16(a), self.sub_i16x16(a, self.splat_i16x16(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
+        i16x32 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_i16x16(self, a: i16x16<Self>) -> (i16x8<Self>, i16x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> (i16x8<Avx2>, i16x8<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> i16x16<Avx2> {
+                _mm256_sub_epi16(_mm256_setzero_si256(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> i32x16<Avx2> {
+                let (a0, a1) = token.split_i16x16(a);
+                let high = _mm256_cvtepi16_epi32(a0.into()).simd_into(token);
+                let low = _mm256_cvtepi16_epi32(a1.into()).simd_into(token);
+                token.combine_i32x8(high, low)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_i8_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> i8x16<Avx2> {
+                let a: __m256i = a.into();
+                let lo = _mm256_castsi256_si128(a);
+                let hi = _mm256_extracti128_si256::<1>(a);
+                _mm_packs_epi16(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_i16x16(self, a: i16x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> u8x16<Avx2> {
+                let a: __m256i = a.into();
+                let lo = _mm256_castsi256_si128(a);
+                let hi = _mm256_extracti128_si256::<1>(a);
+                _mm_packus_epi16(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x16(self, a: i16x16<Self>) -> i32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> i32 {
+                let sums = _mm256_madd_epi16(a.into(), _mm256_set1_epi16(1));
+                let sums = _mm_add_epi32(
+                    _mm256_castsi256_si128(sums),
+                    _mm256_extracti128_si256::<1>(sums),
+                );
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                _mm_cvtsi128_si32(sums)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x16(self, a: i16x16<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x16<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u16x16(self, val: u16) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u16) -> u16x16<Avx2> {
+                _mm256_set1_epi16(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u16x16(self, val: [u16; 16usize]) -> u16x16<Self> {
+        u16x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u16x16(self, val: &[u16; 16usize]) -> u16x16<Self> {
+        u16x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u16x16(self, a: u16x16<Self>) -> [u16; 16usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [u16; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u16x16(self, a: &u16x16<Self>) -> &[u16; 16usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [u16; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u16x16(self, a: &mut u16x16<Self>) -> &mut [u16; 16usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [u16; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u16x16(self, a: u16x16<Self>, dest: &mut [u16; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u16x16(self, a: u8x32<Self>) -> u16x16<Self> {
+        u16x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u16x16<const SHIFT: usize>(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_u16x16(b).val.0,
+            self.cvt_to_bytes_u16x16(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_u16x16(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u16x16<const SHIFT: usize>(
+        self,
+        a: u16x16<Self>,
+        b: u16x16<Self>,
+    ) -> u16x16<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_u16x16(b).val.0,
+            self.cvt_to_bytes_u16x16(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_u16x16(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u16x16<const OFFSET: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_u16x16::<0>(a, a),
+            1 => self.slide_u16x16::<1>(a, a),
+            2 => self.slide_u16x16::<2>(a, a),
+            3 => self.slide_u16x16::<3>(a, a),
+            4 => self.slide_u16x16::<4>(a, a),
+            5 => self.slide_u16x16::<5>(a, a),
+            6 => self.slide_u16x16::<6>(a, a),
+            7 => self.slide_u16x16::<7>(a, a),
+            8 => self.slide_u16x16::<8>(a, a),
+            9 => self.slide_u16x16::<9>(a, a),
+            10 => self.slide_u16x16::<10>(a, a),
+            11 => self.slide_u16x16::<11>(a, a),
+            12 => self.slide_u16x16::<12>(a, a),
+            13 => self.slide_u16x16::<13>(a, a),
+            14 => self.slide_u16x16::<14>(a, a),
+            15 => self.slide_u16x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u16x16<const OFFSET: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_u16x16::<16>(a, a),
+            1 => self.slide_u16x16::<15>(a, a),
+            2 => self.slide_u16x16::<14>(a, a),
+            3 => self.slide_u16x16::<13>(a, a),
+            4 => self.slide_u16x16::<12>(a, a),
+            5 => self.slide_u16x16::<11>(a, a),
+            6 => self.slide_u16x16::<10>(a, a),
+            7 => self.slide_u16x16::<9>(a, a),
+            8 => self.slide_u16x16::<8>(a, a),
+            9 => self.slide_u16x16::<7>(a, a),
+            10 => self.slide_u16x16::<6>(a, a),
+            11 => self.slide_u16x16::<5>(a, a),
+            12 => self.slide_u16x16::<4>(a, a),
+            13 => self.slide_u16x16::<3>(a, a),
+            14 => self.slide_u16x16::<2>(a, a),
+            15 => self.slide_u16x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u16x16<const OFFSET: usize>(
+        self,
+        a: u16x16<Self>,
+        padding: u16,
+    ) -> u16x16<Self> {
+        let padding = self.splat_u16x16(padding);
+        match OFFSET {
+            0 => self.slide_u16x16::<0>(a, padding),
+            1 => self.slide_u16x16::<1>(a, padding),
+            2 => self.slide_u16x16::<2>(a, padding),
+            3 => self.slide_u16x16::<3>(a, padding),
+            4 => self.slide_u16x16::<4>(a, padding),
+            5 => self.slide_u16x16::<5>(a, padding),
+            6 => self.slide_u16x16::<6>(a, padding),
+            7 => self.slide_u16x16::<7>(a, padding),
+            8 => self.slide_u16x16::<8>(a, padding),
+            9 => self.slide_u16x16::<9>(a, padding),
+            10 => self.slide_u16x16::<10>(a, padding),
+            11 => self.slide_u16x16::<11>(a, padding),
+            12 => self.slide_u16x16::<12>(a, padding),
+            13 => self.slide_u16x16::<13>(a, padding),
+            14 => self.slide_u16x16::<14>(a, padding),
+            15 => self.slide_u16x16::<15>(a, padding),
+            16 => self.slide_u16x16::<16>(a, padding),
+            _ => self.slide_u16x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u16x16<const OFFSET: usize>(
+        self,
+        a: u16x16<Self>,
+        padding: u16,
+    ) -> u16x16<Self> {
+        let padding = self.splat_u16x16(padding);
+        match OFFSET {
+            0 => self.slide_u16x16::<16>(padding, a),
+            1 => self.slide_u16x16::<15>(padding, a),
+            2 => self.slide_u16x16::<14>(padding, a),
+            3 => self.slide_u16x16::<13>(padding, a),
+            4 => self.slide_u16x16::<12>(padding, a),
+            5 => self.slide_u16x16::<11>(padding, a),
+            6 => self.slide_u16x16::<10>(padding, a),
+            7 => self.slide_u16x16::<9>(padding, a),
+            8 => self.slide_u16x16::<8>(padding, a),
+            9 => self.slide_u16x16::<7>(padding, a),
+            10 => self.slide_u16x16::<6>(padding, a),
+            11 => self.slide_u16x16::<5>(padding, a),
+            12 => self.slide_u16x16::<4>(padding, a),
+            13 => self.slide_u16x16::<3>(padding, a),
+            14 => self.slide_u16x16::<2>(padding, a),
+            15 => self.slide_u16x16::<1>(padding, a),
+            16 => self.slide_u16x16::<0>(padding, a),
+            _ => self.slide_u16x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u16x16<const LANE: usize>(self, a: u16x16<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x16(a);
+        match LANE {
+            0 => self.extract_u16x8::<0>(a0),
+            1 => self.extract_u16x8::<1>(a0),
+            2 => self.extract_u16x8::<2>(a0),
+            3 => self.extract_u16x8::<3>(a0),
+            4 => self.extract_u16x8::<4>(a0),
+            5 => self.extract_u16x8::<5>(a0),
+            6 => self.extract_u16x8::<6>(a0),
+            7 => self.extract_u16x8::<7>(a0),
+            8 => self.extract_u16x8::<0>(a1),
+            9 => self.extract_u16x8::<1>(a1),
+            10 => self.extract_u16x8::<2>(a1),
+            11 => self.extract_u16x8::<3>(a1),
+            12 => self.extract_u16x8::<4>(a1),
+            13 => self.extract_u16x8::<5>(a1),
+            14 => self.extract_u16x8::<6>(a1),
+            15 => self.extract_u16x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u16x16<const LANE: usize>(self, a: u16x16<Self>, value: u16) -> u16x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x16(a);
+        match LANE {
+            0 => self.combine_u16x8(self.insert_u16x8::<0>(a0, value), a1),
+            1 => self.combine_u16x8(self.insert_u16x8::<1>(a0, value), a1),
+            2 => self.combine_u16x8(self.insert_u16x8::<2>(a0, value), a1),
+            3 => self.combine_u16x8(self.insert_u16x8::<3>(a0, value), a1),
+            4 => self.combine_u16x8(self.insert_u16x8::<4>(a0, value), a1),
+            5 => self.combine_u16x8(self.insert_u16x8::<5>(a0, value), a1),
+            6 => self.combine_u16x8(self.insert_u16x8::<6>(a0, value), a1),
+            7 => self.combine_u16x8(self.insert_u16x8::<7>(a0, value), a1),
+            8 => self.combine_u16x8(a0, self.insert_u16x8::<0>(a1, value)),
+            9 => self.combine_u16x8(a0, self.insert_u16x8::<1>(a1, value)),
+            10 => self.combine_u16x8(a0, self.insert_u16x8::<2>(a1, value)),
+            11 => self.combine_u16x8(a0, self.insert_u16x8::<3>(a1, value)),
+            12 => self.combine_u16x8(a0, self.insert_u16x8::<4>(a1, value)),
+            13 => self.combine_u16x8(a0, self.insert_u16x8::<5>(a1, value)),
+            14 => self.combine_u16x8(a0, self.insert_u16x8::<6>(a1, value)),
+            15 => self.combine_u16x8(a0, self.insert_u16x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x16(self, a: u16x16<Self>, index: usize) -> u16 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u16x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u16x16(
+        self,
+        a: u16x16<Self>,
+        indices: u8x32<Self>,
+    ) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, indices: u8x32<Avx2>) -> u16x16<Avx2> {
+                let result =
+                    _mm256_shuffle_epi8(token.cvt_to_bytes_u16x16(a).val.0, indices.into());
+                token.cvt_from_bytes_u16x16(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_add_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_sub_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_mullo_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        self.xor_u16x16(a, self.splat_u16x16(!0))
+    }
+    #[inline(always)]
+    fn shl_u16x16(self, a: u16x16<Self>, shift: u32) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, shift: u32) -> u16x16<Avx2> {
+                _mm256_sll_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        [
+            u16::wrapping_shl(a[0usize], b[0usize] as u32),
+            u16::wrapping_shl(a[1usize], b[1usize] as u32),
+            u16::wrapping_shl(a[2usize], b[2usize] as u32),
+            u16::wrapping_shl(a[3usize], b[3usize] as u32),
+            u16::wrapping_shl(a[4usize], b[4usize] as u32),
+            u16::wrapping_shl(a[5usize], b[5usize] as u32),
+            u16::wrapping_shl(a[6usize], b[6usize] as u32),
+            u16::wrapping_shl(a[7usize], b[7usize] as u32),
+            u16::wrapping_shl(a[8usize], b[8usize] as u32),
+            u16::wrapping_shl(a[9usize], b[9usize] as u32),
+            u16::wrapping_shl(a[10usize], b[10usize] as u32),
+            u16::wrapping_shl(a[11usize], b[11usize] as u32),
+            u16::wrapping_shl(a[12usize], b[12usize] as u32),
+            u16::wrapping_shl(a[13usize], b[13usize] as u32),
+            u16::wrapping_shl(a[14usize], b[14usize] as u32),
+            u16::wrapping_shl(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u16x16(self, a: u16x16<Self>, shift: u32) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, shift: u32) -> u16x16<Avx2> {
+                _mm256_srl_epi16(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        [
+            u16::wrapping_shr(a[0usize], b[0usize] as u32),
+            u16::wrapping_shr(a[1usize], b[1usize] as u32),
+            u16::wrapping_shr(a[2usize], b[2usize] as u32),
+            u16::wrapping_shr(a[3usize], b[3usize] as u32),
+            u16::wrapping_shr(a[4usize], b[4usize] as u32),
+            u16::wrapping_shr(a[5usize], b[5usize] as u32),
+            u16::wrapping_shr(a[6usize], b[6usize] as u32),
+            u16::wrapping_shr(a[7usize], b[7usize] as u32),
+            u16::wrapping_shr(a[8usize], b[8usize] as u32),
+            u16::wrapping_shr(a[9usize], b[9usize] as u32),
+            u16::wrapping_shr(a[10usize], b[10usize] as u32),
+            u16::wrapping_shr(a[11usize], b[11usize] as u32),
+            u16::wrapping_shr(a[12usize], b[12usize] as u32),
+            u16::wrapping_shr(a[13usize], b[13usize] as u32),
+            u16::wrapping_shr(a[14usize], b[14usize] as u32),
+            u16::wrapping_shr(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u16x16(self, a: u16x16<Self>, b: i16x16<Self>) -> u16x16<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> mask16x16<Avx2> {
+                {
+                    let sign_bit = _mm256_set1_epi16(0x8000u16.cast_signed());
+                    let lhs_signed = _mm256_xor_si256(b.into(), sign_bit);
+                    let rhs_signed = _mm256_xor_si256(a.into(), sign_bit);
+                    _mm256_cmpgt_epi16(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(_mm256_min_epu16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(_mm256_max_epu16(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> mask16x16<Avx2> {
+                {
+                    let sign_bit = _mm256_set1_epi16(0x8000u16.cast_signed());
+                    let lhs_signed = _mm256_xor_si256(a.into(), sign_bit);
+                    let rhs_signed = _mm256_xor_si256(b.into(), sign_bit);
+                    _mm256_cmpgt_epi16(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                let lo = _mm256_unpacklo_epi16(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi16(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                let lo = _mm256_unpacklo_epi16(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi16(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> (u16x16<Self>, u16x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: u16x16<Avx2>,
+                b: u16x16<Avx2>,
+            ) -> (u16x16<Avx2>, u16x16<Avx2>) {
+                let lo = _mm256_unpacklo_epi16(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi16(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> (u16x16<Self>, u16x16<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: u16x16<Avx2>,
+                b: u16x16<Avx2>,
+            ) -> (u16x16<Avx2>, u16x16<Avx2>) {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    a.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_shuffle_epi8(
+                    b.into(),
+                    _mm256_setr_epi8(
+                        0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15, 0, 1, 4, 5, 8, 9, 12,
+                        13, 2, 3, 6, 7, 10, 11, 14, 15,
+                    ),
+                ));
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_u16x16(self, a: mask16x16<Self>, b: u16x16<Self>, c: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask16x16<Avx2>,
+                b: u16x16<Avx2>,
+                c: u16x16<Avx2>,
+            ) -> u16x16<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u16x16(
+        self,
+        a: mask16x16<Self>,
+        b: u16x16<Self>,
+        c: u16x16<Self>,
+    ) -> u16x16<Self> {
+        self.select_u16x16(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_min_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_max_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_adds_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_subs_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u16x16<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    let bytes = _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm256_add_epi16(
+                        _mm256_and_si256(bytes, _mm256_set1_epi16(0xff)),
+                        _mm256_srli_epi16::<8>(bytes),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let a = self.or_u16x16(a, self.shr_u16x16(a, 1u32));
+        let a = self.or_u16x16(a, self.shr_u16x16(a, 2u32));
+        let a = self.or_u16x16(a, self.shr_u16x16(a, 4u32));
+        let a = self.or_u16x16(a, self.shr_u16x16(a, 8u32));
+        self.count_ones_u16x16(self.not_u16x16(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        self.count_ones_u16x16(
+            self.and_u16x16(self.not_u16x16(a), self.sub_u16x16(a, self.splat_u16x16(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
+        u16x32 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_u16x16(self, a: u16x16<Self>) -> (u16x8<Self>, u16x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> (u16x8<Avx2>, u16x8<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u32x16<Avx2> {
+                let (a0, a1) = token.split_u16x16(a);
+                let high = _mm256_cvtepu16_epi32(a0.into()).simd_into(token);
+                let low = _mm256_cvtepu16_epi32(a1.into()).simd_into(token);
+                token.combine_u32x8(high, low)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u8x16<Avx2> {
+                let mask = _mm256_setr_epi8(
+                    0, 2, 4, 6, 8, 10, 12, 14, -1, -1, -1, -1, -1, -1, -1, -1, 0, 2, 4, 6, 8, 10,
+                    12, 14, -1, -1, -1, -1, -1, -1, -1, -1,
+                );
+                let shuffled = _mm256_shuffle_epi8(a.into(), mask);
+                let packed = _mm256_permute4x64_epi64::<0b11_01_10_00>(shuffled);
+                _mm256_castsi256_si128(packed).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_i8_u16x16(self, a: u16x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> i8x16<Avx2> {
+                let a: __m256i = a.into();
+                let lo = _mm256_castsi256_si128(a);
+                let hi = _mm256_extracti128_si256::<1>(a);
+                let max = _mm_set1_epi16(127);
+                let lo = _mm_min_epu16(lo, max);
+                let hi = _mm_min_epu16(hi, max);
+                _mm_packs_epi16(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u8x16<Avx2> {
+                let a: __m256i = a.into();
+                let lo = _mm256_castsi256_si128(a);
+                let hi = _mm256_extracti128_si256::<1>(a);
+                let max = _mm_set1_epi16(255);
+                let lo = _mm_min_epu16(lo, max);
+                let hi = _mm_min_epu16(hi, max);
+                _mm_packus_epi16(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u16x16(
+        self,
+        acc: u32x16<Self>,
+        a: u16x16<Self>,
+        b: u16x16<Self>,
+    ) -> u32x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                acc: u32x16<Avx2>,
+                a: u16x16<Avx2>,
+                b: u16x16<Avx2>,
+            ) -> u32x16<Avx2> {
+                let (a, b) = (a.into(), b.into());
+                let abd = _mm256_or_si256(_mm256_subs_epu16(a, b), _mm256_subs_epu16(b, a));
+                let (acc0, acc1) = token.split_u32x16(acc);
+                let lo = _mm256_add_epi32(
+                    acc0.into(),
+                    _mm256_cvtepu16_epi32(_mm256_castsi256_si128(abd)),
+                )
+                .simd_into(token);
+                let hi = _mm256_add_epi32(
+                    acc1.into(),
+                    _mm256_cvtepu16_epi32(_mm256_extracti128_si256::<1>(abd)),
+                )
+                .simd_into(token);
+                token.combine_u32x8(lo, hi)
+            }
+        );
+        kernel(self, acc, a, b)
+    }
+    #[inline(always)]
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>, b: u16x16<Avx2>) -> u16x16<Avx2> {
+                _mm256_avg_epu16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x16(self, a: u16x16<Self>) -> u32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u32 {
+                let sums = _mm256_madd_epi16(
+                    _mm256_xor_si256(a.into(), _mm256_set1_epi16(i16::MIN)),
+                    _mm256_set1_epi16(1),
+                );
+                let sums = _mm_add_epi32(
+                    _mm256_castsi256_si128(sums),
+                    _mm256_extracti128_si256::<1>(sums),
+                );
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                let sum = _mm_cvtsi128_si32(sums);
+                (sum + 524288).cast_unsigned()
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x16<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn transpose_bits_16x16_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (top, bottom) = self.split_u8x32(a.to_le_bytes());
+        let ac =
+            self.transpose_bits_8x8_u64x2(Bytes::from_le_bytes(self.unzip_low_u8x16(top, bottom)));
+        let bd =
+            self.transpose_bits_8x8_u64x2(Bytes::from_le_bytes(self.unzip_high_u8x16(top, bottom)));
+        let ab = self.zip_low_u64x2(ac, bd).to_le_bytes();
+        let cd = self.zip_high_u64x2(ac, bd).to_le_bytes();
+        Bytes::from_le_bytes(
+            self.combine_u8x16(self.zip_low_u8x16(ab, cd), self.zip_high_u8x16(ab, cd)),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask16x16(self, val: bool) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask16x16<Avx2> {
+                let val: i16 = if val { !0 } else { 0 };
+                _mm256_set1_epi16(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask16x16(self, val: [i16; 16usize]) -> mask16x16<Self> {
+        mask16x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask16x16(self, a: mask16x16<Self>) -> [i16; 16usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i16; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self> {
+        i16x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask16x16<Avx2> {
+                {
+                    let bit_lanes = _mm256_set1_epi16(bits as i16);
+                    let bit_mask = _mm256_setr_epi16(
+                        1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384,
+                        -32768,
+                    );
+                    _mm256_cmpeq_epi16(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x16(self, a: mask16x16<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>) -> u64 {
+                {
+                    let halves: [__m128i; 2usize] =
+                        crate::transmute::checked_transmute_copy(&a.val.0);
+                    let packed = _mm_packs_epi16(halves[0], halves[1]);
+                    _mm_movemask_epi8(packed) as u32 as u64
+                }
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask16x16(self, a: &mut mask16x16<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 16usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        let mut lanes = self.as_array_mask16x16(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask16x16(lanes);
+    }
+    #[inline(always)]
+    fn and_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>, b: mask16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>, b: mask16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>, b: mask16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        self.xor_mask16x16(a, self.splat_mask16x16(true))
+    }
+    #[inline(always)]
+    fn select_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask16x16<Avx2>,
+                b: mask16x16<Avx2>,
+                c: mask16x16<Avx2>,
+            ) -> mask16x16<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        self.select_mask16x16(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>, b: mask16x16<Avx2>) -> mask16x16<Avx2> {
+                _mm256_cmpeq_epi16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask16x16(self, a: mask16x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask16x16(self, a: mask16x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 == 0xffffffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask16x16(self, a: mask16x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 != 0xffffffff
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask16x16(self, a: mask16x16<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>) -> bool {
+                _mm256_movemask_epi8(a.into()) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x32<Self> {
+        mask16x32 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_mask16x16(self, a: mask16x16<Self>) -> (mask16x8<Self>, mask16x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x16<Avx2>) -> (mask16x8<Avx2>, mask16x8<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_i32x8(self, val: i32) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i32) -> i32x8<Avx2> {
+                _mm256_set1_epi32(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i32x8(self, val: [i32; 8usize]) -> i32x8<Self> {
+        i32x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i32x8(self, val: &[i32; 8usize]) -> i32x8<Self> {
+        i32x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i32x8(self, a: i32x8<Self>) -> [i32; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i32x8(self, a: &i32x8<Self>) -> &[i32; 8usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [i32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i32x8(self, a: &mut i32x8<Self>) -> &mut [i32; 8usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [i32; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i32x8(self, a: i32x8<Self>, dest: &mut [i32; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i32x8(self, a: u8x32<Self>) -> i32x8<Self> {
+        i32x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i32x8<const SHIFT: usize>(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_i32x8(b).val.0,
+            self.cvt_to_bytes_i32x8(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_i32x8(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i32x8<const SHIFT: usize>(
+        self,
+        a: i32x8<Self>,
+        b: i32x8<Self>,
+    ) -> i32x8<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_i32x8(b).val.0,
+            self.cvt_to_bytes_i32x8(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_i32x8(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i32x8<const OFFSET: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_i32x8::<0>(a, a),
+            1 => self.slide_i32x8::<1>(a, a),
+            2 => self.slide_i32x8::<2>(a, a),
+            3 => self.slide_i32x8::<3>(a, a),
+            4 => self.slide_i32x8::<4>(a, a),
+            5 => self.slide_i32x8::<5>(a, a),
+            6 => self.slide_i32x8::<6>(a, a),
+            7 => self.slide_i32x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i32x8<const OFFSET: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_i32x8::<8>(a, a),
+            1 => self.slide_i32x8::<7>(a, a),
+            2 => self.slide_i32x8::<6>(a, a),
+            3 => self.slide_i32x8::<5>(a, a),
+            4 => self.slide_i32x8::<4>(a, a),
+            5 => self.slide_i32x8::<3>(a, a),
+            6 => self.slide_i32x8::<2>(a, a),
+            7 => self.slide_i32x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i32x8<const OFFSET: usize>(
+        self,
+        a: i32x8<Self>,
+        padding: i32,
+    ) -> i32x8<Self> {
+        let padding = self.splat_i32x8(padding);
+        match OFFSET {
+            0 => self.slide_i32x8::<0>(a, padding),
+            1 => self.slide_i32x8::<1>(a, padding),
+            2 => self.slide_i32x8::<2>(a, padding),
+            3 => self.slide_i32x8::<3>(a, padding),
+            4 => self.slide_i32x8::<4>(a, padding),
+            5 => self.slide_i32x8::<5>(a, padding),
+            6 => self.slide_i32x8::<6>(a, padding),
+            7 => self.slide_i32x8::<7>(a, padding),
+            8 => self.slide_i32x8::<8>(a, padding),
+            _ => self.slide_i32x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i32x8<const OFFSET: usize>(
+        self,
+        a: i32x8<Self>,
+        padding: i32,
+    ) -> i32x8<Self> {
+        let padding = self.splat_i32x8(padding);
+        match OFFSET {
+            0 => self.slide_i32x8::<8>(padding, a),
+            1 => self.slide_i32x8::<7>(padding, a),
+            2 => self.slide_i32x8::<6>(padding, a),
+            3 => self.slide_i32x8::<5>(padding, a),
+            4 => self.slide_i32x8::<4>(padding, a),
+            5 => self.slide_i32x8::<3>(padding, a),
+            6 => self.slide_i32x8::<2>(padding, a),
+            7 => self.slide_i32x8::<1>(padding, a),
+            8 => self.slide_i32x8::<0>(padding, a),
+            _ => self.slide_i32x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i32x8<const LANE: usize>(self, a: i32x8<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x8(a);
+        match LANE {
+            0 => self.extract_i32x4::<0>(a0),
+            1 => self.extract_i32x4::<1>(a0),
+            2 => self.extract_i32x4::<2>(a0),
+            3 => self.extract_i32x4::<3>(a0),
+            4 => self.extract_i32x4::<0>(a1),
+            5 => self.extract_i32x4::<1>(a1),
+            6 => self.extract_i32x4::<2>(a1),
+            7 => self.extract_i32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i32x8<const LANE: usize>(self, a: i32x8<Self>, value: i32) -> i32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x8(a);
+        match LANE {
+            0 => self.combine_i32x4(self.insert_i32x4::<0>(a0, value), a1),
+            1 => self.combine_i32x4(self.insert_i32x4::<1>(a0, value), a1),
+            2 => self.combine_i32x4(self.insert_i32x4::<2>(a0, value), a1),
+            3 => self.combine_i32x4(self.insert_i32x4::<3>(a0, value), a1),
+            4 => self.combine_i32x4(a0, self.insert_i32x4::<0>(a1, value)),
+            5 => self.combine_i32x4(a0, self.insert_i32x4::<1>(a1, value)),
+            6 => self.combine_i32x4(a0, self.insert_i32x4::<2>(a1, value)),
+            7 => self.combine_i32x4(a0, self.insert_i32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x8(self, a: i32x8<Self>, index: usize) -> i32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i32x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i32x8(self, a: i32x8<Self>, indices: u8x32<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, indices: u8x32<Avx2>) -> i32x8<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_i32x8(a).val.0, indices.into());
+                token.cvt_from_bytes_i32x8(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_add_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_sub_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_mullo_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        self.xor_i32x8(a, self.splat_i32x8(!0))
+    }
+    #[inline(always)]
+    fn shl_i32x8(self, a: i32x8<Self>, shift: u32) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, shift: u32) -> i32x8<Avx2> {
+                _mm256_sll_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_sllv_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_i32x8(self, a: i32x8<Self>, shift: u32) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, shift: u32) -> i32x8<Avx2> {
+                _mm256_sra_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_srav_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shift_signed_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let left = self.shlv_i32x8(a, b.bitcast());
+        let right = self.shrv_i32x8(a, self.neg_i32x8(b).bitcast());
+        self.select_i32x8(self.simd_lt_i32x8(b, self.splat_i32x8(0)), right, left)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpgt_epi32(b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(_mm256_min_epi32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(_mm256_max_epi32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpgt_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                let lo = _mm256_unpacklo_epi32(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi32(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                let lo = _mm256_unpacklo_epi32(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi32(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                let t1 = _mm256_permutevar8x32_epi32(
+                    a.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                let t2 = _mm256_permutevar8x32_epi32(
+                    b.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                let t1 = _mm256_permutevar8x32_epi32(
+                    a.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                let t2 = _mm256_permutevar8x32_epi32(
+                    b.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> (i32x8<Self>, i32x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> (i32x8<Avx2>, i32x8<Avx2>) {
+                let lo = _mm256_unpacklo_epi32(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi32(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> (i32x8<Self>, i32x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> (i32x8<Avx2>, i32x8<Avx2>) {
+                let t1 = _mm256_permutevar8x32_epi32(
+                    a.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                let t2 = _mm256_permutevar8x32_epi32(
+                    b.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_i32x8(self, a: mask32x8<Self>, b: i32x8<Self>, c: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x8<Avx2>,
+                b: i32x8<Avx2>,
+                c: i32x8<Avx2>,
+            ) -> i32x8<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i32x8(
+        self,
+        a: mask32x8<Self>,
+        b: i32x8<Self>,
+        c: i32x8<Self>,
+    ) -> i32x8<Self> {
+        self.select_i32x8(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_min_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>, b: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_max_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        [
+            i32::saturating_add(a[0usize], b[0usize]),
+            i32::saturating_add(a[1usize], b[1usize]),
+            i32::saturating_add(a[2usize], b[2usize]),
+            i32::saturating_add(a[3usize], b[3usize]),
+            i32::saturating_add(a[4usize], b[4usize]),
+            i32::saturating_add(a[5usize], b[5usize]),
+            i32::saturating_add(a[6usize], b[6usize]),
+            i32::saturating_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        [
+            i32::saturating_sub(a[0usize], b[0usize]),
+            i32::saturating_sub(a[1usize], b[1usize]),
+            i32::saturating_sub(a[2usize], b[2usize]),
+            i32::saturating_sub(a[3usize], b[3usize]),
+            i32::saturating_sub(a[4usize], b[4usize]),
+            i32::saturating_sub(a[5usize], b[5usize]),
+            i32::saturating_sub(a[6usize], b[6usize]),
+            i32::saturating_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> i32x8<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    let bytes = _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm256_madd_epi16(
+                        _mm256_add_epi16(
+                            _mm256_and_si256(bytes, _mm256_set1_epi16(0xff)),
+                            _mm256_srli_epi16::<8>(bytes),
+                        ),
+                        _mm256_set1_epi16(1),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let a = self.or_i32x8(a, self.shr_i32x8(a, 1u32));
+        let a = self.or_i32x8(a, self.shr_i32x8(a, 2u32));
+        let a = self.or_i32x8(a, self.shr_i32x8(a, 4u32));
+        let a = self.or_i32x8(a, self.shr_i32x8(a, 8u32));
+        let a = self.or_i32x8(a, self.shr_i32x8(a, 16u32));
+        self.count_ones_i32x8(self.not_i32x8(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        self.count_ones_i32x8(
+            self.and_i32x8(self.not_i32x8(a), self.sub_i32x8(a, self.splat_i32x8(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
+        i32x16 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_i32x8(self, a: i32x8<Self>) -> (i32x4<Self>, i32x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> (i32x4<Avx2>, i32x4<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> i32x8<Avx2> {
+                _mm256_sub_epi32(_mm256_setzero_si256(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> i64x8<Avx2> {
+                let (a0, a1) = token.split_i32x8(a);
+                let high = _mm256_cvtepi32_epi64(a0.into()).simd_into(token);
+                let low = _mm256_cvtepi32_epi64(a1.into()).simd_into(token);
+                token.combine_i64x4(high, low)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x8(self, a: i32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i32x8<Avx2>) -> f32x8<Avx2> {
+                _mm256_cvtepi32_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u32x8(self, val: u32) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u32) -> u32x8<Avx2> {
+                _mm256_set1_epi32(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u32x8(self, val: [u32; 8usize]) -> u32x8<Self> {
+        u32x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u32x8(self, val: &[u32; 8usize]) -> u32x8<Self> {
+        u32x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u32x8(self, a: u32x8<Self>) -> [u32; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [u32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u32x8(self, a: &u32x8<Self>) -> &[u32; 8usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [u32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u32x8(self, a: &mut u32x8<Self>) -> &mut [u32; 8usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [u32; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u32x8(self, a: u32x8<Self>, dest: &mut [u32; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u32x8(self, a: u8x32<Self>) -> u32x8<Self> {
+        u32x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u32x8<const SHIFT: usize>(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_u32x8(b).val.0,
+            self.cvt_to_bytes_u32x8(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_u32x8(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u32x8<const SHIFT: usize>(
+        self,
+        a: u32x8<Self>,
+        b: u32x8<Self>,
+    ) -> u32x8<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_u32x8(b).val.0,
+            self.cvt_to_bytes_u32x8(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_u32x8(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u32x8<const OFFSET: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_u32x8::<0>(a, a),
+            1 => self.slide_u32x8::<1>(a, a),
+            2 => self.slide_u32x8::<2>(a, a),
+            3 => self.slide_u32x8::<3>(a, a),
+            4 => self.slide_u32x8::<4>(a, a),
+            5 => self.slide_u32x8::<5>(a, a),
+            6 => self.slide_u32x8::<6>(a, a),
+            7 => self.slide_u32x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u32x8<const OFFSET: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_u32x8::<8>(a, a),
+            1 => self.slide_u32x8::<7>(a, a),
+            2 => self.slide_u32x8::<6>(a, a),
+            3 => self.slide_u32x8::<5>(a, a),
+            4 => self.slide_u32x8::<4>(a, a),
+            5 => self.slide_u32x8::<3>(a, a),
+            6 => self.slide_u32x8::<2>(a, a),
+            7 => self.slide_u32x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u32x8<const OFFSET: usize>(
+        self,
+        a: u32x8<Self>,
+        padding: u32,
+    ) -> u32x8<Self> {
+        let padding = self.splat_u32x8(padding);
+        match OFFSET {
+            0 => self.slide_u32x8::<0>(a, padding),
+            1 => self.slide_u32x8::<1>(a, padding),
+            2 => self.slide_u32x8::<2>(a, padding),
+            3 => self.slide_u32x8::<3>(a, padding),
+            4 => self.slide_u32x8::<4>(a, padding),
+            5 => self.slide_u32x8::<5>(a, padding),
+            6 => self.slide_u32x8::<6>(a, padding),
+            7 => self.slide_u32x8::<7>(a, padding),
+            8 => self.slide_u32x8::<8>(a, padding),
+            _ => self.slide_u32x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u32x8<const OFFSET: usize>(
+        self,
+        a: u32x8<Self>,
+        padding: u32,
+    ) -> u32x8<Self> {
+        let padding = self.splat_u32x8(padding);
+        match OFFSET {
+            0 => self.slide_u32x8::<8>(padding, a),
+            1 => self.slide_u32x8::<7>(padding, a),
+            2 => self.slide_u32x8::<6>(padding, a),
+            3 => self.slide_u32x8::<5>(padding, a),
+            4 => self.slide_u32x8::<4>(padding, a),
+            5 => self.slide_u32x8::<3>(padding, a),
+            6 => self.slide_u32x8::<2>(padding, a),
+            7 => self.slide_u32x8::<1>(padding, a),
+            8 => self.slide_u32x8::<0>(padding, a),
+            _ => self.slide_u32x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u32x8<const LANE: usize>(self, a: u32x8<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x8(a);
+        match LANE {
+            0 => self.extract_u32x4::<0>(a0),
+            1 => self.extract_u32x4::<1>(a0),
+            2 => self.extract_u32x4::<2>(a0),
+            3 => self.extract_u32x4::<3>(a0),
+            4 => self.extract_u32x4::<0>(a1),
+            5 => self.extract_u32x4::<1>(a1),
+            6 => self.extract_u32x4::<2>(a1),
+            7 => self.extract_u32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u32x8<const LANE: usize>(self, a: u32x8<Self>, value: u32) -> u32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x8(a);
+        match LANE {
+            0 => self.combine_u32x4(self.insert_u32x4::<0>(a0, value), a1),
+            1 => self.combine_u32x4(self.insert_u32x4::<1>(a0, value), a1),
+            2 => self.combine_u32x4(self.insert_u32x4::<2>(a0, value), a1),
+            3 => self.combine_u32x4(self.insert_u32x4::<3>(a0, value), a1),
+            4 => self.combine_u32x4(a0, self.insert_u32x4::<0>(a1, value)),
+            5 => self.combine_u32x4(a0, self.insert_u32x4::<1>(a1, value)),
+            6 => self.combine_u32x4(a0, self.insert_u32x4::<2>(a1, value)),
+            7 => self.combine_u32x4(a0, self.insert_u32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x8(self, a: u32x8<Self>, index: usize) -> u32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u32x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u32x8(self, a: u32x8<Self>, indices: u8x32<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, indices: u8x32<Avx2>) -> u32x8<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_u32x8(a).val.0, indices.into());
+                token.cvt_from_bytes_u32x8(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_add_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_sub_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_mullo_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn and_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        self.xor_u32x8(a, self.splat_u32x8(!0))
+    }
+    #[inline(always)]
+    fn shl_u32x8(self, a: u32x8<Self>, shift: u32) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, shift: u32) -> u32x8<Avx2> {
+                _mm256_sll_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_sllv_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_u32x8(self, a: u32x8<Self>, shift: u32) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, shift: u32) -> u32x8<Avx2> {
+                _mm256_srl_epi32(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_srlv_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shift_signed_u32x8(self, a: u32x8<Self>, b: i32x8<Self>) -> u32x8<Self> {
+        let left = self.shlv_u32x8(a, b.bitcast());
+        let right = self.shrv_u32x8(a, self.neg_i32x8(b).bitcast());
+        self.select_u32x8(self.simd_lt_i32x8(b, self.splat_i32x8(0)), right, left)
+    }
+    #[inline(always)]
+    fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> mask32x8<Avx2> {
+                {
+                    let sign_bit = _mm256_set1_epi32(0x80000000u32.cast_signed());
+                    let lhs_signed = _mm256_xor_si256(b.into(), sign_bit);
+                    let rhs_signed = _mm256_xor_si256(a.into(), sign_bit);
+                    _mm256_cmpgt_epi32(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(_mm256_min_epu32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(_mm256_max_epu32(a.into(), b.into()), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> mask32x8<Avx2> {
+                {
+                    let sign_bit = _mm256_set1_epi32(0x80000000u32.cast_signed());
+                    let lhs_signed = _mm256_xor_si256(a.into(), sign_bit);
+                    let rhs_signed = _mm256_xor_si256(b.into(), sign_bit);
+                    _mm256_cmpgt_epi32(lhs_signed, rhs_signed)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                let lo = _mm256_unpacklo_epi32(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi32(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                let lo = _mm256_unpacklo_epi32(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi32(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                let t1 = _mm256_permutevar8x32_epi32(
+                    a.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                let t2 = _mm256_permutevar8x32_epi32(
+                    b.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                let t1 = _mm256_permutevar8x32_epi32(
+                    a.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                let t2 = _mm256_permutevar8x32_epi32(
+                    b.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> (u32x8<Self>, u32x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> (u32x8<Avx2>, u32x8<Avx2>) {
+                let lo = _mm256_unpacklo_epi32(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi32(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> (u32x8<Self>, u32x8<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> (u32x8<Avx2>, u32x8<Avx2>) {
+                let t1 = _mm256_permutevar8x32_epi32(
+                    a.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                let t2 = _mm256_permutevar8x32_epi32(
+                    b.into(),
+                    _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7),
+                );
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_u32x8(self, a: mask32x8<Self>, b: u32x8<Self>, c: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x8<Avx2>,
+                b: u32x8<Avx2>,
+                c: u32x8<Avx2>,
+            ) -> u32x8<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u32x8(
+        self,
+        a: mask32x8<Self>,
+        b: u32x8<Self>,
+        c: u32x8<Self>,
+    ) -> u32x8<Self> {
+        self.select_u32x8(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_min_epu32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>, b: u32x8<Avx2>) -> u32x8<Avx2> {
+                _mm256_max_epu32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        [
+            u32::saturating_add(a[0usize], b[0usize]),
+            u32::saturating_add(a[1usize], b[1usize]),
+            u32::saturating_add(a[2usize], b[2usize]),
+            u32::saturating_add(a[3usize], b[3usize]),
+            u32::saturating_add(a[4usize], b[4usize]),
+            u32::saturating_add(a[5usize], b[5usize]),
+            u32::saturating_add(a[6usize], b[6usize]),
+            u32::saturating_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        [
+            u32::saturating_sub(a[0usize], b[0usize]),
+            u32::saturating_sub(a[1usize], b[1usize]),
+            u32::saturating_sub(a[2usize], b[2usize]),
+            u32::saturating_sub(a[3usize], b[3usize]),
+            u32::saturating_sub(a[4usize], b[4usize]),
+            u32::saturating_sub(a[5usize], b[5usize]),
+            u32::saturating_sub(a[6usize], b[6usize]),
+            u32::saturating_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>) -> u32x8<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    let bytes = _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm256_madd_epi16(
+                        _mm256_add_epi16(
+                            _mm256_and_si256(bytes, _mm256_set1_epi16(0xff)),
+                            _mm256_srli_epi16::<8>(bytes),
+                        ),
+                        _mm256_set1_epi16(1),
+                    )
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let a = self.or_u32x8(a, self.shr_u32x8(a, 1u32));
+        let a = self.or_u32x8(a, self.shr_u32x8(a, 2u32));
+        let a = self.or_u32x8(a, self.shr_u32x8(a, 4u32));
+        let a = self.or_u32x8(a, self.shr_u32x8(a, 8u32));
+        let a = self.or_u32x8(a, self.shr_u32x8(a, 16u32));
+        self.count_ones_u32x8(self.not_u32x8(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        self.count_ones_u32x8(
+            self.and_u32x8(self.not_u32x8(a), self.sub_u32x8(a, self.splat_u32x8(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
+        u32x16 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_u32x8(self, a: u32x8<Self>) -> (u32x4<Self>, u32x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>) -> (u32x4<Avx2>, u32x4<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>) -> u64x8<Avx2> {
+                let (a0, a1) = token.split_u32x8(a);
+                let high = _mm256_cvtepu32_epi64(a0.into()).simd_into(token);
+                let low = _mm256_cvtepu32_epi64(a1.into()).simd_into(token);
+                token.combine_u64x4(high, low)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x8<Avx2>) -> f32x8<Avx2> {
+                let a = a.into();
+                let lo = _mm256_blend_epi16::<0xAA>(a, _mm256_set1_epi32(0x4B000000));
+                let hi = _mm256_blend_epi16::<0xAA>(
+                    _mm256_srli_epi32::<16>(a),
+                    _mm256_set1_epi32(0x53000000),
+                );
+                let fhi = _mm256_sub_ps(
+                    _mm256_castsi256_ps(hi),
+                    _mm256_set1_ps(f32::from_bits(0x53000080)),
+                );
+                let result = _mm256_add_ps(_mm256_castsi256_ps(lo), fhi);
+                result.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_mask32x8(self, val: bool) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask32x8<Avx2> {
+                let val: i32 = if val { !0 } else { 0 };
+                _mm256_set1_epi32(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask32x8(self, val: [i32; 8usize]) -> mask32x8<Self> {
+        mask32x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask32x8(self, a: mask32x8<Self>) -> [i32; 8usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i32; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self> {
+        i32x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask32x8<Avx2> {
+                {
+                    let bit_lanes = _mm256_set1_epi32(bits as i32);
+                    let bit_mask = _mm256_setr_epi32(1, 2, 4, 8, 16, 32, 64, 128);
+                    _mm256_cmpeq_epi32(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x8(self, a: mask32x8<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>) -> u64 {
+                _mm256_movemask_ps(_mm256_castsi256_ps(a.into())) as u32 as u64
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask32x8(self, a: &mut mask32x8<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 8usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        let mut lanes = self.as_array_mask32x8(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask32x8(lanes);
+    }
+    #[inline(always)]
+    fn and_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>, b: mask32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>, b: mask32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>, b: mask32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        self.xor_mask32x8(a, self.splat_mask32x8(true))
+    }
+    #[inline(always)]
+    fn select_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask32x8<Avx2>,
+                b: mask32x8<Avx2>,
+                c: mask32x8<Avx2>,
+            ) -> mask32x8<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        self.select_mask32x8(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>, b: mask32x8<Avx2>) -> mask32x8<Avx2> {
+                _mm256_cmpeq_epi32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask32x8(self, a: mask32x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>) -> bool {
+                _mm256_movemask_ps(_mm256_castsi256_ps(a.into())) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask32x8(self, a: mask32x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>) -> bool {
+                _mm256_movemask_ps(_mm256_castsi256_ps(a.into())) as u32 == 0b11111111
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask32x8(self, a: mask32x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>) -> bool {
+                _mm256_movemask_ps(_mm256_castsi256_ps(a.into())) as u32 != 0b11111111
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask32x8(self, a: mask32x8<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>) -> bool {
+                _mm256_movemask_ps(_mm256_castsi256_ps(a.into())) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x16<Self> {
+        mask32x16 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_mask32x8(self, a: mask32x8<Self>) -> (mask32x4<Self>, mask32x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask32x8<Avx2>) -> (mask32x4<Avx2>, mask32x4<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_f64x4(self, val: f64) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: f64) -> f64x4<Avx2> {
+                _mm256_set1_pd(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_f64x4(self, val: [f64; 4usize]) -> f64x4<Self> {
+        f64x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_f64x4(self, val: &[f64; 4usize]) -> f64x4<Self> {
+        f64x4 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_f64x4(self, a: f64x4<Self>) -> [f64; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m256d, [f64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_f64x4(self, a: &f64x4<Self>) -> &[f64; 4usize] {
+        crate::transmute::checked_cast_ref::<__m256d, [f64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_f64x4(self, a: &mut f64x4<Self>) -> &mut [f64; 4usize] {
+        crate::transmute::checked_cast_mut::<__m256d, [f64; 4usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_f64x4(self, a: f64x4<Self>, dest: &mut [f64; 4usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_f64x4(self, a: u8x32<Self>) -> f64x4<Self> {
+        f64x4 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_f64x4(self, a: f64x4<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_f64x4<const SHIFT: usize>(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_f64x4(b).val.0,
+            self.cvt_to_bytes_f64x4(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_f64x4(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_f64x4<const SHIFT: usize>(
+        self,
+        a: f64x4<Self>,
+        b: f64x4<Self>,
+    ) -> f64x4<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_f64x4(b).val.0,
+            self.cvt_to_bytes_f64x4(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_f64x4(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_f64x4<const OFFSET: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_f64x4::<0>(a, a),
+            1 => self.slide_f64x4::<1>(a, a),
+            2 => self.slide_f64x4::<2>(a, a),
+            3 => self.slide_f64x4::<3>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_f64x4<const OFFSET: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_f64x4::<4>(a, a),
+            1 => self.slide_f64x4::<3>(a, a),
+            2 => self.slide_f64x4::<2>(a, a),
+            3 => self.slide_f64x4::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_f64x4<const OFFSET: usize>(
+        self,
+        a: f64x4<Self>,
+        padding: f64,
+    ) -> f64x4<Self> {
+        let padding = self.splat_f64x4(padding);
+        match OFFSET {
+            0 => self.slide_f64x4::<0>(a, padding),
+            1 => self.slide_f64x4::<1>(a, padding),
+            2 => self.slide_f64x4::<2>(a, padding),
+            3 => self.slide_f64x4::<3>(a, padding),
+            4 => self.slide_f64x4::<4>(a, padding),
+            _ => self.slide_f64x4::<4>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_f64x4<const OFFSET: usize>(
+        self,
+        a: f64x4<Self>,
+        padding: f64,
+    ) -> f64x4<Self> {
+        let padding = self.splat_f64x4(padding);
+        match OFFSET {
+            0 => self.slide_f64x4::<4>(padding, a),
+            1 => self.slide_f64x4::<3>(padding, a),
+            2 => self.slide_f64x4::<2>(padding, a),
+            3 => self.slide_f64x4::<1>(padding, a),
+            4 => self.slide_f64x4::<0>(padding, a),
+            _ => self.slide_f64x4::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_f64x4<const LANE: usize>(self, a: f64x4<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x4(a);
+        match LANE {
+            0 => self.extract_f64x2::<0>(a0),
+            1 => self.extract_f64x2::<1>(a0),
+            2 => self.extract_f64x2::<0>(a1),
+            3 => self.extract_f64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f64x4<const LANE: usize>(self, a: f64x4<Self>, value: f64) -> f64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x4(a);
+        match LANE {
+            0 => self.combine_f64x2(self.insert_f64x2::<0>(a0, value), a1),
+            1 => self.combine_f64x2(self.insert_f64x2::<1>(a0, value), a1),
+            2 => self.combine_f64x2(a0, self.insert_f64x2::<0>(a1, value)),
+            3 => self.combine_f64x2(a0, self.insert_f64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x4(self, a: f64x4<Self>, index: usize) -> f64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_f64x4(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_f64x4(self, a: f64x4<Self>, indices: u8x32<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, indices: u8x32<Avx2>) -> f64x4<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_f64x4(a).val.0, indices.into());
+                token.cvt_from_bytes_f64x4(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn abs_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_andnot_pd(_mm256_set1_pd(-0.0), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_xor_pd(a.into(), _mm256_set1_pd(-0.0)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn sqrt_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_sqrt_pd(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn approximate_recip_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        self.div_f64x4(self.splat_f64x4(1.0), a)
+    }
+    #[inline(always)]
+    fn add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_add_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_sub_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_mul_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn div_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_div_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn copysign_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let mask = _mm256_set1_pd(-0.0);
+                _mm256_or_pd(
+                    _mm256_and_pd(mask, b.into()),
+                    _mm256_andnot_pd(mask, a.into()),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_eq_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_castpd_si256(_mm256_cmp_pd::<0i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_castpd_si256(_mm256_cmp_pd::<17i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_le_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_castpd_si256(_mm256_cmp_pd::<18i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_ge_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_castpd_si256(_mm256_cmp_pd::<29i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_gt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_castpd_si256(_mm256_cmp_pd::<30i32>(a.into(), b.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let lo = _mm256_unpacklo_pd(a.into(), b.into());
+                let hi = _mm256_unpackhi_pd(a.into(), b.into());
+                _mm256_permute2f128_pd::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let lo = _mm256_unpacklo_pd(a.into(), b.into());
+                let hi = _mm256_unpackhi_pd(a.into(), b.into());
+                _mm256_permute2f128_pd::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let t1 = _mm256_permute4x64_pd::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_pd::<0b11_01_10_00>(b.into());
+                _mm256_permute2f128_pd::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let t1 = _mm256_permute4x64_pd::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_pd::<0b11_01_10_00>(b.into());
+                _mm256_permute2f128_pd::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> (f64x4<Self>, f64x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> (f64x4<Avx2>, f64x4<Avx2>) {
+                let lo = _mm256_unpacklo_pd(a.into(), b.into());
+                let hi = _mm256_unpackhi_pd(a.into(), b.into());
+                (
+                    _mm256_permute2f128_pd::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2f128_pd::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> (f64x4<Self>, f64x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> (f64x4<Avx2>, f64x4<Avx2>) {
+                let t1 = _mm256_permute4x64_pd::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_pd::<0b11_01_10_00>(b.into());
+                (
+                    _mm256_permute2f128_pd::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2f128_pd::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_max_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_min_pd(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn max_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let intermediate = _mm256_max_pd(a.into(), b.into());
+                let b_is_nan = _mm256_cmp_pd::<3i32>(b.into(), b.into());
+                _mm256_blendv_pd(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn min_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>) -> f64x4<Avx2> {
+                let intermediate = _mm256_min_pd(a.into(), b.into());
+                let b_is_nan = _mm256_cmp_pd::<3i32>(b.into(), b.into());
+                _mm256_blendv_pd(intermediate, a.into(), b_is_nan).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>, c: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_fmadd_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>, c: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_fmsub_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>, c: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_fnmadd_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>, b: f64x4<Avx2>, c: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_fnmsub_pd(a.into(), b.into(), c.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        self.mul_add_f64x4(a, b, c)
+    }
+    #[inline(always)]
+    fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_round_pd::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn ceil_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_round_pd::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_round_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn fract_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        self.sub_f64x4(a, self.trunc_f64x4(a))
+    }
+    #[inline(always)]
+    fn trunc_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f64x4<Avx2> {
+                _mm256_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a.into())
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn select_f64x4(self, a: mask64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x4<Avx2>,
+                b: f64x4<Avx2>,
+                c: f64x4<Avx2>,
+            ) -> f64x4<Avx2> {
+                _mm256_blendv_pd(c.into(), b.into(), _mm256_castsi256_pd(a.into())).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_f64x4(
+        self,
+        a: mask64x4<Self>,
+        b: f64x4<Self>,
+        c: f64x4<Self>,
+    ) -> f64x4<Self> {
+        self.select_f64x4(a, b, c)
+    }
+    #[inline(always)]
+    fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self> {
+        f64x8 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_f64x4(self, a: f64x4<Self>) -> (f64x2<Self>, f64x2<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> (f64x2<Avx2>, f64x2<Avx2>) {
+                (
+                    _mm256_extractf128_pd::<0>(a.into()).simd_into(token),
+                    _mm256_extractf128_pd::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x4(self, a: f64x4<Self>) -> f32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f64x4<Avx2>) -> f32x8<Avx2> {
+                _mm256_castpd_ps(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_i64x4(self, val: i64) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: i64) -> i64x4<Avx2> {
+                _mm256_set1_epi64x(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_i64x4(self, val: [i64; 4usize]) -> i64x4<Self> {
+        i64x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i64x4(self, val: &[i64; 4usize]) -> i64x4<Self> {
+        i64x4 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i64x4(self, a: i64x4<Self>) -> [i64; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i64x4(self, a: &i64x4<Self>) -> &[i64; 4usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [i64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i64x4(self, a: &mut i64x4<Self>) -> &mut [i64; 4usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [i64; 4usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i64x4(self, a: i64x4<Self>, dest: &mut [i64; 4usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i64x4(self, a: u8x32<Self>) -> i64x4<Self> {
+        i64x4 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i64x4(self, a: i64x4<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i64x4<const SHIFT: usize>(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_i64x4(b).val.0,
+            self.cvt_to_bytes_i64x4(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_i64x4(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i64x4<const SHIFT: usize>(
+        self,
+        a: i64x4<Self>,
+        b: i64x4<Self>,
+    ) -> i64x4<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_i64x4(b).val.0,
+            self.cvt_to_bytes_i64x4(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_i64x4(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i64x4<const OFFSET: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_i64x4::<0>(a, a),
+            1 => self.slide_i64x4::<1>(a, a),
+            2 => self.slide_i64x4::<2>(a, a),
+            3 => self.slide_i64x4::<3>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i64x4<const OFFSET: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_i64x4::<4>(a, a),
+            1 => self.slide_i64x4::<3>(a, a),
+            2 => self.slide_i64x4::<2>(a, a),
+            3 => self.slide_i64x4::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i64x4<const OFFSET: usize>(
+        self,
+        a: i64x4<Self>,
+        padding: i64,
+    ) -> i64x4<Self> {
+        let padding = self.splat_i64x4(padding);
+        match OFFSET {
+            0 => self.slide_i64x4::<0>(a, padding),
+            1 => self.slide_i64x4::<1>(a, padding),
+            2 => self.slide_i64x4::<2>(a, padding),
+            3 => self.slide_i64x4::<3>(a, padding),
+            4 => self.slide_i64x4::<4>(a, padding),
+            _ => self.slide_i64x4::<4>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i64x4<const OFFSET: usize>(
+        self,
+        a: i64x4<Self>,
+        padding: i64,
+    ) -> i64x4<Self> {
+        let padding = self.splat_i64x4(padding);
+        match OFFSET {
+            0 => self.slide_i64x4::<4>(padding, a),
+            1 => self.slide_i64x4::<3>(padding, a),
+            2 => self.slide_i64x4::<2>(padding, a),
+            3 => self.slide_i64x4::<1>(padding, a),
+            4 => self.slide_i64x4::<0>(padding, a),
+            _ => self.slide_i64x4::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i64x4<const LANE: usize>(self, a: i64x4<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x4(a);
+        match LANE {
+            0 => self.extract_i64x2::<0>(a0),
+            1 => self.extract_i64x2::<1>(a0),
+            2 => self.extract_i64x2::<0>(a1),
+            3 => self.extract_i64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i64x4<const LANE: usize>(self, a: i64x4<Self>, value: i64) -> i64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x4(a);
+        match LANE {
+            0 => self.combine_i64x2(self.insert_i64x2::<0>(a0, value), a1),
+            1 => self.combine_i64x2(self.insert_i64x2::<1>(a0, value), a1),
+            2 => self.combine_i64x2(a0, self.insert_i64x2::<0>(a1, value)),
+            3 => self.combine_i64x2(a0, self.insert_i64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x4(self, a: i64x4<Self>, index: usize) -> i64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_i64x4(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i64x4(self, a: i64x4<Self>, indices: u8x32<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, indices: u8x32<Avx2>) -> i64x4<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_i64x4(a).val.0, indices.into());
+                token.cvt_from_bytes_i64x4(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_add_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_sub_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            i64::wrapping_mul(a[0usize], b[0usize]),
+            i64::wrapping_mul(a[1usize], b[1usize]),
+            i64::wrapping_mul(a[2usize], b[2usize]),
+            i64::wrapping_mul(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        self.xor_i64x4(a, self.splat_i64x4(!0))
+    }
+    #[inline(always)]
+    fn shl_i64x4(self, a: i64x4<Self>, shift: u32) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, shift: u32) -> i64x4<Avx2> {
+                _mm256_sll_epi64(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_sllv_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_i64x4(self, a: i64x4<Self>, shift: u32) -> i64x4<Self> {
+        [
+            i64::wrapping_shr(a[0usize], shift),
+            i64::wrapping_shr(a[1usize], shift),
+            i64::wrapping_shr(a[2usize], shift),
+            i64::wrapping_shr(a[3usize], shift),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shrv_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            i64::wrapping_shr(a[0usize], b[0usize] as u32),
+            i64::wrapping_shr(a[1usize], b[1usize] as u32),
+            i64::wrapping_shr(a[2usize], b[2usize] as u32),
+            i64::wrapping_shr(a[3usize], b[3usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_cmpeq_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        [
+            -(i64::lt(&a[0usize], &b[0usize]) as i64),
+            -(i64::lt(&a[1usize], &b[1usize]) as i64),
+            -(i64::lt(&a[2usize], &b[2usize]) as i64),
+            -(i64::lt(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        [
+            -(i64::le(&a[0usize], &b[0usize]) as i64),
+            -(i64::le(&a[1usize], &b[1usize]) as i64),
+            -(i64::le(&a[2usize], &b[2usize]) as i64),
+            -(i64::le(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        [
+            -(i64::ge(&a[0usize], &b[0usize]) as i64),
+            -(i64::ge(&a[1usize], &b[1usize]) as i64),
+            -(i64::ge(&a[2usize], &b[2usize]) as i64),
+            -(i64::ge(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        [
+            -(i64::gt(&a[0usize], &b[0usize]) as i64),
+            -(i64::gt(&a[1usize], &b[1usize]) as i64),
+            -(i64::gt(&a[2usize], &b[2usize]) as i64),
+            -(i64::gt(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                let lo = _mm256_unpacklo_epi64(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi64(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                let lo = _mm256_unpacklo_epi64(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi64(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> i64x4<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> (i64x4<Self>, i64x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> (i64x4<Avx2>, i64x4<Avx2>) {
+                let lo = _mm256_unpacklo_epi64(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi64(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> (i64x4<Self>, i64x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>, b: i64x4<Avx2>) -> (i64x4<Avx2>, i64x4<Avx2>) {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_i64x4(self, a: mask64x4<Self>, b: i64x4<Self>, c: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x4<Avx2>,
+                b: i64x4<Avx2>,
+                c: i64x4<Avx2>,
+            ) -> i64x4<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_i64x4(
+        self,
+        a: mask64x4<Self>,
+        b: i64x4<Self>,
+        c: i64x4<Self>,
+    ) -> i64x4<Self> {
+        self.select_i64x4(a, b, c)
+    }
+    #[inline(always)]
+    fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            i64::min(a[0usize], b[0usize]),
+            i64::min(a[1usize], b[1usize]),
+            i64::min(a[2usize], b[2usize]),
+            i64::min(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            i64::max(a[0usize], b[0usize]),
+            i64::max(a[1usize], b[1usize]),
+            i64::max(a[2usize], b[2usize]),
+            i64::max(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            i64::saturating_add(a[0usize], b[0usize]),
+            i64::saturating_add(a[1usize], b[1usize]),
+            i64::saturating_add(a[2usize], b[2usize]),
+            i64::saturating_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        [
+            i64::saturating_sub(a[0usize], b[0usize]),
+            i64::saturating_sub(a[1usize], b[1usize]),
+            i64::saturating_sub(a[2usize], b[2usize]),
+            i64::saturating_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>) -> i64x4<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    let bytes = _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm256_sad_epu8(bytes, _mm256_setzero_si256())
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let a = self.or_i64x4(a, self.shr_i64x4(a, 1u32));
+        let a = self.or_i64x4(a, self.shr_i64x4(a, 2u32));
+        let a = self.or_i64x4(a, self.shr_i64x4(a, 4u32));
+        let a = self.or_i64x4(a, self.shr_i64x4(a, 8u32));
+        let a = self.or_i64x4(a, self.shr_i64x4(a, 16u32));
+        let a = self.or_i64x4(a, self.shr_i64x4(a, 32u32));
+        self.count_ones_i64x4(self.not_i64x4(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        self.count_ones_i64x4(
+            self.and_i64x4(self.not_i64x4(a), self.sub_i64x4(a, self.splat_i64x4(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self> {
+        i64x8 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_i64x4(self, a: i64x4<Self>) -> (i64x2<Self>, i64x2<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>) -> (i64x2<Avx2>, i64x2<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn neg_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>) -> i64x4<Avx2> {
+                _mm256_sub_epi64(_mm256_setzero_si256(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x4(self, a: i64x4<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x4(self, a: i64x4<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i64x4<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_u64x4(self, val: u64) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: u64) -> u64x4<Avx2> {
+                _mm256_set1_epi64x(val.cast_signed()).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_u64x4(self, val: [u64; 4usize]) -> u64x4<Self> {
+        u64x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u64x4(self, val: &[u64; 4usize]) -> u64x4<Self> {
+        u64x4 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u64x4(self, a: u64x4<Self>) -> [u64; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [u64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u64x4(self, a: &u64x4<Self>) -> &[u64; 4usize] {
+        crate::transmute::checked_cast_ref::<__m256i, [u64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u64x4(self, a: &mut u64x4<Self>) -> &mut [u64; 4usize] {
+        crate::transmute::checked_cast_mut::<__m256i, [u64; 4usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u64x4(self, a: u64x4<Self>, dest: &mut [u64; 4usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u64x4(self, a: u8x32<Self>) -> u64x4<Self> {
+        u64x4 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u64x4(self, a: u64x4<Self>) -> u8x32<Self> {
+        u8x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u64x4<const SHIFT: usize>(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x1(
+            self,
+            self.cvt_to_bytes_u64x4(b).val.0,
+            self.cvt_to_bytes_u64x4(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_u64x4(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u64x4<const SHIFT: usize>(
+        self,
+        a: u64x4<Self>,
+        b: u64x4<Self>,
+    ) -> u64x4<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
+        let result = dyn_alignr_256(
+            self,
+            self.cvt_to_bytes_u64x4(b).val.0,
+            self.cvt_to_bytes_u64x4(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_u64x4(u8x32 {
+            val: crate::support::Aligned256(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u64x4<const OFFSET: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_u64x4::<0>(a, a),
+            1 => self.slide_u64x4::<1>(a, a),
+            2 => self.slide_u64x4::<2>(a, a),
+            3 => self.slide_u64x4::<3>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u64x4<const OFFSET: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        match OFFSET % 4 {
+            0 => self.slide_u64x4::<4>(a, a),
+            1 => self.slide_u64x4::<3>(a, a),
+            2 => self.slide_u64x4::<2>(a, a),
+            3 => self.slide_u64x4::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u64x4<const OFFSET: usize>(
+        self,
+        a: u64x4<Self>,
+        padding: u64,
+    ) -> u64x4<Self> {
+        let padding = self.splat_u64x4(padding);
+        match OFFSET {
+            0 => self.slide_u64x4::<0>(a, padding),
+            1 => self.slide_u64x4::<1>(a, padding),
+            2 => self.slide_u64x4::<2>(a, padding),
+            3 => self.slide_u64x4::<3>(a, padding),
+            4 => self.slide_u64x4::<4>(a, padding),
+            _ => self.slide_u64x4::<4>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u64x4<const OFFSET: usize>(
+        self,
+        a: u64x4<Self>,
+        padding: u64,
+    ) -> u64x4<Self> {
+        let padding = self.splat_u64x4(padding);
+        match OFFSET {
+            0 => self.slide_u64x4::<4>(padding, a),
+            1 => self.slide_u64x4::<3>(padding, a),
+            2 => self.slide_u64x4::<2>(padding, a),
+            3 => self.slide_u64x4::<1>(padding, a),
+            4 => self.slide_u64x4::<0>(padding, a),
+            _ => self.slide_u64x4::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u64x4<const LANE: usize>(self, a: u64x4<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x4(a);
+        match LANE {
+            0 => self.extract_u64x2::<0>(a0),
+            1 => self.extract_u64x2::<1>(a0),
+            2 => self.extract_u64x2::<0>(a1),
+            3 => self.extract_u64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u64x4<const LANE: usize>(self, a: u64x4<Self>, value: u64) -> u64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x4(a);
+        match LANE {
+            0 => self.combine_u64x2(self.insert_u64x2::<0>(a0, value), a1),
+            1 => self.combine_u64x2(self.insert_u64x2::<1>(a0, value), a1),
+            2 => self.combine_u64x2(a0, self.insert_u64x2::<0>(a1, value)),
+            3 => self.combine_u64x2(a0, self.insert_u64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x4(self, a: u64x4<Self>, index: usize) -> u64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_u64x4(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u64x4(self, a: u64x4<Self>, indices: u8x32<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, indices: u8x32<Avx2>) -> u64x4<Avx2> {
+                let result = _mm256_shuffle_epi8(token.cvt_to_bytes_u64x4(a).val.0, indices.into());
+                token.cvt_from_bytes_u64x4(u8x32 {
+                    val: crate::support::Aligned256(result),
+                    simd: token,
+                })
+            }
+        );
+        kernel(self, a, indices)
+    }
+    #[inline(always)]
+    fn add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_add_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_sub_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn mul_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        [
+            u64::wrapping_mul(a[0usize], b[0usize]),
+            u64::wrapping_mul(a[1usize], b[1usize]),
+            u64::wrapping_mul(a[2usize], b[2usize]),
+            u64::wrapping_mul(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        self.xor_u64x4(a, self.splat_u64x4(!0))
+    }
+    #[inline(always)]
+    fn shl_u64x4(self, a: u64x4<Self>, shift: u32) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, shift: u32) -> u64x4<Avx2> {
+                _mm256_sll_epi64(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shlv_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_sllv_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shr_u64x4(self, a: u64x4<Self>, shift: u32) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, shift: u32) -> u64x4<Avx2> {
+                _mm256_srl_epi64(a.into(), _mm_cvtsi32_si128(shift.cast_signed())).simd_into(token)
+            }
+        );
+        kernel(self, a, shift)
+    }
+    #[inline(always)]
+    fn shrv_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                _mm256_srlv_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn shift_signed_u64x4(self, a: u64x4<Self>, b: i64x4<Self>) -> u64x4<Self> {
+        let left = self.shlv_u64x4(a, b.bitcast());
+        let right = self.shrv_u64x4(a, self.neg_i64x4(b).bitcast());
+        self.select_u64x4(self.simd_lt_i64x4(b, self.splat_i64x4(0)), right, left)
+    }
+    #[inline(always)]
+    fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_cmpeq_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn simd_lt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        [
+            -(u64::lt(&a[0usize], &b[0usize]) as i64),
+            -(u64::lt(&a[1usize], &b[1usize]) as i64),
+            -(u64::lt(&a[2usize], &b[2usize]) as i64),
+            -(u64::lt(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        [
+            -(u64::le(&a[0usize], &b[0usize]) as i64),
+            -(u64::le(&a[1usize], &b[1usize]) as i64),
+            -(u64::le(&a[2usize], &b[2usize]) as i64),
+            -(u64::le(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        [
+            -(u64::ge(&a[0usize], &b[0usize]) as i64),
+            -(u64::ge(&a[1usize], &b[1usize]) as i64),
+            -(u64::ge(&a[2usize], &b[2usize]) as i64),
+            -(u64::ge(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        [
+            -(u64::gt(&a[0usize], &b[0usize]) as i64),
+            -(u64::gt(&a[1usize], &b[1usize]) as i64),
+            -(u64::gt(&a[2usize], &b[2usize]) as i64),
+            -(u64::gt(&a[3usize], &b[3usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                let lo = _mm256_unpacklo_epi64(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi64(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn zip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                let lo = _mm256_unpacklo_epi64(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi64(a.into(), b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(b.into());
+                _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn unzip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> u64x4<Avx2> {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(b.into());
+                _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn interleave_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> (u64x4<Self>, u64x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> (u64x4<Avx2>, u64x4<Avx2>) {
+                let lo = _mm256_unpacklo_epi64(a.into(), b.into());
+                let hi = _mm256_unpackhi_epi64(a.into(), b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(lo, hi).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(lo, hi).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn deinterleave_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> (u64x4<Self>, u64x4<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>, b: u64x4<Avx2>) -> (u64x4<Avx2>, u64x4<Avx2>) {
+                let t1 = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.into());
+                let t2 = _mm256_permute4x64_epi64::<0b11_01_10_00>(b.into());
+                (
+                    _mm256_permute2x128_si256::<0b0010_0000>(t1, t2).simd_into(token),
+                    _mm256_permute2x128_si256::<0b0011_0001>(t1, t2).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn select_u64x4(self, a: mask64x4<Self>, b: u64x4<Self>, c: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x4<Avx2>,
+                b: u64x4<Avx2>,
+                c: u64x4<Avx2>,
+            ) -> u64x4<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_u64x4(
+        self,
+        a: mask64x4<Self>,
+        b: u64x4<Self>,
+        c: u64x4<Self>,
+    ) -> u64x4<Self> {
+        self.select_u64x4(a, b, c)
+    }
+    #[inline(always)]
+    fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        [
+            u64::min(a[0usize], b[0usize]),
+            u64::min(a[1usize], b[1usize]),
+            u64::min(a[2usize], b[2usize]),
+            u64::min(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        [
+            u64::max(a[0usize], b[0usize]),
+            u64::max(a[1usize], b[1usize]),
+            u64::max(a[2usize], b[2usize]),
+            u64::max(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        [
+            u64::saturating_add(a[0usize], b[0usize]),
+            u64::saturating_add(a[1usize], b[1usize]),
+            u64::saturating_add(a[2usize], b[2usize]),
+            u64::saturating_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        [
+            u64::saturating_sub(a[0usize], b[0usize]),
+            u64::saturating_sub(a[1usize], b[1usize]),
+            u64::saturating_sub(a[2usize], b[2usize]),
+            u64::saturating_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>) -> u64x4<Avx2> {
+                let count = {
+                    let a = a.into();
+                    let table = _mm256_setr_epi8(
+                        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1,
+                        2, 2, 3, 2, 3, 3, 4,
+                    );
+                    let low_nibbles = _mm256_and_si256(a, _mm256_set1_epi8(0x0f));
+                    let high_nibbles =
+                        _mm256_and_si256(_mm256_srli_epi16::<4>(a), _mm256_set1_epi8(0x0f));
+                    let bytes = _mm256_add_epi8(
+                        _mm256_shuffle_epi8(table, low_nibbles),
+                        _mm256_shuffle_epi8(table, high_nibbles),
+                    );
+                    _mm256_sad_epu8(bytes, _mm256_setzero_si256())
+                };
+                count.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let a = self.or_u64x4(a, self.shr_u64x4(a, 1u32));
+        let a = self.or_u64x4(a, self.shr_u64x4(a, 2u32));
+        let a = self.or_u64x4(a, self.shr_u64x4(a, 4u32));
+        let a = self.or_u64x4(a, self.shr_u64x4(a, 8u32));
+        let a = self.or_u64x4(a, self.shr_u64x4(a, 16u32));
+        let a = self.or_u64x4(a, self.shr_u64x4(a, 32u32));
+        self.count_ones_u64x4(self.not_u64x4(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        self.count_ones_u64x4(
+            self.and_u64x4(self.not_u64x4(a), self.sub_u64x4(a, self.splat_u64x4(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self> {
+        u64x8 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_u64x4(self, a: u64x4<Self>) -> (u64x2<Self>, u64x2<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>) -> (u64x2<Avx2>, u64x2<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x4(self, a: u64x4<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>) -> u8x32<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x4(self, a: u64x4<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x4<Avx2>) -> u32x8<Avx2> {
+                __m256i::from(a).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn transpose_bits_8x8_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 7)),
+            self.splat_u64x4(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 7));
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 14)),
+            self.splat_u64x4(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 14));
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 28)),
+            self.splat_u64x4(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 28))
+    }
+    #[inline(always)]
+    fn splat_mask64x4(self, val: bool) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, val: bool) -> mask64x4<Avx2> {
+                let val: i64 = if val { !0 } else { 0 };
+                _mm256_set1_epi64x(val).simd_into(token)
+            }
+        );
+        kernel(self, val)
+    }
+    #[inline(always)]
+    fn load_array_mask64x4(self, val: [i64; 4usize]) -> mask64x4<Self> {
+        mask64x4 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask64x4(self, a: mask64x4<Self>) -> [i64; 4usize] {
+        crate::transmute::checked_transmute_copy::<__m256i, [i64; 4usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self> {
+        i64x4 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask64x4<Avx2> {
+                {
+                    let bit_lanes = _mm256_set1_epi64x(bits.cast_signed());
+                    let bit_mask = _mm256_set_epi64x(8, 4, 2, 1);
+                    _mm256_cmpeq_epi64(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x4(self, a: mask64x4<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>) -> u64 {
+                _mm256_movemask_pd(_mm256_castsi256_pd(a.into())) as u32 as u64
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask64x4(self, a: &mut mask64x4<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 4usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        let mut lanes = self.as_array_mask64x4(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask64x4(lanes);
+    }
+    #[inline(always)]
+    fn and_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>, b: mask64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_and_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn or_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>, b: mask64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_or_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn xor_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>, b: mask64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_xor_si256(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn not_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        self.xor_mask64x4(a, self.splat_mask64x4(true))
+    }
+    #[inline(always)]
+    fn select_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Avx2,
+                a: mask64x4<Avx2>,
+                b: mask64x4<Avx2>,
+                c: mask64x4<Avx2>,
+            ) -> mask64x4<Avx2> {
+                _mm256_blendv_epi8(c.into(), b.into(), a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn select_relaxed_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        self.select_mask64x4(a, b, c)
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>, b: mask64x4<Avx2>) -> mask64x4<Avx2> {
+                _mm256_cmpeq_epi64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn any_true_mask64x4(self, a: mask64x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>) -> bool {
+                _mm256_movemask_pd(_mm256_castsi256_pd(a.into())) as u32 != 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_true_mask64x4(self, a: mask64x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>) -> bool {
+                _mm256_movemask_pd(_mm256_castsi256_pd(a.into())) as u32 == 0b1111
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn any_false_mask64x4(self, a: mask64x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>) -> bool {
+                _mm256_movemask_pd(_mm256_castsi256_pd(a.into())) as u32 != 0b1111
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn all_false_mask64x4(self, a: mask64x4<Self>) -> bool {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>) -> bool {
+                _mm256_movemask_pd(_mm256_castsi256_pd(a.into())) as u32 == 0
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn combine_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x8<Self> {
+        mask64x8 {
+            val: crate::support::Aligned512([a.val.0, b.val.0]),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn split_mask64x4(self, a: mask64x4<Self>) -> (mask64x2<Self>, mask64x2<Self>) {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask64x4<Avx2>) -> (mask64x2<Avx2>, mask64x2<Avx2>) {
+                (
+                    _mm256_extracti128_si256::<0>(a.into()).simd_into(token),
+                    _mm256_extracti128_si256::<1>(a.into()).simd_into(token),
+                )
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn splat_f32x16(self, val: f32) -> f32x16<Self> {
+        let half = self.splat_f32x8(val);
+        self.combine_f32x8(half, half)
+    }
+    #[inline(always)]
+    fn load_array_f32x16(self, val: [f32; 16usize]) -> f32x16<Self> {
+        f32x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_f32x16(self, val: &[f32; 16usize]) -> f32x16<Self> {
+        f32x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_f32x16(self, a: f32x16<Self>) -> [f32; 16usize] {
+        crate::transmute::checked_transmute_copy::<[__m256; 2usize], [f32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_f32x16(self, a: &f32x16<Self>) -> &[f32; 16usize] {
+        crate::transmute::checked_cast_ref::<[__m256; 2usize], [f32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_f32x16(self, a: &mut f32x16<Self>) -> &mut [f32; 16usize] {
+        crate::transmute::checked_cast_mut::<[__m256; 2usize], [f32; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_f32x16(self, a: u8x64<Self>) -> f32x16<Self> {
+        f32x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_f32x16(self, a: f32x16<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_f32x16<const SHIFT: usize>(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_f32x16(b).val.0,
+            self.cvt_to_bytes_f32x16(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_f32x16(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_f32x16<const SHIFT: usize>(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.slide_within_blocks_f32x8::<SHIFT>(a0, b0),
+            self.slide_within_blocks_f32x8::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_f32x16<const OFFSET: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_f32x16::<0>(a, a),
+            1 => self.slide_f32x16::<1>(a, a),
+            2 => self.slide_f32x16::<2>(a, a),
+            3 => self.slide_f32x16::<3>(a, a),
+            4 => self.slide_f32x16::<4>(a, a),
+            5 => self.slide_f32x16::<5>(a, a),
+            6 => self.slide_f32x16::<6>(a, a),
+            7 => self.slide_f32x16::<7>(a, a),
+            8 => self.slide_f32x16::<8>(a, a),
+            9 => self.slide_f32x16::<9>(a, a),
+            10 => self.slide_f32x16::<10>(a, a),
+            11 => self.slide_f32x16::<11>(a, a),
+            12 => self.slide_f32x16::<12>(a, a),
+            13 => self.slide_f32x16::<13>(a, a),
+            14 => self.slide_f32x16::<14>(a, a),
+            15 => self.slide_f32x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_f32x16<const OFFSET: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_f32x16::<16>(a, a),
+            1 => self.slide_f32x16::<15>(a, a),
+            2 => self.slide_f32x16::<14>(a, a),
+            3 => self.slide_f32x16::<13>(a, a),
+            4 => self.slide_f32x16::<12>(a, a),
+            5 => self.slide_f32x16::<11>(a, a),
+            6 => self.slide_f32x16::<10>(a, a),
+            7 => self.slide_f32x16::<9>(a, a),
+            8 => self.slide_f32x16::<8>(a, a),
+            9 => self.slide_f32x16::<7>(a, a),
+            10 => self.slide_f32x16::<6>(a, a),
+            11 => self.slide_f32x16::<5>(a, a),
+            12 => self.slide_f32x16::<4>(a, a),
+            13 => self.slide_f32x16::<3>(a, a),
+            14 => self.slide_f32x16::<2>(a, a),
+            15 => self.slide_f32x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_f32x16<const OFFSET: usize>(
+        self,
+        a: f32x16<Self>,
+        padding: f32,
+    ) -> f32x16<Self> {
+        let padding = self.splat_f32x16(padding);
+        match OFFSET {
+            0 => self.slide_f32x16::<0>(a, padding),
+            1 => self.slide_f32x16::<1>(a, padding),
+            2 => self.slide_f32x16::<2>(a, padding),
+            3 => self.slide_f32x16::<3>(a, padding),
+            4 => self.slide_f32x16::<4>(a, padding),
+            5 => self.slide_f32x16::<5>(a, padding),
+            6 => self.slide_f32x16::<6>(a, padding),
+            7 => self.slide_f32x16::<7>(a, padding),
+            8 => self.slide_f32x16::<8>(a, padding),
+            9 => self.slide_f32x16::<9>(a, padding),
+            10 => self.slide_f32x16::<10>(a, padding),
+            11 => self.slide_f32x16::<11>(a, padding),
+            12 => self.slide_f32x16::<12>(a, padding),
+            13 => self.slide_f32x16::<13>(a, padding),
+            14 => self.slide_f32x16::<14>(a, padding),
+            15 => self.slide_f32x16::<15>(a, padding),
+            16 => self.slide_f32x16::<16>(a, padding),
+            _ => self.slide_f32x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_f32x16<const OFFSET: usize>(
+        self,
+        a: f32x16<Self>,
+        padding: f32,
+    ) -> f32x16<Self> {
+        let padding = self.splat_f32x16(padding);
+        match OFFSET {
+            0 => self.slide_f32x16::<16>(padding, a),
+            1 => self.slide_f32x16::<15>(padding, a),
+            2 => self.slide_f32x16::<14>(padding, a),
+            3 => self.slide_f32x16::<13>(padding, a),
+            4 => self.slide_f32x16::<12>(padding, a),
+            5 => self.slide_f32x16::<11>(padding, a),
+            6 => self.slide_f32x16::<10>(padding, a),
+            7 => self.slide_f32x16::<9>(padding, a),
+            8 => self.slide_f32x16::<8>(padding, a),
+            9 => self.slide_f32x16::<7>(padding, a),
+            10 => self.slide_f32x16::<6>(padding, a),
+            11 => self.slide_f32x16::<5>(padding, a),
+            12 => self.slide_f32x16::<4>(padding, a),
+            13 => self.slide_f32x16::<3>(padding, a),
+            14 => self.slide_f32x16::<2>(padding, a),
+            15 => self.slide_f32x16::<1>(padding, a),
+            16 => self.slide_f32x16::<0>(padding, a),
+            _ => self.slide_f32x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_f32x16<const LANE: usize>(self, a: f32x16<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x16(a);
+        match LANE {
+            0 => self.extract_f32x8::<0>(a0),
+            1 => self.extract_f32x8::<1>(a0),
+            2 => self.extract_f32x8::<2>(a0),
+            3 => self.extract_f32x8::<3>(a0),
+            4 => self.extract_f32x8::<4>(a0),
+            5 => self.extract_f32x8::<5>(a0),
+            6 => self.extract_f32x8::<6>(a0),
+            7 => self.extract_f32x8::<7>(a0),
+            8 => self.extract_f32x8::<0>(a1),
+            9 => self.extract_f32x8::<1>(a1),
+            10 => self.extract_f32x8::<2>(a1),
+            11 => self.extract_f32x8::<3>(a1),
+            12 => self.extract_f32x8::<4>(a1),
+            13 => self.extract_f32x8::<5>(a1),
+            14 => self.extract_f32x8::<6>(a1),
+            15 => self.extract_f32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f32x16<const LANE: usize>(self, a: f32x16<Self>, value: f32) -> f32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x16(a);
+        match LANE {
+            0 => self.combine_f32x8(self.insert_f32x8::<0>(a0, value), a1),
+            1 => self.combine_f32x8(self.insert_f32x8::<1>(a0, value), a1),
+            2 => self.combine_f32x8(self.insert_f32x8::<2>(a0, value), a1),
+            3 => self.combine_f32x8(self.insert_f32x8::<3>(a0, value), a1),
+            4 => self.combine_f32x8(self.insert_f32x8::<4>(a0, value), a1),
+            5 => self.combine_f32x8(self.insert_f32x8::<5>(a0, value), a1),
+            6 => self.combine_f32x8(self.insert_f32x8::<6>(a0, value), a1),
+            7 => self.combine_f32x8(self.insert_f32x8::<7>(a0, value), a1),
+            8 => self.combine_f32x8(a0, self.insert_f32x8::<0>(a1, value)),
+            9 => self.combine_f32x8(a0, self.insert_f32x8::<1>(a1, value)),
+            10 => self.combine_f32x8(a0, self.insert_f32x8::<2>(a1, value)),
+            11 => self.combine_f32x8(a0, self.insert_f32x8::<3>(a1, value)),
+            12 => self.combine_f32x8(a0, self.insert_f32x8::<4>(a1, value)),
+            13 => self.combine_f32x8(a0, self.insert_f32x8::<5>(a1, value)),
+            14 => self.combine_f32x8(a0, self.insert_f32x8::<6>(a1, value)),
+            15 => self.combine_f32x8(a0, self.insert_f32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x16(self, a: f32x16<Self>, index: usize) -> f32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_f32x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_f32x16(
+        self,
+        a: f32x16<Self>,
+        indices: u8x64<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_f32x8(
+            self.swizzle_dyn_within_blocks_f32x8(a0, indices0),
+            self.swizzle_dyn_within_blocks_f32x8(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn abs_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.abs_f32x8(a0), self.abs_f32x8(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.neg_f32x8(a0), self.neg_f32x8(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.sqrt_f32x8(a0), self.sqrt_f32x8(a1))
+    }
+    #[inline(always)]
+    fn approximate_recip_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(
+            self.approximate_recip_f32x8(a0),
+            self.approximate_recip_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.add_f32x8(a0, b0), self.add_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.sub_f32x8(a0, b0), self.sub_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.mul_f32x8(a0, b0), self.mul_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.div_f32x8(a0, b0), self.div_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.copysign_f32x8(a0, b0), self.copysign_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_eq_f32x8(a0, b0), self.simd_eq_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_lt_f32x8(a0, b0), self.simd_lt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_le_f32x8(a0, b0), self.simd_le_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ge_f32x8(a0, b0), self.simd_ge_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_gt_f32x8(a0, b0), self.simd_gt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, _) = self.split_f32x16(a);
+        let (b0, _) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a0, b0), self.zip_high_f32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (_, a1) = self.split_f32x16(a);
+        let (_, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a1, b1), self.zip_high_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.unzip_low_f32x8(a0, a1), self.unzip_low_f32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.unzip_high_f32x8(a0, a1), self.unzip_high_f32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> (f32x16<Self>, f32x16<Self>) {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let lo_lo = self.zip_low_f32x8(a0, b0);
+        let lo_hi = self.zip_high_f32x8(a0, b0);
+        let hi_lo = self.zip_low_f32x8(a1, b1);
+        let hi_hi = self.zip_high_f32x8(a1, b1);
+        (
+            self.combine_f32x8(lo_lo, lo_hi),
+            self.combine_f32x8(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> (f32x16<Self>, f32x16<Self>) {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let lo_even = self.unzip_low_f32x8(a0, a1);
+        let lo_odd = self.unzip_high_f32x8(a0, a1);
+        let hi_even = self.unzip_low_f32x8(b0, b1);
+        let hi_odd = self.unzip_high_f32x8(b0, b1);
+        (
+            self.combine_f32x8(lo_even, hi_even),
+            self.combine_f32x8(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn max_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.max_f32x8(a0, b0), self.max_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn min_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.min_f32x8(a0, b0), self.min_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.max_precise_f32x8(a0, b0),
+            self.max_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.min_precise_f32x8(a0, b0),
+            self.min_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_f32x8(a0, b0, c0),
+            self.mul_add_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_sub_f32x8(a0, b0, c0),
+            self.mul_sub_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.nmul_add_f32x8(a0, b0, c0),
+            self.nmul_add_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.nmul_sub_f32x8(a0, b0, c0),
+            self.nmul_sub_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_relaxed_f32x8(a0, b0, c0),
+            self.mul_add_relaxed_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.ceil_f32x8(a0), self.ceil_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(
+            self.round_ties_even_f32x8(a0),
+            self.round_ties_even_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.fract_f32x8(a0), self.fract_f32x8(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.trunc_f32x8(a0), self.trunc_f32x8(a1))
+    }
+    #[inline(always)]
+    fn select_f32x16(self, a: mask32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_f32x16(
+        self,
+        a: mask32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.select_relaxed_f32x8(a0, b0, c0),
+            self.select_relaxed_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
+        (
+            f32x8 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            f32x8 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x16(self, a: f32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_f32x8(a0),
+            self.reinterpret_f64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f32x8(a0),
+            self.reinterpret_i32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn load_interleaved_128_f32x16(self, src: &[f32; 16usize]) -> f32x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, src: &[f32; 16usize]) -> f32x16<Avx2> {
+                let (chunks, []) = src.as_chunks::<4usize>() else {
+                    unreachable!()
+                };
+                let v0: __m128 =
+                    crate::transmute::checked_transmute_copy::<[f32; 4usize], __m128>(&chunks[0]);
+                let v1: __m128 =
+                    crate::transmute::checked_transmute_copy::<[f32; 4usize], __m128>(&chunks[1]);
+                let v2: __m128 =
+                    crate::transmute::checked_transmute_copy::<[f32; 4usize], __m128>(&chunks[2]);
+                let v3: __m128 =
+                    crate::transmute::checked_transmute_copy::<[f32; 4usize], __m128>(&chunks[3]);
+                let tmp0 = _mm_unpacklo_ps(v0, v1);
+                let tmp1 = _mm_unpackhi_ps(v0, v1);
+                let tmp2 = _mm_unpacklo_ps(v2, v3);
+                let tmp3 = _mm_unpackhi_ps(v2, v3);
+                let out0 = _mm_castpd_ps(_mm_unpacklo_pd(_mm_castps_pd(tmp0), _mm_castps_pd(tmp2)));
+                let out1 = _mm_castpd_ps(_mm_unpackhi_pd(_mm_castps_pd(tmp0), _mm_castps_pd(tmp2)));
+                let out2 = _mm_castpd_ps(_mm_unpacklo_pd(_mm_castps_pd(tmp1), _mm_castps_pd(tmp3)));
+                let out3 = _mm_castpd_ps(_mm_unpackhi_pd(_mm_castps_pd(tmp1), _mm_castps_pd(tmp3)));
+                token.combine_f32x8(
+                    token.combine_f32x4(out0.simd_into(token), out1.simd_into(token)),
+                    token.combine_f32x4(out2.simd_into(token), out3.simd_into(token)),
+                )
+            }
+        );
+        kernel(self, src)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> () {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x16<Avx2>, dest: &mut [f32; 16usize]) -> () {
+                let (v01, v23) = token.split_f32x16(a);
+                let (v0, v1) = token.split_f32x8(v01);
+                let (v2, v3) = token.split_f32x8(v23);
+                let v0 = v0.into();
+                let v1 = v1.into();
+                let v2 = v2.into();
+                let v3 = v3.into();
+                let tmp0 = _mm_unpacklo_ps(v0, v1);
+                let tmp1 = _mm_unpackhi_ps(v0, v1);
+                let tmp2 = _mm_unpacklo_ps(v2, v3);
+                let tmp3 = _mm_unpackhi_ps(v2, v3);
+                let out0 = _mm_castpd_ps(_mm_unpacklo_pd(_mm_castps_pd(tmp0), _mm_castps_pd(tmp2)));
+                let out1 = _mm_castpd_ps(_mm_unpackhi_pd(_mm_castps_pd(tmp0), _mm_castps_pd(tmp2)));
+                let out2 = _mm_castpd_ps(_mm_unpacklo_pd(_mm_castps_pd(tmp1), _mm_castps_pd(tmp3)));
+                let out3 = _mm_castpd_ps(_mm_unpackhi_pd(_mm_castps_pd(tmp1), _mm_castps_pd(tmp3)));
+                let (chunks, []) = dest.as_chunks_mut::<4usize>() else {
+                    unreachable!()
+                };
+                crate::transmute::checked_transmute_store::<__m128, [f32; 4usize]>(
+                    out0,
+                    &mut chunks[0],
+                );
+                crate::transmute::checked_transmute_store::<__m128, [f32; 4usize]>(
+                    out1,
+                    &mut chunks[1],
+                );
+                crate::transmute::checked_transmute_store::<__m128, [f32; 4usize]>(
+                    out2,
+                    &mut chunks[2],
+                );
+                crate::transmute::checked_transmute_store::<__m128, [f32; 4usize]>(
+                    out3,
+                    &mut chunks[3],
+                );
+            }
+        );
+        kernel(self, a, dest);
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x16(self, a: f32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_f32x8(a0), self.reinterpret_u8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f32x8(a0),
+            self.reinterpret_u32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_f32x8(a0), self.cvt_u32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_precise_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(
+            self.cvt_u32_precise_f32x8(a0),
+            self.cvt_u32_precise_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_f32x8(a0), self.cvt_i32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_precise_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(
+            self.cvt_i32_precise_f32x8(a0),
+            self.cvt_i32_precise_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn pack_clamped_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: f32x16<Avx2>) -> u8x16<Avx2> {
+                let (a, b) = token.split_f32x16(a);
+                let max = _mm256_set1_ps(255.0);
+                let a = _mm256_cvtps_epi32(_mm256_min_ps(max, a.into()));
+                let b = _mm256_cvtps_epi32(_mm256_min_ps(max, b.into()));
+                let packed = _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packs_epi32(a, b));
+                _mm_packus_epi16(
+                    _mm256_castsi256_si128(packed),
+                    _mm256_extracti128_si256::<1>(packed),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn dot2_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot2_f32x8(a0, b0), self.dot2_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn dot3_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot3_f32x8(a0, b0), self.dot3_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn dot4_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot4_f32x8(a0, b0), self.dot4_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn splat_i8x64(self, val: i8) -> i8x64<Self> {
+        let half = self.splat_i8x32(val);
+        self.combine_i8x32(half, half)
+    }
+    #[inline(always)]
+    fn load_array_i8x64(self, val: [i8; 64usize]) -> i8x64<Self> {
+        i8x64 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i8x64(self, val: &[i8; 64usize]) -> i8x64<Self> {
+        i8x64 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i8x64(self, a: i8x64<Self>) -> [i8; 64usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i8; 64usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i8x64(self, a: &i8x64<Self>) -> &[i8; 64usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [i8; 64usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i8x64(self, a: &mut i8x64<Self>) -> &mut [i8; 64usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [i8; 64usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i8x64(self, a: i8x64<Self>, dest: &mut [i8; 64usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i8x64(self, a: u8x64<Self>) -> i8x64<Self> {
+        i8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i8x64<const SHIFT: usize>(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        if SHIFT >= 64usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_i8x64(b).val.0,
+            self.cvt_to_bytes_i8x64(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_i8x64(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i8x64<const SHIFT: usize>(
+        self,
+        a: i8x64<Self>,
+        b: i8x64<Self>,
+    ) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.slide_within_blocks_i8x32::<SHIFT>(a0, b0),
+            self.slide_within_blocks_i8x32::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i8x64<const OFFSET: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        match OFFSET % 64 {
+            0 => self.slide_i8x64::<0>(a, a),
+            1 => self.slide_i8x64::<1>(a, a),
+            2 => self.slide_i8x64::<2>(a, a),
+            3 => self.slide_i8x64::<3>(a, a),
+            4 => self.slide_i8x64::<4>(a, a),
+            5 => self.slide_i8x64::<5>(a, a),
+            6 => self.slide_i8x64::<6>(a, a),
+            7 => self.slide_i8x64::<7>(a, a),
+            8 => self.slide_i8x64::<8>(a, a),
+            9 => self.slide_i8x64::<9>(a, a),
+            10 => self.slide_i8x64::<10>(a, a),
+            11 => self.slide_i8x64::<11>(a, a),
+            12 => self.slide_i8x64::<12>(a, a),
+            13 => self.slide_i8x64::<13>(a, a),
+            14 => self.slide_i8x64::<14>(a, a),
+            15 => self.slide_i8x64::<15>(a, a),
+            16 => self.slide_i8x64::<16>(a, a),
+            17 => self.slide_i8x64::<17>(a, a),
+            18 => self.slide_i8x64::<18>(a, a),
+            19 => self.slide_i8x64::<19>(a, a),
+            20 => self.slide_i8x64::<20>(a, a),
+            21 => self.slide_i8x64::<21>(a, a),
+            22 => self.slide_i8x64::<22>(a, a),
+            23 => self.slide_i8x64::<23>(a, a),
+            24 => self.slide_i8x64::<24>(a, a),
+            25 => self.slide_i8x64::<25>(a, a),
+            26 => self.slide_i8x64::<26>(a, a),
+            27 => self.slide_i8x64::<27>(a, a),
+            28 => self.slide_i8x64::<28>(a, a),
+            29 => self.slide_i8x64::<29>(a, a),
+            30 => self.slide_i8x64::<30>(a, a),
+            31 => self.slide_i8x64::<31>(a, a),
+            32 => self.slide_i8x64::<32>(a, a),
+            33 => self.slide_i8x64::<33>(a, a),
+            34 => self.slide_i8x64::<34>(a, a),
+            35 => self.slide_i8x64::<35>(a, a),
+            36 => self.slide_i8x64::<36>(a, a),
+            37 => self.slide_i8x64::<37>(a, a),
+            38 => self.slide_i8x64::<38>(a, a),
+            39 => self.slide_i8x64::<39>(a, a),
+            40 => self.slide_i8x64::<40>(a, a),
+            41 => self.slide_i8x64::<41>(a, a),
+            42 => self.slide_i8x64::<42>(a, a),
+            43 => self.slide_i8x64::<43>(a, a),
+            44 => self.slide_i8x64::<44>(a, a),
+            45 => self.slide_i8x64::<45>(a, a),
+            46 => self.slide_i8x64::<46>(a, a),
+            47 => self.slide_i8x64::<47>(a, a),
+            48 => self.slide_i8x64::<48>(a, a),
+            49 => self.slide_i8x64::<49>(a, a),
+            50 => self.slide_i8x64::<50>(a, a),
+            51 => self.slide_i8x64::<51>(a, a),
+            52 => self.slide_i8x64::<52>(a, a),
+            53 => self.slide_i8x64::<53>(a, a),
+            54 => self.slide_i8x64::<54>(a, a),
+            55 => self.slide_i8x64::<55>(a, a),
+            56 => self.slide_i8x64::<56>(a, a),
+            57 => self.slide_i8x64::<57>(a, a),
+            58 => self.slide_i8x64::<58>(a, a),
+            59 => self.slide_i8x64::<59>(a, a),
+            60 => self.slide_i8x64::<60>(a, a),
+            61 => self.slide_i8x64::<61>(a, a),
+            62 => self.slide_i8x64::<62>(a, a),
+            63 => self.slide_i8x64::<63>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i8x64<const OFFSET: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        match OFFSET % 64 {
+            0 => self.slide_i8x64::<64>(a, a),
+            1 => self.slide_i8x64::<63>(a, a),
+            2 => self.slide_i8x64::<62>(a, a),
+            3 => self.slide_i8x64::<61>(a, a),
+            4 => self.slide_i8x64::<60>(a, a),
+            5 => self.slide_i8x64::<59>(a, a),
+            6 => self.slide_i8x64::<58>(a, a),
+            7 => self.slide_i8x64::<57>(a, a),
+            8 => self.slide_i8x64::<56>(a, a),
+            9 => self.slide_i8x64::<55>(a, a),
+            10 => self.slide_i8x64::<54>(a, a),
+            11 => self.slide_i8x64::<53>(a, a),
+            12 => self.slide_i8x64::<52>(a, a),
+            13 => self.slide_i8x64::<51>(a, a),
+            14 => self.slide_i8x64::<50>(a, a),
+            15 => self.slide_i8x64::<49>(a, a),
+            16 => self.slide_i8x64::<48>(a, a),
+            17 => self.slide_i8x64::<47>(a, a),
+            18 => self.slide_i8x64::<46>(a, a),
+            19 => self.slide_i8x64::<45>(a, a),
+            20 => self.slide_i8x64::<44>(a, a),
+            21 => self.slide_i8x64::<43>(a, a),
+            22 => self.slide_i8x64::<42>(a, a),
+            23 => self.slide_i8x64::<41>(a, a),
+            24 => self.slide_i8x64::<40>(a, a),
+            25 => self.slide_i8x64::<39>(a, a),
+            26 => self.slide_i8x64::<38>(a, a),
+            27 => self.slide_i8x64::<37>(a, a),
+            28 => self.slide_i8x64::<36>(a, a),
+            29 => self.slide_i8x64::<35>(a, a),
+            30 => self.slide_i8x64::<34>(a, a),
+            31 => self.slide_i8x64::<33>(a, a),
+            32 => self.slide_i8x64::<32>(a, a),
+            33 => self.slide_i8x64::<31>(a, a),
+            34 => self.slide_i8x64::<30>(a, a),
+            35 => self.slide_i8x64::<29>(a, a),
+            36 => self.slide_i8x64::<28>(a, a),
+            37 => self.slide_i8x64::<27>(a, a),
+            38 => self.slide_i8x64::<26>(a, a),
+            39 => self.slide_i8x64::<25>(a, a),
+            40 => self.slide_i8x64::<24>(a, a),
+            41 => self.slide_i8x64::<23>(a, a),
+            42 => self.slide_i8x64::<22>(a, a),
+            43 => self.slide_i8x64::<21>(a, a),
+            44 => self.slide_i8x64::<20>(a, a),
+            45 => self.slide_i8x64::<19>(a, a),
+            46 => self.slide_i8x64::<18>(a, a),
+            47 => self.slide_i8x64::<17>(a, a),
+            48 => self.slide_i8x64::<16>(a, a),
+            49 => self.slide_i8x64::<15>(a, a),
+            50 => self.slide_i8x64::<14>(a, a),
+            51 => self.slide_i8x64::<13>(a, a),
+            52 => self.slide_i8x64::<12>(a, a),
+            53 => self.slide_i8x64::<11>(a, a),
+            54 => self.slide_i8x64::<10>(a, a),
+            55 => self.slide_i8x64::<9>(a, a),
+            56 => self.slide_i8x64::<8>(a, a),
+            57 => self.slide_i8x64::<7>(a, a),
+            58 => self.slide_i8x64::<6>(a, a),
+            59 => self.slide_i8x64::<5>(a, a),
+            60 => self.slide_i8x64::<4>(a, a),
+            61 => self.slide_i8x64::<3>(a, a),
+            62 => self.slide_i8x64::<2>(a, a),
+            63 => self.slide_i8x64::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i8x64<const OFFSET: usize>(
+        self,
+        a: i8x64<Self>,
+        padding: i8,
+    ) -> i8x64<Self> {
+        let padding = self.splat_i8x64(padding);
+        match OFFSET {
+            0 => self.slide_i8x64::<0>(a, padding),
+            1 => self.slide_i8x64::<1>(a, padding),
+            2 => self.slide_i8x64::<2>(a, padding),
+            3 => self.slide_i8x64::<3>(a, padding),
+            4 => self.slide_i8x64::<4>(a, padding),
+            5 => self.slide_i8x64::<5>(a, padding),
+            6 => self.slide_i8x64::<6>(a, padding),
+            7 => self.slide_i8x64::<7>(a, padding),
+            8 => self.slide_i8x64::<8>(a, padding),
+            9 => self.slide_i8x64::<9>(a, padding),
+            10 => self.slide_i8x64::<10>(a, padding),
+            11 => self.slide_i8x64::<11>(a, padding),
+            12 => self.slide_i8x64::<12>(a, padding),
+            13 => self.slide_i8x64::<13>(a, padding),
+            14 => self.slide_i8x64::<14>(a, padding),
+            15 => self.slide_i8x64::<15>(a, padding),
+            16 => self.slide_i8x64::<16>(a, padding),
+            17 => self.slide_i8x64::<17>(a, padding),
+            18 => self.slide_i8x64::<18>(a, padding),
+            19 => self.slide_i8x64::<19>(a, padding),
+            20 => self.slide_i8x64::<20>(a, padding),
+            21 => self.slide_i8x64::<21>(a, padding),
+            22 => self.slide_i8x64::<22>(a, padding),
+            23 => self.slide_i8x64::<23>(a, padding),
+            24 => self.slide_i8x64::<24>(a, padding),
+            25 => self.slide_i8x64::<25>(a, padding),
+            26 => self.slide_i8x64::<26>(a, padding),
+            27 => self.slide_i8x64::<27>(a, padding),
+            28 => self.slide_i8x64::<28>(a, padding),
+            29 => self.slide_i8x64::<29>(a, padding),
+            30 => self.slide_i8x64::<30>(a, padding),
+            31 => self.slide_i8x64::<31>(a, padding),
+            32 => self.slide_i8x64::<32>(a, padding),
+            33 => self.slide_i8x64::<33>(a, padding),
+            34 => self.slide_i8x64::<34>(a, padding),
+            35 => self.slide_i8x64::<35>(a, padding),
+            36 => self.slide_i8x64::<36>(a, padding),
+            37 => self.slide_i8x64::<37>(a, padding),
+            38 => self.slide_i8x64::<38>(a, padding),
+            39 => self.slide_i8x64::<39>(a, padding),
+            40 => self.slide_i8x64::<40>(a, padding),
+            41 => self.slide_i8x64::<41>(a, padding),
+            42 => self.slide_i8x64::<42>(a, padding),
+            43 => self.slide_i8x64::<43>(a, padding),
+            44 => self.slide_i8x64::<44>(a, padding),
+            45 => self.slide_i8x64::<45>(a, padding),
+            46 => self.slide_i8x64::<46>(a, padding),
+            47 => self.slide_i8x64::<47>(a, padding),
+            48 => self.slide_i8x64::<48>(a, padding),
+            49 => self.slide_i8x64::<49>(a, padding),
+            50 => self.slide_i8x64::<50>(a, padding),
+            51 => self.slide_i8x64::<51>(a, padding),
+            52 => self.slide_i8x64::<52>(a, padding),
+            53 => self.slide_i8x64::<53>(a, padding),
+            54 => self.slide_i8x64::<54>(a, padding),
+            55 => self.slide_i8x64::<55>(a, padding),
+            56 => self.slide_i8x64::<56>(a, padding),
+            57 => self.slide_i8x64::<57>(a, padding),
+            58 => self.slide_i8x64::<58>(a, padding),
+            59 => self.slide_i8x64::<59>(a, padding),
+            60 => self.slide_i8x64::<60>(a, padding),
+            61 => self.slide_i8x64::<61>(a, padding),
+            62 => self.slide_i8x64::<62>(a, padding),
+            63 => self.slide_i8x64::<63>(a, padding),
+            64 => self.slide_i8x64::<64>(a, padding),
+            _ => self.slide_i8x64::<64>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i8x64<const OFFSET: usize>(
+        self,
+        a: i8x64<Self>,
+        padding: i8,
+    ) -> i8x64<Self> {
+        let padding = self.splat_i8x64(padding);
+        match OFFSET {
+            0 => self.slide_i8x64::<64>(padding, a),
+            1 => self.slide_i8x64::<63>(padding, a),
+            2 => self.slide_i8x64::<62>(padding, a),
+            3 => self.slide_i8x64::<61>(padding, a),
+            4 => self.slide_i8x64::<60>(padding, a),
+            5 => self.slide_i8x64::<59>(padding, a),
+            6 => self.slide_i8x64::<58>(padding, a),
+            7 => self.slide_i8x64::<57>(padding, a),
+            8 => self.slide_i8x64::<56>(padding, a),
+            9 => self.slide_i8x64::<55>(padding, a),
+            10 => self.slide_i8x64::<54>(padding, a),
+            11 => self.slide_i8x64::<53>(padding, a),
+            12 => self.slide_i8x64::<52>(padding, a),
+            13 => self.slide_i8x64::<51>(padding, a),
+            14 => self.slide_i8x64::<50>(padding, a),
+            15 => self.slide_i8x64::<49>(padding, a),
+            16 => self.slide_i8x64::<48>(padding, a),
+            17 => self.slide_i8x64::<47>(padding, a),
+            18 => self.slide_i8x64::<46>(padding, a),
+            19 => self.slide_i8x64::<45>(padding, a),
+            20 => self.slide_i8x64::<44>(padding, a),
+            21 => self.slide_i8x64::<43>(padding, a),
+            22 => self.slide_i8x64::<42>(padding, a),
+            23 => self.slide_i8x64::<41>(padding, a),
+            24 => self.slide_i8x64::<40>(padding, a),
+            25 => self.slide_i8x64::<39>(padding, a),
+            26 => self.slide_i8x64::<38>(padding, a),
+            27 => self.slide_i8x64::<37>(padding, a),
+            28 => self.slide_i8x64::<36>(padding, a),
+            29 => self.slide_i8x64::<35>(padding, a),
+            30 => self.slide_i8x64::<34>(padding, a),
+            31 => self.slide_i8x64::<33>(padding, a),
+            32 => self.slide_i8x64::<32>(padding, a),
+            33 => self.slide_i8x64::<31>(padding, a),
+            34 => self.slide_i8x64::<30>(padding, a),
+            35 => self.slide_i8x64::<29>(padding, a),
+            36 => self.slide_i8x64::<28>(padding, a),
+            37 => self.slide_i8x64::<27>(padding, a),
+            38 => self.slide_i8x64::<26>(padding, a),
+            39 => self.slide_i8x64::<25>(padding, a),
+            40 => self.slide_i8x64::<24>(padding, a),
+            41 => self.slide_i8x64::<23>(padding, a),
+            42 => self.slide_i8x64::<22>(padding, a),
+            43 => self.slide_i8x64::<21>(padding, a),
+            44 => self.slide_i8x64::<20>(padding, a),
+            45 => self.slide_i8x64::<19>(padding, a),
+            46 => self.slide_i8x64::<18>(padding, a),
+            47 => self.slide_i8x64::<17>(padding, a),
+            48 => self.slide_i8x64::<16>(padding, a),
+            49 => self.slide_i8x64::<15>(padding, a),
+            50 => self.slide_i8x64::<14>(padding, a),
+            51 => self.slide_i8x64::<13>(padding, a),
+            52 => self.slide_i8x64::<12>(padding, a),
+            53 => self.slide_i8x64::<11>(padding, a),
+            54 => self.slide_i8x64::<10>(padding, a),
+            55 => self.slide_i8x64::<9>(padding, a),
+            56 => self.slide_i8x64::<8>(padding, a),
+            57 => self.slide_i8x64::<7>(padding, a),
+            58 => self.slide_i8x64::<6>(padding, a),
+            59 => self.slide_i8x64::<5>(padding, a),
+            60 => self.slide_i8x64::<4>(padding, a),
+            61 => self.slide_i8x64::<3>(padding, a),
+            62 => self.slide_i8x64::<2>(padding, a),
+            63 => self.slide_i8x64::<1>(padding, a),
+            64 => self.slide_i8x64::<0>(padding, a),
+            _ => self.slide_i8x64::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i8x64<const LANE: usize>(self, a: i8x64<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x64(a);
+        match LANE {
+            0 => self.extract_i8x32::<0>(a0),
+            1 => self.extract_i8x32::<1>(a0),
+            2 => self.extract_i8x32::<2>(a0),
+            3 => self.extract_i8x32::<3>(a0),
+            4 => self.extract_i8x32::<4>(a0),
+            5 => self.extract_i8x32::<5>(a0),
+            6 => self.extract_i8x32::<6>(a0),
+            7 => self.extract_i8x32::<7>(a0),
+            8 => self.extract_i8x32::<8>(a0),
+            9 => self.extract_i8x32::<9>(a0),
+            10 => self.extract_i8x32::<10>(a0),
+            11 => self.extract_i8x32::<11>(a0),
+            12 => self.extract_i8x32::<12>(a0),
+            13 => self.extract_i8x32::<13>(a0),
+            14 => self.extract_i8x32::<14>(a0),
+            15 => self.extract_i8x32::<15>(a0),
+            16 => self.extract_i8x32::<16>(a0),
+            17 => self.extract_i8x32::<17>(a0),
+            18 => self.extract_i8x32::<18>(a0),
+            19 => self.extract_i8x32::<19>(a0),
+            20 => self.extract_i8x32::<20>(a0),
+            21 => self.extract_i8x32::<21>(a0),
+            22 => self.extract_i8x32::<22>(a0),
+            23 => self.extract_i8x32::<23>(a0),
+            24 => self.extract_i8x32::<24>(a0),
+            25 => self.extract_i8x32::<25>(a0),
+            26 => self.extract_i8x32::<26>(a0),
+            27 => self.extract_i8x32::<27>(a0),
+            28 => self.extract_i8x32::<28>(a0),
+            29 => self.extract_i8x32::<29>(a0),
+            30 => self.extract_i8x32::<30>(a0),
+            31 => self.extract_i8x32::<31>(a0),
+            32 => self.extract_i8x32::<0>(a1),
+            33 => self.extract_i8x32::<1>(a1),
+            34 => self.extract_i8x32::<2>(a1),
+            35 => self.extract_i8x32::<3>(a1),
+            36 => self.extract_i8x32::<4>(a1),
+            37 => self.extract_i8x32::<5>(a1),
+            38 => self.extract_i8x32::<6>(a1),
+            39 => self.extract_i8x32::<7>(a1),
+            40 => self.extract_i8x32::<8>(a1),
+            41 => self.extract_i8x32::<9>(a1),
+            42 => self.extract_i8x32::<10>(a1),
+            43 => self.extract_i8x32::<11>(a1),
+            44 => self.extract_i8x32::<12>(a1),
+            45 => self.extract_i8x32::<13>(a1),
+            46 => self.extract_i8x32::<14>(a1),
+            47 => self.extract_i8x32::<15>(a1),
+            48 => self.extract_i8x32::<16>(a1),
+            49 => self.extract_i8x32::<17>(a1),
+            50 => self.extract_i8x32::<18>(a1),
+            51 => self.extract_i8x32::<19>(a1),
+            52 => self.extract_i8x32::<20>(a1),
+            53 => self.extract_i8x32::<21>(a1),
+            54 => self.extract_i8x32::<22>(a1),
+            55 => self.extract_i8x32::<23>(a1),
+            56 => self.extract_i8x32::<24>(a1),
+            57 => self.extract_i8x32::<25>(a1),
+            58 => self.extract_i8x32::<26>(a1),
+            59 => self.extract_i8x32::<27>(a1),
+            60 => self.extract_i8x32::<28>(a1),
+            61 => self.extract_i8x32::<29>(a1),
+            62 => self.extract_i8x32::<30>(a1),
+            63 => self.extract_i8x32::<31>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i8x64<const LANE: usize>(self, a: i8x64<Self>, value: i8) -> i8x64<Self> {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x64(a);
+        match LANE {
+            0 => self.combine_i8x32(self.insert_i8x32::<0>(a0, value), a1),
+            1 => self.combine_i8x32(self.insert_i8x32::<1>(a0, value), a1),
+            2 => self.combine_i8x32(self.insert_i8x32::<2>(a0, value), a1),
+            3 => self.combine_i8x32(self.insert_i8x32::<3>(a0, value), a1),
+            4 => self.combine_i8x32(self.insert_i8x32::<4>(a0, value), a1),
+            5 => self.combine_i8x32(self.insert_i8x32::<5>(a0, value), a1),
+            6 => self.combine_i8x32(self.insert_i8x32::<6>(a0, value), a1),
+            7 => self.combine_i8x32(self.insert_i8x32::<7>(a0, value), a1),
+            8 => self.combine_i8x32(self.insert_i8x32::<8>(a0, value), a1),
+            9 => self.combine_i8x32(self.insert_i8x32::<9>(a0, value), a1),
+            10 => self.combine_i8x32(self.insert_i8x32::<10>(a0, value), a1),
+            11 => self.combine_i8x32(self.insert_i8x32::<11>(a0, value), a1),
+            12 => self.combine_i8x32(self.insert_i8x32::<12>(a0, value), a1),
+            13 => self.combine_i8x32(self.insert_i8x32::<13>(a0, value), a1),
+            14 => self.combine_i8x32(self.insert_i8x32::<14>(a0, value), a1),
+            15 => self.combine_i8x32(self.insert_i8x32::<15>(a0, value), a1),
+            16 => self.combine_i8x32(self.insert_i8x32::<16>(a0, value), a1),
+            17 => self.combine_i8x32(self.insert_i8x32::<17>(a0, value), a1),
+            18 => self.combine_i8x32(self.insert_i8x32::<18>(a0, value), a1),
+            19 => self.combine_i8x32(self.insert_i8x32::<19>(a0, value), a1),
+            20 => self.combine_i8x32(self.insert_i8x32::<20>(a0, value), a1),
+            21 => self.combine_i8x32(self.insert_i8x32::<21>(a0, value), a1),
+            22 => self.combine_i8x32(self.insert_i8x32::<22>(a0, value), a1),
+            23 => self.combine_i8x32(self.insert_i8x32::<23>(a0, value), a1),
+            24 => self.combine_i8x32(self.insert_i8x32::<24>(a0, value), a1),
+            25 => self.combine_i8x32(self.insert_i8x32::<25>(a0, value), a1),
+            26 => self.combine_i8x32(self.insert_i8x32::<26>(a0, value), a1),
+            27 => self.combine_i8x32(self.insert_i8x32::<27>(a0, value), a1),
+            28 => self.combine_i8x32(self.insert_i8x32::<28>(a0, value), a1),
+            29 => self.combine_i8x32(self.insert_i8x32::<29>(a0, value), a1),
+            30 => self.combine_i8x32(self.insert_i8x32::<30>(a0, value), a1),
+            31 => self.combine_i8x32(self.insert_i8x32::<31>(a0, value), a1),
+            32 => self.combine_i8x32(a0, self.insert_i8x32::<0>(a1, value)),
+            33 => self.combine_i8x32(a0, self.insert_i8x32::<1>(a1, value)),
+            34 => self.combine_i8x32(a0, self.insert_i8x32::<2>(a1, value)),
+            35 => self.combine_i8x32(a0, self.insert_i8x32::<3>(a1, value)),
+            36 => self.combine_i8x32(a0, self.insert_i8x32::<4>(a1, value)),
+            37 => self.combine_i8x32(a0, self.insert_i8x32::<5>(a1, value)),
+            38 => self.combine_i8x32(a0, self.insert_i8x32::<6>(a1, value)),
+            39 => self.combine_i8x32(a0, self.insert_i8x32::<7>(a1, value)),
+            40 => self.combine_i8x32(a0, self.insert_i8x32::<8>(a1, value)),
+            41 => self.combine_i8x32(a0, self.insert_i8x32::<9>(a1, value)),
+            42 => self.combine_i8x32(a0, self.insert_i8x32::<10>(a1, value)),
+            43 => self.combine_i8x32(a0, self.insert_i8x32::<11>(a1, value)),
+            44 => self.combine_i8x32(a0, self.insert_i8x32::<12>(a1, value)),
+            45 => self.combine_i8x32(a0, self.insert_i8x32::<13>(a1, value)),
+            46 => self.combine_i8x32(a0, self.insert_i8x32::<14>(a1, value)),
+            47 => self.combine_i8x32(a0, self.insert_i8x32::<15>(a1, value)),
+            48 => self.combine_i8x32(a0, self.insert_i8x32::<16>(a1, value)),
+            49 => self.combine_i8x32(a0, self.insert_i8x32::<17>(a1, value)),
+            50 => self.combine_i8x32(a0, self.insert_i8x32::<18>(a1, value)),
+            51 => self.combine_i8x32(a0, self.insert_i8x32::<19>(a1, value)),
+            52 => self.combine_i8x32(a0, self.insert_i8x32::<20>(a1, value)),
+            53 => self.combine_i8x32(a0, self.insert_i8x32::<21>(a1, value)),
+            54 => self.combine_i8x32(a0, self.insert_i8x32::<22>(a1, value)),
+            55 => self.combine_i8x32(a0, self.insert_i8x32::<23>(a1, value)),
+            56 => self.combine_i8x32(a0, self.insert_i8x32::<24>(a1, value)),
+            57 => self.combine_i8x32(a0, self.insert_i8x32::<25>(a1, value)),
+            58 => self.combine_i8x32(a0, self.insert_i8x32::<26>(a1, value)),
+            59 => self.combine_i8x32(a0, self.insert_i8x32::<27>(a1, value)),
+            60 => self.combine_i8x32(a0, self.insert_i8x32::<28>(a1, value)),
+            61 => self.combine_i8x32(a0, self.insert_i8x32::<29>(a1, value)),
+            62 => self.combine_i8x32(a0, self.insert_i8x32::<30>(a1, value)),
+            63 => self.combine_i8x32(a0, self.insert_i8x32::<31>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x64(self, a: i8x64<Self>, index: usize) -> i8 {
+        assert!(
+            index < 64usize,
+            "lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        self.as_array_ref_i8x64(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i8x64(self, a: i8x64<Self>, indices: u8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_i8x32(
+            self.swizzle_dyn_within_blocks_i8x32(a0, indices0),
+            self.swizzle_dyn_within_blocks_i8x32(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.add_i8x32(a0, b0), self.add_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.sub_i8x32(a0, b0), self.sub_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_i8x32(a0, b0), self.mul_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.and_i8x32(a0, b0), self.and_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.or_i8x32(a0, b0), self.or_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.xor_i8x32(a0, b0), self.xor_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn not_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.not_i8x32(a0), self.not_i8x32(a1))
+    }
+    #[inline(always)]
+    fn shl_i8x64(self, a: i8x64<Self>, shift: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shl_i8x32(a0, shift), self.shl_i8x32(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.shlv_i8x32(a0, b0), self.shlv_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_i8x64(self, a: i8x64<Self>, shift: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shr_i8x32(a0, shift), self.shr_i8x32(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.shrv_i8x32(a0, b0), self.shrv_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.shift_signed_i8x32(a0, b0),
+            self.shift_signed_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_eq_i8x32(a0, b0), self.simd_eq_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_lt_i8x32(a0, b0), self.simd_lt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_le_i8x32(a0, b0), self.simd_le_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_ge_i8x32(a0, b0), self.simd_ge_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_gt_i8x32(a0, b0), self.simd_gt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, _) = self.split_i8x64(a);
+        let (b0, _) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a0, b0), self.zip_high_i8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (_, a1) = self.split_i8x64(a);
+        let (_, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a1, b1), self.zip_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.unzip_low_i8x32(a0, a1), self.unzip_low_i8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.unzip_high_i8x32(a0, a1), self.unzip_high_i8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> (i8x64<Self>, i8x64<Self>) {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let lo_lo = self.zip_low_i8x32(a0, b0);
+        let lo_hi = self.zip_high_i8x32(a0, b0);
+        let hi_lo = self.zip_low_i8x32(a1, b1);
+        let hi_hi = self.zip_high_i8x32(a1, b1);
+        (
+            self.combine_i8x32(lo_lo, lo_hi),
+            self.combine_i8x32(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> (i8x64<Self>, i8x64<Self>) {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let lo_even = self.unzip_low_i8x32(a0, a1);
+        let lo_odd = self.unzip_high_i8x32(a0, a1);
+        let hi_even = self.unzip_low_i8x32(b0, b1);
+        let hi_odd = self.unzip_high_i8x32(b0, b1);
+        (
+            self.combine_i8x32(lo_even, hi_even),
+            self.combine_i8x32(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_i8x64(self, a: mask8x64<Self>, b: i8x64<Self>, c: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_i8x64(
+        self,
+        a: mask8x64<Self>,
+        b: i8x64<Self>,
+        c: i8x64<Self>,
+    ) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(
+            self.select_relaxed_i8x32(a0, b0, c0),
+            self.select_relaxed_i8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.min_i8x32(a0, b0), self.min_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_add_i8x32(a0, b0),
+            self.saturating_add_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_sub_i8x32(a0, b0),
+            self.saturating_sub_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.count_ones_i8x32(a0), self.count_ones_i8x32(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.leading_zeros_i8x32(a0), self.leading_zeros_i8x32(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.trailing_zeros_i8x32(a0), self.trailing_zeros_i8x32(a1))
+    }
+    #[inline(always)]
+    fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
+        (
+            i8x32 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            i8x32 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn neg_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.neg_i8x32(a0), self.neg_i8x32(a1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x64(self, a: i8x64<Self>) -> i32 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_add_widened_i8x32(a0) + self.reduce_add_widened_i8x32(a1)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x64(self, a: i8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i8x32(a0),
+            self.reinterpret_u32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x64(self, val: u8) -> u8x64<Self> {
+        let half = self.splat_u8x32(val);
+        self.combine_u8x32(half, half)
+    }
+    #[inline(always)]
+    fn load_array_u8x64(self, val: [u8; 64usize]) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u8x64(self, val: &[u8; 64usize]) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u8x64(self, a: u8x64<Self>) -> [u8; 64usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [u8; 64usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u8x64(self, a: &u8x64<Self>) -> &[u8; 64usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [u8; 64usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u8x64(self, a: &mut u8x64<Self>) -> &mut [u8; 64usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [u8; 64usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u8x64<const SHIFT: usize>(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        if SHIFT >= 64usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_u8x64(b).val.0,
+            self.cvt_to_bytes_u8x64(a).val.0,
+            SHIFT,
+        );
+        self.cvt_from_bytes_u8x64(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u8x64<const SHIFT: usize>(
+        self,
+        a: u8x64<Self>,
+        b: u8x64<Self>,
+    ) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.slide_within_blocks_u8x32::<SHIFT>(a0, b0),
+            self.slide_within_blocks_u8x32::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u8x64<const OFFSET: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        match OFFSET % 64 {
+            0 => self.slide_u8x64::<0>(a, a),
+            1 => self.slide_u8x64::<1>(a, a),
+            2 => self.slide_u8x64::<2>(a, a),
+            3 => self.slide_u8x64::<3>(a, a),
+            4 => self.slide_u8x64::<4>(a, a),
+            5 => self.slide_u8x64::<5>(a, a),
+            6 => self.slide_u8x64::<6>(a, a),
+            7 => self.slide_u8x64::<7>(a, a),
+            8 => self.slide_u8x64::<8>(a, a),
+            9 => self.slide_u8x64::<9>(a, a),
+            10 => self.slide_u8x64::<10>(a, a),
+            11 => self.slide_u8x64::<11>(a, a),
+            12 => self.slide_u8x64::<12>(a, a),
+            13 => self.slide_u8x64::<13>(a, a),
+            14 => self.slide_u8x64::<14>(a, a),
+            15 => self.slide_u8x64::<15>(a, a),
+            16 => self.slide_u8x64::<16>(a, a),
+            17 => self.slide_u8x64::<17>(a, a),
+            18 => self.slide_u8x64::<18>(a, a),
+            19 => self.slide_u8x64::<19>(a, a),
+            20 => self.slide_u8x64::<20>(a, a),
+            21 => self.slide_u8x64::<21>(a, a),
+            22 => self.slide_u8x64::<22>(a, a),
+            23 => self.slide_u8x64::<23>(a, a),
+            24 => self.slide_u8x64::<24>(a, a),
+            25 => self.slide_u8x64::<25>(a, a),
+            26 => self.slide_u8x64::<26>(a, a),
+            27 => self.slide_u8x64::<27>(a, a),
+            28 => self.slide_u8x64::<28>(a, a),
+            29 => self.slide_u8x64::<29>(a, a),
+            30 => self.slide_u8x64::<30>(a, a),
+            31 => self.slide_u8x64::<31>(a, a),
+            32 => self.slide_u8x64::<32>(a, a),
+            33 => self.slide_u8x64::<33>(a, a),
+            34 => self.slide_u8x64::<34>(a, a),
+            35 => self.slide_u8x64::<35>(a, a),
+            36 => self.slide_u8x64::<36>(a, a),
+            37 => self.slide_u8x64::<37>(a, a),
+            38 => self.slide_u8x64::<38>(a, a),
+            39 => self.slide_u8x64::<39>(a, a),
+            40 => self.slide_u8x64::<40>(a, a),
+            41 => self.slide_u8x64::<41>(a, a),
+            42 => self.slide_u8x64::<42>(a, a),
+            43 => self.slide_u8x64::<43>(a, a),
+            44 => self.slide_u8x64::<44>(a, a),
+            45 => self.slide_u8x64::<45>(a, a),
+            46 => self.slide_u8x64::<46>(a, a),
+            47 => self.slide_u8x64::<47>(a, a),
+            48 => self.slide_u8x64::<48>(a, a),
+            49 => self.slide_u8x64::<49>(a, a),
+            50 => self.slide_u8x64::<50>(a, a),
+            51 => self.slide_u8x64::<51>(a, a),
+            52 => self.slide_u8x64::<52>(a, a),
+            53 => self.slide_u8x64::<53>(a, a),
+            54 => self.slide_u8x64::<54>(a, a),
+            55 => self.slide_u8x64::<55>(a, a),
+            56 => self.slide_u8x64::<56>(a, a),
+            57 => self.slide_u8x64::<57>(a, a),
+            58 => self.slide_u8x64::<58>(a, a),
+            59 => self.slide_u8x64::<59>(a, a),
+            60 => self.slide_u8x64::<60>(a, a),
+            61 => self.slide_u8x64::<61>(a, a),
+            62 => self.slide_u8x64::<62>(a, a),
+            63 => self.slide_u8x64::<63>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u8x64<const OFFSET: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        match OFFSET % 64 {
+            0 => self.slide_u8x64::<64>(a, a),
+            1 => self.slide_u8x64::<63>(a, a),
+            2 => self.slide_u8x64::<62>(a, a),
+            3 => self.slide_u8x64::<61>(a, a),
+            4 => self.slide_u8x64::<60>(a, a),
+            5 => self.slide_u8x64::<59>(a, a),
+            6 => self.slide_u8x64::<58>(a, a),
+            7 => self.slide_u8x64::<57>(a, a),
+            8 => self.slide_u8x64::<56>(a, a),
+            9 => self.slide_u8x64::<55>(a, a),
+            10 => self.slide_u8x64::<54>(a, a),
+            11 => self.slide_u8x64::<53>(a, a),
+            12 => self.slide_u8x64::<52>(a, a),
+            13 => self.slide_u8x64::<51>(a, a),
+            14 => self.slide_u8x64::<50>(a, a),
+            15 => self.slide_u8x64::<49>(a, a),
+            16 => self.slide_u8x64::<48>(a, a),
+            17 => self.slide_u8x64::<47>(a, a),
+            18 => self.slide_u8x64::<46>(a, a),
+            19 => self.slide_u8x64::<45>(a, a),
+            20 => self.slide_u8x64::<44>(a, a),
+            21 => self.slide_u8x64::<43>(a, a),
+            22 => self.slide_u8x64::<42>(a, a),
+            23 => self.slide_u8x64::<41>(a, a),
+            24 => self.slide_u8x64::<40>(a, a),
+            25 => self.slide_u8x64::<39>(a, a),
+            26 => self.slide_u8x64::<38>(a, a),
+            27 => self.slide_u8x64::<37>(a, a),
+            28 => self.slide_u8x64::<36>(a, a),
+            29 => self.slide_u8x64::<35>(a, a),
+            30 => self.slide_u8x64::<34>(a, a),
+            31 => self.slide_u8x64::<33>(a, a),
+            32 => self.slide_u8x64::<32>(a, a),
+            33 => self.slide_u8x64::<31>(a, a),
+            34 => self.slide_u8x64::<30>(a, a),
+            35 => self.slide_u8x64::<29>(a, a),
+            36 => self.slide_u8x64::<28>(a, a),
+            37 => self.slide_u8x64::<27>(a, a),
+            38 => self.slide_u8x64::<26>(a, a),
+            39 => self.slide_u8x64::<25>(a, a),
+            40 => self.slide_u8x64::<24>(a, a),
+            41 => self.slide_u8x64::<23>(a, a),
+            42 => self.slide_u8x64::<22>(a, a),
+            43 => self.slide_u8x64::<21>(a, a),
+            44 => self.slide_u8x64::<20>(a, a),
+            45 => self.slide_u8x64::<19>(a, a),
+            46 => self.slide_u8x64::<18>(a, a),
+            47 => self.slide_u8x64::<17>(a, a),
+            48 => self.slide_u8x64::<16>(a, a),
+            49 => self.slide_u8x64::<15>(a, a),
+            50 => self.slide_u8x64::<14>(a, a),
+            51 => self.slide_u8x64::<13>(a, a),
+            52 => self.slide_u8x64::<12>(a, a),
+            53 => self.slide_u8x64::<11>(a, a),
+            54 => self.slide_u8x64::<10>(a, a),
+            55 => self.slide_u8x64::<9>(a, a),
+            56 => self.slide_u8x64::<8>(a, a),
+            57 => self.slide_u8x64::<7>(a, a),
+            58 => self.slide_u8x64::<6>(a, a),
+            59 => self.slide_u8x64::<5>(a, a),
+            60 => self.slide_u8x64::<4>(a, a),
+            61 => self.slide_u8x64::<3>(a, a),
+            62 => self.slide_u8x64::<2>(a, a),
+            63 => self.slide_u8x64::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u8x64<const OFFSET: usize>(
+        self,
+        a: u8x64<Self>,
+        padding: u8,
+    ) -> u8x64<Self> {
+        let padding = self.splat_u8x64(padding);
+        match OFFSET {
+            0 => self.slide_u8x64::<0>(a, padding),
+            1 => self.slide_u8x64::<1>(a, padding),
+            2 => self.slide_u8x64::<2>(a, padding),
+            3 => self.slide_u8x64::<3>(a, padding),
+            4 => self.slide_u8x64::<4>(a, padding),
+            5 => self.slide_u8x64::<5>(a, padding),
+            6 => self.slide_u8x64::<6>(a, padding),
+            7 => self.slide_u8x64::<7>(a, padding),
+            8 => self.slide_u8x64::<8>(a, padding),
+            9 => self.slide_u8x64::<9>(a, padding),
+            10 => self.slide_u8x64::<10>(a, padding),
+            11 => self.slide_u8x64::<11>(a, padding),
+            12 => self.slide_u8x64::<12>(a, padding),
+            13 => self.slide_u8x64::<13>(a, padding),
+            14 => self.slide_u8x64::<14>(a, padding),
+            15 => self.slide_u8x64::<15>(a, padding),
+            16 => self.slide_u8x64::<16>(a, padding),
+            17 => self.slide_u8x64::<17>(a, padding),
+            18 => self.slide_u8x64::<18>(a, padding),
+            19 => self.slide_u8x64::<19>(a, padding),
+            20 => self.slide_u8x64::<20>(a, padding),
+            21 => self.slide_u8x64::<21>(a, padding),
+            22 => self.slide_u8x64::<22>(a, padding),
+            23 => self.slide_u8x64::<23>(a, padding),
+            24 => self.slide_u8x64::<24>(a, padding),
+            25 => self.slide_u8x64::<25>(a, padding),
+            26 => self.slide_u8x64::<26>(a, padding),
+            27 => self.slide_u8x64::<27>(a, padding),
+            28 => self.slide_u8x64::<28>(a, padding),
+            29 => self.slide_u8x64::<29>(a, padding),
+            30 => self.slide_u8x64::<30>(a, padding),
+            31 => self.slide_u8x64::<31>(a, padding),
+            32 => self.slide_u8x64::<32>(a, padding),
+            33 => self.slide_u8x64::<33>(a, padding),
+            34 => self.slide_u8x64::<34>(a, padding),
+            35 => self.slide_u8x64::<35>(a, padding),
+            36 => self.slide_u8x64::<36>(a, padding),
+            37 => self.slide_u8x64::<37>(a, padding),
+            38 => self.slide_u8x64::<38>(a, padding),
+            39 => self.slide_u8x64::<39>(a, padding),
+            40 => self.slide_u8x64::<40>(a, padding),
+            41 => self.slide_u8x64::<41>(a, padding),
+            42 => self.slide_u8x64::<42>(a, padding),
+            43 => self.slide_u8x64::<43>(a, padding),
+            44 => self.slide_u8x64::<44>(a, padding),
+            45 => self.slide_u8x64::<45>(a, padding),
+            46 => self.slide_u8x64::<46>(a, padding),
+            47 => self.slide_u8x64::<47>(a, padding),
+            48 => self.slide_u8x64::<48>(a, padding),
+            49 => self.slide_u8x64::<49>(a, padding),
+            50 => self.slide_u8x64::<50>(a, padding),
+            51 => self.slide_u8x64::<51>(a, padding),
+            52 => self.slide_u8x64::<52>(a, padding),
+            53 => self.slide_u8x64::<53>(a, padding),
+            54 => self.slide_u8x64::<54>(a, padding),
+            55 => self.slide_u8x64::<55>(a, padding),
+            56 => self.slide_u8x64::<56>(a, padding),
+            57 => self.slide_u8x64::<57>(a, padding),
+            58 => self.slide_u8x64::<58>(a, padding),
+            59 => self.slide_u8x64::<59>(a, padding),
+            60 => self.slide_u8x64::<60>(a, padding),
+            61 => self.slide_u8x64::<61>(a, padding),
+            62 => self.slide_u8x64::<62>(a, padding),
+            63 => self.slide_u8x64::<63>(a, padding),
+            64 => self.slide_u8x64::<64>(a, padding),
+            _ => self.slide_u8x64::<64>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u8x64<const OFFSET: usize>(
+        self,
+        a: u8x64<Self>,
+        padding: u8,
+    ) -> u8x64<Self> {
+        let padding = self.splat_u8x64(padding);
+        match OFFSET {
+            0 => self.slide_u8x64::<64>(padding, a),
+            1 => self.slide_u8x64::<63>(padding, a),
+            2 => self.slide_u8x64::<62>(padding, a),
+            3 => self.slide_u8x64::<61>(padding, a),
+            4 => self.slide_u8x64::<60>(padding, a),
+            5 => self.slide_u8x64::<59>(padding, a),
+            6 => self.slide_u8x64::<58>(padding, a),
+            7 => self.slide_u8x64::<57>(padding, a),
+            8 => self.slide_u8x64::<56>(padding, a),
+            9 => self.slide_u8x64::<55>(padding, a),
+            10 => self.slide_u8x64::<54>(padding, a),
+            11 => self.slide_u8x64::<53>(padding, a),
+            12 => self.slide_u8x64::<52>(padding, a),
+            13 => self.slide_u8x64::<51>(padding, a),
+            14 => self.slide_u8x64::<50>(padding, a),
+            15 => self.slide_u8x64::<49>(padding, a),
+            16 => self.slide_u8x64::<48>(padding, a),
+            17 => self.slide_u8x64::<47>(padding, a),
+            18 => self.slide_u8x64::<46>(padding, a),
+            19 => self.slide_u8x64::<45>(padding, a),
+            20 => self.slide_u8x64::<44>(padding, a),
+            21 => self.slide_u8x64::<43>(padding, a),
+            22 => self.slide_u8x64::<42>(padding, a),
+            23 => self.slide_u8x64::<41>(padding, a),
+            24 => self.slide_u8x64::<40>(padding, a),
+            25 => self.slide_u8x64::<39>(padding, a),
+            26 => self.slide_u8x64::<38>(padding, a),
+            27 => self.slide_u8x64::<37>(padding, a),
+            28 => self.slide_u8x64::<36>(padding, a),
+            29 => self.slide_u8x64::<35>(padding, a),
+            30 => self.slide_u8x64::<34>(padding, a),
+            31 => self.slide_u8x64::<33>(padding, a),
+            32 => self.slide_u8x64::<32>(padding, a),
+            33 => self.slide_u8x64::<31>(padding, a),
+            34 => self.slide_u8x64::<30>(padding, a),
+            35 => self.slide_u8x64::<29>(padding, a),
+            36 => self.slide_u8x64::<28>(padding, a),
+            37 => self.slide_u8x64::<27>(padding, a),
+            38 => self.slide_u8x64::<26>(padding, a),
+            39 => self.slide_u8x64::<25>(padding, a),
+            40 => self.slide_u8x64::<24>(padding, a),
+            41 => self.slide_u8x64::<23>(padding, a),
+            42 => self.slide_u8x64::<22>(padding, a),
+            43 => self.slide_u8x64::<21>(padding, a),
+            44 => self.slide_u8x64::<20>(padding, a),
+            45 => self.slide_u8x64::<19>(padding, a),
+            46 => self.slide_u8x64::<18>(padding, a),
+            47 => self.slide_u8x64::<17>(padding, a),
+            48 => self.slide_u8x64::<16>(padding, a),
+            49 => self.slide_u8x64::<15>(padding, a),
+            50 => self.slide_u8x64::<14>(padding, a),
+            51 => self.slide_u8x64::<13>(padding, a),
+            52 => self.slide_u8x64::<12>(padding, a),
+            53 => self.slide_u8x64::<11>(padding, a),
+            54 => self.slide_u8x64::<10>(padding, a),
+            55 => self.slide_u8x64::<9>(padding, a),
+            56 => self.slide_u8x64::<8>(padding, a),
+            57 => self.slide_u8x64::<7>(padding, a),
+            58 => self.slide_u8x64::<6>(padding, a),
+            59 => self.slide_u8x64::<5>(padding, a),
+            60 => self.slide_u8x64::<4>(padding, a),
+            61 => self.slide_u8x64::<3>(padding, a),
+            62 => self.slide_u8x64::<2>(padding, a),
+            63 => self.slide_u8x64::<1>(padding, a),
+            64 => self.slide_u8x64::<0>(padding, a),
+            _ => self.slide_u8x64::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u8x64<const LANE: usize>(self, a: u8x64<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x64(a);
+        match LANE {
+            0 => self.extract_u8x32::<0>(a0),
+            1 => self.extract_u8x32::<1>(a0),
+            2 => self.extract_u8x32::<2>(a0),
+            3 => self.extract_u8x32::<3>(a0),
+            4 => self.extract_u8x32::<4>(a0),
+            5 => self.extract_u8x32::<5>(a0),
+            6 => self.extract_u8x32::<6>(a0),
+            7 => self.extract_u8x32::<7>(a0),
+            8 => self.extract_u8x32::<8>(a0),
+            9 => self.extract_u8x32::<9>(a0),
+            10 => self.extract_u8x32::<10>(a0),
+            11 => self.extract_u8x32::<11>(a0),
+            12 => self.extract_u8x32::<12>(a0),
+            13 => self.extract_u8x32::<13>(a0),
+            14 => self.extract_u8x32::<14>(a0),
+            15 => self.extract_u8x32::<15>(a0),
+            16 => self.extract_u8x32::<16>(a0),
+            17 => self.extract_u8x32::<17>(a0),
+            18 => self.extract_u8x32::<18>(a0),
+            19 => self.extract_u8x32::<19>(a0),
+            20 => self.extract_u8x32::<20>(a0),
+            21 => self.extract_u8x32::<21>(a0),
+            22 => self.extract_u8x32::<22>(a0),
+            23 => self.extract_u8x32::<23>(a0),
+            24 => self.extract_u8x32::<24>(a0),
+            25 => self.extract_u8x32::<25>(a0),
+            26 => self.extract_u8x32::<26>(a0),
+            27 => self.extract_u8x32::<27>(a0),
+            28 => self.extract_u8x32::<28>(a0),
+            29 => self.extract_u8x32::<29>(a0),
+            30 => self.extract_u8x32::<30>(a0),
+            31 => self.extract_u8x32::<31>(a0),
+            32 => self.extract_u8x32::<0>(a1),
+            33 => self.extract_u8x32::<1>(a1),
+            34 => self.extract_u8x32::<2>(a1),
+            35 => self.extract_u8x32::<3>(a1),
+            36 => self.extract_u8x32::<4>(a1),
+            37 => self.extract_u8x32::<5>(a1),
+            38 => self.extract_u8x32::<6>(a1),
+            39 => self.extract_u8x32::<7>(a1),
+            40 => self.extract_u8x32::<8>(a1),
+            41 => self.extract_u8x32::<9>(a1),
+            42 => self.extract_u8x32::<10>(a1),
+            43 => self.extract_u8x32::<11>(a1),
+            44 => self.extract_u8x32::<12>(a1),
+            45 => self.extract_u8x32::<13>(a1),
+            46 => self.extract_u8x32::<14>(a1),
+            47 => self.extract_u8x32::<15>(a1),
+            48 => self.extract_u8x32::<16>(a1),
+            49 => self.extract_u8x32::<17>(a1),
+            50 => self.extract_u8x32::<18>(a1),
+            51 => self.extract_u8x32::<19>(a1),
+            52 => self.extract_u8x32::<20>(a1),
+            53 => self.extract_u8x32::<21>(a1),
+            54 => self.extract_u8x32::<22>(a1),
+            55 => self.extract_u8x32::<23>(a1),
+            56 => self.extract_u8x32::<24>(a1),
+            57 => self.extract_u8x32::<25>(a1),
+            58 => self.extract_u8x32::<26>(a1),
+            59 => self.extract_u8x32::<27>(a1),
+            60 => self.extract_u8x32::<28>(a1),
+            61 => self.extract_u8x32::<29>(a1),
+            62 => self.extract_u8x32::<30>(a1),
+            63 => self.extract_u8x32::<31>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u8x64<const LANE: usize>(self, a: u8x64<Self>, value: u8) -> u8x64<Self> {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x64(a);
+        match LANE {
+            0 => self.combine_u8x32(self.insert_u8x32::<0>(a0, value), a1),
+            1 => self.combine_u8x32(self.insert_u8x32::<1>(a0, value), a1),
+            2 => self.combine_u8x32(self.insert_u8x32::<2>(a0, value), a1),
+            3 => self.combine_u8x32(self.insert_u8x32::<3>(a0, value), a1),
+            4 => self.combine_u8x32(self.insert_u8x32::<4>(a0, value), a1),
+            5 => self.combine_u8x32(self.insert_u8x32::<5>(a0, value), a1),
+            6 => self.combine_u8x32(self.insert_u8x32::<6>(a0, value), a1),
+            7 => self.combine_u8x32(self.insert_u8x32::<7>(a0, value), a1),
+            8 => self.combine_u8x32(self.insert_u8x32::<8>(a0, value), a1),
+            9 => self.combine_u8x32(self.insert_u8x32::<9>(a0, value), a1),
+            10 => self.combine_u8x32(self.insert_u8x32::<10>(a0, value), a1),
+            11 => self.combine_u8x32(self.insert_u8x32::<11>(a0, value), a1),
+            12 => self.combine_u8x32(self.insert_u8x32::<12>(a0, value), a1),
+            13 => self.combine_u8x32(self.insert_u8x32::<13>(a0, value), a1),
+            14 => self.combine_u8x32(self.insert_u8x32::<14>(a0, value), a1),
+            15 => self.combine_u8x32(self.insert_u8x32::<15>(a0, value), a1),
+            16 => self.combine_u8x32(self.insert_u8x32::<16>(a0, value), a1),
+            17 => self.combine_u8x32(self.insert_u8x32::<17>(a0, value), a1),
+            18 => self.combine_u8x32(self.insert_u8x32::<18>(a0, value), a1),
+            19 => self.combine_u8x32(self.insert_u8x32::<19>(a0, value), a1),
+            20 => self.combine_u8x32(self.insert_u8x32::<20>(a0, value), a1),
+            21 => self.combine_u8x32(self.insert_u8x32::<21>(a0, value), a1),
+            22 => self.combine_u8x32(self.insert_u8x32::<22>(a0, value), a1),
+            23 => self.combine_u8x32(self.insert_u8x32::<23>(a0, value), a1),
+            24 => self.combine_u8x32(self.insert_u8x32::<24>(a0, value), a1),
+            25 => self.combine_u8x32(self.insert_u8x32::<25>(a0, value), a1),
+            26 => self.combine_u8x32(self.insert_u8x32::<26>(a0, value), a1),
+            27 => self.combine_u8x32(self.insert_u8x32::<27>(a0, value), a1),
+            28 => self.combine_u8x32(self.insert_u8x32::<28>(a0, value), a1),
+            29 => self.combine_u8x32(self.insert_u8x32::<29>(a0, value), a1),
+            30 => self.combine_u8x32(self.insert_u8x32::<30>(a0, value), a1),
+            31 => self.combine_u8x32(self.insert_u8x32::<31>(a0, value), a1),
+            32 => self.combine_u8x32(a0, self.insert_u8x32::<0>(a1, value)),
+            33 => self.combine_u8x32(a0, self.insert_u8x32::<1>(a1, value)),
+            34 => self.combine_u8x32(a0, self.insert_u8x32::<2>(a1, value)),
+            35 => self.combine_u8x32(a0, self.insert_u8x32::<3>(a1, value)),
+            36 => self.combine_u8x32(a0, self.insert_u8x32::<4>(a1, value)),
+            37 => self.combine_u8x32(a0, self.insert_u8x32::<5>(a1, value)),
+            38 => self.combine_u8x32(a0, self.insert_u8x32::<6>(a1, value)),
+            39 => self.combine_u8x32(a0, self.insert_u8x32::<7>(a1, value)),
+            40 => self.combine_u8x32(a0, self.insert_u8x32::<8>(a1, value)),
+            41 => self.combine_u8x32(a0, self.insert_u8x32::<9>(a1, value)),
+            42 => self.combine_u8x32(a0, self.insert_u8x32::<10>(a1, value)),
+            43 => self.combine_u8x32(a0, self.insert_u8x32::<11>(a1, value)),
+            44 => self.combine_u8x32(a0, self.insert_u8x32::<12>(a1, value)),
+            45 => self.combine_u8x32(a0, self.insert_u8x32::<13>(a1, value)),
+            46 => self.combine_u8x32(a0, self.insert_u8x32::<14>(a1, value)),
+            47 => self.combine_u8x32(a0, self.insert_u8x32::<15>(a1, value)),
+            48 => self.combine_u8x32(a0, self.insert_u8x32::<16>(a1, value)),
+            49 => self.combine_u8x32(a0, self.insert_u8x32::<17>(a1, value)),
+            50 => self.combine_u8x32(a0, self.insert_u8x32::<18>(a1, value)),
+            51 => self.combine_u8x32(a0, self.insert_u8x32::<19>(a1, value)),
+            52 => self.combine_u8x32(a0, self.insert_u8x32::<20>(a1, value)),
+            53 => self.combine_u8x32(a0, self.insert_u8x32::<21>(a1, value)),
+            54 => self.combine_u8x32(a0, self.insert_u8x32::<22>(a1, value)),
+            55 => self.combine_u8x32(a0, self.insert_u8x32::<23>(a1, value)),
+            56 => self.combine_u8x32(a0, self.insert_u8x32::<24>(a1, value)),
+            57 => self.combine_u8x32(a0, self.insert_u8x32::<25>(a1, value)),
+            58 => self.combine_u8x32(a0, self.insert_u8x32::<26>(a1, value)),
+            59 => self.combine_u8x32(a0, self.insert_u8x32::<27>(a1, value)),
+            60 => self.combine_u8x32(a0, self.insert_u8x32::<28>(a1, value)),
+            61 => self.combine_u8x32(a0, self.insert_u8x32::<29>(a1, value)),
+            62 => self.combine_u8x32(a0, self.insert_u8x32::<30>(a1, value)),
+            63 => self.combine_u8x32(a0, self.insert_u8x32::<31>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x64(self, a: u8x64<Self>, index: usize) -> u8 {
+        assert!(
+            index < 64usize,
+            "lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        self.as_array_ref_u8x64(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u8x64(self, a: u8x64<Self>, indices: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_u8x32(
+            self.swizzle_dyn_within_blocks_u8x32(a0, indices0),
+            self.swizzle_dyn_within_blocks_u8x32(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.add_u8x32(a0, b0), self.add_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.sub_u8x32(a0, b0), self.sub_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_u8x32(a0, b0), self.mul_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.and_u8x32(a0, b0), self.and_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.or_u8x32(a0, b0), self.or_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.xor_u8x32(a0, b0), self.xor_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn not_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.not_u8x32(a0), self.not_u8x32(a1))
+    }
+    #[inline(always)]
+    fn shl_u8x64(self, a: u8x64<Self>, shift: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shl_u8x32(a0, shift), self.shl_u8x32(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.shlv_u8x32(a0, b0), self.shlv_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_u8x64(self, a: u8x64<Self>, shift: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shr_u8x32(a0, shift), self.shr_u8x32(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.shrv_u8x32(a0, b0), self.shrv_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_u8x64(self, a: u8x64<Self>, b: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_u8x32(
+            self.shift_signed_u8x32(a0, b0),
+            self.shift_signed_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_eq_u8x32(a0, b0), self.simd_eq_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_lt_u8x32(a0, b0), self.simd_lt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_le_u8x32(a0, b0), self.simd_le_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_ge_u8x32(a0, b0), self.simd_ge_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_gt_u8x32(a0, b0), self.simd_gt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, _) = self.split_u8x64(a);
+        let (b0, _) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a0, b0), self.zip_high_u8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (_, a1) = self.split_u8x64(a);
+        let (_, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a1, b1), self.zip_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.unzip_low_u8x32(a0, a1), self.unzip_low_u8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.unzip_high_u8x32(a0, a1), self.unzip_high_u8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> (u8x64<Self>, u8x64<Self>) {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let lo_lo = self.zip_low_u8x32(a0, b0);
+        let lo_hi = self.zip_high_u8x32(a0, b0);
+        let hi_lo = self.zip_low_u8x32(a1, b1);
+        let hi_hi = self.zip_high_u8x32(a1, b1);
+        (
+            self.combine_u8x32(lo_lo, lo_hi),
+            self.combine_u8x32(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> (u8x64<Self>, u8x64<Self>) {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let lo_even = self.unzip_low_u8x32(a0, a1);
+        let lo_odd = self.unzip_high_u8x32(a0, a1);
+        let hi_even = self.unzip_low_u8x32(b0, b1);
+        let hi_odd = self.unzip_high_u8x32(b0, b1);
+        (
+            self.combine_u8x32(lo_even, hi_even),
+            self.combine_u8x32(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_u8x64(self, a: mask8x64<Self>, b: u8x64<Self>, c: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_u8x64(
+        self,
+        a: mask8x64<Self>,
+        b: u8x64<Self>,
+        c: u8x64<Self>,
+    ) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(
+            self.select_relaxed_u8x32(a0, b0, c0),
+            self.select_relaxed_u8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.min_u8x32(a0, b0), self.min_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_add_u8x32(a0, b0),
+            self.saturating_add_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_sub_u8x32(a0, b0),
+            self.saturating_sub_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.count_ones_u8x32(a0), self.count_ones_u8x32(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.leading_zeros_u8x32(a0), self.leading_zeros_u8x32(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.trailing_zeros_u8x32(a0), self.trailing_zeros_u8x32(a1))
+    }
+    #[inline(always)]
+    fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
+        (
+            u8x32 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            u8x32 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u8x64(self, src: &[u8; 64usize]) -> u8x64<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, src: &[u8; 64usize]) -> u8x64<Avx2> {
+                let (chunks, []) = src.as_chunks::<16usize>() else {
+                    unreachable!()
+                };
+                let v0: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u8; 16usize], __m128i>(&chunks[0]);
+                let v1: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u8; 16usize], __m128i>(&chunks[1]);
+                let v2: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u8; 16usize], __m128i>(&chunks[2]);
+                let v3: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u8; 16usize], __m128i>(&chunks[3]);
+                let mask = _mm_setr_epi8(0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15);
+                let v0 = _mm_shuffle_epi8(v0, mask);
+                let v1 = _mm_shuffle_epi8(v1, mask);
+                let v2 = _mm_shuffle_epi8(v2, mask);
+                let v3 = _mm_shuffle_epi8(v3, mask);
+                let tmp0 = _mm_unpacklo_epi32(v0, v1);
+                let tmp1 = _mm_unpackhi_epi32(v0, v1);
+                let tmp2 = _mm_unpacklo_epi32(v2, v3);
+                let tmp3 = _mm_unpackhi_epi32(v2, v3);
+                let out0 = _mm_unpacklo_epi64(tmp0, tmp2);
+                let out1 = _mm_unpackhi_epi64(tmp0, tmp2);
+                let out2 = _mm_unpacklo_epi64(tmp1, tmp3);
+                let out3 = _mm_unpackhi_epi64(tmp1, tmp3);
+                token.combine_u8x32(
+                    token.combine_u8x16(out0.simd_into(token), out1.simd_into(token)),
+                    token.combine_u8x16(out2.simd_into(token), out3.simd_into(token)),
+                )
+            }
+        );
+        kernel(self, src)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u8x64<Avx2>, dest: &mut [u8; 64usize]) -> () {
+                let (v01, v23) = token.split_u8x64(a);
+                let (v0, v1) = token.split_u8x32(v01);
+                let (v2, v3) = token.split_u8x32(v23);
+                let v0 = v0.into();
+                let v1 = v1.into();
+                let v2 = v2.into();
+                let v3 = v3.into();
+                let tmp0 = _mm_unpacklo_epi32(v0, v1);
+                let tmp1 = _mm_unpackhi_epi32(v0, v1);
+                let tmp2 = _mm_unpacklo_epi32(v2, v3);
+                let tmp3 = _mm_unpackhi_epi32(v2, v3);
+                let out0 = _mm_unpacklo_epi64(tmp0, tmp2);
+                let out1 = _mm_unpackhi_epi64(tmp0, tmp2);
+                let out2 = _mm_unpacklo_epi64(tmp1, tmp3);
+                let out3 = _mm_unpackhi_epi64(tmp1, tmp3);
+                let mask = _mm_setr_epi8(0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15);
+                let out0 = _mm_shuffle_epi8(out0, mask);
+                let out1 = _mm_shuffle_epi8(out1, mask);
+                let out2 = _mm_shuffle_epi8(out2, mask);
+                let out3 = _mm_shuffle_epi8(out3, mask);
+                let (chunks, []) = dest.as_chunks_mut::<16usize>() else {
+                    unreachable!()
+                };
+                crate::transmute::checked_transmute_store::<__m128i, [u8; 16usize]>(
+                    out0,
+                    &mut chunks[0],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u8; 16usize]>(
+                    out1,
+                    &mut chunks[1],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u8; 16usize]>(
+                    out2,
+                    &mut chunks[2],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u8; 16usize]>(
+                    out3,
+                    &mut chunks[3],
+                );
+            }
+        );
+        kernel(self, a, dest);
+    }
+    #[inline(always)]
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.avg_round_u8x32(a0, b0), self.avg_round_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x64(self, a: u8x64<Self>) -> u32 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_add_widened_u8x32(a0) + self.reduce_add_widened_u8x32(a1)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u8x32(a0),
+            self.reinterpret_u32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn compress_bytes_u8x64(self, a: u8x64<Self>, mask: mask8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (mask0, mask1) = self.split_mask8x64(mask);
+        let count = self.to_bitmask_mask8x32(mask0).count_ones() as usize;
+        let mut result = [0; 64];
+        result[..32].copy_from_slice(&self.as_array_u8x32(self.compress_bytes_u8x32(a0, mask0)));
+        result[count..count + 32]
+            .copy_from_slice(&self.as_array_u8x32(self.compress_bytes_u8x32(a1, mask1)));
+        self.load_array_u8x64(result)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x64(self, a: u8x64<Self>, mask: mask8x64<Self>) -> u8x64<Self> {
+        let (mask0, mask1) = self.split_mask8x64(mask);
+        let count = self.to_bitmask_mask8x32(mask0).count_ones() as usize;
+        let lanes = self.as_array_u8x64(a);
+        let (a0, _) = self.split_u8x64(a);
+        let a1 = self.load_array_u8x32(core::array::from_fn(|i| lanes[count + i]));
+        self.combine_u8x32(
+            self.expand_bytes_u8x32(a0, mask0),
+            self.expand_bytes_u8x32(a1, mask1),
+        )
+    }
+    #[inline(always)]
+    fn table_lookup_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.table_lookup_u8x32(a0, b0),
+            self.table_lookup_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x64<const FACTOR: u8>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<64>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x64(
+            self.load_array_u8x64(low),
+            self.and_u8x64(a, self.splat_u8x64(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x64(self.load_array_u8x64(high), self.shr_u8x64(a, 4));
+        self.xor_u8x64(low, high)
+    }
+    #[inline(always)]
+    fn splat_mask8x64(self, val: bool) -> mask8x64<Self> {
+        let half = self.splat_mask8x32(val);
+        self.combine_mask8x32(half, half)
+    }
+    #[inline(always)]
+    fn load_array_mask8x64(self, val: [i8; 64usize]) -> mask8x64<Self> {
+        mask8x64 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask8x64(self, a: mask8x64<Self>) -> [i8; 64usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i8; 64usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self> {
+        i8x64 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask8x64<Avx2> {
+                {
+                    let bit_bytes = _mm256_set1_epi64x(bits.cast_signed());
+                    let bit_mask = _mm256_setr_epi8(
+                        1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16,
+                        32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128,
+                    );
+                    mask8x64 {
+                        val: crate::support::Aligned512([
+                            {
+                                let bit_bytes = _mm256_shuffle_epi8(
+                                    bit_bytes,
+                                    _mm256_setr_epi8(
+                                        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2,
+                                        2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3,
+                                    ),
+                                );
+                                _mm256_cmpeq_epi8(_mm256_and_si256(bit_bytes, bit_mask), bit_mask)
+                            },
+                            {
+                                let bit_bytes = _mm256_shuffle_epi8(
+                                    bit_bytes,
+                                    _mm256_setr_epi8(
+                                        4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 6, 6, 6, 6,
+                                        6, 6, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7,
+                                    ),
+                                );
+                                _mm256_cmpeq_epi8(_mm256_and_si256(bit_bytes, bit_mask), bit_mask)
+                            },
+                        ]),
+                        simd: token,
+                    }
+                }
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x64(self, a: mask8x64<Self>) -> u64 {
+        let (lo, hi) = self.split_mask8x64(a);
+        let lo = self.to_bitmask_mask8x32(lo);
+        let hi = self.to_bitmask_mask8x32(hi);
+        lo | (hi << 32usize)
+    }
+    #[inline(always)]
+    fn set_mask8x64(self, a: &mut mask8x64<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 64usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        let mut lanes = self.as_array_mask8x64(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask8x64(lanes);
+    }
+    #[inline(always)]
+    fn and_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.and_mask8x32(a0, b0), self.and_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.or_mask8x32(a0, b0), self.or_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.xor_mask8x32(a0, b0), self.xor_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn not_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_mask8x32(self.not_mask8x32(a0), self.not_mask8x32(a1))
+    }
+    #[inline(always)]
+    fn select_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_mask8x32(a0, b0, c0),
+            self.select_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn select_relaxed_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_relaxed_mask8x32(a0, b0, c0),
+            self.select_relaxed_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.simd_eq_mask8x32(a0, b0), self.simd_eq_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn any_true_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.any_true_mask8x32(a0) || self.any_true_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn all_true_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.all_true_mask8x32(a0) && self.all_true_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn any_false_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.any_false_mask8x32(a0) || self.any_false_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn all_false_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.all_false_mask8x32(a0) && self.all_false_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn split_mask8x64(self, a: mask8x64<Self>) -> (mask8x32<Self>, mask8x32<Self>) {
+        (
+            mask8x32 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            mask8x32 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x32(self, val: i16) -> i16x32<Self> {
+        let half = self.splat_i16x16(val);
+        self.combine_i16x16(half, half)
+    }
+    #[inline(always)]
+    fn load_array_i16x32(self, val: [i16; 32usize]) -> i16x32<Self> {
+        i16x32 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i16x32(self, val: &[i16; 32usize]) -> i16x32<Self> {
+        i16x32 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i16x32(self, a: i16x32<Self>) -> [i16; 32usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i16; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i16x32(self, a: &i16x32<Self>) -> &[i16; 32usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [i16; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i16x32(self, a: &mut i16x32<Self>) -> &mut [i16; 32usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [i16; 32usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i16x32(self, a: i16x32<Self>, dest: &mut [i16; 32usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i16x32(self, a: u8x64<Self>) -> i16x32<Self> {
+        i16x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i16x32<const SHIFT: usize>(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_i16x32(b).val.0,
+            self.cvt_to_bytes_i16x32(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_i16x32(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i16x32<const SHIFT: usize>(
+        self,
+        a: i16x32<Self>,
+        b: i16x32<Self>,
+    ) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.slide_within_blocks_i16x16::<SHIFT>(a0, b0),
+            self.slide_within_blocks_i16x16::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i16x32<const OFFSET: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_i16x32::<0>(a, a),
+            1 => self.slide_i16x32::<1>(a, a),
+            2 => self.slide_i16x32::<2>(a, a),
+            3 => self.slide_i16x32::<3>(a, a),
+            4 => self.slide_i16x32::<4>(a, a),
+            5 => self.slide_i16x32::<5>(a, a),
+            6 => self.slide_i16x32::<6>(a, a),
+            7 => self.slide_i16x32::<7>(a, a),
+            8 => self.slide_i16x32::<8>(a, a),
+            9 => self.slide_i16x32::<9>(a, a),
+            10 => self.slide_i16x32::<10>(a, a),
+            11 => self.slide_i16x32::<11>(a, a),
+            12 => self.slide_i16x32::<12>(a, a),
+            13 => self.slide_i16x32::<13>(a, a),
+            14 => self.slide_i16x32::<14>(a, a),
+            15 => self.slide_i16x32::<15>(a, a),
+            16 => self.slide_i16x32::<16>(a, a),
+            17 => self.slide_i16x32::<17>(a, a),
+            18 => self.slide_i16x32::<18>(a, a),
+            19 => self.slide_i16x32::<19>(a, a),
+            20 => self.slide_i16x32::<20>(a, a),
+            21 => self.slide_i16x32::<21>(a, a),
+            22 => self.slide_i16x32::<22>(a, a),
+            23 => self.slide_i16x32::<23>(a, a),
+            24 => self.slide_i16x32::<24>(a, a),
+            25 => self.slide_i16x32::<25>(a, a),
+            26 => self.slide_i16x32::<26>(a, a),
+            27 => self.slide_i16x32::<27>(a, a),
+            28 => self.slide_i16x32::<28>(a, a),
+            29 => self.slide_i16x32::<29>(a, a),
+            30 => self.slide_i16x32::<30>(a, a),
+            31 => self.slide_i16x32::<31>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i16x32<const OFFSET: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_i16x32::<32>(a, a),
+            1 => self.slide_i16x32::<31>(a, a),
+            2 => self.slide_i16x32::<30>(a, a),
+            3 => self.slide_i16x32::<29>(a, a),
+            4 => self.slide_i16x32::<28>(a, a),
+            5 => self.slide_i16x32::<27>(a, a),
+            6 => self.slide_i16x32::<26>(a, a),
+            7 => self.slide_i16x32::<25>(a, a),
+            8 => self.slide_i16x32::<24>(a, a),
+            9 => self.slide_i16x32::<23>(a, a),
+            10 => self.slide_i16x32::<22>(a, a),
+            11 => self.slide_i16x32::<21>(a, a),
+            12 => self.slide_i16x32::<20>(a, a),
+            13 => self.slide_i16x32::<19>(a, a),
+            14 => self.slide_i16x32::<18>(a, a),
+            15 => self.slide_i16x32::<17>(a, a),
+            16 => self.slide_i16x32::<16>(a, a),
+            17 => self.slide_i16x32::<15>(a, a),
+            18 => self.slide_i16x32::<14>(a, a),
+            19 => self.slide_i16x32::<13>(a, a),
+            20 => self.slide_i16x32::<12>(a, a),
+            21 => self.slide_i16x32::<11>(a, a),
+            22 => self.slide_i16x32::<10>(a, a),
+            23 => self.slide_i16x32::<9>(a, a),
+            24 => self.slide_i16x32::<8>(a, a),
+            25 => self.slide_i16x32::<7>(a, a),
+            26 => self.slide_i16x32::<6>(a, a),
+            27 => self.slide_i16x32::<5>(a, a),
+            28 => self.slide_i16x32::<4>(a, a),
+            29 => self.slide_i16x32::<3>(a, a),
+            30 => self.slide_i16x32::<2>(a, a),
+            31 => self.slide_i16x32::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i16x32<const OFFSET: usize>(
+        self,
+        a: i16x32<Self>,
+        padding: i16,
+    ) -> i16x32<Self> {
+        let padding = self.splat_i16x32(padding);
+        match OFFSET {
+            0 => self.slide_i16x32::<0>(a, padding),
+            1 => self.slide_i16x32::<1>(a, padding),
+            2 => self.slide_i16x32::<2>(a, padding),
+            3 => self.slide_i16x32::<3>(a, padding),
+            4 => self.slide_i16x32::<4>(a, padding),
+            5 => self.slide_i16x32::<5>(a, padding),
+            6 => self.slide_i16x32::<6>(a, padding),
+            7 => self.slide_i16x32::<7>(a, padding),
+            8 => self.slide_i16x32::<8>(a, padding),
+            9 => self.slide_i16x32::<9>(a, padding),
+            10 => self.slide_i16x32::<10>(a, padding),
+            11 => self.slide_i16x32::<11>(a, padding),
+            12 => self.slide_i16x32::<12>(a, padding),
+            13 => self.slide_i16x32::<13>(a, padding),
+            14 => self.slide_i16x32::<14>(a, padding),
+            15 => self.slide_i16x32::<15>(a, padding),
+            16 => self.slide_i16x32::<16>(a, padding),
+            17 => self.slide_i16x32::<17>(a, padding),
+            18 => self.slide_i16x32::<18>(a, padding),
+            19 => self.slide_i16x32::<19>(a, padding),
+            20 => self.slide_i16x32::<20>(a, padding),
+            21 => self.slide_i16x32::<21>(a, padding),
+            22 => self.slide_i16x32::<22>(a, padding),
+            23 => self.slide_i16x32::<23>(a, padding),
+            24 => self.slide_i16x32::<24>(a, padding),
+            25 => self.slide_i16x32::<25>(a, padding),
+            26 => self.slide_i16x32::<26>(a, padding),
+            27 => self.slide_i16x32::<27>(a, padding),
+            28 => self.slide_i16x32::<28>(a, padding),
+            29 => self.slide_i16x32::<29>(a, padding),
+            30 => self.slide_i16x32::<30>(a, padding),
+            31 => self.slide_i16x32::<31>(a, padding),
+            32 => self.slide_i16x32::<32>(a, padding),
+            _ => self.slide_i16x32::<32>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i16x32<const OFFSET: usize>(
+        self,
+        a: i16x32<Self>,
+        padding: i16,
+    ) -> i16x32<Self> {
+        let padding = self.splat_i16x32(padding);
+        match OFFSET {
+            0 => self.slide_i16x32::<32>(padding, a),
+            1 => self.slide_i16x32::<31>(padding, a),
+            2 => self.slide_i16x32::<30>(padding, a),
+            3 => self.slide_i16x32::<29>(padding, a),
+            4 => self.slide_i16x32::<28>(padding, a),
+            5 => self.slide_i16x32::<27>(padding, a),
+            6 => self.slide_i16x32::<26>(padding, a),
+            7 => self.slide_i16x32::<25>(padding, a),
+            8 => self.slide_i16x32::<24>(padding, a),
+            9 => self.slide_i16x32::<23>(padding, a),
+            10 => self.slide_i16x32::<22>(padding, a),
+            11 => self.slide_i16x32::<21>(padding, a),
+            12 => self.slide_i16x32::<20>(padding, a),
+            13 => self.slide_i16x32::<19>(padding, a),
+            14 => self.slide_i16x32::<18>(padding, a),
+            15 => self.slide_i16x32::<17>(padding, a),
+            16 => self.slide_i16x32::<16>(padding, a),
+            17 => self.slide_i16x32::<15>(padding, a),
+            18 => self.slide_i16x32::<14>(padding, a),
+            19 => self.slide_i16x32::<13>(padding, a),
+            20 => self.slide_i16x32::<12>(padding, a),
+            21 => self.slide_i16x32::<11>(padding, a),
+            22 => self.slide_i16x32::<10>(padding, a),
+            23 => self.slide_i16x32::<9>(padding, a),
+            24 => self.slide_i16x32::<8>(padding, a),
+            25 => self.slide_i16x32::<7>(padding, a),
+            26 => self.slide_i16x32::<6>(padding, a),
+            27 => self.slide_i16x32::<5>(padding, a),
+            28 => self.slide_i16x32::<4>(padding, a),
+            29 => self.slide_i16x32::<3>(padding, a),
+            30 => self.slide_i16x32::<2>(padding, a),
+            31 => self.slide_i16x32::<1>(padding, a),
+            32 => self.slide_i16x32::<0>(padding, a),
+            _ => self.slide_i16x32::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i16x32<const LANE: usize>(self, a: i16x32<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x32(a);
+        match LANE {
+            0 => self.extract_i16x16::<0>(a0),
+            1 => self.extract_i16x16::<1>(a0),
+            2 => self.extract_i16x16::<2>(a0),
+            3 => self.extract_i16x16::<3>(a0),
+            4 => self.extract_i16x16::<4>(a0),
+            5 => self.extract_i16x16::<5>(a0),
+            6 => self.extract_i16x16::<6>(a0),
+            7 => self.extract_i16x16::<7>(a0),
+            8 => self.extract_i16x16::<8>(a0),
+            9 => self.extract_i16x16::<9>(a0),
+            10 => self.extract_i16x16::<10>(a0),
+            11 => self.extract_i16x16::<11>(a0),
+            12 => self.extract_i16x16::<12>(a0),
+            13 => self.extract_i16x16::<13>(a0),
+            14 => self.extract_i16x16::<14>(a0),
+            15 => self.extract_i16x16::<15>(a0),
+            16 => self.extract_i16x16::<0>(a1),
+            17 => self.extract_i16x16::<1>(a1),
+            18 => self.extract_i16x16::<2>(a1),
+            19 => self.extract_i16x16::<3>(a1),
+            20 => self.extract_i16x16::<4>(a1),
+            21 => self.extract_i16x16::<5>(a1),
+            22 => self.extract_i16x16::<6>(a1),
+            23 => self.extract_i16x16::<7>(a1),
+            24 => self.extract_i16x16::<8>(a1),
+            25 => self.extract_i16x16::<9>(a1),
+            26 => self.extract_i16x16::<10>(a1),
+            27 => self.extract_i16x16::<11>(a1),
+            28 => self.extract_i16x16::<12>(a1),
+            29 => self.extract_i16x16::<13>(a1),
+            30 => self.extract_i16x16::<14>(a1),
+            31 => self.extract_i16x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i16x32<const LANE: usize>(self, a: i16x32<Self>, value: i16) -> i16x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x32(a);
+        match LANE {
+            0 => self.combine_i16x16(self.insert_i16x16::<0>(a0, value), a1),
+            1 => self.combine_i16x16(self.insert_i16x16::<1>(a0, value), a1),
+            2 => self.combine_i16x16(self.insert_i16x16::<2>(a0, value), a1),
+            3 => self.combine_i16x16(self.insert_i16x16::<3>(a0, value), a1),
+            4 => self.combine_i16x16(self.insert_i16x16::<4>(a0, value), a1),
+            5 => self.combine_i16x16(self.insert_i16x16::<5>(a0, value), a1),
+            6 => self.combine_i16x16(self.insert_i16x16::<6>(a0, value), a1),
+            7 => self.combine_i16x16(self.insert_i16x16::<7>(a0, value), a1),
+            8 => self.combine_i16x16(self.insert_i16x16::<8>(a0, value), a1),
+            9 => self.combine_i16x16(self.insert_i16x16::<9>(a0, value), a1),
+            10 => self.combine_i16x16(self.insert_i16x16::<10>(a0, value), a1),
+            11 => self.combine_i16x16(self.insert_i16x16::<11>(a0, value), a1),
+            12 => self.combine_i16x16(self.insert_i16x16::<12>(a0, value), a1),
+            13 => self.combine_i16x16(self.insert_i16x16::<13>(a0, value), a1),
+            14 => self.combine_i16x16(self.insert_i16x16::<14>(a0, value), a1),
+            15 => self.combine_i16x16(self.insert_i16x16::<15>(a0, value), a1),
+            16 => self.combine_i16x16(a0, self.insert_i16x16::<0>(a1, value)),
+            17 => self.combine_i16x16(a0, self.insert_i16x16::<1>(a1, value)),
+            18 => self.combine_i16x16(a0, self.insert_i16x16::<2>(a1, value)),
+            19 => self.combine_i16x16(a0, self.insert_i16x16::<3>(a1, value)),
+            20 => self.combine_i16x16(a0, self.insert_i16x16::<4>(a1, value)),
+            21 => self.combine_i16x16(a0, self.insert_i16x16::<5>(a1, value)),
+            22 => self.combine_i16x16(a0, self.insert_i16x16::<6>(a1, value)),
+            23 => self.combine_i16x16(a0, self.insert_i16x16::<7>(a1, value)),
+            24 => self.combine_i16x16(a0, self.insert_i16x16::<8>(a1, value)),
+            25 => self.combine_i16x16(a0, self.insert_i16x16::<9>(a1, value)),
+            26 => self.combine_i16x16(a0, self.insert_i16x16::<10>(a1, value)),
+            27 => self.combine_i16x16(a0, self.insert_i16x16::<11>(a1, value)),
+            28 => self.combine_i16x16(a0, self.insert_i16x16::<12>(a1, value)),
+            29 => self.combine_i16x16(a0, self.insert_i16x16::<13>(a1, value)),
+            30 => self.combine_i16x16(a0, self.insert_i16x16::<14>(a1, value)),
+            31 => self.combine_i16x16(a0, self.insert_i16x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x32(self, a: i16x32<Self>, index: usize) -> i16 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_i16x32(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i16x32(
+        self,
+        a: i16x32<Self>,
+        indices: u8x64<Self>,
+    ) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_i16x16(
+            self.swizzle_dyn_within_blocks_i16x16(a0, indices0),
+            self.swizzle_dyn_within_blocks_i16x16(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.add_i16x16(a0, b0), self.add_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.sub_i16x16(a0, b0), self.sub_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_i16x16(a0, b0), self.mul_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.and_i16x16(a0, b0), self.and_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.or_i16x16(a0, b0), self.or_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.xor_i16x16(a0, b0), self.xor_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn not_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.not_i16x16(a0), self.not_i16x16(a1))
+    }
+    #[inline(always)]
+    fn shl_i16x32(self, a: i16x32<Self>, shift: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shl_i16x16(a0, shift), self.shl_i16x16(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.shlv_i16x16(a0, b0), self.shlv_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_i16x32(self, a: i16x32<Self>, shift: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shr_i16x16(a0, shift), self.shr_i16x16(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.shrv_i16x16(a0, b0), self.shrv_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.shift_signed_i16x16(a0, b0),
+            self.shift_signed_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_eq_i16x16(a0, b0), self.simd_eq_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_lt_i16x16(a0, b0), self.simd_lt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_le_i16x16(a0, b0), self.simd_le_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_ge_i16x16(a0, b0), self.simd_ge_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_gt_i16x16(a0, b0), self.simd_gt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, _) = self.split_i16x32(a);
+        let (b0, _) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a0, b0), self.zip_high_i16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (_, a1) = self.split_i16x32(a);
+        let (_, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a1, b1), self.zip_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.unzip_low_i16x16(a0, a1), self.unzip_low_i16x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.unzip_high_i16x16(a0, a1),
+            self.unzip_high_i16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn interleave_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> (i16x32<Self>, i16x32<Self>) {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let lo_lo = self.zip_low_i16x16(a0, b0);
+        let lo_hi = self.zip_high_i16x16(a0, b0);
+        let hi_lo = self.zip_low_i16x16(a1, b1);
+        let hi_hi = self.zip_high_i16x16(a1, b1);
+        (
+            self.combine_i16x16(lo_lo, lo_hi),
+            self.combine_i16x16(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> (i16x32<Self>, i16x32<Self>) {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let lo_even = self.unzip_low_i16x16(a0, a1);
+        let lo_odd = self.unzip_high_i16x16(a0, a1);
+        let hi_even = self.unzip_low_i16x16(b0, b1);
+        let hi_odd = self.unzip_high_i16x16(b0, b1);
+        (
+            self.combine_i16x16(lo_even, hi_even),
+            self.combine_i16x16(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_i16x32(self, a: mask16x32<Self>, b: i16x32<Self>, c: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_i16x16(a0, b0, c0),
+            self.select_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn select_relaxed_i16x32(
+        self,
+        a: mask16x32<Self>,
+        b: i16x32<Self>,
+        c: i16x32<Self>,
+    ) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_relaxed_i16x16(a0, b0, c0),
+            self.select_relaxed_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.min_i16x16(a0, b0), self.min_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_add_i16x16(a0, b0),
+            self.saturating_add_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_sub_i16x16(a0, b0),
+            self.saturating_sub_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.count_ones_i16x16(a0), self.count_ones_i16x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.leading_zeros_i16x16(a0), self.leading_zeros_i16x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(
+            self.trailing_zeros_i16x16(a0),
+            self.trailing_zeros_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
+        (
+            i16x16 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            i16x16 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn neg_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.neg_i16x16(a0), self.neg_i16x16(a1))
+    }
+    #[inline(always)]
+    fn pack_saturate_i8_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x32<Avx2>) -> i8x32<Avx2> {
+                let (lo, hi) = token.split_i16x32(a);
+                let (lo, hi) = (lo.into(), hi.into());
+                _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packs_epi16(lo, hi))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_i16x32(self, a: i16x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: i16x32<Avx2>) -> u8x32<Avx2> {
+                let (lo, hi) = token.split_i16x32(a);
+                let (lo, hi) = (lo.into(), hi.into());
+                _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packus_epi16(lo, hi))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x32(self, a: i16x32<Self>) -> i32 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_add_widened_i16x16(a0) + self.reduce_add_widened_i16x16(a1)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_i16x16(a0),
+            self.reinterpret_u8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x32(self, a: i16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i16x16(a0),
+            self.reinterpret_u32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u16x32(self, val: u16) -> u16x32<Self> {
+        let half = self.splat_u16x16(val);
+        self.combine_u16x16(half, half)
+    }
+    #[inline(always)]
+    fn load_array_u16x32(self, val: [u16; 32usize]) -> u16x32<Self> {
+        u16x32 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u16x32(self, val: &[u16; 32usize]) -> u16x32<Self> {
+        u16x32 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u16x32(self, a: u16x32<Self>) -> [u16; 32usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [u16; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u16x32(self, a: &u16x32<Self>) -> &[u16; 32usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [u16; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u16x32(self, a: &mut u16x32<Self>) -> &mut [u16; 32usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [u16; 32usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u16x32(self, a: u8x64<Self>) -> u16x32<Self> {
+        u16x32 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u16x32<const SHIFT: usize>(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_u16x32(b).val.0,
+            self.cvt_to_bytes_u16x32(a).val.0,
+            SHIFT * 2usize,
+        );
+        self.cvt_from_bytes_u16x32(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u16x32<const SHIFT: usize>(
+        self,
+        a: u16x32<Self>,
+        b: u16x32<Self>,
+    ) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.slide_within_blocks_u16x16::<SHIFT>(a0, b0),
+            self.slide_within_blocks_u16x16::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u16x32<const OFFSET: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_u16x32::<0>(a, a),
+            1 => self.slide_u16x32::<1>(a, a),
+            2 => self.slide_u16x32::<2>(a, a),
+            3 => self.slide_u16x32::<3>(a, a),
+            4 => self.slide_u16x32::<4>(a, a),
+            5 => self.slide_u16x32::<5>(a, a),
+            6 => self.slide_u16x32::<6>(a, a),
+            7 => self.slide_u16x32::<7>(a, a),
+            8 => self.slide_u16x32::<8>(a, a),
+            9 => self.slide_u16x32::<9>(a, a),
+            10 => self.slide_u16x32::<10>(a, a),
+            11 => self.slide_u16x32::<11>(a, a),
+            12 => self.slide_u16x32::<12>(a, a),
+            13 => self.slide_u16x32::<13>(a, a),
+            14 => self.slide_u16x32::<14>(a, a),
+            15 => self.slide_u16x32::<15>(a, a),
+            16 => self.slide_u16x32::<16>(a, a),
+            17 => self.slide_u16x32::<17>(a, a),
+            18 => self.slide_u16x32::<18>(a, a),
+            19 => self.slide_u16x32::<19>(a, a),
+            20 => self.slide_u16x32::<20>(a, a),
+            21 => self.slide_u16x32::<21>(a, a),
+            22 => self.slide_u16x32::<22>(a, a),
+            23 => self.slide_u16x32::<23>(a, a),
+            24 => self.slide_u16x32::<24>(a, a),
+            25 => self.slide_u16x32::<25>(a, a),
+            26 => self.slide_u16x32::<26>(a, a),
+            27 => self.slide_u16x32::<27>(a, a),
+            28 => self.slide_u16x32::<28>(a, a),
+            29 => self.slide_u16x32::<29>(a, a),
+            30 => self.slide_u16x32::<30>(a, a),
+            31 => self.slide_u16x32::<31>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u16x32<const OFFSET: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        match OFFSET % 32 {
+            0 => self.slide_u16x32::<32>(a, a),
+            1 => self.slide_u16x32::<31>(a, a),
+            2 => self.slide_u16x32::<30>(a, a),
+            3 => self.slide_u16x32::<29>(a, a),
+            4 => self.slide_u16x32::<28>(a, a),
+            5 => self.slide_u16x32::<27>(a, a),
+            6 => self.slide_u16x32::<26>(a, a),
+            7 => self.slide_u16x32::<25>(a, a),
+            8 => self.slide_u16x32::<24>(a, a),
+            9 => self.slide_u16x32::<23>(a, a),
+            10 => self.slide_u16x32::<22>(a, a),
+            11 => self.slide_u16x32::<21>(a, a),
+            12 => self.slide_u16x32::<20>(a, a),
+            13 => self.slide_u16x32::<19>(a, a),
+            14 => self.slide_u16x32::<18>(a, a),
+            15 => self.slide_u16x32::<17>(a, a),
+            16 => self.slide_u16x32::<16>(a, a),
+            17 => self.slide_u16x32::<15>(a, a),
+            18 => self.slide_u16x32::<14>(a, a),
+            19 => self.slide_u16x32::<13>(a, a),
+            20 => self.slide_u16x32::<12>(a, a),
+            21 => self.slide_u16x32::<11>(a, a),
+            22 => self.slide_u16x32::<10>(a, a),
+            23 => self.slide_u16x32::<9>(a, a),
+            24 => self.slide_u16x32::<8>(a, a),
+            25 => self.slide_u16x32::<7>(a, a),
+            26 => self.slide_u16x32::<6>(a, a),
+            27 => self.slide_u16x32::<5>(a, a),
+            28 => self.slide_u16x32::<4>(a, a),
+            29 => self.slide_u16x32::<3>(a, a),
+            30 => self.slide_u16x32::<2>(a, a),
+            31 => self.slide_u16x32::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u16x32<const OFFSET: usize>(
+        self,
+        a: u16x32<Self>,
+        padding: u16,
+    ) -> u16x32<Self> {
+        let padding = self.splat_u16x32(padding);
+        match OFFSET {
+            0 => self.slide_u16x32::<0>(a, padding),
+            1 => self.slide_u16x32::<1>(a, padding),
+            2 => self.slide_u16x32::<2>(a, padding),
+            3 => self.slide_u16x32::<3>(a, padding),
+            4 => self.slide_u16x32::<4>(a, padding),
+            5 => self.slide_u16x32::<5>(a, padding),
+            6 => self.slide_u16x32::<6>(a, padding),
+            7 => self.slide_u16x32::<7>(a, padding),
+            8 => self.slide_u16x32::<8>(a, padding),
+            9 => self.slide_u16x32::<9>(a, padding),
+            10 => self.slide_u16x32::<10>(a, padding),
+            11 => self.slide_u16x32::<11>(a, padding),
+            12 => self.slide_u16x32::<12>(a, padding),
+            13 => self.slide_u16x32::<13>(a, padding),
+            14 => self.slide_u16x32::<14>(a, padding),
+            15 => self.slide_u16x32::<15>(a, padding),
+            16 => self.slide_u16x32::<16>(a, padding),
+            17 => self.slide_u16x32::<17>(a, padding),
+            18 => self.slide_u16x32::<18>(a, padding),
+            19 => self.slide_u16x32::<19>(a, padding),
+            20 => self.slide_u16x32::<20>(a, padding),
+            21 => self.slide_u16x32::<21>(a, padding),
+            22 => self.slide_u16x32::<22>(a, padding),
+            23 => self.slide_u16x32::<23>(a, padding),
+            24 => self.slide_u16x32::<24>(a, padding),
+            25 => self.slide_u16x32::<25>(a, padding),
+            26 => self.slide_u16x32::<26>(a, padding),
+            27 => self.slide_u16x32::<27>(a, padding),
+            28 => self.slide_u16x32::<28>(a, padding),
+            29 => self.slide_u16x32::<29>(a, padding),
+            30 => self.slide_u16x32::<30>(a, padding),
+            31 => self.slide_u16x32::<31>(a, padding),
+            32 => self.slide_u16x32::<32>(a, padding),
+            _ => self.slide_u16x32::<32>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u16x32<const OFFSET: usize>(
+        self,
+        a: u16x32<Self>,
+        padding: u16,
+    ) -> u16x32<Self> {
+        let padding = self.splat_u16x32(padding);
+        match OFFSET {
+            0 => self.slide_u16x32::<32>(padding, a),
+            1 => self.slide_u16x32::<31>(padding, a),
+            2 => self.slide_u16x32::<30>(padding, a),
+            3 => self.slide_u16x32::<29>(padding, a),
+            4 => self.slide_u16x32::<28>(padding, a),
+            5 => self.slide_u16x32::<27>(padding, a),
+            6 => self.slide_u16x32::<26>(padding, a),
+            7 => self.slide_u16x32::<25>(padding, a),
+            8 => self.slide_u16x32::<24>(padding, a),
+            9 => self.slide_u16x32::<23>(padding, a),
+            10 => self.slide_u16x32::<22>(padding, a),
+            11 => self.slide_u16x32::<21>(padding, a),
+            12 => self.slide_u16x32::<20>(padding, a),
+            13 => self.slide_u16x32::<19>(padding, a),
+            14 => self.slide_u16x32::<18>(padding, a),
+            15 => self.slide_u16x32::<17>(padding, a),
+            16 => self.slide_u16x32::<16>(padding, a),
+            17 => self.slide_u16x32::<15>(padding, a),
+            18 => self.slide_u16x32::<14>(padding, a),
+            19 => self.slide_u16x32::<13>(padding, a),
+            20 => self.slide_u16x32::<12>(padding, a),
+            21 => self.slide_u16x32::<11>(padding, a),
+            22 => self.slide_u16x32::<10>(padding, a),
+            23 => self.slide_u16x32::<9>(padding, a),
+            24 => self.slide_u16x32::<8>(padding, a),
+            25 => self.slide_u16x32::<7>(padding, a),
+            26 => self.slide_u16x32::<6>(padding, a),
+            27 => self.slide_u16x32::<5>(padding, a),
+            28 => self.slide_u16x32::<4>(padding, a),
+            29 => self.slide_u16x32::<3>(padding, a),
+            30 => self.slide_u16x32::<2>(padding, a),
+            31 => self.slide_u16x32::<1>(padding, a),
+            32 => self.slide_u16x32::<0>(padding, a),
+            _ => self.slide_u16x32::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u16x32<const LANE: usize>(self, a: u16x32<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x32(a);
+        match LANE {
+            0 => self.extract_u16x16::<0>(a0),
+            1 => self.extract_u16x16::<1>(a0),
+            2 => self.extract_u16x16::<2>(a0),
+            3 => self.extract_u16x16::<3>(a0),
+            4 => self.extract_u16x16::<4>(a0),
+            5 => self.extract_u16x16::<5>(a0),
+            6 => self.extract_u16x16::<6>(a0),
+            7 => self.extract_u16x16::<7>(a0),
+            8 => self.extract_u16x16::<8>(a0),
+            9 => self.extract_u16x16::<9>(a0),
+            10 => self.extract_u16x16::<10>(a0),
+            11 => self.extract_u16x16::<11>(a0),
+            12 => self.extract_u16x16::<12>(a0),
+            13 => self.extract_u16x16::<13>(a0),
+            14 => self.extract_u16x16::<14>(a0),
+            15 => self.extract_u16x16::<15>(a0),
+            16 => self.extract_u16x16::<0>(a1),
+            17 => self.extract_u16x16::<1>(a1),
+            18 => self.extract_u16x16::<2>(a1),
+            19 => self.extract_u16x16::<3>(a1),
+            20 => self.extract_u16x16::<4>(a1),
+            21 => self.extract_u16x16::<5>(a1),
+            22 => self.extract_u16x16::<6>(a1),
+            23 => self.extract_u16x16::<7>(a1),
+            24 => self.extract_u16x16::<8>(a1),
+            25 => self.extract_u16x16::<9>(a1),
+            26 => self.extract_u16x16::<10>(a1),
+            27 => self.extract_u16x16::<11>(a1),
+            28 => self.extract_u16x16::<12>(a1),
+            29 => self.extract_u16x16::<13>(a1),
+            30 => self.extract_u16x16::<14>(a1),
+            31 => self.extract_u16x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u16x32<const LANE: usize>(self, a: u16x32<Self>, value: u16) -> u16x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x32(a);
+        match LANE {
+            0 => self.combine_u16x16(self.insert_u16x16::<0>(a0, value), a1),
+            1 => self.combine_u16x16(self.insert_u16x16::<1>(a0, value), a1),
+            2 => self.combine_u16x16(self.insert_u16x16::<2>(a0, value), a1),
+            3 => self.combine_u16x16(self.insert_u16x16::<3>(a0, value), a1),
+            4 => self.combine_u16x16(self.insert_u16x16::<4>(a0, value), a1),
+            5 => self.combine_u16x16(self.insert_u16x16::<5>(a0, value), a1),
+            6 => self.combine_u16x16(self.insert_u16x16::<6>(a0, value), a1),
+            7 => self.combine_u16x16(self.insert_u16x16::<7>(a0, value), a1),
+            8 => self.combine_u16x16(self.insert_u16x16::<8>(a0, value), a1),
+            9 => self.combine_u16x16(self.insert_u16x16::<9>(a0, value), a1),
+            10 => self.combine_u16x16(self.insert_u16x16::<10>(a0, value), a1),
+            11 => self.combine_u16x16(self.insert_u16x16::<11>(a0, value), a1),
+            12 => self.combine_u16x16(self.insert_u16x16::<12>(a0, value), a1),
+            13 => self.combine_u16x16(self.insert_u16x16::<13>(a0, value), a1),
+            14 => self.combine_u16x16(self.insert_u16x16::<14>(a0, value), a1),
+            15 => self.combine_u16x16(self.insert_u16x16::<15>(a0, value), a1),
+            16 => self.combine_u16x16(a0, self.insert_u16x16::<0>(a1, value)),
+            17 => self.combine_u16x16(a0, self.insert_u16x16::<1>(a1, value)),
+            18 => self.combine_u16x16(a0, self.insert_u16x16::<2>(a1, value)),
+            19 => self.combine_u16x16(a0, self.insert_u16x16::<3>(a1, value)),
+            20 => self.combine_u16x16(a0, self.insert_u16x16::<4>(a1, value)),
+            21 => self.combine_u16x16(a0, self.insert_u16x16::<5>(a1, value)),
+            22 => self.combine_u16x16(a0, self.insert_u16x16::<6>(a1, value)),
+            23 => self.combine_u16x16(a0, self.insert_u16x16::<7>(a1, value)),
+            24 => self.combine_u16x16(a0, self.insert_u16x16::<8>(a1, value)),
+            25 => self.combine_u16x16(a0, self.insert_u16x16::<9>(a1, value)),
+            26 => self.combine_u16x16(a0, self.insert_u16x16::<10>(a1, value)),
+            27 => self.combine_u16x16(a0, self.insert_u16x16::<11>(a1, value)),
+            28 => self.combine_u16x16(a0, self.insert_u16x16::<12>(a1, value)),
+            29 => self.combine_u16x16(a0, self.insert_u16x16::<13>(a1, value)),
+            30 => self.combine_u16x16(a0, self.insert_u16x16::<14>(a1, value)),
+            31 => self.combine_u16x16(a0, self.insert_u16x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x32(self, a: u16x32<Self>, index: usize) -> u16 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_u16x32(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u16x32(
+        self,
+        a: u16x32<Self>,
+        indices: u8x64<Self>,
+    ) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_u16x16(
+            self.swizzle_dyn_within_blocks_u16x16(a0, indices0),
+            self.swizzle_dyn_within_blocks_u16x16(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.add_u16x16(a0, b0), self.add_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.sub_u16x16(a0, b0), self.sub_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_u16x16(a0, b0), self.mul_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.and_u16x16(a0, b0), self.and_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.or_u16x16(a0, b0), self.or_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.xor_u16x16(a0, b0), self.xor_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn not_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.not_u16x16(a0), self.not_u16x16(a1))
+    }
+    #[inline(always)]
+    fn shl_u16x32(self, a: u16x32<Self>, shift: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shl_u16x16(a0, shift), self.shl_u16x16(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.shlv_u16x16(a0, b0), self.shlv_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_u16x32(self, a: u16x32<Self>, shift: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shr_u16x16(a0, shift), self.shr_u16x16(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.shrv_u16x16(a0, b0), self.shrv_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_u16x32(self, a: u16x32<Self>, b: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_u16x16(
+            self.shift_signed_u16x16(a0, b0),
+            self.shift_signed_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_eq_u16x16(a0, b0), self.simd_eq_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_lt_u16x16(a0, b0), self.simd_lt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_le_u16x16(a0, b0), self.simd_le_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_ge_u16x16(a0, b0), self.simd_ge_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_gt_u16x16(a0, b0), self.simd_gt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, _) = self.split_u16x32(a);
+        let (b0, _) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a0, b0), self.zip_high_u16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (_, a1) = self.split_u16x32(a);
+        let (_, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a1, b1), self.zip_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.unzip_low_u16x16(a0, a1), self.unzip_low_u16x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.unzip_high_u16x16(a0, a1),
+            self.unzip_high_u16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn interleave_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> (u16x32<Self>, u16x32<Self>) {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let lo_lo = self.zip_low_u16x16(a0, b0);
+        let lo_hi = self.zip_high_u16x16(a0, b0);
+        let hi_lo = self.zip_low_u16x16(a1, b1);
+        let hi_hi = self.zip_high_u16x16(a1, b1);
+        (
+            self.combine_u16x16(lo_lo, lo_hi),
+            self.combine_u16x16(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> (u16x32<Self>, u16x32<Self>) {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let lo_even = self.unzip_low_u16x16(a0, a1);
+        let lo_odd = self.unzip_high_u16x16(a0, a1);
+        let hi_even = self.unzip_low_u16x16(b0, b1);
+        let hi_odd = self.unzip_high_u16x16(b0, b1);
+        (
+            self.combine_u16x16(lo_even, hi_even),
+            self.combine_u16x16(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_u16x32(self, a: mask16x32<Self>, b: u16x32<Self>, c: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_u16x16(a0, b0, c0),
+            self.select_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn select_relaxed_u16x32(
+        self,
+        a: mask16x32<Self>,
+        b: u16x32<Self>,
+        c: u16x32<Self>,
+    ) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_relaxed_u16x16(a0, b0, c0),
+            self.select_relaxed_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.min_u16x16(a0, b0), self.min_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_add_u16x16(a0, b0),
+            self.saturating_add_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_sub_u16x16(a0, b0),
+            self.saturating_sub_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.count_ones_u16x16(a0), self.count_ones_u16x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.leading_zeros_u16x16(a0), self.leading_zeros_u16x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(
+            self.trailing_zeros_u16x16(a0),
+            self.trailing_zeros_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
+        (
+            u16x16 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            u16x16 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u16x32(self, src: &[u16; 32usize]) -> u16x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, src: &[u16; 32usize]) -> u16x32<Avx2> {
+                let (chunks, []) = src.as_chunks::<8usize>() else {
+                    unreachable!()
+                };
+                let v0: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u16; 8usize], __m128i>(&chunks[0]);
+                let v1: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u16; 8usize], __m128i>(&chunks[1]);
+                let v2: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u16; 8usize], __m128i>(&chunks[2]);
+                let v3: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u16; 8usize], __m128i>(&chunks[3]);
+                let mask = _mm_setr_epi8(0, 1, 8, 9, 2, 3, 10, 11, 4, 5, 12, 13, 6, 7, 14, 15);
+                let v0 = _mm_shuffle_epi8(v0, mask);
+                let v1 = _mm_shuffle_epi8(v1, mask);
+                let v2 = _mm_shuffle_epi8(v2, mask);
+                let v3 = _mm_shuffle_epi8(v3, mask);
+                let tmp0 = _mm_unpacklo_epi32(v0, v1);
+                let tmp1 = _mm_unpackhi_epi32(v0, v1);
+                let tmp2 = _mm_unpacklo_epi32(v2, v3);
+                let tmp3 = _mm_unpackhi_epi32(v2, v3);
+                let out0 = _mm_unpacklo_epi64(tmp0, tmp2);
+                let out1 = _mm_unpackhi_epi64(tmp0, tmp2);
+                let out2 = _mm_unpacklo_epi64(tmp1, tmp3);
+                let out3 = _mm_unpackhi_epi64(tmp1, tmp3);
+                token.combine_u16x16(
+                    token.combine_u16x8(out0.simd_into(token), out1.simd_into(token)),
+                    token.combine_u16x8(out2.simd_into(token), out3.simd_into(token)),
+                )
+            }
+        );
+        kernel(self, src)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> () {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x32<Avx2>, dest: &mut [u16; 32usize]) -> () {
+                let (v01, v23) = token.split_u16x32(a);
+                let (v0, v1) = token.split_u16x16(v01);
+                let (v2, v3) = token.split_u16x16(v23);
+                let v0 = v0.into();
+                let v1 = v1.into();
+                let v2 = v2.into();
+                let v3 = v3.into();
+                let tmp0 = _mm_unpacklo_epi32(v0, v1);
+                let tmp1 = _mm_unpackhi_epi32(v0, v1);
+                let tmp2 = _mm_unpacklo_epi32(v2, v3);
+                let tmp3 = _mm_unpackhi_epi32(v2, v3);
+                let out0 = _mm_unpacklo_epi64(tmp0, tmp2);
+                let out1 = _mm_unpackhi_epi64(tmp0, tmp2);
+                let out2 = _mm_unpacklo_epi64(tmp1, tmp3);
+                let out3 = _mm_unpackhi_epi64(tmp1, tmp3);
+                let mask = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                let out0 = _mm_shuffle_epi8(out0, mask);
+                let out1 = _mm_shuffle_epi8(out1, mask);
+                let out2 = _mm_shuffle_epi8(out2, mask);
+                let out3 = _mm_shuffle_epi8(out3, mask);
+                let (chunks, []) = dest.as_chunks_mut::<8usize>() else {
+                    unreachable!()
+                };
+                crate::transmute::checked_transmute_store::<__m128i, [u16; 8usize]>(
+                    out0,
+                    &mut chunks[0],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u16; 8usize]>(
+                    out1,
+                    &mut chunks[1],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u16; 8usize]>(
+                    out2,
+                    &mut chunks[2],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u16; 8usize]>(
+                    out3,
+                    &mut chunks[3],
+                );
+            }
+        );
+        kernel(self, a, dest);
+    }
+    #[inline(always)]
+    fn narrow_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x32<Avx2>) -> u8x32<Avx2> {
+                let (a, b) = token.split_u16x32(a);
+                let mask = _mm256_set1_epi16(0xFF);
+                let lo_masked = _mm256_and_si256(a.into(), mask);
+                let hi_masked = _mm256_and_si256(b.into(), mask);
+                let result = _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packus_epi16(
+                    lo_masked, hi_masked,
+                ));
+                result.simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_i8_u16x32(self, a: u16x32<Self>) -> i8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x32<Avx2>) -> i8x32<Avx2> {
+                let (lo, hi) = token.split_u16x32(a);
+                let (lo, hi) = (lo.into(), hi.into());
+                let max = _mm256_set1_epi16(127);
+                let lo = _mm256_min_epu16(lo, max);
+                let hi = _mm256_min_epu16(hi, max);
+                _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packs_epi16(lo, hi))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u16x32<Avx2>) -> u8x32<Avx2> {
+                let (lo, hi) = token.split_u16x32(a);
+                let (lo, hi) = (lo.into(), hi.into());
+                let max = _mm256_set1_epi16(255);
+                let lo = _mm256_min_epu16(lo, max);
+                let hi = _mm256_min_epu16(hi, max);
+                _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packus_epi16(lo, hi))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.avg_round_u16x16(a0, b0), self.avg_round_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x32(self, a: u16x32<Self>) -> u32 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_add_widened_u16x16(a0) + self.reduce_add_widened_u16x16(a1)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_u16x16(a0),
+            self.reinterpret_u8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u16x16(a0),
+            self.reinterpret_u32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask16x32(self, val: bool) -> mask16x32<Self> {
+        let half = self.splat_mask16x16(val);
+        self.combine_mask16x16(half, half)
+    }
+    #[inline(always)]
+    fn load_array_mask16x32(self, val: [i16; 32usize]) -> mask16x32<Self> {
+        mask16x32 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask16x32(self, a: mask16x32<Self>) -> [i16; 32usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i16; 32usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self> {
+        i16x32 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self> {
+        let lo = self.from_bitmask_mask16x16(bits);
+        let hi = self.from_bitmask_mask16x16(bits >> 16usize);
+        self.combine_mask16x16(lo, hi)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x32(self, a: mask16x32<Self>) -> u64 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: mask16x32<Avx2>) -> u64 {
+                {
+                    let lo = _mm256_movemask_epi8(a.val.0[0]) as u32;
+                    let hi = _mm256_movemask_epi8(a.val.0[1]) as u32;
+                    let lo = _pext_u32(lo, 0x5555_5555u32) as u64;
+                    let hi = _pext_u32(hi, 0x5555_5555u32) as u64;
+                    lo | (hi << 16usize)
+                }
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn set_mask16x32(self, a: &mut mask16x32<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 32usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        let mut lanes = self.as_array_mask16x32(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask16x32(lanes);
+    }
+    #[inline(always)]
+    fn and_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.and_mask16x16(a0, b0), self.and_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.or_mask16x16(a0, b0), self.or_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.xor_mask16x16(a0, b0), self.xor_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn not_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_mask16x16(self.not_mask16x16(a0), self.not_mask16x16(a1))
+    }
+    #[inline(always)]
+    fn select_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_mask16x16(a0, b0, c0),
+            self.select_mask16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn select_relaxed_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_relaxed_mask16x16(a0, b0, c0),
+            self.select_relaxed_mask16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.simd_eq_mask16x16(a0, b0),
+            self.simd_eq_mask16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn any_true_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.any_true_mask16x16(a0) || self.any_true_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn all_true_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.all_true_mask16x16(a0) && self.all_true_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn any_false_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.any_false_mask16x16(a0) || self.any_false_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn all_false_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.all_false_mask16x16(a0) && self.all_false_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn split_mask16x32(self, a: mask16x32<Self>) -> (mask16x16<Self>, mask16x16<Self>) {
+        (
+            mask16x16 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            mask16x16 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn splat_i32x16(self, val: i32) -> i32x16<Self> {
+        let half = self.splat_i32x8(val);
+        self.combine_i32x8(half, half)
+    }
+    #[inline(always)]
+    fn load_array_i32x16(self, val: [i32; 16usize]) -> i32x16<Self> {
+        i32x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i32x16(self, val: &[i32; 16usize]) -> i32x16<Self> {
+        i32x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i32x16(self, a: i32x16<Self>) -> [i32; 16usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i32x16(self, a: &i32x16<Self>) -> &[i32; 16usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [i32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i32x16(self, a: &mut i32x16<Self>) -> &mut [i32; 16usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [i32; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i32x16(self, a: i32x16<Self>, dest: &mut [i32; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i32x16(self, a: u8x64<Self>) -> i32x16<Self> {
+        i32x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i32x16(self, a: i32x16<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i32x16<const SHIFT: usize>(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_i32x16(b).val.0,
+            self.cvt_to_bytes_i32x16(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_i32x16(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i32x16<const SHIFT: usize>(
+        self,
+        a: i32x16<Self>,
+        b: i32x16<Self>,
+    ) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.slide_within_blocks_i32x8::<SHIFT>(a0, b0),
+            self.slide_within_blocks_i32x8::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i32x16<const OFFSET: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_i32x16::<0>(a, a),
+            1 => self.slide_i32x16::<1>(a, a),
+            2 => self.slide_i32x16::<2>(a, a),
+            3 => self.slide_i32x16::<3>(a, a),
+            4 => self.slide_i32x16::<4>(a, a),
+            5 => self.slide_i32x16::<5>(a, a),
+            6 => self.slide_i32x16::<6>(a, a),
+            7 => self.slide_i32x16::<7>(a, a),
+            8 => self.slide_i32x16::<8>(a, a),
+            9 => self.slide_i32x16::<9>(a, a),
+            10 => self.slide_i32x16::<10>(a, a),
+            11 => self.slide_i32x16::<11>(a, a),
+            12 => self.slide_i32x16::<12>(a, a),
+            13 => self.slide_i32x16::<13>(a, a),
+            14 => self.slide_i32x16::<14>(a, a),
+            15 => self.slide_i32x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i32x16<const OFFSET: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_i32x16::<16>(a, a),
+            1 => self.slide_i32x16::<15>(a, a),
+            2 => self.slide_i32x16::<14>(a, a),
+            3 => self.slide_i32x16::<13>(a, a),
+            4 => self.slide_i32x16::<12>(a, a),
+            5 => self.slide_i32x16::<11>(a, a),
+            6 => self.slide_i32x16::<10>(a, a),
+            7 => self.slide_i32x16::<9>(a, a),
+            8 => self.slide_i32x16::<8>(a, a),
+            9 => self.slide_i32x16::<7>(a, a),
+            10 => self.slide_i32x16::<6>(a, a),
+            11 => self.slide_i32x16::<5>(a, a),
+            12 => self.slide_i32x16::<4>(a, a),
+            13 => self.slide_i32x16::<3>(a, a),
+            14 => self.slide_i32x16::<2>(a, a),
+            15 => self.slide_i32x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i32x16<const OFFSET: usize>(
+        self,
+        a: i32x16<Self>,
+        padding: i32,
+    ) -> i32x16<Self> {
+        let padding = self.splat_i32x16(padding);
+        match OFFSET {
+            0 => self.slide_i32x16::<0>(a, padding),
+            1 => self.slide_i32x16::<1>(a, padding),
+            2 => self.slide_i32x16::<2>(a, padding),
+            3 => self.slide_i32x16::<3>(a, padding),
+            4 => self.slide_i32x16::<4>(a, padding),
+            5 => self.slide_i32x16::<5>(a, padding),
+            6 => self.slide_i32x16::<6>(a, padding),
+            7 => self.slide_i32x16::<7>(a, padding),
+            8 => self.slide_i32x16::<8>(a, padding),
+            9 => self.slide_i32x16::<9>(a, padding),
+            10 => self.slide_i32x16::<10>(a, padding),
+            11 => self.slide_i32x16::<11>(a, padding),
+            12 => self.slide_i32x16::<12>(a, padding),
+            13 => self.slide_i32x16::<13>(a, padding),
+            14 => self.slide_i32x16::<14>(a, padding),
+            15 => self.slide_i32x16::<15>(a, padding),
+            16 => self.slide_i32x16::<16>(a, padding),
+            _ => self.slide_i32x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i32x16<const OFFSET: usize>(
+        self,
+        a: i32x16<Self>,
+        padding: i32,
+    ) -> i32x16<Self> {
+        let padding = self.splat_i32x16(padding);
+        match OFFSET {
+            0 => self.slide_i32x16::<16>(padding, a),
+            1 => self.slide_i32x16::<15>(padding, a),
+            2 => self.slide_i32x16::<14>(padding, a),
+            3 => self.slide_i32x16::<13>(padding, a),
+            4 => self.slide_i32x16::<12>(padding, a),
+            5 => self.slide_i32x16::<11>(padding, a),
+            6 => self.slide_i32x16::<10>(padding, a),
+            7 => self.slide_i32x16::<9>(padding, a),
+            8 => self.slide_i32x16::<8>(padding, a),
+            9 => self.slide_i32x16::<7>(padding, a),
+            10 => self.slide_i32x16::<6>(padding, a),
+            11 => self.slide_i32x16::<5>(padding, a),
+            12 => self.slide_i32x16::<4>(padding, a),
+            13 => self.slide_i32x16::<3>(padding, a),
+            14 => self.slide_i32x16::<2>(padding, a),
+            15 => self.slide_i32x16::<1>(padding, a),
+            16 => self.slide_i32x16::<0>(padding, a),
+            _ => self.slide_i32x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i32x16<const LANE: usize>(self, a: i32x16<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x16(a);
+        match LANE {
+            0 => self.extract_i32x8::<0>(a0),
+            1 => self.extract_i32x8::<1>(a0),
+            2 => self.extract_i32x8::<2>(a0),
+            3 => self.extract_i32x8::<3>(a0),
+            4 => self.extract_i32x8::<4>(a0),
+            5 => self.extract_i32x8::<5>(a0),
+            6 => self.extract_i32x8::<6>(a0),
+            7 => self.extract_i32x8::<7>(a0),
+            8 => self.extract_i32x8::<0>(a1),
+            9 => self.extract_i32x8::<1>(a1),
+            10 => self.extract_i32x8::<2>(a1),
+            11 => self.extract_i32x8::<3>(a1),
+            12 => self.extract_i32x8::<4>(a1),
+            13 => self.extract_i32x8::<5>(a1),
+            14 => self.extract_i32x8::<6>(a1),
+            15 => self.extract_i32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i32x16<const LANE: usize>(self, a: i32x16<Self>, value: i32) -> i32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x16(a);
+        match LANE {
+            0 => self.combine_i32x8(self.insert_i32x8::<0>(a0, value), a1),
+            1 => self.combine_i32x8(self.insert_i32x8::<1>(a0, value), a1),
+            2 => self.combine_i32x8(self.insert_i32x8::<2>(a0, value), a1),
+            3 => self.combine_i32x8(self.insert_i32x8::<3>(a0, value), a1),
+            4 => self.combine_i32x8(self.insert_i32x8::<4>(a0, value), a1),
+            5 => self.combine_i32x8(self.insert_i32x8::<5>(a0, value), a1),
+            6 => self.combine_i32x8(self.insert_i32x8::<6>(a0, value), a1),
+            7 => self.combine_i32x8(self.insert_i32x8::<7>(a0, value), a1),
+            8 => self.combine_i32x8(a0, self.insert_i32x8::<0>(a1, value)),
+            9 => self.combine_i32x8(a0, self.insert_i32x8::<1>(a1, value)),
+            10 => self.combine_i32x8(a0, self.insert_i32x8::<2>(a1, value)),
+            11 => self.combine_i32x8(a0, self.insert_i32x8::<3>(a1, value)),
+            12 => self.combine_i32x8(a0, self.insert_i32x8::<4>(a1, value)),
+            13 => self.combine_i32x8(a0, self.insert_i32x8::<5>(a1, value)),
+            14 => self.combine_i32x8(a0, self.insert_i32x8::<6>(a1, value)),
+            15 => self.combine_i32x8(a0, self.insert_i32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x16(self, a: i32x16<Self>, index: usize) -> i32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i32x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i32x16(
+        self,
+        a: i32x16<Self>,
+        indices: u8x64<Self>,
+    ) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_i32x8(
+            self.swizzle_dyn_within_blocks_i32x8(a0, indices0),
+            self.swizzle_dyn_within_blocks_i32x8(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.add_i32x8(a0, b0), self.add_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.sub_i32x8(a0, b0), self.sub_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.mul_i32x8(a0, b0), self.mul_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.and_i32x8(a0, b0), self.and_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.or_i32x8(a0, b0), self.or_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.xor_i32x8(a0, b0), self.xor_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn not_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.not_i32x8(a0), self.not_i32x8(a1))
+    }
+    #[inline(always)]
+    fn shl_i32x16(self, a: i32x16<Self>, shift: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shl_i32x8(a0, shift), self.shl_i32x8(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.shlv_i32x8(a0, b0), self.shlv_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_i32x16(self, a: i32x16<Self>, shift: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shr_i32x8(a0, shift), self.shr_i32x8(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.shrv_i32x8(a0, b0), self.shrv_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.shift_signed_i32x8(a0, b0),
+            self.shift_signed_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_eq_i32x8(a0, b0), self.simd_eq_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_lt_i32x8(a0, b0), self.simd_lt_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_le_i32x8(a0, b0), self.simd_le_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_ge_i32x8(a0, b0), self.simd_ge_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_gt_i32x8(a0, b0), self.simd_gt_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, _) = self.split_i32x16(a);
+        let (b0, _) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a0, b0), self.zip_high_i32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (_, a1) = self.split_i32x16(a);
+        let (_, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a1, b1), self.zip_high_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.unzip_low_i32x8(a0, a1), self.unzip_low_i32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.unzip_high_i32x8(a0, a1), self.unzip_high_i32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> (i32x16<Self>, i32x16<Self>) {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let lo_lo = self.zip_low_i32x8(a0, b0);
+        let lo_hi = self.zip_high_i32x8(a0, b0);
+        let hi_lo = self.zip_low_i32x8(a1, b1);
+        let hi_hi = self.zip_high_i32x8(a1, b1);
+        (
+            self.combine_i32x8(lo_lo, lo_hi),
+            self.combine_i32x8(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> (i32x16<Self>, i32x16<Self>) {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let lo_even = self.unzip_low_i32x8(a0, a1);
+        let lo_odd = self.unzip_high_i32x8(a0, a1);
+        let hi_even = self.unzip_low_i32x8(b0, b1);
+        let hi_odd = self.unzip_high_i32x8(b0, b1);
+        (
+            self.combine_i32x8(lo_even, hi_even),
+            self.combine_i32x8(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_i32x16(self, a: mask32x16<Self>, b: i32x16<Self>, c: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_i32x16(
+        self,
+        a: mask32x16<Self>,
+        b: i32x16<Self>,
+        c: i32x16<Self>,
+    ) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(
+            self.select_relaxed_i32x8(a0, b0, c0),
+            self.select_relaxed_i32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.min_i32x8(a0, b0), self.min_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.saturating_add_i32x8(a0, b0),
+            self.saturating_add_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.saturating_sub_i32x8(a0, b0),
+            self.saturating_sub_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.count_ones_i32x8(a0), self.count_ones_i32x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.leading_zeros_i32x8(a0), self.leading_zeros_i32x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.trailing_zeros_i32x8(a0), self.trailing_zeros_i32x8(a1))
+    }
+    #[inline(always)]
+    fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
+        (
+            i32x8 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            i32x8 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn neg_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.neg_i32x8(a0), self.neg_i32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x16(self, a: i32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_i32x8(a0), self.reinterpret_u8_i32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x16(self, a: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i32x8(a0),
+            self.reinterpret_u32_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(self.cvt_f32_i32x8(a0), self.cvt_f32_i32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_u32x16(self, val: u32) -> u32x16<Self> {
+        let half = self.splat_u32x8(val);
+        self.combine_u32x8(half, half)
+    }
+    #[inline(always)]
+    fn load_array_u32x16(self, val: [u32; 16usize]) -> u32x16<Self> {
+        u32x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u32x16(self, val: &[u32; 16usize]) -> u32x16<Self> {
+        u32x16 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u32x16(self, a: u32x16<Self>) -> [u32; 16usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [u32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u32x16(self, a: &u32x16<Self>) -> &[u32; 16usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [u32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u32x16(self, a: &mut u32x16<Self>) -> &mut [u32; 16usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [u32; 16usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u32x16(self, a: u8x64<Self>) -> u32x16<Self> {
+        u32x16 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u32x16(self, a: u32x16<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u32x16<const SHIFT: usize>(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_u32x16(b).val.0,
+            self.cvt_to_bytes_u32x16(a).val.0,
+            SHIFT * 4usize,
+        );
+        self.cvt_from_bytes_u32x16(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u32x16<const SHIFT: usize>(
+        self,
+        a: u32x16<Self>,
+        b: u32x16<Self>,
+    ) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.slide_within_blocks_u32x8::<SHIFT>(a0, b0),
+            self.slide_within_blocks_u32x8::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u32x16<const OFFSET: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_u32x16::<0>(a, a),
+            1 => self.slide_u32x16::<1>(a, a),
+            2 => self.slide_u32x16::<2>(a, a),
+            3 => self.slide_u32x16::<3>(a, a),
+            4 => self.slide_u32x16::<4>(a, a),
+            5 => self.slide_u32x16::<5>(a, a),
+            6 => self.slide_u32x16::<6>(a, a),
+            7 => self.slide_u32x16::<7>(a, a),
+            8 => self.slide_u32x16::<8>(a, a),
+            9 => self.slide_u32x16::<9>(a, a),
+            10 => self.slide_u32x16::<10>(a, a),
+            11 => self.slide_u32x16::<11>(a, a),
+            12 => self.slide_u32x16::<12>(a, a),
+            13 => self.slide_u32x16::<13>(a, a),
+            14 => self.slide_u32x16::<14>(a, a),
+            15 => self.slide_u32x16::<15>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u32x16<const OFFSET: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        match OFFSET % 16 {
+            0 => self.slide_u32x16::<16>(a, a),
+            1 => self.slide_u32x16::<15>(a, a),
+            2 => self.slide_u32x16::<14>(a, a),
+            3 => self.slide_u32x16::<13>(a, a),
+            4 => self.slide_u32x16::<12>(a, a),
+            5 => self.slide_u32x16::<11>(a, a),
+            6 => self.slide_u32x16::<10>(a, a),
+            7 => self.slide_u32x16::<9>(a, a),
+            8 => self.slide_u32x16::<8>(a, a),
+            9 => self.slide_u32x16::<7>(a, a),
+            10 => self.slide_u32x16::<6>(a, a),
+            11 => self.slide_u32x16::<5>(a, a),
+            12 => self.slide_u32x16::<4>(a, a),
+            13 => self.slide_u32x16::<3>(a, a),
+            14 => self.slide_u32x16::<2>(a, a),
+            15 => self.slide_u32x16::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u32x16<const OFFSET: usize>(
+        self,
+        a: u32x16<Self>,
+        padding: u32,
+    ) -> u32x16<Self> {
+        let padding = self.splat_u32x16(padding);
+        match OFFSET {
+            0 => self.slide_u32x16::<0>(a, padding),
+            1 => self.slide_u32x16::<1>(a, padding),
+            2 => self.slide_u32x16::<2>(a, padding),
+            3 => self.slide_u32x16::<3>(a, padding),
+            4 => self.slide_u32x16::<4>(a, padding),
+            5 => self.slide_u32x16::<5>(a, padding),
+            6 => self.slide_u32x16::<6>(a, padding),
+            7 => self.slide_u32x16::<7>(a, padding),
+            8 => self.slide_u32x16::<8>(a, padding),
+            9 => self.slide_u32x16::<9>(a, padding),
+            10 => self.slide_u32x16::<10>(a, padding),
+            11 => self.slide_u32x16::<11>(a, padding),
+            12 => self.slide_u32x16::<12>(a, padding),
+            13 => self.slide_u32x16::<13>(a, padding),
+            14 => self.slide_u32x16::<14>(a, padding),
+            15 => self.slide_u32x16::<15>(a, padding),
+            16 => self.slide_u32x16::<16>(a, padding),
+            _ => self.slide_u32x16::<16>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u32x16<const OFFSET: usize>(
+        self,
+        a: u32x16<Self>,
+        padding: u32,
+    ) -> u32x16<Self> {
+        let padding = self.splat_u32x16(padding);
+        match OFFSET {
+            0 => self.slide_u32x16::<16>(padding, a),
+            1 => self.slide_u32x16::<15>(padding, a),
+            2 => self.slide_u32x16::<14>(padding, a),
+            3 => self.slide_u32x16::<13>(padding, a),
+            4 => self.slide_u32x16::<12>(padding, a),
+            5 => self.slide_u32x16::<11>(padding, a),
+            6 => self.slide_u32x16::<10>(padding, a),
+            7 => self.slide_u32x16::<9>(padding, a),
+            8 => self.slide_u32x16::<8>(padding, a),
+            9 => self.slide_u32x16::<7>(padding, a),
+            10 => self.slide_u32x16::<6>(padding, a),
+            11 => self.slide_u32x16::<5>(padding, a),
+            12 => self.slide_u32x16::<4>(padding, a),
+            13 => self.slide_u32x16::<3>(padding, a),
+            14 => self.slide_u32x16::<2>(padding, a),
+            15 => self.slide_u32x16::<1>(padding, a),
+            16 => self.slide_u32x16::<0>(padding, a),
+            _ => self.slide_u32x16::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u32x16<const LANE: usize>(self, a: u32x16<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x16(a);
+        match LANE {
+            0 => self.extract_u32x8::<0>(a0),
+            1 => self.extract_u32x8::<1>(a0),
+            2 => self.extract_u32x8::<2>(a0),
+            3 => self.extract_u32x8::<3>(a0),
+            4 => self.extract_u32x8::<4>(a0),
+            5 => self.extract_u32x8::<5>(a0),
+            6 => self.extract_u32x8::<6>(a0),
+            7 => self.extract_u32x8::<7>(a0),
+            8 => self.extract_u32x8::<0>(a1),
+            9 => self.extract_u32x8::<1>(a1),
+            10 => self.extract_u32x8::<2>(a1),
+            11 => self.extract_u32x8::<3>(a1),
+            12 => self.extract_u32x8::<4>(a1),
+            13 => self.extract_u32x8::<5>(a1),
+            14 => self.extract_u32x8::<6>(a1),
+            15 => self.extract_u32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u32x16<const LANE: usize>(self, a: u32x16<Self>, value: u32) -> u32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x16(a);
+        match LANE {
+            0 => self.combine_u32x8(self.insert_u32x8::<0>(a0, value), a1),
+            1 => self.combine_u32x8(self.insert_u32x8::<1>(a0, value), a1),
+            2 => self.combine_u32x8(self.insert_u32x8::<2>(a0, value), a1),
+            3 => self.combine_u32x8(self.insert_u32x8::<3>(a0, value), a1),
+            4 => self.combine_u32x8(self.insert_u32x8::<4>(a0, value), a1),
+            5 => self.combine_u32x8(self.insert_u32x8::<5>(a0, value), a1),
+            6 => self.combine_u32x8(self.insert_u32x8::<6>(a0, value), a1),
+            7 => self.combine_u32x8(self.insert_u32x8::<7>(a0, value), a1),
+            8 => self.combine_u32x8(a0, self.insert_u32x8::<0>(a1, value)),
+            9 => self.combine_u32x8(a0, self.insert_u32x8::<1>(a1, value)),
+            10 => self.combine_u32x8(a0, self.insert_u32x8::<2>(a1, value)),
+            11 => self.combine_u32x8(a0, self.insert_u32x8::<3>(a1, value)),
+            12 => self.combine_u32x8(a0, self.insert_u32x8::<4>(a1, value)),
+            13 => self.combine_u32x8(a0, self.insert_u32x8::<5>(a1, value)),
+            14 => self.combine_u32x8(a0, self.insert_u32x8::<6>(a1, value)),
+            15 => self.combine_u32x8(a0, self.insert_u32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x16(self, a: u32x16<Self>, index: usize) -> u32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u32x16(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u32x16(
+        self,
+        a: u32x16<Self>,
+        indices: u8x64<Self>,
+    ) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_u32x8(
+            self.swizzle_dyn_within_blocks_u32x8(a0, indices0),
+            self.swizzle_dyn_within_blocks_u32x8(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.add_u32x8(a0, b0), self.add_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.sub_u32x8(a0, b0), self.sub_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.mul_u32x8(a0, b0), self.mul_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.and_u32x8(a0, b0), self.and_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.or_u32x8(a0, b0), self.or_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.xor_u32x8(a0, b0), self.xor_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn not_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.not_u32x8(a0), self.not_u32x8(a1))
+    }
+    #[inline(always)]
+    fn shl_u32x16(self, a: u32x16<Self>, shift: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shl_u32x8(a0, shift), self.shl_u32x8(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.shlv_u32x8(a0, b0), self.shlv_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_u32x16(self, a: u32x16<Self>, shift: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shr_u32x8(a0, shift), self.shr_u32x8(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.shrv_u32x8(a0, b0), self.shrv_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_u32x16(self, a: u32x16<Self>, b: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_u32x8(
+            self.shift_signed_u32x8(a0, b0),
+            self.shift_signed_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_eq_u32x8(a0, b0), self.simd_eq_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_lt_u32x8(a0, b0), self.simd_lt_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_le_u32x8(a0, b0), self.simd_le_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_ge_u32x8(a0, b0), self.simd_ge_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_gt_u32x8(a0, b0), self.simd_gt_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, _) = self.split_u32x16(a);
+        let (b0, _) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a0, b0), self.zip_high_u32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (_, a1) = self.split_u32x16(a);
+        let (_, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a1, b1), self.zip_high_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.unzip_low_u32x8(a0, a1), self.unzip_low_u32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.unzip_high_u32x8(a0, a1), self.unzip_high_u32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> (u32x16<Self>, u32x16<Self>) {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let lo_lo = self.zip_low_u32x8(a0, b0);
+        let lo_hi = self.zip_high_u32x8(a0, b0);
+        let hi_lo = self.zip_low_u32x8(a1, b1);
+        let hi_hi = self.zip_high_u32x8(a1, b1);
+        (
+            self.combine_u32x8(lo_lo, lo_hi),
+            self.combine_u32x8(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> (u32x16<Self>, u32x16<Self>) {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let lo_even = self.unzip_low_u32x8(a0, a1);
+        let lo_odd = self.unzip_high_u32x8(a0, a1);
+        let hi_even = self.unzip_low_u32x8(b0, b1);
+        let hi_odd = self.unzip_high_u32x8(b0, b1);
+        (
+            self.combine_u32x8(lo_even, hi_even),
+            self.combine_u32x8(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_u32x16(self, a: mask32x16<Self>, b: u32x16<Self>, c: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_u32x16(
+        self,
+        a: mask32x16<Self>,
+        b: u32x16<Self>,
+        c: u32x16<Self>,
+    ) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(
+            self.select_relaxed_u32x8(a0, b0, c0),
+            self.select_relaxed_u32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.min_u32x8(a0, b0), self.min_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.saturating_add_u32x8(a0, b0),
+            self.saturating_add_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.saturating_sub_u32x8(a0, b0),
+            self.saturating_sub_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.count_ones_u32x8(a0), self.count_ones_u32x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.leading_zeros_u32x8(a0), self.leading_zeros_u32x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.trailing_zeros_u32x8(a0), self.trailing_zeros_u32x8(a1))
+    }
+    #[inline(always)]
+    fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
+        (
+            u32x8 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            u32x8 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u32x16(self, src: &[u32; 16usize]) -> u32x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, src: &[u32; 16usize]) -> u32x16<Avx2> {
+                let (chunks, []) = src.as_chunks::<4usize>() else {
+                    unreachable!()
+                };
+                let v0: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u32; 4usize], __m128i>(&chunks[0]);
+                let v1: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u32; 4usize], __m128i>(&chunks[1]);
+                let v2: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u32; 4usize], __m128i>(&chunks[2]);
+                let v3: __m128i =
+                    crate::transmute::checked_transmute_copy::<[u32; 4usize], __m128i>(&chunks[3]);
+                let tmp0 = _mm_unpacklo_epi32(v0, v1);
+                let tmp1 = _mm_unpackhi_epi32(v0, v1);
+                let tmp2 = _mm_unpacklo_epi32(v2, v3);
+                let tmp3 = _mm_unpackhi_epi32(v2, v3);
+                let out0 = _mm_unpacklo_epi64(tmp0, tmp2);
+                let out1 = _mm_unpackhi_epi64(tmp0, tmp2);
+                let out2 = _mm_unpacklo_epi64(tmp1, tmp3);
+                let out3 = _mm_unpackhi_epi64(tmp1, tmp3);
+                token.combine_u32x8(
+                    token.combine_u32x4(out0.simd_into(token), out1.simd_into(token)),
+                    token.combine_u32x4(out2.simd_into(token), out3.simd_into(token)),
+                )
+            }
+        );
+        kernel(self, src)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> () {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u32x16<Avx2>, dest: &mut [u32; 16usize]) -> () {
+                let (v01, v23) = token.split_u32x16(a);
+                let (v0, v1) = token.split_u32x8(v01);
+                let (v2, v3) = token.split_u32x8(v23);
+                let v0 = v0.into();
+                let v1 = v1.into();
+                let v2 = v2.into();
+                let v3 = v3.into();
+                let tmp0 = _mm_unpacklo_epi32(v0, v1);
+                let tmp1 = _mm_unpackhi_epi32(v0, v1);
+                let tmp2 = _mm_unpacklo_epi32(v2, v3);
+                let tmp3 = _mm_unpackhi_epi32(v2, v3);
+                let out0 = _mm_unpacklo_epi64(tmp0, tmp2);
+                let out1 = _mm_unpackhi_epi64(tmp0, tmp2);
+                let out2 = _mm_unpacklo_epi64(tmp1, tmp3);
+                let out3 = _mm_unpackhi_epi64(tmp1, tmp3);
+                let (chunks, []) = dest.as_chunks_mut::<4usize>() else {
+                    unreachable!()
+                };
+                crate::transmute::checked_transmute_store::<__m128i, [u32; 4usize]>(
+                    out0,
+                    &mut chunks[0],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u32; 4usize]>(
+                    out1,
+                    &mut chunks[1],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u32; 4usize]>(
+                    out2,
+                    &mut chunks[2],
+                );
+                crate::transmute::checked_transmute_store::<__m128i, [u32; 4usize]>(
+                    out3,
+                    &mut chunks[3],
+                );
+            }
+        );
+        kernel(self, a, dest);
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x16(self, a: u32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_u32x8(a0), self.reinterpret_u8_u32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(self.cvt_f32_u32x8(a0), self.cvt_f32_u32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_mask32x16(self, val: bool) -> mask32x16<Self> {
+        let half = self.splat_mask32x8(val);
+        self.combine_mask32x8(half, half)
+    }
+    #[inline(always)]
+    fn load_array_mask32x16(self, val: [i32; 16usize]) -> mask32x16<Self> {
+        mask32x16 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask32x16(self, a: mask32x16<Self>) -> [i32; 16usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i32; 16usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self> {
+        i32x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask32x16<Avx2> {
+                {
+                    let bit_lanes = _mm256_set1_epi32(bits as i32);
+                    mask32x16 {
+                        val: crate::support::Aligned512([
+                            {
+                                let bit_mask = _mm256_setr_epi32(1, 2, 4, 8, 16, 32, 64, 128);
+                                _mm256_cmpeq_epi32(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                            },
+                            {
+                                let bit_mask = _mm256_setr_epi32(
+                                    256, 512, 1024, 2048, 4096, 8192, 16384, 32768,
+                                );
+                                _mm256_cmpeq_epi32(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                            },
+                        ]),
+                        simd: token,
+                    }
+                }
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x16(self, a: mask32x16<Self>) -> u64 {
+        let (lo, hi) = self.split_mask32x16(a);
+        let lo = self.to_bitmask_mask32x8(lo);
+        let hi = self.to_bitmask_mask32x8(hi);
+        lo | (hi << 8usize)
+    }
+    #[inline(always)]
+    fn set_mask32x16(self, a: &mut mask32x16<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 16usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        let mut lanes = self.as_array_mask32x16(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask32x16(lanes);
+    }
+    #[inline(always)]
+    fn and_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.and_mask32x8(a0, b0), self.and_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.or_mask32x8(a0, b0), self.or_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.xor_mask32x8(a0, b0), self.xor_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn not_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_mask32x8(self.not_mask32x8(a0), self.not_mask32x8(a1))
+    }
+    #[inline(always)]
+    fn select_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_mask32x8(a0, b0, c0),
+            self.select_mask32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn select_relaxed_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_relaxed_mask32x8(a0, b0, c0),
+            self.select_relaxed_mask32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.simd_eq_mask32x8(a0, b0), self.simd_eq_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn any_true_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.any_true_mask32x8(a0) || self.any_true_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn all_true_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.all_true_mask32x8(a0) && self.all_true_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn any_false_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.any_false_mask32x8(a0) || self.any_false_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn all_false_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.all_false_mask32x8(a0) && self.all_false_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn split_mask32x16(self, a: mask32x16<Self>) -> (mask32x8<Self>, mask32x8<Self>) {
+        (
+            mask32x8 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            mask32x8 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn splat_f64x8(self, val: f64) -> f64x8<Self> {
+        let half = self.splat_f64x4(val);
+        self.combine_f64x4(half, half)
+    }
+    #[inline(always)]
+    fn load_array_f64x8(self, val: [f64; 8usize]) -> f64x8<Self> {
+        f64x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_f64x8(self, val: &[f64; 8usize]) -> f64x8<Self> {
+        f64x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_f64x8(self, a: f64x8<Self>) -> [f64; 8usize] {
+        crate::transmute::checked_transmute_copy::<[__m256d; 2usize], [f64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_f64x8(self, a: &f64x8<Self>) -> &[f64; 8usize] {
+        crate::transmute::checked_cast_ref::<[__m256d; 2usize], [f64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_f64x8(self, a: &mut f64x8<Self>) -> &mut [f64; 8usize] {
+        crate::transmute::checked_cast_mut::<[__m256d; 2usize], [f64; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_f64x8(self, a: f64x8<Self>, dest: &mut [f64; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_f64x8(self, a: u8x64<Self>) -> f64x8<Self> {
+        f64x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_f64x8(self, a: f64x8<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_f64x8<const SHIFT: usize>(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_f64x8(b).val.0,
+            self.cvt_to_bytes_f64x8(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_f64x8(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_f64x8<const SHIFT: usize>(
+        self,
+        a: f64x8<Self>,
+        b: f64x8<Self>,
+    ) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.slide_within_blocks_f64x4::<SHIFT>(a0, b0),
+            self.slide_within_blocks_f64x4::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_f64x8<const OFFSET: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_f64x8::<0>(a, a),
+            1 => self.slide_f64x8::<1>(a, a),
+            2 => self.slide_f64x8::<2>(a, a),
+            3 => self.slide_f64x8::<3>(a, a),
+            4 => self.slide_f64x8::<4>(a, a),
+            5 => self.slide_f64x8::<5>(a, a),
+            6 => self.slide_f64x8::<6>(a, a),
+            7 => self.slide_f64x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_f64x8<const OFFSET: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_f64x8::<8>(a, a),
+            1 => self.slide_f64x8::<7>(a, a),
+            2 => self.slide_f64x8::<6>(a, a),
+            3 => self.slide_f64x8::<5>(a, a),
+            4 => self.slide_f64x8::<4>(a, a),
+            5 => self.slide_f64x8::<3>(a, a),
+            6 => self.slide_f64x8::<2>(a, a),
+            7 => self.slide_f64x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_f64x8<const OFFSET: usize>(
+        self,
+        a: f64x8<Self>,
+        padding: f64,
+    ) -> f64x8<Self> {
+        let padding = self.splat_f64x8(padding);
+        match OFFSET {
+            0 => self.slide_f64x8::<0>(a, padding),
+            1 => self.slide_f64x8::<1>(a, padding),
+            2 => self.slide_f64x8::<2>(a, padding),
+            3 => self.slide_f64x8::<3>(a, padding),
+            4 => self.slide_f64x8::<4>(a, padding),
+            5 => self.slide_f64x8::<5>(a, padding),
+            6 => self.slide_f64x8::<6>(a, padding),
+            7 => self.slide_f64x8::<7>(a, padding),
+            8 => self.slide_f64x8::<8>(a, padding),
+            _ => self.slide_f64x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_f64x8<const OFFSET: usize>(
+        self,
+        a: f64x8<Self>,
+        padding: f64,
+    ) -> f64x8<Self> {
+        let padding = self.splat_f64x8(padding);
+        match OFFSET {
+            0 => self.slide_f64x8::<8>(padding, a),
+            1 => self.slide_f64x8::<7>(padding, a),
+            2 => self.slide_f64x8::<6>(padding, a),
+            3 => self.slide_f64x8::<5>(padding, a),
+            4 => self.slide_f64x8::<4>(padding, a),
+            5 => self.slide_f64x8::<3>(padding, a),
+            6 => self.slide_f64x8::<2>(padding, a),
+            7 => self.slide_f64x8::<1>(padding, a),
+            8 => self.slide_f64x8::<0>(padding, a),
+            _ => self.slide_f64x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_f64x8<const LANE: usize>(self, a: f64x8<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x8(a);
+        match LANE {
+            0 => self.extract_f64x4::<0>(a0),
+            1 => self.extract_f64x4::<1>(a0),
+            2 => self.extract_f64x4::<2>(a0),
+            3 => self.extract_f64x4::<3>(a0),
+            4 => self.extract_f64x4::<0>(a1),
+            5 => self.extract_f64x4::<1>(a1),
+            6 => self.extract_f64x4::<2>(a1),
+            7 => self.extract_f64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f64x8<const LANE: usize>(self, a: f64x8<Self>, value: f64) -> f64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x8(a);
+        match LANE {
+            0 => self.combine_f64x4(self.insert_f64x4::<0>(a0, value), a1),
+            1 => self.combine_f64x4(self.insert_f64x4::<1>(a0, value), a1),
+            2 => self.combine_f64x4(self.insert_f64x4::<2>(a0, value), a1),
+            3 => self.combine_f64x4(self.insert_f64x4::<3>(a0, value), a1),
+            4 => self.combine_f64x4(a0, self.insert_f64x4::<0>(a1, value)),
+            5 => self.combine_f64x4(a0, self.insert_f64x4::<1>(a1, value)),
+            6 => self.combine_f64x4(a0, self.insert_f64x4::<2>(a1, value)),
+            7 => self.combine_f64x4(a0, self.insert_f64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x8(self, a: f64x8<Self>, index: usize) -> f64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_f64x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_f64x8(self, a: f64x8<Self>, indices: u8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_f64x4(
+            self.swizzle_dyn_within_blocks_f64x4(a0, indices0),
+            self.swizzle_dyn_within_blocks_f64x4(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn abs_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.abs_f64x4(a0), self.abs_f64x4(a1))
+    }
+    #[inline(always)]
+    fn neg_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.neg_f64x4(a0), self.neg_f64x4(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.sqrt_f64x4(a0), self.sqrt_f64x4(a1))
+    }
+    #[inline(always)]
+    fn approximate_recip_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(
+            self.approximate_recip_f64x4(a0),
+            self.approximate_recip_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.add_f64x4(a0, b0), self.add_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.sub_f64x4(a0, b0), self.sub_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.mul_f64x4(a0, b0), self.mul_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.div_f64x4(a0, b0), self.div_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.copysign_f64x4(a0, b0), self.copysign_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_eq_f64x4(a0, b0), self.simd_eq_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_lt_f64x4(a0, b0), self.simd_lt_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_le_f64x4(a0, b0), self.simd_le_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ge_f64x4(a0, b0), self.simd_ge_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_gt_f64x4(a0, b0), self.simd_gt_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, _) = self.split_f64x8(a);
+        let (b0, _) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a0, b0), self.zip_high_f64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (_, a1) = self.split_f64x8(a);
+        let (_, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a1, b1), self.zip_high_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.unzip_low_f64x4(a0, a1), self.unzip_low_f64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.unzip_high_f64x4(a0, a1), self.unzip_high_f64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> (f64x8<Self>, f64x8<Self>) {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let lo_lo = self.zip_low_f64x4(a0, b0);
+        let lo_hi = self.zip_high_f64x4(a0, b0);
+        let hi_lo = self.zip_low_f64x4(a1, b1);
+        let hi_hi = self.zip_high_f64x4(a1, b1);
+        (
+            self.combine_f64x4(lo_lo, lo_hi),
+            self.combine_f64x4(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> (f64x8<Self>, f64x8<Self>) {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let lo_even = self.unzip_low_f64x4(a0, a1);
+        let lo_odd = self.unzip_high_f64x4(a0, a1);
+        let hi_even = self.unzip_low_f64x4(b0, b1);
+        let hi_odd = self.unzip_high_f64x4(b0, b1);
+        (
+            self.combine_f64x4(lo_even, hi_even),
+            self.combine_f64x4(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn max_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.max_f64x4(a0, b0), self.max_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn min_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.min_f64x4(a0, b0), self.min_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.max_precise_f64x4(a0, b0),
+            self.max_precise_f64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.min_precise_f64x4(a0, b0),
+            self.min_precise_f64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_f64x4(a0, b0, c0),
+            self.mul_add_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_sub_f64x4(a0, b0, c0),
+            self.mul_sub_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.nmul_add_f64x4(a0, b0, c0),
+            self.nmul_add_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.nmul_sub_f64x4(a0, b0, c0),
+            self.nmul_sub_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_relaxed_f64x4(a0, b0, c0),
+            self.mul_add_relaxed_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.floor_f64x4(a0), self.floor_f64x4(a1))
+    }
+    #[inline(always)]
+    fn ceil_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.ceil_f64x4(a0), self.ceil_f64x4(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(
+            self.round_ties_even_f64x4(a0),
+            self.round_ties_even_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.fract_f64x4(a0), self.fract_f64x4(a1))
+    }
+    #[inline(always)]
+    fn trunc_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.trunc_f64x4(a0), self.trunc_f64x4(a1))
+    }
+    #[inline(always)]
+    fn select_f64x8(self, a: mask64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.select_f64x4(a0, b0, c0), self.select_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_f64x8(
+        self,
+        a: mask64x8<Self>,
+        b: f64x8<Self>,
+        c: f64x8<Self>,
+    ) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.select_relaxed_f64x4(a0, b0, c0),
+            self.select_relaxed_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>) {
+        (
+            f64x4 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            f64x4 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x8(self, a: f64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_f64x4(a0),
+            self.reinterpret_f32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i64x8(self, val: i64) -> i64x8<Self> {
+        let half = self.splat_i64x4(val);
+        self.combine_i64x4(half, half)
+    }
+    #[inline(always)]
+    fn load_array_i64x8(self, val: [i64; 8usize]) -> i64x8<Self> {
+        i64x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_i64x8(self, val: &[i64; 8usize]) -> i64x8<Self> {
+        i64x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_i64x8(self, a: i64x8<Self>) -> [i64; 8usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_i64x8(self, a: &i64x8<Self>) -> &[i64; 8usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [i64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_i64x8(self, a: &mut i64x8<Self>) -> &mut [i64; 8usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [i64; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_i64x8(self, a: i64x8<Self>, dest: &mut [i64; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_i64x8(self, a: u8x64<Self>) -> i64x8<Self> {
+        i64x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_i64x8(self, a: i64x8<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_i64x8<const SHIFT: usize>(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_i64x8(b).val.0,
+            self.cvt_to_bytes_i64x8(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_i64x8(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_i64x8<const SHIFT: usize>(
+        self,
+        a: i64x8<Self>,
+        b: i64x8<Self>,
+    ) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.slide_within_blocks_i64x4::<SHIFT>(a0, b0),
+            self.slide_within_blocks_i64x4::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_i64x8<const OFFSET: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_i64x8::<0>(a, a),
+            1 => self.slide_i64x8::<1>(a, a),
+            2 => self.slide_i64x8::<2>(a, a),
+            3 => self.slide_i64x8::<3>(a, a),
+            4 => self.slide_i64x8::<4>(a, a),
+            5 => self.slide_i64x8::<5>(a, a),
+            6 => self.slide_i64x8::<6>(a, a),
+            7 => self.slide_i64x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_i64x8<const OFFSET: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_i64x8::<8>(a, a),
+            1 => self.slide_i64x8::<7>(a, a),
+            2 => self.slide_i64x8::<6>(a, a),
+            3 => self.slide_i64x8::<5>(a, a),
+            4 => self.slide_i64x8::<4>(a, a),
+            5 => self.slide_i64x8::<3>(a, a),
+            6 => self.slide_i64x8::<2>(a, a),
+            7 => self.slide_i64x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_i64x8<const OFFSET: usize>(
+        self,
+        a: i64x8<Self>,
+        padding: i64,
+    ) -> i64x8<Self> {
+        let padding = self.splat_i64x8(padding);
+        match OFFSET {
+            0 => self.slide_i64x8::<0>(a, padding),
+            1 => self.slide_i64x8::<1>(a, padding),
+            2 => self.slide_i64x8::<2>(a, padding),
+            3 => self.slide_i64x8::<3>(a, padding),
+            4 => self.slide_i64x8::<4>(a, padding),
+            5 => self.slide_i64x8::<5>(a, padding),
+            6 => self.slide_i64x8::<6>(a, padding),
+            7 => self.slide_i64x8::<7>(a, padding),
+            8 => self.slide_i64x8::<8>(a, padding),
+            _ => self.slide_i64x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_i64x8<const OFFSET: usize>(
+        self,
+        a: i64x8<Self>,
+        padding: i64,
+    ) -> i64x8<Self> {
+        let padding = self.splat_i64x8(padding);
+        match OFFSET {
+            0 => self.slide_i64x8::<8>(padding, a),
+            1 => self.slide_i64x8::<7>(padding, a),
+            2 => self.slide_i64x8::<6>(padding, a),
+            3 => self.slide_i64x8::<5>(padding, a),
+            4 => self.slide_i64x8::<4>(padding, a),
+            5 => self.slide_i64x8::<3>(padding, a),
+            6 => self.slide_i64x8::<2>(padding, a),
+            7 => self.slide_i64x8::<1>(padding, a),
+            8 => self.slide_i64x8::<0>(padding, a),
+            _ => self.slide_i64x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_i64x8<const LANE: usize>(self, a: i64x8<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x8(a);
+        match LANE {
+            0 => self.extract_i64x4::<0>(a0),
+            1 => self.extract_i64x4::<1>(a0),
+            2 => self.extract_i64x4::<2>(a0),
+            3 => self.extract_i64x4::<3>(a0),
+            4 => self.extract_i64x4::<0>(a1),
+            5 => self.extract_i64x4::<1>(a1),
+            6 => self.extract_i64x4::<2>(a1),
+            7 => self.extract_i64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i64x8<const LANE: usize>(self, a: i64x8<Self>, value: i64) -> i64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x8(a);
+        match LANE {
+            0 => self.combine_i64x4(self.insert_i64x4::<0>(a0, value), a1),
+            1 => self.combine_i64x4(self.insert_i64x4::<1>(a0, value), a1),
+            2 => self.combine_i64x4(self.insert_i64x4::<2>(a0, value), a1),
+            3 => self.combine_i64x4(self.insert_i64x4::<3>(a0, value), a1),
+            4 => self.combine_i64x4(a0, self.insert_i64x4::<0>(a1, value)),
+            5 => self.combine_i64x4(a0, self.insert_i64x4::<1>(a1, value)),
+            6 => self.combine_i64x4(a0, self.insert_i64x4::<2>(a1, value)),
+            7 => self.combine_i64x4(a0, self.insert_i64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x8(self, a: i64x8<Self>, index: usize) -> i64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i64x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_i64x8(self, a: i64x8<Self>, indices: u8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_i64x4(
+            self.swizzle_dyn_within_blocks_i64x4(a0, indices0),
+            self.swizzle_dyn_within_blocks_i64x4(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.add_i64x4(a0, b0), self.add_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.sub_i64x4(a0, b0), self.sub_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.mul_i64x4(a0, b0), self.mul_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.and_i64x4(a0, b0), self.and_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.or_i64x4(a0, b0), self.or_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.xor_i64x4(a0, b0), self.xor_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn not_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.not_i64x4(a0), self.not_i64x4(a1))
+    }
+    #[inline(always)]
+    fn shl_i64x8(self, a: i64x8<Self>, shift: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shl_i64x4(a0, shift), self.shl_i64x4(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.shlv_i64x4(a0, b0), self.shlv_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_i64x8(self, a: i64x8<Self>, shift: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shr_i64x4(a0, shift), self.shr_i64x4(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.shrv_i64x4(a0, b0), self.shrv_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.shift_signed_i64x4(a0, b0),
+            self.shift_signed_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_eq_i64x4(a0, b0), self.simd_eq_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_lt_i64x4(a0, b0), self.simd_lt_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_le_i64x4(a0, b0), self.simd_le_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_ge_i64x4(a0, b0), self.simd_ge_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_gt_i64x4(a0, b0), self.simd_gt_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, _) = self.split_i64x8(a);
+        let (b0, _) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a0, b0), self.zip_high_i64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (_, a1) = self.split_i64x8(a);
+        let (_, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a1, b1), self.zip_high_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.unzip_low_i64x4(a0, a1), self.unzip_low_i64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.unzip_high_i64x4(a0, a1), self.unzip_high_i64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> (i64x8<Self>, i64x8<Self>) {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let lo_lo = self.zip_low_i64x4(a0, b0);
+        let lo_hi = self.zip_high_i64x4(a0, b0);
+        let hi_lo = self.zip_low_i64x4(a1, b1);
+        let hi_hi = self.zip_high_i64x4(a1, b1);
+        (
+            self.combine_i64x4(lo_lo, lo_hi),
+            self.combine_i64x4(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> (i64x8<Self>, i64x8<Self>) {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let lo_even = self.unzip_low_i64x4(a0, a1);
+        let lo_odd = self.unzip_high_i64x4(a0, a1);
+        let hi_even = self.unzip_low_i64x4(b0, b1);
+        let hi_odd = self.unzip_high_i64x4(b0, b1);
+        (
+            self.combine_i64x4(lo_even, hi_even),
+            self.combine_i64x4(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_i64x8(self, a: mask64x8<Self>, b: i64x8<Self>, c: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(self.select_i64x4(a0, b0, c0), self.select_i64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_i64x8(
+        self,
+        a: mask64x8<Self>,
+        b: i64x8<Self>,
+        c: i64x8<Self>,
+    ) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(
+            self.select_relaxed_i64x4(a0, b0, c0),
+            self.select_relaxed_i64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.min_i64x4(a0, b0), self.min_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.max_i64x4(a0, b0), self.max_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.saturating_add_i64x4(a0, b0),
+            self.saturating_add_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.saturating_sub_i64x4(a0, b0),
+            self.saturating_sub_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.count_ones_i64x4(a0), self.count_ones_i64x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.leading_zeros_i64x4(a0), self.leading_zeros_i64x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.trailing_zeros_i64x4(a0), self.trailing_zeros_i64x4(a1))
+    }
+    #[inline(always)]
+    fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>) {
+        (
+            i64x4 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            i64x4 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn neg_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.neg_i64x4(a0), self.neg_i64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x8(self, a: i64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_i64x4(a0), self.reinterpret_u8_i64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x8(self, a: i64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i64x4(a0),
+            self.reinterpret_u32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u64x8(self, val: u64) -> u64x8<Self> {
+        let half = self.splat_u64x4(val);
+        self.combine_u64x4(half, half)
+    }
+    #[inline(always)]
+    fn load_array_u64x8(self, val: [u64; 8usize]) -> u64x8<Self> {
+        u64x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn load_array_ref_u64x8(self, val: &[u64; 8usize]) -> u64x8<Self> {
+        u64x8 {
+            val: crate::transmute::checked_transmute_copy(val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_u64x8(self, a: u64x8<Self>) -> [u64; 8usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [u64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_ref_u64x8(self, a: &u64x8<Self>) -> &[u64; 8usize] {
+        crate::transmute::checked_cast_ref::<[__m256i; 2usize], [u64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn as_array_mut_u64x8(self, a: &mut u64x8<Self>) -> &mut [u64; 8usize] {
+        crate::transmute::checked_cast_mut::<[__m256i; 2usize], [u64; 8usize]>(&mut a.val.0)
+    }
+    #[inline(always)]
+    fn store_array_u64x8(self, a: u64x8<Self>, dest: &mut [u64; 8usize]) -> () {
+        crate::transmute::checked_transmute_store(a.val.0, dest);
+    }
+    #[inline(always)]
+    fn cvt_from_bytes_u64x8(self, a: u8x64<Self>) -> u64x8<Self> {
+        u64x8 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn cvt_to_bytes_u64x8(self, a: u64x8<Self>) -> u8x64<Self> {
+        u8x64 {
+            val: crate::transmute::checked_transmute_copy(&a.val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn slide_u64x8<const SHIFT: usize>(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
+        let result = cross_block_alignr_256x2(
+            self,
+            self.cvt_to_bytes_u64x8(b).val.0,
+            self.cvt_to_bytes_u64x8(a).val.0,
+            SHIFT * 8usize,
+        );
+        self.cvt_from_bytes_u64x8(u8x64 {
+            val: crate::support::Aligned512(result),
+            simd: self,
+        })
+    }
+    #[inline(always)]
+    fn slide_within_blocks_u64x8<const SHIFT: usize>(
+        self,
+        a: u64x8<Self>,
+        b: u64x8<Self>,
+    ) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.slide_within_blocks_u64x4::<SHIFT>(a0, b0),
+            self.slide_within_blocks_u64x4::<SHIFT>(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn rotate_elements_left_u64x8<const OFFSET: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_u64x8::<0>(a, a),
+            1 => self.slide_u64x8::<1>(a, a),
+            2 => self.slide_u64x8::<2>(a, a),
+            3 => self.slide_u64x8::<3>(a, a),
+            4 => self.slide_u64x8::<4>(a, a),
+            5 => self.slide_u64x8::<5>(a, a),
+            6 => self.slide_u64x8::<6>(a, a),
+            7 => self.slide_u64x8::<7>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn rotate_elements_right_u64x8<const OFFSET: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        match OFFSET % 8 {
+            0 => self.slide_u64x8::<8>(a, a),
+            1 => self.slide_u64x8::<7>(a, a),
+            2 => self.slide_u64x8::<6>(a, a),
+            3 => self.slide_u64x8::<5>(a, a),
+            4 => self.slide_u64x8::<4>(a, a),
+            5 => self.slide_u64x8::<3>(a, a),
+            6 => self.slide_u64x8::<2>(a, a),
+            7 => self.slide_u64x8::<1>(a, a),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_left_u64x8<const OFFSET: usize>(
+        self,
+        a: u64x8<Self>,
+        padding: u64,
+    ) -> u64x8<Self> {
+        let padding = self.splat_u64x8(padding);
+        match OFFSET {
+            0 => self.slide_u64x8::<0>(a, padding),
+            1 => self.slide_u64x8::<1>(a, padding),
+            2 => self.slide_u64x8::<2>(a, padding),
+            3 => self.slide_u64x8::<3>(a, padding),
+            4 => self.slide_u64x8::<4>(a, padding),
+            5 => self.slide_u64x8::<5>(a, padding),
+            6 => self.slide_u64x8::<6>(a, padding),
+            7 => self.slide_u64x8::<7>(a, padding),
+            8 => self.slide_u64x8::<8>(a, padding),
+            _ => self.slide_u64x8::<8>(a, padding),
+        }
+    }
+    #[inline(always)]
+    fn shift_elements_right_u64x8<const OFFSET: usize>(
+        self,
+        a: u64x8<Self>,
+        padding: u64,
+    ) -> u64x8<Self> {
+        let padding = self.splat_u64x8(padding);
+        match OFFSET {
+            0 => self.slide_u64x8::<8>(padding, a),
+            1 => self.slide_u64x8::<7>(padding, a),
+            2 => self.slide_u64x8::<6>(padding, a),
+            3 => self.slide_u64x8::<5>(padding, a),
+            4 => self.slide_u64x8::<4>(padding, a),
+            5 => self.slide_u64x8::<3>(padding, a),
+            6 => self.slide_u64x8::<2>(padding, a),
+            7 => self.slide_u64x8::<1>(padding, a),
+            8 => self.slide_u64x8::<0>(padding, a),
+            _ => self.slide_u64x8::<0>(padding, a),
+        }
+    }
+    #[inline(always)]
+    fn extract_u64x8<const LANE: usize>(self, a: u64x8<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x8(a);
+        match LANE {
+            0 => self.extract_u64x4::<0>(a0),
+            1 => self.extract_u64x4::<1>(a0),
+            2 => self.extract_u64x4::<2>(a0),
+            3 => self.extract_u64x4::<3>(a0),
+            4 => self.extract_u64x4::<0>(a1),
+            5 => self.extract_u64x4::<1>(a1),
+            6 => self.extract_u64x4::<2>(a1),
+            7 => self.extract_u64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u64x8<const LANE: usize>(self, a: u64x8<Self>, value: u64) -> u64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x8(a);
+        match LANE {
+            0 => self.combine_u64x4(self.insert_u64x4::<0>(a0, value), a1),
+            1 => self.combine_u64x4(self.insert_u64x4::<1>(a0, value), a1),
+            2 => self.combine_u64x4(self.insert_u64x4::<2>(a0, value), a1),
+            3 => self.combine_u64x4(self.insert_u64x4::<3>(a0, value), a1),
+            4 => self.combine_u64x4(a0, self.insert_u64x4::<0>(a1, value)),
+            5 => self.combine_u64x4(a0, self.insert_u64x4::<1>(a1, value)),
+            6 => self.combine_u64x4(a0, self.insert_u64x4::<2>(a1, value)),
+            7 => self.combine_u64x4(a0, self.insert_u64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x8(self, a: u64x8<Self>, index: usize) -> u64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u64x8(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u64x8(self, a: u64x8<Self>, indices: u8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (indices0, indices1) = self.split_u8x64(indices);
+        self.combine_u64x4(
+            self.swizzle_dyn_within_blocks_u64x4(a0, indices0),
+            self.swizzle_dyn_within_blocks_u64x4(a1, indices1),
+        )
+    }
+    #[inline(always)]
+    fn add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.add_u64x4(a0, b0), self.add_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.sub_u64x4(a0, b0), self.sub_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.mul_u64x4(a0, b0), self.mul_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.and_u64x4(a0, b0), self.and_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.or_u64x4(a0, b0), self.or_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.xor_u64x4(a0, b0), self.xor_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn not_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.not_u64x4(a0), self.not_u64x4(a1))
+    }
+    #[inline(always)]
+    fn shl_u64x8(self, a: u64x8<Self>, shift: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shl_u64x4(a0, shift), self.shl_u64x4(a1, shift))
+    }
+    #[inline(always)]
+    fn shlv_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.shlv_u64x4(a0, b0), self.shlv_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_u64x8(self, a: u64x8<Self>, shift: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shr_u64x4(a0, shift), self.shr_u64x4(a1, shift))
+    }
+    #[inline(always)]
+    fn shrv_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.shrv_u64x4(a0, b0), self.shrv_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shift_signed_u64x8(self, a: u64x8<Self>, b: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_u64x4(
+            self.shift_signed_u64x4(a0, b0),
+            self.shift_signed_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_eq_u64x4(a0, b0), self.simd_eq_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_lt_u64x4(a0, b0), self.simd_lt_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_le_u64x4(a0, b0), self.simd_le_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_ge_u64x4(a0, b0), self.simd_ge_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_gt_u64x4(a0, b0), self.simd_gt_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, _) = self.split_u64x8(a);
+        let (b0, _) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a0, b0), self.zip_high_u64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (_, a1) = self.split_u64x8(a);
+        let (_, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a1, b1), self.zip_high_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn unzip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.unzip_low_u64x4(a0, a1), self.unzip_low_u64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.unzip_high_u64x4(a0, a1), self.unzip_high_u64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn interleave_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> (u64x8<Self>, u64x8<Self>) {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let lo_lo = self.zip_low_u64x4(a0, b0);
+        let lo_hi = self.zip_high_u64x4(a0, b0);
+        let hi_lo = self.zip_low_u64x4(a1, b1);
+        let hi_hi = self.zip_high_u64x4(a1, b1);
+        (
+            self.combine_u64x4(lo_lo, lo_hi),
+            self.combine_u64x4(hi_lo, hi_hi),
+        )
+    }
+    #[inline(always)]
+    fn deinterleave_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> (u64x8<Self>, u64x8<Self>) {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let lo_even = self.unzip_low_u64x4(a0, a1);
+        let lo_odd = self.unzip_high_u64x4(a0, a1);
+        let hi_even = self.unzip_low_u64x4(b0, b1);
+        let hi_odd = self.unzip_high_u64x4(b0, b1);
+        (
+            self.combine_u64x4(lo_even, hi_even),
+            self.combine_u64x4(lo_odd, hi_odd),
+        )
+    }
+    #[inline(always)]
+    fn select_u64x8(self, a: mask64x8<Self>, b: u64x8<Self>, c: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(self.select_u64x4(a0, b0, c0), self.select_u64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_u64x8(
+        self,
+        a: mask64x8<Self>,
+        b: u64x8<Self>,
+        c: u64x8<Self>,
+    ) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(
+            self.select_relaxed_u64x4(a0, b0, c0),
+            self.select_relaxed_u64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.min_u64x4(a0, b0), self.min_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.max_u64x4(a0, b0), self.max_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.saturating_add_u64x4(a0, b0),
+            self.saturating_add_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.saturating_sub_u64x4(a0, b0),
+            self.saturating_sub_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.count_ones_u64x4(a0), self.count_ones_u64x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.leading_zeros_u64x4(a0), self.leading_zeros_u64x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.trailing_zeros_u64x4(a0), self.trailing_zeros_u64x4(a1))
+    }
+    #[inline(always)]
+    fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>) {
+        (
+            u64x4 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            u64x4 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u64x8(self, src: &[u64; 8usize]) -> u64x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, src: &[u64; 8usize]) -> u64x8<Avx2> {
+                let (chunks, []) = src.as_chunks::<4>() else {
+                    unreachable!()
+                };
+                let v0: __m256i =
+                    crate::transmute::checked_transmute_copy::<[u64; 4], __m256i>(&chunks[0]);
+                let v1: __m256i =
+                    crate::transmute::checked_transmute_copy::<[u64; 4], __m256i>(&chunks[1]);
+                let lo = _mm256_unpacklo_epi64(v0, v1);
+                let hi = _mm256_unpackhi_epi64(v0, v1);
+                let out0 = _mm256_permute2x128_si256::<0x20>(lo, hi);
+                let out1 = _mm256_permute2x128_si256::<0x31>(lo, hi);
+                token.combine_u64x4(out0.simd_into(token), out1.simd_into(token))
+            }
+        );
+        kernel(self, src)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u64x8(self, a: u64x8<Self>, dest: &mut [u64; 8usize]) -> () {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, a: u64x8<Avx2>, dest: &mut [u64; 8usize]) -> () {
+                let (v0, v1) = token.split_u64x8(a);
+                let v0: __m256i = v0.into();
+                let v1: __m256i = v1.into();
+                let lo = _mm256_permute2x128_si256::<0x20>(v0, v1);
+                let hi = _mm256_permute2x128_si256::<0x31>(v0, v1);
+                let out0 = _mm256_unpacklo_epi64(lo, hi);
+                let out1 = _mm256_unpackhi_epi64(lo, hi);
+                let (chunks, []) = dest.as_chunks_mut::<4>() else {
+                    unreachable!()
+                };
+                crate::transmute::checked_transmute_store::<__m256i, [u64; 4]>(
+                    out0,
+                    &mut chunks[0],
+                );
+                crate::transmute::checked_transmute_store::<__m256i, [u64; 4]>(
+                    out1,
+                    &mut chunks[1],
+                );
+            }
+        );
+        kernel(self, a, dest);
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x8(self, a: u64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_u64x4(a0), self.reinterpret_u8_u64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x8(self, a: u64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u64x4(a0),
+            self.reinterpret_u32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn transpose_bits_8x8_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 7)),
+            self.splat_u64x8(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 7));
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 14)),
+            self.splat_u64x8(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 14));
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 28)),
+            self.splat_u64x8(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 28))
+    }
+    #[inline(always)]
+    fn splat_mask64x8(self, val: bool) -> mask64x8<Self> {
+        let half = self.splat_mask64x4(val);
+        self.combine_mask64x4(half, half)
+    }
+    #[inline(always)]
+    fn load_array_mask64x8(self, val: [i64; 8usize]) -> mask64x8<Self> {
+        mask64x8 {
+            val: crate::transmute::checked_transmute_copy(&val),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn as_array_mask64x8(self, a: mask64x8<Self>) -> [i64; 8usize] {
+        crate::transmute::checked_transmute_copy::<[__m256i; 2usize], [i64; 8usize]>(&a.val.0)
+    }
+    #[inline(always)]
+    fn to_int_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self> {
+        i64x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Avx2, bits: u64) -> mask64x8<Avx2> {
+                {
+                    let bit_lanes = _mm256_set1_epi64x(bits.cast_signed());
+                    mask64x8 {
+                        val: crate::support::Aligned512([
+                            {
+                                let bit_mask = _mm256_set_epi64x(8, 4, 2, 1);
+                                _mm256_cmpeq_epi64(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                            },
+                            {
+                                let bit_mask = _mm256_set_epi64x(128, 64, 32, 16);
+                                _mm256_cmpeq_epi64(_mm256_and_si256(bit_lanes, bit_mask), bit_mask)
+                            },
+                        ]),
+                        simd: token,
+                    }
+                }
+            }
+        );
+        kernel(self, bits)
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x8(self, a: mask64x8<Self>) -> u64 {
+        let (lo, hi) = self.split_mask64x8(a);
+        let lo = self.to_bitmask_mask64x4(lo);
+        let hi = self.to_bitmask_mask64x4(hi);
+        lo | (hi << 4usize)
+    }
+    #[inline(always)]
+    fn set_mask64x8(self, a: &mut mask64x8<Self>, index: usize, value: bool) -> () {
+        assert!(
+            index < 8usize,
+            "mask lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        let mut lanes = self.as_array_mask64x8(*a);
+        lanes[index] = if value { !0 } else { 0 };
+        *a = self.load_array_mask64x8(lanes);
+    }
+    #[inline(always)]
+    fn and_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.and_mask64x4(a0, b0), self.and_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.or_mask64x4(a0, b0), self.or_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.xor_mask64x4(a0, b0), self.xor_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn not_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_mask64x4(self.not_mask64x4(a0), self.not_mask64x4(a1))
+    }
+    #[inline(always)]
+    fn select_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_mask64x4(a0, b0, c0),
+            self.select_mask64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn select_relaxed_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_relaxed_mask64x4(a0, b0, c0),
+            self.select_relaxed_mask64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.simd_eq_mask64x4(a0, b0), self.simd_eq_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn any_true_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.any_true_mask64x4(a0) || self.any_true_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn all_true_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.all_true_mask64x4(a0) && self.all_true_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn any_false_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.any_false_mask64x4(a0) || self.any_false_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn all_false_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.all_false_mask64x4(a0) && self.all_false_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn split_mask64x8(self, a: mask64x8<Self>) -> (mask64x4<Self>, mask64x4<Self>) {
+        (
+            mask64x4 {
+                val: crate::support::Aligned256(a.val.0[0]),
+                simd: self,
+            },
+            mask64x4 {
+                val: crate::support::Aligned256(a.val.0[1]),
+                simd: self,
+            },
+        )
+    }
+}
+crate::kernel!(
+    #[doc = r" This is a version of the `alignr` intrinsic that takes a non-const shift argument. The shift is still"]
+    #[doc = r" expected to be constant in practice, so the match statement will be optimized out. This exists because"]
+    #[doc = r" Rust doesn't currently let you do math on const generics."]
+    #[inline(always)]
+    fn dyn_alignr_128(token: Avx2, a: __m128i, b: __m128i, shift: usize) -> __m128i {
+        match shift {
+            0usize => _mm_alignr_epi8::<0i32>(a, b),
+            1usize => _mm_alignr_epi8::<1i32>(a, b),
+            2usize => _mm_alignr_epi8::<2i32>(a, b),
+            3usize => _mm_alignr_epi8::<3i32>(a, b),
+            4usize => _mm_alignr_epi8::<4i32>(a, b),
+            5usize => _mm_alignr_epi8::<5i32>(a, b),
+            6usize => _mm_alignr_epi8::<6i32>(a, b),
+            7usize => _mm_alignr_epi8::<7i32>(a, b),
+            8usize => _mm_alignr_epi8::<8i32>(a, b),
+            9usize => _mm_alignr_epi8::<9i32>(a, b),
+            10usize => _mm_alignr_epi8::<10i32>(a, b),
+            11usize => _mm_alignr_epi8::<11i32>(a, b),
+            12usize => _mm_alignr_epi8::<12i32>(a, b),
+            13usize => _mm_alignr_epi8::<13i32>(a, b),
+            14usize => _mm_alignr_epi8::<14i32>(a, b),
+            15usize => _mm_alignr_epi8::<15i32>(a, b),
+            _ => unreachable!(),
+        }
+    }
+);
+crate::kernel!(
+    #[doc = r" This is a version of the `alignr` intrinsic that takes a non-const shift argument. The shift is still"]
+    #[doc = r" expected to be constant in practice, so the match statement will be optimized out. This exists because"]
+    #[doc = r" Rust doesn't currently let you do math on const generics."]
+    #[inline(always)]
+    fn dyn_alignr_256(token: Avx2, a: __m256i, b: __m256i, shift: usize) -> __m256i {
+        match shift {
+            0usize => _mm256_alignr_epi8::<0i32>(a, b),
+            1usize => _mm256_alignr_epi8::<1i32>(a, b),
+            2usize => _mm256_alignr_epi8::<2i32>(a, b),
+            3usize => _mm256_alignr_epi8::<3i32>(a, b),
+            4usize => _mm256_alignr_epi8::<4i32>(a, b),
+            5usize => _mm256_alignr_epi8::<5i32>(a, b),
+            6usize => _mm256_alignr_epi8::<6i32>(a, b),
+            7usize => _mm256_alignr_epi8::<7i32>(a, b),
+            8usize => _mm256_alignr_epi8::<8i32>(a, b),
+            9usize => _mm256_alignr_epi8::<9i32>(a, b),
+            10usize => _mm256_alignr_epi8::<10i32>(a, b),
+            11usize => _mm256_alignr_epi8::<11i32>(a, b),
+            12usize => _mm256_alignr_epi8::<12i32>(a, b),
+            13usize => _mm256_alignr_epi8::<13i32>(a, b),
+            14usize => _mm256_alignr_epi8::<14i32>(a, b),
+            15usize => _mm256_alignr_epi8::<15i32>(a, b),
+            _ => unreachable!(),
+        }
+    }
+);
+crate::kernel!(
+    #[doc = r" Computes one output __m256i for `cross_block_alignr_*` operations."]
+    #[doc = r""]
+    #[doc = r" Given an array of registers, each containing two 128-bit blocks, extracts two adjacent blocks (`lo_idx` and"]
+    #[doc = r" `hi_idx` = `lo_idx + 1`) and performs `alignr` with `intra_shift`."]
+    #[inline(always)]
+    fn cross_block_alignr_one(
+        token: Avx2,
+        regs: &[__m256i],
+        block_idx: usize,
+        shift_bytes: usize,
+    ) -> __m256i {
+        let lo_idx = block_idx + (shift_bytes / 16);
+        let intra_shift = shift_bytes % 16;
+        let lo_blocks = if lo_idx & 1 == 0 {
+            regs[lo_idx / 2]
+        } else {
+            _mm256_permute2x128_si256::<0x21>(regs[lo_idx / 2], regs[(lo_idx / 2) + 1])
+        };
+        let hi_idx = lo_idx + 1;
+        let hi_blocks = if hi_idx & 1 == 0 {
+            regs[hi_idx / 2]
+        } else {
+            _mm256_permute2x128_si256::<0x21>(regs[hi_idx / 2], regs[(hi_idx / 2) + 1])
+        };
+        dyn_alignr_256(token, hi_blocks, lo_blocks, intra_shift)
+    }
+);
+crate::kernel!(
+    #[doc = r" Concatenates `b` and `a` (each 2 x __m256i = 4 blocks) and extracts 4 blocks starting at byte offset"]
+    #[doc = r" `shift_bytes`. Extracts from [b : a] (b in low bytes, a in high bytes), matching alignr semantics."]
+    #[inline(always)]
+    fn cross_block_alignr_256x2(
+        token: Avx2,
+        a: [__m256i; 2],
+        b: [__m256i; 2],
+        shift_bytes: usize,
+    ) -> [__m256i; 2] {
+        let regs = [b[0], b[1], a[0], a[1]];
+        [
+            cross_block_alignr_one(token, &regs, 0, shift_bytes),
+            cross_block_alignr_one(token, &regs, 2, shift_bytes),
+        ]
+    }
+);
+crate::kernel!(
+    #[doc = r" Concatenates `b` and `a` (each 1 x __m256i = 2 blocks) and extracts 2 blocks starting at byte offset"]
+    #[doc = r" `shift_bytes`. Extracts from [b : a] (b in low bytes, a in high bytes), matching alignr semantics."]
+    #[inline(always)]
+    fn cross_block_alignr_256x1(
+        token: Avx2,
+        a: __m256i,
+        b: __m256i,
+        shift_bytes: usize,
+    ) -> __m256i {
+        let regs = [b, a];
+        cross_block_alignr_one(token, &regs, 0, shift_bytes)
+    }
+);
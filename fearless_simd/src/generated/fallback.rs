@@ -3,6 +3,7 @@
 
 // This file is autogenerated by fearless_simd_gen
 
+use crate::fallback_float::FallbackFloat;
 use crate::{Level, arch_types::ArchTypes, prelude::*, seal::Seal};
 use crate::{
     f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4,
@@ -11,73 +12,6 @@ use crate::{
     u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
 };
 use core::ops::*;
-#[cfg(all(feature = "libm", not(feature = "std")))]
-#[allow(
-    dead_code,
-    reason = "Generated backends use different subsets of these helpers"
-)]
-trait FloatExt {
-    fn floor(self) -> Self;
-    fn ceil(self) -> Self;
-    fn round_ties_even(self) -> Self;
-    fn fract(self) -> Self;
-    fn sqrt(self) -> Self;
-    fn trunc(self) -> Self;
-}
-#[cfg(all(feature = "libm", not(feature = "std")))]
-impl FloatExt for f32 {
-    #[inline(always)]
-    fn floor(self) -> f32 {
-        libm::floorf(self)
-    }
-    #[inline(always)]
-    fn ceil(self) -> f32 {
-        libm::ceilf(self)
-    }
-    #[inline(always)]
-    fn round_ties_even(self) -> f32 {
-        libm::rintf(self)
-    }
-    #[inline(always)]
-    fn sqrt(self) -> f32 {
-        libm::sqrtf(self)
-    }
-    #[inline(always)]
-    fn fract(self) -> f32 {
-        self - self.trunc()
-    }
-    #[inline(always)]
-    fn trunc(self) -> f32 {
-        libm::truncf(self)
-    }
-}
-#[cfg(all(feature = "libm", not(feature = "std")))]
-impl FloatExt for f64 {
-    #[inline(always)]
-    fn floor(self) -> f64 {
-        libm::floor(self)
-    }
-    #[inline(always)]
-    fn ceil(self) -> f64 {
-        libm::ceil(self)
-    }
-    #[inline(always)]
-    fn round_ties_even(self) -> f64 {
-        libm::rint(self)
-    }
-    #[inline(always)]
-    fn sqrt(self) -> f64 {
-        libm::sqrt(self)
-    }
-    #[inline(always)]
-    fn fract(self) -> f64 {
-        self - self.trunc()
-    }
-    #[inline(always)]
-    fn trunc(self) -> f64 {
-        libm::trunc(self)
-    }
-}
 #[doc = "A token for scalar fallback SIMD, representing the \"fallback\" level."]
 #[derive(Clone, Copy, Debug)]
 pub struct Fallback {
@@ -149,6 +83,9 @@ impl Simd for Fallback {
     type mask16s = mask16x8<Self>;
     type mask32s = mask32x4<Self>;
     type mask64s = mask64x2<Self>;
+    const NATIVE_BITS: usize = 128;
+    const FUSED_MUL_ADD: bool = false;
+    const SOFT_FLOAT: bool = cfg!(feature = "soft_float");
     #[inline(always)]
     fn level(self) -> Level {
         #[cfg(feature = "force_support_fallback")]
@@ -213,6 +150,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_f32x4<const SHIFT: usize>(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
         let mut dest = [Default::default(); 4usize];
         dest[..4usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[4usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -279,6 +219,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_f32x4<const LANE: usize>(self, a: f32x4<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_f32x4<const LANE: usize>(self, a: f32x4<Self>, value: f32) -> f32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x4(self, a: f32x4<Self>, index: usize) -> f32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_f32x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f32x4(self, a: f32x4<Self>, indices: u8x16<Self>) -> f32x4<Self> {
         let bytes = self.cvt_to_bytes_f32x4(a);
         let result: u8x16<Self> = [
@@ -373,10 +344,10 @@ impl Simd for Fallback {
     #[inline(always)]
     fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::sqrt(a[0usize]),
-            f32::sqrt(a[1usize]),
-            f32::sqrt(a[2usize]),
-            f32::sqrt(a[3usize]),
+            FallbackFloat::sqrt(a[0usize]),
+            FallbackFloat::sqrt(a[1usize]),
+            FallbackFloat::sqrt(a[2usize]),
+            FallbackFloat::sqrt(a[3usize]),
         ]
         .simd_into(self)
     }
@@ -387,40 +358,40 @@ impl Simd for Fallback {
     #[inline(always)]
     fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::add(a[0usize], &b[0usize]),
-            f32::add(a[1usize], &b[1usize]),
-            f32::add(a[2usize], &b[2usize]),
-            f32::add(a[3usize], &b[3usize]),
+            FallbackFloat::add(a[0usize], b[0usize]),
+            FallbackFloat::add(a[1usize], b[1usize]),
+            FallbackFloat::add(a[2usize], b[2usize]),
+            FallbackFloat::add(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::sub(a[0usize], &b[0usize]),
-            f32::sub(a[1usize], &b[1usize]),
-            f32::sub(a[2usize], &b[2usize]),
-            f32::sub(a[3usize], &b[3usize]),
+            FallbackFloat::sub(a[0usize], b[0usize]),
+            FallbackFloat::sub(a[1usize], b[1usize]),
+            FallbackFloat::sub(a[2usize], b[2usize]),
+            FallbackFloat::sub(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::mul(a[0usize], &b[0usize]),
-            f32::mul(a[1usize], &b[1usize]),
-            f32::mul(a[2usize], &b[2usize]),
-            f32::mul(a[3usize], &b[3usize]),
+            FallbackFloat::mul(a[0usize], b[0usize]),
+            FallbackFloat::mul(a[1usize], b[1usize]),
+            FallbackFloat::mul(a[2usize], b[2usize]),
+            FallbackFloat::mul(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::div(a[0usize], &b[0usize]),
-            f32::div(a[1usize], &b[1usize]),
-            f32::div(a[2usize], &b[2usize]),
-            f32::div(a[3usize], &b[3usize]),
+            FallbackFloat::div(a[0usize], b[0usize]),
+            FallbackFloat::div(a[1usize], b[1usize]),
+            FallbackFloat::div(a[2usize], b[2usize]),
+            FallbackFloat::div(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
@@ -511,40 +482,40 @@ impl Simd for Fallback {
     #[inline(always)]
     fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::max(a[0usize], b[0usize]),
-            f32::max(a[1usize], b[1usize]),
-            f32::max(a[2usize], b[2usize]),
-            f32::max(a[3usize], b[3usize]),
+            FallbackFloat::max(a[0usize], b[0usize]),
+            FallbackFloat::max(a[1usize], b[1usize]),
+            FallbackFloat::max(a[2usize], b[2usize]),
+            FallbackFloat::max(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn min_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::min(a[0usize], b[0usize]),
-            f32::min(a[1usize], b[1usize]),
-            f32::min(a[2usize], b[2usize]),
-            f32::min(a[3usize], b[3usize]),
+            FallbackFloat::min(a[0usize], b[0usize]),
+            FallbackFloat::min(a[1usize], b[1usize]),
+            FallbackFloat::min(a[2usize], b[2usize]),
+            FallbackFloat::min(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn max_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::max(a[0usize], b[0usize]),
-            f32::max(a[1usize], b[1usize]),
-            f32::max(a[2usize], b[2usize]),
-            f32::max(a[3usize], b[3usize]),
+            FallbackFloat::max(a[0usize], b[0usize]),
+            FallbackFloat::max(a[1usize], b[1usize]),
+            FallbackFloat::max(a[2usize], b[2usize]),
+            FallbackFloat::max(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::min(a[0usize], b[0usize]),
-            f32::min(a[1usize], b[1usize]),
-            f32::min(a[2usize], b[2usize]),
-            f32::min(a[3usize], b[3usize]),
+            FallbackFloat::min(a[0usize], b[0usize]),
+            FallbackFloat::min(a[1usize], b[1usize]),
+            FallbackFloat::min(a[2usize], b[2usize]),
+            FallbackFloat::min(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
@@ -557,52 +528,64 @@ impl Simd for Fallback {
         a.mul(b).sub(c)
     }
     #[inline(always)]
+    fn nmul_add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        c.sub(a.mul(b))
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        a.mul(b).neg().sub(c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.mul_add_f32x4(a, b, c)
+    }
+    #[inline(always)]
     fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::floor(a[0usize]),
-            f32::floor(a[1usize]),
-            f32::floor(a[2usize]),
-            f32::floor(a[3usize]),
+            FallbackFloat::floor(a[0usize]),
+            FallbackFloat::floor(a[1usize]),
+            FallbackFloat::floor(a[2usize]),
+            FallbackFloat::floor(a[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn ceil_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::ceil(a[0usize]),
-            f32::ceil(a[1usize]),
-            f32::ceil(a[2usize]),
-            f32::ceil(a[3usize]),
+            FallbackFloat::ceil(a[0usize]),
+            FallbackFloat::ceil(a[1usize]),
+            FallbackFloat::ceil(a[2usize]),
+            FallbackFloat::ceil(a[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn round_ties_even_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::round_ties_even(a[0usize]),
-            f32::round_ties_even(a[1usize]),
-            f32::round_ties_even(a[2usize]),
-            f32::round_ties_even(a[3usize]),
+            FallbackFloat::round_ties_even(a[0usize]),
+            FallbackFloat::round_ties_even(a[1usize]),
+            FallbackFloat::round_ties_even(a[2usize]),
+            FallbackFloat::round_ties_even(a[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::fract(a[0usize]),
-            f32::fract(a[1usize]),
-            f32::fract(a[2usize]),
-            f32::fract(a[3usize]),
+            FallbackFloat::fract(a[0usize]),
+            FallbackFloat::fract(a[1usize]),
+            FallbackFloat::fract(a[2usize]),
+            FallbackFloat::fract(a[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         [
-            f32::trunc(a[0usize]),
-            f32::trunc(a[1usize]),
-            f32::trunc(a[2usize]),
-            f32::trunc(a[3usize]),
+            FallbackFloat::trunc(a[0usize]),
+            FallbackFloat::trunc(a[1usize]),
+            FallbackFloat::trunc(a[2usize]),
+            FallbackFloat::trunc(a[3usize]),
         ]
         .simd_into(self)
     }
@@ -633,6 +616,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_f32x4(
+        self,
+        a: mask32x4<Self>,
+        b: f32x4<Self>,
+        c: f32x4<Self>,
+    ) -> f32x4<Self> {
+        self.select_f32x4(a, b, c)
+    }
+    #[inline(always)]
     fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
         let mut result = [0.0; 8usize];
         result[0..4usize].copy_from_slice(&a.val.0);
@@ -696,6 +688,78 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn dot2_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let p = [
+            FallbackFloat::mul(a[0usize], b[0usize]),
+            FallbackFloat::mul(a[1usize], b[1usize]),
+            FallbackFloat::mul(a[2usize], b[2usize]),
+            FallbackFloat::mul(a[3usize], b[3usize]),
+        ];
+        [
+            FallbackFloat::add(p[0usize], p[1usize]),
+            FallbackFloat::add(p[0usize], p[1usize]),
+            FallbackFloat::add(p[2usize], p[3usize]),
+            FallbackFloat::add(p[2usize], p[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn dot3_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let p = [
+            FallbackFloat::mul(a[0usize], b[0usize]),
+            FallbackFloat::mul(a[1usize], b[1usize]),
+            FallbackFloat::mul(a[2usize], b[2usize]),
+            FallbackFloat::mul(a[3usize], b[3usize]),
+        ];
+        [
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], 0.0),
+            ),
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], 0.0),
+            ),
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], 0.0),
+            ),
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], 0.0),
+            ),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn dot4_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let p = [
+            FallbackFloat::mul(a[0usize], b[0usize]),
+            FallbackFloat::mul(a[1usize], b[1usize]),
+            FallbackFloat::mul(a[2usize], b[2usize]),
+            FallbackFloat::mul(a[3usize], b[3usize]),
+        ];
+        [
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], p[3usize]),
+            ),
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], p[3usize]),
+            ),
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], p[3usize]),
+            ),
+            FallbackFloat::add(
+                FallbackFloat::add(p[0usize], p[1usize]),
+                FallbackFloat::add(p[2usize], p[3usize]),
+            ),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn splat_i8x16(self, val: i8) -> i8x16<Self> {
         [val; 16usize].simd_into(self)
     }
@@ -745,6 +809,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i8x16<const SHIFT: usize>(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -859,6 +926,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i8x16<const LANE: usize>(self, a: i8x16<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_i8x16<const LANE: usize>(self, a: i8x16<Self>, value: i8) -> i8x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x16(self, a: i8x16<Self>, index: usize) -> i8 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i8x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i8x16(self, a: i8x16<Self>, indices: u8x16<Self>) -> i8x16<Self> {
         let bytes = self.cvt_to_bytes_i8x16(a);
         let result: u8x16<Self> = [
@@ -1087,88 +1185,174 @@ impl Simd for Fallback {
     #[inline(always)]
     fn shl_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self> {
         [
-            i8::shl(a[0usize], shift),
-            i8::shl(a[1usize], shift),
-            i8::shl(a[2usize], shift),
-            i8::shl(a[3usize], shift),
-            i8::shl(a[4usize], shift),
-            i8::shl(a[5usize], shift),
-            i8::shl(a[6usize], shift),
-            i8::shl(a[7usize], shift),
-            i8::shl(a[8usize], shift),
-            i8::shl(a[9usize], shift),
-            i8::shl(a[10usize], shift),
-            i8::shl(a[11usize], shift),
-            i8::shl(a[12usize], shift),
-            i8::shl(a[13usize], shift),
-            i8::shl(a[14usize], shift),
-            i8::shl(a[15usize], shift),
+            i8::wrapping_shl(a[0usize], shift),
+            i8::wrapping_shl(a[1usize], shift),
+            i8::wrapping_shl(a[2usize], shift),
+            i8::wrapping_shl(a[3usize], shift),
+            i8::wrapping_shl(a[4usize], shift),
+            i8::wrapping_shl(a[5usize], shift),
+            i8::wrapping_shl(a[6usize], shift),
+            i8::wrapping_shl(a[7usize], shift),
+            i8::wrapping_shl(a[8usize], shift),
+            i8::wrapping_shl(a[9usize], shift),
+            i8::wrapping_shl(a[10usize], shift),
+            i8::wrapping_shl(a[11usize], shift),
+            i8::wrapping_shl(a[12usize], shift),
+            i8::wrapping_shl(a[13usize], shift),
+            i8::wrapping_shl(a[14usize], shift),
+            i8::wrapping_shl(a[15usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shlv_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
         [
-            i8::shl(a[0usize], &b[0usize]),
-            i8::shl(a[1usize], &b[1usize]),
-            i8::shl(a[2usize], &b[2usize]),
-            i8::shl(a[3usize], &b[3usize]),
-            i8::shl(a[4usize], &b[4usize]),
-            i8::shl(a[5usize], &b[5usize]),
-            i8::shl(a[6usize], &b[6usize]),
-            i8::shl(a[7usize], &b[7usize]),
-            i8::shl(a[8usize], &b[8usize]),
-            i8::shl(a[9usize], &b[9usize]),
-            i8::shl(a[10usize], &b[10usize]),
-            i8::shl(a[11usize], &b[11usize]),
-            i8::shl(a[12usize], &b[12usize]),
-            i8::shl(a[13usize], &b[13usize]),
-            i8::shl(a[14usize], &b[14usize]),
-            i8::shl(a[15usize], &b[15usize]),
+            i8::wrapping_shl(a[0usize], b[0usize] as u32),
+            i8::wrapping_shl(a[1usize], b[1usize] as u32),
+            i8::wrapping_shl(a[2usize], b[2usize] as u32),
+            i8::wrapping_shl(a[3usize], b[3usize] as u32),
+            i8::wrapping_shl(a[4usize], b[4usize] as u32),
+            i8::wrapping_shl(a[5usize], b[5usize] as u32),
+            i8::wrapping_shl(a[6usize], b[6usize] as u32),
+            i8::wrapping_shl(a[7usize], b[7usize] as u32),
+            i8::wrapping_shl(a[8usize], b[8usize] as u32),
+            i8::wrapping_shl(a[9usize], b[9usize] as u32),
+            i8::wrapping_shl(a[10usize], b[10usize] as u32),
+            i8::wrapping_shl(a[11usize], b[11usize] as u32),
+            i8::wrapping_shl(a[12usize], b[12usize] as u32),
+            i8::wrapping_shl(a[13usize], b[13usize] as u32),
+            i8::wrapping_shl(a[14usize], b[14usize] as u32),
+            i8::wrapping_shl(a[15usize], b[15usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self> {
         [
-            i8::shr(a[0usize], shift),
-            i8::shr(a[1usize], shift),
-            i8::shr(a[2usize], shift),
-            i8::shr(a[3usize], shift),
-            i8::shr(a[4usize], shift),
-            i8::shr(a[5usize], shift),
-            i8::shr(a[6usize], shift),
-            i8::shr(a[7usize], shift),
-            i8::shr(a[8usize], shift),
-            i8::shr(a[9usize], shift),
-            i8::shr(a[10usize], shift),
-            i8::shr(a[11usize], shift),
-            i8::shr(a[12usize], shift),
-            i8::shr(a[13usize], shift),
-            i8::shr(a[14usize], shift),
-            i8::shr(a[15usize], shift),
+            i8::wrapping_shr(a[0usize], shift),
+            i8::wrapping_shr(a[1usize], shift),
+            i8::wrapping_shr(a[2usize], shift),
+            i8::wrapping_shr(a[3usize], shift),
+            i8::wrapping_shr(a[4usize], shift),
+            i8::wrapping_shr(a[5usize], shift),
+            i8::wrapping_shr(a[6usize], shift),
+            i8::wrapping_shr(a[7usize], shift),
+            i8::wrapping_shr(a[8usize], shift),
+            i8::wrapping_shr(a[9usize], shift),
+            i8::wrapping_shr(a[10usize], shift),
+            i8::wrapping_shr(a[11usize], shift),
+            i8::wrapping_shr(a[12usize], shift),
+            i8::wrapping_shr(a[13usize], shift),
+            i8::wrapping_shr(a[14usize], shift),
+            i8::wrapping_shr(a[15usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shrv_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
         [
-            i8::shr(a[0usize], &b[0usize]),
-            i8::shr(a[1usize], &b[1usize]),
-            i8::shr(a[2usize], &b[2usize]),
-            i8::shr(a[3usize], &b[3usize]),
-            i8::shr(a[4usize], &b[4usize]),
-            i8::shr(a[5usize], &b[5usize]),
-            i8::shr(a[6usize], &b[6usize]),
-            i8::shr(a[7usize], &b[7usize]),
-            i8::shr(a[8usize], &b[8usize]),
-            i8::shr(a[9usize], &b[9usize]),
-            i8::shr(a[10usize], &b[10usize]),
-            i8::shr(a[11usize], &b[11usize]),
-            i8::shr(a[12usize], &b[12usize]),
-            i8::shr(a[13usize], &b[13usize]),
-            i8::shr(a[14usize], &b[14usize]),
-            i8::shr(a[15usize], &b[15usize]),
+            i8::wrapping_shr(a[0usize], b[0usize] as u32),
+            i8::wrapping_shr(a[1usize], b[1usize] as u32),
+            i8::wrapping_shr(a[2usize], b[2usize] as u32),
+            i8::wrapping_shr(a[3usize], b[3usize] as u32),
+            i8::wrapping_shr(a[4usize], b[4usize] as u32),
+            i8::wrapping_shr(a[5usize], b[5usize] as u32),
+            i8::wrapping_shr(a[6usize], b[6usize] as u32),
+            i8::wrapping_shr(a[7usize], b[7usize] as u32),
+            i8::wrapping_shr(a[8usize], b[8usize] as u32),
+            i8::wrapping_shr(a[9usize], b[9usize] as u32),
+            i8::wrapping_shr(a[10usize], b[10usize] as u32),
+            i8::wrapping_shr(a[11usize], b[11usize] as u32),
+            i8::wrapping_shr(a[12usize], b[12usize] as u32),
+            i8::wrapping_shr(a[13usize], b[13usize] as u32),
+            i8::wrapping_shr(a[14usize], b[14usize] as u32),
+            i8::wrapping_shr(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -1412,6 +1596,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_i8x16(
+        self,
+        a: mask8x16<Self>,
+        b: i8x16<Self>,
+        c: i8x16<Self>,
+    ) -> i8x16<Self> {
+        self.select_i8x16(a, b, c)
+    }
+    #[inline(always)]
     fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
         [
             i8::min(a[0usize], b[0usize]),
@@ -1456,6 +1649,116 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::saturating_add(a[0usize], b[0usize]),
+            i8::saturating_add(a[1usize], b[1usize]),
+            i8::saturating_add(a[2usize], b[2usize]),
+            i8::saturating_add(a[3usize], b[3usize]),
+            i8::saturating_add(a[4usize], b[4usize]),
+            i8::saturating_add(a[5usize], b[5usize]),
+            i8::saturating_add(a[6usize], b[6usize]),
+            i8::saturating_add(a[7usize], b[7usize]),
+            i8::saturating_add(a[8usize], b[8usize]),
+            i8::saturating_add(a[9usize], b[9usize]),
+            i8::saturating_add(a[10usize], b[10usize]),
+            i8::saturating_add(a[11usize], b[11usize]),
+            i8::saturating_add(a[12usize], b[12usize]),
+            i8::saturating_add(a[13usize], b[13usize]),
+            i8::saturating_add(a[14usize], b[14usize]),
+            i8::saturating_add(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::saturating_sub(a[0usize], b[0usize]),
+            i8::saturating_sub(a[1usize], b[1usize]),
+            i8::saturating_sub(a[2usize], b[2usize]),
+            i8::saturating_sub(a[3usize], b[3usize]),
+            i8::saturating_sub(a[4usize], b[4usize]),
+            i8::saturating_sub(a[5usize], b[5usize]),
+            i8::saturating_sub(a[6usize], b[6usize]),
+            i8::saturating_sub(a[7usize], b[7usize]),
+            i8::saturating_sub(a[8usize], b[8usize]),
+            i8::saturating_sub(a[9usize], b[9usize]),
+            i8::saturating_sub(a[10usize], b[10usize]),
+            i8::saturating_sub(a[11usize], b[11usize]),
+            i8::saturating_sub(a[12usize], b[12usize]),
+            i8::saturating_sub(a[13usize], b[13usize]),
+            i8::saturating_sub(a[14usize], b[14usize]),
+            i8::saturating_sub(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::count_ones(a[0usize]) as i8,
+            i8::count_ones(a[1usize]) as i8,
+            i8::count_ones(a[2usize]) as i8,
+            i8::count_ones(a[3usize]) as i8,
+            i8::count_ones(a[4usize]) as i8,
+            i8::count_ones(a[5usize]) as i8,
+            i8::count_ones(a[6usize]) as i8,
+            i8::count_ones(a[7usize]) as i8,
+            i8::count_ones(a[8usize]) as i8,
+            i8::count_ones(a[9usize]) as i8,
+            i8::count_ones(a[10usize]) as i8,
+            i8::count_ones(a[11usize]) as i8,
+            i8::count_ones(a[12usize]) as i8,
+            i8::count_ones(a[13usize]) as i8,
+            i8::count_ones(a[14usize]) as i8,
+            i8::count_ones(a[15usize]) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::leading_zeros(a[0usize]) as i8,
+            i8::leading_zeros(a[1usize]) as i8,
+            i8::leading_zeros(a[2usize]) as i8,
+            i8::leading_zeros(a[3usize]) as i8,
+            i8::leading_zeros(a[4usize]) as i8,
+            i8::leading_zeros(a[5usize]) as i8,
+            i8::leading_zeros(a[6usize]) as i8,
+            i8::leading_zeros(a[7usize]) as i8,
+            i8::leading_zeros(a[8usize]) as i8,
+            i8::leading_zeros(a[9usize]) as i8,
+            i8::leading_zeros(a[10usize]) as i8,
+            i8::leading_zeros(a[11usize]) as i8,
+            i8::leading_zeros(a[12usize]) as i8,
+            i8::leading_zeros(a[13usize]) as i8,
+            i8::leading_zeros(a[14usize]) as i8,
+            i8::leading_zeros(a[15usize]) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::trailing_zeros(a[0usize]) as i8,
+            i8::trailing_zeros(a[1usize]) as i8,
+            i8::trailing_zeros(a[2usize]) as i8,
+            i8::trailing_zeros(a[3usize]) as i8,
+            i8::trailing_zeros(a[4usize]) as i8,
+            i8::trailing_zeros(a[5usize]) as i8,
+            i8::trailing_zeros(a[6usize]) as i8,
+            i8::trailing_zeros(a[7usize]) as i8,
+            i8::trailing_zeros(a[8usize]) as i8,
+            i8::trailing_zeros(a[9usize]) as i8,
+            i8::trailing_zeros(a[10usize]) as i8,
+            i8::trailing_zeros(a[11usize]) as i8,
+            i8::trailing_zeros(a[12usize]) as i8,
+            i8::trailing_zeros(a[13usize]) as i8,
+            i8::trailing_zeros(a[14usize]) as i8,
+            i8::trailing_zeros(a[15usize]) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
         let mut result = [0; 32usize];
         result[0..16usize].copy_from_slice(&a.val.0);
@@ -1485,6 +1788,47 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self> {
+        [
+            a[0usize] as i16,
+            a[1usize] as i16,
+            a[2usize] as i16,
+            a[3usize] as i16,
+            a[4usize] as i16,
+            a[5usize] as i16,
+            a[6usize] as i16,
+            a[7usize] as i16,
+            a[8usize] as i16,
+            a[9usize] as i16,
+            a[10usize] as i16,
+            a[11usize] as i16,
+            a[12usize] as i16,
+            a[13usize] as i16,
+            a[14usize] as i16,
+            a[15usize] as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x16(self, a: i8x16<Self>) -> i32 {
+        i32::from(a[0usize])
+            + i32::from(a[1usize])
+            + i32::from(a[2usize])
+            + i32::from(a[3usize])
+            + i32::from(a[4usize])
+            + i32::from(a[5usize])
+            + i32::from(a[6usize])
+            + i32::from(a[7usize])
+            + i32::from(a[8usize])
+            + i32::from(a[9usize])
+            + i32::from(a[10usize])
+            + i32::from(a[11usize])
+            + i32::from(a[12usize])
+            + i32::from(a[13usize])
+            + i32::from(a[14usize])
+            + i32::from(a[15usize])
+    }
+    #[inline(always)]
     fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
         a.bitcast()
     }
@@ -1542,6 +1886,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u8x16<const SHIFT: usize>(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -1656,6 +2003,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u8x16<const LANE: usize>(self, a: u8x16<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_u8x16<const LANE: usize>(self, a: u8x16<Self>, value: u8) -> u8x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x16(self, a: u8x16<Self>, index: usize) -> u8 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u8x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u8x16(self, a: u8x16<Self>, indices: u8x16<Self>) -> u8x16<Self> {
         let bytes = self.cvt_to_bytes_u8x16(a);
         let result: u8x16<Self> = [
@@ -1884,88 +2262,174 @@ impl Simd for Fallback {
     #[inline(always)]
     fn shl_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self> {
         [
-            u8::shl(a[0usize], shift),
-            u8::shl(a[1usize], shift),
-            u8::shl(a[2usize], shift),
-            u8::shl(a[3usize], shift),
-            u8::shl(a[4usize], shift),
-            u8::shl(a[5usize], shift),
-            u8::shl(a[6usize], shift),
-            u8::shl(a[7usize], shift),
-            u8::shl(a[8usize], shift),
-            u8::shl(a[9usize], shift),
-            u8::shl(a[10usize], shift),
-            u8::shl(a[11usize], shift),
-            u8::shl(a[12usize], shift),
-            u8::shl(a[13usize], shift),
-            u8::shl(a[14usize], shift),
-            u8::shl(a[15usize], shift),
+            u8::wrapping_shl(a[0usize], shift),
+            u8::wrapping_shl(a[1usize], shift),
+            u8::wrapping_shl(a[2usize], shift),
+            u8::wrapping_shl(a[3usize], shift),
+            u8::wrapping_shl(a[4usize], shift),
+            u8::wrapping_shl(a[5usize], shift),
+            u8::wrapping_shl(a[6usize], shift),
+            u8::wrapping_shl(a[7usize], shift),
+            u8::wrapping_shl(a[8usize], shift),
+            u8::wrapping_shl(a[9usize], shift),
+            u8::wrapping_shl(a[10usize], shift),
+            u8::wrapping_shl(a[11usize], shift),
+            u8::wrapping_shl(a[12usize], shift),
+            u8::wrapping_shl(a[13usize], shift),
+            u8::wrapping_shl(a[14usize], shift),
+            u8::wrapping_shl(a[15usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shlv_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
         [
-            u8::shl(a[0usize], &b[0usize]),
-            u8::shl(a[1usize], &b[1usize]),
-            u8::shl(a[2usize], &b[2usize]),
-            u8::shl(a[3usize], &b[3usize]),
-            u8::shl(a[4usize], &b[4usize]),
-            u8::shl(a[5usize], &b[5usize]),
-            u8::shl(a[6usize], &b[6usize]),
-            u8::shl(a[7usize], &b[7usize]),
-            u8::shl(a[8usize], &b[8usize]),
-            u8::shl(a[9usize], &b[9usize]),
-            u8::shl(a[10usize], &b[10usize]),
-            u8::shl(a[11usize], &b[11usize]),
-            u8::shl(a[12usize], &b[12usize]),
-            u8::shl(a[13usize], &b[13usize]),
-            u8::shl(a[14usize], &b[14usize]),
-            u8::shl(a[15usize], &b[15usize]),
+            u8::wrapping_shl(a[0usize], b[0usize] as u32),
+            u8::wrapping_shl(a[1usize], b[1usize] as u32),
+            u8::wrapping_shl(a[2usize], b[2usize] as u32),
+            u8::wrapping_shl(a[3usize], b[3usize] as u32),
+            u8::wrapping_shl(a[4usize], b[4usize] as u32),
+            u8::wrapping_shl(a[5usize], b[5usize] as u32),
+            u8::wrapping_shl(a[6usize], b[6usize] as u32),
+            u8::wrapping_shl(a[7usize], b[7usize] as u32),
+            u8::wrapping_shl(a[8usize], b[8usize] as u32),
+            u8::wrapping_shl(a[9usize], b[9usize] as u32),
+            u8::wrapping_shl(a[10usize], b[10usize] as u32),
+            u8::wrapping_shl(a[11usize], b[11usize] as u32),
+            u8::wrapping_shl(a[12usize], b[12usize] as u32),
+            u8::wrapping_shl(a[13usize], b[13usize] as u32),
+            u8::wrapping_shl(a[14usize], b[14usize] as u32),
+            u8::wrapping_shl(a[15usize], b[15usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self> {
         [
-            u8::shr(a[0usize], shift),
-            u8::shr(a[1usize], shift),
-            u8::shr(a[2usize], shift),
-            u8::shr(a[3usize], shift),
-            u8::shr(a[4usize], shift),
-            u8::shr(a[5usize], shift),
-            u8::shr(a[6usize], shift),
-            u8::shr(a[7usize], shift),
-            u8::shr(a[8usize], shift),
-            u8::shr(a[9usize], shift),
-            u8::shr(a[10usize], shift),
-            u8::shr(a[11usize], shift),
-            u8::shr(a[12usize], shift),
-            u8::shr(a[13usize], shift),
-            u8::shr(a[14usize], shift),
-            u8::shr(a[15usize], shift),
+            u8::wrapping_shr(a[0usize], shift),
+            u8::wrapping_shr(a[1usize], shift),
+            u8::wrapping_shr(a[2usize], shift),
+            u8::wrapping_shr(a[3usize], shift),
+            u8::wrapping_shr(a[4usize], shift),
+            u8::wrapping_shr(a[5usize], shift),
+            u8::wrapping_shr(a[6usize], shift),
+            u8::wrapping_shr(a[7usize], shift),
+            u8::wrapping_shr(a[8usize], shift),
+            u8::wrapping_shr(a[9usize], shift),
+            u8::wrapping_shr(a[10usize], shift),
+            u8::wrapping_shr(a[11usize], shift),
+            u8::wrapping_shr(a[12usize], shift),
+            u8::wrapping_shr(a[13usize], shift),
+            u8::wrapping_shr(a[14usize], shift),
+            u8::wrapping_shr(a[15usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shrv_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
         [
-            u8::shr(a[0usize], &b[0usize]),
-            u8::shr(a[1usize], &b[1usize]),
-            u8::shr(a[2usize], &b[2usize]),
-            u8::shr(a[3usize], &b[3usize]),
-            u8::shr(a[4usize], &b[4usize]),
-            u8::shr(a[5usize], &b[5usize]),
-            u8::shr(a[6usize], &b[6usize]),
-            u8::shr(a[7usize], &b[7usize]),
-            u8::shr(a[8usize], &b[8usize]),
-            u8::shr(a[9usize], &b[9usize]),
-            u8::shr(a[10usize], &b[10usize]),
-            u8::shr(a[11usize], &b[11usize]),
-            u8::shr(a[12usize], &b[12usize]),
-            u8::shr(a[13usize], &b[13usize]),
-            u8::shr(a[14usize], &b[14usize]),
-            u8::shr(a[15usize], &b[15usize]),
+            u8::wrapping_shr(a[0usize], b[0usize] as u32),
+            u8::wrapping_shr(a[1usize], b[1usize] as u32),
+            u8::wrapping_shr(a[2usize], b[2usize] as u32),
+            u8::wrapping_shr(a[3usize], b[3usize] as u32),
+            u8::wrapping_shr(a[4usize], b[4usize] as u32),
+            u8::wrapping_shr(a[5usize], b[5usize] as u32),
+            u8::wrapping_shr(a[6usize], b[6usize] as u32),
+            u8::wrapping_shr(a[7usize], b[7usize] as u32),
+            u8::wrapping_shr(a[8usize], b[8usize] as u32),
+            u8::wrapping_shr(a[9usize], b[9usize] as u32),
+            u8::wrapping_shr(a[10usize], b[10usize] as u32),
+            u8::wrapping_shr(a[11usize], b[11usize] as u32),
+            u8::wrapping_shr(a[12usize], b[12usize] as u32),
+            u8::wrapping_shr(a[13usize], b[13usize] as u32),
+            u8::wrapping_shr(a[14usize], b[14usize] as u32),
+            u8::wrapping_shr(a[15usize], b[15usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u8x16(self, a: u8x16<Self>, b: i8x16<Self>) -> u8x16<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
+            if b[8usize] >= 0 {
+                a[8usize].wrapping_shl(b[8usize] as u32)
+            } else {
+                a[8usize].wrapping_shr((b[8usize] as u32).wrapping_neg())
+            },
+            if b[9usize] >= 0 {
+                a[9usize].wrapping_shl(b[9usize] as u32)
+            } else {
+                a[9usize].wrapping_shr((b[9usize] as u32).wrapping_neg())
+            },
+            if b[10usize] >= 0 {
+                a[10usize].wrapping_shl(b[10usize] as u32)
+            } else {
+                a[10usize].wrapping_shr((b[10usize] as u32).wrapping_neg())
+            },
+            if b[11usize] >= 0 {
+                a[11usize].wrapping_shl(b[11usize] as u32)
+            } else {
+                a[11usize].wrapping_shr((b[11usize] as u32).wrapping_neg())
+            },
+            if b[12usize] >= 0 {
+                a[12usize].wrapping_shl(b[12usize] as u32)
+            } else {
+                a[12usize].wrapping_shr((b[12usize] as u32).wrapping_neg())
+            },
+            if b[13usize] >= 0 {
+                a[13usize].wrapping_shl(b[13usize] as u32)
+            } else {
+                a[13usize].wrapping_shr((b[13usize] as u32).wrapping_neg())
+            },
+            if b[14usize] >= 0 {
+                a[14usize].wrapping_shl(b[14usize] as u32)
+            } else {
+                a[14usize].wrapping_shr((b[14usize] as u32).wrapping_neg())
+            },
+            if b[15usize] >= 0 {
+                a[15usize].wrapping_shl(b[15usize] as u32)
+            } else {
+                a[15usize].wrapping_shr((b[15usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -2209,6 +2673,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_u8x16(
+        self,
+        a: mask8x16<Self>,
+        b: u8x16<Self>,
+        c: u8x16<Self>,
+    ) -> u8x16<Self> {
+        self.select_u8x16(a, b, c)
+    }
+    #[inline(always)]
     fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
         [
             u8::min(a[0usize], b[0usize]),
@@ -2253,6 +2726,116 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::saturating_add(a[0usize], b[0usize]),
+            u8::saturating_add(a[1usize], b[1usize]),
+            u8::saturating_add(a[2usize], b[2usize]),
+            u8::saturating_add(a[3usize], b[3usize]),
+            u8::saturating_add(a[4usize], b[4usize]),
+            u8::saturating_add(a[5usize], b[5usize]),
+            u8::saturating_add(a[6usize], b[6usize]),
+            u8::saturating_add(a[7usize], b[7usize]),
+            u8::saturating_add(a[8usize], b[8usize]),
+            u8::saturating_add(a[9usize], b[9usize]),
+            u8::saturating_add(a[10usize], b[10usize]),
+            u8::saturating_add(a[11usize], b[11usize]),
+            u8::saturating_add(a[12usize], b[12usize]),
+            u8::saturating_add(a[13usize], b[13usize]),
+            u8::saturating_add(a[14usize], b[14usize]),
+            u8::saturating_add(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::saturating_sub(a[0usize], b[0usize]),
+            u8::saturating_sub(a[1usize], b[1usize]),
+            u8::saturating_sub(a[2usize], b[2usize]),
+            u8::saturating_sub(a[3usize], b[3usize]),
+            u8::saturating_sub(a[4usize], b[4usize]),
+            u8::saturating_sub(a[5usize], b[5usize]),
+            u8::saturating_sub(a[6usize], b[6usize]),
+            u8::saturating_sub(a[7usize], b[7usize]),
+            u8::saturating_sub(a[8usize], b[8usize]),
+            u8::saturating_sub(a[9usize], b[9usize]),
+            u8::saturating_sub(a[10usize], b[10usize]),
+            u8::saturating_sub(a[11usize], b[11usize]),
+            u8::saturating_sub(a[12usize], b[12usize]),
+            u8::saturating_sub(a[13usize], b[13usize]),
+            u8::saturating_sub(a[14usize], b[14usize]),
+            u8::saturating_sub(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::count_ones(a[0usize]) as u8,
+            u8::count_ones(a[1usize]) as u8,
+            u8::count_ones(a[2usize]) as u8,
+            u8::count_ones(a[3usize]) as u8,
+            u8::count_ones(a[4usize]) as u8,
+            u8::count_ones(a[5usize]) as u8,
+            u8::count_ones(a[6usize]) as u8,
+            u8::count_ones(a[7usize]) as u8,
+            u8::count_ones(a[8usize]) as u8,
+            u8::count_ones(a[9usize]) as u8,
+            u8::count_ones(a[10usize]) as u8,
+            u8::count_ones(a[11usize]) as u8,
+            u8::count_ones(a[12usize]) as u8,
+            u8::count_ones(a[13usize]) as u8,
+            u8::count_ones(a[14usize]) as u8,
+            u8::count_ones(a[15usize]) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::leading_zeros(a[0usize]) as u8,
+            u8::leading_zeros(a[1usize]) as u8,
+            u8::leading_zeros(a[2usize]) as u8,
+            u8::leading_zeros(a[3usize]) as u8,
+            u8::leading_zeros(a[4usize]) as u8,
+            u8::leading_zeros(a[5usize]) as u8,
+            u8::leading_zeros(a[6usize]) as u8,
+            u8::leading_zeros(a[7usize]) as u8,
+            u8::leading_zeros(a[8usize]) as u8,
+            u8::leading_zeros(a[9usize]) as u8,
+            u8::leading_zeros(a[10usize]) as u8,
+            u8::leading_zeros(a[11usize]) as u8,
+            u8::leading_zeros(a[12usize]) as u8,
+            u8::leading_zeros(a[13usize]) as u8,
+            u8::leading_zeros(a[14usize]) as u8,
+            u8::leading_zeros(a[15usize]) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::trailing_zeros(a[0usize]) as u8,
+            u8::trailing_zeros(a[1usize]) as u8,
+            u8::trailing_zeros(a[2usize]) as u8,
+            u8::trailing_zeros(a[3usize]) as u8,
+            u8::trailing_zeros(a[4usize]) as u8,
+            u8::trailing_zeros(a[5usize]) as u8,
+            u8::trailing_zeros(a[6usize]) as u8,
+            u8::trailing_zeros(a[7usize]) as u8,
+            u8::trailing_zeros(a[8usize]) as u8,
+            u8::trailing_zeros(a[9usize]) as u8,
+            u8::trailing_zeros(a[10usize]) as u8,
+            u8::trailing_zeros(a[11usize]) as u8,
+            u8::trailing_zeros(a[12usize]) as u8,
+            u8::trailing_zeros(a[13usize]) as u8,
+            u8::trailing_zeros(a[14usize]) as u8,
+            u8::trailing_zeros(a[15usize]) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
         let mut result = [0; 32usize];
         result[0..16usize].copy_from_slice(&a.val.0);
@@ -2282,13 +2865,188 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
-    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
-        a.bitcast()
-    }
-    #[inline(always)]
-    fn splat_mask8x16(self, val: bool) -> mask8x16<Self> {
-        let val: i8 = if val { !0 } else { 0 };
-        [val; 16usize].simd_into(self)
+    fn abd_accumulate_u8x16(
+        self,
+        acc: u16x16<Self>,
+        a: u8x16<Self>,
+        b: u8x16<Self>,
+    ) -> u16x16<Self> {
+        [
+            acc[0usize].wrapping_add(a[0usize].abs_diff(b[0usize]) as u16),
+            acc[1usize].wrapping_add(a[1usize].abs_diff(b[1usize]) as u16),
+            acc[2usize].wrapping_add(a[2usize].abs_diff(b[2usize]) as u16),
+            acc[3usize].wrapping_add(a[3usize].abs_diff(b[3usize]) as u16),
+            acc[4usize].wrapping_add(a[4usize].abs_diff(b[4usize]) as u16),
+            acc[5usize].wrapping_add(a[5usize].abs_diff(b[5usize]) as u16),
+            acc[6usize].wrapping_add(a[6usize].abs_diff(b[6usize]) as u16),
+            acc[7usize].wrapping_add(a[7usize].abs_diff(b[7usize]) as u16),
+            acc[8usize].wrapping_add(a[8usize].abs_diff(b[8usize]) as u16),
+            acc[9usize].wrapping_add(a[9usize].abs_diff(b[9usize]) as u16),
+            acc[10usize].wrapping_add(a[10usize].abs_diff(b[10usize]) as u16),
+            acc[11usize].wrapping_add(a[11usize].abs_diff(b[11usize]) as u16),
+            acc[12usize].wrapping_add(a[12usize].abs_diff(b[12usize]) as u16),
+            acc[13usize].wrapping_add(a[13usize].abs_diff(b[13usize]) as u16),
+            acc[14usize].wrapping_add(a[14usize].abs_diff(b[14usize]) as u16),
+            acc[15usize].wrapping_add(a[15usize].abs_diff(b[15usize]) as u16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            (a[0usize] | b[0usize]) - ((a[0usize] ^ b[0usize]) >> 1),
+            (a[1usize] | b[1usize]) - ((a[1usize] ^ b[1usize]) >> 1),
+            (a[2usize] | b[2usize]) - ((a[2usize] ^ b[2usize]) >> 1),
+            (a[3usize] | b[3usize]) - ((a[3usize] ^ b[3usize]) >> 1),
+            (a[4usize] | b[4usize]) - ((a[4usize] ^ b[4usize]) >> 1),
+            (a[5usize] | b[5usize]) - ((a[5usize] ^ b[5usize]) >> 1),
+            (a[6usize] | b[6usize]) - ((a[6usize] ^ b[6usize]) >> 1),
+            (a[7usize] | b[7usize]) - ((a[7usize] ^ b[7usize]) >> 1),
+            (a[8usize] | b[8usize]) - ((a[8usize] ^ b[8usize]) >> 1),
+            (a[9usize] | b[9usize]) - ((a[9usize] ^ b[9usize]) >> 1),
+            (a[10usize] | b[10usize]) - ((a[10usize] ^ b[10usize]) >> 1),
+            (a[11usize] | b[11usize]) - ((a[11usize] ^ b[11usize]) >> 1),
+            (a[12usize] | b[12usize]) - ((a[12usize] ^ b[12usize]) >> 1),
+            (a[13usize] | b[13usize]) - ((a[13usize] ^ b[13usize]) >> 1),
+            (a[14usize] | b[14usize]) - ((a[14usize] ^ b[14usize]) >> 1),
+            (a[15usize] | b[15usize]) - ((a[15usize] ^ b[15usize]) >> 1),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x16(self, a: u8x16<Self>) -> u32 {
+        u32::from(a[0usize])
+            + u32::from(a[1usize])
+            + u32::from(a[2usize])
+            + u32::from(a[3usize])
+            + u32::from(a[4usize])
+            + u32::from(a[5usize])
+            + u32::from(a[6usize])
+            + u32::from(a[7usize])
+            + u32::from(a[8usize])
+            + u32::from(a[9usize])
+            + u32::from(a[10usize])
+            + u32::from(a[11usize])
+            + u32::from(a[12usize])
+            + u32::from(a[13usize])
+            + u32::from(a[14usize])
+            + u32::from(a[15usize])
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
+        a.bitcast()
+    }
+    #[inline(always)]
+    fn compress_bytes_u8x16(self, a: u8x16<Self>, mask: mask8x16<Self>) -> u8x16<Self> {
+        let mask = self.as_array_mask8x16(mask);
+        let mut result = [0; 16usize];
+        let mut count = 0;
+        for i in 0..16usize {
+            if mask[i] != 0 {
+                result[count] = a[i];
+                count += 1;
+            }
+        }
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x16(self, a: u8x16<Self>, mask: mask8x16<Self>) -> u8x16<Self> {
+        let mask = self.as_array_mask8x16(mask);
+        let mut result = [0; 16usize];
+        let mut count = 0;
+        for i in 0..16usize {
+            if mask[i] != 0 {
+                result[i] = a[count];
+                count += 1;
+            }
+        }
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn table_lookup_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            {
+                let index = b[0usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[1usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[2usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[3usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[4usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[5usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[6usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[7usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[8usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[9usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[10usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[11usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[12usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[13usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[14usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+            {
+                let index = b[15usize] as usize;
+                if index < 16 { a[index] } else { 0 }
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x16<const FACTOR: u8>(self, a: u8x16<Self>) -> u8x16<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<16>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x16(
+            self.load_array_u8x16(low),
+            self.and_u8x16(a, self.splat_u8x16(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x16(self.load_array_u8x16(high), self.shr_u8x16(a, 4));
+        self.xor_u8x16(low, high)
+    }
+    #[inline(always)]
+    fn splat_mask8x16(self, val: bool) -> mask8x16<Self> {
+        let val: i8 = if val { !0 } else { 0 };
+        [val; 16usize].simd_into(self)
     }
     #[inline(always)]
     fn load_array_mask8x16(self, val: [i8; 16usize]) -> mask8x16<Self> {
@@ -2302,6 +3060,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self> {
         let lanes: [i8; 16usize] = [
             if bits & 1 != 0 { !0 } else { 0 },
@@ -2527,6 +3292,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        self.select_mask8x16(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
         [
             -(i8::eq(&a.val.0[0usize], &b.val.0[0usize]) as i8),
@@ -2681,6 +3455,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i16x8<const SHIFT: usize>(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -2763,6 +3540,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i16x8<const LANE: usize>(self, a: i16x8<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_i16x8<const LANE: usize>(self, a: i16x8<Self>, value: i16) -> i16x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x8(self, a: i16x8<Self>, index: usize) -> i16 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i16x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i16x8(self, a: i16x8<Self>, indices: u8x16<Self>) -> i16x8<Self> {
         let bytes = self.cvt_to_bytes_i16x8(a);
         let result: u8x16<Self> = [
@@ -2935,56 +3743,102 @@ impl Simd for Fallback {
     #[inline(always)]
     fn shl_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self> {
         [
-            i16::shl(a[0usize], shift),
-            i16::shl(a[1usize], shift),
-            i16::shl(a[2usize], shift),
-            i16::shl(a[3usize], shift),
-            i16::shl(a[4usize], shift),
-            i16::shl(a[5usize], shift),
-            i16::shl(a[6usize], shift),
-            i16::shl(a[7usize], shift),
+            i16::wrapping_shl(a[0usize], shift),
+            i16::wrapping_shl(a[1usize], shift),
+            i16::wrapping_shl(a[2usize], shift),
+            i16::wrapping_shl(a[3usize], shift),
+            i16::wrapping_shl(a[4usize], shift),
+            i16::wrapping_shl(a[5usize], shift),
+            i16::wrapping_shl(a[6usize], shift),
+            i16::wrapping_shl(a[7usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shlv_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
         [
-            i16::shl(a[0usize], &b[0usize]),
-            i16::shl(a[1usize], &b[1usize]),
-            i16::shl(a[2usize], &b[2usize]),
-            i16::shl(a[3usize], &b[3usize]),
-            i16::shl(a[4usize], &b[4usize]),
-            i16::shl(a[5usize], &b[5usize]),
-            i16::shl(a[6usize], &b[6usize]),
-            i16::shl(a[7usize], &b[7usize]),
+            i16::wrapping_shl(a[0usize], b[0usize] as u32),
+            i16::wrapping_shl(a[1usize], b[1usize] as u32),
+            i16::wrapping_shl(a[2usize], b[2usize] as u32),
+            i16::wrapping_shl(a[3usize], b[3usize] as u32),
+            i16::wrapping_shl(a[4usize], b[4usize] as u32),
+            i16::wrapping_shl(a[5usize], b[5usize] as u32),
+            i16::wrapping_shl(a[6usize], b[6usize] as u32),
+            i16::wrapping_shl(a[7usize], b[7usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self> {
         [
-            i16::shr(a[0usize], shift),
-            i16::shr(a[1usize], shift),
-            i16::shr(a[2usize], shift),
-            i16::shr(a[3usize], shift),
-            i16::shr(a[4usize], shift),
-            i16::shr(a[5usize], shift),
-            i16::shr(a[6usize], shift),
-            i16::shr(a[7usize], shift),
+            i16::wrapping_shr(a[0usize], shift),
+            i16::wrapping_shr(a[1usize], shift),
+            i16::wrapping_shr(a[2usize], shift),
+            i16::wrapping_shr(a[3usize], shift),
+            i16::wrapping_shr(a[4usize], shift),
+            i16::wrapping_shr(a[5usize], shift),
+            i16::wrapping_shr(a[6usize], shift),
+            i16::wrapping_shr(a[7usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shrv_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
         [
-            i16::shr(a[0usize], &b[0usize]),
-            i16::shr(a[1usize], &b[1usize]),
-            i16::shr(a[2usize], &b[2usize]),
-            i16::shr(a[3usize], &b[3usize]),
-            i16::shr(a[4usize], &b[4usize]),
-            i16::shr(a[5usize], &b[5usize]),
-            i16::shr(a[6usize], &b[6usize]),
-            i16::shr(a[7usize], &b[7usize]),
+            i16::wrapping_shr(a[0usize], b[0usize] as u32),
+            i16::wrapping_shr(a[1usize], b[1usize] as u32),
+            i16::wrapping_shr(a[2usize], b[2usize] as u32),
+            i16::wrapping_shr(a[3usize], b[3usize] as u32),
+            i16::wrapping_shr(a[4usize], b[4usize] as u32),
+            i16::wrapping_shr(a[5usize], b[5usize] as u32),
+            i16::wrapping_shr(a[6usize], b[6usize] as u32),
+            i16::wrapping_shr(a[7usize], b[7usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -3141,6 +3995,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_i16x8(
+        self,
+        a: mask16x8<Self>,
+        b: i16x8<Self>,
+        c: i16x8<Self>,
+    ) -> i16x8<Self> {
+        self.select_i16x8(a, b, c)
+    }
+    #[inline(always)]
     fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
         [
             i16::min(a[0usize], b[0usize]),
@@ -3169,6 +4032,76 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::saturating_add(a[0usize], b[0usize]),
+            i16::saturating_add(a[1usize], b[1usize]),
+            i16::saturating_add(a[2usize], b[2usize]),
+            i16::saturating_add(a[3usize], b[3usize]),
+            i16::saturating_add(a[4usize], b[4usize]),
+            i16::saturating_add(a[5usize], b[5usize]),
+            i16::saturating_add(a[6usize], b[6usize]),
+            i16::saturating_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::saturating_sub(a[0usize], b[0usize]),
+            i16::saturating_sub(a[1usize], b[1usize]),
+            i16::saturating_sub(a[2usize], b[2usize]),
+            i16::saturating_sub(a[3usize], b[3usize]),
+            i16::saturating_sub(a[4usize], b[4usize]),
+            i16::saturating_sub(a[5usize], b[5usize]),
+            i16::saturating_sub(a[6usize], b[6usize]),
+            i16::saturating_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::count_ones(a[0usize]) as i16,
+            i16::count_ones(a[1usize]) as i16,
+            i16::count_ones(a[2usize]) as i16,
+            i16::count_ones(a[3usize]) as i16,
+            i16::count_ones(a[4usize]) as i16,
+            i16::count_ones(a[5usize]) as i16,
+            i16::count_ones(a[6usize]) as i16,
+            i16::count_ones(a[7usize]) as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::leading_zeros(a[0usize]) as i16,
+            i16::leading_zeros(a[1usize]) as i16,
+            i16::leading_zeros(a[2usize]) as i16,
+            i16::leading_zeros(a[3usize]) as i16,
+            i16::leading_zeros(a[4usize]) as i16,
+            i16::leading_zeros(a[5usize]) as i16,
+            i16::leading_zeros(a[6usize]) as i16,
+            i16::leading_zeros(a[7usize]) as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::trailing_zeros(a[0usize]) as i16,
+            i16::trailing_zeros(a[1usize]) as i16,
+            i16::trailing_zeros(a[2usize]) as i16,
+            i16::trailing_zeros(a[3usize]) as i16,
+            i16::trailing_zeros(a[4usize]) as i16,
+            i16::trailing_zeros(a[5usize]) as i16,
+            i16::trailing_zeros(a[6usize]) as i16,
+            i16::trailing_zeros(a[7usize]) as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
         let mut result = [0; 16usize];
         result[0..8usize].copy_from_slice(&a.val.0);
@@ -3190,6 +4123,31 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self> {
+        [
+            a[0usize] as i32,
+            a[1usize] as i32,
+            a[2usize] as i32,
+            a[3usize] as i32,
+            a[4usize] as i32,
+            a[5usize] as i32,
+            a[6usize] as i32,
+            a[7usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x8(self, a: i16x8<Self>) -> i32 {
+        i32::from(a[0usize])
+            + i32::from(a[1usize])
+            + i32::from(a[2usize])
+            + i32::from(a[3usize])
+            + i32::from(a[4usize])
+            + i32::from(a[5usize])
+            + i32::from(a[6usize])
+            + i32::from(a[7usize])
+    }
+    #[inline(always)]
     fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
         a.bitcast()
     }
@@ -3247,6 +4205,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u16x8<const SHIFT: usize>(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -3329,6 +4290,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u16x8<const LANE: usize>(self, a: u16x8<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_u16x8<const LANE: usize>(self, a: u16x8<Self>, value: u16) -> u16x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x8(self, a: u16x8<Self>, index: usize) -> u16 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u16x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u16x8(self, a: u16x8<Self>, indices: u8x16<Self>) -> u16x8<Self> {
         let bytes = self.cvt_to_bytes_u16x8(a);
         let result: u8x16<Self> = [
@@ -3501,56 +4493,102 @@ impl Simd for Fallback {
     #[inline(always)]
     fn shl_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self> {
         [
-            u16::shl(a[0usize], shift),
-            u16::shl(a[1usize], shift),
-            u16::shl(a[2usize], shift),
-            u16::shl(a[3usize], shift),
-            u16::shl(a[4usize], shift),
-            u16::shl(a[5usize], shift),
-            u16::shl(a[6usize], shift),
-            u16::shl(a[7usize], shift),
+            u16::wrapping_shl(a[0usize], shift),
+            u16::wrapping_shl(a[1usize], shift),
+            u16::wrapping_shl(a[2usize], shift),
+            u16::wrapping_shl(a[3usize], shift),
+            u16::wrapping_shl(a[4usize], shift),
+            u16::wrapping_shl(a[5usize], shift),
+            u16::wrapping_shl(a[6usize], shift),
+            u16::wrapping_shl(a[7usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shlv_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
         [
-            u16::shl(a[0usize], &b[0usize]),
-            u16::shl(a[1usize], &b[1usize]),
-            u16::shl(a[2usize], &b[2usize]),
-            u16::shl(a[3usize], &b[3usize]),
-            u16::shl(a[4usize], &b[4usize]),
-            u16::shl(a[5usize], &b[5usize]),
-            u16::shl(a[6usize], &b[6usize]),
-            u16::shl(a[7usize], &b[7usize]),
+            u16::wrapping_shl(a[0usize], b[0usize] as u32),
+            u16::wrapping_shl(a[1usize], b[1usize] as u32),
+            u16::wrapping_shl(a[2usize], b[2usize] as u32),
+            u16::wrapping_shl(a[3usize], b[3usize] as u32),
+            u16::wrapping_shl(a[4usize], b[4usize] as u32),
+            u16::wrapping_shl(a[5usize], b[5usize] as u32),
+            u16::wrapping_shl(a[6usize], b[6usize] as u32),
+            u16::wrapping_shl(a[7usize], b[7usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self> {
         [
-            u16::shr(a[0usize], shift),
-            u16::shr(a[1usize], shift),
-            u16::shr(a[2usize], shift),
-            u16::shr(a[3usize], shift),
-            u16::shr(a[4usize], shift),
-            u16::shr(a[5usize], shift),
-            u16::shr(a[6usize], shift),
-            u16::shr(a[7usize], shift),
+            u16::wrapping_shr(a[0usize], shift),
+            u16::wrapping_shr(a[1usize], shift),
+            u16::wrapping_shr(a[2usize], shift),
+            u16::wrapping_shr(a[3usize], shift),
+            u16::wrapping_shr(a[4usize], shift),
+            u16::wrapping_shr(a[5usize], shift),
+            u16::wrapping_shr(a[6usize], shift),
+            u16::wrapping_shr(a[7usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shrv_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
         [
-            u16::shr(a[0usize], &b[0usize]),
-            u16::shr(a[1usize], &b[1usize]),
-            u16::shr(a[2usize], &b[2usize]),
-            u16::shr(a[3usize], &b[3usize]),
-            u16::shr(a[4usize], &b[4usize]),
-            u16::shr(a[5usize], &b[5usize]),
-            u16::shr(a[6usize], &b[6usize]),
-            u16::shr(a[7usize], &b[7usize]),
+            u16::wrapping_shr(a[0usize], b[0usize] as u32),
+            u16::wrapping_shr(a[1usize], b[1usize] as u32),
+            u16::wrapping_shr(a[2usize], b[2usize] as u32),
+            u16::wrapping_shr(a[3usize], b[3usize] as u32),
+            u16::wrapping_shr(a[4usize], b[4usize] as u32),
+            u16::wrapping_shr(a[5usize], b[5usize] as u32),
+            u16::wrapping_shr(a[6usize], b[6usize] as u32),
+            u16::wrapping_shr(a[7usize], b[7usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u16x8(self, a: u16x8<Self>, b: i16x8<Self>) -> u16x8<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
+            if b[4usize] >= 0 {
+                a[4usize].wrapping_shl(b[4usize] as u32)
+            } else {
+                a[4usize].wrapping_shr((b[4usize] as u32).wrapping_neg())
+            },
+            if b[5usize] >= 0 {
+                a[5usize].wrapping_shl(b[5usize] as u32)
+            } else {
+                a[5usize].wrapping_shr((b[5usize] as u32).wrapping_neg())
+            },
+            if b[6usize] >= 0 {
+                a[6usize].wrapping_shl(b[6usize] as u32)
+            } else {
+                a[6usize].wrapping_shr((b[6usize] as u32).wrapping_neg())
+            },
+            if b[7usize] >= 0 {
+                a[7usize].wrapping_shl(b[7usize] as u32)
+            } else {
+                a[7usize].wrapping_shr((b[7usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -3707,6 +4745,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_u16x8(
+        self,
+        a: mask16x8<Self>,
+        b: u16x8<Self>,
+        c: u16x8<Self>,
+    ) -> u16x8<Self> {
+        self.select_u16x8(a, b, c)
+    }
+    #[inline(always)]
     fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
         [
             u16::min(a[0usize], b[0usize]),
@@ -3735,6 +4782,76 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::saturating_add(a[0usize], b[0usize]),
+            u16::saturating_add(a[1usize], b[1usize]),
+            u16::saturating_add(a[2usize], b[2usize]),
+            u16::saturating_add(a[3usize], b[3usize]),
+            u16::saturating_add(a[4usize], b[4usize]),
+            u16::saturating_add(a[5usize], b[5usize]),
+            u16::saturating_add(a[6usize], b[6usize]),
+            u16::saturating_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::saturating_sub(a[0usize], b[0usize]),
+            u16::saturating_sub(a[1usize], b[1usize]),
+            u16::saturating_sub(a[2usize], b[2usize]),
+            u16::saturating_sub(a[3usize], b[3usize]),
+            u16::saturating_sub(a[4usize], b[4usize]),
+            u16::saturating_sub(a[5usize], b[5usize]),
+            u16::saturating_sub(a[6usize], b[6usize]),
+            u16::saturating_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::count_ones(a[0usize]) as u16,
+            u16::count_ones(a[1usize]) as u16,
+            u16::count_ones(a[2usize]) as u16,
+            u16::count_ones(a[3usize]) as u16,
+            u16::count_ones(a[4usize]) as u16,
+            u16::count_ones(a[5usize]) as u16,
+            u16::count_ones(a[6usize]) as u16,
+            u16::count_ones(a[7usize]) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::leading_zeros(a[0usize]) as u16,
+            u16::leading_zeros(a[1usize]) as u16,
+            u16::leading_zeros(a[2usize]) as u16,
+            u16::leading_zeros(a[3usize]) as u16,
+            u16::leading_zeros(a[4usize]) as u16,
+            u16::leading_zeros(a[5usize]) as u16,
+            u16::leading_zeros(a[6usize]) as u16,
+            u16::leading_zeros(a[7usize]) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::trailing_zeros(a[0usize]) as u16,
+            u16::trailing_zeros(a[1usize]) as u16,
+            u16::trailing_zeros(a[2usize]) as u16,
+            u16::trailing_zeros(a[3usize]) as u16,
+            u16::trailing_zeros(a[4usize]) as u16,
+            u16::trailing_zeros(a[5usize]) as u16,
+            u16::trailing_zeros(a[6usize]) as u16,
+            u16::trailing_zeros(a[7usize]) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
         let mut result = [0; 16usize];
         result[0..8usize].copy_from_slice(&a.val.0);
@@ -3742,6 +4859,59 @@ impl Simd for Fallback {
         result.simd_into(self)
     }
     #[inline(always)]
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self> {
+        [
+            a[0usize] as u32,
+            a[1usize] as u32,
+            a[2usize] as u32,
+            a[3usize] as u32,
+            a[4usize] as u32,
+            a[5usize] as u32,
+            a[6usize] as u32,
+            a[7usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u16x8(self, acc: u32x8<Self>, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self> {
+        [
+            acc[0usize].wrapping_add(a[0usize].abs_diff(b[0usize]) as u32),
+            acc[1usize].wrapping_add(a[1usize].abs_diff(b[1usize]) as u32),
+            acc[2usize].wrapping_add(a[2usize].abs_diff(b[2usize]) as u32),
+            acc[3usize].wrapping_add(a[3usize].abs_diff(b[3usize]) as u32),
+            acc[4usize].wrapping_add(a[4usize].abs_diff(b[4usize]) as u32),
+            acc[5usize].wrapping_add(a[5usize].abs_diff(b[5usize]) as u32),
+            acc[6usize].wrapping_add(a[6usize].abs_diff(b[6usize]) as u32),
+            acc[7usize].wrapping_add(a[7usize].abs_diff(b[7usize]) as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            (a[0usize] | b[0usize]) - ((a[0usize] ^ b[0usize]) >> 1),
+            (a[1usize] | b[1usize]) - ((a[1usize] ^ b[1usize]) >> 1),
+            (a[2usize] | b[2usize]) - ((a[2usize] ^ b[2usize]) >> 1),
+            (a[3usize] | b[3usize]) - ((a[3usize] ^ b[3usize]) >> 1),
+            (a[4usize] | b[4usize]) - ((a[4usize] ^ b[4usize]) >> 1),
+            (a[5usize] | b[5usize]) - ((a[5usize] ^ b[5usize]) >> 1),
+            (a[6usize] | b[6usize]) - ((a[6usize] ^ b[6usize]) >> 1),
+            (a[7usize] | b[7usize]) - ((a[7usize] ^ b[7usize]) >> 1),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x8(self, a: u16x8<Self>) -> u32 {
+        u32::from(a[0usize])
+            + u32::from(a[1usize])
+            + u32::from(a[2usize])
+            + u32::from(a[3usize])
+            + u32::from(a[4usize])
+            + u32::from(a[5usize])
+            + u32::from(a[6usize])
+            + u32::from(a[7usize])
+    }
+    #[inline(always)]
     fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
         a.bitcast()
     }
@@ -3766,6 +4936,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self> {
         let lanes: [i16; 8usize] = [
             if bits & 1 != 0 { !0 } else { 0 },
@@ -3911,6 +5088,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        self.select_mask16x8(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
         [
             -(i16::eq(&a.val.0[0usize], &b.val.0[0usize]) as i16),
@@ -4025,6 +5211,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i32x4<const SHIFT: usize>(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
         let mut dest = [Default::default(); 4usize];
         dest[..4usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[4usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -4091,6 +5280,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i32x4<const LANE: usize>(self, a: i32x4<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_i32x4<const LANE: usize>(self, a: i32x4<Self>, value: i32) -> i32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x4(self, a: i32x4<Self>, index: usize) -> i32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_i32x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i32x4(self, a: i32x4<Self>, indices: u8x16<Self>) -> i32x4<Self> {
         let bytes = self.cvt_to_bytes_i32x4(a);
         let result: u8x16<Self> = [
@@ -4235,40 +5455,66 @@ impl Simd for Fallback {
     #[inline(always)]
     fn shl_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self> {
         [
-            i32::shl(a[0usize], shift),
-            i32::shl(a[1usize], shift),
-            i32::shl(a[2usize], shift),
-            i32::shl(a[3usize], shift),
+            i32::wrapping_shl(a[0usize], shift),
+            i32::wrapping_shl(a[1usize], shift),
+            i32::wrapping_shl(a[2usize], shift),
+            i32::wrapping_shl(a[3usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shlv_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
         [
-            i32::shl(a[0usize], &b[0usize]),
-            i32::shl(a[1usize], &b[1usize]),
-            i32::shl(a[2usize], &b[2usize]),
-            i32::shl(a[3usize], &b[3usize]),
+            i32::wrapping_shl(a[0usize], b[0usize] as u32),
+            i32::wrapping_shl(a[1usize], b[1usize] as u32),
+            i32::wrapping_shl(a[2usize], b[2usize] as u32),
+            i32::wrapping_shl(a[3usize], b[3usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self> {
         [
-            i32::shr(a[0usize], shift),
-            i32::shr(a[1usize], shift),
-            i32::shr(a[2usize], shift),
-            i32::shr(a[3usize], shift),
+            i32::wrapping_shr(a[0usize], shift),
+            i32::wrapping_shr(a[1usize], shift),
+            i32::wrapping_shr(a[2usize], shift),
+            i32::wrapping_shr(a[3usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shrv_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
         [
-            i32::shr(a[0usize], &b[0usize]),
-            i32::shr(a[1usize], &b[1usize]),
-            i32::shr(a[2usize], &b[2usize]),
-            i32::shr(a[3usize], &b[3usize]),
+            i32::wrapping_shr(a[0usize], b[0usize] as u32),
+            i32::wrapping_shr(a[1usize], b[1usize] as u32),
+            i32::wrapping_shr(a[2usize], b[2usize] as u32),
+            i32::wrapping_shr(a[3usize], b[3usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -4373,6 +5619,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_i32x4(
+        self,
+        a: mask32x4<Self>,
+        b: i32x4<Self>,
+        c: i32x4<Self>,
+    ) -> i32x4<Self> {
+        self.select_i32x4(a, b, c)
+    }
+    #[inline(always)]
     fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
         [
             i32::min(a[0usize], b[0usize]),
@@ -4393,6 +5648,56 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::saturating_add(a[0usize], b[0usize]),
+            i32::saturating_add(a[1usize], b[1usize]),
+            i32::saturating_add(a[2usize], b[2usize]),
+            i32::saturating_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::saturating_sub(a[0usize], b[0usize]),
+            i32::saturating_sub(a[1usize], b[1usize]),
+            i32::saturating_sub(a[2usize], b[2usize]),
+            i32::saturating_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::count_ones(a[0usize]).cast_signed(),
+            i32::count_ones(a[1usize]).cast_signed(),
+            i32::count_ones(a[2usize]).cast_signed(),
+            i32::count_ones(a[3usize]).cast_signed(),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::leading_zeros(a[0usize]).cast_signed(),
+            i32::leading_zeros(a[1usize]).cast_signed(),
+            i32::leading_zeros(a[2usize]).cast_signed(),
+            i32::leading_zeros(a[3usize]).cast_signed(),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::trailing_zeros(a[0usize]).cast_signed(),
+            i32::trailing_zeros(a[1usize]).cast_signed(),
+            i32::trailing_zeros(a[2usize]).cast_signed(),
+            i32::trailing_zeros(a[3usize]).cast_signed(),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
         let mut result = [0; 8usize];
         result[0..4usize].copy_from_slice(&a.val.0);
@@ -4410,6 +5715,16 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self> {
+        [
+            a[0usize] as i64,
+            a[1usize] as i64,
+            a[2usize] as i64,
+            a[3usize] as i64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
         a.bitcast()
     }
@@ -4477,6 +5792,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u32x4<const SHIFT: usize>(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
         let mut dest = [Default::default(); 4usize];
         dest[..4usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[4usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -4543,6 +5861,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u32x4<const LANE: usize>(self, a: u32x4<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_u32x4<const LANE: usize>(self, a: u32x4<Self>, value: u32) -> u32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x4(self, a: u32x4<Self>, index: usize) -> u32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_u32x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u32x4(self, a: u32x4<Self>, indices: u8x16<Self>) -> u32x4<Self> {
         let bytes = self.cvt_to_bytes_u32x4(a);
         let result: u8x16<Self> = [
@@ -4687,40 +6036,66 @@ impl Simd for Fallback {
     #[inline(always)]
     fn shl_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self> {
         [
-            u32::shl(a[0usize], shift),
-            u32::shl(a[1usize], shift),
-            u32::shl(a[2usize], shift),
-            u32::shl(a[3usize], shift),
+            u32::wrapping_shl(a[0usize], shift),
+            u32::wrapping_shl(a[1usize], shift),
+            u32::wrapping_shl(a[2usize], shift),
+            u32::wrapping_shl(a[3usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shlv_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
         [
-            u32::shl(a[0usize], &b[0usize]),
-            u32::shl(a[1usize], &b[1usize]),
-            u32::shl(a[2usize], &b[2usize]),
-            u32::shl(a[3usize], &b[3usize]),
+            u32::wrapping_shl(a[0usize], b[0usize]),
+            u32::wrapping_shl(a[1usize], b[1usize]),
+            u32::wrapping_shl(a[2usize], b[2usize]),
+            u32::wrapping_shl(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self> {
         [
-            u32::shr(a[0usize], shift),
-            u32::shr(a[1usize], shift),
-            u32::shr(a[2usize], shift),
-            u32::shr(a[3usize], shift),
+            u32::wrapping_shr(a[0usize], shift),
+            u32::wrapping_shr(a[1usize], shift),
+            u32::wrapping_shr(a[2usize], shift),
+            u32::wrapping_shr(a[3usize], shift),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shrv_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
         [
-            u32::shr(a[0usize], &b[0usize]),
-            u32::shr(a[1usize], &b[1usize]),
-            u32::shr(a[2usize], &b[2usize]),
-            u32::shr(a[3usize], &b[3usize]),
+            u32::wrapping_shr(a[0usize], b[0usize]),
+            u32::wrapping_shr(a[1usize], b[1usize]),
+            u32::wrapping_shr(a[2usize], b[2usize]),
+            u32::wrapping_shr(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u32x4(self, a: u32x4<Self>, b: i32x4<Self>) -> u32x4<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
+            if b[2usize] >= 0 {
+                a[2usize].wrapping_shl(b[2usize] as u32)
+            } else {
+                a[2usize].wrapping_shr((b[2usize] as u32).wrapping_neg())
+            },
+            if b[3usize] >= 0 {
+                a[3usize].wrapping_shl(b[3usize] as u32)
+            } else {
+                a[3usize].wrapping_shr((b[3usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -4825,6 +6200,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_u32x4(
+        self,
+        a: mask32x4<Self>,
+        b: u32x4<Self>,
+        c: u32x4<Self>,
+    ) -> u32x4<Self> {
+        self.select_u32x4(a, b, c)
+    }
+    #[inline(always)]
     fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
         [
             u32::min(a[0usize], b[0usize]),
@@ -4845,6 +6229,56 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::saturating_add(a[0usize], b[0usize]),
+            u32::saturating_add(a[1usize], b[1usize]),
+            u32::saturating_add(a[2usize], b[2usize]),
+            u32::saturating_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::saturating_sub(a[0usize], b[0usize]),
+            u32::saturating_sub(a[1usize], b[1usize]),
+            u32::saturating_sub(a[2usize], b[2usize]),
+            u32::saturating_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::count_ones(a[0usize]),
+            u32::count_ones(a[1usize]),
+            u32::count_ones(a[2usize]),
+            u32::count_ones(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::leading_zeros(a[0usize]),
+            u32::leading_zeros(a[1usize]),
+            u32::leading_zeros(a[2usize]),
+            u32::leading_zeros(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::trailing_zeros(a[0usize]),
+            u32::trailing_zeros(a[1usize]),
+            u32::trailing_zeros(a[2usize]),
+            u32::trailing_zeros(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
         let mut result = [0; 8usize];
         result[0..4usize].copy_from_slice(&a.val.0);
@@ -4852,6 +6286,16 @@ impl Simd for Fallback {
         result.simd_into(self)
     }
     #[inline(always)]
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self> {
+        [
+            a[0usize] as u64,
+            a[1usize] as u64,
+            a[2usize] as u64,
+            a[3usize] as u64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
         a.bitcast()
     }
@@ -4882,6 +6326,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self> {
         let lanes: [i32; 4usize] = [
             if bits & 1 != 0 { !0 } else { 0 },
@@ -4987,6 +6438,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        self.select_mask32x4(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
         [
             -(i32::eq(&a.val.0[0usize], &b.val.0[0usize]) as i32),
@@ -5069,6 +6529,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_f64x2<const SHIFT: usize>(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
         let mut dest = [Default::default(); 2usize];
         dest[..2usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[2usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -5127,6 +6590,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_f64x2<const LANE: usize>(self, a: f64x2<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_f64x2<const LANE: usize>(self, a: f64x2<Self>, value: f64) -> f64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x2(self, a: f64x2<Self>, index: usize) -> f64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_f64x2(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f64x2(self, a: f64x2<Self>, indices: u8x16<Self>) -> f64x2<Self> {
         let bytes = self.cvt_to_bytes_f64x2(a);
         let result: u8x16<Self> = [
@@ -5208,7 +6702,11 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn sqrt_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
-        [f64::sqrt(a[0usize]), f64::sqrt(a[1usize])].simd_into(self)
+        [
+            FallbackFloat::sqrt(a[0usize]),
+            FallbackFloat::sqrt(a[1usize]),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn approximate_recip_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
@@ -5217,32 +6715,32 @@ impl Simd for Fallback {
     #[inline(always)]
     fn add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::add(a[0usize], &b[0usize]),
-            f64::add(a[1usize], &b[1usize]),
+            FallbackFloat::add(a[0usize], b[0usize]),
+            FallbackFloat::add(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::sub(a[0usize], &b[0usize]),
-            f64::sub(a[1usize], &b[1usize]),
+            FallbackFloat::sub(a[0usize], b[0usize]),
+            FallbackFloat::sub(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn mul_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::mul(a[0usize], &b[0usize]),
-            f64::mul(a[1usize], &b[1usize]),
+            FallbackFloat::mul(a[0usize], b[0usize]),
+            FallbackFloat::mul(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn div_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::div(a[0usize], &b[0usize]),
-            f64::div(a[1usize], &b[1usize]),
+            FallbackFloat::div(a[0usize], b[0usize]),
+            FallbackFloat::div(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
@@ -5321,32 +6819,32 @@ impl Simd for Fallback {
     #[inline(always)]
     fn max_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::max(a[0usize], b[0usize]),
-            f64::max(a[1usize], b[1usize]),
+            FallbackFloat::max(a[0usize], b[0usize]),
+            FallbackFloat::max(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn min_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::min(a[0usize], b[0usize]),
-            f64::min(a[1usize], b[1usize]),
+            FallbackFloat::min(a[0usize], b[0usize]),
+            FallbackFloat::min(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn max_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::max(a[0usize], b[0usize]),
-            f64::max(a[1usize], b[1usize]),
+            FallbackFloat::max(a[0usize], b[0usize]),
+            FallbackFloat::max(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn min_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::min(a[0usize], b[0usize]),
-            f64::min(a[1usize], b[1usize]),
+            FallbackFloat::min(a[0usize], b[0usize]),
+            FallbackFloat::min(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
@@ -5359,28 +6857,56 @@ impl Simd for Fallback {
         a.mul(b).sub(c)
     }
     #[inline(always)]
+    fn nmul_add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        c.sub(a.mul(b))
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        a.mul(b).neg().sub(c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.mul_add_f64x2(a, b, c)
+    }
+    #[inline(always)]
     fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
-        [f64::floor(a[0usize]), f64::floor(a[1usize])].simd_into(self)
+        [
+            FallbackFloat::floor(a[0usize]),
+            FallbackFloat::floor(a[1usize]),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn ceil_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
-        [f64::ceil(a[0usize]), f64::ceil(a[1usize])].simd_into(self)
+        [
+            FallbackFloat::ceil(a[0usize]),
+            FallbackFloat::ceil(a[1usize]),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn round_ties_even_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
         [
-            f64::round_ties_even(a[0usize]),
-            f64::round_ties_even(a[1usize]),
+            FallbackFloat::round_ties_even(a[0usize]),
+            FallbackFloat::round_ties_even(a[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn fract_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
-        [f64::fract(a[0usize]), f64::fract(a[1usize])].simd_into(self)
+        [
+            FallbackFloat::fract(a[0usize]),
+            FallbackFloat::fract(a[1usize]),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn trunc_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
-        [f64::trunc(a[0usize]), f64::trunc(a[1usize])].simd_into(self)
+        [
+            FallbackFloat::trunc(a[0usize]),
+            FallbackFloat::trunc(a[1usize]),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn select_f64x2(self, a: mask64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
@@ -5399,6 +6925,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_f64x2(
+        self,
+        a: mask64x2<Self>,
+        b: f64x2<Self>,
+        c: f64x2<Self>,
+    ) -> f64x2<Self> {
+        self.select_f64x2(a, b, c)
+    }
+    #[inline(always)]
     fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self> {
         let mut result = [0.0; 4usize];
         result[0..2usize].copy_from_slice(&a.val.0);
@@ -5459,6 +6994,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i64x2<const SHIFT: usize>(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
         let mut dest = [Default::default(); 2usize];
         dest[..2usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[2usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -5517,6 +7055,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i64x2<const LANE: usize>(self, a: i64x2<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_i64x2<const LANE: usize>(self, a: i64x2<Self>, value: i64) -> i64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x2(self, a: i64x2<Self>, index: usize) -> i64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_i64x2(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i64x2(self, a: i64x2<Self>, indices: u8x16<Self>) -> i64x2<Self> {
         let bytes = self.cvt_to_bytes_i64x2(a);
         let result: u8x16<Self> = [
@@ -5642,25 +7211,49 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn shl_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self> {
-        [i64::shl(a[0usize], shift), i64::shl(a[1usize], shift)].simd_into(self)
+        [
+            i64::wrapping_shl(a[0usize], shift),
+            i64::wrapping_shl(a[1usize], shift),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn shlv_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
         [
-            i64::shl(a[0usize], &b[0usize]),
-            i64::shl(a[1usize], &b[1usize]),
+            i64::wrapping_shl(a[0usize], b[0usize] as u32),
+            i64::wrapping_shl(a[1usize], b[1usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self> {
-        [i64::shr(a[0usize], shift), i64::shr(a[1usize], shift)].simd_into(self)
+        [
+            i64::wrapping_shr(a[0usize], shift),
+            i64::wrapping_shr(a[1usize], shift),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn shrv_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
         [
-            i64::shr(a[0usize], &b[0usize]),
-            i64::shr(a[1usize], &b[1usize]),
+            i64::wrapping_shr(a[0usize], b[0usize] as u32),
+            i64::wrapping_shr(a[1usize], b[1usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -5745,6 +7338,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_i64x2(
+        self,
+        a: mask64x2<Self>,
+        b: i64x2<Self>,
+        c: i64x2<Self>,
+    ) -> i64x2<Self> {
+        self.select_i64x2(a, b, c)
+    }
+    #[inline(always)]
     fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
         [
             i64::min(a[0usize], b[0usize]),
@@ -5761,6 +7363,46 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::saturating_add(a[0usize], b[0usize]),
+            i64::saturating_add(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::saturating_sub(a[0usize], b[0usize]),
+            i64::saturating_sub(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::count_ones(a[0usize]) as i64,
+            i64::count_ones(a[1usize]) as i64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::leading_zeros(a[0usize]) as i64,
+            i64::leading_zeros(a[1usize]) as i64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::trailing_zeros(a[0usize]) as i64,
+            i64::trailing_zeros(a[1usize]) as i64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self> {
         let mut result = [0; 4usize];
         result[0..2usize].copy_from_slice(&a.val.0);
@@ -5829,6 +7471,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u64x2<const SHIFT: usize>(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        if SHIFT >= 2usize {
+            return b;
+        }
         let mut dest = [Default::default(); 2usize];
         dest[..2usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[2usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -5887,6 +7532,37 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u64x2<const LANE: usize>(self, a: u64x2<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        a.val.0[LANE]
+    }
+    #[inline(always)]
+    fn insert_u64x2<const LANE: usize>(self, a: u64x2<Self>, value: u64) -> u64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let mut lanes = a.val.0;
+        lanes[LANE] = value;
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x2(self, a: u64x2<Self>, index: usize) -> u64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_u64x2(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u64x2(self, a: u64x2<Self>, indices: u8x16<Self>) -> u64x2<Self> {
         let bytes = self.cvt_to_bytes_u64x2(a);
         let result: u8x16<Self> = [
@@ -6012,25 +7688,49 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn shl_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self> {
-        [u64::shl(a[0usize], shift), u64::shl(a[1usize], shift)].simd_into(self)
+        [
+            u64::wrapping_shl(a[0usize], shift),
+            u64::wrapping_shl(a[1usize], shift),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn shlv_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
         [
-            u64::shl(a[0usize], &b[0usize]),
-            u64::shl(a[1usize], &b[1usize]),
+            u64::wrapping_shl(a[0usize], b[0usize] as u32),
+            u64::wrapping_shl(a[1usize], b[1usize] as u32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
     fn shr_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self> {
-        [u64::shr(a[0usize], shift), u64::shr(a[1usize], shift)].simd_into(self)
+        [
+            u64::wrapping_shr(a[0usize], shift),
+            u64::wrapping_shr(a[1usize], shift),
+        ]
+        .simd_into(self)
     }
     #[inline(always)]
     fn shrv_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
         [
-            u64::shr(a[0usize], &b[0usize]),
-            u64::shr(a[1usize], &b[1usize]),
+            u64::wrapping_shr(a[0usize], b[0usize] as u32),
+            u64::wrapping_shr(a[1usize], b[1usize] as u32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shift_signed_u64x2(self, a: u64x2<Self>, b: i64x2<Self>) -> u64x2<Self> {
+        [
+            if b[0usize] >= 0 {
+                a[0usize].wrapping_shl(b[0usize] as u32)
+            } else {
+                a[0usize].wrapping_shr((b[0usize] as u32).wrapping_neg())
+            },
+            if b[1usize] >= 0 {
+                a[1usize].wrapping_shl(b[1usize] as u32)
+            } else {
+                a[1usize].wrapping_shr((b[1usize] as u32).wrapping_neg())
+            },
         ]
         .simd_into(self)
     }
@@ -6115,6 +7815,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_u64x2(
+        self,
+        a: mask64x2<Self>,
+        b: u64x2<Self>,
+        c: u64x2<Self>,
+    ) -> u64x2<Self> {
+        self.select_u64x2(a, b, c)
+    }
+    #[inline(always)]
     fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
         [
             u64::min(a[0usize], b[0usize]),
@@ -6131,6 +7840,46 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::saturating_add(a[0usize], b[0usize]),
+            u64::saturating_add(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::saturating_sub(a[0usize], b[0usize]),
+            u64::saturating_sub(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn count_ones_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::count_ones(a[0usize]) as u64,
+            u64::count_ones(a[1usize]) as u64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::leading_zeros(a[0usize]) as u64,
+            u64::leading_zeros(a[1usize]) as u64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::trailing_zeros(a[0usize]) as u64,
+            u64::trailing_zeros(a[1usize]) as u64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
     fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self> {
         let mut result = [0; 4usize];
         result[0..2usize].copy_from_slice(&a.val.0);
@@ -6146,6 +7895,24 @@ impl Simd for Fallback {
         a.bitcast()
     }
     #[inline(always)]
+    fn transpose_bits_8x8_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 7)),
+            self.splat_u64x2(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 7));
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 14)),
+            self.splat_u64x2(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 14));
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 28)),
+            self.splat_u64x2(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 28))
+    }
+    #[inline(always)]
     fn splat_mask64x2(self, val: bool) -> mask64x2<Self> {
         let val: i64 = if val { !0 } else { 0 };
         [val; 2usize].simd_into(self)
@@ -6162,6 +7929,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self> {
         let lanes: [i64; 2usize] = [
             if bits & 1 != 0 { !0 } else { 0 },
@@ -6243,6 +8017,15 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn select_relaxed_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        self.select_mask64x2(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
         [
             -(i64::eq(&a.val.0[0usize], &b.val.0[0usize]) as i64),
@@ -6324,6 +8107,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_f32x8<const SHIFT: usize>(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -6411,6 +8197,57 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_f32x8<const LANE: usize>(self, a: f32x8<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x8(a);
+        match LANE {
+            0 => self.extract_f32x4::<0>(a0),
+            1 => self.extract_f32x4::<1>(a0),
+            2 => self.extract_f32x4::<2>(a0),
+            3 => self.extract_f32x4::<3>(a0),
+            4 => self.extract_f32x4::<0>(a1),
+            5 => self.extract_f32x4::<1>(a1),
+            6 => self.extract_f32x4::<2>(a1),
+            7 => self.extract_f32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f32x8<const LANE: usize>(self, a: f32x8<Self>, value: f32) -> f32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x8(a);
+        match LANE {
+            0 => self.combine_f32x4(self.insert_f32x4::<0>(a0, value), a1),
+            1 => self.combine_f32x4(self.insert_f32x4::<1>(a0, value), a1),
+            2 => self.combine_f32x4(self.insert_f32x4::<2>(a0, value), a1),
+            3 => self.combine_f32x4(self.insert_f32x4::<3>(a0, value), a1),
+            4 => self.combine_f32x4(a0, self.insert_f32x4::<0>(a1, value)),
+            5 => self.combine_f32x4(a0, self.insert_f32x4::<1>(a1, value)),
+            6 => self.combine_f32x4(a0, self.insert_f32x4::<2>(a1, value)),
+            7 => self.combine_f32x4(a0, self.insert_f32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x8(self, a: f32x8<Self>, index: usize) -> f32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_f32x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f32x8(self, a: f32x8<Self>, indices: u8x32<Self>) -> f32x8<Self> {
         let (a0, a1) = self.split_f32x8(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -6603,6 +8440,36 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn nmul_add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.nmul_add_f32x4(a0, b0, c0),
+            self.nmul_add_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.nmul_sub_f32x4(a0, b0, c0),
+            self.nmul_sub_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_relaxed_f32x4(a0, b0, c0),
+            self.mul_add_relaxed_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
         let (a0, a1) = self.split_f32x8(a);
         self.combine_f32x4(self.floor_f32x4(a0), self.floor_f32x4(a1))
@@ -6638,6 +8505,21 @@ impl Simd for Fallback {
         self.combine_f32x4(self.select_f32x4(a0, b0, c0), self.select_f32x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f32x8(
+        self,
+        a: mask32x8<Self>,
+        b: f32x8<Self>,
+        c: f32x8<Self>,
+    ) -> f32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.select_relaxed_f32x4(a0, b0, c0),
+            self.select_relaxed_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
         let mut result = [0.0; 16usize];
         result[0..8usize].copy_from_slice(&a.val.0);
@@ -6708,6 +8590,24 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn dot2_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.dot2_f32x4(a0, b0), self.dot2_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn dot3_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.dot3_f32x4(a0, b0), self.dot3_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn dot4_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.dot4_f32x4(a0, b0), self.dot4_f32x4(a1, b1))
+    }
+    #[inline(always)]
     fn splat_i8x32(self, val: i8) -> i8x32<Self> {
         let half = self.splat_i8x16(val);
         self.combine_i8x16(half, half)
@@ -6758,6 +8658,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i8x32<const SHIFT: usize>(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
         let mut dest = [Default::default(); 32usize];
         dest[..32usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[32usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -6941,6 +8844,105 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i8x32<const LANE: usize>(self, a: i8x32<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x32(a);
+        match LANE {
+            0 => self.extract_i8x16::<0>(a0),
+            1 => self.extract_i8x16::<1>(a0),
+            2 => self.extract_i8x16::<2>(a0),
+            3 => self.extract_i8x16::<3>(a0),
+            4 => self.extract_i8x16::<4>(a0),
+            5 => self.extract_i8x16::<5>(a0),
+            6 => self.extract_i8x16::<6>(a0),
+            7 => self.extract_i8x16::<7>(a0),
+            8 => self.extract_i8x16::<8>(a0),
+            9 => self.extract_i8x16::<9>(a0),
+            10 => self.extract_i8x16::<10>(a0),
+            11 => self.extract_i8x16::<11>(a0),
+            12 => self.extract_i8x16::<12>(a0),
+            13 => self.extract_i8x16::<13>(a0),
+            14 => self.extract_i8x16::<14>(a0),
+            15 => self.extract_i8x16::<15>(a0),
+            16 => self.extract_i8x16::<0>(a1),
+            17 => self.extract_i8x16::<1>(a1),
+            18 => self.extract_i8x16::<2>(a1),
+            19 => self.extract_i8x16::<3>(a1),
+            20 => self.extract_i8x16::<4>(a1),
+            21 => self.extract_i8x16::<5>(a1),
+            22 => self.extract_i8x16::<6>(a1),
+            23 => self.extract_i8x16::<7>(a1),
+            24 => self.extract_i8x16::<8>(a1),
+            25 => self.extract_i8x16::<9>(a1),
+            26 => self.extract_i8x16::<10>(a1),
+            27 => self.extract_i8x16::<11>(a1),
+            28 => self.extract_i8x16::<12>(a1),
+            29 => self.extract_i8x16::<13>(a1),
+            30 => self.extract_i8x16::<14>(a1),
+            31 => self.extract_i8x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i8x32<const LANE: usize>(self, a: i8x32<Self>, value: i8) -> i8x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x32(a);
+        match LANE {
+            0 => self.combine_i8x16(self.insert_i8x16::<0>(a0, value), a1),
+            1 => self.combine_i8x16(self.insert_i8x16::<1>(a0, value), a1),
+            2 => self.combine_i8x16(self.insert_i8x16::<2>(a0, value), a1),
+            3 => self.combine_i8x16(self.insert_i8x16::<3>(a0, value), a1),
+            4 => self.combine_i8x16(self.insert_i8x16::<4>(a0, value), a1),
+            5 => self.combine_i8x16(self.insert_i8x16::<5>(a0, value), a1),
+            6 => self.combine_i8x16(self.insert_i8x16::<6>(a0, value), a1),
+            7 => self.combine_i8x16(self.insert_i8x16::<7>(a0, value), a1),
+            8 => self.combine_i8x16(self.insert_i8x16::<8>(a0, value), a1),
+            9 => self.combine_i8x16(self.insert_i8x16::<9>(a0, value), a1),
+            10 => self.combine_i8x16(self.insert_i8x16::<10>(a0, value), a1),
+            11 => self.combine_i8x16(self.insert_i8x16::<11>(a0, value), a1),
+            12 => self.combine_i8x16(self.insert_i8x16::<12>(a0, value), a1),
+            13 => self.combine_i8x16(self.insert_i8x16::<13>(a0, value), a1),
+            14 => self.combine_i8x16(self.insert_i8x16::<14>(a0, value), a1),
+            15 => self.combine_i8x16(self.insert_i8x16::<15>(a0, value), a1),
+            16 => self.combine_i8x16(a0, self.insert_i8x16::<0>(a1, value)),
+            17 => self.combine_i8x16(a0, self.insert_i8x16::<1>(a1, value)),
+            18 => self.combine_i8x16(a0, self.insert_i8x16::<2>(a1, value)),
+            19 => self.combine_i8x16(a0, self.insert_i8x16::<3>(a1, value)),
+            20 => self.combine_i8x16(a0, self.insert_i8x16::<4>(a1, value)),
+            21 => self.combine_i8x16(a0, self.insert_i8x16::<5>(a1, value)),
+            22 => self.combine_i8x16(a0, self.insert_i8x16::<6>(a1, value)),
+            23 => self.combine_i8x16(a0, self.insert_i8x16::<7>(a1, value)),
+            24 => self.combine_i8x16(a0, self.insert_i8x16::<8>(a1, value)),
+            25 => self.combine_i8x16(a0, self.insert_i8x16::<9>(a1, value)),
+            26 => self.combine_i8x16(a0, self.insert_i8x16::<10>(a1, value)),
+            27 => self.combine_i8x16(a0, self.insert_i8x16::<11>(a1, value)),
+            28 => self.combine_i8x16(a0, self.insert_i8x16::<12>(a1, value)),
+            29 => self.combine_i8x16(a0, self.insert_i8x16::<13>(a1, value)),
+            30 => self.combine_i8x16(a0, self.insert_i8x16::<14>(a1, value)),
+            31 => self.combine_i8x16(a0, self.insert_i8x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x32(self, a: i8x32<Self>, index: usize) -> i8 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_i8x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i8x32(self, a: i8x32<Self>, indices: u8x32<Self>) -> i8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -7013,6 +9015,15 @@ impl Simd for Fallback {
         self.combine_i8x16(self.shrv_i8x16(a0, b0), self.shrv_i8x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.shift_signed_i8x16(a0, b0),
+            self.shift_signed_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         let (b0, b1) = self.split_i8x32(b);
@@ -7100,6 +9111,21 @@ impl Simd for Fallback {
         self.combine_i8x16(self.select_i8x16(a0, b0, c0), self.select_i8x16(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i8x32(
+        self,
+        a: mask8x32<Self>,
+        b: i8x32<Self>,
+        c: i8x32<Self>,
+    ) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        let (c0, c1) = self.split_i8x32(c);
+        self.combine_i8x16(
+            self.select_relaxed_i8x16(a0, b0, c0),
+            self.select_relaxed_i8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         let (b0, b1) = self.split_i8x32(b);
@@ -7112,6 +9138,39 @@ impl Simd for Fallback {
         self.combine_i8x16(self.max_i8x16(a0, b0), self.max_i8x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_add_i8x16(a0, b0),
+            self.saturating_add_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_sub_i8x16(a0, b0),
+            self.saturating_sub_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.count_ones_i8x16(a0), self.count_ones_i8x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.leading_zeros_i8x16(a0), self.leading_zeros_i8x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.trailing_zeros_i8x16(a0), self.trailing_zeros_i8x16(a1))
+    }
+    #[inline(always)]
     fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
         let mut result = [0; 64usize];
         result[0..32usize].copy_from_slice(&a.val.0);
@@ -7132,6 +9191,16 @@ impl Simd for Fallback {
         self.combine_i8x16(self.neg_i8x16(a0), self.neg_i8x16(a1))
     }
     #[inline(always)]
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x16(self.widen_i8x16(a0), self.widen_i8x16(a1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x32(self, a: i8x32<Self>) -> i32 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_add_widened_i8x16(a0) + self.reduce_add_widened_i8x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         self.combine_u8x16(self.reinterpret_u8_i8x16(a0), self.reinterpret_u8_i8x16(a1))
@@ -7195,6 +9264,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u8x32<const SHIFT: usize>(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
         let mut dest = [Default::default(); 32usize];
         dest[..32usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[32usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -7378,6 +9450,105 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u8x32<const LANE: usize>(self, a: u8x32<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x32(a);
+        match LANE {
+            0 => self.extract_u8x16::<0>(a0),
+            1 => self.extract_u8x16::<1>(a0),
+            2 => self.extract_u8x16::<2>(a0),
+            3 => self.extract_u8x16::<3>(a0),
+            4 => self.extract_u8x16::<4>(a0),
+            5 => self.extract_u8x16::<5>(a0),
+            6 => self.extract_u8x16::<6>(a0),
+            7 => self.extract_u8x16::<7>(a0),
+            8 => self.extract_u8x16::<8>(a0),
+            9 => self.extract_u8x16::<9>(a0),
+            10 => self.extract_u8x16::<10>(a0),
+            11 => self.extract_u8x16::<11>(a0),
+            12 => self.extract_u8x16::<12>(a0),
+            13 => self.extract_u8x16::<13>(a0),
+            14 => self.extract_u8x16::<14>(a0),
+            15 => self.extract_u8x16::<15>(a0),
+            16 => self.extract_u8x16::<0>(a1),
+            17 => self.extract_u8x16::<1>(a1),
+            18 => self.extract_u8x16::<2>(a1),
+            19 => self.extract_u8x16::<3>(a1),
+            20 => self.extract_u8x16::<4>(a1),
+            21 => self.extract_u8x16::<5>(a1),
+            22 => self.extract_u8x16::<6>(a1),
+            23 => self.extract_u8x16::<7>(a1),
+            24 => self.extract_u8x16::<8>(a1),
+            25 => self.extract_u8x16::<9>(a1),
+            26 => self.extract_u8x16::<10>(a1),
+            27 => self.extract_u8x16::<11>(a1),
+            28 => self.extract_u8x16::<12>(a1),
+            29 => self.extract_u8x16::<13>(a1),
+            30 => self.extract_u8x16::<14>(a1),
+            31 => self.extract_u8x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u8x32<const LANE: usize>(self, a: u8x32<Self>, value: u8) -> u8x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x32(a);
+        match LANE {
+            0 => self.combine_u8x16(self.insert_u8x16::<0>(a0, value), a1),
+            1 => self.combine_u8x16(self.insert_u8x16::<1>(a0, value), a1),
+            2 => self.combine_u8x16(self.insert_u8x16::<2>(a0, value), a1),
+            3 => self.combine_u8x16(self.insert_u8x16::<3>(a0, value), a1),
+            4 => self.combine_u8x16(self.insert_u8x16::<4>(a0, value), a1),
+            5 => self.combine_u8x16(self.insert_u8x16::<5>(a0, value), a1),
+            6 => self.combine_u8x16(self.insert_u8x16::<6>(a0, value), a1),
+            7 => self.combine_u8x16(self.insert_u8x16::<7>(a0, value), a1),
+            8 => self.combine_u8x16(self.insert_u8x16::<8>(a0, value), a1),
+            9 => self.combine_u8x16(self.insert_u8x16::<9>(a0, value), a1),
+            10 => self.combine_u8x16(self.insert_u8x16::<10>(a0, value), a1),
+            11 => self.combine_u8x16(self.insert_u8x16::<11>(a0, value), a1),
+            12 => self.combine_u8x16(self.insert_u8x16::<12>(a0, value), a1),
+            13 => self.combine_u8x16(self.insert_u8x16::<13>(a0, value), a1),
+            14 => self.combine_u8x16(self.insert_u8x16::<14>(a0, value), a1),
+            15 => self.combine_u8x16(self.insert_u8x16::<15>(a0, value), a1),
+            16 => self.combine_u8x16(a0, self.insert_u8x16::<0>(a1, value)),
+            17 => self.combine_u8x16(a0, self.insert_u8x16::<1>(a1, value)),
+            18 => self.combine_u8x16(a0, self.insert_u8x16::<2>(a1, value)),
+            19 => self.combine_u8x16(a0, self.insert_u8x16::<3>(a1, value)),
+            20 => self.combine_u8x16(a0, self.insert_u8x16::<4>(a1, value)),
+            21 => self.combine_u8x16(a0, self.insert_u8x16::<5>(a1, value)),
+            22 => self.combine_u8x16(a0, self.insert_u8x16::<6>(a1, value)),
+            23 => self.combine_u8x16(a0, self.insert_u8x16::<7>(a1, value)),
+            24 => self.combine_u8x16(a0, self.insert_u8x16::<8>(a1, value)),
+            25 => self.combine_u8x16(a0, self.insert_u8x16::<9>(a1, value)),
+            26 => self.combine_u8x16(a0, self.insert_u8x16::<10>(a1, value)),
+            27 => self.combine_u8x16(a0, self.insert_u8x16::<11>(a1, value)),
+            28 => self.combine_u8x16(a0, self.insert_u8x16::<12>(a1, value)),
+            29 => self.combine_u8x16(a0, self.insert_u8x16::<13>(a1, value)),
+            30 => self.combine_u8x16(a0, self.insert_u8x16::<14>(a1, value)),
+            31 => self.combine_u8x16(a0, self.insert_u8x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x32(self, a: u8x32<Self>, index: usize) -> u8 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_u8x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u8x32(self, a: u8x32<Self>, indices: u8x32<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u8x32(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -7450,6 +9621,15 @@ impl Simd for Fallback {
         self.combine_u8x16(self.shrv_u8x16(a0, b0), self.shrv_u8x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u8x32(self, a: u8x32<Self>, b: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_u8x16(
+            self.shift_signed_u8x16(a0, b0),
+            self.shift_signed_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
         let (a0, a1) = self.split_u8x32(a);
         let (b0, b1) = self.split_u8x32(b);
@@ -7537,6 +9717,21 @@ impl Simd for Fallback {
         self.combine_u8x16(self.select_u8x16(a0, b0, c0), self.select_u8x16(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u8x32(
+        self,
+        a: mask8x32<Self>,
+        b: u8x32<Self>,
+        c: u8x32<Self>,
+    ) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        let (c0, c1) = self.split_u8x32(c);
+        self.combine_u8x16(
+            self.select_relaxed_u8x16(a0, b0, c0),
+            self.select_relaxed_u8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u8x32(a);
         let (b0, b1) = self.split_u8x32(b);
@@ -7549,6 +9744,39 @@ impl Simd for Fallback {
         self.combine_u8x16(self.max_u8x16(a0, b0), self.max_u8x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_add_u8x16(a0, b0),
+            self.saturating_add_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_sub_u8x16(a0, b0),
+            self.saturating_sub_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.count_ones_u8x16(a0), self.count_ones_u8x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.leading_zeros_u8x16(a0), self.leading_zeros_u8x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.trailing_zeros_u8x16(a0), self.trailing_zeros_u8x16(a1))
+    }
+    #[inline(always)]
     fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
         let mut result = [0; 64usize];
         result[0..32usize].copy_from_slice(&a.val.0);
@@ -7569,17 +9797,86 @@ impl Simd for Fallback {
         self.combine_u16x16(self.widen_u8x16(a0), self.widen_u8x16(a1))
     }
     #[inline(always)]
-    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
+    fn abd_accumulate_u8x32(
+        self,
+        acc: u16x32<Self>,
+        a: u8x32<Self>,
+        b: u8x32<Self>,
+    ) -> u16x32<Self> {
+        let (acc0, acc1) = self.split_u16x32(acc);
         let (a0, a1) = self.split_u8x32(a);
-        self.combine_u32x4(
-            self.reinterpret_u32_u8x16(a0),
-            self.reinterpret_u32_u8x16(a1),
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u16x16(
+            self.abd_accumulate_u8x16(acc0, a0, b0),
+            self.abd_accumulate_u8x16(acc1, a1, b1),
         )
     }
     #[inline(always)]
-    fn splat_mask8x32(self, val: bool) -> mask8x32<Self> {
-        let half = self.splat_mask8x16(val);
-        self.combine_mask8x16(half, half)
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.avg_round_u8x16(a0, b0), self.avg_round_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x32(self, a: u8x32<Self>) -> u32 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_add_widened_u8x16(a0) + self.reduce_add_widened_u8x16(a1)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u8x16(a0),
+            self.reinterpret_u32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn compress_bytes_u8x32(self, a: u8x32<Self>, mask: mask8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (mask0, mask1) = self.split_mask8x32(mask);
+        let count = self.to_bitmask_mask8x16(mask0).count_ones() as usize;
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.as_array_u8x16(self.compress_bytes_u8x16(a0, mask0)));
+        result[count..count + 16]
+            .copy_from_slice(&self.as_array_u8x16(self.compress_bytes_u8x16(a1, mask1)));
+        self.load_array_u8x32(result)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x32(self, a: u8x32<Self>, mask: mask8x32<Self>) -> u8x32<Self> {
+        let (mask0, mask1) = self.split_mask8x32(mask);
+        let count = self.to_bitmask_mask8x16(mask0).count_ones() as usize;
+        let lanes = self.as_array_u8x32(a);
+        let (a0, _) = self.split_u8x32(a);
+        let a1 = self.load_array_u8x16(core::array::from_fn(|i| lanes[count + i]));
+        self.combine_u8x16(
+            self.expand_bytes_u8x16(a0, mask0),
+            self.expand_bytes_u8x16(a1, mask1),
+        )
+    }
+    #[inline(always)]
+    fn table_lookup_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.table_lookup_u8x16(a0, b0),
+            self.table_lookup_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x32<const FACTOR: u8>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<32>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x32(
+            self.load_array_u8x32(low),
+            self.and_u8x32(a, self.splat_u8x32(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x32(self.load_array_u8x32(high), self.shr_u8x32(a, 4));
+        self.xor_u8x32(low, high)
+    }
+    #[inline(always)]
+    fn splat_mask8x32(self, val: bool) -> mask8x32<Self> {
+        let half = self.splat_mask8x16(val);
+        self.combine_mask8x16(half, half)
     }
     #[inline(always)]
     fn load_array_mask8x32(self, val: [i8; 32usize]) -> mask8x32<Self> {
@@ -7593,6 +9890,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self> {
+        i8x32 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self> {
         let lo = self.from_bitmask_mask8x16(bits);
         let hi = self.from_bitmask_mask8x16(bits >> 16usize);
@@ -7655,6 +9959,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        let (c0, c1) = self.split_mask8x32(c);
+        self.combine_mask8x16(
+            self.select_relaxed_mask8x16(a0, b0, c0),
+            self.select_relaxed_mask8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
         let (a0, a1) = self.split_mask8x32(a);
         let (b0, b1) = self.split_mask8x32(b);
@@ -7746,6 +10065,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i16x16<const SHIFT: usize>(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -7865,6 +10187,73 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i16x16<const LANE: usize>(self, a: i16x16<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x16(a);
+        match LANE {
+            0 => self.extract_i16x8::<0>(a0),
+            1 => self.extract_i16x8::<1>(a0),
+            2 => self.extract_i16x8::<2>(a0),
+            3 => self.extract_i16x8::<3>(a0),
+            4 => self.extract_i16x8::<4>(a0),
+            5 => self.extract_i16x8::<5>(a0),
+            6 => self.extract_i16x8::<6>(a0),
+            7 => self.extract_i16x8::<7>(a0),
+            8 => self.extract_i16x8::<0>(a1),
+            9 => self.extract_i16x8::<1>(a1),
+            10 => self.extract_i16x8::<2>(a1),
+            11 => self.extract_i16x8::<3>(a1),
+            12 => self.extract_i16x8::<4>(a1),
+            13 => self.extract_i16x8::<5>(a1),
+            14 => self.extract_i16x8::<6>(a1),
+            15 => self.extract_i16x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i16x16<const LANE: usize>(self, a: i16x16<Self>, value: i16) -> i16x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x16(a);
+        match LANE {
+            0 => self.combine_i16x8(self.insert_i16x8::<0>(a0, value), a1),
+            1 => self.combine_i16x8(self.insert_i16x8::<1>(a0, value), a1),
+            2 => self.combine_i16x8(self.insert_i16x8::<2>(a0, value), a1),
+            3 => self.combine_i16x8(self.insert_i16x8::<3>(a0, value), a1),
+            4 => self.combine_i16x8(self.insert_i16x8::<4>(a0, value), a1),
+            5 => self.combine_i16x8(self.insert_i16x8::<5>(a0, value), a1),
+            6 => self.combine_i16x8(self.insert_i16x8::<6>(a0, value), a1),
+            7 => self.combine_i16x8(self.insert_i16x8::<7>(a0, value), a1),
+            8 => self.combine_i16x8(a0, self.insert_i16x8::<0>(a1, value)),
+            9 => self.combine_i16x8(a0, self.insert_i16x8::<1>(a1, value)),
+            10 => self.combine_i16x8(a0, self.insert_i16x8::<2>(a1, value)),
+            11 => self.combine_i16x8(a0, self.insert_i16x8::<3>(a1, value)),
+            12 => self.combine_i16x8(a0, self.insert_i16x8::<4>(a1, value)),
+            13 => self.combine_i16x8(a0, self.insert_i16x8::<5>(a1, value)),
+            14 => self.combine_i16x8(a0, self.insert_i16x8::<6>(a1, value)),
+            15 => self.combine_i16x8(a0, self.insert_i16x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x16(self, a: i16x16<Self>, index: usize) -> i16 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i16x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i16x16(
         self,
         a: i16x16<Self>,
@@ -7941,6 +10330,15 @@ impl Simd for Fallback {
         self.combine_i16x8(self.shrv_i16x8(a0, b0), self.shrv_i16x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.shift_signed_i16x8(a0, b0),
+            self.shift_signed_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
         let (a0, a1) = self.split_i16x16(a);
         let (b0, b1) = self.split_i16x16(b);
@@ -8028,6 +10426,21 @@ impl Simd for Fallback {
         self.combine_i16x8(self.select_i16x8(a0, b0, c0), self.select_i16x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i16x16(
+        self,
+        a: mask16x16<Self>,
+        b: i16x16<Self>,
+        c: i16x16<Self>,
+    ) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        let (c0, c1) = self.split_i16x16(c);
+        self.combine_i16x8(
+            self.select_relaxed_i16x8(a0, b0, c0),
+            self.select_relaxed_i16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
         let (a0, a1) = self.split_i16x16(a);
         let (b0, b1) = self.split_i16x16(b);
@@ -8040,6 +10453,39 @@ impl Simd for Fallback {
         self.combine_i16x8(self.max_i16x8(a0, b0), self.max_i16x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_add_i16x8(a0, b0),
+            self.saturating_add_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_sub_i16x8(a0, b0),
+            self.saturating_sub_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.count_ones_i16x8(a0), self.count_ones_i16x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.leading_zeros_i16x8(a0), self.leading_zeros_i16x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.trailing_zeros_i16x8(a0), self.trailing_zeros_i16x8(a1))
+    }
+    #[inline(always)]
     fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
         let mut result = [0; 32usize];
         result[0..16usize].copy_from_slice(&a.val.0);
@@ -8060,6 +10506,60 @@ impl Simd for Fallback {
         self.combine_i16x8(self.neg_i16x8(a0), self.neg_i16x8(a1))
     }
     #[inline(always)]
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x8(self.widen_i16x8(a0), self.widen_i16x8(a1))
+    }
+    #[inline(always)]
+    fn pack_saturate_i8_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize].clamp(-128, 127) as i8,
+            a[1usize].clamp(-128, 127) as i8,
+            a[2usize].clamp(-128, 127) as i8,
+            a[3usize].clamp(-128, 127) as i8,
+            a[4usize].clamp(-128, 127) as i8,
+            a[5usize].clamp(-128, 127) as i8,
+            a[6usize].clamp(-128, 127) as i8,
+            a[7usize].clamp(-128, 127) as i8,
+            a[8usize].clamp(-128, 127) as i8,
+            a[9usize].clamp(-128, 127) as i8,
+            a[10usize].clamp(-128, 127) as i8,
+            a[11usize].clamp(-128, 127) as i8,
+            a[12usize].clamp(-128, 127) as i8,
+            a[13usize].clamp(-128, 127) as i8,
+            a[14usize].clamp(-128, 127) as i8,
+            a[15usize].clamp(-128, 127) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_i16x16(self, a: i16x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize].clamp(0, 255) as u8,
+            a[1usize].clamp(0, 255) as u8,
+            a[2usize].clamp(0, 255) as u8,
+            a[3usize].clamp(0, 255) as u8,
+            a[4usize].clamp(0, 255) as u8,
+            a[5usize].clamp(0, 255) as u8,
+            a[6usize].clamp(0, 255) as u8,
+            a[7usize].clamp(0, 255) as u8,
+            a[8usize].clamp(0, 255) as u8,
+            a[9usize].clamp(0, 255) as u8,
+            a[10usize].clamp(0, 255) as u8,
+            a[11usize].clamp(0, 255) as u8,
+            a[12usize].clamp(0, 255) as u8,
+            a[13usize].clamp(0, 255) as u8,
+            a[14usize].clamp(0, 255) as u8,
+            a[15usize].clamp(0, 255) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x16(self, a: i16x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_add_widened_i16x8(a0) + self.reduce_add_widened_i16x8(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_i16x16(a);
         self.combine_u8x16(self.reinterpret_u8_i16x8(a0), self.reinterpret_u8_i16x8(a1))
@@ -8123,6 +10623,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u16x16<const SHIFT: usize>(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -8242,6 +10745,73 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u16x16<const LANE: usize>(self, a: u16x16<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x16(a);
+        match LANE {
+            0 => self.extract_u16x8::<0>(a0),
+            1 => self.extract_u16x8::<1>(a0),
+            2 => self.extract_u16x8::<2>(a0),
+            3 => self.extract_u16x8::<3>(a0),
+            4 => self.extract_u16x8::<4>(a0),
+            5 => self.extract_u16x8::<5>(a0),
+            6 => self.extract_u16x8::<6>(a0),
+            7 => self.extract_u16x8::<7>(a0),
+            8 => self.extract_u16x8::<0>(a1),
+            9 => self.extract_u16x8::<1>(a1),
+            10 => self.extract_u16x8::<2>(a1),
+            11 => self.extract_u16x8::<3>(a1),
+            12 => self.extract_u16x8::<4>(a1),
+            13 => self.extract_u16x8::<5>(a1),
+            14 => self.extract_u16x8::<6>(a1),
+            15 => self.extract_u16x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u16x16<const LANE: usize>(self, a: u16x16<Self>, value: u16) -> u16x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x16(a);
+        match LANE {
+            0 => self.combine_u16x8(self.insert_u16x8::<0>(a0, value), a1),
+            1 => self.combine_u16x8(self.insert_u16x8::<1>(a0, value), a1),
+            2 => self.combine_u16x8(self.insert_u16x8::<2>(a0, value), a1),
+            3 => self.combine_u16x8(self.insert_u16x8::<3>(a0, value), a1),
+            4 => self.combine_u16x8(self.insert_u16x8::<4>(a0, value), a1),
+            5 => self.combine_u16x8(self.insert_u16x8::<5>(a0, value), a1),
+            6 => self.combine_u16x8(self.insert_u16x8::<6>(a0, value), a1),
+            7 => self.combine_u16x8(self.insert_u16x8::<7>(a0, value), a1),
+            8 => self.combine_u16x8(a0, self.insert_u16x8::<0>(a1, value)),
+            9 => self.combine_u16x8(a0, self.insert_u16x8::<1>(a1, value)),
+            10 => self.combine_u16x8(a0, self.insert_u16x8::<2>(a1, value)),
+            11 => self.combine_u16x8(a0, self.insert_u16x8::<3>(a1, value)),
+            12 => self.combine_u16x8(a0, self.insert_u16x8::<4>(a1, value)),
+            13 => self.combine_u16x8(a0, self.insert_u16x8::<5>(a1, value)),
+            14 => self.combine_u16x8(a0, self.insert_u16x8::<6>(a1, value)),
+            15 => self.combine_u16x8(a0, self.insert_u16x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x16(self, a: u16x16<Self>, index: usize) -> u16 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u16x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u16x16(
         self,
         a: u16x16<Self>,
@@ -8318,6 +10888,15 @@ impl Simd for Fallback {
         self.combine_u16x8(self.shrv_u16x8(a0, b0), self.shrv_u16x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u16x16(self, a: u16x16<Self>, b: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_u16x8(
+            self.shift_signed_u16x8(a0, b0),
+            self.shift_signed_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
         let (a0, a1) = self.split_u16x16(a);
         let (b0, b1) = self.split_u16x16(b);
@@ -8405,6 +10984,21 @@ impl Simd for Fallback {
         self.combine_u16x8(self.select_u16x8(a0, b0, c0), self.select_u16x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u16x16(
+        self,
+        a: mask16x16<Self>,
+        b: u16x16<Self>,
+        c: u16x16<Self>,
+    ) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        let (c0, c1) = self.split_u16x16(c);
+        self.combine_u16x8(
+            self.select_relaxed_u16x8(a0, b0, c0),
+            self.select_relaxed_u16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
         let (a0, a1) = self.split_u16x16(a);
         let (b0, b1) = self.split_u16x16(b);
@@ -8417,6 +11011,39 @@ impl Simd for Fallback {
         self.combine_u16x8(self.max_u16x8(a0, b0), self.max_u16x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_add_u16x8(a0, b0),
+            self.saturating_add_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_sub_u16x8(a0, b0),
+            self.saturating_sub_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.count_ones_u16x8(a0), self.count_ones_u16x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.leading_zeros_u16x8(a0), self.leading_zeros_u16x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.trailing_zeros_u16x8(a0), self.trailing_zeros_u16x8(a1))
+    }
+    #[inline(always)]
     fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
         let mut result = [0; 32usize];
         result[0..16usize].copy_from_slice(&a.val.0);
@@ -8432,6 +11059,11 @@ impl Simd for Fallback {
         (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x8(self.widen_u16x8(a0), self.widen_u16x8(a1))
+    }
+    #[inline(always)]
     fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
         [
             a[0usize] as u8,
@@ -8454,6 +11086,76 @@ impl Simd for Fallback {
         .simd_into(self)
     }
     #[inline(always)]
+    fn pack_saturate_i8_u16x16(self, a: u16x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize].min(127) as i8,
+            a[1usize].min(127) as i8,
+            a[2usize].min(127) as i8,
+            a[3usize].min(127) as i8,
+            a[4usize].min(127) as i8,
+            a[5usize].min(127) as i8,
+            a[6usize].min(127) as i8,
+            a[7usize].min(127) as i8,
+            a[8usize].min(127) as i8,
+            a[9usize].min(127) as i8,
+            a[10usize].min(127) as i8,
+            a[11usize].min(127) as i8,
+            a[12usize].min(127) as i8,
+            a[13usize].min(127) as i8,
+            a[14usize].min(127) as i8,
+            a[15usize].min(127) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize].min(255) as u8,
+            a[1usize].min(255) as u8,
+            a[2usize].min(255) as u8,
+            a[3usize].min(255) as u8,
+            a[4usize].min(255) as u8,
+            a[5usize].min(255) as u8,
+            a[6usize].min(255) as u8,
+            a[7usize].min(255) as u8,
+            a[8usize].min(255) as u8,
+            a[9usize].min(255) as u8,
+            a[10usize].min(255) as u8,
+            a[11usize].min(255) as u8,
+            a[12usize].min(255) as u8,
+            a[13usize].min(255) as u8,
+            a[14usize].min(255) as u8,
+            a[15usize].min(255) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u16x16(
+        self,
+        acc: u32x16<Self>,
+        a: u16x16<Self>,
+        b: u16x16<Self>,
+    ) -> u32x16<Self> {
+        let (acc0, acc1) = self.split_u32x16(acc);
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u32x8(
+            self.abd_accumulate_u16x8(acc0, a0, b0),
+            self.abd_accumulate_u16x8(acc1, a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.avg_round_u16x8(a0, b0), self.avg_round_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x16(self, a: u16x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_add_widened_u16x8(a0) + self.reduce_add_widened_u16x8(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u16x16(a);
         self.combine_u8x16(self.reinterpret_u8_u16x8(a0), self.reinterpret_u8_u16x8(a1))
@@ -8467,6 +11169,19 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn transpose_bits_16x16_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (top, bottom) = self.split_u8x32(a.to_le_bytes());
+        let ac =
+            self.transpose_bits_8x8_u64x2(Bytes::from_le_bytes(self.unzip_low_u8x16(top, bottom)));
+        let bd =
+            self.transpose_bits_8x8_u64x2(Bytes::from_le_bytes(self.unzip_high_u8x16(top, bottom)));
+        let ab = self.zip_low_u64x2(ac, bd).to_le_bytes();
+        let cd = self.zip_high_u64x2(ac, bd).to_le_bytes();
+        Bytes::from_le_bytes(
+            self.combine_u8x16(self.zip_low_u8x16(ab, cd), self.zip_high_u8x16(ab, cd)),
+        )
+    }
+    #[inline(always)]
     fn splat_mask16x16(self, val: bool) -> mask16x16<Self> {
         let half = self.splat_mask16x8(val);
         self.combine_mask16x8(half, half)
@@ -8483,6 +11198,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self> {
+        i16x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self> {
         let lo = self.from_bitmask_mask16x8(bits);
         let hi = self.from_bitmask_mask16x8(bits >> 8usize);
@@ -8545,6 +11267,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        let (c0, c1) = self.split_mask16x16(c);
+        self.combine_mask16x8(
+            self.select_relaxed_mask16x8(a0, b0, c0),
+            self.select_relaxed_mask16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
         let (a0, a1) = self.split_mask16x16(a);
         let (b0, b1) = self.split_mask16x16(b);
@@ -8636,6 +11373,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i32x8<const SHIFT: usize>(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -8723,6 +11463,57 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i32x8<const LANE: usize>(self, a: i32x8<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x8(a);
+        match LANE {
+            0 => self.extract_i32x4::<0>(a0),
+            1 => self.extract_i32x4::<1>(a0),
+            2 => self.extract_i32x4::<2>(a0),
+            3 => self.extract_i32x4::<3>(a0),
+            4 => self.extract_i32x4::<0>(a1),
+            5 => self.extract_i32x4::<1>(a1),
+            6 => self.extract_i32x4::<2>(a1),
+            7 => self.extract_i32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i32x8<const LANE: usize>(self, a: i32x8<Self>, value: i32) -> i32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x8(a);
+        match LANE {
+            0 => self.combine_i32x4(self.insert_i32x4::<0>(a0, value), a1),
+            1 => self.combine_i32x4(self.insert_i32x4::<1>(a0, value), a1),
+            2 => self.combine_i32x4(self.insert_i32x4::<2>(a0, value), a1),
+            3 => self.combine_i32x4(self.insert_i32x4::<3>(a0, value), a1),
+            4 => self.combine_i32x4(a0, self.insert_i32x4::<0>(a1, value)),
+            5 => self.combine_i32x4(a0, self.insert_i32x4::<1>(a1, value)),
+            6 => self.combine_i32x4(a0, self.insert_i32x4::<2>(a1, value)),
+            7 => self.combine_i32x4(a0, self.insert_i32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x8(self, a: i32x8<Self>, index: usize) -> i32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i32x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i32x8(self, a: i32x8<Self>, indices: u8x32<Self>) -> i32x8<Self> {
         let (a0, a1) = self.split_i32x8(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -8795,6 +11586,15 @@ impl Simd for Fallback {
         self.combine_i32x4(self.shrv_i32x4(a0, b0), self.shrv_i32x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(
+            self.shift_signed_i32x4(a0, b0),
+            self.shift_signed_i32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
         let (a0, a1) = self.split_i32x8(a);
         let (b0, b1) = self.split_i32x8(b);
@@ -8882,6 +11682,21 @@ impl Simd for Fallback {
         self.combine_i32x4(self.select_i32x4(a0, b0, c0), self.select_i32x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i32x8(
+        self,
+        a: mask32x8<Self>,
+        b: i32x8<Self>,
+        c: i32x8<Self>,
+    ) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        let (c0, c1) = self.split_i32x8(c);
+        self.combine_i32x4(
+            self.select_relaxed_i32x4(a0, b0, c0),
+            self.select_relaxed_i32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
         let (a0, a1) = self.split_i32x8(a);
         let (b0, b1) = self.split_i32x8(b);
@@ -8894,6 +11709,39 @@ impl Simd for Fallback {
         self.combine_i32x4(self.max_i32x4(a0, b0), self.max_i32x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(
+            self.saturating_add_i32x4(a0, b0),
+            self.saturating_add_i32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(
+            self.saturating_sub_i32x4(a0, b0),
+            self.saturating_sub_i32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.count_ones_i32x4(a0), self.count_ones_i32x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.leading_zeros_i32x4(a0), self.leading_zeros_i32x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.trailing_zeros_i32x4(a0), self.trailing_zeros_i32x4(a1))
+    }
+    #[inline(always)]
     fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
         let mut result = [0; 16usize];
         result[0..8usize].copy_from_slice(&a.val.0);
@@ -8914,6 +11762,11 @@ impl Simd for Fallback {
         self.combine_i32x4(self.neg_i32x4(a0), self.neg_i32x4(a1))
     }
     #[inline(always)]
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x4(self.widen_i32x4(a0), self.widen_i32x4(a1))
+    }
+    #[inline(always)]
     fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_i32x8(a);
         self.combine_u8x16(self.reinterpret_u8_i32x4(a0), self.reinterpret_u8_i32x4(a1))
@@ -8982,6 +11835,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u32x8<const SHIFT: usize>(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -9069,6 +11925,57 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u32x8<const LANE: usize>(self, a: u32x8<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x8(a);
+        match LANE {
+            0 => self.extract_u32x4::<0>(a0),
+            1 => self.extract_u32x4::<1>(a0),
+            2 => self.extract_u32x4::<2>(a0),
+            3 => self.extract_u32x4::<3>(a0),
+            4 => self.extract_u32x4::<0>(a1),
+            5 => self.extract_u32x4::<1>(a1),
+            6 => self.extract_u32x4::<2>(a1),
+            7 => self.extract_u32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u32x8<const LANE: usize>(self, a: u32x8<Self>, value: u32) -> u32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x8(a);
+        match LANE {
+            0 => self.combine_u32x4(self.insert_u32x4::<0>(a0, value), a1),
+            1 => self.combine_u32x4(self.insert_u32x4::<1>(a0, value), a1),
+            2 => self.combine_u32x4(self.insert_u32x4::<2>(a0, value), a1),
+            3 => self.combine_u32x4(self.insert_u32x4::<3>(a0, value), a1),
+            4 => self.combine_u32x4(a0, self.insert_u32x4::<0>(a1, value)),
+            5 => self.combine_u32x4(a0, self.insert_u32x4::<1>(a1, value)),
+            6 => self.combine_u32x4(a0, self.insert_u32x4::<2>(a1, value)),
+            7 => self.combine_u32x4(a0, self.insert_u32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x8(self, a: u32x8<Self>, index: usize) -> u32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u32x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u32x8(self, a: u32x8<Self>, indices: u8x32<Self>) -> u32x8<Self> {
         let (a0, a1) = self.split_u32x8(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -9141,6 +12048,15 @@ impl Simd for Fallback {
         self.combine_u32x4(self.shrv_u32x4(a0, b0), self.shrv_u32x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u32x8(self, a: u32x8<Self>, b: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_u32x4(
+            self.shift_signed_u32x4(a0, b0),
+            self.shift_signed_u32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
         let (a0, a1) = self.split_u32x8(a);
         let (b0, b1) = self.split_u32x8(b);
@@ -9228,6 +12144,21 @@ impl Simd for Fallback {
         self.combine_u32x4(self.select_u32x4(a0, b0, c0), self.select_u32x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u32x8(
+        self,
+        a: mask32x8<Self>,
+        b: u32x8<Self>,
+        c: u32x8<Self>,
+    ) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        let (c0, c1) = self.split_u32x8(c);
+        self.combine_u32x4(
+            self.select_relaxed_u32x4(a0, b0, c0),
+            self.select_relaxed_u32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
         let (a0, a1) = self.split_u32x8(a);
         let (b0, b1) = self.split_u32x8(b);
@@ -9240,6 +12171,39 @@ impl Simd for Fallback {
         self.combine_u32x4(self.max_u32x4(a0, b0), self.max_u32x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(
+            self.saturating_add_u32x4(a0, b0),
+            self.saturating_add_u32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(
+            self.saturating_sub_u32x4(a0, b0),
+            self.saturating_sub_u32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.count_ones_u32x4(a0), self.count_ones_u32x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.leading_zeros_u32x4(a0), self.leading_zeros_u32x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.trailing_zeros_u32x4(a0), self.trailing_zeros_u32x4(a1))
+    }
+    #[inline(always)]
     fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
         let mut result = [0; 16usize];
         result[0..8usize].copy_from_slice(&a.val.0);
@@ -9255,6 +12219,11 @@ impl Simd for Fallback {
         (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x4(self.widen_u32x4(a0), self.widen_u32x4(a1))
+    }
+    #[inline(always)]
     fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u32x8(a);
         self.combine_u8x16(self.reinterpret_u8_u32x4(a0), self.reinterpret_u8_u32x4(a1))
@@ -9281,6 +12250,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self> {
+        i32x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self> {
         let lo = self.from_bitmask_mask32x4(bits);
         let hi = self.from_bitmask_mask32x4(bits >> 4usize);
@@ -9343,6 +12319,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        let (c0, c1) = self.split_mask32x8(c);
+        self.combine_mask32x4(
+            self.select_relaxed_mask32x4(a0, b0, c0),
+            self.select_relaxed_mask32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
         let (a0, a1) = self.split_mask32x8(a);
         let (b0, b1) = self.split_mask32x8(b);
@@ -9434,6 +12425,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_f64x4<const SHIFT: usize>(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
         let mut dest = [Default::default(); 4usize];
         dest[..4usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[4usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -9505,6 +12499,49 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_f64x4<const LANE: usize>(self, a: f64x4<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x4(a);
+        match LANE {
+            0 => self.extract_f64x2::<0>(a0),
+            1 => self.extract_f64x2::<1>(a0),
+            2 => self.extract_f64x2::<0>(a1),
+            3 => self.extract_f64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f64x4<const LANE: usize>(self, a: f64x4<Self>, value: f64) -> f64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x4(a);
+        match LANE {
+            0 => self.combine_f64x2(self.insert_f64x2::<0>(a0, value), a1),
+            1 => self.combine_f64x2(self.insert_f64x2::<1>(a0, value), a1),
+            2 => self.combine_f64x2(a0, self.insert_f64x2::<0>(a1, value)),
+            3 => self.combine_f64x2(a0, self.insert_f64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x4(self, a: f64x4<Self>, index: usize) -> f64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_f64x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f64x4(self, a: f64x4<Self>, indices: u8x32<Self>) -> f64x4<Self> {
         let (a0, a1) = self.split_f64x4(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -9697,6 +12734,36 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn nmul_add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.nmul_add_f64x2(a0, b0, c0),
+            self.nmul_add_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.nmul_sub_f64x2(a0, b0, c0),
+            self.nmul_sub_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_relaxed_f64x2(a0, b0, c0),
+            self.mul_add_relaxed_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
         let (a0, a1) = self.split_f64x4(a);
         self.combine_f64x2(self.floor_f64x2(a0), self.floor_f64x2(a1))
@@ -9732,6 +12799,21 @@ impl Simd for Fallback {
         self.combine_f64x2(self.select_f64x2(a0, b0, c0), self.select_f64x2(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f64x4(
+        self,
+        a: mask64x4<Self>,
+        b: f64x4<Self>,
+        c: f64x4<Self>,
+    ) -> f64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.select_relaxed_f64x2(a0, b0, c0),
+            self.select_relaxed_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self> {
         let mut result = [0.0; 8usize];
         result[0..4usize].copy_from_slice(&a.val.0);
@@ -9805,6 +12887,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i64x4<const SHIFT: usize>(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
         let mut dest = [Default::default(); 4usize];
         dest[..4usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[4usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -9876,6 +12961,49 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i64x4<const LANE: usize>(self, a: i64x4<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x4(a);
+        match LANE {
+            0 => self.extract_i64x2::<0>(a0),
+            1 => self.extract_i64x2::<1>(a0),
+            2 => self.extract_i64x2::<0>(a1),
+            3 => self.extract_i64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i64x4<const LANE: usize>(self, a: i64x4<Self>, value: i64) -> i64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x4(a);
+        match LANE {
+            0 => self.combine_i64x2(self.insert_i64x2::<0>(a0, value), a1),
+            1 => self.combine_i64x2(self.insert_i64x2::<1>(a0, value), a1),
+            2 => self.combine_i64x2(a0, self.insert_i64x2::<0>(a1, value)),
+            3 => self.combine_i64x2(a0, self.insert_i64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x4(self, a: i64x4<Self>, index: usize) -> i64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_i64x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i64x4(self, a: i64x4<Self>, indices: u8x32<Self>) -> i64x4<Self> {
         let (a0, a1) = self.split_i64x4(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -9948,6 +13076,15 @@ impl Simd for Fallback {
         self.combine_i64x2(self.shrv_i64x2(a0, b0), self.shrv_i64x2(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(
+            self.shift_signed_i64x2(a0, b0),
+            self.shift_signed_i64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
         let (a0, a1) = self.split_i64x4(a);
         let (b0, b1) = self.split_i64x4(b);
@@ -10035,6 +13172,21 @@ impl Simd for Fallback {
         self.combine_i64x2(self.select_i64x2(a0, b0, c0), self.select_i64x2(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i64x4(
+        self,
+        a: mask64x4<Self>,
+        b: i64x4<Self>,
+        c: i64x4<Self>,
+    ) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        let (c0, c1) = self.split_i64x4(c);
+        self.combine_i64x2(
+            self.select_relaxed_i64x2(a0, b0, c0),
+            self.select_relaxed_i64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
         let (a0, a1) = self.split_i64x4(a);
         let (b0, b1) = self.split_i64x4(b);
@@ -10047,6 +13199,39 @@ impl Simd for Fallback {
         self.combine_i64x2(self.max_i64x2(a0, b0), self.max_i64x2(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(
+            self.saturating_add_i64x2(a0, b0),
+            self.saturating_add_i64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(
+            self.saturating_sub_i64x2(a0, b0),
+            self.saturating_sub_i64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.count_ones_i64x2(a0), self.count_ones_i64x2(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.leading_zeros_i64x2(a0), self.leading_zeros_i64x2(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.trailing_zeros_i64x2(a0), self.trailing_zeros_i64x2(a1))
+    }
+    #[inline(always)]
     fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self> {
         let mut result = [0; 8usize];
         result[0..4usize].copy_from_slice(&a.val.0);
@@ -10130,6 +13315,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u64x4<const SHIFT: usize>(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        if SHIFT >= 4usize {
+            return b;
+        }
         let mut dest = [Default::default(); 4usize];
         dest[..4usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[4usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -10201,6 +13389,49 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u64x4<const LANE: usize>(self, a: u64x4<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x4(a);
+        match LANE {
+            0 => self.extract_u64x2::<0>(a0),
+            1 => self.extract_u64x2::<1>(a0),
+            2 => self.extract_u64x2::<0>(a1),
+            3 => self.extract_u64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u64x4<const LANE: usize>(self, a: u64x4<Self>, value: u64) -> u64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x4(a);
+        match LANE {
+            0 => self.combine_u64x2(self.insert_u64x2::<0>(a0, value), a1),
+            1 => self.combine_u64x2(self.insert_u64x2::<1>(a0, value), a1),
+            2 => self.combine_u64x2(a0, self.insert_u64x2::<0>(a1, value)),
+            3 => self.combine_u64x2(a0, self.insert_u64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x4(self, a: u64x4<Self>, index: usize) -> u64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_u64x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u64x4(self, a: u64x4<Self>, indices: u8x32<Self>) -> u64x4<Self> {
         let (a0, a1) = self.split_u64x4(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -10273,6 +13504,15 @@ impl Simd for Fallback {
         self.combine_u64x2(self.shrv_u64x2(a0, b0), self.shrv_u64x2(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u64x4(self, a: u64x4<Self>, b: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_u64x2(
+            self.shift_signed_u64x2(a0, b0),
+            self.shift_signed_u64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
         let (a0, a1) = self.split_u64x4(a);
         let (b0, b1) = self.split_u64x4(b);
@@ -10357,7 +13597,22 @@ impl Simd for Fallback {
         let (a0, a1) = self.split_mask64x4(a);
         let (b0, b1) = self.split_u64x4(b);
         let (c0, c1) = self.split_u64x4(c);
-        self.combine_u64x2(self.select_u64x2(a0, b0, c0), self.select_u64x2(a1, b1, c1))
+        self.combine_u64x2(self.select_u64x2(a0, b0, c0), self.select_u64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn select_relaxed_u64x4(
+        self,
+        a: mask64x4<Self>,
+        b: u64x4<Self>,
+        c: u64x4<Self>,
+    ) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        let (c0, c1) = self.split_u64x4(c);
+        self.combine_u64x2(
+            self.select_relaxed_u64x2(a0, b0, c0),
+            self.select_relaxed_u64x2(a1, b1, c1),
+        )
     }
     #[inline(always)]
     fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
@@ -10372,6 +13627,39 @@ impl Simd for Fallback {
         self.combine_u64x2(self.max_u64x2(a0, b0), self.max_u64x2(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(
+            self.saturating_add_u64x2(a0, b0),
+            self.saturating_add_u64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(
+            self.saturating_sub_u64x2(a0, b0),
+            self.saturating_sub_u64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.count_ones_u64x2(a0), self.count_ones_u64x2(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.leading_zeros_u64x2(a0), self.leading_zeros_u64x2(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.trailing_zeros_u64x2(a0), self.trailing_zeros_u64x2(a1))
+    }
+    #[inline(always)]
     fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self> {
         let mut result = [0; 8usize];
         result[0..4usize].copy_from_slice(&a.val.0);
@@ -10400,6 +13688,24 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn transpose_bits_8x8_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 7)),
+            self.splat_u64x4(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 7));
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 14)),
+            self.splat_u64x4(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 14));
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 28)),
+            self.splat_u64x4(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 28))
+    }
+    #[inline(always)]
     fn splat_mask64x4(self, val: bool) -> mask64x4<Self> {
         let half = self.splat_mask64x2(val);
         self.combine_mask64x2(half, half)
@@ -10416,6 +13722,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self> {
+        i64x4 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self> {
         let lo = self.from_bitmask_mask64x2(bits);
         let hi = self.from_bitmask_mask64x2(bits >> 2usize);
@@ -10478,6 +13791,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        let (c0, c1) = self.split_mask64x4(c);
+        self.combine_mask64x2(
+            self.select_relaxed_mask64x2(a0, b0, c0),
+            self.select_relaxed_mask64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
         let (a0, a1) = self.split_mask64x4(a);
         let (b0, b1) = self.split_mask64x4(b);
@@ -10569,6 +13897,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_f32x16<const SHIFT: usize>(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -10688,6 +14019,73 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_f32x16<const LANE: usize>(self, a: f32x16<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x16(a);
+        match LANE {
+            0 => self.extract_f32x8::<0>(a0),
+            1 => self.extract_f32x8::<1>(a0),
+            2 => self.extract_f32x8::<2>(a0),
+            3 => self.extract_f32x8::<3>(a0),
+            4 => self.extract_f32x8::<4>(a0),
+            5 => self.extract_f32x8::<5>(a0),
+            6 => self.extract_f32x8::<6>(a0),
+            7 => self.extract_f32x8::<7>(a0),
+            8 => self.extract_f32x8::<0>(a1),
+            9 => self.extract_f32x8::<1>(a1),
+            10 => self.extract_f32x8::<2>(a1),
+            11 => self.extract_f32x8::<3>(a1),
+            12 => self.extract_f32x8::<4>(a1),
+            13 => self.extract_f32x8::<5>(a1),
+            14 => self.extract_f32x8::<6>(a1),
+            15 => self.extract_f32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f32x16<const LANE: usize>(self, a: f32x16<Self>, value: f32) -> f32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x16(a);
+        match LANE {
+            0 => self.combine_f32x8(self.insert_f32x8::<0>(a0, value), a1),
+            1 => self.combine_f32x8(self.insert_f32x8::<1>(a0, value), a1),
+            2 => self.combine_f32x8(self.insert_f32x8::<2>(a0, value), a1),
+            3 => self.combine_f32x8(self.insert_f32x8::<3>(a0, value), a1),
+            4 => self.combine_f32x8(self.insert_f32x8::<4>(a0, value), a1),
+            5 => self.combine_f32x8(self.insert_f32x8::<5>(a0, value), a1),
+            6 => self.combine_f32x8(self.insert_f32x8::<6>(a0, value), a1),
+            7 => self.combine_f32x8(self.insert_f32x8::<7>(a0, value), a1),
+            8 => self.combine_f32x8(a0, self.insert_f32x8::<0>(a1, value)),
+            9 => self.combine_f32x8(a0, self.insert_f32x8::<1>(a1, value)),
+            10 => self.combine_f32x8(a0, self.insert_f32x8::<2>(a1, value)),
+            11 => self.combine_f32x8(a0, self.insert_f32x8::<3>(a1, value)),
+            12 => self.combine_f32x8(a0, self.insert_f32x8::<4>(a1, value)),
+            13 => self.combine_f32x8(a0, self.insert_f32x8::<5>(a1, value)),
+            14 => self.combine_f32x8(a0, self.insert_f32x8::<6>(a1, value)),
+            15 => self.combine_f32x8(a0, self.insert_f32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x16(self, a: f32x16<Self>, index: usize) -> f32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_f32x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f32x16(
         self,
         a: f32x16<Self>,
@@ -10884,6 +14282,41 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn nmul_add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.nmul_add_f32x8(a0, b0, c0),
+            self.nmul_add_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.nmul_sub_f32x8(a0, b0, c0),
+            self.nmul_sub_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_relaxed_f32x8(a0, b0, c0),
+            self.mul_add_relaxed_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
         let (a0, a1) = self.split_f32x16(a);
         self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
@@ -10919,6 +14352,21 @@ impl Simd for Fallback {
         self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f32x16(
+        self,
+        a: mask32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.select_relaxed_f32x8(a0, b0, c0),
+            self.select_relaxed_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
         let mut b0 = [0.0; 8usize];
         let mut b1 = [0.0; 8usize];
@@ -11012,6 +14460,46 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn pack_clamped_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        [
+            FallbackFloat::round_ties_even(a[0usize]) as u8,
+            FallbackFloat::round_ties_even(a[1usize]) as u8,
+            FallbackFloat::round_ties_even(a[2usize]) as u8,
+            FallbackFloat::round_ties_even(a[3usize]) as u8,
+            FallbackFloat::round_ties_even(a[4usize]) as u8,
+            FallbackFloat::round_ties_even(a[5usize]) as u8,
+            FallbackFloat::round_ties_even(a[6usize]) as u8,
+            FallbackFloat::round_ties_even(a[7usize]) as u8,
+            FallbackFloat::round_ties_even(a[8usize]) as u8,
+            FallbackFloat::round_ties_even(a[9usize]) as u8,
+            FallbackFloat::round_ties_even(a[10usize]) as u8,
+            FallbackFloat::round_ties_even(a[11usize]) as u8,
+            FallbackFloat::round_ties_even(a[12usize]) as u8,
+            FallbackFloat::round_ties_even(a[13usize]) as u8,
+            FallbackFloat::round_ties_even(a[14usize]) as u8,
+            FallbackFloat::round_ties_even(a[15usize]) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn dot2_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot2_f32x8(a0, b0), self.dot2_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn dot3_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot3_f32x8(a0, b0), self.dot3_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn dot4_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot4_f32x8(a0, b0), self.dot4_f32x8(a1, b1))
+    }
+    #[inline(always)]
     fn splat_i8x64(self, val: i8) -> i8x64<Self> {
         let half = self.splat_i8x32(val);
         self.combine_i8x32(half, half)
@@ -11062,6 +14550,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i8x64<const SHIFT: usize>(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        if SHIFT >= 64usize {
+            return b;
+        }
         let mut dest = [Default::default(); 64usize];
         dest[..64usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[64usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -11373,6 +14864,169 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i8x64<const LANE: usize>(self, a: i8x64<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x64(a);
+        match LANE {
+            0 => self.extract_i8x32::<0>(a0),
+            1 => self.extract_i8x32::<1>(a0),
+            2 => self.extract_i8x32::<2>(a0),
+            3 => self.extract_i8x32::<3>(a0),
+            4 => self.extract_i8x32::<4>(a0),
+            5 => self.extract_i8x32::<5>(a0),
+            6 => self.extract_i8x32::<6>(a0),
+            7 => self.extract_i8x32::<7>(a0),
+            8 => self.extract_i8x32::<8>(a0),
+            9 => self.extract_i8x32::<9>(a0),
+            10 => self.extract_i8x32::<10>(a0),
+            11 => self.extract_i8x32::<11>(a0),
+            12 => self.extract_i8x32::<12>(a0),
+            13 => self.extract_i8x32::<13>(a0),
+            14 => self.extract_i8x32::<14>(a0),
+            15 => self.extract_i8x32::<15>(a0),
+            16 => self.extract_i8x32::<16>(a0),
+            17 => self.extract_i8x32::<17>(a0),
+            18 => self.extract_i8x32::<18>(a0),
+            19 => self.extract_i8x32::<19>(a0),
+            20 => self.extract_i8x32::<20>(a0),
+            21 => self.extract_i8x32::<21>(a0),
+            22 => self.extract_i8x32::<22>(a0),
+            23 => self.extract_i8x32::<23>(a0),
+            24 => self.extract_i8x32::<24>(a0),
+            25 => self.extract_i8x32::<25>(a0),
+            26 => self.extract_i8x32::<26>(a0),
+            27 => self.extract_i8x32::<27>(a0),
+            28 => self.extract_i8x32::<28>(a0),
+            29 => self.extract_i8x32::<29>(a0),
+            30 => self.extract_i8x32::<30>(a0),
+            31 => self.extract_i8x32::<31>(a0),
+            32 => self.extract_i8x32::<0>(a1),
+            33 => self.extract_i8x32::<1>(a1),
+            34 => self.extract_i8x32::<2>(a1),
+            35 => self.extract_i8x32::<3>(a1),
+            36 => self.extract_i8x32::<4>(a1),
+            37 => self.extract_i8x32::<5>(a1),
+            38 => self.extract_i8x32::<6>(a1),
+            39 => self.extract_i8x32::<7>(a1),
+            40 => self.extract_i8x32::<8>(a1),
+            41 => self.extract_i8x32::<9>(a1),
+            42 => self.extract_i8x32::<10>(a1),
+            43 => self.extract_i8x32::<11>(a1),
+            44 => self.extract_i8x32::<12>(a1),
+            45 => self.extract_i8x32::<13>(a1),
+            46 => self.extract_i8x32::<14>(a1),
+            47 => self.extract_i8x32::<15>(a1),
+            48 => self.extract_i8x32::<16>(a1),
+            49 => self.extract_i8x32::<17>(a1),
+            50 => self.extract_i8x32::<18>(a1),
+            51 => self.extract_i8x32::<19>(a1),
+            52 => self.extract_i8x32::<20>(a1),
+            53 => self.extract_i8x32::<21>(a1),
+            54 => self.extract_i8x32::<22>(a1),
+            55 => self.extract_i8x32::<23>(a1),
+            56 => self.extract_i8x32::<24>(a1),
+            57 => self.extract_i8x32::<25>(a1),
+            58 => self.extract_i8x32::<26>(a1),
+            59 => self.extract_i8x32::<27>(a1),
+            60 => self.extract_i8x32::<28>(a1),
+            61 => self.extract_i8x32::<29>(a1),
+            62 => self.extract_i8x32::<30>(a1),
+            63 => self.extract_i8x32::<31>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i8x64<const LANE: usize>(self, a: i8x64<Self>, value: i8) -> i8x64<Self> {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x64(a);
+        match LANE {
+            0 => self.combine_i8x32(self.insert_i8x32::<0>(a0, value), a1),
+            1 => self.combine_i8x32(self.insert_i8x32::<1>(a0, value), a1),
+            2 => self.combine_i8x32(self.insert_i8x32::<2>(a0, value), a1),
+            3 => self.combine_i8x32(self.insert_i8x32::<3>(a0, value), a1),
+            4 => self.combine_i8x32(self.insert_i8x32::<4>(a0, value), a1),
+            5 => self.combine_i8x32(self.insert_i8x32::<5>(a0, value), a1),
+            6 => self.combine_i8x32(self.insert_i8x32::<6>(a0, value), a1),
+            7 => self.combine_i8x32(self.insert_i8x32::<7>(a0, value), a1),
+            8 => self.combine_i8x32(self.insert_i8x32::<8>(a0, value), a1),
+            9 => self.combine_i8x32(self.insert_i8x32::<9>(a0, value), a1),
+            10 => self.combine_i8x32(self.insert_i8x32::<10>(a0, value), a1),
+            11 => self.combine_i8x32(self.insert_i8x32::<11>(a0, value), a1),
+            12 => self.combine_i8x32(self.insert_i8x32::<12>(a0, value), a1),
+            13 => self.combine_i8x32(self.insert_i8x32::<13>(a0, value), a1),
+            14 => self.combine_i8x32(self.insert_i8x32::<14>(a0, value), a1),
+            15 => self.combine_i8x32(self.insert_i8x32::<15>(a0, value), a1),
+            16 => self.combine_i8x32(self.insert_i8x32::<16>(a0, value), a1),
+            17 => self.combine_i8x32(self.insert_i8x32::<17>(a0, value), a1),
+            18 => self.combine_i8x32(self.insert_i8x32::<18>(a0, value), a1),
+            19 => self.combine_i8x32(self.insert_i8x32::<19>(a0, value), a1),
+            20 => self.combine_i8x32(self.insert_i8x32::<20>(a0, value), a1),
+            21 => self.combine_i8x32(self.insert_i8x32::<21>(a0, value), a1),
+            22 => self.combine_i8x32(self.insert_i8x32::<22>(a0, value), a1),
+            23 => self.combine_i8x32(self.insert_i8x32::<23>(a0, value), a1),
+            24 => self.combine_i8x32(self.insert_i8x32::<24>(a0, value), a1),
+            25 => self.combine_i8x32(self.insert_i8x32::<25>(a0, value), a1),
+            26 => self.combine_i8x32(self.insert_i8x32::<26>(a0, value), a1),
+            27 => self.combine_i8x32(self.insert_i8x32::<27>(a0, value), a1),
+            28 => self.combine_i8x32(self.insert_i8x32::<28>(a0, value), a1),
+            29 => self.combine_i8x32(self.insert_i8x32::<29>(a0, value), a1),
+            30 => self.combine_i8x32(self.insert_i8x32::<30>(a0, value), a1),
+            31 => self.combine_i8x32(self.insert_i8x32::<31>(a0, value), a1),
+            32 => self.combine_i8x32(a0, self.insert_i8x32::<0>(a1, value)),
+            33 => self.combine_i8x32(a0, self.insert_i8x32::<1>(a1, value)),
+            34 => self.combine_i8x32(a0, self.insert_i8x32::<2>(a1, value)),
+            35 => self.combine_i8x32(a0, self.insert_i8x32::<3>(a1, value)),
+            36 => self.combine_i8x32(a0, self.insert_i8x32::<4>(a1, value)),
+            37 => self.combine_i8x32(a0, self.insert_i8x32::<5>(a1, value)),
+            38 => self.combine_i8x32(a0, self.insert_i8x32::<6>(a1, value)),
+            39 => self.combine_i8x32(a0, self.insert_i8x32::<7>(a1, value)),
+            40 => self.combine_i8x32(a0, self.insert_i8x32::<8>(a1, value)),
+            41 => self.combine_i8x32(a0, self.insert_i8x32::<9>(a1, value)),
+            42 => self.combine_i8x32(a0, self.insert_i8x32::<10>(a1, value)),
+            43 => self.combine_i8x32(a0, self.insert_i8x32::<11>(a1, value)),
+            44 => self.combine_i8x32(a0, self.insert_i8x32::<12>(a1, value)),
+            45 => self.combine_i8x32(a0, self.insert_i8x32::<13>(a1, value)),
+            46 => self.combine_i8x32(a0, self.insert_i8x32::<14>(a1, value)),
+            47 => self.combine_i8x32(a0, self.insert_i8x32::<15>(a1, value)),
+            48 => self.combine_i8x32(a0, self.insert_i8x32::<16>(a1, value)),
+            49 => self.combine_i8x32(a0, self.insert_i8x32::<17>(a1, value)),
+            50 => self.combine_i8x32(a0, self.insert_i8x32::<18>(a1, value)),
+            51 => self.combine_i8x32(a0, self.insert_i8x32::<19>(a1, value)),
+            52 => self.combine_i8x32(a0, self.insert_i8x32::<20>(a1, value)),
+            53 => self.combine_i8x32(a0, self.insert_i8x32::<21>(a1, value)),
+            54 => self.combine_i8x32(a0, self.insert_i8x32::<22>(a1, value)),
+            55 => self.combine_i8x32(a0, self.insert_i8x32::<23>(a1, value)),
+            56 => self.combine_i8x32(a0, self.insert_i8x32::<24>(a1, value)),
+            57 => self.combine_i8x32(a0, self.insert_i8x32::<25>(a1, value)),
+            58 => self.combine_i8x32(a0, self.insert_i8x32::<26>(a1, value)),
+            59 => self.combine_i8x32(a0, self.insert_i8x32::<27>(a1, value)),
+            60 => self.combine_i8x32(a0, self.insert_i8x32::<28>(a1, value)),
+            61 => self.combine_i8x32(a0, self.insert_i8x32::<29>(a1, value)),
+            62 => self.combine_i8x32(a0, self.insert_i8x32::<30>(a1, value)),
+            63 => self.combine_i8x32(a0, self.insert_i8x32::<31>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x64(self, a: i8x64<Self>, index: usize) -> i8 {
+        assert!(
+            index < 64usize,
+            "lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        self.as_array_ref_i8x64(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i8x64(self, a: i8x64<Self>, indices: u8x64<Self>) -> i8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -11445,6 +15099,15 @@ impl Simd for Fallback {
         self.combine_i8x32(self.shrv_i8x32(a0, b0), self.shrv_i8x32(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.shift_signed_i8x32(a0, b0),
+            self.shift_signed_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         let (b0, b1) = self.split_i8x64(b);
@@ -11532,6 +15195,21 @@ impl Simd for Fallback {
         self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i8x64(
+        self,
+        a: mask8x64<Self>,
+        b: i8x64<Self>,
+        c: i8x64<Self>,
+    ) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(
+            self.select_relaxed_i8x32(a0, b0, c0),
+            self.select_relaxed_i8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         let (b0, b1) = self.split_i8x64(b);
@@ -11544,6 +15222,39 @@ impl Simd for Fallback {
         self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_add_i8x32(a0, b0),
+            self.saturating_add_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_sub_i8x32(a0, b0),
+            self.saturating_sub_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.count_ones_i8x32(a0), self.count_ones_i8x32(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.leading_zeros_i8x32(a0), self.leading_zeros_i8x32(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.trailing_zeros_i8x32(a0), self.trailing_zeros_i8x32(a1))
+    }
+    #[inline(always)]
     fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
         let mut b0 = [0; 32usize];
         let mut b1 = [0; 32usize];
@@ -11557,6 +15268,11 @@ impl Simd for Fallback {
         self.combine_i8x32(self.neg_i8x32(a0), self.neg_i8x32(a1))
     }
     #[inline(always)]
+    fn reduce_add_widened_i8x64(self, a: i8x64<Self>) -> i32 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_add_widened_i8x32(a0) + self.reduce_add_widened_i8x32(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
@@ -11620,6 +15336,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u8x64<const SHIFT: usize>(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        if SHIFT >= 64usize {
+            return b;
+        }
         let mut dest = [Default::default(); 64usize];
         dest[..64usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[64usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -11931,6 +15650,169 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u8x64<const LANE: usize>(self, a: u8x64<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x64(a);
+        match LANE {
+            0 => self.extract_u8x32::<0>(a0),
+            1 => self.extract_u8x32::<1>(a0),
+            2 => self.extract_u8x32::<2>(a0),
+            3 => self.extract_u8x32::<3>(a0),
+            4 => self.extract_u8x32::<4>(a0),
+            5 => self.extract_u8x32::<5>(a0),
+            6 => self.extract_u8x32::<6>(a0),
+            7 => self.extract_u8x32::<7>(a0),
+            8 => self.extract_u8x32::<8>(a0),
+            9 => self.extract_u8x32::<9>(a0),
+            10 => self.extract_u8x32::<10>(a0),
+            11 => self.extract_u8x32::<11>(a0),
+            12 => self.extract_u8x32::<12>(a0),
+            13 => self.extract_u8x32::<13>(a0),
+            14 => self.extract_u8x32::<14>(a0),
+            15 => self.extract_u8x32::<15>(a0),
+            16 => self.extract_u8x32::<16>(a0),
+            17 => self.extract_u8x32::<17>(a0),
+            18 => self.extract_u8x32::<18>(a0),
+            19 => self.extract_u8x32::<19>(a0),
+            20 => self.extract_u8x32::<20>(a0),
+            21 => self.extract_u8x32::<21>(a0),
+            22 => self.extract_u8x32::<22>(a0),
+            23 => self.extract_u8x32::<23>(a0),
+            24 => self.extract_u8x32::<24>(a0),
+            25 => self.extract_u8x32::<25>(a0),
+            26 => self.extract_u8x32::<26>(a0),
+            27 => self.extract_u8x32::<27>(a0),
+            28 => self.extract_u8x32::<28>(a0),
+            29 => self.extract_u8x32::<29>(a0),
+            30 => self.extract_u8x32::<30>(a0),
+            31 => self.extract_u8x32::<31>(a0),
+            32 => self.extract_u8x32::<0>(a1),
+            33 => self.extract_u8x32::<1>(a1),
+            34 => self.extract_u8x32::<2>(a1),
+            35 => self.extract_u8x32::<3>(a1),
+            36 => self.extract_u8x32::<4>(a1),
+            37 => self.extract_u8x32::<5>(a1),
+            38 => self.extract_u8x32::<6>(a1),
+            39 => self.extract_u8x32::<7>(a1),
+            40 => self.extract_u8x32::<8>(a1),
+            41 => self.extract_u8x32::<9>(a1),
+            42 => self.extract_u8x32::<10>(a1),
+            43 => self.extract_u8x32::<11>(a1),
+            44 => self.extract_u8x32::<12>(a1),
+            45 => self.extract_u8x32::<13>(a1),
+            46 => self.extract_u8x32::<14>(a1),
+            47 => self.extract_u8x32::<15>(a1),
+            48 => self.extract_u8x32::<16>(a1),
+            49 => self.extract_u8x32::<17>(a1),
+            50 => self.extract_u8x32::<18>(a1),
+            51 => self.extract_u8x32::<19>(a1),
+            52 => self.extract_u8x32::<20>(a1),
+            53 => self.extract_u8x32::<21>(a1),
+            54 => self.extract_u8x32::<22>(a1),
+            55 => self.extract_u8x32::<23>(a1),
+            56 => self.extract_u8x32::<24>(a1),
+            57 => self.extract_u8x32::<25>(a1),
+            58 => self.extract_u8x32::<26>(a1),
+            59 => self.extract_u8x32::<27>(a1),
+            60 => self.extract_u8x32::<28>(a1),
+            61 => self.extract_u8x32::<29>(a1),
+            62 => self.extract_u8x32::<30>(a1),
+            63 => self.extract_u8x32::<31>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u8x64<const LANE: usize>(self, a: u8x64<Self>, value: u8) -> u8x64<Self> {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x64(a);
+        match LANE {
+            0 => self.combine_u8x32(self.insert_u8x32::<0>(a0, value), a1),
+            1 => self.combine_u8x32(self.insert_u8x32::<1>(a0, value), a1),
+            2 => self.combine_u8x32(self.insert_u8x32::<2>(a0, value), a1),
+            3 => self.combine_u8x32(self.insert_u8x32::<3>(a0, value), a1),
+            4 => self.combine_u8x32(self.insert_u8x32::<4>(a0, value), a1),
+            5 => self.combine_u8x32(self.insert_u8x32::<5>(a0, value), a1),
+            6 => self.combine_u8x32(self.insert_u8x32::<6>(a0, value), a1),
+            7 => self.combine_u8x32(self.insert_u8x32::<7>(a0, value), a1),
+            8 => self.combine_u8x32(self.insert_u8x32::<8>(a0, value), a1),
+            9 => self.combine_u8x32(self.insert_u8x32::<9>(a0, value), a1),
+            10 => self.combine_u8x32(self.insert_u8x32::<10>(a0, value), a1),
+            11 => self.combine_u8x32(self.insert_u8x32::<11>(a0, value), a1),
+            12 => self.combine_u8x32(self.insert_u8x32::<12>(a0, value), a1),
+            13 => self.combine_u8x32(self.insert_u8x32::<13>(a0, value), a1),
+            14 => self.combine_u8x32(self.insert_u8x32::<14>(a0, value), a1),
+            15 => self.combine_u8x32(self.insert_u8x32::<15>(a0, value), a1),
+            16 => self.combine_u8x32(self.insert_u8x32::<16>(a0, value), a1),
+            17 => self.combine_u8x32(self.insert_u8x32::<17>(a0, value), a1),
+            18 => self.combine_u8x32(self.insert_u8x32::<18>(a0, value), a1),
+            19 => self.combine_u8x32(self.insert_u8x32::<19>(a0, value), a1),
+            20 => self.combine_u8x32(self.insert_u8x32::<20>(a0, value), a1),
+            21 => self.combine_u8x32(self.insert_u8x32::<21>(a0, value), a1),
+            22 => self.combine_u8x32(self.insert_u8x32::<22>(a0, value), a1),
+            23 => self.combine_u8x32(self.insert_u8x32::<23>(a0, value), a1),
+            24 => self.combine_u8x32(self.insert_u8x32::<24>(a0, value), a1),
+            25 => self.combine_u8x32(self.insert_u8x32::<25>(a0, value), a1),
+            26 => self.combine_u8x32(self.insert_u8x32::<26>(a0, value), a1),
+            27 => self.combine_u8x32(self.insert_u8x32::<27>(a0, value), a1),
+            28 => self.combine_u8x32(self.insert_u8x32::<28>(a0, value), a1),
+            29 => self.combine_u8x32(self.insert_u8x32::<29>(a0, value), a1),
+            30 => self.combine_u8x32(self.insert_u8x32::<30>(a0, value), a1),
+            31 => self.combine_u8x32(self.insert_u8x32::<31>(a0, value), a1),
+            32 => self.combine_u8x32(a0, self.insert_u8x32::<0>(a1, value)),
+            33 => self.combine_u8x32(a0, self.insert_u8x32::<1>(a1, value)),
+            34 => self.combine_u8x32(a0, self.insert_u8x32::<2>(a1, value)),
+            35 => self.combine_u8x32(a0, self.insert_u8x32::<3>(a1, value)),
+            36 => self.combine_u8x32(a0, self.insert_u8x32::<4>(a1, value)),
+            37 => self.combine_u8x32(a0, self.insert_u8x32::<5>(a1, value)),
+            38 => self.combine_u8x32(a0, self.insert_u8x32::<6>(a1, value)),
+            39 => self.combine_u8x32(a0, self.insert_u8x32::<7>(a1, value)),
+            40 => self.combine_u8x32(a0, self.insert_u8x32::<8>(a1, value)),
+            41 => self.combine_u8x32(a0, self.insert_u8x32::<9>(a1, value)),
+            42 => self.combine_u8x32(a0, self.insert_u8x32::<10>(a1, value)),
+            43 => self.combine_u8x32(a0, self.insert_u8x32::<11>(a1, value)),
+            44 => self.combine_u8x32(a0, self.insert_u8x32::<12>(a1, value)),
+            45 => self.combine_u8x32(a0, self.insert_u8x32::<13>(a1, value)),
+            46 => self.combine_u8x32(a0, self.insert_u8x32::<14>(a1, value)),
+            47 => self.combine_u8x32(a0, self.insert_u8x32::<15>(a1, value)),
+            48 => self.combine_u8x32(a0, self.insert_u8x32::<16>(a1, value)),
+            49 => self.combine_u8x32(a0, self.insert_u8x32::<17>(a1, value)),
+            50 => self.combine_u8x32(a0, self.insert_u8x32::<18>(a1, value)),
+            51 => self.combine_u8x32(a0, self.insert_u8x32::<19>(a1, value)),
+            52 => self.combine_u8x32(a0, self.insert_u8x32::<20>(a1, value)),
+            53 => self.combine_u8x32(a0, self.insert_u8x32::<21>(a1, value)),
+            54 => self.combine_u8x32(a0, self.insert_u8x32::<22>(a1, value)),
+            55 => self.combine_u8x32(a0, self.insert_u8x32::<23>(a1, value)),
+            56 => self.combine_u8x32(a0, self.insert_u8x32::<24>(a1, value)),
+            57 => self.combine_u8x32(a0, self.insert_u8x32::<25>(a1, value)),
+            58 => self.combine_u8x32(a0, self.insert_u8x32::<26>(a1, value)),
+            59 => self.combine_u8x32(a0, self.insert_u8x32::<27>(a1, value)),
+            60 => self.combine_u8x32(a0, self.insert_u8x32::<28>(a1, value)),
+            61 => self.combine_u8x32(a0, self.insert_u8x32::<29>(a1, value)),
+            62 => self.combine_u8x32(a0, self.insert_u8x32::<30>(a1, value)),
+            63 => self.combine_u8x32(a0, self.insert_u8x32::<31>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x64(self, a: u8x64<Self>, index: usize) -> u8 {
+        assert!(
+            index < 64usize,
+            "lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        self.as_array_ref_u8x64(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u8x64(self, a: u8x64<Self>, indices: u8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -12003,6 +15885,15 @@ impl Simd for Fallback {
         self.combine_u8x32(self.shrv_u8x32(a0, b0), self.shrv_u8x32(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u8x64(self, a: u8x64<Self>, b: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_u8x32(
+            self.shift_signed_u8x32(a0, b0),
+            self.shift_signed_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
         let (b0, b1) = self.split_u8x64(b);
@@ -12090,6 +15981,21 @@ impl Simd for Fallback {
         self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u8x64(
+        self,
+        a: mask8x64<Self>,
+        b: u8x64<Self>,
+        c: u8x64<Self>,
+    ) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(
+            self.select_relaxed_u8x32(a0, b0, c0),
+            self.select_relaxed_u8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
         let (b0, b1) = self.split_u8x64(b);
@@ -12102,6 +16008,39 @@ impl Simd for Fallback {
         self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_add_u8x32(a0, b0),
+            self.saturating_add_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_sub_u8x32(a0, b0),
+            self.saturating_sub_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.count_ones_u8x32(a0), self.count_ones_u8x32(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.leading_zeros_u8x32(a0), self.leading_zeros_u8x32(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.trailing_zeros_u8x32(a0), self.trailing_zeros_u8x32(a1))
+    }
+    #[inline(always)]
     fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
         let mut b0 = [0; 32usize];
         let mut b1 = [0; 32usize];
@@ -12195,14 +16134,68 @@ impl Simd for Fallback {
         ];
     }
     #[inline(always)]
-    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.avg_round_u8x32(a0, b0), self.avg_round_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x64(self, a: u8x64<Self>) -> u32 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_add_widened_u8x32(a0) + self.reduce_add_widened_u8x32(a1)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u8x32(a0),
+            self.reinterpret_u32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn compress_bytes_u8x64(self, a: u8x64<Self>, mask: mask8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (mask0, mask1) = self.split_mask8x64(mask);
+        let count = self.to_bitmask_mask8x32(mask0).count_ones() as usize;
+        let mut result = [0; 64];
+        result[..32].copy_from_slice(&self.as_array_u8x32(self.compress_bytes_u8x32(a0, mask0)));
+        result[count..count + 32]
+            .copy_from_slice(&self.as_array_u8x32(self.compress_bytes_u8x32(a1, mask1)));
+        self.load_array_u8x64(result)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x64(self, a: u8x64<Self>, mask: mask8x64<Self>) -> u8x64<Self> {
+        let (mask0, mask1) = self.split_mask8x64(mask);
+        let count = self.to_bitmask_mask8x32(mask0).count_ones() as usize;
+        let lanes = self.as_array_u8x64(a);
+        let (a0, _) = self.split_u8x64(a);
+        let a1 = self.load_array_u8x32(core::array::from_fn(|i| lanes[count + i]));
+        self.combine_u8x32(
+            self.expand_bytes_u8x32(a0, mask0),
+            self.expand_bytes_u8x32(a1, mask1),
+        )
+    }
+    #[inline(always)]
+    fn table_lookup_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
-        self.combine_u32x8(
-            self.reinterpret_u32_u8x32(a0),
-            self.reinterpret_u32_u8x32(a1),
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.table_lookup_u8x32(a0, b0),
+            self.table_lookup_u8x32(a1, b1),
         )
     }
     #[inline(always)]
+    fn gf_mul_const_u8x64<const FACTOR: u8>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<64>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x64(
+            self.load_array_u8x64(low),
+            self.and_u8x64(a, self.splat_u8x64(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x64(self.load_array_u8x64(high), self.shr_u8x64(a, 4));
+        self.xor_u8x64(low, high)
+    }
+    #[inline(always)]
     fn splat_mask8x64(self, val: bool) -> mask8x64<Self> {
         let half = self.splat_mask8x32(val);
         self.combine_mask8x32(half, half)
@@ -12219,6 +16212,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self> {
+        i8x64 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self> {
         let lo = self.from_bitmask_mask8x32(bits);
         let hi = self.from_bitmask_mask8x32(bits >> 32usize);
@@ -12281,6 +16281,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_relaxed_mask8x32(a0, b0, c0),
+            self.select_relaxed_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
         let (a0, a1) = self.split_mask8x64(a);
         let (b0, b1) = self.split_mask8x64(b);
@@ -12365,6 +16380,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i16x32<const SHIFT: usize>(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
         let mut dest = [Default::default(); 32usize];
         dest[..32usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[32usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -12548,6 +16566,105 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i16x32<const LANE: usize>(self, a: i16x32<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x32(a);
+        match LANE {
+            0 => self.extract_i16x16::<0>(a0),
+            1 => self.extract_i16x16::<1>(a0),
+            2 => self.extract_i16x16::<2>(a0),
+            3 => self.extract_i16x16::<3>(a0),
+            4 => self.extract_i16x16::<4>(a0),
+            5 => self.extract_i16x16::<5>(a0),
+            6 => self.extract_i16x16::<6>(a0),
+            7 => self.extract_i16x16::<7>(a0),
+            8 => self.extract_i16x16::<8>(a0),
+            9 => self.extract_i16x16::<9>(a0),
+            10 => self.extract_i16x16::<10>(a0),
+            11 => self.extract_i16x16::<11>(a0),
+            12 => self.extract_i16x16::<12>(a0),
+            13 => self.extract_i16x16::<13>(a0),
+            14 => self.extract_i16x16::<14>(a0),
+            15 => self.extract_i16x16::<15>(a0),
+            16 => self.extract_i16x16::<0>(a1),
+            17 => self.extract_i16x16::<1>(a1),
+            18 => self.extract_i16x16::<2>(a1),
+            19 => self.extract_i16x16::<3>(a1),
+            20 => self.extract_i16x16::<4>(a1),
+            21 => self.extract_i16x16::<5>(a1),
+            22 => self.extract_i16x16::<6>(a1),
+            23 => self.extract_i16x16::<7>(a1),
+            24 => self.extract_i16x16::<8>(a1),
+            25 => self.extract_i16x16::<9>(a1),
+            26 => self.extract_i16x16::<10>(a1),
+            27 => self.extract_i16x16::<11>(a1),
+            28 => self.extract_i16x16::<12>(a1),
+            29 => self.extract_i16x16::<13>(a1),
+            30 => self.extract_i16x16::<14>(a1),
+            31 => self.extract_i16x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i16x32<const LANE: usize>(self, a: i16x32<Self>, value: i16) -> i16x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x32(a);
+        match LANE {
+            0 => self.combine_i16x16(self.insert_i16x16::<0>(a0, value), a1),
+            1 => self.combine_i16x16(self.insert_i16x16::<1>(a0, value), a1),
+            2 => self.combine_i16x16(self.insert_i16x16::<2>(a0, value), a1),
+            3 => self.combine_i16x16(self.insert_i16x16::<3>(a0, value), a1),
+            4 => self.combine_i16x16(self.insert_i16x16::<4>(a0, value), a1),
+            5 => self.combine_i16x16(self.insert_i16x16::<5>(a0, value), a1),
+            6 => self.combine_i16x16(self.insert_i16x16::<6>(a0, value), a1),
+            7 => self.combine_i16x16(self.insert_i16x16::<7>(a0, value), a1),
+            8 => self.combine_i16x16(self.insert_i16x16::<8>(a0, value), a1),
+            9 => self.combine_i16x16(self.insert_i16x16::<9>(a0, value), a1),
+            10 => self.combine_i16x16(self.insert_i16x16::<10>(a0, value), a1),
+            11 => self.combine_i16x16(self.insert_i16x16::<11>(a0, value), a1),
+            12 => self.combine_i16x16(self.insert_i16x16::<12>(a0, value), a1),
+            13 => self.combine_i16x16(self.insert_i16x16::<13>(a0, value), a1),
+            14 => self.combine_i16x16(self.insert_i16x16::<14>(a0, value), a1),
+            15 => self.combine_i16x16(self.insert_i16x16::<15>(a0, value), a1),
+            16 => self.combine_i16x16(a0, self.insert_i16x16::<0>(a1, value)),
+            17 => self.combine_i16x16(a0, self.insert_i16x16::<1>(a1, value)),
+            18 => self.combine_i16x16(a0, self.insert_i16x16::<2>(a1, value)),
+            19 => self.combine_i16x16(a0, self.insert_i16x16::<3>(a1, value)),
+            20 => self.combine_i16x16(a0, self.insert_i16x16::<4>(a1, value)),
+            21 => self.combine_i16x16(a0, self.insert_i16x16::<5>(a1, value)),
+            22 => self.combine_i16x16(a0, self.insert_i16x16::<6>(a1, value)),
+            23 => self.combine_i16x16(a0, self.insert_i16x16::<7>(a1, value)),
+            24 => self.combine_i16x16(a0, self.insert_i16x16::<8>(a1, value)),
+            25 => self.combine_i16x16(a0, self.insert_i16x16::<9>(a1, value)),
+            26 => self.combine_i16x16(a0, self.insert_i16x16::<10>(a1, value)),
+            27 => self.combine_i16x16(a0, self.insert_i16x16::<11>(a1, value)),
+            28 => self.combine_i16x16(a0, self.insert_i16x16::<12>(a1, value)),
+            29 => self.combine_i16x16(a0, self.insert_i16x16::<13>(a1, value)),
+            30 => self.combine_i16x16(a0, self.insert_i16x16::<14>(a1, value)),
+            31 => self.combine_i16x16(a0, self.insert_i16x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x32(self, a: i16x32<Self>, index: usize) -> i16 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_i16x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i16x32(
         self,
         a: i16x32<Self>,
@@ -12624,6 +16741,15 @@ impl Simd for Fallback {
         self.combine_i16x16(self.shrv_i16x16(a0, b0), self.shrv_i16x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.shift_signed_i16x16(a0, b0),
+            self.shift_signed_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
         let (a0, a1) = self.split_i16x32(a);
         let (b0, b1) = self.split_i16x32(b);
@@ -12717,6 +16843,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_i16x32(
+        self,
+        a: mask16x32<Self>,
+        b: i16x32<Self>,
+        c: i16x32<Self>,
+    ) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_relaxed_i16x16(a0, b0, c0),
+            self.select_relaxed_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
         let (a0, a1) = self.split_i16x32(a);
         let (b0, b1) = self.split_i16x32(b);
@@ -12729,6 +16870,42 @@ impl Simd for Fallback {
         self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_add_i16x16(a0, b0),
+            self.saturating_add_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_sub_i16x16(a0, b0),
+            self.saturating_sub_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.count_ones_i16x16(a0), self.count_ones_i16x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.leading_zeros_i16x16(a0), self.leading_zeros_i16x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(
+            self.trailing_zeros_i16x16(a0),
+            self.trailing_zeros_i16x16(a1),
+        )
+    }
+    #[inline(always)]
     fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
         let mut b0 = [0; 16usize];
         let mut b1 = [0; 16usize];
@@ -12742,6 +16919,27 @@ impl Simd for Fallback {
         self.combine_i16x16(self.neg_i16x16(a0), self.neg_i16x16(a1))
     }
     #[inline(always)]
+    fn pack_saturate_i8_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(
+            self.pack_saturate_i8_i16x16(a0),
+            self.pack_saturate_i8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_i16x32(self, a: i16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u8x16(
+            self.pack_saturate_u8_i16x16(a0),
+            self.pack_saturate_u8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x32(self, a: i16x32<Self>) -> i32 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_add_widened_i16x16(a0) + self.reduce_add_widened_i16x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_i16x32(a);
         self.combine_u8x32(
@@ -12808,6 +17006,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u16x32<const SHIFT: usize>(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        if SHIFT >= 32usize {
+            return b;
+        }
         let mut dest = [Default::default(); 32usize];
         dest[..32usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[32usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -12991,6 +17192,105 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u16x32<const LANE: usize>(self, a: u16x32<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x32(a);
+        match LANE {
+            0 => self.extract_u16x16::<0>(a0),
+            1 => self.extract_u16x16::<1>(a0),
+            2 => self.extract_u16x16::<2>(a0),
+            3 => self.extract_u16x16::<3>(a0),
+            4 => self.extract_u16x16::<4>(a0),
+            5 => self.extract_u16x16::<5>(a0),
+            6 => self.extract_u16x16::<6>(a0),
+            7 => self.extract_u16x16::<7>(a0),
+            8 => self.extract_u16x16::<8>(a0),
+            9 => self.extract_u16x16::<9>(a0),
+            10 => self.extract_u16x16::<10>(a0),
+            11 => self.extract_u16x16::<11>(a0),
+            12 => self.extract_u16x16::<12>(a0),
+            13 => self.extract_u16x16::<13>(a0),
+            14 => self.extract_u16x16::<14>(a0),
+            15 => self.extract_u16x16::<15>(a0),
+            16 => self.extract_u16x16::<0>(a1),
+            17 => self.extract_u16x16::<1>(a1),
+            18 => self.extract_u16x16::<2>(a1),
+            19 => self.extract_u16x16::<3>(a1),
+            20 => self.extract_u16x16::<4>(a1),
+            21 => self.extract_u16x16::<5>(a1),
+            22 => self.extract_u16x16::<6>(a1),
+            23 => self.extract_u16x16::<7>(a1),
+            24 => self.extract_u16x16::<8>(a1),
+            25 => self.extract_u16x16::<9>(a1),
+            26 => self.extract_u16x16::<10>(a1),
+            27 => self.extract_u16x16::<11>(a1),
+            28 => self.extract_u16x16::<12>(a1),
+            29 => self.extract_u16x16::<13>(a1),
+            30 => self.extract_u16x16::<14>(a1),
+            31 => self.extract_u16x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u16x32<const LANE: usize>(self, a: u16x32<Self>, value: u16) -> u16x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x32(a);
+        match LANE {
+            0 => self.combine_u16x16(self.insert_u16x16::<0>(a0, value), a1),
+            1 => self.combine_u16x16(self.insert_u16x16::<1>(a0, value), a1),
+            2 => self.combine_u16x16(self.insert_u16x16::<2>(a0, value), a1),
+            3 => self.combine_u16x16(self.insert_u16x16::<3>(a0, value), a1),
+            4 => self.combine_u16x16(self.insert_u16x16::<4>(a0, value), a1),
+            5 => self.combine_u16x16(self.insert_u16x16::<5>(a0, value), a1),
+            6 => self.combine_u16x16(self.insert_u16x16::<6>(a0, value), a1),
+            7 => self.combine_u16x16(self.insert_u16x16::<7>(a0, value), a1),
+            8 => self.combine_u16x16(self.insert_u16x16::<8>(a0, value), a1),
+            9 => self.combine_u16x16(self.insert_u16x16::<9>(a0, value), a1),
+            10 => self.combine_u16x16(self.insert_u16x16::<10>(a0, value), a1),
+            11 => self.combine_u16x16(self.insert_u16x16::<11>(a0, value), a1),
+            12 => self.combine_u16x16(self.insert_u16x16::<12>(a0, value), a1),
+            13 => self.combine_u16x16(self.insert_u16x16::<13>(a0, value), a1),
+            14 => self.combine_u16x16(self.insert_u16x16::<14>(a0, value), a1),
+            15 => self.combine_u16x16(self.insert_u16x16::<15>(a0, value), a1),
+            16 => self.combine_u16x16(a0, self.insert_u16x16::<0>(a1, value)),
+            17 => self.combine_u16x16(a0, self.insert_u16x16::<1>(a1, value)),
+            18 => self.combine_u16x16(a0, self.insert_u16x16::<2>(a1, value)),
+            19 => self.combine_u16x16(a0, self.insert_u16x16::<3>(a1, value)),
+            20 => self.combine_u16x16(a0, self.insert_u16x16::<4>(a1, value)),
+            21 => self.combine_u16x16(a0, self.insert_u16x16::<5>(a1, value)),
+            22 => self.combine_u16x16(a0, self.insert_u16x16::<6>(a1, value)),
+            23 => self.combine_u16x16(a0, self.insert_u16x16::<7>(a1, value)),
+            24 => self.combine_u16x16(a0, self.insert_u16x16::<8>(a1, value)),
+            25 => self.combine_u16x16(a0, self.insert_u16x16::<9>(a1, value)),
+            26 => self.combine_u16x16(a0, self.insert_u16x16::<10>(a1, value)),
+            27 => self.combine_u16x16(a0, self.insert_u16x16::<11>(a1, value)),
+            28 => self.combine_u16x16(a0, self.insert_u16x16::<12>(a1, value)),
+            29 => self.combine_u16x16(a0, self.insert_u16x16::<13>(a1, value)),
+            30 => self.combine_u16x16(a0, self.insert_u16x16::<14>(a1, value)),
+            31 => self.combine_u16x16(a0, self.insert_u16x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x32(self, a: u16x32<Self>, index: usize) -> u16 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_u16x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u16x32(
         self,
         a: u16x32<Self>,
@@ -13067,6 +17367,15 @@ impl Simd for Fallback {
         self.combine_u16x16(self.shrv_u16x16(a0, b0), self.shrv_u16x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u16x32(self, a: u16x32<Self>, b: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_u16x16(
+            self.shift_signed_u16x16(a0, b0),
+            self.shift_signed_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
         let (a0, a1) = self.split_u16x32(a);
         let (b0, b1) = self.split_u16x32(b);
@@ -13160,6 +17469,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_u16x32(
+        self,
+        a: mask16x32<Self>,
+        b: u16x32<Self>,
+        c: u16x32<Self>,
+    ) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_relaxed_u16x16(a0, b0, c0),
+            self.select_relaxed_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
         let (a0, a1) = self.split_u16x32(a);
         let (b0, b1) = self.split_u16x32(b);
@@ -13172,6 +17496,42 @@ impl Simd for Fallback {
         self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_add_u16x16(a0, b0),
+            self.saturating_add_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_sub_u16x16(a0, b0),
+            self.saturating_sub_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.count_ones_u16x16(a0), self.count_ones_u16x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.leading_zeros_u16x16(a0), self.leading_zeros_u16x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(
+            self.trailing_zeros_u16x16(a0),
+            self.trailing_zeros_u16x16(a1),
+        )
+    }
+    #[inline(always)]
     fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
         let mut b0 = [0; 16usize];
         let mut b1 = [0; 16usize];
@@ -13233,6 +17593,33 @@ impl Simd for Fallback {
         self.combine_u8x16(self.narrow_u16x16(a0), self.narrow_u16x16(a1))
     }
     #[inline(always)]
+    fn pack_saturate_i8_u16x32(self, a: u16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i8x16(
+            self.pack_saturate_i8_u16x16(a0),
+            self.pack_saturate_i8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(
+            self.pack_saturate_u8_u16x16(a0),
+            self.pack_saturate_u8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.avg_round_u16x16(a0, b0), self.avg_round_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x32(self, a: u16x32<Self>) -> u32 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_add_widened_u16x16(a0) + self.reduce_add_widened_u16x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u16x32(a);
         self.combine_u8x32(
@@ -13265,6 +17652,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self> {
+        i16x32 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self> {
         let lo = self.from_bitmask_mask16x16(bits);
         let hi = self.from_bitmask_mask16x16(bits >> 16usize);
@@ -13327,6 +17721,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_relaxed_mask16x16(a0, b0, c0),
+            self.select_relaxed_mask16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
         let (a0, a1) = self.split_mask16x32(a);
         let (b0, b1) = self.split_mask16x32(b);
@@ -13414,6 +17823,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i32x16<const SHIFT: usize>(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -13533,6 +17945,73 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i32x16<const LANE: usize>(self, a: i32x16<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x16(a);
+        match LANE {
+            0 => self.extract_i32x8::<0>(a0),
+            1 => self.extract_i32x8::<1>(a0),
+            2 => self.extract_i32x8::<2>(a0),
+            3 => self.extract_i32x8::<3>(a0),
+            4 => self.extract_i32x8::<4>(a0),
+            5 => self.extract_i32x8::<5>(a0),
+            6 => self.extract_i32x8::<6>(a0),
+            7 => self.extract_i32x8::<7>(a0),
+            8 => self.extract_i32x8::<0>(a1),
+            9 => self.extract_i32x8::<1>(a1),
+            10 => self.extract_i32x8::<2>(a1),
+            11 => self.extract_i32x8::<3>(a1),
+            12 => self.extract_i32x8::<4>(a1),
+            13 => self.extract_i32x8::<5>(a1),
+            14 => self.extract_i32x8::<6>(a1),
+            15 => self.extract_i32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i32x16<const LANE: usize>(self, a: i32x16<Self>, value: i32) -> i32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x16(a);
+        match LANE {
+            0 => self.combine_i32x8(self.insert_i32x8::<0>(a0, value), a1),
+            1 => self.combine_i32x8(self.insert_i32x8::<1>(a0, value), a1),
+            2 => self.combine_i32x8(self.insert_i32x8::<2>(a0, value), a1),
+            3 => self.combine_i32x8(self.insert_i32x8::<3>(a0, value), a1),
+            4 => self.combine_i32x8(self.insert_i32x8::<4>(a0, value), a1),
+            5 => self.combine_i32x8(self.insert_i32x8::<5>(a0, value), a1),
+            6 => self.combine_i32x8(self.insert_i32x8::<6>(a0, value), a1),
+            7 => self.combine_i32x8(self.insert_i32x8::<7>(a0, value), a1),
+            8 => self.combine_i32x8(a0, self.insert_i32x8::<0>(a1, value)),
+            9 => self.combine_i32x8(a0, self.insert_i32x8::<1>(a1, value)),
+            10 => self.combine_i32x8(a0, self.insert_i32x8::<2>(a1, value)),
+            11 => self.combine_i32x8(a0, self.insert_i32x8::<3>(a1, value)),
+            12 => self.combine_i32x8(a0, self.insert_i32x8::<4>(a1, value)),
+            13 => self.combine_i32x8(a0, self.insert_i32x8::<5>(a1, value)),
+            14 => self.combine_i32x8(a0, self.insert_i32x8::<6>(a1, value)),
+            15 => self.combine_i32x8(a0, self.insert_i32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x16(self, a: i32x16<Self>, index: usize) -> i32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i32x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i32x16(
         self,
         a: i32x16<Self>,
@@ -13609,6 +18088,15 @@ impl Simd for Fallback {
         self.combine_i32x8(self.shrv_i32x8(a0, b0), self.shrv_i32x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.shift_signed_i32x8(a0, b0),
+            self.shift_signed_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
         let (a0, a1) = self.split_i32x16(a);
         let (b0, b1) = self.split_i32x16(b);
@@ -13696,6 +18184,21 @@ impl Simd for Fallback {
         self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i32x16(
+        self,
+        a: mask32x16<Self>,
+        b: i32x16<Self>,
+        c: i32x16<Self>,
+    ) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(
+            self.select_relaxed_i32x8(a0, b0, c0),
+            self.select_relaxed_i32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
         let (a0, a1) = self.split_i32x16(a);
         let (b0, b1) = self.split_i32x16(b);
@@ -13708,6 +18211,39 @@ impl Simd for Fallback {
         self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.saturating_add_i32x8(a0, b0),
+            self.saturating_add_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.saturating_sub_i32x8(a0, b0),
+            self.saturating_sub_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.count_ones_i32x8(a0), self.count_ones_i32x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.leading_zeros_i32x8(a0), self.leading_zeros_i32x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.trailing_zeros_i32x8(a0), self.trailing_zeros_i32x8(a1))
+    }
+    #[inline(always)]
     fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
         let mut b0 = [0; 8usize];
         let mut b1 = [0; 8usize];
@@ -13789,6 +18325,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u32x16<const SHIFT: usize>(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        if SHIFT >= 16usize {
+            return b;
+        }
         let mut dest = [Default::default(); 16usize];
         dest[..16usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[16usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -13908,6 +18447,73 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u32x16<const LANE: usize>(self, a: u32x16<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x16(a);
+        match LANE {
+            0 => self.extract_u32x8::<0>(a0),
+            1 => self.extract_u32x8::<1>(a0),
+            2 => self.extract_u32x8::<2>(a0),
+            3 => self.extract_u32x8::<3>(a0),
+            4 => self.extract_u32x8::<4>(a0),
+            5 => self.extract_u32x8::<5>(a0),
+            6 => self.extract_u32x8::<6>(a0),
+            7 => self.extract_u32x8::<7>(a0),
+            8 => self.extract_u32x8::<0>(a1),
+            9 => self.extract_u32x8::<1>(a1),
+            10 => self.extract_u32x8::<2>(a1),
+            11 => self.extract_u32x8::<3>(a1),
+            12 => self.extract_u32x8::<4>(a1),
+            13 => self.extract_u32x8::<5>(a1),
+            14 => self.extract_u32x8::<6>(a1),
+            15 => self.extract_u32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u32x16<const LANE: usize>(self, a: u32x16<Self>, value: u32) -> u32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x16(a);
+        match LANE {
+            0 => self.combine_u32x8(self.insert_u32x8::<0>(a0, value), a1),
+            1 => self.combine_u32x8(self.insert_u32x8::<1>(a0, value), a1),
+            2 => self.combine_u32x8(self.insert_u32x8::<2>(a0, value), a1),
+            3 => self.combine_u32x8(self.insert_u32x8::<3>(a0, value), a1),
+            4 => self.combine_u32x8(self.insert_u32x8::<4>(a0, value), a1),
+            5 => self.combine_u32x8(self.insert_u32x8::<5>(a0, value), a1),
+            6 => self.combine_u32x8(self.insert_u32x8::<6>(a0, value), a1),
+            7 => self.combine_u32x8(self.insert_u32x8::<7>(a0, value), a1),
+            8 => self.combine_u32x8(a0, self.insert_u32x8::<0>(a1, value)),
+            9 => self.combine_u32x8(a0, self.insert_u32x8::<1>(a1, value)),
+            10 => self.combine_u32x8(a0, self.insert_u32x8::<2>(a1, value)),
+            11 => self.combine_u32x8(a0, self.insert_u32x8::<3>(a1, value)),
+            12 => self.combine_u32x8(a0, self.insert_u32x8::<4>(a1, value)),
+            13 => self.combine_u32x8(a0, self.insert_u32x8::<5>(a1, value)),
+            14 => self.combine_u32x8(a0, self.insert_u32x8::<6>(a1, value)),
+            15 => self.combine_u32x8(a0, self.insert_u32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x16(self, a: u32x16<Self>, index: usize) -> u32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u32x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u32x16(
         self,
         a: u32x16<Self>,
@@ -13984,6 +18590,15 @@ impl Simd for Fallback {
         self.combine_u32x8(self.shrv_u32x8(a0, b0), self.shrv_u32x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u32x16(self, a: u32x16<Self>, b: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_u32x8(
+            self.shift_signed_u32x8(a0, b0),
+            self.shift_signed_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
         let (a0, a1) = self.split_u32x16(a);
         let (b0, b1) = self.split_u32x16(b);
@@ -14071,6 +18686,21 @@ impl Simd for Fallback {
         self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u32x16(
+        self,
+        a: mask32x16<Self>,
+        b: u32x16<Self>,
+        c: u32x16<Self>,
+    ) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(
+            self.select_relaxed_u32x8(a0, b0, c0),
+            self.select_relaxed_u32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
         let (a0, a1) = self.split_u32x16(a);
         let (b0, b1) = self.split_u32x16(b);
@@ -14083,6 +18713,39 @@ impl Simd for Fallback {
         self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.saturating_add_u32x8(a0, b0),
+            self.saturating_add_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.saturating_sub_u32x8(a0, b0),
+            self.saturating_sub_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.count_ones_u32x8(a0), self.count_ones_u32x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.leading_zeros_u32x8(a0), self.leading_zeros_u32x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.trailing_zeros_u32x8(a0), self.trailing_zeros_u32x8(a1))
+    }
+    #[inline(always)]
     fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
         let mut b0 = [0; 8usize];
         let mut b1 = [0; 8usize];
@@ -14147,6 +18810,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self> {
+        i32x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self> {
         let lo = self.from_bitmask_mask32x8(bits);
         let hi = self.from_bitmask_mask32x8(bits >> 8usize);
@@ -14209,6 +18879,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_relaxed_mask32x8(a0, b0, c0),
+            self.select_relaxed_mask32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
         let (a0, a1) = self.split_mask32x16(a);
         let (b0, b1) = self.split_mask32x16(b);
@@ -14293,6 +18978,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_f64x8<const SHIFT: usize>(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -14380,6 +19068,57 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_f64x8<const LANE: usize>(self, a: f64x8<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x8(a);
+        match LANE {
+            0 => self.extract_f64x4::<0>(a0),
+            1 => self.extract_f64x4::<1>(a0),
+            2 => self.extract_f64x4::<2>(a0),
+            3 => self.extract_f64x4::<3>(a0),
+            4 => self.extract_f64x4::<0>(a1),
+            5 => self.extract_f64x4::<1>(a1),
+            6 => self.extract_f64x4::<2>(a1),
+            7 => self.extract_f64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f64x8<const LANE: usize>(self, a: f64x8<Self>, value: f64) -> f64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x8(a);
+        match LANE {
+            0 => self.combine_f64x4(self.insert_f64x4::<0>(a0, value), a1),
+            1 => self.combine_f64x4(self.insert_f64x4::<1>(a0, value), a1),
+            2 => self.combine_f64x4(self.insert_f64x4::<2>(a0, value), a1),
+            3 => self.combine_f64x4(self.insert_f64x4::<3>(a0, value), a1),
+            4 => self.combine_f64x4(a0, self.insert_f64x4::<0>(a1, value)),
+            5 => self.combine_f64x4(a0, self.insert_f64x4::<1>(a1, value)),
+            6 => self.combine_f64x4(a0, self.insert_f64x4::<2>(a1, value)),
+            7 => self.combine_f64x4(a0, self.insert_f64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x8(self, a: f64x8<Self>, index: usize) -> f64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_f64x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f64x8(self, a: f64x8<Self>, indices: u8x64<Self>) -> f64x8<Self> {
         let (a0, a1) = self.split_f64x8(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -14572,6 +19311,36 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn nmul_add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.nmul_add_f64x4(a0, b0, c0),
+            self.nmul_add_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.nmul_sub_f64x4(a0, b0, c0),
+            self.nmul_sub_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_relaxed_f64x4(a0, b0, c0),
+            self.mul_add_relaxed_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
         let (a0, a1) = self.split_f64x8(a);
         self.combine_f64x4(self.floor_f64x4(a0), self.floor_f64x4(a1))
@@ -14607,6 +19376,21 @@ impl Simd for Fallback {
         self.combine_f64x4(self.select_f64x4(a0, b0, c0), self.select_f64x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f64x8(
+        self,
+        a: mask64x8<Self>,
+        b: f64x8<Self>,
+        c: f64x8<Self>,
+    ) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.select_relaxed_f64x4(a0, b0, c0),
+            self.select_relaxed_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>) {
         let mut b0 = [0.0; 4usize];
         let mut b1 = [0.0; 4usize];
@@ -14673,6 +19457,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_i64x8<const SHIFT: usize>(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -14760,6 +19547,57 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_i64x8<const LANE: usize>(self, a: i64x8<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x8(a);
+        match LANE {
+            0 => self.extract_i64x4::<0>(a0),
+            1 => self.extract_i64x4::<1>(a0),
+            2 => self.extract_i64x4::<2>(a0),
+            3 => self.extract_i64x4::<3>(a0),
+            4 => self.extract_i64x4::<0>(a1),
+            5 => self.extract_i64x4::<1>(a1),
+            6 => self.extract_i64x4::<2>(a1),
+            7 => self.extract_i64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i64x8<const LANE: usize>(self, a: i64x8<Self>, value: i64) -> i64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x8(a);
+        match LANE {
+            0 => self.combine_i64x4(self.insert_i64x4::<0>(a0, value), a1),
+            1 => self.combine_i64x4(self.insert_i64x4::<1>(a0, value), a1),
+            2 => self.combine_i64x4(self.insert_i64x4::<2>(a0, value), a1),
+            3 => self.combine_i64x4(self.insert_i64x4::<3>(a0, value), a1),
+            4 => self.combine_i64x4(a0, self.insert_i64x4::<0>(a1, value)),
+            5 => self.combine_i64x4(a0, self.insert_i64x4::<1>(a1, value)),
+            6 => self.combine_i64x4(a0, self.insert_i64x4::<2>(a1, value)),
+            7 => self.combine_i64x4(a0, self.insert_i64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x8(self, a: i64x8<Self>, index: usize) -> i64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i64x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i64x8(self, a: i64x8<Self>, indices: u8x64<Self>) -> i64x8<Self> {
         let (a0, a1) = self.split_i64x8(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -14832,6 +19670,15 @@ impl Simd for Fallback {
         self.combine_i64x4(self.shrv_i64x4(a0, b0), self.shrv_i64x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.shift_signed_i64x4(a0, b0),
+            self.shift_signed_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
         let (a0, a1) = self.split_i64x8(a);
         let (b0, b1) = self.split_i64x8(b);
@@ -14919,6 +19766,21 @@ impl Simd for Fallback {
         self.combine_i64x4(self.select_i64x4(a0, b0, c0), self.select_i64x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i64x8(
+        self,
+        a: mask64x8<Self>,
+        b: i64x8<Self>,
+        c: i64x8<Self>,
+    ) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(
+            self.select_relaxed_i64x4(a0, b0, c0),
+            self.select_relaxed_i64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
         let (a0, a1) = self.split_i64x8(a);
         let (b0, b1) = self.split_i64x8(b);
@@ -14931,6 +19793,39 @@ impl Simd for Fallback {
         self.combine_i64x4(self.max_i64x4(a0, b0), self.max_i64x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.saturating_add_i64x4(a0, b0),
+            self.saturating_add_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.saturating_sub_i64x4(a0, b0),
+            self.saturating_sub_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.count_ones_i64x4(a0), self.count_ones_i64x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.leading_zeros_i64x4(a0), self.leading_zeros_i64x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.trailing_zeros_i64x4(a0), self.trailing_zeros_i64x4(a1))
+    }
+    #[inline(always)]
     fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>) {
         let mut b0 = [0; 4usize];
         let mut b1 = [0; 4usize];
@@ -15007,6 +19902,9 @@ impl Simd for Fallback {
     }
     #[inline(always)]
     fn slide_u64x8<const SHIFT: usize>(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        if SHIFT >= 8usize {
+            return b;
+        }
         let mut dest = [Default::default(); 8usize];
         dest[..8usize - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
         dest[8usize - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -15094,6 +19992,57 @@ impl Simd for Fallback {
         }
     }
     #[inline(always)]
+    fn extract_u64x8<const LANE: usize>(self, a: u64x8<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x8(a);
+        match LANE {
+            0 => self.extract_u64x4::<0>(a0),
+            1 => self.extract_u64x4::<1>(a0),
+            2 => self.extract_u64x4::<2>(a0),
+            3 => self.extract_u64x4::<3>(a0),
+            4 => self.extract_u64x4::<0>(a1),
+            5 => self.extract_u64x4::<1>(a1),
+            6 => self.extract_u64x4::<2>(a1),
+            7 => self.extract_u64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u64x8<const LANE: usize>(self, a: u64x8<Self>, value: u64) -> u64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x8(a);
+        match LANE {
+            0 => self.combine_u64x4(self.insert_u64x4::<0>(a0, value), a1),
+            1 => self.combine_u64x4(self.insert_u64x4::<1>(a0, value), a1),
+            2 => self.combine_u64x4(self.insert_u64x4::<2>(a0, value), a1),
+            3 => self.combine_u64x4(self.insert_u64x4::<3>(a0, value), a1),
+            4 => self.combine_u64x4(a0, self.insert_u64x4::<0>(a1, value)),
+            5 => self.combine_u64x4(a0, self.insert_u64x4::<1>(a1, value)),
+            6 => self.combine_u64x4(a0, self.insert_u64x4::<2>(a1, value)),
+            7 => self.combine_u64x4(a0, self.insert_u64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x8(self, a: u64x8<Self>, index: usize) -> u64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u64x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u64x8(self, a: u64x8<Self>, indices: u8x64<Self>) -> u64x8<Self> {
         let (a0, a1) = self.split_u64x8(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -15166,6 +20115,15 @@ impl Simd for Fallback {
         self.combine_u64x4(self.shrv_u64x4(a0, b0), self.shrv_u64x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u64x8(self, a: u64x8<Self>, b: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_u64x4(
+            self.shift_signed_u64x4(a0, b0),
+            self.shift_signed_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
         let (a0, a1) = self.split_u64x8(a);
         let (b0, b1) = self.split_u64x8(b);
@@ -15253,6 +20211,21 @@ impl Simd for Fallback {
         self.combine_u64x4(self.select_u64x4(a0, b0, c0), self.select_u64x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u64x8(
+        self,
+        a: mask64x8<Self>,
+        b: u64x8<Self>,
+        c: u64x8<Self>,
+    ) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(
+            self.select_relaxed_u64x4(a0, b0, c0),
+            self.select_relaxed_u64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
         let (a0, a1) = self.split_u64x8(a);
         let (b0, b1) = self.split_u64x8(b);
@@ -15265,6 +20238,39 @@ impl Simd for Fallback {
         self.combine_u64x4(self.max_u64x4(a0, b0), self.max_u64x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.saturating_add_u64x4(a0, b0),
+            self.saturating_add_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.saturating_sub_u64x4(a0, b0),
+            self.saturating_sub_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.count_ones_u64x4(a0), self.count_ones_u64x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.leading_zeros_u64x4(a0), self.leading_zeros_u64x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.trailing_zeros_u64x4(a0), self.trailing_zeros_u64x4(a1))
+    }
+    #[inline(always)]
     fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>) {
         let mut b0 = [0; 4usize];
         let mut b1 = [0; 4usize];
@@ -15306,6 +20312,24 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn transpose_bits_8x8_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 7)),
+            self.splat_u64x8(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 7));
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 14)),
+            self.splat_u64x8(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 14));
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 28)),
+            self.splat_u64x8(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 28))
+    }
+    #[inline(always)]
     fn splat_mask64x8(self, val: bool) -> mask64x8<Self> {
         let half = self.splat_mask64x4(val);
         self.combine_mask64x4(half, half)
@@ -15322,6 +20346,13 @@ impl Simd for Fallback {
         a.val.0
     }
     #[inline(always)]
+    fn to_int_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self> {
+        i64x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self> {
         let lo = self.from_bitmask_mask64x4(bits);
         let hi = self.from_bitmask_mask64x4(bits >> 4usize);
@@ -15384,6 +20415,21 @@ impl Simd for Fallback {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_relaxed_mask64x4(a0, b0, c0),
+            self.select_relaxed_mask64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
         let (a0, a1) = self.split_mask64x8(a);
         let (b0, b1) = self.split_mask64x8(b);
@@ -0,0 +1,35342 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This file is autogenerated by fearless_simd_gen
+
+#[doc = r" The names of the levels, in the order of [`OpLowering::levels`]."]
+pub const LEVELS: [&str; 7] = [
+    "Fallback",
+    "Neon",
+    "WasmSimd128",
+    "Sse2",
+    "Sse4_2",
+    "Avx2",
+    "Avx512",
+];
+#[doc = r" How a level implements an op for a vector type."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LoweringKind {
+    #[doc = r" The level shares the implementation of the `Fallback` level."]
+    #[doc = r""]
+    #[doc = r" This is either scalar code, or composed from other methods, which the level may implement with"]
+    #[doc = r" intrinsics."]
+    Portable,
+    #[doc = r" The level has its own implementation."]
+    Native,
+    #[doc = r" The vector is split into halves, which the level implements separately, and the results are combined."]
+    Split,
+}
+#[doc = r" How one level implements an op for a vector type."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Lowering {
+    #[doc = r" How the op is implemented."]
+    pub kind: LoweringKind,
+    #[doc = r" The intrinsics used, in the order they first appear, including those of any other `Simd` methods"]
+    #[doc = r" which the implementation calls."]
+    #[doc = r""]
+    #[doc = r" This is empty if the implementation doesn't use any SIMD intrinsics, for example because it only"]
+    #[doc = r" reinterprets the vector, or works on scalars."]
+    pub intrinsics: &'static [&'static str],
+}
+#[doc = r" How every level implements an op for a vector type."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OpLowering {
+    #[doc = r#" The name of the [`Simd`](crate::Simd) method, like `"mul_add_f32x4"`."#]
+    pub method: &'static str,
+    #[doc = r#" The name of the op, like `"mul_add"`."#]
+    pub op: &'static str,
+    #[doc = r#" The name of the vector type, like `"f32x4"`."#]
+    pub vector: &'static str,
+    #[doc = r" The lowering on each level, in the order of [`LEVELS`]."]
+    pub levels: [Lowering; 7],
+}
+impl OpLowering {
+    #[doc = r" The lowering on the level named `level`, which is one of [`LEVELS`]."]
+    pub fn level(&self, level: &str) -> Option<&Lowering> {
+        let index = LEVELS.iter().position(|name| *name == level)?;
+        self.levels.get(index)
+    }
+}
+#[doc = r#" Finds the lowering of the [`Simd`](crate::Simd) method named `method`, like `"mul_add_f32x4"`."#]
+pub fn find(method: &str) -> Option<&'static OpLowering> {
+    let index = LOWERINGS
+        .binary_search_by(|lowering| lowering.method.cmp(method))
+        .ok()?;
+    LOWERINGS.get(index)
+}
+#[doc = r" The lowering of every [`Simd`](crate::Simd) method, sorted by method name."]
+pub const LOWERINGS: &[OpLowering] = &[
+    op(
+        "abd_accumulate_u16x16",
+        "abd_accumulate",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vabal_u16", "vget_low_u16", "vabal_high_u16"]),
+            split(&[
+                "v128_or",
+                "u16x8_sub_sat",
+                "i32x4_add",
+                "u32x4_extend_low_u16x8",
+                "u32x4_extend_high_u16x8",
+            ]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_subs_epu16",
+                "_mm_add_epi32",
+                "_mm_unpacklo_epi16",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi16",
+            ]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_subs_epu16",
+                "_mm_add_epi32",
+                "_mm_unpacklo_epi16",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi16",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_subs_epu16",
+                "_mm256_add_epi32",
+                "_mm256_cvtepu16_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_subs_epu16",
+                "_mm512_add_epi32",
+                "_mm512_cvtepu16_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "abd_accumulate_u16x8",
+        "abd_accumulate",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vabal_u16", "vget_low_u16", "vabal_high_u16"]),
+            native(&[
+                "v128_or",
+                "u16x8_sub_sat",
+                "i32x4_add",
+                "u32x4_extend_low_u16x8",
+                "u32x4_extend_high_u16x8",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu16",
+                "_mm_add_epi32",
+                "_mm_unpacklo_epi16",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi16",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu16",
+                "_mm_add_epi32",
+                "_mm_unpacklo_epi16",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi16",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu16",
+                "_mm256_add_epi32",
+                "_mm256_cvtepu16_epi32",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu16",
+                "_mm256_add_epi32",
+                "_mm256_cvtepu16_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "abd_accumulate_u8x16",
+        "abd_accumulate",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vabal_u8", "vget_low_u8", "vabal_high_u8"]),
+            native(&[
+                "v128_or",
+                "u8x16_sub_sat",
+                "i16x8_add",
+                "u16x8_extend_low_u8x16",
+                "u16x8_extend_high_u8x16",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu8",
+                "_mm_add_epi16",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu8",
+                "_mm_add_epi16",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu8",
+                "_mm256_add_epi16",
+                "_mm256_cvtepu8_epi16",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_subs_epu8",
+                "_mm256_add_epi16",
+                "_mm256_cvtepu8_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "abd_accumulate_u8x32",
+        "abd_accumulate",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vabal_u8", "vget_low_u8", "vabal_high_u8"]),
+            split(&[
+                "v128_or",
+                "u8x16_sub_sat",
+                "i16x8_add",
+                "u16x8_extend_low_u8x16",
+                "u16x8_extend_high_u8x16",
+            ]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_subs_epu8",
+                "_mm_add_epi16",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+            ]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_subs_epu8",
+                "_mm_add_epi16",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_subs_epu8",
+                "_mm256_add_epi16",
+                "_mm256_cvtepu8_epi16",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_subs_epu8",
+                "_mm512_add_epi16",
+                "_mm512_cvtepu8_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "abs_f32x16",
+        "abs",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vabsq_f32"]),
+            split(&["f32x4_abs"]),
+            split(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            split(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            split(&["_mm256_andnot_ps", "_mm256_set1_ps"]),
+            native(&["_mm512_andnot_ps", "_mm512_set1_ps"]),
+        ],
+    ),
+    op(
+        "abs_f32x4",
+        "abs",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vabsq_f32"]),
+            native(&["f32x4_abs"]),
+            native(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            native(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            native(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            native(&["_mm_andnot_ps", "_mm_set1_ps"]),
+        ],
+    ),
+    op(
+        "abs_f32x8",
+        "abs",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vabsq_f32"]),
+            split(&["f32x4_abs"]),
+            split(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            split(&["_mm_andnot_ps", "_mm_set1_ps"]),
+            native(&["_mm256_andnot_ps", "_mm256_set1_ps"]),
+            native(&["_mm256_andnot_ps", "_mm256_set1_ps"]),
+        ],
+    ),
+    op(
+        "abs_f64x2",
+        "abs",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vabsq_f64"]),
+            native(&["f64x2_abs"]),
+            native(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            native(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            native(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            native(&["_mm_andnot_pd", "_mm_set1_pd"]),
+        ],
+    ),
+    op(
+        "abs_f64x4",
+        "abs",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vabsq_f64"]),
+            split(&["f64x2_abs"]),
+            split(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            split(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            native(&["_mm256_andnot_pd", "_mm256_set1_pd"]),
+            native(&["_mm256_andnot_pd", "_mm256_set1_pd"]),
+        ],
+    ),
+    op(
+        "abs_f64x8",
+        "abs",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vabsq_f64"]),
+            split(&["f64x2_abs"]),
+            split(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            split(&["_mm_andnot_pd", "_mm_set1_pd"]),
+            split(&["_mm256_andnot_pd", "_mm256_set1_pd"]),
+            native(&["_mm512_andnot_pd", "_mm512_set1_pd"]),
+        ],
+    ),
+    op(
+        "add_f32x16",
+        "add",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vaddq_f32"]),
+            split(&["f32x4_add"]),
+            split(&["_mm_add_ps"]),
+            split(&["_mm_add_ps"]),
+            split(&["_mm256_add_ps"]),
+            native(&["_mm512_add_ps"]),
+        ],
+    ),
+    op(
+        "add_f32x4",
+        "add",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vaddq_f32"]),
+            native(&["f32x4_add"]),
+            native(&["_mm_add_ps"]),
+            native(&["_mm_add_ps"]),
+            native(&["_mm_add_ps"]),
+            native(&["_mm_add_ps"]),
+        ],
+    ),
+    op(
+        "add_f32x8",
+        "add",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vaddq_f32"]),
+            split(&["f32x4_add"]),
+            split(&["_mm_add_ps"]),
+            split(&["_mm_add_ps"]),
+            native(&["_mm256_add_ps"]),
+            native(&["_mm256_add_ps"]),
+        ],
+    ),
+    op(
+        "add_f64x2",
+        "add",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vaddq_f64"]),
+            native(&["f64x2_add"]),
+            native(&["_mm_add_pd"]),
+            native(&["_mm_add_pd"]),
+            native(&["_mm_add_pd"]),
+            native(&["_mm_add_pd"]),
+        ],
+    ),
+    op(
+        "add_f64x4",
+        "add",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vaddq_f64"]),
+            split(&["f64x2_add"]),
+            split(&["_mm_add_pd"]),
+            split(&["_mm_add_pd"]),
+            native(&["_mm256_add_pd"]),
+            native(&["_mm256_add_pd"]),
+        ],
+    ),
+    op(
+        "add_f64x8",
+        "add",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vaddq_f64"]),
+            split(&["f64x2_add"]),
+            split(&["_mm_add_pd"]),
+            split(&["_mm_add_pd"]),
+            split(&["_mm256_add_pd"]),
+            native(&["_mm512_add_pd"]),
+        ],
+    ),
+    op(
+        "add_i16x16",
+        "add",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vaddq_s16"]),
+            split(&["i16x8_add"]),
+            split(&["_mm_add_epi16"]),
+            split(&["_mm_add_epi16"]),
+            native(&["_mm256_add_epi16"]),
+            native(&["_mm256_add_epi16"]),
+        ],
+    ),
+    op(
+        "add_i16x32",
+        "add",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vaddq_s16"]),
+            split(&["i16x8_add"]),
+            split(&["_mm_add_epi16"]),
+            split(&["_mm_add_epi16"]),
+            split(&["_mm256_add_epi16"]),
+            native(&["_mm512_add_epi16"]),
+        ],
+    ),
+    op(
+        "add_i16x8",
+        "add",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vaddq_s16"]),
+            native(&["i16x8_add"]),
+            native(&["_mm_add_epi16"]),
+            native(&["_mm_add_epi16"]),
+            native(&["_mm_add_epi16"]),
+            native(&["_mm_add_epi16"]),
+        ],
+    ),
+    op(
+        "add_i32x16",
+        "add",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vaddq_s32"]),
+            split(&["i32x4_add"]),
+            split(&["_mm_add_epi32"]),
+            split(&["_mm_add_epi32"]),
+            split(&["_mm256_add_epi32"]),
+            native(&["_mm512_add_epi32"]),
+        ],
+    ),
+    op(
+        "add_i32x4",
+        "add",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vaddq_s32"]),
+            native(&["i32x4_add"]),
+            native(&["_mm_add_epi32"]),
+            native(&["_mm_add_epi32"]),
+            native(&["_mm_add_epi32"]),
+            native(&["_mm_add_epi32"]),
+        ],
+    ),
+    op(
+        "add_i32x8",
+        "add",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vaddq_s32"]),
+            split(&["i32x4_add"]),
+            split(&["_mm_add_epi32"]),
+            split(&["_mm_add_epi32"]),
+            native(&["_mm256_add_epi32"]),
+            native(&["_mm256_add_epi32"]),
+        ],
+    ),
+    op(
+        "add_i64x2",
+        "add",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vaddq_s64"]),
+            native(&["i64x2_add"]),
+            native(&["_mm_add_epi64"]),
+            native(&["_mm_add_epi64"]),
+            native(&["_mm_add_epi64"]),
+            native(&["_mm_add_epi64"]),
+        ],
+    ),
+    op(
+        "add_i64x4",
+        "add",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vaddq_s64"]),
+            split(&["i64x2_add"]),
+            split(&["_mm_add_epi64"]),
+            split(&["_mm_add_epi64"]),
+            native(&["_mm256_add_epi64"]),
+            native(&["_mm256_add_epi64"]),
+        ],
+    ),
+    op(
+        "add_i64x8",
+        "add",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vaddq_s64"]),
+            split(&["i64x2_add"]),
+            split(&["_mm_add_epi64"]),
+            split(&["_mm_add_epi64"]),
+            split(&["_mm256_add_epi64"]),
+            native(&["_mm512_add_epi64"]),
+        ],
+    ),
+    op(
+        "add_i8x16",
+        "add",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vaddq_s8"]),
+            native(&["i8x16_add"]),
+            native(&["_mm_add_epi8"]),
+            native(&["_mm_add_epi8"]),
+            native(&["_mm_add_epi8"]),
+            native(&["_mm_add_epi8"]),
+        ],
+    ),
+    op(
+        "add_i8x32",
+        "add",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vaddq_s8"]),
+            split(&["i8x16_add"]),
+            split(&["_mm_add_epi8"]),
+            split(&["_mm_add_epi8"]),
+            native(&["_mm256_add_epi8"]),
+            native(&["_mm256_add_epi8"]),
+        ],
+    ),
+    op(
+        "add_i8x64",
+        "add",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vaddq_s8"]),
+            split(&["i8x16_add"]),
+            split(&["_mm_add_epi8"]),
+            split(&["_mm_add_epi8"]),
+            split(&["_mm256_add_epi8"]),
+            native(&["_mm512_add_epi8"]),
+        ],
+    ),
+    op(
+        "add_u16x16",
+        "add",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vaddq_u16"]),
+            split(&["u16x8_add"]),
+            split(&["_mm_add_epi16"]),
+            split(&["_mm_add_epi16"]),
+            native(&["_mm256_add_epi16"]),
+            native(&["_mm256_add_epi16"]),
+        ],
+    ),
+    op(
+        "add_u16x32",
+        "add",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vaddq_u16"]),
+            split(&["u16x8_add"]),
+            split(&["_mm_add_epi16"]),
+            split(&["_mm_add_epi16"]),
+            split(&["_mm256_add_epi16"]),
+            native(&["_mm512_add_epi16"]),
+        ],
+    ),
+    op(
+        "add_u16x8",
+        "add",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vaddq_u16"]),
+            native(&["u16x8_add"]),
+            native(&["_mm_add_epi16"]),
+            native(&["_mm_add_epi16"]),
+            native(&["_mm_add_epi16"]),
+            native(&["_mm_add_epi16"]),
+        ],
+    ),
+    op(
+        "add_u32x16",
+        "add",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vaddq_u32"]),
+            split(&["u32x4_add"]),
+            split(&["_mm_add_epi32"]),
+            split(&["_mm_add_epi32"]),
+            split(&["_mm256_add_epi32"]),
+            native(&["_mm512_add_epi32"]),
+        ],
+    ),
+    op(
+        "add_u32x4",
+        "add",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vaddq_u32"]),
+            native(&["u32x4_add"]),
+            native(&["_mm_add_epi32"]),
+            native(&["_mm_add_epi32"]),
+            native(&["_mm_add_epi32"]),
+            native(&["_mm_add_epi32"]),
+        ],
+    ),
+    op(
+        "add_u32x8",
+        "add",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vaddq_u32"]),
+            split(&["u32x4_add"]),
+            split(&["_mm_add_epi32"]),
+            split(&["_mm_add_epi32"]),
+            native(&["_mm256_add_epi32"]),
+            native(&["_mm256_add_epi32"]),
+        ],
+    ),
+    op(
+        "add_u64x2",
+        "add",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vaddq_u64"]),
+            native(&["u64x2_add"]),
+            native(&["_mm_add_epi64"]),
+            native(&["_mm_add_epi64"]),
+            native(&["_mm_add_epi64"]),
+            native(&["_mm_add_epi64"]),
+        ],
+    ),
+    op(
+        "add_u64x4",
+        "add",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vaddq_u64"]),
+            split(&["u64x2_add"]),
+            split(&["_mm_add_epi64"]),
+            split(&["_mm_add_epi64"]),
+            native(&["_mm256_add_epi64"]),
+            native(&["_mm256_add_epi64"]),
+        ],
+    ),
+    op(
+        "add_u64x8",
+        "add",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vaddq_u64"]),
+            split(&["u64x2_add"]),
+            split(&["_mm_add_epi64"]),
+            split(&["_mm_add_epi64"]),
+            split(&["_mm256_add_epi64"]),
+            native(&["_mm512_add_epi64"]),
+        ],
+    ),
+    op(
+        "add_u8x16",
+        "add",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vaddq_u8"]),
+            native(&["u8x16_add"]),
+            native(&["_mm_add_epi8"]),
+            native(&["_mm_add_epi8"]),
+            native(&["_mm_add_epi8"]),
+            native(&["_mm_add_epi8"]),
+        ],
+    ),
+    op(
+        "add_u8x32",
+        "add",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vaddq_u8"]),
+            split(&["u8x16_add"]),
+            split(&["_mm_add_epi8"]),
+            split(&["_mm_add_epi8"]),
+            native(&["_mm256_add_epi8"]),
+            native(&["_mm256_add_epi8"]),
+        ],
+    ),
+    op(
+        "add_u8x64",
+        "add",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vaddq_u8"]),
+            split(&["u8x16_add"]),
+            split(&["_mm_add_epi8"]),
+            split(&["_mm_add_epi8"]),
+            split(&["_mm256_add_epi8"]),
+            native(&["_mm512_add_epi8"]),
+        ],
+    ),
+    op(
+        "all_false_mask16x16",
+        "all_false",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask16x32",
+        "all_false",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask16x8",
+        "all_false",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s16"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask32x16",
+        "all_false",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask32x4",
+        "all_false",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s32"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask32x8",
+        "all_false",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask64x2",
+        "all_false",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s64"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask64x4",
+        "all_false",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask64x8",
+        "all_false",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask8x16",
+        "all_false",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s8"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask8x32",
+        "all_false",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_false_mask8x64",
+        "all_false",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask16x16",
+        "all_true",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["i16x8_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask16x32",
+        "all_true",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["i16x8_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask16x8",
+        "all_true",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s16"]),
+            native(&["i16x8_all_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask32x16",
+        "all_true",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_all_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask32x4",
+        "all_true",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s32"]),
+            native(&["i32x4_all_true"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask32x8",
+        "all_true",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_all_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask64x2",
+        "all_true",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s64"]),
+            native(&["i64x2_all_true"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask64x4",
+        "all_true",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["i64x2_all_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask64x8",
+        "all_true",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["i64x2_all_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask8x16",
+        "all_true",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s8"]),
+            native(&["i8x16_all_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask8x32",
+        "all_true",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["i8x16_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "all_true_mask8x64",
+        "all_true",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["i8x16_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_i16x16",
+        "and",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vandq_s16"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_i16x32",
+        "and",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vandq_s16"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_i16x8",
+        "and",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vandq_s16"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_i32x16",
+        "and",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vandq_s32"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_i32x4",
+        "and",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vandq_s32"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_i32x8",
+        "and",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vandq_s32"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_i64x2",
+        "and",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vandq_s64"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_i64x4",
+        "and",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vandq_s64"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_i64x8",
+        "and",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vandq_s64"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_i8x16",
+        "and",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vandq_s8"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_i8x32",
+        "and",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vandq_s8"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_i8x64",
+        "and",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vandq_s8"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_mask16x16",
+        "and",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vandq_s16"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask16x32",
+        "and",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vandq_s16"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask16x8",
+        "and",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vandq_s16"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask32x16",
+        "and",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vandq_s32"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask32x4",
+        "and",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vandq_s32"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask32x8",
+        "and",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vandq_s32"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask64x2",
+        "and",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vandq_s64"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask64x4",
+        "and",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vandq_s64"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask64x8",
+        "and",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vandq_s64"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask8x16",
+        "and",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vandq_s8"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask8x32",
+        "and",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vandq_s8"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_mask8x64",
+        "and",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vandq_s8"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "and_u16x16",
+        "and",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vandq_u16"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_u16x32",
+        "and",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vandq_u16"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_u16x8",
+        "and",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vandq_u16"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_u32x16",
+        "and",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vandq_u32"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_u32x4",
+        "and",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vandq_u32"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_u32x8",
+        "and",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vandq_u32"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_u64x2",
+        "and",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vandq_u64"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_u64x4",
+        "and",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vandq_u64"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_u64x8",
+        "and",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vandq_u64"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "and_u8x16",
+        "and",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vandq_u8"]),
+            native(&["v128_and"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+            native(&["_mm_and_si128"]),
+        ],
+    ),
+    op(
+        "and_u8x32",
+        "and",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vandq_u8"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            native(&["_mm256_and_si256"]),
+            native(&["_mm256_and_si256"]),
+        ],
+    ),
+    op(
+        "and_u8x64",
+        "and",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vandq_u8"]),
+            split(&["v128_and"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm_and_si128"]),
+            split(&["_mm256_and_si256"]),
+            native(&["_mm512_and_si512"]),
+        ],
+    ),
+    op(
+        "any_false_mask16x16",
+        "any_false",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["i16x8_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask16x32",
+        "any_false",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["i16x8_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask16x8",
+        "any_false",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s16"]),
+            native(&["i16x8_all_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask32x16",
+        "any_false",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_all_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask32x4",
+        "any_false",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s32"]),
+            native(&["i32x4_all_true"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask32x8",
+        "any_false",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_all_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask64x2",
+        "any_false",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s64"]),
+            native(&["i64x2_all_true"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask64x4",
+        "any_false",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["i64x2_all_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask64x8",
+        "any_false",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["i64x2_all_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask8x16",
+        "any_false",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vminvq_u32", "vreinterpretq_u32_s8"]),
+            native(&["i8x16_all_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask8x32",
+        "any_false",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["i8x16_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_false_mask8x64",
+        "any_false",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vminvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["i8x16_all_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask16x16",
+        "any_true",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask16x32",
+        "any_true",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s16"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask16x8",
+        "any_true",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s16"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask32x16",
+        "any_true",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask32x4",
+        "any_true",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s32"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask32x8",
+        "any_true",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s32"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask64x2",
+        "any_true",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s64"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask64x4",
+        "any_true",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask64x8",
+        "any_true",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s64"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask8x16",
+        "any_true",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vmaxvq_u32", "vreinterpretq_u32_s8"]),
+            native(&["v128_any_true"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask8x32",
+        "any_true",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "any_true_mask8x64",
+        "any_true",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vmaxvq_u32", "vreinterpretq_u32_s8"]),
+            split(&["v128_any_true"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "approximate_recip_f32x16",
+        "approximate_recip",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vrecpeq_f32"]),
+            split(&[]),
+            split(&["_mm_rcp_ps"]),
+            split(&["_mm_rcp_ps"]),
+            split(&["_mm256_rcp_ps"]),
+            native(&["_mm512_rcp14_ps"]),
+        ],
+    ),
+    op(
+        "approximate_recip_f32x4",
+        "approximate_recip",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vrecpeq_f32"]),
+            PORTABLE,
+            native(&["_mm_rcp_ps"]),
+            native(&["_mm_rcp_ps"]),
+            native(&["_mm_rcp_ps"]),
+            native(&["_mm_rcp14_ps"]),
+        ],
+    ),
+    op(
+        "approximate_recip_f32x8",
+        "approximate_recip",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vrecpeq_f32"]),
+            split(&[]),
+            split(&["_mm_rcp_ps"]),
+            split(&["_mm_rcp_ps"]),
+            native(&["_mm256_rcp_ps"]),
+            native(&["_mm256_rcp14_ps"]),
+        ],
+    ),
+    op(
+        "approximate_recip_f64x2",
+        "approximate_recip",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vrecpeq_f64"]),
+            PORTABLE,
+            native(&["_mm_div_pd", "_mm_set1_pd"]),
+            native(&["_mm_div_pd", "_mm_set1_pd"]),
+            native(&["_mm_div_pd", "_mm_set1_pd"]),
+            native(&["_mm_rcp14_pd"]),
+        ],
+    ),
+    op(
+        "approximate_recip_f64x4",
+        "approximate_recip",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vrecpeq_f64"]),
+            split(&[]),
+            split(&["_mm_div_pd", "_mm_set1_pd"]),
+            split(&["_mm_div_pd", "_mm_set1_pd"]),
+            native(&["_mm256_div_pd", "_mm256_set1_pd"]),
+            native(&["_mm256_rcp14_pd"]),
+        ],
+    ),
+    op(
+        "approximate_recip_f64x8",
+        "approximate_recip",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vrecpeq_f64"]),
+            split(&[]),
+            split(&["_mm_div_pd", "_mm_set1_pd"]),
+            split(&["_mm_div_pd", "_mm_set1_pd"]),
+            split(&["_mm256_div_pd", "_mm256_set1_pd"]),
+            native(&["_mm512_rcp14_pd"]),
+        ],
+    ),
+    op(
+        "as_array_f32x16",
+        "as_array",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_f32x4",
+        "as_array",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_f32x8",
+        "as_array",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_f64x2",
+        "as_array",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_f64x4",
+        "as_array",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_f64x8",
+        "as_array",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i16x16",
+        "as_array",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i16x32",
+        "as_array",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i16x8",
+        "as_array",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i32x16",
+        "as_array",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i32x4",
+        "as_array",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i32x8",
+        "as_array",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i64x2",
+        "as_array",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i64x4",
+        "as_array",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i64x8",
+        "as_array",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i8x16",
+        "as_array",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i8x32",
+        "as_array",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_i8x64",
+        "as_array",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mask16x16",
+        "as_array",
+        "mask16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movm_epi16"]),
+        ],
+    ),
+    op(
+        "as_array_mask16x32",
+        "as_array",
+        "mask16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movm_epi16"]),
+        ],
+    ),
+    op(
+        "as_array_mask16x8",
+        "as_array",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movm_epi16"]),
+        ],
+    ),
+    op(
+        "as_array_mask32x16",
+        "as_array",
+        "mask32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movm_epi32"]),
+        ],
+    ),
+    op(
+        "as_array_mask32x4",
+        "as_array",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movm_epi32"]),
+        ],
+    ),
+    op(
+        "as_array_mask32x8",
+        "as_array",
+        "mask32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movm_epi32"]),
+        ],
+    ),
+    op(
+        "as_array_mask64x2",
+        "as_array",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movm_epi64"]),
+        ],
+    ),
+    op(
+        "as_array_mask64x4",
+        "as_array",
+        "mask64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movm_epi64"]),
+        ],
+    ),
+    op(
+        "as_array_mask64x8",
+        "as_array",
+        "mask64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movm_epi64"]),
+        ],
+    ),
+    op(
+        "as_array_mask8x16",
+        "as_array",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movm_epi8"]),
+        ],
+    ),
+    op(
+        "as_array_mask8x32",
+        "as_array",
+        "mask8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movm_epi8"]),
+        ],
+    ),
+    op(
+        "as_array_mask8x64",
+        "as_array",
+        "mask8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movm_epi8"]),
+        ],
+    ),
+    op(
+        "as_array_mut_f32x16",
+        "as_array_mut",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_f32x4",
+        "as_array_mut",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_f32x8",
+        "as_array_mut",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_f64x2",
+        "as_array_mut",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_f64x4",
+        "as_array_mut",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_f64x8",
+        "as_array_mut",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i16x16",
+        "as_array_mut",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i16x32",
+        "as_array_mut",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i16x8",
+        "as_array_mut",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i32x16",
+        "as_array_mut",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i32x4",
+        "as_array_mut",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i32x8",
+        "as_array_mut",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i64x2",
+        "as_array_mut",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i64x4",
+        "as_array_mut",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i64x8",
+        "as_array_mut",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i8x16",
+        "as_array_mut",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i8x32",
+        "as_array_mut",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_i8x64",
+        "as_array_mut",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u16x16",
+        "as_array_mut",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u16x32",
+        "as_array_mut",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u16x8",
+        "as_array_mut",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u32x16",
+        "as_array_mut",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u32x4",
+        "as_array_mut",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u32x8",
+        "as_array_mut",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u64x2",
+        "as_array_mut",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u64x4",
+        "as_array_mut",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u64x8",
+        "as_array_mut",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u8x16",
+        "as_array_mut",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u8x32",
+        "as_array_mut",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_mut_u8x64",
+        "as_array_mut",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_f32x16",
+        "as_array_ref",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_f32x4",
+        "as_array_ref",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_f32x8",
+        "as_array_ref",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_f64x2",
+        "as_array_ref",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_f64x4",
+        "as_array_ref",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_f64x8",
+        "as_array_ref",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i16x16",
+        "as_array_ref",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i16x32",
+        "as_array_ref",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i16x8",
+        "as_array_ref",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i32x16",
+        "as_array_ref",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i32x4",
+        "as_array_ref",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i32x8",
+        "as_array_ref",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i64x2",
+        "as_array_ref",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i64x4",
+        "as_array_ref",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i64x8",
+        "as_array_ref",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i8x16",
+        "as_array_ref",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i8x32",
+        "as_array_ref",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_i8x64",
+        "as_array_ref",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u16x16",
+        "as_array_ref",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u16x32",
+        "as_array_ref",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u16x8",
+        "as_array_ref",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u32x16",
+        "as_array_ref",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u32x4",
+        "as_array_ref",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u32x8",
+        "as_array_ref",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u64x2",
+        "as_array_ref",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u64x4",
+        "as_array_ref",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u64x8",
+        "as_array_ref",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u8x16",
+        "as_array_ref",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u8x32",
+        "as_array_ref",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_ref_u8x64",
+        "as_array_ref",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u16x16",
+        "as_array",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u16x32",
+        "as_array",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u16x8",
+        "as_array",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u32x16",
+        "as_array",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u32x4",
+        "as_array",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u32x8",
+        "as_array",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u64x2",
+        "as_array",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u64x4",
+        "as_array",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u64x8",
+        "as_array",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u8x16",
+        "as_array",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u8x32",
+        "as_array",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "as_array_u8x64",
+        "as_array",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "avg_round_u16x16",
+        "avg_round",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vrhaddq_u16"]),
+            split(&["u16x8_avgr"]),
+            split(&["_mm_avg_epu16"]),
+            split(&["_mm_avg_epu16"]),
+            native(&["_mm256_avg_epu16"]),
+            native(&["_mm256_avg_epu16"]),
+        ],
+    ),
+    op(
+        "avg_round_u16x32",
+        "avg_round",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vrhaddq_u16"]),
+            split(&["u16x8_avgr"]),
+            split(&["_mm_avg_epu16"]),
+            split(&["_mm_avg_epu16"]),
+            split(&["_mm256_avg_epu16"]),
+            native(&["_mm512_avg_epu16"]),
+        ],
+    ),
+    op(
+        "avg_round_u16x8",
+        "avg_round",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vrhaddq_u16"]),
+            native(&["u16x8_avgr"]),
+            native(&["_mm_avg_epu16"]),
+            native(&["_mm_avg_epu16"]),
+            native(&["_mm_avg_epu16"]),
+            native(&["_mm_avg_epu16"]),
+        ],
+    ),
+    op(
+        "avg_round_u8x16",
+        "avg_round",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vrhaddq_u8"]),
+            native(&["u8x16_avgr"]),
+            native(&["_mm_avg_epu8"]),
+            native(&["_mm_avg_epu8"]),
+            native(&["_mm_avg_epu8"]),
+            native(&["_mm_avg_epu8"]),
+        ],
+    ),
+    op(
+        "avg_round_u8x32",
+        "avg_round",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vrhaddq_u8"]),
+            split(&["u8x16_avgr"]),
+            split(&["_mm_avg_epu8"]),
+            split(&["_mm_avg_epu8"]),
+            native(&["_mm256_avg_epu8"]),
+            native(&["_mm256_avg_epu8"]),
+        ],
+    ),
+    op(
+        "avg_round_u8x64",
+        "avg_round",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vrhaddq_u8"]),
+            split(&["u8x16_avgr"]),
+            split(&["_mm_avg_epu8"]),
+            split(&["_mm_avg_epu8"]),
+            split(&["_mm256_avg_epu8"]),
+            native(&["_mm512_avg_epu8"]),
+        ],
+    ),
+    op(
+        "ceil_f32x16",
+        "ceil",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vrndpq_f32"]),
+            split(&["f32x4_ceil"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            split(&["_mm256_round_ps"]),
+            native(&["_mm512_roundscale_ps"]),
+        ],
+    ),
+    op(
+        "ceil_f32x4",
+        "ceil",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vrndpq_f32"]),
+            native(&["f32x4_ceil"]),
+            PORTABLE,
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+        ],
+    ),
+    op(
+        "ceil_f32x8",
+        "ceil",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vrndpq_f32"]),
+            split(&["f32x4_ceil"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            native(&["_mm256_round_ps"]),
+            native(&["_mm256_round_ps"]),
+        ],
+    ),
+    op(
+        "ceil_f64x2",
+        "ceil",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vrndpq_f64"]),
+            native(&["f64x2_ceil"]),
+            PORTABLE,
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+        ],
+    ),
+    op(
+        "ceil_f64x4",
+        "ceil",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vrndpq_f64"]),
+            split(&["f64x2_ceil"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            native(&["_mm256_round_pd"]),
+            native(&["_mm256_round_pd"]),
+        ],
+    ),
+    op(
+        "ceil_f64x8",
+        "ceil",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vrndpq_f64"]),
+            split(&["f64x2_ceil"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            split(&["_mm256_round_pd"]),
+            native(&["_mm512_roundscale_pd"]),
+        ],
+    ),
+    op(
+        "combine_f32x4",
+        "combine",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128"]),
+            native(&["_mm256_setr_m128"]),
+        ],
+    ),
+    op(
+        "combine_f32x8",
+        "combine",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_insertf32x8", "_mm512_castps256_ps512"]),
+        ],
+    ),
+    op(
+        "combine_f64x2",
+        "combine",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128d"]),
+            native(&["_mm256_setr_m128d"]),
+        ],
+    ),
+    op(
+        "combine_f64x4",
+        "combine",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_insertf64x4", "_mm512_castpd256_pd512"]),
+        ],
+    ),
+    op(
+        "combine_i16x16",
+        "combine",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_i16x8",
+        "combine",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_i32x4",
+        "combine",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_i32x8",
+        "combine",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_i64x2",
+        "combine",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_i64x4",
+        "combine",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_i8x16",
+        "combine",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_i8x32",
+        "combine",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_mask16x16",
+        "combine",
+        "mask16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask16x8",
+        "combine",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask32x4",
+        "combine",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask32x8",
+        "combine",
+        "mask32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask64x2",
+        "combine",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask64x4",
+        "combine",
+        "mask64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask8x16",
+        "combine",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_mask8x32",
+        "combine",
+        "mask8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "combine_u16x16",
+        "combine",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_u16x8",
+        "combine",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_u32x4",
+        "combine",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_u32x8",
+        "combine",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_u64x2",
+        "combine",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_u64x4",
+        "combine",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "combine_u8x16",
+        "combine",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_setr_m128i"]),
+            native(&["_mm256_setr_m128i"]),
+        ],
+    ),
+    op(
+        "combine_u8x32",
+        "combine",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_inserti64x4", "_mm512_castsi256_si512"]),
+        ],
+    ),
+    op(
+        "compress_bytes_u8x16",
+        "compress_bytes",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["i8x16_bitmask", "u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_maskz_compress_epi8"]),
+        ],
+    ),
+    op(
+        "compress_bytes_u8x32",
+        "compress_bytes",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+                "vqtbl1q_u8",
+            ]),
+            split(&["i8x16_bitmask", "u8x16_swizzle"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8", "_mm_shuffle_epi8"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_movemask_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm256_maskz_compress_epi8"]),
+        ],
+    ),
+    op(
+        "compress_bytes_u8x64",
+        "compress_bytes",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+                "vqtbl1q_u8",
+            ]),
+            split(&["i8x16_bitmask", "u8x16_swizzle"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8", "_mm_shuffle_epi8"]),
+            split(&[
+                "_mm256_movemask_epi8",
+                "_mm256_extracti128_si256",
+                "_mm_movemask_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm512_maskz_compress_epi8"]),
+        ],
+    ),
+    op(
+        "copysign_f32x16",
+        "copysign",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vbslq_f32"]),
+            split(&["f32x4_splat", "v128_and", "v128_andnot", "v128_or"]),
+            split(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            split(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_or_ps",
+                "_mm256_and_ps",
+                "_mm256_andnot_ps",
+            ]),
+            native(&[
+                "_mm512_set1_ps",
+                "_mm512_or_ps",
+                "_mm512_and_ps",
+                "_mm512_andnot_ps",
+            ]),
+        ],
+    ),
+    op(
+        "copysign_f32x4",
+        "copysign",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vdupq_n_u32", "vbslq_f32"]),
+            native(&["f32x4_splat", "v128_and", "v128_andnot", "v128_or"]),
+            native(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            native(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            native(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            native(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+        ],
+    ),
+    op(
+        "copysign_f32x8",
+        "copysign",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vbslq_f32"]),
+            split(&["f32x4_splat", "v128_and", "v128_andnot", "v128_or"]),
+            split(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            split(&["_mm_set1_ps", "_mm_or_ps", "_mm_and_ps", "_mm_andnot_ps"]),
+            native(&[
+                "_mm256_set1_ps",
+                "_mm256_or_ps",
+                "_mm256_and_ps",
+                "_mm256_andnot_ps",
+            ]),
+            native(&[
+                "_mm256_set1_ps",
+                "_mm256_or_ps",
+                "_mm256_and_ps",
+                "_mm256_andnot_ps",
+            ]),
+        ],
+    ),
+    op(
+        "copysign_f64x2",
+        "copysign",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vdupq_n_u64", "vbslq_f64"]),
+            native(&["f64x2_splat", "v128_and", "v128_andnot", "v128_or"]),
+            native(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            native(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            native(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            native(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+        ],
+    ),
+    op(
+        "copysign_f64x4",
+        "copysign",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vbslq_f64"]),
+            split(&["f64x2_splat", "v128_and", "v128_andnot", "v128_or"]),
+            split(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            split(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            native(&[
+                "_mm256_set1_pd",
+                "_mm256_or_pd",
+                "_mm256_and_pd",
+                "_mm256_andnot_pd",
+            ]),
+            native(&[
+                "_mm256_set1_pd",
+                "_mm256_or_pd",
+                "_mm256_and_pd",
+                "_mm256_andnot_pd",
+            ]),
+        ],
+    ),
+    op(
+        "copysign_f64x8",
+        "copysign",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vbslq_f64"]),
+            split(&["f64x2_splat", "v128_and", "v128_andnot", "v128_or"]),
+            split(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            split(&["_mm_set1_pd", "_mm_or_pd", "_mm_and_pd", "_mm_andnot_pd"]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_or_pd",
+                "_mm256_and_pd",
+                "_mm256_andnot_pd",
+            ]),
+            native(&[
+                "_mm512_set1_pd",
+                "_mm512_or_pd",
+                "_mm512_and_pd",
+                "_mm512_andnot_pd",
+            ]),
+        ],
+    ),
+    op(
+        "count_ones_i16x16",
+        "count_ones",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s16_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s16",
+            ]),
+            split(&["i16x8_extadd_pairwise_u8x16", "i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm256_popcnt_epi16"]),
+        ],
+    ),
+    op(
+        "count_ones_i16x32",
+        "count_ones",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s16_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s16",
+            ]),
+            split(&["i16x8_extadd_pairwise_u8x16", "i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm512_popcnt_epi16"]),
+        ],
+    ),
+    op(
+        "count_ones_i16x8",
+        "count_ones",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s16_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s16",
+            ]),
+            native(&["i16x8_extadd_pairwise_u8x16", "i8x16_popcnt"]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&["_mm_popcnt_epi16"]),
+        ],
+    ),
+    op(
+        "count_ones_i32x16",
+        "count_ones",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s32_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm512_popcnt_epi32"]),
+        ],
+    ),
+    op(
+        "count_ones_i32x4",
+        "count_ones",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s32_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s32",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&["_mm_popcnt_epi32"]),
+        ],
+    ),
+    op(
+        "count_ones_i32x8",
+        "count_ones",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s32_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm256_popcnt_epi32"]),
+        ],
+    ),
+    op(
+        "count_ones_i64x2",
+        "count_ones",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            native(&["_mm_popcnt_epi64"]),
+        ],
+    ),
+    op(
+        "count_ones_i64x4",
+        "count_ones",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+            ]),
+            native(&["_mm256_popcnt_epi64"]),
+        ],
+    ),
+    op(
+        "count_ones_i64x8",
+        "count_ones",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+            ]),
+            native(&["_mm512_popcnt_epi64"]),
+        ],
+    ),
+    op(
+        "count_ones_i8x16",
+        "count_ones",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vcntq_s8"]),
+            native(&["i8x16_popcnt"]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm_popcnt_epi8"]),
+        ],
+    ),
+    op(
+        "count_ones_i8x32",
+        "count_ones",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vcntq_s8"]),
+            split(&["i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+            native(&["_mm256_popcnt_epi8"]),
+        ],
+    ),
+    op(
+        "count_ones_i8x64",
+        "count_ones",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vcntq_s8"]),
+            split(&["i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+            native(&["_mm512_popcnt_epi8"]),
+        ],
+    ),
+    op(
+        "count_ones_u16x16",
+        "count_ones",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vpaddlq_u8", "vcntq_u8", "vreinterpretq_u8_u16"]),
+            split(&["i16x8_extadd_pairwise_u8x16", "i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm256_popcnt_epi16"]),
+        ],
+    ),
+    op(
+        "count_ones_u16x32",
+        "count_ones",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vpaddlq_u8", "vcntq_u8", "vreinterpretq_u8_u16"]),
+            split(&["i16x8_extadd_pairwise_u8x16", "i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm512_popcnt_epi16"]),
+        ],
+    ),
+    op(
+        "count_ones_u16x8",
+        "count_ones",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vpaddlq_u8", "vcntq_u8", "vreinterpretq_u8_u16"]),
+            native(&["i16x8_extadd_pairwise_u8x16", "i8x16_popcnt"]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&["_mm_popcnt_epi16"]),
+        ],
+    ),
+    op(
+        "count_ones_u32x16",
+        "count_ones",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm512_popcnt_epi32"]),
+        ],
+    ),
+    op(
+        "count_ones_u32x4",
+        "count_ones",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u32",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&["_mm_popcnt_epi32"]),
+        ],
+    ),
+    op(
+        "count_ones_u32x8",
+        "count_ones",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+            ]),
+            native(&["_mm256_popcnt_epi32"]),
+        ],
+    ),
+    op(
+        "count_ones_u64x2",
+        "count_ones",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            native(&["_mm_popcnt_epi64"]),
+        ],
+    ),
+    op(
+        "count_ones_u64x4",
+        "count_ones",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+            ]),
+            native(&["_mm256_popcnt_epi64"]),
+        ],
+    ),
+    op(
+        "count_ones_u64x8",
+        "count_ones",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+            ]),
+            native(&["_mm512_popcnt_epi64"]),
+        ],
+    ),
+    op(
+        "count_ones_u8x16",
+        "count_ones",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vcntq_u8"]),
+            native(&["i8x16_popcnt"]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm_popcnt_epi8"]),
+        ],
+    ),
+    op(
+        "count_ones_u8x32",
+        "count_ones",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vcntq_u8"]),
+            split(&["i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+            native(&["_mm256_popcnt_epi8"]),
+        ],
+    ),
+    op(
+        "count_ones_u8x64",
+        "count_ones",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vcntq_u8"]),
+            split(&["i8x16_popcnt"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+            native(&["_mm512_popcnt_epi8"]),
+        ],
+    ),
+    op(
+        "cvt_f32_i32x16",
+        "cvt_f32",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_f32_s32"]),
+            split(&["f32x4_convert_i32x4"]),
+            split(&["_mm_cvtepi32_ps"]),
+            split(&["_mm_cvtepi32_ps"]),
+            split(&["_mm256_cvtepi32_ps"]),
+            native(&["_mm512_cvtepi32_ps"]),
+        ],
+    ),
+    op(
+        "cvt_f32_i32x4",
+        "cvt_f32",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_f32_s32"]),
+            native(&["f32x4_convert_i32x4"]),
+            native(&["_mm_cvtepi32_ps"]),
+            native(&["_mm_cvtepi32_ps"]),
+            native(&["_mm_cvtepi32_ps"]),
+            native(&["_mm_cvtepi32_ps"]),
+        ],
+    ),
+    op(
+        "cvt_f32_i32x8",
+        "cvt_f32",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_f32_s32"]),
+            split(&["f32x4_convert_i32x4"]),
+            split(&["_mm_cvtepi32_ps"]),
+            split(&["_mm_cvtepi32_ps"]),
+            native(&["_mm256_cvtepi32_ps"]),
+            native(&["_mm256_cvtepi32_ps"]),
+        ],
+    ),
+    op(
+        "cvt_f32_u32x16",
+        "cvt_f32",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_f32_u32"]),
+            split(&["f32x4_convert_u32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_blend_epi16",
+                "_mm_set1_epi32",
+                "_mm_srli_epi32",
+                "_mm_sub_ps",
+                "_mm_castsi128_ps",
+                "_mm_set1_ps",
+                "_mm_add_ps",
+            ]),
+            split(&[
+                "_mm256_blend_epi16",
+                "_mm256_set1_epi32",
+                "_mm256_srli_epi32",
+                "_mm256_sub_ps",
+                "_mm256_castsi256_ps",
+                "_mm256_set1_ps",
+                "_mm256_add_ps",
+            ]),
+            native(&["_mm512_cvtepu32_ps"]),
+        ],
+    ),
+    op(
+        "cvt_f32_u32x4",
+        "cvt_f32",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_f32_u32"]),
+            native(&["f32x4_convert_u32x4"]),
+            PORTABLE,
+            native(&[
+                "_mm_blend_epi16",
+                "_mm_set1_epi32",
+                "_mm_srli_epi32",
+                "_mm_sub_ps",
+                "_mm_castsi128_ps",
+                "_mm_set1_ps",
+                "_mm_add_ps",
+            ]),
+            native(&[
+                "_mm_blend_epi16",
+                "_mm_set1_epi32",
+                "_mm_srli_epi32",
+                "_mm_sub_ps",
+                "_mm_castsi128_ps",
+                "_mm_set1_ps",
+                "_mm_add_ps",
+            ]),
+            native(&[
+                "_mm512_castps512_ps128",
+                "_mm512_cvtepu32_ps",
+                "_mm512_zextsi128_si512",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_f32_u32x8",
+        "cvt_f32",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_f32_u32"]),
+            split(&["f32x4_convert_u32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_blend_epi16",
+                "_mm_set1_epi32",
+                "_mm_srli_epi32",
+                "_mm_sub_ps",
+                "_mm_castsi128_ps",
+                "_mm_set1_ps",
+                "_mm_add_ps",
+            ]),
+            native(&[
+                "_mm256_blend_epi16",
+                "_mm256_set1_epi32",
+                "_mm256_srli_epi32",
+                "_mm256_sub_ps",
+                "_mm256_castsi256_ps",
+                "_mm256_set1_ps",
+                "_mm256_add_ps",
+            ]),
+            native(&[
+                "_mm512_castps512_ps256",
+                "_mm512_cvtepu32_ps",
+                "_mm512_zextsi256_si512",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_f32x16",
+        "cvt_from_bytes",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_f32x4",
+        "cvt_from_bytes",
+        "f32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_f32x8",
+        "cvt_from_bytes",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_f64x2",
+        "cvt_from_bytes",
+        "f64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_f64x4",
+        "cvt_from_bytes",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_f64x8",
+        "cvt_from_bytes",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i16x16",
+        "cvt_from_bytes",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i16x32",
+        "cvt_from_bytes",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i16x8",
+        "cvt_from_bytes",
+        "i16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i32x16",
+        "cvt_from_bytes",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i32x4",
+        "cvt_from_bytes",
+        "i32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i32x8",
+        "cvt_from_bytes",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i64x2",
+        "cvt_from_bytes",
+        "i64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i64x4",
+        "cvt_from_bytes",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i64x8",
+        "cvt_from_bytes",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i8x16",
+        "cvt_from_bytes",
+        "i8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i8x32",
+        "cvt_from_bytes",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_i8x64",
+        "cvt_from_bytes",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u16x16",
+        "cvt_from_bytes",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u16x32",
+        "cvt_from_bytes",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u16x8",
+        "cvt_from_bytes",
+        "u16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u32x16",
+        "cvt_from_bytes",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u32x4",
+        "cvt_from_bytes",
+        "u32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u32x8",
+        "cvt_from_bytes",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u64x2",
+        "cvt_from_bytes",
+        "u64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u64x4",
+        "cvt_from_bytes",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u64x8",
+        "cvt_from_bytes",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u8x16",
+        "cvt_from_bytes",
+        "u8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u8x32",
+        "cvt_from_bytes",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_from_bytes_u8x64",
+        "cvt_from_bytes",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_i32_f32x16",
+        "cvt_i32",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_s32_f32"]),
+            split(&["i32x4_relaxed_trunc_f32x4", "i32x4_trunc_sat_f32x4"]),
+            split(&["_mm_cvttps_epi32"]),
+            split(&["_mm_cvttps_epi32"]),
+            split(&["_mm256_cvttps_epi32"]),
+            native(&["_mm512_cvttps_epi32"]),
+        ],
+    ),
+    op(
+        "cvt_i32_f32x4",
+        "cvt_i32",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_s32_f32"]),
+            native(&["i32x4_relaxed_trunc_f32x4", "i32x4_trunc_sat_f32x4"]),
+            native(&["_mm_cvttps_epi32"]),
+            native(&["_mm_cvttps_epi32"]),
+            native(&["_mm_cvttps_epi32"]),
+            native(&["_mm_cvttps_epi32"]),
+        ],
+    ),
+    op(
+        "cvt_i32_f32x8",
+        "cvt_i32",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_s32_f32"]),
+            split(&["i32x4_relaxed_trunc_f32x4", "i32x4_trunc_sat_f32x4"]),
+            split(&["_mm_cvttps_epi32"]),
+            split(&["_mm_cvttps_epi32"]),
+            native(&["_mm256_cvttps_epi32"]),
+            native(&["_mm256_cvttps_epi32"]),
+        ],
+    ),
+    op(
+        "cvt_i32_precise_f32x16",
+        "cvt_i32_precise",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_s32_f32"]),
+            split(&["i32x4_trunc_sat_f32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+                "_mm_castps_si128",
+                "_mm_cmpord_ps",
+                "_mm_and_si128",
+            ]),
+            split(&[
+                "_mm256_cvttps_epi32",
+                "_mm256_cmp_ps",
+                "_mm256_set1_ps",
+                "_mm256_movemask_ps",
+                "_mm256_blendv_epi8",
+                "_mm256_set1_epi32",
+                "_mm256_castps_si256",
+                "_mm256_and_si256",
+            ]),
+            native(&[
+                "_mm512_cmp_ps_mask",
+                "_mm512_set1_ps",
+                "_mm512_mask_cvttps_epi32",
+                "_mm512_set1_epi32",
+                "_mm512_mask_blend_epi32",
+                "_mm512_setzero_si512",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_i32_precise_f32x4",
+        "cvt_i32_precise",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_s32_f32"]),
+            native(&["i32x4_trunc_sat_f32x4"]),
+            PORTABLE,
+            native(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+                "_mm_castps_si128",
+                "_mm_cmpord_ps",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+                "_mm_castps_si128",
+                "_mm_cmpord_ps",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_cmp_ps_mask",
+                "_mm_set1_ps",
+                "_mm_mask_cvttps_epi32",
+                "_mm_set1_epi32",
+                "_mm_mask_blend_epi32",
+                "_mm_setzero_si128",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_i32_precise_f32x8",
+        "cvt_i32_precise",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_s32_f32"]),
+            split(&["i32x4_trunc_sat_f32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+                "_mm_castps_si128",
+                "_mm_cmpord_ps",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_cvttps_epi32",
+                "_mm256_cmp_ps",
+                "_mm256_set1_ps",
+                "_mm256_movemask_ps",
+                "_mm256_blendv_epi8",
+                "_mm256_set1_epi32",
+                "_mm256_castps_si256",
+                "_mm256_and_si256",
+            ]),
+            native(&[
+                "_mm256_cmp_ps_mask",
+                "_mm256_set1_ps",
+                "_mm256_mask_cvttps_epi32",
+                "_mm256_set1_epi32",
+                "_mm256_mask_blend_epi32",
+                "_mm256_setzero_si256",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_f32x16",
+        "cvt_to_bytes",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_f32x4",
+        "cvt_to_bytes",
+        "f32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_f32x8",
+        "cvt_to_bytes",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_f64x2",
+        "cvt_to_bytes",
+        "f64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_f64x4",
+        "cvt_to_bytes",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_f64x8",
+        "cvt_to_bytes",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i16x16",
+        "cvt_to_bytes",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i16x32",
+        "cvt_to_bytes",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i16x8",
+        "cvt_to_bytes",
+        "i16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i32x16",
+        "cvt_to_bytes",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i32x4",
+        "cvt_to_bytes",
+        "i32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i32x8",
+        "cvt_to_bytes",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i64x2",
+        "cvt_to_bytes",
+        "i64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i64x4",
+        "cvt_to_bytes",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i64x8",
+        "cvt_to_bytes",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i8x16",
+        "cvt_to_bytes",
+        "i8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i8x32",
+        "cvt_to_bytes",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_i8x64",
+        "cvt_to_bytes",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u16x16",
+        "cvt_to_bytes",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u16x32",
+        "cvt_to_bytes",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u16x8",
+        "cvt_to_bytes",
+        "u16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u32x16",
+        "cvt_to_bytes",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u32x4",
+        "cvt_to_bytes",
+        "u32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u32x8",
+        "cvt_to_bytes",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u64x2",
+        "cvt_to_bytes",
+        "u64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u64x4",
+        "cvt_to_bytes",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u64x8",
+        "cvt_to_bytes",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u8x16",
+        "cvt_to_bytes",
+        "u8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u8x32",
+        "cvt_to_bytes",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_to_bytes_u8x64",
+        "cvt_to_bytes",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "cvt_u32_f32x16",
+        "cvt_u32",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_u32_f32"]),
+            split(&["u32x4_relaxed_trunc_f32x4", "u32x4_trunc_sat_f32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+            ]),
+            split(&[
+                "_mm256_cvttps_epi32",
+                "_mm256_cmp_ps",
+                "_mm256_set1_ps",
+                "_mm256_movemask_ps",
+                "_mm256_sub_ps",
+                "_mm256_andnot_ps",
+                "_mm256_add_epi32",
+            ]),
+            native(&["_mm512_cvttps_epu32"]),
+        ],
+    ),
+    op(
+        "cvt_u32_f32x4",
+        "cvt_u32",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_u32_f32"]),
+            native(&["u32x4_relaxed_trunc_f32x4", "u32x4_trunc_sat_f32x4"]),
+            PORTABLE,
+            native(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+            ]),
+            native(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+            ]),
+            native(&["_mm_cvttps_epu32"]),
+        ],
+    ),
+    op(
+        "cvt_u32_f32x8",
+        "cvt_u32",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_u32_f32"]),
+            split(&["u32x4_relaxed_trunc_f32x4", "u32x4_trunc_sat_f32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+            ]),
+            native(&[
+                "_mm256_cvttps_epi32",
+                "_mm256_cmp_ps",
+                "_mm256_set1_ps",
+                "_mm256_movemask_ps",
+                "_mm256_sub_ps",
+                "_mm256_andnot_ps",
+                "_mm256_add_epi32",
+            ]),
+            native(&["_mm256_cvttps_epu32"]),
+        ],
+    ),
+    op(
+        "cvt_u32_precise_f32x16",
+        "cvt_u32_precise",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_u32_f32"]),
+            split(&["u32x4_trunc_sat_f32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_max_ps",
+                "_mm_setzero_ps",
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_castps_si128",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+            ]),
+            split(&[
+                "_mm256_max_ps",
+                "_mm256_setzero_ps",
+                "_mm256_cvttps_epi32",
+                "_mm256_cmp_ps",
+                "_mm256_set1_ps",
+                "_mm256_movemask_ps",
+                "_mm256_castps_si256",
+                "_mm256_sub_ps",
+                "_mm256_andnot_ps",
+                "_mm256_add_epi32",
+                "_mm256_blendv_epi8",
+                "_mm256_set1_epi32",
+            ]),
+            native(&[
+                "_mm512_max_ps",
+                "_mm512_setzero_ps",
+                "_mm512_cvttps_epu32",
+                "_mm512_cmp_ps_mask",
+                "_mm512_set1_ps",
+                "_mm512_mask_blend_epi32",
+                "_mm512_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_u32_precise_f32x4",
+        "cvt_u32_precise",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_u32_f32"]),
+            native(&["u32x4_trunc_sat_f32x4"]),
+            PORTABLE,
+            native(&[
+                "_mm_max_ps",
+                "_mm_setzero_ps",
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_castps_si128",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm_max_ps",
+                "_mm_setzero_ps",
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_castps_si128",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm_max_ps",
+                "_mm_setzero_ps",
+                "_mm_cvttps_epu32",
+                "_mm_cmp_ps_mask",
+                "_mm_set1_ps",
+                "_mm_mask_blend_epi32",
+                "_mm_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "cvt_u32_precise_f32x8",
+        "cvt_u32_precise",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_u32_f32"]),
+            split(&["u32x4_trunc_sat_f32x4"]),
+            split(&[]),
+            split(&[
+                "_mm_max_ps",
+                "_mm_setzero_ps",
+                "_mm_cvttps_epi32",
+                "_mm_cmplt_ps",
+                "_mm_set1_ps",
+                "_mm_movemask_ps",
+                "_mm_castps_si128",
+                "_mm_sub_ps",
+                "_mm_andnot_ps",
+                "_mm_add_epi32",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm256_max_ps",
+                "_mm256_setzero_ps",
+                "_mm256_cvttps_epi32",
+                "_mm256_cmp_ps",
+                "_mm256_set1_ps",
+                "_mm256_movemask_ps",
+                "_mm256_castps_si256",
+                "_mm256_sub_ps",
+                "_mm256_andnot_ps",
+                "_mm256_add_epi32",
+                "_mm256_blendv_epi8",
+                "_mm256_set1_epi32",
+            ]),
+            native(&[
+                "_mm256_max_ps",
+                "_mm256_setzero_ps",
+                "_mm256_cvttps_epu32",
+                "_mm256_cmp_ps_mask",
+                "_mm256_set1_ps",
+                "_mm256_mask_blend_epi32",
+                "_mm256_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "deinterleave_f32x16",
+        "deinterleave",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f32", "vuzp2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&[
+                "_mm256_permutevar8x32_ps",
+                "_mm256_setr_epi32",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm512_permutex2var_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_f32x4",
+        "deinterleave",
+        "f32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "deinterleave_f32x8",
+        "deinterleave",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f32", "vuzp2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&["_mm_shuffle_ps"]),
+            native(&[
+                "_mm256_permutevar8x32_ps",
+                "_mm256_setr_epi32",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm256_permutex2var_ps", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_f64x2",
+        "deinterleave",
+        "f64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "deinterleave_f64x4",
+        "deinterleave",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f64", "vuzp2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm_shuffle_pd"]),
+            native(&["_mm256_permute4x64_pd", "_mm256_permute2f128_pd"]),
+            native(&["_mm256_permutex2var_pd", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "deinterleave_f64x8",
+        "deinterleave",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f64", "vuzp2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm256_permute4x64_pd", "_mm256_permute2f128_pd"]),
+            native(&["_mm512_permutex2var_pd", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "deinterleave_i16x16",
+        "deinterleave",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s16", "vuzp2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "deinterleave_i16x32",
+        "deinterleave",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s16", "vuzp2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "deinterleave_i16x8",
+        "deinterleave",
+        "i16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi16", "_mm_setr_epi16"]),
+        ],
+    ),
+    op(
+        "deinterleave_i32x16",
+        "deinterleave",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s32", "vuzp2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_i32x4",
+        "deinterleave",
+        "i32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi32", "_mm_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_i32x8",
+        "deinterleave",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s32", "vuzp2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_i64x2",
+        "deinterleave",
+        "i64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi64", "_mm_set_epi64x"]),
+        ],
+    ),
+    op(
+        "deinterleave_i64x4",
+        "deinterleave",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s64", "vuzp2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "deinterleave_i64x8",
+        "deinterleave",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s64", "vuzp2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "deinterleave_i8x16",
+        "deinterleave",
+        "i8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi8", "_mm_setr_epi8"]),
+        ],
+    ),
+    op(
+        "deinterleave_i8x32",
+        "deinterleave",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s8", "vuzp2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "deinterleave_i8x64",
+        "deinterleave",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s8", "vuzp2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "deinterleave_u16x16",
+        "deinterleave",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u16", "vuzp2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "deinterleave_u16x32",
+        "deinterleave",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u16", "vuzp2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "deinterleave_u16x8",
+        "deinterleave",
+        "u16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi16", "_mm_setr_epi16"]),
+        ],
+    ),
+    op(
+        "deinterleave_u32x16",
+        "deinterleave",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u32", "vuzp2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_u32x4",
+        "deinterleave",
+        "u32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi32", "_mm_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_u32x8",
+        "deinterleave",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u32", "vuzp2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm_shuffle_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "deinterleave_u64x2",
+        "deinterleave",
+        "u64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi64", "_mm_set_epi64x"]),
+        ],
+    ),
+    op(
+        "deinterleave_u64x4",
+        "deinterleave",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u64", "vuzp2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "deinterleave_u64x8",
+        "deinterleave",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u64", "vuzp2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "deinterleave_u8x16",
+        "deinterleave",
+        "u8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_permutex2var_epi8", "_mm_setr_epi8"]),
+        ],
+    ),
+    op(
+        "deinterleave_u8x32",
+        "deinterleave",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u8", "vuzp2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "deinterleave_u8x64",
+        "deinterleave",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u8", "vuzp2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "div_f32x16",
+        "div",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vdivq_f32"]),
+            split(&["f32x4_div"]),
+            split(&["_mm_div_ps"]),
+            split(&["_mm_div_ps"]),
+            split(&["_mm256_div_ps"]),
+            native(&["_mm512_div_ps"]),
+        ],
+    ),
+    op(
+        "div_f32x4",
+        "div",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vdivq_f32"]),
+            native(&["f32x4_div"]),
+            native(&["_mm_div_ps"]),
+            native(&["_mm_div_ps"]),
+            native(&["_mm_div_ps"]),
+            native(&["_mm_div_ps"]),
+        ],
+    ),
+    op(
+        "div_f32x8",
+        "div",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vdivq_f32"]),
+            split(&["f32x4_div"]),
+            split(&["_mm_div_ps"]),
+            split(&["_mm_div_ps"]),
+            native(&["_mm256_div_ps"]),
+            native(&["_mm256_div_ps"]),
+        ],
+    ),
+    op(
+        "div_f64x2",
+        "div",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vdivq_f64"]),
+            native(&["f64x2_div"]),
+            native(&["_mm_div_pd"]),
+            native(&["_mm_div_pd"]),
+            native(&["_mm_div_pd"]),
+            native(&["_mm_div_pd"]),
+        ],
+    ),
+    op(
+        "div_f64x4",
+        "div",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vdivq_f64"]),
+            split(&["f64x2_div"]),
+            split(&["_mm_div_pd"]),
+            split(&["_mm_div_pd"]),
+            native(&["_mm256_div_pd"]),
+            native(&["_mm256_div_pd"]),
+        ],
+    ),
+    op(
+        "div_f64x8",
+        "div",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vdivq_f64"]),
+            split(&["f64x2_div"]),
+            split(&["_mm_div_pd"]),
+            split(&["_mm_div_pd"]),
+            split(&["_mm256_div_pd"]),
+            native(&["_mm512_div_pd"]),
+        ],
+    ),
+    op(
+        "dot2_f32x16",
+        "dot2",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vmulq_f32", "vaddq_f32", "vrev64q_f32"]),
+            split(&["f32x4_mul", "f32x4_add", "i32x4_shuffle"]),
+            split(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            split(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            split(&["_mm256_mul_ps", "_mm256_add_ps", "_mm256_shuffle_ps"]),
+            native(&["_mm512_mul_ps", "_mm512_add_ps", "_mm512_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "dot2_f32x4",
+        "dot2",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vmulq_f32", "vaddq_f32", "vrev64q_f32"]),
+            native(&["f32x4_mul", "f32x4_add", "i32x4_shuffle"]),
+            native(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            native(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            native(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            native(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "dot2_f32x8",
+        "dot2",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vmulq_f32", "vaddq_f32", "vrev64q_f32"]),
+            split(&["f32x4_mul", "f32x4_add", "i32x4_shuffle"]),
+            split(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            split(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            native(&["_mm256_mul_ps", "_mm256_add_ps", "_mm256_shuffle_ps"]),
+            native(&["_mm256_mul_ps", "_mm256_add_ps", "_mm256_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "dot3_f32x16",
+        "dot3",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vmulq_f32", "vsetq_lane_f32", "vpaddq_f32"]),
+            split(&[
+                "f32x4_mul",
+                "f32x4_replace_lane",
+                "f32x4_add",
+                "i32x4_shuffle",
+            ]),
+            split(&[
+                "_mm_mul_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_set_epi32",
+                "_mm_add_ps",
+                "_mm_shuffle_ps",
+            ]),
+            split(&["_mm_dp_ps"]),
+            split(&["_mm256_dp_ps"]),
+            native(&[
+                "_mm512_mul_ps",
+                "_mm512_maskz_mov_ps",
+                "_mm512_add_ps",
+                "_mm512_shuffle_ps",
+            ]),
+        ],
+    ),
+    op(
+        "dot3_f32x4",
+        "dot3",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vmulq_f32", "vsetq_lane_f32", "vpaddq_f32"]),
+            native(&[
+                "f32x4_mul",
+                "f32x4_replace_lane",
+                "f32x4_add",
+                "i32x4_shuffle",
+            ]),
+            native(&[
+                "_mm_mul_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_set_epi32",
+                "_mm_add_ps",
+                "_mm_shuffle_ps",
+            ]),
+            native(&["_mm_dp_ps"]),
+            native(&["_mm_dp_ps"]),
+            native(&["_mm_dp_ps"]),
+        ],
+    ),
+    op(
+        "dot3_f32x8",
+        "dot3",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vmulq_f32", "vsetq_lane_f32", "vpaddq_f32"]),
+            split(&[
+                "f32x4_mul",
+                "f32x4_replace_lane",
+                "f32x4_add",
+                "i32x4_shuffle",
+            ]),
+            split(&[
+                "_mm_mul_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_set_epi32",
+                "_mm_add_ps",
+                "_mm_shuffle_ps",
+            ]),
+            split(&["_mm_dp_ps"]),
+            native(&["_mm256_dp_ps"]),
+            native(&["_mm256_dp_ps"]),
+        ],
+    ),
+    op(
+        "dot4_f32x16",
+        "dot4",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vmulq_f32", "vpaddq_f32"]),
+            split(&["f32x4_mul", "f32x4_add", "i32x4_shuffle"]),
+            split(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            split(&["_mm_dp_ps"]),
+            split(&["_mm256_dp_ps"]),
+            native(&["_mm512_mul_ps", "_mm512_add_ps", "_mm512_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "dot4_f32x4",
+        "dot4",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vmulq_f32", "vpaddq_f32"]),
+            native(&["f32x4_mul", "f32x4_add", "i32x4_shuffle"]),
+            native(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            native(&["_mm_dp_ps"]),
+            native(&["_mm_dp_ps"]),
+            native(&["_mm_dp_ps"]),
+        ],
+    ),
+    op(
+        "dot4_f32x8",
+        "dot4",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vmulq_f32", "vpaddq_f32"]),
+            split(&["f32x4_mul", "f32x4_add", "i32x4_shuffle"]),
+            split(&["_mm_mul_ps", "_mm_add_ps", "_mm_shuffle_ps"]),
+            split(&["_mm_dp_ps"]),
+            native(&["_mm256_dp_ps"]),
+            native(&["_mm256_dp_ps"]),
+        ],
+    ),
+    op(
+        "expand_bytes_u8x16",
+        "expand_bytes",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["i8x16_bitmask", "u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_maskz_expand_epi8"]),
+        ],
+    ),
+    op(
+        "expand_bytes_u8x32",
+        "expand_bytes",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+                "vqtbl1q_u8",
+            ]),
+            split(&["i8x16_bitmask", "u8x16_swizzle"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8", "_mm_shuffle_epi8"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_movemask_epi8",
+                "_mm256_setr_m128i",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm256_maskz_expand_epi8"]),
+        ],
+    ),
+    op(
+        "expand_bytes_u8x64",
+        "expand_bytes",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+                "vqtbl1q_u8",
+            ]),
+            split(&["i8x16_bitmask", "u8x16_swizzle"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8", "_mm_shuffle_epi8"]),
+            split(&[
+                "_mm256_movemask_epi8",
+                "_mm256_extracti128_si256",
+                "_mm_movemask_epi8",
+                "_mm256_setr_m128i",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm512_maskz_expand_epi8"]),
+        ],
+    ),
+    op(
+        "extract_dyn_f32x16",
+        "extract_dyn",
+        "f32x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_f32x4",
+        "extract_dyn",
+        "f32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_f32x8",
+        "extract_dyn",
+        "f32x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_f64x2",
+        "extract_dyn",
+        "f64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_f64x4",
+        "extract_dyn",
+        "f64x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_f64x8",
+        "extract_dyn",
+        "f64x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i16x16",
+        "extract_dyn",
+        "i16x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i16x32",
+        "extract_dyn",
+        "i16x32",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i16x8",
+        "extract_dyn",
+        "i16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i32x16",
+        "extract_dyn",
+        "i32x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i32x4",
+        "extract_dyn",
+        "i32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i32x8",
+        "extract_dyn",
+        "i32x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i64x2",
+        "extract_dyn",
+        "i64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i64x4",
+        "extract_dyn",
+        "i64x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i64x8",
+        "extract_dyn",
+        "i64x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i8x16",
+        "extract_dyn",
+        "i8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i8x32",
+        "extract_dyn",
+        "i8x32",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_i8x64",
+        "extract_dyn",
+        "i8x64",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u16x16",
+        "extract_dyn",
+        "u16x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u16x32",
+        "extract_dyn",
+        "u16x32",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u16x8",
+        "extract_dyn",
+        "u16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u32x16",
+        "extract_dyn",
+        "u32x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u32x4",
+        "extract_dyn",
+        "u32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u32x8",
+        "extract_dyn",
+        "u32x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u64x2",
+        "extract_dyn",
+        "u64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u64x4",
+        "extract_dyn",
+        "u64x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u64x8",
+        "extract_dyn",
+        "u64x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u8x16",
+        "extract_dyn",
+        "u8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u8x32",
+        "extract_dyn",
+        "u8x32",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_dyn_u8x64",
+        "extract_dyn",
+        "u8x64",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "extract_f32x16",
+        "extract",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_f32"]),
+            split(&["f32x4_extract_lane"]),
+            split(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            split(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            split(&["_mm256_extractf128_ps", "_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            split(&[
+                "_mm512_castps512_ps256",
+                "_mm512_extractf32x8_ps",
+                "_mm256_extractf128_ps",
+                "_mm_cvtss_f32",
+                "_mm_shuffle_ps",
+            ]),
+        ],
+    ),
+    op(
+        "extract_f32x4",
+        "extract",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_f32"]),
+            native(&["f32x4_extract_lane"]),
+            native(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            native(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            native(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            native(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "extract_f32x8",
+        "extract",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_f32"]),
+            split(&["f32x4_extract_lane"]),
+            split(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            split(&["_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            split(&["_mm256_extractf128_ps", "_mm_cvtss_f32", "_mm_shuffle_ps"]),
+            split(&["_mm256_extractf128_ps", "_mm_cvtss_f32", "_mm_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "extract_f64x2",
+        "extract",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_f64"]),
+            native(&["f64x2_extract_lane"]),
+            native(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+        ],
+    ),
+    op(
+        "extract_f64x4",
+        "extract",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_f64"]),
+            split(&["f64x2_extract_lane"]),
+            split(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            split(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            split(&["_mm256_extractf128_pd", "_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            split(&["_mm256_extractf128_pd", "_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+        ],
+    ),
+    op(
+        "extract_f64x8",
+        "extract",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_f64"]),
+            split(&["f64x2_extract_lane"]),
+            split(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            split(&["_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            split(&["_mm256_extractf128_pd", "_mm_cvtsd_f64", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm512_castpd512_pd256",
+                "_mm512_extractf64x4_pd",
+                "_mm256_extractf128_pd",
+                "_mm_cvtsd_f64",
+                "_mm_unpackhi_pd",
+            ]),
+        ],
+    ),
+    op(
+        "extract_i16x16",
+        "extract",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s16"]),
+            split(&["i16x8_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi16"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi16"]),
+        ],
+    ),
+    op(
+        "extract_i16x32",
+        "extract",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s16"]),
+            split(&["i16x8_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi16"]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_extract_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "extract_i16x8",
+        "extract",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_s16"]),
+            native(&["i16x8_extract_lane"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi16"]),
+        ],
+    ),
+    op(
+        "extract_i32x16",
+        "extract",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s32"]),
+            split(&["i32x4_extract_lane"]),
+            split(&["_mm_cvtsi128_si32", "_mm_shuffle_epi32"]),
+            split(&["_mm_extract_epi32"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi32"]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_extract_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "extract_i32x4",
+        "extract",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_s32"]),
+            native(&["i32x4_extract_lane"]),
+            native(&["_mm_cvtsi128_si32", "_mm_shuffle_epi32"]),
+            native(&["_mm_extract_epi32"]),
+            native(&["_mm_extract_epi32"]),
+            native(&["_mm_extract_epi32"]),
+        ],
+    ),
+    op(
+        "extract_i32x8",
+        "extract",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s32"]),
+            split(&["i32x4_extract_lane"]),
+            split(&["_mm_cvtsi128_si32", "_mm_shuffle_epi32"]),
+            split(&["_mm_extract_epi32"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi32"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi32"]),
+        ],
+    ),
+    op(
+        "extract_i64x2",
+        "extract",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_s64"]),
+            native(&["i64x2_extract_lane"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+        ],
+    ),
+    op(
+        "extract_i64x4",
+        "extract",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s64"]),
+            split(&["i64x2_extract_lane"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+        ],
+    ),
+    op(
+        "extract_i64x8",
+        "extract",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s64"]),
+            split(&["i64x2_extract_lane"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+        ],
+    ),
+    op(
+        "extract_i8x16",
+        "extract",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_s8"]),
+            native(&["i8x16_extract_lane"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi8"]),
+            native(&["_mm_extract_epi8"]),
+            native(&["_mm_extract_epi8"]),
+        ],
+    ),
+    op(
+        "extract_i8x32",
+        "extract",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s8"]),
+            split(&["i8x16_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi8"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi8"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi8"]),
+        ],
+    ),
+    op(
+        "extract_i8x64",
+        "extract",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_s8"]),
+            split(&["i8x16_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi8"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi8"]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_extract_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "extract_u16x16",
+        "extract",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u16"]),
+            split(&["u16x8_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi16"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi16"]),
+        ],
+    ),
+    op(
+        "extract_u16x32",
+        "extract",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u16"]),
+            split(&["u16x8_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi16"]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_extract_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "extract_u16x8",
+        "extract",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_u16"]),
+            native(&["u16x8_extract_lane"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi16"]),
+        ],
+    ),
+    op(
+        "extract_u32x16",
+        "extract",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u32"]),
+            split(&["u32x4_extract_lane"]),
+            split(&["_mm_cvtsi128_si32", "_mm_shuffle_epi32"]),
+            split(&["_mm_extract_epi32"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi32"]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_extract_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "extract_u32x4",
+        "extract",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_u32"]),
+            native(&["u32x4_extract_lane"]),
+            native(&["_mm_cvtsi128_si32", "_mm_shuffle_epi32"]),
+            native(&["_mm_extract_epi32"]),
+            native(&["_mm_extract_epi32"]),
+            native(&["_mm_extract_epi32"]),
+        ],
+    ),
+    op(
+        "extract_u32x8",
+        "extract",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u32"]),
+            split(&["u32x4_extract_lane"]),
+            split(&["_mm_cvtsi128_si32", "_mm_shuffle_epi32"]),
+            split(&["_mm_extract_epi32"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi32"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi32"]),
+        ],
+    ),
+    op(
+        "extract_u64x2",
+        "extract",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_u64"]),
+            native(&["u64x2_extract_lane"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            native(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+        ],
+    ),
+    op(
+        "extract_u64x4",
+        "extract",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u64"]),
+            split(&["u64x2_extract_lane"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+        ],
+    ),
+    op(
+        "extract_u64x8",
+        "extract",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u64"]),
+            split(&["u64x2_extract_lane"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_cvtsd_f64", "_mm_castsi128_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_cvtsd_f64",
+                "_mm_castsi128_pd",
+                "_mm_unpackhi_pd",
+            ]),
+        ],
+    ),
+    op(
+        "extract_u8x16",
+        "extract",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vgetq_lane_u8"]),
+            native(&["u8x16_extract_lane"]),
+            native(&["_mm_extract_epi16"]),
+            native(&["_mm_extract_epi8"]),
+            native(&["_mm_extract_epi8"]),
+            native(&["_mm_extract_epi8"]),
+        ],
+    ),
+    op(
+        "extract_u8x32",
+        "extract",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u8"]),
+            split(&["u8x16_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi8"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi8"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi8"]),
+        ],
+    ),
+    op(
+        "extract_u8x64",
+        "extract",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vgetq_lane_u8"]),
+            split(&["u8x16_extract_lane"]),
+            split(&["_mm_extract_epi16"]),
+            split(&["_mm_extract_epi8"]),
+            split(&["_mm256_extracti128_si256", "_mm_extract_epi8"]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm256_extracti128_si256",
+                "_mm_extract_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "floor_f32x16",
+        "floor",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vrndmq_f32"]),
+            split(&["f32x4_floor"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            split(&["_mm256_round_ps"]),
+            native(&["_mm512_roundscale_ps"]),
+        ],
+    ),
+    op(
+        "floor_f32x4",
+        "floor",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vrndmq_f32"]),
+            native(&["f32x4_floor"]),
+            PORTABLE,
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+        ],
+    ),
+    op(
+        "floor_f32x8",
+        "floor",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vrndmq_f32"]),
+            split(&["f32x4_floor"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            native(&["_mm256_round_ps"]),
+            native(&["_mm256_round_ps"]),
+        ],
+    ),
+    op(
+        "floor_f64x2",
+        "floor",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vrndmq_f64"]),
+            native(&["f64x2_floor"]),
+            PORTABLE,
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+        ],
+    ),
+    op(
+        "floor_f64x4",
+        "floor",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vrndmq_f64"]),
+            split(&["f64x2_floor"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            native(&["_mm256_round_pd"]),
+            native(&["_mm256_round_pd"]),
+        ],
+    ),
+    op(
+        "floor_f64x8",
+        "floor",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vrndmq_f64"]),
+            split(&["f64x2_floor"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            split(&["_mm256_round_pd"]),
+            native(&["_mm512_roundscale_pd"]),
+        ],
+    ),
+    op(
+        "fract_f32x16",
+        "fract",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vcvtq_s32_f32", "vcvtq_f32_s32", "vsubq_f32"]),
+            split(&["f32x4_sub", "f32x4_trunc"]),
+            split(&["_mm_sub_ps"]),
+            split(&["_mm_sub_ps", "_mm_round_ps"]),
+            split(&["_mm256_sub_ps", "_mm256_round_ps"]),
+            native(&["_mm512_sub_ps", "_mm512_roundscale_ps"]),
+        ],
+    ),
+    op(
+        "fract_f32x4",
+        "fract",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vcvtq_s32_f32", "vcvtq_f32_s32", "vsubq_f32"]),
+            native(&["f32x4_sub", "f32x4_trunc"]),
+            native(&["_mm_sub_ps"]),
+            native(&["_mm_sub_ps", "_mm_round_ps"]),
+            native(&["_mm_sub_ps", "_mm_round_ps"]),
+            native(&["_mm_sub_ps", "_mm_round_ps"]),
+        ],
+    ),
+    op(
+        "fract_f32x8",
+        "fract",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_s32_f32", "vcvtq_f32_s32", "vsubq_f32"]),
+            split(&["f32x4_sub", "f32x4_trunc"]),
+            split(&["_mm_sub_ps"]),
+            split(&["_mm_sub_ps", "_mm_round_ps"]),
+            native(&["_mm256_sub_ps", "_mm256_round_ps"]),
+            native(&["_mm256_sub_ps", "_mm256_round_ps"]),
+        ],
+    ),
+    op(
+        "fract_f64x2",
+        "fract",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vcvtq_s64_f64", "vcvtq_f64_s64", "vsubq_f64"]),
+            native(&["f64x2_sub", "f64x2_trunc"]),
+            native(&["_mm_sub_pd"]),
+            native(&["_mm_sub_pd", "_mm_round_pd"]),
+            native(&["_mm_sub_pd", "_mm_round_pd"]),
+            native(&["_mm_sub_pd", "_mm_round_pd"]),
+        ],
+    ),
+    op(
+        "fract_f64x4",
+        "fract",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vcvtq_s64_f64", "vcvtq_f64_s64", "vsubq_f64"]),
+            split(&["f64x2_sub", "f64x2_trunc"]),
+            split(&["_mm_sub_pd"]),
+            split(&["_mm_sub_pd", "_mm_round_pd"]),
+            native(&["_mm256_sub_pd", "_mm256_round_pd"]),
+            native(&["_mm256_sub_pd", "_mm256_round_pd"]),
+        ],
+    ),
+    op(
+        "fract_f64x8",
+        "fract",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vcvtq_s64_f64", "vcvtq_f64_s64", "vsubq_f64"]),
+            split(&["f64x2_sub", "f64x2_trunc"]),
+            split(&["_mm_sub_pd"]),
+            split(&["_mm_sub_pd", "_mm_round_pd"]),
+            split(&["_mm256_sub_pd", "_mm256_round_pd"]),
+            native(&["_mm512_sub_pd", "_mm512_roundscale_pd"]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask16x16",
+        "from_bitmask",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u16",
+                "vdupq_n_u16",
+                "vcltq_s16",
+                "vreinterpretq_s16_u16",
+                "vdupq_n_s16",
+            ]),
+            split(&["i16x8_splat", "v128_and", "i16x8_ne"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_set1_epi16",
+                "_mm256_setr_epi16",
+                "_mm256_cmpeq_epi16",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask16x32",
+        "from_bitmask",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u16",
+                "vdupq_n_u16",
+                "vcltq_s16",
+                "vreinterpretq_s16_u16",
+                "vdupq_n_s16",
+            ]),
+            split(&["i16x8_splat", "v128_and", "i16x8_ne"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_setr_epi16",
+                "_mm256_cmpeq_epi16",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask16x8",
+        "from_bitmask",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&[
+                "vshlq_u16",
+                "vdupq_n_u16",
+                "vcltq_s16",
+                "vreinterpretq_s16_u16",
+                "vdupq_n_s16",
+            ]),
+            native(&["i16x8_splat", "v128_and", "i16x8_ne"]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_setr_epi16",
+                "_mm_cmpeq_epi16",
+                "_mm_and_si128",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask32x16",
+        "from_bitmask",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u32",
+                "vdupq_n_u32",
+                "vcltq_s32",
+                "vreinterpretq_s32_u32",
+                "vdupq_n_s32",
+            ]),
+            split(&["i32x4_splat", "v128_and", "i32x4_ne"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_set1_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_cmpeq_epi32",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask32x4",
+        "from_bitmask",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&[
+                "vshlq_u32",
+                "vdupq_n_u32",
+                "vcltq_s32",
+                "vreinterpretq_s32_u32",
+                "vdupq_n_s32",
+            ]),
+            native(&["i32x4_splat", "v128_and", "i32x4_ne"]),
+            native(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask32x8",
+        "from_bitmask",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u32",
+                "vdupq_n_u32",
+                "vcltq_s32",
+                "vreinterpretq_s32_u32",
+                "vdupq_n_s32",
+            ]),
+            split(&["i32x4_splat", "v128_and", "i32x4_ne"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_setr_epi32",
+                "_mm_cmpeq_epi32",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_set1_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_cmpeq_epi32",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask64x2",
+        "from_bitmask",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vshlq_u64",
+                "vdupq_n_u64",
+                "vcltq_s64",
+                "vreinterpretq_s64_u64",
+                "vdupq_n_s64",
+            ]),
+            native(&["i64x2_splat", "v128_and", "i64x2_ne"]),
+            PORTABLE,
+            native(&[
+                "_mm_set1_epi64x",
+                "_mm_set_epi64x",
+                "_mm_cmpeq_epi64",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_set1_epi64x",
+                "_mm_set_epi64x",
+                "_mm_cmpeq_epi64",
+                "_mm_and_si128",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask64x4",
+        "from_bitmask",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u64",
+                "vdupq_n_u64",
+                "vcltq_s64",
+                "vreinterpretq_s64_u64",
+                "vdupq_n_s64",
+            ]),
+            split(&["i64x2_splat", "v128_and", "i64x2_ne"]),
+            split(&[]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_set_epi64x",
+                "_mm_cmpeq_epi64",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_set1_epi64x",
+                "_mm256_set_epi64x",
+                "_mm256_cmpeq_epi64",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask64x8",
+        "from_bitmask",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u64",
+                "vdupq_n_u64",
+                "vcltq_s64",
+                "vreinterpretq_s64_u64",
+                "vdupq_n_s64",
+            ]),
+            split(&["i64x2_splat", "v128_and", "i64x2_ne"]),
+            split(&[]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_set_epi64x",
+                "_mm_cmpeq_epi64",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_set1_epi64x",
+                "_mm256_set_epi64x",
+                "_mm256_cmpeq_epi64",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask8x16",
+        "from_bitmask",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&[
+                "vshlq_u16",
+                "vdupq_n_u16",
+                "vcltq_s16",
+                "vreinterpretq_s16_u16",
+                "vdupq_n_s16",
+                "vcombine_s8",
+                "vmovn_s16",
+            ]),
+            native(&["i8x16_splat", "u8x16_shuffle", "v128_and", "i8x16_ne"]),
+            PORTABLE,
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_shuffle_epi8",
+                "_mm_setr_epi8",
+                "_mm_cmpeq_epi8",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_shuffle_epi8",
+                "_mm_setr_epi8",
+                "_mm_cmpeq_epi8",
+                "_mm_and_si128",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask8x32",
+        "from_bitmask",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u16",
+                "vdupq_n_u16",
+                "vcltq_s16",
+                "vreinterpretq_s16_u16",
+                "vdupq_n_s16",
+                "vcombine_s8",
+                "vmovn_s16",
+            ]),
+            split(&["i8x16_splat", "u8x16_shuffle", "v128_and", "i8x16_ne"]),
+            split(&[]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_shuffle_epi8",
+                "_mm_setr_epi8",
+                "_mm_cmpeq_epi8",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_broadcastsi128_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_cmpeq_epi8",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "from_bitmask_mask8x64",
+        "from_bitmask",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&[
+                "vshlq_u16",
+                "vdupq_n_u16",
+                "vcltq_s16",
+                "vreinterpretq_s16_u16",
+                "vdupq_n_s16",
+                "vcombine_s8",
+                "vmovn_s16",
+            ]),
+            split(&["i8x16_splat", "u8x16_shuffle", "v128_and", "i8x16_ne"]),
+            split(&[]),
+            native(&[
+                "_mm_set1_epi64x",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_cmpeq_epi8",
+                "_mm_and_si128",
+            ]),
+            native(&[
+                "_mm256_set1_epi64x",
+                "_mm256_setr_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_cmpeq_epi8",
+                "_mm256_and_si256",
+            ]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "gf_mul_const_u8x16",
+        "gf_mul_const",
+        "u8x16",
+        [
+            PORTABLE,
+            split(&[
+                "vqtbl1q_u8",
+                "vandq_u8",
+                "vdupq_n_u8",
+                "vshlq_u8",
+                "vdupq_n_s8",
+                "veorq_u8",
+            ]),
+            split(&[
+                "u8x16_swizzle",
+                "v128_and",
+                "u8x16_splat",
+                "u8x16_shr",
+                "v128_xor",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm_shuffle_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm_shuffle_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&["_mm_gf2p8mul_epi8", "_mm_set1_epi8"]),
+        ],
+    ),
+    op(
+        "gf_mul_const_u8x32",
+        "gf_mul_const",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&[
+                "vqtbl1q_u8",
+                "vandq_u8",
+                "vdupq_n_u8",
+                "vshlq_u8",
+                "vdupq_n_s8",
+                "veorq_u8",
+            ]),
+            split(&[
+                "u8x16_swizzle",
+                "v128_and",
+                "u8x16_splat",
+                "u8x16_shr",
+                "v128_xor",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm_shuffle_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm256_shuffle_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_xor_si256",
+            ]),
+            native(&["_mm256_gf2p8mul_epi8", "_mm256_set1_epi8"]),
+        ],
+    ),
+    op(
+        "gf_mul_const_u8x64",
+        "gf_mul_const",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&[
+                "vqtbl1q_u8",
+                "vandq_u8",
+                "vdupq_n_u8",
+                "vshlq_u8",
+                "vdupq_n_s8",
+                "veorq_u8",
+            ]),
+            split(&[
+                "u8x16_swizzle",
+                "v128_and",
+                "u8x16_splat",
+                "u8x16_shr",
+                "v128_xor",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm_shuffle_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm256_shuffle_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_xor_si256",
+            ]),
+            native(&["_mm512_gf2p8mul_epi8", "_mm512_set1_epi8"]),
+        ],
+    ),
+    op(
+        "insert_f32x16",
+        "insert",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_f32"]),
+            split(&["f32x4_replace_lane"]),
+            split(&[
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_castsi128_ps",
+                "_mm_insert_epi16",
+                "_mm_castps_si128",
+            ]),
+            split(&["_mm_move_ss", "_mm_set_ss", "_mm_insert_ps"]),
+            split(&[
+                "_mm256_extractf128_ps",
+                "_mm256_setr_m128",
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_insert_ps",
+            ]),
+            split(&[
+                "_mm512_castps512_ps256",
+                "_mm512_extractf32x8_ps",
+                "_mm512_insertf32x8",
+                "_mm512_castps256_ps512",
+                "_mm256_extractf128_ps",
+                "_mm256_setr_m128",
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_insert_ps",
+            ]),
+        ],
+    ),
+    op(
+        "insert_f32x4",
+        "insert",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_f32"]),
+            native(&["f32x4_replace_lane"]),
+            native(&[
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_castsi128_ps",
+                "_mm_insert_epi16",
+                "_mm_castps_si128",
+            ]),
+            native(&["_mm_move_ss", "_mm_set_ss", "_mm_insert_ps"]),
+            native(&["_mm_move_ss", "_mm_set_ss", "_mm_insert_ps"]),
+            native(&["_mm_move_ss", "_mm_set_ss", "_mm_insert_ps"]),
+        ],
+    ),
+    op(
+        "insert_f32x8",
+        "insert",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_f32"]),
+            split(&["f32x4_replace_lane"]),
+            split(&[
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_castsi128_ps",
+                "_mm_insert_epi16",
+                "_mm_castps_si128",
+            ]),
+            split(&["_mm_move_ss", "_mm_set_ss", "_mm_insert_ps"]),
+            split(&[
+                "_mm256_extractf128_ps",
+                "_mm256_setr_m128",
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_insert_ps",
+            ]),
+            split(&[
+                "_mm256_extractf128_ps",
+                "_mm256_setr_m128",
+                "_mm_move_ss",
+                "_mm_set_ss",
+                "_mm_insert_ps",
+            ]),
+        ],
+    ),
+    op(
+        "insert_f64x2",
+        "insert",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_f64"]),
+            native(&["f64x2_replace_lane"]),
+            native(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            native(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            native(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            native(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+        ],
+    ),
+    op(
+        "insert_f64x4",
+        "insert",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_f64"]),
+            split(&["f64x2_replace_lane"]),
+            split(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            split(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            split(&[
+                "_mm256_extractf128_pd",
+                "_mm256_setr_m128d",
+                "_mm_move_sd",
+                "_mm_set_sd",
+                "_mm_unpacklo_pd",
+            ]),
+            split(&[
+                "_mm256_extractf128_pd",
+                "_mm256_setr_m128d",
+                "_mm_move_sd",
+                "_mm_set_sd",
+                "_mm_unpacklo_pd",
+            ]),
+        ],
+    ),
+    op(
+        "insert_f64x8",
+        "insert",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_f64"]),
+            split(&["f64x2_replace_lane"]),
+            split(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            split(&["_mm_move_sd", "_mm_set_sd", "_mm_unpacklo_pd"]),
+            split(&[
+                "_mm256_extractf128_pd",
+                "_mm256_setr_m128d",
+                "_mm_move_sd",
+                "_mm_set_sd",
+                "_mm_unpacklo_pd",
+            ]),
+            split(&[
+                "_mm512_castpd512_pd256",
+                "_mm512_extractf64x4_pd",
+                "_mm512_insertf64x4",
+                "_mm512_castpd256_pd512",
+                "_mm256_extractf128_pd",
+                "_mm256_setr_m128d",
+                "_mm_move_sd",
+                "_mm_set_sd",
+                "_mm_unpacklo_pd",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i16x16",
+        "insert",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s16"]),
+            split(&["i16x8_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi16"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i16x32",
+        "insert",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s16"]),
+            split(&["i16x8_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi16"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i16x8",
+        "insert",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_s16"]),
+            native(&["i16x8_replace_lane"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi16"]),
+        ],
+    ),
+    op(
+        "insert_i32x16",
+        "insert",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s32"]),
+            split(&["i32x4_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi32"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i32x4",
+        "insert",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_s32"]),
+            native(&["i32x4_replace_lane"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi32"]),
+            native(&["_mm_insert_epi32"]),
+            native(&["_mm_insert_epi32"]),
+        ],
+    ),
+    op(
+        "insert_i32x8",
+        "insert",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s32"]),
+            split(&["i32x4_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi32"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i64x2",
+        "insert",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_s64"]),
+            native(&["i64x2_replace_lane"]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i64x4",
+        "insert",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s64"]),
+            split(&["i64x2_replace_lane"]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i64x8",
+        "insert",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s64"]),
+            split(&["i64x2_replace_lane"]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i8x16",
+        "insert",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_s8"]),
+            native(&["i8x16_replace_lane"]),
+            native(&["_mm_insert_epi16", "_mm_extract_epi16"]),
+            native(&["_mm_insert_epi8"]),
+            native(&["_mm_insert_epi8"]),
+            native(&["_mm_insert_epi8"]),
+        ],
+    ),
+    op(
+        "insert_i8x32",
+        "insert",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s8"]),
+            split(&["i8x16_replace_lane"]),
+            split(&["_mm_insert_epi16", "_mm_extract_epi16"]),
+            split(&["_mm_insert_epi8"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "insert_i8x64",
+        "insert",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_s8"]),
+            split(&["i8x16_replace_lane"]),
+            split(&["_mm_insert_epi16", "_mm_extract_epi16"]),
+            split(&["_mm_insert_epi8"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u16x16",
+        "insert",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u16"]),
+            split(&["u16x8_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi16"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u16x32",
+        "insert",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u16"]),
+            split(&["u16x8_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi16"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u16x8",
+        "insert",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_u16"]),
+            native(&["u16x8_replace_lane"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi16"]),
+        ],
+    ),
+    op(
+        "insert_u32x16",
+        "insert",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u32"]),
+            split(&["u32x4_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi32"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u32x4",
+        "insert",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_u32"]),
+            native(&["u32x4_replace_lane"]),
+            native(&["_mm_insert_epi16"]),
+            native(&["_mm_insert_epi32"]),
+            native(&["_mm_insert_epi32"]),
+            native(&["_mm_insert_epi32"]),
+        ],
+    ),
+    op(
+        "insert_u32x8",
+        "insert",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u32"]),
+            split(&["u32x4_replace_lane"]),
+            split(&["_mm_insert_epi16"]),
+            split(&["_mm_insert_epi32"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u64x2",
+        "insert",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_u64"]),
+            native(&["u64x2_replace_lane"]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            native(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u64x4",
+        "insert",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u64"]),
+            split(&["u64x2_replace_lane"]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u64x8",
+        "insert",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u64"]),
+            split(&["u64x2_replace_lane"]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_unpackhi_epi64",
+                "_mm_set1_epi64x",
+                "_mm_unpacklo_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u8x16",
+        "insert",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vsetq_lane_u8"]),
+            native(&["u8x16_replace_lane"]),
+            native(&["_mm_insert_epi16", "_mm_extract_epi16"]),
+            native(&["_mm_insert_epi8"]),
+            native(&["_mm_insert_epi8"]),
+            native(&["_mm_insert_epi8"]),
+        ],
+    ),
+    op(
+        "insert_u8x32",
+        "insert",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u8"]),
+            split(&["u8x16_replace_lane"]),
+            split(&["_mm_insert_epi16", "_mm_extract_epi16"]),
+            split(&["_mm_insert_epi8"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "insert_u8x64",
+        "insert",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vsetq_lane_u8"]),
+            split(&["u8x16_replace_lane"]),
+            split(&["_mm_insert_epi16", "_mm_extract_epi16"]),
+            split(&["_mm_insert_epi8"]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+            split(&[
+                "_mm512_castsi512_si256",
+                "_mm512_extracti64x4_epi64",
+                "_mm512_inserti64x4",
+                "_mm512_castsi256_si512",
+                "_mm256_extracti128_si256",
+                "_mm256_setr_m128i",
+                "_mm_insert_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "interleave_f32x16",
+        "interleave",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_f32", "vzip2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&[
+                "_mm256_unpacklo_ps",
+                "_mm256_unpackhi_ps",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm512_permutex2var_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "interleave_f32x4",
+        "interleave",
+        "f32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_f32x8",
+        "interleave",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_f32", "vzip2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            native(&[
+                "_mm256_unpacklo_ps",
+                "_mm256_unpackhi_ps",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm256_permutex2var_ps", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "interleave_f64x2",
+        "interleave",
+        "f64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_f64x4",
+        "interleave",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_f64", "vzip2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            native(&[
+                "_mm256_unpacklo_pd",
+                "_mm256_unpackhi_pd",
+                "_mm256_permute2f128_pd",
+            ]),
+            native(&["_mm256_permutex2var_pd", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "interleave_f64x8",
+        "interleave",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_f64", "vzip2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_unpacklo_pd",
+                "_mm256_unpackhi_pd",
+                "_mm256_permute2f128_pd",
+            ]),
+            native(&["_mm512_permutex2var_pd", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "interleave_i16x16",
+        "interleave",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_s16", "vzip2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            native(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "interleave_i16x32",
+        "interleave",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_s16", "vzip2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "interleave_i16x8",
+        "interleave",
+        "i16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_i32x16",
+        "interleave",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_s32", "vzip2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "interleave_i32x4",
+        "interleave",
+        "i32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_i32x8",
+        "interleave",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_s32", "vzip2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            native(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "interleave_i64x2",
+        "interleave",
+        "i64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_i64x4",
+        "interleave",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_s64", "vzip2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "interleave_i64x8",
+        "interleave",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_s64", "vzip2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "interleave_i8x16",
+        "interleave",
+        "i8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_i8x32",
+        "interleave",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_s8", "vzip2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            native(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "interleave_i8x64",
+        "interleave",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vzip1q_s8", "vzip2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "interleave_u16x16",
+        "interleave",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_u16", "vzip2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            native(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "interleave_u16x32",
+        "interleave",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_u16", "vzip2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "interleave_u16x8",
+        "interleave",
+        "u16x8",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_u32x16",
+        "interleave",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_u32", "vzip2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "interleave_u32x4",
+        "interleave",
+        "u32x4",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_u32x8",
+        "interleave",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_u32", "vzip2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            native(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "interleave_u64x2",
+        "interleave",
+        "u64x2",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_u64x4",
+        "interleave",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_u64", "vzip2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "interleave_u64x8",
+        "interleave",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_u64", "vzip2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "interleave_u8x16",
+        "interleave",
+        "u8x16",
+        [
+            PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE, PORTABLE,
+        ],
+    ),
+    op(
+        "interleave_u8x32",
+        "interleave",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_u8", "vzip2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            native(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "interleave_u8x64",
+        "interleave",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vzip1q_u8", "vzip2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i16x16",
+        "leading_zeros",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vclzq_s16"]),
+            split(&[
+                "v128_or",
+                "i16x8_shr",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm256_popcnt_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_i16x32",
+        "leading_zeros",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vclzq_s16"]),
+            split(&[
+                "v128_or",
+                "i16x8_shr",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm256_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm512_or_si512",
+                "_mm512_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm512_popcnt_epi16",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_i16x8",
+        "leading_zeros",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vclzq_s16"]),
+            native(&[
+                "v128_or",
+                "i16x8_shr",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_sra_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_popcnt_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_i32x16",
+        "leading_zeros",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vclzq_s32"]),
+            split(&[
+                "v128_or",
+                "i32x4_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_sra_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm256_sra_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+            ]),
+            native(&["_mm512_lzcnt_epi32"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i32x4",
+        "leading_zeros",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vclzq_s32"]),
+            native(&[
+                "v128_or",
+                "i32x4_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_sra_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_sra_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            native(&["_mm_lzcnt_epi32"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i32x8",
+        "leading_zeros",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vclzq_s32"]),
+            split(&[
+                "v128_or",
+                "i32x4_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_sra_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_sra_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+            ]),
+            native(&["_mm256_lzcnt_epi32"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i64x2",
+        "leading_zeros",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vorrq_s64",
+                "vshlq_s64",
+                "vdupq_n_s64",
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            native(&[
+                "v128_or",
+                "i64x2_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            native(&["_mm_lzcnt_epi64"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i64x4",
+        "leading_zeros",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vorrq_s64",
+                "vshlq_s64",
+                "vdupq_n_s64",
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            split(&[
+                "v128_or",
+                "i64x2_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+            ]),
+            native(&["_mm256_lzcnt_epi64"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i64x8",
+        "leading_zeros",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vorrq_s64",
+                "vshlq_s64",
+                "vdupq_n_s64",
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            split(&[
+                "v128_or",
+                "i64x2_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+            ]),
+            native(&["_mm512_lzcnt_epi64"]),
+        ],
+    ),
+    op(
+        "leading_zeros_i8x16",
+        "leading_zeros",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vclzq_s8"]),
+            native(&["v128_or", "i8x16_shr", "i8x16_popcnt", "v128_not"]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+                "_mm_popcnt_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_i8x32",
+        "leading_zeros",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vclzq_s8"]),
+            split(&["v128_or", "i8x16_shr", "i8x16_popcnt", "v128_not"]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sra_epi16",
+                "_mm256_packs_epi16",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sra_epi16",
+                "_mm256_packs_epi16",
+                "_mm256_popcnt_epi8",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_i8x64",
+        "leading_zeros",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vclzq_s8"]),
+            split(&["v128_or", "i8x16_shr", "i8x16_popcnt", "v128_not"]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sra_epi16",
+                "_mm256_packs_epi16",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm512_or_si512",
+                "_mm_cvtsi32_si128",
+                "_mm512_unpacklo_epi8",
+                "_mm512_movm_epi8",
+                "_mm512_cmpgt_epi8_mask",
+                "_mm512_setzero_si512",
+                "_mm512_unpackhi_epi8",
+                "_mm512_sra_epi16",
+                "_mm512_packs_epi16",
+                "_mm512_popcnt_epi8",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_u16x16",
+        "leading_zeros",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vclzq_u16"]),
+            split(&[
+                "v128_or",
+                "u16x8_shr",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm256_popcnt_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_u16x32",
+        "leading_zeros",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vclzq_u16"]),
+            split(&[
+                "v128_or",
+                "u16x8_shr",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm512_or_si512",
+                "_mm512_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm512_popcnt_epi16",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_u16x8",
+        "leading_zeros",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vclzq_u16"]),
+            native(&[
+                "v128_or",
+                "u16x8_shr",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi16",
+                "_mm_cvtsi32_si128",
+                "_mm_popcnt_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_u32x16",
+        "leading_zeros",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vclzq_u32"]),
+            split(&[
+                "v128_or",
+                "u32x4_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_srl_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+            ]),
+            native(&["_mm512_lzcnt_epi32"]),
+        ],
+    ),
+    op(
+        "leading_zeros_u32x4",
+        "leading_zeros",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vclzq_u32"]),
+            native(&[
+                "v128_or",
+                "u32x4_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            native(&["_mm_lzcnt_epi32"]),
+        ],
+    ),
+    op(
+        "leading_zeros_u32x8",
+        "leading_zeros",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vclzq_u32"]),
+            split(&[
+                "v128_or",
+                "u32x4_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_srl_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi32",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+            ]),
+            native(&["_mm256_lzcnt_epi32"]),
+        ],
+    ),
+    op(
+        "leading_zeros_u64x2",
+        "leading_zeros",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vorrq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+            ]),
+            native(&[
+                "v128_or",
+                "u64x2_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "v128_not",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            native(&["_mm_lzcnt_epi64"]),
+        ],
+    ),
+    op(
+        "leading_zeros_u64x4",
+        "leading_zeros",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vorrq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+            ]),
+            split(&[
+                "v128_or",
+                "u64x2_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+            ]),
+            native(&["_mm256_lzcnt_epi64"]),
+        ],
+    ),
+    op(
+        "leading_zeros_u64x8",
+        "leading_zeros",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vorrq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+            ]),
+            split(&[
+                "v128_or",
+                "u64x2_shr",
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "v128_not",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm256_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+            ]),
+            native(&["_mm512_lzcnt_epi64"]),
+        ],
+    ),
+    op(
+        "leading_zeros_u8x16",
+        "leading_zeros",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vclzq_u8"]),
+            native(&["v128_or", "u8x16_shr", "i8x16_popcnt", "v128_not"]),
+            PORTABLE,
+            native(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_popcnt_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_u8x32",
+        "leading_zeros",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vclzq_u8"]),
+            split(&["v128_or", "u8x16_shr", "i8x16_popcnt", "v128_not"]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm256_or_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_popcnt_epi8",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "leading_zeros_u8x64",
+        "leading_zeros",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vclzq_u8"]),
+            split(&["v128_or", "u8x16_shr", "i8x16_popcnt", "v128_not"]),
+            split(&[]),
+            split(&[
+                "_mm_or_si128",
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+            ]),
+            split(&[
+                "_mm256_or_si256",
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+            ]),
+            native(&[
+                "_mm512_or_si512",
+                "_mm_cvtsi32_si128",
+                "_mm512_unpacklo_epi8",
+                "_mm512_setzero_si512",
+                "_mm512_unpackhi_epi8",
+                "_mm512_srl_epi16",
+                "_mm512_packus_epi16",
+                "_mm512_popcnt_epi8",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "load_array_f32x16",
+        "load_array",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_f32x4",
+        "load_array",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_f32x8",
+        "load_array",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_f64x2",
+        "load_array",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_f64x4",
+        "load_array",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_f64x8",
+        "load_array",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i16x16",
+        "load_array",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i16x32",
+        "load_array",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i16x8",
+        "load_array",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i32x16",
+        "load_array",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i32x4",
+        "load_array",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i32x8",
+        "load_array",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i64x2",
+        "load_array",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i64x4",
+        "load_array",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i64x8",
+        "load_array",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i8x16",
+        "load_array",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i8x32",
+        "load_array",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_i8x64",
+        "load_array",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_mask16x16",
+        "load_array",
+        "mask16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movepi16_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask16x32",
+        "load_array",
+        "mask16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movepi16_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask16x8",
+        "load_array",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movepi16_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask32x16",
+        "load_array",
+        "mask32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movepi32_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask32x4",
+        "load_array",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movepi32_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask32x8",
+        "load_array",
+        "mask32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movepi32_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask64x2",
+        "load_array",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movepi64_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask64x4",
+        "load_array",
+        "mask64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movepi64_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask64x8",
+        "load_array",
+        "mask64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movepi64_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask8x16",
+        "load_array",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm_movepi8_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask8x32",
+        "load_array",
+        "mask8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_movepi8_mask"]),
+        ],
+    ),
+    op(
+        "load_array_mask8x64",
+        "load_array",
+        "mask8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_movepi8_mask"]),
+        ],
+    ),
+    op(
+        "load_array_ref_f32x16",
+        "load_array_ref",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_f32x4",
+        "load_array_ref",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_f32x8",
+        "load_array_ref",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_f64x2",
+        "load_array_ref",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_f64x4",
+        "load_array_ref",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_f64x8",
+        "load_array_ref",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i16x16",
+        "load_array_ref",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i16x32",
+        "load_array_ref",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i16x8",
+        "load_array_ref",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i32x16",
+        "load_array_ref",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i32x4",
+        "load_array_ref",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i32x8",
+        "load_array_ref",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i64x2",
+        "load_array_ref",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i64x4",
+        "load_array_ref",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i64x8",
+        "load_array_ref",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i8x16",
+        "load_array_ref",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i8x32",
+        "load_array_ref",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_i8x64",
+        "load_array_ref",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u16x16",
+        "load_array_ref",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u16x32",
+        "load_array_ref",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u16x8",
+        "load_array_ref",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u32x16",
+        "load_array_ref",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u32x4",
+        "load_array_ref",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u32x8",
+        "load_array_ref",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u64x2",
+        "load_array_ref",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u64x4",
+        "load_array_ref",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u64x8",
+        "load_array_ref",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u8x16",
+        "load_array_ref",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u8x32",
+        "load_array_ref",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_ref_u8x64",
+        "load_array_ref",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u16x16",
+        "load_array",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u16x32",
+        "load_array",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u16x8",
+        "load_array",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u32x16",
+        "load_array",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u32x4",
+        "load_array",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u32x8",
+        "load_array",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u64x2",
+        "load_array",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u64x4",
+        "load_array",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u64x8",
+        "load_array",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u8x16",
+        "load_array",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u8x32",
+        "load_array",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_array_u8x64",
+        "load_array",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "load_interleaved_128_f32x16",
+        "load_interleaved_128",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&["vld4q_f32"]),
+            native(&["u32x4_shuffle"]),
+            native(&[
+                "_mm_unpacklo_ps",
+                "_mm_unpackhi_ps",
+                "_mm_castpd_ps",
+                "_mm_unpacklo_pd",
+                "_mm_castps_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            native(&[
+                "_mm_unpacklo_ps",
+                "_mm_unpackhi_ps",
+                "_mm_castpd_ps",
+                "_mm_unpacklo_pd",
+                "_mm_castps_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            native(&[
+                "_mm_unpacklo_ps",
+                "_mm_unpackhi_ps",
+                "_mm_castpd_ps",
+                "_mm_unpacklo_pd",
+                "_mm_castps_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            native(&["_mm512_permutexvar_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "load_interleaved_128_u16x32",
+        "load_interleaved_128",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&["vld4q_u16"]),
+            native(&["u16x8_shuffle"]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&["_mm512_permutexvar_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "load_interleaved_128_u32x16",
+        "load_interleaved_128",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&["vld4q_u32"]),
+            native(&["u32x4_shuffle"]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&["_mm512_permutexvar_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "load_interleaved_128_u64x8",
+        "load_interleaved_128",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&["vld4q_u64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutexvar_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "load_interleaved_128_u8x64",
+        "load_interleaved_128",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&["vld4q_u8"]),
+            native(&["u8x16_shuffle"]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&["_mm512_permutexvar_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "max_f32x16",
+        "max",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vmaxq_f32"]),
+            split(&["f32x4_relaxed_max", "f32x4_max"]),
+            split(&["_mm_max_ps"]),
+            split(&["_mm_max_ps"]),
+            split(&["_mm256_max_ps"]),
+            native(&["_mm512_max_ps"]),
+        ],
+    ),
+    op(
+        "max_f32x4",
+        "max",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vmaxq_f32"]),
+            native(&["f32x4_relaxed_max", "f32x4_max"]),
+            native(&["_mm_max_ps"]),
+            native(&["_mm_max_ps"]),
+            native(&["_mm_max_ps"]),
+            native(&["_mm_max_ps"]),
+        ],
+    ),
+    op(
+        "max_f32x8",
+        "max",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vmaxq_f32"]),
+            split(&["f32x4_relaxed_max", "f32x4_max"]),
+            split(&["_mm_max_ps"]),
+            split(&["_mm_max_ps"]),
+            native(&["_mm256_max_ps"]),
+            native(&["_mm256_max_ps"]),
+        ],
+    ),
+    op(
+        "max_f64x2",
+        "max",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vmaxq_f64"]),
+            native(&["f64x2_relaxed_max", "f64x2_max"]),
+            native(&["_mm_max_pd"]),
+            native(&["_mm_max_pd"]),
+            native(&["_mm_max_pd"]),
+            native(&["_mm_max_pd"]),
+        ],
+    ),
+    op(
+        "max_f64x4",
+        "max",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vmaxq_f64"]),
+            split(&["f64x2_relaxed_max", "f64x2_max"]),
+            split(&["_mm_max_pd"]),
+            split(&["_mm_max_pd"]),
+            native(&["_mm256_max_pd"]),
+            native(&["_mm256_max_pd"]),
+        ],
+    ),
+    op(
+        "max_f64x8",
+        "max",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vmaxq_f64"]),
+            split(&["f64x2_relaxed_max", "f64x2_max"]),
+            split(&["_mm_max_pd"]),
+            split(&["_mm_max_pd"]),
+            split(&["_mm256_max_pd"]),
+            native(&["_mm512_max_pd"]),
+        ],
+    ),
+    op(
+        "max_i16x16",
+        "max",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vmaxq_s16"]),
+            split(&["i16x8_max"]),
+            split(&["_mm_max_epi16"]),
+            split(&["_mm_max_epi16"]),
+            native(&["_mm256_max_epi16"]),
+            native(&["_mm256_max_epi16"]),
+        ],
+    ),
+    op(
+        "max_i16x32",
+        "max",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vmaxq_s16"]),
+            split(&["i16x8_max"]),
+            split(&["_mm_max_epi16"]),
+            split(&["_mm_max_epi16"]),
+            split(&["_mm256_max_epi16"]),
+            native(&["_mm512_max_epi16"]),
+        ],
+    ),
+    op(
+        "max_i16x8",
+        "max",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vmaxq_s16"]),
+            native(&["i16x8_max"]),
+            native(&["_mm_max_epi16"]),
+            native(&["_mm_max_epi16"]),
+            native(&["_mm_max_epi16"]),
+            native(&["_mm_max_epi16"]),
+        ],
+    ),
+    op(
+        "max_i32x16",
+        "max",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vmaxq_s32"]),
+            split(&["i32x4_max"]),
+            split(&[
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epi32"]),
+            split(&["_mm256_max_epi32"]),
+            native(&["_mm512_max_epi32"]),
+        ],
+    ),
+    op(
+        "max_i32x4",
+        "max",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vmaxq_s32"]),
+            native(&["i32x4_max"]),
+            native(&[
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_max_epi32"]),
+            native(&["_mm_max_epi32"]),
+            native(&["_mm_max_epi32"]),
+        ],
+    ),
+    op(
+        "max_i32x8",
+        "max",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vmaxq_s32"]),
+            split(&["i32x4_max"]),
+            split(&[
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epi32"]),
+            native(&["_mm256_max_epi32"]),
+            native(&["_mm256_max_epi32"]),
+        ],
+    ),
+    op(
+        "max_i64x2",
+        "max",
+        "i64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_max_epi64"]),
+        ],
+    ),
+    op(
+        "max_i64x4",
+        "max",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_max_epi64"]),
+        ],
+    ),
+    op(
+        "max_i64x8",
+        "max",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_max_epi64"]),
+        ],
+    ),
+    op(
+        "max_i8x16",
+        "max",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vmaxq_s8"]),
+            native(&["i8x16_max"]),
+            native(&[
+                "_mm_cmpgt_epi8",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_max_epi8"]),
+            native(&["_mm_max_epi8"]),
+            native(&["_mm_max_epi8"]),
+        ],
+    ),
+    op(
+        "max_i8x32",
+        "max",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vmaxq_s8"]),
+            split(&["i8x16_max"]),
+            split(&[
+                "_mm_cmpgt_epi8",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epi8"]),
+            native(&["_mm256_max_epi8"]),
+            native(&["_mm256_max_epi8"]),
+        ],
+    ),
+    op(
+        "max_i8x64",
+        "max",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vmaxq_s8"]),
+            split(&["i8x16_max"]),
+            split(&[
+                "_mm_cmpgt_epi8",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epi8"]),
+            split(&["_mm256_max_epi8"]),
+            native(&["_mm512_max_epi8"]),
+        ],
+    ),
+    op(
+        "max_precise_f32x16",
+        "max_precise",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vmaxnmq_f32"]),
+            split(&["f32x4_pmax", "f32x4_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_max_ps",
+                "_mm_cmpunord_ps",
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_max_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            split(&["_mm256_max_ps", "_mm256_cmp_ps", "_mm256_blendv_ps"]),
+            native(&["_mm512_range_ps"]),
+        ],
+    ),
+    op(
+        "max_precise_f32x4",
+        "max_precise",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vmaxnmq_f32"]),
+            native(&["f32x4_pmax", "f32x4_ne", "v128_bitselect"]),
+            native(&[
+                "_mm_max_ps",
+                "_mm_cmpunord_ps",
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_andnot_ps",
+            ]),
+            native(&["_mm_max_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            native(&["_mm_max_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            native(&["_mm_range_ps"]),
+        ],
+    ),
+    op(
+        "max_precise_f32x8",
+        "max_precise",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vmaxnmq_f32"]),
+            split(&["f32x4_pmax", "f32x4_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_max_ps",
+                "_mm_cmpunord_ps",
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_max_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            native(&["_mm256_max_ps", "_mm256_cmp_ps", "_mm256_blendv_ps"]),
+            native(&["_mm256_range_ps"]),
+        ],
+    ),
+    op(
+        "max_precise_f64x2",
+        "max_precise",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vmaxnmq_f64"]),
+            native(&["f64x2_pmax", "f64x2_ne", "v128_bitselect"]),
+            native(&[
+                "_mm_max_pd",
+                "_mm_cmpunord_pd",
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_andnot_pd",
+            ]),
+            native(&["_mm_max_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            native(&["_mm_max_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            native(&["_mm_range_pd"]),
+        ],
+    ),
+    op(
+        "max_precise_f64x4",
+        "max_precise",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vmaxnmq_f64"]),
+            split(&["f64x2_pmax", "f64x2_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_max_pd",
+                "_mm_cmpunord_pd",
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_max_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            native(&["_mm256_max_pd", "_mm256_cmp_pd", "_mm256_blendv_pd"]),
+            native(&["_mm256_range_pd"]),
+        ],
+    ),
+    op(
+        "max_precise_f64x8",
+        "max_precise",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vmaxnmq_f64"]),
+            split(&["f64x2_pmax", "f64x2_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_max_pd",
+                "_mm_cmpunord_pd",
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_max_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            split(&["_mm256_max_pd", "_mm256_cmp_pd", "_mm256_blendv_pd"]),
+            native(&["_mm512_range_pd"]),
+        ],
+    ),
+    op(
+        "max_u16x16",
+        "max",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vmaxq_u16"]),
+            split(&["u16x8_max"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi16",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epu16"]),
+            native(&["_mm256_max_epu16"]),
+            native(&["_mm256_max_epu16"]),
+        ],
+    ),
+    op(
+        "max_u16x32",
+        "max",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vmaxq_u16"]),
+            split(&["u16x8_max"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi16",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epu16"]),
+            split(&["_mm256_max_epu16"]),
+            native(&["_mm512_max_epu16"]),
+        ],
+    ),
+    op(
+        "max_u16x8",
+        "max",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vmaxq_u16"]),
+            native(&["u16x8_max"]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi16",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_max_epu16"]),
+            native(&["_mm_max_epu16"]),
+            native(&["_mm_max_epu16"]),
+        ],
+    ),
+    op(
+        "max_u32x16",
+        "max",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vmaxq_u32"]),
+            split(&["u32x4_max"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epu32"]),
+            split(&["_mm256_max_epu32"]),
+            native(&["_mm512_max_epu32"]),
+        ],
+    ),
+    op(
+        "max_u32x4",
+        "max",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vmaxq_u32"]),
+            native(&["u32x4_max"]),
+            native(&[
+                "_mm_set1_epi32",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_max_epu32"]),
+            native(&["_mm_max_epu32"]),
+            native(&["_mm_max_epu32"]),
+        ],
+    ),
+    op(
+        "max_u32x8",
+        "max",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vmaxq_u32"]),
+            split(&["u32x4_max"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_max_epu32"]),
+            native(&["_mm256_max_epu32"]),
+            native(&["_mm256_max_epu32"]),
+        ],
+    ),
+    op(
+        "max_u64x2",
+        "max",
+        "u64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_max_epu64"]),
+        ],
+    ),
+    op(
+        "max_u64x4",
+        "max",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_max_epu64"]),
+        ],
+    ),
+    op(
+        "max_u64x8",
+        "max",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_max_epu64"]),
+        ],
+    ),
+    op(
+        "max_u8x16",
+        "max",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vmaxq_u8"]),
+            native(&["u8x16_max"]),
+            native(&["_mm_max_epu8"]),
+            native(&["_mm_max_epu8"]),
+            native(&["_mm_max_epu8"]),
+            native(&["_mm_max_epu8"]),
+        ],
+    ),
+    op(
+        "max_u8x32",
+        "max",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vmaxq_u8"]),
+            split(&["u8x16_max"]),
+            split(&["_mm_max_epu8"]),
+            split(&["_mm_max_epu8"]),
+            native(&["_mm256_max_epu8"]),
+            native(&["_mm256_max_epu8"]),
+        ],
+    ),
+    op(
+        "max_u8x64",
+        "max",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vmaxq_u8"]),
+            split(&["u8x16_max"]),
+            split(&["_mm_max_epu8"]),
+            split(&["_mm_max_epu8"]),
+            split(&["_mm256_max_epu8"]),
+            native(&["_mm512_max_epu8"]),
+        ],
+    ),
+    op(
+        "min_f32x16",
+        "min",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vminq_f32"]),
+            split(&["f32x4_relaxed_min", "f32x4_min"]),
+            split(&["_mm_min_ps"]),
+            split(&["_mm_min_ps"]),
+            split(&["_mm256_min_ps"]),
+            native(&["_mm512_min_ps"]),
+        ],
+    ),
+    op(
+        "min_f32x4",
+        "min",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vminq_f32"]),
+            native(&["f32x4_relaxed_min", "f32x4_min"]),
+            native(&["_mm_min_ps"]),
+            native(&["_mm_min_ps"]),
+            native(&["_mm_min_ps"]),
+            native(&["_mm_min_ps"]),
+        ],
+    ),
+    op(
+        "min_f32x8",
+        "min",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vminq_f32"]),
+            split(&["f32x4_relaxed_min", "f32x4_min"]),
+            split(&["_mm_min_ps"]),
+            split(&["_mm_min_ps"]),
+            native(&["_mm256_min_ps"]),
+            native(&["_mm256_min_ps"]),
+        ],
+    ),
+    op(
+        "min_f64x2",
+        "min",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vminq_f64"]),
+            native(&["f64x2_relaxed_min", "f64x2_min"]),
+            native(&["_mm_min_pd"]),
+            native(&["_mm_min_pd"]),
+            native(&["_mm_min_pd"]),
+            native(&["_mm_min_pd"]),
+        ],
+    ),
+    op(
+        "min_f64x4",
+        "min",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vminq_f64"]),
+            split(&["f64x2_relaxed_min", "f64x2_min"]),
+            split(&["_mm_min_pd"]),
+            split(&["_mm_min_pd"]),
+            native(&["_mm256_min_pd"]),
+            native(&["_mm256_min_pd"]),
+        ],
+    ),
+    op(
+        "min_f64x8",
+        "min",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vminq_f64"]),
+            split(&["f64x2_relaxed_min", "f64x2_min"]),
+            split(&["_mm_min_pd"]),
+            split(&["_mm_min_pd"]),
+            split(&["_mm256_min_pd"]),
+            native(&["_mm512_min_pd"]),
+        ],
+    ),
+    op(
+        "min_i16x16",
+        "min",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vminq_s16"]),
+            split(&["i16x8_min"]),
+            split(&["_mm_min_epi16"]),
+            split(&["_mm_min_epi16"]),
+            native(&["_mm256_min_epi16"]),
+            native(&["_mm256_min_epi16"]),
+        ],
+    ),
+    op(
+        "min_i16x32",
+        "min",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vminq_s16"]),
+            split(&["i16x8_min"]),
+            split(&["_mm_min_epi16"]),
+            split(&["_mm_min_epi16"]),
+            split(&["_mm256_min_epi16"]),
+            native(&["_mm512_min_epi16"]),
+        ],
+    ),
+    op(
+        "min_i16x8",
+        "min",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vminq_s16"]),
+            native(&["i16x8_min"]),
+            native(&["_mm_min_epi16"]),
+            native(&["_mm_min_epi16"]),
+            native(&["_mm_min_epi16"]),
+            native(&["_mm_min_epi16"]),
+        ],
+    ),
+    op(
+        "min_i32x16",
+        "min",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vminq_s32"]),
+            split(&["i32x4_min"]),
+            split(&[
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epi32"]),
+            split(&["_mm256_min_epi32"]),
+            native(&["_mm512_min_epi32"]),
+        ],
+    ),
+    op(
+        "min_i32x4",
+        "min",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vminq_s32"]),
+            native(&["i32x4_min"]),
+            native(&[
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_min_epi32"]),
+            native(&["_mm_min_epi32"]),
+            native(&["_mm_min_epi32"]),
+        ],
+    ),
+    op(
+        "min_i32x8",
+        "min",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vminq_s32"]),
+            split(&["i32x4_min"]),
+            split(&[
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epi32"]),
+            native(&["_mm256_min_epi32"]),
+            native(&["_mm256_min_epi32"]),
+        ],
+    ),
+    op(
+        "min_i64x2",
+        "min",
+        "i64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_min_epi64"]),
+        ],
+    ),
+    op(
+        "min_i64x4",
+        "min",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_min_epi64"]),
+        ],
+    ),
+    op(
+        "min_i64x8",
+        "min",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_min_epi64"]),
+        ],
+    ),
+    op(
+        "min_i8x16",
+        "min",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vminq_s8"]),
+            native(&["i8x16_min"]),
+            native(&[
+                "_mm_cmpgt_epi8",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_min_epi8"]),
+            native(&["_mm_min_epi8"]),
+            native(&["_mm_min_epi8"]),
+        ],
+    ),
+    op(
+        "min_i8x32",
+        "min",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vminq_s8"]),
+            split(&["i8x16_min"]),
+            split(&[
+                "_mm_cmpgt_epi8",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epi8"]),
+            native(&["_mm256_min_epi8"]),
+            native(&["_mm256_min_epi8"]),
+        ],
+    ),
+    op(
+        "min_i8x64",
+        "min",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vminq_s8"]),
+            split(&["i8x16_min"]),
+            split(&[
+                "_mm_cmpgt_epi8",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epi8"]),
+            split(&["_mm256_min_epi8"]),
+            native(&["_mm512_min_epi8"]),
+        ],
+    ),
+    op(
+        "min_precise_f32x16",
+        "min_precise",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vminnmq_f32"]),
+            split(&["f32x4_pmin", "f32x4_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_min_ps",
+                "_mm_cmpunord_ps",
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_min_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            split(&["_mm256_min_ps", "_mm256_cmp_ps", "_mm256_blendv_ps"]),
+            native(&["_mm512_range_ps"]),
+        ],
+    ),
+    op(
+        "min_precise_f32x4",
+        "min_precise",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vminnmq_f32"]),
+            native(&["f32x4_pmin", "f32x4_ne", "v128_bitselect"]),
+            native(&[
+                "_mm_min_ps",
+                "_mm_cmpunord_ps",
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_andnot_ps",
+            ]),
+            native(&["_mm_min_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            native(&["_mm_min_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            native(&["_mm_range_ps"]),
+        ],
+    ),
+    op(
+        "min_precise_f32x8",
+        "min_precise",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vminnmq_f32"]),
+            split(&["f32x4_pmin", "f32x4_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_min_ps",
+                "_mm_cmpunord_ps",
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_min_ps", "_mm_cmpunord_ps", "_mm_blendv_ps"]),
+            native(&["_mm256_min_ps", "_mm256_cmp_ps", "_mm256_blendv_ps"]),
+            native(&["_mm256_range_ps"]),
+        ],
+    ),
+    op(
+        "min_precise_f64x2",
+        "min_precise",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vminnmq_f64"]),
+            native(&["f64x2_pmin", "f64x2_ne", "v128_bitselect"]),
+            native(&[
+                "_mm_min_pd",
+                "_mm_cmpunord_pd",
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_andnot_pd",
+            ]),
+            native(&["_mm_min_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            native(&["_mm_min_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            native(&["_mm_range_pd"]),
+        ],
+    ),
+    op(
+        "min_precise_f64x4",
+        "min_precise",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vminnmq_f64"]),
+            split(&["f64x2_pmin", "f64x2_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_min_pd",
+                "_mm_cmpunord_pd",
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_min_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            native(&["_mm256_min_pd", "_mm256_cmp_pd", "_mm256_blendv_pd"]),
+            native(&["_mm256_range_pd"]),
+        ],
+    ),
+    op(
+        "min_precise_f64x8",
+        "min_precise",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vminnmq_f64"]),
+            split(&["f64x2_pmin", "f64x2_ne", "v128_bitselect"]),
+            split(&[
+                "_mm_min_pd",
+                "_mm_cmpunord_pd",
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_min_pd", "_mm_cmpunord_pd", "_mm_blendv_pd"]),
+            split(&["_mm256_min_pd", "_mm256_cmp_pd", "_mm256_blendv_pd"]),
+            native(&["_mm512_range_pd"]),
+        ],
+    ),
+    op(
+        "min_u16x16",
+        "min",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vminq_u16"]),
+            split(&["u16x8_min"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi16",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epu16"]),
+            native(&["_mm256_min_epu16"]),
+            native(&["_mm256_min_epu16"]),
+        ],
+    ),
+    op(
+        "min_u16x32",
+        "min",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vminq_u16"]),
+            split(&["u16x8_min"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi16",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epu16"]),
+            split(&["_mm256_min_epu16"]),
+            native(&["_mm512_min_epu16"]),
+        ],
+    ),
+    op(
+        "min_u16x8",
+        "min",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vminq_u16"]),
+            native(&["u16x8_min"]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi16",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_min_epu16"]),
+            native(&["_mm_min_epu16"]),
+            native(&["_mm_min_epu16"]),
+        ],
+    ),
+    op(
+        "min_u32x16",
+        "min",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vminq_u32"]),
+            split(&["u32x4_min"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epu32"]),
+            split(&["_mm256_min_epu32"]),
+            native(&["_mm512_min_epu32"]),
+        ],
+    ),
+    op(
+        "min_u32x4",
+        "min",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vminq_u32"]),
+            native(&["u32x4_min"]),
+            native(&[
+                "_mm_set1_epi32",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            native(&["_mm_min_epu32"]),
+            native(&["_mm_min_epu32"]),
+            native(&["_mm_min_epu32"]),
+        ],
+    ),
+    op(
+        "min_u32x8",
+        "min",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vminq_u32"]),
+            split(&["u32x4_min"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_xor_si128",
+                "_mm_cmpgt_epi32",
+                "_mm_or_si128",
+                "_mm_and_si128",
+                "_mm_andnot_si128",
+            ]),
+            split(&["_mm_min_epu32"]),
+            native(&["_mm256_min_epu32"]),
+            native(&["_mm256_min_epu32"]),
+        ],
+    ),
+    op(
+        "min_u64x2",
+        "min",
+        "u64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_min_epu64"]),
+        ],
+    ),
+    op(
+        "min_u64x4",
+        "min",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_min_epu64"]),
+        ],
+    ),
+    op(
+        "min_u64x8",
+        "min",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_min_epu64"]),
+        ],
+    ),
+    op(
+        "min_u8x16",
+        "min",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vminq_u8"]),
+            native(&["u8x16_min"]),
+            native(&["_mm_min_epu8"]),
+            native(&["_mm_min_epu8"]),
+            native(&["_mm_min_epu8"]),
+            native(&["_mm_min_epu8"]),
+        ],
+    ),
+    op(
+        "min_u8x32",
+        "min",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vminq_u8"]),
+            split(&["u8x16_min"]),
+            split(&["_mm_min_epu8"]),
+            split(&["_mm_min_epu8"]),
+            native(&["_mm256_min_epu8"]),
+            native(&["_mm256_min_epu8"]),
+        ],
+    ),
+    op(
+        "min_u8x64",
+        "min",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vminq_u8"]),
+            split(&["u8x16_min"]),
+            split(&["_mm_min_epu8"]),
+            split(&["_mm_min_epu8"]),
+            split(&["_mm256_min_epu8"]),
+            native(&["_mm512_min_epu8"]),
+        ],
+    ),
+    op(
+        "mul_add_f32x16",
+        "mul_add",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vfmaq_f32"]),
+            split(&["f32x4_add", "f32x4_mul"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            split(&["_mm256_fmadd_ps"]),
+            native(&["_mm512_fmadd_ps"]),
+        ],
+    ),
+    op(
+        "mul_add_f32x4",
+        "mul_add",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vfmaq_f32"]),
+            native(&["f32x4_add", "f32x4_mul"]),
+            native(&["_mm_add_ps", "_mm_mul_ps"]),
+            native(&["_mm_add_ps", "_mm_mul_ps"]),
+            native(&["_mm_fmadd_ps"]),
+            native(&["_mm_fmadd_ps"]),
+        ],
+    ),
+    op(
+        "mul_add_f32x8",
+        "mul_add",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vfmaq_f32"]),
+            split(&["f32x4_add", "f32x4_mul"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            native(&["_mm256_fmadd_ps"]),
+            native(&["_mm256_fmadd_ps"]),
+        ],
+    ),
+    op(
+        "mul_add_f64x2",
+        "mul_add",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vfmaq_f64"]),
+            native(&["f64x2_add", "f64x2_mul"]),
+            native(&["_mm_add_pd", "_mm_mul_pd"]),
+            native(&["_mm_add_pd", "_mm_mul_pd"]),
+            native(&["_mm_fmadd_pd"]),
+            native(&["_mm_fmadd_pd"]),
+        ],
+    ),
+    op(
+        "mul_add_f64x4",
+        "mul_add",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vfmaq_f64"]),
+            split(&["f64x2_add", "f64x2_mul"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            native(&["_mm256_fmadd_pd"]),
+            native(&["_mm256_fmadd_pd"]),
+        ],
+    ),
+    op(
+        "mul_add_f64x8",
+        "mul_add",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vfmaq_f64"]),
+            split(&["f64x2_add", "f64x2_mul"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            split(&["_mm256_fmadd_pd"]),
+            native(&["_mm512_fmadd_pd"]),
+        ],
+    ),
+    op(
+        "mul_add_relaxed_f32x16",
+        "mul_add_relaxed",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vfmaq_f32"]),
+            split(&["f32x4_relaxed_madd", "f32x4_add", "f32x4_mul"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            split(&["_mm256_fmadd_ps"]),
+            native(&["_mm512_fmadd_ps"]),
+        ],
+    ),
+    op(
+        "mul_add_relaxed_f32x4",
+        "mul_add_relaxed",
+        "f32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["f32x4_relaxed_madd", "f32x4_add", "f32x4_mul"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "mul_add_relaxed_f32x8",
+        "mul_add_relaxed",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vfmaq_f32"]),
+            split(&["f32x4_relaxed_madd", "f32x4_add", "f32x4_mul"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            split(&["_mm_add_ps", "_mm_mul_ps"]),
+            native(&["_mm256_fmadd_ps"]),
+            native(&["_mm256_fmadd_ps"]),
+        ],
+    ),
+    op(
+        "mul_add_relaxed_f64x2",
+        "mul_add_relaxed",
+        "f64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["f64x2_relaxed_madd", "f64x2_add", "f64x2_mul"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "mul_add_relaxed_f64x4",
+        "mul_add_relaxed",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vfmaq_f64"]),
+            split(&["f64x2_relaxed_madd", "f64x2_add", "f64x2_mul"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            native(&["_mm256_fmadd_pd"]),
+            native(&["_mm256_fmadd_pd"]),
+        ],
+    ),
+    op(
+        "mul_add_relaxed_f64x8",
+        "mul_add_relaxed",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vfmaq_f64"]),
+            split(&["f64x2_relaxed_madd", "f64x2_add", "f64x2_mul"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            split(&["_mm_add_pd", "_mm_mul_pd"]),
+            split(&["_mm256_fmadd_pd"]),
+            native(&["_mm512_fmadd_pd"]),
+        ],
+    ),
+    op(
+        "mul_f32x16",
+        "mul",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vmulq_f32"]),
+            split(&["f32x4_mul"]),
+            split(&["_mm_mul_ps"]),
+            split(&["_mm_mul_ps"]),
+            split(&["_mm256_mul_ps"]),
+            native(&["_mm512_mul_ps"]),
+        ],
+    ),
+    op(
+        "mul_f32x4",
+        "mul",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vmulq_f32"]),
+            native(&["f32x4_mul"]),
+            native(&["_mm_mul_ps"]),
+            native(&["_mm_mul_ps"]),
+            native(&["_mm_mul_ps"]),
+            native(&["_mm_mul_ps"]),
+        ],
+    ),
+    op(
+        "mul_f32x8",
+        "mul",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vmulq_f32"]),
+            split(&["f32x4_mul"]),
+            split(&["_mm_mul_ps"]),
+            split(&["_mm_mul_ps"]),
+            native(&["_mm256_mul_ps"]),
+            native(&["_mm256_mul_ps"]),
+        ],
+    ),
+    op(
+        "mul_f64x2",
+        "mul",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vmulq_f64"]),
+            native(&["f64x2_mul"]),
+            native(&["_mm_mul_pd"]),
+            native(&["_mm_mul_pd"]),
+            native(&["_mm_mul_pd"]),
+            native(&["_mm_mul_pd"]),
+        ],
+    ),
+    op(
+        "mul_f64x4",
+        "mul",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vmulq_f64"]),
+            split(&["f64x2_mul"]),
+            split(&["_mm_mul_pd"]),
+            split(&["_mm_mul_pd"]),
+            native(&["_mm256_mul_pd"]),
+            native(&["_mm256_mul_pd"]),
+        ],
+    ),
+    op(
+        "mul_f64x8",
+        "mul",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vmulq_f64"]),
+            split(&["f64x2_mul"]),
+            split(&["_mm_mul_pd"]),
+            split(&["_mm_mul_pd"]),
+            split(&["_mm256_mul_pd"]),
+            native(&["_mm512_mul_pd"]),
+        ],
+    ),
+    op(
+        "mul_i16x16",
+        "mul",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vmulq_s16"]),
+            split(&["i16x8_mul"]),
+            split(&["_mm_mullo_epi16"]),
+            split(&["_mm_mullo_epi16"]),
+            native(&["_mm256_mullo_epi16"]),
+            native(&["_mm256_mullo_epi16"]),
+        ],
+    ),
+    op(
+        "mul_i16x32",
+        "mul",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vmulq_s16"]),
+            split(&["i16x8_mul"]),
+            split(&["_mm_mullo_epi16"]),
+            split(&["_mm_mullo_epi16"]),
+            split(&["_mm256_mullo_epi16"]),
+            native(&["_mm512_mullo_epi16"]),
+        ],
+    ),
+    op(
+        "mul_i16x8",
+        "mul",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vmulq_s16"]),
+            native(&["i16x8_mul"]),
+            native(&["_mm_mullo_epi16"]),
+            native(&["_mm_mullo_epi16"]),
+            native(&["_mm_mullo_epi16"]),
+            native(&["_mm_mullo_epi16"]),
+        ],
+    ),
+    op(
+        "mul_i32x16",
+        "mul",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vmulq_s32"]),
+            split(&["i32x4_mul"]),
+            split(&[]),
+            split(&["_mm_mullo_epi32"]),
+            split(&["_mm256_mullo_epi32"]),
+            native(&["_mm512_mullo_epi32"]),
+        ],
+    ),
+    op(
+        "mul_i32x4",
+        "mul",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vmulq_s32"]),
+            native(&["i32x4_mul"]),
+            PORTABLE,
+            native(&["_mm_mullo_epi32"]),
+            native(&["_mm_mullo_epi32"]),
+            native(&["_mm_mullo_epi32"]),
+        ],
+    ),
+    op(
+        "mul_i32x8",
+        "mul",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vmulq_s32"]),
+            split(&["i32x4_mul"]),
+            split(&[]),
+            split(&["_mm_mullo_epi32"]),
+            native(&["_mm256_mullo_epi32"]),
+            native(&["_mm256_mullo_epi32"]),
+        ],
+    ),
+    op(
+        "mul_i64x2",
+        "mul",
+        "i64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i64x2_mul"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_mullo_epi64"]),
+        ],
+    ),
+    op(
+        "mul_i64x4",
+        "mul",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&["i64x2_mul"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_mullo_epi64"]),
+        ],
+    ),
+    op(
+        "mul_i64x8",
+        "mul",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&["i64x2_mul"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_mullo_epi64"]),
+        ],
+    ),
+    op(
+        "mul_i8x16",
+        "mul",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vmulq_s8"]),
+            native(&[
+                "i16x8_extmul_low_i8x16",
+                "i16x8_extmul_high_i8x16",
+                "u8x16_shuffle",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "mul_i8x32",
+        "mul",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vmulq_s8"]),
+            split(&[
+                "i16x8_extmul_low_i8x16",
+                "i16x8_extmul_high_i8x16",
+                "u8x16_shuffle",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_mullo_epi16",
+                "_mm256_srli_epi16",
+                "_mm256_or_si256",
+                "_mm256_slli_epi16",
+                "_mm256_and_si256",
+                "_mm256_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_mullo_epi16",
+                "_mm256_srli_epi16",
+                "_mm256_or_si256",
+                "_mm256_slli_epi16",
+                "_mm256_and_si256",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "mul_i8x64",
+        "mul",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vmulq_s8"]),
+            split(&[
+                "i16x8_extmul_low_i8x16",
+                "i16x8_extmul_high_i8x16",
+                "u8x16_shuffle",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm256_mullo_epi16",
+                "_mm256_srli_epi16",
+                "_mm256_or_si256",
+                "_mm256_slli_epi16",
+                "_mm256_and_si256",
+                "_mm256_set1_epi16",
+            ]),
+            native(&[
+                "_mm512_mullo_epi16",
+                "_mm512_srli_epi16",
+                "_mm512_or_si512",
+                "_mm512_slli_epi16",
+                "_mm512_and_si512",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "mul_sub_f32x16",
+        "mul_sub",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vnegq_f32", "vfmsq_f32"]),
+            split(&["f32x4_sub", "f32x4_mul"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            split(&["_mm256_fmsub_ps"]),
+            native(&["_mm512_fmsub_ps"]),
+        ],
+    ),
+    op(
+        "mul_sub_f32x4",
+        "mul_sub",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vnegq_f32", "vfmsq_f32"]),
+            native(&["f32x4_sub", "f32x4_mul"]),
+            native(&["_mm_sub_ps", "_mm_mul_ps"]),
+            native(&["_mm_sub_ps", "_mm_mul_ps"]),
+            native(&["_mm_fmsub_ps"]),
+            native(&["_mm_fmsub_ps"]),
+        ],
+    ),
+    op(
+        "mul_sub_f32x8",
+        "mul_sub",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vnegq_f32", "vfmsq_f32"]),
+            split(&["f32x4_sub", "f32x4_mul"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            native(&["_mm256_fmsub_ps"]),
+            native(&["_mm256_fmsub_ps"]),
+        ],
+    ),
+    op(
+        "mul_sub_f64x2",
+        "mul_sub",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vnegq_f64", "vfmsq_f64"]),
+            native(&["f64x2_sub", "f64x2_mul"]),
+            native(&["_mm_sub_pd", "_mm_mul_pd"]),
+            native(&["_mm_sub_pd", "_mm_mul_pd"]),
+            native(&["_mm_fmsub_pd"]),
+            native(&["_mm_fmsub_pd"]),
+        ],
+    ),
+    op(
+        "mul_sub_f64x4",
+        "mul_sub",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vnegq_f64", "vfmsq_f64"]),
+            split(&["f64x2_sub", "f64x2_mul"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            native(&["_mm256_fmsub_pd"]),
+            native(&["_mm256_fmsub_pd"]),
+        ],
+    ),
+    op(
+        "mul_sub_f64x8",
+        "mul_sub",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vnegq_f64", "vfmsq_f64"]),
+            split(&["f64x2_sub", "f64x2_mul"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            split(&["_mm256_fmsub_pd"]),
+            native(&["_mm512_fmsub_pd"]),
+        ],
+    ),
+    op(
+        "mul_u16x16",
+        "mul",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vmulq_u16"]),
+            split(&["u16x8_mul"]),
+            split(&["_mm_mullo_epi16"]),
+            split(&["_mm_mullo_epi16"]),
+            native(&["_mm256_mullo_epi16"]),
+            native(&["_mm256_mullo_epi16"]),
+        ],
+    ),
+    op(
+        "mul_u16x32",
+        "mul",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vmulq_u16"]),
+            split(&["u16x8_mul"]),
+            split(&["_mm_mullo_epi16"]),
+            split(&["_mm_mullo_epi16"]),
+            split(&["_mm256_mullo_epi16"]),
+            native(&["_mm512_mullo_epi16"]),
+        ],
+    ),
+    op(
+        "mul_u16x8",
+        "mul",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vmulq_u16"]),
+            native(&["u16x8_mul"]),
+            native(&["_mm_mullo_epi16"]),
+            native(&["_mm_mullo_epi16"]),
+            native(&["_mm_mullo_epi16"]),
+            native(&["_mm_mullo_epi16"]),
+        ],
+    ),
+    op(
+        "mul_u32x16",
+        "mul",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vmulq_u32"]),
+            split(&["u32x4_mul"]),
+            split(&[]),
+            split(&["_mm_mullo_epi32"]),
+            split(&["_mm256_mullo_epi32"]),
+            native(&["_mm512_mullo_epi32"]),
+        ],
+    ),
+    op(
+        "mul_u32x4",
+        "mul",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vmulq_u32"]),
+            native(&["u32x4_mul"]),
+            PORTABLE,
+            native(&["_mm_mullo_epi32"]),
+            native(&["_mm_mullo_epi32"]),
+            native(&["_mm_mullo_epi32"]),
+        ],
+    ),
+    op(
+        "mul_u32x8",
+        "mul",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vmulq_u32"]),
+            split(&["u32x4_mul"]),
+            split(&[]),
+            split(&["_mm_mullo_epi32"]),
+            native(&["_mm256_mullo_epi32"]),
+            native(&["_mm256_mullo_epi32"]),
+        ],
+    ),
+    op(
+        "mul_u64x2",
+        "mul",
+        "u64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["u64x2_mul"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_mullo_epi64"]),
+        ],
+    ),
+    op(
+        "mul_u64x4",
+        "mul",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&["u64x2_mul"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_mullo_epi64"]),
+        ],
+    ),
+    op(
+        "mul_u64x8",
+        "mul",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[]),
+            split(&["u64x2_mul"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_mullo_epi64"]),
+        ],
+    ),
+    op(
+        "mul_u8x16",
+        "mul",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vmulq_u8"]),
+            native(&[
+                "u16x8_extmul_low_u8x16",
+                "u16x8_extmul_high_u8x16",
+                "u8x16_shuffle",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "mul_u8x32",
+        "mul",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vmulq_u8"]),
+            split(&[
+                "u16x8_extmul_low_u8x16",
+                "u16x8_extmul_high_u8x16",
+                "u8x16_shuffle",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_mullo_epi16",
+                "_mm256_srli_epi16",
+                "_mm256_or_si256",
+                "_mm256_slli_epi16",
+                "_mm256_and_si256",
+                "_mm256_set1_epi16",
+            ]),
+            native(&[
+                "_mm256_mullo_epi16",
+                "_mm256_srli_epi16",
+                "_mm256_or_si256",
+                "_mm256_slli_epi16",
+                "_mm256_and_si256",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "mul_u8x64",
+        "mul",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vmulq_u8"]),
+            split(&[
+                "u16x8_extmul_low_u8x16",
+                "u16x8_extmul_high_u8x16",
+                "u8x16_shuffle",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm_mullo_epi16",
+                "_mm_srli_epi16",
+                "_mm_or_si128",
+                "_mm_slli_epi16",
+                "_mm_and_si128",
+                "_mm_set1_epi16",
+            ]),
+            split(&[
+                "_mm256_mullo_epi16",
+                "_mm256_srli_epi16",
+                "_mm256_or_si256",
+                "_mm256_slli_epi16",
+                "_mm256_and_si256",
+                "_mm256_set1_epi16",
+            ]),
+            native(&[
+                "_mm512_mullo_epi16",
+                "_mm512_srli_epi16",
+                "_mm512_or_si512",
+                "_mm512_slli_epi16",
+                "_mm512_and_si512",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "narrow_u16x16",
+        "narrow",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&["vmovn_u16", "vcombine_u8"]),
+            native(&["u16x8_splat", "v128_and", "u8x16_narrow_i16x8"]),
+            native(&["_mm_set1_epi16", "_mm_and_si128", "_mm_packus_epi16"]),
+            native(&["_mm_set1_epi16", "_mm_and_si128", "_mm_packus_epi16"]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_permute4x64_epi64",
+                "_mm256_castsi256_si128",
+            ]),
+            native(&["_mm256_cvtepi16_epi8"]),
+        ],
+    ),
+    op(
+        "narrow_u16x32",
+        "narrow",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vmovn_u16", "vcombine_u8"]),
+            split(&["u16x8_splat", "v128_and", "u8x16_narrow_i16x8"]),
+            split(&["_mm_set1_epi16", "_mm_and_si128", "_mm_packus_epi16"]),
+            split(&["_mm_set1_epi16", "_mm_and_si128", "_mm_packus_epi16"]),
+            native(&[
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_permute4x64_epi64",
+                "_mm256_packus_epi16",
+            ]),
+            native(&["_mm512_cvtepi16_epi8"]),
+        ],
+    ),
+    op(
+        "neg_f32x16",
+        "neg",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vnegq_f32"]),
+            split(&["f32x4_neg"]),
+            split(&["_mm_xor_ps", "_mm_set1_ps"]),
+            split(&["_mm_xor_ps", "_mm_set1_ps"]),
+            split(&["_mm256_xor_ps", "_mm256_set1_ps"]),
+            native(&["_mm512_xor_ps", "_mm512_set1_ps"]),
+        ],
+    ),
+    op(
+        "neg_f32x4",
+        "neg",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vnegq_f32"]),
+            native(&["f32x4_neg"]),
+            native(&["_mm_xor_ps", "_mm_set1_ps"]),
+            native(&["_mm_xor_ps", "_mm_set1_ps"]),
+            native(&["_mm_xor_ps", "_mm_set1_ps"]),
+            native(&["_mm_xor_ps", "_mm_set1_ps"]),
+        ],
+    ),
+    op(
+        "neg_f32x8",
+        "neg",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vnegq_f32"]),
+            split(&["f32x4_neg"]),
+            split(&["_mm_xor_ps", "_mm_set1_ps"]),
+            split(&["_mm_xor_ps", "_mm_set1_ps"]),
+            native(&["_mm256_xor_ps", "_mm256_set1_ps"]),
+            native(&["_mm256_xor_ps", "_mm256_set1_ps"]),
+        ],
+    ),
+    op(
+        "neg_f64x2",
+        "neg",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vnegq_f64"]),
+            native(&["f64x2_neg"]),
+            native(&["_mm_xor_pd", "_mm_set1_pd"]),
+            native(&["_mm_xor_pd", "_mm_set1_pd"]),
+            native(&["_mm_xor_pd", "_mm_set1_pd"]),
+            native(&["_mm_xor_pd", "_mm_set1_pd"]),
+        ],
+    ),
+    op(
+        "neg_f64x4",
+        "neg",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vnegq_f64"]),
+            split(&["f64x2_neg"]),
+            split(&["_mm_xor_pd", "_mm_set1_pd"]),
+            split(&["_mm_xor_pd", "_mm_set1_pd"]),
+            native(&["_mm256_xor_pd", "_mm256_set1_pd"]),
+            native(&["_mm256_xor_pd", "_mm256_set1_pd"]),
+        ],
+    ),
+    op(
+        "neg_f64x8",
+        "neg",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vnegq_f64"]),
+            split(&["f64x2_neg"]),
+            split(&["_mm_xor_pd", "_mm_set1_pd"]),
+            split(&["_mm_xor_pd", "_mm_set1_pd"]),
+            split(&["_mm256_xor_pd", "_mm256_set1_pd"]),
+            native(&["_mm512_xor_pd", "_mm512_set1_pd"]),
+        ],
+    ),
+    op(
+        "neg_i16x16",
+        "neg",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vnegq_s16"]),
+            split(&["i16x8_neg"]),
+            split(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            native(&["_mm256_sub_epi16", "_mm256_setzero_si256"]),
+            native(&["_mm256_sub_epi16", "_mm256_setzero_si256"]),
+        ],
+    ),
+    op(
+        "neg_i16x32",
+        "neg",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vnegq_s16"]),
+            split(&["i16x8_neg"]),
+            split(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            split(&["_mm256_sub_epi16", "_mm256_setzero_si256"]),
+            native(&["_mm512_sub_epi16", "_mm512_setzero_si512"]),
+        ],
+    ),
+    op(
+        "neg_i16x8",
+        "neg",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vnegq_s16"]),
+            native(&["i16x8_neg"]),
+            native(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi16", "_mm_setzero_si128"]),
+        ],
+    ),
+    op(
+        "neg_i32x16",
+        "neg",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vnegq_s32"]),
+            split(&["i32x4_neg"]),
+            split(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            split(&["_mm256_sub_epi32", "_mm256_setzero_si256"]),
+            native(&["_mm512_sub_epi32", "_mm512_setzero_si512"]),
+        ],
+    ),
+    op(
+        "neg_i32x4",
+        "neg",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vnegq_s32"]),
+            native(&["i32x4_neg"]),
+            native(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+        ],
+    ),
+    op(
+        "neg_i32x8",
+        "neg",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vnegq_s32"]),
+            split(&["i32x4_neg"]),
+            split(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi32", "_mm_setzero_si128"]),
+            native(&["_mm256_sub_epi32", "_mm256_setzero_si256"]),
+            native(&["_mm256_sub_epi32", "_mm256_setzero_si256"]),
+        ],
+    ),
+    op(
+        "neg_i64x2",
+        "neg",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vnegq_s64"]),
+            native(&["i64x2_neg"]),
+            native(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+        ],
+    ),
+    op(
+        "neg_i64x4",
+        "neg",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vnegq_s64"]),
+            split(&["i64x2_neg"]),
+            split(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            native(&["_mm256_sub_epi64", "_mm256_setzero_si256"]),
+            native(&["_mm256_sub_epi64", "_mm256_setzero_si256"]),
+        ],
+    ),
+    op(
+        "neg_i64x8",
+        "neg",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vnegq_s64"]),
+            split(&["i64x2_neg"]),
+            split(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi64", "_mm_setzero_si128"]),
+            split(&["_mm256_sub_epi64", "_mm256_setzero_si256"]),
+            native(&["_mm512_sub_epi64", "_mm512_setzero_si512"]),
+        ],
+    ),
+    op(
+        "neg_i8x16",
+        "neg",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vnegq_s8"]),
+            native(&["i8x16_neg"]),
+            native(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            native(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+        ],
+    ),
+    op(
+        "neg_i8x32",
+        "neg",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vnegq_s8"]),
+            split(&["i8x16_neg"]),
+            split(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            native(&["_mm256_sub_epi8", "_mm256_setzero_si256"]),
+            native(&["_mm256_sub_epi8", "_mm256_setzero_si256"]),
+        ],
+    ),
+    op(
+        "neg_i8x64",
+        "neg",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vnegq_s8"]),
+            split(&["i8x16_neg"]),
+            split(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            split(&["_mm_sub_epi8", "_mm_setzero_si128"]),
+            split(&["_mm256_sub_epi8", "_mm256_setzero_si256"]),
+            native(&["_mm512_sub_epi8", "_mm512_setzero_si512"]),
+        ],
+    ),
+    op(
+        "nmul_add_f32x16",
+        "nmul_add",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vfmsq_f32"]),
+            split(&["f32x4_sub", "f32x4_mul"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            split(&["_mm256_fnmadd_ps"]),
+            native(&["_mm512_fnmadd_ps"]),
+        ],
+    ),
+    op(
+        "nmul_add_f32x4",
+        "nmul_add",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vfmsq_f32"]),
+            native(&["f32x4_sub", "f32x4_mul"]),
+            native(&["_mm_sub_ps", "_mm_mul_ps"]),
+            native(&["_mm_sub_ps", "_mm_mul_ps"]),
+            native(&["_mm_fnmadd_ps"]),
+            native(&["_mm_fnmadd_ps"]),
+        ],
+    ),
+    op(
+        "nmul_add_f32x8",
+        "nmul_add",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vfmsq_f32"]),
+            split(&["f32x4_sub", "f32x4_mul"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            split(&["_mm_sub_ps", "_mm_mul_ps"]),
+            native(&["_mm256_fnmadd_ps"]),
+            native(&["_mm256_fnmadd_ps"]),
+        ],
+    ),
+    op(
+        "nmul_add_f64x2",
+        "nmul_add",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vfmsq_f64"]),
+            native(&["f64x2_sub", "f64x2_mul"]),
+            native(&["_mm_sub_pd", "_mm_mul_pd"]),
+            native(&["_mm_sub_pd", "_mm_mul_pd"]),
+            native(&["_mm_fnmadd_pd"]),
+            native(&["_mm_fnmadd_pd"]),
+        ],
+    ),
+    op(
+        "nmul_add_f64x4",
+        "nmul_add",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vfmsq_f64"]),
+            split(&["f64x2_sub", "f64x2_mul"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            native(&["_mm256_fnmadd_pd"]),
+            native(&["_mm256_fnmadd_pd"]),
+        ],
+    ),
+    op(
+        "nmul_add_f64x8",
+        "nmul_add",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vfmsq_f64"]),
+            split(&["f64x2_sub", "f64x2_mul"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            split(&["_mm_sub_pd", "_mm_mul_pd"]),
+            split(&["_mm256_fnmadd_pd"]),
+            native(&["_mm512_fnmadd_pd"]),
+        ],
+    ),
+    op(
+        "nmul_sub_f32x16",
+        "nmul_sub",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vfmsq_f32", "vnegq_f32"]),
+            split(&["f32x4_sub", "f32x4_neg", "f32x4_mul"]),
+            split(&["_mm_sub_ps", "_mm_xor_ps", "_mm_set1_ps", "_mm_mul_ps"]),
+            split(&["_mm_sub_ps", "_mm_xor_ps", "_mm_set1_ps", "_mm_mul_ps"]),
+            split(&["_mm256_fnmsub_ps"]),
+            native(&["_mm512_fnmsub_ps"]),
+        ],
+    ),
+    op(
+        "nmul_sub_f32x4",
+        "nmul_sub",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vfmsq_f32", "vnegq_f32"]),
+            native(&["f32x4_sub", "f32x4_neg", "f32x4_mul"]),
+            native(&["_mm_sub_ps", "_mm_xor_ps", "_mm_set1_ps", "_mm_mul_ps"]),
+            native(&["_mm_sub_ps", "_mm_xor_ps", "_mm_set1_ps", "_mm_mul_ps"]),
+            native(&["_mm_fnmsub_ps"]),
+            native(&["_mm_fnmsub_ps"]),
+        ],
+    ),
+    op(
+        "nmul_sub_f32x8",
+        "nmul_sub",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vfmsq_f32", "vnegq_f32"]),
+            split(&["f32x4_sub", "f32x4_neg", "f32x4_mul"]),
+            split(&["_mm_sub_ps", "_mm_xor_ps", "_mm_set1_ps", "_mm_mul_ps"]),
+            split(&["_mm_sub_ps", "_mm_xor_ps", "_mm_set1_ps", "_mm_mul_ps"]),
+            native(&["_mm256_fnmsub_ps"]),
+            native(&["_mm256_fnmsub_ps"]),
+        ],
+    ),
+    op(
+        "nmul_sub_f64x2",
+        "nmul_sub",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vfmsq_f64", "vnegq_f64"]),
+            native(&["f64x2_sub", "f64x2_neg", "f64x2_mul"]),
+            native(&["_mm_sub_pd", "_mm_xor_pd", "_mm_set1_pd", "_mm_mul_pd"]),
+            native(&["_mm_sub_pd", "_mm_xor_pd", "_mm_set1_pd", "_mm_mul_pd"]),
+            native(&["_mm_fnmsub_pd"]),
+            native(&["_mm_fnmsub_pd"]),
+        ],
+    ),
+    op(
+        "nmul_sub_f64x4",
+        "nmul_sub",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vfmsq_f64", "vnegq_f64"]),
+            split(&["f64x2_sub", "f64x2_neg", "f64x2_mul"]),
+            split(&["_mm_sub_pd", "_mm_xor_pd", "_mm_set1_pd", "_mm_mul_pd"]),
+            split(&["_mm_sub_pd", "_mm_xor_pd", "_mm_set1_pd", "_mm_mul_pd"]),
+            native(&["_mm256_fnmsub_pd"]),
+            native(&["_mm256_fnmsub_pd"]),
+        ],
+    ),
+    op(
+        "nmul_sub_f64x8",
+        "nmul_sub",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vfmsq_f64", "vnegq_f64"]),
+            split(&["f64x2_sub", "f64x2_neg", "f64x2_mul"]),
+            split(&["_mm_sub_pd", "_mm_xor_pd", "_mm_set1_pd", "_mm_mul_pd"]),
+            split(&["_mm_sub_pd", "_mm_xor_pd", "_mm_set1_pd", "_mm_mul_pd"]),
+            split(&["_mm256_fnmsub_pd"]),
+            native(&["_mm512_fnmsub_pd"]),
+        ],
+    ),
+    op(
+        "not_i16x16",
+        "not",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vmvnq_s16"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+        ],
+    ),
+    op(
+        "not_i16x32",
+        "not",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vmvnq_s16"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi16"]),
+        ],
+    ),
+    op(
+        "not_i16x8",
+        "not",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vmvnq_s16"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+        ],
+    ),
+    op(
+        "not_i32x16",
+        "not",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vmvnq_s32"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi32"]),
+        ],
+    ),
+    op(
+        "not_i32x4",
+        "not",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vmvnq_s32"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+        ],
+    ),
+    op(
+        "not_i32x8",
+        "not",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vmvnq_s32"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+        ],
+    ),
+    op(
+        "not_i64x2",
+        "not",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "not_i64x4",
+        "not",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "not_i64x8",
+        "not",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi64"]),
+        ],
+    ),
+    op(
+        "not_i8x16",
+        "not",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vmvnq_s8"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+        ],
+    ),
+    op(
+        "not_i8x32",
+        "not",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vmvnq_s8"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+        ],
+    ),
+    op(
+        "not_i8x64",
+        "not",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vmvnq_s8"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi8"]),
+        ],
+    ),
+    op(
+        "not_mask16x16",
+        "not",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vmvnq_s16"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask16x32",
+        "not",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vmvnq_s16"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask16x8",
+        "not",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vmvnq_s16"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask32x16",
+        "not",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vmvnq_s32"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask32x4",
+        "not",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vmvnq_s32"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask32x8",
+        "not",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vmvnq_s32"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask64x2",
+        "not",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask64x4",
+        "not",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask64x8",
+        "not",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+            ]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask8x16",
+        "not",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vmvnq_s8"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask8x32",
+        "not",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vmvnq_s8"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_mask8x64",
+        "not",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vmvnq_s8"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "not_u16x16",
+        "not",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vmvnq_u16"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+        ],
+    ),
+    op(
+        "not_u16x32",
+        "not",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vmvnq_u16"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi16"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi16"]),
+        ],
+    ),
+    op(
+        "not_u16x8",
+        "not",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vmvnq_u16"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi16"]),
+        ],
+    ),
+    op(
+        "not_u32x16",
+        "not",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vmvnq_u32"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi32"]),
+        ],
+    ),
+    op(
+        "not_u32x4",
+        "not",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vmvnq_u32"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi32"]),
+        ],
+    ),
+    op(
+        "not_u32x8",
+        "not",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vmvnq_u32"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi32"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi32"]),
+        ],
+    ),
+    op(
+        "not_u64x2",
+        "not",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+            ]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "not_u64x4",
+        "not",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+            ]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "not_u64x8",
+        "not",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+            ]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi64x"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi64x"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi64"]),
+        ],
+    ),
+    op(
+        "not_u8x16",
+        "not",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vmvnq_u8"]),
+            native(&["v128_not"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm_xor_si128", "_mm_set1_epi8"]),
+        ],
+    ),
+    op(
+        "not_u8x32",
+        "not",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vmvnq_u8"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+            native(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+        ],
+    ),
+    op(
+        "not_u8x64",
+        "not",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vmvnq_u8"]),
+            split(&["v128_not"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm_xor_si128", "_mm_set1_epi8"]),
+            split(&["_mm256_xor_si256", "_mm256_set1_epi8"]),
+            native(&["_mm512_xor_si512", "_mm512_set1_epi8"]),
+        ],
+    ),
+    op(
+        "or_i16x16",
+        "or",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vorrq_s16"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_i16x32",
+        "or",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vorrq_s16"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_i16x8",
+        "or",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vorrq_s16"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_i32x16",
+        "or",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vorrq_s32"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_i32x4",
+        "or",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vorrq_s32"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_i32x8",
+        "or",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vorrq_s32"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_i64x2",
+        "or",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vorrq_s64"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_i64x4",
+        "or",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vorrq_s64"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_i64x8",
+        "or",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vorrq_s64"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_i8x16",
+        "or",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vorrq_s8"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_i8x32",
+        "or",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vorrq_s8"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_i8x64",
+        "or",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vorrq_s8"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_mask16x16",
+        "or",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vorrq_s16"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask16x32",
+        "or",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vorrq_s16"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask16x8",
+        "or",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vorrq_s16"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask32x16",
+        "or",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vorrq_s32"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask32x4",
+        "or",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vorrq_s32"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask32x8",
+        "or",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vorrq_s32"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask64x2",
+        "or",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vorrq_s64"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask64x4",
+        "or",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vorrq_s64"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask64x8",
+        "or",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vorrq_s64"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask8x16",
+        "or",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vorrq_s8"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask8x32",
+        "or",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vorrq_s8"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_mask8x64",
+        "or",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vorrq_s8"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "or_u16x16",
+        "or",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vorrq_u16"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_u16x32",
+        "or",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vorrq_u16"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_u16x8",
+        "or",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vorrq_u16"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_u32x16",
+        "or",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vorrq_u32"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_u32x4",
+        "or",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vorrq_u32"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_u32x8",
+        "or",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vorrq_u32"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_u64x2",
+        "or",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vorrq_u64"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_u64x4",
+        "or",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vorrq_u64"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_u64x8",
+        "or",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vorrq_u64"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "or_u8x16",
+        "or",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vorrq_u8"]),
+            native(&["v128_or"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+            native(&["_mm_or_si128"]),
+        ],
+    ),
+    op(
+        "or_u8x32",
+        "or",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vorrq_u8"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            native(&["_mm256_or_si256"]),
+            native(&["_mm256_or_si256"]),
+        ],
+    ),
+    op(
+        "or_u8x64",
+        "or",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vorrq_u8"]),
+            split(&["v128_or"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm_or_si128"]),
+            split(&["_mm256_or_si256"]),
+            native(&["_mm512_or_si512"]),
+        ],
+    ),
+    op(
+        "pack_clamped_u8_f32x16",
+        "pack_clamped_u8",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[
+                "vcombine_s16",
+                "vqmovn_s32",
+                "vcvtnq_s32_f32",
+                "vcombine_u8",
+                "vqmovun_s16",
+            ]),
+            native(&[
+                "i32x4_trunc_sat_f32x4",
+                "f32x4_nearest",
+                "i16x8_narrow_i32x4",
+                "u8x16_narrow_i16x8",
+            ]),
+            native(&[
+                "_mm_set1_ps",
+                "_mm_cvtps_epi32",
+                "_mm_min_ps",
+                "_mm_packus_epi16",
+                "_mm_packs_epi32",
+            ]),
+            native(&[
+                "_mm_set1_ps",
+                "_mm_cvtps_epi32",
+                "_mm_min_ps",
+                "_mm_packus_epi16",
+                "_mm_packs_epi32",
+            ]),
+            native(&[
+                "_mm256_set1_ps",
+                "_mm256_cvtps_epi32",
+                "_mm256_min_ps",
+                "_mm256_permute4x64_epi64",
+                "_mm256_packs_epi32",
+                "_mm_packus_epi16",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+            ]),
+            native(&[
+                "_mm512_max_ps",
+                "_mm512_min_ps",
+                "_mm512_set1_ps",
+                "_mm512_setzero_ps",
+                "_mm512_cvtepi32_epi8",
+                "_mm512_cvtps_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "pack_saturate_i8_i16x16",
+        "pack_saturate_i8",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&["vcombine_s8", "vqmovn_s16"]),
+            native(&["i8x16_narrow_i16x8"]),
+            native(&["_mm_packs_epi16"]),
+            native(&["_mm_packs_epi16"]),
+            native(&[
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_packs_epi16",
+            ]),
+            native(&["_mm256_cvtsepi16_epi8"]),
+        ],
+    ),
+    op(
+        "pack_saturate_i8_i16x32",
+        "pack_saturate_i8",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vcombine_s8", "vqmovn_s16"]),
+            split(&["i8x16_narrow_i16x8"]),
+            split(&["_mm_packs_epi16"]),
+            split(&["_mm_packs_epi16"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_packs_epi16"]),
+            native(&["_mm512_cvtsepi16_epi8"]),
+        ],
+    ),
+    op(
+        "pack_saturate_i8_u16x16",
+        "pack_saturate_i8",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s8_u8",
+                "vcombine_u8",
+                "vqmovn_u16",
+                "vminq_u16",
+                "vdupq_n_u16",
+            ]),
+            native(&["u16x8_splat", "u16x8_min", "i8x16_narrow_i16x8"]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_sub_epi16",
+                "_mm_subs_epu16",
+                "_mm_packs_epi16",
+            ]),
+            native(&["_mm_set1_epi16", "_mm_min_epu16", "_mm_packs_epi16"]),
+            native(&[
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_set1_epi16",
+                "_mm_min_epu16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm256_cvtepi16_epi8",
+                "_mm256_min_epu16",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "pack_saturate_i8_u16x32",
+        "pack_saturate_i8",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s8_u8",
+                "vcombine_u8",
+                "vqmovn_u16",
+                "vminq_u16",
+                "vdupq_n_u16",
+            ]),
+            split(&["u16x8_splat", "u16x8_min", "i8x16_narrow_i16x8"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_sub_epi16",
+                "_mm_subs_epu16",
+                "_mm_packs_epi16",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_min_epu16", "_mm_packs_epi16"]),
+            native(&[
+                "_mm256_set1_epi16",
+                "_mm256_min_epu16",
+                "_mm256_permute4x64_epi64",
+                "_mm256_packs_epi16",
+            ]),
+            native(&[
+                "_mm512_cvtepi16_epi8",
+                "_mm512_min_epu16",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "pack_saturate_u8_i16x16",
+        "pack_saturate_u8",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&["vcombine_u8", "vqmovun_s16"]),
+            native(&["u8x16_narrow_i16x8"]),
+            native(&["_mm_packus_epi16"]),
+            native(&["_mm_packus_epi16"]),
+            native(&[
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm256_cvtusepi16_epi8",
+                "_mm256_max_epi16",
+                "_mm256_setzero_si256",
+            ]),
+        ],
+    ),
+    op(
+        "pack_saturate_u8_i16x32",
+        "pack_saturate_u8",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vcombine_u8", "vqmovun_s16"]),
+            split(&["u8x16_narrow_i16x8"]),
+            split(&["_mm_packus_epi16"]),
+            split(&["_mm_packus_epi16"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_packus_epi16"]),
+            native(&[
+                "_mm512_cvtusepi16_epi8",
+                "_mm512_max_epi16",
+                "_mm512_setzero_si512",
+            ]),
+        ],
+    ),
+    op(
+        "pack_saturate_u8_u16x16",
+        "pack_saturate_u8",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&["vcombine_u8", "vqmovn_u16"]),
+            native(&["u16x8_splat", "u16x8_min", "u8x16_narrow_i16x8"]),
+            native(&[
+                "_mm_set1_epi16",
+                "_mm_sub_epi16",
+                "_mm_subs_epu16",
+                "_mm_packus_epi16",
+            ]),
+            native(&["_mm_set1_epi16", "_mm_min_epu16", "_mm_packus_epi16"]),
+            native(&[
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_set1_epi16",
+                "_mm_min_epu16",
+                "_mm_packus_epi16",
+            ]),
+            native(&["_mm256_cvtusepi16_epi8"]),
+        ],
+    ),
+    op(
+        "pack_saturate_u8_u16x32",
+        "pack_saturate_u8",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vcombine_u8", "vqmovn_u16"]),
+            split(&["u16x8_splat", "u16x8_min", "u8x16_narrow_i16x8"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_sub_epi16",
+                "_mm_subs_epu16",
+                "_mm_packus_epi16",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_min_epu16", "_mm_packus_epi16"]),
+            native(&[
+                "_mm256_set1_epi16",
+                "_mm256_min_epu16",
+                "_mm256_permute4x64_epi64",
+                "_mm256_packus_epi16",
+            ]),
+            native(&["_mm512_cvtusepi16_epi8"]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_i16x16",
+        "reduce_add_widened",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vaddlvq_s16"]),
+            split(&[
+                "i32x4_extadd_pairwise_i16x8",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "i32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_madd_epi16",
+                "_mm256_set1_epi16",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_madd_epi16",
+                "_mm256_set1_epi16",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_i16x32",
+        "reduce_add_widened",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vaddlvq_s16"]),
+            split(&[
+                "i32x4_extadd_pairwise_i16x8",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "i32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm256_madd_epi16",
+                "_mm256_set1_epi16",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm512_madd_epi16",
+                "_mm512_set1_epi16",
+                "_mm512_reduce_add_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_i16x8",
+        "reduce_add_widened",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vaddlvq_s16"]),
+            native(&[
+                "i32x4_extadd_pairwise_i16x8",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "i32x4_extract_lane",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_i8x16",
+        "reduce_add_widened",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vaddlvq_s8"]),
+            native(&[
+                "i32x4_extadd_pairwise_i16x8",
+                "i16x8_extadd_pairwise_i8x16",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "i32x4_extract_lane",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_i8x32",
+        "reduce_add_widened",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vaddlvq_s8"]),
+            split(&[
+                "i32x4_extadd_pairwise_i16x8",
+                "i16x8_extadd_pairwise_i8x16",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "i32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_sad_epu8",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+                "_mm256_setzero_si256",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_sad_epu8",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+                "_mm256_setzero_si256",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_i8x64",
+        "reduce_add_widened",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vaddlvq_s8"]),
+            split(&[
+                "i32x4_extadd_pairwise_i16x8",
+                "i16x8_extadd_pairwise_i8x16",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "i32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm256_sad_epu8",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+                "_mm256_setzero_si256",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm512_sad_epu8",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi8",
+                "_mm512_setzero_si512",
+                "_mm512_reduce_add_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_u16x16",
+        "reduce_add_widened",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vaddlvq_u16"]),
+            split(&[
+                "u32x4_extadd_pairwise_u16x8",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "u32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_madd_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_madd_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_u16x32",
+        "reduce_add_widened",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vaddlvq_u16"]),
+            split(&[
+                "u32x4_extadd_pairwise_u16x8",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "u32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm256_madd_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm512_madd_epi16",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi16",
+                "_mm512_reduce_add_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_u16x8",
+        "reduce_add_widened",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vaddlvq_u16"]),
+            native(&[
+                "u32x4_extadd_pairwise_u16x8",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "u32x4_extract_lane",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_madd_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_u8x16",
+        "reduce_add_widened",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vaddlvq_u8"]),
+            native(&[
+                "u32x4_extadd_pairwise_u16x8",
+                "u16x8_extadd_pairwise_u8x16",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "u32x4_extract_lane",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_u8x32",
+        "reduce_add_widened",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vaddlvq_u8"]),
+            split(&[
+                "u32x4_extadd_pairwise_u16x8",
+                "u16x8_extadd_pairwise_u8x16",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "u32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+        ],
+    ),
+    op(
+        "reduce_add_widened_u8x64",
+        "reduce_add_widened",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vaddlvq_u8"]),
+            split(&[
+                "u32x4_extadd_pairwise_u16x8",
+                "u16x8_extadd_pairwise_u8x16",
+                "i32x4_add",
+                "i32x4_shuffle",
+                "u32x4_extract_lane",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_add_epi32",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            split(&[
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm_add_epi32",
+                "_mm256_castsi256_si128",
+                "_mm256_extracti128_si256",
+                "_mm_shuffle_epi32",
+                "_mm_cvtsi128_si32",
+            ]),
+            native(&[
+                "_mm512_sad_epu8",
+                "_mm512_setzero_si512",
+                "_mm512_reduce_add_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "reinterpret_f32_f64x2",
+        "reinterpret_f32",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_f32_f64"]),
+            native(&[]),
+            native(&["_mm_castpd_ps"]),
+            native(&["_mm_castpd_ps"]),
+            native(&["_mm_castpd_ps"]),
+            native(&["_mm_castpd_ps"]),
+        ],
+    ),
+    op(
+        "reinterpret_f32_f64x4",
+        "reinterpret_f32",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_f32_f64"]),
+            split(&[]),
+            split(&["_mm_castpd_ps"]),
+            split(&["_mm_castpd_ps"]),
+            native(&["_mm256_castpd_ps"]),
+            native(&["_mm256_castpd_ps"]),
+        ],
+    ),
+    op(
+        "reinterpret_f32_f64x8",
+        "reinterpret_f32",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_f32_f64"]),
+            split(&[]),
+            split(&["_mm_castpd_ps"]),
+            split(&["_mm_castpd_ps"]),
+            split(&["_mm256_castpd_ps"]),
+            native(&["_mm512_castpd_ps"]),
+        ],
+    ),
+    op(
+        "reinterpret_f64_f32x16",
+        "reinterpret_f64",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_f64_f32"]),
+            split(&[]),
+            split(&["_mm_castps_pd"]),
+            split(&["_mm_castps_pd"]),
+            split(&["_mm256_castps_pd"]),
+            native(&["_mm512_castps_pd"]),
+        ],
+    ),
+    op(
+        "reinterpret_f64_f32x4",
+        "reinterpret_f64",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_f64_f32"]),
+            native(&[]),
+            native(&["_mm_castps_pd"]),
+            native(&["_mm_castps_pd"]),
+            native(&["_mm_castps_pd"]),
+            native(&["_mm_castps_pd"]),
+        ],
+    ),
+    op(
+        "reinterpret_f64_f32x8",
+        "reinterpret_f64",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_f64_f32"]),
+            split(&[]),
+            split(&["_mm_castps_pd"]),
+            split(&["_mm_castps_pd"]),
+            native(&["_mm256_castps_pd"]),
+            native(&["_mm256_castps_pd"]),
+        ],
+    ),
+    op(
+        "reinterpret_i32_f32x16",
+        "reinterpret_i32",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_f32"]),
+            split(&[]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm256_castps_si256"]),
+            native(&["_mm512_castps_si512"]),
+        ],
+    ),
+    op(
+        "reinterpret_i32_f32x4",
+        "reinterpret_i32",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_f32"]),
+            native(&[]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+        ],
+    ),
+    op(
+        "reinterpret_i32_f32x8",
+        "reinterpret_i32",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_f32"]),
+            split(&[]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm_castps_si128"]),
+            native(&["_mm256_castps_si256"]),
+            native(&["_mm256_castps_si256"]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_f32x16",
+        "reinterpret_u32",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_f32"]),
+            split(&[]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm256_castps_si256"]),
+            native(&["_mm512_castps_si512"]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_f32x4",
+        "reinterpret_u32",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_f32"]),
+            native(&[]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_f32x8",
+        "reinterpret_u32",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_f32"]),
+            split(&[]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm_castps_si128"]),
+            native(&["_mm256_castps_si256"]),
+            native(&["_mm256_castps_si256"]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i16x16",
+        "reinterpret_u32",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i16x32",
+        "reinterpret_u32",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i16x8",
+        "reinterpret_u32",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_s16"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i32x16",
+        "reinterpret_u32",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i32x4",
+        "reinterpret_u32",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_s32"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i32x8",
+        "reinterpret_u32",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i64x2",
+        "reinterpret_u32",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_s64"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i64x4",
+        "reinterpret_u32",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i64x8",
+        "reinterpret_u32",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i8x16",
+        "reinterpret_u32",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_s8"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i8x32",
+        "reinterpret_u32",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_i8x64",
+        "reinterpret_u32",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u16x16",
+        "reinterpret_u32",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u16x32",
+        "reinterpret_u32",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u16x8",
+        "reinterpret_u32",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_u16"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u64x2",
+        "reinterpret_u32",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_u64"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u64x4",
+        "reinterpret_u32",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u64x8",
+        "reinterpret_u32",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u8x16",
+        "reinterpret_u32",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u32_u8"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u8x32",
+        "reinterpret_u32",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u32_u8x64",
+        "reinterpret_u32",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u32_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_f32x16",
+        "reinterpret_u8",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_f32"]),
+            split(&[]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm256_castps_si256"]),
+            native(&["_mm512_castps_si512"]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_f32x4",
+        "reinterpret_u8",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_f32"]),
+            native(&[]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+            native(&["_mm_castps_si128"]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_f32x8",
+        "reinterpret_u8",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_f32"]),
+            split(&[]),
+            split(&["_mm_castps_si128"]),
+            split(&["_mm_castps_si128"]),
+            native(&["_mm256_castps_si256"]),
+            native(&["_mm256_castps_si256"]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i16x16",
+        "reinterpret_u8",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i16x32",
+        "reinterpret_u8",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i16x8",
+        "reinterpret_u8",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_s16"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i32x16",
+        "reinterpret_u8",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i32x4",
+        "reinterpret_u8",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_s32"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i32x8",
+        "reinterpret_u8",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i64x2",
+        "reinterpret_u8",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_s64"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i64x4",
+        "reinterpret_u8",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i64x8",
+        "reinterpret_u8",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i8x16",
+        "reinterpret_u8",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_s8"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i8x32",
+        "reinterpret_u8",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_i8x64",
+        "reinterpret_u8",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u16x16",
+        "reinterpret_u8",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u16x32",
+        "reinterpret_u8",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u16x8",
+        "reinterpret_u8",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_u16"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u32x16",
+        "reinterpret_u8",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u32x4",
+        "reinterpret_u8",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_u32"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u32x8",
+        "reinterpret_u8",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u64x2",
+        "reinterpret_u8",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_u8_u64"]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u64x4",
+        "reinterpret_u8",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "reinterpret_u8_u64x8",
+        "reinterpret_u8",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_u8_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_f32x16",
+        "rotate_elements_left",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_f32x4",
+        "rotate_elements_left",
+        "f32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_f32x8",
+        "rotate_elements_left",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_f64x2",
+        "rotate_elements_left",
+        "f64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_f64x4",
+        "rotate_elements_left",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_f64x8",
+        "rotate_elements_left",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i16x16",
+        "rotate_elements_left",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i16x32",
+        "rotate_elements_left",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i16x8",
+        "rotate_elements_left",
+        "i16x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i32x16",
+        "rotate_elements_left",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i32x4",
+        "rotate_elements_left",
+        "i32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i32x8",
+        "rotate_elements_left",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i64x2",
+        "rotate_elements_left",
+        "i64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i64x4",
+        "rotate_elements_left",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i64x8",
+        "rotate_elements_left",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i8x16",
+        "rotate_elements_left",
+        "i8x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i8x32",
+        "rotate_elements_left",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_i8x64",
+        "rotate_elements_left",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u16x16",
+        "rotate_elements_left",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u16x32",
+        "rotate_elements_left",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u16x8",
+        "rotate_elements_left",
+        "u16x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u32x16",
+        "rotate_elements_left",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u32x4",
+        "rotate_elements_left",
+        "u32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u32x8",
+        "rotate_elements_left",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u64x2",
+        "rotate_elements_left",
+        "u64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u64x4",
+        "rotate_elements_left",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u64x8",
+        "rotate_elements_left",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u8x16",
+        "rotate_elements_left",
+        "u8x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u8x32",
+        "rotate_elements_left",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_left_u8x64",
+        "rotate_elements_left",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_f32x16",
+        "rotate_elements_right",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_f32x4",
+        "rotate_elements_right",
+        "f32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_f32x8",
+        "rotate_elements_right",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_f64x2",
+        "rotate_elements_right",
+        "f64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_f64x4",
+        "rotate_elements_right",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_f64x8",
+        "rotate_elements_right",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i16x16",
+        "rotate_elements_right",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i16x32",
+        "rotate_elements_right",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i16x8",
+        "rotate_elements_right",
+        "i16x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i32x16",
+        "rotate_elements_right",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i32x4",
+        "rotate_elements_right",
+        "i32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i32x8",
+        "rotate_elements_right",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i64x2",
+        "rotate_elements_right",
+        "i64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i64x4",
+        "rotate_elements_right",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i64x8",
+        "rotate_elements_right",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i8x16",
+        "rotate_elements_right",
+        "i8x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i8x32",
+        "rotate_elements_right",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_i8x64",
+        "rotate_elements_right",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u16x16",
+        "rotate_elements_right",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u16x32",
+        "rotate_elements_right",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u16x8",
+        "rotate_elements_right",
+        "u16x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u32x16",
+        "rotate_elements_right",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u32x4",
+        "rotate_elements_right",
+        "u32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u32x8",
+        "rotate_elements_right",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u64x2",
+        "rotate_elements_right",
+        "u64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u64x4",
+        "rotate_elements_right",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u64x8",
+        "rotate_elements_right",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u8x16",
+        "rotate_elements_right",
+        "u8x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u8x32",
+        "rotate_elements_right",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "rotate_elements_right_u8x64",
+        "rotate_elements_right",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            split(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "round_ties_even_f32x16",
+        "round_ties_even",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vrndnq_f32"]),
+            split(&["f32x4_nearest"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            split(&["_mm256_round_ps"]),
+            native(&["_mm512_roundscale_ps"]),
+        ],
+    ),
+    op(
+        "round_ties_even_f32x4",
+        "round_ties_even",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vrndnq_f32"]),
+            native(&["f32x4_nearest"]),
+            PORTABLE,
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+        ],
+    ),
+    op(
+        "round_ties_even_f32x8",
+        "round_ties_even",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vrndnq_f32"]),
+            split(&["f32x4_nearest"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            native(&["_mm256_round_ps"]),
+            native(&["_mm256_round_ps"]),
+        ],
+    ),
+    op(
+        "round_ties_even_f64x2",
+        "round_ties_even",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vrndnq_f64"]),
+            native(&["f64x2_nearest"]),
+            PORTABLE,
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+        ],
+    ),
+    op(
+        "round_ties_even_f64x4",
+        "round_ties_even",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vrndnq_f64"]),
+            split(&["f64x2_nearest"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            native(&["_mm256_round_pd"]),
+            native(&["_mm256_round_pd"]),
+        ],
+    ),
+    op(
+        "round_ties_even_f64x8",
+        "round_ties_even",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vrndnq_f64"]),
+            split(&["f64x2_nearest"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            split(&["_mm256_round_pd"]),
+            native(&["_mm512_roundscale_pd"]),
+        ],
+    ),
+    op(
+        "saturating_add_i16x16",
+        "saturating_add",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vqaddq_s16"]),
+            split(&["i16x8_add_sat"]),
+            split(&["_mm_adds_epi16"]),
+            split(&["_mm_adds_epi16"]),
+            native(&["_mm256_adds_epi16"]),
+            native(&["_mm256_adds_epi16"]),
+        ],
+    ),
+    op(
+        "saturating_add_i16x32",
+        "saturating_add",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vqaddq_s16"]),
+            split(&["i16x8_add_sat"]),
+            split(&["_mm_adds_epi16"]),
+            split(&["_mm_adds_epi16"]),
+            split(&["_mm256_adds_epi16"]),
+            native(&["_mm512_adds_epi16"]),
+        ],
+    ),
+    op(
+        "saturating_add_i16x8",
+        "saturating_add",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vqaddq_s16"]),
+            native(&["i16x8_add_sat"]),
+            native(&["_mm_adds_epi16"]),
+            native(&["_mm_adds_epi16"]),
+            native(&["_mm_adds_epi16"]),
+            native(&["_mm_adds_epi16"]),
+        ],
+    ),
+    op(
+        "saturating_add_i32x16",
+        "saturating_add",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vqaddq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_i32x4",
+        "saturating_add",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vqaddq_s32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_add_i32x8",
+        "saturating_add",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vqaddq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_i64x2",
+        "saturating_add",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vqaddq_s64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_add_i64x4",
+        "saturating_add",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vqaddq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_i64x8",
+        "saturating_add",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vqaddq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_i8x16",
+        "saturating_add",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vqaddq_s8"]),
+            native(&["i8x16_add_sat"]),
+            native(&["_mm_adds_epi8"]),
+            native(&["_mm_adds_epi8"]),
+            native(&["_mm_adds_epi8"]),
+            native(&["_mm_adds_epi8"]),
+        ],
+    ),
+    op(
+        "saturating_add_i8x32",
+        "saturating_add",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vqaddq_s8"]),
+            split(&["i8x16_add_sat"]),
+            split(&["_mm_adds_epi8"]),
+            split(&["_mm_adds_epi8"]),
+            native(&["_mm256_adds_epi8"]),
+            native(&["_mm256_adds_epi8"]),
+        ],
+    ),
+    op(
+        "saturating_add_i8x64",
+        "saturating_add",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vqaddq_s8"]),
+            split(&["i8x16_add_sat"]),
+            split(&["_mm_adds_epi8"]),
+            split(&["_mm_adds_epi8"]),
+            split(&["_mm256_adds_epi8"]),
+            native(&["_mm512_adds_epi8"]),
+        ],
+    ),
+    op(
+        "saturating_add_u16x16",
+        "saturating_add",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vqaddq_u16"]),
+            split(&["u16x8_add_sat"]),
+            split(&["_mm_adds_epu16"]),
+            split(&["_mm_adds_epu16"]),
+            native(&["_mm256_adds_epu16"]),
+            native(&["_mm256_adds_epu16"]),
+        ],
+    ),
+    op(
+        "saturating_add_u16x32",
+        "saturating_add",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vqaddq_u16"]),
+            split(&["u16x8_add_sat"]),
+            split(&["_mm_adds_epu16"]),
+            split(&["_mm_adds_epu16"]),
+            split(&["_mm256_adds_epu16"]),
+            native(&["_mm512_adds_epu16"]),
+        ],
+    ),
+    op(
+        "saturating_add_u16x8",
+        "saturating_add",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vqaddq_u16"]),
+            native(&["u16x8_add_sat"]),
+            native(&["_mm_adds_epu16"]),
+            native(&["_mm_adds_epu16"]),
+            native(&["_mm_adds_epu16"]),
+            native(&["_mm_adds_epu16"]),
+        ],
+    ),
+    op(
+        "saturating_add_u32x16",
+        "saturating_add",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vqaddq_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_u32x4",
+        "saturating_add",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vqaddq_u32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_add_u32x8",
+        "saturating_add",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vqaddq_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_u64x2",
+        "saturating_add",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vqaddq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_add_u64x4",
+        "saturating_add",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vqaddq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_u64x8",
+        "saturating_add",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vqaddq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_add_u8x16",
+        "saturating_add",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vqaddq_u8"]),
+            native(&["u8x16_add_sat"]),
+            native(&["_mm_adds_epu8"]),
+            native(&["_mm_adds_epu8"]),
+            native(&["_mm_adds_epu8"]),
+            native(&["_mm_adds_epu8"]),
+        ],
+    ),
+    op(
+        "saturating_add_u8x32",
+        "saturating_add",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vqaddq_u8"]),
+            split(&["u8x16_add_sat"]),
+            split(&["_mm_adds_epu8"]),
+            split(&["_mm_adds_epu8"]),
+            native(&["_mm256_adds_epu8"]),
+            native(&["_mm256_adds_epu8"]),
+        ],
+    ),
+    op(
+        "saturating_add_u8x64",
+        "saturating_add",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vqaddq_u8"]),
+            split(&["u8x16_add_sat"]),
+            split(&["_mm_adds_epu8"]),
+            split(&["_mm_adds_epu8"]),
+            split(&["_mm256_adds_epu8"]),
+            native(&["_mm512_adds_epu8"]),
+        ],
+    ),
+    op(
+        "saturating_sub_i16x16",
+        "saturating_sub",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vqsubq_s16"]),
+            split(&["i16x8_sub_sat"]),
+            split(&["_mm_subs_epi16"]),
+            split(&["_mm_subs_epi16"]),
+            native(&["_mm256_subs_epi16"]),
+            native(&["_mm256_subs_epi16"]),
+        ],
+    ),
+    op(
+        "saturating_sub_i16x32",
+        "saturating_sub",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vqsubq_s16"]),
+            split(&["i16x8_sub_sat"]),
+            split(&["_mm_subs_epi16"]),
+            split(&["_mm_subs_epi16"]),
+            split(&["_mm256_subs_epi16"]),
+            native(&["_mm512_subs_epi16"]),
+        ],
+    ),
+    op(
+        "saturating_sub_i16x8",
+        "saturating_sub",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vqsubq_s16"]),
+            native(&["i16x8_sub_sat"]),
+            native(&["_mm_subs_epi16"]),
+            native(&["_mm_subs_epi16"]),
+            native(&["_mm_subs_epi16"]),
+            native(&["_mm_subs_epi16"]),
+        ],
+    ),
+    op(
+        "saturating_sub_i32x16",
+        "saturating_sub",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vqsubq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_i32x4",
+        "saturating_sub",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vqsubq_s32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_sub_i32x8",
+        "saturating_sub",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vqsubq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_i64x2",
+        "saturating_sub",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vqsubq_s64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_sub_i64x4",
+        "saturating_sub",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vqsubq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_i64x8",
+        "saturating_sub",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vqsubq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_i8x16",
+        "saturating_sub",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vqsubq_s8"]),
+            native(&["i8x16_sub_sat"]),
+            native(&["_mm_subs_epi8"]),
+            native(&["_mm_subs_epi8"]),
+            native(&["_mm_subs_epi8"]),
+            native(&["_mm_subs_epi8"]),
+        ],
+    ),
+    op(
+        "saturating_sub_i8x32",
+        "saturating_sub",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vqsubq_s8"]),
+            split(&["i8x16_sub_sat"]),
+            split(&["_mm_subs_epi8"]),
+            split(&["_mm_subs_epi8"]),
+            native(&["_mm256_subs_epi8"]),
+            native(&["_mm256_subs_epi8"]),
+        ],
+    ),
+    op(
+        "saturating_sub_i8x64",
+        "saturating_sub",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vqsubq_s8"]),
+            split(&["i8x16_sub_sat"]),
+            split(&["_mm_subs_epi8"]),
+            split(&["_mm_subs_epi8"]),
+            split(&["_mm256_subs_epi8"]),
+            native(&["_mm512_subs_epi8"]),
+        ],
+    ),
+    op(
+        "saturating_sub_u16x16",
+        "saturating_sub",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vqsubq_u16"]),
+            split(&["u16x8_sub_sat"]),
+            split(&["_mm_subs_epu16"]),
+            split(&["_mm_subs_epu16"]),
+            native(&["_mm256_subs_epu16"]),
+            native(&["_mm256_subs_epu16"]),
+        ],
+    ),
+    op(
+        "saturating_sub_u16x32",
+        "saturating_sub",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vqsubq_u16"]),
+            split(&["u16x8_sub_sat"]),
+            split(&["_mm_subs_epu16"]),
+            split(&["_mm_subs_epu16"]),
+            split(&["_mm256_subs_epu16"]),
+            native(&["_mm512_subs_epu16"]),
+        ],
+    ),
+    op(
+        "saturating_sub_u16x8",
+        "saturating_sub",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vqsubq_u16"]),
+            native(&["u16x8_sub_sat"]),
+            native(&["_mm_subs_epu16"]),
+            native(&["_mm_subs_epu16"]),
+            native(&["_mm_subs_epu16"]),
+            native(&["_mm_subs_epu16"]),
+        ],
+    ),
+    op(
+        "saturating_sub_u32x16",
+        "saturating_sub",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vqsubq_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_u32x4",
+        "saturating_sub",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vqsubq_u32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_sub_u32x8",
+        "saturating_sub",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vqsubq_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_u64x2",
+        "saturating_sub",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vqsubq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "saturating_sub_u64x4",
+        "saturating_sub",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vqsubq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_u64x8",
+        "saturating_sub",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vqsubq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "saturating_sub_u8x16",
+        "saturating_sub",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vqsubq_u8"]),
+            native(&["u8x16_sub_sat"]),
+            native(&["_mm_subs_epu8"]),
+            native(&["_mm_subs_epu8"]),
+            native(&["_mm_subs_epu8"]),
+            native(&["_mm_subs_epu8"]),
+        ],
+    ),
+    op(
+        "saturating_sub_u8x32",
+        "saturating_sub",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vqsubq_u8"]),
+            split(&["u8x16_sub_sat"]),
+            split(&["_mm_subs_epu8"]),
+            split(&["_mm_subs_epu8"]),
+            native(&["_mm256_subs_epu8"]),
+            native(&["_mm256_subs_epu8"]),
+        ],
+    ),
+    op(
+        "saturating_sub_u8x64",
+        "saturating_sub",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vqsubq_u8"]),
+            split(&["u8x16_sub_sat"]),
+            split(&["_mm_subs_epu8"]),
+            split(&["_mm_subs_epu8"]),
+            split(&["_mm256_subs_epu8"]),
+            native(&["_mm512_subs_epu8"]),
+        ],
+    ),
+    op(
+        "select_f32x16",
+        "select",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_f32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&[
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_blendv_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_blendv_ps", "_mm256_castsi256_ps"]),
+            native(&["_mm512_mask_blend_ps"]),
+        ],
+    ),
+    op(
+        "select_f32x4",
+        "select",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vbslq_f32", "vreinterpretq_u32_s32"]),
+            native(&["v128_bitselect"]),
+            native(&[
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_andnot_ps",
+            ]),
+            native(&["_mm_blendv_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_blendv_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_mask_blend_ps"]),
+        ],
+    ),
+    op(
+        "select_f32x8",
+        "select",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_f32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&[
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_blendv_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_blendv_ps", "_mm256_castsi256_ps"]),
+            native(&["_mm256_mask_blend_ps"]),
+        ],
+    ),
+    op(
+        "select_f64x2",
+        "select",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vbslq_f64", "vreinterpretq_u64_s64"]),
+            native(&["v128_bitselect"]),
+            native(&[
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_castsi128_pd",
+                "_mm_andnot_pd",
+            ]),
+            native(&["_mm_blendv_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_blendv_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_mask_blend_pd"]),
+        ],
+    ),
+    op(
+        "select_f64x4",
+        "select",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_f64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&[
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_castsi128_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_blendv_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_blendv_pd", "_mm256_castsi256_pd"]),
+            native(&["_mm256_mask_blend_pd"]),
+        ],
+    ),
+    op(
+        "select_f64x8",
+        "select",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_f64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&[
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_castsi128_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_blendv_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_blendv_pd", "_mm256_castsi256_pd"]),
+            native(&["_mm512_mask_blend_pd"]),
+        ],
+    ),
+    op(
+        "select_i16x16",
+        "select",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_i16x32",
+        "select",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_i16x8",
+        "select",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_i32x16",
+        "select",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_i32x4",
+        "select",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_i32x8",
+        "select",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_i64x2",
+        "select",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_i64x4",
+        "select",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_i64x8",
+        "select",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_i8x16",
+        "select",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_i8x32",
+        "select",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_i8x64",
+        "select",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_mask16x16",
+        "select",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask16x32",
+        "select",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask16x8",
+        "select",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask32x16",
+        "select",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask32x4",
+        "select",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask32x8",
+        "select",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask64x2",
+        "select",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask64x4",
+        "select",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask64x8",
+        "select",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask8x16",
+        "select",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask8x32",
+        "select",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_mask8x64",
+        "select",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_f32x16",
+        "select_relaxed",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_f32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&[
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_blendv_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_blendv_ps", "_mm256_castsi256_ps"]),
+            native(&["_mm512_mask_blend_ps"]),
+        ],
+    ),
+    op(
+        "select_relaxed_f32x4",
+        "select_relaxed",
+        "f32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_f32x8",
+        "select_relaxed",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_f32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&[
+                "_mm_or_ps",
+                "_mm_and_ps",
+                "_mm_castsi128_ps",
+                "_mm_andnot_ps",
+            ]),
+            split(&["_mm_blendv_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_blendv_ps", "_mm256_castsi256_ps"]),
+            native(&["_mm256_mask_blend_ps"]),
+        ],
+    ),
+    op(
+        "select_relaxed_f64x2",
+        "select_relaxed",
+        "f64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_f64x4",
+        "select_relaxed",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_f64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&[
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_castsi128_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_blendv_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_blendv_pd", "_mm256_castsi256_pd"]),
+            native(&["_mm256_mask_blend_pd"]),
+        ],
+    ),
+    op(
+        "select_relaxed_f64x8",
+        "select_relaxed",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_f64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&[
+                "_mm_or_pd",
+                "_mm_and_pd",
+                "_mm_castsi128_pd",
+                "_mm_andnot_pd",
+            ]),
+            split(&["_mm_blendv_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_blendv_pd", "_mm256_castsi256_pd"]),
+            native(&["_mm512_mask_blend_pd"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i16x16",
+        "select_relaxed",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i16x32",
+        "select_relaxed",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i16x8",
+        "select_relaxed",
+        "i16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_i32x16",
+        "select_relaxed",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i32x4",
+        "select_relaxed",
+        "i32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_i32x8",
+        "select_relaxed",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i64x2",
+        "select_relaxed",
+        "i64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_i64x4",
+        "select_relaxed",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i64x8",
+        "select_relaxed",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i8x16",
+        "select_relaxed",
+        "i8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_i8x32",
+        "select_relaxed",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_relaxed_i8x64",
+        "select_relaxed",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask16x16",
+        "select_relaxed",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask16x32",
+        "select_relaxed",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s16", "vreinterpretq_u16_s16"]),
+            split(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask16x8",
+        "select_relaxed",
+        "mask16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_mask32x16",
+        "select_relaxed",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask32x4",
+        "select_relaxed",
+        "mask32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_mask32x8",
+        "select_relaxed",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask64x2",
+        "select_relaxed",
+        "mask64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_mask64x4",
+        "select_relaxed",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask64x8",
+        "select_relaxed",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_s64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask8x16",
+        "select_relaxed",
+        "mask8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_mask8x32",
+        "select_relaxed",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_mask8x64",
+        "select_relaxed",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vbslq_s8", "vreinterpretq_u8_s8"]),
+            split(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "select_relaxed_u16x16",
+        "select_relaxed",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vbslq_u16", "vreinterpretq_u16_s16"]),
+            split(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u16x32",
+        "select_relaxed",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vbslq_u16", "vreinterpretq_u16_s16"]),
+            split(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u16x8",
+        "select_relaxed",
+        "u16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i16x8_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_u32x16",
+        "select_relaxed",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_u32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u32x4",
+        "select_relaxed",
+        "u32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_u32x8",
+        "select_relaxed",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_u32", "vreinterpretq_u32_s32"]),
+            split(&["i32x4_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u64x2",
+        "select_relaxed",
+        "u64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_u64x4",
+        "select_relaxed",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_u64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u64x8",
+        "select_relaxed",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_u64", "vreinterpretq_u64_s64"]),
+            split(&["i64x2_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u8x16",
+        "select_relaxed",
+        "u8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            native(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+        ],
+    ),
+    op(
+        "select_relaxed_u8x32",
+        "select_relaxed",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vbslq_u8", "vreinterpretq_u8_s8"]),
+            split(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_relaxed_u8x64",
+        "select_relaxed",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vbslq_u8", "vreinterpretq_u8_s8"]),
+            split(&["i8x16_relaxed_laneselect", "v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_u16x16",
+        "select",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vbslq_u16", "vreinterpretq_u16_s16"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_u16x32",
+        "select",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vbslq_u16", "vreinterpretq_u16_s16"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_u16x8",
+        "select",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vbslq_u16", "vreinterpretq_u16_s16"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi16"]),
+        ],
+    ),
+    op(
+        "select_u32x16",
+        "select",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vbslq_u32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_u32x4",
+        "select",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vbslq_u32", "vreinterpretq_u32_s32"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_u32x8",
+        "select",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vbslq_u32", "vreinterpretq_u32_s32"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi32"]),
+        ],
+    ),
+    op(
+        "select_u64x2",
+        "select",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vbslq_u64", "vreinterpretq_u64_s64"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_u64x4",
+        "select",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vbslq_u64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_u64x8",
+        "select",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vbslq_u64", "vreinterpretq_u64_s64"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi64"]),
+        ],
+    ),
+    op(
+        "select_u8x16",
+        "select",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vbslq_u8", "vreinterpretq_u8_s8"]),
+            native(&["v128_bitselect"]),
+            native(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_blendv_epi8"]),
+            native(&["_mm_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_u8x32",
+        "select",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vbslq_u8", "vreinterpretq_u8_s8"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            native(&["_mm256_blendv_epi8"]),
+            native(&["_mm256_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "select_u8x64",
+        "select",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vbslq_u8", "vreinterpretq_u8_s8"]),
+            split(&["v128_bitselect"]),
+            split(&["_mm_or_si128", "_mm_and_si128", "_mm_andnot_si128"]),
+            split(&["_mm_blendv_epi8"]),
+            split(&["_mm256_blendv_epi8"]),
+            native(&["_mm512_mask_blend_epi8"]),
+        ],
+    ),
+    op(
+        "set_mask16x16",
+        "set",
+        "mask16x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask16x32",
+        "set",
+        "mask16x32",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask16x8",
+        "set",
+        "mask16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask32x16",
+        "set",
+        "mask32x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask32x4",
+        "set",
+        "mask32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask32x8",
+        "set",
+        "mask32x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask64x2",
+        "set",
+        "mask64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask64x4",
+        "set",
+        "mask64x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask64x8",
+        "set",
+        "mask64x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask8x16",
+        "set",
+        "mask8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask8x32",
+        "set",
+        "mask8x32",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "set_mask8x64",
+        "set",
+        "mask8x64",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[]),
+        ],
+    ),
+    op(
+        "shift_elements_left_f32x16",
+        "shift_elements_left",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32", "vextq_u8"]),
+            split(&["f32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_ps",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_ps",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_f32x4",
+        "shift_elements_left",
+        "f32x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32", "vextq_u8"]),
+            split(&["f32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_ps",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_f32x8",
+        "shift_elements_left",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32", "vextq_u8"]),
+            split(&["f32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_ps",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_f64x2",
+        "shift_elements_left",
+        "f64x2",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64", "vextq_u8"]),
+            split(&["f64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_pd",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_f64x4",
+        "shift_elements_left",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64", "vextq_u8"]),
+            split(&["f64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_pd",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_f64x8",
+        "shift_elements_left",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64", "vextq_u8"]),
+            split(&["f64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_pd",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_pd",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i16x16",
+        "shift_elements_left",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16", "vextq_u8"]),
+            split(&["i16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i16x32",
+        "shift_elements_left",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16", "vextq_u8"]),
+            split(&["i16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi16",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i16x8",
+        "shift_elements_left",
+        "i16x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16", "vextq_u8"]),
+            split(&["i16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i32x16",
+        "shift_elements_left",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32", "vextq_u8"]),
+            split(&["i32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi32",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i32x4",
+        "shift_elements_left",
+        "i32x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32", "vextq_u8"]),
+            split(&["i32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i32x8",
+        "shift_elements_left",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32", "vextq_u8"]),
+            split(&["i32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i64x2",
+        "shift_elements_left",
+        "i64x2",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64", "vextq_u8"]),
+            split(&["i64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i64x4",
+        "shift_elements_left",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64", "vextq_u8"]),
+            split(&["i64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i64x8",
+        "shift_elements_left",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64", "vextq_u8"]),
+            split(&["i64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi64",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i8x16",
+        "shift_elements_left",
+        "i8x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8", "vextq_u8"]),
+            split(&["i8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i8x32",
+        "shift_elements_left",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8", "vextq_u8"]),
+            split(&["i8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_i8x64",
+        "shift_elements_left",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8", "vextq_u8"]),
+            split(&["i8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi8",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u16x16",
+        "shift_elements_left",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16", "vextq_u8"]),
+            split(&["u16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u16x32",
+        "shift_elements_left",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16", "vextq_u8"]),
+            split(&["u16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi16",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u16x8",
+        "shift_elements_left",
+        "u16x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16", "vextq_u8"]),
+            split(&["u16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u32x16",
+        "shift_elements_left",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vextq_u8"]),
+            split(&["u32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi32",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u32x4",
+        "shift_elements_left",
+        "u32x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vextq_u8"]),
+            split(&["u32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u32x8",
+        "shift_elements_left",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vextq_u8"]),
+            split(&["u32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u64x2",
+        "shift_elements_left",
+        "u64x2",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vextq_u8"]),
+            split(&["u64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u64x4",
+        "shift_elements_left",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vextq_u8"]),
+            split(&["u64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u64x8",
+        "shift_elements_left",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vextq_u8"]),
+            split(&["u64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi64",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u8x16",
+        "shift_elements_left",
+        "u8x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8", "vextq_u8"]),
+            split(&["u8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u8x32",
+        "shift_elements_left",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8", "vextq_u8"]),
+            split(&["u8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_left_u8x64",
+        "shift_elements_left",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8", "vextq_u8"]),
+            split(&["u8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi8",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_f32x16",
+        "shift_elements_right",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32", "vextq_u8"]),
+            split(&["f32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_ps",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_ps",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_f32x4",
+        "shift_elements_right",
+        "f32x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32", "vextq_u8"]),
+            split(&["f32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_ps",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_f32x8",
+        "shift_elements_right",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32", "vextq_u8"]),
+            split(&["f32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_ps",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_ps", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_ps",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_f64x2",
+        "shift_elements_right",
+        "f64x2",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64", "vextq_u8"]),
+            split(&["f64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_pd",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_f64x4",
+        "shift_elements_right",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64", "vextq_u8"]),
+            split(&["f64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_pd",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_f64x8",
+        "shift_elements_right",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64", "vextq_u8"]),
+            split(&["f64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_pd",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_pd", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_pd",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_pd",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i16x16",
+        "shift_elements_right",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16", "vextq_u8"]),
+            split(&["i16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i16x32",
+        "shift_elements_right",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16", "vextq_u8"]),
+            split(&["i16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi16",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i16x8",
+        "shift_elements_right",
+        "i16x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16", "vextq_u8"]),
+            split(&["i16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i32x16",
+        "shift_elements_right",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32", "vextq_u8"]),
+            split(&["i32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi32",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i32x4",
+        "shift_elements_right",
+        "i32x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32", "vextq_u8"]),
+            split(&["i32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i32x8",
+        "shift_elements_right",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32", "vextq_u8"]),
+            split(&["i32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i64x2",
+        "shift_elements_right",
+        "i64x2",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64", "vextq_u8"]),
+            split(&["i64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i64x4",
+        "shift_elements_right",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64", "vextq_u8"]),
+            split(&["i64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i64x8",
+        "shift_elements_right",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64", "vextq_u8"]),
+            split(&["i64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi64",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i8x16",
+        "shift_elements_right",
+        "i8x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8", "vextq_u8"]),
+            split(&["i8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i8x32",
+        "shift_elements_right",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8", "vextq_u8"]),
+            split(&["i8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_i8x64",
+        "shift_elements_right",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8", "vextq_u8"]),
+            split(&["i8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi8",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u16x16",
+        "shift_elements_right",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16", "vextq_u8"]),
+            split(&["u16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u16x32",
+        "shift_elements_right",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16", "vextq_u8"]),
+            split(&["u16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi16",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u16x8",
+        "shift_elements_right",
+        "u16x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16", "vextq_u8"]),
+            split(&["u16x8_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi16",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi16", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u32x16",
+        "shift_elements_right",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vextq_u8"]),
+            split(&["u32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi32",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u32x4",
+        "shift_elements_right",
+        "u32x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vextq_u8"]),
+            split(&["u32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u32x8",
+        "shift_elements_right",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32", "vextq_u8"]),
+            split(&["u32x4_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi32",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi32", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u64x2",
+        "shift_elements_right",
+        "u64x2",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vextq_u8"]),
+            split(&["u64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u64x4",
+        "shift_elements_right",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vextq_u8"]),
+            split(&["u64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u64x8",
+        "shift_elements_right",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64", "vextq_u8"]),
+            split(&["u64x2_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi64x",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi64x", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi64x",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi64",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u8x16",
+        "shift_elements_right",
+        "u8x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8", "vextq_u8"]),
+            split(&["u8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u8x32",
+        "shift_elements_right",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8", "vextq_u8"]),
+            split(&["u8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_elements_right_u8x64",
+        "shift_elements_right",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8", "vextq_u8"]),
+            split(&["u8x16_splat", "i8x16_shuffle"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_srli_si128",
+                "_mm_slli_si128",
+                "_mm_or_si128",
+            ]),
+            split(&["_mm_set1_epi8", "_mm_alignr_epi8"]),
+            split(&[
+                "_mm256_set1_epi8",
+                "_mm256_permute2x128_si256",
+                "_mm256_alignr_epi8",
+            ]),
+            split(&[
+                "_mm512_set1_epi8",
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i16x16",
+        "shift_signed",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_sllv_epi16",
+                "_mm256_srav_epi16",
+                "_mm256_sub_epi16",
+                "_mm256_setzero_si256",
+                "_mm256_mask_blend_epi16",
+                "_mm256_cmplt_epi16_mask",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i16x32",
+        "shift_signed",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_sllv_epi16",
+                "_mm512_srav_epi16",
+                "_mm512_sub_epi16",
+                "_mm512_setzero_si512",
+                "_mm512_mask_blend_epi16",
+                "_mm512_cmplt_epi16_mask",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i16x8",
+        "shift_signed",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_s16"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_sllv_epi16",
+                "_mm_srav_epi16",
+                "_mm_sub_epi16",
+                "_mm_setzero_si128",
+                "_mm_mask_blend_epi16",
+                "_mm_cmplt_epi16_mask",
+                "_mm_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i32x16",
+        "shift_signed",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[
+                "_mm256_sllv_epi32",
+                "_mm256_srav_epi32",
+                "_mm256_sub_epi32",
+                "_mm256_setzero_si256",
+                "_mm256_blendv_epi8",
+                "_mm256_cmpgt_epi32",
+                "_mm256_set1_epi32",
+            ]),
+            native(&[
+                "_mm512_sllv_epi32",
+                "_mm512_srav_epi32",
+                "_mm512_sub_epi32",
+                "_mm512_setzero_si512",
+                "_mm512_mask_blend_epi32",
+                "_mm512_cmplt_epi32_mask",
+                "_mm512_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i32x4",
+        "shift_signed",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_s32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_sllv_epi32",
+                "_mm_srav_epi32",
+                "_mm_sub_epi32",
+                "_mm_setzero_si128",
+                "_mm_blendv_epi8",
+                "_mm_cmpgt_epi32",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm_sllv_epi32",
+                "_mm_srav_epi32",
+                "_mm_sub_epi32",
+                "_mm_setzero_si128",
+                "_mm_mask_blend_epi32",
+                "_mm_cmplt_epi32_mask",
+                "_mm_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i32x8",
+        "shift_signed",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm256_sllv_epi32",
+                "_mm256_srav_epi32",
+                "_mm256_sub_epi32",
+                "_mm256_setzero_si256",
+                "_mm256_blendv_epi8",
+                "_mm256_cmpgt_epi32",
+                "_mm256_set1_epi32",
+            ]),
+            native(&[
+                "_mm256_sllv_epi32",
+                "_mm256_srav_epi32",
+                "_mm256_sub_epi32",
+                "_mm256_setzero_si256",
+                "_mm256_mask_blend_epi32",
+                "_mm256_cmplt_epi32_mask",
+                "_mm256_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i64x2",
+        "shift_signed",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_s64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_sllv_epi64",
+                "_mm_srav_epi64",
+                "_mm_sub_epi64",
+                "_mm_setzero_si128",
+                "_mm_mask_blend_epi64",
+                "_mm_cmplt_epi64_mask",
+                "_mm_set1_epi64x",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i64x4",
+        "shift_signed",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_sllv_epi64",
+                "_mm256_srav_epi64",
+                "_mm256_sub_epi64",
+                "_mm256_setzero_si256",
+                "_mm256_mask_blend_epi64",
+                "_mm256_cmplt_epi64_mask",
+                "_mm256_set1_epi64x",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i64x8",
+        "shift_signed",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_sllv_epi64",
+                "_mm512_srav_epi64",
+                "_mm512_sub_epi64",
+                "_mm512_setzero_si512",
+                "_mm512_mask_blend_epi64",
+                "_mm512_cmplt_epi64_mask",
+                "_mm512_set1_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i8x16",
+        "shift_signed",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_s8"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+                "_mm_set1_epi16",
+                "_mm_and_si128",
+                "_mm_sllv_epi16",
+                "_mm_packus_epi16",
+                "_mm_cmpgt_epi8",
+                "_mm_srav_epi16",
+                "_mm_sub_epi8",
+                "_mm_mask_blend_epi8",
+                "_mm_cmplt_epi8_mask",
+                "_mm_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i8x32",
+        "shift_signed",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_setzero_si256",
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_sllv_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_cmpgt_epi8",
+                "_mm256_srav_epi16",
+                "_mm256_sub_epi8",
+                "_mm256_mask_blend_epi8",
+                "_mm256_cmplt_epi8_mask",
+                "_mm256_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_i8x64",
+        "shift_signed",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_setzero_si512",
+                "_mm512_unpacklo_epi8",
+                "_mm512_unpackhi_epi8",
+                "_mm512_set1_epi16",
+                "_mm512_and_si512",
+                "_mm512_sllv_epi16",
+                "_mm512_packus_epi16",
+                "_mm512_movm_epi8",
+                "_mm512_cmpgt_epi8_mask",
+                "_mm512_srav_epi16",
+                "_mm512_sub_epi8",
+                "_mm512_mask_blend_epi8",
+                "_mm512_cmplt_epi8_mask",
+                "_mm512_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u16x16",
+        "shift_signed",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_sllv_epi16",
+                "_mm256_srlv_epi16",
+                "_mm256_sub_epi16",
+                "_mm256_setzero_si256",
+                "_mm256_mask_blend_epi16",
+                "_mm256_cmplt_epi16_mask",
+                "_mm256_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u16x32",
+        "shift_signed",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_sllv_epi16",
+                "_mm512_srlv_epi16",
+                "_mm512_sub_epi16",
+                "_mm512_setzero_si512",
+                "_mm512_mask_blend_epi16",
+                "_mm512_cmplt_epi16_mask",
+                "_mm512_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u16x8",
+        "shift_signed",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_u16"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_sllv_epi16",
+                "_mm_srlv_epi16",
+                "_mm_sub_epi16",
+                "_mm_setzero_si128",
+                "_mm_mask_blend_epi16",
+                "_mm_cmplt_epi16_mask",
+                "_mm_set1_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u32x16",
+        "shift_signed",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[
+                "_mm256_sllv_epi32",
+                "_mm256_srlv_epi32",
+                "_mm256_sub_epi32",
+                "_mm256_setzero_si256",
+                "_mm256_blendv_epi8",
+                "_mm256_cmpgt_epi32",
+                "_mm256_set1_epi32",
+            ]),
+            native(&[
+                "_mm512_sllv_epi32",
+                "_mm512_srlv_epi32",
+                "_mm512_sub_epi32",
+                "_mm512_setzero_si512",
+                "_mm512_mask_blend_epi32",
+                "_mm512_cmplt_epi32_mask",
+                "_mm512_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u32x4",
+        "shift_signed",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_u32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_sllv_epi32",
+                "_mm_srlv_epi32",
+                "_mm_sub_epi32",
+                "_mm_setzero_si128",
+                "_mm_blendv_epi8",
+                "_mm_cmpgt_epi32",
+                "_mm_set1_epi32",
+            ]),
+            native(&[
+                "_mm_sllv_epi32",
+                "_mm_srlv_epi32",
+                "_mm_sub_epi32",
+                "_mm_setzero_si128",
+                "_mm_mask_blend_epi32",
+                "_mm_cmplt_epi32_mask",
+                "_mm_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u32x8",
+        "shift_signed",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm256_sllv_epi32",
+                "_mm256_srlv_epi32",
+                "_mm256_sub_epi32",
+                "_mm256_setzero_si256",
+                "_mm256_blendv_epi8",
+                "_mm256_cmpgt_epi32",
+                "_mm256_set1_epi32",
+            ]),
+            native(&[
+                "_mm256_sllv_epi32",
+                "_mm256_srlv_epi32",
+                "_mm256_sub_epi32",
+                "_mm256_setzero_si256",
+                "_mm256_mask_blend_epi32",
+                "_mm256_cmplt_epi32_mask",
+                "_mm256_set1_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u64x2",
+        "shift_signed",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_sllv_epi64",
+                "_mm_srlv_epi64",
+                "_mm_sub_epi64",
+                "_mm_setzero_si128",
+                "_mm_blendv_epi8",
+                "_mm_set1_epi64x",
+            ]),
+            native(&[
+                "_mm_sllv_epi64",
+                "_mm_srlv_epi64",
+                "_mm_sub_epi64",
+                "_mm_setzero_si128",
+                "_mm_mask_blend_epi64",
+                "_mm_cmplt_epi64_mask",
+                "_mm_set1_epi64x",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u64x4",
+        "shift_signed",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm256_sllv_epi64",
+                "_mm256_srlv_epi64",
+                "_mm256_sub_epi64",
+                "_mm256_setzero_si256",
+                "_mm256_blendv_epi8",
+                "_mm256_set1_epi64x",
+            ]),
+            native(&[
+                "_mm256_sllv_epi64",
+                "_mm256_srlv_epi64",
+                "_mm256_sub_epi64",
+                "_mm256_setzero_si256",
+                "_mm256_mask_blend_epi64",
+                "_mm256_cmplt_epi64_mask",
+                "_mm256_set1_epi64x",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u64x8",
+        "shift_signed",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[
+                "_mm256_sllv_epi64",
+                "_mm256_srlv_epi64",
+                "_mm256_sub_epi64",
+                "_mm256_setzero_si256",
+                "_mm256_blendv_epi8",
+                "_mm256_set1_epi64x",
+            ]),
+            native(&[
+                "_mm512_sllv_epi64",
+                "_mm512_srlv_epi64",
+                "_mm512_sub_epi64",
+                "_mm512_setzero_si512",
+                "_mm512_mask_blend_epi64",
+                "_mm512_cmplt_epi64_mask",
+                "_mm512_set1_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u8x16",
+        "shift_signed",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_u8"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+                "_mm_set1_epi16",
+                "_mm_and_si128",
+                "_mm_sllv_epi16",
+                "_mm_packus_epi16",
+                "_mm_srlv_epi16",
+                "_mm_sub_epi8",
+                "_mm_mask_blend_epi8",
+                "_mm_cmplt_epi8_mask",
+                "_mm_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u8x32",
+        "shift_signed",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_setzero_si256",
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_sllv_epi16",
+                "_mm256_packus_epi16",
+                "_mm256_srlv_epi16",
+                "_mm256_sub_epi8",
+                "_mm256_mask_blend_epi8",
+                "_mm256_cmplt_epi8_mask",
+                "_mm256_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shift_signed_u8x64",
+        "shift_signed",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_setzero_si512",
+                "_mm512_unpacklo_epi8",
+                "_mm512_unpackhi_epi8",
+                "_mm512_set1_epi16",
+                "_mm512_and_si512",
+                "_mm512_sllv_epi16",
+                "_mm512_packus_epi16",
+                "_mm512_srlv_epi16",
+                "_mm512_sub_epi8",
+                "_mm512_mask_blend_epi8",
+                "_mm512_cmplt_epi8_mask",
+                "_mm512_set1_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "shl_i16x16",
+        "shl",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s16", "vdupq_n_s16"]),
+            split(&["i16x8_shl"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i16x32",
+        "shl",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s16", "vdupq_n_s16"]),
+            split(&["i16x8_shl"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sll_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i16x8",
+        "shl",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_s16", "vdupq_n_s16"]),
+            native(&["i16x8_shl"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i32x16",
+        "shl",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s32", "vdupq_n_s32"]),
+            split(&["i32x4_shl"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sll_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i32x4",
+        "shl",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_s32", "vdupq_n_s32"]),
+            native(&["i32x4_shl"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i32x8",
+        "shl",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s32", "vdupq_n_s32"]),
+            split(&["i32x4_shl"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i64x2",
+        "shl",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_s64", "vdupq_n_s64"]),
+            native(&["i64x2_shl"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i64x4",
+        "shl",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_s64", "vdupq_n_s64"]),
+            split(&["i64x2_shl"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i64x8",
+        "shl",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s64", "vdupq_n_s64"]),
+            split(&["i64x2_shl"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sll_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_i8x16",
+        "shl",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_s8", "vdupq_n_s8"]),
+            native(&["i8x16_shl"]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shl_i8x32",
+        "shl",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s8", "vdupq_n_s8"]),
+            split(&["i8x16_shl"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sll_epi16",
+                "_mm256_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sll_epi16",
+                "_mm256_packs_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shl_i8x64",
+        "shl",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_s8", "vdupq_n_s8"]),
+            split(&["i8x16_shl"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packs_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sll_epi16",
+                "_mm256_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm512_unpacklo_epi8",
+                "_mm512_movm_epi8",
+                "_mm512_cmpgt_epi8_mask",
+                "_mm512_setzero_si512",
+                "_mm512_unpackhi_epi8",
+                "_mm512_sll_epi16",
+                "_mm512_packs_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shl_u16x16",
+        "shl",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vdupq_n_s16"]),
+            split(&["u16x8_shl"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u16x32",
+        "shl",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vdupq_n_s16"]),
+            split(&["u16x8_shl"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sll_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u16x8",
+        "shl",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_u16", "vdupq_n_s16"]),
+            native(&["u16x8_shl"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u32x16",
+        "shl",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vdupq_n_s32"]),
+            split(&["u32x4_shl"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sll_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u32x4",
+        "shl",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_u32", "vdupq_n_s32"]),
+            native(&["u32x4_shl"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u32x8",
+        "shl",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vdupq_n_s32"]),
+            split(&["u32x4_shl"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u64x2",
+        "shl",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_u64", "vdupq_n_s64"]),
+            native(&["u64x2_shl"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u64x4",
+        "shl",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vdupq_n_s64"]),
+            split(&["u64x2_shl"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sll_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u64x8",
+        "shl",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vdupq_n_s64"]),
+            split(&["u64x2_shl"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sll_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sll_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sll_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shl_u8x16",
+        "shl",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_u8", "vdupq_n_s8"]),
+            native(&["u8x16_shl"]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shl_u8x32",
+        "shl",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vdupq_n_s8"]),
+            split(&["u8x16_shl"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sll_epi16",
+                "_mm256_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sll_epi16",
+                "_mm256_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shl_u8x64",
+        "shl",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vdupq_n_s8"]),
+            split(&["u8x16_shl"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sll_epi16",
+                "_mm_packus_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sll_epi16",
+                "_mm256_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm512_unpacklo_epi8",
+                "_mm512_setzero_si512",
+                "_mm512_unpackhi_epi8",
+                "_mm512_sll_epi16",
+                "_mm512_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shlv_i16x16",
+        "shlv",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_sllv_epi16"]),
+        ],
+    ),
+    op(
+        "shlv_i16x32",
+        "shlv",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_sllv_epi16"]),
+        ],
+    ),
+    op(
+        "shlv_i16x8",
+        "shlv",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_s16"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sllv_epi16"]),
+        ],
+    ),
+    op(
+        "shlv_i32x16",
+        "shlv",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_sllv_epi32"]),
+            native(&["_mm512_sllv_epi32"]),
+        ],
+    ),
+    op(
+        "shlv_i32x4",
+        "shlv",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_s32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sllv_epi32"]),
+            native(&["_mm_sllv_epi32"]),
+        ],
+    ),
+    op(
+        "shlv_i32x8",
+        "shlv",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_sllv_epi32"]),
+            native(&["_mm256_sllv_epi32"]),
+        ],
+    ),
+    op(
+        "shlv_i64x2",
+        "shlv",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_s64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sllv_epi64"]),
+            native(&["_mm_sllv_epi64"]),
+        ],
+    ),
+    op(
+        "shlv_i64x4",
+        "shlv",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_sllv_epi64"]),
+            native(&["_mm256_sllv_epi64"]),
+        ],
+    ),
+    op(
+        "shlv_i64x8",
+        "shlv",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_sllv_epi64"]),
+            native(&["_mm512_sllv_epi64"]),
+        ],
+    ),
+    op(
+        "shlv_i8x16",
+        "shlv",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_s8"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+                "_mm_set1_epi16",
+                "_mm_and_si128",
+                "_mm_sllv_epi16",
+                "_mm_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shlv_i8x32",
+        "shlv",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_setzero_si256",
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_sllv_epi16",
+                "_mm256_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shlv_i8x64",
+        "shlv",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_setzero_si512",
+                "_mm512_unpacklo_epi8",
+                "_mm512_unpackhi_epi8",
+                "_mm512_set1_epi16",
+                "_mm512_and_si512",
+                "_mm512_sllv_epi16",
+                "_mm512_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shlv_u16x16",
+        "shlv",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vreinterpretq_s16_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_sllv_epi16"]),
+        ],
+    ),
+    op(
+        "shlv_u16x32",
+        "shlv",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vreinterpretq_s16_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_sllv_epi16"]),
+        ],
+    ),
+    op(
+        "shlv_u16x8",
+        "shlv",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_u16", "vreinterpretq_s16_u16"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sllv_epi16"]),
+        ],
+    ),
+    op(
+        "shlv_u32x16",
+        "shlv",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vreinterpretq_s32_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_sllv_epi32"]),
+            native(&["_mm512_sllv_epi32"]),
+        ],
+    ),
+    op(
+        "shlv_u32x4",
+        "shlv",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_u32", "vreinterpretq_s32_u32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sllv_epi32"]),
+            native(&["_mm_sllv_epi32"]),
+        ],
+    ),
+    op(
+        "shlv_u32x8",
+        "shlv",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vreinterpretq_s32_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_sllv_epi32"]),
+            native(&["_mm256_sllv_epi32"]),
+        ],
+    ),
+    op(
+        "shlv_u64x2",
+        "shlv",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_u64", "vreinterpretq_s64_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sllv_epi64"]),
+            native(&["_mm_sllv_epi64"]),
+        ],
+    ),
+    op(
+        "shlv_u64x4",
+        "shlv",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vreinterpretq_s64_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_sllv_epi64"]),
+            native(&["_mm256_sllv_epi64"]),
+        ],
+    ),
+    op(
+        "shlv_u64x8",
+        "shlv",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vreinterpretq_s64_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_sllv_epi64"]),
+            native(&["_mm512_sllv_epi64"]),
+        ],
+    ),
+    op(
+        "shlv_u8x16",
+        "shlv",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_u8", "vreinterpretq_s8_u8"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+                "_mm_set1_epi16",
+                "_mm_and_si128",
+                "_mm_sllv_epi16",
+                "_mm_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shlv_u8x32",
+        "shlv",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vreinterpretq_s8_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_setzero_si256",
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_sllv_epi16",
+                "_mm256_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shlv_u8x64",
+        "shlv",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vreinterpretq_s8_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_setzero_si512",
+                "_mm512_unpacklo_epi8",
+                "_mm512_unpackhi_epi8",
+                "_mm512_set1_epi16",
+                "_mm512_and_si512",
+                "_mm512_sllv_epi16",
+                "_mm512_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shr_i16x16",
+        "shr",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s16", "vdupq_n_s16"]),
+            split(&["i16x8_shr"]),
+            split(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sra_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sra_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i16x32",
+        "shr",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s16", "vdupq_n_s16"]),
+            split(&["i16x8_shr"]),
+            split(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sra_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sra_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i16x8",
+        "shr",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_s16", "vdupq_n_s16"]),
+            native(&["i16x8_shr"]),
+            native(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sra_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i32x16",
+        "shr",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s32", "vdupq_n_s32"]),
+            split(&["i32x4_shr"]),
+            split(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_sra_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_sra_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i32x4",
+        "shr",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_s32", "vdupq_n_s32"]),
+            native(&["i32x4_shr"]),
+            native(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i32x8",
+        "shr",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s32", "vdupq_n_s32"]),
+            split(&["i32x4_shr"]),
+            split(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_sra_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sra_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_sra_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i64x2",
+        "shr",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_s64", "vdupq_n_s64"]),
+            native(&["i64x2_shr"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_sra_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i64x4",
+        "shr",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_s64", "vdupq_n_s64"]),
+            split(&["i64x2_shr"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_sra_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i64x8",
+        "shr",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s64", "vdupq_n_s64"]),
+            split(&["i64x2_shr"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_sra_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_i8x16",
+        "shr",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_s8", "vdupq_n_s8"]),
+            native(&["i8x16_shr"]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shr_i8x32",
+        "shr",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s8", "vdupq_n_s8"]),
+            split(&["i8x16_shr"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sra_epi16",
+                "_mm256_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sra_epi16",
+                "_mm256_packs_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shr_i8x64",
+        "shr",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_s8", "vdupq_n_s8"]),
+            split(&["i8x16_shr"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_sra_epi16",
+                "_mm_packs_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_cmpgt_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_sra_epi16",
+                "_mm256_packs_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm512_unpacklo_epi8",
+                "_mm512_movm_epi8",
+                "_mm512_cmpgt_epi8_mask",
+                "_mm512_setzero_si512",
+                "_mm512_unpackhi_epi8",
+                "_mm512_sra_epi16",
+                "_mm512_packs_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shr_u16x16",
+        "shr",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vdupq_n_s16"]),
+            split(&["u16x8_shr"]),
+            split(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_srl_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_srl_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u16x32",
+        "shr",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vdupq_n_s16"]),
+            split(&["u16x8_shr"]),
+            split(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_srl_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_srl_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u16x8",
+        "shr",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_u16", "vdupq_n_s16"]),
+            native(&["u16x8_shr"]),
+            native(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi16", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u32x16",
+        "shr",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vdupq_n_s32"]),
+            split(&["u32x4_shr"]),
+            split(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_srl_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_srl_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u32x4",
+        "shr",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_u32", "vdupq_n_s32"]),
+            native(&["u32x4_shr"]),
+            native(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u32x8",
+        "shr",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vdupq_n_s32"]),
+            split(&["u32x4_shr"]),
+            split(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            split(&["_mm_srl_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_srl_epi32", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_srl_epi32", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u64x2",
+        "shr",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_u64", "vdupq_n_s64"]),
+            native(&["u64x2_shr"]),
+            native(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u64x4",
+        "shr",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vdupq_n_s64"]),
+            split(&["u64x2_shr"]),
+            split(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_srl_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm256_srl_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u64x8",
+        "shr",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vdupq_n_s64"]),
+            split(&["u64x2_shr"]),
+            split(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm_srl_epi64", "_mm_cvtsi32_si128"]),
+            split(&["_mm256_srl_epi64", "_mm_cvtsi32_si128"]),
+            native(&["_mm512_srl_epi64", "_mm_cvtsi32_si128"]),
+        ],
+    ),
+    op(
+        "shr_u8x16",
+        "shr",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_u8", "vdupq_n_s8"]),
+            native(&["u8x16_shr"]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shr_u8x32",
+        "shr",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vdupq_n_s8"]),
+            split(&["u8x16_shr"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shr_u8x64",
+        "shr",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vdupq_n_s8"]),
+            split(&["u8x16_shr"]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpackhi_epi8",
+                "_mm_srl_epi16",
+                "_mm_packus_epi16",
+            ]),
+            split(&[
+                "_mm_cvtsi32_si128",
+                "_mm256_unpacklo_epi8",
+                "_mm256_setzero_si256",
+                "_mm256_unpackhi_epi8",
+                "_mm256_srl_epi16",
+                "_mm256_packus_epi16",
+            ]),
+            native(&[
+                "_mm_cvtsi32_si128",
+                "_mm512_unpacklo_epi8",
+                "_mm512_setzero_si512",
+                "_mm512_unpackhi_epi8",
+                "_mm512_srl_epi16",
+                "_mm512_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shrv_i16x16",
+        "shrv",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s16", "vnegq_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_srav_epi16"]),
+        ],
+    ),
+    op(
+        "shrv_i16x32",
+        "shrv",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s16", "vnegq_s16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_srav_epi16"]),
+        ],
+    ),
+    op(
+        "shrv_i16x8",
+        "shrv",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_s16", "vnegq_s16"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_srav_epi16"]),
+        ],
+    ),
+    op(
+        "shrv_i32x16",
+        "shrv",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_s32", "vnegq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_srav_epi32"]),
+            native(&["_mm512_srav_epi32"]),
+        ],
+    ),
+    op(
+        "shrv_i32x4",
+        "shrv",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_s32", "vnegq_s32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_srav_epi32"]),
+            native(&["_mm_srav_epi32"]),
+        ],
+    ),
+    op(
+        "shrv_i32x8",
+        "shrv",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s32", "vnegq_s32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_srav_epi32"]),
+            native(&["_mm256_srav_epi32"]),
+        ],
+    ),
+    op(
+        "shrv_i64x2",
+        "shrv",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_s64", "vnegq_s64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_srav_epi64"]),
+        ],
+    ),
+    op(
+        "shrv_i64x4",
+        "shrv",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_s64", "vnegq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_srav_epi64"]),
+        ],
+    ),
+    op(
+        "shrv_i64x8",
+        "shrv",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_s64", "vnegq_s64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_srav_epi64"]),
+        ],
+    ),
+    op(
+        "shrv_i8x16",
+        "shrv",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_s8", "vnegq_s8"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_cmpgt_epi8",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+                "_mm_set1_epi16",
+                "_mm_and_si128",
+                "_mm_srav_epi16",
+                "_mm_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shrv_i8x32",
+        "shrv",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_s8", "vnegq_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_setzero_si256",
+                "_mm256_cmpgt_epi8",
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_srav_epi16",
+                "_mm256_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shrv_i8x64",
+        "shrv",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_s8", "vnegq_s8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_setzero_si512",
+                "_mm512_movm_epi8",
+                "_mm512_cmpgt_epi8_mask",
+                "_mm512_unpacklo_epi8",
+                "_mm512_unpackhi_epi8",
+                "_mm512_set1_epi16",
+                "_mm512_and_si512",
+                "_mm512_srav_epi16",
+                "_mm512_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shrv_u16x16",
+        "shrv",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vnegq_s16", "vreinterpretq_s16_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_srlv_epi16"]),
+        ],
+    ),
+    op(
+        "shrv_u16x32",
+        "shrv",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u16", "vnegq_s16", "vreinterpretq_s16_u16"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_srlv_epi16"]),
+        ],
+    ),
+    op(
+        "shrv_u16x8",
+        "shrv",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vshlq_u16", "vnegq_s16", "vreinterpretq_s16_u16"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_srlv_epi16"]),
+        ],
+    ),
+    op(
+        "shrv_u32x16",
+        "shrv",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vnegq_s32", "vreinterpretq_s32_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_srlv_epi32"]),
+            native(&["_mm512_srlv_epi32"]),
+        ],
+    ),
+    op(
+        "shrv_u32x4",
+        "shrv",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vshlq_u32", "vnegq_s32", "vreinterpretq_s32_u32"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_srlv_epi32"]),
+            native(&["_mm_srlv_epi32"]),
+        ],
+    ),
+    op(
+        "shrv_u32x8",
+        "shrv",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u32", "vnegq_s32", "vreinterpretq_s32_u32"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_srlv_epi32"]),
+            native(&["_mm256_srlv_epi32"]),
+        ],
+    ),
+    op(
+        "shrv_u64x2",
+        "shrv",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vshlq_u64", "vnegq_s64", "vreinterpretq_s64_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_srlv_epi64"]),
+            native(&["_mm_srlv_epi64"]),
+        ],
+    ),
+    op(
+        "shrv_u64x4",
+        "shrv",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vnegq_s64", "vreinterpretq_s64_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm256_srlv_epi64"]),
+            native(&["_mm256_srlv_epi64"]),
+        ],
+    ),
+    op(
+        "shrv_u64x8",
+        "shrv",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vshlq_u64", "vnegq_s64", "vreinterpretq_s64_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&["_mm256_srlv_epi64"]),
+            native(&["_mm512_srlv_epi64"]),
+        ],
+    ),
+    op(
+        "shrv_u8x16",
+        "shrv",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vshlq_u8", "vnegq_s8", "vreinterpretq_s8_u8"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+                "_mm_set1_epi16",
+                "_mm_and_si128",
+                "_mm_srlv_epi16",
+                "_mm_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shrv_u8x32",
+        "shrv",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vnegq_s8", "vreinterpretq_s8_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&[
+                "_mm256_setzero_si256",
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_set1_epi16",
+                "_mm256_and_si256",
+                "_mm256_srlv_epi16",
+                "_mm256_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "shrv_u8x64",
+        "shrv",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vshlq_u8", "vnegq_s8", "vreinterpretq_s8_u8"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[
+                "_mm512_setzero_si512",
+                "_mm512_unpacklo_epi8",
+                "_mm512_unpackhi_epi8",
+                "_mm512_set1_epi16",
+                "_mm512_and_si512",
+                "_mm512_srlv_epi16",
+                "_mm512_packus_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "simd_eq_f32x16",
+        "simd_eq",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_f32"]),
+            split(&["f32x4_eq"]),
+            split(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            split(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm512_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_f32x4",
+        "simd_eq",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vceqq_f32"]),
+            native(&["f32x4_eq"]),
+            native(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            native(&["_mm_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_f32x8",
+        "simd_eq",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_f32"]),
+            split(&["f32x4_eq"]),
+            split(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmpeq_ps"]),
+            native(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm256_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_f64x2",
+        "simd_eq",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vceqq_f64"]),
+            native(&["f64x2_eq"]),
+            native(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            native(&["_mm_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_f64x4",
+        "simd_eq",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_f64"]),
+            split(&["f64x2_eq"]),
+            split(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            native(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm256_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_f64x8",
+        "simd_eq",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_f64"]),
+            split(&["f64x2_eq"]),
+            split(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmpeq_pd"]),
+            split(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm512_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i16x16",
+        "simd_eq",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vceqq_s16"]),
+            split(&["i16x8_eq"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm_cmpeq_epi16"]),
+            native(&["_mm256_cmpeq_epi16"]),
+            native(&["_mm256_cmpeq_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i16x32",
+        "simd_eq",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vceqq_s16"]),
+            split(&["i16x8_eq"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm256_cmpeq_epi16"]),
+            native(&["_mm512_cmpeq_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i16x8",
+        "simd_eq",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vceqq_s16"]),
+            native(&["i16x8_eq"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i32x16",
+        "simd_eq",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_s32"]),
+            split(&["i32x4_eq"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm256_cmpeq_epi32"]),
+            native(&["_mm512_cmpeq_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i32x4",
+        "simd_eq",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vceqq_s32"]),
+            native(&["i32x4_eq"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i32x8",
+        "simd_eq",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_s32"]),
+            split(&["i32x4_eq"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm_cmpeq_epi32"]),
+            native(&["_mm256_cmpeq_epi32"]),
+            native(&["_mm256_cmpeq_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i64x2",
+        "simd_eq",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vceqq_s64"]),
+            native(&["i64x2_eq"]),
+            native(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            native(&["_mm_cmpeq_epi64"]),
+            native(&["_mm_cmpeq_epi64"]),
+            native(&["_mm_cmpeq_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i64x4",
+        "simd_eq",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_s64"]),
+            split(&["i64x2_eq"]),
+            split(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            split(&["_mm_cmpeq_epi64"]),
+            native(&["_mm256_cmpeq_epi64"]),
+            native(&["_mm256_cmpeq_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i64x8",
+        "simd_eq",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_s64"]),
+            split(&["i64x2_eq"]),
+            split(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            split(&["_mm_cmpeq_epi64"]),
+            split(&["_mm256_cmpeq_epi64"]),
+            native(&["_mm512_cmpeq_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i8x16",
+        "simd_eq",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vceqq_s8"]),
+            native(&["i8x16_eq"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i8x32",
+        "simd_eq",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vceqq_s8"]),
+            split(&["i8x16_eq"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm_cmpeq_epi8"]),
+            native(&["_mm256_cmpeq_epi8"]),
+            native(&["_mm256_cmpeq_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_i8x64",
+        "simd_eq",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vceqq_s8"]),
+            split(&["i8x16_eq"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm256_cmpeq_epi8"]),
+            native(&["_mm512_cmpeq_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_mask16x16",
+        "simd_eq",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vceqq_s16"]),
+            split(&["i16x8_eq"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm_cmpeq_epi16"]),
+            native(&["_mm256_cmpeq_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask16x32",
+        "simd_eq",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vceqq_s16"]),
+            split(&["i16x8_eq"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm256_cmpeq_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask16x8",
+        "simd_eq",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vceqq_s16"]),
+            native(&["i16x8_eq"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask32x16",
+        "simd_eq",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_s32"]),
+            split(&["i32x4_eq"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm256_cmpeq_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask32x4",
+        "simd_eq",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vceqq_s32"]),
+            native(&["i32x4_eq"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask32x8",
+        "simd_eq",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_s32"]),
+            split(&["i32x4_eq"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm_cmpeq_epi32"]),
+            native(&["_mm256_cmpeq_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask64x2",
+        "simd_eq",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vceqq_s64"]),
+            native(&["i64x2_eq"]),
+            native(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            native(&["_mm_cmpeq_epi64"]),
+            native(&["_mm_cmpeq_epi64"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask64x4",
+        "simd_eq",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_s64"]),
+            split(&["i64x2_eq"]),
+            split(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            split(&["_mm_cmpeq_epi64"]),
+            native(&["_mm256_cmpeq_epi64"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask64x8",
+        "simd_eq",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_s64"]),
+            split(&["i64x2_eq"]),
+            split(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            split(&["_mm_cmpeq_epi64"]),
+            split(&["_mm256_cmpeq_epi64"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask8x16",
+        "simd_eq",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vceqq_s8"]),
+            native(&["i8x16_eq"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask8x32",
+        "simd_eq",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vceqq_s8"]),
+            split(&["i8x16_eq"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm_cmpeq_epi8"]),
+            native(&["_mm256_cmpeq_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_mask8x64",
+        "simd_eq",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vceqq_s8"]),
+            split(&["i8x16_eq"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm256_cmpeq_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "simd_eq_u16x16",
+        "simd_eq",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vceqq_u16"]),
+            split(&["u16x8_eq"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm_cmpeq_epi16"]),
+            native(&["_mm256_cmpeq_epi16"]),
+            native(&["_mm256_cmpeq_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u16x32",
+        "simd_eq",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vceqq_u16"]),
+            split(&["u16x8_eq"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm_cmpeq_epi16"]),
+            split(&["_mm256_cmpeq_epi16"]),
+            native(&["_mm512_cmpeq_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u16x8",
+        "simd_eq",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vceqq_u16"]),
+            native(&["u16x8_eq"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epi16"]),
+            native(&["_mm_cmpeq_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u32x16",
+        "simd_eq",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_u32"]),
+            split(&["u32x4_eq"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm256_cmpeq_epi32"]),
+            native(&["_mm512_cmpeq_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u32x4",
+        "simd_eq",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vceqq_u32"]),
+            native(&["u32x4_eq"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epi32"]),
+            native(&["_mm_cmpeq_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u32x8",
+        "simd_eq",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vceqq_u32"]),
+            split(&["u32x4_eq"]),
+            split(&["_mm_cmpeq_epi32"]),
+            split(&["_mm_cmpeq_epi32"]),
+            native(&["_mm256_cmpeq_epi32"]),
+            native(&["_mm256_cmpeq_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u64x2",
+        "simd_eq",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vceqq_u64"]),
+            PORTABLE,
+            native(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            native(&["_mm_cmpeq_epi64"]),
+            native(&["_mm_cmpeq_epi64"]),
+            native(&["_mm_cmpeq_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u64x4",
+        "simd_eq",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_u64"]),
+            split(&[]),
+            split(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            split(&["_mm_cmpeq_epi64"]),
+            native(&["_mm256_cmpeq_epi64"]),
+            native(&["_mm256_cmpeq_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u64x8",
+        "simd_eq",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vceqq_u64"]),
+            split(&[]),
+            split(&["_mm_cmpeq_epi32", "_mm_shuffle_epi32", "_mm_and_si128"]),
+            split(&["_mm_cmpeq_epi64"]),
+            split(&["_mm256_cmpeq_epi64"]),
+            native(&["_mm512_cmpeq_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u8x16",
+        "simd_eq",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vceqq_u8"]),
+            native(&["u8x16_eq"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epi8"]),
+            native(&["_mm_cmpeq_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u8x32",
+        "simd_eq",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vceqq_u8"]),
+            split(&["u8x16_eq"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm_cmpeq_epi8"]),
+            native(&["_mm256_cmpeq_epi8"]),
+            native(&["_mm256_cmpeq_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_eq_u8x64",
+        "simd_eq",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vceqq_u8"]),
+            split(&["u8x16_eq"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm_cmpeq_epi8"]),
+            split(&["_mm256_cmpeq_epi8"]),
+            native(&["_mm512_cmpeq_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_f32x16",
+        "simd_ge",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgeq_f32"]),
+            split(&["f32x4_ge"]),
+            split(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            split(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm512_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_f32x4",
+        "simd_ge",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcgeq_f32"]),
+            native(&["f32x4_ge"]),
+            native(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            native(&["_mm_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_f32x8",
+        "simd_ge",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgeq_f32"]),
+            split(&["f32x4_ge"]),
+            split(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmpge_ps"]),
+            native(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm256_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_f64x2",
+        "simd_ge",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcgeq_f64"]),
+            native(&["f64x2_ge"]),
+            native(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            native(&["_mm_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_f64x4",
+        "simd_ge",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgeq_f64"]),
+            split(&["f64x2_ge"]),
+            split(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            native(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm256_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_f64x8",
+        "simd_ge",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgeq_f64"]),
+            split(&["f64x2_ge"]),
+            split(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmpge_pd"]),
+            split(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm512_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i16x16",
+        "simd_ge",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgeq_s16"]),
+            split(&["i16x8_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpeq_epi16", "_mm_max_epi16"]),
+            native(&["_mm256_cmpeq_epi16", "_mm256_max_epi16"]),
+            native(&["_mm256_cmpge_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i16x32",
+        "simd_ge",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgeq_s16"]),
+            split(&["i16x8_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpeq_epi16", "_mm_max_epi16"]),
+            split(&["_mm256_cmpeq_epi16", "_mm256_max_epi16"]),
+            native(&["_mm512_cmpge_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i16x8",
+        "simd_ge",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcgeq_s16"]),
+            native(&["i16x8_ge"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpeq_epi16", "_mm_max_epi16"]),
+            native(&["_mm_cmpeq_epi16", "_mm_max_epi16"]),
+            native(&["_mm_cmpge_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i32x16",
+        "simd_ge",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgeq_s32"]),
+            split(&["i32x4_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpeq_epi32", "_mm_max_epi32"]),
+            split(&["_mm256_cmpeq_epi32", "_mm256_max_epi32"]),
+            native(&["_mm512_cmpge_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i32x4",
+        "simd_ge",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcgeq_s32"]),
+            native(&["i32x4_ge"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpeq_epi32", "_mm_max_epi32"]),
+            native(&["_mm_cmpeq_epi32", "_mm_max_epi32"]),
+            native(&["_mm_cmpge_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i32x8",
+        "simd_ge",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgeq_s32"]),
+            split(&["i32x4_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpeq_epi32", "_mm_max_epi32"]),
+            native(&["_mm256_cmpeq_epi32", "_mm256_max_epi32"]),
+            native(&["_mm256_cmpge_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i64x2",
+        "simd_ge",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcgeq_s64"]),
+            native(&["i64x2_ge"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmpge_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i64x4",
+        "simd_ge",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgeq_s64"]),
+            split(&["i64x2_ge"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmpge_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i64x8",
+        "simd_ge",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgeq_s64"]),
+            split(&["i64x2_ge"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmpge_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i8x16",
+        "simd_ge",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcgeq_s8"]),
+            native(&["i8x16_ge"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_max_epi8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_max_epi8"]),
+            native(&["_mm_cmpge_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i8x32",
+        "simd_ge",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgeq_s8"]),
+            split(&["i8x16_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_max_epi8"]),
+            native(&["_mm256_cmpeq_epi8", "_mm256_max_epi8"]),
+            native(&["_mm256_cmpge_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_i8x64",
+        "simd_ge",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgeq_s8"]),
+            split(&["i8x16_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_max_epi8"]),
+            split(&["_mm256_cmpeq_epi8", "_mm256_max_epi8"]),
+            native(&["_mm512_cmpge_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u16x16",
+        "simd_ge",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgeq_u16"]),
+            split(&["u16x8_ge"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_cmpgt_epi16",
+            ]),
+            split(&["_mm_cmpeq_epi16", "_mm_max_epu16"]),
+            native(&["_mm256_cmpeq_epi16", "_mm256_max_epu16"]),
+            native(&["_mm256_cmpge_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u16x32",
+        "simd_ge",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgeq_u16"]),
+            split(&["u16x8_ge"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_cmpgt_epi16",
+            ]),
+            split(&["_mm_cmpeq_epi16", "_mm_max_epu16"]),
+            split(&["_mm256_cmpeq_epi16", "_mm256_max_epu16"]),
+            native(&["_mm512_cmpge_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u16x8",
+        "simd_ge",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcgeq_u16"]),
+            native(&["u16x8_ge"]),
+            native(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_cmpgt_epi16",
+            ]),
+            native(&["_mm_cmpeq_epi16", "_mm_max_epu16"]),
+            native(&["_mm_cmpeq_epi16", "_mm_max_epu16"]),
+            native(&["_mm_cmpge_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u32x16",
+        "simd_ge",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgeq_u32"]),
+            split(&["u32x4_ge"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_cmpgt_epi32",
+            ]),
+            split(&["_mm_cmpeq_epi32", "_mm_max_epu32"]),
+            split(&["_mm256_cmpeq_epi32", "_mm256_max_epu32"]),
+            native(&["_mm512_cmpge_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u32x4",
+        "simd_ge",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcgeq_u32"]),
+            native(&["u32x4_ge"]),
+            native(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_cmpgt_epi32",
+            ]),
+            native(&["_mm_cmpeq_epi32", "_mm_max_epu32"]),
+            native(&["_mm_cmpeq_epi32", "_mm_max_epu32"]),
+            native(&["_mm_cmpge_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u32x8",
+        "simd_ge",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgeq_u32"]),
+            split(&["u32x4_ge"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_cmpgt_epi32",
+            ]),
+            split(&["_mm_cmpeq_epi32", "_mm_max_epu32"]),
+            native(&["_mm256_cmpeq_epi32", "_mm256_max_epu32"]),
+            native(&["_mm256_cmpge_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u64x2",
+        "simd_ge",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcgeq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmpge_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u64x4",
+        "simd_ge",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgeq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmpge_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u64x8",
+        "simd_ge",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgeq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmpge_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u8x16",
+        "simd_ge",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcgeq_u8"]),
+            native(&["u8x16_ge"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_max_epu8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_max_epu8"]),
+            native(&["_mm_cmpge_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u8x32",
+        "simd_ge",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgeq_u8"]),
+            split(&["u8x16_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_max_epu8"]),
+            native(&["_mm256_cmpeq_epi8", "_mm256_max_epu8"]),
+            native(&["_mm256_cmpge_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_ge_u8x64",
+        "simd_ge",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgeq_u8"]),
+            split(&["u8x16_ge"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_max_epu8"]),
+            split(&["_mm256_cmpeq_epi8", "_mm256_max_epu8"]),
+            native(&["_mm512_cmpge_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_f32x16",
+        "simd_gt",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgtq_f32"]),
+            split(&["f32x4_gt"]),
+            split(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            split(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm512_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_f32x4",
+        "simd_gt",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcgtq_f32"]),
+            native(&["f32x4_gt"]),
+            native(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            native(&["_mm_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_f32x8",
+        "simd_gt",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgtq_f32"]),
+            split(&["f32x4_gt"]),
+            split(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmpgt_ps"]),
+            native(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm256_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_f64x2",
+        "simd_gt",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcgtq_f64"]),
+            native(&["f64x2_gt"]),
+            native(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            native(&["_mm_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_f64x4",
+        "simd_gt",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgtq_f64"]),
+            split(&["f64x2_gt"]),
+            split(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            native(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm256_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_f64x8",
+        "simd_gt",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgtq_f64"]),
+            split(&["f64x2_gt"]),
+            split(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmpgt_pd"]),
+            split(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm512_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i16x16",
+        "simd_gt",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgtq_s16"]),
+            split(&["i16x8_gt"]),
+            split(&["_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpgt_epi16"]),
+            native(&["_mm256_cmpgt_epi16"]),
+            native(&["_mm256_cmpgt_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i16x32",
+        "simd_gt",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgtq_s16"]),
+            split(&["i16x8_gt"]),
+            split(&["_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpgt_epi16"]),
+            split(&["_mm256_cmpgt_epi16"]),
+            native(&["_mm512_cmpgt_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i16x8",
+        "simd_gt",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcgtq_s16"]),
+            native(&["i16x8_gt"]),
+            native(&["_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpgt_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i32x16",
+        "simd_gt",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgtq_s32"]),
+            split(&["i32x4_gt"]),
+            split(&["_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpgt_epi32"]),
+            split(&["_mm256_cmpgt_epi32"]),
+            native(&["_mm512_cmpgt_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i32x4",
+        "simd_gt",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcgtq_s32"]),
+            native(&["i32x4_gt"]),
+            native(&["_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpgt_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i32x8",
+        "simd_gt",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgtq_s32"]),
+            split(&["i32x4_gt"]),
+            split(&["_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpgt_epi32"]),
+            native(&["_mm256_cmpgt_epi32"]),
+            native(&["_mm256_cmpgt_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i64x2",
+        "simd_gt",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcgtq_s64"]),
+            native(&["i64x2_gt"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmpgt_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i64x4",
+        "simd_gt",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgtq_s64"]),
+            split(&["i64x2_gt"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmpgt_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i64x8",
+        "simd_gt",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgtq_s64"]),
+            split(&["i64x2_gt"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmpgt_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i8x16",
+        "simd_gt",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcgtq_s8"]),
+            native(&["i8x16_gt"]),
+            native(&["_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpgt_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i8x32",
+        "simd_gt",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgtq_s8"]),
+            split(&["i8x16_gt"]),
+            split(&["_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpgt_epi8"]),
+            native(&["_mm256_cmpgt_epi8"]),
+            native(&["_mm256_cmpgt_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_i8x64",
+        "simd_gt",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgtq_s8"]),
+            split(&["i8x16_gt"]),
+            split(&["_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpgt_epi8"]),
+            split(&["_mm256_cmpgt_epi8"]),
+            native(&["_mm512_cmpgt_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u16x16",
+        "simd_gt",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgtq_u16"]),
+            split(&["u16x8_gt"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&[
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi16",
+            ]),
+            native(&["_mm256_cmpgt_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u16x32",
+        "simd_gt",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcgtq_u16"]),
+            split(&["u16x8_gt"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi16",
+            ]),
+            native(&["_mm512_cmpgt_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u16x8",
+        "simd_gt",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcgtq_u16"]),
+            native(&["u16x8_gt"]),
+            native(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpgt_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u32x16",
+        "simd_gt",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgtq_u32"]),
+            split(&["u32x4_gt"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi32",
+            ]),
+            native(&["_mm512_cmpgt_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u32x4",
+        "simd_gt",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcgtq_u32"]),
+            native(&["u32x4_gt"]),
+            native(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpgt_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u32x8",
+        "simd_gt",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcgtq_u32"]),
+            split(&["u32x4_gt"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&[
+                "_mm256_set1_epi32",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi32",
+            ]),
+            native(&["_mm256_cmpgt_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u64x2",
+        "simd_gt",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcgtq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmpgt_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u64x4",
+        "simd_gt",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgtq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmpgt_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u64x8",
+        "simd_gt",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcgtq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmpgt_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u8x16",
+        "simd_gt",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcgtq_u8"]),
+            native(&["u8x16_gt"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpgt_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u8x32",
+        "simd_gt",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgtq_u8"]),
+            split(&["u8x16_gt"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm256_set1_epi8", "_mm256_xor_si256", "_mm256_cmpgt_epi8"]),
+            native(&["_mm256_cmpgt_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_gt_u8x64",
+        "simd_gt",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcgtq_u8"]),
+            split(&["u8x16_gt"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm256_set1_epi8", "_mm256_xor_si256", "_mm256_cmpgt_epi8"]),
+            native(&["_mm512_cmpgt_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_f32x16",
+        "simd_le",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcleq_f32"]),
+            split(&["f32x4_le"]),
+            split(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            split(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm512_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_f32x4",
+        "simd_le",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcleq_f32"]),
+            native(&["f32x4_le"]),
+            native(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            native(&["_mm_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_f32x8",
+        "simd_le",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcleq_f32"]),
+            split(&["f32x4_le"]),
+            split(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmple_ps"]),
+            native(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm256_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_f64x2",
+        "simd_le",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcleq_f64"]),
+            native(&["f64x2_le"]),
+            native(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            native(&["_mm_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_f64x4",
+        "simd_le",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcleq_f64"]),
+            split(&["f64x2_le"]),
+            split(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            native(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm256_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_f64x8",
+        "simd_le",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcleq_f64"]),
+            split(&["f64x2_le"]),
+            split(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmple_pd"]),
+            split(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm512_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i16x16",
+        "simd_le",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcleq_s16"]),
+            split(&["i16x8_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpeq_epi16", "_mm_min_epi16"]),
+            native(&["_mm256_cmpeq_epi16", "_mm256_min_epi16"]),
+            native(&["_mm256_cmple_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i16x32",
+        "simd_le",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcleq_s16"]),
+            split(&["i16x8_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpeq_epi16", "_mm_min_epi16"]),
+            split(&["_mm256_cmpeq_epi16", "_mm256_min_epi16"]),
+            native(&["_mm512_cmple_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i16x8",
+        "simd_le",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcleq_s16"]),
+            native(&["i16x8_le"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpeq_epi16", "_mm_min_epi16"]),
+            native(&["_mm_cmpeq_epi16", "_mm_min_epi16"]),
+            native(&["_mm_cmple_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i32x16",
+        "simd_le",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcleq_s32"]),
+            split(&["i32x4_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpeq_epi32", "_mm_min_epi32"]),
+            split(&["_mm256_cmpeq_epi32", "_mm256_min_epi32"]),
+            native(&["_mm512_cmple_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i32x4",
+        "simd_le",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcleq_s32"]),
+            native(&["i32x4_le"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpeq_epi32", "_mm_min_epi32"]),
+            native(&["_mm_cmpeq_epi32", "_mm_min_epi32"]),
+            native(&["_mm_cmple_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i32x8",
+        "simd_le",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcleq_s32"]),
+            split(&["i32x4_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpeq_epi32", "_mm_min_epi32"]),
+            native(&["_mm256_cmpeq_epi32", "_mm256_min_epi32"]),
+            native(&["_mm256_cmple_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i64x2",
+        "simd_le",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcleq_s64"]),
+            native(&["i64x2_le"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmple_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i64x4",
+        "simd_le",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcleq_s64"]),
+            split(&["i64x2_le"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmple_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i64x8",
+        "simd_le",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcleq_s64"]),
+            split(&["i64x2_le"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmple_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i8x16",
+        "simd_le",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcleq_s8"]),
+            native(&["i8x16_le"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_min_epi8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_min_epi8"]),
+            native(&["_mm_cmple_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i8x32",
+        "simd_le",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcleq_s8"]),
+            split(&["i8x16_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_min_epi8"]),
+            native(&["_mm256_cmpeq_epi8", "_mm256_min_epi8"]),
+            native(&["_mm256_cmple_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_i8x64",
+        "simd_le",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcleq_s8"]),
+            split(&["i8x16_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_min_epi8"]),
+            split(&["_mm256_cmpeq_epi8", "_mm256_min_epi8"]),
+            native(&["_mm512_cmple_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u16x16",
+        "simd_le",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcleq_u16"]),
+            split(&["u16x8_le"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_cmpgt_epi16",
+            ]),
+            split(&["_mm_cmpeq_epi16", "_mm_min_epu16"]),
+            native(&["_mm256_cmpeq_epi16", "_mm256_min_epu16"]),
+            native(&["_mm256_cmple_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u16x32",
+        "simd_le",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcleq_u16"]),
+            split(&["u16x8_le"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_cmpgt_epi16",
+            ]),
+            split(&["_mm_cmpeq_epi16", "_mm_min_epu16"]),
+            split(&["_mm256_cmpeq_epi16", "_mm256_min_epu16"]),
+            native(&["_mm512_cmple_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u16x8",
+        "simd_le",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcleq_u16"]),
+            native(&["u16x8_le"]),
+            native(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_cmpgt_epi16",
+            ]),
+            native(&["_mm_cmpeq_epi16", "_mm_min_epu16"]),
+            native(&["_mm_cmpeq_epi16", "_mm_min_epu16"]),
+            native(&["_mm_cmple_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u32x16",
+        "simd_le",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcleq_u32"]),
+            split(&["u32x4_le"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_cmpgt_epi32",
+            ]),
+            split(&["_mm_cmpeq_epi32", "_mm_min_epu32"]),
+            split(&["_mm256_cmpeq_epi32", "_mm256_min_epu32"]),
+            native(&["_mm512_cmple_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u32x4",
+        "simd_le",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcleq_u32"]),
+            native(&["u32x4_le"]),
+            native(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_cmpgt_epi32",
+            ]),
+            native(&["_mm_cmpeq_epi32", "_mm_min_epu32"]),
+            native(&["_mm_cmpeq_epi32", "_mm_min_epu32"]),
+            native(&["_mm_cmple_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u32x8",
+        "simd_le",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcleq_u32"]),
+            split(&["u32x4_le"]),
+            split(&[
+                "_mm_set1_epi8",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_cmpgt_epi32",
+            ]),
+            split(&["_mm_cmpeq_epi32", "_mm_min_epu32"]),
+            native(&["_mm256_cmpeq_epi32", "_mm256_min_epu32"]),
+            native(&["_mm256_cmple_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u64x2",
+        "simd_le",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcleq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmple_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u64x4",
+        "simd_le",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcleq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmple_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u64x8",
+        "simd_le",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcleq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmple_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u8x16",
+        "simd_le",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcleq_u8"]),
+            native(&["u8x16_le"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_min_epu8"]),
+            native(&["_mm_cmpeq_epi8", "_mm_min_epu8"]),
+            native(&["_mm_cmple_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u8x32",
+        "simd_le",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcleq_u8"]),
+            split(&["u8x16_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_min_epu8"]),
+            native(&["_mm256_cmpeq_epi8", "_mm256_min_epu8"]),
+            native(&["_mm256_cmple_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_le_u8x64",
+        "simd_le",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcleq_u8"]),
+            split(&["u8x16_le"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpeq_epi8", "_mm_min_epu8"]),
+            split(&["_mm256_cmpeq_epi8", "_mm256_min_epu8"]),
+            native(&["_mm512_cmple_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_f32x16",
+        "simd_lt",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcltq_f32"]),
+            split(&["f32x4_lt"]),
+            split(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            split(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm512_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_f32x4",
+        "simd_lt",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcltq_f32"]),
+            native(&["f32x4_lt"]),
+            native(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            native(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            native(&["_mm_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_f32x8",
+        "simd_lt",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcltq_f32"]),
+            split(&["f32x4_lt"]),
+            split(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            split(&["_mm_castps_si128", "_mm_cmplt_ps"]),
+            native(&["_mm256_castps_si256", "_mm256_cmp_ps"]),
+            native(&["_mm256_cmp_ps_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_f64x2",
+        "simd_lt",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcltq_f64"]),
+            native(&["f64x2_lt"]),
+            native(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            native(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            native(&["_mm_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_f64x4",
+        "simd_lt",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcltq_f64"]),
+            split(&["f64x2_lt"]),
+            split(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            native(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm256_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_f64x8",
+        "simd_lt",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcltq_f64"]),
+            split(&["f64x2_lt"]),
+            split(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            split(&["_mm_castpd_si128", "_mm_cmplt_pd"]),
+            split(&["_mm256_castpd_si256", "_mm256_cmp_pd"]),
+            native(&["_mm512_cmp_pd_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i16x16",
+        "simd_lt",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcltq_s16"]),
+            split(&["i16x8_lt"]),
+            split(&["_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpgt_epi16"]),
+            native(&["_mm256_cmpgt_epi16"]),
+            native(&["_mm256_cmplt_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i16x32",
+        "simd_lt",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcltq_s16"]),
+            split(&["i16x8_lt"]),
+            split(&["_mm_cmpgt_epi16"]),
+            split(&["_mm_cmpgt_epi16"]),
+            split(&["_mm256_cmpgt_epi16"]),
+            native(&["_mm512_cmplt_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i16x8",
+        "simd_lt",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcltq_s16"]),
+            native(&["i16x8_lt"]),
+            native(&["_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpgt_epi16"]),
+            native(&["_mm_cmpgt_epi16"]),
+            native(&["_mm_cmplt_epi16_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i32x16",
+        "simd_lt",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcltq_s32"]),
+            split(&["i32x4_lt"]),
+            split(&["_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpgt_epi32"]),
+            split(&["_mm256_cmpgt_epi32"]),
+            native(&["_mm512_cmplt_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i32x4",
+        "simd_lt",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcltq_s32"]),
+            native(&["i32x4_lt"]),
+            native(&["_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpgt_epi32"]),
+            native(&["_mm_cmpgt_epi32"]),
+            native(&["_mm_cmplt_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i32x8",
+        "simd_lt",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcltq_s32"]),
+            split(&["i32x4_lt"]),
+            split(&["_mm_cmpgt_epi32"]),
+            split(&["_mm_cmpgt_epi32"]),
+            native(&["_mm256_cmpgt_epi32"]),
+            native(&["_mm256_cmplt_epi32_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i64x2",
+        "simd_lt",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcltq_s64"]),
+            native(&["i64x2_lt"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmplt_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i64x4",
+        "simd_lt",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcltq_s64"]),
+            split(&["i64x2_lt"]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmplt_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i64x8",
+        "simd_lt",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcltq_s64"]),
+            split(&["i64x2_lt"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmplt_epi64_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i8x16",
+        "simd_lt",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcltq_s8"]),
+            native(&["i8x16_lt"]),
+            native(&["_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpgt_epi8"]),
+            native(&["_mm_cmpgt_epi8"]),
+            native(&["_mm_cmplt_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i8x32",
+        "simd_lt",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcltq_s8"]),
+            split(&["i8x16_lt"]),
+            split(&["_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpgt_epi8"]),
+            native(&["_mm256_cmpgt_epi8"]),
+            native(&["_mm256_cmplt_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_i8x64",
+        "simd_lt",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcltq_s8"]),
+            split(&["i8x16_lt"]),
+            split(&["_mm_cmpgt_epi8"]),
+            split(&["_mm_cmpgt_epi8"]),
+            split(&["_mm256_cmpgt_epi8"]),
+            native(&["_mm512_cmplt_epi8_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u16x16",
+        "simd_lt",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcltq_u16"]),
+            split(&["u16x8_lt"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&[
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi16",
+            ]),
+            native(&["_mm256_cmplt_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u16x32",
+        "simd_lt",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s16_u16", "vcltq_u16"]),
+            split(&["u16x8_lt"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            split(&[
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi16",
+            ]),
+            native(&["_mm512_cmplt_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u16x8",
+        "simd_lt",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s16_u16", "vcltq_u16"]),
+            native(&["u16x8_lt"]),
+            native(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_set1_epi16", "_mm_xor_si128", "_mm_cmpgt_epi16"]),
+            native(&["_mm_cmplt_epu16_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u32x16",
+        "simd_lt",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcltq_u32"]),
+            split(&["u32x4_lt"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&[
+                "_mm256_set1_epi32",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi32",
+            ]),
+            native(&["_mm512_cmplt_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u32x4",
+        "simd_lt",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s32_u32", "vcltq_u32"]),
+            native(&["u32x4_lt"]),
+            native(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&["_mm_cmplt_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u32x8",
+        "simd_lt",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s32_u32", "vcltq_u32"]),
+            split(&["u32x4_lt"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            split(&["_mm_set1_epi32", "_mm_xor_si128", "_mm_cmpgt_epi32"]),
+            native(&[
+                "_mm256_set1_epi32",
+                "_mm256_xor_si256",
+                "_mm256_cmpgt_epi32",
+            ]),
+            native(&["_mm256_cmplt_epu32_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u64x2",
+        "simd_lt",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s64_u64", "vcltq_u64"]),
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_cmplt_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u64x4",
+        "simd_lt",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcltq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&[]),
+            native(&["_mm256_cmplt_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u64x8",
+        "simd_lt",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s64_u64", "vcltq_u64"]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            split(&[]),
+            native(&["_mm512_cmplt_epu64_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u8x16",
+        "simd_lt",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vreinterpretq_s8_u8", "vcltq_u8"]),
+            native(&["u8x16_lt"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm_cmplt_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u8x32",
+        "simd_lt",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcltq_u8"]),
+            split(&["u8x16_lt"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            native(&["_mm256_set1_epi8", "_mm256_xor_si256", "_mm256_cmpgt_epi8"]),
+            native(&["_mm256_cmplt_epu8_mask"]),
+        ],
+    ),
+    op(
+        "simd_lt_u8x64",
+        "simd_lt",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vreinterpretq_s8_u8", "vcltq_u8"]),
+            split(&["u8x16_lt"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm_set1_epi8", "_mm_xor_si128", "_mm_cmpgt_epi8"]),
+            split(&["_mm256_set1_epi8", "_mm256_xor_si256", "_mm256_cmpgt_epi8"]),
+            native(&["_mm512_cmplt_epu8_mask"]),
+        ],
+    ),
+    op(
+        "slide_f32x16",
+        "slide",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_f32x4",
+        "slide",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_f32x8",
+        "slide",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_f64x2",
+        "slide",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_f64x4",
+        "slide",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_f64x8",
+        "slide",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i16x16",
+        "slide",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i16x32",
+        "slide",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i16x8",
+        "slide",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_i32x16",
+        "slide",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i32x4",
+        "slide",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_i32x8",
+        "slide",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i64x2",
+        "slide",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_i64x4",
+        "slide",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i64x8",
+        "slide",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i8x16",
+        "slide",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_i8x32",
+        "slide",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_i8x64",
+        "slide",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u16x16",
+        "slide",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u16x32",
+        "slide",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u16x8",
+        "slide",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_u32x16",
+        "slide",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u32x4",
+        "slide",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_u32x8",
+        "slide",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u64x2",
+        "slide",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_u64x4",
+        "slide",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u64x8",
+        "slide",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u8x16",
+        "slide",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_u8x32",
+        "slide",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm256_add_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_set1_epi8",
+                "_mm256_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_u8x64",
+        "slide",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&["vextq_u8"]),
+            native(&["i8x16_shuffle"]),
+            native(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            native(&["_mm_alignr_epi8"]),
+            native(&["_mm256_permute2x128_si256", "_mm256_alignr_epi8"]),
+            native(&[
+                "_mm512_add_epi8",
+                "_mm512_set_epi8",
+                "_mm512_set1_epi8",
+                "_mm512_permutex2var_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_f32x16",
+        "slide_within_blocks",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_f32x4",
+        "slide_within_blocks",
+        "f32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_f32x8",
+        "slide_within_blocks",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_f64x2",
+        "slide_within_blocks",
+        "f64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_f64x4",
+        "slide_within_blocks",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_f64x8",
+        "slide_within_blocks",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i16x16",
+        "slide_within_blocks",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i16x32",
+        "slide_within_blocks",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i16x8",
+        "slide_within_blocks",
+        "i16x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i32x16",
+        "slide_within_blocks",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i32x4",
+        "slide_within_blocks",
+        "i32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i32x8",
+        "slide_within_blocks",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i64x2",
+        "slide_within_blocks",
+        "i64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i64x4",
+        "slide_within_blocks",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i64x8",
+        "slide_within_blocks",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i8x16",
+        "slide_within_blocks",
+        "i8x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i8x32",
+        "slide_within_blocks",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_i8x64",
+        "slide_within_blocks",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u16x16",
+        "slide_within_blocks",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u16x32",
+        "slide_within_blocks",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u16x8",
+        "slide_within_blocks",
+        "u16x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u32x16",
+        "slide_within_blocks",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u32x4",
+        "slide_within_blocks",
+        "u32x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u32x8",
+        "slide_within_blocks",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u64x2",
+        "slide_within_blocks",
+        "u64x2",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u64x4",
+        "slide_within_blocks",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u64x8",
+        "slide_within_blocks",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u8x16",
+        "slide_within_blocks",
+        "u8x16",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u8x32",
+        "slide_within_blocks",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+            native(&["_mm256_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "slide_within_blocks_u8x64",
+        "slide_within_blocks",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vextq_u8"]),
+            split(&["i8x16_shuffle"]),
+            split(&["_mm_srli_si128", "_mm_slli_si128", "_mm_or_si128"]),
+            split(&["_mm_alignr_epi8"]),
+            split(&["_mm256_alignr_epi8"]),
+            native(&["_mm512_alignr_epi8"]),
+        ],
+    ),
+    op(
+        "splat_f32x16",
+        "splat",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32"]),
+            split(&["f32x4_splat"]),
+            split(&["_mm_set1_ps"]),
+            split(&["_mm_set1_ps"]),
+            split(&["_mm256_set1_ps"]),
+            native(&["_mm512_set1_ps"]),
+        ],
+    ),
+    op(
+        "splat_f32x4",
+        "splat",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vdupq_n_f32"]),
+            native(&["f32x4_splat"]),
+            native(&["_mm_set1_ps"]),
+            native(&["_mm_set1_ps"]),
+            native(&["_mm_set1_ps"]),
+            native(&["_mm_set1_ps"]),
+        ],
+    ),
+    op(
+        "splat_f32x8",
+        "splat",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f32"]),
+            split(&["f32x4_splat"]),
+            split(&["_mm_set1_ps"]),
+            split(&["_mm_set1_ps"]),
+            native(&["_mm256_set1_ps"]),
+            native(&["_mm256_set1_ps"]),
+        ],
+    ),
+    op(
+        "splat_f64x2",
+        "splat",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vdupq_n_f64"]),
+            native(&["f64x2_splat"]),
+            native(&["_mm_set1_pd"]),
+            native(&["_mm_set1_pd"]),
+            native(&["_mm_set1_pd"]),
+            native(&["_mm_set1_pd"]),
+        ],
+    ),
+    op(
+        "splat_f64x4",
+        "splat",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64"]),
+            split(&["f64x2_splat"]),
+            split(&["_mm_set1_pd"]),
+            split(&["_mm_set1_pd"]),
+            native(&["_mm256_set1_pd"]),
+            native(&["_mm256_set1_pd"]),
+        ],
+    ),
+    op(
+        "splat_f64x8",
+        "splat",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_f64"]),
+            split(&["f64x2_splat"]),
+            split(&["_mm_set1_pd"]),
+            split(&["_mm_set1_pd"]),
+            split(&["_mm256_set1_pd"]),
+            native(&["_mm512_set1_pd"]),
+        ],
+    ),
+    op(
+        "splat_i16x16",
+        "splat",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16"]),
+            split(&["i16x8_splat"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm_set1_epi16"]),
+            native(&["_mm256_set1_epi16"]),
+            native(&["_mm256_set1_epi16"]),
+        ],
+    ),
+    op(
+        "splat_i16x32",
+        "splat",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16"]),
+            split(&["i16x8_splat"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm256_set1_epi16"]),
+            native(&["_mm512_set1_epi16"]),
+        ],
+    ),
+    op(
+        "splat_i16x8",
+        "splat",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s16"]),
+            native(&["i16x8_splat"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+        ],
+    ),
+    op(
+        "splat_i32x16",
+        "splat",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32"]),
+            split(&["i32x4_splat"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm256_set1_epi32"]),
+            native(&["_mm512_set1_epi32"]),
+        ],
+    ),
+    op(
+        "splat_i32x4",
+        "splat",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s32"]),
+            native(&["i32x4_splat"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+        ],
+    ),
+    op(
+        "splat_i32x8",
+        "splat",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32"]),
+            split(&["i32x4_splat"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm_set1_epi32"]),
+            native(&["_mm256_set1_epi32"]),
+            native(&["_mm256_set1_epi32"]),
+        ],
+    ),
+    op(
+        "splat_i64x2",
+        "splat",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s64"]),
+            native(&["i64x2_splat"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "splat_i64x4",
+        "splat",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64"]),
+            split(&["i64x2_splat"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm_set1_epi64x"]),
+            native(&["_mm256_set1_epi64x"]),
+            native(&["_mm256_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "splat_i64x8",
+        "splat",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64"]),
+            split(&["i64x2_splat"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm256_set1_epi64x"]),
+            native(&["_mm512_set1_epi64"]),
+        ],
+    ),
+    op(
+        "splat_i8x16",
+        "splat",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s8"]),
+            native(&["i8x16_splat"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+        ],
+    ),
+    op(
+        "splat_i8x32",
+        "splat",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8"]),
+            split(&["i8x16_splat"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm_set1_epi8"]),
+            native(&["_mm256_set1_epi8"]),
+            native(&["_mm256_set1_epi8"]),
+        ],
+    ),
+    op(
+        "splat_i8x64",
+        "splat",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8"]),
+            split(&["i8x16_splat"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm256_set1_epi8"]),
+            native(&["_mm512_set1_epi8"]),
+        ],
+    ),
+    op(
+        "splat_mask16x16",
+        "splat",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16"]),
+            split(&["i16x8_splat"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm_set1_epi16"]),
+            native(&["_mm256_set1_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask16x32",
+        "splat",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s16"]),
+            split(&["i16x8_splat"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm256_set1_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask16x8",
+        "splat",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s16"]),
+            native(&["i16x8_splat"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask32x16",
+        "splat",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32"]),
+            split(&["i32x4_splat"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm256_set1_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask32x4",
+        "splat",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s32"]),
+            native(&["i32x4_splat"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask32x8",
+        "splat",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s32"]),
+            split(&["i32x4_splat"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm_set1_epi32"]),
+            native(&["_mm256_set1_epi32"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask64x2",
+        "splat",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s64"]),
+            native(&["i64x2_splat"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask64x4",
+        "splat",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64"]),
+            split(&["i64x2_splat"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm_set1_epi64x"]),
+            native(&["_mm256_set1_epi64x"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask64x8",
+        "splat",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s64"]),
+            split(&["i64x2_splat"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm256_set1_epi64x"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask8x16",
+        "splat",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["vdupq_n_s8"]),
+            native(&["i8x16_splat"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask8x32",
+        "splat",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8"]),
+            split(&["i8x16_splat"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm_set1_epi8"]),
+            native(&["_mm256_set1_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_mask8x64",
+        "splat",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_s8"]),
+            split(&["i8x16_splat"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm256_set1_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "splat_u16x16",
+        "splat",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16"]),
+            split(&["u16x8_splat"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm_set1_epi16"]),
+            native(&["_mm256_set1_epi16"]),
+            native(&["_mm256_set1_epi16"]),
+        ],
+    ),
+    op(
+        "splat_u16x32",
+        "splat",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u16"]),
+            split(&["u16x8_splat"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm_set1_epi16"]),
+            split(&["_mm256_set1_epi16"]),
+            native(&["_mm512_set1_epi16"]),
+        ],
+    ),
+    op(
+        "splat_u16x8",
+        "splat",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vdupq_n_u16"]),
+            native(&["u16x8_splat"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+            native(&["_mm_set1_epi16"]),
+        ],
+    ),
+    op(
+        "splat_u32x16",
+        "splat",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32"]),
+            split(&["u32x4_splat"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm256_set1_epi32"]),
+            native(&["_mm512_set1_epi32"]),
+        ],
+    ),
+    op(
+        "splat_u32x4",
+        "splat",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vdupq_n_u32"]),
+            native(&["u32x4_splat"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+            native(&["_mm_set1_epi32"]),
+        ],
+    ),
+    op(
+        "splat_u32x8",
+        "splat",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u32"]),
+            split(&["u32x4_splat"]),
+            split(&["_mm_set1_epi32"]),
+            split(&["_mm_set1_epi32"]),
+            native(&["_mm256_set1_epi32"]),
+            native(&["_mm256_set1_epi32"]),
+        ],
+    ),
+    op(
+        "splat_u64x2",
+        "splat",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vdupq_n_u64"]),
+            native(&["u64x2_splat"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+            native(&["_mm_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "splat_u64x4",
+        "splat",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64"]),
+            split(&["u64x2_splat"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm_set1_epi64x"]),
+            native(&["_mm256_set1_epi64x"]),
+            native(&["_mm256_set1_epi64x"]),
+        ],
+    ),
+    op(
+        "splat_u64x8",
+        "splat",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u64"]),
+            split(&["u64x2_splat"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm_set1_epi64x"]),
+            split(&["_mm256_set1_epi64x"]),
+            native(&["_mm512_set1_epi64"]),
+        ],
+    ),
+    op(
+        "splat_u8x16",
+        "splat",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vdupq_n_u8"]),
+            native(&["u8x16_splat"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+            native(&["_mm_set1_epi8"]),
+        ],
+    ),
+    op(
+        "splat_u8x32",
+        "splat",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8"]),
+            split(&["u8x16_splat"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm_set1_epi8"]),
+            native(&["_mm256_set1_epi8"]),
+            native(&["_mm256_set1_epi8"]),
+        ],
+    ),
+    op(
+        "splat_u8x64",
+        "splat",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vdupq_n_u8"]),
+            split(&["u8x16_splat"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm_set1_epi8"]),
+            split(&["_mm256_set1_epi8"]),
+            native(&["_mm512_set1_epi8"]),
+        ],
+    ),
+    op(
+        "split_f32x16",
+        "split",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castps512_ps256", "_mm512_extractf32x8_ps"]),
+        ],
+    ),
+    op(
+        "split_f32x8",
+        "split",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extractf128_ps"]),
+            native(&["_mm256_extractf128_ps"]),
+        ],
+    ),
+    op(
+        "split_f64x4",
+        "split",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extractf128_pd"]),
+            native(&["_mm256_extractf128_pd"]),
+        ],
+    ),
+    op(
+        "split_f64x8",
+        "split",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castpd512_pd256", "_mm512_extractf64x4_pd"]),
+        ],
+    ),
+    op(
+        "split_i16x16",
+        "split",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_i16x32",
+        "split",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_i32x16",
+        "split",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_i32x8",
+        "split",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_i64x4",
+        "split",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_i64x8",
+        "split",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_i8x32",
+        "split",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_i8x64",
+        "split",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_mask16x16",
+        "split",
+        "mask16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask16x32",
+        "split",
+        "mask16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask32x16",
+        "split",
+        "mask32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask32x8",
+        "split",
+        "mask32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask64x4",
+        "split",
+        "mask64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask64x8",
+        "split",
+        "mask64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask8x32",
+        "split",
+        "mask8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_mask8x64",
+        "split",
+        "mask8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "split_u16x16",
+        "split",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_u16x32",
+        "split",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_u32x16",
+        "split",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_u32x8",
+        "split",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_u64x4",
+        "split",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_u64x8",
+        "split",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "split_u8x32",
+        "split",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm256_extracti128_si256"]),
+            native(&["_mm256_extracti128_si256"]),
+        ],
+    ),
+    op(
+        "split_u8x64",
+        "split",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&["_mm512_castsi512_si256", "_mm512_extracti64x4_epi64"]),
+        ],
+    ),
+    op(
+        "sqrt_f32x16",
+        "sqrt",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vsqrtq_f32"]),
+            split(&["f32x4_sqrt"]),
+            split(&["_mm_sqrt_ps"]),
+            split(&["_mm_sqrt_ps"]),
+            split(&["_mm256_sqrt_ps"]),
+            native(&["_mm512_sqrt_ps"]),
+        ],
+    ),
+    op(
+        "sqrt_f32x4",
+        "sqrt",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vsqrtq_f32"]),
+            native(&["f32x4_sqrt"]),
+            native(&["_mm_sqrt_ps"]),
+            native(&["_mm_sqrt_ps"]),
+            native(&["_mm_sqrt_ps"]),
+            native(&["_mm_sqrt_ps"]),
+        ],
+    ),
+    op(
+        "sqrt_f32x8",
+        "sqrt",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vsqrtq_f32"]),
+            split(&["f32x4_sqrt"]),
+            split(&["_mm_sqrt_ps"]),
+            split(&["_mm_sqrt_ps"]),
+            native(&["_mm256_sqrt_ps"]),
+            native(&["_mm256_sqrt_ps"]),
+        ],
+    ),
+    op(
+        "sqrt_f64x2",
+        "sqrt",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vsqrtq_f64"]),
+            native(&["f64x2_sqrt"]),
+            native(&["_mm_sqrt_pd"]),
+            native(&["_mm_sqrt_pd"]),
+            native(&["_mm_sqrt_pd"]),
+            native(&["_mm_sqrt_pd"]),
+        ],
+    ),
+    op(
+        "sqrt_f64x4",
+        "sqrt",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vsqrtq_f64"]),
+            split(&["f64x2_sqrt"]),
+            split(&["_mm_sqrt_pd"]),
+            split(&["_mm_sqrt_pd"]),
+            native(&["_mm256_sqrt_pd"]),
+            native(&["_mm256_sqrt_pd"]),
+        ],
+    ),
+    op(
+        "sqrt_f64x8",
+        "sqrt",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vsqrtq_f64"]),
+            split(&["f64x2_sqrt"]),
+            split(&["_mm_sqrt_pd"]),
+            split(&["_mm_sqrt_pd"]),
+            split(&["_mm256_sqrt_pd"]),
+            native(&["_mm512_sqrt_pd"]),
+        ],
+    ),
+    op(
+        "store_array_f32x16",
+        "store_array",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_f32x4",
+        "store_array",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_f32x8",
+        "store_array",
+        "f32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_f64x2",
+        "store_array",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_f64x4",
+        "store_array",
+        "f64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_f64x8",
+        "store_array",
+        "f64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i16x16",
+        "store_array",
+        "i16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i16x32",
+        "store_array",
+        "i16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i16x8",
+        "store_array",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i32x16",
+        "store_array",
+        "i32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i32x4",
+        "store_array",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i32x8",
+        "store_array",
+        "i32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i64x2",
+        "store_array",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i64x4",
+        "store_array",
+        "i64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i64x8",
+        "store_array",
+        "i64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i8x16",
+        "store_array",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i8x32",
+        "store_array",
+        "i8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_i8x64",
+        "store_array",
+        "i8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u16x16",
+        "store_array",
+        "u16x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u16x32",
+        "store_array",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u16x8",
+        "store_array",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u32x16",
+        "store_array",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u32x4",
+        "store_array",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u32x8",
+        "store_array",
+        "u32x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u64x2",
+        "store_array",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u64x4",
+        "store_array",
+        "u64x4",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u64x8",
+        "store_array",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u8x16",
+        "store_array",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u8x32",
+        "store_array",
+        "u8x32",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_array_u8x64",
+        "store_array",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "store_interleaved_128_f32x16",
+        "store_interleaved_128",
+        "f32x16",
+        [
+            PORTABLE,
+            native(&["vst4q_f32"]),
+            native(&["u32x4_shuffle"]),
+            native(&[
+                "_mm_unpacklo_ps",
+                "_mm_unpackhi_ps",
+                "_mm_castpd_ps",
+                "_mm_unpacklo_pd",
+                "_mm_castps_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            native(&[
+                "_mm_unpacklo_ps",
+                "_mm_unpackhi_ps",
+                "_mm_castpd_ps",
+                "_mm_unpacklo_pd",
+                "_mm_castps_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            native(&[
+                "_mm_unpacklo_ps",
+                "_mm_unpackhi_ps",
+                "_mm_castpd_ps",
+                "_mm_unpacklo_pd",
+                "_mm_castps_pd",
+                "_mm_unpackhi_pd",
+            ]),
+            native(&["_mm512_permutexvar_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "store_interleaved_128_u16x32",
+        "store_interleaved_128",
+        "u16x32",
+        [
+            PORTABLE,
+            native(&["vst4q_u16"]),
+            native(&["u16x8_shuffle"]),
+            PORTABLE,
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm512_permutexvar_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "store_interleaved_128_u32x16",
+        "store_interleaved_128",
+        "u32x16",
+        [
+            PORTABLE,
+            native(&["vst4q_u32"]),
+            native(&["u32x4_shuffle"]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+            ]),
+            native(&["_mm512_permutexvar_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "store_interleaved_128_u64x8",
+        "store_interleaved_128",
+        "u64x8",
+        [
+            PORTABLE,
+            native(&["vst4q_u64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permute2x128_si256",
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+            ]),
+            native(&["_mm512_permutexvar_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "store_interleaved_128_u8x64",
+        "store_interleaved_128",
+        "u8x64",
+        [
+            PORTABLE,
+            native(&["vst4q_u8"]),
+            native(&["u8x16_shuffle"]),
+            PORTABLE,
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+            ]),
+            native(&["_mm512_permutexvar_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "sub_f32x16",
+        "sub",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vsubq_f32"]),
+            split(&["f32x4_sub"]),
+            split(&["_mm_sub_ps"]),
+            split(&["_mm_sub_ps"]),
+            split(&["_mm256_sub_ps"]),
+            native(&["_mm512_sub_ps"]),
+        ],
+    ),
+    op(
+        "sub_f32x4",
+        "sub",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vsubq_f32"]),
+            native(&["f32x4_sub"]),
+            native(&["_mm_sub_ps"]),
+            native(&["_mm_sub_ps"]),
+            native(&["_mm_sub_ps"]),
+            native(&["_mm_sub_ps"]),
+        ],
+    ),
+    op(
+        "sub_f32x8",
+        "sub",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vsubq_f32"]),
+            split(&["f32x4_sub"]),
+            split(&["_mm_sub_ps"]),
+            split(&["_mm_sub_ps"]),
+            native(&["_mm256_sub_ps"]),
+            native(&["_mm256_sub_ps"]),
+        ],
+    ),
+    op(
+        "sub_f64x2",
+        "sub",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vsubq_f64"]),
+            native(&["f64x2_sub"]),
+            native(&["_mm_sub_pd"]),
+            native(&["_mm_sub_pd"]),
+            native(&["_mm_sub_pd"]),
+            native(&["_mm_sub_pd"]),
+        ],
+    ),
+    op(
+        "sub_f64x4",
+        "sub",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vsubq_f64"]),
+            split(&["f64x2_sub"]),
+            split(&["_mm_sub_pd"]),
+            split(&["_mm_sub_pd"]),
+            native(&["_mm256_sub_pd"]),
+            native(&["_mm256_sub_pd"]),
+        ],
+    ),
+    op(
+        "sub_f64x8",
+        "sub",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vsubq_f64"]),
+            split(&["f64x2_sub"]),
+            split(&["_mm_sub_pd"]),
+            split(&["_mm_sub_pd"]),
+            split(&["_mm256_sub_pd"]),
+            native(&["_mm512_sub_pd"]),
+        ],
+    ),
+    op(
+        "sub_i16x16",
+        "sub",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vsubq_s16"]),
+            split(&["i16x8_sub"]),
+            split(&["_mm_sub_epi16"]),
+            split(&["_mm_sub_epi16"]),
+            native(&["_mm256_sub_epi16"]),
+            native(&["_mm256_sub_epi16"]),
+        ],
+    ),
+    op(
+        "sub_i16x32",
+        "sub",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vsubq_s16"]),
+            split(&["i16x8_sub"]),
+            split(&["_mm_sub_epi16"]),
+            split(&["_mm_sub_epi16"]),
+            split(&["_mm256_sub_epi16"]),
+            native(&["_mm512_sub_epi16"]),
+        ],
+    ),
+    op(
+        "sub_i16x8",
+        "sub",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vsubq_s16"]),
+            native(&["i16x8_sub"]),
+            native(&["_mm_sub_epi16"]),
+            native(&["_mm_sub_epi16"]),
+            native(&["_mm_sub_epi16"]),
+            native(&["_mm_sub_epi16"]),
+        ],
+    ),
+    op(
+        "sub_i32x16",
+        "sub",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vsubq_s32"]),
+            split(&["i32x4_sub"]),
+            split(&["_mm_sub_epi32"]),
+            split(&["_mm_sub_epi32"]),
+            split(&["_mm256_sub_epi32"]),
+            native(&["_mm512_sub_epi32"]),
+        ],
+    ),
+    op(
+        "sub_i32x4",
+        "sub",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vsubq_s32"]),
+            native(&["i32x4_sub"]),
+            native(&["_mm_sub_epi32"]),
+            native(&["_mm_sub_epi32"]),
+            native(&["_mm_sub_epi32"]),
+            native(&["_mm_sub_epi32"]),
+        ],
+    ),
+    op(
+        "sub_i32x8",
+        "sub",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vsubq_s32"]),
+            split(&["i32x4_sub"]),
+            split(&["_mm_sub_epi32"]),
+            split(&["_mm_sub_epi32"]),
+            native(&["_mm256_sub_epi32"]),
+            native(&["_mm256_sub_epi32"]),
+        ],
+    ),
+    op(
+        "sub_i64x2",
+        "sub",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vsubq_s64"]),
+            native(&["i64x2_sub"]),
+            native(&["_mm_sub_epi64"]),
+            native(&["_mm_sub_epi64"]),
+            native(&["_mm_sub_epi64"]),
+            native(&["_mm_sub_epi64"]),
+        ],
+    ),
+    op(
+        "sub_i64x4",
+        "sub",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vsubq_s64"]),
+            split(&["i64x2_sub"]),
+            split(&["_mm_sub_epi64"]),
+            split(&["_mm_sub_epi64"]),
+            native(&["_mm256_sub_epi64"]),
+            native(&["_mm256_sub_epi64"]),
+        ],
+    ),
+    op(
+        "sub_i64x8",
+        "sub",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vsubq_s64"]),
+            split(&["i64x2_sub"]),
+            split(&["_mm_sub_epi64"]),
+            split(&["_mm_sub_epi64"]),
+            split(&["_mm256_sub_epi64"]),
+            native(&["_mm512_sub_epi64"]),
+        ],
+    ),
+    op(
+        "sub_i8x16",
+        "sub",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vsubq_s8"]),
+            native(&["i8x16_sub"]),
+            native(&["_mm_sub_epi8"]),
+            native(&["_mm_sub_epi8"]),
+            native(&["_mm_sub_epi8"]),
+            native(&["_mm_sub_epi8"]),
+        ],
+    ),
+    op(
+        "sub_i8x32",
+        "sub",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vsubq_s8"]),
+            split(&["i8x16_sub"]),
+            split(&["_mm_sub_epi8"]),
+            split(&["_mm_sub_epi8"]),
+            native(&["_mm256_sub_epi8"]),
+            native(&["_mm256_sub_epi8"]),
+        ],
+    ),
+    op(
+        "sub_i8x64",
+        "sub",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vsubq_s8"]),
+            split(&["i8x16_sub"]),
+            split(&["_mm_sub_epi8"]),
+            split(&["_mm_sub_epi8"]),
+            split(&["_mm256_sub_epi8"]),
+            native(&["_mm512_sub_epi8"]),
+        ],
+    ),
+    op(
+        "sub_u16x16",
+        "sub",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vsubq_u16"]),
+            split(&["u16x8_sub"]),
+            split(&["_mm_sub_epi16"]),
+            split(&["_mm_sub_epi16"]),
+            native(&["_mm256_sub_epi16"]),
+            native(&["_mm256_sub_epi16"]),
+        ],
+    ),
+    op(
+        "sub_u16x32",
+        "sub",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vsubq_u16"]),
+            split(&["u16x8_sub"]),
+            split(&["_mm_sub_epi16"]),
+            split(&["_mm_sub_epi16"]),
+            split(&["_mm256_sub_epi16"]),
+            native(&["_mm512_sub_epi16"]),
+        ],
+    ),
+    op(
+        "sub_u16x8",
+        "sub",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vsubq_u16"]),
+            native(&["u16x8_sub"]),
+            native(&["_mm_sub_epi16"]),
+            native(&["_mm_sub_epi16"]),
+            native(&["_mm_sub_epi16"]),
+            native(&["_mm_sub_epi16"]),
+        ],
+    ),
+    op(
+        "sub_u32x16",
+        "sub",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vsubq_u32"]),
+            split(&["u32x4_sub"]),
+            split(&["_mm_sub_epi32"]),
+            split(&["_mm_sub_epi32"]),
+            split(&["_mm256_sub_epi32"]),
+            native(&["_mm512_sub_epi32"]),
+        ],
+    ),
+    op(
+        "sub_u32x4",
+        "sub",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vsubq_u32"]),
+            native(&["u32x4_sub"]),
+            native(&["_mm_sub_epi32"]),
+            native(&["_mm_sub_epi32"]),
+            native(&["_mm_sub_epi32"]),
+            native(&["_mm_sub_epi32"]),
+        ],
+    ),
+    op(
+        "sub_u32x8",
+        "sub",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vsubq_u32"]),
+            split(&["u32x4_sub"]),
+            split(&["_mm_sub_epi32"]),
+            split(&["_mm_sub_epi32"]),
+            native(&["_mm256_sub_epi32"]),
+            native(&["_mm256_sub_epi32"]),
+        ],
+    ),
+    op(
+        "sub_u64x2",
+        "sub",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vsubq_u64"]),
+            native(&["u64x2_sub"]),
+            native(&["_mm_sub_epi64"]),
+            native(&["_mm_sub_epi64"]),
+            native(&["_mm_sub_epi64"]),
+            native(&["_mm_sub_epi64"]),
+        ],
+    ),
+    op(
+        "sub_u64x4",
+        "sub",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vsubq_u64"]),
+            split(&["u64x2_sub"]),
+            split(&["_mm_sub_epi64"]),
+            split(&["_mm_sub_epi64"]),
+            native(&["_mm256_sub_epi64"]),
+            native(&["_mm256_sub_epi64"]),
+        ],
+    ),
+    op(
+        "sub_u64x8",
+        "sub",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vsubq_u64"]),
+            split(&["u64x2_sub"]),
+            split(&["_mm_sub_epi64"]),
+            split(&["_mm_sub_epi64"]),
+            split(&["_mm256_sub_epi64"]),
+            native(&["_mm512_sub_epi64"]),
+        ],
+    ),
+    op(
+        "sub_u8x16",
+        "sub",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vsubq_u8"]),
+            native(&["u8x16_sub"]),
+            native(&["_mm_sub_epi8"]),
+            native(&["_mm_sub_epi8"]),
+            native(&["_mm_sub_epi8"]),
+            native(&["_mm_sub_epi8"]),
+        ],
+    ),
+    op(
+        "sub_u8x32",
+        "sub",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vsubq_u8"]),
+            split(&["u8x16_sub"]),
+            split(&["_mm_sub_epi8"]),
+            split(&["_mm_sub_epi8"]),
+            native(&["_mm256_sub_epi8"]),
+            native(&["_mm256_sub_epi8"]),
+        ],
+    ),
+    op(
+        "sub_u8x64",
+        "sub",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vsubq_u8"]),
+            split(&["u8x16_sub"]),
+            split(&["_mm_sub_epi8"]),
+            split(&["_mm_sub_epi8"]),
+            split(&["_mm256_sub_epi8"]),
+            native(&["_mm512_sub_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_f32x16",
+        "swizzle_dyn_within_blocks",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_f32x4",
+        "swizzle_dyn_within_blocks",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_f32x8",
+        "swizzle_dyn_within_blocks",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_f64x2",
+        "swizzle_dyn_within_blocks",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_f64x4",
+        "swizzle_dyn_within_blocks",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_f64x8",
+        "swizzle_dyn_within_blocks",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i16x16",
+        "swizzle_dyn_within_blocks",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i16x32",
+        "swizzle_dyn_within_blocks",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i16x8",
+        "swizzle_dyn_within_blocks",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i32x16",
+        "swizzle_dyn_within_blocks",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i32x4",
+        "swizzle_dyn_within_blocks",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i32x8",
+        "swizzle_dyn_within_blocks",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i64x2",
+        "swizzle_dyn_within_blocks",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i64x4",
+        "swizzle_dyn_within_blocks",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i64x8",
+        "swizzle_dyn_within_blocks",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i8x16",
+        "swizzle_dyn_within_blocks",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i8x32",
+        "swizzle_dyn_within_blocks",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_i8x64",
+        "swizzle_dyn_within_blocks",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u16x16",
+        "swizzle_dyn_within_blocks",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u16x32",
+        "swizzle_dyn_within_blocks",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u16x8",
+        "swizzle_dyn_within_blocks",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u32x16",
+        "swizzle_dyn_within_blocks",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u32x4",
+        "swizzle_dyn_within_blocks",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u32x8",
+        "swizzle_dyn_within_blocks",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u64x2",
+        "swizzle_dyn_within_blocks",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u64x4",
+        "swizzle_dyn_within_blocks",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u64x8",
+        "swizzle_dyn_within_blocks",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u8x16",
+        "swizzle_dyn_within_blocks",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_shuffle_epi8"]),
+            native(&["_mm_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u8x32",
+        "swizzle_dyn_within_blocks",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            native(&["_mm256_shuffle_epi8"]),
+            native(&["_mm256_mask_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "swizzle_dyn_within_blocks_u8x64",
+        "swizzle_dyn_within_blocks",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_shuffle_epi8"]),
+            split(&["_mm256_shuffle_epi8"]),
+            native(&["_mm512_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "table_lookup_u8x16",
+        "table_lookup",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vqtbl1q_u8"]),
+            native(&["u8x16_swizzle"]),
+            PORTABLE,
+            native(&["_mm_adds_epu8", "_mm_set1_epi8", "_mm_shuffle_epi8"]),
+            native(&["_mm_adds_epu8", "_mm_set1_epi8", "_mm_shuffle_epi8"]),
+            native(&["_mm_adds_epu8", "_mm_set1_epi8", "_mm_shuffle_epi8"]),
+        ],
+    ),
+    op(
+        "table_lookup_u8x32",
+        "table_lookup",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_adds_epu8", "_mm_set1_epi8", "_mm_shuffle_epi8"]),
+            native(&[
+                "_mm256_adds_epu8",
+                "_mm256_set1_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm256_adds_epu8",
+                "_mm256_set1_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "table_lookup_u8x64",
+        "table_lookup",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vqtbl1q_u8"]),
+            split(&["u8x16_swizzle"]),
+            split(&[]),
+            split(&["_mm_adds_epu8", "_mm_set1_epi8", "_mm_shuffle_epi8"]),
+            split(&[
+                "_mm256_adds_epu8",
+                "_mm256_set1_epi8",
+                "_mm256_shuffle_epi8",
+            ]),
+            native(&[
+                "_mm512_adds_epu8",
+                "_mm512_set1_epi8",
+                "_mm512_shuffle_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask16x16",
+        "to_bitmask",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["vandq_u16", "vreinterpretq_u16_s16", "vaddvq_u16"]),
+            split(&["i16x8_bitmask"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask16x32",
+        "to_bitmask",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["vandq_u16", "vreinterpretq_u16_s16", "vaddvq_u16"]),
+            split(&["i16x8_bitmask"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask16x8",
+        "to_bitmask",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["vandq_u16", "vreinterpretq_u16_s16", "vaddvq_u16"]),
+            native(&["i16x8_bitmask"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&["_mm_packs_epi16", "_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask32x16",
+        "to_bitmask",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["vandq_u32", "vreinterpretq_u32_s32", "vaddvq_u32"]),
+            split(&["i32x4_bitmask"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask32x4",
+        "to_bitmask",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["vandq_u32", "vreinterpretq_u32_s32", "vaddvq_u32"]),
+            native(&["i32x4_bitmask"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask32x8",
+        "to_bitmask",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["vandq_u32", "vreinterpretq_u32_s32", "vaddvq_u32"]),
+            split(&["i32x4_bitmask"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            split(&["_mm_movemask_ps", "_mm_castsi128_ps"]),
+            native(&["_mm256_movemask_ps", "_mm256_castsi256_ps"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask64x2",
+        "to_bitmask",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["vandq_u64", "vreinterpretq_u64_s64", "vaddvq_u64"]),
+            native(&["i64x2_bitmask"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask64x4",
+        "to_bitmask",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["vandq_u64", "vreinterpretq_u64_s64", "vaddvq_u64"]),
+            split(&["i64x2_bitmask"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            native(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask64x8",
+        "to_bitmask",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["vandq_u64", "vreinterpretq_u64_s64", "vaddvq_u64"]),
+            split(&["i64x2_bitmask"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm_movemask_pd", "_mm_castsi128_pd"]),
+            split(&["_mm256_movemask_pd", "_mm256_castsi256_pd"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask8x16",
+        "to_bitmask",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+            ]),
+            native(&["i8x16_bitmask"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&["_mm_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask8x32",
+        "to_bitmask",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+            ]),
+            split(&["i8x16_bitmask"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            native(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_bitmask_mask8x64",
+        "to_bitmask",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u8",
+                "vreinterpretq_u8_s8",
+                "vaddv_u8",
+                "vget_low_u8",
+                "vget_high_u8",
+            ]),
+            split(&["i8x16_bitmask"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm_movemask_epi8"]),
+            split(&["_mm256_movemask_epi8"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "to_int_mask16x16",
+        "to_int",
+        "mask16x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm256_movm_epi16"]),
+        ],
+    ),
+    op(
+        "to_int_mask16x32",
+        "to_int",
+        "mask16x32",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm512_movm_epi16"]),
+        ],
+    ),
+    op(
+        "to_int_mask16x8",
+        "to_int",
+        "mask16x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_movm_epi16"]),
+        ],
+    ),
+    op(
+        "to_int_mask32x16",
+        "to_int",
+        "mask32x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm512_movm_epi32"]),
+        ],
+    ),
+    op(
+        "to_int_mask32x4",
+        "to_int",
+        "mask32x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_movm_epi32"]),
+        ],
+    ),
+    op(
+        "to_int_mask32x8",
+        "to_int",
+        "mask32x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm256_movm_epi32"]),
+        ],
+    ),
+    op(
+        "to_int_mask64x2",
+        "to_int",
+        "mask64x2",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_movm_epi64"]),
+        ],
+    ),
+    op(
+        "to_int_mask64x4",
+        "to_int",
+        "mask64x4",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm256_movm_epi64"]),
+        ],
+    ),
+    op(
+        "to_int_mask64x8",
+        "to_int",
+        "mask64x8",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm512_movm_epi64"]),
+        ],
+    ),
+    op(
+        "to_int_mask8x16",
+        "to_int",
+        "mask8x16",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm_movm_epi8"]),
+        ],
+    ),
+    op(
+        "to_int_mask8x32",
+        "to_int",
+        "mask8x32",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm256_movm_epi8"]),
+        ],
+    ),
+    op(
+        "to_int_mask8x64",
+        "to_int",
+        "mask8x64",
+        [
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            PORTABLE,
+            native(&["_mm512_movm_epi8"]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i16x16",
+        "trailing_zeros",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s16_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s16",
+                "vandq_s16",
+                "vmvnq_s16",
+                "vsubq_s16",
+                "vdupq_n_s16",
+            ]),
+            split(&[
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i16x8_sub",
+                "i16x8_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi16",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi16",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+                "_mm256_sub_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i16x32",
+        "trailing_zeros",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s16_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s16",
+                "vandq_s16",
+                "vmvnq_s16",
+                "vsubq_s16",
+                "vdupq_n_s16",
+            ]),
+            split(&[
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i16x8_sub",
+                "i16x8_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi16",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi16",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi16",
+                "_mm512_sub_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i16x8",
+        "trailing_zeros",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s16_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s16",
+                "vandq_s16",
+                "vmvnq_s16",
+                "vsubq_s16",
+                "vdupq_n_s16",
+            ]),
+            native(&[
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i16x8_sub",
+                "i16x8_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            native(&[
+                "_mm_popcnt_epi16",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_sub_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i32x16",
+        "trailing_zeros",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s32_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s32",
+                "vandq_s32",
+                "vmvnq_s32",
+                "vsubq_s32",
+                "vdupq_n_s32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i32x4_sub",
+                "i32x4_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+                "_mm256_sub_epi32",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi32",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi32",
+                "_mm512_sub_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i32x4",
+        "trailing_zeros",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s32_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s32",
+                "vandq_s32",
+                "vmvnq_s32",
+                "vsubq_s32",
+                "vdupq_n_s32",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i32x4_sub",
+                "i32x4_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            native(&[
+                "_mm_popcnt_epi32",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i32x8",
+        "trailing_zeros",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s32_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s32",
+                "vandq_s32",
+                "vmvnq_s32",
+                "vsubq_s32",
+                "vdupq_n_s32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i32x4_sub",
+                "i32x4_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+                "_mm256_sub_epi32",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi32",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+                "_mm256_sub_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i64x2",
+        "trailing_zeros",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+                "vandq_s64",
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+                "vsubq_s64",
+                "vdupq_n_s64",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+                "i64x2_sub",
+                "i64x2_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            native(&[
+                "_mm_popcnt_epi64",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i64x4",
+        "trailing_zeros",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+                "vandq_s64",
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+                "vsubq_s64",
+                "vdupq_n_s64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+                "i64x2_sub",
+                "i64x2_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+                "_mm256_sub_epi64",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi64",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+                "_mm256_sub_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i64x8",
+        "trailing_zeros",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vreinterpretq_s64_u64",
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_s64",
+                "vandq_s64",
+                "vreinterpretq_s64_s32",
+                "vmvnq_s32",
+                "vreinterpretq_s32_s64",
+                "vsubq_s64",
+                "vdupq_n_s64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+                "i64x2_sub",
+                "i64x2_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+                "_mm256_sub_epi64",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi64",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi64",
+                "_mm512_sub_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i8x16",
+        "trailing_zeros",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vcntq_s8", "vandq_s8", "vmvnq_s8", "vsubq_s8", "vdupq_n_s8"]),
+            native(&[
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i8x16_sub",
+                "i8x16_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            native(&[
+                "_mm_popcnt_epi8",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_sub_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i8x32",
+        "trailing_zeros",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vcntq_s8", "vandq_s8", "vmvnq_s8", "vsubq_s8", "vdupq_n_s8"]),
+            split(&[
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i8x16_sub",
+                "i8x16_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi8",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi8",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+                "_mm256_sub_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_i8x64",
+        "trailing_zeros",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vcntq_s8", "vandq_s8", "vmvnq_s8", "vsubq_s8", "vdupq_n_s8"]),
+            split(&[
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "i8x16_sub",
+                "i8x16_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi8",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi8",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi8",
+                "_mm512_sub_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u16x16",
+        "trailing_zeros",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u16",
+                "vandq_u16",
+                "vmvnq_u16",
+                "vsubq_u16",
+                "vdupq_n_u16",
+            ]),
+            split(&[
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u16x8_sub",
+                "u16x8_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi16",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi16",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi16",
+                "_mm256_sub_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u16x32",
+        "trailing_zeros",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u16",
+                "vandq_u16",
+                "vmvnq_u16",
+                "vsubq_u16",
+                "vdupq_n_u16",
+            ]),
+            split(&[
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u16x8_sub",
+                "u16x8_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi16",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi16",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi16",
+                "_mm512_sub_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u16x8",
+        "trailing_zeros",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&[
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u16",
+                "vandq_u16",
+                "vmvnq_u16",
+                "vsubq_u16",
+                "vdupq_n_u16",
+            ]),
+            native(&[
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u16x8_sub",
+                "u16x8_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_sub_epi16",
+            ]),
+            native(&[
+                "_mm_popcnt_epi16",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi16",
+                "_mm_sub_epi16",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u32x16",
+        "trailing_zeros",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u32",
+                "vandq_u32",
+                "vmvnq_u32",
+                "vsubq_u32",
+                "vdupq_n_u32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u32x4_sub",
+                "u32x4_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+                "_mm256_sub_epi32",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi32",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi32",
+                "_mm512_sub_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u32x4",
+        "trailing_zeros",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&[
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u32",
+                "vandq_u32",
+                "vmvnq_u32",
+                "vsubq_u32",
+                "vdupq_n_u32",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u32x4_sub",
+                "u32x4_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            native(&[
+                "_mm_popcnt_epi32",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u32x8",
+        "trailing_zeros",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u32",
+                "vandq_u32",
+                "vmvnq_u32",
+                "vsubq_u32",
+                "vdupq_n_u32",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u32x4_sub",
+                "u32x4_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_madd_epi16",
+                "_mm_add_epi16",
+                "_mm_set1_epi16",
+                "_mm_xor_si128",
+                "_mm_set1_epi32",
+                "_mm_sub_epi32",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_madd_epi16",
+                "_mm256_add_epi16",
+                "_mm256_set1_epi16",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+                "_mm256_sub_epi32",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi32",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi32",
+                "_mm256_sub_epi32",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u64x2",
+        "trailing_zeros",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&[
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+                "vandq_u64",
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+                "vsubq_u64",
+                "vdupq_n_u64",
+            ]),
+            native(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+                "u64x2_sub",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            native(&[
+                "_mm_popcnt_epi64",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u64x4",
+        "trailing_zeros",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+                "vandq_u64",
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+                "vsubq_u64",
+                "vdupq_n_u64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+                "u64x2_sub",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+                "_mm256_sub_epi64",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi64",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+                "_mm256_sub_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u64x8",
+        "trailing_zeros",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vpaddlq_u32",
+                "vpaddlq_u16",
+                "vpaddlq_u8",
+                "vcntq_u8",
+                "vreinterpretq_u8_u64",
+                "vandq_u64",
+                "vreinterpretq_u64_u32",
+                "vmvnq_u32",
+                "vreinterpretq_u32_u64",
+                "vsubq_u64",
+                "vdupq_n_u64",
+            ]),
+            split(&[
+                "i32x4_extadd_pairwise_u16x8",
+                "i16x8_extadd_pairwise_u8x16",
+                "i8x16_popcnt",
+                "i64x2_add",
+                "v128_and",
+                "u64x2_splat",
+                "u64x2_shr",
+                "v128_not",
+                "u64x2_sub",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_sad_epu8",
+                "_mm_setzero_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi64x",
+                "_mm_sub_epi64",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_sad_epu8",
+                "_mm256_setzero_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi64x",
+                "_mm256_sub_epi64",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi64",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi64",
+                "_mm512_sub_epi64",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u8x16",
+        "trailing_zeros",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vcntq_u8", "vandq_u8", "vmvnq_u8", "vsubq_u8", "vdupq_n_u8"]),
+            native(&[
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u8x16_sub",
+                "u8x16_splat",
+            ]),
+            PORTABLE,
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            native(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            native(&[
+                "_mm_popcnt_epi8",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_set1_epi8",
+                "_mm_sub_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u8x32",
+        "trailing_zeros",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vcntq_u8", "vandq_u8", "vmvnq_u8", "vsubq_u8", "vdupq_n_u8"]),
+            split(&[
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u8x16_sub",
+                "u8x16_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            native(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi8",
+            ]),
+            native(&[
+                "_mm256_popcnt_epi8",
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_set1_epi8",
+                "_mm256_sub_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "trailing_zeros_u8x64",
+        "trailing_zeros",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vcntq_u8", "vandq_u8", "vmvnq_u8", "vsubq_u8", "vdupq_n_u8"]),
+            split(&[
+                "i8x16_popcnt",
+                "v128_and",
+                "v128_not",
+                "u8x16_sub",
+                "u8x16_splat",
+            ]),
+            split(&[]),
+            split(&[
+                "_mm_setr_epi8",
+                "_mm_and_si128",
+                "_mm_set1_epi8",
+                "_mm_srli_epi16",
+                "_mm_add_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_xor_si128",
+                "_mm_sub_epi8",
+            ]),
+            split(&[
+                "_mm256_setr_epi8",
+                "_mm256_and_si256",
+                "_mm256_set1_epi8",
+                "_mm256_srli_epi16",
+                "_mm256_add_epi8",
+                "_mm256_shuffle_epi8",
+                "_mm256_xor_si256",
+                "_mm256_sub_epi8",
+            ]),
+            native(&[
+                "_mm512_popcnt_epi8",
+                "_mm512_and_si512",
+                "_mm512_xor_si512",
+                "_mm512_set1_epi8",
+                "_mm512_sub_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "transpose_bits_16x16_u16x16",
+        "transpose_bits_16x16",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u64",
+                "veorq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vdupq_n_u64",
+                "vuzp1q_u8",
+                "vuzp2q_u8",
+                "vzip1q_u64",
+                "vzip2q_u64",
+                "vzip1q_u8",
+                "vzip2q_u8",
+            ]),
+            split(&[
+                "v128_and",
+                "v128_xor",
+                "u64x2_shr",
+                "u64x2_splat",
+                "u64x2_shl",
+                "u8x16_shuffle",
+                "u64x2_shuffle",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+                "_mm_setr_epi8",
+                "_mm_shuffle_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm256_setr_m128i",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            split(&[
+                "_mm256_extracti128_si256",
+                "_mm_set1_epi64x",
+                "_mm_gf2p8affine_epi64_epi8",
+                "_mm_permutex2var_epi8",
+                "_mm_setr_epi8",
+                "_mm_unpacklo_epi64",
+                "_mm_unpackhi_epi64",
+                "_mm256_setr_m128i",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+        ],
+    ),
+    op(
+        "transpose_bits_8x8_u64x2",
+        "transpose_bits_8x8",
+        "u64x2",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u64",
+                "veorq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vdupq_n_u64",
+            ]),
+            split(&[
+                "v128_and",
+                "v128_xor",
+                "u64x2_shr",
+                "u64x2_splat",
+                "u64x2_shl",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            native(&["_mm_set1_epi64x", "_mm_gf2p8affine_epi64_epi8"]),
+        ],
+    ),
+    op(
+        "transpose_bits_8x8_u64x4",
+        "transpose_bits_8x8",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u64",
+                "veorq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vdupq_n_u64",
+            ]),
+            split(&[
+                "v128_and",
+                "v128_xor",
+                "u64x2_shr",
+                "u64x2_splat",
+                "u64x2_shl",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            split(&[
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm256_set1_epi64x",
+                "_mm256_sll_epi64",
+            ]),
+            native(&["_mm256_set1_epi64x", "_mm256_gf2p8affine_epi64_epi8"]),
+        ],
+    ),
+    op(
+        "transpose_bits_8x8_u64x8",
+        "transpose_bits_8x8",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&[
+                "vandq_u64",
+                "veorq_u64",
+                "vshlq_u64",
+                "vdupq_n_s64",
+                "vdupq_n_u64",
+            ]),
+            split(&[
+                "v128_and",
+                "v128_xor",
+                "u64x2_shr",
+                "u64x2_splat",
+                "u64x2_shl",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            split(&[
+                "_mm_and_si128",
+                "_mm_xor_si128",
+                "_mm_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm_set1_epi64x",
+                "_mm_sll_epi64",
+            ]),
+            split(&[
+                "_mm256_and_si256",
+                "_mm256_xor_si256",
+                "_mm256_srl_epi64",
+                "_mm_cvtsi32_si128",
+                "_mm256_set1_epi64x",
+                "_mm256_sll_epi64",
+            ]),
+            native(&["_mm512_set1_epi64", "_mm512_gf2p8affine_epi64_epi8"]),
+        ],
+    ),
+    op(
+        "trunc_f32x16",
+        "trunc",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vrndq_f32"]),
+            split(&["f32x4_trunc"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            split(&["_mm256_round_ps"]),
+            native(&["_mm512_roundscale_ps"]),
+        ],
+    ),
+    op(
+        "trunc_f32x4",
+        "trunc",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vrndq_f32"]),
+            native(&["f32x4_trunc"]),
+            PORTABLE,
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+            native(&["_mm_round_ps"]),
+        ],
+    ),
+    op(
+        "trunc_f32x8",
+        "trunc",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vrndq_f32"]),
+            split(&["f32x4_trunc"]),
+            split(&[]),
+            split(&["_mm_round_ps"]),
+            native(&["_mm256_round_ps"]),
+            native(&["_mm256_round_ps"]),
+        ],
+    ),
+    op(
+        "trunc_f64x2",
+        "trunc",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vrndq_f64"]),
+            native(&["f64x2_trunc"]),
+            PORTABLE,
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+            native(&["_mm_round_pd"]),
+        ],
+    ),
+    op(
+        "trunc_f64x4",
+        "trunc",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vrndq_f64"]),
+            split(&["f64x2_trunc"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            native(&["_mm256_round_pd"]),
+            native(&["_mm256_round_pd"]),
+        ],
+    ),
+    op(
+        "trunc_f64x8",
+        "trunc",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vrndq_f64"]),
+            split(&["f64x2_trunc"]),
+            split(&[]),
+            split(&["_mm_round_pd"]),
+            split(&["_mm256_round_pd"]),
+            native(&["_mm512_roundscale_pd"]),
+        ],
+    ),
+    op(
+        "unzip_high_f32x16",
+        "unzip_high",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vuzp2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&[
+                "_mm256_permutevar8x32_ps",
+                "_mm256_setr_epi32",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm512_permutex2var_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_f32x4",
+        "unzip_high",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vuzp2q_f32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_shuffle_ps"]),
+            native(&["_mm_shuffle_ps"]),
+            native(&["_mm_shuffle_ps"]),
+            native(&["_mm_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "unzip_high_f32x8",
+        "unzip_high",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vuzp2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&["_mm_shuffle_ps"]),
+            native(&[
+                "_mm256_permutevar8x32_ps",
+                "_mm256_setr_epi32",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm256_permutex2var_ps", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_f64x2",
+        "unzip_high",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vuzp2q_f64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_shuffle_pd"]),
+            native(&["_mm_shuffle_pd"]),
+            native(&["_mm_shuffle_pd"]),
+            native(&["_mm_shuffle_pd"]),
+        ],
+    ),
+    op(
+        "unzip_high_f64x4",
+        "unzip_high",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vuzp2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm_shuffle_pd"]),
+            native(&["_mm256_permute4x64_pd", "_mm256_permute2f128_pd"]),
+            native(&["_mm256_permutex2var_pd", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_high_f64x8",
+        "unzip_high",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vuzp2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm256_permute4x64_pd", "_mm256_permute2f128_pd"]),
+            native(&["_mm512_permutex2var_pd", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "unzip_high_i16x16",
+        "unzip_high",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_high_i16x32",
+        "unzip_high",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_high_i16x8",
+        "unzip_high",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vuzp2q_s16"]),
+            native(&["u16x8_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi16", "_mm_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_high_i32x16",
+        "unzip_high",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_i32x4",
+        "unzip_high",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vuzp2q_s32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi32", "_mm_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_i32x8",
+        "unzip_high",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_i64x2",
+        "unzip_high",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vuzp2q_s64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi64", "_mm_set_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_high_i64x4",
+        "unzip_high",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpackhi_epi64"]),
+            split(&["_mm_unpackhi_epi64"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_high_i64x8",
+        "unzip_high",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpackhi_epi64"]),
+            split(&["_mm_unpackhi_epi64"]),
+            split(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "unzip_high_i8x16",
+        "unzip_high",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vuzp2q_s8"]),
+            native(&["u8x16_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi8", "_mm_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_high_i8x32",
+        "unzip_high",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_high_i8x64",
+        "unzip_high",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vuzp2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_high_u16x16",
+        "unzip_high",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_high_u16x32",
+        "unzip_high",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_high_u16x8",
+        "unzip_high",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vuzp2q_u16"]),
+            native(&["u16x8_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi16", "_mm_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_high_u32x16",
+        "unzip_high",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_u32x4",
+        "unzip_high",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vuzp2q_u32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi32", "_mm_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_u32x8",
+        "unzip_high",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_high_u64x2",
+        "unzip_high",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vuzp2q_u64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi64", "_mm_set_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_high_u64x4",
+        "unzip_high",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpackhi_epi64"]),
+            split(&["_mm_unpackhi_epi64"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_high_u64x8",
+        "unzip_high",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpackhi_epi64"]),
+            split(&["_mm_unpackhi_epi64"]),
+            split(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "unzip_high_u8x16",
+        "unzip_high",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vuzp2q_u8"]),
+            native(&["u8x16_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&["_mm_permutex2var_epi8", "_mm_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_high_u8x32",
+        "unzip_high",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_high_u8x64",
+        "unzip_high",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vuzp2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_low_f32x16",
+        "unzip_low",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&[
+                "_mm256_permutevar8x32_ps",
+                "_mm256_setr_epi32",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm512_permutex2var_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_f32x4",
+        "unzip_low",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vuzp1q_f32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_shuffle_ps"]),
+            native(&["_mm_shuffle_ps"]),
+            native(&["_mm_shuffle_ps"]),
+            native(&["_mm_shuffle_ps"]),
+        ],
+    ),
+    op(
+        "unzip_low_f32x8",
+        "unzip_low",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_ps"]),
+            split(&["_mm_shuffle_ps"]),
+            native(&[
+                "_mm256_permutevar8x32_ps",
+                "_mm256_setr_epi32",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm256_permutex2var_ps", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_f64x2",
+        "unzip_low",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vuzp1q_f64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_shuffle_pd"]),
+            native(&["_mm_shuffle_pd"]),
+            native(&["_mm_shuffle_pd"]),
+            native(&["_mm_shuffle_pd"]),
+        ],
+    ),
+    op(
+        "unzip_low_f64x4",
+        "unzip_low",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm_shuffle_pd"]),
+            native(&["_mm256_permute4x64_pd", "_mm256_permute2f128_pd"]),
+            native(&["_mm256_permutex2var_pd", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_low_f64x8",
+        "unzip_low",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm_shuffle_pd"]),
+            split(&["_mm256_permute4x64_pd", "_mm256_permute2f128_pd"]),
+            native(&["_mm512_permutex2var_pd", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "unzip_low_i16x16",
+        "unzip_low",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_low_i16x32",
+        "unzip_low",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_low_i16x8",
+        "unzip_low",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vuzp1q_s16"]),
+            native(&["u16x8_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi16", "_mm_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_low_i32x16",
+        "unzip_low",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            split(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_i32x4",
+        "unzip_low",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vuzp1q_s32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi32", "_mm_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_i32x8",
+        "unzip_low",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_i64x2",
+        "unzip_low",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vuzp1q_s64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi64", "_mm_set_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_low_i64x4",
+        "unzip_low",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64"]),
+            split(&["_mm_unpacklo_epi64"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_low_i64x8",
+        "unzip_low",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64"]),
+            split(&["_mm_unpacklo_epi64"]),
+            split(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "unzip_low_i8x16",
+        "unzip_low",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vuzp1q_s8"]),
+            native(&["u8x16_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi8", "_mm_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_low_i8x32",
+        "unzip_low",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_low_i8x64",
+        "unzip_low",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vuzp1q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_low_u16x16",
+        "unzip_low",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_low_u16x32",
+        "unzip_low",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_low_u16x8",
+        "unzip_low",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vuzp1q_u16"]),
+            native(&["u16x8_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi16", "_mm_setr_epi16"]),
+        ],
+    ),
+    op(
+        "unzip_low_u32x16",
+        "unzip_low",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            split(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_u32x4",
+        "unzip_low",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vuzp1q_u32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi32", "_mm_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_u32x8",
+        "unzip_low",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            split(&["_mm_shuffle_epi32", "_mm_unpacklo_epi64"]),
+            native(&[
+                "_mm256_permutevar8x32_epi32",
+                "_mm256_setr_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "unzip_low_u64x2",
+        "unzip_low",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vuzp1q_u64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi64", "_mm_set_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_low_u64x4",
+        "unzip_low",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64"]),
+            split(&["_mm_unpacklo_epi64"]),
+            native(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "unzip_low_u64x8",
+        "unzip_low",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64"]),
+            split(&["_mm_unpacklo_epi64"]),
+            split(&["_mm256_permute4x64_epi64", "_mm256_permute2x128_si256"]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "unzip_low_u8x16",
+        "unzip_low",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vuzp1q_u8"]),
+            native(&["u8x16_shuffle"]),
+            PORTABLE,
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&["_mm_permutex2var_epi8", "_mm_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_low_u8x32",
+        "unzip_low",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            native(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "unzip_low_u8x64",
+        "unzip_low",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vuzp1q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&[]),
+            split(&["_mm_setr_epi8", "_mm_shuffle_epi8", "_mm_unpacklo_epi64"]),
+            split(&[
+                "_mm256_permute4x64_epi64",
+                "_mm256_shuffle_epi8",
+                "_mm256_setr_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "widen_i16x16",
+        "widen",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vmovl_s16", "vget_low_s16", "vget_high_s16"]),
+            split(&["i32x4_extend_low_i16x8", "i32x4_extend_high_i16x8"]),
+            split(&[
+                "_mm_cmpgt_epi16",
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi16",
+                "_mm_unpackhi_epi16",
+            ]),
+            split(&["_mm_cvtepi16_epi32", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepi16_epi32"]),
+            native(&["_mm512_cvtepi16_epi32"]),
+        ],
+    ),
+    op(
+        "widen_i16x8",
+        "widen",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vmovl_s16", "vget_low_s16", "vget_high_s16"]),
+            native(&["i32x4_extend_low_i16x8", "i32x4_extend_high_i16x8"]),
+            native(&[
+                "_mm_cmpgt_epi16",
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi16",
+                "_mm_unpackhi_epi16",
+            ]),
+            native(&["_mm_cvtepi16_epi32", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepi16_epi32"]),
+            native(&["_mm256_cvtepi16_epi32"]),
+        ],
+    ),
+    op(
+        "widen_i32x4",
+        "widen",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vmovl_s32", "vget_low_s32", "vget_high_s32"]),
+            native(&["i64x2_extend_low_i32x4", "i64x2_extend_high_i32x4"]),
+            native(&[
+                "_mm_cmpgt_epi32",
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+            ]),
+            native(&["_mm_cvtepi32_epi64", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepi32_epi64"]),
+            native(&["_mm256_cvtepi32_epi64"]),
+        ],
+    ),
+    op(
+        "widen_i32x8",
+        "widen",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vmovl_s32", "vget_low_s32", "vget_high_s32"]),
+            split(&["i64x2_extend_low_i32x4", "i64x2_extend_high_i32x4"]),
+            split(&[
+                "_mm_cmpgt_epi32",
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+            ]),
+            split(&["_mm_cvtepi32_epi64", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepi32_epi64"]),
+            native(&["_mm512_cvtepi32_epi64"]),
+        ],
+    ),
+    op(
+        "widen_i8x16",
+        "widen",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vmovl_s8", "vget_low_s8", "vget_high_s8"]),
+            native(&["i16x8_extend_low_i8x16", "i16x8_extend_high_i8x16"]),
+            native(&[
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            native(&["_mm_cvtepi8_epi16", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepi8_epi16"]),
+            native(&["_mm256_cvtepi8_epi16"]),
+        ],
+    ),
+    op(
+        "widen_i8x32",
+        "widen",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vmovl_s8", "vget_low_s8", "vget_high_s8"]),
+            split(&["i16x8_extend_low_i8x16", "i16x8_extend_high_i8x16"]),
+            split(&[
+                "_mm_cmpgt_epi8",
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            split(&["_mm_cvtepi8_epi16", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepi8_epi16"]),
+            native(&["_mm512_cvtepi8_epi16"]),
+        ],
+    ),
+    op(
+        "widen_u16x16",
+        "widen",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vmovl_u16", "vget_low_u16", "vget_high_u16"]),
+            split(&["u32x4_extend_low_u16x8", "u32x4_extend_high_u16x8"]),
+            split(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi16",
+                "_mm_unpackhi_epi16",
+            ]),
+            split(&["_mm_cvtepu16_epi32", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepu16_epi32"]),
+            native(&["_mm512_cvtepu16_epi32"]),
+        ],
+    ),
+    op(
+        "widen_u16x8",
+        "widen",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vmovl_u16", "vget_low_u16", "vget_high_u16"]),
+            native(&["u32x4_extend_low_u16x8", "u32x4_extend_high_u16x8"]),
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi16",
+                "_mm_unpackhi_epi16",
+            ]),
+            native(&["_mm_cvtepu16_epi32", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepu16_epi32"]),
+            native(&["_mm256_cvtepu16_epi32"]),
+        ],
+    ),
+    op(
+        "widen_u32x4",
+        "widen",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vmovl_u32", "vget_low_u32", "vget_high_u32"]),
+            native(&["u64x2_extend_low_u32x4", "u64x2_extend_high_u32x4"]),
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+            ]),
+            native(&["_mm_cvtepu32_epi64", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepu32_epi64"]),
+            native(&["_mm256_cvtepu32_epi64"]),
+        ],
+    ),
+    op(
+        "widen_u32x8",
+        "widen",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vmovl_u32", "vget_low_u32", "vget_high_u32"]),
+            split(&["u64x2_extend_low_u32x4", "u64x2_extend_high_u32x4"]),
+            split(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi32",
+                "_mm_unpackhi_epi32",
+            ]),
+            split(&["_mm_cvtepu32_epi64", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepu32_epi64"]),
+            native(&["_mm512_cvtepu32_epi64"]),
+        ],
+    ),
+    op(
+        "widen_u8x16",
+        "widen",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vmovl_u8", "vget_low_u8", "vget_high_u8"]),
+            native(&["u16x8_extend_low_u8x16", "u16x8_extend_high_u8x16"]),
+            native(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            native(&["_mm_cvtepu8_epi16", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepu8_epi16"]),
+            native(&["_mm256_cvtepu8_epi16"]),
+        ],
+    ),
+    op(
+        "widen_u8x32",
+        "widen",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vmovl_u8", "vget_low_u8", "vget_high_u8"]),
+            split(&["u16x8_extend_low_u8x16", "u16x8_extend_high_u8x16"]),
+            split(&[
+                "_mm_setzero_si128",
+                "_mm_unpacklo_epi8",
+                "_mm_unpackhi_epi8",
+            ]),
+            split(&["_mm_cvtepu8_epi16", "_mm_srli_si128"]),
+            native(&["_mm256_cvtepu8_epi16"]),
+            native(&["_mm512_cvtepu8_epi16"]),
+        ],
+    ),
+    op(
+        "xor_i16x16",
+        "xor",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["veorq_s16"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_i16x32",
+        "xor",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["veorq_s16"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_i16x8",
+        "xor",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["veorq_s16"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_i32x16",
+        "xor",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["veorq_s32"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_i32x4",
+        "xor",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["veorq_s32"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_i32x8",
+        "xor",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["veorq_s32"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_i64x2",
+        "xor",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["veorq_s64"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_i64x4",
+        "xor",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["veorq_s64"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_i64x8",
+        "xor",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["veorq_s64"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_i8x16",
+        "xor",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["veorq_s8"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_i8x32",
+        "xor",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["veorq_s8"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_i8x64",
+        "xor",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["veorq_s8"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_mask16x16",
+        "xor",
+        "mask16x16",
+        [
+            PORTABLE,
+            split(&["veorq_s16"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask16x32",
+        "xor",
+        "mask16x32",
+        [
+            PORTABLE,
+            split(&["veorq_s16"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask16x8",
+        "xor",
+        "mask16x8",
+        [
+            PORTABLE,
+            native(&["veorq_s16"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask32x16",
+        "xor",
+        "mask32x16",
+        [
+            PORTABLE,
+            split(&["veorq_s32"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask32x4",
+        "xor",
+        "mask32x4",
+        [
+            PORTABLE,
+            native(&["veorq_s32"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask32x8",
+        "xor",
+        "mask32x8",
+        [
+            PORTABLE,
+            split(&["veorq_s32"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask64x2",
+        "xor",
+        "mask64x2",
+        [
+            PORTABLE,
+            native(&["veorq_s64"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask64x4",
+        "xor",
+        "mask64x4",
+        [
+            PORTABLE,
+            split(&["veorq_s64"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask64x8",
+        "xor",
+        "mask64x8",
+        [
+            PORTABLE,
+            split(&["veorq_s64"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask8x16",
+        "xor",
+        "mask8x16",
+        [
+            PORTABLE,
+            native(&["veorq_s8"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask8x32",
+        "xor",
+        "mask8x32",
+        [
+            PORTABLE,
+            split(&["veorq_s8"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_mask8x64",
+        "xor",
+        "mask8x64",
+        [
+            PORTABLE,
+            split(&["veorq_s8"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&[]),
+        ],
+    ),
+    op(
+        "xor_u16x16",
+        "xor",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["veorq_u16"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_u16x32",
+        "xor",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["veorq_u16"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_u16x8",
+        "xor",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["veorq_u16"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_u32x16",
+        "xor",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["veorq_u32"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_u32x4",
+        "xor",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["veorq_u32"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_u32x8",
+        "xor",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["veorq_u32"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_u64x2",
+        "xor",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["veorq_u64"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_u64x4",
+        "xor",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["veorq_u64"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_u64x8",
+        "xor",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["veorq_u64"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "xor_u8x16",
+        "xor",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["veorq_u8"]),
+            native(&["v128_xor"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+            native(&["_mm_xor_si128"]),
+        ],
+    ),
+    op(
+        "xor_u8x32",
+        "xor",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["veorq_u8"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            native(&["_mm256_xor_si256"]),
+            native(&["_mm256_xor_si256"]),
+        ],
+    ),
+    op(
+        "xor_u8x64",
+        "xor",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["veorq_u8"]),
+            split(&["v128_xor"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm_xor_si128"]),
+            split(&["_mm256_xor_si256"]),
+            native(&["_mm512_xor_si512"]),
+        ],
+    ),
+    op(
+        "zip_high_f32x16",
+        "zip_high",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_f32", "vzip2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&[
+                "_mm256_unpacklo_ps",
+                "_mm256_unpackhi_ps",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm512_permutex2var_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_f32x4",
+        "zip_high",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vzip2q_f32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_unpackhi_ps"]),
+            native(&["_mm_unpackhi_ps"]),
+            native(&["_mm_unpackhi_ps"]),
+            native(&["_mm_unpackhi_ps"]),
+        ],
+    ),
+    op(
+        "zip_high_f32x8",
+        "zip_high",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_f32", "vzip2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            native(&[
+                "_mm256_unpacklo_ps",
+                "_mm256_unpackhi_ps",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm256_permutex2var_ps", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_f64x2",
+        "zip_high",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vzip2q_f64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpackhi_pd"]),
+            native(&["_mm_unpackhi_pd"]),
+            native(&["_mm_unpackhi_pd"]),
+            native(&["_mm_unpackhi_pd"]),
+        ],
+    ),
+    op(
+        "zip_high_f64x4",
+        "zip_high",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_f64", "vzip2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            native(&[
+                "_mm256_unpacklo_pd",
+                "_mm256_unpackhi_pd",
+                "_mm256_permute2f128_pd",
+            ]),
+            native(&["_mm256_permutex2var_pd", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "zip_high_f64x8",
+        "zip_high",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_f64", "vzip2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_unpacklo_pd",
+                "_mm256_unpackhi_pd",
+                "_mm256_permute2f128_pd",
+            ]),
+            native(&["_mm512_permutex2var_pd", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "zip_high_i16x16",
+        "zip_high",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_s16", "vzip2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            native(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "zip_high_i16x32",
+        "zip_high",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_s16", "vzip2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "zip_high_i16x8",
+        "zip_high",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vzip2q_s16"]),
+            native(&["u16x8_shuffle"]),
+            native(&["_mm_unpackhi_epi16"]),
+            native(&["_mm_unpackhi_epi16"]),
+            native(&["_mm_unpackhi_epi16"]),
+            native(&["_mm_unpackhi_epi16"]),
+        ],
+    ),
+    op(
+        "zip_high_i32x16",
+        "zip_high",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_s32", "vzip2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_i32x4",
+        "zip_high",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vzip2q_s32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_unpackhi_epi32"]),
+            native(&["_mm_unpackhi_epi32"]),
+            native(&["_mm_unpackhi_epi32"]),
+            native(&["_mm_unpackhi_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_i32x8",
+        "zip_high",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_s32", "vzip2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            native(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_i64x2",
+        "zip_high",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vzip2q_s64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+        ],
+    ),
+    op(
+        "zip_high_i64x4",
+        "zip_high",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_s64", "vzip2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "zip_high_i64x8",
+        "zip_high",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_s64", "vzip2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "zip_high_i8x16",
+        "zip_high",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vzip2q_s8"]),
+            native(&["u8x16_shuffle"]),
+            native(&["_mm_unpackhi_epi8"]),
+            native(&["_mm_unpackhi_epi8"]),
+            native(&["_mm_unpackhi_epi8"]),
+            native(&["_mm_unpackhi_epi8"]),
+        ],
+    ),
+    op(
+        "zip_high_i8x32",
+        "zip_high",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_s8", "vzip2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            native(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "zip_high_i8x64",
+        "zip_high",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vzip1q_s8", "vzip2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "zip_high_u16x16",
+        "zip_high",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_u16", "vzip2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            native(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "zip_high_u16x32",
+        "zip_high",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_u16", "vzip2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "zip_high_u16x8",
+        "zip_high",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vzip2q_u16"]),
+            native(&["u16x8_shuffle"]),
+            native(&["_mm_unpackhi_epi16"]),
+            native(&["_mm_unpackhi_epi16"]),
+            native(&["_mm_unpackhi_epi16"]),
+            native(&["_mm_unpackhi_epi16"]),
+        ],
+    ),
+    op(
+        "zip_high_u32x16",
+        "zip_high",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_u32", "vzip2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_u32x4",
+        "zip_high",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vzip2q_u32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_unpackhi_epi32"]),
+            native(&["_mm_unpackhi_epi32"]),
+            native(&["_mm_unpackhi_epi32"]),
+            native(&["_mm_unpackhi_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_u32x8",
+        "zip_high",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_u32", "vzip2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            native(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_high_u64x2",
+        "zip_high",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vzip2q_u64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+            native(&["_mm_unpackhi_epi64"]),
+        ],
+    ),
+    op(
+        "zip_high_u64x4",
+        "zip_high",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_u64", "vzip2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "zip_high_u64x8",
+        "zip_high",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_u64", "vzip2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "zip_high_u8x16",
+        "zip_high",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vzip2q_u8"]),
+            native(&["u8x16_shuffle"]),
+            native(&["_mm_unpackhi_epi8"]),
+            native(&["_mm_unpackhi_epi8"]),
+            native(&["_mm_unpackhi_epi8"]),
+            native(&["_mm_unpackhi_epi8"]),
+        ],
+    ),
+    op(
+        "zip_high_u8x32",
+        "zip_high",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_u8", "vzip2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            native(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "zip_high_u8x64",
+        "zip_high",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vzip1q_u8", "vzip2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "zip_low_f32x16",
+        "zip_low",
+        "f32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_f32", "vzip2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&[
+                "_mm256_unpacklo_ps",
+                "_mm256_unpackhi_ps",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm512_permutex2var_ps", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_f32x4",
+        "zip_low",
+        "f32x4",
+        [
+            PORTABLE,
+            native(&["vzip1q_f32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_unpacklo_ps"]),
+            native(&["_mm_unpacklo_ps"]),
+            native(&["_mm_unpacklo_ps"]),
+            native(&["_mm_unpacklo_ps"]),
+        ],
+    ),
+    op(
+        "zip_low_f32x8",
+        "zip_low",
+        "f32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_f32", "vzip2q_f32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            split(&["_mm_unpacklo_ps", "_mm_unpackhi_ps"]),
+            native(&[
+                "_mm256_unpacklo_ps",
+                "_mm256_unpackhi_ps",
+                "_mm256_permute2f128_ps",
+            ]),
+            native(&["_mm256_permutex2var_ps", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_f64x2",
+        "zip_low",
+        "f64x2",
+        [
+            PORTABLE,
+            native(&["vzip1q_f64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_pd"]),
+            native(&["_mm_unpacklo_pd"]),
+            native(&["_mm_unpacklo_pd"]),
+            native(&["_mm_unpacklo_pd"]),
+        ],
+    ),
+    op(
+        "zip_low_f64x4",
+        "zip_low",
+        "f64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_f64", "vzip2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            native(&[
+                "_mm256_unpacklo_pd",
+                "_mm256_unpackhi_pd",
+                "_mm256_permute2f128_pd",
+            ]),
+            native(&["_mm256_permutex2var_pd", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "zip_low_f64x8",
+        "zip_low",
+        "f64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_f64", "vzip2q_f64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&["_mm_unpacklo_pd", "_mm_unpackhi_pd"]),
+            split(&[
+                "_mm256_unpacklo_pd",
+                "_mm256_unpackhi_pd",
+                "_mm256_permute2f128_pd",
+            ]),
+            native(&["_mm512_permutex2var_pd", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "zip_low_i16x16",
+        "zip_low",
+        "i16x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_s16", "vzip2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            native(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "zip_low_i16x32",
+        "zip_low",
+        "i16x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_s16", "vzip2q_s16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "zip_low_i16x8",
+        "zip_low",
+        "i16x8",
+        [
+            PORTABLE,
+            native(&["vzip1q_s16"]),
+            native(&["u16x8_shuffle"]),
+            native(&["_mm_unpacklo_epi16"]),
+            native(&["_mm_unpacklo_epi16"]),
+            native(&["_mm_unpacklo_epi16"]),
+            native(&["_mm_unpacklo_epi16"]),
+        ],
+    ),
+    op(
+        "zip_low_i32x16",
+        "zip_low",
+        "i32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_s32", "vzip2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_i32x4",
+        "zip_low",
+        "i32x4",
+        [
+            PORTABLE,
+            native(&["vzip1q_s32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_unpacklo_epi32"]),
+            native(&["_mm_unpacklo_epi32"]),
+            native(&["_mm_unpacklo_epi32"]),
+            native(&["_mm_unpacklo_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_i32x8",
+        "zip_low",
+        "i32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_s32", "vzip2q_s32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            native(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_i64x2",
+        "zip_low",
+        "i64x2",
+        [
+            PORTABLE,
+            native(&["vzip1q_s64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+        ],
+    ),
+    op(
+        "zip_low_i64x4",
+        "zip_low",
+        "i64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_s64", "vzip2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "zip_low_i64x8",
+        "zip_low",
+        "i64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_s64", "vzip2q_s64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "zip_low_i8x16",
+        "zip_low",
+        "i8x16",
+        [
+            PORTABLE,
+            native(&["vzip1q_s8"]),
+            native(&["u8x16_shuffle"]),
+            native(&["_mm_unpacklo_epi8"]),
+            native(&["_mm_unpacklo_epi8"]),
+            native(&["_mm_unpacklo_epi8"]),
+            native(&["_mm_unpacklo_epi8"]),
+        ],
+    ),
+    op(
+        "zip_low_i8x32",
+        "zip_low",
+        "i8x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_s8", "vzip2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            native(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "zip_low_i8x64",
+        "zip_low",
+        "i8x64",
+        [
+            PORTABLE,
+            split(&["vzip1q_s8", "vzip2q_s8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+    op(
+        "zip_low_u16x16",
+        "zip_low",
+        "u16x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_u16", "vzip2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            native(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi16", "_mm256_setr_epi16"]),
+        ],
+    ),
+    op(
+        "zip_low_u16x32",
+        "zip_low",
+        "u16x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_u16", "vzip2q_u16"]),
+            split(&["u16x8_shuffle"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&["_mm_unpacklo_epi16", "_mm_unpackhi_epi16"]),
+            split(&[
+                "_mm256_unpacklo_epi16",
+                "_mm256_unpackhi_epi16",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi16", "_mm512_set_epi16"]),
+        ],
+    ),
+    op(
+        "zip_low_u16x8",
+        "zip_low",
+        "u16x8",
+        [
+            PORTABLE,
+            native(&["vzip1q_u16"]),
+            native(&["u16x8_shuffle"]),
+            native(&["_mm_unpacklo_epi16"]),
+            native(&["_mm_unpacklo_epi16"]),
+            native(&["_mm_unpacklo_epi16"]),
+            native(&["_mm_unpacklo_epi16"]),
+        ],
+    ),
+    op(
+        "zip_low_u32x16",
+        "zip_low",
+        "u32x16",
+        [
+            PORTABLE,
+            split(&["vzip1q_u32", "vzip2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi32", "_mm512_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_u32x4",
+        "zip_low",
+        "u32x4",
+        [
+            PORTABLE,
+            native(&["vzip1q_u32"]),
+            native(&["u32x4_shuffle"]),
+            native(&["_mm_unpacklo_epi32"]),
+            native(&["_mm_unpacklo_epi32"]),
+            native(&["_mm_unpacklo_epi32"]),
+            native(&["_mm_unpacklo_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_u32x8",
+        "zip_low",
+        "u32x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_u32", "vzip2q_u32"]),
+            split(&["u32x4_shuffle"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            split(&["_mm_unpacklo_epi32", "_mm_unpackhi_epi32"]),
+            native(&[
+                "_mm256_unpacklo_epi32",
+                "_mm256_unpackhi_epi32",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi32", "_mm256_setr_epi32"]),
+        ],
+    ),
+    op(
+        "zip_low_u64x2",
+        "zip_low",
+        "u64x2",
+        [
+            PORTABLE,
+            native(&["vzip1q_u64"]),
+            native(&["u64x2_shuffle"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+            native(&["_mm_unpacklo_epi64"]),
+        ],
+    ),
+    op(
+        "zip_low_u64x4",
+        "zip_low",
+        "u64x4",
+        [
+            PORTABLE,
+            split(&["vzip1q_u64", "vzip2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            native(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi64", "_mm256_setr_epi64x"]),
+        ],
+    ),
+    op(
+        "zip_low_u64x8",
+        "zip_low",
+        "u64x8",
+        [
+            PORTABLE,
+            split(&["vzip1q_u64", "vzip2q_u64"]),
+            split(&["u64x2_shuffle"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&["_mm_unpacklo_epi64", "_mm_unpackhi_epi64"]),
+            split(&[
+                "_mm256_unpacklo_epi64",
+                "_mm256_unpackhi_epi64",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi64", "_mm512_setr_epi64"]),
+        ],
+    ),
+    op(
+        "zip_low_u8x16",
+        "zip_low",
+        "u8x16",
+        [
+            PORTABLE,
+            native(&["vzip1q_u8"]),
+            native(&["u8x16_shuffle"]),
+            native(&["_mm_unpacklo_epi8"]),
+            native(&["_mm_unpacklo_epi8"]),
+            native(&["_mm_unpacklo_epi8"]),
+            native(&["_mm_unpacklo_epi8"]),
+        ],
+    ),
+    op(
+        "zip_low_u8x32",
+        "zip_low",
+        "u8x32",
+        [
+            PORTABLE,
+            split(&["vzip1q_u8", "vzip2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            native(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm256_permutex2var_epi8", "_mm256_setr_epi8"]),
+        ],
+    ),
+    op(
+        "zip_low_u8x64",
+        "zip_low",
+        "u8x64",
+        [
+            PORTABLE,
+            split(&["vzip1q_u8", "vzip2q_u8"]),
+            split(&["u8x16_shuffle"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&["_mm_unpacklo_epi8", "_mm_unpackhi_epi8"]),
+            split(&[
+                "_mm256_unpacklo_epi8",
+                "_mm256_unpackhi_epi8",
+                "_mm256_permute2x128_si256",
+            ]),
+            native(&["_mm512_permutex2var_epi8", "_mm512_set_epi8"]),
+        ],
+    ),
+];
+const fn op(
+    method: &'static str,
+    op: &'static str,
+    vector: &'static str,
+    levels: [Lowering; 7],
+) -> OpLowering {
+    OpLowering {
+        method,
+        op,
+        vector,
+        levels,
+    }
+}
+const PORTABLE: Lowering = Lowering {
+    kind: LoweringKind::Portable,
+    intrinsics: &[],
+};
+const fn native(intrinsics: &'static [&'static str]) -> Lowering {
+    Lowering {
+        kind: LoweringKind::Native,
+        intrinsics,
+    }
+}
+const fn split(intrinsics: &'static [&'static str]) -> Lowering {
+    Lowering {
+        kind: LoweringKind::Split,
+        intrinsics,
+    }
+}
@@ -82,6 +82,9 @@ impl Simd for Neon {
     type mask16s = mask16x8<Self>;
     type mask32s = mask32x4<Self>;
     type mask64s = mask64x2<Self>;
+    const NATIVE_BITS: usize = 128;
+    const FUSED_MUL_ADD: bool = true;
+    const SOFT_FLOAT: bool = false;
     #[inline(always)]
     fn level(self) -> Level {
         Level::Neon(self)
@@ -225,6 +228,62 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_f32x4<const LANE: usize>(self, a: f32x4<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, lane: usize) -> f32 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_f32::<0>(a),
+                    1 => vgetq_lane_f32::<1>(a),
+                    2 => vgetq_lane_f32::<2>(a),
+                    3 => vgetq_lane_f32::<3>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_f32x4<const LANE: usize>(self, a: f32x4<Self>, value: f32) -> f32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, value: f32, lane: usize) -> f32x4<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_f32::<0>(value, a),
+                    1 => vsetq_lane_f32::<1>(value, a),
+                    2 => vsetq_lane_f32::<2>(value, a),
+                    3 => vsetq_lane_f32::<3>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x4(self, a: f32x4<Self>, index: usize) -> f32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_f32x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f32x4(self, a: f32x4<Self>, indices: u8x16<Self>) -> f32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -496,6 +555,30 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn nmul_add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, b: f32x4<Neon>, c: f32x4<Neon>) -> f32x4<Neon> {
+                vfmsq_f32(c.into(), a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, b: f32x4<Neon>, c: f32x4<Neon>) -> f32x4<Neon> {
+                vfmsq_f32(vnegq_f32(c.into()), a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.mul_add_f32x4(a, b, c)
+    }
+    #[inline(always)]
     fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -563,6 +646,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_f32x4(
+        self,
+        a: mask32x4<Self>,
+        b: f32x4<Self>,
+        c: f32x4<Self>,
+    ) -> f32x4<Self> {
+        self.select_f32x4(a, b, c)
+    }
+    #[inline(always)]
     fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
         f32x8 {
             val: crate::support::Aligned256(float32x4x2_t(a.val.0, b.val.0)),
@@ -638,6 +730,42 @@ impl Simd for Neon {
         self.cvt_i32_f32x4(a)
     }
     #[inline(always)]
+    fn dot2_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, b: f32x4<Neon>) -> f32x4<Neon> {
+                let p = vmulq_f32(a.into(), b.into());
+                vaddq_f32(p, vrev64q_f32(p)).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn dot3_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, b: f32x4<Neon>) -> f32x4<Neon> {
+                let p = vmulq_f32(a.into(), b.into());
+                let p = vsetq_lane_f32::<3>(0.0, p);
+                let sums = vpaddq_f32(p, p);
+                vpaddq_f32(sums, sums).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn dot4_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x4<Neon>, b: f32x4<Neon>) -> f32x4<Neon> {
+                let p = vmulq_f32(a.into(), b.into());
+                let sums = vpaddq_f32(p, p);
+                vpaddq_f32(sums, sums).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn splat_i8x16(self, val: i8) -> i8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -816,6 +944,86 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i8x16<const LANE: usize>(self, a: i8x16<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>, lane: usize) -> i8 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_s8::<0>(a),
+                    1 => vgetq_lane_s8::<1>(a),
+                    2 => vgetq_lane_s8::<2>(a),
+                    3 => vgetq_lane_s8::<3>(a),
+                    4 => vgetq_lane_s8::<4>(a),
+                    5 => vgetq_lane_s8::<5>(a),
+                    6 => vgetq_lane_s8::<6>(a),
+                    7 => vgetq_lane_s8::<7>(a),
+                    8 => vgetq_lane_s8::<8>(a),
+                    9 => vgetq_lane_s8::<9>(a),
+                    10 => vgetq_lane_s8::<10>(a),
+                    11 => vgetq_lane_s8::<11>(a),
+                    12 => vgetq_lane_s8::<12>(a),
+                    13 => vgetq_lane_s8::<13>(a),
+                    14 => vgetq_lane_s8::<14>(a),
+                    15 => vgetq_lane_s8::<15>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i8x16<const LANE: usize>(self, a: i8x16<Self>, value: i8) -> i8x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>, value: i8, lane: usize) -> i8x16<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_s8::<0>(value, a),
+                    1 => vsetq_lane_s8::<1>(value, a),
+                    2 => vsetq_lane_s8::<2>(value, a),
+                    3 => vsetq_lane_s8::<3>(value, a),
+                    4 => vsetq_lane_s8::<4>(value, a),
+                    5 => vsetq_lane_s8::<5>(value, a),
+                    6 => vsetq_lane_s8::<6>(value, a),
+                    7 => vsetq_lane_s8::<7>(value, a),
+                    8 => vsetq_lane_s8::<8>(value, a),
+                    9 => vsetq_lane_s8::<9>(value, a),
+                    10 => vsetq_lane_s8::<10>(value, a),
+                    11 => vsetq_lane_s8::<11>(value, a),
+                    12 => vsetq_lane_s8::<12>(value, a),
+                    13 => vsetq_lane_s8::<13>(value, a),
+                    14 => vsetq_lane_s8::<14>(value, a),
+                    15 => vsetq_lane_s8::<15>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x16(self, a: i8x16<Self>, index: usize) -> i8 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i8x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i8x16(self, a: i8x16<Self>, indices: u8x16<Self>) -> i8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -940,6 +1148,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>, b: i8x16<Neon>) -> i8x16<Neon> {
+                vshlq_s8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1061,6 +1279,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_i8x16(
+        self,
+        a: mask8x16<Self>,
+        b: i8x16<Self>,
+        c: i8x16<Self>,
+    ) -> i8x16<Self> {
+        self.select_i8x16(a, b, c)
+    }
+    #[inline(always)]
     fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1081,6 +1308,52 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>, b: i8x16<Neon>) -> i8x16<Neon> {
+                vqaddq_s8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>, b: i8x16<Neon>) -> i8x16<Neon> {
+                vqsubq_s8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>) -> i8x16<Neon> {
+                vcntq_s8(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>) -> i8x16<Neon> {
+                vclzq_s8(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        self.count_ones_i8x16(
+            self.and_i8x16(self.not_i8x16(a), self.sub_i8x16(a, self.splat_i8x16(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
         i8x32 {
             val: crate::support::Aligned256(int8x16x2_t(a.val.0, b.val.0)),
@@ -1098,6 +1371,28 @@ impl Simd for Neon {
         kernel(self, a)
     }
     #[inline(always)]
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>) -> i16x16<Neon> {
+                let low = vmovl_s8(vget_low_s8(a.into()));
+                let high = vmovl_s8(vget_high_s8(a.into()));
+                int16x8x2_t(low, high).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x16(self, a: i8x16<Self>) -> i32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i8x16<Neon>) -> i32 {
+                i32::from(vaddlvq_s8(a.into()))
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1296,6 +1591,86 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u8x16<const LANE: usize>(self, a: u8x16<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, lane: usize) -> u8 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_u8::<0>(a),
+                    1 => vgetq_lane_u8::<1>(a),
+                    2 => vgetq_lane_u8::<2>(a),
+                    3 => vgetq_lane_u8::<3>(a),
+                    4 => vgetq_lane_u8::<4>(a),
+                    5 => vgetq_lane_u8::<5>(a),
+                    6 => vgetq_lane_u8::<6>(a),
+                    7 => vgetq_lane_u8::<7>(a),
+                    8 => vgetq_lane_u8::<8>(a),
+                    9 => vgetq_lane_u8::<9>(a),
+                    10 => vgetq_lane_u8::<10>(a),
+                    11 => vgetq_lane_u8::<11>(a),
+                    12 => vgetq_lane_u8::<12>(a),
+                    13 => vgetq_lane_u8::<13>(a),
+                    14 => vgetq_lane_u8::<14>(a),
+                    15 => vgetq_lane_u8::<15>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u8x16<const LANE: usize>(self, a: u8x16<Self>, value: u8) -> u8x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, value: u8, lane: usize) -> u8x16<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_u8::<0>(value, a),
+                    1 => vsetq_lane_u8::<1>(value, a),
+                    2 => vsetq_lane_u8::<2>(value, a),
+                    3 => vsetq_lane_u8::<3>(value, a),
+                    4 => vsetq_lane_u8::<4>(value, a),
+                    5 => vsetq_lane_u8::<5>(value, a),
+                    6 => vsetq_lane_u8::<6>(value, a),
+                    7 => vsetq_lane_u8::<7>(value, a),
+                    8 => vsetq_lane_u8::<8>(value, a),
+                    9 => vsetq_lane_u8::<9>(value, a),
+                    10 => vsetq_lane_u8::<10>(value, a),
+                    11 => vsetq_lane_u8::<11>(value, a),
+                    12 => vsetq_lane_u8::<12>(value, a),
+                    13 => vsetq_lane_u8::<13>(value, a),
+                    14 => vsetq_lane_u8::<14>(value, a),
+                    15 => vsetq_lane_u8::<15>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x16(self, a: u8x16<Self>, index: usize) -> u8 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u8x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u8x16(self, a: u8x16<Self>, indices: u8x16<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1420,6 +1795,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_u8x16(self, a: u8x16<Self>, b: i8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, b: i8x16<Neon>) -> u8x16<Neon> {
+                vshlq_u8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1541,6 +1926,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_u8x16(
+        self,
+        a: mask8x16<Self>,
+        b: u8x16<Self>,
+        c: u8x16<Self>,
+    ) -> u8x16<Self> {
+        self.select_u8x16(a, b, c)
+    }
+    #[inline(always)]
     fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1561,6 +1955,52 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, b: u8x16<Neon>) -> u8x16<Neon> {
+                vqaddq_u8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, b: u8x16<Neon>) -> u8x16<Neon> {
+                vqsubq_u8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>) -> u8x16<Neon> {
+                vcntq_u8(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>) -> u8x16<Neon> {
+                vclzq_u8(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        self.count_ones_u8x16(
+            self.and_u8x16(self.not_u8x16(a), self.sub_u8x16(a, self.splat_u8x16(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
         u8x32 {
             val: crate::support::Aligned256(uint8x16x2_t(a.val.0, b.val.0)),
@@ -1580,6 +2020,52 @@ impl Simd for Neon {
         kernel(self, a)
     }
     #[inline(always)]
+    fn abd_accumulate_u8x16(
+        self,
+        acc: u16x16<Self>,
+        a: u8x16<Self>,
+        b: u8x16<Self>,
+    ) -> u16x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Neon,
+                acc: u16x16<Neon>,
+                a: u8x16<Neon>,
+                b: u8x16<Neon>,
+            ) -> u16x16<Neon> {
+                let acc: uint16x8x2_t = acc.into();
+                let (a, b) = (a.into(), b.into());
+                uint16x8x2_t(
+                    vabal_u8(acc.0, vget_low_u8(a), vget_low_u8(b)),
+                    vabal_high_u8(acc.1, a, b),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, acc, a, b)
+    }
+    #[inline(always)]
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, b: u8x16<Neon>) -> u8x16<Neon> {
+                vrhaddq_u8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x16(self, a: u8x16<Self>) -> u32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>) -> u32 {
+                u32::from(vaddlvq_u8(a.into()))
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
     fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1590,6 +2076,51 @@ impl Simd for Neon {
         kernel(self, a)
     }
     #[inline(always)]
+    fn compress_bytes_u8x16(self, a: u8x16<Self>, mask: mask8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, mask: mask8x16<Neon>) -> u8x16<Neon> {
+                let (shuffle, merge) =
+                    crate::support::compress_bytes_indices(token.to_bitmask_mask8x16(mask));
+                let packed = vqtbl1q_u8(a.into(), token.load_array_u8x16(shuffle).into());
+                vqtbl1q_u8(packed, token.load_array_u8x16(merge).into()).simd_into(token)
+            }
+        );
+        kernel(self, a, mask)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x16(self, a: u8x16<Self>, mask: mask8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, mask: mask8x16<Neon>) -> u8x16<Neon> {
+                let indices = crate::support::expand_bytes_indices(token.to_bitmask_mask8x16(mask));
+                vqtbl1q_u8(a.into(), token.load_array_u8x16(indices).into()).simd_into(token)
+            }
+        );
+        kernel(self, a, mask)
+    }
+    #[inline(always)]
+    fn table_lookup_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u8x16<Neon>, b: u8x16<Neon>) -> u8x16<Neon> {
+                vqtbl1q_u8(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x16<const FACTOR: u8>(self, a: u8x16<Self>) -> u8x16<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<16>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x16(
+            self.load_array_u8x16(low),
+            self.and_u8x16(a, self.splat_u8x16(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x16(self.load_array_u8x16(high), self.shr_u8x16(a, 4));
+        self.xor_u8x16(low, high)
+    }
+    #[inline(always)]
     fn splat_mask8x16(self, val: bool) -> mask8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1612,6 +2143,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int8x16_t, [i8; 16usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1720,6 +2258,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        self.select_mask8x16(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -1923,6 +2470,70 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i16x8<const LANE: usize>(self, a: i16x8<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>, lane: usize) -> i16 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_s16::<0>(a),
+                    1 => vgetq_lane_s16::<1>(a),
+                    2 => vgetq_lane_s16::<2>(a),
+                    3 => vgetq_lane_s16::<3>(a),
+                    4 => vgetq_lane_s16::<4>(a),
+                    5 => vgetq_lane_s16::<5>(a),
+                    6 => vgetq_lane_s16::<6>(a),
+                    7 => vgetq_lane_s16::<7>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i16x8<const LANE: usize>(self, a: i16x8<Self>, value: i16) -> i16x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>, value: i16, lane: usize) -> i16x8<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_s16::<0>(value, a),
+                    1 => vsetq_lane_s16::<1>(value, a),
+                    2 => vsetq_lane_s16::<2>(value, a),
+                    3 => vsetq_lane_s16::<3>(value, a),
+                    4 => vsetq_lane_s16::<4>(value, a),
+                    5 => vsetq_lane_s16::<5>(value, a),
+                    6 => vsetq_lane_s16::<6>(value, a),
+                    7 => vsetq_lane_s16::<7>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x8(self, a: i16x8<Self>, index: usize) -> i16 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i16x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i16x8(self, a: i16x8<Self>, indices: u8x16<Self>) -> i16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2047,6 +2658,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>, b: i16x8<Neon>) -> i16x8<Neon> {
+                vshlq_s16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2168,6 +2789,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_i16x8(
+        self,
+        a: mask16x8<Self>,
+        b: i16x8<Self>,
+        c: i16x8<Self>,
+    ) -> i16x8<Self> {
+        self.select_i16x8(a, b, c)
+    }
+    #[inline(always)]
     fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2188,6 +2818,53 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>, b: i16x8<Neon>) -> i16x8<Neon> {
+                vqaddq_s16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>, b: i16x8<Neon>) -> i16x8<Neon> {
+                vqsubq_s16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>) -> i16x8<Neon> {
+                vreinterpretq_s16_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_s16(a.into()))))
+                    .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>) -> i16x8<Neon> {
+                vclzq_s16(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        self.count_ones_i16x8(
+            self.and_i16x8(self.not_i16x8(a), self.sub_i16x8(a, self.splat_i16x8(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
         i16x16 {
             val: crate::support::Aligned256(int16x8x2_t(a.val.0, b.val.0)),
@@ -2205,6 +2882,28 @@ impl Simd for Neon {
         kernel(self, a)
     }
     #[inline(always)]
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>) -> i32x8<Neon> {
+                let low = vmovl_s16(vget_low_s16(a.into()));
+                let high = vmovl_s16(vget_high_s16(a.into()));
+                int32x4x2_t(low, high).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x8(self, a: i16x8<Self>) -> i32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x8<Neon>) -> i32 {
+                vaddlvq_s16(a.into())
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2371,6 +3070,70 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u16x8<const LANE: usize>(self, a: u16x8<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>, lane: usize) -> u16 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_u16::<0>(a),
+                    1 => vgetq_lane_u16::<1>(a),
+                    2 => vgetq_lane_u16::<2>(a),
+                    3 => vgetq_lane_u16::<3>(a),
+                    4 => vgetq_lane_u16::<4>(a),
+                    5 => vgetq_lane_u16::<5>(a),
+                    6 => vgetq_lane_u16::<6>(a),
+                    7 => vgetq_lane_u16::<7>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u16x8<const LANE: usize>(self, a: u16x8<Self>, value: u16) -> u16x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>, value: u16, lane: usize) -> u16x8<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_u16::<0>(value, a),
+                    1 => vsetq_lane_u16::<1>(value, a),
+                    2 => vsetq_lane_u16::<2>(value, a),
+                    3 => vsetq_lane_u16::<3>(value, a),
+                    4 => vsetq_lane_u16::<4>(value, a),
+                    5 => vsetq_lane_u16::<5>(value, a),
+                    6 => vsetq_lane_u16::<6>(value, a),
+                    7 => vsetq_lane_u16::<7>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x8(self, a: u16x8<Self>, index: usize) -> u16 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u16x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u16x8(self, a: u16x8<Self>, indices: u8x16<Self>) -> u16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2495,6 +3258,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_u16x8(self, a: u16x8<Self>, b: i16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>, b: i16x8<Neon>) -> u16x8<Neon> {
+                vshlq_u16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2616,6 +3389,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_u16x8(
+        self,
+        a: mask16x8<Self>,
+        b: u16x8<Self>,
+        c: u16x8<Self>,
+    ) -> u16x8<Self> {
+        self.select_u16x8(a, b, c)
+    }
+    #[inline(always)]
     fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2636,6 +3418,52 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>, b: u16x8<Neon>) -> u16x8<Neon> {
+                vqaddq_u16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>, b: u16x8<Neon>) -> u16x8<Neon> {
+                vqsubq_u16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>) -> u16x8<Neon> {
+                vpaddlq_u8(vcntq_u8(vreinterpretq_u8_u16(a.into()))).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>) -> u16x8<Neon> {
+                vclzq_u16(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        self.count_ones_u16x8(
+            self.and_u16x8(self.not_u16x8(a), self.sub_u16x8(a, self.splat_u16x8(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
         u16x16 {
             val: crate::support::Aligned256(uint16x8x2_t(a.val.0, b.val.0)),
@@ -2643,6 +3471,59 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>) -> u32x8<Neon> {
+                let low = vmovl_u16(vget_low_u16(a.into()));
+                let high = vmovl_u16(vget_high_u16(a.into()));
+                uint32x4x2_t(low, high).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u16x8(self, acc: u32x8<Self>, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(
+                token: Neon,
+                acc: u32x8<Neon>,
+                a: u16x8<Neon>,
+                b: u16x8<Neon>,
+            ) -> u32x8<Neon> {
+                let acc: uint32x4x2_t = acc.into();
+                let (a, b) = (a.into(), b.into());
+                uint32x4x2_t(
+                    vabal_u16(acc.0, vget_low_u16(a), vget_low_u16(b)),
+                    vabal_high_u16(acc.1, a, b),
+                )
+                .simd_into(token)
+            }
+        );
+        kernel(self, acc, a, b)
+    }
+    #[inline(always)]
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>, b: u16x8<Neon>) -> u16x8<Neon> {
+                vrhaddq_u16(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x8(self, a: u16x8<Self>) -> u32 {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x8<Neon>) -> u32 {
+                vaddlvq_u16(a.into())
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2685,6 +3566,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int16x8_t, [i16; 8usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2785,6 +3673,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        self.select_mask16x8(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -2972,6 +3869,62 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i32x4<const LANE: usize>(self, a: i32x4<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>, lane: usize) -> i32 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_s32::<0>(a),
+                    1 => vgetq_lane_s32::<1>(a),
+                    2 => vgetq_lane_s32::<2>(a),
+                    3 => vgetq_lane_s32::<3>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i32x4<const LANE: usize>(self, a: i32x4<Self>, value: i32) -> i32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>, value: i32, lane: usize) -> i32x4<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_s32::<0>(value, a),
+                    1 => vsetq_lane_s32::<1>(value, a),
+                    2 => vsetq_lane_s32::<2>(value, a),
+                    3 => vsetq_lane_s32::<3>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x4(self, a: i32x4<Self>, index: usize) -> i32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_i32x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i32x4(self, a: i32x4<Self>, indices: u8x16<Self>) -> i32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3096,6 +4049,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>, b: i32x4<Neon>) -> i32x4<Neon> {
+                vshlq_s32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3217,6 +4180,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_i32x4(
+        self,
+        a: mask32x4<Self>,
+        b: i32x4<Self>,
+        c: i32x4<Self>,
+    ) -> i32x4<Self> {
+        self.select_i32x4(a, b, c)
+    }
+    #[inline(always)]
     fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3237,23 +4209,84 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
-    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
-        i32x8 {
-            val: crate::support::Aligned256(int32x4x2_t(a.val.0, b.val.0)),
-            simd: self,
-        }
-    }
-    #[inline(always)]
-    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+    fn saturating_add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
         crate::kernel!(
             #[inline(always)]
-            fn kernel(token: Neon, a: i32x4<Neon>) -> i32x4<Neon> {
+            fn kernel(token: Neon, a: i32x4<Neon>, b: i32x4<Neon>) -> i32x4<Neon> {
+                vqaddq_s32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>, b: i32x4<Neon>) -> i32x4<Neon> {
+                vqsubq_s32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>) -> i32x4<Neon> {
+                vreinterpretq_s32_u32(vpaddlq_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_s32(
+                    a.into(),
+                )))))
+                .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>) -> i32x4<Neon> {
+                vclzq_s32(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        self.count_ones_i32x4(
+            self.and_i32x4(self.not_i32x4(a), self.sub_i32x4(a, self.splat_i32x4(1))),
+        )
+    }
+    #[inline(always)]
+    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
+        i32x8 {
+            val: crate::support::Aligned256(int32x4x2_t(a.val.0, b.val.0)),
+            simd: self,
+        }
+    }
+    #[inline(always)]
+    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>) -> i32x4<Neon> {
                 vnegq_s32(a.into()).simd_into(token)
             }
         );
         kernel(self, a)
     }
     #[inline(always)]
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i32x4<Neon>) -> i64x4<Neon> {
+                let low = vmovl_s32(vget_low_s32(a.into()));
+                let high = vmovl_s32(vget_high_s32(a.into()));
+                int64x2x2_t(low, high).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3414,6 +4447,62 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u32x4<const LANE: usize>(self, a: u32x4<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>, lane: usize) -> u32 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_u32::<0>(a),
+                    1 => vgetq_lane_u32::<1>(a),
+                    2 => vgetq_lane_u32::<2>(a),
+                    3 => vgetq_lane_u32::<3>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u32x4<const LANE: usize>(self, a: u32x4<Self>, value: u32) -> u32x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>, value: u32, lane: usize) -> u32x4<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_u32::<0>(value, a),
+                    1 => vsetq_lane_u32::<1>(value, a),
+                    2 => vsetq_lane_u32::<2>(value, a),
+                    3 => vsetq_lane_u32::<3>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x4(self, a: u32x4<Self>, index: usize) -> u32 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_u32x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u32x4(self, a: u32x4<Self>, indices: u8x16<Self>) -> u32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3538,6 +4627,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_u32x4(self, a: u32x4<Self>, b: i32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>, b: i32x4<Neon>) -> u32x4<Neon> {
+                vshlq_u32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3659,6 +4758,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_u32x4(
+        self,
+        a: mask32x4<Self>,
+        b: u32x4<Self>,
+        c: u32x4<Self>,
+    ) -> u32x4<Self> {
+        self.select_u32x4(a, b, c)
+    }
+    #[inline(always)]
     fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3679,6 +4787,52 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn saturating_add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>, b: u32x4<Neon>) -> u32x4<Neon> {
+                vqaddq_u32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>, b: u32x4<Neon>) -> u32x4<Neon> {
+                vqsubq_u32(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>) -> u32x4<Neon> {
+                vpaddlq_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_u32(a.into())))).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>) -> u32x4<Neon> {
+                vclzq_u32(a.into()).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        self.count_ones_u32x4(
+            self.and_u32x4(self.not_u32x4(a), self.sub_u32x4(a, self.splat_u32x4(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
         u32x8 {
             val: crate::support::Aligned256(uint32x4x2_t(a.val.0, b.val.0)),
@@ -3686,6 +4840,18 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u32x4<Neon>) -> u64x4<Neon> {
+                let low = vmovl_u32(vget_low_u32(a.into()));
+                let high = vmovl_u32(vget_high_u32(a.into()));
+                uint64x2x2_t(low, high).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3728,6 +4894,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int32x4_t, [i32; 4usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -3827,6 +5000,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        self.select_mask32x4(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -4006,6 +5188,58 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_f64x2<const LANE: usize>(self, a: f64x2<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f64x2<Neon>, lane: usize) -> f64 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_f64::<0>(a),
+                    1 => vgetq_lane_f64::<1>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_f64x2<const LANE: usize>(self, a: f64x2<Self>, value: f64) -> f64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f64x2<Neon>, value: f64, lane: usize) -> f64x2<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_f64::<0>(value, a),
+                    1 => vsetq_lane_f64::<1>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x2(self, a: f64x2<Self>, index: usize) -> f64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_f64x2(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f64x2(self, a: f64x2<Self>, indices: u8x16<Self>) -> f64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -4277,6 +5511,30 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn nmul_add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f64x2<Neon>, b: f64x2<Neon>, c: f64x2<Neon>) -> f64x2<Neon> {
+                vfmsq_f64(c.into(), a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f64x2<Neon>, b: f64x2<Neon>, c: f64x2<Neon>) -> f64x2<Neon> {
+                vfmsq_f64(vnegq_f64(c.into()), a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b, c)
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.mul_add_f64x2(a, b, c)
+    }
+    #[inline(always)]
     fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -4344,6 +5602,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_f64x2(
+        self,
+        a: mask64x2<Self>,
+        b: f64x2<Self>,
+        c: f64x2<Self>,
+    ) -> f64x2<Self> {
+        self.select_f64x2(a, b, c)
+    }
+    #[inline(always)]
     fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self> {
         f64x4 {
             val: crate::support::Aligned256(float64x2x2_t(a.val.0, b.val.0)),
@@ -4483,6 +5750,58 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i64x2<const LANE: usize>(self, a: i64x2<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i64x2<Neon>, lane: usize) -> i64 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_s64::<0>(a),
+                    1 => vgetq_lane_s64::<1>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_i64x2<const LANE: usize>(self, a: i64x2<Self>, value: i64) -> i64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i64x2<Neon>, value: i64, lane: usize) -> i64x2<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_s64::<0>(value, a),
+                    1 => vsetq_lane_s64::<1>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x2(self, a: i64x2<Self>, index: usize) -> i64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_i64x2(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i64x2(self, a: i64x2<Self>, indices: u8x16<Self>) -> i64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -4605,6 +5924,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i64x2<Neon>, b: i64x2<Neon>) -> i64x2<Neon> {
+                vshlq_s64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -4726,6 +6055,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_i64x2(
+        self,
+        a: mask64x2<Self>,
+        b: i64x2<Self>,
+        c: i64x2<Self>,
+    ) -> i64x2<Self> {
+        self.select_i64x2(a, b, c)
+    }
+    #[inline(always)]
     fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
         [
             i64::min(a[0usize], b[0usize]),
@@ -4742,6 +6080,55 @@ impl Simd for Neon {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i64x2<Neon>, b: i64x2<Neon>) -> i64x2<Neon> {
+                vqaddq_s64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i64x2<Neon>, b: i64x2<Neon>) -> i64x2<Neon> {
+                vqsubq_s64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i64x2<Neon>) -> i64x2<Neon> {
+                vreinterpretq_s64_u64(vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(vcntq_u8(
+                    vreinterpretq_u8_s64(a.into()),
+                )))))
+                .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 1u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 2u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 4u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 8u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 16u32));
+        let a = self.or_i64x2(a, self.shr_i64x2(a, 32u32));
+        self.count_ones_i64x2(self.not_i64x2(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        self.count_ones_i64x2(
+            self.and_i64x2(self.not_i64x2(a), self.sub_i64x2(a, self.splat_i64x2(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self> {
         i64x4 {
             val: crate::support::Aligned256(int64x2x2_t(a.val.0, b.val.0)),
@@ -4901,12 +6288,64 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
-    fn swizzle_dyn_within_blocks_u64x2(self, a: u64x2<Self>, indices: u8x16<Self>) -> u64x2<Self> {
+    fn extract_u64x2<const LANE: usize>(self, a: u64x2<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
         crate::kernel!(
             #[inline(always)]
-            fn kernel(token: Neon, a: u64x2<Neon>, indices: u8x16<Neon>) -> u64x2<Neon> {
-                let result = vqtbl1q_u8(token.cvt_to_bytes_u64x2(a).val.0, indices.into());
-                token.cvt_from_bytes_u64x2(u8x16 {
+            fn kernel(token: Neon, a: u64x2<Neon>, lane: usize) -> u64 {
+                let a = a.into();
+                match lane {
+                    0 => vgetq_lane_u64::<0>(a),
+                    1 => vgetq_lane_u64::<1>(a),
+                    _ => unreachable!(),
+                }
+            }
+        );
+        kernel(self, a, LANE)
+    }
+    #[inline(always)]
+    fn insert_u64x2<const LANE: usize>(self, a: u64x2<Self>, value: u64) -> u64x2<Self> {
+        const {
+            assert!(
+                LANE < 2usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u64x2<Neon>, value: u64, lane: usize) -> u64x2<Neon> {
+                let a = a.into();
+                match lane {
+                    0 => vsetq_lane_u64::<0>(value, a),
+                    1 => vsetq_lane_u64::<1>(value, a),
+                    _ => unreachable!(),
+                }
+                .simd_into(token)
+            }
+        );
+        kernel(self, a, value, LANE)
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x2(self, a: u64x2<Self>, index: usize) -> u64 {
+        assert!(
+            index < 2usize,
+            "lane index {index} is out of bounds for {} lanes",
+            2usize
+        );
+        self.as_array_ref_u64x2(&a)[index]
+    }
+    #[inline(always)]
+    fn swizzle_dyn_within_blocks_u64x2(self, a: u64x2<Self>, indices: u8x16<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u64x2<Neon>, indices: u8x16<Neon>) -> u64x2<Neon> {
+                let result = vqtbl1q_u8(token.cvt_to_bytes_u64x2(a).val.0, indices.into());
+                token.cvt_from_bytes_u64x2(u8x16 {
                     val: crate::support::Aligned128(result),
                     simd: token,
                 })
@@ -5023,6 +6462,16 @@ impl Simd for Neon {
         kernel(self, a, b)
     }
     #[inline(always)]
+    fn shift_signed_u64x2(self, a: u64x2<Self>, b: i64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u64x2<Neon>, b: i64x2<Neon>) -> u64x2<Neon> {
+                vshlq_u64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
     fn simd_eq_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -5144,6 +6593,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_u64x2(
+        self,
+        a: mask64x2<Self>,
+        b: u64x2<Self>,
+        c: u64x2<Self>,
+    ) -> u64x2<Self> {
+        self.select_u64x2(a, b, c)
+    }
+    #[inline(always)]
     fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
         [
             u64::min(a[0usize], b[0usize]),
@@ -5160,6 +6618,55 @@ impl Simd for Neon {
         .simd_into(self)
     }
     #[inline(always)]
+    fn saturating_add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u64x2<Neon>, b: u64x2<Neon>) -> u64x2<Neon> {
+                vqaddq_u64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u64x2<Neon>, b: u64x2<Neon>) -> u64x2<Neon> {
+                vqsubq_u64(a.into(), b.into()).simd_into(token)
+            }
+        );
+        kernel(self, a, b)
+    }
+    #[inline(always)]
+    fn count_ones_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u64x2<Neon>) -> u64x2<Neon> {
+                vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_u64(
+                    a.into(),
+                )))))
+                .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 1u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 2u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 4u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 8u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 16u32));
+        let a = self.or_u64x2(a, self.shr_u64x2(a, 32u32));
+        self.count_ones_u64x2(self.not_u64x2(a))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        self.count_ones_u64x2(
+            self.and_u64x2(self.not_u64x2(a), self.sub_u64x2(a, self.splat_u64x2(1))),
+        )
+    }
+    #[inline(always)]
     fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self> {
         u64x4 {
             val: crate::support::Aligned256(uint64x2x2_t(a.val.0, b.val.0)),
@@ -5187,6 +6694,24 @@ impl Simd for Neon {
         kernel(self, a)
     }
     #[inline(always)]
+    fn transpose_bits_8x8_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 7)),
+            self.splat_u64x2(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 7));
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 14)),
+            self.splat_u64x2(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 14));
+        let t = self.and_u64x2(
+            self.xor_u64x2(a, self.shr_u64x2(a, 28)),
+            self.splat_u64x2(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x2(self.xor_u64x2(a, t), self.shl_u64x2(t, 28))
+    }
+    #[inline(always)]
     fn splat_mask64x2(self, val: bool) -> mask64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -5209,6 +6734,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int64x2_t, [i64; 2usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -5307,6 +6839,15 @@ impl Simd for Neon {
         kernel(self, a, b, c)
     }
     #[inline(always)]
+    fn select_relaxed_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        self.select_mask64x2(a, b, c)
+    }
+    #[inline(always)]
     fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -5531,6 +7072,57 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_f32x8<const LANE: usize>(self, a: f32x8<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x8(a);
+        match LANE {
+            0 => self.extract_f32x4::<0>(a0),
+            1 => self.extract_f32x4::<1>(a0),
+            2 => self.extract_f32x4::<2>(a0),
+            3 => self.extract_f32x4::<3>(a0),
+            4 => self.extract_f32x4::<0>(a1),
+            5 => self.extract_f32x4::<1>(a1),
+            6 => self.extract_f32x4::<2>(a1),
+            7 => self.extract_f32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f32x8<const LANE: usize>(self, a: f32x8<Self>, value: f32) -> f32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x8(a);
+        match LANE {
+            0 => self.combine_f32x4(self.insert_f32x4::<0>(a0, value), a1),
+            1 => self.combine_f32x4(self.insert_f32x4::<1>(a0, value), a1),
+            2 => self.combine_f32x4(self.insert_f32x4::<2>(a0, value), a1),
+            3 => self.combine_f32x4(self.insert_f32x4::<3>(a0, value), a1),
+            4 => self.combine_f32x4(a0, self.insert_f32x4::<0>(a1, value)),
+            5 => self.combine_f32x4(a0, self.insert_f32x4::<1>(a1, value)),
+            6 => self.combine_f32x4(a0, self.insert_f32x4::<2>(a1, value)),
+            7 => self.combine_f32x4(a0, self.insert_f32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x8(self, a: f32x8<Self>, index: usize) -> f32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_f32x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f32x8(self, a: f32x8<Self>, indices: u8x32<Self>) -> f32x8<Self> {
         let (a0, a1) = self.split_f32x8(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -5723,6 +7315,36 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn nmul_add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.nmul_add_f32x4(a0, b0, c0),
+            self.nmul_add_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.nmul_sub_f32x4(a0, b0, c0),
+            self.nmul_sub_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_relaxed_f32x4(a0, b0, c0),
+            self.mul_add_relaxed_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
         let (a0, a1) = self.split_f32x8(a);
         self.combine_f32x4(self.floor_f32x4(a0), self.floor_f32x4(a1))
@@ -5758,6 +7380,21 @@ impl Simd for Neon {
         self.combine_f32x4(self.select_f32x4(a0, b0, c0), self.select_f32x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f32x8(
+        self,
+        a: mask32x8<Self>,
+        b: f32x8<Self>,
+        c: f32x8<Self>,
+    ) -> f32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.select_relaxed_f32x4(a0, b0, c0),
+            self.select_relaxed_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
         f32x16 {
             val: crate::support::Aligned512(float32x4x4_t(
@@ -5835,6 +7472,24 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn dot2_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.dot2_f32x4(a0, b0), self.dot2_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn dot3_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.dot3_f32x4(a0, b0), self.dot3_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn dot4_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.dot4_f32x4(a0, b0), self.dot4_f32x4(a1, b1))
+    }
+    #[inline(always)]
     fn splat_i8x32(self, val: i8) -> i8x32<Self> {
         let half = self.splat_i8x16(val);
         self.combine_i8x16(half, half)
@@ -6098,6 +7753,105 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i8x32<const LANE: usize>(self, a: i8x32<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x32(a);
+        match LANE {
+            0 => self.extract_i8x16::<0>(a0),
+            1 => self.extract_i8x16::<1>(a0),
+            2 => self.extract_i8x16::<2>(a0),
+            3 => self.extract_i8x16::<3>(a0),
+            4 => self.extract_i8x16::<4>(a0),
+            5 => self.extract_i8x16::<5>(a0),
+            6 => self.extract_i8x16::<6>(a0),
+            7 => self.extract_i8x16::<7>(a0),
+            8 => self.extract_i8x16::<8>(a0),
+            9 => self.extract_i8x16::<9>(a0),
+            10 => self.extract_i8x16::<10>(a0),
+            11 => self.extract_i8x16::<11>(a0),
+            12 => self.extract_i8x16::<12>(a0),
+            13 => self.extract_i8x16::<13>(a0),
+            14 => self.extract_i8x16::<14>(a0),
+            15 => self.extract_i8x16::<15>(a0),
+            16 => self.extract_i8x16::<0>(a1),
+            17 => self.extract_i8x16::<1>(a1),
+            18 => self.extract_i8x16::<2>(a1),
+            19 => self.extract_i8x16::<3>(a1),
+            20 => self.extract_i8x16::<4>(a1),
+            21 => self.extract_i8x16::<5>(a1),
+            22 => self.extract_i8x16::<6>(a1),
+            23 => self.extract_i8x16::<7>(a1),
+            24 => self.extract_i8x16::<8>(a1),
+            25 => self.extract_i8x16::<9>(a1),
+            26 => self.extract_i8x16::<10>(a1),
+            27 => self.extract_i8x16::<11>(a1),
+            28 => self.extract_i8x16::<12>(a1),
+            29 => self.extract_i8x16::<13>(a1),
+            30 => self.extract_i8x16::<14>(a1),
+            31 => self.extract_i8x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i8x32<const LANE: usize>(self, a: i8x32<Self>, value: i8) -> i8x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x32(a);
+        match LANE {
+            0 => self.combine_i8x16(self.insert_i8x16::<0>(a0, value), a1),
+            1 => self.combine_i8x16(self.insert_i8x16::<1>(a0, value), a1),
+            2 => self.combine_i8x16(self.insert_i8x16::<2>(a0, value), a1),
+            3 => self.combine_i8x16(self.insert_i8x16::<3>(a0, value), a1),
+            4 => self.combine_i8x16(self.insert_i8x16::<4>(a0, value), a1),
+            5 => self.combine_i8x16(self.insert_i8x16::<5>(a0, value), a1),
+            6 => self.combine_i8x16(self.insert_i8x16::<6>(a0, value), a1),
+            7 => self.combine_i8x16(self.insert_i8x16::<7>(a0, value), a1),
+            8 => self.combine_i8x16(self.insert_i8x16::<8>(a0, value), a1),
+            9 => self.combine_i8x16(self.insert_i8x16::<9>(a0, value), a1),
+            10 => self.combine_i8x16(self.insert_i8x16::<10>(a0, value), a1),
+            11 => self.combine_i8x16(self.insert_i8x16::<11>(a0, value), a1),
+            12 => self.combine_i8x16(self.insert_i8x16::<12>(a0, value), a1),
+            13 => self.combine_i8x16(self.insert_i8x16::<13>(a0, value), a1),
+            14 => self.combine_i8x16(self.insert_i8x16::<14>(a0, value), a1),
+            15 => self.combine_i8x16(self.insert_i8x16::<15>(a0, value), a1),
+            16 => self.combine_i8x16(a0, self.insert_i8x16::<0>(a1, value)),
+            17 => self.combine_i8x16(a0, self.insert_i8x16::<1>(a1, value)),
+            18 => self.combine_i8x16(a0, self.insert_i8x16::<2>(a1, value)),
+            19 => self.combine_i8x16(a0, self.insert_i8x16::<3>(a1, value)),
+            20 => self.combine_i8x16(a0, self.insert_i8x16::<4>(a1, value)),
+            21 => self.combine_i8x16(a0, self.insert_i8x16::<5>(a1, value)),
+            22 => self.combine_i8x16(a0, self.insert_i8x16::<6>(a1, value)),
+            23 => self.combine_i8x16(a0, self.insert_i8x16::<7>(a1, value)),
+            24 => self.combine_i8x16(a0, self.insert_i8x16::<8>(a1, value)),
+            25 => self.combine_i8x16(a0, self.insert_i8x16::<9>(a1, value)),
+            26 => self.combine_i8x16(a0, self.insert_i8x16::<10>(a1, value)),
+            27 => self.combine_i8x16(a0, self.insert_i8x16::<11>(a1, value)),
+            28 => self.combine_i8x16(a0, self.insert_i8x16::<12>(a1, value)),
+            29 => self.combine_i8x16(a0, self.insert_i8x16::<13>(a1, value)),
+            30 => self.combine_i8x16(a0, self.insert_i8x16::<14>(a1, value)),
+            31 => self.combine_i8x16(a0, self.insert_i8x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x32(self, a: i8x32<Self>, index: usize) -> i8 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_i8x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i8x32(self, a: i8x32<Self>, indices: u8x32<Self>) -> i8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -6170,6 +7924,15 @@ impl Simd for Neon {
         self.combine_i8x16(self.shrv_i8x16(a0, b0), self.shrv_i8x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.shift_signed_i8x16(a0, b0),
+            self.shift_signed_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         let (b0, b1) = self.split_i8x32(b);
@@ -6257,6 +8020,21 @@ impl Simd for Neon {
         self.combine_i8x16(self.select_i8x16(a0, b0, c0), self.select_i8x16(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i8x32(
+        self,
+        a: mask8x32<Self>,
+        b: i8x32<Self>,
+        c: i8x32<Self>,
+    ) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        let (c0, c1) = self.split_i8x32(c);
+        self.combine_i8x16(
+            self.select_relaxed_i8x16(a0, b0, c0),
+            self.select_relaxed_i8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         let (b0, b1) = self.split_i8x32(b);
@@ -6269,6 +8047,39 @@ impl Simd for Neon {
         self.combine_i8x16(self.max_i8x16(a0, b0), self.max_i8x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_add_i8x16(a0, b0),
+            self.saturating_add_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_sub_i8x16(a0, b0),
+            self.saturating_sub_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.count_ones_i8x16(a0), self.count_ones_i8x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.leading_zeros_i8x16(a0), self.leading_zeros_i8x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.trailing_zeros_i8x16(a0), self.trailing_zeros_i8x16(a1))
+    }
+    #[inline(always)]
     fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
         i8x64 {
             val: crate::support::Aligned512(int8x16x4_t(
@@ -6296,6 +8107,16 @@ impl Simd for Neon {
         self.combine_i8x16(self.neg_i8x16(a0), self.neg_i8x16(a1))
     }
     #[inline(always)]
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x16(self.widen_i8x16(a0), self.widen_i8x16(a1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i8x32(self, a: i8x32<Self>) -> i32 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_add_widened_i8x16(a0) + self.reduce_add_widened_i8x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_i8x32(a);
         self.combine_u8x16(self.reinterpret_u8_i8x16(a0), self.reinterpret_u8_i8x16(a1))
@@ -6572,6 +8393,105 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u8x32<const LANE: usize>(self, a: u8x32<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x32(a);
+        match LANE {
+            0 => self.extract_u8x16::<0>(a0),
+            1 => self.extract_u8x16::<1>(a0),
+            2 => self.extract_u8x16::<2>(a0),
+            3 => self.extract_u8x16::<3>(a0),
+            4 => self.extract_u8x16::<4>(a0),
+            5 => self.extract_u8x16::<5>(a0),
+            6 => self.extract_u8x16::<6>(a0),
+            7 => self.extract_u8x16::<7>(a0),
+            8 => self.extract_u8x16::<8>(a0),
+            9 => self.extract_u8x16::<9>(a0),
+            10 => self.extract_u8x16::<10>(a0),
+            11 => self.extract_u8x16::<11>(a0),
+            12 => self.extract_u8x16::<12>(a0),
+            13 => self.extract_u8x16::<13>(a0),
+            14 => self.extract_u8x16::<14>(a0),
+            15 => self.extract_u8x16::<15>(a0),
+            16 => self.extract_u8x16::<0>(a1),
+            17 => self.extract_u8x16::<1>(a1),
+            18 => self.extract_u8x16::<2>(a1),
+            19 => self.extract_u8x16::<3>(a1),
+            20 => self.extract_u8x16::<4>(a1),
+            21 => self.extract_u8x16::<5>(a1),
+            22 => self.extract_u8x16::<6>(a1),
+            23 => self.extract_u8x16::<7>(a1),
+            24 => self.extract_u8x16::<8>(a1),
+            25 => self.extract_u8x16::<9>(a1),
+            26 => self.extract_u8x16::<10>(a1),
+            27 => self.extract_u8x16::<11>(a1),
+            28 => self.extract_u8x16::<12>(a1),
+            29 => self.extract_u8x16::<13>(a1),
+            30 => self.extract_u8x16::<14>(a1),
+            31 => self.extract_u8x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u8x32<const LANE: usize>(self, a: u8x32<Self>, value: u8) -> u8x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x32(a);
+        match LANE {
+            0 => self.combine_u8x16(self.insert_u8x16::<0>(a0, value), a1),
+            1 => self.combine_u8x16(self.insert_u8x16::<1>(a0, value), a1),
+            2 => self.combine_u8x16(self.insert_u8x16::<2>(a0, value), a1),
+            3 => self.combine_u8x16(self.insert_u8x16::<3>(a0, value), a1),
+            4 => self.combine_u8x16(self.insert_u8x16::<4>(a0, value), a1),
+            5 => self.combine_u8x16(self.insert_u8x16::<5>(a0, value), a1),
+            6 => self.combine_u8x16(self.insert_u8x16::<6>(a0, value), a1),
+            7 => self.combine_u8x16(self.insert_u8x16::<7>(a0, value), a1),
+            8 => self.combine_u8x16(self.insert_u8x16::<8>(a0, value), a1),
+            9 => self.combine_u8x16(self.insert_u8x16::<9>(a0, value), a1),
+            10 => self.combine_u8x16(self.insert_u8x16::<10>(a0, value), a1),
+            11 => self.combine_u8x16(self.insert_u8x16::<11>(a0, value), a1),
+            12 => self.combine_u8x16(self.insert_u8x16::<12>(a0, value), a1),
+            13 => self.combine_u8x16(self.insert_u8x16::<13>(a0, value), a1),
+            14 => self.combine_u8x16(self.insert_u8x16::<14>(a0, value), a1),
+            15 => self.combine_u8x16(self.insert_u8x16::<15>(a0, value), a1),
+            16 => self.combine_u8x16(a0, self.insert_u8x16::<0>(a1, value)),
+            17 => self.combine_u8x16(a0, self.insert_u8x16::<1>(a1, value)),
+            18 => self.combine_u8x16(a0, self.insert_u8x16::<2>(a1, value)),
+            19 => self.combine_u8x16(a0, self.insert_u8x16::<3>(a1, value)),
+            20 => self.combine_u8x16(a0, self.insert_u8x16::<4>(a1, value)),
+            21 => self.combine_u8x16(a0, self.insert_u8x16::<5>(a1, value)),
+            22 => self.combine_u8x16(a0, self.insert_u8x16::<6>(a1, value)),
+            23 => self.combine_u8x16(a0, self.insert_u8x16::<7>(a1, value)),
+            24 => self.combine_u8x16(a0, self.insert_u8x16::<8>(a1, value)),
+            25 => self.combine_u8x16(a0, self.insert_u8x16::<9>(a1, value)),
+            26 => self.combine_u8x16(a0, self.insert_u8x16::<10>(a1, value)),
+            27 => self.combine_u8x16(a0, self.insert_u8x16::<11>(a1, value)),
+            28 => self.combine_u8x16(a0, self.insert_u8x16::<12>(a1, value)),
+            29 => self.combine_u8x16(a0, self.insert_u8x16::<13>(a1, value)),
+            30 => self.combine_u8x16(a0, self.insert_u8x16::<14>(a1, value)),
+            31 => self.combine_u8x16(a0, self.insert_u8x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x32(self, a: u8x32<Self>, index: usize) -> u8 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_u8x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u8x32(self, a: u8x32<Self>, indices: u8x32<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u8x32(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -6644,6 +8564,15 @@ impl Simd for Neon {
         self.combine_u8x16(self.shrv_u8x16(a0, b0), self.shrv_u8x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u8x32(self, a: u8x32<Self>, b: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_u8x16(
+            self.shift_signed_u8x16(a0, b0),
+            self.shift_signed_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
         let (a0, a1) = self.split_u8x32(a);
         let (b0, b1) = self.split_u8x32(b);
@@ -6731,6 +8660,21 @@ impl Simd for Neon {
         self.combine_u8x16(self.select_u8x16(a0, b0, c0), self.select_u8x16(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u8x32(
+        self,
+        a: mask8x32<Self>,
+        b: u8x32<Self>,
+        c: u8x32<Self>,
+    ) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        let (c0, c1) = self.split_u8x32(c);
+        self.combine_u8x16(
+            self.select_relaxed_u8x16(a0, b0, c0),
+            self.select_relaxed_u8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u8x32(a);
         let (b0, b1) = self.split_u8x32(b);
@@ -6743,6 +8687,39 @@ impl Simd for Neon {
         self.combine_u8x16(self.max_u8x16(a0, b0), self.max_u8x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_add_u8x16(a0, b0),
+            self.saturating_add_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_sub_u8x16(a0, b0),
+            self.saturating_sub_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.count_ones_u8x16(a0), self.count_ones_u8x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.leading_zeros_u8x16(a0), self.leading_zeros_u8x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.trailing_zeros_u8x16(a0), self.trailing_zeros_u8x16(a1))
+    }
+    #[inline(always)]
     fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
         u8x64 {
             val: crate::support::Aligned512(uint8x16x4_t(
@@ -6770,6 +8747,32 @@ impl Simd for Neon {
         self.combine_u16x16(self.widen_u8x16(a0), self.widen_u8x16(a1))
     }
     #[inline(always)]
+    fn abd_accumulate_u8x32(
+        self,
+        acc: u16x32<Self>,
+        a: u8x32<Self>,
+        b: u8x32<Self>,
+    ) -> u16x32<Self> {
+        let (acc0, acc1) = self.split_u16x32(acc);
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u16x16(
+            self.abd_accumulate_u8x16(acc0, a0, b0),
+            self.abd_accumulate_u8x16(acc1, a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.avg_round_u8x16(a0, b0), self.avg_round_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x32(self, a: u8x32<Self>) -> u32 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_add_widened_u8x16(a0) + self.reduce_add_widened_u8x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
         let (a0, a1) = self.split_u8x32(a);
         self.combine_u32x4(
@@ -6778,6 +8781,49 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn compress_bytes_u8x32(self, a: u8x32<Self>, mask: mask8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (mask0, mask1) = self.split_mask8x32(mask);
+        let count = self.to_bitmask_mask8x16(mask0).count_ones() as usize;
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.as_array_u8x16(self.compress_bytes_u8x16(a0, mask0)));
+        result[count..count + 16]
+            .copy_from_slice(&self.as_array_u8x16(self.compress_bytes_u8x16(a1, mask1)));
+        self.load_array_u8x32(result)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x32(self, a: u8x32<Self>, mask: mask8x32<Self>) -> u8x32<Self> {
+        let (mask0, mask1) = self.split_mask8x32(mask);
+        let count = self.to_bitmask_mask8x16(mask0).count_ones() as usize;
+        let lanes = self.as_array_u8x32(a);
+        let (a0, _) = self.split_u8x32(a);
+        let a1 = self.load_array_u8x16(core::array::from_fn(|i| lanes[count + i]));
+        self.combine_u8x16(
+            self.expand_bytes_u8x16(a0, mask0),
+            self.expand_bytes_u8x16(a1, mask1),
+        )
+    }
+    #[inline(always)]
+    fn table_lookup_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.table_lookup_u8x16(a0, b0),
+            self.table_lookup_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x32<const FACTOR: u8>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<32>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x32(
+            self.load_array_u8x32(low),
+            self.and_u8x32(a, self.splat_u8x32(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x32(self.load_array_u8x32(high), self.shr_u8x32(a, 4));
+        self.xor_u8x32(low, high)
+    }
+    #[inline(always)]
     fn splat_mask8x32(self, val: bool) -> mask8x32<Self> {
         let half = self.splat_mask8x16(val);
         self.combine_mask8x16(half, half)
@@ -6794,6 +8840,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int8x16x2_t, [i8; 32usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self> {
+        i8x32 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self> {
         let lo = self.from_bitmask_mask8x16(bits);
         let hi = self.from_bitmask_mask8x16(bits >> 16usize);
@@ -6856,6 +8909,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        let (c0, c1) = self.split_mask8x32(c);
+        self.combine_mask8x16(
+            self.select_relaxed_mask8x16(a0, b0, c0),
+            self.select_relaxed_mask8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
         let (a0, a1) = self.split_mask8x32(a);
         let (b0, b1) = self.split_mask8x32(b);
@@ -7103,6 +9171,73 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i16x16<const LANE: usize>(self, a: i16x16<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x16(a);
+        match LANE {
+            0 => self.extract_i16x8::<0>(a0),
+            1 => self.extract_i16x8::<1>(a0),
+            2 => self.extract_i16x8::<2>(a0),
+            3 => self.extract_i16x8::<3>(a0),
+            4 => self.extract_i16x8::<4>(a0),
+            5 => self.extract_i16x8::<5>(a0),
+            6 => self.extract_i16x8::<6>(a0),
+            7 => self.extract_i16x8::<7>(a0),
+            8 => self.extract_i16x8::<0>(a1),
+            9 => self.extract_i16x8::<1>(a1),
+            10 => self.extract_i16x8::<2>(a1),
+            11 => self.extract_i16x8::<3>(a1),
+            12 => self.extract_i16x8::<4>(a1),
+            13 => self.extract_i16x8::<5>(a1),
+            14 => self.extract_i16x8::<6>(a1),
+            15 => self.extract_i16x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i16x16<const LANE: usize>(self, a: i16x16<Self>, value: i16) -> i16x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x16(a);
+        match LANE {
+            0 => self.combine_i16x8(self.insert_i16x8::<0>(a0, value), a1),
+            1 => self.combine_i16x8(self.insert_i16x8::<1>(a0, value), a1),
+            2 => self.combine_i16x8(self.insert_i16x8::<2>(a0, value), a1),
+            3 => self.combine_i16x8(self.insert_i16x8::<3>(a0, value), a1),
+            4 => self.combine_i16x8(self.insert_i16x8::<4>(a0, value), a1),
+            5 => self.combine_i16x8(self.insert_i16x8::<5>(a0, value), a1),
+            6 => self.combine_i16x8(self.insert_i16x8::<6>(a0, value), a1),
+            7 => self.combine_i16x8(self.insert_i16x8::<7>(a0, value), a1),
+            8 => self.combine_i16x8(a0, self.insert_i16x8::<0>(a1, value)),
+            9 => self.combine_i16x8(a0, self.insert_i16x8::<1>(a1, value)),
+            10 => self.combine_i16x8(a0, self.insert_i16x8::<2>(a1, value)),
+            11 => self.combine_i16x8(a0, self.insert_i16x8::<3>(a1, value)),
+            12 => self.combine_i16x8(a0, self.insert_i16x8::<4>(a1, value)),
+            13 => self.combine_i16x8(a0, self.insert_i16x8::<5>(a1, value)),
+            14 => self.combine_i16x8(a0, self.insert_i16x8::<6>(a1, value)),
+            15 => self.combine_i16x8(a0, self.insert_i16x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x16(self, a: i16x16<Self>, index: usize) -> i16 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i16x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i16x16(
         self,
         a: i16x16<Self>,
@@ -7179,6 +9314,15 @@ impl Simd for Neon {
         self.combine_i16x8(self.shrv_i16x8(a0, b0), self.shrv_i16x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.shift_signed_i16x8(a0, b0),
+            self.shift_signed_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
         let (a0, a1) = self.split_i16x16(a);
         let (b0, b1) = self.split_i16x16(b);
@@ -7266,6 +9410,21 @@ impl Simd for Neon {
         self.combine_i16x8(self.select_i16x8(a0, b0, c0), self.select_i16x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i16x16(
+        self,
+        a: mask16x16<Self>,
+        b: i16x16<Self>,
+        c: i16x16<Self>,
+    ) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        let (c0, c1) = self.split_i16x16(c);
+        self.combine_i16x8(
+            self.select_relaxed_i16x8(a0, b0, c0),
+            self.select_relaxed_i16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
         let (a0, a1) = self.split_i16x16(a);
         let (b0, b1) = self.split_i16x16(b);
@@ -7278,6 +9437,39 @@ impl Simd for Neon {
         self.combine_i16x8(self.max_i16x8(a0, b0), self.max_i16x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_add_i16x8(a0, b0),
+            self.saturating_add_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_sub_i16x8(a0, b0),
+            self.saturating_sub_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.count_ones_i16x8(a0), self.count_ones_i16x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.leading_zeros_i16x8(a0), self.leading_zeros_i16x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.trailing_zeros_i16x8(a0), self.trailing_zeros_i16x8(a1))
+    }
+    #[inline(always)]
     fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
         i16x32 {
             val: crate::support::Aligned512(int16x8x4_t(
@@ -7305,6 +9497,38 @@ impl Simd for Neon {
         self.combine_i16x8(self.neg_i16x8(a0), self.neg_i16x8(a1))
     }
     #[inline(always)]
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x8(self.widen_i16x8(a0), self.widen_i16x8(a1))
+    }
+    #[inline(always)]
+    fn pack_saturate_i8_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x16<Neon>) -> i8x16<Neon> {
+                let a: int16x8x2_t = a.into();
+                vcombine_s8(vqmovn_s16(a.0), vqmovn_s16(a.1)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_i16x16(self, a: i16x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: i16x16<Neon>) -> u8x16<Neon> {
+                let a: int16x8x2_t = a.into();
+                vcombine_u8(vqmovun_s16(a.0), vqmovun_s16(a.1)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x16(self, a: i16x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_add_widened_i16x8(a0) + self.reduce_add_widened_i16x8(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_i16x16(a);
         self.combine_u8x16(self.reinterpret_u8_i16x8(a0), self.reinterpret_u8_i16x8(a1))
@@ -7517,6 +9741,73 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u16x16<const LANE: usize>(self, a: u16x16<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x16(a);
+        match LANE {
+            0 => self.extract_u16x8::<0>(a0),
+            1 => self.extract_u16x8::<1>(a0),
+            2 => self.extract_u16x8::<2>(a0),
+            3 => self.extract_u16x8::<3>(a0),
+            4 => self.extract_u16x8::<4>(a0),
+            5 => self.extract_u16x8::<5>(a0),
+            6 => self.extract_u16x8::<6>(a0),
+            7 => self.extract_u16x8::<7>(a0),
+            8 => self.extract_u16x8::<0>(a1),
+            9 => self.extract_u16x8::<1>(a1),
+            10 => self.extract_u16x8::<2>(a1),
+            11 => self.extract_u16x8::<3>(a1),
+            12 => self.extract_u16x8::<4>(a1),
+            13 => self.extract_u16x8::<5>(a1),
+            14 => self.extract_u16x8::<6>(a1),
+            15 => self.extract_u16x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u16x16<const LANE: usize>(self, a: u16x16<Self>, value: u16) -> u16x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x16(a);
+        match LANE {
+            0 => self.combine_u16x8(self.insert_u16x8::<0>(a0, value), a1),
+            1 => self.combine_u16x8(self.insert_u16x8::<1>(a0, value), a1),
+            2 => self.combine_u16x8(self.insert_u16x8::<2>(a0, value), a1),
+            3 => self.combine_u16x8(self.insert_u16x8::<3>(a0, value), a1),
+            4 => self.combine_u16x8(self.insert_u16x8::<4>(a0, value), a1),
+            5 => self.combine_u16x8(self.insert_u16x8::<5>(a0, value), a1),
+            6 => self.combine_u16x8(self.insert_u16x8::<6>(a0, value), a1),
+            7 => self.combine_u16x8(self.insert_u16x8::<7>(a0, value), a1),
+            8 => self.combine_u16x8(a0, self.insert_u16x8::<0>(a1, value)),
+            9 => self.combine_u16x8(a0, self.insert_u16x8::<1>(a1, value)),
+            10 => self.combine_u16x8(a0, self.insert_u16x8::<2>(a1, value)),
+            11 => self.combine_u16x8(a0, self.insert_u16x8::<3>(a1, value)),
+            12 => self.combine_u16x8(a0, self.insert_u16x8::<4>(a1, value)),
+            13 => self.combine_u16x8(a0, self.insert_u16x8::<5>(a1, value)),
+            14 => self.combine_u16x8(a0, self.insert_u16x8::<6>(a1, value)),
+            15 => self.combine_u16x8(a0, self.insert_u16x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x16(self, a: u16x16<Self>, index: usize) -> u16 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u16x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u16x16(
         self,
         a: u16x16<Self>,
@@ -7593,6 +9884,15 @@ impl Simd for Neon {
         self.combine_u16x8(self.shrv_u16x8(a0, b0), self.shrv_u16x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u16x16(self, a: u16x16<Self>, b: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_u16x8(
+            self.shift_signed_u16x8(a0, b0),
+            self.shift_signed_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
         let (a0, a1) = self.split_u16x16(a);
         let (b0, b1) = self.split_u16x16(b);
@@ -7680,6 +9980,21 @@ impl Simd for Neon {
         self.combine_u16x8(self.select_u16x8(a0, b0, c0), self.select_u16x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u16x16(
+        self,
+        a: mask16x16<Self>,
+        b: u16x16<Self>,
+        c: u16x16<Self>,
+    ) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        let (c0, c1) = self.split_u16x16(c);
+        self.combine_u16x8(
+            self.select_relaxed_u16x8(a0, b0, c0),
+            self.select_relaxed_u16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
         let (a0, a1) = self.split_u16x16(a);
         let (b0, b1) = self.split_u16x16(b);
@@ -7692,6 +10007,39 @@ impl Simd for Neon {
         self.combine_u16x8(self.max_u16x8(a0, b0), self.max_u16x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_add_u16x8(a0, b0),
+            self.saturating_add_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_sub_u16x8(a0, b0),
+            self.saturating_sub_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.count_ones_u16x8(a0), self.count_ones_u16x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.leading_zeros_u16x8(a0), self.leading_zeros_u16x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.trailing_zeros_u16x8(a0), self.trailing_zeros_u16x8(a1))
+    }
+    #[inline(always)]
     fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
         u16x32 {
             val: crate::support::Aligned512(uint16x8x4_t(
@@ -7714,6 +10062,11 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x8(self.widen_u16x8(a0), self.widen_u16x8(a1))
+    }
+    #[inline(always)]
     fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
         crate::kernel!(
             #[inline(always)]
@@ -7727,6 +10080,58 @@ impl Simd for Neon {
         kernel(self, a)
     }
     #[inline(always)]
+    fn pack_saturate_i8_u16x16(self, a: u16x16<Self>) -> i8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x16<Neon>) -> i8x16<Neon> {
+                let a: uint16x8x2_t = a.into();
+                vreinterpretq_s8_u8(vcombine_u8(
+                    vqmovn_u16(vminq_u16(a.0, vdupq_n_u16(127))),
+                    vqmovn_u16(vminq_u16(a.1, vdupq_n_u16(127))),
+                ))
+                .simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: u16x16<Neon>) -> u8x16<Neon> {
+                let a: uint16x8x2_t = a.into();
+                vcombine_u8(vqmovn_u16(a.0), vqmovn_u16(a.1)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn abd_accumulate_u16x16(
+        self,
+        acc: u32x16<Self>,
+        a: u16x16<Self>,
+        b: u16x16<Self>,
+    ) -> u32x16<Self> {
+        let (acc0, acc1) = self.split_u32x16(acc);
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u32x8(
+            self.abd_accumulate_u16x8(acc0, a0, b0),
+            self.abd_accumulate_u16x8(acc1, a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.avg_round_u16x8(a0, b0), self.avg_round_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x16(self, a: u16x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_add_widened_u16x8(a0) + self.reduce_add_widened_u16x8(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u16x16(a);
         self.combine_u8x16(self.reinterpret_u8_u16x8(a0), self.reinterpret_u8_u16x8(a1))
@@ -7740,6 +10145,19 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn transpose_bits_16x16_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (top, bottom) = self.split_u8x32(a.to_le_bytes());
+        let ac =
+            self.transpose_bits_8x8_u64x2(Bytes::from_le_bytes(self.unzip_low_u8x16(top, bottom)));
+        let bd =
+            self.transpose_bits_8x8_u64x2(Bytes::from_le_bytes(self.unzip_high_u8x16(top, bottom)));
+        let ab = self.zip_low_u64x2(ac, bd).to_le_bytes();
+        let cd = self.zip_high_u64x2(ac, bd).to_le_bytes();
+        Bytes::from_le_bytes(
+            self.combine_u8x16(self.zip_low_u8x16(ab, cd), self.zip_high_u8x16(ab, cd)),
+        )
+    }
+    #[inline(always)]
     fn splat_mask16x16(self, val: bool) -> mask16x16<Self> {
         let half = self.splat_mask16x8(val);
         self.combine_mask16x8(half, half)
@@ -7756,6 +10174,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int16x8x2_t, [i16; 16usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self> {
+        i16x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self> {
         let lo = self.from_bitmask_mask16x8(bits);
         let hi = self.from_bitmask_mask16x8(bits >> 8usize);
@@ -7818,6 +10243,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        let (c0, c1) = self.split_mask16x16(c);
+        self.combine_mask16x8(
+            self.select_relaxed_mask16x8(a0, b0, c0),
+            self.select_relaxed_mask16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
         let (a0, a1) = self.split_mask16x16(a);
         let (b0, b1) = self.split_mask16x16(b);
@@ -8033,6 +10473,57 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i32x8<const LANE: usize>(self, a: i32x8<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x8(a);
+        match LANE {
+            0 => self.extract_i32x4::<0>(a0),
+            1 => self.extract_i32x4::<1>(a0),
+            2 => self.extract_i32x4::<2>(a0),
+            3 => self.extract_i32x4::<3>(a0),
+            4 => self.extract_i32x4::<0>(a1),
+            5 => self.extract_i32x4::<1>(a1),
+            6 => self.extract_i32x4::<2>(a1),
+            7 => self.extract_i32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i32x8<const LANE: usize>(self, a: i32x8<Self>, value: i32) -> i32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x8(a);
+        match LANE {
+            0 => self.combine_i32x4(self.insert_i32x4::<0>(a0, value), a1),
+            1 => self.combine_i32x4(self.insert_i32x4::<1>(a0, value), a1),
+            2 => self.combine_i32x4(self.insert_i32x4::<2>(a0, value), a1),
+            3 => self.combine_i32x4(self.insert_i32x4::<3>(a0, value), a1),
+            4 => self.combine_i32x4(a0, self.insert_i32x4::<0>(a1, value)),
+            5 => self.combine_i32x4(a0, self.insert_i32x4::<1>(a1, value)),
+            6 => self.combine_i32x4(a0, self.insert_i32x4::<2>(a1, value)),
+            7 => self.combine_i32x4(a0, self.insert_i32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x8(self, a: i32x8<Self>, index: usize) -> i32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i32x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i32x8(self, a: i32x8<Self>, indices: u8x32<Self>) -> i32x8<Self> {
         let (a0, a1) = self.split_i32x8(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -8105,6 +10596,15 @@ impl Simd for Neon {
         self.combine_i32x4(self.shrv_i32x4(a0, b0), self.shrv_i32x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(
+            self.shift_signed_i32x4(a0, b0),
+            self.shift_signed_i32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
         let (a0, a1) = self.split_i32x8(a);
         let (b0, b1) = self.split_i32x8(b);
@@ -8192,6 +10692,21 @@ impl Simd for Neon {
         self.combine_i32x4(self.select_i32x4(a0, b0, c0), self.select_i32x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i32x8(
+        self,
+        a: mask32x8<Self>,
+        b: i32x8<Self>,
+        c: i32x8<Self>,
+    ) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        let (c0, c1) = self.split_i32x8(c);
+        self.combine_i32x4(
+            self.select_relaxed_i32x4(a0, b0, c0),
+            self.select_relaxed_i32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
         let (a0, a1) = self.split_i32x8(a);
         let (b0, b1) = self.split_i32x8(b);
@@ -8204,6 +10719,39 @@ impl Simd for Neon {
         self.combine_i32x4(self.max_i32x4(a0, b0), self.max_i32x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(
+            self.saturating_add_i32x4(a0, b0),
+            self.saturating_add_i32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(
+            self.saturating_sub_i32x4(a0, b0),
+            self.saturating_sub_i32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.count_ones_i32x4(a0), self.count_ones_i32x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.leading_zeros_i32x4(a0), self.leading_zeros_i32x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.trailing_zeros_i32x4(a0), self.trailing_zeros_i32x4(a1))
+    }
+    #[inline(always)]
     fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
         i32x16 {
             val: crate::support::Aligned512(int32x4x4_t(
@@ -8231,6 +10779,11 @@ impl Simd for Neon {
         self.combine_i32x4(self.neg_i32x4(a0), self.neg_i32x4(a1))
     }
     #[inline(always)]
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x4(self.widen_i32x4(a0), self.widen_i32x4(a1))
+    }
+    #[inline(always)]
     fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_i32x8(a);
         self.combine_u8x16(self.reinterpret_u8_i32x4(a0), self.reinterpret_u8_i32x4(a1))
@@ -8416,6 +10969,57 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u32x8<const LANE: usize>(self, a: u32x8<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x8(a);
+        match LANE {
+            0 => self.extract_u32x4::<0>(a0),
+            1 => self.extract_u32x4::<1>(a0),
+            2 => self.extract_u32x4::<2>(a0),
+            3 => self.extract_u32x4::<3>(a0),
+            4 => self.extract_u32x4::<0>(a1),
+            5 => self.extract_u32x4::<1>(a1),
+            6 => self.extract_u32x4::<2>(a1),
+            7 => self.extract_u32x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u32x8<const LANE: usize>(self, a: u32x8<Self>, value: u32) -> u32x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x8(a);
+        match LANE {
+            0 => self.combine_u32x4(self.insert_u32x4::<0>(a0, value), a1),
+            1 => self.combine_u32x4(self.insert_u32x4::<1>(a0, value), a1),
+            2 => self.combine_u32x4(self.insert_u32x4::<2>(a0, value), a1),
+            3 => self.combine_u32x4(self.insert_u32x4::<3>(a0, value), a1),
+            4 => self.combine_u32x4(a0, self.insert_u32x4::<0>(a1, value)),
+            5 => self.combine_u32x4(a0, self.insert_u32x4::<1>(a1, value)),
+            6 => self.combine_u32x4(a0, self.insert_u32x4::<2>(a1, value)),
+            7 => self.combine_u32x4(a0, self.insert_u32x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x8(self, a: u32x8<Self>, index: usize) -> u32 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u32x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u32x8(self, a: u32x8<Self>, indices: u8x32<Self>) -> u32x8<Self> {
         let (a0, a1) = self.split_u32x8(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -8488,6 +11092,15 @@ impl Simd for Neon {
         self.combine_u32x4(self.shrv_u32x4(a0, b0), self.shrv_u32x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u32x8(self, a: u32x8<Self>, b: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_u32x4(
+            self.shift_signed_u32x4(a0, b0),
+            self.shift_signed_u32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
         let (a0, a1) = self.split_u32x8(a);
         let (b0, b1) = self.split_u32x8(b);
@@ -8575,6 +11188,21 @@ impl Simd for Neon {
         self.combine_u32x4(self.select_u32x4(a0, b0, c0), self.select_u32x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u32x8(
+        self,
+        a: mask32x8<Self>,
+        b: u32x8<Self>,
+        c: u32x8<Self>,
+    ) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        let (c0, c1) = self.split_u32x8(c);
+        self.combine_u32x4(
+            self.select_relaxed_u32x4(a0, b0, c0),
+            self.select_relaxed_u32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
         let (a0, a1) = self.split_u32x8(a);
         let (b0, b1) = self.split_u32x8(b);
@@ -8587,6 +11215,39 @@ impl Simd for Neon {
         self.combine_u32x4(self.max_u32x4(a0, b0), self.max_u32x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(
+            self.saturating_add_u32x4(a0, b0),
+            self.saturating_add_u32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(
+            self.saturating_sub_u32x4(a0, b0),
+            self.saturating_sub_u32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.count_ones_u32x4(a0), self.count_ones_u32x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.leading_zeros_u32x4(a0), self.leading_zeros_u32x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.trailing_zeros_u32x4(a0), self.trailing_zeros_u32x4(a1))
+    }
+    #[inline(always)]
     fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
         u32x16 {
             val: crate::support::Aligned512(uint32x4x4_t(
@@ -8609,6 +11270,11 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x4(self.widen_u32x4(a0), self.widen_u32x4(a1))
+    }
+    #[inline(always)]
     fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
         let (a0, a1) = self.split_u32x8(a);
         self.combine_u8x16(self.reinterpret_u8_u32x4(a0), self.reinterpret_u8_u32x4(a1))
@@ -8635,6 +11301,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int32x4x2_t, [i32; 8usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self> {
+        i32x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self> {
         let lo = self.from_bitmask_mask32x4(bits);
         let hi = self.from_bitmask_mask32x4(bits >> 4usize);
@@ -8697,6 +11370,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        let (c0, c1) = self.split_mask32x8(c);
+        self.combine_mask32x4(
+            self.select_relaxed_mask32x4(a0, b0, c0),
+            self.select_relaxed_mask32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
         let (a0, a1) = self.split_mask32x8(a);
         let (b0, b1) = self.split_mask32x8(b);
@@ -8896,6 +11584,49 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_f64x4<const LANE: usize>(self, a: f64x4<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x4(a);
+        match LANE {
+            0 => self.extract_f64x2::<0>(a0),
+            1 => self.extract_f64x2::<1>(a0),
+            2 => self.extract_f64x2::<0>(a1),
+            3 => self.extract_f64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f64x4<const LANE: usize>(self, a: f64x4<Self>, value: f64) -> f64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x4(a);
+        match LANE {
+            0 => self.combine_f64x2(self.insert_f64x2::<0>(a0, value), a1),
+            1 => self.combine_f64x2(self.insert_f64x2::<1>(a0, value), a1),
+            2 => self.combine_f64x2(a0, self.insert_f64x2::<0>(a1, value)),
+            3 => self.combine_f64x2(a0, self.insert_f64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x4(self, a: f64x4<Self>, index: usize) -> f64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_f64x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f64x4(self, a: f64x4<Self>, indices: u8x32<Self>) -> f64x4<Self> {
         let (a0, a1) = self.split_f64x4(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -9088,6 +11819,36 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn nmul_add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.nmul_add_f64x2(a0, b0, c0),
+            self.nmul_add_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.nmul_sub_f64x2(a0, b0, c0),
+            self.nmul_sub_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_relaxed_f64x2(a0, b0, c0),
+            self.mul_add_relaxed_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
         let (a0, a1) = self.split_f64x4(a);
         self.combine_f64x2(self.floor_f64x2(a0), self.floor_f64x2(a1))
@@ -9123,6 +11884,21 @@ impl Simd for Neon {
         self.combine_f64x2(self.select_f64x2(a0, b0, c0), self.select_f64x2(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f64x4(
+        self,
+        a: mask64x4<Self>,
+        b: f64x4<Self>,
+        c: f64x4<Self>,
+    ) -> f64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.select_relaxed_f64x2(a0, b0, c0),
+            self.select_relaxed_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self> {
         f64x8 {
             val: crate::support::Aligned512(float64x2x4_t(
@@ -9304,6 +12080,49 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i64x4<const LANE: usize>(self, a: i64x4<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x4(a);
+        match LANE {
+            0 => self.extract_i64x2::<0>(a0),
+            1 => self.extract_i64x2::<1>(a0),
+            2 => self.extract_i64x2::<0>(a1),
+            3 => self.extract_i64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i64x4<const LANE: usize>(self, a: i64x4<Self>, value: i64) -> i64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x4(a);
+        match LANE {
+            0 => self.combine_i64x2(self.insert_i64x2::<0>(a0, value), a1),
+            1 => self.combine_i64x2(self.insert_i64x2::<1>(a0, value), a1),
+            2 => self.combine_i64x2(a0, self.insert_i64x2::<0>(a1, value)),
+            3 => self.combine_i64x2(a0, self.insert_i64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x4(self, a: i64x4<Self>, index: usize) -> i64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_i64x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i64x4(self, a: i64x4<Self>, indices: u8x32<Self>) -> i64x4<Self> {
         let (a0, a1) = self.split_i64x4(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -9376,6 +12195,15 @@ impl Simd for Neon {
         self.combine_i64x2(self.shrv_i64x2(a0, b0), self.shrv_i64x2(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(
+            self.shift_signed_i64x2(a0, b0),
+            self.shift_signed_i64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
         let (a0, a1) = self.split_i64x4(a);
         let (b0, b1) = self.split_i64x4(b);
@@ -9463,6 +12291,21 @@ impl Simd for Neon {
         self.combine_i64x2(self.select_i64x2(a0, b0, c0), self.select_i64x2(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i64x4(
+        self,
+        a: mask64x4<Self>,
+        b: i64x4<Self>,
+        c: i64x4<Self>,
+    ) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        let (c0, c1) = self.split_i64x4(c);
+        self.combine_i64x2(
+            self.select_relaxed_i64x2(a0, b0, c0),
+            self.select_relaxed_i64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
         let (a0, a1) = self.split_i64x4(a);
         let (b0, b1) = self.split_i64x4(b);
@@ -9475,6 +12318,39 @@ impl Simd for Neon {
         self.combine_i64x2(self.max_i64x2(a0, b0), self.max_i64x2(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(
+            self.saturating_add_i64x2(a0, b0),
+            self.saturating_add_i64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(
+            self.saturating_sub_i64x2(a0, b0),
+            self.saturating_sub_i64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.count_ones_i64x2(a0), self.count_ones_i64x2(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.leading_zeros_i64x2(a0), self.leading_zeros_i64x2(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.trailing_zeros_i64x2(a0), self.trailing_zeros_i64x2(a1))
+    }
+    #[inline(always)]
     fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self> {
         i64x8 {
             val: crate::support::Aligned512(int64x2x4_t(
@@ -9666,6 +12542,49 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u64x4<const LANE: usize>(self, a: u64x4<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x4(a);
+        match LANE {
+            0 => self.extract_u64x2::<0>(a0),
+            1 => self.extract_u64x2::<1>(a0),
+            2 => self.extract_u64x2::<0>(a1),
+            3 => self.extract_u64x2::<1>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u64x4<const LANE: usize>(self, a: u64x4<Self>, value: u64) -> u64x4<Self> {
+        const {
+            assert!(
+                LANE < 4usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x4(a);
+        match LANE {
+            0 => self.combine_u64x2(self.insert_u64x2::<0>(a0, value), a1),
+            1 => self.combine_u64x2(self.insert_u64x2::<1>(a0, value), a1),
+            2 => self.combine_u64x2(a0, self.insert_u64x2::<0>(a1, value)),
+            3 => self.combine_u64x2(a0, self.insert_u64x2::<1>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x4(self, a: u64x4<Self>, index: usize) -> u64 {
+        assert!(
+            index < 4usize,
+            "lane index {index} is out of bounds for {} lanes",
+            4usize
+        );
+        self.as_array_ref_u64x4(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u64x4(self, a: u64x4<Self>, indices: u8x32<Self>) -> u64x4<Self> {
         let (a0, a1) = self.split_u64x4(a);
         let (indices0, indices1) = self.split_u8x32(indices);
@@ -9738,6 +12657,15 @@ impl Simd for Neon {
         self.combine_u64x2(self.shrv_u64x2(a0, b0), self.shrv_u64x2(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u64x4(self, a: u64x4<Self>, b: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_u64x2(
+            self.shift_signed_u64x2(a0, b0),
+            self.shift_signed_u64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
         let (a0, a1) = self.split_u64x4(a);
         let (b0, b1) = self.split_u64x4(b);
@@ -9825,6 +12753,21 @@ impl Simd for Neon {
         self.combine_u64x2(self.select_u64x2(a0, b0, c0), self.select_u64x2(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u64x4(
+        self,
+        a: mask64x4<Self>,
+        b: u64x4<Self>,
+        c: u64x4<Self>,
+    ) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        let (c0, c1) = self.split_u64x4(c);
+        self.combine_u64x2(
+            self.select_relaxed_u64x2(a0, b0, c0),
+            self.select_relaxed_u64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
         let (a0, a1) = self.split_u64x4(a);
         let (b0, b1) = self.split_u64x4(b);
@@ -9837,6 +12780,39 @@ impl Simd for Neon {
         self.combine_u64x2(self.max_u64x2(a0, b0), self.max_u64x2(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(
+            self.saturating_add_u64x2(a0, b0),
+            self.saturating_add_u64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(
+            self.saturating_sub_u64x2(a0, b0),
+            self.saturating_sub_u64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.count_ones_u64x2(a0), self.count_ones_u64x2(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.leading_zeros_u64x2(a0), self.leading_zeros_u64x2(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.trailing_zeros_u64x2(a0), self.trailing_zeros_u64x2(a1))
+    }
+    #[inline(always)]
     fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self> {
         u64x8 {
             val: crate::support::Aligned512(uint64x2x4_t(
@@ -9872,6 +12848,24 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn transpose_bits_8x8_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 7)),
+            self.splat_u64x4(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 7));
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 14)),
+            self.splat_u64x4(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 14));
+        let t = self.and_u64x4(
+            self.xor_u64x4(a, self.shr_u64x4(a, 28)),
+            self.splat_u64x4(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x4(self.xor_u64x4(a, t), self.shl_u64x4(t, 28))
+    }
+    #[inline(always)]
     fn splat_mask64x4(self, val: bool) -> mask64x4<Self> {
         let half = self.splat_mask64x2(val);
         self.combine_mask64x2(half, half)
@@ -9888,6 +12882,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int64x2x2_t, [i64; 4usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self> {
+        i64x4 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self> {
         let lo = self.from_bitmask_mask64x2(bits);
         let hi = self.from_bitmask_mask64x2(bits >> 2usize);
@@ -9950,6 +12951,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        let (c0, c1) = self.split_mask64x4(c);
+        self.combine_mask64x2(
+            self.select_relaxed_mask64x2(a0, b0, c0),
+            self.select_relaxed_mask64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
         let (a0, a1) = self.split_mask64x4(a);
         let (b0, b1) = self.split_mask64x4(b);
@@ -10215,6 +13231,73 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_f32x16<const LANE: usize>(self, a: f32x16<Self>) -> f32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x16(a);
+        match LANE {
+            0 => self.extract_f32x8::<0>(a0),
+            1 => self.extract_f32x8::<1>(a0),
+            2 => self.extract_f32x8::<2>(a0),
+            3 => self.extract_f32x8::<3>(a0),
+            4 => self.extract_f32x8::<4>(a0),
+            5 => self.extract_f32x8::<5>(a0),
+            6 => self.extract_f32x8::<6>(a0),
+            7 => self.extract_f32x8::<7>(a0),
+            8 => self.extract_f32x8::<0>(a1),
+            9 => self.extract_f32x8::<1>(a1),
+            10 => self.extract_f32x8::<2>(a1),
+            11 => self.extract_f32x8::<3>(a1),
+            12 => self.extract_f32x8::<4>(a1),
+            13 => self.extract_f32x8::<5>(a1),
+            14 => self.extract_f32x8::<6>(a1),
+            15 => self.extract_f32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f32x16<const LANE: usize>(self, a: f32x16<Self>, value: f32) -> f32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f32x16(a);
+        match LANE {
+            0 => self.combine_f32x8(self.insert_f32x8::<0>(a0, value), a1),
+            1 => self.combine_f32x8(self.insert_f32x8::<1>(a0, value), a1),
+            2 => self.combine_f32x8(self.insert_f32x8::<2>(a0, value), a1),
+            3 => self.combine_f32x8(self.insert_f32x8::<3>(a0, value), a1),
+            4 => self.combine_f32x8(self.insert_f32x8::<4>(a0, value), a1),
+            5 => self.combine_f32x8(self.insert_f32x8::<5>(a0, value), a1),
+            6 => self.combine_f32x8(self.insert_f32x8::<6>(a0, value), a1),
+            7 => self.combine_f32x8(self.insert_f32x8::<7>(a0, value), a1),
+            8 => self.combine_f32x8(a0, self.insert_f32x8::<0>(a1, value)),
+            9 => self.combine_f32x8(a0, self.insert_f32x8::<1>(a1, value)),
+            10 => self.combine_f32x8(a0, self.insert_f32x8::<2>(a1, value)),
+            11 => self.combine_f32x8(a0, self.insert_f32x8::<3>(a1, value)),
+            12 => self.combine_f32x8(a0, self.insert_f32x8::<4>(a1, value)),
+            13 => self.combine_f32x8(a0, self.insert_f32x8::<5>(a1, value)),
+            14 => self.combine_f32x8(a0, self.insert_f32x8::<6>(a1, value)),
+            15 => self.combine_f32x8(a0, self.insert_f32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f32x16(self, a: f32x16<Self>, index: usize) -> f32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_f32x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f32x16(
         self,
         a: f32x16<Self>,
@@ -10411,6 +13494,41 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn nmul_add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.nmul_add_f32x8(a0, b0, c0),
+            self.nmul_add_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.nmul_sub_f32x8(a0, b0, c0),
+            self.nmul_sub_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_relaxed_f32x8(a0, b0, c0),
+            self.mul_add_relaxed_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
         let (a0, a1) = self.split_f32x16(a);
         self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
@@ -10446,6 +13564,21 @@ impl Simd for Neon {
         self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f32x16(
+        self,
+        a: mask32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.select_relaxed_f32x8(a0, b0, c0),
+            self.select_relaxed_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
         (
             f32x8 {
@@ -10522,6 +13655,43 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn pack_clamped_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        crate::kernel!(
+            #[inline(always)]
+            fn kernel(token: Neon, a: f32x16<Neon>) -> u8x16<Neon> {
+                let a: float32x4x4_t = a.into();
+                let lo = vcombine_s16(
+                    vqmovn_s32(vcvtnq_s32_f32(a.0)),
+                    vqmovn_s32(vcvtnq_s32_f32(a.1)),
+                );
+                let hi = vcombine_s16(
+                    vqmovn_s32(vcvtnq_s32_f32(a.2)),
+                    vqmovn_s32(vcvtnq_s32_f32(a.3)),
+                );
+                vcombine_u8(vqmovun_s16(lo), vqmovun_s16(hi)).simd_into(token)
+            }
+        );
+        kernel(self, a)
+    }
+    #[inline(always)]
+    fn dot2_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot2_f32x8(a0, b0), self.dot2_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn dot3_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot3_f32x8(a0, b0), self.dot3_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn dot4_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.dot4_f32x8(a0, b0), self.dot4_f32x8(a1, b1))
+    }
+    #[inline(always)]
     fn splat_i8x64(self, val: i8) -> i8x64<Self> {
         let half = self.splat_i8x32(val);
         self.combine_i8x32(half, half)
@@ -10931,6 +14101,169 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i8x64<const LANE: usize>(self, a: i8x64<Self>) -> i8 {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x64(a);
+        match LANE {
+            0 => self.extract_i8x32::<0>(a0),
+            1 => self.extract_i8x32::<1>(a0),
+            2 => self.extract_i8x32::<2>(a0),
+            3 => self.extract_i8x32::<3>(a0),
+            4 => self.extract_i8x32::<4>(a0),
+            5 => self.extract_i8x32::<5>(a0),
+            6 => self.extract_i8x32::<6>(a0),
+            7 => self.extract_i8x32::<7>(a0),
+            8 => self.extract_i8x32::<8>(a0),
+            9 => self.extract_i8x32::<9>(a0),
+            10 => self.extract_i8x32::<10>(a0),
+            11 => self.extract_i8x32::<11>(a0),
+            12 => self.extract_i8x32::<12>(a0),
+            13 => self.extract_i8x32::<13>(a0),
+            14 => self.extract_i8x32::<14>(a0),
+            15 => self.extract_i8x32::<15>(a0),
+            16 => self.extract_i8x32::<16>(a0),
+            17 => self.extract_i8x32::<17>(a0),
+            18 => self.extract_i8x32::<18>(a0),
+            19 => self.extract_i8x32::<19>(a0),
+            20 => self.extract_i8x32::<20>(a0),
+            21 => self.extract_i8x32::<21>(a0),
+            22 => self.extract_i8x32::<22>(a0),
+            23 => self.extract_i8x32::<23>(a0),
+            24 => self.extract_i8x32::<24>(a0),
+            25 => self.extract_i8x32::<25>(a0),
+            26 => self.extract_i8x32::<26>(a0),
+            27 => self.extract_i8x32::<27>(a0),
+            28 => self.extract_i8x32::<28>(a0),
+            29 => self.extract_i8x32::<29>(a0),
+            30 => self.extract_i8x32::<30>(a0),
+            31 => self.extract_i8x32::<31>(a0),
+            32 => self.extract_i8x32::<0>(a1),
+            33 => self.extract_i8x32::<1>(a1),
+            34 => self.extract_i8x32::<2>(a1),
+            35 => self.extract_i8x32::<3>(a1),
+            36 => self.extract_i8x32::<4>(a1),
+            37 => self.extract_i8x32::<5>(a1),
+            38 => self.extract_i8x32::<6>(a1),
+            39 => self.extract_i8x32::<7>(a1),
+            40 => self.extract_i8x32::<8>(a1),
+            41 => self.extract_i8x32::<9>(a1),
+            42 => self.extract_i8x32::<10>(a1),
+            43 => self.extract_i8x32::<11>(a1),
+            44 => self.extract_i8x32::<12>(a1),
+            45 => self.extract_i8x32::<13>(a1),
+            46 => self.extract_i8x32::<14>(a1),
+            47 => self.extract_i8x32::<15>(a1),
+            48 => self.extract_i8x32::<16>(a1),
+            49 => self.extract_i8x32::<17>(a1),
+            50 => self.extract_i8x32::<18>(a1),
+            51 => self.extract_i8x32::<19>(a1),
+            52 => self.extract_i8x32::<20>(a1),
+            53 => self.extract_i8x32::<21>(a1),
+            54 => self.extract_i8x32::<22>(a1),
+            55 => self.extract_i8x32::<23>(a1),
+            56 => self.extract_i8x32::<24>(a1),
+            57 => self.extract_i8x32::<25>(a1),
+            58 => self.extract_i8x32::<26>(a1),
+            59 => self.extract_i8x32::<27>(a1),
+            60 => self.extract_i8x32::<28>(a1),
+            61 => self.extract_i8x32::<29>(a1),
+            62 => self.extract_i8x32::<30>(a1),
+            63 => self.extract_i8x32::<31>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i8x64<const LANE: usize>(self, a: i8x64<Self>, value: i8) -> i8x64<Self> {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i8x64(a);
+        match LANE {
+            0 => self.combine_i8x32(self.insert_i8x32::<0>(a0, value), a1),
+            1 => self.combine_i8x32(self.insert_i8x32::<1>(a0, value), a1),
+            2 => self.combine_i8x32(self.insert_i8x32::<2>(a0, value), a1),
+            3 => self.combine_i8x32(self.insert_i8x32::<3>(a0, value), a1),
+            4 => self.combine_i8x32(self.insert_i8x32::<4>(a0, value), a1),
+            5 => self.combine_i8x32(self.insert_i8x32::<5>(a0, value), a1),
+            6 => self.combine_i8x32(self.insert_i8x32::<6>(a0, value), a1),
+            7 => self.combine_i8x32(self.insert_i8x32::<7>(a0, value), a1),
+            8 => self.combine_i8x32(self.insert_i8x32::<8>(a0, value), a1),
+            9 => self.combine_i8x32(self.insert_i8x32::<9>(a0, value), a1),
+            10 => self.combine_i8x32(self.insert_i8x32::<10>(a0, value), a1),
+            11 => self.combine_i8x32(self.insert_i8x32::<11>(a0, value), a1),
+            12 => self.combine_i8x32(self.insert_i8x32::<12>(a0, value), a1),
+            13 => self.combine_i8x32(self.insert_i8x32::<13>(a0, value), a1),
+            14 => self.combine_i8x32(self.insert_i8x32::<14>(a0, value), a1),
+            15 => self.combine_i8x32(self.insert_i8x32::<15>(a0, value), a1),
+            16 => self.combine_i8x32(self.insert_i8x32::<16>(a0, value), a1),
+            17 => self.combine_i8x32(self.insert_i8x32::<17>(a0, value), a1),
+            18 => self.combine_i8x32(self.insert_i8x32::<18>(a0, value), a1),
+            19 => self.combine_i8x32(self.insert_i8x32::<19>(a0, value), a1),
+            20 => self.combine_i8x32(self.insert_i8x32::<20>(a0, value), a1),
+            21 => self.combine_i8x32(self.insert_i8x32::<21>(a0, value), a1),
+            22 => self.combine_i8x32(self.insert_i8x32::<22>(a0, value), a1),
+            23 => self.combine_i8x32(self.insert_i8x32::<23>(a0, value), a1),
+            24 => self.combine_i8x32(self.insert_i8x32::<24>(a0, value), a1),
+            25 => self.combine_i8x32(self.insert_i8x32::<25>(a0, value), a1),
+            26 => self.combine_i8x32(self.insert_i8x32::<26>(a0, value), a1),
+            27 => self.combine_i8x32(self.insert_i8x32::<27>(a0, value), a1),
+            28 => self.combine_i8x32(self.insert_i8x32::<28>(a0, value), a1),
+            29 => self.combine_i8x32(self.insert_i8x32::<29>(a0, value), a1),
+            30 => self.combine_i8x32(self.insert_i8x32::<30>(a0, value), a1),
+            31 => self.combine_i8x32(self.insert_i8x32::<31>(a0, value), a1),
+            32 => self.combine_i8x32(a0, self.insert_i8x32::<0>(a1, value)),
+            33 => self.combine_i8x32(a0, self.insert_i8x32::<1>(a1, value)),
+            34 => self.combine_i8x32(a0, self.insert_i8x32::<2>(a1, value)),
+            35 => self.combine_i8x32(a0, self.insert_i8x32::<3>(a1, value)),
+            36 => self.combine_i8x32(a0, self.insert_i8x32::<4>(a1, value)),
+            37 => self.combine_i8x32(a0, self.insert_i8x32::<5>(a1, value)),
+            38 => self.combine_i8x32(a0, self.insert_i8x32::<6>(a1, value)),
+            39 => self.combine_i8x32(a0, self.insert_i8x32::<7>(a1, value)),
+            40 => self.combine_i8x32(a0, self.insert_i8x32::<8>(a1, value)),
+            41 => self.combine_i8x32(a0, self.insert_i8x32::<9>(a1, value)),
+            42 => self.combine_i8x32(a0, self.insert_i8x32::<10>(a1, value)),
+            43 => self.combine_i8x32(a0, self.insert_i8x32::<11>(a1, value)),
+            44 => self.combine_i8x32(a0, self.insert_i8x32::<12>(a1, value)),
+            45 => self.combine_i8x32(a0, self.insert_i8x32::<13>(a1, value)),
+            46 => self.combine_i8x32(a0, self.insert_i8x32::<14>(a1, value)),
+            47 => self.combine_i8x32(a0, self.insert_i8x32::<15>(a1, value)),
+            48 => self.combine_i8x32(a0, self.insert_i8x32::<16>(a1, value)),
+            49 => self.combine_i8x32(a0, self.insert_i8x32::<17>(a1, value)),
+            50 => self.combine_i8x32(a0, self.insert_i8x32::<18>(a1, value)),
+            51 => self.combine_i8x32(a0, self.insert_i8x32::<19>(a1, value)),
+            52 => self.combine_i8x32(a0, self.insert_i8x32::<20>(a1, value)),
+            53 => self.combine_i8x32(a0, self.insert_i8x32::<21>(a1, value)),
+            54 => self.combine_i8x32(a0, self.insert_i8x32::<22>(a1, value)),
+            55 => self.combine_i8x32(a0, self.insert_i8x32::<23>(a1, value)),
+            56 => self.combine_i8x32(a0, self.insert_i8x32::<24>(a1, value)),
+            57 => self.combine_i8x32(a0, self.insert_i8x32::<25>(a1, value)),
+            58 => self.combine_i8x32(a0, self.insert_i8x32::<26>(a1, value)),
+            59 => self.combine_i8x32(a0, self.insert_i8x32::<27>(a1, value)),
+            60 => self.combine_i8x32(a0, self.insert_i8x32::<28>(a1, value)),
+            61 => self.combine_i8x32(a0, self.insert_i8x32::<29>(a1, value)),
+            62 => self.combine_i8x32(a0, self.insert_i8x32::<30>(a1, value)),
+            63 => self.combine_i8x32(a0, self.insert_i8x32::<31>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i8x64(self, a: i8x64<Self>, index: usize) -> i8 {
+        assert!(
+            index < 64usize,
+            "lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        self.as_array_ref_i8x64(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i8x64(self, a: i8x64<Self>, indices: u8x64<Self>) -> i8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -11003,6 +14336,15 @@ impl Simd for Neon {
         self.combine_i8x32(self.shrv_i8x32(a0, b0), self.shrv_i8x32(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.shift_signed_i8x32(a0, b0),
+            self.shift_signed_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         let (b0, b1) = self.split_i8x64(b);
@@ -11090,6 +14432,21 @@ impl Simd for Neon {
         self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i8x64(
+        self,
+        a: mask8x64<Self>,
+        b: i8x64<Self>,
+        c: i8x64<Self>,
+    ) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(
+            self.select_relaxed_i8x32(a0, b0, c0),
+            self.select_relaxed_i8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         let (b0, b1) = self.split_i8x64(b);
@@ -11102,6 +14459,39 @@ impl Simd for Neon {
         self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_add_i8x32(a0, b0),
+            self.saturating_add_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_sub_i8x32(a0, b0),
+            self.saturating_sub_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.count_ones_i8x32(a0), self.count_ones_i8x32(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.leading_zeros_i8x32(a0), self.leading_zeros_i8x32(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.trailing_zeros_i8x32(a0), self.trailing_zeros_i8x32(a1))
+    }
+    #[inline(always)]
     fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
         (
             i8x32 {
@@ -11120,6 +14510,11 @@ impl Simd for Neon {
         self.combine_i8x32(self.neg_i8x32(a0), self.neg_i8x32(a1))
     }
     #[inline(always)]
+    fn reduce_add_widened_i8x64(self, a: i8x64<Self>) -> i32 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_add_widened_i8x32(a0) + self.reduce_add_widened_i8x32(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_i8x64(a);
         self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
@@ -11542,6 +14937,169 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u8x64<const LANE: usize>(self, a: u8x64<Self>) -> u8 {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x64(a);
+        match LANE {
+            0 => self.extract_u8x32::<0>(a0),
+            1 => self.extract_u8x32::<1>(a0),
+            2 => self.extract_u8x32::<2>(a0),
+            3 => self.extract_u8x32::<3>(a0),
+            4 => self.extract_u8x32::<4>(a0),
+            5 => self.extract_u8x32::<5>(a0),
+            6 => self.extract_u8x32::<6>(a0),
+            7 => self.extract_u8x32::<7>(a0),
+            8 => self.extract_u8x32::<8>(a0),
+            9 => self.extract_u8x32::<9>(a0),
+            10 => self.extract_u8x32::<10>(a0),
+            11 => self.extract_u8x32::<11>(a0),
+            12 => self.extract_u8x32::<12>(a0),
+            13 => self.extract_u8x32::<13>(a0),
+            14 => self.extract_u8x32::<14>(a0),
+            15 => self.extract_u8x32::<15>(a0),
+            16 => self.extract_u8x32::<16>(a0),
+            17 => self.extract_u8x32::<17>(a0),
+            18 => self.extract_u8x32::<18>(a0),
+            19 => self.extract_u8x32::<19>(a0),
+            20 => self.extract_u8x32::<20>(a0),
+            21 => self.extract_u8x32::<21>(a0),
+            22 => self.extract_u8x32::<22>(a0),
+            23 => self.extract_u8x32::<23>(a0),
+            24 => self.extract_u8x32::<24>(a0),
+            25 => self.extract_u8x32::<25>(a0),
+            26 => self.extract_u8x32::<26>(a0),
+            27 => self.extract_u8x32::<27>(a0),
+            28 => self.extract_u8x32::<28>(a0),
+            29 => self.extract_u8x32::<29>(a0),
+            30 => self.extract_u8x32::<30>(a0),
+            31 => self.extract_u8x32::<31>(a0),
+            32 => self.extract_u8x32::<0>(a1),
+            33 => self.extract_u8x32::<1>(a1),
+            34 => self.extract_u8x32::<2>(a1),
+            35 => self.extract_u8x32::<3>(a1),
+            36 => self.extract_u8x32::<4>(a1),
+            37 => self.extract_u8x32::<5>(a1),
+            38 => self.extract_u8x32::<6>(a1),
+            39 => self.extract_u8x32::<7>(a1),
+            40 => self.extract_u8x32::<8>(a1),
+            41 => self.extract_u8x32::<9>(a1),
+            42 => self.extract_u8x32::<10>(a1),
+            43 => self.extract_u8x32::<11>(a1),
+            44 => self.extract_u8x32::<12>(a1),
+            45 => self.extract_u8x32::<13>(a1),
+            46 => self.extract_u8x32::<14>(a1),
+            47 => self.extract_u8x32::<15>(a1),
+            48 => self.extract_u8x32::<16>(a1),
+            49 => self.extract_u8x32::<17>(a1),
+            50 => self.extract_u8x32::<18>(a1),
+            51 => self.extract_u8x32::<19>(a1),
+            52 => self.extract_u8x32::<20>(a1),
+            53 => self.extract_u8x32::<21>(a1),
+            54 => self.extract_u8x32::<22>(a1),
+            55 => self.extract_u8x32::<23>(a1),
+            56 => self.extract_u8x32::<24>(a1),
+            57 => self.extract_u8x32::<25>(a1),
+            58 => self.extract_u8x32::<26>(a1),
+            59 => self.extract_u8x32::<27>(a1),
+            60 => self.extract_u8x32::<28>(a1),
+            61 => self.extract_u8x32::<29>(a1),
+            62 => self.extract_u8x32::<30>(a1),
+            63 => self.extract_u8x32::<31>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u8x64<const LANE: usize>(self, a: u8x64<Self>, value: u8) -> u8x64<Self> {
+        const {
+            assert!(
+                LANE < 64usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u8x64(a);
+        match LANE {
+            0 => self.combine_u8x32(self.insert_u8x32::<0>(a0, value), a1),
+            1 => self.combine_u8x32(self.insert_u8x32::<1>(a0, value), a1),
+            2 => self.combine_u8x32(self.insert_u8x32::<2>(a0, value), a1),
+            3 => self.combine_u8x32(self.insert_u8x32::<3>(a0, value), a1),
+            4 => self.combine_u8x32(self.insert_u8x32::<4>(a0, value), a1),
+            5 => self.combine_u8x32(self.insert_u8x32::<5>(a0, value), a1),
+            6 => self.combine_u8x32(self.insert_u8x32::<6>(a0, value), a1),
+            7 => self.combine_u8x32(self.insert_u8x32::<7>(a0, value), a1),
+            8 => self.combine_u8x32(self.insert_u8x32::<8>(a0, value), a1),
+            9 => self.combine_u8x32(self.insert_u8x32::<9>(a0, value), a1),
+            10 => self.combine_u8x32(self.insert_u8x32::<10>(a0, value), a1),
+            11 => self.combine_u8x32(self.insert_u8x32::<11>(a0, value), a1),
+            12 => self.combine_u8x32(self.insert_u8x32::<12>(a0, value), a1),
+            13 => self.combine_u8x32(self.insert_u8x32::<13>(a0, value), a1),
+            14 => self.combine_u8x32(self.insert_u8x32::<14>(a0, value), a1),
+            15 => self.combine_u8x32(self.insert_u8x32::<15>(a0, value), a1),
+            16 => self.combine_u8x32(self.insert_u8x32::<16>(a0, value), a1),
+            17 => self.combine_u8x32(self.insert_u8x32::<17>(a0, value), a1),
+            18 => self.combine_u8x32(self.insert_u8x32::<18>(a0, value), a1),
+            19 => self.combine_u8x32(self.insert_u8x32::<19>(a0, value), a1),
+            20 => self.combine_u8x32(self.insert_u8x32::<20>(a0, value), a1),
+            21 => self.combine_u8x32(self.insert_u8x32::<21>(a0, value), a1),
+            22 => self.combine_u8x32(self.insert_u8x32::<22>(a0, value), a1),
+            23 => self.combine_u8x32(self.insert_u8x32::<23>(a0, value), a1),
+            24 => self.combine_u8x32(self.insert_u8x32::<24>(a0, value), a1),
+            25 => self.combine_u8x32(self.insert_u8x32::<25>(a0, value), a1),
+            26 => self.combine_u8x32(self.insert_u8x32::<26>(a0, value), a1),
+            27 => self.combine_u8x32(self.insert_u8x32::<27>(a0, value), a1),
+            28 => self.combine_u8x32(self.insert_u8x32::<28>(a0, value), a1),
+            29 => self.combine_u8x32(self.insert_u8x32::<29>(a0, value), a1),
+            30 => self.combine_u8x32(self.insert_u8x32::<30>(a0, value), a1),
+            31 => self.combine_u8x32(self.insert_u8x32::<31>(a0, value), a1),
+            32 => self.combine_u8x32(a0, self.insert_u8x32::<0>(a1, value)),
+            33 => self.combine_u8x32(a0, self.insert_u8x32::<1>(a1, value)),
+            34 => self.combine_u8x32(a0, self.insert_u8x32::<2>(a1, value)),
+            35 => self.combine_u8x32(a0, self.insert_u8x32::<3>(a1, value)),
+            36 => self.combine_u8x32(a0, self.insert_u8x32::<4>(a1, value)),
+            37 => self.combine_u8x32(a0, self.insert_u8x32::<5>(a1, value)),
+            38 => self.combine_u8x32(a0, self.insert_u8x32::<6>(a1, value)),
+            39 => self.combine_u8x32(a0, self.insert_u8x32::<7>(a1, value)),
+            40 => self.combine_u8x32(a0, self.insert_u8x32::<8>(a1, value)),
+            41 => self.combine_u8x32(a0, self.insert_u8x32::<9>(a1, value)),
+            42 => self.combine_u8x32(a0, self.insert_u8x32::<10>(a1, value)),
+            43 => self.combine_u8x32(a0, self.insert_u8x32::<11>(a1, value)),
+            44 => self.combine_u8x32(a0, self.insert_u8x32::<12>(a1, value)),
+            45 => self.combine_u8x32(a0, self.insert_u8x32::<13>(a1, value)),
+            46 => self.combine_u8x32(a0, self.insert_u8x32::<14>(a1, value)),
+            47 => self.combine_u8x32(a0, self.insert_u8x32::<15>(a1, value)),
+            48 => self.combine_u8x32(a0, self.insert_u8x32::<16>(a1, value)),
+            49 => self.combine_u8x32(a0, self.insert_u8x32::<17>(a1, value)),
+            50 => self.combine_u8x32(a0, self.insert_u8x32::<18>(a1, value)),
+            51 => self.combine_u8x32(a0, self.insert_u8x32::<19>(a1, value)),
+            52 => self.combine_u8x32(a0, self.insert_u8x32::<20>(a1, value)),
+            53 => self.combine_u8x32(a0, self.insert_u8x32::<21>(a1, value)),
+            54 => self.combine_u8x32(a0, self.insert_u8x32::<22>(a1, value)),
+            55 => self.combine_u8x32(a0, self.insert_u8x32::<23>(a1, value)),
+            56 => self.combine_u8x32(a0, self.insert_u8x32::<24>(a1, value)),
+            57 => self.combine_u8x32(a0, self.insert_u8x32::<25>(a1, value)),
+            58 => self.combine_u8x32(a0, self.insert_u8x32::<26>(a1, value)),
+            59 => self.combine_u8x32(a0, self.insert_u8x32::<27>(a1, value)),
+            60 => self.combine_u8x32(a0, self.insert_u8x32::<28>(a1, value)),
+            61 => self.combine_u8x32(a0, self.insert_u8x32::<29>(a1, value)),
+            62 => self.combine_u8x32(a0, self.insert_u8x32::<30>(a1, value)),
+            63 => self.combine_u8x32(a0, self.insert_u8x32::<31>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u8x64(self, a: u8x64<Self>, index: usize) -> u8 {
+        assert!(
+            index < 64usize,
+            "lane index {index} is out of bounds for {} lanes",
+            64usize
+        );
+        self.as_array_ref_u8x64(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u8x64(self, a: u8x64<Self>, indices: u8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -11614,6 +15172,15 @@ impl Simd for Neon {
         self.combine_u8x32(self.shrv_u8x32(a0, b0), self.shrv_u8x32(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u8x64(self, a: u8x64<Self>, b: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_u8x32(
+            self.shift_signed_u8x32(a0, b0),
+            self.shift_signed_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
         let (b0, b1) = self.split_u8x64(b);
@@ -11701,6 +15268,21 @@ impl Simd for Neon {
         self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u8x64(
+        self,
+        a: mask8x64<Self>,
+        b: u8x64<Self>,
+        c: u8x64<Self>,
+    ) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(
+            self.select_relaxed_u8x32(a0, b0, c0),
+            self.select_relaxed_u8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u8x64(a);
         let (b0, b1) = self.split_u8x64(b);
@@ -11713,6 +15295,39 @@ impl Simd for Neon {
         self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_add_u8x32(a0, b0),
+            self.saturating_add_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_sub_u8x32(a0, b0),
+            self.saturating_sub_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.count_ones_u8x32(a0), self.count_ones_u8x32(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.leading_zeros_u8x32(a0), self.leading_zeros_u8x32(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.trailing_zeros_u8x32(a0), self.trailing_zeros_u8x32(a1))
+    }
+    #[inline(always)]
     fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
         (
             u8x32 {
@@ -11730,8 +15345,19 @@ impl Simd for Neon {
         unsafe { vld4q_u8(src.as_ptr()).simd_into(self) }
     }
     #[inline(always)]
-    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
-        unsafe { vst4q_u8(dest.as_mut_ptr(), a.into()) }
+    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
+        unsafe { vst4q_u8(dest.as_mut_ptr(), a.into()) }
+    }
+    #[inline(always)]
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.avg_round_u8x32(a0, b0), self.avg_round_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u8x64(self, a: u8x64<Self>) -> u32 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_add_widened_u8x32(a0) + self.reduce_add_widened_u8x32(a1)
     }
     #[inline(always)]
     fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
@@ -11742,6 +15368,49 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn compress_bytes_u8x64(self, a: u8x64<Self>, mask: mask8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (mask0, mask1) = self.split_mask8x64(mask);
+        let count = self.to_bitmask_mask8x32(mask0).count_ones() as usize;
+        let mut result = [0; 64];
+        result[..32].copy_from_slice(&self.as_array_u8x32(self.compress_bytes_u8x32(a0, mask0)));
+        result[count..count + 32]
+            .copy_from_slice(&self.as_array_u8x32(self.compress_bytes_u8x32(a1, mask1)));
+        self.load_array_u8x64(result)
+    }
+    #[inline(always)]
+    fn expand_bytes_u8x64(self, a: u8x64<Self>, mask: mask8x64<Self>) -> u8x64<Self> {
+        let (mask0, mask1) = self.split_mask8x64(mask);
+        let count = self.to_bitmask_mask8x32(mask0).count_ones() as usize;
+        let lanes = self.as_array_u8x64(a);
+        let (a0, _) = self.split_u8x64(a);
+        let a1 = self.load_array_u8x32(core::array::from_fn(|i| lanes[count + i]));
+        self.combine_u8x32(
+            self.expand_bytes_u8x32(a0, mask0),
+            self.expand_bytes_u8x32(a1, mask1),
+        )
+    }
+    #[inline(always)]
+    fn table_lookup_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.table_lookup_u8x32(a0, b0),
+            self.table_lookup_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn gf_mul_const_u8x64<const FACTOR: u8>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (low, high) = const { crate::support::gf_mul_nibble_tables::<64>(FACTOR) };
+        let low = self.swizzle_dyn_within_blocks_u8x64(
+            self.load_array_u8x64(low),
+            self.and_u8x64(a, self.splat_u8x64(0x0F)),
+        );
+        let high =
+            self.swizzle_dyn_within_blocks_u8x64(self.load_array_u8x64(high), self.shr_u8x64(a, 4));
+        self.xor_u8x64(low, high)
+    }
+    #[inline(always)]
     fn splat_mask8x64(self, val: bool) -> mask8x64<Self> {
         let half = self.splat_mask8x32(val);
         self.combine_mask8x32(half, half)
@@ -11758,6 +15427,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int8x16x4_t, [i8; 64usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self> {
+        i8x64 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self> {
         let lo = self.from_bitmask_mask8x32(bits);
         let hi = self.from_bitmask_mask8x32(bits >> 32usize);
@@ -11820,6 +15496,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_relaxed_mask8x32(a0, b0, c0),
+            self.select_relaxed_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
         let (a0, a1) = self.split_mask8x64(a);
         let (b0, b1) = self.split_mask8x64(b);
@@ -12140,6 +15831,105 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i16x32<const LANE: usize>(self, a: i16x32<Self>) -> i16 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x32(a);
+        match LANE {
+            0 => self.extract_i16x16::<0>(a0),
+            1 => self.extract_i16x16::<1>(a0),
+            2 => self.extract_i16x16::<2>(a0),
+            3 => self.extract_i16x16::<3>(a0),
+            4 => self.extract_i16x16::<4>(a0),
+            5 => self.extract_i16x16::<5>(a0),
+            6 => self.extract_i16x16::<6>(a0),
+            7 => self.extract_i16x16::<7>(a0),
+            8 => self.extract_i16x16::<8>(a0),
+            9 => self.extract_i16x16::<9>(a0),
+            10 => self.extract_i16x16::<10>(a0),
+            11 => self.extract_i16x16::<11>(a0),
+            12 => self.extract_i16x16::<12>(a0),
+            13 => self.extract_i16x16::<13>(a0),
+            14 => self.extract_i16x16::<14>(a0),
+            15 => self.extract_i16x16::<15>(a0),
+            16 => self.extract_i16x16::<0>(a1),
+            17 => self.extract_i16x16::<1>(a1),
+            18 => self.extract_i16x16::<2>(a1),
+            19 => self.extract_i16x16::<3>(a1),
+            20 => self.extract_i16x16::<4>(a1),
+            21 => self.extract_i16x16::<5>(a1),
+            22 => self.extract_i16x16::<6>(a1),
+            23 => self.extract_i16x16::<7>(a1),
+            24 => self.extract_i16x16::<8>(a1),
+            25 => self.extract_i16x16::<9>(a1),
+            26 => self.extract_i16x16::<10>(a1),
+            27 => self.extract_i16x16::<11>(a1),
+            28 => self.extract_i16x16::<12>(a1),
+            29 => self.extract_i16x16::<13>(a1),
+            30 => self.extract_i16x16::<14>(a1),
+            31 => self.extract_i16x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i16x32<const LANE: usize>(self, a: i16x32<Self>, value: i16) -> i16x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i16x32(a);
+        match LANE {
+            0 => self.combine_i16x16(self.insert_i16x16::<0>(a0, value), a1),
+            1 => self.combine_i16x16(self.insert_i16x16::<1>(a0, value), a1),
+            2 => self.combine_i16x16(self.insert_i16x16::<2>(a0, value), a1),
+            3 => self.combine_i16x16(self.insert_i16x16::<3>(a0, value), a1),
+            4 => self.combine_i16x16(self.insert_i16x16::<4>(a0, value), a1),
+            5 => self.combine_i16x16(self.insert_i16x16::<5>(a0, value), a1),
+            6 => self.combine_i16x16(self.insert_i16x16::<6>(a0, value), a1),
+            7 => self.combine_i16x16(self.insert_i16x16::<7>(a0, value), a1),
+            8 => self.combine_i16x16(self.insert_i16x16::<8>(a0, value), a1),
+            9 => self.combine_i16x16(self.insert_i16x16::<9>(a0, value), a1),
+            10 => self.combine_i16x16(self.insert_i16x16::<10>(a0, value), a1),
+            11 => self.combine_i16x16(self.insert_i16x16::<11>(a0, value), a1),
+            12 => self.combine_i16x16(self.insert_i16x16::<12>(a0, value), a1),
+            13 => self.combine_i16x16(self.insert_i16x16::<13>(a0, value), a1),
+            14 => self.combine_i16x16(self.insert_i16x16::<14>(a0, value), a1),
+            15 => self.combine_i16x16(self.insert_i16x16::<15>(a0, value), a1),
+            16 => self.combine_i16x16(a0, self.insert_i16x16::<0>(a1, value)),
+            17 => self.combine_i16x16(a0, self.insert_i16x16::<1>(a1, value)),
+            18 => self.combine_i16x16(a0, self.insert_i16x16::<2>(a1, value)),
+            19 => self.combine_i16x16(a0, self.insert_i16x16::<3>(a1, value)),
+            20 => self.combine_i16x16(a0, self.insert_i16x16::<4>(a1, value)),
+            21 => self.combine_i16x16(a0, self.insert_i16x16::<5>(a1, value)),
+            22 => self.combine_i16x16(a0, self.insert_i16x16::<6>(a1, value)),
+            23 => self.combine_i16x16(a0, self.insert_i16x16::<7>(a1, value)),
+            24 => self.combine_i16x16(a0, self.insert_i16x16::<8>(a1, value)),
+            25 => self.combine_i16x16(a0, self.insert_i16x16::<9>(a1, value)),
+            26 => self.combine_i16x16(a0, self.insert_i16x16::<10>(a1, value)),
+            27 => self.combine_i16x16(a0, self.insert_i16x16::<11>(a1, value)),
+            28 => self.combine_i16x16(a0, self.insert_i16x16::<12>(a1, value)),
+            29 => self.combine_i16x16(a0, self.insert_i16x16::<13>(a1, value)),
+            30 => self.combine_i16x16(a0, self.insert_i16x16::<14>(a1, value)),
+            31 => self.combine_i16x16(a0, self.insert_i16x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i16x32(self, a: i16x32<Self>, index: usize) -> i16 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_i16x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i16x32(
         self,
         a: i16x32<Self>,
@@ -12216,6 +16006,15 @@ impl Simd for Neon {
         self.combine_i16x16(self.shrv_i16x16(a0, b0), self.shrv_i16x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.shift_signed_i16x16(a0, b0),
+            self.shift_signed_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
         let (a0, a1) = self.split_i16x32(a);
         let (b0, b1) = self.split_i16x32(b);
@@ -12309,6 +16108,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_i16x32(
+        self,
+        a: mask16x32<Self>,
+        b: i16x32<Self>,
+        c: i16x32<Self>,
+    ) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_relaxed_i16x16(a0, b0, c0),
+            self.select_relaxed_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
         let (a0, a1) = self.split_i16x32(a);
         let (b0, b1) = self.split_i16x32(b);
@@ -12321,6 +16135,42 @@ impl Simd for Neon {
         self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_add_i16x16(a0, b0),
+            self.saturating_add_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_sub_i16x16(a0, b0),
+            self.saturating_sub_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.count_ones_i16x16(a0), self.count_ones_i16x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.leading_zeros_i16x16(a0), self.leading_zeros_i16x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(
+            self.trailing_zeros_i16x16(a0),
+            self.trailing_zeros_i16x16(a1),
+        )
+    }
+    #[inline(always)]
     fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
         (
             i16x16 {
@@ -12339,6 +16189,27 @@ impl Simd for Neon {
         self.combine_i16x16(self.neg_i16x16(a0), self.neg_i16x16(a1))
     }
     #[inline(always)]
+    fn pack_saturate_i8_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(
+            self.pack_saturate_i8_i16x16(a0),
+            self.pack_saturate_i8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_i16x32(self, a: i16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u8x16(
+            self.pack_saturate_u8_i16x16(a0),
+            self.pack_saturate_u8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reduce_add_widened_i16x32(self, a: i16x32<Self>) -> i32 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_add_widened_i16x16(a0) + self.reduce_add_widened_i16x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_i16x32(a);
         self.combine_u8x32(
@@ -12636,6 +16507,105 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u16x32<const LANE: usize>(self, a: u16x32<Self>) -> u16 {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x32(a);
+        match LANE {
+            0 => self.extract_u16x16::<0>(a0),
+            1 => self.extract_u16x16::<1>(a0),
+            2 => self.extract_u16x16::<2>(a0),
+            3 => self.extract_u16x16::<3>(a0),
+            4 => self.extract_u16x16::<4>(a0),
+            5 => self.extract_u16x16::<5>(a0),
+            6 => self.extract_u16x16::<6>(a0),
+            7 => self.extract_u16x16::<7>(a0),
+            8 => self.extract_u16x16::<8>(a0),
+            9 => self.extract_u16x16::<9>(a0),
+            10 => self.extract_u16x16::<10>(a0),
+            11 => self.extract_u16x16::<11>(a0),
+            12 => self.extract_u16x16::<12>(a0),
+            13 => self.extract_u16x16::<13>(a0),
+            14 => self.extract_u16x16::<14>(a0),
+            15 => self.extract_u16x16::<15>(a0),
+            16 => self.extract_u16x16::<0>(a1),
+            17 => self.extract_u16x16::<1>(a1),
+            18 => self.extract_u16x16::<2>(a1),
+            19 => self.extract_u16x16::<3>(a1),
+            20 => self.extract_u16x16::<4>(a1),
+            21 => self.extract_u16x16::<5>(a1),
+            22 => self.extract_u16x16::<6>(a1),
+            23 => self.extract_u16x16::<7>(a1),
+            24 => self.extract_u16x16::<8>(a1),
+            25 => self.extract_u16x16::<9>(a1),
+            26 => self.extract_u16x16::<10>(a1),
+            27 => self.extract_u16x16::<11>(a1),
+            28 => self.extract_u16x16::<12>(a1),
+            29 => self.extract_u16x16::<13>(a1),
+            30 => self.extract_u16x16::<14>(a1),
+            31 => self.extract_u16x16::<15>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u16x32<const LANE: usize>(self, a: u16x32<Self>, value: u16) -> u16x32<Self> {
+        const {
+            assert!(
+                LANE < 32usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u16x32(a);
+        match LANE {
+            0 => self.combine_u16x16(self.insert_u16x16::<0>(a0, value), a1),
+            1 => self.combine_u16x16(self.insert_u16x16::<1>(a0, value), a1),
+            2 => self.combine_u16x16(self.insert_u16x16::<2>(a0, value), a1),
+            3 => self.combine_u16x16(self.insert_u16x16::<3>(a0, value), a1),
+            4 => self.combine_u16x16(self.insert_u16x16::<4>(a0, value), a1),
+            5 => self.combine_u16x16(self.insert_u16x16::<5>(a0, value), a1),
+            6 => self.combine_u16x16(self.insert_u16x16::<6>(a0, value), a1),
+            7 => self.combine_u16x16(self.insert_u16x16::<7>(a0, value), a1),
+            8 => self.combine_u16x16(self.insert_u16x16::<8>(a0, value), a1),
+            9 => self.combine_u16x16(self.insert_u16x16::<9>(a0, value), a1),
+            10 => self.combine_u16x16(self.insert_u16x16::<10>(a0, value), a1),
+            11 => self.combine_u16x16(self.insert_u16x16::<11>(a0, value), a1),
+            12 => self.combine_u16x16(self.insert_u16x16::<12>(a0, value), a1),
+            13 => self.combine_u16x16(self.insert_u16x16::<13>(a0, value), a1),
+            14 => self.combine_u16x16(self.insert_u16x16::<14>(a0, value), a1),
+            15 => self.combine_u16x16(self.insert_u16x16::<15>(a0, value), a1),
+            16 => self.combine_u16x16(a0, self.insert_u16x16::<0>(a1, value)),
+            17 => self.combine_u16x16(a0, self.insert_u16x16::<1>(a1, value)),
+            18 => self.combine_u16x16(a0, self.insert_u16x16::<2>(a1, value)),
+            19 => self.combine_u16x16(a0, self.insert_u16x16::<3>(a1, value)),
+            20 => self.combine_u16x16(a0, self.insert_u16x16::<4>(a1, value)),
+            21 => self.combine_u16x16(a0, self.insert_u16x16::<5>(a1, value)),
+            22 => self.combine_u16x16(a0, self.insert_u16x16::<6>(a1, value)),
+            23 => self.combine_u16x16(a0, self.insert_u16x16::<7>(a1, value)),
+            24 => self.combine_u16x16(a0, self.insert_u16x16::<8>(a1, value)),
+            25 => self.combine_u16x16(a0, self.insert_u16x16::<9>(a1, value)),
+            26 => self.combine_u16x16(a0, self.insert_u16x16::<10>(a1, value)),
+            27 => self.combine_u16x16(a0, self.insert_u16x16::<11>(a1, value)),
+            28 => self.combine_u16x16(a0, self.insert_u16x16::<12>(a1, value)),
+            29 => self.combine_u16x16(a0, self.insert_u16x16::<13>(a1, value)),
+            30 => self.combine_u16x16(a0, self.insert_u16x16::<14>(a1, value)),
+            31 => self.combine_u16x16(a0, self.insert_u16x16::<15>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u16x32(self, a: u16x32<Self>, index: usize) -> u16 {
+        assert!(
+            index < 32usize,
+            "lane index {index} is out of bounds for {} lanes",
+            32usize
+        );
+        self.as_array_ref_u16x32(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u16x32(
         self,
         a: u16x32<Self>,
@@ -12712,6 +16682,15 @@ impl Simd for Neon {
         self.combine_u16x16(self.shrv_u16x16(a0, b0), self.shrv_u16x16(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u16x32(self, a: u16x32<Self>, b: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_u16x16(
+            self.shift_signed_u16x16(a0, b0),
+            self.shift_signed_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
         let (a0, a1) = self.split_u16x32(a);
         let (b0, b1) = self.split_u16x32(b);
@@ -12805,6 +16784,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_u16x32(
+        self,
+        a: mask16x32<Self>,
+        b: u16x32<Self>,
+        c: u16x32<Self>,
+    ) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_relaxed_u16x16(a0, b0, c0),
+            self.select_relaxed_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
         let (a0, a1) = self.split_u16x32(a);
         let (b0, b1) = self.split_u16x32(b);
@@ -12817,6 +16811,42 @@ impl Simd for Neon {
         self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_add_u16x16(a0, b0),
+            self.saturating_add_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_sub_u16x16(a0, b0),
+            self.saturating_sub_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.count_ones_u16x16(a0), self.count_ones_u16x16(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.leading_zeros_u16x16(a0), self.leading_zeros_u16x16(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(
+            self.trailing_zeros_u16x16(a0),
+            self.trailing_zeros_u16x16(a1),
+        )
+    }
+    #[inline(always)]
     fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
         (
             u16x16 {
@@ -12843,6 +16873,33 @@ impl Simd for Neon {
         self.combine_u8x16(self.narrow_u16x16(a0), self.narrow_u16x16(a1))
     }
     #[inline(always)]
+    fn pack_saturate_i8_u16x32(self, a: u16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i8x16(
+            self.pack_saturate_i8_u16x16(a0),
+            self.pack_saturate_i8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn pack_saturate_u8_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(
+            self.pack_saturate_u8_u16x16(a0),
+            self.pack_saturate_u8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.avg_round_u16x16(a0, b0), self.avg_round_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_widened_u16x32(self, a: u16x32<Self>) -> u32 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_add_widened_u16x16(a0) + self.reduce_add_widened_u16x16(a1)
+    }
+    #[inline(always)]
     fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
         let (a0, a1) = self.split_u16x32(a);
         self.combine_u8x32(
@@ -12875,6 +16932,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int16x8x4_t, [i16; 32usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self> {
+        i16x32 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self> {
         let lo = self.from_bitmask_mask16x16(bits);
         let hi = self.from_bitmask_mask16x16(bits >> 16usize);
@@ -12937,6 +17001,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_relaxed_mask16x16(a0, b0, c0),
+            self.select_relaxed_mask16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
         let (a0, a1) = self.split_mask16x32(a);
         let (b0, b1) = self.split_mask16x32(b);
@@ -13196,6 +17275,73 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i32x16<const LANE: usize>(self, a: i32x16<Self>) -> i32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x16(a);
+        match LANE {
+            0 => self.extract_i32x8::<0>(a0),
+            1 => self.extract_i32x8::<1>(a0),
+            2 => self.extract_i32x8::<2>(a0),
+            3 => self.extract_i32x8::<3>(a0),
+            4 => self.extract_i32x8::<4>(a0),
+            5 => self.extract_i32x8::<5>(a0),
+            6 => self.extract_i32x8::<6>(a0),
+            7 => self.extract_i32x8::<7>(a0),
+            8 => self.extract_i32x8::<0>(a1),
+            9 => self.extract_i32x8::<1>(a1),
+            10 => self.extract_i32x8::<2>(a1),
+            11 => self.extract_i32x8::<3>(a1),
+            12 => self.extract_i32x8::<4>(a1),
+            13 => self.extract_i32x8::<5>(a1),
+            14 => self.extract_i32x8::<6>(a1),
+            15 => self.extract_i32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i32x16<const LANE: usize>(self, a: i32x16<Self>, value: i32) -> i32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i32x16(a);
+        match LANE {
+            0 => self.combine_i32x8(self.insert_i32x8::<0>(a0, value), a1),
+            1 => self.combine_i32x8(self.insert_i32x8::<1>(a0, value), a1),
+            2 => self.combine_i32x8(self.insert_i32x8::<2>(a0, value), a1),
+            3 => self.combine_i32x8(self.insert_i32x8::<3>(a0, value), a1),
+            4 => self.combine_i32x8(self.insert_i32x8::<4>(a0, value), a1),
+            5 => self.combine_i32x8(self.insert_i32x8::<5>(a0, value), a1),
+            6 => self.combine_i32x8(self.insert_i32x8::<6>(a0, value), a1),
+            7 => self.combine_i32x8(self.insert_i32x8::<7>(a0, value), a1),
+            8 => self.combine_i32x8(a0, self.insert_i32x8::<0>(a1, value)),
+            9 => self.combine_i32x8(a0, self.insert_i32x8::<1>(a1, value)),
+            10 => self.combine_i32x8(a0, self.insert_i32x8::<2>(a1, value)),
+            11 => self.combine_i32x8(a0, self.insert_i32x8::<3>(a1, value)),
+            12 => self.combine_i32x8(a0, self.insert_i32x8::<4>(a1, value)),
+            13 => self.combine_i32x8(a0, self.insert_i32x8::<5>(a1, value)),
+            14 => self.combine_i32x8(a0, self.insert_i32x8::<6>(a1, value)),
+            15 => self.combine_i32x8(a0, self.insert_i32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i32x16(self, a: i32x16<Self>, index: usize) -> i32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_i32x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i32x16(
         self,
         a: i32x16<Self>,
@@ -13272,6 +17418,15 @@ impl Simd for Neon {
         self.combine_i32x8(self.shrv_i32x8(a0, b0), self.shrv_i32x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.shift_signed_i32x8(a0, b0),
+            self.shift_signed_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
         let (a0, a1) = self.split_i32x16(a);
         let (b0, b1) = self.split_i32x16(b);
@@ -13359,6 +17514,21 @@ impl Simd for Neon {
         self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i32x16(
+        self,
+        a: mask32x16<Self>,
+        b: i32x16<Self>,
+        c: i32x16<Self>,
+    ) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(
+            self.select_relaxed_i32x8(a0, b0, c0),
+            self.select_relaxed_i32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
         let (a0, a1) = self.split_i32x16(a);
         let (b0, b1) = self.split_i32x16(b);
@@ -13371,6 +17541,39 @@ impl Simd for Neon {
         self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.saturating_add_i32x8(a0, b0),
+            self.saturating_add_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(
+            self.saturating_sub_i32x8(a0, b0),
+            self.saturating_sub_i32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.count_ones_i32x8(a0), self.count_ones_i32x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.leading_zeros_i32x8(a0), self.leading_zeros_i32x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.trailing_zeros_i32x8(a0), self.trailing_zeros_i32x8(a1))
+    }
+    #[inline(always)]
     fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
         (
             i32x8 {
@@ -13624,6 +17827,73 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u32x16<const LANE: usize>(self, a: u32x16<Self>) -> u32 {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x16(a);
+        match LANE {
+            0 => self.extract_u32x8::<0>(a0),
+            1 => self.extract_u32x8::<1>(a0),
+            2 => self.extract_u32x8::<2>(a0),
+            3 => self.extract_u32x8::<3>(a0),
+            4 => self.extract_u32x8::<4>(a0),
+            5 => self.extract_u32x8::<5>(a0),
+            6 => self.extract_u32x8::<6>(a0),
+            7 => self.extract_u32x8::<7>(a0),
+            8 => self.extract_u32x8::<0>(a1),
+            9 => self.extract_u32x8::<1>(a1),
+            10 => self.extract_u32x8::<2>(a1),
+            11 => self.extract_u32x8::<3>(a1),
+            12 => self.extract_u32x8::<4>(a1),
+            13 => self.extract_u32x8::<5>(a1),
+            14 => self.extract_u32x8::<6>(a1),
+            15 => self.extract_u32x8::<7>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u32x16<const LANE: usize>(self, a: u32x16<Self>, value: u32) -> u32x16<Self> {
+        const {
+            assert!(
+                LANE < 16usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u32x16(a);
+        match LANE {
+            0 => self.combine_u32x8(self.insert_u32x8::<0>(a0, value), a1),
+            1 => self.combine_u32x8(self.insert_u32x8::<1>(a0, value), a1),
+            2 => self.combine_u32x8(self.insert_u32x8::<2>(a0, value), a1),
+            3 => self.combine_u32x8(self.insert_u32x8::<3>(a0, value), a1),
+            4 => self.combine_u32x8(self.insert_u32x8::<4>(a0, value), a1),
+            5 => self.combine_u32x8(self.insert_u32x8::<5>(a0, value), a1),
+            6 => self.combine_u32x8(self.insert_u32x8::<6>(a0, value), a1),
+            7 => self.combine_u32x8(self.insert_u32x8::<7>(a0, value), a1),
+            8 => self.combine_u32x8(a0, self.insert_u32x8::<0>(a1, value)),
+            9 => self.combine_u32x8(a0, self.insert_u32x8::<1>(a1, value)),
+            10 => self.combine_u32x8(a0, self.insert_u32x8::<2>(a1, value)),
+            11 => self.combine_u32x8(a0, self.insert_u32x8::<3>(a1, value)),
+            12 => self.combine_u32x8(a0, self.insert_u32x8::<4>(a1, value)),
+            13 => self.combine_u32x8(a0, self.insert_u32x8::<5>(a1, value)),
+            14 => self.combine_u32x8(a0, self.insert_u32x8::<6>(a1, value)),
+            15 => self.combine_u32x8(a0, self.insert_u32x8::<7>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u32x16(self, a: u32x16<Self>, index: usize) -> u32 {
+        assert!(
+            index < 16usize,
+            "lane index {index} is out of bounds for {} lanes",
+            16usize
+        );
+        self.as_array_ref_u32x16(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u32x16(
         self,
         a: u32x16<Self>,
@@ -13700,6 +17970,15 @@ impl Simd for Neon {
         self.combine_u32x8(self.shrv_u32x8(a0, b0), self.shrv_u32x8(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u32x16(self, a: u32x16<Self>, b: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_u32x8(
+            self.shift_signed_u32x8(a0, b0),
+            self.shift_signed_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
         let (a0, a1) = self.split_u32x16(a);
         let (b0, b1) = self.split_u32x16(b);
@@ -13787,6 +18066,21 @@ impl Simd for Neon {
         self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u32x16(
+        self,
+        a: mask32x16<Self>,
+        b: u32x16<Self>,
+        c: u32x16<Self>,
+    ) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(
+            self.select_relaxed_u32x8(a0, b0, c0),
+            self.select_relaxed_u32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
         let (a0, a1) = self.split_u32x16(a);
         let (b0, b1) = self.split_u32x16(b);
@@ -13799,6 +18093,39 @@ impl Simd for Neon {
         self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.saturating_add_u32x8(a0, b0),
+            self.saturating_add_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(
+            self.saturating_sub_u32x8(a0, b0),
+            self.saturating_sub_u32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.count_ones_u32x8(a0), self.count_ones_u32x8(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.leading_zeros_u32x8(a0), self.leading_zeros_u32x8(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.trailing_zeros_u32x8(a0), self.trailing_zeros_u32x8(a1))
+    }
+    #[inline(always)]
     fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
         (
             u32x8 {
@@ -13846,6 +18173,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int32x4x4_t, [i32; 16usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self> {
+        i32x16 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self> {
         let lo = self.from_bitmask_mask32x8(bits);
         let hi = self.from_bitmask_mask32x8(bits >> 8usize);
@@ -13908,6 +18242,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_relaxed_mask32x8(a0, b0, c0),
+            self.select_relaxed_mask32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
         let (a0, a1) = self.split_mask32x16(a);
         let (b0, b1) = self.split_mask32x16(b);
@@ -14132,6 +18481,57 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_f64x8<const LANE: usize>(self, a: f64x8<Self>) -> f64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x8(a);
+        match LANE {
+            0 => self.extract_f64x4::<0>(a0),
+            1 => self.extract_f64x4::<1>(a0),
+            2 => self.extract_f64x4::<2>(a0),
+            3 => self.extract_f64x4::<3>(a0),
+            4 => self.extract_f64x4::<0>(a1),
+            5 => self.extract_f64x4::<1>(a1),
+            6 => self.extract_f64x4::<2>(a1),
+            7 => self.extract_f64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_f64x8<const LANE: usize>(self, a: f64x8<Self>, value: f64) -> f64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_f64x8(a);
+        match LANE {
+            0 => self.combine_f64x4(self.insert_f64x4::<0>(a0, value), a1),
+            1 => self.combine_f64x4(self.insert_f64x4::<1>(a0, value), a1),
+            2 => self.combine_f64x4(self.insert_f64x4::<2>(a0, value), a1),
+            3 => self.combine_f64x4(self.insert_f64x4::<3>(a0, value), a1),
+            4 => self.combine_f64x4(a0, self.insert_f64x4::<0>(a1, value)),
+            5 => self.combine_f64x4(a0, self.insert_f64x4::<1>(a1, value)),
+            6 => self.combine_f64x4(a0, self.insert_f64x4::<2>(a1, value)),
+            7 => self.combine_f64x4(a0, self.insert_f64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_f64x8(self, a: f64x8<Self>, index: usize) -> f64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_f64x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_f64x8(self, a: f64x8<Self>, indices: u8x64<Self>) -> f64x8<Self> {
         let (a0, a1) = self.split_f64x8(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -14324,6 +18724,36 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn nmul_add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.nmul_add_f64x4(a0, b0, c0),
+            self.nmul_add_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn nmul_sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.nmul_sub_f64x4(a0, b0, c0),
+            self.nmul_sub_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_relaxed_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_relaxed_f64x4(a0, b0, c0),
+            self.mul_add_relaxed_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
         let (a0, a1) = self.split_f64x8(a);
         self.combine_f64x4(self.floor_f64x4(a0), self.floor_f64x4(a1))
@@ -14359,6 +18789,21 @@ impl Simd for Neon {
         self.combine_f64x4(self.select_f64x4(a0, b0, c0), self.select_f64x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_f64x8(
+        self,
+        a: mask64x8<Self>,
+        b: f64x8<Self>,
+        c: f64x8<Self>,
+    ) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.select_relaxed_f64x4(a0, b0, c0),
+            self.select_relaxed_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>) {
         (
             f64x4 {
@@ -14565,6 +19010,57 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_i64x8<const LANE: usize>(self, a: i64x8<Self>) -> i64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x8(a);
+        match LANE {
+            0 => self.extract_i64x4::<0>(a0),
+            1 => self.extract_i64x4::<1>(a0),
+            2 => self.extract_i64x4::<2>(a0),
+            3 => self.extract_i64x4::<3>(a0),
+            4 => self.extract_i64x4::<0>(a1),
+            5 => self.extract_i64x4::<1>(a1),
+            6 => self.extract_i64x4::<2>(a1),
+            7 => self.extract_i64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_i64x8<const LANE: usize>(self, a: i64x8<Self>, value: i64) -> i64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_i64x8(a);
+        match LANE {
+            0 => self.combine_i64x4(self.insert_i64x4::<0>(a0, value), a1),
+            1 => self.combine_i64x4(self.insert_i64x4::<1>(a0, value), a1),
+            2 => self.combine_i64x4(self.insert_i64x4::<2>(a0, value), a1),
+            3 => self.combine_i64x4(self.insert_i64x4::<3>(a0, value), a1),
+            4 => self.combine_i64x4(a0, self.insert_i64x4::<0>(a1, value)),
+            5 => self.combine_i64x4(a0, self.insert_i64x4::<1>(a1, value)),
+            6 => self.combine_i64x4(a0, self.insert_i64x4::<2>(a1, value)),
+            7 => self.combine_i64x4(a0, self.insert_i64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_i64x8(self, a: i64x8<Self>, index: usize) -> i64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_i64x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_i64x8(self, a: i64x8<Self>, indices: u8x64<Self>) -> i64x8<Self> {
         let (a0, a1) = self.split_i64x8(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -14637,6 +19133,15 @@ impl Simd for Neon {
         self.combine_i64x4(self.shrv_i64x4(a0, b0), self.shrv_i64x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.shift_signed_i64x4(a0, b0),
+            self.shift_signed_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
         let (a0, a1) = self.split_i64x8(a);
         let (b0, b1) = self.split_i64x8(b);
@@ -14724,6 +19229,21 @@ impl Simd for Neon {
         self.combine_i64x4(self.select_i64x4(a0, b0, c0), self.select_i64x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_i64x8(
+        self,
+        a: mask64x8<Self>,
+        b: i64x8<Self>,
+        c: i64x8<Self>,
+    ) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(
+            self.select_relaxed_i64x4(a0, b0, c0),
+            self.select_relaxed_i64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
         let (a0, a1) = self.split_i64x8(a);
         let (b0, b1) = self.split_i64x8(b);
@@ -14736,6 +19256,39 @@ impl Simd for Neon {
         self.combine_i64x4(self.max_i64x4(a0, b0), self.max_i64x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.saturating_add_i64x4(a0, b0),
+            self.saturating_add_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(
+            self.saturating_sub_i64x4(a0, b0),
+            self.saturating_sub_i64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.count_ones_i64x4(a0), self.count_ones_i64x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.leading_zeros_i64x4(a0), self.leading_zeros_i64x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.trailing_zeros_i64x4(a0), self.trailing_zeros_i64x4(a1))
+    }
+    #[inline(always)]
     fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>) {
         (
             i64x4 {
@@ -14952,6 +19505,57 @@ impl Simd for Neon {
         }
     }
     #[inline(always)]
+    fn extract_u64x8<const LANE: usize>(self, a: u64x8<Self>) -> u64 {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x8(a);
+        match LANE {
+            0 => self.extract_u64x4::<0>(a0),
+            1 => self.extract_u64x4::<1>(a0),
+            2 => self.extract_u64x4::<2>(a0),
+            3 => self.extract_u64x4::<3>(a0),
+            4 => self.extract_u64x4::<0>(a1),
+            5 => self.extract_u64x4::<1>(a1),
+            6 => self.extract_u64x4::<2>(a1),
+            7 => self.extract_u64x4::<3>(a1),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn insert_u64x8<const LANE: usize>(self, a: u64x8<Self>, value: u64) -> u64x8<Self> {
+        const {
+            assert!(
+                LANE < 8usize,
+                "the lane index should be less than the number of lanes"
+            );
+        }
+        let (a0, a1) = self.split_u64x8(a);
+        match LANE {
+            0 => self.combine_u64x4(self.insert_u64x4::<0>(a0, value), a1),
+            1 => self.combine_u64x4(self.insert_u64x4::<1>(a0, value), a1),
+            2 => self.combine_u64x4(self.insert_u64x4::<2>(a0, value), a1),
+            3 => self.combine_u64x4(self.insert_u64x4::<3>(a0, value), a1),
+            4 => self.combine_u64x4(a0, self.insert_u64x4::<0>(a1, value)),
+            5 => self.combine_u64x4(a0, self.insert_u64x4::<1>(a1, value)),
+            6 => self.combine_u64x4(a0, self.insert_u64x4::<2>(a1, value)),
+            7 => self.combine_u64x4(a0, self.insert_u64x4::<3>(a1, value)),
+            _ => unreachable!(),
+        }
+    }
+    #[inline(always)]
+    fn extract_dyn_u64x8(self, a: u64x8<Self>, index: usize) -> u64 {
+        assert!(
+            index < 8usize,
+            "lane index {index} is out of bounds for {} lanes",
+            8usize
+        );
+        self.as_array_ref_u64x8(&a)[index]
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks_u64x8(self, a: u64x8<Self>, indices: u8x64<Self>) -> u64x8<Self> {
         let (a0, a1) = self.split_u64x8(a);
         let (indices0, indices1) = self.split_u8x64(indices);
@@ -15024,6 +19628,15 @@ impl Simd for Neon {
         self.combine_u64x4(self.shrv_u64x4(a0, b0), self.shrv_u64x4(a1, b1))
     }
     #[inline(always)]
+    fn shift_signed_u64x8(self, a: u64x8<Self>, b: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_u64x4(
+            self.shift_signed_u64x4(a0, b0),
+            self.shift_signed_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
         let (a0, a1) = self.split_u64x8(a);
         let (b0, b1) = self.split_u64x8(b);
@@ -15111,6 +19724,21 @@ impl Simd for Neon {
         self.combine_u64x4(self.select_u64x4(a0, b0, c0), self.select_u64x4(a1, b1, c1))
     }
     #[inline(always)]
+    fn select_relaxed_u64x8(
+        self,
+        a: mask64x8<Self>,
+        b: u64x8<Self>,
+        c: u64x8<Self>,
+    ) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(
+            self.select_relaxed_u64x4(a0, b0, c0),
+            self.select_relaxed_u64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
         let (a0, a1) = self.split_u64x8(a);
         let (b0, b1) = self.split_u64x8(b);
@@ -15123,6 +19751,39 @@ impl Simd for Neon {
         self.combine_u64x4(self.max_u64x4(a0, b0), self.max_u64x4(a1, b1))
     }
     #[inline(always)]
+    fn saturating_add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.saturating_add_u64x4(a0, b0),
+            self.saturating_add_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(
+            self.saturating_sub_u64x4(a0, b0),
+            self.saturating_sub_u64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn count_ones_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.count_ones_u64x4(a0), self.count_ones_u64x4(a1))
+    }
+    #[inline(always)]
+    fn leading_zeros_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.leading_zeros_u64x4(a0), self.leading_zeros_u64x4(a1))
+    }
+    #[inline(always)]
+    fn trailing_zeros_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.trailing_zeros_u64x4(a0), self.trailing_zeros_u64x4(a1))
+    }
+    #[inline(always)]
     fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>) {
         (
             u64x4 {
@@ -15157,6 +19818,24 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn transpose_bits_8x8_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 7)),
+            self.splat_u64x8(0x00AA_00AA_00AA_00AA),
+        );
+        let a = self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 7));
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 14)),
+            self.splat_u64x8(0x0000_CCCC_0000_CCCC),
+        );
+        let a = self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 14));
+        let t = self.and_u64x8(
+            self.xor_u64x8(a, self.shr_u64x8(a, 28)),
+            self.splat_u64x8(0x0000_0000_F0F0_F0F0),
+        );
+        self.xor_u64x8(self.xor_u64x8(a, t), self.shl_u64x8(t, 28))
+    }
+    #[inline(always)]
     fn splat_mask64x8(self, val: bool) -> mask64x8<Self> {
         let half = self.splat_mask64x4(val);
         self.combine_mask64x4(half, half)
@@ -15173,6 +19852,13 @@ impl Simd for Neon {
         crate::transmute::checked_transmute_copy::<int64x2x4_t, [i64; 8usize]>(&a.val.0)
     }
     #[inline(always)]
+    fn to_int_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self> {
+        i64x8 {
+            val: a.val,
+            simd: self,
+        }
+    }
+    #[inline(always)]
     fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self> {
         let lo = self.from_bitmask_mask64x4(bits);
         let hi = self.from_bitmask_mask64x4(bits >> 4usize);
@@ -15235,6 +19921,21 @@ impl Simd for Neon {
         )
     }
     #[inline(always)]
+    fn select_relaxed_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_relaxed_mask64x4(a0, b0, c0),
+            self.select_relaxed_mask64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
     fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
         let (a0, a1) = self.split_mask64x8(a);
         let (b0, b1) = self.split_mask64x8(b);
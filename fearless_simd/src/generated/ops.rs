@@ -771,6 +771,14 @@ impl<S: Simd> core::ops::Not for mask8x16<S> {
         self.simd.not_mask8x16(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask8x16<S> {
+    type Output = i8x16<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i8x16(self.simd.to_int_mask8x16(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for i16x8<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector, wrapping on overflow."]
@@ -1384,6 +1392,14 @@ impl<S: Simd> core::ops::Not for mask16x8<S> {
         self.simd.not_mask16x8(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask16x8<S> {
+    type Output = i16x8<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i16x8(self.simd.to_int_mask16x8(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for i32x4<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector, wrapping on overflow."]
@@ -1997,6 +2013,14 @@ impl<S: Simd> core::ops::Not for mask32x4<S> {
         self.simd.not_mask32x4(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask32x4<S> {
+    type Output = i32x4<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x4(self.simd.to_int_mask32x4(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for f64x2<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector."]
@@ -2758,6 +2782,14 @@ impl<S: Simd> core::ops::Not for mask64x2<S> {
         self.simd.not_mask64x2(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask64x2<S> {
+    type Output = i64x2<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i64x2(self.simd.to_int_mask64x2(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for f32x8<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector."]
@@ -3519,6 +3551,14 @@ impl<S: Simd> core::ops::Not for mask8x32<S> {
         self.simd.not_mask8x32(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask8x32<S> {
+    type Output = i8x32<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i8x32(self.simd.to_int_mask8x32(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for i16x16<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector, wrapping on overflow."]
@@ -4132,6 +4172,14 @@ impl<S: Simd> core::ops::Not for mask16x16<S> {
         self.simd.not_mask16x16(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask16x16<S> {
+    type Output = i16x16<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i16x16(self.simd.to_int_mask16x16(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for i32x8<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector, wrapping on overflow."]
@@ -4745,6 +4793,14 @@ impl<S: Simd> core::ops::Not for mask32x8<S> {
         self.simd.not_mask32x8(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask32x8<S> {
+    type Output = i32x8<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x8(self.simd.to_int_mask32x8(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for f64x4<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector."]
@@ -5506,6 +5562,14 @@ impl<S: Simd> core::ops::Not for mask64x4<S> {
         self.simd.not_mask64x4(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask64x4<S> {
+    type Output = i64x4<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i64x4(self.simd.to_int_mask64x4(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for f32x16<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector."]
@@ -6267,6 +6331,14 @@ impl<S: Simd> core::ops::Not for mask8x64<S> {
         self.simd.not_mask8x64(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask8x64<S> {
+    type Output = i8x64<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i8x64(self.simd.to_int_mask8x64(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for i16x32<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector, wrapping on overflow."]
@@ -6880,6 +6952,14 @@ impl<S: Simd> core::ops::Not for mask16x32<S> {
         self.simd.not_mask16x32(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask16x32<S> {
+    type Output = i16x32<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i16x32(self.simd.to_int_mask16x32(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for i32x16<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector, wrapping on overflow."]
@@ -7493,6 +7573,14 @@ impl<S: Simd> core::ops::Not for mask32x16<S> {
         self.simd.not_mask32x16(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask32x16<S> {
+    type Output = i32x16<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x16(self.simd.to_int_mask32x16(self))
+    }
+}
 impl<S: Simd> core::ops::Neg for f64x8<S> {
     type Output = Self;
     #[doc = "Negate each element of the vector."]
@@ -8254,3 +8342,11 @@ impl<S: Simd> core::ops::Not for mask64x8<S> {
         self.simd.not_mask64x8(self)
     }
 }
+impl<S: Simd> core::ops::Neg for mask64x8<S> {
+    type Output = i64x8<S>;
+    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i64x8(self.simd.to_int_mask64x8(self))
+    }
+}
@@ -0,0 +1,7182 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This file is autogenerated by fearless_simd_gen
+
+use crate::{Fallback, Simd, SimdInto, SimdMask};
+#[doc = "Reference implementation of [`Simd::abs_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn abs_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.abs_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.neg_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::sqrt_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sqrt_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.sqrt_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::approximate_recip_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn approximate_recip_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.approximate_recip_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.add_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.sub_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.mul_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::div_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn div_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.div_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::copysign_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn copysign_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.copysign_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.zip_low_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.zip_high_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.unzip_low_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.unzip_high_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_f32x4(a: &[f32; 4], b: &[f32; 4]) -> ([f32; 4], [f32; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_f32x4(a: &[f32; 4], b: &[f32; 4]) -> ([f32; 4], [f32; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_f32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::max_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.max_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.min_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_precise_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_precise_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.max_precise_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_precise_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_precise_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.min_precise_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_f32x4(a: &[f32; 4], b: &[f32; 4], c: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.mul_add_f32x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_sub_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_sub_f32x4(a: &[f32; 4], b: &[f32; 4], c: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.mul_sub_f32x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_add_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_add_f32x4(a: &[f32; 4], b: &[f32; 4], c: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.nmul_add_f32x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_sub_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_sub_f32x4(a: &[f32; 4], b: &[f32; 4], c: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.nmul_sub_f32x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_relaxed_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_relaxed_f32x4(a: &[f32; 4], b: &[f32; 4], c: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.mul_add_relaxed_f32x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::floor_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn floor_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.floor_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::ceil_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn ceil_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.ceil_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::round_ties_even_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn round_ties_even_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.round_ties_even_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::fract_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn fract_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.fract_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trunc_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trunc_f32x4(a: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.trunc_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_f64_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_f64_f32x4(a: &[f32; 4]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.reinterpret_f64_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_i32_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_i32_f32x4(a: &[f32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_i32_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_f32x4(a: &[f32; 4]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_f32x4(a: &[f32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_u32_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_u32_f32x4(a: &[f32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.cvt_u32_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_u32_precise_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_u32_precise_f32x4(a: &[f32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.cvt_u32_precise_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_i32_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_i32_f32x4(a: &[f32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.cvt_i32_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_i32_precise_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_i32_precise_f32x4(a: &[f32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.cvt_i32_precise_f32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::dot2_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot2_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.dot2_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::dot3_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot3_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.dot3_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::dot4_f32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot4_f32x4(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.dot4_f32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::add_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.add_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.sub_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.mul_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.and_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.or_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.xor_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i8x16(a: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.not_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i8x16(a: &[i8; 16], shift: u32) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.shl_i8x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.shlv_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i8x16(a: &[i8; 16], shift: u32) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.shr_i8x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.shrv_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.shift_signed_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i8x16(a: &[i8; 16], b: &[i8; 16]) -> ([i8; 16], [i8; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i8x16(a: &[i8; 16], b: &[i8; 16]) -> ([i8; 16], [i8; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.min_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.max_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.saturating_add_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i8x16(a: &[i8; 16], b: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i8x16(a: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.count_ones_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i8x16(a: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i8x16(a: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i8x16(a: &[i8; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.neg_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_i8x16(a: &[i8; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.widen_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i8x16(a: &[i8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i8x16(a: &[i8; 16]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.add_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.sub_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.mul_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.and_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.or_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.xor_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u8x16(a: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.not_u8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u8x16(a: &[u8; 16], shift: u32) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.shl_u8x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.shlv_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u8x16(a: &[u8; 16], shift: u32) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.shr_u8x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.shrv_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u8x16(a: &[u8; 16], b: &[i8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.shift_signed_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u8x16(a: &[u8; 16], b: &[u8; 16]) -> ([u8; 16], [u8; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u8x16(a: &[u8; 16], b: &[u8; 16]) -> ([u8; 16], [u8; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u8x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.min_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.max_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.saturating_add_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u8x16(a: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.count_ones_u8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u8x16(a: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u8x16(a: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_u8x16(a: &[u8; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.widen_u8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::avg_round_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn avg_round_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.avg_round_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u8x16(a: &[u8; 16]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u8x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::table_lookup_u8x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn table_lookup_u8x16(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.table_lookup_u8x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::add_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.add_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.sub_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.mul_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.and_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.or_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.xor_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i16x8(a: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.not_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i16x8(a: &[i16; 8], shift: u32) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.shl_i16x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.shlv_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i16x8(a: &[i16; 8], shift: u32) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.shr_i16x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.shrv_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.shift_signed_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i16x8(a: &[i16; 8], b: &[i16; 8]) -> ([i16; 8], [i16; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i16x8(a: &[i16; 8], b: &[i16; 8]) -> ([i16; 8], [i16; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.min_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.max_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.saturating_add_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i16x8(a: &[i16; 8], b: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i16x8(a: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.count_ones_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i16x8(a: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i16x8(a: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i16x8(a: &[i16; 8]) -> [i16; 8] {
+    let simd = Fallback::new();
+    simd.neg_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_i16x8(a: &[i16; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.widen_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i16x8(a: &[i16; 8]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i16x8(a: &[i16; 8]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.add_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.sub_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.mul_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.and_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.or_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.xor_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u16x8(a: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.not_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u16x8(a: &[u16; 8], shift: u32) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.shl_u16x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.shlv_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u16x8(a: &[u16; 8], shift: u32) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.shr_u16x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.shrv_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u16x8(a: &[u16; 8], b: &[i16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.shift_signed_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u16x8(a: &[u16; 8], b: &[u16; 8]) -> ([u16; 8], [u16; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u16x8(a: &[u16; 8], b: &[u16; 8]) -> ([u16; 8], [u16; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u16x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.min_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.max_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.saturating_add_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u16x8(a: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.count_ones_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u16x8(a: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u16x8(a: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_u16x8(a: &[u16; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.widen_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::avg_round_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn avg_round_u16x8(a: &[u16; 8], b: &[u16; 8]) -> [u16; 8] {
+    let simd = Fallback::new();
+    simd.avg_round_u16x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u16x8(a: &[u16; 8]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u16x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u16x8(a: &[u16; 8]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u16x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.add_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.sub_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.mul_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.and_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.or_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.xor_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i32x4(a: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.not_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i32x4(a: &[i32; 4], shift: u32) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.shl_i32x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.shlv_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i32x4(a: &[i32; 4], shift: u32) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.shr_i32x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.shrv_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.shift_signed_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.zip_low_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.zip_high_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.unzip_low_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.unzip_high_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i32x4(a: &[i32; 4], b: &[i32; 4]) -> ([i32; 4], [i32; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i32x4(a: &[i32; 4], b: &[i32; 4]) -> ([i32; 4], [i32; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.min_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.max_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.saturating_add_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i32x4(a: &[i32; 4], b: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i32x4(a: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.count_ones_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i32x4(a: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i32x4(a: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i32x4(a: &[i32; 4]) -> [i32; 4] {
+    let simd = Fallback::new();
+    simd.neg_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_i32x4(a: &[i32; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.widen_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i32x4(a: &[i32; 4]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i32x4(a: &[i32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_f32_i32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_f32_i32x4(a: &[i32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.cvt_f32_i32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.add_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.sub_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.mul_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.and_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.or_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.xor_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u32x4(a: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.not_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u32x4(a: &[u32; 4], shift: u32) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.shl_u32x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.shlv_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u32x4(a: &[u32; 4], shift: u32) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.shr_u32x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.shrv_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u32x4(a: &[u32; 4], b: &[i32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.shift_signed_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.zip_low_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.zip_high_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.unzip_low_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.unzip_high_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u32x4(a: &[u32; 4], b: &[u32; 4]) -> ([u32; 4], [u32; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u32x4(a: &[u32; 4], b: &[u32; 4]) -> ([u32; 4], [u32; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u32x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.min_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.max_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.saturating_add_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u32x4(a: &[u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u32x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u32x4(a: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.count_ones_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u32x4(a: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u32x4(a: &[u32; 4]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_u32x4(a: &[u32; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.widen_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u32x4(a: &[u32; 4]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_f32_u32x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_f32_u32x4(a: &[u32; 4]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.cvt_f32_u32x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::abs_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn abs_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.abs_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.neg_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::sqrt_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sqrt_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.sqrt_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::approximate_recip_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn approximate_recip_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.approximate_recip_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.add_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.sub_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.mul_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::div_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn div_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.div_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::copysign_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn copysign_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.copysign_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.zip_low_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.zip_high_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.unzip_low_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.unzip_high_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_f64x2(a: &[f64; 2], b: &[f64; 2]) -> ([f64; 2], [f64; 2]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_f64x2(a: &[f64; 2], b: &[f64; 2]) -> ([f64; 2], [f64; 2]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_f64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::max_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.max_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.min_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_precise_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_precise_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.max_precise_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_precise_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_precise_f64x2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.min_precise_f64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_f64x2(a: &[f64; 2], b: &[f64; 2], c: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.mul_add_f64x2(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_sub_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_sub_f64x2(a: &[f64; 2], b: &[f64; 2], c: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.mul_sub_f64x2(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_add_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_add_f64x2(a: &[f64; 2], b: &[f64; 2], c: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.nmul_add_f64x2(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_sub_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_sub_f64x2(a: &[f64; 2], b: &[f64; 2], c: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.nmul_sub_f64x2(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_relaxed_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_relaxed_f64x2(a: &[f64; 2], b: &[f64; 2], c: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.mul_add_relaxed_f64x2(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::floor_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn floor_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.floor_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::ceil_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn ceil_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.ceil_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::round_ties_even_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn round_ties_even_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.round_ties_even_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::fract_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn fract_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.fract_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trunc_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trunc_f64x2(a: &[f64; 2]) -> [f64; 2] {
+    let simd = Fallback::new();
+    simd.trunc_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_f32_f64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_f32_f64x2(a: &[f64; 2]) -> [f32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_f32_f64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.add_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.sub_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.mul_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.and_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.or_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.xor_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i64x2(a: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.not_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i64x2(a: &[i64; 2], shift: u32) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.shl_i64x2((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.shlv_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i64x2(a: &[i64; 2], shift: u32) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.shr_i64x2((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.shrv_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.shift_signed_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.zip_low_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.zip_high_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.unzip_low_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.unzip_high_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i64x2(a: &[i64; 2], b: &[i64; 2]) -> ([i64; 2], [i64; 2]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i64x2(a: &[i64; 2], b: &[i64; 2]) -> ([i64; 2], [i64; 2]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.min_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.max_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.saturating_add_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i64x2(a: &[i64; 2], b: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i64x2(a: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.count_ones_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i64x2(a: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i64x2(a: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i64x2(a: &[i64; 2]) -> [i64; 2] {
+    let simd = Fallback::new();
+    simd.neg_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i64x2(a: &[i64; 2]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i64x2(a: &[i64; 2]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.add_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.sub_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.mul_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.and_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.or_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.xor_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u64x2(a: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.not_u64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u64x2(a: &[u64; 2], shift: u32) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.shl_u64x2((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.shlv_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u64x2(a: &[u64; 2], shift: u32) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.shr_u64x2((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.shrv_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u64x2(a: &[u64; 2], b: &[i64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.shift_signed_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [bool; 2] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.zip_low_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.zip_high_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.unzip_low_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.unzip_high_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u64x2(a: &[u64; 2], b: &[u64; 2]) -> ([u64; 2], [u64; 2]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u64x2(a: &[u64; 2], b: &[u64; 2]) -> ([u64; 2], [u64; 2]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u64x2((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.min_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.max_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.saturating_add_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u64x2(a: &[u64; 2], b: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u64x2((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u64x2(a: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.count_ones_u64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u64x2(a: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u64x2(a: &[u64; 2]) -> [u64; 2] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u64x2(a: &[u64; 2]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u64x2`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u64x2(a: &[u64; 2]) -> [u32; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u64x2((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::abs_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn abs_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.abs_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.neg_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::sqrt_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sqrt_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.sqrt_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::approximate_recip_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn approximate_recip_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.approximate_recip_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.add_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.sub_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.mul_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::div_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn div_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.div_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::copysign_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn copysign_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.copysign_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_f32x8(a: &[f32; 8], b: &[f32; 8]) -> ([f32; 8], [f32; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_f32x8(a: &[f32; 8], b: &[f32; 8]) -> ([f32; 8], [f32; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_f32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::max_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.max_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.min_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_precise_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_precise_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.max_precise_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_precise_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_precise_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.min_precise_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_f32x8(a: &[f32; 8], b: &[f32; 8], c: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.mul_add_f32x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_sub_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_sub_f32x8(a: &[f32; 8], b: &[f32; 8], c: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.mul_sub_f32x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_add_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_add_f32x8(a: &[f32; 8], b: &[f32; 8], c: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.nmul_add_f32x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_sub_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_sub_f32x8(a: &[f32; 8], b: &[f32; 8], c: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.nmul_sub_f32x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_relaxed_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_relaxed_f32x8(a: &[f32; 8], b: &[f32; 8], c: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.mul_add_relaxed_f32x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::floor_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn floor_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.floor_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::ceil_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn ceil_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.ceil_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::round_ties_even_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn round_ties_even_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.round_ties_even_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::fract_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn fract_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.fract_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trunc_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trunc_f32x8(a: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.trunc_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_f64_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_f64_f32x8(a: &[f32; 8]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.reinterpret_f64_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_i32_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_i32_f32x8(a: &[f32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_i32_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_f32x8(a: &[f32; 8]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_f32x8(a: &[f32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_u32_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_u32_f32x8(a: &[f32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.cvt_u32_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_u32_precise_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_u32_precise_f32x8(a: &[f32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.cvt_u32_precise_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_i32_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_i32_f32x8(a: &[f32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.cvt_i32_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_i32_precise_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_i32_precise_f32x8(a: &[f32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.cvt_i32_precise_f32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::dot2_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot2_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.dot2_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::dot3_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot3_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.dot3_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::dot4_f32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot4_f32x8(a: &[f32; 8], b: &[f32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.dot4_f32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::add_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.add_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.sub_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.mul_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.and_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.or_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.xor_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i8x32(a: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.not_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i8x32(a: &[i8; 32], shift: u32) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.shl_i8x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.shlv_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i8x32(a: &[i8; 32], shift: u32) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.shr_i8x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.shrv_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.shift_signed_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.zip_low_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.zip_high_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.unzip_low_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.unzip_high_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i8x32(a: &[i8; 32], b: &[i8; 32]) -> ([i8; 32], [i8; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i8x32(a: &[i8; 32], b: &[i8; 32]) -> ([i8; 32], [i8; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.min_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.max_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.saturating_add_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i8x32(a: &[i8; 32], b: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i8x32(a: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.count_ones_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i8x32(a: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i8x32(a: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i8x32(a: &[i8; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.neg_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_i8x32(a: &[i8; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.widen_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i8x32(a: &[i8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i8x32(a: &[i8; 32]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.add_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.sub_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.mul_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.and_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.or_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.xor_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u8x32(a: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.not_u8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u8x32(a: &[u8; 32], shift: u32) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.shl_u8x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.shlv_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u8x32(a: &[u8; 32], shift: u32) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.shr_u8x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.shrv_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u8x32(a: &[u8; 32], b: &[i8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.shift_signed_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.zip_low_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.zip_high_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.unzip_low_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.unzip_high_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u8x32(a: &[u8; 32], b: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u8x32(a: &[u8; 32], b: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u8x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.min_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.max_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.saturating_add_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u8x32(a: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.count_ones_u8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u8x32(a: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u8x32(a: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_u8x32(a: &[u8; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.widen_u8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::avg_round_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn avg_round_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.avg_round_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u8x32(a: &[u8; 32]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u8x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::table_lookup_u8x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn table_lookup_u8x32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.table_lookup_u8x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::add_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.add_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.sub_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.mul_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.and_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.or_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.xor_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i16x16(a: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.not_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i16x16(a: &[i16; 16], shift: u32) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.shl_i16x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.shlv_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i16x16(a: &[i16; 16], shift: u32) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.shr_i16x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.shrv_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.shift_signed_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i16x16(a: &[i16; 16], b: &[i16; 16]) -> ([i16; 16], [i16; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i16x16(a: &[i16; 16], b: &[i16; 16]) -> ([i16; 16], [i16; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.min_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.max_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.saturating_add_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i16x16(a: &[i16; 16], b: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i16x16(a: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.count_ones_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i16x16(a: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i16x16(a: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i16x16(a: &[i16; 16]) -> [i16; 16] {
+    let simd = Fallback::new();
+    simd.neg_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_i16x16(a: &[i16; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.widen_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_i8_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_i8_i16x16(a: &[i16; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.pack_saturate_i8_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_u8_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_u8_i16x16(a: &[i16; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.pack_saturate_u8_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i16x16(a: &[i16; 16]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i16x16(a: &[i16; 16]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.add_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.sub_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.mul_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.and_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.or_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.xor_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u16x16(a: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.not_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u16x16(a: &[u16; 16], shift: u32) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.shl_u16x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.shlv_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u16x16(a: &[u16; 16], shift: u32) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.shr_u16x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.shrv_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u16x16(a: &[u16; 16], b: &[i16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.shift_signed_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u16x16(a: &[u16; 16], b: &[u16; 16]) -> ([u16; 16], [u16; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u16x16(a: &[u16; 16], b: &[u16; 16]) -> ([u16; 16], [u16; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u16x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.min_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.max_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.saturating_add_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u16x16(a: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.count_ones_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u16x16(a: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u16x16(a: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_u16x16(a: &[u16; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.widen_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::narrow_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn narrow_u16x16(a: &[u16; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.narrow_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_i8_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_i8_u16x16(a: &[u16; 16]) -> [i8; 16] {
+    let simd = Fallback::new();
+    simd.pack_saturate_i8_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_u8_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_u8_u16x16(a: &[u16; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.pack_saturate_u8_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::avg_round_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn avg_round_u16x16(a: &[u16; 16], b: &[u16; 16]) -> [u16; 16] {
+    let simd = Fallback::new();
+    simd.avg_round_u16x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u16x16(a: &[u16; 16]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u16x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u16x16(a: &[u16; 16]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u16x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.add_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.sub_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.mul_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.and_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.or_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.xor_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i32x8(a: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.not_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i32x8(a: &[i32; 8], shift: u32) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.shl_i32x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.shlv_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i32x8(a: &[i32; 8], shift: u32) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.shr_i32x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.shrv_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.shift_signed_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i32x8(a: &[i32; 8], b: &[i32; 8]) -> ([i32; 8], [i32; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i32x8(a: &[i32; 8], b: &[i32; 8]) -> ([i32; 8], [i32; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.min_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.max_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.saturating_add_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i32x8(a: &[i32; 8], b: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i32x8(a: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.count_ones_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i32x8(a: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i32x8(a: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i32x8(a: &[i32; 8]) -> [i32; 8] {
+    let simd = Fallback::new();
+    simd.neg_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_i32x8(a: &[i32; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.widen_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i32x8(a: &[i32; 8]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i32x8(a: &[i32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_f32_i32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_f32_i32x8(a: &[i32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.cvt_f32_i32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.add_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.sub_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.mul_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.and_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.or_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.xor_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u32x8(a: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.not_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u32x8(a: &[u32; 8], shift: u32) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.shl_u32x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.shlv_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u32x8(a: &[u32; 8], shift: u32) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.shr_u32x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.shrv_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u32x8(a: &[u32; 8], b: &[i32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.shift_signed_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u32x8(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u32x8(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u32x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.min_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.max_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.saturating_add_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u32x8(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u32x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u32x8(a: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.count_ones_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u32x8(a: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u32x8(a: &[u32; 8]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::widen_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn widen_u32x8(a: &[u32; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.widen_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u32x8(a: &[u32; 8]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_f32_u32x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_f32_u32x8(a: &[u32; 8]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.cvt_f32_u32x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::abs_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn abs_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.abs_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.neg_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::sqrt_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sqrt_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.sqrt_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::approximate_recip_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn approximate_recip_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.approximate_recip_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.add_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.sub_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.mul_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::div_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn div_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.div_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::copysign_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn copysign_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.copysign_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.zip_low_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.zip_high_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.unzip_low_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.unzip_high_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_f64x4(a: &[f64; 4], b: &[f64; 4]) -> ([f64; 4], [f64; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_f64x4(a: &[f64; 4], b: &[f64; 4]) -> ([f64; 4], [f64; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_f64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::max_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.max_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.min_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_precise_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_precise_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.max_precise_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_precise_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_precise_f64x4(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.min_precise_f64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_f64x4(a: &[f64; 4], b: &[f64; 4], c: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.mul_add_f64x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_sub_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_sub_f64x4(a: &[f64; 4], b: &[f64; 4], c: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.mul_sub_f64x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_add_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_add_f64x4(a: &[f64; 4], b: &[f64; 4], c: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.nmul_add_f64x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_sub_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_sub_f64x4(a: &[f64; 4], b: &[f64; 4], c: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.nmul_sub_f64x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_relaxed_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_relaxed_f64x4(a: &[f64; 4], b: &[f64; 4], c: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.mul_add_relaxed_f64x4(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::floor_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn floor_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.floor_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::ceil_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn ceil_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.ceil_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::round_ties_even_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn round_ties_even_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.round_ties_even_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::fract_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn fract_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.fract_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trunc_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trunc_f64x4(a: &[f64; 4]) -> [f64; 4] {
+    let simd = Fallback::new();
+    simd.trunc_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_f32_f64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_f32_f64x4(a: &[f64; 4]) -> [f32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_f32_f64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.add_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.sub_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.mul_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.and_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.or_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.xor_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i64x4(a: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.not_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i64x4(a: &[i64; 4], shift: u32) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.shl_i64x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.shlv_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i64x4(a: &[i64; 4], shift: u32) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.shr_i64x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.shrv_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.shift_signed_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.zip_low_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.zip_high_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.unzip_low_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.unzip_high_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i64x4(a: &[i64; 4], b: &[i64; 4]) -> ([i64; 4], [i64; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i64x4(a: &[i64; 4], b: &[i64; 4]) -> ([i64; 4], [i64; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.min_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.max_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.saturating_add_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i64x4(a: &[i64; 4], b: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i64x4(a: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.count_ones_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i64x4(a: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i64x4(a: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i64x4(a: &[i64; 4]) -> [i64; 4] {
+    let simd = Fallback::new();
+    simd.neg_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i64x4(a: &[i64; 4]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i64x4(a: &[i64; 4]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.add_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.sub_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.mul_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.and_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.or_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.xor_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u64x4(a: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.not_u64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u64x4(a: &[u64; 4], shift: u32) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.shl_u64x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.shlv_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u64x4(a: &[u64; 4], shift: u32) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.shr_u64x4((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.shrv_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u64x4(a: &[u64; 4], b: &[i64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.shift_signed_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [bool; 4] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.zip_low_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.zip_high_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.unzip_low_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.unzip_high_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u64x4(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], [u64; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u64x4(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], [u64; 4]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u64x4((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.min_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.max_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.saturating_add_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u64x4(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u64x4((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u64x4(a: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.count_ones_u64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u64x4(a: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u64x4(a: &[u64; 4]) -> [u64; 4] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u64x4(a: &[u64; 4]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u64x4`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u64x4(a: &[u64; 4]) -> [u32; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u64x4((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::abs_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn abs_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.abs_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.neg_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::sqrt_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sqrt_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.sqrt_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::approximate_recip_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn approximate_recip_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.approximate_recip_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.add_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.sub_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.mul_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::div_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn div_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.div_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::copysign_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn copysign_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.copysign_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_f32x16(a: &[f32; 16], b: &[f32; 16]) -> ([f32; 16], [f32; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_f32x16(a: &[f32; 16], b: &[f32; 16]) -> ([f32; 16], [f32; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_f32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::max_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.max_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.min_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_precise_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_precise_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.max_precise_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_precise_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_precise_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.min_precise_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_f32x16(a: &[f32; 16], b: &[f32; 16], c: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.mul_add_f32x16(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_sub_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_sub_f32x16(a: &[f32; 16], b: &[f32; 16], c: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.mul_sub_f32x16(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_add_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_add_f32x16(a: &[f32; 16], b: &[f32; 16], c: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.nmul_add_f32x16(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_sub_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_sub_f32x16(a: &[f32; 16], b: &[f32; 16], c: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.nmul_sub_f32x16(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_relaxed_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_relaxed_f32x16(a: &[f32; 16], b: &[f32; 16], c: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.mul_add_relaxed_f32x16(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::floor_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn floor_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.floor_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::ceil_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn ceil_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.ceil_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::round_ties_even_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn round_ties_even_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.round_ties_even_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::fract_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn fract_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.fract_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trunc_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trunc_f32x16(a: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.trunc_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_f64_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_f64_f32x16(a: &[f32; 16]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.reinterpret_f64_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_i32_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_i32_f32x16(a: &[f32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_i32_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_f32x16(a: &[f32; 16]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_f32x16(a: &[f32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_u32_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_u32_f32x16(a: &[f32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.cvt_u32_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_u32_precise_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_u32_precise_f32x16(a: &[f32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.cvt_u32_precise_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_i32_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_i32_f32x16(a: &[f32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.cvt_i32_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_i32_precise_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_i32_precise_f32x16(a: &[f32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.cvt_i32_precise_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_clamped_u8_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_clamped_u8_f32x16(a: &[f32; 16]) -> [u8; 16] {
+    let simd = Fallback::new();
+    simd.pack_clamped_u8_f32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::dot2_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot2_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.dot2_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::dot3_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot3_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.dot3_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::dot4_f32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn dot4_f32x16(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.dot4_f32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::add_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.add_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.sub_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.mul_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.and_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.or_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.xor_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i8x64(a: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.not_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i8x64(a: &[i8; 64], shift: u32) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.shl_i8x64((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.shlv_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i8x64(a: &[i8; 64], shift: u32) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.shr_i8x64((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.shrv_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.shift_signed_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.zip_low_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.zip_high_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.unzip_low_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.unzip_high_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i8x64(a: &[i8; 64], b: &[i8; 64]) -> ([i8; 64], [i8; 64]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i8x64(a: &[i8; 64], b: &[i8; 64]) -> ([i8; 64], [i8; 64]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.min_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.max_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.saturating_add_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i8x64(a: &[i8; 64], b: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i8x64(a: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.count_ones_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i8x64(a: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i8x64(a: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i8x64(a: &[i8; 64]) -> [i8; 64] {
+    let simd = Fallback::new();
+    simd.neg_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i8x64(a: &[i8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i8x64(a: &[i8; 64]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.add_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.sub_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.mul_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.and_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.or_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.xor_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u8x64(a: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.not_u8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u8x64(a: &[u8; 64], shift: u32) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.shl_u8x64((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.shlv_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u8x64(a: &[u8; 64], shift: u32) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.shr_u8x64((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.shrv_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u8x64(a: &[u8; 64], b: &[i8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.shift_signed_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [bool; 64] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.zip_low_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.zip_high_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.unzip_low_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.unzip_high_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u8x64(a: &[u8; 64], b: &[u8; 64]) -> ([u8; 64], [u8; 64]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u8x64(a: &[u8; 64], b: &[u8; 64]) -> ([u8; 64], [u8; 64]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u8x64((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.min_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.max_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.saturating_add_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u8x64(a: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.count_ones_u8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u8x64(a: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u8x64(a: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::avg_round_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn avg_round_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.avg_round_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u8x64(a: &[u8; 64]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u8x64((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::table_lookup_u8x64`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn table_lookup_u8x64(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.table_lookup_u8x64((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::add_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.add_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.sub_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.mul_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.and_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.or_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.xor_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i16x32(a: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.not_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i16x32(a: &[i16; 32], shift: u32) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.shl_i16x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.shlv_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i16x32(a: &[i16; 32], shift: u32) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.shr_i16x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.shrv_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.shift_signed_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.zip_low_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.zip_high_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.unzip_low_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.unzip_high_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i16x32(a: &[i16; 32], b: &[i16; 32]) -> ([i16; 32], [i16; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i16x32(a: &[i16; 32], b: &[i16; 32]) -> ([i16; 32], [i16; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.min_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.max_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.saturating_add_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i16x32(a: &[i16; 32], b: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i16x32(a: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.count_ones_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i16x32(a: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i16x32(a: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i16x32(a: &[i16; 32]) -> [i16; 32] {
+    let simd = Fallback::new();
+    simd.neg_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_i8_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_i8_i16x32(a: &[i16; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.pack_saturate_i8_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_u8_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_u8_i16x32(a: &[i16; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.pack_saturate_u8_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i16x32(a: &[i16; 32]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i16x32(a: &[i16; 32]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.add_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.sub_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.mul_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.and_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.or_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.xor_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u16x32(a: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.not_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u16x32(a: &[u16; 32], shift: u32) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.shl_u16x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.shlv_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u16x32(a: &[u16; 32], shift: u32) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.shr_u16x32((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.shrv_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u16x32(a: &[u16; 32], b: &[i16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.shift_signed_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [bool; 32] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.zip_low_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.zip_high_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.unzip_low_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.unzip_high_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u16x32(a: &[u16; 32], b: &[u16; 32]) -> ([u16; 32], [u16; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u16x32(a: &[u16; 32], b: &[u16; 32]) -> ([u16; 32], [u16; 32]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u16x32((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.min_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.max_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.saturating_add_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u16x32(a: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.count_ones_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u16x32(a: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u16x32(a: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::narrow_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn narrow_u16x32(a: &[u16; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.narrow_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_i8_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_i8_u16x32(a: &[u16; 32]) -> [i8; 32] {
+    let simd = Fallback::new();
+    simd.pack_saturate_i8_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::pack_saturate_u8_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn pack_saturate_u8_u16x32(a: &[u16; 32]) -> [u8; 32] {
+    let simd = Fallback::new();
+    simd.pack_saturate_u8_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::avg_round_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn avg_round_u16x32(a: &[u16; 32], b: &[u16; 32]) -> [u16; 32] {
+    let simd = Fallback::new();
+    simd.avg_round_u16x32((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u16x32(a: &[u16; 32]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u16x32`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u16x32(a: &[u16; 32]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u16x32((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.add_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.sub_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.mul_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.and_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.or_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.xor_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i32x16(a: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.not_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i32x16(a: &[i32; 16], shift: u32) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.shl_i32x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.shlv_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i32x16(a: &[i32; 16], shift: u32) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.shr_i32x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.shrv_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.shift_signed_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i32x16(a: &[i32; 16], b: &[i32; 16]) -> ([i32; 16], [i32; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i32x16(a: &[i32; 16], b: &[i32; 16]) -> ([i32; 16], [i32; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.min_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.max_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.saturating_add_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i32x16(a: &[i32; 16], b: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i32x16(a: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.count_ones_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i32x16(a: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i32x16(a: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i32x16(a: &[i32; 16]) -> [i32; 16] {
+    let simd = Fallback::new();
+    simd.neg_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i32x16(a: &[i32; 16]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i32x16(a: &[i32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_f32_i32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_f32_i32x16(a: &[i32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.cvt_f32_i32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.add_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.sub_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.mul_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.and_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.or_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.xor_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u32x16(a: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.not_u32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u32x16(a: &[u32; 16], shift: u32) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.shl_u32x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.shlv_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u32x16(a: &[u32; 16], shift: u32) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.shr_u32x16((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.shrv_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u32x16(a: &[u32; 16], b: &[i32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.shift_signed_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [bool; 16] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.zip_low_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.zip_high_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.unzip_low_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.unzip_high_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u32x16(a: &[u32; 16], b: &[u32; 16]) -> ([u32; 16], [u32; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u32x16(a: &[u32; 16], b: &[u32; 16]) -> ([u32; 16], [u32; 16]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u32x16((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.min_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.max_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.saturating_add_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u32x16(a: &[u32; 16], b: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u32x16((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u32x16(a: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.count_ones_u32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u32x16(a: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u32x16(a: &[u32; 16]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u32x16(a: &[u32; 16]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::cvt_f32_u32x16`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn cvt_f32_u32x16(a: &[u32; 16]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.cvt_f32_u32x16((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::abs_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn abs_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.abs_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.neg_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::sqrt_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sqrt_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.sqrt_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::approximate_recip_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn approximate_recip_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.approximate_recip_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.add_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.sub_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.mul_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::div_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn div_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.div_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::copysign_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn copysign_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.copysign_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_f64x8(a: &[f64; 8], b: &[f64; 8]) -> ([f64; 8], [f64; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_f64x8(a: &[f64; 8], b: &[f64; 8]) -> ([f64; 8], [f64; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_f64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::max_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.max_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.min_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_precise_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_precise_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.max_precise_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::min_precise_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_precise_f64x8(a: &[f64; 8], b: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.min_precise_f64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_f64x8(a: &[f64; 8], b: &[f64; 8], c: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.mul_add_f64x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_sub_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_sub_f64x8(a: &[f64; 8], b: &[f64; 8], c: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.mul_sub_f64x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_add_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_add_f64x8(a: &[f64; 8], b: &[f64; 8], c: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.nmul_add_f64x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::nmul_sub_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn nmul_sub_f64x8(a: &[f64; 8], b: &[f64; 8], c: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.nmul_sub_f64x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_add_relaxed_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_add_relaxed_f64x8(a: &[f64; 8], b: &[f64; 8], c: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.mul_add_relaxed_f64x8(
+        (*a).simd_into(simd),
+        (*b).simd_into(simd),
+        (*c).simd_into(simd),
+    )
+    .into()
+}
+#[doc = "Reference implementation of [`Simd::floor_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn floor_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.floor_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::ceil_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn ceil_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.ceil_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::round_ties_even_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn round_ties_even_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.round_ties_even_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::fract_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn fract_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.fract_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trunc_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trunc_f64x8(a: &[f64; 8]) -> [f64; 8] {
+    let simd = Fallback::new();
+    simd.trunc_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_f32_f64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_f32_f64x8(a: &[f64; 8]) -> [f32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_f32_f64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.add_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.sub_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.mul_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.and_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.or_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.xor_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_i64x8(a: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.not_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_i64x8(a: &[i64; 8], shift: u32) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.shl_i64x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.shlv_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_i64x8(a: &[i64; 8], shift: u32) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.shr_i64x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.shrv_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.shift_signed_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_i64x8(a: &[i64; 8], b: &[i64; 8]) -> ([i64; 8], [i64; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_i64x8(a: &[i64; 8], b: &[i64; 8]) -> ([i64; 8], [i64; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_i64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.min_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.max_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.saturating_add_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_i64x8(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.saturating_sub_i64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_i64x8(a: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.count_ones_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_i64x8(a: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.leading_zeros_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_i64x8(a: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::neg_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn neg_i64x8(a: &[i64; 8]) -> [i64; 8] {
+    let simd = Fallback::new();
+    simd.neg_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_i64x8(a: &[i64; 8]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_i64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_i64x8(a: &[i64; 8]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_i64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::add_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn add_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.add_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::sub_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn sub_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.sub_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::mul_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn mul_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.mul_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::and_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn and_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.and_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::or_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn or_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.or_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::xor_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn xor_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.xor_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::not_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn not_u64x8(a: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.not_u64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::shl_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shl_u64x8(a: &[u64; 8], shift: u32) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.shl_u64x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shlv_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shlv_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.shlv_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shr_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shr_u64x8(a: &[u64; 8], shift: u32) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.shr_u64x8((*a).simd_into(simd), shift).into()
+}
+#[doc = "Reference implementation of [`Simd::shrv_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shrv_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.shrv_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::shift_signed_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn shift_signed_u64x8(a: &[u64; 8], b: &[i64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.shift_signed_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::simd_eq_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_eq_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_eq_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_lt_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_lt_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_lt_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_le_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_le_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_le_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_ge_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_ge_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_ge_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::simd_gt_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn simd_gt_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [bool; 8] {
+    let simd = Fallback::new();
+    let mask = simd.simd_gt_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    core::array::from_fn(|i| mask.test(i))
+}
+#[doc = "Reference implementation of [`Simd::zip_low_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_low_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.zip_low_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::zip_high_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn zip_high_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.zip_high_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_low_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_low_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.unzip_low_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::unzip_high_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn unzip_high_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.unzip_high_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::interleave_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn interleave_u64x8(a: &[u64; 8], b: &[u64; 8]) -> ([u64; 8], [u64; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.interleave_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::deinterleave_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn deinterleave_u64x8(a: &[u64; 8], b: &[u64; 8]) -> ([u64; 8], [u64; 8]) {
+    let simd = Fallback::new();
+    let (lo, hi) = simd.deinterleave_u64x8((*a).simd_into(simd), (*b).simd_into(simd));
+    (lo.into(), hi.into())
+}
+#[doc = "Reference implementation of [`Simd::min_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn min_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.min_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::max_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn max_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.max_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_add_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_add_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.saturating_add_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::saturating_sub_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn saturating_sub_u64x8(a: &[u64; 8], b: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.saturating_sub_u64x8((*a).simd_into(simd), (*b).simd_into(simd))
+        .into()
+}
+#[doc = "Reference implementation of [`Simd::count_ones_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn count_ones_u64x8(a: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.count_ones_u64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::leading_zeros_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn leading_zeros_u64x8(a: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.leading_zeros_u64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::trailing_zeros_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn trailing_zeros_u64x8(a: &[u64; 8]) -> [u64; 8] {
+    let simd = Fallback::new();
+    simd.trailing_zeros_u64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u8_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u8_u64x8(a: &[u64; 8]) -> [u8; 64] {
+    let simd = Fallback::new();
+    simd.reinterpret_u8_u64x8((*a).simd_into(simd)).into()
+}
+#[doc = "Reference implementation of [`Simd::reinterpret_u32_u64x8`], computed by the [`Fallback`] level."]
+#[inline]
+pub fn reinterpret_u32_u64x8(a: &[u64; 8]) -> [u32; 16] {
+    let simd = Fallback::new();
+    simd.reinterpret_u32_u64x8((*a).simd_into(simd)).into()
+}
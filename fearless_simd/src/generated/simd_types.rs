@@ -5,7 +5,7 @@
 
 use crate::{
     Bytes, Select, Simd, SimdBase, SimdCvtFloat, SimdCvtTruncate, SimdFrom, SimdInto, SimdMask,
-    seal::Seal,
+    SimdVector, seal::Seal,
 };
 #[doc = "A SIMD vector of 4 [`f32`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, f32x4};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = f32x4::splat(simd, 1.0);\n    let b = f32x4::simd_from(simd, 1.0);\n\n    // From a slice:\n    let c = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\n    // From an array:\n    let d = f32x4::simd_from(simd, [1.0, 2.0, 3.0, 4.0]);\n\n    // From an element-wise function:\n    let e = f32x4::from_fn(simd, |i| i as f32);\n}\n```"]
 #[derive(Clone, Copy)]
@@ -69,6 +69,10 @@ impl<S: Simd> Select<f32x4<S>> for mask32x4<S> {
     fn select(self, if_true: f32x4<S>, if_false: f32x4<S>) -> f32x4<S> {
         self.simd.select_f32x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: f32x4<S>, if_false: f32x4<S>) -> f32x4<S> {
+        self.simd.select_relaxed_f32x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for f32x4<S> {
     type Bytes = u8x16<S>;
@@ -80,10 +84,33 @@ impl<S: Simd> Bytes for f32x4<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_f32x4(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = f32::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = f32::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for f32x4<S> {
     type Element = f32;
     const N: usize = 4;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: f32 = -0.0;
+    const MIN_IDENTITY: f32 = f32::INFINITY;
+    const MAX_IDENTITY: f32 = f32::NEG_INFINITY;
     type Mask = mask32x4<S>;
     type Block = f32x4<S>;
     type Array = [f32; 4];
@@ -104,11 +131,27 @@ impl<S: Simd> SimdBase<S> for f32x4<S> {
         simd.load_array_ref_f32x4(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[f32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_f32x4(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [f32]) {
         self.simd
             .store_array_f32x4(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<f32>]) -> &'a mut [f32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_f32x4(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_f32x4(0.0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: f32) -> Self {
         simd.splat_f32x4(val)
     }
@@ -148,6 +191,18 @@ impl<S: Simd> SimdBase<S> for f32x4<S> {
             .shift_elements_right_f32x4::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> f32 {
+        self.simd.extract_f32x4::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: f32) -> Self {
+        self.simd.insert_f32x4::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> f32 {
+        self.simd.extract_dyn_f32x4(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_f32x4(self, indices.simd_into(self.simd))
@@ -241,6 +296,21 @@ impl<S: Simd> crate::SimdFloat<S> for f32x4<S> {
             .mul_sub_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
+    fn nmul_add(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_add_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmul_sub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_sub_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_relaxed(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .mul_add_relaxed_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
     fn floor(self) -> Self {
         self.simd.floor_f32x4(self)
     }
@@ -260,6 +330,12 @@ impl<S: Simd> crate::SimdFloat<S> for f32x4<S> {
     fn trunc(self) -> Self {
         self.simd.trunc_f32x4(self)
     }
+    type Int = i32x4<S>;
+    type Uint = u32x4<S>;
+}
+impl<S: Simd> SimdVector for f32x4<S> {
+    type Simd = S;
+    const LANES: usize = 4;
 }
 impl<S: Simd> SimdCvtFloat<u32x4<S>> for f32x4<S> {
     #[doc = "Convert each unsigned 32-bit integer element to a floating-point value.\n\nValues that cannot be exactly represented are rounded to the nearest representable value."]
@@ -275,6 +351,20 @@ impl<S: Simd> SimdCvtFloat<i32x4<S>> for f32x4<S> {
         x.simd.cvt_f32_i32x4(x)
     }
 }
+impl<S: Simd> crate::SimdDot<S> for f32x4<S> {
+    #[inline(always)]
+    fn dot2(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot2_f32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn dot3(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot3_f32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn dot4(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot4_f32x4(self, rhs.simd_into(self.simd))
+    }
+}
 impl<S: Simd> crate::SimdCombine<S> for f32x4<S> {
     type Combined = f32x8<S>;
     #[inline(always)]
@@ -282,6 +372,37 @@ impl<S: Simd> crate::SimdCombine<S> for f32x4<S> {
         self.simd.combine_f32x4(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<f32x4<S>> for f32x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: f32x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_f32x4(x, simd.splat_f32x4(0.0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<f32x8<S>> for f32x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: f32x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_f32x8(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<f32x4<S>> for f32x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: f32x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_f32x8(
+            simd.combine_f32x4(x, simd.splat_f32x4(0.0)),
+            simd.splat_f32x8(0.0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<f32x16<S>> for f32x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: f32x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_f32x8(simd.split_f32x16(x).0).0
+    }
+}
 #[doc = "A SIMD vector of 16 [`i8`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, i8x16};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = i8x16::splat(simd, 1);\n    let b = i8x16::simd_from(simd, 1);\n\n    // From a slice:\n    let c = i8x16::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);\n\n    // From an array:\n    let d = i8x16::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);\n\n    // From an element-wise function:\n    let e = i8x16::from_fn(simd, |i| i as i8);\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
@@ -344,6 +465,10 @@ impl<S: Simd> Select<i8x16<S>> for mask8x16<S> {
     fn select(self, if_true: i8x16<S>, if_false: i8x16<S>) -> i8x16<S> {
         self.simd.select_i8x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i8x16<S>, if_false: i8x16<S>) -> i8x16<S> {
+        self.simd.select_relaxed_i8x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i8x16<S> {
     type Bytes = u8x16<S>;
@@ -355,10 +480,22 @@ impl<S: Simd> Bytes for i8x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i8x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        Self::from_bytes(value)
+    }
 }
 impl<S: Simd> SimdBase<S> for i8x16<S> {
     type Element = i8;
     const N: usize = 16;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: i8 = 0;
+    const MIN_IDENTITY: i8 = i8::MAX;
+    const MAX_IDENTITY: i8 = i8::MIN;
     type Mask = mask8x16<S>;
     type Block = i8x16<S>;
     type Array = [i8; 16];
@@ -379,11 +516,27 @@ impl<S: Simd> SimdBase<S> for i8x16<S> {
         simd.load_array_ref_i8x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i8]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i8x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i8]) {
         self.simd
             .store_array_i8x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i8>]) -> &'a mut [i8] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i8x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i8x16(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i8) -> Self {
         simd.splat_i8x16(val)
     }
@@ -440,12 +593,29 @@ impl<S: Simd> SimdBase<S> for i8x16<S> {
             .shift_elements_right_i8x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i8 {
+        self.simd.extract_i8x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i8) -> Self {
+        self.simd.insert_i8x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i8 {
+        self.simd.extract_dyn_i8x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i8x16(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i8x16<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i8x16(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i8x16(self, rhs.simd_into(self.simd))
@@ -498,6 +668,47 @@ impl<S: Simd> crate::SimdInt<S> for i8x16<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i8x16(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i8x16(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i8x16(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i8x16(self)
+    }
+    type Signed = i8x16<S>;
+    type Unsigned = u8x16<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i8 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i8x16([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    }
+}
+impl<S: Simd> SimdVector for i8x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
 }
 impl<S: Simd> crate::SimdCombine<S> for i8x16<S> {
     type Combined = i8x32<S>;
@@ -506,6 +717,37 @@ impl<S: Simd> crate::SimdCombine<S> for i8x16<S> {
         self.simd.combine_i8x16(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i8x16<S>> for i8x32<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i8x16<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i8x16(x, simd.splat_i8x16(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i8x32<S>> for i8x16<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i8x32<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i8x32(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<i8x16<S>> for i8x64<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i8x16<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i8x32(
+            simd.combine_i8x16(x, simd.splat_i8x16(0)),
+            simd.splat_i8x32(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i8x64<S>> for i8x16<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i8x64<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i8x32(simd.split_i8x64(x).0).0
+    }
+}
 #[doc = "A SIMD vector of 16 [`u8`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u8x16};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u8x16::splat(simd, 1);\n    let b = u8x16::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u8x16::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);\n\n    // From an array:\n    let d = u8x16::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);\n\n    // From an element-wise function:\n    let e = u8x16::from_fn(simd, |i| i as u8);\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
@@ -568,6 +810,10 @@ impl<S: Simd> Select<u8x16<S>> for mask8x16<S> {
     fn select(self, if_true: u8x16<S>, if_false: u8x16<S>) -> u8x16<S> {
         self.simd.select_u8x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u8x16<S>, if_false: u8x16<S>) -> u8x16<S> {
+        self.simd.select_relaxed_u8x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u8x16<S> {
     type Bytes = u8x16<S>;
@@ -579,10 +825,22 @@ impl<S: Simd> Bytes for u8x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u8x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        Self::from_bytes(value)
+    }
 }
 impl<S: Simd> SimdBase<S> for u8x16<S> {
     type Element = u8;
     const N: usize = 16;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: u8 = 0;
+    const MIN_IDENTITY: u8 = u8::MAX;
+    const MAX_IDENTITY: u8 = u8::MIN;
     type Mask = mask8x16<S>;
     type Block = u8x16<S>;
     type Array = [u8; 16];
@@ -603,11 +861,27 @@ impl<S: Simd> SimdBase<S> for u8x16<S> {
         simd.load_array_ref_u8x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u8]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u8x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u8]) {
         self.simd
             .store_array_u8x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u8x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u8x16(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u8) -> Self {
         simd.splat_u8x16(val)
     }
@@ -664,12 +938,29 @@ impl<S: Simd> SimdBase<S> for u8x16<S> {
             .shift_elements_right_u8x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u8 {
+        self.simd.extract_u8x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u8) -> Self {
+        self.simd.insert_u8x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u8 {
+        self.simd.extract_dyn_u8x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u8x16(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u8x16<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u8x16(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u8x16(self, rhs.simd_into(self.simd))
@@ -722,6 +1013,47 @@ impl<S: Simd> crate::SimdInt<S> for u8x16<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u8x16(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u8x16(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u8x16(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u8x16(self)
+    }
+    type Signed = i8x16<S>;
+    type Unsigned = u8x16<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u8 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u8x16([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    }
+}
+impl<S: Simd> SimdVector for u8x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
 }
 impl<S: Simd> crate::SimdCombine<S> for u8x16<S> {
     type Combined = u8x32<S>;
@@ -730,7 +1062,38 @@ impl<S: Simd> crate::SimdCombine<S> for u8x16<S> {
         self.simd.combine_u8x16(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 16 logical lanes corresponding to 8-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask8x16};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask8x16::splat(simd, true);\n    let b = mask8x16::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask8x16::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask8x16::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask8x16::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask8x16::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u8x16<S>> for u8x32<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u8x16<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u8x16(x, simd.splat_u8x16(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u8x32<S>> for u8x16<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u8x32<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u8x32(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<u8x16<S>> for u8x64<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u8x16<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u8x32(
+            simd.combine_u8x16(x, simd.splat_u8x16(0)),
+            simd.splat_u8x32(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u8x64<S>> for u8x16<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u8x64<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u8x32(simd.split_u8x64(x).0).0
+    }
+}
+#[doc = "A SIMD mask of 16 logical lanes corresponding to 8-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask8x16};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask8x16::splat(simd, true);\n    let b = mask8x16::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask8x16::from_bools(simd, &[true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false]);\n    let h = mask8x16::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask8x16::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask8x16::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask8x16::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask8x16::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask8x16<S: Simd> {
     pub(crate) val: S::mask8x16,
@@ -755,6 +1118,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask8x16<S> {
         crate::support::simd_debug_impl(f, "mask8x16", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask8x16<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 16]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask8x16<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -766,9 +1136,14 @@ impl<S: Simd> Select<mask8x16<S>> for mask8x16<S> {
     fn select(self, if_true: mask8x16<S>, if_false: mask8x16<S>) -> mask8x16<S> {
         self.simd.select_mask8x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask8x16<S>, if_false: mask8x16<S>) -> mask8x16<S> {
+        self.simd.select_relaxed_mask8x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask8x16<S> {
     type Element = i8;
+    type Int = i8x16<S>;
     const N: usize = 16;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -787,10 +1162,35 @@ impl<S: Simd> SimdMask<S> for mask8x16<S> {
         self.simd.to_bitmask_mask8x16(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i8x16<S> {
+        self.simd.to_int_mask8x16(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask8x16(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask8x16([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+            if f(8usize) { -1 } else { 0 },
+            if f(9usize) { -1 } else { 0 },
+            if f(10usize) { -1 } else { 0 },
+            if f(11usize) { -1 } else { 0 },
+            if f(12usize) { -1 } else { 0 },
+            if f(13usize) { -1 } else { 0 },
+            if f(14usize) { -1 } else { 0 },
+            if f(15usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i8]) -> Self {
         let slice: &[i8; 16] = slice.try_into().unwrap();
         simd.load_array_mask8x16(*slice)
@@ -883,6 +1283,10 @@ impl<S: Simd> Select<i16x8<S>> for mask16x8<S> {
     fn select(self, if_true: i16x8<S>, if_false: i16x8<S>) -> i16x8<S> {
         self.simd.select_i16x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i16x8<S>, if_false: i16x8<S>) -> i16x8<S> {
+        self.simd.select_relaxed_i16x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i16x8<S> {
     type Bytes = u8x16<S>;
@@ -894,10 +1298,33 @@ impl<S: Simd> Bytes for i16x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i16x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i16x8<S> {
     type Element = i16;
     const N: usize = 8;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: i16 = 0;
+    const MIN_IDENTITY: i16 = i16::MAX;
+    const MAX_IDENTITY: i16 = i16::MIN;
     type Mask = mask16x8<S>;
     type Block = i16x8<S>;
     type Array = [i16; 8];
@@ -918,11 +1345,27 @@ impl<S: Simd> SimdBase<S> for i16x8<S> {
         simd.load_array_ref_i16x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i16]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i16x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i16]) {
         self.simd
             .store_array_i16x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i16>]) -> &'a mut [i16] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i16x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i16x8(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i16) -> Self {
         simd.splat_i16x8(val)
     }
@@ -971,12 +1414,29 @@ impl<S: Simd> SimdBase<S> for i16x8<S> {
             .shift_elements_right_i16x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i16 {
+        self.simd.extract_i16x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i16) -> Self {
+        self.simd.insert_i16x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i16 {
+        self.simd.extract_dyn_i16x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i16x8(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i16x8<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i16x8(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i16x8(self, rhs.simd_into(self.simd))
@@ -1029,6 +1489,47 @@ impl<S: Simd> crate::SimdInt<S> for i16x8<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i16x8(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i16x8(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i16x8(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i16x8(self)
+    }
+    type Signed = i16x8<S>;
+    type Unsigned = u16x8<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i16 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i16x8([0, 1, 2, 3, 4, 5, 6, 7])
+    }
+}
+impl<S: Simd> SimdVector for i16x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> crate::SimdCombine<S> for i16x8<S> {
     type Combined = i16x16<S>;
@@ -1037,6 +1538,37 @@ impl<S: Simd> crate::SimdCombine<S> for i16x8<S> {
         self.simd.combine_i16x8(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i16x8<S>> for i16x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i16x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i16x8(x, simd.splat_i16x8(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i16x16<S>> for i16x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i16x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i16x16(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<i16x8<S>> for i16x32<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i16x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i16x16(
+            simd.combine_i16x8(x, simd.splat_i16x8(0)),
+            simd.splat_i16x16(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i16x32<S>> for i16x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i16x32<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i16x16(simd.split_i16x32(x).0).0
+    }
+}
 #[doc = "A SIMD vector of 8 [`u16`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u16x8};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u16x8::splat(simd, 1);\n    let b = u16x8::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u16x8::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8]);\n\n    // From an array:\n    let d = u16x8::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8]);\n\n    // From an element-wise function:\n    let e = u16x8::from_fn(simd, |i| i as u16);\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
@@ -1099,6 +1631,10 @@ impl<S: Simd> Select<u16x8<S>> for mask16x8<S> {
     fn select(self, if_true: u16x8<S>, if_false: u16x8<S>) -> u16x8<S> {
         self.simd.select_u16x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u16x8<S>, if_false: u16x8<S>) -> u16x8<S> {
+        self.simd.select_relaxed_u16x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u16x8<S> {
     type Bytes = u8x16<S>;
@@ -1110,10 +1646,33 @@ impl<S: Simd> Bytes for u16x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u16x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u16x8<S> {
     type Element = u16;
     const N: usize = 8;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: u16 = 0;
+    const MIN_IDENTITY: u16 = u16::MAX;
+    const MAX_IDENTITY: u16 = u16::MIN;
     type Mask = mask16x8<S>;
     type Block = u16x8<S>;
     type Array = [u16; 8];
@@ -1134,11 +1693,27 @@ impl<S: Simd> SimdBase<S> for u16x8<S> {
         simd.load_array_ref_u16x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u16]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u16x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u16]) {
         self.simd
             .store_array_u16x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u16>]) -> &'a mut [u16] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u16x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u16x8(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u16) -> Self {
         simd.splat_u16x8(val)
     }
@@ -1187,12 +1762,29 @@ impl<S: Simd> SimdBase<S> for u16x8<S> {
             .shift_elements_right_u16x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u16 {
+        self.simd.extract_u16x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u16) -> Self {
+        self.simd.insert_u16x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u16 {
+        self.simd.extract_dyn_u16x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u16x8(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u16x8<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u16x8(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u16x8(self, rhs.simd_into(self.simd))
@@ -1242,10 +1834,51 @@ impl<S: Simd> crate::SimdInt<S> for u16x8<S> {
         self.simd.min_u16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
-        self.simd.max_u16x8(self, rhs.simd_into(self.simd))
+    fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.max_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u16x8(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u16x8(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u16x8(self)
+    }
+    type Signed = i16x8<S>;
+    type Unsigned = u16x8<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u16 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u16x8([0, 1, 2, 3, 4, 5, 6, 7])
     }
 }
+impl<S: Simd> SimdVector for u16x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
+}
 impl<S: Simd> crate::SimdCombine<S> for u16x8<S> {
     type Combined = u16x16<S>;
     #[inline(always)]
@@ -1253,7 +1886,38 @@ impl<S: Simd> crate::SimdCombine<S> for u16x8<S> {
         self.simd.combine_u16x8(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 8 logical lanes corresponding to 16-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask16x8};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask16x8::splat(simd, true);\n    let b = mask16x8::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask16x8::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask16x8::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask16x8::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask16x8::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u16x8<S>> for u16x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u16x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u16x8(x, simd.splat_u16x8(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u16x16<S>> for u16x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u16x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u16x16(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<u16x8<S>> for u16x32<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u16x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u16x16(
+            simd.combine_u16x8(x, simd.splat_u16x8(0)),
+            simd.splat_u16x16(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u16x32<S>> for u16x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u16x32<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u16x16(simd.split_u16x32(x).0).0
+    }
+}
+#[doc = "A SIMD mask of 8 logical lanes corresponding to 16-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask16x8};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask16x8::splat(simd, true);\n    let b = mask16x8::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask16x8::from_bools(simd, &[true, false, false, false, false, false, false, false]);\n    let h = mask16x8::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask16x8::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask16x8::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask16x8::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask16x8::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask16x8<S: Simd> {
     pub(crate) val: S::mask16x8,
@@ -1278,6 +1942,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask16x8<S> {
         crate::support::simd_debug_impl(f, "mask16x8", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask16x8<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 8]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask16x8<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -1289,9 +1960,14 @@ impl<S: Simd> Select<mask16x8<S>> for mask16x8<S> {
     fn select(self, if_true: mask16x8<S>, if_false: mask16x8<S>) -> mask16x8<S> {
         self.simd.select_mask16x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask16x8<S>, if_false: mask16x8<S>) -> mask16x8<S> {
+        self.simd.select_relaxed_mask16x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask16x8<S> {
     type Element = i16;
+    type Int = i16x8<S>;
     const N: usize = 8;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -1310,10 +1986,27 @@ impl<S: Simd> SimdMask<S> for mask16x8<S> {
         self.simd.to_bitmask_mask16x8(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i16x8<S> {
+        self.simd.to_int_mask16x8(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask16x8(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask16x8([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i16]) -> Self {
         let slice: &[i16; 8] = slice.try_into().unwrap();
         simd.load_array_mask16x8(*slice)
@@ -1406,6 +2099,10 @@ impl<S: Simd> Select<i32x4<S>> for mask32x4<S> {
     fn select(self, if_true: i32x4<S>, if_false: i32x4<S>) -> i32x4<S> {
         self.simd.select_i32x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i32x4<S>, if_false: i32x4<S>) -> i32x4<S> {
+        self.simd.select_relaxed_i32x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i32x4<S> {
     type Bytes = u8x16<S>;
@@ -1417,10 +2114,33 @@ impl<S: Simd> Bytes for i32x4<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i32x4(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i32x4<S> {
     type Element = i32;
     const N: usize = 4;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: i32 = 0;
+    const MIN_IDENTITY: i32 = i32::MAX;
+    const MAX_IDENTITY: i32 = i32::MIN;
     type Mask = mask32x4<S>;
     type Block = i32x4<S>;
     type Array = [i32; 4];
@@ -1441,11 +2161,27 @@ impl<S: Simd> SimdBase<S> for i32x4<S> {
         simd.load_array_ref_i32x4(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i32x4(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i32]) {
         self.simd
             .store_array_i32x4(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i32>]) -> &'a mut [i32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i32x4(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i32x4(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i32) -> Self {
         simd.splat_i32x4(val)
     }
@@ -1485,12 +2221,29 @@ impl<S: Simd> SimdBase<S> for i32x4<S> {
             .shift_elements_right_i32x4::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i32 {
+        self.simd.extract_i32x4::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i32) -> Self {
+        self.simd.insert_i32x4::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i32 {
+        self.simd.extract_dyn_i32x4(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i32x4(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i32x4<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i32x4(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i32x4(self, rhs.simd_into(self.simd))
@@ -1543,6 +2296,47 @@ impl<S: Simd> crate::SimdInt<S> for i32x4<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i32x4(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i32x4(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i32x4(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i32x4(self)
+    }
+    type Signed = i32x4<S>;
+    type Unsigned = u32x4<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i32 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i32x4([0, 1, 2, 3])
+    }
+}
+impl<S: Simd> SimdVector for i32x4<S> {
+    type Simd = S;
+    const LANES: usize = 4;
 }
 impl<S: Simd> SimdCvtTruncate<f32x4<S>> for i32x4<S> {
     #[doc = "Convert each floating-point element to a signed 32-bit integer, truncating towards zero.\n\nOut-of-range values or NaN will produce implementation-defined results."]
@@ -1563,6 +2357,37 @@ impl<S: Simd> crate::SimdCombine<S> for i32x4<S> {
         self.simd.combine_i32x4(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i32x4<S>> for i32x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i32x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i32x4(x, simd.splat_i32x4(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i32x8<S>> for i32x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i32x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i32x8(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<i32x4<S>> for i32x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i32x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i32x8(
+            simd.combine_i32x4(x, simd.splat_i32x4(0)),
+            simd.splat_i32x8(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i32x16<S>> for i32x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i32x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i32x8(simd.split_i32x16(x).0).0
+    }
+}
 #[doc = "A SIMD vector of 4 [`u32`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u32x4};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u32x4::splat(simd, 1);\n    let b = u32x4::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u32x4::from_slice(simd, &[1, 2, 3, 4]);\n\n    // From an array:\n    let d = u32x4::simd_from(simd, [1, 2, 3, 4]);\n\n    // From an element-wise function:\n    let e = u32x4::from_fn(simd, |i| i as u32);\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
@@ -1625,6 +2450,10 @@ impl<S: Simd> Select<u32x4<S>> for mask32x4<S> {
     fn select(self, if_true: u32x4<S>, if_false: u32x4<S>) -> u32x4<S> {
         self.simd.select_u32x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u32x4<S>, if_false: u32x4<S>) -> u32x4<S> {
+        self.simd.select_relaxed_u32x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u32x4<S> {
     type Bytes = u8x16<S>;
@@ -1636,10 +2465,33 @@ impl<S: Simd> Bytes for u32x4<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u32x4(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u32x4<S> {
     type Element = u32;
     const N: usize = 4;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: u32 = 0;
+    const MIN_IDENTITY: u32 = u32::MAX;
+    const MAX_IDENTITY: u32 = u32::MIN;
     type Mask = mask32x4<S>;
     type Block = u32x4<S>;
     type Array = [u32; 4];
@@ -1660,11 +2512,27 @@ impl<S: Simd> SimdBase<S> for u32x4<S> {
         simd.load_array_ref_u32x4(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u32x4(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u32]) {
         self.simd
             .store_array_u32x4(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u32>]) -> &'a mut [u32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u32x4(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u32x4(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u32) -> Self {
         simd.splat_u32x4(val)
     }
@@ -1704,12 +2572,29 @@ impl<S: Simd> SimdBase<S> for u32x4<S> {
             .shift_elements_right_u32x4::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u32 {
+        self.simd.extract_u32x4::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u32) -> Self {
+        self.simd.insert_u32x4::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u32 {
+        self.simd.extract_dyn_u32x4(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u32x4(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u32x4<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u32x4(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u32x4(self, rhs.simd_into(self.simd))
@@ -1762,6 +2647,47 @@ impl<S: Simd> crate::SimdInt<S> for u32x4<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u32x4(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u32x4(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u32x4(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u32x4(self)
+    }
+    type Signed = i32x4<S>;
+    type Unsigned = u32x4<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u32 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u32x4([0, 1, 2, 3])
+    }
+}
+impl<S: Simd> SimdVector for u32x4<S> {
+    type Simd = S;
+    const LANES: usize = 4;
 }
 impl<S: Simd> SimdCvtTruncate<f32x4<S>> for u32x4<S> {
     #[doc = "Convert each floating-point element to an unsigned 32-bit integer, truncating towards zero.\n\nOut-of-range values or NaN will produce implementation-defined results.\n\nOn x86 platforms below AVX-512, this operation will still be slower than converting to `i32`, because there is no native instruction for converting to `u32`.\nIf you know your values fit within range of an `i32`, you should convert to an `i32` and cast to your desired datatype afterwards."]
@@ -1782,7 +2708,38 @@ impl<S: Simd> crate::SimdCombine<S> for u32x4<S> {
         self.simd.combine_u32x4(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 4 logical lanes corresponding to 32-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask32x4};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask32x4::splat(simd, true);\n    let b = mask32x4::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask32x4::from_slice(simd, &[-1, 0, 0, 0]);\n    let d = mask32x4::simd_from(simd, [-1, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask32x4::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask32x4::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u32x4<S>> for u32x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u32x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u32x4(x, simd.splat_u32x4(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u32x8<S>> for u32x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u32x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u32x8(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<u32x4<S>> for u32x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u32x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u32x8(
+            simd.combine_u32x4(x, simd.splat_u32x4(0)),
+            simd.splat_u32x8(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u32x16<S>> for u32x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u32x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u32x8(simd.split_u32x16(x).0).0
+    }
+}
+#[doc = "A SIMD mask of 4 logical lanes corresponding to 32-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask32x4};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask32x4::splat(simd, true);\n    let b = mask32x4::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask32x4::from_bools(simd, &[true, false, false, false]);\n    let h = mask32x4::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask32x4::from_slice(simd, &[-1, 0, 0, 0]);\n    let d = mask32x4::simd_from(simd, [-1, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask32x4::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask32x4::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask32x4<S: Simd> {
     pub(crate) val: S::mask32x4,
@@ -1807,6 +2764,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask32x4<S> {
         crate::support::simd_debug_impl(f, "mask32x4", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask32x4<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 4]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask32x4<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -1818,9 +2782,14 @@ impl<S: Simd> Select<mask32x4<S>> for mask32x4<S> {
     fn select(self, if_true: mask32x4<S>, if_false: mask32x4<S>) -> mask32x4<S> {
         self.simd.select_mask32x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask32x4<S>, if_false: mask32x4<S>) -> mask32x4<S> {
+        self.simd.select_relaxed_mask32x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask32x4<S> {
     type Element = i32;
+    type Int = i32x4<S>;
     const N: usize = 4;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -1839,10 +2808,23 @@ impl<S: Simd> SimdMask<S> for mask32x4<S> {
         self.simd.to_bitmask_mask32x4(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i32x4<S> {
+        self.simd.to_int_mask32x4(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask32x4(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask32x4([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i32]) -> Self {
         let slice: &[i32; 4] = slice.try_into().unwrap();
         simd.load_array_mask32x4(*slice)
@@ -1935,6 +2917,10 @@ impl<S: Simd> Select<f64x2<S>> for mask64x2<S> {
     fn select(self, if_true: f64x2<S>, if_false: f64x2<S>) -> f64x2<S> {
         self.simd.select_f64x2(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: f64x2<S>, if_false: f64x2<S>) -> f64x2<S> {
+        self.simd.select_relaxed_f64x2(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for f64x2<S> {
     type Bytes = u8x16<S>;
@@ -1946,10 +2932,33 @@ impl<S: Simd> Bytes for f64x2<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_f64x2(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = f64::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = f64::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for f64x2<S> {
     type Element = f64;
     const N: usize = 2;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: f64 = -0.0;
+    const MIN_IDENTITY: f64 = f64::INFINITY;
+    const MAX_IDENTITY: f64 = f64::NEG_INFINITY;
     type Mask = mask64x2<S>;
     type Block = f64x2<S>;
     type Array = [f64; 2];
@@ -1970,11 +2979,27 @@ impl<S: Simd> SimdBase<S> for f64x2<S> {
         simd.load_array_ref_f64x2(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[f64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_f64x2(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [f64]) {
         self.simd
             .store_array_f64x2(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<f64>]) -> &'a mut [f64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_f64x2(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_f64x2(0.0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: f64) -> Self {
         simd.splat_f64x2(val)
     }
@@ -2014,6 +3039,18 @@ impl<S: Simd> SimdBase<S> for f64x2<S> {
             .shift_elements_right_f64x2::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> f64 {
+        self.simd.extract_f64x2::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: f64) -> Self {
+        self.simd.insert_f64x2::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> f64 {
+        self.simd.extract_dyn_f64x2(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_f64x2(self, indices.simd_into(self.simd))
@@ -2107,6 +3144,21 @@ impl<S: Simd> crate::SimdFloat<S> for f64x2<S> {
             .mul_sub_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
+    fn nmul_add(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_add_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmul_sub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_sub_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_relaxed(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .mul_add_relaxed_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
     fn floor(self) -> Self {
         self.simd.floor_f64x2(self)
     }
@@ -2126,6 +3178,12 @@ impl<S: Simd> crate::SimdFloat<S> for f64x2<S> {
     fn trunc(self) -> Self {
         self.simd.trunc_f64x2(self)
     }
+    type Int = i64x2<S>;
+    type Uint = u64x2<S>;
+}
+impl<S: Simd> SimdVector for f64x2<S> {
+    type Simd = S;
+    const LANES: usize = 2;
 }
 impl<S: Simd> crate::SimdCombine<S> for f64x2<S> {
     type Combined = f64x4<S>;
@@ -2134,6 +3192,37 @@ impl<S: Simd> crate::SimdCombine<S> for f64x2<S> {
         self.simd.combine_f64x2(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<f64x2<S>> for f64x4<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: f64x2<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_f64x2(x, simd.splat_f64x2(0.0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<f64x4<S>> for f64x2<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: f64x4<S>) -> Self {
+        let simd = x.simd;
+        simd.split_f64x4(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<f64x2<S>> for f64x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: f64x2<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_f64x4(
+            simd.combine_f64x2(x, simd.splat_f64x2(0.0)),
+            simd.splat_f64x4(0.0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<f64x8<S>> for f64x2<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: f64x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_f64x4(simd.split_f64x8(x).0).0
+    }
+}
 #[doc = "A SIMD vector of 2 [`i64`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, i64x2};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = i64x2::splat(simd, 1);\n    let b = i64x2::simd_from(simd, 1);\n\n    // From a slice:\n    let c = i64x2::from_slice(simd, &[1, 2]);\n\n    // From an array:\n    let d = i64x2::simd_from(simd, [1, 2]);\n\n    // From an element-wise function:\n    let e = i64x2::from_fn(simd, |i| i as i64);\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
@@ -2196,6 +3285,10 @@ impl<S: Simd> Select<i64x2<S>> for mask64x2<S> {
     fn select(self, if_true: i64x2<S>, if_false: i64x2<S>) -> i64x2<S> {
         self.simd.select_i64x2(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i64x2<S>, if_false: i64x2<S>) -> i64x2<S> {
+        self.simd.select_relaxed_i64x2(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i64x2<S> {
     type Bytes = u8x16<S>;
@@ -2207,10 +3300,33 @@ impl<S: Simd> Bytes for i64x2<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i64x2(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i64x2<S> {
     type Element = i64;
     const N: usize = 2;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: i64 = 0;
+    const MIN_IDENTITY: i64 = i64::MAX;
+    const MAX_IDENTITY: i64 = i64::MIN;
     type Mask = mask64x2<S>;
     type Block = i64x2<S>;
     type Array = [i64; 2];
@@ -2231,11 +3347,27 @@ impl<S: Simd> SimdBase<S> for i64x2<S> {
         simd.load_array_ref_i64x2(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i64x2(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i64]) {
         self.simd
             .store_array_i64x2(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i64>]) -> &'a mut [i64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i64x2(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i64x2(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i64) -> Self {
         simd.splat_i64x2(val)
     }
@@ -2275,12 +3407,29 @@ impl<S: Simd> SimdBase<S> for i64x2<S> {
             .shift_elements_right_i64x2::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i64 {
+        self.simd.extract_i64x2::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i64) -> Self {
+        self.simd.insert_i64x2::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i64 {
+        self.simd.extract_dyn_i64x2(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i64x2(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i64x2<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i64x2(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i64x2(self, rhs.simd_into(self.simd))
@@ -2333,6 +3482,47 @@ impl<S: Simd> crate::SimdInt<S> for i64x2<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i64x2(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i64x2(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i64x2(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i64x2(self)
+    }
+    type Signed = i64x2<S>;
+    type Unsigned = u64x2<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i64 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i64x2([0, 1])
+    }
+}
+impl<S: Simd> SimdVector for i64x2<S> {
+    type Simd = S;
+    const LANES: usize = 2;
 }
 impl<S: Simd> crate::SimdCombine<S> for i64x2<S> {
     type Combined = i64x4<S>;
@@ -2341,6 +3531,37 @@ impl<S: Simd> crate::SimdCombine<S> for i64x2<S> {
         self.simd.combine_i64x2(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i64x2<S>> for i64x4<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i64x2<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i64x2(x, simd.splat_i64x2(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i64x4<S>> for i64x2<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i64x4<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i64x4(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<i64x2<S>> for i64x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i64x2<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i64x4(
+            simd.combine_i64x2(x, simd.splat_i64x2(0)),
+            simd.splat_i64x4(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i64x8<S>> for i64x2<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i64x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i64x4(simd.split_i64x8(x).0).0
+    }
+}
 #[doc = "A SIMD vector of 2 [`u64`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u64x2};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u64x2::splat(simd, 1);\n    let b = u64x2::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u64x2::from_slice(simd, &[1, 2]);\n\n    // From an array:\n    let d = u64x2::simd_from(simd, [1, 2]);\n\n    // From an element-wise function:\n    let e = u64x2::from_fn(simd, |i| i as u64);\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
@@ -2403,6 +3624,10 @@ impl<S: Simd> Select<u64x2<S>> for mask64x2<S> {
     fn select(self, if_true: u64x2<S>, if_false: u64x2<S>) -> u64x2<S> {
         self.simd.select_u64x2(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u64x2<S>, if_false: u64x2<S>) -> u64x2<S> {
+        self.simd.select_relaxed_u64x2(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u64x2<S> {
     type Bytes = u8x16<S>;
@@ -2414,10 +3639,33 @@ impl<S: Simd> Bytes for u64x2<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u64x2(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u64x2<S> {
     type Element = u64;
     const N: usize = 2;
+    const BITS: usize = 128;
+    const ADD_IDENTITY: u64 = 0;
+    const MIN_IDENTITY: u64 = u64::MAX;
+    const MAX_IDENTITY: u64 = u64::MIN;
     type Mask = mask64x2<S>;
     type Block = u64x2<S>;
     type Array = [u64; 2];
@@ -2438,9 +3686,25 @@ impl<S: Simd> SimdBase<S> for u64x2<S> {
         simd.load_array_ref_u64x2(slice.try_into().unwrap())
     }
     #[inline(always)]
-    fn store_slice(&self, slice: &mut [u64]) {
-        self.simd
-            .store_array_u64x2(*self, slice.try_into().unwrap());
+    fn try_from_slice(simd: S, slice: &[u64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u64x2(array))
+    }
+    #[inline(always)]
+    fn store_slice(&self, slice: &mut [u64]) {
+        self.simd
+            .store_array_u64x2(*self, slice.try_into().unwrap());
+    }
+    #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u64>]) -> &'a mut [u64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u64x2(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u64x2(0)
     }
     #[inline(always)]
     fn splat(simd: S, val: u64) -> Self {
@@ -2482,12 +3746,29 @@ impl<S: Simd> SimdBase<S> for u64x2<S> {
             .shift_elements_right_u64x2::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u64 {
+        self.simd.extract_u64x2::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u64) -> Self {
+        self.simd.insert_u64x2::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u64 {
+        self.simd.extract_dyn_u64x2(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u64x2(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u64x2<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u64x2(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u64x2(self, rhs.simd_into(self.simd))
@@ -2540,6 +3821,47 @@ impl<S: Simd> crate::SimdInt<S> for u64x2<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u64x2(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u64x2(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u64x2(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u64x2(self)
+    }
+    type Signed = i64x2<S>;
+    type Unsigned = u64x2<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u64 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u64x2([0, 1])
+    }
+}
+impl<S: Simd> SimdVector for u64x2<S> {
+    type Simd = S;
+    const LANES: usize = 2;
 }
 impl<S: Simd> crate::SimdCombine<S> for u64x2<S> {
     type Combined = u64x4<S>;
@@ -2548,7 +3870,38 @@ impl<S: Simd> crate::SimdCombine<S> for u64x2<S> {
         self.simd.combine_u64x2(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 2 logical lanes corresponding to 64-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask64x2};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask64x2::splat(simd, true);\n    let b = mask64x2::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask64x2::from_slice(simd, &[-1, 0]);\n    let d = mask64x2::simd_from(simd, [-1, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask64x2::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask64x2::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u64x2<S>> for u64x4<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u64x2<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u64x2(x, simd.splat_u64x2(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u64x4<S>> for u64x2<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u64x4<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u64x4(x).0
+    }
+}
+impl<S: Simd> crate::SimdZeroExtend<u64x2<S>> for u64x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u64x2<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u64x4(
+            simd.combine_u64x2(x, simd.splat_u64x2(0)),
+            simd.splat_u64x4(0),
+        )
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u64x8<S>> for u64x2<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u64x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u64x4(simd.split_u64x8(x).0).0
+    }
+}
+#[doc = "A SIMD mask of 2 logical lanes corresponding to 64-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask64x2};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask64x2::splat(simd, true);\n    let b = mask64x2::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask64x2::from_bools(simd, &[true, false]);\n    let h = mask64x2::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask64x2::from_slice(simd, &[-1, 0]);\n    let d = mask64x2::simd_from(simd, [-1, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask64x2::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask64x2::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask64x2<S: Simd> {
     pub(crate) val: S::mask64x2,
@@ -2573,6 +3926,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask64x2<S> {
         crate::support::simd_debug_impl(f, "mask64x2", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask64x2<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 2]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask64x2<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -2584,9 +3944,14 @@ impl<S: Simd> Select<mask64x2<S>> for mask64x2<S> {
     fn select(self, if_true: mask64x2<S>, if_false: mask64x2<S>) -> mask64x2<S> {
         self.simd.select_mask64x2(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask64x2<S>, if_false: mask64x2<S>) -> mask64x2<S> {
+        self.simd.select_relaxed_mask64x2(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask64x2<S> {
     type Element = i64;
+    type Int = i64x2<S>;
     const N: usize = 2;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -2605,10 +3970,21 @@ impl<S: Simd> SimdMask<S> for mask64x2<S> {
         self.simd.to_bitmask_mask64x2(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i64x2<S> {
+        self.simd.to_int_mask64x2(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask64x2(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask64x2([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i64]) -> Self {
         let slice: &[i64; 2] = slice.try_into().unwrap();
         simd.load_array_mask64x2(*slice)
@@ -2701,6 +4077,10 @@ impl<S: Simd> Select<f32x8<S>> for mask32x8<S> {
     fn select(self, if_true: f32x8<S>, if_false: f32x8<S>) -> f32x8<S> {
         self.simd.select_f32x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: f32x8<S>, if_false: f32x8<S>) -> f32x8<S> {
+        self.simd.select_relaxed_f32x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for f32x8<S> {
     type Bytes = u8x32<S>;
@@ -2712,10 +4092,33 @@ impl<S: Simd> Bytes for f32x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_f32x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = f32::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = f32::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for f32x8<S> {
     type Element = f32;
     const N: usize = 8;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: f32 = -0.0;
+    const MIN_IDENTITY: f32 = f32::INFINITY;
+    const MAX_IDENTITY: f32 = f32::NEG_INFINITY;
     type Mask = mask32x8<S>;
     type Block = f32x4<S>;
     type Array = [f32; 8];
@@ -2736,11 +4139,27 @@ impl<S: Simd> SimdBase<S> for f32x8<S> {
         simd.load_array_ref_f32x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[f32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_f32x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [f32]) {
         self.simd
             .store_array_f32x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<f32>]) -> &'a mut [f32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_f32x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_f32x8(0.0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: f32) -> Self {
         simd.splat_f32x8(val)
     }
@@ -2789,6 +4208,18 @@ impl<S: Simd> SimdBase<S> for f32x8<S> {
             .shift_elements_right_f32x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> f32 {
+        self.simd.extract_f32x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: f32) -> Self {
+        self.simd.insert_f32x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> f32 {
+        self.simd.extract_dyn_f32x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_f32x8(self, indices.simd_into(self.simd))
@@ -2882,6 +4313,21 @@ impl<S: Simd> crate::SimdFloat<S> for f32x8<S> {
             .mul_sub_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
+    fn nmul_add(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_add_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmul_sub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_sub_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_relaxed(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .mul_add_relaxed_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
     fn floor(self) -> Self {
         self.simd.floor_f32x8(self)
     }
@@ -2901,6 +4347,12 @@ impl<S: Simd> crate::SimdFloat<S> for f32x8<S> {
     fn trunc(self) -> Self {
         self.simd.trunc_f32x8(self)
     }
+    type Int = i32x8<S>;
+    type Uint = u32x8<S>;
+}
+impl<S: Simd> SimdVector for f32x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> SimdCvtFloat<u32x8<S>> for f32x8<S> {
     #[doc = "Convert each unsigned 32-bit integer element to a floating-point value.\n\nValues that cannot be exactly represented are rounded to the nearest representable value."]
@@ -2916,6 +4368,20 @@ impl<S: Simd> SimdCvtFloat<i32x8<S>> for f32x8<S> {
         x.simd.cvt_f32_i32x8(x)
     }
 }
+impl<S: Simd> crate::SimdDot<S> for f32x8<S> {
+    #[inline(always)]
+    fn dot2(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot2_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn dot3(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot3_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn dot4(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot4_f32x8(self, rhs.simd_into(self.simd))
+    }
+}
 impl<S: Simd> crate::SimdSplit<S> for f32x8<S> {
     type Split = f32x4<S>;
     #[inline(always)]
@@ -2930,6 +4396,20 @@ impl<S: Simd> crate::SimdCombine<S> for f32x8<S> {
         self.simd.combine_f32x8(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<f32x8<S>> for f32x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: f32x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_f32x8(x, simd.splat_f32x8(0.0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<f32x16<S>> for f32x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: f32x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_f32x16(x).0
+    }
+}
 #[doc = "A SIMD vector of 32 [`i8`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, i8x32};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = i8x32::splat(simd, 1);\n    let b = i8x32::simd_from(simd, 1);\n\n    // From a slice:\n    let c = i8x32::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]);\n\n    // From an array:\n    let d = i8x32::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]);\n\n    // From an element-wise function:\n    let e = i8x32::from_fn(simd, |i| i as i8);\n    # use fearless_simd::i8x16;\n    // From `Self::Block`:\n    let f = i8x32::block_splat(i8x16::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]));\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(32))]
@@ -2992,6 +4472,10 @@ impl<S: Simd> Select<i8x32<S>> for mask8x32<S> {
     fn select(self, if_true: i8x32<S>, if_false: i8x32<S>) -> i8x32<S> {
         self.simd.select_i8x32(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i8x32<S>, if_false: i8x32<S>) -> i8x32<S> {
+        self.simd.select_relaxed_i8x32(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i8x32<S> {
     type Bytes = u8x32<S>;
@@ -3003,10 +4487,22 @@ impl<S: Simd> Bytes for i8x32<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i8x32(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        Self::from_bytes(value)
+    }
 }
 impl<S: Simd> SimdBase<S> for i8x32<S> {
     type Element = i8;
     const N: usize = 32;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: i8 = 0;
+    const MIN_IDENTITY: i8 = i8::MAX;
+    const MAX_IDENTITY: i8 = i8::MIN;
     type Mask = mask8x32<S>;
     type Block = i8x16<S>;
     type Array = [i8; 32];
@@ -3027,11 +4523,27 @@ impl<S: Simd> SimdBase<S> for i8x32<S> {
         simd.load_array_ref_i8x32(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i8]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i8x32(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i8]) {
         self.simd
             .store_array_i8x32(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i8>]) -> &'a mut [i8] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i8x32(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i8x32(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i8) -> Self {
         simd.splat_i8x32(val)
     }
@@ -3104,12 +4616,29 @@ impl<S: Simd> SimdBase<S> for i8x32<S> {
             .shift_elements_right_i8x32::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i8 {
+        self.simd.extract_i8x32::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i8) -> Self {
+        self.simd.insert_i8x32::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i8 {
+        self.simd.extract_dyn_i8x32(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i8x32(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i8x32<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i8x32(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i8x32(self, rhs.simd_into(self.simd))
@@ -3162,6 +4691,50 @@ impl<S: Simd> crate::SimdInt<S> for i8x32<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i8x32(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i8x32(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i8x32(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i8x32(self)
+    }
+    type Signed = i8x32<S>;
+    type Unsigned = u8x32<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i8 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i8x32([
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ])
+    }
+}
+impl<S: Simd> SimdVector for i8x32<S> {
+    type Simd = S;
+    const LANES: usize = 32;
 }
 impl<S: Simd> crate::SimdSplit<S> for i8x32<S> {
     type Split = i8x16<S>;
@@ -3177,6 +4750,20 @@ impl<S: Simd> crate::SimdCombine<S> for i8x32<S> {
         self.simd.combine_i8x32(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i8x32<S>> for i8x64<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i8x32<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i8x32(x, simd.splat_i8x32(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i8x64<S>> for i8x32<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i8x64<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i8x64(x).0
+    }
+}
 #[doc = "A SIMD vector of 32 [`u8`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u8x32};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u8x32::splat(simd, 1);\n    let b = u8x32::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u8x32::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]);\n\n    // From an array:\n    let d = u8x32::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32]);\n\n    // From an element-wise function:\n    let e = u8x32::from_fn(simd, |i| i as u8);\n    # use fearless_simd::u8x16;\n    // From `Self::Block`:\n    let f = u8x32::block_splat(u8x16::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]));\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(32))]
@@ -3239,6 +4826,10 @@ impl<S: Simd> Select<u8x32<S>> for mask8x32<S> {
     fn select(self, if_true: u8x32<S>, if_false: u8x32<S>) -> u8x32<S> {
         self.simd.select_u8x32(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u8x32<S>, if_false: u8x32<S>) -> u8x32<S> {
+        self.simd.select_relaxed_u8x32(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u8x32<S> {
     type Bytes = u8x32<S>;
@@ -3250,10 +4841,22 @@ impl<S: Simd> Bytes for u8x32<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u8x32(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        Self::from_bytes(value)
+    }
 }
 impl<S: Simd> SimdBase<S> for u8x32<S> {
     type Element = u8;
     const N: usize = 32;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: u8 = 0;
+    const MIN_IDENTITY: u8 = u8::MAX;
+    const MAX_IDENTITY: u8 = u8::MIN;
     type Mask = mask8x32<S>;
     type Block = u8x16<S>;
     type Array = [u8; 32];
@@ -3274,11 +4877,27 @@ impl<S: Simd> SimdBase<S> for u8x32<S> {
         simd.load_array_ref_u8x32(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u8]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u8x32(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u8]) {
         self.simd
             .store_array_u8x32(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u8x32(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u8x32(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u8) -> Self {
         simd.splat_u8x32(val)
     }
@@ -3351,12 +4970,29 @@ impl<S: Simd> SimdBase<S> for u8x32<S> {
             .shift_elements_right_u8x32::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u8 {
+        self.simd.extract_u8x32::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u8) -> Self {
+        self.simd.insert_u8x32::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u8 {
+        self.simd.extract_dyn_u8x32(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u8x32(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u8x32<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u8x32(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u8x32(self, rhs.simd_into(self.simd))
@@ -3409,6 +5045,50 @@ impl<S: Simd> crate::SimdInt<S> for u8x32<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u8x32(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u8x32(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u8x32(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u8x32(self)
+    }
+    type Signed = i8x32<S>;
+    type Unsigned = u8x32<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u8 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u8x32([
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ])
+    }
+}
+impl<S: Simd> SimdVector for u8x32<S> {
+    type Simd = S;
+    const LANES: usize = 32;
 }
 impl<S: Simd> crate::SimdSplit<S> for u8x32<S> {
     type Split = u8x16<S>;
@@ -3424,7 +5104,21 @@ impl<S: Simd> crate::SimdCombine<S> for u8x32<S> {
         self.simd.combine_u8x32(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 32 logical lanes corresponding to 8-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask8x32};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask8x32::splat(simd, true);\n    let b = mask8x32::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask8x32::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask8x32::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask8x32::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask8x32::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u8x32<S>> for u8x64<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u8x32<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u8x32(x, simd.splat_u8x32(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u8x64<S>> for u8x32<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u8x64<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u8x64(x).0
+    }
+}
+#[doc = "A SIMD mask of 32 logical lanes corresponding to 8-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask8x32};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask8x32::splat(simd, true);\n    let b = mask8x32::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask8x32::from_bools(simd, &[true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false]);\n    let h = mask8x32::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask8x32::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask8x32::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask8x32::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask8x32::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask8x32<S: Simd> {
     pub(crate) val: S::mask8x32,
@@ -3449,6 +5143,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask8x32<S> {
         crate::support::simd_debug_impl(f, "mask8x32", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask8x32<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 32]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask8x32<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -3460,9 +5161,14 @@ impl<S: Simd> Select<mask8x32<S>> for mask8x32<S> {
     fn select(self, if_true: mask8x32<S>, if_false: mask8x32<S>) -> mask8x32<S> {
         self.simd.select_mask8x32(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask8x32<S>, if_false: mask8x32<S>) -> mask8x32<S> {
+        self.simd.select_relaxed_mask8x32(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask8x32<S> {
     type Element = i8;
+    type Int = i8x32<S>;
     const N: usize = 32;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -3481,10 +5187,51 @@ impl<S: Simd> SimdMask<S> for mask8x32<S> {
         self.simd.to_bitmask_mask8x32(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i8x32<S> {
+        self.simd.to_int_mask8x32(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask8x32(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask8x32([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+            if f(8usize) { -1 } else { 0 },
+            if f(9usize) { -1 } else { 0 },
+            if f(10usize) { -1 } else { 0 },
+            if f(11usize) { -1 } else { 0 },
+            if f(12usize) { -1 } else { 0 },
+            if f(13usize) { -1 } else { 0 },
+            if f(14usize) { -1 } else { 0 },
+            if f(15usize) { -1 } else { 0 },
+            if f(16usize) { -1 } else { 0 },
+            if f(17usize) { -1 } else { 0 },
+            if f(18usize) { -1 } else { 0 },
+            if f(19usize) { -1 } else { 0 },
+            if f(20usize) { -1 } else { 0 },
+            if f(21usize) { -1 } else { 0 },
+            if f(22usize) { -1 } else { 0 },
+            if f(23usize) { -1 } else { 0 },
+            if f(24usize) { -1 } else { 0 },
+            if f(25usize) { -1 } else { 0 },
+            if f(26usize) { -1 } else { 0 },
+            if f(27usize) { -1 } else { 0 },
+            if f(28usize) { -1 } else { 0 },
+            if f(29usize) { -1 } else { 0 },
+            if f(30usize) { -1 } else { 0 },
+            if f(31usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i8]) -> Self {
         let slice: &[i8; 32] = slice.try_into().unwrap();
         simd.load_array_mask8x32(*slice)
@@ -3582,6 +5329,10 @@ impl<S: Simd> Select<i16x16<S>> for mask16x16<S> {
     fn select(self, if_true: i16x16<S>, if_false: i16x16<S>) -> i16x16<S> {
         self.simd.select_i16x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i16x16<S>, if_false: i16x16<S>) -> i16x16<S> {
+        self.simd.select_relaxed_i16x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i16x16<S> {
     type Bytes = u8x32<S>;
@@ -3593,10 +5344,33 @@ impl<S: Simd> Bytes for i16x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i16x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i16x16<S> {
     type Element = i16;
     const N: usize = 16;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: i16 = 0;
+    const MIN_IDENTITY: i16 = i16::MAX;
+    const MAX_IDENTITY: i16 = i16::MIN;
     type Mask = mask16x16<S>;
     type Block = i16x8<S>;
     type Array = [i16; 16];
@@ -3617,11 +5391,27 @@ impl<S: Simd> SimdBase<S> for i16x16<S> {
         simd.load_array_ref_i16x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i16]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i16x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i16]) {
         self.simd
             .store_array_i16x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i16>]) -> &'a mut [i16] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i16x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i16x16(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i16) -> Self {
         simd.splat_i16x16(val)
     }
@@ -3679,12 +5469,29 @@ impl<S: Simd> SimdBase<S> for i16x16<S> {
             .shift_elements_right_i16x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i16 {
+        self.simd.extract_i16x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i16) -> Self {
+        self.simd.insert_i16x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i16 {
+        self.simd.extract_dyn_i16x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i16x16(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i16x16<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i16x16(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i16x16(self, rhs.simd_into(self.simd))
@@ -3714,31 +5521,72 @@ impl<S: Simd> crate::SimdInt<S> for i16x16<S> {
         self.simd.zip_high_i16x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
-        self.simd.unzip_low_i16x16(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn interleave(self, rhs: impl SimdInto<Self, S>) -> (Self, Self) {
+        self.simd.interleave_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn deinterleave(self, rhs: impl SimdInto<Self, S>) -> (Self, Self) {
+        self.simd
+            .deinterleave_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.min_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.max_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i16x16(self)
     }
     #[inline(always)]
-    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
-        self.simd.unzip_high_i16x16(self, rhs.simd_into(self.simd))
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i16x16(self)
     }
     #[inline(always)]
-    fn interleave(self, rhs: impl SimdInto<Self, S>) -> (Self, Self) {
-        self.simd.interleave_i16x16(self, rhs.simd_into(self.simd))
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i16x16(self)
     }
+    type Signed = i16x16<S>;
+    type Unsigned = u16x16<S>;
     #[inline(always)]
-    fn deinterleave(self, rhs: impl SimdInto<Self, S>) -> (Self, Self) {
-        self.simd
-            .deinterleave_i16x16(self, rhs.simd_into(self.simd))
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> Self {
-        self.simd.min_i16x16(self, rhs.simd_into(self.simd))
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
     }
+    const AND_IDENTITY: i16 = !0;
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
-        self.simd.max_i16x16(self, rhs.simd_into(self.simd))
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i16x16([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
     }
 }
+impl<S: Simd> SimdVector for i16x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
+}
 impl<S: Simd> crate::SimdSplit<S> for i16x16<S> {
     type Split = i16x8<S>;
     #[inline(always)]
@@ -3753,6 +5601,20 @@ impl<S: Simd> crate::SimdCombine<S> for i16x16<S> {
         self.simd.combine_i16x16(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i16x16<S>> for i16x32<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i16x16<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i16x16(x, simd.splat_i16x16(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i16x32<S>> for i16x16<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i16x32<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i16x32(x).0
+    }
+}
 #[doc = "A SIMD vector of 16 [`u16`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u16x16};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u16x16::splat(simd, 1);\n    let b = u16x16::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u16x16::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);\n\n    // From an array:\n    let d = u16x16::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);\n\n    // From an element-wise function:\n    let e = u16x16::from_fn(simd, |i| i as u16);\n    # use fearless_simd::u16x8;\n    // From `Self::Block`:\n    let f = u16x16::block_splat(u16x8::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8]));\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(32))]
@@ -3820,6 +5682,10 @@ impl<S: Simd> Select<u16x16<S>> for mask16x16<S> {
     fn select(self, if_true: u16x16<S>, if_false: u16x16<S>) -> u16x16<S> {
         self.simd.select_u16x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u16x16<S>, if_false: u16x16<S>) -> u16x16<S> {
+        self.simd.select_relaxed_u16x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u16x16<S> {
     type Bytes = u8x32<S>;
@@ -3831,10 +5697,33 @@ impl<S: Simd> Bytes for u16x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u16x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u16x16<S> {
     type Element = u16;
     const N: usize = 16;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: u16 = 0;
+    const MIN_IDENTITY: u16 = u16::MAX;
+    const MAX_IDENTITY: u16 = u16::MIN;
     type Mask = mask16x16<S>;
     type Block = u16x8<S>;
     type Array = [u16; 16];
@@ -3855,11 +5744,27 @@ impl<S: Simd> SimdBase<S> for u16x16<S> {
         simd.load_array_ref_u16x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u16]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u16x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u16]) {
         self.simd
             .store_array_u16x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u16>]) -> &'a mut [u16] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u16x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u16x16(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u16) -> Self {
         simd.splat_u16x16(val)
     }
@@ -3917,12 +5822,29 @@ impl<S: Simd> SimdBase<S> for u16x16<S> {
             .shift_elements_right_u16x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u16 {
+        self.simd.extract_u16x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u16) -> Self {
+        self.simd.insert_u16x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u16 {
+        self.simd.extract_dyn_u16x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u16x16(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u16x16<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u16x16(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u16x16(self, rhs.simd_into(self.simd))
@@ -3976,6 +5898,47 @@ impl<S: Simd> crate::SimdInt<S> for u16x16<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u16x16(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u16x16(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u16x16(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u16x16(self)
+    }
+    type Signed = i16x16<S>;
+    type Unsigned = u16x16<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u16 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u16x16([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    }
+}
+impl<S: Simd> SimdVector for u16x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
 }
 impl<S: Simd> crate::SimdSplit<S> for u16x16<S> {
     type Split = u16x8<S>;
@@ -3991,7 +5954,21 @@ impl<S: Simd> crate::SimdCombine<S> for u16x16<S> {
         self.simd.combine_u16x16(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 16 logical lanes corresponding to 16-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask16x16};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask16x16::splat(simd, true);\n    let b = mask16x16::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask16x16::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask16x16::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask16x16::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask16x16::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u16x16<S>> for u16x32<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u16x16<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u16x16(x, simd.splat_u16x16(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u16x32<S>> for u16x16<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u16x32<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u16x32(x).0
+    }
+}
+#[doc = "A SIMD mask of 16 logical lanes corresponding to 16-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask16x16};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask16x16::splat(simd, true);\n    let b = mask16x16::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask16x16::from_bools(simd, &[true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false]);\n    let h = mask16x16::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask16x16::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask16x16::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask16x16::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask16x16::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask16x16<S: Simd> {
     pub(crate) val: S::mask16x16,
@@ -4016,6 +5993,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask16x16<S> {
         crate::support::simd_debug_impl(f, "mask16x16", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask16x16<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 16]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask16x16<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -4027,9 +6011,14 @@ impl<S: Simd> Select<mask16x16<S>> for mask16x16<S> {
     fn select(self, if_true: mask16x16<S>, if_false: mask16x16<S>) -> mask16x16<S> {
         self.simd.select_mask16x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask16x16<S>, if_false: mask16x16<S>) -> mask16x16<S> {
+        self.simd.select_relaxed_mask16x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask16x16<S> {
     type Element = i16;
+    type Int = i16x16<S>;
     const N: usize = 16;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -4048,10 +6037,35 @@ impl<S: Simd> SimdMask<S> for mask16x16<S> {
         self.simd.to_bitmask_mask16x16(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i16x16<S> {
+        self.simd.to_int_mask16x16(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask16x16(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask16x16([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+            if f(8usize) { -1 } else { 0 },
+            if f(9usize) { -1 } else { 0 },
+            if f(10usize) { -1 } else { 0 },
+            if f(11usize) { -1 } else { 0 },
+            if f(12usize) { -1 } else { 0 },
+            if f(13usize) { -1 } else { 0 },
+            if f(14usize) { -1 } else { 0 },
+            if f(15usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i16]) -> Self {
         let slice: &[i16; 16] = slice.try_into().unwrap();
         simd.load_array_mask16x16(*slice)
@@ -4144,6 +6158,10 @@ impl<S: Simd> Select<i32x8<S>> for mask32x8<S> {
     fn select(self, if_true: i32x8<S>, if_false: i32x8<S>) -> i32x8<S> {
         self.simd.select_i32x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i32x8<S>, if_false: i32x8<S>) -> i32x8<S> {
+        self.simd.select_relaxed_i32x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i32x8<S> {
     type Bytes = u8x32<S>;
@@ -4155,10 +6173,33 @@ impl<S: Simd> Bytes for i32x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i32x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i32x8<S> {
     type Element = i32;
     const N: usize = 8;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: i32 = 0;
+    const MIN_IDENTITY: i32 = i32::MAX;
+    const MAX_IDENTITY: i32 = i32::MIN;
     type Mask = mask32x8<S>;
     type Block = i32x4<S>;
     type Array = [i32; 8];
@@ -4179,11 +6220,27 @@ impl<S: Simd> SimdBase<S> for i32x8<S> {
         simd.load_array_ref_i32x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i32x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i32]) {
         self.simd
             .store_array_i32x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i32>]) -> &'a mut [i32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i32x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i32x8(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i32) -> Self {
         simd.splat_i32x8(val)
     }
@@ -4232,12 +6289,29 @@ impl<S: Simd> SimdBase<S> for i32x8<S> {
             .shift_elements_right_i32x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i32 {
+        self.simd.extract_i32x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i32) -> Self {
+        self.simd.insert_i32x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i32 {
+        self.simd.extract_dyn_i32x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i32x8(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i32x8<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i32x8(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i32x8(self, rhs.simd_into(self.simd))
@@ -4290,6 +6364,47 @@ impl<S: Simd> crate::SimdInt<S> for i32x8<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i32x8(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i32x8(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i32x8(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i32x8(self)
+    }
+    type Signed = i32x8<S>;
+    type Unsigned = u32x8<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i32 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i32x8([0, 1, 2, 3, 4, 5, 6, 7])
+    }
+}
+impl<S: Simd> SimdVector for i32x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> SimdCvtTruncate<f32x8<S>> for i32x8<S> {
     #[doc = "Convert each floating-point element to a signed 32-bit integer, truncating towards zero.\n\nOut-of-range values or NaN will produce implementation-defined results."]
@@ -4317,6 +6432,20 @@ impl<S: Simd> crate::SimdCombine<S> for i32x8<S> {
         self.simd.combine_i32x8(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i32x8<S>> for i32x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i32x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i32x8(x, simd.splat_i32x8(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i32x16<S>> for i32x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i32x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i32x16(x).0
+    }
+}
 #[doc = "A SIMD vector of 8 [`u32`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u32x8};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u32x8::splat(simd, 1);\n    let b = u32x8::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u32x8::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8]);\n\n    // From an array:\n    let d = u32x8::simd_from(simd, [1, 2, 3, 4, 5, 6, 7, 8]);\n\n    // From an element-wise function:\n    let e = u32x8::from_fn(simd, |i| i as u32);\n    # use fearless_simd::u32x4;\n    // From `Self::Block`:\n    let f = u32x8::block_splat(u32x4::simd_from(simd, [1, 2, 3, 4]));\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(32))]
@@ -4379,6 +6508,10 @@ impl<S: Simd> Select<u32x8<S>> for mask32x8<S> {
     fn select(self, if_true: u32x8<S>, if_false: u32x8<S>) -> u32x8<S> {
         self.simd.select_u32x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u32x8<S>, if_false: u32x8<S>) -> u32x8<S> {
+        self.simd.select_relaxed_u32x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u32x8<S> {
     type Bytes = u8x32<S>;
@@ -4390,10 +6523,33 @@ impl<S: Simd> Bytes for u32x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u32x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u32x8<S> {
     type Element = u32;
     const N: usize = 8;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: u32 = 0;
+    const MIN_IDENTITY: u32 = u32::MAX;
+    const MAX_IDENTITY: u32 = u32::MIN;
     type Mask = mask32x8<S>;
     type Block = u32x4<S>;
     type Array = [u32; 8];
@@ -4414,11 +6570,27 @@ impl<S: Simd> SimdBase<S> for u32x8<S> {
         simd.load_array_ref_u32x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u32x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u32]) {
         self.simd
             .store_array_u32x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u32>]) -> &'a mut [u32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u32x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u32x8(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u32) -> Self {
         simd.splat_u32x8(val)
     }
@@ -4467,12 +6639,29 @@ impl<S: Simd> SimdBase<S> for u32x8<S> {
             .shift_elements_right_u32x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u32 {
+        self.simd.extract_u32x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u32) -> Self {
+        self.simd.insert_u32x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u32 {
+        self.simd.extract_dyn_u32x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u32x8(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u32x8<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u32x8(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u32x8(self, rhs.simd_into(self.simd))
@@ -4525,6 +6714,47 @@ impl<S: Simd> crate::SimdInt<S> for u32x8<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u32x8(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u32x8(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u32x8(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u32x8(self)
+    }
+    type Signed = i32x8<S>;
+    type Unsigned = u32x8<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u32 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u32x8([0, 1, 2, 3, 4, 5, 6, 7])
+    }
+}
+impl<S: Simd> SimdVector for u32x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> SimdCvtTruncate<f32x8<S>> for u32x8<S> {
     #[doc = "Convert each floating-point element to an unsigned 32-bit integer, truncating towards zero.\n\nOut-of-range values or NaN will produce implementation-defined results.\n\nOn x86 platforms below AVX-512, this operation will still be slower than converting to `i32`, because there is no native instruction for converting to `u32`.\nIf you know your values fit within range of an `i32`, you should convert to an `i32` and cast to your desired datatype afterwards."]
@@ -4552,7 +6782,21 @@ impl<S: Simd> crate::SimdCombine<S> for u32x8<S> {
         self.simd.combine_u32x8(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 8 logical lanes corresponding to 32-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask32x8};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask32x8::splat(simd, true);\n    let b = mask32x8::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask32x8::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask32x8::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask32x8::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask32x8::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u32x8<S>> for u32x16<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u32x8<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u32x8(x, simd.splat_u32x8(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u32x16<S>> for u32x8<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u32x16<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u32x16(x).0
+    }
+}
+#[doc = "A SIMD mask of 8 logical lanes corresponding to 32-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask32x8};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask32x8::splat(simd, true);\n    let b = mask32x8::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask32x8::from_bools(simd, &[true, false, false, false, false, false, false, false]);\n    let h = mask32x8::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask32x8::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask32x8::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask32x8::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask32x8::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask32x8<S: Simd> {
     pub(crate) val: S::mask32x8,
@@ -4577,6 +6821,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask32x8<S> {
         crate::support::simd_debug_impl(f, "mask32x8", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask32x8<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 8]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask32x8<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -4588,9 +6839,14 @@ impl<S: Simd> Select<mask32x8<S>> for mask32x8<S> {
     fn select(self, if_true: mask32x8<S>, if_false: mask32x8<S>) -> mask32x8<S> {
         self.simd.select_mask32x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask32x8<S>, if_false: mask32x8<S>) -> mask32x8<S> {
+        self.simd.select_relaxed_mask32x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask32x8<S> {
     type Element = i32;
+    type Int = i32x8<S>;
     const N: usize = 8;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -4609,10 +6865,27 @@ impl<S: Simd> SimdMask<S> for mask32x8<S> {
         self.simd.to_bitmask_mask32x8(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i32x8<S> {
+        self.simd.to_int_mask32x8(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask32x8(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask32x8([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i32]) -> Self {
         let slice: &[i32; 8] = slice.try_into().unwrap();
         simd.load_array_mask32x8(*slice)
@@ -4705,6 +6978,10 @@ impl<S: Simd> Select<f64x4<S>> for mask64x4<S> {
     fn select(self, if_true: f64x4<S>, if_false: f64x4<S>) -> f64x4<S> {
         self.simd.select_f64x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: f64x4<S>, if_false: f64x4<S>) -> f64x4<S> {
+        self.simd.select_relaxed_f64x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for f64x4<S> {
     type Bytes = u8x32<S>;
@@ -4716,10 +6993,33 @@ impl<S: Simd> Bytes for f64x4<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_f64x4(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = f64::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = f64::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for f64x4<S> {
     type Element = f64;
     const N: usize = 4;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: f64 = -0.0;
+    const MIN_IDENTITY: f64 = f64::INFINITY;
+    const MAX_IDENTITY: f64 = f64::NEG_INFINITY;
     type Mask = mask64x4<S>;
     type Block = f64x2<S>;
     type Array = [f64; 4];
@@ -4740,11 +7040,27 @@ impl<S: Simd> SimdBase<S> for f64x4<S> {
         simd.load_array_ref_f64x4(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[f64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_f64x4(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [f64]) {
         self.simd
             .store_array_f64x4(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<f64>]) -> &'a mut [f64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_f64x4(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_f64x4(0.0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: f64) -> Self {
         simd.splat_f64x4(val)
     }
@@ -4784,6 +7100,18 @@ impl<S: Simd> SimdBase<S> for f64x4<S> {
             .shift_elements_right_f64x4::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> f64 {
+        self.simd.extract_f64x4::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: f64) -> Self {
+        self.simd.insert_f64x4::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> f64 {
+        self.simd.extract_dyn_f64x4(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_f64x4(self, indices.simd_into(self.simd))
@@ -4877,6 +7205,21 @@ impl<S: Simd> crate::SimdFloat<S> for f64x4<S> {
             .mul_sub_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
+    fn nmul_add(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_add_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmul_sub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_sub_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_relaxed(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .mul_add_relaxed_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
     fn floor(self) -> Self {
         self.simd.floor_f64x4(self)
     }
@@ -4896,6 +7239,12 @@ impl<S: Simd> crate::SimdFloat<S> for f64x4<S> {
     fn trunc(self) -> Self {
         self.simd.trunc_f64x4(self)
     }
+    type Int = i64x4<S>;
+    type Uint = u64x4<S>;
+}
+impl<S: Simd> SimdVector for f64x4<S> {
+    type Simd = S;
+    const LANES: usize = 4;
 }
 impl<S: Simd> crate::SimdSplit<S> for f64x4<S> {
     type Split = f64x2<S>;
@@ -4911,6 +7260,20 @@ impl<S: Simd> crate::SimdCombine<S> for f64x4<S> {
         self.simd.combine_f64x4(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<f64x4<S>> for f64x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: f64x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_f64x4(x, simd.splat_f64x4(0.0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<f64x8<S>> for f64x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: f64x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_f64x8(x).0
+    }
+}
 #[doc = "A SIMD vector of 4 [`i64`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, i64x4};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = i64x4::splat(simd, 1);\n    let b = i64x4::simd_from(simd, 1);\n\n    // From a slice:\n    let c = i64x4::from_slice(simd, &[1, 2, 3, 4]);\n\n    // From an array:\n    let d = i64x4::simd_from(simd, [1, 2, 3, 4]);\n\n    // From an element-wise function:\n    let e = i64x4::from_fn(simd, |i| i as i64);\n    # use fearless_simd::i64x2;\n    // From `Self::Block`:\n    let f = i64x4::block_splat(i64x2::simd_from(simd, [1, 2]));\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(32))]
@@ -4973,6 +7336,10 @@ impl<S: Simd> Select<i64x4<S>> for mask64x4<S> {
     fn select(self, if_true: i64x4<S>, if_false: i64x4<S>) -> i64x4<S> {
         self.simd.select_i64x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i64x4<S>, if_false: i64x4<S>) -> i64x4<S> {
+        self.simd.select_relaxed_i64x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i64x4<S> {
     type Bytes = u8x32<S>;
@@ -4984,10 +7351,33 @@ impl<S: Simd> Bytes for i64x4<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i64x4(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i64x4<S> {
     type Element = i64;
     const N: usize = 4;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: i64 = 0;
+    const MIN_IDENTITY: i64 = i64::MAX;
+    const MAX_IDENTITY: i64 = i64::MIN;
     type Mask = mask64x4<S>;
     type Block = i64x2<S>;
     type Array = [i64; 4];
@@ -5008,11 +7398,27 @@ impl<S: Simd> SimdBase<S> for i64x4<S> {
         simd.load_array_ref_i64x4(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i64x4(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i64]) {
         self.simd
             .store_array_i64x4(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i64>]) -> &'a mut [i64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i64x4(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i64x4(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i64) -> Self {
         simd.splat_i64x4(val)
     }
@@ -5052,12 +7458,29 @@ impl<S: Simd> SimdBase<S> for i64x4<S> {
             .shift_elements_right_i64x4::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i64 {
+        self.simd.extract_i64x4::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i64) -> Self {
+        self.simd.insert_i64x4::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i64 {
+        self.simd.extract_dyn_i64x4(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i64x4(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i64x4<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i64x4(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i64x4(self, rhs.simd_into(self.simd))
@@ -5110,6 +7533,47 @@ impl<S: Simd> crate::SimdInt<S> for i64x4<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i64x4(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i64x4(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i64x4(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i64x4(self)
+    }
+    type Signed = i64x4<S>;
+    type Unsigned = u64x4<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i64 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i64x4([0, 1, 2, 3])
+    }
+}
+impl<S: Simd> SimdVector for i64x4<S> {
+    type Simd = S;
+    const LANES: usize = 4;
 }
 impl<S: Simd> crate::SimdSplit<S> for i64x4<S> {
     type Split = i64x2<S>;
@@ -5125,6 +7589,20 @@ impl<S: Simd> crate::SimdCombine<S> for i64x4<S> {
         self.simd.combine_i64x4(self, rhs.simd_into(self.simd))
     }
 }
+impl<S: Simd> crate::SimdZeroExtend<i64x4<S>> for i64x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: i64x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_i64x4(x, simd.splat_i64x4(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<i64x8<S>> for i64x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: i64x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_i64x8(x).0
+    }
+}
 #[doc = "A SIMD vector of 4 [`u64`] elements.\n\nYou may construct this vector type using the [`Self::splat`], [`Self::from_slice`], [`Self::simd_from`], [`Self::from_fn`], and [`Self::block_splat`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, u64x4};\nfn construct_simd<S: Simd>(simd: S) {\n    // From a single scalar value:\n    let a = u64x4::splat(simd, 1);\n    let b = u64x4::simd_from(simd, 1);\n\n    // From a slice:\n    let c = u64x4::from_slice(simd, &[1, 2, 3, 4]);\n\n    // From an array:\n    let d = u64x4::simd_from(simd, [1, 2, 3, 4]);\n\n    // From an element-wise function:\n    let e = u64x4::from_fn(simd, |i| i as u64);\n    # use fearless_simd::u64x2;\n    // From `Self::Block`:\n    let f = u64x4::block_splat(u64x2::simd_from(simd, [1, 2]));\n}\n```"]
 #[derive(Clone, Copy)]
 #[repr(C, align(32))]
@@ -5187,6 +7665,10 @@ impl<S: Simd> Select<u64x4<S>> for mask64x4<S> {
     fn select(self, if_true: u64x4<S>, if_false: u64x4<S>) -> u64x4<S> {
         self.simd.select_u64x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u64x4<S>, if_false: u64x4<S>) -> u64x4<S> {
+        self.simd.select_relaxed_u64x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u64x4<S> {
     type Bytes = u8x32<S>;
@@ -5198,10 +7680,33 @@ impl<S: Simd> Bytes for u64x4<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u64x4(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u64x4<S> {
     type Element = u64;
     const N: usize = 4;
+    const BITS: usize = 256;
+    const ADD_IDENTITY: u64 = 0;
+    const MIN_IDENTITY: u64 = u64::MAX;
+    const MAX_IDENTITY: u64 = u64::MIN;
     type Mask = mask64x4<S>;
     type Block = u64x2<S>;
     type Array = [u64; 4];
@@ -5222,11 +7727,27 @@ impl<S: Simd> SimdBase<S> for u64x4<S> {
         simd.load_array_ref_u64x4(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u64x4(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u64]) {
         self.simd
             .store_array_u64x4(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u64>]) -> &'a mut [u64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u64x4(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u64x4(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u64) -> Self {
         simd.splat_u64x4(val)
     }
@@ -5266,12 +7787,29 @@ impl<S: Simd> SimdBase<S> for u64x4<S> {
             .shift_elements_right_u64x4::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u64 {
+        self.simd.extract_u64x4::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u64) -> Self {
+        self.simd.insert_u64x4::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u64 {
+        self.simd.extract_dyn_u64x4(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u64x4(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u64x4<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u64x4(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u64x4(self, rhs.simd_into(self.simd))
@@ -5324,6 +7862,47 @@ impl<S: Simd> crate::SimdInt<S> for u64x4<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u64x4(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u64x4(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u64x4(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u64x4(self)
+    }
+    type Signed = i64x4<S>;
+    type Unsigned = u64x4<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u64 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u64x4([0, 1, 2, 3])
+    }
+}
+impl<S: Simd> SimdVector for u64x4<S> {
+    type Simd = S;
+    const LANES: usize = 4;
 }
 impl<S: Simd> crate::SimdSplit<S> for u64x4<S> {
     type Split = u64x2<S>;
@@ -5339,7 +7918,21 @@ impl<S: Simd> crate::SimdCombine<S> for u64x4<S> {
         self.simd.combine_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-#[doc = "A SIMD mask of 4 logical lanes corresponding to 64-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask64x4};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask64x4::splat(simd, true);\n    let b = mask64x4::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask64x4::from_slice(simd, &[-1, 0, 0, 0]);\n    let d = mask64x4::simd_from(simd, [-1, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask64x4::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask64x4::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+impl<S: Simd> crate::SimdZeroExtend<u64x4<S>> for u64x8<S> {
+    #[inline(always)]
+    fn zero_extend_from(x: u64x4<S>) -> Self {
+        let simd = x.simd;
+        simd.combine_u64x4(x, simd.splat_u64x4(0))
+    }
+}
+impl<S: Simd> crate::SimdTruncateLanes<u64x8<S>> for u64x4<S> {
+    #[inline(always)]
+    fn truncate_lanes_from(x: u64x8<S>) -> Self {
+        let simd = x.simd;
+        simd.split_u64x8(x).0
+    }
+}
+#[doc = "A SIMD mask of 4 logical lanes corresponding to 64-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask64x4};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask64x4::splat(simd, true);\n    let b = mask64x4::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask64x4::from_bools(simd, &[true, false, false, false]);\n    let h = mask64x4::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask64x4::from_slice(simd, &[-1, 0, 0, 0]);\n    let d = mask64x4::simd_from(simd, [-1, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask64x4::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask64x4::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask64x4<S: Simd> {
     pub(crate) val: S::mask64x4,
@@ -5364,6 +7957,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask64x4<S> {
         crate::support::simd_debug_impl(f, "mask64x4", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask64x4<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 4]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask64x4<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -5375,9 +7975,14 @@ impl<S: Simd> Select<mask64x4<S>> for mask64x4<S> {
     fn select(self, if_true: mask64x4<S>, if_false: mask64x4<S>) -> mask64x4<S> {
         self.simd.select_mask64x4(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask64x4<S>, if_false: mask64x4<S>) -> mask64x4<S> {
+        self.simd.select_relaxed_mask64x4(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask64x4<S> {
     type Element = i64;
+    type Int = i64x4<S>;
     const N: usize = 4;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -5396,10 +8001,23 @@ impl<S: Simd> SimdMask<S> for mask64x4<S> {
         self.simd.to_bitmask_mask64x4(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i64x4<S> {
+        self.simd.to_int_mask64x4(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask64x4(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask64x4([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i64]) -> Self {
         let slice: &[i64; 4] = slice.try_into().unwrap();
         simd.load_array_mask64x4(*slice)
@@ -5497,6 +8115,10 @@ impl<S: Simd> Select<f32x16<S>> for mask32x16<S> {
     fn select(self, if_true: f32x16<S>, if_false: f32x16<S>) -> f32x16<S> {
         self.simd.select_f32x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: f32x16<S>, if_false: f32x16<S>) -> f32x16<S> {
+        self.simd.select_relaxed_f32x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for f32x16<S> {
     type Bytes = u8x64<S>;
@@ -5508,10 +8130,33 @@ impl<S: Simd> Bytes for f32x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_f32x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = f32::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = f32::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for f32x16<S> {
     type Element = f32;
     const N: usize = 16;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: f32 = -0.0;
+    const MIN_IDENTITY: f32 = f32::INFINITY;
+    const MAX_IDENTITY: f32 = f32::NEG_INFINITY;
     type Mask = mask32x16<S>;
     type Block = f32x4<S>;
     type Array = [f32; 16];
@@ -5532,11 +8177,27 @@ impl<S: Simd> SimdBase<S> for f32x16<S> {
         simd.load_array_ref_f32x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[f32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_f32x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [f32]) {
         self.simd
             .store_array_f32x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<f32>]) -> &'a mut [f32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_f32x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_f32x16(0.0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: f32) -> Self {
         simd.splat_f32x16(val)
     }
@@ -5595,6 +8256,18 @@ impl<S: Simd> SimdBase<S> for f32x16<S> {
             .shift_elements_right_f32x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> f32 {
+        self.simd.extract_f32x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: f32) -> Self {
+        self.simd.insert_f32x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> f32 {
+        self.simd.extract_dyn_f32x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_f32x16(self, indices.simd_into(self.simd))
@@ -5689,6 +8362,21 @@ impl<S: Simd> crate::SimdFloat<S> for f32x16<S> {
             .mul_sub_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
+    fn nmul_add(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_add_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmul_sub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_sub_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_relaxed(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .mul_add_relaxed_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
     fn floor(self) -> Self {
         self.simd.floor_f32x16(self)
     }
@@ -5708,6 +8396,12 @@ impl<S: Simd> crate::SimdFloat<S> for f32x16<S> {
     fn trunc(self) -> Self {
         self.simd.trunc_f32x16(self)
     }
+    type Int = i32x16<S>;
+    type Uint = u32x16<S>;
+}
+impl<S: Simd> SimdVector for f32x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
 }
 impl<S: Simd> SimdCvtFloat<u32x16<S>> for f32x16<S> {
     #[doc = "Convert each unsigned 32-bit integer element to a floating-point value.\n\nValues that cannot be exactly represented are rounded to the nearest representable value."]
@@ -5723,6 +8417,20 @@ impl<S: Simd> SimdCvtFloat<i32x16<S>> for f32x16<S> {
         x.simd.cvt_f32_i32x16(x)
     }
 }
+impl<S: Simd> crate::SimdDot<S> for f32x16<S> {
+    #[inline(always)]
+    fn dot2(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot2_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn dot3(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot3_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn dot4(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.dot4_f32x16(self, rhs.simd_into(self.simd))
+    }
+}
 impl<S: Simd> crate::SimdSplit<S> for f32x16<S> {
     type Split = f32x8<S>;
     #[inline(always)]
@@ -5792,6 +8500,10 @@ impl<S: Simd> Select<i8x64<S>> for mask8x64<S> {
     fn select(self, if_true: i8x64<S>, if_false: i8x64<S>) -> i8x64<S> {
         self.simd.select_i8x64(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i8x64<S>, if_false: i8x64<S>) -> i8x64<S> {
+        self.simd.select_relaxed_i8x64(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i8x64<S> {
     type Bytes = u8x64<S>;
@@ -5803,10 +8515,22 @@ impl<S: Simd> Bytes for i8x64<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i8x64(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        Self::from_bytes(value)
+    }
 }
 impl<S: Simd> SimdBase<S> for i8x64<S> {
     type Element = i8;
     const N: usize = 64;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: i8 = 0;
+    const MIN_IDENTITY: i8 = i8::MAX;
+    const MAX_IDENTITY: i8 = i8::MIN;
     type Mask = mask8x64<S>;
     type Block = i8x16<S>;
     type Array = [i8; 64];
@@ -5827,11 +8551,27 @@ impl<S: Simd> SimdBase<S> for i8x64<S> {
         simd.load_array_ref_i8x64(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i8]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i8x64(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i8]) {
         self.simd
             .store_array_i8x64(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i8>]) -> &'a mut [i8] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i8x64(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i8x64(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i8) -> Self {
         simd.splat_i8x64(val)
     }
@@ -5937,12 +8677,29 @@ impl<S: Simd> SimdBase<S> for i8x64<S> {
             .shift_elements_right_i8x64::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i8 {
+        self.simd.extract_i8x64::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i8) -> Self {
+        self.simd.insert_i8x64::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i8 {
+        self.simd.extract_dyn_i8x64(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i8x64(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i8x64<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i8x64(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i8x64(self, rhs.simd_into(self.simd))
@@ -5995,6 +8752,51 @@ impl<S: Simd> crate::SimdInt<S> for i8x64<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i8x64(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i8x64(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i8x64(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i8x64(self)
+    }
+    type Signed = i8x64<S>;
+    type Unsigned = u8x64<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i8 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i8x64([
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+        ])
+    }
+}
+impl<S: Simd> SimdVector for i8x64<S> {
+    type Simd = S;
+    const LANES: usize = 64;
 }
 impl<S: Simd> crate::SimdSplit<S> for i8x64<S> {
     type Split = i8x32<S>;
@@ -6065,6 +8867,10 @@ impl<S: Simd> Select<u8x64<S>> for mask8x64<S> {
     fn select(self, if_true: u8x64<S>, if_false: u8x64<S>) -> u8x64<S> {
         self.simd.select_u8x64(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u8x64<S>, if_false: u8x64<S>) -> u8x64<S> {
+        self.simd.select_relaxed_u8x64(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u8x64<S> {
     type Bytes = u8x64<S>;
@@ -6076,10 +8882,22 @@ impl<S: Simd> Bytes for u8x64<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u8x64(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        Self::from_bytes(value)
+    }
 }
 impl<S: Simd> SimdBase<S> for u8x64<S> {
     type Element = u8;
     const N: usize = 64;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: u8 = 0;
+    const MIN_IDENTITY: u8 = u8::MAX;
+    const MAX_IDENTITY: u8 = u8::MIN;
     type Mask = mask8x64<S>;
     type Block = u8x16<S>;
     type Array = [u8; 64];
@@ -6100,11 +8918,27 @@ impl<S: Simd> SimdBase<S> for u8x64<S> {
         simd.load_array_ref_u8x64(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u8]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u8x64(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u8]) {
         self.simd
             .store_array_u8x64(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u8x64(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u8x64(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u8) -> Self {
         simd.splat_u8x64(val)
     }
@@ -6210,12 +9044,29 @@ impl<S: Simd> SimdBase<S> for u8x64<S> {
             .shift_elements_right_u8x64::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u8 {
+        self.simd.extract_u8x64::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u8) -> Self {
+        self.simd.insert_u8x64::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u8 {
+        self.simd.extract_dyn_u8x64(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u8x64(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u8x64<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u8x64(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u8x64(self, rhs.simd_into(self.simd))
@@ -6268,6 +9119,51 @@ impl<S: Simd> crate::SimdInt<S> for u8x64<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u8x64(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u8x64(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u8x64(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u8x64(self)
+    }
+    type Signed = i8x64<S>;
+    type Unsigned = u8x64<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u8 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u8x64([
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+        ])
+    }
+}
+impl<S: Simd> SimdVector for u8x64<S> {
+    type Simd = S;
+    const LANES: usize = 64;
 }
 impl<S: Simd> crate::SimdSplit<S> for u8x64<S> {
     type Split = u8x32<S>;
@@ -6276,7 +9172,7 @@ impl<S: Simd> crate::SimdSplit<S> for u8x64<S> {
         self.simd.split_u8x64(self)
     }
 }
-#[doc = "A SIMD mask of 64 logical lanes corresponding to 8-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask8x64};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask8x64::splat(simd, true);\n    let b = mask8x64::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask8x64::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask8x64::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask8x64::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask8x64::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+#[doc = "A SIMD mask of 64 logical lanes corresponding to 8-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask8x64};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask8x64::splat(simd, true);\n    let b = mask8x64::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask8x64::from_bools(simd, &[true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false]);\n    let h = mask8x64::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask8x64::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask8x64::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask8x64::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask8x64::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask8x64<S: Simd> {
     pub(crate) val: S::mask8x64,
@@ -6301,6 +9197,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask8x64<S> {
         crate::support::simd_debug_impl(f, "mask8x64", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask8x64<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 64]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask8x64<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -6312,9 +9215,14 @@ impl<S: Simd> Select<mask8x64<S>> for mask8x64<S> {
     fn select(self, if_true: mask8x64<S>, if_false: mask8x64<S>) -> mask8x64<S> {
         self.simd.select_mask8x64(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask8x64<S>, if_false: mask8x64<S>) -> mask8x64<S> {
+        self.simd.select_relaxed_mask8x64(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask8x64<S> {
     type Element = i8;
+    type Int = i8x64<S>;
     const N: usize = 64;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -6333,10 +9241,83 @@ impl<S: Simd> SimdMask<S> for mask8x64<S> {
         self.simd.to_bitmask_mask8x64(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i8x64<S> {
+        self.simd.to_int_mask8x64(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask8x64(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask8x64([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+            if f(8usize) { -1 } else { 0 },
+            if f(9usize) { -1 } else { 0 },
+            if f(10usize) { -1 } else { 0 },
+            if f(11usize) { -1 } else { 0 },
+            if f(12usize) { -1 } else { 0 },
+            if f(13usize) { -1 } else { 0 },
+            if f(14usize) { -1 } else { 0 },
+            if f(15usize) { -1 } else { 0 },
+            if f(16usize) { -1 } else { 0 },
+            if f(17usize) { -1 } else { 0 },
+            if f(18usize) { -1 } else { 0 },
+            if f(19usize) { -1 } else { 0 },
+            if f(20usize) { -1 } else { 0 },
+            if f(21usize) { -1 } else { 0 },
+            if f(22usize) { -1 } else { 0 },
+            if f(23usize) { -1 } else { 0 },
+            if f(24usize) { -1 } else { 0 },
+            if f(25usize) { -1 } else { 0 },
+            if f(26usize) { -1 } else { 0 },
+            if f(27usize) { -1 } else { 0 },
+            if f(28usize) { -1 } else { 0 },
+            if f(29usize) { -1 } else { 0 },
+            if f(30usize) { -1 } else { 0 },
+            if f(31usize) { -1 } else { 0 },
+            if f(32usize) { -1 } else { 0 },
+            if f(33usize) { -1 } else { 0 },
+            if f(34usize) { -1 } else { 0 },
+            if f(35usize) { -1 } else { 0 },
+            if f(36usize) { -1 } else { 0 },
+            if f(37usize) { -1 } else { 0 },
+            if f(38usize) { -1 } else { 0 },
+            if f(39usize) { -1 } else { 0 },
+            if f(40usize) { -1 } else { 0 },
+            if f(41usize) { -1 } else { 0 },
+            if f(42usize) { -1 } else { 0 },
+            if f(43usize) { -1 } else { 0 },
+            if f(44usize) { -1 } else { 0 },
+            if f(45usize) { -1 } else { 0 },
+            if f(46usize) { -1 } else { 0 },
+            if f(47usize) { -1 } else { 0 },
+            if f(48usize) { -1 } else { 0 },
+            if f(49usize) { -1 } else { 0 },
+            if f(50usize) { -1 } else { 0 },
+            if f(51usize) { -1 } else { 0 },
+            if f(52usize) { -1 } else { 0 },
+            if f(53usize) { -1 } else { 0 },
+            if f(54usize) { -1 } else { 0 },
+            if f(55usize) { -1 } else { 0 },
+            if f(56usize) { -1 } else { 0 },
+            if f(57usize) { -1 } else { 0 },
+            if f(58usize) { -1 } else { 0 },
+            if f(59usize) { -1 } else { 0 },
+            if f(60usize) { -1 } else { 0 },
+            if f(61usize) { -1 } else { 0 },
+            if f(62usize) { -1 } else { 0 },
+            if f(63usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i8]) -> Self {
         let slice: &[i8; 64] = slice.try_into().unwrap();
         simd.load_array_mask8x64(*slice)
@@ -6434,6 +9415,10 @@ impl<S: Simd> Select<i16x32<S>> for mask16x32<S> {
     fn select(self, if_true: i16x32<S>, if_false: i16x32<S>) -> i16x32<S> {
         self.simd.select_i16x32(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i16x32<S>, if_false: i16x32<S>) -> i16x32<S> {
+        self.simd.select_relaxed_i16x32(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i16x32<S> {
     type Bytes = u8x64<S>;
@@ -6445,10 +9430,33 @@ impl<S: Simd> Bytes for i16x32<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i16x32(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i16x32<S> {
     type Element = i16;
     const N: usize = 32;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: i16 = 0;
+    const MIN_IDENTITY: i16 = i16::MAX;
+    const MAX_IDENTITY: i16 = i16::MIN;
     type Mask = mask16x32<S>;
     type Block = i16x8<S>;
     type Array = [i16; 32];
@@ -6469,11 +9477,27 @@ impl<S: Simd> SimdBase<S> for i16x32<S> {
         simd.load_array_ref_i16x32(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i16]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i16x32(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i16]) {
         self.simd
             .store_array_i16x32(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i16>]) -> &'a mut [i16] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i16x32(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i16x32(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i16) -> Self {
         simd.splat_i16x32(val)
     }
@@ -6548,12 +9572,29 @@ impl<S: Simd> SimdBase<S> for i16x32<S> {
             .shift_elements_right_i16x32::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i16 {
+        self.simd.extract_i16x32::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i16) -> Self {
+        self.simd.insert_i16x32::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i16 {
+        self.simd.extract_dyn_i16x32(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i16x32(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i16x32<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i16x32(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i16x32(self, rhs.simd_into(self.simd))
@@ -6607,6 +9648,50 @@ impl<S: Simd> crate::SimdInt<S> for i16x32<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i16x32(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i16x32(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i16x32(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i16x32(self)
+    }
+    type Signed = i16x32<S>;
+    type Unsigned = u16x32<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i16 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i16x32([
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ])
+    }
+}
+impl<S: Simd> SimdVector for i16x32<S> {
+    type Simd = S;
+    const LANES: usize = 32;
 }
 impl<S: Simd> crate::SimdSplit<S> for i16x32<S> {
     type Split = i16x16<S>;
@@ -6682,6 +9767,10 @@ impl<S: Simd> Select<u16x32<S>> for mask16x32<S> {
     fn select(self, if_true: u16x32<S>, if_false: u16x32<S>) -> u16x32<S> {
         self.simd.select_u16x32(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u16x32<S>, if_false: u16x32<S>) -> u16x32<S> {
+        self.simd.select_relaxed_u16x32(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u16x32<S> {
     type Bytes = u8x64<S>;
@@ -6693,10 +9782,33 @@ impl<S: Simd> Bytes for u16x32<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u16x32(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u16x32<S> {
     type Element = u16;
     const N: usize = 32;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: u16 = 0;
+    const MIN_IDENTITY: u16 = u16::MAX;
+    const MAX_IDENTITY: u16 = u16::MIN;
     type Mask = mask16x32<S>;
     type Block = u16x8<S>;
     type Array = [u16; 32];
@@ -6717,9 +9829,25 @@ impl<S: Simd> SimdBase<S> for u16x32<S> {
         simd.load_array_ref_u16x32(slice.try_into().unwrap())
     }
     #[inline(always)]
-    fn store_slice(&self, slice: &mut [u16]) {
-        self.simd
-            .store_array_u16x32(*self, slice.try_into().unwrap());
+    fn try_from_slice(simd: S, slice: &[u16]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u16x32(array))
+    }
+    #[inline(always)]
+    fn store_slice(&self, slice: &mut [u16]) {
+        self.simd
+            .store_array_u16x32(*self, slice.try_into().unwrap());
+    }
+    #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u16>]) -> &'a mut [u16] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u16x32(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u16x32(0)
     }
     #[inline(always)]
     fn splat(simd: S, val: u16) -> Self {
@@ -6796,12 +9924,29 @@ impl<S: Simd> SimdBase<S> for u16x32<S> {
             .shift_elements_right_u16x32::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u16 {
+        self.simd.extract_u16x32::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u16) -> Self {
+        self.simd.insert_u16x32::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u16 {
+        self.simd.extract_dyn_u16x32(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u16x32(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u16x32<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u16x32(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u16x32(self, rhs.simd_into(self.simd))
@@ -6855,6 +10000,50 @@ impl<S: Simd> crate::SimdInt<S> for u16x32<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u16x32(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u16x32(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u16x32(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u16x32(self)
+    }
+    type Signed = i16x32<S>;
+    type Unsigned = u16x32<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u16 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u16x32([
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ])
+    }
+}
+impl<S: Simd> SimdVector for u16x32<S> {
+    type Simd = S;
+    const LANES: usize = 32;
 }
 impl<S: Simd> crate::SimdSplit<S> for u16x32<S> {
     type Split = u16x16<S>;
@@ -6863,7 +10052,7 @@ impl<S: Simd> crate::SimdSplit<S> for u16x32<S> {
         self.simd.split_u16x32(self)
     }
 }
-#[doc = "A SIMD mask of 32 logical lanes corresponding to 16-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask16x32};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask16x32::splat(simd, true);\n    let b = mask16x32::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask16x32::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask16x32::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask16x32::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask16x32::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+#[doc = "A SIMD mask of 32 logical lanes corresponding to 16-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask16x32};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask16x32::splat(simd, true);\n    let b = mask16x32::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask16x32::from_bools(simd, &[true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false]);\n    let h = mask16x32::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask16x32::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask16x32::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask16x32::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask16x32::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask16x32<S: Simd> {
     pub(crate) val: S::mask16x32,
@@ -6888,6 +10077,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask16x32<S> {
         crate::support::simd_debug_impl(f, "mask16x32", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask16x32<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 32]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask16x32<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -6899,9 +10095,14 @@ impl<S: Simd> Select<mask16x32<S>> for mask16x32<S> {
     fn select(self, if_true: mask16x32<S>, if_false: mask16x32<S>) -> mask16x32<S> {
         self.simd.select_mask16x32(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask16x32<S>, if_false: mask16x32<S>) -> mask16x32<S> {
+        self.simd.select_relaxed_mask16x32(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask16x32<S> {
     type Element = i16;
+    type Int = i16x32<S>;
     const N: usize = 32;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -6920,10 +10121,51 @@ impl<S: Simd> SimdMask<S> for mask16x32<S> {
         self.simd.to_bitmask_mask16x32(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i16x32<S> {
+        self.simd.to_int_mask16x32(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask16x32(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask16x32([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+            if f(8usize) { -1 } else { 0 },
+            if f(9usize) { -1 } else { 0 },
+            if f(10usize) { -1 } else { 0 },
+            if f(11usize) { -1 } else { 0 },
+            if f(12usize) { -1 } else { 0 },
+            if f(13usize) { -1 } else { 0 },
+            if f(14usize) { -1 } else { 0 },
+            if f(15usize) { -1 } else { 0 },
+            if f(16usize) { -1 } else { 0 },
+            if f(17usize) { -1 } else { 0 },
+            if f(18usize) { -1 } else { 0 },
+            if f(19usize) { -1 } else { 0 },
+            if f(20usize) { -1 } else { 0 },
+            if f(21usize) { -1 } else { 0 },
+            if f(22usize) { -1 } else { 0 },
+            if f(23usize) { -1 } else { 0 },
+            if f(24usize) { -1 } else { 0 },
+            if f(25usize) { -1 } else { 0 },
+            if f(26usize) { -1 } else { 0 },
+            if f(27usize) { -1 } else { 0 },
+            if f(28usize) { -1 } else { 0 },
+            if f(29usize) { -1 } else { 0 },
+            if f(30usize) { -1 } else { 0 },
+            if f(31usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i16]) -> Self {
         let slice: &[i16; 32] = slice.try_into().unwrap();
         simd.load_array_mask16x32(*slice)
@@ -7021,6 +10263,10 @@ impl<S: Simd> Select<i32x16<S>> for mask32x16<S> {
     fn select(self, if_true: i32x16<S>, if_false: i32x16<S>) -> i32x16<S> {
         self.simd.select_i32x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i32x16<S>, if_false: i32x16<S>) -> i32x16<S> {
+        self.simd.select_relaxed_i32x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i32x16<S> {
     type Bytes = u8x64<S>;
@@ -7032,10 +10278,33 @@ impl<S: Simd> Bytes for i32x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i32x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i32x16<S> {
     type Element = i32;
     const N: usize = 16;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: i32 = 0;
+    const MIN_IDENTITY: i32 = i32::MAX;
+    const MAX_IDENTITY: i32 = i32::MIN;
     type Mask = mask32x16<S>;
     type Block = i32x4<S>;
     type Array = [i32; 16];
@@ -7056,11 +10325,27 @@ impl<S: Simd> SimdBase<S> for i32x16<S> {
         simd.load_array_ref_i32x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i32x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i32]) {
         self.simd
             .store_array_i32x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i32>]) -> &'a mut [i32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i32x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i32x16(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i32) -> Self {
         simd.splat_i32x16(val)
     }
@@ -7119,12 +10404,29 @@ impl<S: Simd> SimdBase<S> for i32x16<S> {
             .shift_elements_right_i32x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i32 {
+        self.simd.extract_i32x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i32) -> Self {
+        self.simd.insert_i32x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i32 {
+        self.simd.extract_dyn_i32x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i32x16(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i32x16<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i32x16(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i32x16(self, rhs.simd_into(self.simd))
@@ -7178,6 +10480,47 @@ impl<S: Simd> crate::SimdInt<S> for i32x16<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i32x16(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i32x16(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i32x16(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i32x16(self)
+    }
+    type Signed = i32x16<S>;
+    type Unsigned = u32x16<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i32 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i32x16([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    }
+}
+impl<S: Simd> SimdVector for i32x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
 }
 impl<S: Simd> SimdCvtTruncate<f32x16<S>> for i32x16<S> {
     #[doc = "Convert each floating-point element to a signed 32-bit integer, truncating towards zero.\n\nOut-of-range values or NaN will produce implementation-defined results."]
@@ -7265,6 +10608,10 @@ impl<S: Simd> Select<u32x16<S>> for mask32x16<S> {
     fn select(self, if_true: u32x16<S>, if_false: u32x16<S>) -> u32x16<S> {
         self.simd.select_u32x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u32x16<S>, if_false: u32x16<S>) -> u32x16<S> {
+        self.simd.select_relaxed_u32x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u32x16<S> {
     type Bytes = u8x64<S>;
@@ -7276,10 +10623,33 @@ impl<S: Simd> Bytes for u32x16<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u32x16(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u32x16<S> {
     type Element = u32;
     const N: usize = 16;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: u32 = 0;
+    const MIN_IDENTITY: u32 = u32::MAX;
+    const MAX_IDENTITY: u32 = u32::MIN;
     type Mask = mask32x16<S>;
     type Block = u32x4<S>;
     type Array = [u32; 16];
@@ -7300,11 +10670,27 @@ impl<S: Simd> SimdBase<S> for u32x16<S> {
         simd.load_array_ref_u32x16(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u32]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u32x16(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u32]) {
         self.simd
             .store_array_u32x16(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u32>]) -> &'a mut [u32] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u32x16(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u32x16(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u32) -> Self {
         simd.splat_u32x16(val)
     }
@@ -7363,12 +10749,29 @@ impl<S: Simd> SimdBase<S> for u32x16<S> {
             .shift_elements_right_u32x16::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u32 {
+        self.simd.extract_u32x16::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u32) -> Self {
+        self.simd.insert_u32x16::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u32 {
+        self.simd.extract_dyn_u32x16(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u32x16(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u32x16<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u32x16(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u32x16(self, rhs.simd_into(self.simd))
@@ -7422,6 +10825,47 @@ impl<S: Simd> crate::SimdInt<S> for u32x16<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u32x16(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u32x16(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u32x16(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u32x16(self)
+    }
+    type Signed = i32x16<S>;
+    type Unsigned = u32x16<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u32 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u32x16([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    }
+}
+impl<S: Simd> SimdVector for u32x16<S> {
+    type Simd = S;
+    const LANES: usize = 16;
 }
 impl<S: Simd> SimdCvtTruncate<f32x16<S>> for u32x16<S> {
     #[doc = "Convert each floating-point element to an unsigned 32-bit integer, truncating towards zero.\n\nOut-of-range values or NaN will produce implementation-defined results.\n\nOn x86 platforms below AVX-512, this operation will still be slower than converting to `i32`, because there is no native instruction for converting to `u32`.\nIf you know your values fit within range of an `i32`, you should convert to an `i32` and cast to your desired datatype afterwards."]
@@ -7442,7 +10886,7 @@ impl<S: Simd> crate::SimdSplit<S> for u32x16<S> {
         self.simd.split_u32x16(self)
     }
 }
-#[doc = "A SIMD mask of 16 logical lanes corresponding to 32-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask32x16};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask32x16::splat(simd, true);\n    let b = mask32x16::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask32x16::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask32x16::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask32x16::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask32x16::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+#[doc = "A SIMD mask of 16 logical lanes corresponding to 32-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask32x16};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask32x16::splat(simd, true);\n    let b = mask32x16::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask32x16::from_bools(simd, &[true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false]);\n    let h = mask32x16::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask32x16::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask32x16::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask32x16::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask32x16::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask32x16<S: Simd> {
     pub(crate) val: S::mask32x16,
@@ -7467,6 +10911,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask32x16<S> {
         crate::support::simd_debug_impl(f, "mask32x16", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask32x16<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 16]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask32x16<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -7478,9 +10929,14 @@ impl<S: Simd> Select<mask32x16<S>> for mask32x16<S> {
     fn select(self, if_true: mask32x16<S>, if_false: mask32x16<S>) -> mask32x16<S> {
         self.simd.select_mask32x16(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask32x16<S>, if_false: mask32x16<S>) -> mask32x16<S> {
+        self.simd.select_relaxed_mask32x16(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask32x16<S> {
     type Element = i32;
+    type Int = i32x16<S>;
     const N: usize = 16;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -7499,10 +10955,35 @@ impl<S: Simd> SimdMask<S> for mask32x16<S> {
         self.simd.to_bitmask_mask32x16(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i32x16<S> {
+        self.simd.to_int_mask32x16(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask32x16(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask32x16([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+            if f(8usize) { -1 } else { 0 },
+            if f(9usize) { -1 } else { 0 },
+            if f(10usize) { -1 } else { 0 },
+            if f(11usize) { -1 } else { 0 },
+            if f(12usize) { -1 } else { 0 },
+            if f(13usize) { -1 } else { 0 },
+            if f(14usize) { -1 } else { 0 },
+            if f(15usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i32]) -> Self {
         let slice: &[i32; 16] = slice.try_into().unwrap();
         simd.load_array_mask32x16(*slice)
@@ -7595,6 +11076,10 @@ impl<S: Simd> Select<f64x8<S>> for mask64x8<S> {
     fn select(self, if_true: f64x8<S>, if_false: f64x8<S>) -> f64x8<S> {
         self.simd.select_f64x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: f64x8<S>, if_false: f64x8<S>) -> f64x8<S> {
+        self.simd.select_relaxed_f64x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for f64x8<S> {
     type Bytes = u8x64<S>;
@@ -7606,10 +11091,33 @@ impl<S: Simd> Bytes for f64x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_f64x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = f64::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = f64::from_bits(lane.to_bits().swap_bytes());
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for f64x8<S> {
     type Element = f64;
     const N: usize = 8;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: f64 = -0.0;
+    const MIN_IDENTITY: f64 = f64::INFINITY;
+    const MAX_IDENTITY: f64 = f64::NEG_INFINITY;
     type Mask = mask64x8<S>;
     type Block = f64x2<S>;
     type Array = [f64; 8];
@@ -7630,11 +11138,27 @@ impl<S: Simd> SimdBase<S> for f64x8<S> {
         simd.load_array_ref_f64x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[f64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_f64x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [f64]) {
         self.simd
             .store_array_f64x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<f64>]) -> &'a mut [f64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_f64x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_f64x8(0.0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: f64) -> Self {
         simd.splat_f64x8(val)
     }
@@ -7684,6 +11208,18 @@ impl<S: Simd> SimdBase<S> for f64x8<S> {
             .shift_elements_right_f64x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> f64 {
+        self.simd.extract_f64x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: f64) -> Self {
+        self.simd.insert_f64x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> f64 {
+        self.simd.extract_dyn_f64x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_f64x8(self, indices.simd_into(self.simd))
@@ -7777,6 +11313,21 @@ impl<S: Simd> crate::SimdFloat<S> for f64x8<S> {
             .mul_sub_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
+    fn nmul_add(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_add_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmul_sub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .nmul_sub_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_relaxed(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .mul_add_relaxed_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
     fn floor(self) -> Self {
         self.simd.floor_f64x8(self)
     }
@@ -7796,6 +11347,12 @@ impl<S: Simd> crate::SimdFloat<S> for f64x8<S> {
     fn trunc(self) -> Self {
         self.simd.trunc_f64x8(self)
     }
+    type Int = i64x8<S>;
+    type Uint = u64x8<S>;
+}
+impl<S: Simd> SimdVector for f64x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> crate::SimdSplit<S> for f64x8<S> {
     type Split = f64x4<S>;
@@ -7866,6 +11423,10 @@ impl<S: Simd> Select<i64x8<S>> for mask64x8<S> {
     fn select(self, if_true: i64x8<S>, if_false: i64x8<S>) -> i64x8<S> {
         self.simd.select_i64x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: i64x8<S>, if_false: i64x8<S>) -> i64x8<S> {
+        self.simd.select_relaxed_i64x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for i64x8<S> {
     type Bytes = u8x64<S>;
@@ -7877,10 +11438,33 @@ impl<S: Simd> Bytes for i64x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_i64x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for i64x8<S> {
     type Element = i64;
     const N: usize = 8;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: i64 = 0;
+    const MIN_IDENTITY: i64 = i64::MAX;
+    const MAX_IDENTITY: i64 = i64::MIN;
     type Mask = mask64x8<S>;
     type Block = i64x2<S>;
     type Array = [i64; 8];
@@ -7901,11 +11485,27 @@ impl<S: Simd> SimdBase<S> for i64x8<S> {
         simd.load_array_ref_i64x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[i64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_i64x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [i64]) {
         self.simd
             .store_array_i64x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<i64>]) -> &'a mut [i64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_i64x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_i64x8(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: i64) -> Self {
         simd.splat_i64x8(val)
     }
@@ -7955,12 +11555,29 @@ impl<S: Simd> SimdBase<S> for i64x8<S> {
             .shift_elements_right_i64x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> i64 {
+        self.simd.extract_i64x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: i64) -> Self {
+        self.simd.insert_i64x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> i64 {
+        self.simd.extract_dyn_i64x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_i64x8(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for i64x8<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_i64x8(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_i64x8(self, rhs.simd_into(self.simd))
@@ -8013,6 +11630,47 @@ impl<S: Simd> crate::SimdInt<S> for i64x8<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_i64x8(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_i64x8(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_i64x8(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_i64x8(self)
+    }
+    type Signed = i64x8<S>;
+    type Unsigned = u64x8<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: i64 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_i64x8([0, 1, 2, 3, 4, 5, 6, 7])
+    }
+}
+impl<S: Simd> SimdVector for i64x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> crate::SimdSplit<S> for i64x8<S> {
     type Split = i64x4<S>;
@@ -8083,6 +11741,10 @@ impl<S: Simd> Select<u64x8<S>> for mask64x8<S> {
     fn select(self, if_true: u64x8<S>, if_false: u64x8<S>) -> u64x8<S> {
         self.simd.select_u64x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: u64x8<S>, if_false: u64x8<S>) -> u64x8<S> {
+        self.simd.select_relaxed_u64x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> Bytes for u64x8<S> {
     type Bytes = u8x64<S>;
@@ -8094,10 +11756,33 @@ impl<S: Simd> Bytes for u64x8<S> {
     fn from_bytes(value: Self::Bytes) -> Self {
         value.simd.cvt_from_bytes_u64x8(value)
     }
+    #[inline(always)]
+    fn to_le_bytes(mut self) -> Self::Bytes {
+        if cfg!(target_endian = "big") {
+            for lane in self.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        self.to_bytes()
+    }
+    #[inline(always)]
+    fn from_le_bytes(value: Self::Bytes) -> Self {
+        let mut result = Self::from_bytes(value);
+        if cfg!(target_endian = "big") {
+            for lane in result.as_mut_slice() {
+                *lane = lane.swap_bytes();
+            }
+        }
+        result
+    }
 }
 impl<S: Simd> SimdBase<S> for u64x8<S> {
     type Element = u64;
     const N: usize = 8;
+    const BITS: usize = 512;
+    const ADD_IDENTITY: u64 = 0;
+    const MIN_IDENTITY: u64 = u64::MAX;
+    const MAX_IDENTITY: u64 = u64::MIN;
     type Mask = mask64x8<S>;
     type Block = u64x2<S>;
     type Array = [u64; 8];
@@ -8118,11 +11803,27 @@ impl<S: Simd> SimdBase<S> for u64x8<S> {
         simd.load_array_ref_u64x8(slice.try_into().unwrap())
     }
     #[inline(always)]
+    fn try_from_slice(simd: S, slice: &[u64]) -> Option<Self> {
+        let array = slice.try_into().ok()?;
+        Some(simd.load_array_ref_u64x8(array))
+    }
+    #[inline(always)]
     fn store_slice(&self, slice: &mut [u64]) {
         self.simd
             .store_array_u64x8(*self, slice.try_into().unwrap());
     }
     #[inline(always)]
+    fn write_to_uninit<'a>(&self, slice: &'a mut [core::mem::MaybeUninit<u64>]) -> &'a mut [u64] {
+        crate::support::write_uninit_array(
+            slice.try_into().unwrap(),
+            self.simd.as_array_u64x8(*self),
+        )
+    }
+    #[inline(always)]
+    fn zeroed(simd: S) -> Self {
+        simd.splat_u64x8(0)
+    }
+    #[inline(always)]
     fn splat(simd: S, val: u64) -> Self {
         simd.splat_u64x8(val)
     }
@@ -8172,12 +11873,29 @@ impl<S: Simd> SimdBase<S> for u64x8<S> {
             .shift_elements_right_u64x8::<OFFSET>(self, padding)
     }
     #[inline(always)]
+    fn extract<const LANE: usize>(self) -> u64 {
+        self.simd.extract_u64x8::<LANE>(self)
+    }
+    #[inline(always)]
+    fn insert<const LANE: usize>(self, value: u64) -> Self {
+        self.simd.insert_u64x8::<LANE>(self, value)
+    }
+    #[inline(always)]
+    fn extract_dyn(self, index: usize) -> u64 {
+        self.simd.extract_dyn_u64x8(self, index)
+    }
+    #[inline(always)]
     fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
         self.simd
             .swizzle_dyn_within_blocks_u64x8(self, indices.simd_into(self.simd))
     }
 }
 impl<S: Simd> crate::SimdInt<S> for u64x8<S> {
+    #[inline(always)]
+    fn shift_signed(self, amounts: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self {
+        self.simd
+            .shift_signed_u64x8(self, amounts.simd_into(self.simd))
+    }
     #[inline(always)]
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask {
         self.simd.simd_eq_u64x8(self, rhs.simd_into(self.simd))
@@ -8230,6 +11948,47 @@ impl<S: Simd> crate::SimdInt<S> for u64x8<S> {
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self {
         self.simd.max_u64x8(self, rhs.simd_into(self.simd))
     }
+    #[inline(always)]
+    fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_add_u64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .saturating_sub_u64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn count_ones(self) -> Self {
+        self.simd.count_ones_u64x8(self)
+    }
+    #[inline(always)]
+    fn leading_zeros(self) -> Self {
+        self.simd.leading_zeros_u64x8(self)
+    }
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        self.simd.trailing_zeros_u64x8(self)
+    }
+    type Signed = i64x8<S>;
+    type Unsigned = u64x8<S>;
+    #[inline(always)]
+    fn as_signed(self) -> Self::Signed {
+        self.bitcast()
+    }
+    #[inline(always)]
+    fn as_unsigned(self) -> Self::Unsigned {
+        self.bitcast()
+    }
+    const AND_IDENTITY: u64 = !0;
+    #[inline(always)]
+    fn lane_indices(simd: S) -> Self {
+        simd.load_array_u64x8([0, 1, 2, 3, 4, 5, 6, 7])
+    }
+}
+impl<S: Simd> SimdVector for u64x8<S> {
+    type Simd = S;
+    const LANES: usize = 8;
 }
 impl<S: Simd> crate::SimdSplit<S> for u64x8<S> {
     type Split = u64x4<S>;
@@ -8238,7 +11997,7 @@ impl<S: Simd> crate::SimdSplit<S> for u64x8<S> {
         self.simd.split_u64x8(self)
     }
 }
-#[doc = "A SIMD mask of 8 logical lanes corresponding to 64-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask64x8};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask64x8::splat(simd, true);\n    let b = mask64x8::simd_from(simd, true);\n\n    // From signed integer mask lanes:\n    let c = mask64x8::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask64x8::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask64x8::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask64x8::splat(simd, false);\n    f.set(0, true);\n}\n```"]
+#[doc = "A SIMD mask of 8 logical lanes corresponding to 64-bit vector elements.\n\nThe storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\nYou can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n```rust\n# use fearless_simd::{prelude::*, mask64x8};\nfn construct_mask<S: Simd>(simd: S) {\n    // From a single boolean value:\n    let a = mask64x8::splat(simd, true);\n    let b = mask64x8::simd_from(simd, true);\n\n    // From a boolean for each lane:\n    let g = mask64x8::from_bools(simd, &[true, false, false, false, false, false, false, false]);\n    let h = mask64x8::from_fn(simd, |i| i == 0);\n\n    // From signed integer mask lanes:\n    let c = mask64x8::from_slice(simd, &[-1, 0, 0, 0, 0, 0, 0, 0]);\n    let d = mask64x8::simd_from(simd, [-1, 0, 0, 0, 0, 0, 0, 0]);\n\n    // From a compact bitmask (same mask as above, least significant bit maps to lane 0):\n    let e = mask64x8::from_bitmask(simd, 0b0001);\n\n    // By setting individual lanes:\n    let mut f = mask64x8::splat(simd, false);\n    f.set(0, true);\n}\n```"]
 #[derive(Clone, Copy)]
 pub struct mask64x8<S: Simd> {
     pub(crate) val: S::mask64x8,
@@ -8263,6 +12022,13 @@ impl<S: Simd + core::fmt::Debug> core::fmt::Debug for mask64x8<S> {
         crate::support::simd_debug_impl(f, "mask64x8", &self.simd, &lanes)
     }
 }
+impl<S: Simd> mask64x8<S> {
+    #[doc = r" Create a SIMD mask with each lane set to the corresponding boolean."]
+    #[inline(always)]
+    pub fn from_bools(simd: S, bools: &[bool; 8]) -> Self {
+        Self::from_fn(simd, |i| bools[i])
+    }
+}
 impl<S: Simd> SimdFrom<bool, S> for mask64x8<S> {
     #[inline(always)]
     fn simd_from(simd: S, value: bool) -> Self {
@@ -8274,9 +12040,14 @@ impl<S: Simd> Select<mask64x8<S>> for mask64x8<S> {
     fn select(self, if_true: mask64x8<S>, if_false: mask64x8<S>) -> mask64x8<S> {
         self.simd.select_mask64x8(self, if_true, if_false)
     }
+    #[inline(always)]
+    fn select_relaxed(self, if_true: mask64x8<S>, if_false: mask64x8<S>) -> mask64x8<S> {
+        self.simd.select_relaxed_mask64x8(self, if_true, if_false)
+    }
 }
 impl<S: Simd> SimdMask<S> for mask64x8<S> {
     type Element = i64;
+    type Int = i64x8<S>;
     const N: usize = 8;
     #[inline(always)]
     fn witness(&self) -> S {
@@ -8295,10 +12066,27 @@ impl<S: Simd> SimdMask<S> for mask64x8<S> {
         self.simd.to_bitmask_mask64x8(self)
     }
     #[inline(always)]
+    fn to_int(self) -> i64x8<S> {
+        self.simd.to_int_mask64x8(self)
+    }
+    #[inline(always)]
     fn set(&mut self, index: usize, value: bool) {
         self.simd.set_mask64x8(self, index, value);
     }
     #[inline(always)]
+    fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+        simd.load_array_mask64x8([
+            if f(0usize) { -1 } else { 0 },
+            if f(1usize) { -1 } else { 0 },
+            if f(2usize) { -1 } else { 0 },
+            if f(3usize) { -1 } else { 0 },
+            if f(4usize) { -1 } else { 0 },
+            if f(5usize) { -1 } else { 0 },
+            if f(6usize) { -1 } else { 0 },
+            if f(7usize) { -1 } else { 0 },
+        ])
+    }
+    #[inline(always)]
     fn from_slice(simd: S, slice: &[i64]) -> Self {
         let slice: &[i64; 8] = slice.try_into().unwrap();
         simd.load_array_mask64x8(*slice)
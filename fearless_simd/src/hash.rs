@@ -0,0 +1,283 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Deterministic hashing of vector contents, for comparing results across machines, and of each lane, for hash
+//! tables.
+
+use crate::{Simd, SimdBase, SimdInt, SimdMask};
+use crate::{
+    f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4,
+    i32x8, i32x16, i64x2, i64x4, i64x8, mask8x16, mask8x32, mask8x64, mask16x8, mask16x16,
+    mask16x32, mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x16, u8x32, u8x64,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// A hash of the lanes of a vector or mask, which is the same on every machine and level.
+///
+/// This is intended for test fixtures, differential tests and fuzzers, where comparing a single
+/// `u64` is more convenient than storing or sending every lane. It is a 64-bit FNV-1a hash, so it
+/// is neither cryptographically secure nor resistant to collisions chosen by an attacker.
+///
+/// Only the lanes are hashed, not the type or the level: vectors of different types with the
+/// same lane values have the same hash.
+///
+/// - Vector lanes are hashed as their little-endian bytes. Floating point lanes are hashed as
+///   their bits, so `0.0` and `-0.0`, or NaNs with different payloads, have different hashes.
+/// - Mask lanes are hashed as their logical value, so masks of different lane widths with the
+///   same lanes have the same hash. A canonical mask lane is either all zeros (`false`) or all
+///   ones (`true`). Any other value is hashed with a marker and its raw bits, so that a
+///   non-canonical mask never has the same hash as a canonical one. This makes operations which
+///   produce non-canonical masks on some levels show up as mismatches.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, LaneHash, f32x4, prelude::*};
+///
+/// let simd = Fallback::new();
+/// let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+/// let b = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(a.lane_hash(), b.lane_hash());
+/// assert_ne!(a.lane_hash(), (a + 1.0).lane_hash());
+/// ```
+pub trait LaneHash {
+    /// Hash the lanes of this value.
+    fn lane_hash(&self) -> u64;
+}
+
+/// The running state of an FNV-1a hash.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// A scalar which can be written into the hash as its little-endian bytes.
+trait HashLane: Copy {
+    fn write_to(self, hash: &mut Fnv);
+}
+
+macro_rules! impl_hash_lane {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HashLane for $ty {
+                #[inline]
+                fn write_to(self, hash: &mut Fnv) {
+                    hash.write(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_hash_lane!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+impl HashLane for f32 {
+    #[inline]
+    fn write_to(self, hash: &mut Fnv) {
+        self.to_bits().write_to(hash);
+    }
+}
+
+impl HashLane for f64 {
+    #[inline]
+    fn write_to(self, hash: &mut Fnv) {
+        self.to_bits().write_to(hash);
+    }
+}
+
+/// Marks a mask lane which is neither all zeros nor all ones.
+const NON_CANONICAL_MASK_LANE: u8 = 0xff;
+
+#[inline]
+fn write_mask_lane<T: HashLane + PartialEq + From<i8>>(lane: T, hash: &mut Fnv) {
+    if lane == T::from(0) {
+        hash.write(&[0]);
+    } else if lane == T::from(-1) {
+        hash.write(&[1]);
+    } else {
+        hash.write(&[NON_CANONICAL_MASK_LANE]);
+        lane.write_to(hash);
+    }
+}
+
+macro_rules! impl_lane_hash_vector {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<S: Simd> LaneHash for $ty<S> {
+                fn lane_hash(&self) -> u64 {
+                    let mut hash = Fnv::new();
+                    for &lane in self.as_slice() {
+                        lane.write_to(&mut hash);
+                    }
+                    hash.0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lane_hash_mask {
+    ($($ty:ident: $len:literal),* $(,)?) => {
+        $(
+            impl<S: Simd> LaneHash for $ty<S> {
+                fn lane_hash(&self) -> u64 {
+                    let mut lanes = [0; $len];
+                    self.store_slice(&mut lanes);
+                    let mut hash = Fnv::new();
+                    for lane in lanes {
+                        write_mask_lane(lane, &mut hash);
+                    }
+                    hash.0
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_hash_vector!(
+    f32x4, i8x16, u8x16, i16x8, u16x8, i32x4, u32x4, f64x2, i64x2, u64x2, f32x8, i8x32, u8x32,
+    i16x16, u16x16, i32x8, u32x8, f64x4, i64x4, u64x4, f32x16, i8x64, u8x64, i16x32, u16x32,
+    i32x16, u32x16, f64x8, i64x8, u64x8,
+);
+
+impl_lane_hash_mask!(
+    mask8x16: 16, mask16x8: 8, mask32x4: 4, mask64x2: 2, mask8x32: 32, mask16x16: 16,
+    mask32x8: 8, mask64x4: 4, mask8x64: 64, mask16x32: 32, mask32x16: 16, mask64x8: 8,
+);
+
+/// A hash of each lane of a vector of `u32` or `u64`, which is the same on every machine and level.
+///
+/// This is for hash tables, Bloom filters and sketches which look up or insert many keys at once. The hash is the
+/// finalizer of `MurmurHash3` (`fmix32` and `fmix64`), which is a few shifts, XORs and multiplications. Every bit of
+/// the input affects every bit of the result, so the low bits are good bucket indices, even for keys which only
+/// differ in their high bits. It isn't resistant to collisions chosen by an attacker.
+///
+/// The hash is a bijection: different lanes always have different hashes, and zero is hashed to zero. To hash
+/// several values into one lane, or to get independent hashes for the functions of a Bloom filter, combine the
+/// values or a seed into each lane first, for example with XOR or a multiplication by an odd constant.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, prelude::*, u32x4};
+///
+/// let simd = Fallback::new();
+/// let keys = u32x4::from_slice(simd, &[1, 2, 3, 4]);
+/// let buckets = keys.hash_lanes() & 1023;
+/// assert_eq!(buckets[0], 0x514e_28b7 & 1023);
+/// ```
+pub trait SimdHashLanes<S: Simd>: SimdInt<S> {
+    /// Hash each lane.
+    fn hash_lanes(self) -> Self;
+}
+
+macro_rules! impl_hash_lanes {
+    ($($ty:ident),* $(,)? => $shifts:expr, $multipliers:expr) => {
+        $(
+            impl<S: Simd> SimdHashLanes<S> for $ty<S> {
+                #[inline(always)]
+                fn hash_lanes(self) -> Self {
+                    let [s0, s1, s2] = $shifts;
+                    let [m0, m1] = $multipliers;
+                    let h = (self ^ (self >> s0)) * m0;
+                    let h = (h ^ (h >> s1)) * m1;
+                    h ^ (h >> s2)
+                }
+            }
+        )*
+    };
+}
+
+impl_hash_lanes!(u32x4, u32x8, u32x16 => [16, 13, 16], [0x85eb_ca6b, 0xc2b2_ae35]);
+impl_hash_lanes!(u64x2, u64x4, u64x8 => [33, 33, 33], [0xff51_afd7_ed55_8ccd, 0xc4ce_b9fe_1a85_ec53]);
+
+#[cfg(test)]
+mod tests {
+    use super::{LaneHash, SimdHashLanes};
+    use crate::{Fallback, SimdBase, SimdMask, f32x4, mask8x16, mask16x16, mask32x4, u32x4, u64x2};
+
+    #[test]
+    fn vector_hash_is_fnv1a_of_le_bytes() {
+        let simd = Fallback::new();
+        let v = u32x4::from_slice(simd, &[0x6463_6261, 0, 0, 0]);
+        // The well-known FNV-1a hash of "abcd" followed by twelve zero bytes, computed bytewise.
+        let mut expected = 0xcbf2_9ce4_8422_2325_u64;
+        for byte in b"abcd\0\0\0\0\0\0\0\0\0\0\0\0" {
+            expected = (expected ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+        assert_eq!(
+            v.lane_hash(),
+            expected,
+            "lanes should be hashed as LE bytes"
+        );
+    }
+
+    #[test]
+    fn float_hash_distinguishes_signed_zero() {
+        let simd = Fallback::new();
+        let pos = f32x4::splat(simd, 0.0);
+        let neg = f32x4::splat(simd, -0.0);
+        assert_ne!(
+            pos.lane_hash(),
+            neg.lane_hash(),
+            "float lanes should be hashed by their bits"
+        );
+    }
+
+    #[test]
+    fn mask_hash_ignores_lane_width() {
+        let simd = Fallback::new();
+        let bits = 0b1010_0000_0000_1011;
+        assert_eq!(
+            mask8x16::from_bitmask(simd, bits).lane_hash(),
+            mask16x16::from_bitmask(simd, bits).lane_hash(),
+            "masks with the same logical lanes should have the same hash"
+        );
+        assert_ne!(
+            mask32x4::from_bitmask(simd, 0b0001).lane_hash(),
+            mask32x4::from_bitmask(simd, 0b0010).lane_hash(),
+            "different masks should have different hashes"
+        );
+    }
+
+    #[test]
+    fn non_canonical_mask_lanes_change_the_hash() {
+        let simd = Fallback::new();
+        let canonical = mask32x4::from_slice(simd, &[-1, 0, 0, 0]);
+        let non_canonical = mask32x4::from_slice(simd, &[1, 0, 0, 0]);
+        assert!(
+            canonical.test(0) && non_canonical.test(0),
+            "both masks should be true in lane 0"
+        );
+        assert_ne!(
+            canonical.lane_hash(),
+            non_canonical.lane_hash(),
+            "a non-canonical mask should not hash like a canonical one"
+        );
+    }
+
+    #[test]
+    fn hash_lanes_is_murmur3_finalizer() {
+        let simd = Fallback::new();
+        let hashed = u32x4::from_slice(simd, &[0, 1, 2, 0xdead_beef]).hash_lanes();
+        assert_eq!(
+            *hashed,
+            [0, 0x514e_28b7, 0x30f4_c306, 0x0de5_c6a9],
+            "u32 lanes should be hashed with fmix32"
+        );
+        let hashed = u64x2::from_slice(simd, &[1, 0xdead_beef]).hash_lanes();
+        assert_eq!(
+            *hashed,
+            [0xb456_bcfc_34c2_cb2c, 0xd24b_d59f_862a_1dac],
+            "u64 lanes should be hashed with fmix64"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Infinite impulse response (IIR) filters, which run many independent channels at once, one in each lane.
+//!
+//! Each output of a recursive filter depends on the previous one, so the samples of a single channel can't be
+//! computed in parallel. Separate channels are independent, though: the voices of a synthesizer, the channels of a
+//! mixing desk, or the bands of an equalizer bank which all filter the same input. [`Biquad`] and [`BiquadCascade`]
+//! process `S::f32s::N` channels at once, with their own coefficients and state in each lane of a native-width
+//! vector, so a frame of one sample from every channel is filtered with a handful of vector operations.
+//!
+//! The number of channels depends on the level, in the same way as for the [`soa`](crate::soa) types, so a fixed
+//! number of channels is processed in groups of `S::f32s::N`, with unused lanes left at zero.
+//!
+//! Like any other SIMD code, these are marked `#[inline(always)]`, so they should be used from within
+//! [`dispatch`](crate::dispatch) or another function which already has a SIMD token. The filters use
+//! [`mul_add`](SimdFloat::mul_add), so their results can differ in the last bits between levels with and without
+//! FMA.
+
+use core::fmt;
+
+use crate::{Simd, SimdBase, SimdFloat};
+
+/// The coefficients of one second-order section, normalized so that `a0` is 1.
+///
+/// The transfer function is `(b0 + b1 z^-1 + b2 z^-2) / (1 + a1 z^-1 + a2 z^-2)`. This is the sign convention of
+/// the [Audio EQ Cookbook] and of most filter design tools; coefficients which use `-a1` and `-a2` need negating.
+///
+/// [Audio EQ Cookbook]: https://www.w3.org/TR/audio-eq-cookbook/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BiquadCoeffs {
+    /// The weight of the current input.
+    pub b0: f32,
+    /// The weight of the previous input.
+    pub b1: f32,
+    /// The weight of the input from two samples earlier.
+    pub b2: f32,
+    /// The weight of the previous output, which is subtracted.
+    pub a1: f32,
+    /// The weight of the output from two samples earlier, which is subtracted.
+    pub a2: f32,
+}
+
+impl BiquadCoeffs {
+    /// The coefficients of a section which passes its input through unchanged.
+    pub const IDENTITY: Self = Self {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+}
+
+/// A second-order section (biquad) filter for each lane, in transposed direct form II.
+///
+/// Each lane is a separate channel, with its own coefficients and state. Transposed direct form II only keeps two
+/// values of state for each channel, and has good numerical behavior in floating point.
+///
+/// ```rust
+/// use fearless_simd::iir::{Biquad, BiquadCoeffs};
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*};
+///
+/// /// Filter frames of interleaved channels, where channel `c` of each frame has a gain of `c + 1`, and return the
+/// /// number of channels.
+/// #[inline(always)]
+/// fn apply_gains<S: Simd>(simd: S, frames: &mut [f32]) -> usize {
+///     let mut filter = Biquad::from_fn(simd, |channel| BiquadCoeffs {
+///         b0: channel as f32 + 1.0,
+///         ..BiquadCoeffs::IDENTITY
+///     });
+///     filter.process_interleaved(frames);
+///     S::f32s::N
+/// }
+///
+/// // Every level has at most 16 channels, so this is 3 whole frames or more.
+/// let mut frames = vec![1.0; 16 * 3];
+/// let channels = dispatch!(Level::new(), simd => apply_gains(simd, &mut frames));
+/// assert_eq!(frames[..3], [1.0, 2.0, 3.0]);
+/// assert_eq!(frames[channels..channels + 3], [1.0, 2.0, 3.0]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Biquad<S: Simd> {
+    b0: S::f32s,
+    b1: S::f32s,
+    b2: S::f32s,
+    /// The feedback coefficients are negated, so that they can be used with `mul_add`.
+    neg_a1: S::f32s,
+    neg_a2: S::f32s,
+    s1: S::f32s,
+    s2: S::f32s,
+}
+
+impl<S: Simd> Biquad<S> {
+    /// Create a filter with the same coefficients in every lane.
+    #[inline(always)]
+    pub fn splat(simd: S, coeffs: BiquadCoeffs) -> Self {
+        Self::from_fn(simd, |_| coeffs)
+    }
+
+    /// Create a filter with the coefficients `f(lane)` in each lane.
+    ///
+    /// The filter starts as if it had only been given zeros.
+    #[inline(always)]
+    pub fn from_fn(simd: S, mut f: impl FnMut(usize) -> BiquadCoeffs) -> Self {
+        let mut coeffs = [BiquadCoeffs::IDENTITY; 16];
+        let coeffs = &mut coeffs[..S::f32s::N];
+        for (lane, c) in coeffs.iter_mut().enumerate() {
+            *c = f(lane);
+        }
+        let zero = S::f32s::splat(simd, 0.0);
+        Self {
+            b0: S::f32s::from_fn(simd, |i| coeffs[i].b0),
+            b1: S::f32s::from_fn(simd, |i| coeffs[i].b1),
+            b2: S::f32s::from_fn(simd, |i| coeffs[i].b2),
+            neg_a1: S::f32s::from_fn(simd, |i| -coeffs[i].a1),
+            neg_a2: S::f32s::from_fn(simd, |i| -coeffs[i].a2),
+            s1: zero,
+            s2: zero,
+        }
+    }
+
+    /// Forget the previous input, as if the filter had only been given zeros.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        let zero = S::f32s::splat(self.b0.witness(), 0.0);
+        self.s1 = zero;
+        self.s2 = zero;
+    }
+
+    /// Filter one sample of each channel, and return the outputs.
+    #[inline(always)]
+    pub fn process_frame(&mut self, x: S::f32s) -> S::f32s {
+        let y = x.mul_add(self.b0, self.s1);
+        self.s1 = y.mul_add(self.neg_a1, x.mul_add(self.b1, self.s2));
+        self.s2 = y.mul_add(self.neg_a2, x * self.b2);
+        y
+    }
+
+    /// Filter a sequence of frames in place, where each vector holds one sample of each channel.
+    #[inline(always)]
+    pub fn process(&mut self, frames: &mut [S::f32s]) {
+        for frame in frames {
+            *frame = self.process_frame(*frame);
+        }
+    }
+
+    /// Filter interleaved samples in place, where sample `c` of each group of `S::f32s::N` is from channel `c`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `samples` isn't a multiple of `S::f32s::N`.
+    #[inline(always)]
+    pub fn process_interleaved(&mut self, samples: &mut [f32]) {
+        process_interleaved(self.b0.witness(), samples, |x| self.process_frame(x));
+    }
+}
+
+impl<S: Simd> fmt::Debug for Biquad<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Biquad")
+            .field("b0", &self.b0.as_slice())
+            .field("b1", &self.b1.as_slice())
+            .field("b2", &self.b2.as_slice())
+            .field("neg_a1", &self.neg_a1.as_slice())
+            .field("neg_a2", &self.neg_a2.as_slice())
+            .field("s1", &self.s1.as_slice())
+            .field("s2", &self.s2.as_slice())
+            .finish()
+    }
+}
+
+/// A chain of `STAGES` biquad filters for each lane, where the output of each stage is the input of the next.
+///
+/// Higher-order filters, such as Butterworth filters of order 4 and above and parametric equalizers with several
+/// bands, are a cascade of second-order sections. Keeping them as separate sections, rather than multiplying them
+/// into a single high-order filter, avoids the loss of precision of high-order polynomials.
+#[derive(Clone, Copy)]
+pub struct BiquadCascade<S: Simd, const STAGES: usize> {
+    stages: [Biquad<S>; STAGES],
+}
+
+impl<S: Simd, const STAGES: usize> BiquadCascade<S, STAGES> {
+    /// Create a cascade from its stages, which are applied in order.
+    #[inline(always)]
+    pub fn new(stages: [Biquad<S>; STAGES]) -> Self {
+        const { assert!(STAGES > 0, "a cascade needs at least one stage") };
+        Self { stages }
+    }
+
+    /// The stages of the cascade.
+    #[inline(always)]
+    pub fn stages(&self) -> &[Biquad<S>; STAGES] {
+        &self.stages
+    }
+
+    /// The stages of the cascade, to reset or replace some of them.
+    #[inline(always)]
+    pub fn stages_mut(&mut self) -> &mut [Biquad<S>; STAGES] {
+        &mut self.stages
+    }
+
+    /// Forget the previous input of every stage.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        for stage in &mut self.stages {
+            stage.reset();
+        }
+    }
+
+    /// Filter one sample of each channel through every stage, and return the outputs.
+    #[inline(always)]
+    pub fn process_frame(&mut self, x: S::f32s) -> S::f32s {
+        self.stages
+            .iter_mut()
+            .fold(x, |x, stage| stage.process_frame(x))
+    }
+
+    /// Filter a sequence of frames in place, where each vector holds one sample of each channel.
+    #[inline(always)]
+    pub fn process(&mut self, frames: &mut [S::f32s]) {
+        for frame in frames {
+            *frame = self.process_frame(*frame);
+        }
+    }
+
+    /// Filter interleaved samples in place, where sample `c` of each group of `S::f32s::N` is from channel `c`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `samples` isn't a multiple of `S::f32s::N`.
+    #[inline(always)]
+    pub fn process_interleaved(&mut self, samples: &mut [f32]) {
+        process_interleaved(self.stages[0].b0.witness(), samples, |x| {
+            self.process_frame(x)
+        });
+    }
+}
+
+impl<S: Simd, const STAGES: usize> fmt::Debug for BiquadCascade<S, STAGES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BiquadCascade")
+            .field("stages", &self.stages)
+            .finish()
+    }
+}
+
+/// Apply `frame` to each group of `S::f32s::N` samples, in place.
+#[inline(always)]
+fn process_interleaved<S: Simd>(
+    simd: S,
+    samples: &mut [f32],
+    mut frame: impl FnMut(S::f32s) -> S::f32s,
+) {
+    let channels = S::f32s::N;
+    assert!(
+        samples.len().is_multiple_of(channels),
+        "expected whole frames of {channels} samples, got {} samples",
+        samples.len()
+    );
+    for samples in samples.chunks_exact_mut(channels) {
+        frame(S::f32s::from_slice(simd, samples)).store_slice(samples);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Integral images (summed-area tables), which give the sum of any rectangle of an image in four lookups.
+//!
+//! The Haar-like features of Viola-Jones face detection, box filters of any size, and the local statistics of
+//! adaptive thresholding are all sums over rectangles, evaluated at many positions. An integral image is built
+//! once, and then each of these sums is constant-time.
+//!
+//! [`integral_image`] uses the same layout as `cv::integral`: the table has one more row and column than the
+//! image, and `table[y][x]` is the sum of the pixels above and to the left of `(x, y)`, not including that row and
+//! column. The first row and column are zero, so the sum of the pixels in `x0..x1` and `y0..y1` is
+//! `table[y1][x1] - table[y0][x1] - table[y1][x0] + table[y0][x0]`, with no special cases at the edges.
+//!
+//! ```rust
+//! use fearless_simd::{Level, dispatch, integral::integral_image};
+//!
+//! // A 4x3 image, and its table of 5x4 sums.
+//! let image = [
+//!     1, 2, 3, 4,
+//!     5, 6, 7, 8,
+//!     9, 10, 11, 12,
+//! ];
+//! let mut table = [0; 5 * 4];
+//! dispatch!(Level::new(), simd => integral_image(simd, &image, 4, &mut table));
+//! let at = |x: usize, y: usize| table[y * 5 + x];
+//!
+//! // The sum of the 2x2 rectangle at (1, 1) is 6 + 7 + 10 + 11.
+//! assert_eq!(at(3, 3) - at(3, 1) - at(1, 3) + at(1, 1), 34);
+//! // The sum of the whole image.
+//! assert_eq!(at(4, 3), 78);
+//! ```
+
+use crate::slice::{exclusive_scan_vector, load_tail};
+use crate::{Simd, SimdBase, u8x16};
+
+/// Build the integral image of an image with rows of `width` pixels into `table`.
+///
+/// `table` has `width + 1` columns and one more row than the image. See the [module documentation](self) for its
+/// layout.
+///
+/// The sums wrap on overflow, which needs an image of more than 16 million pixels. Even then, the sum of a
+/// rectangle is correct as long as that sum fits in a `u32`, because the differences wrap back.
+///
+/// This is done in two passes. The first computes the prefix sum of each row, 16 pixels at a time, widened to
+/// 32 bits, with the sum of the previous pixels of the row carried between vectors. The second adds each row of
+/// the table to the one below it with vectors of the native width.
+///
+/// # Panics
+///
+/// Panics if `width` is zero, if the number of pixels isn't a multiple of `width`, or if `table` doesn't have
+/// `(width + 1) * (pixels.len() / width + 1)` elements.
+#[inline(always)]
+pub fn integral_image<S: Simd>(simd: S, pixels: &[u8], width: usize, table: &mut [u32]) {
+    assert!(width > 0, "the width of the image should be at least 1");
+    assert!(
+        pixels.len().is_multiple_of(width),
+        "the image should be whole rows of {width} pixels, not {} pixels",
+        pixels.len()
+    );
+    let stride = width + 1;
+    assert_eq!(
+        table.len(),
+        stride * (pixels.len() / width + 1),
+        "`table` should have one more row and column than the image"
+    );
+
+    let (first, rows) = table.split_at_mut(stride);
+    first.fill(0);
+    for (pixels, row) in pixels
+        .chunks_exact(width)
+        .zip(rows.chunks_exact_mut(stride))
+    {
+        row_prefix_sum(simd, pixels, row);
+    }
+
+    // Add each row to the one below it, from the top, so that each row has the sums of every row above it.
+    for y in 1..table.len() / stride - 1 {
+        let (above, below) = table[y * stride..].split_at_mut(stride);
+        let below = &mut below[..stride];
+        let mut above = above.chunks_exact(S::u32s::N);
+        let mut below = below.chunks_exact_mut(S::u32s::N);
+        for (above, below) in (&mut above).zip(&mut below) {
+            (S::u32s::from_slice(simd, below) + S::u32s::from_slice(simd, above))
+                .store_slice(below);
+        }
+        for (above, below) in above.remainder().iter().zip(below.into_remainder()) {
+            *below = below.wrapping_add(*above);
+        }
+    }
+}
+
+/// Write the exclusive prefix sum of `pixels` to `row`, which has one more element for the total.
+#[inline(always)]
+fn row_prefix_sum<S: Simd>(simd: S, pixels: &[u8], row: &mut [u32]) {
+    let mut carry = 0;
+    for (pixels, row) in pixels.chunks(16).zip(row.chunks_mut(16)) {
+        let pixels: u8x16<S> = if pixels.len() == 16 {
+            u8x16::from_slice(simd, pixels)
+        } else {
+            load_tail(simd, pixels, 0)
+        };
+        let widened = simd.widen_u16x16(simd.widen_u8x16(pixels));
+        let (scanned, total) = exclusive_scan_vector(widened, carry);
+        if row.len() == 16 {
+            scanned.store_slice(row);
+        } else {
+            row.copy_from_slice(&scanned.as_slice()[..row.len()]);
+        }
+        carry = total;
+    }
+    // The last element is the sum of the whole row. If the width is a multiple of 16, it wasn't stored above.
+    row[pixels.len()] = carry;
+}
@@ -108,7 +108,7 @@ macro_rules! __fearless_simd_kernel_dispatch {
         $($body:tt)*
     ) => {
         $crate::__fearless_simd_kernel_impl! {
-            @cfg target_arch = "aarch64";
+            @cfg any(target_arch = "aarch64", target_arch = "arm64ec");
             @token_ty $crate::Neon;
             @kernel_attrs #[target_feature(enable = "neon")];
             $($body)*
@@ -227,17 +227,202 @@ macro_rules! __fearless_simd_kernel_impl {
     };
 }
 
+/// Defines a function which is generic over [`Simd`](crate::Simd), with specialized
+/// implementations for some levels and a portable implementation for the others.
+///
+/// This is the usual way to extend Fearless SIMD with an operation of your own. Each
+/// specialization is a [`kernel!`](crate::kernel) for one SIMD token, and the generated function
+/// calls the first one whose token can be obtained from the level of the `Simd` it's called
+/// with, in the order they're written. Otherwise, it runs the portable body after `_ =>`. As the
+/// level of a `Simd` type is known at compile time, this choice is made when the function is
+/// inlined, and costs nothing at runtime. Note that the level of [`Fallback`](crate::Fallback) is
+/// the baseline of the target (such as SSE2 on x86-64) unless the `force_support_fallback`
+/// feature is enabled, so it can also use specializations.
+///
+/// The arguments are converted to the specialization's parameter types with [`Into`], in order,
+/// and its result is converted back with [`SimdInto`](crate::SimdInto). This means vectors can be
+/// passed to and returned from specializations which use the architecture's own vector types,
+/// such as `__m256` or `float32x4_t`, and other arguments can be passed unchanged.
+///
+/// ## Example
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, f32x4, prelude::*, simd_op};
+/// #[cfg(target_arch = "aarch64")]
+/// use core::arch::aarch64::{float32x4_t, vcopyq_laneq_f32};
+/// #[cfg(target_arch = "x86")]
+/// use core::arch::x86::{__m128, _mm_blend_ps};
+/// #[cfg(target_arch = "x86_64")]
+/// use core::arch::x86_64::{__m128, _mm_blend_ps};
+///
+/// simd_op! {
+///     /// Return `a` with its last lane replaced by the last lane of `b`.
+///     pub fn copy_last<S: Simd>(simd: S, a: f32x4<S>, b: f32x4<S>) -> f32x4<S> {
+///         Sse4_2(sse4_2, a: __m128, b: __m128) -> __m128 {
+///             _mm_blend_ps::<8>(a, b)
+///         }
+///         Neon(neon, a: float32x4_t, b: float32x4_t) -> float32x4_t {
+///             vcopyq_laneq_f32::<3, 3>(a, b)
+///         }
+///         _ => {
+///             let mut result = a;
+///             result[3] = b[3];
+///             result
+///         }
+///     }
+/// }
+///
+/// #[inline(always)]
+/// fn run<S: Simd>(simd: S) -> [f32; 4] {
+///     let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+///     let b = f32x4::splat(simd, 9.0);
+///     copy_last(simd, a, b).into()
+/// }
+///
+/// assert_eq!(dispatch!(Level::new(), simd => run(simd)), [1.0, 2.0, 3.0, 9.0]);
+/// ```
+///
+/// ## Limitations
+///
+/// The function must have exactly one generic parameter, written as `<S: Simd>`, and its first
+/// argument must be the `Simd` value. As with [`kernel!`](crate::kernel), the token types of the
+/// specializations must be written as bare names: `Neon`, `WasmSimd128`, `Sse2`, `Sse4_2`,
+/// `Avx2`, or `Avx512`. Specializations for other architectures are compiled out, so their
+/// parameter types only need to exist on their own architecture.
+///
+/// A stronger level is also able to produce the tokens of weaker ones, so specializations should
+/// be written from the strongest level to the weakest; an `Avx2` specialization after an `Sse2`
+/// one would never be used.
+#[macro_export]
+macro_rules! simd_op {
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident<$simd_ty:ident: Simd>(
+            $simd:ident : $simd_arg_ty:ty $(, $arg:ident : $arg_ty:ty)* $(,)?
+        ) $(-> $ret:ty)? {
+            $($body:tt)*
+        }
+    ) => {
+        $crate::__fearless_simd_op_impl! {
+            @args [$($arg),*];
+            $(#[$meta])*
+            $vis fn $name<$simd_ty>($simd: $simd_arg_ty $(, $arg: $arg_ty)*) $(-> $ret)? {
+                $($body)*
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fearless_simd_op_impl {
+    (
+        @args $args:tt;
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident<$simd_ty:ident>(
+            $simd:ident : $simd_arg_ty:ty $(, $arg:ident : $arg_ty:ty)*
+        ) $(-> $ret:ty)? {
+            $(
+                $level:ident($token:ident $(, $kernel_arg:ident : $kernel_arg_ty:ty)* $(,)?)
+                $(-> $kernel_ret:ty)? {
+                    $($kernel_body:tt)*
+                }
+            )*
+            _ => {
+                $($body:tt)*
+            }
+        }
+    ) => {
+        $(#[$meta])*
+        #[inline(always)]
+        $vis fn $name<$simd_ty: $crate::Simd>(
+            $simd: $simd_arg_ty $(, $arg: $arg_ty)*
+        ) $(-> $ret)? {
+            $(
+                $crate::__fearless_simd_op_specialization! {
+                    $level, $simd, $args,
+                    fn __fearless_simd_op(
+                        $token: $level $(, $kernel_arg: $kernel_arg_ty)*
+                    ) $(-> $kernel_ret)? {
+                        $($kernel_body)*
+                    }
+                }
+            )*
+            $($body)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fearless_simd_op_specialization {
+    (Neon, $($rest:tt)*) => {
+        $crate::__fearless_simd_op_specialization! {
+            @cfg any(target_arch = "aarch64", target_arch = "arm64ec"); @as as_neon; $($rest)*
+        }
+    };
+    (WasmSimd128, $($rest:tt)*) => {
+        $crate::__fearless_simd_op_specialization! {
+            @cfg all(target_arch = "wasm32", target_feature = "simd128"); @as as_wasm_simd128;
+            $($rest)*
+        }
+    };
+    (Sse2, $($rest:tt)*) => {
+        $crate::__fearless_simd_op_specialization! {
+            @cfg any(target_arch = "x86", target_arch = "x86_64"); @as as_sse2; $($rest)*
+        }
+    };
+    (Sse4_2, $($rest:tt)*) => {
+        $crate::__fearless_simd_op_specialization! {
+            @cfg any(target_arch = "x86", target_arch = "x86_64"); @as as_sse4_2; $($rest)*
+        }
+    };
+    (Avx2, $($rest:tt)*) => {
+        $crate::__fearless_simd_op_specialization! {
+            @cfg any(target_arch = "x86", target_arch = "x86_64"); @as as_avx2; $($rest)*
+        }
+    };
+    (Avx512, $($rest:tt)*) => {
+        $crate::__fearless_simd_op_specialization! {
+            @cfg any(target_arch = "x86", target_arch = "x86_64"); @as as_avx512; $($rest)*
+        }
+    };
+    (
+        @cfg $cfg:meta; @as $as_token:ident;
+        $simd:ident, [$($arg:ident),*], $($kernel:tt)*
+    ) => {
+        #[cfg($cfg)]
+        {
+            $crate::kernel! { $($kernel)* }
+            if let ::core::option::Option::Some(token) = $crate::Simd::level($simd).$as_token() {
+                return $crate::SimdInto::simd_into(
+                    __fearless_simd_op(token $(, ::core::convert::Into::into($arg))*),
+                    $simd,
+                );
+            }
+        }
+    };
+    ($level:ident, $($rest:tt)*) => {
+        compile_error!(concat!(
+            "fearless_simd::simd_op! expects each specialization to be named after one of ",
+            "`Neon`, `WasmSimd128`, `Sse2`, `Sse4_2`, `Avx2`, or `Avx512`; got `",
+            stringify!($level),
+            "`",
+        ));
+    };
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(any(
         target_arch = "aarch64",
-        target_arch = "x86",
-        target_arch = "x86_64",
+        target_arch = "arm64ec",
+        all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse2"),
         all(target_arch = "wasm32", target_feature = "simd128")
     ))]
     use crate::prelude::*;
 
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
     use core::arch::aarch64::{float32x4_t, vaddq_f32};
     #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
     use core::arch::wasm32::{f32x4_add, v128};
@@ -280,7 +465,7 @@ mod tests {
         }
     }
 
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
     #[test]
     fn kernel_instantiates_for_neon() {
         let Some(neon) = crate::Level::new().as_neon() else {
@@ -316,7 +501,7 @@ mod tests {
         );
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse2"))]
     #[test]
     fn kernel_instantiates_for_sse2() {
         let Some(sse2) = crate::Level::new().as_sse2() else {
@@ -334,7 +519,7 @@ mod tests {
         );
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx2"))]
     #[test]
     fn kernel_instantiates_for_avx2() {
         let Some(avx2) = crate::Level::new().as_avx2() else {
@@ -352,7 +537,7 @@ mod tests {
         );
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
     #[test]
     fn kernel_instantiates_for_avx512() {
         let Some(avx512) = crate::Level::new().as_avx512() else {
@@ -379,9 +564,11 @@ mod tests {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn x86_kernel_functions_are_not_multiversion_gated() {
+        fn accept_sse2_kernel(_: fn(crate::Sse2, __m128i, __m128i) -> __m128i) {}
         fn accept_avx2_kernel(_: fn(crate::Avx2, __m256i, __m256i) -> __m256i) {}
         fn accept_avx512_kernel(_: fn(crate::Avx512, __m512i, __m512i) -> __m512i) {}
 
+        accept_sse2_kernel(add_i32x4_sse2);
         accept_avx2_kernel(add_i32x8_avx2);
         accept_avx512_kernel(add_i32x16_avx512);
     }
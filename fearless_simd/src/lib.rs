@@ -9,7 +9,7 @@
 //! No matter what level of abstraction you're after, be it autovectorization and multiversioning, or portable SIMD, or safe access to raw
 //! intrinsics and nothing more, `fearless_simd` has you covered!
 //!
-//! Zero dependencies, from-scratch build time under 1 second, safe public APIs, and [very little](https://shnatsel.github.io/safe-simd-in-rust-even-on-the-inside/) `unsafe` under the hood.
+//! Zero dependencies by default, from-scratch build time under 1 second, safe public APIs, and [very little](https://shnatsel.github.io/safe-simd-in-rust-even-on-the-inside/) `unsafe` under the hood.
 //!
 //! # Automatic vectorization
 //!
@@ -112,10 +112,18 @@
 //! [The article describing the design](https://shnatsel.github.io/safe-simd-in-rust-even-on-the-inside/#the-abi-would-like-a-word) covers why this is the
 //! case. There's also Q&A on [Zulip](https://xi.zulipchat.com/#narrow/channel/514230-simd/topic/inlining/with/546913433).
 //!
+//! # Panics
+//!
+//! Operations on vectors and masks don't panic or allocate, so they're suitable for real-time code, such as audio callbacks.
+//! The exceptions are the operations which take a slice or a lane index, which panic if it's out of bounds: `from_slice`, `store_slice`, `write_to_uninit`, indexing, and setting or testing a lane of a mask.
+//! [`SimdBase::try_from_slice`] returns `None` instead of panicking.
+//! Integer arithmetic wraps on overflow, even in debug builds, and shifting by at least the width of the lanes gives an unspecified result rather than panicking.
+//! The test suite checks that the generated code has no other panicking paths.
+//!
 //! # Instruction set support
 //!
 //! - x86/x86-64: SSE2 baseline, [v2](https://en.wikipedia.org/wiki/X86-64#Microarchitecture_levels) (SSE4.2), [v3](https://en.wikipedia.org/wiki/X86-64#Microarchitecture_levels) (AVX2), [Ice Lake](https://en.wikipedia.org/wiki/AVX-512#CPUs_with_AVX-512) (AVX-512, avoiding early slow implementations)
-//! - Aarch64: Baseline [NEON](https://en.wikipedia.org/wiki/Arm_architecture_family#Advanced_SIMD_(Neon))
+//! - Aarch64: Baseline [NEON](https://en.wikipedia.org/wiki/Arm_architecture_family#Advanced_SIMD_(Neon)), including Windows on Arm and [ARM64EC](https://learn.microsoft.com/en-us/windows/arm/arm64ec)
 //! - WebAssembly: [128-bit packed SIMD](https://github.com/WebAssembly/spec/blob/main/proposals/simd/SIMD.md), [relaxed SIMD](https://github.com/WebAssembly/relaxed-simd/blob/main/proposals/relaxed-simd/Overview.md)
 //!
 //! A scalar fallback is also provided for platforms, so your code still works even if SIMD is not available.
@@ -145,6 +153,10 @@
 //! Fearless SIMD can make use of the [relaxed SIMD](https://github.com/WebAssembly/relaxed-simd/blob/main/proposals/relaxed-simd/Overview.md)
 //! WebAssembly instructions, if the requisite target feature is enabled. These instructions can return implementation-dependent results
 //! depending on what is fastest on the underlying hardware. They are only used for operations where we already give hardware-dependent results.
+//! Those results can differ between hosts running the same binary, so the relaxed instructions are only used by
+//! [`mul_add_relaxed`](SimdFloat::mul_add_relaxed) and [`select_relaxed`](Select::select_relaxed), which the
+//! [`Fast`](determinism::Fast) tier calls. [`mul_add`](SimdFloat::mul_add), the other multiply-adds and
+//! [`select`](Select::select) give the same results with and without the `relaxed-simd` feature.
 //!
 //! At the time of writing, relaxed SIMD is only supported in Chrome. To make use of it, you'll need to build two versions of your library, one
 //! with relaxed SIMD enabled (`RUSTFLAGS="-Ctarget-feature=+simd128,+relaxed-simd"`) and one with it disabled, and then feature-detect at
@@ -167,6 +179,12 @@
 //! [`Simd`] implementation, or explicit [`kernel`] support; for example, an `Avx2` token can still be used to call an
 //! AVX2 kernel when the CPU supports it.
 //!
+//! Multiversioning also increases compile times, as each level's [`Simd`] implementation is large.
+//! If this matters more than performance on some CPUs, you can disable the default features, and only enable
+//! the levels you need with the `sse2`, `sse4_2`, `avx2` or `avx512` [features](#feature-flags).
+//! Leaving a level out removes its [`Simd`] implementation, and [`dispatch`] uses the best level which is compiled in instead.
+//! As with the configuration flags, its token type and [`kernel`] support remain available.
+//!
 //! Note that later extensions can be beneficial even if you are only using 128-bit vectors:
 //! AVX2 and AVX-512 provide more efficient instructions for some operations,
 //! and AVX-512 also more than doubles the number of vector registers of all sizes.
@@ -179,9 +197,26 @@
 //! The following crate [feature flags](https://doc.rust-lang.org/cargo/reference/features.html#dependency-features) are available:
 //!
 //! - `std` (enabled by default): Get floating point functions from the standard library (likely using your target's libc).
-//!   Also allows using [`Level::new`] on all platforms, to detect which target features are enabled.
+//!   Also allows using [`Level::new`] on all platforms, to detect which target features are enabled,
+//!   and [`Level::set_detection_hook`] to record the level it selects.
+//!   On x86, [`Level::try_detect`] can detect the level without this feature.
 //! - `libm`: Use floating point implementations from [libm]. Useful for `#[no_std]`.
+//! - `sse2`, `sse4_2`, `avx2` and `avx512` (enabled by default): Compile the [`Simd`] implementation for the x86
+//!   level of the same name, and allow [`dispatch`] to select it. Each of these also enables the levels below it.
+//!   These have no effect on other architectures.
+//!   See [multiversioning on x86](#multiversioning-on-x86) for why you might disable them.
 //! - `force_support_fallback`: Force scalar fallback, to be supported, even if your compilation target has a better baseline.
+//! - `soft_float`: Compute the float operations of the fallback level with integer arithmetic, rather than the host's
+//!   float instructions. The results are then the same on every host, including the sign and payload of NaN results,
+//!   so the fallback level can be used as a bit-exact oracle in differential tests. This is much slower, so is only
+//!   intended for testing.
+//! - `custom_level`: Allow other crates to implement [`Simd`] for their own levels, on top of the levels of this
+//!   crate, through the `custom::CustomLevel` trait. This adds `Level::Custom`, and
+//!   `custom::assert_conforms` to test a custom level. It's intended for experimenting with new backends.
+//! - `rayon`: Enable `par_simd_map`, which processes the chunks of a slice in parallel with [rayon], with SIMD on
+//!   each thread. This implies `std`.
+//! - `macros`: Enable the `simd_impl` attribute macro, for dispatching methods from a stored [`Level`].
+//!   This adds a dependency on a procedural macro crate, so is disabled by default.
 //!
 //! At least one of `std` and `libm` is required; `std` overrides `libm`.
 //!
@@ -191,6 +226,7 @@
 //! It benefited from conversations with Luca Versari, though he is not responsible for any of the mistakes or bad decisions.
 //!
 //! [`pulp`]: https://crates.io/crates/pulp
+//! [rayon]: https://crates.io/crates/rayon
 // LINEBENDER LINT SET - lib.rs - v3
 // See https://linebender.org/wiki/canonical-lints/
 // These lints shouldn't apply to examples or tests.
@@ -212,30 +248,99 @@ extern crate std;
 #[cfg(all(not(feature = "libm"), not(feature = "std")))]
 compile_error!("fearless_simd requires either the `std` or `libm` feature");
 
-// Suppress the unused_crate_dependencies lint when both std and libm are specified.
-#[cfg(all(feature = "std", feature = "libm"))]
+// Suppress the unused_crate_dependencies lint when libm is specified, but std or soft_float is used instead.
+#[cfg(all(feature = "libm", any(feature = "std", feature = "soft_float")))]
 use libm as _;
 
+#[cfg(feature = "std")]
+pub mod aligned;
+mod bf16;
+pub mod bitpack;
+#[cfg(feature = "custom_level")]
+pub mod custom;
+pub mod dct;
+#[cfg(feature = "std")]
+mod debug;
+pub mod decimal;
+pub mod delta;
+pub mod denormal;
+pub mod determinism;
+mod fallback_float;
+pub mod fir;
+#[cfg(feature = "std")]
+pub mod format;
+pub mod gemm_ukernel;
 mod generated;
+mod hash;
+pub mod iir;
+pub mod integral;
 mod kernel_macros;
 mod macros;
+mod math;
+pub mod morphology;
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+mod neon_features;
+#[cfg(feature = "rayon")]
+pub mod par;
+mod partial;
+pub mod png;
+mod reduce;
+pub mod rng;
+mod shuffle;
+mod simd_fn;
+pub mod slice;
+pub mod soa;
+#[cfg(feature = "soft_float")]
+mod soft_float;
 mod support;
+pub mod testing;
+pub mod topk;
 mod traits;
 mod transmute;
+pub mod varint;
+#[cfg(all(
+    any(test, not(feature = "std")),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod x86_cpuid;
+pub mod yuv;
 
+pub use bf16::bf16x8;
+pub use denormal::SimdFlushToZero;
 pub use generated::*;
+pub use hash::{LaneHash, SimdHashLanes};
+pub use math::{SimdPow, SimdTrig, poly_eval};
+pub use partial::PartialVec;
+pub use reduce::{SimdArgMinMax, reduce_lanes};
+pub use simd_fn::SimdFn;
 pub use traits::*;
 
+#[cfg(feature = "macros")]
+pub use fearless_simd_macros::simd_impl;
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use debug::{DumpLanes as __DumpLanes, dump_lanes_impl as __dump_lanes};
+#[doc(hidden)]
+pub use shuffle::{
+    shuffle as __shuffle, shuffle_indices as __shuffle_indices, shuffle2 as __shuffle2,
+    shuffle2_indices as __shuffle2_indices,
+};
+
 /// This prelude module re-exports every SIMD trait defined in this library. It's useful for accessing trait methods.
 ///
 /// Only traits are exported through the prelude; types must be exported separately.
 pub mod prelude {
+    pub use crate::denormal::SimdFlushToZero;
     pub use crate::generated::simd_trait::*;
+    pub use crate::hash::{LaneHash, SimdHashLanes};
+    pub use crate::math::{SimdPow, SimdTrig};
+    pub use crate::reduce::SimdArgMinMax;
     pub use crate::traits::*;
 }
 
 /// Implementations of [`Simd`] for 64 bit ARM.
-#[cfg(target_arch = "aarch64")]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 pub mod aarch64 {
     pub use crate::generated::Neon;
 }
@@ -295,6 +400,70 @@ fn x86_detects_icelake_avx512() -> bool {
         && std::arch::is_x86_feature_detected!("xsaves")
 }
 
+// The Linux `AT_HWCAP` bits which `Level::from_aarch64_hwcaps` reads.
+// These are also the bits which the standard library uses to detect `neon`.
+#[cfg(target_arch = "aarch64")]
+const AARCH64_HWCAP_FP: u64 = 1 << 0;
+
+#[cfg(target_arch = "aarch64")]
+const AARCH64_HWCAP_ASIMD: u64 = 1 << 1;
+
+#[cfg(target_arch = "aarch64")]
+const AARCH64_HWCAP_FPHP: u64 = 1 << 9;
+
+#[cfg(target_arch = "aarch64")]
+const AARCH64_HWCAP_ASIMDHP: u64 = 1 << 10;
+
+// The feature sets which `Level::from_feature_snapshot` requires for each x86 level.
+// Unlike `is_x86_feature_detected`, a snapshot isn't guaranteed to include the features implied
+// by the ones it lists, so each set also spells out the implied features the level relies on.
+// These must be kept in sync with the detection in `Level::new`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const X86_SSE2_FEATURES: &[&str] = &["sse", "sse2", "fxsr"];
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const X86_SSE4_2_FEATURES: &[&str] = &["sse3", "ssse3", "sse4.1", "sse4.2", "cmpxchg16b", "popcnt"];
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const X86_AVX2_FEATURES: &[&str] = &[
+    "avx", "avx2", "bmi1", "bmi2", "f16c", "fma", "lzcnt", "movbe", "xsave",
+];
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const X86_AVX512_FEATURES: &[&str] = &[
+    "adx",
+    "aes",
+    "avx512bitalg",
+    "avx512bw",
+    "avx512cd",
+    "avx512dq",
+    "avx512f",
+    "avx512ifma",
+    "avx512vbmi",
+    "avx512vbmi2",
+    "avx512vl",
+    "avx512vnni",
+    "avx512vpopcntdq",
+    "gfni",
+    "pclmulqdq",
+    "rdrand",
+    "rdseed",
+    "sha",
+    "vaes",
+    "vpclmulqdq",
+    "xsavec",
+    "xsaveopt",
+    "xsaves",
+];
+
+/// The hook set by [`Level::set_detection_hook`].
+#[cfg(feature = "std")]
+static DETECTION_HOOK: std::sync::OnceLock<fn(Level)> = std::sync::OnceLock::new();
+
+/// Guards the single call of [`DETECTION_HOOK`].
+#[cfg(feature = "std")]
+static DETECTION_HOOK_CALLED: std::sync::Once = std::sync::Once::new();
+
 /// The level enum with the specific SIMD capabilities available.
 ///
 /// The contained values serve as a proof that the associated target
@@ -307,7 +476,7 @@ pub enum Level {
     /// This can be created with [`Level::fallback`].
     Fallback(Fallback),
     /// The Neon instruction set on 64 bit ARM.
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
     Neon(Neon),
     /// The SIMD 128 instructions on 32-bit WebAssembly.
     #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
@@ -329,6 +498,12 @@ pub enum Level {
     /// The x86-64-v3 instruction set on (32 and 64 bit) x86, including AVX2 and FMA.
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     Avx2(Avx2),
+    /// A level implemented outside of this crate, which is created by [`Level::custom`].
+    ///
+    /// [`dispatch`] runs code with the custom level's host, as it can't name the custom level's type.
+    /// Code which wants to use the custom level itself should recover it with [`Level::as_custom`].
+    #[cfg(feature = "custom_level")]
+    Custom(custom::CustomToken),
     // If new variants are added, make sure to handle them in `Level::dispatch`
     // and `dispatch!()`
 }
@@ -356,6 +531,8 @@ impl Level {
     /// If you are on an embedded device where these macros are not supported,
     /// you should construct the relevant variants yourself, using whatever
     /// way your specific chip supports accessing the current level.
+    /// On aarch64, [`from_aarch64_hwcaps`](Self::from_aarch64_hwcaps) can do this from the
+    /// hardware capabilities, if you are able to read them.
     ///
     /// This value should be passed to [`dispatch`].
     #[cfg(any(feature = "std", target_arch = "wasm32"))]
@@ -365,7 +542,56 @@ impl Level {
         reason = "The `Level::new()` function is not always available, and we also want to be explicit about when runtime feature detection happens"
     )]
     pub fn new() -> Self {
-        #[cfg(target_arch = "aarch64")]
+        let level = Self::detect();
+        #[cfg(feature = "std")]
+        if let Some(hook) = DETECTION_HOOK.get() {
+            // If an earlier call of the hook panicked, don't call it again, but don't make
+            // detection panic either.
+            DETECTION_HOOK_CALLED.call_once_force(|state| {
+                if !state.is_poisoned() {
+                    hook(level);
+                }
+            });
+        }
+        level
+    }
+
+    /// Register a function to be told which level [`Level::new`] selects.
+    ///
+    /// This is intended for applications which want to record the SIMD level in crash reports
+    /// or metrics, without wrapping each of their calls to `Level::new`.
+    /// The hook is called once per process, by the first call to `Level::new` after it is set,
+    /// and that call doesn't return until the hook has finished.
+    /// Levels created in other ways, such as by [`Level::baseline`] or
+    /// [`Level::from_feature_snapshot`], aren't reported.
+    ///
+    /// Only one hook can be set. If one has already been set, this returns `Err` with `hook`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fearless_simd::Level;
+    ///
+    /// fn record_level(level: Level) {
+    ///     // E.g. attach to crash reports.
+    ///     eprintln!("Using SIMD level {level:?}");
+    /// }
+    ///
+    /// Level::set_detection_hook(record_level).expect("no other hook was set");
+    /// let level = Level::new(); // Calls `record_level`.
+    /// # let _ = level;
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_detection_hook(hook: fn(Self)) -> Result<(), fn(Self)> {
+        DETECTION_HOOK.set(hook)
+    }
+
+    /// The runtime detection for [`Level::new`].
+    #[cfg(any(feature = "std", target_arch = "wasm32"))]
+    fn detect() -> Self {
+        // ARM64EC code runs natively on Arm, even though it is linked with emulated x64 code. The emulator's CPUID
+        // describes the x64 features it translates, not the Arm processor, so this uses the Arm detection.
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
         if std::arch::is_aarch64_feature_detected!("neon") {
             return unsafe { Self::Neon(Neon::new_unchecked()) };
         }
@@ -421,7 +647,10 @@ impl Level {
             }
         }
         #[cfg(any(
-            all(target_arch = "aarch64", not(target_feature = "neon")),
+            all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                not(target_feature = "neon")
+            ),
             all(
                 any(target_arch = "x86", target_arch = "x86_64"),
                 not(all(target_feature = "sse2", target_feature = "fxsr"))
@@ -431,6 +660,7 @@ impl Level {
                 target_arch = "x86",
                 target_arch = "x86_64",
                 target_arch = "aarch64",
+                target_arch = "arm64ec",
                 target_arch = "wasm32"
             )),
         ))]
@@ -452,14 +682,159 @@ impl Level {
     /// target features cannot be detected at runtime.
     /// Most users should prefer [`new`](Self::new).
     /// This is discussed in more detail in `new`'s documentation.
+    ///
+    /// On x86 and x86-64, this also works without the `std` feature, by reading the `cpuid` and
+    /// `xgetbv` instructions directly.
+    /// This selects the same level as `new` would, including only selecting AVX2 or AVX-512 if the
+    /// operating system (or for a kernel, the kernel itself) has enabled saving their registers.
+    /// Unlike `new`, the result isn't cached, so it should be stored rather than detected repeatedly.
     #[allow(clippy::allow_attributes, reason = "Only needed in some cfgs.")]
     #[allow(unreachable_code, reason = "Fallback unreachable in some cfgs.")]
     pub fn try_detect() -> Option<Self> {
         #[cfg(any(feature = "std", target_arch = "wasm32"))]
         return Some(Self::new());
+        #[cfg(all(not(feature = "std"), any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            let cpuid = x86_cpuid::Cpuid::read();
+            // Safety: The features were read from the current CPU, and x86 processes are expected to have
+            // the same features on every core, just as `is_x86_feature_detected` expects.
+            return Some(unsafe {
+                Self::from_feature_snapshot(|feature| cpuid.has_feature(feature))
+            });
+        }
         None
     }
 
+    /// Select the best level supported by a snapshot of CPU features taken elsewhere.
+    ///
+    /// This is an alternative to [`Level::new`] for processes which must not detect features
+    /// themselves, such as seccomp-sandboxed processes, or code running in a signal handler.
+    /// The snapshot could instead be parsed from `/proc/cpuinfo` or the auxiliary vector by a
+    /// more privileged process, and passed over IPC.
+    ///
+    /// `has_feature` is called with Rust target feature names, as used by `#[target_feature]` and
+    /// `is_x86_feature_detected!` (such as `"sse4.2"`, `"avx2"` or `"neon"`), and should report
+    /// whether the snapshot contains that feature.
+    /// Note that the names used by `/proc/cpuinfo` or `HWCAP` are often different
+    /// (`sse4_2`, `cx16`, `asimd`), so will need to be translated.
+    ///
+    /// The snapshot is validated before being trusted: a level is only selected if every
+    /// feature it depends on is reported, including features which are normally implied by
+    /// others (for example, AVX2 requires both `"avx2"` and `"avx"` to be reported).
+    /// The features enabled at compile time are known to be available, so the result is never
+    /// lower than [`Level::baseline`].
+    ///
+    /// On WebAssembly, SIMD support is fixed at compile time, so this always returns
+    /// [`Level::baseline`].
+    ///
+    /// # Safety
+    ///
+    /// `has_feature` must only return `true` for features which are available on every CPU
+    /// that this process may run on.
+    /// Passing an inaccurate snapshot is undefined behavior, in the same way as calling the
+    /// tokens' `new_unchecked` constructors is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fearless_simd::Level;
+    ///
+    /// // E.g. received from a supervisor process.
+    /// let snapshot = ["sse", "sse2", "fxsr"];
+    /// // Safety: The supervisor detected these features on this machine.
+    /// let level = unsafe { Level::from_feature_snapshot(|feature| snapshot.contains(&feature)) };
+    /// # let _ = level;
+    /// ```
+    #[allow(clippy::allow_attributes, reason = "Only needed in some cfgs.")]
+    #[allow(
+        unused_variables,
+        reason = "Unused on targets without runtime detection."
+    )]
+    pub unsafe fn from_feature_snapshot(has_feature: impl Fn(&str) -> bool) -> Self {
+        let baseline = Self::baseline();
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        if baseline.as_neon().is_some() || has_feature("neon") {
+            // Safety: The caller guarantees that the snapshot is accurate.
+            return unsafe { Self::Neon(Neon::new_unchecked()) };
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            let has_all = |features: &[&str]| features.iter().all(|feature| has_feature(feature));
+            let sse2 = baseline.as_sse2().is_some() || has_all(X86_SSE2_FEATURES);
+            let sse4_2 = baseline.as_sse4_2().is_some() || (sse2 && has_all(X86_SSE4_2_FEATURES));
+            let avx2 = baseline.as_avx2().is_some() || (sse4_2 && has_all(X86_AVX2_FEATURES));
+            let avx512 = baseline.as_avx512().is_some() || (avx2 && has_all(X86_AVX512_FEATURES));
+
+            // Safety: Each level was either enabled at compile time, or every feature its
+            // token requires was reported by the snapshot, which the caller guarantees is accurate.
+            unsafe {
+                if avx512 {
+                    return Self::Avx512(Avx512::new_unchecked());
+                } else if avx2 {
+                    return Self::Avx2(Avx2::new_unchecked());
+                } else if sse4_2 {
+                    return Self::Sse4_2(Sse4_2::new_unchecked());
+                } else if sse2 {
+                    return Self::Sse2(Sse2::new_unchecked());
+                }
+            }
+        }
+
+        baseline
+    }
+
+    /// Select the best level supported by a 64-bit ARM `HWCAP` value.
+    ///
+    /// This is intended for `no_std` targets, such as an RTOS, where [`Level::new`] isn't
+    /// available, but the hardware capabilities can be found in some other way: for example,
+    /// from the `AT_HWCAP` entry of the auxiliary vector, or by translating the device tree or
+    /// the CPU's ID registers.
+    ///
+    /// `hwcap` uses the bit layout of `AT_HWCAP` on aarch64 Linux. The bits which are read are:
+    ///
+    /// - `HWCAP_FP` (bit 0) and `HWCAP_ASIMD` (bit 1), which are both required for Neon.
+    /// - `HWCAP_FPHP` (bit 9) and `HWCAP_ASIMDHP` (bit 10). If half-precision floating point is
+    ///   reported, Neon is only selected if half-precision Advanced SIMD is also reported.
+    ///   This is the same rule that `is_aarch64_feature_detected!("neon")` uses.
+    ///
+    /// All other bits are ignored.
+    /// As with [`from_feature_snapshot`](Self::from_feature_snapshot), the result is never lower
+    /// than [`Level::baseline`], so if Neon is enabled at compile time, it is always selected.
+    ///
+    /// # Safety
+    ///
+    /// `hwcap` must only report features which are available on every CPU that this process
+    /// may run on.
+    /// Passing an inaccurate value is undefined behavior, in the same way as calling
+    /// [`Neon::new_unchecked`](crate::aarch64::Neon::new_unchecked) is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(target_arch = "aarch64")] {
+    /// use fearless_simd::Level;
+    ///
+    /// # fn read_hwcap() -> u64 { 0b11 }
+    /// // E.g. read from the auxiliary vector by the RTOS.
+    /// let hwcap: u64 = read_hwcap();
+    /// // Safety: The RTOS reports the capabilities of every core.
+    /// let level = unsafe { Level::from_aarch64_hwcaps(hwcap) };
+    /// # let _ = level;
+    /// # }
+    /// ```
+    #[cfg(target_arch = "aarch64")]
+    pub unsafe fn from_aarch64_hwcaps(hwcap: u64) -> Self {
+        let has = |bit: u64| hwcap & bit != 0;
+        let neon = has(AARCH64_HWCAP_FP)
+            && has(AARCH64_HWCAP_ASIMD)
+            && (!has(AARCH64_HWCAP_FPHP) || has(AARCH64_HWCAP_ASIMDHP));
+        // Safety: The caller guarantees that `hwcap` is accurate, and `neon` is only reported
+        // if `hwcap` proves it's available.
+        unsafe { Self::from_feature_snapshot(|feature| neon && feature == "neon") }
+    }
+
     /// Check whether this is the `Fallback` level; that is, whether no better feature level could
     /// be statically or dynamically detected. This is useful if there's a scalarized version of
     /// your algorithm that runs faster if SIMD isn't supported.
@@ -475,7 +850,7 @@ impl Level {
     ///
     /// This can be used in combination with the [kernel] macro to safely access level-specific
     /// SIMD intrinsics.
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
     #[inline]
     pub fn as_neon(self) -> Option<Neon> {
         #[allow(
@@ -605,6 +980,30 @@ impl Level {
         }
     }
 
+    /// Carry the custom level `token` in a `Level`.
+    ///
+    /// This is usually returned from [`CustomLevel::level`](custom::CustomLevel::level), so that the
+    /// custom level can be recovered with [`Level::as_custom`] from a `Level` which is passed around.
+    /// A unit struct can be borrowed for `'static`, like `Level::custom(&MyLevel)`.
+    #[cfg(feature = "custom_level")]
+    pub fn custom<L: custom::CustomLevel>(token: &'static L) -> Self {
+        Self::Custom(custom::CustomToken::new(token))
+    }
+
+    /// If this is a custom level of type `L`, access it.
+    ///
+    /// Custom levels aren't proof that any instruction set is available, so [`Level::as_sse2`]
+    /// and similar methods return `None` for them, even if the custom level's host is one of those
+    /// levels. Use [`CustomToken::host`](custom::CustomToken::host) to access the host's level.
+    #[cfg(feature = "custom_level")]
+    #[inline]
+    pub fn as_custom<L: custom::CustomLevel>(self) -> Option<L> {
+        match self {
+            Self::Custom(custom) => custom.downcast(),
+            _ => None,
+        }
+    }
+
     /// Get the strongest statically supported SIMD level.
     ///
     /// That is, if your compilation run ambiently declares that a target feature is enabled,
@@ -633,12 +1032,13 @@ impl Level {
             target_arch = "x86",
             target_arch = "x86_64",
             target_arch = "aarch64",
+            target_arch = "arm64ec",
             target_arch = "wasm32"
         )))]
         {
             return Self::Fallback(Fallback::new());
         }
-        #[cfg(target_arch = "aarch64")]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
         {
             #[cfg(target_feature = "neon")]
             return unsafe { Self::Neon(Neon::new_unchecked()) };
@@ -782,6 +1182,11 @@ impl Level {
         // still name lower levels even when the ambient target baseline makes those backends
         // redundant. Normalize the proof to the best dispatchable level, while leaving exact
         // token identity available for `kernel!` and explicit token use.
+        #[cfg(feature = "custom_level")]
+        if let Self::Custom(custom) = self {
+            return custom.host().__dispatch_target();
+        }
+
         #[cfg(feature = "force_support_fallback")]
         #[allow(
             irrefutable_let_patterns,
@@ -796,28 +1201,28 @@ impl Level {
             #[allow(unused_variables, reason = "Unused with all cfgs active")]
             let baseline = Self::baseline();
 
-            #[cfg(not(disable_dispatch_avx512))]
+            #[cfg(all(feature = "avx512", not(disable_dispatch_avx512)))]
             if let Some(avx512) = self.as_avx512().or_else(|| baseline.as_avx512()) {
                 return Self::Avx512(avx512);
             }
 
-            #[cfg(not(disable_dispatch_avx2))]
+            #[cfg(all(feature = "avx2", not(disable_dispatch_avx2)))]
             if let Some(avx2) = self.as_avx2().or_else(|| baseline.as_avx2()) {
                 return Self::Avx2(avx2);
             }
 
-            #[cfg(not(disable_dispatch_sse4_2))]
+            #[cfg(all(feature = "sse4_2", not(disable_dispatch_sse4_2)))]
             if let Some(sse4_2) = self.as_sse4_2().or_else(|| baseline.as_sse4_2()) {
                 return Self::Sse4_2(sse4_2);
             }
 
-            #[cfg(not(disable_dispatch_sse2))]
+            #[cfg(all(feature = "sse2", not(disable_dispatch_sse2)))]
             if let Some(sse2) = self.as_sse2().or_else(|| baseline.as_sse2()) {
                 return Self::Sse2(sse2);
             }
         }
 
-        #[cfg(target_arch = "aarch64")]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
         {
             let baseline = Self::baseline();
             if let Some(neon) = self.as_neon().or_else(|| baseline.as_neon()) {
@@ -869,9 +1274,12 @@ impl Level {
     ///
     /// [enabled]: https://doc.rust-lang.org/reference/attributes/codegen.html#the-target_feature-attribute
     #[inline]
-    #[expect(
-        unreachable_patterns,
-        reason = "Level is `non_exhaustive`, but we are in the crate it's defined."
+    #[cfg_attr(
+        not(feature = "custom_level"),
+        expect(
+            unreachable_patterns,
+            reason = "Level is `non_exhaustive`, but we are in the crate it's defined."
+        )
     )]
     pub fn dispatch<W: WithSimd>(self, f: W) -> W::Output {
         dispatch!(self, simd => f.with_simd(simd))
@@ -888,4 +1296,175 @@ mod tests {
     fn level_is_send_sync() {
         assert_is_send_sync::<Level>();
     }
+
+    /// Tokens and vectors can be held across `.await` points in futures which must be `Send`.
+    #[test]
+    fn tokens_and_vectors_are_send_sync() {
+        fn vectors_are_send_sync<S: crate::Simd>() {
+            assert_is_send_sync::<S>();
+            assert_is_send_sync::<crate::f32x4<S>>();
+            assert_is_send_sync::<crate::mask32x4<S>>();
+            assert_is_send_sync::<S::f32s>();
+            assert_is_send_sync::<S::u8s>();
+        }
+        vectors_are_send_sync::<crate::Fallback>();
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        vectors_are_send_sync::<crate::Neon>();
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        vectors_are_send_sync::<crate::WasmSimd128>();
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse2"))]
+        vectors_are_send_sync::<crate::Sse2>();
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse4_2"))]
+        vectors_are_send_sync::<crate::Sse4_2>();
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx2"))]
+        vectors_are_send_sync::<crate::Avx2>();
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+        vectors_are_send_sync::<crate::Avx512>();
+    }
+
+    #[test]
+    fn simd_fn_is_send_sync() {
+        assert_is_send_sync::<crate::SimdFn<(*const u8, core::cell::Cell<u8>), *mut u8>>();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn detection_hook_is_called_once() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn hook(_: Level) {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        assert!(
+            Level::set_detection_hook(hook).is_ok(),
+            "no other test should set a hook"
+        );
+        let _ = Level::new();
+        let _ = Level::new();
+        assert_eq!(
+            CALLS.load(Ordering::Relaxed),
+            1,
+            "the hook should be called by the first detection only"
+        );
+        assert!(
+            Level::set_detection_hook(hook).is_err(),
+            "a second hook should be rejected"
+        );
+    }
+
+    #[test]
+    fn empty_feature_snapshot_is_baseline() {
+        let level = unsafe { Level::from_feature_snapshot(|_| false) };
+        assert_eq!(
+            level.is_fallback(),
+            Level::baseline().is_fallback(),
+            "an empty snapshot should select the baseline level"
+        );
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn x86_feature_snapshot_selects_best_level() {
+        use crate::{
+            X86_AVX2_FEATURES, X86_AVX512_FEATURES, X86_SSE2_FEATURES, X86_SSE4_2_FEATURES,
+        };
+
+        let all = [
+            X86_SSE2_FEATURES,
+            X86_SSE4_2_FEATURES,
+            X86_AVX2_FEATURES,
+            X86_AVX512_FEATURES,
+        ];
+        let has_feature = |feature: &str| all.iter().any(|set| set.contains(&feature));
+        let level = unsafe { Level::from_feature_snapshot(has_feature) };
+        assert!(
+            level.as_avx512().is_some(),
+            "the full Ice Lake feature set should select AVX-512"
+        );
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn x86_feature_snapshot_requires_implied_features() {
+        use crate::{X86_AVX2_FEATURES, X86_SSE2_FEATURES, X86_SSE4_2_FEATURES};
+
+        // A snapshot claiming AVX2, but which is missing `avx`, must not be trusted with AVX2.
+        let features = [X86_SSE2_FEATURES, X86_SSE4_2_FEATURES, X86_AVX2_FEATURES];
+        let without_avx =
+            |feature: &str| feature != "avx" && features.iter().any(|set| set.contains(&feature));
+        let level = unsafe { Level::from_feature_snapshot(without_avx) };
+        assert_eq!(
+            level.as_avx2().is_some(),
+            Level::baseline().as_avx2().is_some(),
+            "AVX2 should only be selected if enabled at compile time"
+        );
+        assert!(
+            level.as_sse4_2().is_some(),
+            "the snapshot still proves SSE4.2 support"
+        );
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn x86_cpuid_matches_std_detection() {
+        let cpuid = crate::x86_cpuid::Cpuid::read();
+        let level = unsafe { Level::from_feature_snapshot(|feature| cpuid.has_feature(feature)) };
+        assert_eq!(
+            core::mem::discriminant(&level),
+            core::mem::discriminant(&Level::new()),
+            "reading `cpuid` directly should select the same level as the standard library, from {cpuid:?}"
+        );
+    }
+
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    #[test]
+    fn aarch64_feature_snapshot_selects_neon() {
+        let level = unsafe { Level::from_feature_snapshot(|feature| feature == "neon") };
+        assert!(
+            level.as_neon().is_some(),
+            "`neon` should select the Neon level"
+        );
+    }
+
+    #[cfg(all(
+        feature = "std",
+        any(target_arch = "aarch64", target_arch = "arm64ec"),
+        target_feature = "neon"
+    ))]
+    #[test]
+    fn neon_baseline_is_detected() {
+        // Every Windows on Arm target, including ARM64EC, enables Neon at compile time.
+        assert!(
+            Level::new().as_neon().is_some(),
+            "detection should select Neon when it is enabled at compile time"
+        );
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn aarch64_hwcaps_select_neon() {
+        use crate::{AARCH64_HWCAP_ASIMD, AARCH64_HWCAP_FP};
+
+        let level = unsafe { Level::from_aarch64_hwcaps(AARCH64_HWCAP_FP | AARCH64_HWCAP_ASIMD) };
+        assert!(
+            level.as_neon().is_some(),
+            "FP and ASIMD should select the Neon level"
+        );
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn aarch64_hwcaps_require_half_precision_asimd() {
+        use crate::{AARCH64_HWCAP_ASIMD, AARCH64_HWCAP_FP, AARCH64_HWCAP_FPHP};
+
+        let hwcap = AARCH64_HWCAP_FP | AARCH64_HWCAP_ASIMD | AARCH64_HWCAP_FPHP;
+        let level = unsafe { Level::from_aarch64_hwcaps(hwcap) };
+        assert_eq!(
+            level.as_neon().is_some(),
+            Level::baseline().as_neon().is_some(),
+            "FPHP without ASIMDHP should only select Neon if enabled at compile time"
+        );
+    }
 }
@@ -29,6 +29,21 @@
 /// majority of cases), you can use `?` on the return value of dispatch instead.
 /// To emulate early return, you can use [`ControlFlow`](core::ops::ControlFlow) instead.
 ///
+/// # Performance
+///
+/// Selecting the implementation is a short chain of comparisons on the level, starting with the
+/// strongest level supported on the target. As a program's level doesn't change, these branches
+/// are always predicted correctly after the first call, and none of the paths are marked as cold.
+/// On x86, each level which is [disabled at compile time](crate#multiversioning-on-x86) removes
+/// one comparison.
+///
+/// The operation is compiled into a separate function for each level with target features enabled,
+/// which can't be inlined into the caller. The cost of a call is typically only a few nanoseconds,
+/// but it prevents optimisations across the call.
+/// In hot loops, such as an audio callback processing one sample at a time, you should dispatch once
+/// around the loop rather than inside it.
+/// The `dispatch_overhead` example measures the difference between these on your machine.
+///
 /// # Example
 ///
 /// ```rust
@@ -49,7 +64,7 @@
 macro_rules! dispatch {
     ($level:expr, $simd:pat => $op:expr) => {{
         match $crate::Level::__dispatch_target($level) {
-            #[cfg(target_arch = "aarch64")]
+            #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
             $crate::Level::Neon(neon) => {
                 $crate::__fearless_simd_dispatch_with_token!(neon, $simd => $op)
             }
@@ -57,21 +72,23 @@ macro_rules! dispatch {
             $crate::Level::WasmSimd128(wasm) => {
                 $crate::__fearless_simd_dispatch_with_token!(wasm, $simd => $op)
             }
+            // The x86 levels are listed strongest first, as the level detected at runtime is
+            // usually the strongest one compiled in.
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-            $crate::Level::Sse2(sse2) => {
-                $crate::__fearless_simd_dispatch_dispatch_sse2!(sse2, $simd => $op)
-            }
-            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-            $crate::Level::Sse4_2(sse4_2) => {
-                $crate::__fearless_simd_dispatch_dispatch_sse4_2!(sse4_2, $simd => $op)
+            $crate::Level::Avx512(avx512) => {
+                $crate::__fearless_simd_dispatch_dispatch_avx512!(avx512, $simd => $op)
             }
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             $crate::Level::Avx2(avx2) => {
                 $crate::__fearless_simd_dispatch_dispatch_avx2!(avx2, $simd => $op)
             }
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-            $crate::Level::Avx512(avx512) => {
-                $crate::__fearless_simd_dispatch_dispatch_avx512!(avx512, $simd => $op)
+            $crate::Level::Sse4_2(sse4_2) => {
+                $crate::__fearless_simd_dispatch_dispatch_sse4_2!(sse4_2, $simd => $op)
+            }
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            $crate::Level::Sse2(sse2) => {
+                $crate::__fearless_simd_dispatch_dispatch_sse2!(sse2, $simd => $op)
             }
             $crate::Level::Fallback(fb) => {
                 $crate::__fearless_simd_dispatch_dispatch_fallback!(fb, $simd => $op)
@@ -129,11 +146,14 @@ macro_rules! __fearless_simd_dispatch_pruned {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(any(
-    all(target_arch = "aarch64", not(target_feature = "neon")),
+    all(
+        any(target_arch = "aarch64", target_arch = "arm64ec"),
+        not(target_feature = "neon")
+    ),
     all(
         any(target_arch = "x86", target_arch = "x86_64"),
         any(
-            disable_dispatch_sse2,
+            any(disable_dispatch_sse2, not(feature = "sse2")),
             not(all(target_feature = "sse2", target_feature = "fxsr"))
         ),
     ),
@@ -142,6 +162,7 @@ macro_rules! __fearless_simd_dispatch_pruned {
         target_arch = "x86",
         target_arch = "x86_64",
         target_arch = "aarch64",
+        target_arch = "arm64ec",
         target_arch = "wasm32"
     )),
     feature = "force_support_fallback"
@@ -156,11 +177,14 @@ macro_rules! __fearless_simd_dispatch_dispatch_fallback {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(not(any(
-    all(target_arch = "aarch64", not(target_feature = "neon")),
+    all(
+        any(target_arch = "aarch64", target_arch = "arm64ec"),
+        not(target_feature = "neon")
+    ),
     all(
         any(target_arch = "x86", target_arch = "x86_64"),
         any(
-            disable_dispatch_sse2,
+            any(disable_dispatch_sse2, not(feature = "sse2")),
             not(all(target_feature = "sse2", target_feature = "fxsr"))
         ),
     ),
@@ -169,6 +193,7 @@ macro_rules! __fearless_simd_dispatch_dispatch_fallback {
         target_arch = "x86",
         target_arch = "x86_64",
         target_arch = "aarch64",
+        target_arch = "arm64ec",
         target_arch = "wasm32"
     )),
     feature = "force_support_fallback"
@@ -180,7 +205,7 @@ macro_rules! __fearless_simd_dispatch_dispatch_fallback {
 /// Implementation detail of [`crate::dispatch`]; this is not public API.
 #[macro_export]
 #[doc(hidden)]
-#[cfg(not(disable_dispatch_avx512))]
+#[cfg(all(feature = "avx512", not(disable_dispatch_avx512)))]
 macro_rules! __fearless_simd_dispatch_dispatch_avx512 {
     ($avx512:expr, $simd:pat => $op:expr) => {
         $crate::__fearless_simd_dispatch_with_token!($avx512, $simd => $op)
@@ -190,7 +215,7 @@ macro_rules! __fearless_simd_dispatch_dispatch_avx512 {
 /// Implementation detail of [`crate::dispatch`]; this is not public API.
 #[macro_export]
 #[doc(hidden)]
-#[cfg(disable_dispatch_avx512)]
+#[cfg(any(disable_dispatch_avx512, not(feature = "avx512")))]
 macro_rules! __fearless_simd_dispatch_dispatch_avx512 {
     ($avx512:expr, $simd:pat => $op:expr) => {{ $crate::__fearless_simd_dispatch_pruned!($avx512) }};
 }
@@ -199,9 +224,9 @@ macro_rules! __fearless_simd_dispatch_dispatch_avx512 {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(all(
-    not(disable_dispatch_avx2),
+    all(feature = "avx2", not(disable_dispatch_avx2)),
     any(
-        disable_dispatch_avx512,
+        any(disable_dispatch_avx512, not(feature = "avx512")),
         not(all(
             target_feature = "aes",
             target_feature = "avx512bitalg",
@@ -247,9 +272,9 @@ macro_rules! __fearless_simd_dispatch_dispatch_avx2 {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(any(
-    disable_dispatch_avx2,
+    any(disable_dispatch_avx2, not(feature = "avx2")),
     all(
-        not(disable_dispatch_avx512),
+        all(feature = "avx512", not(disable_dispatch_avx512)),
         target_feature = "aes",
         target_feature = "avx512bitalg",
         target_feature = "avx512bw",
@@ -291,9 +316,9 @@ macro_rules! __fearless_simd_dispatch_dispatch_avx2 {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(all(
-    not(disable_dispatch_sse4_2),
+    all(feature = "sse4_2", not(disable_dispatch_sse4_2)),
     any(
-        disable_dispatch_avx2,
+        any(disable_dispatch_avx2, not(feature = "avx2")),
         not(all(
             target_feature = "avx2",
             target_feature = "bmi1",
@@ -319,9 +344,9 @@ macro_rules! __fearless_simd_dispatch_dispatch_sse4_2 {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(any(
-    disable_dispatch_sse4_2,
+    any(disable_dispatch_sse4_2, not(feature = "sse4_2")),
     all(
-        not(disable_dispatch_avx2),
+        all(feature = "avx2", not(disable_dispatch_avx2)),
         target_feature = "avx2",
         target_feature = "bmi1",
         target_feature = "bmi2",
@@ -343,9 +368,9 @@ macro_rules! __fearless_simd_dispatch_dispatch_sse4_2 {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(all(
-    not(disable_dispatch_sse2),
+    all(feature = "sse2", not(disable_dispatch_sse2)),
     any(
-        disable_dispatch_sse4_2,
+        any(disable_dispatch_sse4_2, not(feature = "sse4_2")),
         not(all(
             target_feature = "fxsr",
             target_feature = "sse4.2",
@@ -364,9 +389,9 @@ macro_rules! __fearless_simd_dispatch_dispatch_sse2 {
 #[macro_export]
 #[doc(hidden)]
 #[cfg(any(
-    disable_dispatch_sse2,
+    any(disable_dispatch_sse2, not(feature = "sse2")),
     all(
-        not(disable_dispatch_sse4_2),
+        all(feature = "sse4_2", not(disable_dispatch_sse4_2)),
         target_feature = "fxsr",
         target_feature = "sse4.2",
         target_feature = "cmpxchg16b",
@@ -377,11 +402,336 @@ macro_rules! __fearless_simd_dispatch_dispatch_sse2 {
     ($sse2:expr, $simd:pat => $op:expr) => {{ $crate::__fearless_simd_dispatch_pruned!($sse2) }};
 }
 
+/// Print a SIMD vector or mask to stderr, one column per lane, and return it.
+///
+/// This works like [`std::dbg!`], but lays the lanes out in aligned columns under their
+/// lane indices, and also prints the type and the [`Simd`](crate::Simd) implementation it was created with.
+/// Mask lanes are shown as `true` or `false`.
+/// Vectors with more than 16 lanes are wrapped over several rows.
+///
+/// The first parameter is a label to print alongside the value, which can be anything
+/// implementing [`Display`](core::fmt::Display).
+/// As the value is returned, `dump_lanes!` can be wrapped around an intermediate
+/// expression inside a kernel without restructuring the code.
+///
+/// This macro requires the `std` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, dump_lanes, prelude::*, f32x4};
+///
+/// #[inline(always)]
+/// fn halve<S: Simd>(simd: S) -> [f32; 4] {
+///     let v = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+///     let halved = dump_lanes!("halved", v * 0.5);
+///     dump_lanes!("small", halved.simd_lt(1.0));
+///     halved.into()
+/// }
+///
+/// let level = Level::new();
+/// assert_eq!(dispatch!(level, simd => halve(simd)), [0.5, 1.0, 1.5, 2.0]);
+/// ```
+///
+/// This prints something like:
+///
+/// ```text
+/// [src/main.rs:6] halved: f32x4 (Avx2)
+///   lane |   0   1   2   3
+///        | 0.5 1.0 1.5 2.0
+/// [src/main.rs:7] small: mask32x4 (Avx2)
+///   lane |     0     1     2     3
+///        |  true false false false
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! dump_lanes {
+    ($label:expr, $value:expr $(,)?) => {{
+        let __fearless_simd_value = $value;
+        $crate::__dump_lanes(
+            ::core::concat!(::core::file!(), ":", ::core::line!()),
+            &::std::string::ToString::to_string(&$label),
+            &__fearless_simd_value,
+        );
+        __fearless_simd_value
+    }};
+}
+
+/// Permute the lanes of a vector, using indices known at compile time.
+///
+/// `shuffle!(a, [i0, i1, ...])` returns a vector of the same type as `a`, where lane `n` is lane
+/// `in` of `a`. Indices can be repeated or left out, so this covers reversals, rotations,
+/// broadcasts of one lane and swaps of color channels.
+///
+/// There must be exactly one index for each lane, and each index must be less than the lane count;
+/// both are checked at compile time. Because the indices are constant, the permutation is compiled
+/// to the best instruction sequence for the pattern on each level, such as `pshufd`, `vpermilps`
+/// or `pshufb` on x86 and `rev64`, `dup` or `tbl` on Arm. To combine the lanes of two vectors, use
+/// [`shuffle2`].
+///
+/// # Example
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*, shuffle, u8x16};
+///
+/// /// Swap the red and blue channels of four RGBA pixels.
+/// #[inline(always)]
+/// fn rgba_to_bgra<S: Simd>(simd: S, pixels: [u8; 16]) -> [u8; 16] {
+///     let pixels = u8x16::from_slice(simd, &pixels);
+///     shuffle!(pixels, [2, 1, 0, 3, 6, 5, 4, 7, 10, 9, 8, 11, 14, 13, 12, 15]).into()
+/// }
+///
+/// let level = Level::new();
+/// let pixels = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+/// assert_eq!(
+///     dispatch!(level, simd => rgba_to_bgra(simd, pixels)),
+///     [3, 2, 1, 4, 7, 6, 5, 8, 11, 10, 9, 12, 15, 14, 13, 16]
+/// );
+/// ```
+///
+/// Indices which are out of range fail to compile:
+///
+/// ```rust,compile_fail
+/// use fearless_simd::{Fallback, f32x4, prelude::*, shuffle};
+///
+/// let simd = Fallback::new();
+/// let a = f32x4::splat(simd, 1.0);
+/// let _ = shuffle!(a, [0, 1, 2, 4]);
+/// ```
+#[macro_export]
+macro_rules! shuffle {
+    ($a:expr, [$($index:expr),+ $(,)?] $(,)?) => {
+        $crate::__shuffle($a, const { $crate::__shuffle_indices([$($index),+]) })
+    };
+}
+
+/// Shuffle the lanes of two vectors together, using indices known at compile time.
+///
+/// `shuffle2!(a, b, [i0, i1, ...])` returns a vector of the same type as `a` and `b`, where lane
+/// `n` is lane `in` of the concatenation `[a, b]`. Indices below the lane count select from `a`,
+/// and the rest select from `b`.
+///
+/// There must be exactly one index for each lane, and each index must be less than twice the
+/// lane count; both are checked at compile time. Because the indices are constant, the shuffle is
+/// compiled to the best instruction sequence for the pattern on each level, such as `palignr` or
+/// `vperm2f128` on x86 and `ext` or `tbl` on Arm.
+///
+/// Unlike the fixed [`zip_low`](crate::SimdFloat::zip_low) or
+/// [`unzip_low`](crate::SimdFloat::unzip_low) operations, this can express any two-source
+/// pattern, which is useful for filters and transposes.
+///
+/// # Example
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, f32x4, prelude::*, shuffle2};
+///
+/// #[inline(always)]
+/// fn odd_even<S: Simd>(simd: S) -> [f32; 4] {
+///     let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);
+///     let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);
+///     shuffle2!(a, b, [0, 5, 2, 7]).into()
+/// }
+///
+/// let level = Level::new();
+/// assert_eq!(dispatch!(level, simd => odd_even(simd)), [0.0, 5.0, 2.0, 7.0]);
+/// ```
+///
+/// Indices which are out of range fail to compile:
+///
+/// ```rust,compile_fail
+/// use fearless_simd::{Fallback, f32x4, prelude::*, shuffle2};
+///
+/// let simd = Fallback::new();
+/// let a = f32x4::splat(simd, 1.0);
+/// let _ = shuffle2!(a, a, [0, 1, 2, 8]);
+/// ```
+#[macro_export]
+macro_rules! shuffle2 {
+    ($a:expr, $b:expr, [$($index:expr),+ $(,)?] $(,)?) => {
+        $crate::__shuffle2(
+            $a,
+            $b,
+            const { $crate::__shuffle2_indices([$($index),+]) },
+        )
+    };
+}
+
+/// Create a [`SimdFn`] from a function which is generic over [`Simd`], dispatching on `level`.
+///
+/// The function must have the signature `fn<S: Simd>(simd: S, args: Args) -> Out`, and should be
+/// `#[inline(always)]`, as with any function called from [`dispatch`].
+/// The resulting `SimdFn<Args, Out>` stores the level, so it can be kept around and called
+/// without a token. See [`SimdFn`] for details.
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, f32x8, prelude::*, simd_fn};
+///
+/// #[inline(always)]
+/// fn sum<S: Simd>(simd: S, values: [f32; 8]) -> f32 {
+///     let v = f32x8::from_slice(simd, &values);
+///     v.as_slice().iter().sum()
+/// }
+///
+/// let sum = simd_fn!(Level::new(), sum);
+/// assert_eq!(sum.call([1.0; 8]), 8.0);
+/// ```
+///
+/// [`Simd`]: crate::Simd
+/// [`SimdFn`]: crate::SimdFn
+/// [`dispatch`]: crate::dispatch
+#[macro_export]
+macro_rules! simd_fn {
+    ($level:expr, $kernel:path $(,)?) => {
+        $crate::SimdFn::__new($level, |level, args| {
+            $crate::dispatch!(level, simd => $kernel(simd, args))
+        })
+    };
+}
+
+/// Assert that two floating point values are equal to within `max_ulps` units in the last place,
+/// lane by lane.
+///
+/// The values can be scalars, arrays or slices of [`f32`] or [`f64`], or floating point vectors,
+/// and they don't need to have the same type as long as their lanes do; see
+/// [`FloatLanes`](crate::testing::FloatLanes). This is useful for comparing the results of a
+/// kernel on different levels, or against a scalar reference.
+///
+/// NaN lanes are only equal to other NaN lanes, and `0.0` is equal to `-0.0`; see
+/// [`Ulps::ulps_diff`](crate::testing::Ulps::ulps_diff). On failure, the panic message shows the
+/// first lane which differs too much, followed by an optional custom message, as with
+/// [`assert_eq!`].
+///
+/// ```rust
+/// use fearless_simd::{Fallback, assert_ulps_eq, f32x4, prelude::*};
+///
+/// let simd = Fallback::new();
+/// let a = f32x4::from_slice(simd, &[0.1, 0.2, 0.7, f32::NAN]);
+/// assert_ulps_eq!(a * 3.0 / 3.0, a, 1);
+/// // 0.1 + 0.2 isn't exactly 0.3, but it's the next representable value.
+/// assert_ulps_eq!(a[0] + a[1], 0.3_f32, 1, "0.1 + 0.2 should be close to 0.3");
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr, $max_ulps:expr $(,)?) => {
+        $crate::testing::__assert_ulps_eq(&$left, &$right, $max_ulps, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $max_ulps:expr, $($arg:tt)+) => {
+        $crate::testing::__assert_ulps_eq(
+            &$left,
+            &$right,
+            $max_ulps,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Repeat a block of code a fixed number of times, passing it the index of each repetition.
+///
+/// `unroll!(4, |i| body)` expands to four copies of `body`, in which `i` is `0_usize`,
+/// `1_usize`, `2_usize` and `3_usize` in turn. The copies are written out by the macro, so the
+/// loop is unrolled even in debug builds, and doesn't depend on the optimizer's heuristics, which
+/// may give up on large bodies such as SIMD kernels with several independent accumulators. The
+/// count must be an integer literal from 1 to 16.
+///
+/// The body is pasted into the surrounding code rather than being called as a closure, so it can
+/// mutate local variables directly, and `return`, `break` and `?` apply to the enclosing function
+/// or loop. The index is an ordinary `usize` variable, so it can index arrays, but it can't be
+/// used as a const generic argument.
+///
+/// # Example
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*, unroll};
+///
+/// #[inline(always)]
+/// fn sum<S: Simd>(simd: S, values: &[f32]) -> f32 {
+///     // Four accumulators hide the latency of the additions.
+///     let mut sums = [S::f32s::splat(simd, 0.0); 4];
+///     let mut chunks = values.chunks_exact(4 * S::f32s::N);
+///     for chunk in &mut chunks {
+///         unroll!(4, |i| {
+///             sums[i] += S::f32s::from_slice(simd, &chunk[i * S::f32s::N..][..S::f32s::N]);
+///         });
+///     }
+///     let total = (sums[0] + sums[1]) + (sums[2] + sums[3]);
+///     total.as_slice().iter().sum::<f32>() + chunks.remainder().iter().sum::<f32>()
+/// }
+///
+/// let values = [1.0; 100];
+/// assert_eq!(dispatch!(Level::new(), simd => sum(simd, &values)), 100.0);
+/// ```
+///
+/// Other counts fail to compile:
+///
+/// ```rust,compile_fail
+/// fearless_simd::unroll!(17, |i| {
+///     let _ = i;
+/// });
+/// ```
+#[macro_export]
+macro_rules! unroll {
+    (1, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0] $i, $body) };
+    (2, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1] $i, $body) };
+    (3, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2] $i, $body) };
+    (4, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2 3] $i, $body) };
+    (5, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2 3 4] $i, $body) };
+    (6, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2 3 4 5] $i, $body) };
+    (7, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2 3 4 5 6] $i, $body) };
+    (8, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2 3 4 5 6 7] $i, $body) };
+    (9, |$i:pat_param| $body:expr $(,)?) => { $crate::__unroll!([0 1 2 3 4 5 6 7 8] $i, $body) };
+    (10, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9] $i, $body)
+    };
+    (11, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9 10] $i, $body)
+    };
+    (12, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9 10 11] $i, $body)
+    };
+    (13, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9 10 11 12] $i, $body)
+    };
+    (14, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9 10 11 12 13] $i, $body)
+    };
+    (15, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9 10 11 12 13 14] $i, $body)
+    };
+    (16, |$i:pat_param| $body:expr $(,)?) => {
+        $crate::__unroll!([0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] $i, $body)
+    };
+    ($count:expr, |$i:pat_param| $body:expr $(,)?) => {
+        ::core::compile_error!("`unroll!` takes an integer literal count from 1 to 16")
+    };
+}
+
+/// Implementation detail of [`crate::unroll`]; this is not public API.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __unroll {
+    ([$($index:literal)+] $i:pat_param, $body:expr) => {
+        $(
+            #[allow(
+                unused_assignments,
+                reason = "The last repetition may update variables for the next one, like a loop."
+            )]
+            {
+                let $i: usize = $index;
+                $body;
+            }
+        )+
+    };
+}
+
 #[cfg(test)]
 // This expect also validates that we haven't missed any levels!
-#[expect(
-    unreachable_patterns,
-    reason = "Level is non_exhaustive, but you must be exhaustive within the same crate."
+// `Level::Custom` is normalized to its host before the match, so `dispatch!` doesn't have an arm for it.
+#[cfg_attr(
+    not(feature = "custom_level"),
+    expect(
+        unreachable_patterns,
+        reason = "Level is non_exhaustive, but you must be exhaustive within the same crate."
+    )
 )]
 mod tests {
     use crate::{Level, Simd};
@@ -417,13 +767,21 @@ mod tests {
 
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     fn expected_x86_dispatch_backend(level: Level) -> X86DispatchBackend {
-        if cfg!(not(disable_dispatch_avx512)) && level.as_avx512().is_some() {
+        if cfg!(all(feature = "avx512", not(disable_dispatch_avx512)))
+            && level.as_avx512().is_some()
+        {
             X86DispatchBackend::Avx512
-        } else if cfg!(not(disable_dispatch_avx2)) && level.as_avx2().is_some() {
+        } else if cfg!(all(feature = "avx2", not(disable_dispatch_avx2)))
+            && level.as_avx2().is_some()
+        {
             X86DispatchBackend::Avx2
-        } else if cfg!(not(disable_dispatch_sse4_2)) && level.as_sse4_2().is_some() {
+        } else if cfg!(all(feature = "sse4_2", not(disable_dispatch_sse4_2)))
+            && level.as_sse4_2().is_some()
+        {
             X86DispatchBackend::Sse4_2
-        } else if cfg!(not(disable_dispatch_sse2)) && level.as_sse2().is_some() {
+        } else if cfg!(all(feature = "sse2", not(disable_dispatch_sse2)))
+            && level.as_sse2().is_some()
+        {
             X86DispatchBackend::Sse2
         } else {
             X86DispatchBackend::Fallback
@@ -492,7 +850,11 @@ mod tests {
             disable_dispatch_sse2,
             disable_dispatch_sse4_2,
             disable_dispatch_avx2,
-            disable_dispatch_avx512
+            disable_dispatch_avx512,
+            not(feature = "sse2"),
+            not(feature = "sse4_2"),
+            not(feature = "avx2"),
+            not(feature = "avx512")
         )
     ))]
     #[test]
@@ -500,16 +862,16 @@ mod tests {
         let level = Level::new();
 
         // If dispatch disabling accidentally removed token access, these method calls stop compiling.
-        #[cfg(disable_dispatch_sse2)]
+        #[cfg(any(disable_dispatch_sse2, not(feature = "sse2")))]
         let _ = level.as_sse2().is_some();
 
-        #[cfg(disable_dispatch_sse4_2)]
+        #[cfg(any(disable_dispatch_sse4_2, not(feature = "sse4_2")))]
         let _ = level.as_sse4_2().is_some();
 
-        #[cfg(disable_dispatch_avx2)]
+        #[cfg(any(disable_dispatch_avx2, not(feature = "avx2")))]
         let _ = level.as_avx2().is_some();
 
-        #[cfg(disable_dispatch_avx512)]
+        #[cfg(any(disable_dispatch_avx512, not(feature = "avx512")))]
         let _ = level.as_avx512().is_some();
     }
 
@@ -518,13 +880,13 @@ mod tests {
     #[test]
     fn high_baseline_dispatches_lower_tokens() {
         if let Some(sse2) = Level::baseline().as_sse2() {
-            let actual = dispatch!(sse2.level(), simd => x86_dispatch_backend(simd));
+            let actual = dispatch!(Level::Sse2(sse2), simd => x86_dispatch_backend(simd));
 
             assert_eq!(actual, expected_x86_dispatch_backend(Level::baseline()));
         }
 
         if let Some(sse4_2) = Level::baseline().as_sse4_2() {
-            let actual = dispatch!(sse4_2.level(), simd => x86_dispatch_backend(simd));
+            let actual = dispatch!(Level::Sse4_2(sse4_2), simd => x86_dispatch_backend(simd));
 
             assert_eq!(actual, expected_x86_dispatch_backend(Level::baseline()));
         }
@@ -543,6 +905,32 @@ mod tests {
         assert_eq!(actual, expected_x86_dispatch_backend(Level::baseline()));
     }
 
+    #[test]
+    fn unroll_visits_indices_in_order() {
+        let mut visited = [usize::MAX; 16];
+        let mut count = 0;
+        unroll!(16, |i| {
+            visited[count] = i;
+            count += 1;
+        });
+        assert_eq!(visited, core::array::from_fn(|i| i));
+
+        fn first_even(values: [u32; 3]) -> Option<usize> {
+            unroll!(3, |i| {
+                if values[i].is_multiple_of(2) {
+                    return Some(i);
+                }
+            });
+            None
+        }
+        assert_eq!(
+            first_even([1, 4, 6]),
+            Some(1),
+            "`return` should leave the function"
+        );
+        assert_eq!(first_even([1, 3, 5]), None);
+    }
+
     mod no_import_simd {
         /// We should be able to use [`dispatch`] in a scope which doesn't import anything.
         #[test]
@@ -0,0 +1,287 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Transcendental functions built from the portable vector operations, and the polynomial evaluation they share.
+
+use crate::{Bytes, Select, Simd, SimdCvtFloat, SimdCvtTruncate, SimdFloat, SimdInt, SimdInto};
+
+// The high, middle and low parts of π/2, for extended precision range reduction.
+// The high parts have few enough significant bits that multiplying them by a quadrant index
+// of up to 2^16 is exact.
+const PIO2_1: f32 = 1.570_312_5;
+const PIO2_2: f32 = 4.837_513e-4;
+const PIO2_3: f32 = 7.549_79e-8;
+
+// Minimax coefficients of `sin(r) / r - 1` and `cos(r) - 1 + r² / 2` in `r²`, for `|r| <= π/4`.
+// These are the single-precision polynomials from Cephes.
+const SIN_COEFFS: [f32; 3] = [-1.666_665_5e-1, 8.332_161e-3, -1.951_529_6e-4];
+const COS_COEFFS: [f32; 3] = [4.166_664_6e-2, -1.388_731_6e-3, 2.443_315_7e-5];
+
+// Minimax coefficients of `(ln(1 + f) - f + f² / 2) / f³` in `f`, for `√½ - 1 <= f <= √2 - 1`.
+// This is the single-precision polynomial from Cephes.
+const LOG_COEFFS: [f32; 9] = [
+    3.333_333e-1,
+    -2.499_999_4e-1,
+    2.000_071_5e-1,
+    -1.666_805_8e-1,
+    1.424_932_3e-1,
+    -1.242_014_1e-1,
+    1.167_699_9e-1,
+    -1.151_461e-1,
+    7.037_683_6e-2,
+];
+
+// Minimax coefficients of `(2^f - 1) / f` in `f`, for `|f| <= 1/2`, from Cephes.
+const EXP2_COEFFS: [f32; 6] = [
+    6.931_472e-1,
+    2.402_264_8e-1,
+    5.550_332_5e-2,
+    9.618_437e-3,
+    1.339_887_4e-3,
+    1.535_336_2e-4,
+];
+
+/// Evaluate the polynomial with the coefficients `coeffs`, in ascending order of degree, at each element of `x`.
+///
+/// That is, this computes `coeffs[0] + coeffs[1] * x + coeffs[2] * x² + ...`. It uses Estrin's scheme, which
+/// evaluates pairs of terms independently and then combines them with `x²`, `x⁴` and so on, rather than Horner's
+/// scheme, whose multiply-adds each depend on the previous one. This halves the length of the chain of dependent
+/// operations, so the processor can overlap more of them. The number of coefficients is a constant, so the
+/// evaluation is fully unrolled. An empty polynomial is zero.
+///
+/// Each step is a [`mul_add`](SimdFloat::mul_add), so the results can differ slightly between levels, depending on
+/// whether [`Simd::FUSED_MUL_ADD`] is set.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, f32x4, poly_eval, prelude::*};
+///
+/// let simd = Fallback::new();
+/// let x = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, -3.0]);
+/// // 1 + 2x + 3x² + 4x³
+/// let y = poly_eval(x, [1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(*y, [1.0, 10.0, 49.0, -86.0]);
+/// ```
+#[inline(always)]
+pub fn poly_eval<S: Simd, V: SimdFloat<S>, const N: usize>(x: V, coeffs: [V::Element; N]) -> V {
+    estrin(x, coeffs, |a, b, c| a.mul_add(b, c))
+}
+
+/// Estrin's scheme for [`poly_eval`], with `mul_add` computing `a * b + c`.
+#[inline(always)]
+fn estrin<S: Simd, V: SimdFloat<S>, const N: usize>(
+    x: V,
+    coeffs: [V::Element; N],
+    mul_add: impl Fn(V, V, V) -> V,
+) -> V {
+    let simd = x.witness();
+    let mut terms: [V; N] = coeffs.map(|c| V::splat(simd, c));
+    let mut len = N;
+    let mut power = x;
+    // Each pass combines pairs of adjacent terms, halving their number, and squares the power which the higher
+    // term of each pair is multiplied by.
+    while len > 1 {
+        for i in 0..len / 2 {
+            terms[i] = mul_add(terms[2 * i + 1], power, terms[2 * i]);
+        }
+        if len % 2 == 1 {
+            terms[len / 2] = terms[len - 1];
+        }
+        len = len.div_ceil(2);
+        power = power * power;
+    }
+    terms
+        .first()
+        .copied()
+        .unwrap_or(V::splat(simd, V::ADD_IDENTITY))
+}
+
+/// Trigonometric functions on vectors of `f32`.
+///
+/// This is implemented for every `f32` vector type, including [`Simd::f32s`]. The results are
+/// the same on every level, and are accurate to within a few ULP for inputs with a magnitude of
+/// up to about 10<sup>4</sup>. Beyond that, the accuracy gradually decreases. Infinities and NaN
+/// produce NaN.
+pub trait SimdTrig<S: Simd>: SimdFloat<S> {
+    /// Compute the sine of each element, in radians.
+    fn sin(self) -> Self;
+
+    /// Compute the cosine of each element, in radians.
+    fn cos(self) -> Self;
+
+    /// Compute both the sine and the cosine of each element, in radians.
+    ///
+    /// This shares the range reduction between both results, so is cheaper than calling
+    /// [`sin`](SimdTrig::sin) and [`cos`](SimdTrig::cos) separately.
+    ///
+    /// Returns a tuple of `(sin, cos)`.
+    ///
+    /// ```rust
+    /// use fearless_simd::{Fallback, prelude::*, f32x4};
+    ///
+    /// let simd = Fallback::new();
+    /// let angles = f32x4::from_slice(simd, &[0.0, core::f32::consts::FRAC_PI_2, 1.0, -2.0]);
+    /// let (sin, cos) = angles.sincos();
+    /// for i in 0..4 {
+    ///     assert!((sin[i] - angles[i].sin()).abs() < 1e-6);
+    ///     assert!((cos[i] - angles[i].cos()).abs() < 1e-6);
+    /// }
+    /// ```
+    fn sincos(self) -> (Self, Self);
+}
+
+impl<S: Simd, V> SimdTrig<S> for V
+where
+    V: SimdFloat<S, Element = f32>,
+    V::Mask: Select<V>,
+{
+    #[inline(always)]
+    fn sin(self) -> Self {
+        self.sincos().0
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Self {
+        self.sincos().1
+    }
+
+    #[inline(always)]
+    fn sincos(self) -> (Self, Self) {
+        let simd = self.witness();
+
+        // Reduce to `r` in [-π/4, π/4], with `self = q * π/2 + r`.
+        let q = (self * core::f32::consts::FRAC_2_PI).round_ties_even();
+        let r = self - q * PIO2_1 - q * PIO2_2 - q * PIO2_3;
+        let z = r * r;
+
+        // The polynomials aren't fused, so that the results are the same on every level.
+        let unfused = |a: V, b: V, c: V| a * b + c;
+        let sin_r = r + r * z * estrin(z, SIN_COEFFS, unfused);
+        let cos_r = V::splat(simd, 1.0) - z * 0.5 + z * z * estrin(z, COS_COEFFS, unfused);
+
+        // The quadrant `q mod 4` determines which polynomial each result comes from, and its sign:
+        //
+        // | quadrant | sin    | cos    |
+        // |----------|--------|--------|
+        // | 0        | sin_r  | cos_r  |
+        // | 1        | cos_r  | -sin_r |
+        // | 2        | -sin_r | -cos_r |
+        // | 3        | -cos_r | sin_r  |
+        let quadrant = q - (q * 0.25).floor() * 4.0;
+        let is_one = quadrant.simd_eq(1.0);
+        let is_two = quadrant.simd_eq(2.0);
+        let swap = is_one | quadrant.simd_eq(3.0);
+        let sin_negative = quadrant.simd_ge(2.0);
+        let cos_negative = is_one | is_two;
+
+        let sin = swap.select(cos_r, sin_r);
+        let cos = swap.select(sin_r, cos_r);
+        (
+            sin_negative.select(-sin, sin),
+            cos_negative.select(-cos, cos),
+        )
+    }
+}
+
+/// Fast approximations of powers and logarithms on vectors of `f32`.
+///
+/// This is implemented for every `f32` vector type, including [`Simd::f32s`]. These are for
+/// uses such as gamma correction and loudness curves, which need a power of each lane but not a
+/// correctly rounded one. They're built from polynomials evaluated with
+/// [`mul_add`](SimdFloat::mul_add), so the results can differ slightly between levels.
+///
+/// [`exp2_fast`](SimdPow::exp2_fast) and [`log2_fast`](SimdPow::log2_fast) are accurate to within
+/// about 2 ULP. [`pow_fast`](SimdPow::pow_fast) computes `exp2(y * log2(x))`, so its relative
+/// error grows with the magnitude of `y * log2(x)`: it's below 2<sup>-20</sup> while that is
+/// below 16, and below 2<sup>-17</sup> up to the limit of the range of `f32` at 128. Results
+/// below [`f32::MIN_POSITIVE`] may be flushed to zero.
+pub trait SimdPow<S: Simd>: SimdFloat<S> {
+    /// Compute `2` raised to the power of each element.
+    ///
+    /// Results greater than about 2<sup>127.5</sup> overflow to infinity.
+    fn exp2_fast(self) -> Self;
+
+    /// Compute the base 2 logarithm of each element.
+    ///
+    /// Zero produces negative infinity, and negative elements produce NaN.
+    fn log2_fast(self) -> Self;
+
+    /// Raise each element of `self` to the power of the corresponding element of `y`.
+    ///
+    /// This is for positive `self`, of which it computes `exp2(y * log2(self))`. Zero produces
+    /// zero for positive `y` and infinity for negative `y`, and negative elements produce NaN,
+    /// even for integer `y`. Like [`f32::powf`], a `y` of zero produces one for any `self`.
+    ///
+    /// ```rust
+    /// use fearless_simd::{Fallback, prelude::*, f32x4};
+    ///
+    /// let simd = Fallback::new();
+    /// // Gamma correction of linear values.
+    /// let linear = f32x4::from_slice(simd, &[0.0, 0.2, 0.5, 1.0]);
+    /// let encoded = linear.pow_fast(1.0 / 2.2);
+    /// for i in 0..4 {
+    ///     let expected = linear[i].powf(1.0 / 2.2);
+    ///     assert!((encoded[i] - expected).abs() <= 1e-6 * expected);
+    /// }
+    /// ```
+    fn pow_fast(self, y: impl SimdInto<Self, S>) -> Self;
+}
+
+impl<S: Simd, V> SimdPow<S> for V
+where
+    V: SimdFloat<S, Element = f32> + SimdCvtFloat<V::Int>,
+    V::Mask: Select<V>,
+    V::Int: Bytes<Bytes = V::Bytes> + SimdCvtTruncate<V>,
+{
+    #[inline(always)]
+    fn exp2_fast(self) -> Self {
+        // Split into `n + f`, with `n` an integer and `|f| <= 1/2`. An `n` of -127 has a zero
+        // exponent field, and one of 128 has the exponent field of infinity.
+        let x = self.max(-127.0).min(128.0);
+        let n = x.round_ties_even();
+        let f = x - n;
+        let p = f.mul_add(poly_eval(f, EXP2_COEFFS), 1.0);
+        let scale: V = ((n.to_int::<V::Int>() + 127) << 23).bitcast();
+
+        // `max` and `min` don't keep NaN on every level.
+        self.simd_eq(self).select(p * scale, self)
+    }
+
+    #[inline(always)]
+    fn log2_fast(self) -> Self {
+        let simd = self.witness();
+
+        // Scale subnormals into the normal range, so their exponent can be read.
+        let subnormal = self.simd_lt(f32::MIN_POSITIVE);
+        // 2^23
+        let x = subnormal.select(self * 8_388_608.0, self);
+        let bits = x.bitcast::<V::Int>();
+        let exponent = ((bits >> 23) - 127).to_float::<V>();
+        let exponent = subnormal.select(exponent - 23.0, exponent);
+
+        // The mantissa in [1, 2), moved to [√½, √2) so that the polynomial is accurate on both sides
+        // of 1.
+        let m: V = ((bits & 0x007f_ffff) | 0x3f80_0000).bitcast();
+        let high = m.simd_gt(core::f32::consts::SQRT_2);
+        let m = high.select(m * 0.5, m);
+        let exponent = high.select(exponent + 1.0, exponent);
+
+        let f = m - 1.0;
+        let z = f * f;
+        let ln = (f * z).mul_add(poly_eval(f, LOG_COEFFS), z.mul_add(-0.5, f));
+        let log2 = ln.mul_add(core::f32::consts::LOG2_E, exponent);
+
+        let log2 = self.simd_eq(f32::INFINITY).select(self, log2);
+        let log2 = self
+            .simd_eq(0.0)
+            .select(V::splat(simd, f32::NEG_INFINITY), log2);
+        // This is false for NaN, as well as negative elements.
+        self.simd_ge(0.0).select(log2, V::splat(simd, f32::NAN))
+    }
+
+    #[inline(always)]
+    fn pow_fast(self, y: impl SimdInto<Self, S>) -> Self {
+        let y = y.simd_into(self.witness());
+        let pow = (y * self.log2_fast()).exp2_fast();
+        y.simd_eq(0.0).select(V::splat(self.witness(), 1.0), pow)
+    }
+}
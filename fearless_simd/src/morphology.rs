@@ -0,0 +1,166 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Erosion and dilation of 8-bit images with 3-pixel structuring elements, the building blocks of morphology.
+//!
+//! Erosion replaces each pixel with the minimum of its neighborhood, which shrinks bright regions and removes
+//! specks of noise. Dilation takes the maximum instead, which grows bright regions and fills small holes. Opening
+//! (an erosion followed by a dilation) and closing (the reverse) clean up the binarized images of OCR and other
+//! vision pipelines.
+//!
+//! A 3x3 square is separable into a horizontal 3x1 pass over each row and a vertical 1x3 pass over each group of
+//! three rows, so these functions only handle one row at a time, and the caller decides how to store the image and
+//! which of its rows to combine. At the left and right edges of a row, the pixels outside the image are ignored,
+//! which is the same as repeating the edge pixel. At the top and bottom, pass the edge row in place of the row
+//! outside the image.
+//!
+//! ```rust
+//! use fearless_simd::{Level, dispatch, morphology};
+//!
+//! // Erode a 3x3 square with the bright pixel in the middle of a 5x5 image, which leaves only the middle pixel.
+//! let image = [
+//!     [0, 0, 0, 0, 0],
+//!     [0, 9, 9, 9, 0],
+//!     [0, 9, 9, 9, 0],
+//!     [0, 9, 9, 9, 0],
+//!     [0, 0, 0, 0, 0],
+//! ];
+//! let mut rows = [[0; 5]; 5];
+//! let mut eroded = [[0; 5]; 5];
+//! dispatch!(Level::new(), simd => {
+//!     for (row, out) in image.iter().zip(&mut rows) {
+//!         morphology::erode_3x1(simd, row, out);
+//!     }
+//!     for (y, out) in eroded.iter_mut().enumerate() {
+//!         let above = &rows[y.saturating_sub(1)];
+//!         let below = &rows[(y + 1).min(4)];
+//!         morphology::erode_1x3(simd, [above, &rows[y], below], out);
+//!     }
+//! });
+//! assert_eq!(eroded[2], [0, 0, 9, 0, 0]);
+//! assert_eq!(eroded.iter().flatten().filter(|&&pixel| pixel != 0).count(), 1);
+//! ```
+
+use crate::slice::load_tail;
+use crate::{Simd, SimdBase, SimdInt};
+
+/// Set each pixel of `out` to the minimum of the pixel of `row` at the same position and its left and right
+/// neighbors.
+///
+/// # Panics
+///
+/// Panics if `row` and `out` have different lengths.
+#[inline(always)]
+pub fn erode_3x1<S: Simd>(simd: S, row: &[u8], out: &mut [u8]) {
+    horizontal_3(simd, row, out, S::u8s::min);
+}
+
+/// Set each pixel of `out` to the maximum of the pixel of `row` at the same position and its left and right
+/// neighbors.
+///
+/// # Panics
+///
+/// Panics if `row` and `out` have different lengths.
+#[inline(always)]
+pub fn dilate_3x1<S: Simd>(simd: S, row: &[u8], out: &mut [u8]) {
+    horizontal_3(simd, row, out, S::u8s::max);
+}
+
+/// Set each pixel of `out` to the minimum of the pixels at the same position in the three `rows`.
+///
+/// The rows are usually the row above, the row itself and the row below.
+///
+/// # Panics
+///
+/// Panics if the rows and `out` don't all have the same length.
+#[inline(always)]
+pub fn erode_1x3<S: Simd>(simd: S, rows: [&[u8]; 3], out: &mut [u8]) {
+    vertical_3(simd, rows, out, S::u8s::min);
+}
+
+/// Set each pixel of `out` to the maximum of the pixels at the same position in the three `rows`.
+///
+/// The rows are usually the row above, the row itself and the row below.
+///
+/// # Panics
+///
+/// Panics if the rows and `out` don't all have the same length.
+#[inline(always)]
+pub fn dilate_1x3<S: Simd>(simd: S, rows: [&[u8]; 3], out: &mut [u8]) {
+    vertical_3(simd, rows, out, S::u8s::max);
+}
+
+/// Combine each pixel with its left and right neighbors using `op`.
+///
+/// The neighbors come from the vectors on either side with [`slide`](SimdBase::slide) and
+/// [`shift_elements_right`](SimdBase::shift_elements_right), so each pixel is only loaded once.
+#[inline(always)]
+fn horizontal_3<S: Simd>(
+    simd: S,
+    row: &[u8],
+    out: &mut [u8],
+    op: impl Fn(S::u8s, S::u8s) -> S::u8s,
+) {
+    assert_eq!(
+        row.len(),
+        out.len(),
+        "`row` and `out` should have the same length"
+    );
+    let (Some(&first), Some(&last)) = (row.first(), row.last()) else {
+        return;
+    };
+    let lanes = S::u8s::N;
+    // Past the end, the last pixel is repeated, so that it is its own right neighbor.
+    let load = |offset: usize| -> S::u8s {
+        match row.get(offset..offset + lanes) {
+            Some(pixels) => S::u8s::from_slice(simd, pixels),
+            None => load_tail(simd, row.get(offset..).unwrap_or_default(), last),
+        }
+    };
+    let mut before = first;
+    let mut current = load(0);
+    for (i, out) in out.chunks_mut(lanes).enumerate() {
+        let next = load((i + 1) * lanes);
+        let left = current.shift_elements_right::<1>(before);
+        let right = current.slide::<1>(next);
+        let result = op(op(left, current), right);
+        if out.len() == lanes {
+            result.store_slice(out);
+        } else {
+            out.copy_from_slice(&result.as_slice()[..out.len()]);
+        }
+        before = current[lanes - 1];
+        current = next;
+    }
+}
+
+/// Combine the pixels at the same position in three rows using `op`.
+#[inline(always)]
+fn vertical_3<S: Simd>(
+    simd: S,
+    rows: [&[u8]; 3],
+    out: &mut [u8],
+    op: impl Fn(S::u8s, S::u8s) -> S::u8s,
+) {
+    assert!(
+        rows.iter().all(|row| row.len() == out.len()),
+        "the rows and `out` should have the same length"
+    );
+    let lanes = S::u8s::N;
+    for (offset, out) in (0..).step_by(lanes).zip(out.chunks_mut(lanes)) {
+        let [a, b, c] = rows.map(|row| -> S::u8s {
+            let pixels = &row[offset..offset + out.len()];
+            if pixels.len() == lanes {
+                S::u8s::from_slice(simd, pixels)
+            } else {
+                load_tail(simd, pixels, 0)
+            }
+        });
+        let result = op(op(a, b), c);
+        if out.len() == lanes {
+            result.store_slice(out);
+        } else {
+            out.copy_from_slice(&result.as_slice()[..out.len()]);
+        }
+    }
+}
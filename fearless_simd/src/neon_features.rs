@@ -0,0 +1,174 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Queries for the optional Arm extensions which build on the Neon level.
+//!
+//! These don't have levels of their own, as they only add a few instructions, and most code doesn't need them.
+//! Instead, kernels which can use them check the [`Neon`] token, and call a function with the extension enabled
+//! using `#[target_feature]`.
+
+use crate::Neon;
+
+/// The optional extensions which were detected at runtime.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+struct Extensions {
+    fp16: bool,
+    bf16: bool,
+    i8mm: bool,
+}
+
+#[cfg(feature = "std")]
+impl Extensions {
+    fn get() -> Self {
+        static DETECTED: std::sync::OnceLock<Extensions> = std::sync::OnceLock::new();
+        *DETECTED.get_or_init(Self::detect)
+    }
+
+    // Apple's processors report their extensions through `sysctl`, under their `FEAT_*` names from the Arm
+    // architecture manual. macOS 11 only had the older `neon_fp16` name, which is also checked.
+    #[cfg(target_vendor = "apple")]
+    fn detect() -> Self {
+        Self {
+            fp16: sysctl_flag(c"hw.optional.arm.FEAT_FP16")
+                || sysctl_flag(c"hw.optional.neon_fp16"),
+            bf16: sysctl_flag(c"hw.optional.arm.FEAT_BF16"),
+            i8mm: sysctl_flag(c"hw.optional.arm.FEAT_I8MM"),
+        }
+    }
+
+    #[cfg(not(target_vendor = "apple"))]
+    fn detect() -> Self {
+        Self {
+            fp16: std::arch::is_aarch64_feature_detected!("fp16"),
+            bf16: std::arch::is_aarch64_feature_detected!("bf16"),
+            i8mm: std::arch::is_aarch64_feature_detected!("i8mm"),
+        }
+    }
+}
+
+/// Whether the integer `sysctl` with the given name exists and is non-zero.
+#[cfg(all(feature = "std", target_vendor = "apple"))]
+fn sysctl_flag(name: &core::ffi::CStr) -> bool {
+    use core::ffi::{c_char, c_int, c_void};
+
+    unsafe extern "C" {
+        fn sysctlbyname(
+            name: *const c_char,
+            oldp: *mut c_void,
+            oldlenp: *mut usize,
+            newp: *mut c_void,
+            newlen: usize,
+        ) -> c_int;
+    }
+
+    let mut value: c_int = 0;
+    let mut len = size_of::<c_int>();
+    // Safety: `name` is nul-terminated, and `value` is writable for `len` bytes. Nothing is written to the `sysctl`.
+    let result = unsafe {
+        sysctlbyname(
+            name.as_ptr(),
+            (&raw mut value).cast(),
+            &raw mut len,
+            core::ptr::null_mut(),
+            0,
+        )
+    };
+    result == 0 && len == size_of::<c_int>() && value != 0
+}
+
+impl Neon {
+    /// Whether the half-precision floating point extension (`FEAT_FP16`) is available.
+    ///
+    /// This adds arithmetic on `f16` lanes, such as `vaddq_f16` and `vfmaq_f16`. It is on every Apple Silicon
+    /// processor, and on most other Armv8.2 and later cores.
+    ///
+    /// This is true if the `fp16` target feature is enabled at compile time. Otherwise, it is detected at runtime,
+    /// which needs the `std` feature; without it, this is false.
+    ///
+    /// ```rust
+    /// # #[cfg(target_arch = "aarch64")] {
+    /// use fearless_simd::Level;
+    ///
+    /// if let Some(neon) = Level::new().as_neon() {
+    ///     println!("fp16: {}", neon.has_fp16());
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn has_fp16(self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            cfg!(target_feature = "fp16") || Extensions::get().fp16
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "fp16")
+        }
+    }
+
+    /// Whether the `bfloat16` extension (`FEAT_BF16`) is available.
+    ///
+    /// This adds conversions to `bf16`, and dot products and multiply-accumulates of `bf16` lanes into `f32` lanes,
+    /// such as `vbfdotq_f32`. It is on Apple M2 and later processors.
+    ///
+    /// This is true if the `bf16` target feature is enabled at compile time. Otherwise, it is detected at runtime,
+    /// which needs the `std` feature; without it, this is false.
+    #[inline]
+    pub fn has_bf16(self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            cfg!(target_feature = "bf16") || Extensions::get().bf16
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "bf16")
+        }
+    }
+
+    /// Whether the 8-bit integer matrix multiplication extension (`FEAT_I8MM`) is available.
+    ///
+    /// This adds multiplications of 2x8 by 8x2 matrices of `i8` or `u8` elements into `i32` elements, such as
+    /// `vmmlaq_s32`, and mixed-sign dot products. It is on Apple M2 and later processors.
+    ///
+    /// This is true if the `i8mm` target feature is enabled at compile time. Otherwise, it is detected at runtime,
+    /// which needs the `std` feature; without it, this is false.
+    #[inline]
+    pub fn has_i8mm(self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            cfg!(target_feature = "i8mm") || Extensions::get().i8mm
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "i8mm")
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::Level;
+
+    #[test]
+    fn extensions_match_std_detection() {
+        let Some(neon) = Level::new().as_neon() else {
+            return;
+        };
+        assert_eq!(
+            neon.has_fp16(),
+            std::arch::is_aarch64_feature_detected!("fp16"),
+            "fp16 detection should agree with the standard library"
+        );
+        assert_eq!(
+            neon.has_bf16(),
+            std::arch::is_aarch64_feature_detected!("bf16"),
+            "bf16 detection should agree with the standard library"
+        );
+        assert_eq!(
+            neon.has_i8mm(),
+            std::arch::is_aarch64_feature_detected!("i8mm"),
+            "i8mm detection should agree with the standard library"
+        );
+    }
+}
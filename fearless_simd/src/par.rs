@@ -0,0 +1,181 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Processing a slice on several threads with [rayon], with SIMD on each of them.
+//!
+//! Combining threads and SIMD has a few pitfalls. Each thread runs its part of the work in a separate function
+//! from the one which [`dispatch`](crate::dispatch) enables target features for, so the kernel needs to be called
+//! through [`vectorize`](crate::Simd::vectorize) on the thread to be vectorized. The level should be detected once,
+//! rather than by each thread or for each part. And the parts should be large enough to amortize handing them out
+//! to threads, but small enough to stay in the cache of one core.
+//!
+//! [`par_simd_map`](crate::par_simd_map) handles these: it splits the slice into chunks of [`CHUNK_BYTES`] (or of
+//! a given length), dispatches on the level once, and calls the kernel on each chunk inside `vectorize`, on the
+//! threads of rayon's global thread pool. It returns the result for each chunk, in order.
+//!
+//! This is only available with the `rayon` feature.
+//!
+//! [rayon]: https://docs.rs/rayon
+
+use core::mem::size_of;
+use std::vec::Vec;
+
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{Chunks, ChunksMut, ParallelSlice, ParallelSliceMut};
+
+/// The size in bytes of the chunks which [`par_simd_map`](crate::par_simd_map) splits a slice into by default.
+///
+/// This is small enough for a chunk to stay in the L1 or L2 cache of a core, and large enough that handing it to a
+/// thread is cheap in comparison to processing it. As it's a power of two, chunks of elements whose size is a
+/// power of two hold a whole number of vectors, so only the last chunk has a tail.
+pub const CHUNK_BYTES: usize = 16 * 1024;
+
+/// The number of elements of `T` in a chunk of [`CHUNK_BYTES`], which is at least one.
+#[inline]
+pub const fn default_chunk_len<T>() -> usize {
+    if size_of::<T>() == 0 || size_of::<T>() >= CHUNK_BYTES {
+        1
+    } else {
+        CHUNK_BYTES / size_of::<T>()
+    }
+}
+
+/// Implementation detail of [`par_simd_map`](crate::par_simd_map); this is not public API.
+///
+/// Splits a shared or mutable slice into parallel chunks.
+#[doc(hidden)]
+pub trait ParChunks: Sized {
+    /// The parallel iterator over the chunks.
+    type Iter: IndexedParallelIterator;
+
+    /// The length of the chunks of [`CHUNK_BYTES`].
+    fn __default_chunk_len(&self) -> usize;
+
+    /// Split the slice into chunks of `len` elements, the last of which may be shorter.
+    fn __par_chunks(self, len: usize) -> Self::Iter;
+}
+
+impl<'a, T: Sync + 'a> ParChunks for &'a [T] {
+    type Iter = Chunks<'a, T>;
+
+    #[inline]
+    fn __default_chunk_len(&self) -> usize {
+        default_chunk_len::<T>()
+    }
+
+    #[inline]
+    fn __par_chunks(self, len: usize) -> Self::Iter {
+        self.par_chunks(len)
+    }
+}
+
+impl<'a, T: Send + 'a> ParChunks for &'a mut [T] {
+    type Iter = ChunksMut<'a, T>;
+
+    #[inline]
+    fn __default_chunk_len(&self) -> usize {
+        default_chunk_len::<T>()
+    }
+
+    #[inline]
+    fn __par_chunks(self, len: usize) -> Self::Iter {
+        self.par_chunks_mut(len)
+    }
+}
+
+/// Implementation detail of [`par_simd_map`](crate::par_simd_map); this is not public API.
+///
+/// Calls `f` with each chunk, in parallel, and collects the results in order.
+#[doc(hidden)]
+#[inline(always)]
+pub fn __map_chunks<I: IndexedParallelIterator, R: Send>(
+    chunks: I,
+    f: impl Fn(I::Item) -> R + Sync + Send,
+) -> Vec<R> {
+    chunks.map(f).collect()
+}
+
+/// Processes the chunks of a slice in parallel with [rayon], dispatching on a [`Level`](crate::Level) once and
+/// running the kernel with the level's target features on every thread.
+///
+/// The slice can be shared (`&[T]`) or mutable (`&mut [T]`). It's split into chunks of
+/// [`CHUNK_BYTES`](crate::par::CHUNK_BYTES) bytes, or of `chunk_len` elements if that's given, of which only the
+/// last may be shorter. The kernel is written like a closure taking the [`Simd`](crate::Simd) value and a chunk, as
+/// in [`dispatch`](crate::dispatch), and is called inside [`vectorize`](crate::Simd::vectorize) on the thread which
+/// processes the chunk. The result is a `Vec` of the kernel's result for each chunk, in the order of the chunks.
+///
+/// The kernel should call `#[inline(always)]` functions, as with [`dispatch`](crate::dispatch). The chunks are
+/// processed by rayon's global thread pool, or by the pool which this is called from.
+///
+/// This is only available with the `rayon` feature. See the [`par`](crate::par) module for more details.
+///
+/// # Panics
+///
+/// Panics if `chunk_len` is zero, or if the kernel panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, par_simd_map, prelude::*};
+///
+/// #[inline(always)]
+/// fn sum<S: Simd>(simd: S, values: &[f32]) -> f32 {
+///     let mut chunks = values.chunks_exact(S::f32s::N);
+///     let mut sums = S::f32s::splat(simd, 0.0);
+///     for chunk in &mut chunks {
+///         sums += S::f32s::from_slice(simd, chunk);
+///     }
+///     sums.as_slice().iter().chain(chunks.remainder()).sum()
+/// }
+///
+/// #[inline(always)]
+/// fn square<S: Simd>(simd: S, values: &mut [f32]) {
+///     let mut chunks = values.chunks_exact_mut(S::f32s::N);
+///     for chunk in &mut chunks {
+///         let v = S::f32s::from_slice(simd, chunk);
+///         (v * v).store_slice(chunk);
+///     }
+///     for value in chunks.into_remainder() {
+///         *value *= *value;
+///     }
+/// }
+///
+/// let level = Level::new(); // Detect SIMD available on the CPU. Expensive, so do it once.
+/// let mut values = vec![2.0_f32; 100_000];
+///
+/// par_simd_map!(level, &mut values[..], |simd, chunk| square(simd, chunk));
+/// let sums = par_simd_map!(level, &values[..], chunk_len = 4096, |simd, chunk| sum(simd, chunk));
+/// assert_eq!(sums.len(), 25);
+/// assert_eq!(sums.iter().sum::<f32>(), 400_000.0);
+/// ```
+#[macro_export]
+macro_rules! par_simd_map {
+    ($level:expr, $values:expr, |$simd:pat_param, $chunk:pat_param| $kernel:expr) => {{
+        let __fearless_simd_values = $values;
+        let __fearless_simd_chunk_len =
+            $crate::par::ParChunks::__default_chunk_len(&__fearless_simd_values);
+        $crate::par_simd_map!(@run $level, __fearless_simd_values, __fearless_simd_chunk_len, $simd, $chunk, $kernel)
+    }};
+    ($level:expr, $values:expr, chunk_len = $chunk_len:expr, |$simd:pat_param, $chunk:pat_param| $kernel:expr) => {{
+        let __fearless_simd_values = $values;
+        let __fearless_simd_chunk_len: usize = $chunk_len;
+        $crate::par_simd_map!(@run $level, __fearless_simd_values, __fearless_simd_chunk_len, $simd, $chunk, $kernel)
+    }};
+    (@run $level:expr, $values:ident, $chunk_len:ident, $simd:pat, $chunk:pat, $kernel:expr) => {
+        $crate::dispatch!($level, __fearless_simd_token => {
+            let __fearless_simd_chunks = $crate::par::ParChunks::__par_chunks($values, $chunk_len);
+            $crate::par::__map_chunks(__fearless_simd_chunks, |$chunk| {
+                // This runs on another thread, outside of the function which `dispatch` enables target features
+                // for, so they're enabled again here.
+                $crate::Simd::vectorize(
+                    __fearless_simd_token,
+                    #[inline(always)]
+                    || {
+                        let $simd = __fearless_simd_token;
+                        $kernel
+                    },
+                )
+            })
+        })
+    };
+}
@@ -0,0 +1,264 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Vectors whose lanes past a count don't hold elements, for handling the tail of a slice.
+
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Sub};
+
+use crate::{Select, Simd, SimdBase, SimdMask};
+
+/// A vector of which only the first [`len`](Self::len) lanes hold elements, such as the last vector of a slice
+/// whose length isn't a multiple of the vector's.
+///
+/// The tail of a slice is usually handled by convention: it's loaded into a padded vector, processed like the
+/// others, and only the lanes which hold elements are stored. Nothing checks that the padding isn't stored, or
+/// that a reduction masks it out. A `PartialVec` carries the number of valid lanes with the vector, so that the
+/// operators keep track of it, and [`commit`](Self::commit) stores exactly those lanes.
+///
+/// The padding lanes are zero when loaded with [`load`](Self::load), but operations can change them, so they should
+/// be treated as unspecified, like uninitialized memory. [`assume_init`](Self::assume_init) returns the vector with
+/// them, for operations which ignore them or which the padding doesn't affect, and
+/// [`fill_padding`](Self::fill_padding) replaces them with a value which doesn't change the result, such as the
+/// identity of a reduction.
+///
+/// The operators keep the lanes which are valid in both operands, so the result of an operator on two partial
+/// vectors has as many valid lanes as the shorter of them. The right operand can also be a plain vector, such as a
+/// constant from [`splat`](SimdBase::splat), which is valid in every lane.
+///
+/// ```rust
+/// use fearless_simd::{Level, PartialVec, Simd, dispatch, prelude::*};
+///
+/// /// Computes `x * scale + offset` for each element.
+/// #[inline(always)]
+/// fn scale<S: Simd>(simd: S, values: &[f32], scale: f32, offset: f32, out: &mut [f32]) {
+///     let scale = S::f32s::splat(simd, scale);
+///     let offset = S::f32s::splat(simd, offset);
+///     for (chunk, out) in PartialVec::chunks(simd, values).zip(out.chunks_mut(S::f32s::N)) {
+///         (chunk * scale + offset).commit(out);
+///     }
+/// }
+///
+/// let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let mut out = [0.0; 5];
+/// dispatch!(Level::new(), simd => scale(simd, &values, 2.0, 0.5, &mut out));
+/// assert_eq!(out, [2.5, 4.5, 6.5, 8.5, 10.5]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PartialVec<S: Simd, V: SimdBase<S>> {
+    vector: V,
+    len: usize,
+    simd: S,
+}
+
+impl<S: Simd, V: SimdBase<S>> PartialVec<S, V> {
+    /// Create a partial vector whose first `len` lanes are valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is more than the number of lanes of `V`.
+    #[inline(always)]
+    pub fn new(vector: V, len: usize) -> Self {
+        assert!(
+            len <= V::N,
+            "a vector of {} lanes can't have {len} valid lanes",
+            V::N
+        );
+        Self {
+            vector,
+            len,
+            simd: vector.witness(),
+        }
+    }
+
+    /// Create a partial vector whose lanes are all valid.
+    #[inline(always)]
+    pub fn full(vector: V) -> Self {
+        Self::new(vector, V::N)
+    }
+
+    /// Load the first elements of `values`, up to a whole vector, padding the lanes past the end of `values` with
+    /// zero.
+    #[inline(always)]
+    pub fn load(simd: S, values: &[V::Element]) -> Self
+    where
+        V::Element: Copy,
+    {
+        if values.len() >= V::N {
+            Self::full(V::from_slice(simd, &values[..V::N]))
+        } else {
+            Self::new(
+                crate::slice::load_tail(simd, values, V::ADD_IDENTITY),
+                values.len(),
+            )
+        }
+    }
+
+    /// Load each vector of `values`, the last of which may be partial.
+    ///
+    /// The vectors line up with the chunks of `values.chunks(V::N)`, so that they can be stored with
+    /// [`commit`](Self::commit) to the chunks of the same length of another slice.
+    #[inline(always)]
+    pub fn chunks(simd: S, values: &[V::Element]) -> impl Iterator<Item = Self> + '_
+    where
+        V::Element: Copy,
+    {
+        values
+            .chunks(V::N)
+            .map(move |chunk| Self::load(simd, chunk))
+    }
+
+    /// The number of valid lanes, which are the first lanes of the vector.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no lanes are valid.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether every lane is valid.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.len == V::N
+    }
+
+    /// A mask of which lanes are valid.
+    #[inline(always)]
+    pub fn mask(&self) -> V::Mask {
+        let bits = if self.len == 0 {
+            0
+        } else {
+            u64::MAX >> (u64::BITS as usize - self.len)
+        };
+        V::Mask::from_bitmask(self.simd, bits)
+    }
+
+    /// The elements of the valid lanes.
+    #[inline(always)]
+    pub fn valid(&self) -> &[V::Element] {
+        &self.vector.as_slice()[..self.len]
+    }
+
+    /// Return the vector, including the padding lanes, whose values are unspecified.
+    #[inline(always)]
+    pub fn assume_init(self) -> V {
+        self.vector
+    }
+
+    /// Replace the padding lanes with `value`.
+    ///
+    /// This makes the padding safe to include in operations which combine lanes, such as a sum, for which `value`
+    /// would be zero, or a minimum, for which it would be the largest value.
+    #[inline(always)]
+    pub fn fill_padding(self, value: V::Element) -> Self
+    where
+        V::Mask: Select<V>,
+    {
+        let vector = self.mask().select(self.vector, V::splat(self.simd, value));
+        Self { vector, ..self }
+    }
+
+    /// Apply `f` to the vector, keeping the same lanes valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` has fewer lanes than there are valid lanes.
+    #[inline(always)]
+    pub fn map<W: SimdBase<S>>(self, f: impl FnOnce(V) -> W) -> PartialVec<S, W> {
+        PartialVec::new(f(self.vector), self.len)
+    }
+
+    /// Apply `f` to this vector and `other`, keeping the lanes which are valid in both.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` has fewer lanes than there are valid lanes in the result.
+    #[inline(always)]
+    pub fn zip_with<W: SimdBase<S>, R: SimdBase<S>>(
+        self,
+        other: PartialVec<S, W>,
+        f: impl FnOnce(V, W) -> R,
+    ) -> PartialVec<S, R> {
+        PartialVec::new(f(self.vector, other.vector), self.len.min(other.len))
+    }
+
+    /// Store the valid lanes to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` doesn't have exactly as many elements as there are valid lanes.
+    #[inline(always)]
+    pub fn commit(self, dst: &mut [V::Element])
+    where
+        V::Element: Copy,
+    {
+        assert_eq!(
+            dst.len(),
+            self.len,
+            "the destination should have one element per valid lane"
+        );
+        if self.is_full() {
+            self.vector.store_slice(dst);
+        } else {
+            dst.copy_from_slice(self.valid());
+        }
+    }
+}
+
+impl<S: Simd, V: SimdBase<S>> From<V> for PartialVec<S, V> {
+    #[inline(always)]
+    fn from(vector: V) -> Self {
+        Self::full(vector)
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl<S: Simd, V: SimdBase<S> + $trait<Output = V>> $trait for PartialVec<S, V> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self {
+                self.zip_with(rhs, V::$method)
+            }
+        }
+
+        impl<S: Simd, V: SimdBase<S> + $trait<Output = V>> $trait<V> for PartialVec<S, V> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: V) -> Self {
+                self.map(|v| v.$method(rhs))
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add);
+impl_binary_op!(Sub, sub);
+impl_binary_op!(Mul, mul);
+impl_binary_op!(Div, div);
+impl_binary_op!(BitAnd, bitand);
+impl_binary_op!(BitOr, bitor);
+impl_binary_op!(BitXor, bitxor);
+
+impl<S: Simd, V: SimdBase<S> + Neg<Output = V>> Neg for PartialVec<S, V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        self.map(V::neg)
+    }
+}
+
+impl<S: Simd, V: SimdBase<S> + Not<Output = V>> Not for PartialVec<S, V> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        self.map(V::not)
+    }
+}
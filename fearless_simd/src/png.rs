@@ -0,0 +1,209 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reversing the row filters of PNG, which is most of the work of decoding an image after decompression.
+//!
+//! Before compression, a PNG encoder replaces each byte of a row with its difference from a prediction based on the
+//! bytes of the same channel in the pixel to the left (`a`), the pixel above (`b`) and the pixel above and to the left
+//! (`c`). [`unfilter_row`] adds the predictions back, with wrapping arithmetic, to recover the row.
+//!
+//! The rows are the bytes after the filter type byte at the start of each row. Each pixel is `bpp` bytes, rounded up
+//! to a whole byte for bit depths below 8 (so `bpp` is 1 for those), and the bytes to the left of the first pixel
+//! and above the first row are zero. Pass a row of zeros as the previous row of the first row of the image, and of
+//! the first row of each pass of an interlaced image.
+//!
+//! The filters which use `a` depend on the result for the previous pixel, so they can't simply be computed for a
+//! vector of bytes at once:
+//!
+//! - [`Up`](FilterType::Up) has no such dependency, and is computed with vectors of the native width.
+//! - [`Sub`](FilterType::Sub) is a prefix sum of each channel, computed in logarithmic steps for 16 bytes at once.
+//! - [`Average`](FilterType::Average) and [`Paeth`](FilterType::Paeth) are computed one pixel at a time, with the
+//!   channels of the pixel in the lanes of a vector, like libpng's SSE2 and Neon code.
+//!
+//! ```rust
+//! use fearless_simd::png::{FilterType, unfilter_row};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! // Two gray and alpha pixels, filtered with the differences from the pixel to their left.
+//! let previous = [0; 4];
+//! let mut row = [100, 255, 5, 0];
+//! let filter = FilterType::from_byte(1).unwrap();
+//! dispatch!(Level::new(), simd => unfilter_row(simd, filter, 2, &previous, &mut row));
+//! assert_eq!(row, [100, 255, 105, 255]);
+//! ```
+
+use crate::slice::load_tail;
+use crate::{Select, Simd, SimdBase, SimdInt, u8x16};
+
+/// The filter which was applied to a row of a PNG image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterType {
+    /// The bytes are unchanged.
+    None = 0,
+    /// Each byte is predicted by the byte to its left, `a`.
+    Sub = 1,
+    /// Each byte is predicted by the byte above it, `b`.
+    Up = 2,
+    /// Each byte is predicted by the average of `a` and `b`, rounded down.
+    Average = 3,
+    /// Each byte is predicted by whichever of `a`, `b` and `c` is closest to `a + b - c`.
+    Paeth = 4,
+}
+
+impl FilterType {
+    /// The filter type with the value `byte`, which starts each row of the image data, if it is valid.
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::None),
+            1 => Some(Self::Sub),
+            2 => Some(Self::Up),
+            3 => Some(Self::Average),
+            4 => Some(Self::Paeth),
+            _ => None,
+        }
+    }
+}
+
+/// Reverse `filter` on the row `current`, in place, given the previous row of the image after reversing its filter.
+///
+/// Each pixel is `bpp` bytes. See the [module documentation](self) for the previous row of the first row.
+///
+/// # Panics
+///
+/// Panics if `bpp` isn't from 1 to 8, if the rows have different lengths, or if they aren't a whole number of
+/// pixels.
+#[inline(always)]
+pub fn unfilter_row<S: Simd>(
+    simd: S,
+    filter: FilterType,
+    bpp: usize,
+    previous: &[u8],
+    current: &mut [u8],
+) {
+    assert!(
+        (1..=8).contains(&bpp),
+        "PNG pixels should be from 1 to 8 bytes, not {bpp}"
+    );
+    assert_eq!(
+        previous.len(),
+        current.len(),
+        "`previous` and `current` should have the same length"
+    );
+    assert!(
+        current.len().is_multiple_of(bpp),
+        "the rows should be whole pixels of {bpp} bytes, not {} bytes",
+        current.len()
+    );
+    match filter {
+        FilterType::None => {}
+        FilterType::Sub => match bpp {
+            1 => unfilter_sub::<S, 1, 2, 4, 8>(simd, current),
+            2 => unfilter_sub::<S, 2, 4, 8, 16>(simd, current),
+            3 => unfilter_sub::<S, 3, 6, 12, 24>(simd, current),
+            4 => unfilter_sub::<S, 4, 8, 16, 32>(simd, current),
+            5 => unfilter_sub::<S, 5, 10, 20, 40>(simd, current),
+            6 => unfilter_sub::<S, 6, 12, 24, 48>(simd, current),
+            7 => unfilter_sub::<S, 7, 14, 28, 56>(simd, current),
+            _ => unfilter_sub::<S, 8, 16, 32, 64>(simd, current),
+        },
+        FilterType::Up => unfilter_up(simd, previous, current),
+        FilterType::Average => unfilter_per_pixel(simd, bpp, previous, current, |a, b, _| {
+            // The average of each pair of bytes, without overflowing.
+            (a & b) + ((a ^ b) >> 1)
+        }),
+        FilterType::Paeth => unfilter_per_pixel(simd, bpp, previous, current, paeth),
+    }
+}
+
+/// Add the previous row to the current one.
+#[inline(always)]
+fn unfilter_up<S: Simd>(simd: S, previous: &[u8], current: &mut [u8]) {
+    let mut previous = previous.chunks_exact(S::u8s::N);
+    let mut current = current.chunks_exact_mut(S::u8s::N);
+    for (above, row) in (&mut previous).zip(&mut current) {
+        (S::u8s::from_slice(simd, row) + S::u8s::from_slice(simd, above)).store_slice(row);
+    }
+    for (above, byte) in previous.remainder().iter().zip(current.into_remainder()) {
+        *byte = byte.wrapping_add(*above);
+    }
+}
+
+/// Add the byte `BPP` to the left of each byte to it, for the whole row.
+///
+/// Each vector is loaded starting with the last pixel which is already unfiltered, so that it carries into the rest
+/// of the vector. The prefix sum of each channel then takes four steps, shifting by `BPP`, `BPP2 = 2 * BPP`,
+/// `BPP4 = 4 * BPP` and `BPP8 = 8 * BPP` lanes, which are separate parameters because they can't be computed from
+/// `BPP` in a const generic argument. Shifts of 16 lanes or more add zero.
+#[inline(always)]
+fn unfilter_sub<
+    S: Simd,
+    const BPP: usize,
+    const BPP2: usize,
+    const BPP4: usize,
+    const BPP8: usize,
+>(
+    simd: S,
+    row: &mut [u8],
+) {
+    // The first pixel has nothing to its left.
+    let mut offset = BPP;
+    while offset < row.len() {
+        let start = offset - BPP;
+        let v = load(simd, &row[start..]);
+        let v = v + v.shift_elements_right::<BPP>(0);
+        let v = v + v.shift_elements_right::<BPP2>(0);
+        let v = v + v.shift_elements_right::<BPP4>(0);
+        let v = v + v.shift_elements_right::<BPP8>(0);
+        let end = (start + 16).min(row.len());
+        row[offset..end].copy_from_slice(&v.as_slice()[BPP..end - start]);
+        offset = end;
+    }
+}
+
+/// Add `predict(a, b, c)` to each pixel, from left to right.
+///
+/// Only the first `bpp` lanes of the vectors are pixels; the rest are ignored.
+#[inline(always)]
+fn unfilter_per_pixel<S: Simd>(
+    simd: S,
+    bpp: usize,
+    previous: &[u8],
+    current: &mut [u8],
+    predict: impl Fn(u8x16<S>, u8x16<S>, u8x16<S>) -> u8x16<S>,
+) {
+    let mut a = u8x16::splat(simd, 0);
+    let mut c = u8x16::splat(simd, 0);
+    for offset in (0..current.len()).step_by(bpp) {
+        let b = load(simd, &previous[offset..]);
+        let x = load(simd, &current[offset..]) + predict(a, b, c);
+        current[offset..offset + bpp].copy_from_slice(&x.as_slice()[..bpp]);
+        a = x;
+        c = b;
+    }
+}
+
+/// The Paeth predictor of each lane.
+///
+/// The distances of `a`, `b` and `c` from `p = a + b - c` are `|b - c|`, `|a - c|` and `|(b - c) + (a - c)|`. The
+/// last can be up to 510, but it is only compared with the other two, which are at most 255, so it is clamped to 255
+/// to fit in a byte without changing the result.
+#[inline(always)]
+fn paeth<S: Simd>(a: u8x16<S>, b: u8x16<S>, c: u8x16<S>) -> u8x16<S> {
+    let abs_diff = |x: u8x16<S>, y: u8x16<S>| x.max(y) - x.min(y);
+    let pa = abs_diff(b, c);
+    let pb = abs_diff(a, c);
+    // `b - c` and `a - c` have the same sign when their magnitudes add.
+    let same_sign = !(b.simd_ge(c) ^ a.simd_ge(c));
+    let pc = same_sign.select(pa + pb.min(!pa), abs_diff(pa, pb));
+    let use_a = pa.simd_le(pb) & pa.simd_le(pc);
+    use_a.select(a, pb.simd_le(pc).select(b, c))
+}
+
+/// Load up to 16 bytes from the start of `bytes`, with zeros past its end.
+#[inline(always)]
+fn load<S: Simd>(simd: S, bytes: &[u8]) -> u8x16<S> {
+    match bytes.get(..16) {
+        Some(bytes) => u8x16::from_slice(simd, bytes),
+        None => load_tail(simd, bytes, 0),
+    }
+}
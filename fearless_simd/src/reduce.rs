@@ -0,0 +1,120 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Horizontal reductions which find the position of an element, or combine the lanes with a custom operation.
+
+use crate::{Simd, SimdBase};
+
+/// Find the lane index of the smallest or largest element of a vector.
+///
+/// This is implemented for every vector type, including the native-width types such as
+/// [`Simd::f32s`].
+///
+/// These are horizontal operations, so they are comparatively slow. When searching a large amount
+/// of data, it's faster to keep the best candidate of each lane, and only reduce those at the end;
+/// [`slice::argmin`](crate::slice::argmin) and [`slice::argmax`](crate::slice::argmax) do this for
+/// slices of `f32`.
+///
+/// If several lanes have the same value, the index of the first of them is returned. This also
+/// applies to `0.0` and `-0.0`, which compare equal. NaN elements are ignored, and if every
+/// element is NaN, lane 0 is returned.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, f32x4, prelude::*};
+///
+/// let simd = Fallback::new();
+/// let a = f32x4::from_slice(simd, &[3.0, -1.0, 4.0, -1.0]);
+/// assert_eq!(a.argmin(), 1);
+/// assert_eq!(a.argmax(), 2);
+/// ```
+pub trait SimdArgMinMax<S: Simd>: SimdBase<S> {
+    /// Find the index of the smallest element.
+    fn argmin(self) -> usize;
+
+    /// Find the index of the largest element.
+    fn argmax(self) -> usize;
+}
+
+impl<S: Simd, V> SimdArgMinMax<S> for V
+where
+    V: SimdBase<S>,
+    V::Element: PartialOrd + Copy,
+{
+    #[inline(always)]
+    fn argmin(self) -> usize {
+        first_index_where(self.as_slice(), |x, best| x < best)
+    }
+
+    #[inline(always)]
+    fn argmax(self) -> usize {
+        first_index_where(self.as_slice(), |x, best| x > best)
+    }
+}
+
+/// Reduce the lanes of `v` to one element with `f`, which combines two vectors lane by lane.
+///
+/// This is for reductions which don't have a dedicated operation, such as the smallest element together with its
+/// index, or a sum in a different type. `f` is called with whole vectors `log2(N)` times, so it should be cheap and
+/// act on each lane separately. The lanes are combined in order, as in `v[0] ⊕ v[1] ⊕ ... ⊕ v[N - 1]`, so `f` has to
+/// be associative, but not commutative.
+///
+/// Each step combines every lane with the lane after the block of lanes which it already holds, which is found by
+/// rotating the vector. After the last step, the first lane holds the whole reduction, and is returned.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, prelude::*, reduce_lanes, u32x8, u64x8};
+///
+/// let simd = Fallback::new();
+/// // The smallest distance and its index, packed into a `u64` with the distance in the upper half. The bits of
+/// // positive floats are in the same order as their values, so the smallest `u64` has the smallest distance, and
+/// // the lowest index of the ties.
+/// let distances = [2.5_f32, 0.5, 3.0, 0.5, 1.0, 4.0, 0.75, 2.0];
+/// let packed = u64x8::from_fn(simd, |i| u64::from(distances[i].to_bits()) << 32 | i as u64);
+/// let nearest = reduce_lanes(packed, |a, b| a.min(b));
+/// assert_eq!(nearest & 0xffff_ffff, 1);
+///
+/// // The last non-zero lane, which isn't commutative.
+/// let v = u32x8::from_slice(simd, &[3, 0, 7, 0, 0, 9, 0, 0]);
+/// let last = reduce_lanes(v, |a, b| b.simd_eq(0).select(a, b));
+/// assert_eq!(last, 9);
+/// ```
+#[inline(always)]
+pub fn reduce_lanes<S: Simd, V: SimdBase<S>>(v: V, f: impl Fn(V, V) -> V) -> V::Element
+where
+    V::Element: Copy,
+{
+    const { assert!(V::N <= 64, "vectors have at most 64 lanes") };
+    // After the step which rotates by `K`, each lane holds the reduction of the `2 * K` lanes from it, wrapping
+    // around the end of the vector. The first lane never wraps.
+    let mut v = f(v, v.rotate_elements_left::<1>());
+    if V::N > 2 {
+        v = f(v, v.rotate_elements_left::<2>());
+    }
+    if V::N > 4 {
+        v = f(v, v.rotate_elements_left::<4>());
+    }
+    if V::N > 8 {
+        v = f(v, v.rotate_elements_left::<8>());
+    }
+    if V::N > 16 {
+        v = f(v, v.rotate_elements_left::<16>());
+    }
+    if V::N > 32 {
+        v = f(v, v.rotate_elements_left::<32>());
+    }
+    v[0]
+}
+
+/// The index of the element which `better` prefers over all earlier ones, skipping unordered
+/// elements (that is, NaN).
+#[inline(always)]
+fn first_index_where<T: PartialOrd + Copy>(lanes: &[T], better: impl Fn(T, T) -> bool) -> usize {
+    let is_ordered = |x: T| x.partial_cmp(&x).is_some();
+    let mut best = 0;
+    for (i, &x) in lanes.iter().enumerate().skip(1) {
+        if is_ordered(x) && (!is_ordered(lanes[best]) || better(x, lanes[best])) {
+            best = i;
+        }
+    }
+    best
+}
@@ -0,0 +1,232 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A pseudo-random number generator which produces a vector of values at once, for simulations and procedural
+//! generation.
+//!
+//! [`Xoshiro128PlusPlus`] runs 8 xoshiro128++ generators side by side, one in each lane of a `u32x8`. Every lane is
+//! exactly the scalar xoshiro128++ generator from its authors' reference implementation, started at a known point of
+//! the same sequence, so any value can be reproduced and checked with a scalar implementation. The vector width is
+//! fixed, so the output is the same on every level.
+//!
+//! This isn't a cryptographically secure generator, and it shouldn't be used where the output must be
+//! unpredictable.
+//!
+//! ## Partitioning streams
+//!
+//! The sequence of xoshiro128++ has a period of 2<sup>128</sup> - 1, and its reference implementation has two jump
+//! functions, which advance a generator by 2<sup>64</sup> and 2<sup>96</sup> steps without computing the values in
+//! between. These split the sequence into subsequences which never overlap, for use in parallel:
+//!
+//! - The lanes of a generator start 2<sup>64</sup> steps apart: lane `i` is the scalar generator with the seed state,
+//!   after `i` calls to the reference `jump()`.
+//! - [`jump`](Xoshiro128PlusPlus::jump) moves every lane past the subsequences of the others, 8 × 2<sup>64</sup>
+//!   steps ahead. A generator and the copies made by cloning and jumping it repeatedly cover consecutive groups of 8
+//!   subsequences, for up to 2<sup>29</sup> generators.
+//! - [`long_jump`](Xoshiro128PlusPlus::long_jump) moves every lane 2<sup>96</sup> steps ahead, like the reference
+//!   `long_jump()`. This is for a second level of splitting, such as one long jump for each machine or thread, and
+//!   then jumps for each of its tasks.
+//!
+//! ```rust
+//! use fearless_simd::{Level, dispatch, rng::Xoshiro128PlusPlus};
+//!
+//! // Each task of a simulation gets its own generator, which only depends on the seed and the index of the task.
+//! let samples = dispatch!(Level::new(), simd => {
+//!     let mut rng = Xoshiro128PlusPlus::seed_from_u64(simd, 42);
+//!     let mut tasks = Vec::new();
+//!     for _ in 0..4 {
+//!         tasks.push(rng.clone());
+//!         rng.jump();
+//!     }
+//!     tasks.iter_mut().map(|rng| *rng.next_f32()).collect::<Vec<_>>()
+//! });
+//! assert!(samples.iter().flatten().all(|x| (0.0..1.0).contains(x)));
+//! ```
+
+use core::fmt;
+
+use crate::{Simd, SimdBase, f32x8, u32x8};
+
+/// The reference `jump()` polynomial, which advances a generator by 2^64 steps.
+const LANE_JUMP: [u32; 4] = [0x8764_000b, 0xf542_d2d3, 0x6fa0_35c3, 0x77f2_db5b];
+
+/// The polynomial which advances a generator by 8 * 2^64 = 2^67 steps, past the subsequences of all 8 lanes.
+const JUMP: [u32; 4] = [0xf705_85fb, 0x4e0c_5957, 0xbce2_50c3, 0x17a8_96ff];
+
+/// The reference `long_jump()` polynomial, which advances a generator by 2^96 steps.
+const LONG_JUMP: [u32; 4] = [0xb523_952e, 0x0b6f_099f, 0xccf5_a0ef, 0x1c58_0662];
+
+/// Eight xoshiro128++ generators, one in each lane.
+///
+/// See the [module documentation](self) for how the lanes relate to the scalar generator. The generator isn't
+/// `Copy`, so that it isn't accidentally duplicated and the same values used twice; use [`Clone`] when that is
+/// intended, followed by [`jump`](Self::jump) to get a separate stream.
+#[derive(Clone)]
+pub struct Xoshiro128PlusPlus<S: Simd> {
+    s: [u32x8<S>; 4],
+}
+
+impl<S: Simd> Xoshiro128PlusPlus<S> {
+    /// The number of generators, which is also the number of lanes of each output.
+    pub const LANES: usize = 8;
+
+    /// Create a generator whose lane `i` is the scalar generator with the state `state`, after `i` jumps.
+    ///
+    /// The state uses the order of the reference implementation, from `s[0]` to `s[3]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is all zeros, which is the only state that xoshiro can't leave.
+    #[inline(always)]
+    pub fn from_state(simd: S, state: [u32; 4]) -> Self {
+        assert_ne!(
+            state, [0; 4],
+            "the state of xoshiro128++ can't be all zeros"
+        );
+        let mut lanes = [state; 8];
+        for i in 1..lanes.len() {
+            lanes[i] = scalar_jump(lanes[i - 1], LANE_JUMP);
+        }
+        Self {
+            s: core::array::from_fn(|word| u32x8::from_fn(simd, |lane| lanes[lane][word])),
+        }
+    }
+
+    /// Create a generator from a 64-bit seed, as recommended by the authors of xoshiro.
+    ///
+    /// The state is the first two outputs of a `SplitMix64` generator with the state `seed`, split into their low
+    /// and high halves: `[a_lo, a_hi, b_lo, b_hi]`. This is never all zeros, and nearby seeds give unrelated states.
+    #[inline(always)]
+    pub fn seed_from_u64(simd: S, mut seed: u64) -> Self {
+        let a = splitmix64(&mut seed);
+        let b = splitmix64(&mut seed);
+        let low = |x: u64| u32::try_from(x & u64::from(u32::MAX)).unwrap();
+        let high = |x: u64| u32::try_from(x >> 32).unwrap();
+        Self::from_state(simd, [low(a), high(a), low(b), high(b)])
+    }
+
+    /// The state of lane `lane`, in the order of the reference implementation.
+    ///
+    /// A scalar xoshiro128++ generator with this state produces the same values as the lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is 8 or more.
+    #[inline(always)]
+    pub fn lane_state(&self, lane: usize) -> [u32; 4] {
+        self.s.each_ref().map(|word| word[lane])
+    }
+
+    /// Return the next value of each generator.
+    #[inline(always)]
+    pub fn next_u32(&mut self) -> u32x8<S> {
+        let [s0, _, _, s3] = self.s;
+        let result = rotate_left(s0 + s3, 7) + s0;
+        self.step();
+        result
+    }
+
+    /// Return a value in `[0, 1)` from each generator, with a uniform distribution on multiples of 2<sup>-24</sup>.
+    ///
+    /// This uses the upper 24 bits of [`next_u32`](Self::next_u32), which are converted exactly.
+    #[inline(always)]
+    pub fn next_f32(&mut self) -> f32x8<S> {
+        let simd = self.s[0].simd;
+        simd.cvt_f32_u32x8(self.next_u32() >> 8) * (1.0 / 16_777_216.0)
+    }
+
+    /// Advance every lane by 8 × 2<sup>64</sup> steps, past the starting points of all the other lanes.
+    ///
+    /// This is the same as 8 calls to the reference `jump()` for each lane.
+    #[inline(always)]
+    pub fn jump(&mut self) {
+        self.apply_jump(JUMP);
+    }
+
+    /// Advance every lane by 2<sup>96</sup> steps, like the reference `long_jump()`.
+    #[inline(always)]
+    pub fn long_jump(&mut self) {
+        self.apply_jump(LONG_JUMP);
+    }
+
+    /// Advance the state by one step, without computing an output.
+    #[inline(always)]
+    fn step(&mut self) {
+        let [s0, s1, s2, s3] = self.s;
+        let t = s1 << 9;
+        let s2 = s2 ^ s0;
+        let s3 = s3 ^ s1;
+        let s1 = s1 ^ s2;
+        let s0 = s0 ^ s3;
+        self.s = [s0, s1, s2 ^ t, rotate_left(s3, 11)];
+    }
+
+    /// Advance the state by the number of steps which the jump polynomial `poly` represents.
+    #[inline(always)]
+    fn apply_jump(&mut self, poly: [u32; 4]) {
+        let mut acc = [u32x8::splat(self.s[0].simd, 0); 4];
+        for word in poly {
+            for bit in 0..32 {
+                if word & (1 << bit) != 0 {
+                    for (acc, s) in acc.iter_mut().zip(self.s) {
+                        *acc ^= s;
+                    }
+                }
+                self.step();
+            }
+        }
+        self.s = acc;
+    }
+}
+
+impl<S: Simd> fmt::Debug for Xoshiro128PlusPlus<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoshiro128PlusPlus")
+            .field("s", &self.s.each_ref().map(|word| word.as_slice()))
+            .finish()
+    }
+}
+
+#[inline(always)]
+fn rotate_left<S: Simd>(x: u32x8<S>, k: u32) -> u32x8<S> {
+    (x << k) | (x >> (32 - k))
+}
+
+/// One step of the scalar generator, for setting up the lanes.
+#[inline(always)]
+fn scalar_step(s: &mut [u32; 4]) {
+    let t = s[1] << 9;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(11);
+}
+
+/// Advance a scalar state by the number of steps which the jump polynomial `poly` represents.
+#[inline(always)]
+fn scalar_jump(mut s: [u32; 4], poly: [u32; 4]) -> [u32; 4] {
+    let mut acc = [0; 4];
+    for word in poly {
+        for bit in 0..32 {
+            if word & (1 << bit) != 0 {
+                for (acc, s) in acc.iter_mut().zip(s) {
+                    *acc ^= s;
+                }
+            }
+            scalar_step(&mut s);
+        }
+    }
+    acc
+}
+
+/// The `SplitMix64` generator, which is used to seed xoshiro.
+#[inline(always)]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let z = *state;
+    let z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
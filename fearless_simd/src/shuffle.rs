@@ -0,0 +1,85 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support code for the [`shuffle`](crate::shuffle) and [`shuffle2`](crate::shuffle2) macros.
+
+use crate::{Simd, SimdBase};
+
+/// Implementation detail of [`shuffle`](crate::shuffle); this is not public API.
+///
+/// Validates the indices at compile time, when evaluated in a `const` block.
+#[doc(hidden)]
+pub const fn shuffle_indices<const N: usize>(indices: [usize; N]) -> [usize; N] {
+    let mut i = 0;
+    while i < N {
+        assert!(
+            indices[i] < N,
+            "shuffle! indices must be less than the number of lanes"
+        );
+        i += 1;
+    }
+    indices
+}
+
+/// Implementation detail of [`shuffle`](crate::shuffle); this is not public API.
+///
+/// With constant indices, this inlines to a single permutation of the input register, which the
+/// compiler lowers to the best instructions the level has for that pattern.
+#[doc(hidden)]
+#[inline(always)]
+pub fn shuffle<S: Simd, V: SimdBase<S>, const N: usize>(a: V, indices: [usize; N]) -> V
+where
+    V::Element: Copy,
+{
+    const {
+        assert!(
+            N == V::N,
+            "shuffle! needs exactly one index for each lane of the vector"
+        );
+    }
+    let lanes = a.as_slice();
+    V::from_fn(a.witness(), |i| lanes[indices[i]])
+}
+
+/// Implementation detail of [`shuffle2`](crate::shuffle2); this is not public API.
+///
+/// Validates the indices at compile time, when evaluated in a `const` block.
+#[doc(hidden)]
+pub const fn shuffle2_indices<const N: usize>(indices: [usize; N]) -> [usize; N] {
+    let mut i = 0;
+    while i < N {
+        assert!(
+            indices[i] < 2 * N,
+            "shuffle2! indices must be less than twice the number of lanes"
+        );
+        i += 1;
+    }
+    indices
+}
+
+/// Implementation detail of [`shuffle2`](crate::shuffle2); this is not public API.
+///
+/// With constant indices, this inlines to a single shuffle of the two input registers, which
+/// the compiler lowers to the best instructions the level has for that pattern.
+#[doc(hidden)]
+#[inline(always)]
+pub fn shuffle2<S: Simd, V: SimdBase<S>, const N: usize>(a: V, b: V, indices: [usize; N]) -> V
+where
+    V::Element: Copy,
+{
+    const {
+        assert!(
+            N == V::N,
+            "shuffle2! needs exactly one index for each lane of the vector"
+        );
+    }
+    let (a_lanes, b_lanes) = (a.as_slice(), b.as_slice());
+    V::from_fn(a.witness(), |i| {
+        let index = indices[i];
+        if index < N {
+            a_lanes[index]
+        } else {
+            b_lanes[index - N]
+        }
+    })
+}
@@ -0,0 +1,124 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A stored SIMD kernel, which dispatches on a [`Level`] each time it's called.
+
+use core::fmt;
+
+use crate::Level;
+
+/// A SIMD function bound to a [`Level`], which can be called from non-SIMD code.
+///
+/// This is created with the [`simd_fn`](crate::simd_fn) macro from a function which is generic
+/// over [`Simd`](crate::Simd), and dispatches to the best implementation for the stored level
+/// on each call. Dispatching is a few well-predicted branches on the level, so it's cheap to call
+/// repeatedly; see [`dispatch`](crate::dispatch#performance) for details.
+///
+/// A `SimdFn` only stores the [`Level`] and a function pointer, so it's always [`Copy`],
+/// [`Send`] and [`Sync`]. This makes it a convenient way to hold on to a kernel in
+/// long-lived state, such as a service or a processing pipeline, including across `.await`
+/// points in async code.
+///
+/// The SIMD tokens and vector types are also `Send` and `Sync`, so holding them across an
+/// `.await` is sound. However, the target features they prove are only enabled inside
+/// [`dispatch`](crate::dispatch), and an `async` block can't be dispatched, so code using them
+/// there won't be vectorized. Keeping a `SimdFn` and calling it between `.await` points, rather
+/// than holding a token, makes sure that every call runs with the target features enabled.
+///
+/// The kernel takes the token and a single argument; use a tuple to pass more than one value.
+/// Arguments which borrow data are easiest to use when the `SimdFn` is a local variable, because
+/// naming its type requires spelling out their lifetimes.
+///
+/// # Example
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, SimdFn, prelude::*, simd_fn};
+///
+/// #[inline(always)]
+/// fn scale<S: Simd>(simd: S, (mut samples, gain): (Vec<f32>, f32)) -> Vec<f32> {
+///     let mut chunks = samples.chunks_exact_mut(S::f32s::N);
+///     for chunk in &mut chunks {
+///         (S::f32s::from_slice(simd, chunk) * gain).store_slice(chunk);
+///     }
+///     for sample in chunks.into_remainder() {
+///         *sample *= gain;
+///     }
+///     samples
+/// }
+///
+/// async fn process_frames(
+///     kernel: SimdFn<(Vec<f32>, f32), Vec<f32>>,
+///     frames: Vec<Vec<f32>>,
+/// ) -> Vec<Vec<f32>> {
+///     let mut processed = Vec::new();
+///     for frame in frames {
+///         processed.push(kernel.call((frame, 0.5)));
+///         // Stand-in for waiting on more input.
+///         core::future::ready(()).await;
+///     }
+///     processed
+/// }
+///
+/// let kernel = simd_fn!(Level::new(), scale);
+/// assert_eq!(kernel.call((vec![1.0, 2.0, 3.0], 2.0)), [2.0, 4.0, 6.0]);
+///
+/// // The future holds the kernel across `.await` points, and can still be sent between threads.
+/// fn assert_send<T: Send>(_: T) {}
+/// assert_send(process_frames(kernel, vec![vec![1.0; 16]]));
+/// ```
+pub struct SimdFn<Args, Out> {
+    level: Level,
+    dispatch: fn(Level, Args) -> Out,
+}
+
+impl<Args, Out> SimdFn<Args, Out> {
+    /// Implementation detail of [`simd_fn`](crate::simd_fn); this is not public API.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn __new(level: Level, dispatch: fn(Level, Args) -> Out) -> Self {
+        Self { level, dispatch }
+    }
+
+    /// Call the kernel, dispatching to the implementation for the stored level.
+    #[inline]
+    pub fn call(&self, args: Args) -> Out {
+        (self.dispatch)(self.level, args)
+    }
+
+    /// The level which this kernel dispatches on.
+    #[inline]
+    pub const fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Use a different level for future calls of this kernel.
+    ///
+    /// This is useful for testing a kernel on each level, or for limiting the instruction sets
+    /// it uses.
+    #[inline]
+    pub const fn with_level(self, level: Level) -> Self {
+        Self {
+            level,
+            dispatch: self.dispatch,
+        }
+    }
+}
+
+// These are implemented manually, because deriving them would require `Args` and `Out` to
+// implement the traits, even though only a function pointer is stored.
+impl<Args, Out> Clone for SimdFn<Args, Out> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Args, Out> Copy for SimdFn<Args, Out> {}
+
+impl<Args, Out> fmt::Debug for SimdFn<Args, Out> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimdFn")
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,1158 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Operations on whole slices, using the native vector width of the [`Simd`] level.
+//!
+//! These are generic over [`Simd`] and marked `#[inline(always)]`, like any other SIMD code, so
+//! they should be called from within [`dispatch`](crate::dispatch) or another function which
+//! already has a SIMD token.
+//!
+//! The results don't depend on the vector width: every level returns the same value for the same
+//! input.
+//!
+//! ```rust
+//! use fearless_simd::{Level, dispatch, slice};
+//!
+//! let samples = [0.25, -3.0, 1.5, 2.0, -0.5];
+//! let level = Level::new();
+//! assert_eq!(dispatch!(level, simd => slice::max_abs(simd, &samples)), 3.0);
+//! assert!(!dispatch!(level, simd => slice::any_nan(simd, &samples)));
+//! ```
+
+use core::ops::Add;
+
+use crate::{
+    Select, Simd, SimdBase, SimdFloat, SimdHashLanes, SimdInt, SimdInto, SimdMask, f32x4, f32x8,
+    mask32x8, u8x32, u32x8,
+};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::transmute::{SimdPod, checked_transmute_copy};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{
+    __m256, __m256i, _mm256_and_si256, _mm256_cmpgt_epi32, _mm256_cmplt_epu32_mask,
+    _mm256_i32gather_epi32, _mm256_i32gather_ps, _mm256_i32scatter_epi32, _mm256_i32scatter_ps,
+    _mm256_movemask_epi8, _mm256_set1_epi32,
+};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{
+    __m256, __m256i, _mm256_and_si256, _mm256_cmpgt_epi32, _mm256_cmplt_epu32_mask,
+    _mm256_i32gather_epi32, _mm256_i32gather_ps, _mm256_i32scatter_epi32, _mm256_i32scatter_ps,
+    _mm256_movemask_epi8, _mm256_set1_epi32,
+};
+
+/// Load the remainder of a slice that's shorter than a vector, filling the remaining lanes with `fill`.
+#[inline(always)]
+pub(crate) fn load_tail<S: Simd, V: SimdBase<S>>(
+    simd: S,
+    tail: &[V::Element],
+    fill: V::Element,
+) -> V
+where
+    V::Element: Copy,
+{
+    V::from_fn(simd, |i| tail.get(i).copied().unwrap_or(fill))
+}
+
+/// Calls `f` with each vector of `values`, and a mask of which of its lanes hold elements of `values`.
+///
+/// This is the loop structure of predicated kernels, as written for SVE or with AVX-512 masks: the last vector,
+/// which is only partly filled, is passed to the same code as the others, rather than to a scalar loop. The mask
+/// is all true for every vector but the last, whose remaining lanes are filled with zero. Levels without mask
+/// registers emulate it with an ordinary mask vector, so the kernel can use it with [`select`](Select::select) and
+/// the bitwise operators on every level.
+///
+/// The vector type is chosen by `f`, so any width can be used, not only the native one.
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*, slice};
+///
+/// /// Counts the elements of `values` which are less than `limit`.
+/// #[inline(always)]
+/// fn count_less<S: Simd>(simd: S, values: &[f32], limit: f32) -> usize {
+///     let mut count = 0;
+///     slice::for_each_vector_with_tail(simd, values, |v: S::f32s, valid| {
+///         // The padding is zero, which is less than `limit`, so it must be masked out.
+///         count += (v.simd_lt(limit) & valid).count_true();
+///     });
+///     count
+/// }
+///
+/// let values = [0.5, 3.0, -1.0, 2.5, 7.0];
+/// assert_eq!(dispatch!(Level::new(), simd => count_less(simd, &values, 2.75)), 3);
+/// ```
+#[inline(always)]
+pub fn for_each_vector_with_tail<S: Simd, V: SimdBase<S>>(
+    simd: S,
+    values: &[V::Element],
+    mut f: impl FnMut(V, V::Mask),
+) where
+    V::Element: Copy,
+{
+    let mut chunks = values.chunks_exact(V::N);
+    let all = V::Mask::splat(simd, true);
+    for chunk in &mut chunks {
+        f(V::from_slice(simd, chunk), all);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let v: V = load_tail(simd, tail, V::ADD_IDENTITY);
+        // The tail is shorter than a vector, which has at most 64 lanes, so this doesn't overflow.
+        let valid = V::Mask::from_bitmask(simd, (1 << tail.len()) - 1);
+        f(v, valid);
+    }
+}
+
+/// Calls `predicate` with each vector of `values` until it returns a mask with a true lane, and returns the index
+/// of the element in that lane.
+///
+/// This is the loop structure of scanning for a condition, such as the next delimiter in a buffer or the first
+/// sample which is out of range. The position is found with [`first_true`](SimdMask::first_true) on the mask, so
+/// only the vector which stops the scan is inspected lane by lane. Returns `None` if no element satisfies the
+/// predicate.
+///
+/// The last vector, which is only partly filled, is padded with zeros, like in [`for_each_vector_with_tail`]. The
+/// padding lanes are ignored, so `predicate` doesn't need to handle them. `predicate` may also process each vector
+/// which it's called with, but the scan stops at the first one which satisfies it.
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*, slice};
+///
+/// /// Finds the first sample whose magnitude is more than `limit`.
+/// #[inline(always)]
+/// fn first_clipped<S: Simd>(simd: S, samples: &[f32], limit: f32) -> Option<usize> {
+///     slice::process_until(simd, samples, |v: S::f32s| v.abs().simd_gt(limit))
+/// }
+///
+/// let samples = [0.25, -0.5, 0.75, 0.5, -1.25, 2.0, 0.0];
+/// let level = Level::new();
+/// assert_eq!(dispatch!(level, simd => first_clipped(simd, &samples, 1.0)), Some(4));
+/// assert_eq!(dispatch!(level, simd => first_clipped(simd, &samples, 2.0)), None);
+/// ```
+#[inline(always)]
+pub fn process_until<S: Simd, V: SimdBase<S>>(
+    simd: S,
+    values: &[V::Element],
+    mut predicate: impl FnMut(V) -> V::Mask,
+) -> Option<usize>
+where
+    V::Element: Copy,
+{
+    let mut chunks = values.chunks_exact(V::N);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        if let Some(lane) = predicate(V::from_slice(simd, chunk)).first_true() {
+            return Some(offset + lane);
+        }
+        offset += V::N;
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let v: V = load_tail(simd, tail, V::ADD_IDENTITY);
+        // The tail is shorter than a vector, which has at most 64 lanes, so this doesn't overflow.
+        let valid = V::Mask::from_bitmask(simd, (1 << tail.len()) - 1);
+        if let Some(lane) = (predicate(v) & valid).first_true() {
+            return Some(offset + lane);
+        }
+    }
+    None
+}
+
+/// Computes `out[i] = f(a[i], b[i])` a vector at a time, for every element of `out`.
+///
+/// This is the loop structure of elementwise binary kernels, such as adding two buffers of samples or blending two
+/// rows of pixels. The lengths are checked once, before the loop, and two vectors of each slice are processed in
+/// each iteration, so that the loads of one can be overlapped with the arithmetic of the other.
+///
+/// The last vector, which is only partly filled, is padded with zeros, like in [`for_each_vector_with_tail`], and
+/// only the lanes of the result which hold elements are stored. So `f` is called with the padding, but its results
+/// for those lanes are ignored. The vector type is chosen by `f`, so any width can be used, not only the native one.
+///
+/// # Panics
+///
+/// Panics if `a` or `b` has a different length to `out`.
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*, slice};
+///
+/// /// Computes `a * x + y` for each element.
+/// #[inline(always)]
+/// fn axpy<S: Simd>(simd: S, a: f32, x: &[f32], y: &[f32], out: &mut [f32]) {
+///     slice::zip_map(simd, x, y, out, |x: S::f32s, y| x.mul_add(a, y));
+/// }
+///
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let y = [0.5, 0.5, 0.5, 1.0, 1.0];
+/// let mut out = [0.0; 5];
+/// dispatch!(Level::new(), simd => axpy(simd, 2.0, &x, &y, &mut out));
+/// assert_eq!(out, [2.5, 4.5, 6.5, 9.0, 11.0]);
+/// ```
+#[inline(always)]
+pub fn zip_map<S: Simd, V: SimdBase<S>>(
+    simd: S,
+    a: &[V::Element],
+    b: &[V::Element],
+    out: &mut [V::Element],
+    mut f: impl FnMut(V, V) -> V,
+) where
+    V::Element: Copy,
+{
+    zip_map_n(simd, [a, b], out, |[a, b]| f(a, b));
+}
+
+/// Computes `out[i] = f(a[i], b[i], c[i])` a vector at a time, for every element of `out`.
+///
+/// This is the same as [`zip_map`], with three inputs, for kernels such as a fused multiply-add of three buffers or
+/// a linear interpolation with a separate weight for each element.
+///
+/// # Panics
+///
+/// Panics if `a`, `b` or `c` has a different length to `out`.
+///
+/// ```rust
+/// use fearless_simd::{Level, Simd, dispatch, prelude::*, slice};
+///
+/// /// Interpolates between `from` and `to` by `t`.
+/// #[inline(always)]
+/// fn lerp<S: Simd>(simd: S, from: &[f32], to: &[f32], t: &[f32], out: &mut [f32]) {
+///     slice::zip3_map(simd, from, to, t, out, |from: S::f32s, to, t| (to - from).mul_add(t, from));
+/// }
+///
+/// let from = [0.0, 1.0, 2.0, -4.0, 8.0, 0.0, 1.0];
+/// let to = [1.0, 3.0, 2.0, 4.0, 0.0, 10.0, 2.0];
+/// let t = [0.5, 0.25, 1.0, 0.75, 0.5, 0.0, 1.0];
+/// let mut out = [0.0; 7];
+/// dispatch!(Level::new(), simd => lerp(simd, &from, &to, &t, &mut out));
+/// assert_eq!(out, [0.5, 1.5, 2.0, 2.0, 4.0, 0.0, 2.0]);
+/// ```
+#[inline(always)]
+pub fn zip3_map<S: Simd, V: SimdBase<S>>(
+    simd: S,
+    a: &[V::Element],
+    b: &[V::Element],
+    c: &[V::Element],
+    out: &mut [V::Element],
+    mut f: impl FnMut(V, V, V) -> V,
+) where
+    V::Element: Copy,
+{
+    zip_map_n(simd, [a, b, c], out, |[a, b, c]| f(a, b, c));
+}
+
+/// Computes `out[i] = f(inputs[0][i], inputs[1][i], ...)` a vector at a time.
+///
+/// This is the implementation of [`zip_map`] and [`zip3_map`].
+#[inline(always)]
+fn zip_map_n<S: Simd, V: SimdBase<S>, const N: usize>(
+    simd: S,
+    inputs: [&[V::Element]; N],
+    out: &mut [V::Element],
+    mut f: impl FnMut([V; N]) -> V,
+) where
+    V::Element: Copy,
+{
+    let len = out.len();
+    for input in inputs {
+        assert_eq!(
+            input.len(),
+            len,
+            "every input should be as long as the output"
+        );
+    }
+    let load =
+        |offset: usize| inputs.map(|input| V::from_slice(simd, &input[offset..offset + V::N]));
+    // Two vectors are processed in each iteration, so that one vector's loads can overlap the other's arithmetic.
+    let mut pairs = out.chunks_exact_mut(2 * V::N);
+    let mut offset = 0;
+    for pair in &mut pairs {
+        let (first, second) = pair.split_at_mut(V::N);
+        f(load(offset)).store_slice(first);
+        f(load(offset + V::N)).store_slice(second);
+        offset += 2 * V::N;
+    }
+    for chunk in pairs.into_remainder().chunks_mut(V::N) {
+        if chunk.len() == V::N {
+            f(load(offset)).store_slice(chunk);
+        } else {
+            let tail = inputs.map(|input| load_tail(simd, &input[offset..], V::ADD_IDENTITY));
+            chunk.copy_from_slice(&f(tail).as_slice()[..chunk.len()]);
+        }
+        offset += V::N;
+    }
+}
+
+/// Returns `true` if any element of `values` is NaN.
+#[inline(always)]
+pub fn any_nan<S: Simd>(simd: S, values: &[f32]) -> bool {
+    let mut chunks = values.chunks_exact(S::f32s::N);
+    let mut nan = S::mask32s::splat(simd, false);
+    for chunk in &mut chunks {
+        let v = S::f32s::from_slice(simd, chunk);
+        nan |= !v.simd_eq(v);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        // Zero is never NaN, so padding the tail with it doesn't change the result.
+        let v: S::f32s = load_tail(simd, tail, 0.0);
+        nan |= !v.simd_eq(v);
+    }
+    nan.any_true()
+}
+
+/// Returns the largest absolute value of the elements of `values`.
+///
+/// NaN elements are ignored. Returns `0.0` if `values` is empty or only contains NaN.
+#[inline(always)]
+pub fn max_abs<S: Simd>(simd: S, values: &[f32]) -> f32 {
+    let mut chunks = values.chunks_exact(S::f32s::N);
+    let mut max = S::f32s::splat(simd, 0.0);
+    for chunk in &mut chunks {
+        max = max.max_precise(S::f32s::from_slice(simd, chunk).abs());
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let v: S::f32s = load_tail(simd, tail, 0.0);
+        max = max.max_precise(v.abs());
+    }
+    // Every lane is non-negative and not NaN, so the order of this reduction doesn't matter.
+    max.as_slice().iter().copied().fold(0.0, f32::max)
+}
+
+/// Returns the index of the smallest element of `values`.
+///
+/// If several elements have the same value, the index of the first of them is returned. This also
+/// applies to `0.0` and `-0.0`, which compare equal. NaN elements are ignored. Returns `None` if
+/// `values` is empty or only contains NaN.
+#[inline(always)]
+pub fn argmin<S: Simd>(simd: S, values: &[f32]) -> Option<usize> {
+    argmin_by_key(simd, values, |v| v)
+}
+
+/// Returns the index of the largest element of `values`.
+///
+/// If several elements have the same value, the index of the first of them is returned. This also
+/// applies to `0.0` and `-0.0`, which compare equal. NaN elements are ignored. Returns `None` if
+/// `values` is empty or only contains NaN.
+#[inline(always)]
+pub fn argmax<S: Simd>(simd: S, values: &[f32]) -> Option<usize> {
+    // Negation preserves NaN and equality, so the largest element is the first smallest negated one.
+    argmin_by_key(simd, values, |v| -v)
+}
+
+/// The shared implementation of [`argmin`] and [`argmax`], which finds the smallest `key(v)`.
+#[inline(always)]
+fn argmin_by_key<S: Simd>(
+    simd: S,
+    values: &[f32],
+    key: impl Fn(S::f32s) -> S::f32s,
+) -> Option<usize> {
+    let lanes = S::f32s::N;
+    // Each lane tracks its smallest key, and the index of the vector it came from. Those indices
+    // are stored in `u32` lanes, so very long slices are processed in segments.
+    let segment_len = usize::try_from(u32::MAX).unwrap_or(usize::MAX) / lanes * lanes;
+    let mut result: Option<(f32, usize)> = None;
+    for (segment_idx, segment) in values.chunks(segment_len).enumerate() {
+        let mut min = S::f32s::splat(simd, f32::NAN);
+        let mut min_vector = S::u32s::splat(simd, 0);
+        let mut update = |v: S::f32s, vector: u32| {
+            // A lane is replaced if the new key is smaller, or if the lane is still NaN. The
+            // comparison is strict, so the first of several equal keys is kept.
+            let v = key(v);
+            let replace = v.simd_lt(min) | !min.simd_eq(min);
+            min = replace.select(v, min);
+            min_vector = replace.select(S::u32s::splat(simd, vector), min_vector);
+        };
+        let mut chunks = segment.chunks_exact(lanes);
+        let mut vector = 0;
+        for chunk in &mut chunks {
+            update(S::f32s::from_slice(simd, chunk), vector);
+            vector += 1;
+        }
+        let tail = chunks.remainder();
+        if !tail.is_empty() {
+            // NaN is ignored, so padding the tail with it doesn't change the result.
+            update(load_tail(simd, tail, f32::NAN), vector);
+        }
+
+        let segment_start = segment_idx * segment_len;
+        for lane in 0..lanes {
+            let value = min[lane];
+            if value.is_nan() {
+                continue;
+            }
+            let index = segment_start + min_vector[lane] as usize * lanes + lane;
+            let is_better = match result {
+                None => true,
+                Some((min_value, min_index)) => {
+                    value < min_value || (value == min_value && index < min_index)
+                }
+            };
+            if is_better {
+                result = Some((value, index));
+            }
+        }
+    }
+    result.map(|(_, index)| index)
+}
+
+/// Adds the number of occurrences of each value `0..BINS` in `values` to `bins`.
+///
+/// This is a histogram of small values, such as the nibbles of a byte stream, which is kept in
+/// vector registers rather than scattered to memory. Every bin has a vector of 8-bit counters, and
+/// each input vector is compared against every bin; the counters are added to `bins` before they
+/// can overflow. The cost is proportional to `BINS`, which is limited to 16 so that the counters
+/// fit in the registers of every level.
+///
+/// Values which are `BINS` or larger aren't counted.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let data = [0x3a_u8, 0x1f, 0x33, 0xa0];
+/// let nibbles: Vec<u8> = data.iter().flat_map(|b| [b >> 4, b & 0xf]).collect();
+/// let mut bins = [0_u32; 16];
+/// dispatch!(Level::new(), simd => slice::accumulate_u8_bins(simd, &nibbles, &mut bins));
+/// assert_eq!(bins[3], 3);
+/// assert_eq!(bins[0xa], 2);
+/// ```
+#[inline(always)]
+pub fn accumulate_u8_bins<S: Simd, const BINS: usize>(
+    simd: S,
+    values: &[u8],
+    bins: &mut [u32; BINS],
+) {
+    const { assert!(BINS <= 16, "at most 16 bins are supported") };
+    // There are at most 16 bins, so padding the tail with `u8::MAX` doesn't count anything.
+    count_u8_bins::<S, BINS, 1>(
+        simd,
+        values,
+        u8::MAX,
+        |v| [v],
+        |bin, count| {
+            bins[bin] += count;
+        },
+    );
+}
+
+/// Returns the number of occurrences of each nibble value in `bytes`, counting both nibbles of every byte.
+///
+/// This is the distribution which compression-ratio estimators and randomness tests (such as the poker test of FIPS
+/// 140-1) compute the entropy or the chi-squared statistic of. The nibbles are counted in vector registers, like in
+/// [`accumulate_u8_bins`], without first being split into a separate buffer.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let data = [0x3a_u8, 0x1f, 0x33, 0xa0];
+/// let histogram = dispatch!(Level::new(), simd => slice::nibble_histogram(simd, &data));
+/// assert_eq!(histogram[3], 3);
+/// assert_eq!(histogram[0xa], 2);
+/// assert_eq!(histogram.iter().sum::<u64>(), 8);
+/// ```
+#[inline(always)]
+pub fn nibble_histogram<S: Simd>(simd: S, bytes: &[u8]) -> [u64; 16] {
+    let mut histogram = [0; 16];
+    count_u8_bins::<S, 16, 2>(
+        simd,
+        bytes,
+        0,
+        |v| [v & 0x0f, v >> 4],
+        |bin, count| histogram[bin] += u64::from(count),
+    );
+    // The tail is padded with zero bytes, each of which was counted as two zero nibbles.
+    let padding = bytes.len().next_multiple_of(S::u8s::N) - bytes.len();
+    histogram[0] -= 2 * padding as u64;
+    histogram
+}
+
+/// Counts the occurrences of each value `0..BINS` in the `K` keys which `keys` derives from each vector of `values`,
+/// and calls `add` with each bin and the number of occurrences, once per batch of vectors.
+///
+/// The tail is padded with `fill`, whose keys are counted too.
+#[inline(always)]
+fn count_u8_bins<S: Simd, const BINS: usize, const K: usize>(
+    simd: S,
+    values: &[u8],
+    fill: u8,
+    keys: impl Fn(S::u8s) -> [S::u8s; K],
+    mut add: impl FnMut(usize, u32),
+) {
+    // Each counter lane is incremented at most `K` times per vector, so it can't wrap within a batch of this many
+    // vectors. The counters are `i8`, so that they can be incremented by subtracting a mask, but they're read back as
+    // `u8`.
+    let vectors_per_batch = u8::MAX as usize / K;
+
+    let targets: [S::u8s; BINS] = core::array::from_fn(|bin| {
+        S::u8s::splat(simd, u8::try_from(bin).expect("there are at most 16 bins"))
+    });
+    for batch in values.chunks(vectors_per_batch * S::u8s::N) {
+        let mut counters = [S::i8s::splat(simd, 0); BINS];
+        let mut count = |v: S::u8s| {
+            for key in keys(v) {
+                for (counter, &target) in counters.iter_mut().zip(&targets) {
+                    *counter -= key.simd_eq(target).to_int();
+                }
+            }
+        };
+        let mut chunks = batch.chunks_exact(S::u8s::N);
+        for chunk in &mut chunks {
+            count(S::u8s::from_slice(simd, chunk));
+        }
+        let tail = chunks.remainder();
+        if !tail.is_empty() {
+            count(load_tail(simd, tail, fill));
+        }
+        for (bin, counter) in counters.iter().enumerate() {
+            add(
+                bin,
+                counter
+                    .as_slice()
+                    .iter()
+                    .map(|&c| u32::from(c.cast_unsigned()))
+                    .sum(),
+            );
+        }
+    }
+}
+
+/// Returns the number of set bits in `bytes`.
+///
+/// This is the population count of a bitmap, such as the number of elements in a bitset or the number of ones
+/// for the monobit test of a random stream. Each vector of bytes is counted with
+/// [`count_ones`](SimdInt::count_ones), and the counts are accumulated in 8-bit lanes, which are only widened and
+/// summed once for every 31 vectors. The vectors are 256 bits wide on every level, as the widening sum is only
+/// available for vectors of a fixed width.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let bitmap = [0xff_u8, 0x01, 0x80, 0x0f, 0x00];
+/// assert_eq!(dispatch!(Level::new(), simd => slice::count_set_bits(simd, &bitmap)), 14);
+/// ```
+#[inline(always)]
+pub fn count_set_bits<S: Simd>(simd: S, bytes: &[u8]) -> u64 {
+    // Each byte has at most 8 set bits, so the 8-bit counts can't wrap within a batch of this many vectors.
+    const VECTORS_PER_BATCH: usize = u8::MAX as usize / 8;
+    let mut total = 0;
+    for batch in bytes.chunks(VECTORS_PER_BATCH * u8x32::<S>::N) {
+        let mut counts = u8x32::splat(simd, 0);
+        let mut chunks = batch.chunks_exact(u8x32::<S>::N);
+        for chunk in &mut chunks {
+            counts += u8x32::from_slice(simd, chunk).count_ones();
+        }
+        let tail = chunks.remainder();
+        if !tail.is_empty() {
+            // Zero has no set bits, so padding the tail with it doesn't change the count.
+            let v: u8x32<S> = load_tail(simd, tail, 0);
+            counts += v.count_ones();
+        }
+        total += u64::from(simd.reduce_add_widened_u8x32(counts));
+    }
+    total
+}
+
+/// Returns the index of the first byte which differs between `a` and `b`, or `None` if they're equal.
+///
+/// If one slice is longer than the other, but they're otherwise equal, this is the length of the shorter one.
+///
+/// The bytes are compared a whole vector at a time, and the position of a difference is found from the bitmask of
+/// the comparison, so this is much faster than a byte-by-byte loop for long slices. It returns as soon as it finds a
+/// difference, so the cost depends on the position of the mismatch rather than the length of the slices.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let level = Level::new();
+/// let a = b"content-addressed";
+/// assert_eq!(dispatch!(level, simd => slice::first_mismatch(simd, a, b"content-defined")), Some(8));
+/// assert_eq!(dispatch!(level, simd => slice::first_mismatch(simd, a, b"content")), Some(7));
+/// assert_eq!(dispatch!(level, simd => slice::first_mismatch(simd, a, a)), None);
+/// ```
+#[inline(always)]
+pub fn first_mismatch<S: Simd>(simd: S, a: &[u8], b: &[u8]) -> Option<usize> {
+    // Several vectors are compared before branching on the result, so that the loop over a long equal prefix isn't
+    // limited by one branch per vector. A mismatch is then located in the loop over single vectors.
+    const UNROLL: usize = 4;
+    let lanes = S::u8s::N;
+    let len = a.len().min(b.len());
+    let same_len = a.len() == b.len();
+    let (a, b) = (&a[..len], &b[..len]);
+    let eq_at = |offset: usize| {
+        S::u8s::from_slice(simd, &a[offset..offset + lanes])
+            .simd_eq(S::u8s::from_slice(simd, &b[offset..offset + lanes]))
+    };
+    let mut offset = 0;
+    while offset + UNROLL * lanes <= len {
+        let eq = eq_at(offset)
+            & eq_at(offset + lanes)
+            & eq_at(offset + 2 * lanes)
+            & eq_at(offset + 3 * lanes);
+        if !eq.all_true() {
+            break;
+        }
+        offset += UNROLL * lanes;
+    }
+    while offset + lanes <= len {
+        if let Some(lane) = (!eq_at(offset)).first_true() {
+            return Some(offset + lane);
+        }
+        offset += lanes;
+    }
+    if offset < len {
+        // The padding is the same in both vectors, so it never differs.
+        let a_tail: S::u8s = load_tail(simd, &a[offset..], 0);
+        let b_tail: S::u8s = load_tail(simd, &b[offset..], 0);
+        if let Some(lane) = (!a_tail.simd_eq(b_tail)).first_true() {
+            return Some(offset + lane);
+        }
+    }
+    (!same_len).then_some(len)
+}
+
+/// Returns `true` if `a` and `b` have the same length and contents.
+///
+/// This compares the bytes in the same way as [`first_mismatch`], and returns as soon as it finds a difference.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let block = [7_u8; 100];
+/// let mut copy = block;
+/// let level = Level::new();
+/// assert!(dispatch!(level, simd => slice::eq_slices(simd, &block, &copy)));
+/// copy[99] = 0;
+/// assert!(!dispatch!(level, simd => slice::eq_slices(simd, &block, &copy)));
+/// ```
+#[inline(always)]
+pub fn eq_slices<S: Simd>(simd: S, a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && first_mismatch(simd, a, b).is_none()
+}
+
+/// Packs a bitmap of which of `pixels` are brighter than `threshold` into `bitmap`.
+///
+/// This is the binarization step of OCR and of other vision pipelines, with one bit for each pixel rather than a
+/// byte: bit `i % 8` of `bitmap[i / 8]` is set if `pixels[i] > threshold`. The unused bits of the last byte are
+/// cleared. Each vector of pixels is compared with the threshold, and the mask is packed with
+/// [`to_bitmask`](SimdMask::to_bitmask), which is a single `movemask` on x86 and a masked horizontal add for each
+/// half of a vector on Neon.
+///
+/// # Panics
+///
+/// Panics if `bitmap` doesn't have exactly `pixels.len().div_ceil(8)` bytes.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let pixels = [0, 200, 50, 128, 129, 255, 10, 10, 250, 3];
+/// let mut bitmap = [0; 2];
+/// dispatch!(Level::new(), simd => slice::threshold_to_bitmap(simd, &pixels, 128, &mut bitmap));
+/// assert_eq!(bitmap, [0b0011_0010, 0b01]);
+/// ```
+#[inline(always)]
+pub fn threshold_to_bitmap<S: Simd>(simd: S, pixels: &[u8], threshold: u8, bitmap: &mut [u8]) {
+    assert_eq!(
+        bitmap.len(),
+        pixels.len().div_ceil(8),
+        "`bitmap` should have one bit for each pixel"
+    );
+    let threshold = S::u8s::splat(simd, threshold);
+    // Every vector but the last covers a whole number of bytes of the bitmap. The padding of the last one is zero,
+    // which is never brighter than the threshold, so the unused bits are cleared.
+    for (pixels, bitmap) in pixels
+        .chunks(S::u8s::N)
+        .zip(bitmap.chunks_mut(S::u8s::N / 8))
+    {
+        let pixels: S::u8s = if pixels.len() == S::u8s::N {
+            S::u8s::from_slice(simd, pixels)
+        } else {
+            load_tail(simd, pixels, 0)
+        };
+        let bits = pixels.simd_gt(threshold).to_bitmask().to_le_bytes();
+        bitmap.copy_from_slice(&bits[..bitmap.len()]);
+    }
+}
+
+/// XORs every slice in `sources` into `dst`.
+///
+/// This is the parity of RAID 5, and of the first parity block of most erasure codes. Each vector of `dst` is loaded
+/// once, combined with the same vector of every source, and stored once, so adding more sources doesn't add more
+/// passes over the destination.
+///
+/// # Panics
+///
+/// Panics if any source has a different length to `dst`.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let blocks = [[0x0f_u8; 40], [0xf0; 40], [0xff; 40]];
+/// let mut parity = [0; 40];
+/// dispatch!(Level::new(), simd => slice::xor_accumulate(simd, &mut parity, &[&blocks[0], &blocks[1], &blocks[2]]));
+/// assert_eq!(parity, [0; 40]);
+/// ```
+#[inline(always)]
+pub fn xor_accumulate<S: Simd>(simd: S, dst: &mut [u8], sources: &[&[u8]]) {
+    accumulate_bytes(simd, dst, sources, |_, v| v);
+}
+
+/// The number of sources [`xor_scale_accumulate`] multiplies into each vector of the destination before storing it.
+///
+/// Each source needs two lookup tables, and these should all stay in registers.
+const SCALE_SOURCES_PER_PASS: usize = 8;
+
+/// Multiplies each slice in `sources` by the coefficient at the same index of `coeffs` in the Galois field GF(2^8),
+/// and XORs the products into `dst`.
+///
+/// This computes the parity blocks of Reed-Solomon erasure codes, where each parity block is the sum of the data
+/// blocks multiplied by a row of coefficients. Decoding is the same operation, with the coefficients of the inverted
+/// matrix. The field uses the polynomial x^8 + x^4 + x^3 + x + 1 (`0x11B`), like the
+/// [`gf_mul_const`](crate::Simd::gf_mul_const_u8x16) operations, so the codes of libraries which use another
+/// polynomial need different coefficients.
+///
+/// The products are found with two 16-entry table lookups per byte, using tables which are built once per source.
+/// The destination is loaded and stored once for every eight sources.
+///
+/// # Panics
+///
+/// Panics if `coeffs` doesn't have one coefficient per source, or if any source has a different length to `dst`.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let level = Level::new();
+/// let data = [[1_u8, 2, 3, 4, 5], [6, 7, 8, 9, 10], [11, 12, 13, 14, 15]];
+/// let mut parity = [0; 5];
+/// dispatch!(level, simd => slice::xor_scale_accumulate(simd, &mut parity, &[&data[0], &data[1], &data[2]], &[1, 2, 3]));
+///
+/// // Recover the second block from the parity and the other blocks. `0x8d` is the inverse of 2 in this field.
+/// dispatch!(level, simd => slice::xor_scale_accumulate(simd, &mut parity, &[&data[0], &data[2]], &[1, 3]));
+/// let mut recovered = [0; 5];
+/// dispatch!(level, simd => slice::xor_scale_accumulate(simd, &mut recovered, &[&parity], &[0x8d]));
+/// assert_eq!(recovered, data[1]);
+/// ```
+#[inline(always)]
+pub fn xor_scale_accumulate<S: Simd>(simd: S, dst: &mut [u8], sources: &[&[u8]], coeffs: &[u8]) {
+    assert_eq!(
+        coeffs.len(),
+        sources.len(),
+        "expected one coefficient per source"
+    );
+    let zero = S::u8s::splat(simd, 0);
+    for (sources, coeffs) in sources
+        .chunks(SCALE_SOURCES_PER_PASS)
+        .zip(coeffs.chunks(SCALE_SOURCES_PER_PASS))
+    {
+        let mut tables = [(zero, zero); SCALE_SOURCES_PER_PASS];
+        for (table, &coeff) in tables.iter_mut().zip(coeffs) {
+            let (low, high) = crate::support::gf_mul_nibble_tables::<16>(coeff);
+            *table = (
+                S::u8s::block_splat(low.simd_into(simd)),
+                S::u8s::block_splat(high.simd_into(simd)),
+            );
+        }
+        accumulate_bytes(simd, dst, sources, |i, v| {
+            let (low, high) = tables[i];
+            low.swizzle_dyn_within_blocks(v & 0x0f) ^ high.swizzle_dyn_within_blocks(v >> 4)
+        });
+    }
+}
+
+/// XORs `term(i, v)` into each vector of `dst`, for the vector `v` at the same offset in each source `i`.
+///
+/// The tail is padded with zeros, so `term` must map zero to zero.
+#[inline(always)]
+fn accumulate_bytes<S: Simd>(
+    simd: S,
+    dst: &mut [u8],
+    sources: &[&[u8]],
+    term: impl Fn(usize, S::u8s) -> S::u8s,
+) {
+    for source in sources {
+        assert_eq!(
+            source.len(),
+            dst.len(),
+            "every source should be as long as the destination"
+        );
+    }
+    let lanes = S::u8s::N;
+    let mut chunks = dst.chunks_exact_mut(lanes);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let mut acc = S::u8s::from_slice(simd, chunk);
+        for (i, source) in sources.iter().enumerate() {
+            acc ^= term(i, S::u8s::from_slice(simd, &source[offset..offset + lanes]));
+        }
+        acc.store_slice(chunk);
+        offset += lanes;
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        let mut acc: S::u8s = load_tail(simd, tail, 0);
+        for (i, source) in sources.iter().enumerate() {
+            acc ^= term(i, load_tail(simd, &source[offset..], 0));
+        }
+        tail.copy_from_slice(&acc.as_slice()[..tail.len()]);
+    }
+}
+
+/// Replaces each element of `values` with the sum of `init` and all of the elements before it, and returns the sum
+/// of `init` and all of the elements.
+///
+/// This is an exclusive prefix sum, which turns a list of counts into the offset at which each item starts, for
+/// example to find where each kept element goes when compacting a stream, or where each field of a line starts.
+/// The sum wraps on overflow.
+///
+/// Each vector is summed in registers, and then offset by the total of the vectors before it. The returned total
+/// makes it possible to scan a long buffer in parts: passing the total of one part as the `init` of the next gives
+/// the same result as scanning the whole buffer at once. Since the totals of the parts can be found first, the
+/// parts can then also be scanned on different threads.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, slice};
+///
+/// let mut lengths = [3_u32, 0, 2, 5, 1];
+/// let level = Level::new();
+/// let total = dispatch!(level, simd => slice::exclusive_scan_u32(simd, &mut lengths, 10));
+/// assert_eq!(lengths, [10, 13, 13, 15, 20]);
+/// assert_eq!(total, 21);
+/// ```
+#[inline(always)]
+pub fn exclusive_scan_u32<S: Simd>(simd: S, values: &mut [u32], init: u32) -> u32 {
+    exclusive_scan::<S, S::u32s>(simd, values, init)
+}
+
+/// Replaces each element of `values` with the sum of `init` and all of the elements before it, and returns the sum
+/// of `init` and all of the elements.
+///
+/// This is the floating-point version of [`exclusive_scan_u32`]. The elements are added in a different order than
+/// a sequential loop would, so the results may be rounded differently from one. They are the same on every level,
+/// because every level sums groups of 4 elements in the same way, even if it has wider vectors.
+#[inline(always)]
+pub fn exclusive_scan_f32<S: Simd>(simd: S, values: &mut [f32], init: f32) -> f32 {
+    exclusive_scan::<S, f32x4<S>>(simd, values, init)
+}
+
+/// The shared implementation of [`exclusive_scan_u32`] and [`exclusive_scan_f32`].
+#[inline(always)]
+fn exclusive_scan<S: Simd, V>(simd: S, values: &mut [V::Element], init: V::Element) -> V::Element
+where
+    V: SimdBase<S> + Add<Output = V> + Add<V::Element, Output = V>,
+    V::Element: Copy,
+{
+    let mut chunks = values.chunks_exact_mut(V::N);
+    let mut carry = init;
+    for chunk in &mut chunks {
+        let (scanned, total) = exclusive_scan_vector(V::from_slice(simd, chunk), carry);
+        scanned.store_slice(chunk);
+        carry = total;
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        // Padding the tail with the identity doesn't change the sums of the lanes which are stored.
+        let v: V = load_tail(simd, tail, V::ADD_IDENTITY);
+        let (scanned, total) = exclusive_scan_vector(v, carry);
+        tail.copy_from_slice(&scanned.as_slice()[..tail.len()]);
+        carry = total;
+    }
+    carry
+}
+
+/// The exclusive prefix sum of the lanes of `v`, offset by `carry`, and the sum of `carry` and all of the lanes.
+#[inline(always)]
+pub(crate) fn exclusive_scan_vector<S: Simd, V>(v: V, carry: V::Element) -> (V, V::Element)
+where
+    V: SimdBase<S> + Add<Output = V> + Add<V::Element, Output = V>,
+    V::Element: Copy,
+{
+    let inclusive = inclusive_scan_vector(v, carry);
+    (
+        inclusive.shift_elements_right::<1>(carry),
+        inclusive[V::N - 1],
+    )
+}
+
+/// The inclusive prefix sum of the lanes of `v`, offset by `carry`.
+#[inline(always)]
+pub(crate) fn inclusive_scan_vector<S: Simd, V>(v: V, carry: V::Element) -> V
+where
+    V: SimdBase<S> + Add<Output = V> + Add<V::Element, Output = V>,
+    V::Element: Copy,
+{
+    const { assert!(V::N <= 16, "vectors have at most 16 lanes of 32 bits") };
+    // After each step, every lane holds the sum of the lanes up to twice as far before it.
+    let zero = V::ADD_IDENTITY;
+    let mut inclusive = v + v.shift_elements_right::<1>(zero);
+    if V::N > 2 {
+        inclusive = inclusive + inclusive.shift_elements_right::<2>(zero);
+    }
+    if V::N > 4 {
+        inclusive = inclusive + inclusive.shift_elements_right::<4>(zero);
+    }
+    if V::N > 8 {
+        inclusive = inclusive + inclusive.shift_elements_right::<8>(zero);
+    }
+    inclusive + carry
+}
+
+crate::simd_op! {
+    /// Loads the element of `values` at each index in `indices`.
+    ///
+    /// This is a table lookup with a vector of indices, such as for the bins of a histogram or the entries of a
+    /// palette. With AVX2 and AVX-512, the indices are checked against the length of `values` with vector
+    /// comparisons, and the elements are then loaded with a single gather instruction (`vpgatherdd`). Other levels
+    /// load them one at a time. A gather isn't much faster than separate loads on most processors, but it keeps the
+    /// indices and the result in registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `values`.
+    ///
+    /// ```rust
+    /// use fearless_simd::{Level, dispatch, prelude::*, slice, u32x8};
+    ///
+    /// let squares: Vec<u32> = (0..100).map(|i| i * i).collect();
+    /// let found = dispatch!(Level::new(), simd => {
+    ///     let indices = u32x8::from_slice(simd, &[3, 1, 4, 1, 5, 9, 2, 6]);
+    ///     *slice::gather_u32(simd, &squares, indices)
+    /// });
+    /// assert_eq!(found, [9, 1, 16, 1, 25, 81, 4, 36]);
+    /// ```
+    pub fn gather_u32<S: Simd>(simd: S, values: &[u32], indices: u32x8<S>) -> u32x8<S> {
+        Avx2(avx2, values: &[u32], indices: __m256i) -> __m256i {
+            if gather_indices_in_bounds(avx2, values.len(), indices) {
+                // Safety: every index is non-negative and less than the length of `values`, so each of the 4-byte
+                // elements which are loaded is within `values`.
+                unsafe { _mm256_i32gather_epi32::<4>(values.as_ptr().cast(), indices) }
+            } else {
+                gather_scalar(values, indices)
+            }
+        }
+        _ => {
+            u32x8::from_fn(simd, |i| values[usize::try_from(indices[i]).unwrap()])
+        }
+    }
+}
+
+crate::simd_op! {
+    /// Loads the element of `values` at each index in `indices`.
+    ///
+    /// This is the floating-point version of [`gather_u32`], which uses `vgatherdps` with AVX2 and AVX-512.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `values`.
+    pub fn gather_f32<S: Simd>(simd: S, values: &[f32], indices: u32x8<S>) -> f32x8<S> {
+        Avx2(avx2, values: &[f32], indices: __m256i) -> __m256 {
+            if gather_indices_in_bounds(avx2, values.len(), indices) {
+                // Safety: as in `gather_u32`, every element which is loaded is within `values`.
+                unsafe { _mm256_i32gather_ps::<4>(values.as_ptr(), indices) }
+            } else {
+                checked_transmute_copy(&gather_scalar(values, indices))
+            }
+        }
+        _ => {
+            f32x8::from_fn(simd, |i| values[usize::try_from(indices[i]).unwrap()])
+        }
+    }
+}
+
+crate::simd_op! {
+    /// Stores each lane of `data` to the element of `values` at the index in the same lane of `indices`.
+    ///
+    /// This is the reverse of [`gather_u32`], such as for writing to the bins of a histogram. If several lanes have
+    /// the same index, the element is set to the last of them. With AVX-512, the indices are checked against the
+    /// length of `values` with a vector comparison, and the lanes are then stored with a single scatter instruction
+    /// (`vpscatterdd`). Other levels store them one at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `values`, in which case nothing is stored.
+    ///
+    /// ```rust
+    /// use fearless_simd::{Level, dispatch, prelude::*, slice, u32x8};
+    ///
+    /// let mut palette = [0_u32; 16];
+    /// dispatch!(Level::new(), simd => {
+    ///     let indices = u32x8::from_slice(simd, &[3, 1, 4, 1, 5, 9, 2, 6]);
+    ///     let colors = u32x8::from_slice(simd, &[30, 10, 40, 11, 50, 90, 20, 60]);
+    ///     slice::scatter_u32(simd, &mut palette, indices, colors);
+    /// });
+    /// assert_eq!(palette[..10], [0, 11, 20, 30, 40, 50, 60, 0, 0, 90]);
+    /// ```
+    pub fn scatter_u32<S: Simd>(simd: S, values: &mut [u32], indices: u32x8<S>, data: u32x8<S>) {
+        Avx512(avx512, values: &mut [u32], indices: __m256i, data: __m256i) {
+            if scatter_indices_in_bounds(avx512, values.len(), indices) {
+                // Safety: every index is less than the length of `values`, so each of the 4-byte elements which are
+                // stored is within `values`.
+                unsafe { _mm256_i32scatter_epi32::<4>(values.as_mut_ptr().cast(), indices, data) }
+            } else {
+                scatter_scalar(values, checked_transmute_copy(&indices), checked_transmute_copy(&data));
+            }
+        }
+        _ => {
+            scatter_scalar(values, *indices, *data);
+        }
+    }
+}
+
+crate::simd_op! {
+    /// Stores each lane of `data` to the element of `values` at the index in the same lane of `indices`.
+    ///
+    /// This is the floating-point version of [`scatter_u32`], which uses `vscatterdps` with AVX-512.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `values`, in which case nothing is stored.
+    pub fn scatter_f32<S: Simd>(simd: S, values: &mut [f32], indices: u32x8<S>, data: f32x8<S>) {
+        Avx512(avx512, values: &mut [f32], indices: __m256i, data: __m256) {
+            if scatter_indices_in_bounds(avx512, values.len(), indices) {
+                // Safety: as in `scatter_u32`, every element which is stored is within `values`.
+                unsafe { _mm256_i32scatter_ps::<4>(values.as_mut_ptr().cast(), indices, data) }
+            } else {
+                scatter_scalar(values, checked_transmute_copy(&indices), checked_transmute_copy(&data));
+            }
+        }
+        _ => {
+            scatter_scalar(values, *indices, *data);
+        }
+    }
+}
+
+/// Stores each of `data` to `values` at the index in the same lane of `indices`, checking every index before
+/// storing any of them.
+#[inline(always)]
+fn scatter_scalar<T: Copy>(values: &mut [T], indices: [u32; 8], data: [T; 8]) {
+    let indices = indices.map(|i| usize::try_from(i).unwrap());
+    assert!(
+        indices.iter().all(|&i| i < values.len()),
+        "scatter index out of bounds"
+    );
+    for (i, value) in indices.into_iter().zip(data) {
+        values[i] = value;
+    }
+}
+
+crate::kernel!(
+    /// Whether every index is less than `len`, and is a non-negative offset for the scatter instructions.
+    fn scatter_indices_in_bounds(avx512: Avx512, len: usize, indices: __m256i) -> bool {
+        // Indices from 2^31 are negative offsets for a scatter, so the limit is at most that. Slices of over 8 GiB
+        // are left to `scatter_scalar` for their higher indices.
+        let limit = i32::try_from(len).unwrap_or(i32::MAX);
+        _mm256_cmplt_epu32_mask(indices, _mm256_set1_epi32(limit)) == 0xff
+    }
+);
+
+crate::kernel!(
+    /// Whether every index is less than `len`, when the indices are treated as signed like the gather instructions
+    /// do.
+    fn gather_indices_in_bounds(avx2: Avx2, len: usize, indices: __m256i) -> bool {
+        // Indices from 2^31 are negative offsets for a gather. They can only be in bounds for slices of over 8 GiB,
+        // which are left to `gather_scalar`.
+        let limit = i32::try_from(len).unwrap_or(i32::MAX);
+        let below_limit = _mm256_cmpgt_epi32(_mm256_set1_epi32(limit), indices);
+        let non_negative = _mm256_cmpgt_epi32(indices, _mm256_set1_epi32(-1));
+        _mm256_movemask_epi8(_mm256_and_si256(below_limit, non_negative)) == -1
+    }
+);
+
+/// Loads the elements of `values` at `indices` one at a time, panicking if one is out of bounds.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+fn gather_scalar<T: SimdPod>(values: &[T], indices: __m256i) -> __m256i {
+    let indices: [u32; 8] = checked_transmute_copy(&indices);
+    checked_transmute_copy(&indices.map(|i| values[usize::try_from(i).unwrap()]))
+}
+
+/// The value which is mixed into each key for the second hash of a Bloom filter.
+const BLOOM_SEED: u32 = 0x9e37_79b9;
+
+/// Sets the bits of the Bloom filter `bits` for each of `keys`, using `hashes` hash functions.
+///
+/// The filter is a bit array, where bit `b` is bit `b % 32` of `bits[b / 32]`. Each key sets the bits
+/// `(h1 + i * h2) % (32 * bits.len())` for `i` in `0..hashes`, where `h1` is the [`hash_lanes`] of the key and `h2`
+/// is the [`hash_lanes`] of the key after an XOR with `0x9e37_79b9`, with its lowest bit set. Filters which are
+/// built in other code can be probed with [`bloom_probe`] if they use the same bits.
+///
+/// # Panics
+///
+/// Panics if the length of `bits` isn't a power of two, or is more than 2<sup>27</sup>, so that a bit index fits
+/// in a `u32`.
+///
+/// [`hash_lanes`]: crate::SimdHashLanes::hash_lanes
+#[inline(always)]
+pub fn bloom_insert<S: Simd>(simd: S, bits: &mut [u32], keys: &[u32], hashes: u32) {
+    let index_mask = bloom_index_mask(bits.len());
+    for keys in keys.chunks(8) {
+        // The unused lanes of the last chunk repeat its last key, which sets the same bits again.
+        let keys = u32x8::from_fn(simd, |i| keys[i.min(keys.len() - 1)]);
+        let (mut index, step) = bloom_hashes(keys);
+        for _ in 0..hashes {
+            for bit in *(index & index_mask) {
+                bits[usize::try_from(bit / 32).unwrap()] |= 1 << (bit % 32);
+            }
+            index += step;
+        }
+    }
+}
+
+/// Returns a mask of which of `keys` may be in the Bloom filter `bits`, which uses `hashes` hash functions.
+///
+/// A lane is false if the key was certainly not inserted, and true if every one of its bits is set, which is the
+/// case for every inserted key and for a fraction of other keys. See [`bloom_insert`] for the layout of the filter
+/// and the bits which are tested for each key. With no hash functions, every lane is true.
+///
+/// The bits for each hash function are loaded with [`gather_u32`], so AVX2 and AVX-512 load the words for all 8
+/// keys with one instruction, and other levels load them one at a time. The loop stops early once every key has
+/// been rejected, which is usual for the queries of a join or a filter which mostly miss.
+///
+/// # Panics
+///
+/// Panics if the length of `bits` isn't a power of two, or is more than 2<sup>27</sup>.
+///
+/// ```rust
+/// use fearless_simd::{Level, dispatch, prelude::*, slice, u32x8};
+///
+/// let mut filter = vec![0; 64];
+/// let found = dispatch!(Level::new(), simd => {
+///     slice::bloom_insert(simd, &mut filter, &[10, 20, 30], 3);
+///     let keys = u32x8::from_slice(simd, &[10, 11, 20, 21, 30, 31, 40, 41]);
+///     slice::bloom_probe(simd, &filter, keys, 3).to_bitmask()
+/// });
+/// // Every inserted key is found. The others are very unlikely to be, with this few keys in 2048 bits.
+/// assert_eq!(found & 0b01_0101, 0b01_0101);
+/// ```
+#[inline(always)]
+pub fn bloom_probe<S: Simd>(simd: S, bits: &[u32], keys: u32x8<S>, hashes: u32) -> mask32x8<S> {
+    let index_mask = bloom_index_mask(bits.len());
+    let (mut index, step) = bloom_hashes(keys);
+    let mut found = mask32x8::splat(simd, true);
+    for _ in 0..hashes {
+        let bit = index & index_mask;
+        // The indices of the words are less than `bits.len()`, so the bounds check of the gather always passes.
+        let words = gather_u32(simd, bits, bit >> 5);
+        let set = simd.shrv_u32x8(words, bit & 31) & 1;
+        found &= set.simd_eq(u32x8::splat(simd, 1));
+        if found.all_false() {
+            break;
+        }
+        index += step;
+    }
+    found
+}
+
+/// Returns the mask which reduces a hash to a bit index of a Bloom filter with `len` words.
+#[inline(always)]
+fn bloom_index_mask(len: usize) -> u32 {
+    assert!(
+        len.is_power_of_two() && len <= 1 << 27,
+        "a Bloom filter should have a power of two words, up to 2^27, not {len}"
+    );
+    u32::try_from(len * 32 - 1).unwrap()
+}
+
+/// Returns the first bit index of each key, and the step between the indices for successive hash functions.
+#[inline(always)]
+fn bloom_hashes<S: Simd>(keys: u32x8<S>) -> (u32x8<S>, u32x8<S>) {
+    // An odd step visits a different bit for each hash function, until they wrap around the whole filter.
+    (keys.hash_lanes(), (keys ^ BLOOM_SEED).hash_lanes() | 1)
+}
@@ -0,0 +1,444 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Small vectors in structure-of-arrays layout, using the native vector width of the [`Simd`]
+//! level.
+//!
+//! A [`Vec3xN`] holds `S::f32s::N` three-dimensional vectors, with the `x` components of all of
+//! them in one SIMD vector, the `y` components in another, and so on. Each operation then works
+//! on every vector at once with plain lane-wise arithmetic, without the shuffles that are needed
+//! when a single vector is packed into the lanes of a SIMD vector (as with
+//! [`SimdDot`](crate::SimdDot)). This suits ray tracers and particle systems, which apply the
+//! same math to many independent points or directions. [`QuatxN`] and [`Rot2xN`] store 3D and
+//! 2D rotations in the same way, for animation and physics code which rotates many bodies at once.
+//!
+//! Like any other SIMD code, these are marked `#[inline(always)]`, so they should be used from
+//! within [`dispatch`](crate::dispatch) or another function which already has a SIMD token. The
+//! vector operations never fuse products and sums, so their results are the same on every level.
+//! Composing and applying rotations uses [`mul_add`](SimdFloat::mul_add) instead, which is a
+//! single rounding on levels with FMA, so those results can differ in the last bits between
+//! levels.
+//!
+//! ```rust
+//! use fearless_simd::{Level, Simd, dispatch, prelude::*, soa::Vec3xN};
+//!
+//! #[inline(always)]
+//! fn step<S: Simd>(simd: S, positions: &mut [[f32; 3]], velocities: &[[f32; 3]], dt: f32) {
+//!     let dt = S::f32s::splat(simd, dt);
+//!     let n = S::f32s::N;
+//!     for (positions, velocities) in positions.chunks_exact_mut(n).zip(velocities.chunks_exact(n)) {
+//!         let p = Vec3xN::from_slice(simd, positions) + Vec3xN::from_slice(simd, velocities) * dt;
+//!         p.store_slice(positions);
+//!     }
+//! }
+//!
+//! let mut positions = [[0.0, 1.0, 2.0]; 16];
+//! let velocities = [[1.0, 0.0, -2.0]; 16];
+//! dispatch!(Level::new(), simd => step(simd, &mut positions, &velocities, 0.5));
+//! assert_eq!(positions, [[0.5, 1.0, 1.0]; 16]);
+//! ```
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Simd, SimdBase, SimdFloat, SimdTrig};
+
+macro_rules! impl_soa_vec {
+    ($(#[$attr:meta])* $name:ident, $dim:literal, $($field:ident: $index:literal),+) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy)]
+        pub struct $name<S: Simd> {
+            $(
+                #[doc = concat!("The `", stringify!($field), "` component of each vector.")]
+                pub $field: S::f32s,
+            )+
+        }
+
+        impl<S: Simd> $name<S> {
+            /// Create vectors from their components.
+            #[inline(always)]
+            pub fn new($($field: S::f32s),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Create vectors which are all the same.
+            #[inline(always)]
+            pub fn splat(simd: S, value: [f32; $dim]) -> Self {
+                Self { $($field: S::f32s::splat(simd, value[$index])),+ }
+            }
+
+            /// Load one vector into each lane from a slice of vectors.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slice doesn't contain exactly `S::f32s::N` vectors.
+            #[inline(always)]
+            pub fn from_slice(simd: S, vectors: &[[f32; $dim]]) -> Self {
+                assert_eq!(vectors.len(), S::f32s::N, "expected one vector per lane");
+                Self { $($field: S::f32s::from_fn(simd, |i| vectors[i][$index])),+ }
+            }
+
+            /// Store the vector in each lane into a slice of vectors.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slice doesn't contain exactly `S::f32s::N` vectors.
+            #[inline(always)]
+            pub fn store_slice(&self, vectors: &mut [[f32; $dim]]) {
+                assert_eq!(vectors.len(), S::f32s::N, "expected one vector per lane");
+                for (i, vector) in vectors.iter_mut().enumerate() {
+                    *vector = [$(self.$field[i]),+];
+                }
+            }
+
+            /// Compute the dot product of each pair of vectors.
+            #[inline(always)]
+            pub fn dot(self, rhs: Self) -> S::f32s {
+                let [first, rest @ ..] = [$(self.$field * rhs.$field),+];
+                rest.into_iter().fold(first, |sum, product| sum + product)
+            }
+
+            /// Compute the squared length of each vector.
+            ///
+            /// This avoids the square root of [`length`](Self::length), so it's cheaper for
+            /// comparing lengths.
+            #[inline(always)]
+            pub fn length_squared(self) -> S::f32s {
+                self.dot(self)
+            }
+
+            /// Compute the length of each vector.
+            #[inline(always)]
+            pub fn length(self) -> S::f32s {
+                self.length_squared().sqrt()
+            }
+
+            /// Scale each vector to a length of one.
+            ///
+            /// Vectors with a length of zero become NaN, as in scalar code. The length is computed by
+            /// squaring the components, so vectors longer than about `1.8e19` overflow and become
+            /// zero.
+            #[inline(always)]
+            pub fn normalize(self) -> Self {
+                let length = self.length();
+                let recip = S::f32s::splat(length.witness(), 1.0) / length;
+                Self { $($field: self.$field * recip),+ }
+            }
+        }
+
+        impl<S: Simd> fmt::Debug for $name<S> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field.as_slice()))+
+                    .finish()
+            }
+        }
+
+        impl<S: Simd> Add for $name<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<S: Simd> AddAssign for $name<S> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<S: Simd> Sub for $name<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<S: Simd> SubAssign for $name<S> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<S: Simd> Neg for $name<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+
+/// Implement scaling and the component-wise product for the types which are plain vectors.
+macro_rules! impl_soa_scale {
+    ($name:ident, $($field:ident),+) => {
+        impl<S: Simd> $name<S> {
+            /// Multiply the components of each pair of vectors, for example to apply a color filter.
+            ///
+            /// This isn't the `*` operator, which scales each vector by a scalar instead.
+            #[inline(always)]
+            pub fn component_mul(self, rhs: Self) -> Self {
+                Self { $($field: self.$field * rhs.$field),+ }
+            }
+        }
+
+        /// Scale each vector by the scalar in the same lane.
+        impl<S: Simd> Mul<S::f32s> for $name<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn mul(self, rhs: S::f32s) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<S: Simd> MulAssign<S::f32s> for $name<S> {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: S::f32s) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+impl_soa_vec!(
+    /// `S::f32s::N` two-dimensional vectors, stored as one SIMD vector per component.
+    Vec2xN, 2, x: 0, y: 1
+);
+impl_soa_scale!(Vec2xN, x, y);
+
+impl_soa_vec!(
+    /// `S::f32s::N` three-dimensional vectors, stored as one SIMD vector per component.
+    Vec3xN, 3, x: 0, y: 1, z: 2
+);
+impl_soa_scale!(Vec3xN, x, y, z);
+
+impl_soa_vec!(
+    /// `S::f32s::N` four-dimensional vectors, stored as one SIMD vector per component.
+    Vec4xN, 4, x: 0, y: 1, z: 2, w: 3
+);
+impl_soa_scale!(Vec4xN, x, y, z, w);
+
+impl_soa_vec!(
+    /// `S::f32s::N` quaternions, stored as one SIMD vector per component.
+    ///
+    /// The `w` component is the real part, and the vector part is `(x, y, z)`, so the identity
+    /// is `[0.0, 0.0, 0.0, 1.0]` when loading from and storing to slices. Rotations should be
+    /// unit quaternions; [`normalize`](Self::normalize) them after a long chain of products to
+    /// correct rounding errors. The `*` operator composes two rotations: `a * b` applies `b`
+    /// first and then `a`.
+    ///
+    /// ```rust
+    /// use core::f32::consts::FRAC_PI_2;
+    /// use fearless_simd::{Fallback, f32x4, prelude::*, soa::{QuatxN, Vec3xN}};
+    ///
+    /// let simd = Fallback::new();
+    /// let z_axis = Vec3xN::splat(simd, [0.0, 0.0, 1.0]);
+    /// let quarter_turn = QuatxN::from_axis_angle(z_axis, f32x4::splat(simd, FRAC_PI_2));
+    /// let half_turn = quarter_turn * quarter_turn;
+    ///
+    /// let mut rotated = [[0.0; 3]; 4];
+    /// half_turn.rotate(Vec3xN::splat(simd, [1.0, 2.0, 3.0])).store_slice(&mut rotated);
+    /// for [x, y, z] in rotated {
+    ///     assert!((x + 1.0).abs() < 1e-6 && (y + 2.0).abs() < 1e-6 && z == 3.0);
+    /// }
+    /// ```
+    QuatxN, 4, x: 0, y: 1, z: 2, w: 3
+);
+
+impl<S: Simd> Vec3xN<S> {
+    /// Compute the cross product of each pair of vectors.
+    #[inline(always)]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+impl<S: Simd> QuatxN<S> {
+    /// Create identity quaternions, which don't rotate.
+    #[inline(always)]
+    pub fn identity(simd: S) -> Self {
+        Self::splat(simd, [0.0, 0.0, 0.0, 1.0])
+    }
+
+    /// Create the rotations by `angle` radians around each `axis`, which must have unit length.
+    ///
+    /// Positive angles rotate counterclockwise when looking along the axis towards the origin.
+    #[inline(always)]
+    pub fn from_axis_angle(axis: Vec3xN<S>, angle: S::f32s) -> Self {
+        let (sin, cos) = (angle * 0.5).sincos();
+        Self {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: cos,
+        }
+    }
+
+    /// Compute the conjugate of each quaternion, which is the inverse rotation for unit
+    /// quaternions.
+    #[inline(always)]
+    pub fn conjugate(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Rotate each vector by the quaternion in the same lane, which must have unit length.
+    #[inline(always)]
+    pub fn rotate(self, v: Vec3xN<S>) -> Vec3xN<S> {
+        // v + 2w(q × v) + 2q × (q × v), with the common factor in `t`.
+        let q = Vec3xN::<S>::new(self.x, self.y, self.z);
+        let t = q.cross(v);
+        let t = Vec3xN::new(t.x + t.x, t.y + t.y, t.z + t.z);
+        let u = q.cross(t);
+        Vec3xN {
+            x: t.x.mul_add(self.w, v.x + u.x),
+            y: t.y.mul_add(self.w, v.y + u.y),
+            z: t.z.mul_add(self.w, v.z + u.z),
+        }
+    }
+}
+
+/// The Hamilton product, which composes the rotations so that `b` is applied first.
+impl<S: Simd> Mul for QuatxN<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, b: Self) -> Self {
+        let a = self;
+        Self {
+            x: a.w
+                .mul_add(b.x, a.x.mul_add(b.w, a.y.mul_sub(b.z, a.z * b.y))),
+            y: a.w
+                .mul_add(b.y, a.y.mul_add(b.w, a.z.mul_sub(b.x, a.x * b.z))),
+            z: a.w
+                .mul_add(b.z, a.z.mul_add(b.w, a.x.mul_sub(b.y, a.y * b.x))),
+            w: a.w
+                .mul_sub(b.w, a.x.mul_add(b.x, a.y.mul_add(b.y, a.z * b.z))),
+        }
+    }
+}
+
+impl<S: Simd> MulAssign for QuatxN<S> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// `S::f32s::N` 2D rotations, stored as the cosine and sine of their angles.
+///
+/// This is the unit complex number `cos + i sin`, so rotating a vector is a complex
+/// multiplication, and doesn't need to evaluate any trigonometric functions. The `*` operator
+/// composes two rotations, which adds their angles.
+///
+/// ```rust
+/// use core::f32::consts::FRAC_PI_2;
+/// use fearless_simd::{Fallback, f32x4, prelude::*, soa::{Rot2xN, Vec2xN}};
+///
+/// let simd = Fallback::new();
+/// let angles = f32x4::from_slice(simd, &[0.0, FRAC_PI_2, -FRAC_PI_2, 3.0 * FRAC_PI_2]);
+/// let rotation = Rot2xN::from_angle(angles);
+/// let mut rotated = [[0.0; 2]; 4];
+/// rotation.rotate(Vec2xN::splat(simd, [1.0, 0.0])).store_slice(&mut rotated);
+/// let expected = [[1.0, 0.0], [0.0, 1.0], [0.0, -1.0], [0.0, -1.0]];
+/// for (r, e) in rotated.iter().zip(expected) {
+///     assert!((r[0] - e[0]).abs() < 1e-6 && (r[1] - e[1]).abs() < 1e-6);
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Rot2xN<S: Simd> {
+    /// The cosine of each angle.
+    pub cos: S::f32s,
+    /// The sine of each angle.
+    pub sin: S::f32s,
+}
+
+impl<S: Simd> Rot2xN<S> {
+    /// Create the rotations by `angle` radians, counterclockwise.
+    #[inline(always)]
+    pub fn from_angle(angle: S::f32s) -> Self {
+        let (sin, cos) = angle.sincos();
+        Self { cos, sin }
+    }
+
+    /// Create identity rotations, which don't rotate.
+    #[inline(always)]
+    pub fn identity(simd: S) -> Self {
+        Self {
+            cos: S::f32s::splat(simd, 1.0),
+            sin: S::f32s::splat(simd, 0.0),
+        }
+    }
+
+    /// Compute the inverse of each rotation, which rotates by the negated angle.
+    #[inline(always)]
+    pub fn inverse(self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Rotate each vector by the rotation in the same lane.
+    #[inline(always)]
+    pub fn rotate(self, v: Vec2xN<S>) -> Vec2xN<S> {
+        Vec2xN {
+            x: v.x.mul_sub(self.cos, v.y * self.sin),
+            y: v.x.mul_add(self.sin, v.y * self.cos),
+        }
+    }
+
+    /// Rescale each rotation to unit length, correcting the rounding errors of a long chain of
+    /// products.
+    #[inline(always)]
+    pub fn normalize(self) -> Self {
+        let v = Vec2xN::<S>::new(self.cos, self.sin).normalize();
+        Self { cos: v.x, sin: v.y }
+    }
+}
+
+impl<S: Simd> fmt::Debug for Rot2xN<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rot2xN")
+            .field("cos", &self.cos.as_slice())
+            .field("sin", &self.sin.as_slice())
+            .finish()
+    }
+}
+
+/// Compose the rotations, adding their angles.
+impl<S: Simd> Mul for Rot2xN<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cos: self.cos.mul_sub(rhs.cos, self.sin * rhs.sin),
+            sin: self.sin.mul_add(rhs.cos, self.cos * rhs.sin),
+        }
+    }
+}
+
+impl<S: Simd> MulAssign for Rot2xN<S> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
@@ -0,0 +1,510 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Float operations computed with integer arithmetic, for the fallback level with the `soft_float` feature.
+//!
+//! This makes the fallback level a bit-exact oracle, which doesn't depend on how the host computes floats: for
+//! example, x87 rounds to extended precision, and the sign and payload of NaN results vary between processors.
+//! Every result is correctly rounded, with ties to even, and subnormals are kept rather than flushed to zero.
+//!
+//! The results which IEEE 754 leaves open are chosen as follows:
+//!
+//! - If an operand is NaN, the result is the first NaN operand, made quiet. Otherwise, invalid operations, such as
+//!   `0.0 / 0.0`, return the positive quiet NaN with no payload (the same as [`f32::NAN`]). These are the rules of
+//!   Arm processors, so their vector and scalar results match this exactly.
+//! - `max` and `min` return the other operand if one operand is NaN, and order `-0.0` below `0.0`.
+//!
+//! These are slower than the host's float instructions, so are only intended for testing.
+
+use crate::fallback_float::FallbackFloat;
+
+macro_rules! soft_float {
+    ($module:ident, $float:ident, $bits:ident, $wide:ident) => {
+        #[allow(clippy::allow_attributes, reason = "Only needed for some types.")]
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss,
+            reason = "The exponents and the rounded significands always fit."
+        )]
+        mod $module {
+            /// The number of stored significand bits.
+            const MANT_BITS: u32 = $float::MANTISSA_DIGITS - 1;
+            const MANT_DIGITS: i32 = MANT_BITS as i32;
+            const SIGN: $bits = 1 << ($bits::BITS - 1);
+            const MANT_MASK: $bits = (1 << MANT_BITS) - 1;
+            const INF: $bits = !SIGN & !MANT_MASK;
+            const QUIET: $bits = 1 << (MANT_BITS - 1);
+            const CANONICAL_NAN: $bits = INF | QUIET;
+            /// The biased exponent of infinity and NaN.
+            const EXP_MAX: i32 = (INF >> MANT_BITS) as i32;
+            const BIAS: i32 = EXP_MAX >> 1;
+            const ONE: $bits = (BIAS as $bits) << MANT_BITS;
+            const HALF: $bits = ONE - (1 << MANT_BITS);
+
+            fn is_nan(x: $bits) -> bool {
+                x & !SIGN > INF
+            }
+
+            /// The NaN result of an operation on `a` and `b`, which aren't both numbers, or are an invalid pair.
+            fn nan(a: $bits, b: $bits) -> $bits {
+                if is_nan(a) {
+                    a | QUIET
+                } else if is_nan(b) {
+                    b | QUIET
+                } else {
+                    CANONICAL_NAN
+                }
+            }
+
+            /// Shifts `x` right by `shift` bits, setting the lowest bit if any set bits were shifted out.
+            fn shift_right_sticky(x: $wide, shift: u32) -> $wide {
+                match x.checked_shr(shift) {
+                    Some(kept) => kept | $wide::from(x & ((1 << shift) - 1) != 0),
+                    None => $wide::from(x != 0),
+                }
+            }
+
+            /// Splits the magnitude of a finite, nonzero float into a significand with its highest set bit at
+            /// `MANT_BITS`, and the exponent of the significand's lowest bit.
+            fn unpack(x: $bits) -> ($wide, i32) {
+                let exp = ((x & !SIGN) >> MANT_BITS) as i32;
+                let mant = $wide::from(x & MANT_MASK);
+                if exp == 0 {
+                    // Subnormals are normalized, so the significands of all operands have the same length.
+                    let shift = mant.leading_zeros() - ($wide::BITS - 1 - MANT_BITS);
+                    (mant << shift, 1 - BIAS - MANT_DIGITS - shift as i32)
+                } else {
+                    (mant | (1 << MANT_BITS), exp - BIAS - MANT_DIGITS)
+                }
+            }
+
+            /// Rounds `sig * 2^scale` to the nearest float, with ties to even, and gives it the sign `sign`.
+            fn round(sign: $bits, sig: $wide, scale: i32) -> $bits {
+                if sig == 0 {
+                    return sign;
+                }
+                let top = ($wide::BITS - 1 - sig.leading_zeros()) as i32;
+                let exp = top + scale + BIAS;
+                if exp >= EXP_MAX {
+                    return sign | INF;
+                }
+                // Subnormals have the same lowest bit as the smallest normal exponent.
+                let field = exp.max(1);
+                let shift = field - BIAS - MANT_DIGITS - scale;
+                let rounded = if shift <= 0 {
+                    sig << -shift
+                } else if shift > top + 1 {
+                    // Less than half of the smallest subnormal.
+                    0
+                } else {
+                    let shift = shift as u32;
+                    let kept = sig.checked_shr(shift).unwrap_or(0);
+                    let rest = sig - kept.checked_shl(shift).unwrap_or(0);
+                    let half = 1 << (shift - 1);
+                    kept + $wide::from(rest > half || (rest == half && kept & 1 == 1))
+                };
+                // The implicit bit of `rounded` adds one to the exponent field, and rounding up to the next power
+                // of two carries into it.
+                sign | ((((field - 1) as $bits) << MANT_BITS) + rounded as $bits)
+            }
+
+            pub(super) fn add(a: $bits, b: $bits) -> $bits {
+                if is_nan(a) || is_nan(b) {
+                    return nan(a, b);
+                }
+                let (a_abs, b_abs) = (a & !SIGN, b & !SIGN);
+                if a_abs == INF {
+                    return if b_abs == INF && (a ^ b) & SIGN != 0 {
+                        CANONICAL_NAN
+                    } else {
+                        a
+                    };
+                }
+                if b_abs == INF {
+                    return b;
+                }
+                if a_abs == 0 || b_abs == 0 {
+                    // An exact zero sum is only negative if both zeros are.
+                    return if a_abs == 0 && b_abs == 0 {
+                        a & b
+                    } else if a_abs == 0 {
+                        b
+                    } else {
+                        a
+                    };
+                }
+
+                let (big, small) = if a_abs >= b_abs { (a, b) } else { (b, a) };
+                let (big_sig, big_scale) = unpack(big);
+                let (small_sig, small_scale) = unpack(small);
+                // Leave room for a carry, and keep many more bits than are rounded to, so that the bits of the
+                // smaller operand which are shifted out only matter as a sticky bit.
+                let guard = $wide::BITS - 2 - MANT_BITS;
+                let big_sig = big_sig << guard;
+                let small_sig =
+                    shift_right_sticky(small_sig << guard, (big_scale - small_scale) as u32);
+                let sig = if (a ^ b) & SIGN == 0 {
+                    big_sig + small_sig
+                } else {
+                    big_sig - small_sig
+                };
+                if sig == 0 {
+                    // Exact cancellation gives positive zero.
+                    return 0;
+                }
+                round(big & SIGN, sig, big_scale - guard as i32)
+            }
+
+            pub(super) fn sub(a: $bits, b: $bits) -> $bits {
+                // The sign of a NaN operand is kept.
+                if is_nan(a) || is_nan(b) {
+                    return nan(a, b);
+                }
+                add(a, b ^ SIGN)
+            }
+
+            pub(super) fn mul(a: $bits, b: $bits) -> $bits {
+                if is_nan(a) || is_nan(b) {
+                    return nan(a, b);
+                }
+                let sign = (a ^ b) & SIGN;
+                let (a_abs, b_abs) = (a & !SIGN, b & !SIGN);
+                if a_abs == INF || b_abs == INF {
+                    return if a_abs == 0 || b_abs == 0 {
+                        CANONICAL_NAN
+                    } else {
+                        sign | INF
+                    };
+                }
+                if a_abs == 0 || b_abs == 0 {
+                    return sign;
+                }
+                let (a_sig, a_scale) = unpack(a);
+                let (b_sig, b_scale) = unpack(b);
+                // The product is exact, as the wide type has room for twice the significand bits.
+                round(sign, a_sig * b_sig, a_scale + b_scale)
+            }
+
+            pub(super) fn div(a: $bits, b: $bits) -> $bits {
+                if is_nan(a) || is_nan(b) {
+                    return nan(a, b);
+                }
+                let sign = (a ^ b) & SIGN;
+                let (a_abs, b_abs) = (a & !SIGN, b & !SIGN);
+                if a_abs == INF {
+                    return if b_abs == INF {
+                        CANONICAL_NAN
+                    } else {
+                        sign | INF
+                    };
+                }
+                if b_abs == INF {
+                    return sign;
+                }
+                if b_abs == 0 {
+                    return if a_abs == 0 {
+                        CANONICAL_NAN
+                    } else {
+                        sign | INF
+                    };
+                }
+                if a_abs == 0 {
+                    return sign;
+                }
+                let (a_sig, a_scale) = unpack(a);
+                let (b_sig, b_scale) = unpack(b);
+                // This gives a quotient with many more bits than are rounded to, and a sticky bit for the remainder.
+                let guard = $wide::BITS - 2 - MANT_BITS;
+                let num = a_sig << guard;
+                let quotient = ((num / b_sig) << 1) | $wide::from(num % b_sig != 0);
+                round(sign, quotient, a_scale - guard as i32 - b_scale - 1)
+            }
+
+            pub(super) fn sqrt(a: $bits) -> $bits {
+                if is_nan(a) {
+                    return a | QUIET;
+                }
+                if a & !SIGN == 0 || a == INF {
+                    return a;
+                }
+                if a & SIGN != 0 {
+                    return CANONICAL_NAN;
+                }
+                let (mut sig, mut scale) = unpack(a);
+                if scale % 2 != 0 {
+                    sig <<= 1;
+                    scale -= 1;
+                }
+                // Shifting by an even amount keeps the square root exact, and gives a root with many more bits than
+                // are rounded to. The root of a float is never exactly halfway between two floats.
+                let shift = ($wide::BITS - 2 - MANT_BITS) / 2 * 2;
+                let square = sig << shift;
+                let root = square.isqrt();
+                let sticky = $wide::from(root * root != square);
+                round(0, (root << 1) | sticky, (scale - shift as i32) / 2 - 1)
+            }
+
+            /// The ways of rounding to an integer.
+            #[derive(Clone, Copy, PartialEq, Eq)]
+            pub(super) enum Rounding {
+                Floor,
+                Ceil,
+                TiesEven,
+                Trunc,
+            }
+
+            pub(super) fn round_to_integer(a: $bits, rounding: Rounding) -> $bits {
+                if is_nan(a) {
+                    return a | QUIET;
+                }
+                let negative = a & SIGN != 0;
+                let exp = ((a & !SIGN) >> MANT_BITS) as i32;
+                if exp >= BIAS + MANT_DIGITS {
+                    // Already an integer, or infinite.
+                    return a;
+                }
+                if exp < BIAS {
+                    // The magnitude is less than one, so this rounds to zero or one, keeping the sign.
+                    let away = a & !SIGN != 0
+                        && match rounding {
+                            Rounding::Floor => negative,
+                            Rounding::Ceil => !negative,
+                            Rounding::TiesEven => a & !SIGN > HALF,
+                            Rounding::Trunc => false,
+                        };
+                    return (a & SIGN) | if away { ONE } else { 0 };
+                }
+                let fraction_bits = (BIAS + MANT_DIGITS - exp) as u32;
+                let unit: $bits = 1 << fraction_bits;
+                let fraction = a & (unit - 1);
+                let truncated = a & !(unit - 1);
+                let away = fraction != 0
+                    && match rounding {
+                        Rounding::Floor => negative,
+                        Rounding::Ceil => !negative,
+                        Rounding::TiesEven => {
+                            let half = unit >> 1;
+                            fraction > half || (fraction == half && truncated & unit != 0)
+                        }
+                        Rounding::Trunc => false,
+                    };
+                // Adding a unit to the magnitude carries into the exponent if needed.
+                if away { truncated + unit } else { truncated }
+            }
+
+            /// Maps a float which isn't NaN to an integer with the same order, with `-0.0` below `0.0`.
+            fn order_key(x: $bits) -> $bits {
+                if x & SIGN != 0 { !x } else { x | SIGN }
+            }
+
+            pub(super) fn max(a: $bits, b: $bits) -> $bits {
+                match (is_nan(a), is_nan(b)) {
+                    (true, true) => nan(a, b),
+                    (true, false) => b,
+                    (false, true) => a,
+                    (false, false) => {
+                        if order_key(a) >= order_key(b) {
+                            a
+                        } else {
+                            b
+                        }
+                    }
+                }
+            }
+
+            pub(super) fn min(a: $bits, b: $bits) -> $bits {
+                match (is_nan(a), is_nan(b)) {
+                    (true, true) => nan(a, b),
+                    (true, false) => b,
+                    (false, true) => a,
+                    (false, false) => {
+                        if order_key(a) <= order_key(b) {
+                            a
+                        } else {
+                            b
+                        }
+                    }
+                }
+            }
+        }
+
+        impl FallbackFloat for $float {
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $float::from_bits($module::add(self.to_bits(), rhs.to_bits()))
+            }
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                $float::from_bits($module::sub(self.to_bits(), rhs.to_bits()))
+            }
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                $float::from_bits($module::mul(self.to_bits(), rhs.to_bits()))
+            }
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                $float::from_bits($module::div(self.to_bits(), rhs.to_bits()))
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                $float::from_bits($module::sqrt(self.to_bits()))
+            }
+            #[inline]
+            fn floor(self) -> Self {
+                $float::from_bits($module::round_to_integer(
+                    self.to_bits(),
+                    $module::Rounding::Floor,
+                ))
+            }
+            #[inline]
+            fn ceil(self) -> Self {
+                $float::from_bits($module::round_to_integer(
+                    self.to_bits(),
+                    $module::Rounding::Ceil,
+                ))
+            }
+            #[inline]
+            fn round_ties_even(self) -> Self {
+                $float::from_bits($module::round_to_integer(
+                    self.to_bits(),
+                    $module::Rounding::TiesEven,
+                ))
+            }
+            #[inline]
+            fn trunc(self) -> Self {
+                $float::from_bits($module::round_to_integer(
+                    self.to_bits(),
+                    $module::Rounding::Trunc,
+                ))
+            }
+            #[inline]
+            fn fract(self) -> Self {
+                FallbackFloat::sub(self, FallbackFloat::trunc(self))
+            }
+            #[inline]
+            fn max(self, rhs: Self) -> Self {
+                $float::from_bits($module::max(self.to_bits(), rhs.to_bits()))
+            }
+            #[inline]
+            fn min(self, rhs: Self) -> Self {
+                $float::from_bits($module::min(self.to_bits(), rhs.to_bits()))
+            }
+        }
+    };
+}
+
+soft_float!(soft_f32, f32, u32, u64);
+soft_float!(soft_f64, f64, u64, u128);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fallback_float::FallbackFloat;
+
+    /// Compares the soft float results against the host's, which are correctly rounded on the tested targets.
+    ///
+    /// The operands are random, but are drawn from special values, nearby exponents, and subnormals often enough to
+    /// hit the edge cases of each operation.
+    macro_rules! check_against_host {
+        ($float:ident, $bits:ident) => {{
+            let specials: [$float; 12] = [
+                0.0,
+                -0.0,
+                1.0,
+                -1.5,
+                0.5,
+                2.5,
+                $float::INFINITY,
+                $float::NEG_INFINITY,
+                $float::NAN,
+                $float::MAX,
+                $float::MIN_POSITIVE,
+                $float::from_bits(1),
+            ];
+            let mut state = 0x2545_f491_4f6c_dd1d_u64;
+            let mut random = || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let bytes = state.to_le_bytes();
+                let bits = $bits::from_le_bytes(bytes[..size_of::<$bits>()].try_into().unwrap());
+                match state >> 60 {
+                    0 => specials[usize::try_from(state >> 56).unwrap() % specials.len()],
+                    // Clearing the high exponent bits gives tiny operands, which are often close or subnormal.
+                    1..=4 => $float::from_bits(bits & !(0b1111 << ($bits::BITS - 5))),
+                    _ => $float::from_bits(bits),
+                }
+            };
+            let same = |soft: $float, host: $float| {
+                if host.is_nan() {
+                    soft.is_nan() && soft.to_bits() & (1 << ($float::MANTISSA_DIGITS - 2)) != 0
+                } else {
+                    soft.to_bits() == host.to_bits()
+                }
+            };
+            for _ in 0..200_000 {
+                let (a, b) = (random(), random());
+                let binary: [(&str, $float, $float); 4] = [
+                    ("add", FallbackFloat::add(a, b), a + b),
+                    ("sub", FallbackFloat::sub(a, b), a - b),
+                    ("mul", FallbackFloat::mul(a, b), a * b),
+                    ("div", FallbackFloat::div(a, b), a / b),
+                ];
+                for (name, soft, host) in binary {
+                    assert!(same(soft, host), "{name}({a:e}, {b:e}) was {soft:e}, not {host:e}");
+                }
+                let unary: [(&str, $float, $float); 6] = [
+                    ("sqrt", FallbackFloat::sqrt(a), a.sqrt()),
+                    ("floor", FallbackFloat::floor(a), a.floor()),
+                    ("ceil", FallbackFloat::ceil(a), a.ceil()),
+                    ("round_ties_even", FallbackFloat::round_ties_even(a), a.round_ties_even()),
+                    ("trunc", FallbackFloat::trunc(a), a.trunc()),
+                    ("fract", FallbackFloat::fract(a), a.fract()),
+                ];
+                for (name, soft, host) in unary {
+                    assert!(same(soft, host), "{name}({a:e}) was {soft:e}, not {host:e}");
+                }
+                // The host may return either zero when they are compared.
+                if !(a == 0.0 && b == 0.0) {
+                    assert!(same(FallbackFloat::max(a, b), a.max(b)), "max({a:e}, {b:e})");
+                    assert!(same(FallbackFloat::min(a, b), a.min(b)), "min({a:e}, {b:e})");
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn f32_matches_host() {
+        check_against_host!(f32, u32);
+    }
+
+    #[test]
+    fn f64_matches_host() {
+        check_against_host!(f64, u64);
+    }
+
+    #[test]
+    fn unspecified_results_are_fixed() {
+        let nan = f32::from_bits(0xffa0_0001);
+        assert_eq!(
+            FallbackFloat::add(1.0, nan).to_bits(),
+            0xffe0_0001,
+            "a NaN operand should be made quiet and kept"
+        );
+        assert_eq!(
+            FallbackFloat::sub(f32::INFINITY, f32::INFINITY).to_bits(),
+            f32::NAN.to_bits(),
+            "invalid operations should return the positive quiet NaN"
+        );
+        assert_eq!(
+            FallbackFloat::max(-0.0_f32, 0.0).to_bits(),
+            0.0_f32.to_bits(),
+            "the larger zero should be positive"
+        );
+        assert_eq!(
+            FallbackFloat::min(0.0_f32, -0.0).to_bits(),
+            (-0.0_f32).to_bits(),
+            "the smaller zero should be negative"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 // Copyright 2025 the Fearless_SIMD Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::mem::MaybeUninit;
+use core::ptr;
+
 #[derive(Clone, Copy, Debug)]
 #[repr(C, align(16))]
 #[expect(
@@ -63,3 +66,150 @@ pub(crate) fn cross_block_slide_blocks_at<const N: usize, Block: Copy>(
     let hi_block = if hi_idx < N { a[hi_idx] } else { b[hi_idx - N] };
     [lo_block, hi_block]
 }
+
+/// A byte shuffle index which zeroes the destination lane on every level.
+///
+/// `pshufb` zeroes lanes whose index has the high bit set, and `tbl` and `i8x16.swizzle` zero lanes whose index is
+/// out of range. Adding an offset of up to 8 to this keeps both properties.
+const ZERO_LANE: u8 = 0x80;
+
+/// For each 8-lane mask, the indices of its set lanes followed by [`ZERO_LANE`], as little-endian bytes.
+static COMPRESS_BYTES_TABLE: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut mask = 0;
+    while mask < 256 {
+        let mut indices = [ZERO_LANE; 8];
+        let mut count = 0;
+        let mut lane: u8 = 0;
+        while lane < 8 {
+            if mask & (1 << lane) != 0 {
+                indices[count] = lane;
+                count += 1;
+            }
+            lane += 1;
+        }
+        table[mask] = u64::from_le_bytes(indices);
+        mask += 1;
+    }
+    table
+};
+
+/// For each 8-lane mask, the index of the source lane for each of its set lanes, with [`ZERO_LANE`] for the others, as
+/// little-endian bytes.
+static EXPAND_BYTES_TABLE: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut mask = 0;
+    while mask < 256 {
+        let mut indices = [ZERO_LANE; 8];
+        let mut count = 0;
+        let mut lane = 0;
+        while lane < 8 {
+            if mask & (1 << lane) != 0 {
+                indices[lane] = count;
+                count += 1;
+            }
+            lane += 1;
+        }
+        table[mask] = u64::from_le_bytes(indices);
+        mask += 1;
+    }
+    table
+};
+
+/// For each number of bytes kept from the lower half of a vector, the indices which keep those bytes, then move the
+/// compressed upper half of the vector down to follow them.
+static COMPRESS_BYTES_MERGE: [[u8; 16]; 9] = {
+    let mut table = [[ZERO_LANE; 16]; 9];
+    let mut count: u8 = 0;
+    while count <= 8 {
+        let mut lane: u8 = 0;
+        while lane < 16 {
+            let index = if lane < count { lane } else { lane - count + 8 };
+            if index < 16 {
+                table[count as usize][lane as usize] = index;
+            }
+            lane += 1;
+        }
+        count += 1;
+    }
+    table
+};
+
+/// Adds `offset` to every byte of `indices`, which must not carry between bytes.
+#[inline(always)]
+fn offset_indices(indices: u64, offset: u32) -> u64 {
+    indices + u64::from(offset) * 0x0101_0101_0101_0101
+}
+
+/// The two byte shuffles which implement `compress_bytes` for a 16-byte vector, given the bitmask of lanes to keep.
+///
+/// The first shuffle compresses each 8-byte half separately, and the second moves the upper half down to follow the
+/// bytes kept from the lower half.
+#[inline(always)]
+#[allow(clippy::allow_attributes, reason = "Only needed in some cfgs.")]
+#[allow(dead_code, reason = "Only used in some cfgs.")]
+pub(crate) fn compress_bytes_indices(bits: u64) -> ([u8; 16], [u8; 16]) {
+    let lo = (bits & 0xff) as u8;
+    let hi = ((bits >> 8) & 0xff) as u8;
+    let lo_indices = COMPRESS_BYTES_TABLE[usize::from(lo)];
+    let hi_indices = offset_indices(COMPRESS_BYTES_TABLE[usize::from(hi)], 8);
+    let shuffle = u128::from(lo_indices) | (u128::from(hi_indices) << 64);
+    (
+        shuffle.to_le_bytes(),
+        COMPRESS_BYTES_MERGE[lo.count_ones() as usize],
+    )
+}
+
+/// The byte shuffle which implements `expand_bytes` for a 16-byte vector, given the bitmask of lanes to fill.
+#[inline(always)]
+#[allow(clippy::allow_attributes, reason = "Only needed in some cfgs.")]
+#[allow(dead_code, reason = "Only used in some cfgs.")]
+pub(crate) fn expand_bytes_indices(bits: u64) -> [u8; 16] {
+    let lo = (bits & 0xff) as u8;
+    let hi = ((bits >> 8) & 0xff) as u8;
+    let lo_indices = EXPAND_BYTES_TABLE[usize::from(lo)];
+    // The upper half takes its bytes from after the ones used by the lower half.
+    let hi_indices = offset_indices(EXPAND_BYTES_TABLE[usize::from(hi)], lo.count_ones());
+    (u128::from(lo_indices) | (u128::from(hi_indices) << 64)).to_le_bytes()
+}
+
+/// Multiplies two elements of GF(2^8), reducing by x^8 + x^4 + x^3 + x + 1 like GFNI.
+const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1B } else { 0 };
+        b >>= 1;
+    }
+    product
+}
+
+/// The byte shuffles which implement `gf_mul_const` for an `N`-byte vector: the products of `factor` with each
+/// value of the low nibble of a byte, and with each value of its high nibble, repeated for every 16-byte block.
+pub(crate) const fn gf_mul_nibble_tables<const N: usize>(factor: u8) -> ([u8; N], [u8; N]) {
+    let mut low = [0; N];
+    let mut high = [0; N];
+    let mut i = 0;
+    let mut nibble: u8 = 0;
+    while i < N {
+        low[i] = gf_mul(factor, nibble);
+        high[i] = gf_mul(factor, nibble << 4);
+        i += 1;
+        nibble = (nibble + 1) & 0x0F;
+    }
+    (low, high)
+}
+
+/// Initializes every element of `dest` from `values`, and returns it as an initialized slice. This is the shared
+/// implementation of `write_to_uninit` for all vector types.
+#[inline(always)]
+pub(crate) fn write_uninit_array<T: Copy, const N: usize>(
+    dest: &mut [MaybeUninit<T>; N],
+    values: [T; N],
+) -> &mut [T] {
+    *dest = values.map(MaybeUninit::new);
+    // SAFETY: every element of `dest` was initialized above, and `MaybeUninit<T>` has the same layout as `T`.
+    unsafe { &mut *(ptr::from_mut(dest).cast::<[T; N]>()) }
+}
@@ -0,0 +1,225 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers for comparing floating point results in tests.
+//!
+//! Floating point operations can legitimately give slightly different results on different
+//! levels, for example because some fuse a multiply and an add and others round in between, or
+//! because an approximation uses a different instruction sequence. Comparing with
+//! [`assert_eq!`] is then too strict, and a fixed absolute tolerance is meaningless for values
+//! of very different magnitudes. [`assert_ulps_eq!`](crate::assert_ulps_eq) instead allows each
+//! lane to differ by a number of [units in the last place](https://en.wikipedia.org/wiki/Unit_in_the_last_place),
+//! which scales with the magnitude of the values.
+
+use core::fmt;
+
+use crate::{Simd, SimdBase, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, seal::Seal};
+
+/// A floating point scalar type, which can measure its distance to another value in ULPs.
+///
+/// This is implemented for [`f32`] and [`f64`].
+pub trait Ulps: Copy + fmt::Debug + Seal {
+    /// The number of representable values between `self` and `other`, or `None` if exactly one
+    /// of them is NaN.
+    ///
+    /// Two NaNs are the same value, regardless of their sign or payload, as are `0.0` and `-0.0`.
+    /// The largest finite value is one ULP from infinity.
+    ///
+    /// ```rust
+    /// use fearless_simd::testing::Ulps;
+    ///
+    /// assert_eq!(1.0_f32.ulps_diff(1.0 + f32::EPSILON), Some(1));
+    /// assert_eq!(f32::MAX.ulps_diff(f32::INFINITY), Some(1));
+    /// assert_eq!((-0.0_f32).ulps_diff(0.0), Some(0));
+    /// assert_eq!(f32::NAN.ulps_diff(-f32::NAN), Some(0));
+    /// assert_eq!(f32::NAN.ulps_diff(1.0), None);
+    /// ```
+    fn ulps_diff(self, other: Self) -> Option<u64>;
+}
+
+impl Ulps for f32 {
+    #[inline]
+    fn ulps_diff(self, other: Self) -> Option<u64> {
+        // Map the bits to integers with the same order as the floats, where both zeros are 0.
+        fn ordered(x: f32) -> i64 {
+            let magnitude = i64::from(x.to_bits() & 0x7fff_ffff);
+            if x.is_sign_negative() {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Some(0),
+            (false, false) => Some(ordered(self).abs_diff(ordered(other))),
+            _ => None,
+        }
+    }
+}
+
+impl Ulps for f64 {
+    #[inline]
+    fn ulps_diff(self, other: Self) -> Option<u64> {
+        // As for `f32`. The magnitude of infinity is less than 2^63, so the distance fits in a `u64`.
+        fn ordered(x: f64) -> i64 {
+            let magnitude = (x.to_bits() & 0x7fff_ffff_ffff_ffff).cast_signed();
+            if x.is_sign_negative() {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Some(0),
+            (false, false) => Some(ordered(self).abs_diff(ordered(other))),
+            _ => None,
+        }
+    }
+}
+
+/// A floating point value with one or more lanes, which can be compared with
+/// [`assert_ulps_eq!`](crate::assert_ulps_eq).
+///
+/// This is implemented for [`f32`] and [`f64`] scalars, arrays and slices of them, and the
+/// floating point vector types.
+pub trait FloatLanes {
+    /// The type of each lane.
+    type Lane: Ulps;
+
+    /// The lanes of this value.
+    fn float_lanes(&self) -> &[Self::Lane];
+}
+
+impl<T: Ulps> FloatLanes for T {
+    type Lane = T;
+
+    #[inline]
+    fn float_lanes(&self) -> &[T] {
+        core::slice::from_ref(self)
+    }
+}
+
+impl<T: Ulps, const N: usize> FloatLanes for [T; N] {
+    type Lane = T;
+
+    #[inline]
+    fn float_lanes(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Ulps> FloatLanes for [T] {
+    type Lane = T;
+
+    #[inline]
+    fn float_lanes(&self) -> &[T] {
+        self
+    }
+}
+
+macro_rules! impl_float_lanes {
+    ($($ty:ident: $lane:ty),* $(,)?) => {
+        $(
+            impl<S: Simd> FloatLanes for $ty<S> {
+                type Lane = $lane;
+
+                #[inline]
+                fn float_lanes(&self) -> &[$lane] {
+                    self.as_slice()
+                }
+            }
+        )*
+    };
+}
+
+impl_float_lanes!(
+    f32x4: f32, f32x8: f32, f32x16: f32, f64x2: f64, f64x4: f64, f64x8: f64,
+);
+
+/// Implementation detail of [`assert_ulps_eq!`](crate::assert_ulps_eq); this is not public API.
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_ulps_eq<T, A, B>(
+    left: &A,
+    right: &B,
+    max_ulps: u64,
+    message: Option<fmt::Arguments<'_>>,
+) where
+    T: Ulps,
+    A: FloatLanes<Lane = T> + ?Sized,
+    B: FloatLanes<Lane = T> + ?Sized,
+{
+    let (left, right) = (left.float_lanes(), right.float_lanes());
+    let message = Message(message);
+    assert!(
+        left.len() == right.len(),
+        "assertion `left ≈ right` failed{message}\n left has {} lanes, but right has {}",
+        left.len(),
+        right.len(),
+    );
+    for (lane, (&l, &r)) in left.iter().zip(right).enumerate() {
+        match l.ulps_diff(r) {
+            Some(diff) if diff <= max_ulps => {}
+            Some(diff) => panic!(
+                "assertion `left ≈ right` failed{message}\n lane {lane} differs by {diff} ULPs, more than {max_ulps}: {l:?} and {r:?}\n  left: {left:?}\n right: {right:?}"
+            ),
+            None => panic!(
+                "assertion `left ≈ right` failed{message}\n lane {lane} is NaN on only one side: {l:?} and {r:?}\n  left: {left:?}\n right: {right:?}"
+            ),
+        }
+    }
+}
+
+/// The custom message of a failed assertion, if any, formatted like in [`assert_eq!`].
+struct Message<'a>(Option<fmt::Arguments<'a>>);
+
+impl fmt::Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(message) => write!(f, ": {message}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ulps;
+    use crate::{Fallback, SimdBase, f64x2};
+
+    #[test]
+    fn ulps_diff_crosses_zero() {
+        let tiny = f32::from_bits(1);
+        assert_eq!(
+            tiny.ulps_diff(-tiny),
+            Some(2),
+            "zero should be counted once"
+        );
+        assert_eq!(
+            f64::NEG_INFINITY.ulps_diff(f64::INFINITY),
+            Some(2 * f64::INFINITY.to_bits()),
+            "the largest distance should fit"
+        );
+    }
+
+    #[test]
+    fn assert_ulps_eq_accepts_mixed_types() {
+        let simd = Fallback::new();
+        let v = f64x2::from_slice(simd, &[1.0, -0.0]);
+        crate::assert_ulps_eq!(v, [1.0, 0.0], 0);
+        crate::assert_ulps_eq!(v, *v.as_slice(), 0, "vectors and slices should compare");
+    }
+
+    #[test]
+    #[should_panic(expected = "lane 1 differs by 2 ULPs, more than 1")]
+    fn assert_ulps_eq_reports_lane() {
+        let next = |x: f32| f32::from_bits(x.to_bits() + 1);
+        crate::assert_ulps_eq!([1.0_f32, 2.0], [1.0, next(next(2.0))], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "lane 0 is NaN on only one side")]
+    fn assert_ulps_eq_rejects_single_nan() {
+        crate::assert_ulps_eq!(f32::NAN, 0.0_f32, u64::MAX);
+    }
+}
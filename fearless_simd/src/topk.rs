@@ -0,0 +1,240 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Partial selection of the largest or smallest few elements, such as the best hypotheses of a beam search or the
+//! shortlist of a nearest-neighbor search.
+//!
+//! Everything here is built from one step of a sorting network, a compare-exchange, which orders two candidates in
+//! every lane at once. Candidates are ordered by value, with ties going to the lower index, so the results are the
+//! same on every level. [`top2`] and [`top4`] select among the lanes of one vector, by merging the candidates of
+//! pairs of lanes until every lane has the best of all of them. [`largest`] and [`smallest`] keep a sorted list of
+//! candidates in each lane while scanning a slice, and only merge the lanes at the end.
+//!
+//! ```rust
+//! use fearless_simd::{Level, dispatch, topk};
+//!
+//! let scores = [0.1, 0.7, 0.2, 0.9, 0.4, 0.7, 0.0, 0.3, 0.5];
+//! let (best, nearest) = dispatch!(Level::new(), simd => {
+//!     (topk::largest::<_, 3>(simd, &scores), topk::smallest::<_, 2>(simd, &scores))
+//! });
+//! // The tie between the two scores of 0.7 goes to the lower index.
+//! assert_eq!(best, [Some(3), Some(1), Some(5)]);
+//! assert_eq!(nearest, [Some(6), Some(0)]);
+//! ```
+
+use crate::slice::load_tail;
+use crate::{Select, Simd, SimdBase, SimdFloat, SimdInt};
+
+/// Returns the lanes of `v` with the largest and the second largest values.
+///
+/// Ties go to the lower lane. NaN lanes rank below every other lane, so they are only returned if there are fewer
+/// than 2 other lanes.
+#[inline(always)]
+pub fn top2<S: Simd>(simd: S, v: S::f32s) -> [usize; 2] {
+    top_lanes(simd, v)
+}
+
+/// Returns the lanes of `v` with the 4 largest values, from the largest.
+///
+/// Ties go to the lower lane. NaN lanes rank below every other lane, so they are only returned if there are fewer
+/// than 4 other lanes.
+#[inline(always)]
+pub fn top4<S: Simd>(simd: S, v: S::f32s) -> [usize; 4] {
+    top_lanes(simd, v)
+}
+
+/// Returns the indices of the `K` largest elements of `values`, from the largest.
+///
+/// Ties go to the lower index. NaN elements are ignored, and if there are fewer than `K` other elements, the
+/// remaining indices are `None`. `K` must be from 1 to 16.
+///
+/// Each lane keeps a sorted list of its `K` best elements, and every vector is inserted into these lists with `K`
+/// compare-exchanges. The lists of the lanes are merged once, at the end, so the cost per element grows with `K`
+/// but doesn't depend on the length of `values`.
+#[inline(always)]
+pub fn largest<S: Simd, const K: usize>(simd: S, values: &[f32]) -> [Option<usize>; K] {
+    select_by_key(simd, values, |v| v)
+}
+
+/// Returns the indices of the `K` smallest elements of `values`, from the smallest.
+///
+/// This is [`largest`] with the order reversed: ties go to the lower index, NaN elements are ignored, and `K` must
+/// be from 1 to 16.
+#[inline(always)]
+pub fn smallest<S: Simd, const K: usize>(simd: S, values: &[f32]) -> [Option<usize>; K] {
+    // Negation preserves NaN and equality, so the smallest elements are the largest negated ones.
+    select_by_key(simd, values, |v| -v)
+}
+
+/// A candidate in each lane: a value and the index it came from.
+#[derive(Clone, Copy)]
+struct Ranked<S: Simd> {
+    values: S::f32s,
+    indices: S::u32s,
+}
+
+impl<S: Simd> Ranked<S> {
+    /// A placeholder which ranks below every element, including NaN.
+    #[inline(always)]
+    fn empty(simd: S) -> Self {
+        Self {
+            values: S::f32s::splat(simd, f32::NAN),
+            indices: S::u32s::splat(simd, u32::MAX),
+        }
+    }
+
+    #[inline(always)]
+    fn rotate_lanes<const OFFSET: usize>(self) -> Self {
+        Self {
+            values: self.values.rotate_elements_left::<OFFSET>(),
+            indices: self.indices.rotate_elements_left::<OFFSET>(),
+        }
+    }
+}
+
+/// Orders `a` and `b` in each lane, returning the one which ranks higher first.
+///
+/// A value ranks higher if it's larger, or if it's equal and has a lower index. NaN ranks below every other value.
+#[inline(always)]
+fn compare_exchange<S: Simd>(a: Ranked<S>, b: Ranked<S>) -> (Ranked<S>, Ranked<S>) {
+    let a_nan = !a.values.simd_eq(a.values);
+    let b_nan = !b.values.simd_eq(b.values);
+    let equal = a.values.simd_eq(b.values) | (a_nan & b_nan);
+    let a_first =
+        a.values.simd_gt(b.values) | (b_nan & !a_nan) | (equal & a.indices.simd_lt(b.indices));
+    (
+        Ranked {
+            values: a_first.select(a.values, b.values),
+            indices: a_first.select(a.indices, b.indices),
+        },
+        Ranked {
+            values: a_first.select(b.values, a.values),
+            indices: a_first.select(b.indices, a.indices),
+        },
+    )
+}
+
+/// Inserts `candidate` into the sorted list of each lane, dropping the lowest ranked candidate.
+#[inline(always)]
+fn insert<S: Simd, const K: usize>(list: &mut [Ranked<S>; K], candidate: Ranked<S>) {
+    let mut carry = candidate;
+    for entry in list {
+        (*entry, carry) = compare_exchange(*entry, carry);
+    }
+}
+
+/// Merges two sorted lists of distinct candidates in each lane, keeping the `K` which rank highest, in order.
+#[inline(always)]
+fn merge<S: Simd, const K: usize>(a: [Ranked<S>; K], b: [Ranked<S>; K]) -> [Ranked<S>; K] {
+    // Pairing the best of one list with the worst of the other keeps exactly the `K` highest of both, but not in
+    // order, so they are then sorted by an insertion network.
+    let mut merged: [Ranked<S>; K] =
+        core::array::from_fn(|i| compare_exchange(a[i], b[K - 1 - i]).0);
+    for i in 1..K {
+        for j in (0..i).rev() {
+            (merged[j], merged[j + 1]) = compare_exchange(merged[j], merged[j + 1]);
+        }
+    }
+    merged
+}
+
+/// Merges the lists of all lanes, so that every lane has the sorted list of the `K` best candidates of the vector.
+#[inline(always)]
+fn merge_lanes<S: Simd, const K: usize>(list: [Ranked<S>; K]) -> [Ranked<S>; K] {
+    const { assert!(S::f32s::N <= 16, "vectors have at most 16 lanes of 32 bits") };
+    // After each step, every lane has the best candidates of twice as many lanes.
+    let mut list = list;
+    if S::f32s::N > 8 {
+        list = merge(list, list.map(Ranked::rotate_lanes::<8>));
+    }
+    if S::f32s::N > 4 {
+        list = merge(list, list.map(Ranked::rotate_lanes::<4>));
+    }
+    if S::f32s::N > 2 {
+        list = merge(list, list.map(Ranked::rotate_lanes::<2>));
+    }
+    merge(list, list.map(Ranked::rotate_lanes::<1>))
+}
+
+/// The shared implementation of [`top2`] and [`top4`].
+#[inline(always)]
+fn top_lanes<S: Simd, const K: usize>(simd: S, v: S::f32s) -> [usize; K] {
+    const { assert!(K <= S::f32s::N, "a vector has at least 4 lanes") };
+    let mut list = [Ranked::empty(simd); K];
+    list[0] = Ranked {
+        values: v,
+        indices: S::u32s::from_fn(simd, |i| u32::try_from(i).unwrap()),
+    };
+    merge_lanes(list).map(|entry| usize::try_from(entry.indices[0]).unwrap())
+}
+
+/// The shared implementation of [`largest`] and [`smallest`], which selects the largest `key(v)`.
+#[inline(always)]
+fn select_by_key<S: Simd, const K: usize>(
+    simd: S,
+    values: &[f32],
+    key: impl Fn(S::f32s) -> S::f32s,
+) -> [Option<usize>; K] {
+    const {
+        assert!(
+            K >= 1 && K <= 16,
+            "between 1 and 16 elements can be selected"
+        );
+    };
+    let lanes = S::f32s::N;
+    // The indices are stored in `u32` lanes, so very long slices are selected from in segments. They are kept below
+    // `u32::MAX`, which is the index of the placeholders.
+    let segment_len = usize::try_from(u32::MAX).unwrap_or(usize::MAX) / lanes * lanes;
+    let mut result: [Option<(f32, usize)>; K] = [None; K];
+    for (segment_idx, segment) in values.chunks(segment_len).enumerate() {
+        let mut list = [Ranked::empty(simd); K];
+        let mut indices = S::u32s::from_fn(simd, |i| u32::try_from(i).unwrap());
+        let step = u32::try_from(lanes).unwrap();
+        let mut chunks = segment.chunks_exact(lanes);
+        for chunk in &mut chunks {
+            let values = key(S::f32s::from_slice(simd, chunk));
+            insert(&mut list, Ranked { values, indices });
+            indices += step;
+        }
+        let tail = chunks.remainder();
+        if !tail.is_empty() {
+            // NaN is ignored, so padding the tail with it doesn't change the result.
+            let values = key(load_tail(simd, tail, f32::NAN));
+            insert(&mut list, Ranked { values, indices });
+        }
+
+        let segment_start = segment_idx * segment_len;
+        let found = merge_lanes(list).map(|entry| {
+            let value = entry.values[0];
+            (!value.is_nan()).then(|| {
+                (
+                    value,
+                    segment_start + usize::try_from(entry.indices[0]).unwrap(),
+                )
+            })
+        });
+        result = merge_found(result, found);
+    }
+    result.map(|found| found.map(|(_, index)| index))
+}
+
+/// Merges the sorted candidates of an earlier and a later segment, keeping the `K` best.
+#[inline(always)]
+fn merge_found<const K: usize>(
+    earlier: [Option<(f32, usize)>; K],
+    later: [Option<(f32, usize)>; K],
+) -> [Option<(f32, usize)>; K] {
+    let (mut i, mut j) = (0, 0);
+    core::array::from_fn(|_| {
+        let a = earlier.get(i).copied().flatten();
+        let b = later.get(j).copied().flatten();
+        // Ties go to the earlier segment, which has the lower indices.
+        if b.is_some_and(|(b, _)| a.is_none_or(|(a, _)| b > a)) {
+            j += 1;
+            b
+        } else {
+            i += 1;
+            a
+        }
+    })
+}
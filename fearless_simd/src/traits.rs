@@ -16,6 +16,15 @@ pub trait Select<T: Seal>: Seal {
     /// conversions, false is encoded as all zeroes (integer value 0) and true is encoded as all ones (integer value -1).
     /// If a mask is constructed from any other integer bit pattern, the result of this operation is unspecified.
     fn select(self, if_true: T, if_false: T) -> T;
+
+    /// Like [`select`](Self::select), but using WebAssembly's relaxed lane select when the `relaxed-simd` feature is
+    /// enabled.
+    ///
+    /// For masks whose lanes are all zeroes or all ones, which includes every mask produced by a comparison, this is
+    /// the same as [`select`](Self::select). For other masks, the relaxed instruction may select by the top bit of
+    /// each lane, or bit by bit, depending on the host, so unlike `select`, a level doesn't give the same result on
+    /// every host. This is what the [`Fast`](crate::determinism::Fast) tier uses.
+    fn select_relaxed(self, if_true: T, if_false: T) -> T;
 }
 
 // Same as pulp
@@ -35,16 +44,65 @@ impl<R, F: FnOnce(Level) -> R> WithSimd for F {
 }
 
 /// Conversion of SIMD types to and from raw bytes.
+///
+/// The bytes of lane 0 come first, followed by those of lane 1, and so on. Within each lane,
+/// [`to_bytes`](Bytes::to_bytes) and [`from_bytes`](Bytes::from_bytes) use the target's native
+/// byte order, like [`u32::to_ne_bytes`], as they're the same as reinterpreting the vector's
+/// memory. Every target with a SIMD level is little-endian, but the [`Fallback`](crate::Fallback)
+/// level also runs on big-endian targets such as `s390x`. Code which stores bytes or sends them
+/// to another machine should use [`to_le_bytes`](Bytes::to_le_bytes) and
+/// [`from_le_bytes`](Bytes::from_le_bytes), which give the same bytes on every target.
 pub trait Bytes: Sized + Seal {
     type Bytes;
 
-    /// Convert this type to an array of bytes.
+    /// Convert this type to an array of bytes, in native byte order.
     fn to_bytes(self) -> Self::Bytes;
 
-    /// Create an instance of this type from an array of bytes.
+    /// Create an instance of this type from an array of bytes, in native byte order.
     fn from_bytes(value: Self::Bytes) -> Self;
 
+    /// Convert this type to an array of bytes, with the bytes of each lane in little-endian order.
+    ///
+    /// This is the same as [`to_bytes`](Bytes::to_bytes) on little-endian targets, and swaps the
+    /// bytes of each lane on big-endian targets.
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Create an instance of this type from an array of bytes, with the bytes of each lane in
+    /// little-endian order.
+    ///
+    /// This is the same as [`from_bytes`](Bytes::from_bytes) on little-endian targets, and swaps
+    /// the bytes of each lane on big-endian targets.
+    fn from_le_bytes(value: Self::Bytes) -> Self;
+
     /// Bitcast directly from this type to another one of the same size.
+    ///
+    /// This is implemented for every pair of vector types with the same number of bits,
+    /// regardless of their element types or lane counts, such as `f32x8` and `u16x16`. The
+    /// native-width types of a [`Simd`](crate::Simd) level, such as `S::f32s` and `S::u8s`, are
+    /// also all the same size. It's a free operation, which reinterprets the vector's register
+    /// without any instructions, and is a safe replacement for [`core::mem::transmute`] between
+    /// vector types.
+    ///
+    /// If the lanes of `U` have a different width, the result depends on the target's byte order.
+    ///
+    /// Masks aren't included, as their representation depends on the level; use
+    /// [`SimdMask::to_int`](crate::SimdMask::to_int) to get a vector whose lanes are all ones or
+    /// all zeros, and compare an integer vector to produce a mask.
+    ///
+    /// ```rust
+    /// use fearless_simd::{Fallback, f32x4, prelude::*, u16x8};
+    ///
+    /// let simd = Fallback::new();
+    /// let a = f32x4::splat(simd, -1.0);
+    /// let bits: u16x8<_> = a.bitcast();
+    /// assert_eq!(bits.bitcast::<f32x4<_>>().as_slice(), a.as_slice());
+    ///
+    /// #[inline(always)]
+    /// fn sign_bits<S: Simd>(simd: S, x: &[f32]) -> S::u32s {
+    ///     S::f32s::from_slice(simd, x).bitcast::<S::u32s>() >> 31
+    /// }
+    /// assert_eq!(sign_bits(simd, &[-1.0; 4])[0], 1);
+    /// ```
     fn bitcast<U: Bytes<Bytes = Self::Bytes>>(self) -> U {
         U::from_bytes(self.to_bytes())
     }
@@ -161,6 +219,22 @@ pub trait SimdCvtFloat<T: Seal>: Seal {
     fn float_from(x: T) -> Self;
 }
 
+/// Zero-extension of SIMD vectors into longer vectors with the same element type.
+///
+/// This is implemented on all vectors 256 bits and higher, for every narrower vector with the same element type.
+/// The lanes of the source vector are kept in place, and the remaining upper lanes are set to zero.
+pub trait SimdZeroExtend<T: Seal>: Seal {
+    fn zero_extend_from(x: T) -> Self;
+}
+
+/// Truncation of SIMD vectors into shorter vectors with the same element type.
+///
+/// This is implemented on all vectors 256 bits and lower, for every wider vector with the same element type.
+/// The lowest lanes of the source vector are kept, and the remaining upper lanes are discarded.
+pub trait SimdTruncateLanes<T: Seal>: Seal {
+    fn truncate_lanes_from(x: T) -> Self;
+}
+
 /// Concatenation of two SIMD vectors.
 ///
 /// This is implemented on all vectors 256 bits and lower, producing vectors of up to 512 bits.
@@ -171,6 +245,45 @@ pub trait SimdCombine<S: Simd>: SimdBase<S> + Seal {
     fn combine(self, rhs: impl SimdInto<Self, S>) -> Self::Combined;
 }
 
+/// Dot products of small vectors packed into the lanes of SIMD vectors.
+///
+/// This is implemented on all `f32` vectors, including [`Simd::f32s`], which treat their lanes
+/// as consecutive 2D, 3D or 4D vectors such as positions, normals or colors. A 3D vector takes up
+/// four lanes, with the last one ignored. Each product is written to every lane of its group, so
+/// it can be used directly to scale the group, for example to normalize it.
+///
+/// The products and sums are rounded in the same order on every level, so the results are the
+/// same everywhere.
+///
+/// ```rust
+/// use fearless_simd::{Fallback, f32x8, prelude::*};
+///
+/// let simd = Fallback::new();
+/// // Two 3D vectors, each padded to four lanes.
+/// let a = f32x8::from_slice(simd, &[1.0, 2.0, 3.0, 0.0, 3.0, 0.0, 4.0, 0.0]);
+/// let squared_lengths = a.dot3(a);
+/// assert_eq!(*squared_lengths, [14.0, 14.0, 14.0, 14.0, 25.0, 25.0, 25.0, 25.0]);
+/// let normalized = a / squared_lengths.sqrt();
+/// assert_eq!(normalized.as_slice()[4..7], [0.6, 0.0, 0.8]);
+/// ```
+pub trait SimdDot<S: Simd>: SimdBase<S> + Seal {
+    /// Compute the dot product of each pair of adjacent lanes.
+    ///
+    /// Both lanes of each pair in the result hold `a[0] * b[0] + a[1] * b[1]`.
+    fn dot2(self, rhs: impl SimdInto<Self, S>) -> Self;
+
+    /// Compute the dot product of the first three lanes of each group of four lanes.
+    ///
+    /// The fourth lane of each group is ignored in both vectors, even if it's NaN or infinite.
+    fn dot3(self, rhs: impl SimdInto<Self, S>) -> Self;
+
+    /// Compute the dot product of each group of four lanes.
+    ///
+    /// Every lane of each group in the result holds
+    /// `(a[0] * b[0] + a[1] * b[1]) + (a[2] * b[2] + a[3] * b[3])`.
+    fn dot4(self, rhs: impl SimdInto<Self, S>) -> Self;
+}
+
 /// Splitting of one SIMD vector into two.
 ///
 /// This is implemented on all vectors 256 bits and higher, producing vectors of down to 128 bits.
@@ -20,7 +20,7 @@ use core::mem::{align_of, size_of};
 
 use crate::support::{Aligned128, Aligned256, Aligned512};
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 use core::arch::aarch64::{
     float32x4_t, float32x4x2_t, float32x4x4_t, float64x2_t, float64x2x2_t, float64x2x4_t,
     int8x16_t, int8x16x2_t, int8x16x4_t, int16x8_t, int16x8x2_t, int16x8x4_t, int32x4_t,
@@ -149,7 +149,7 @@ const _: () = {
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 unsafe impl SimdPod for v128 {}
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 const _: () = {
     // SAFETY:
     // Compound types like float32x4x4_t are defined as #[repr(C)] tuples of basic types,
@@ -215,7 +215,7 @@ impl_aligned_simd_pod!(
     Aligned512<[v128; 4]>
 );
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 impl_aligned_simd_pod!(
     Aligned128<float32x4_t>,
     Aligned128<float64x2_t>,
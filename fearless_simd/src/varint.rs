@@ -0,0 +1,157 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Decoding variable-length integers (LEB128 varints), as used by Protocol Buffers and many columnar formats.
+//!
+//! A varint stores an unsigned integer 7 bits at a time, starting with the least significant, in the low bits of
+//! each byte. The high bit of each byte is set if another byte follows, so a varint ends at the first byte below
+//! `0x80`, and small values take a single byte.
+//!
+//! Finding where varints end is the same for every byte, so it vectorizes well: [`end_mask`] compares a whole block
+//! of bytes at once. [`decode_u32`] uses the positions of the ends to decode up to 16 varints from each 16 bytes of
+//! input, without a branch for each byte.
+//!
+//! ```rust
+//! use fearless_simd::varint::{decode_u32, end_mask};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! // 1, 300 and 150000, which take 1, 2 and 3 bytes.
+//! let input = [0x01, 0xac, 0x02, 0xf0, 0x93, 0x09];
+//! let mut values = [0; 4];
+//! let (ends, decoded) = dispatch!(Level::new(), simd => {
+//!     (end_mask(simd, &input), decode_u32(simd, &input, &mut values))
+//! });
+//! assert_eq!(ends, 0b100101);
+//! assert_eq!((decoded.bytes, decoded.values), (6, 3));
+//! assert_eq!(values, [1, 300, 150_000, 0]);
+//! ```
+
+use crate::slice::load_tail;
+use crate::{Select, Simd, SimdBase, SimdInt, SimdMask, mask8x16, u8x16, u8x64};
+
+/// The number of bytes which are decoded at once.
+const BLOCK: usize = 16;
+
+/// How much of the input [`decode_u32`] decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    /// The number of bytes of input which were consumed, which is the end of the last decoded varint.
+    pub bytes: usize,
+    /// The number of values which were decoded.
+    pub values: usize,
+}
+
+/// Return the bitmask of the bytes which end a varint, with bit `i` set if byte `i` is below `0x80`.
+///
+/// # Panics
+///
+/// Panics if `bytes` is longer than 64 bytes.
+#[inline(always)]
+pub fn end_mask<S: Simd>(simd: S, bytes: &[u8]) -> u64 {
+    assert!(
+        bytes.len() <= 64,
+        "`bytes` should be at most 64 bytes, not {}",
+        bytes.len()
+    );
+    // The padding continues a varint, so it never ends one.
+    let bytes: u8x64<S> = load_tail(simd, bytes, 0x80);
+    bytes.simd_lt(0x80).to_bitmask()
+}
+
+/// Decode varints from the start of `input` into `out`, and return how much was decoded.
+///
+/// Decoding stops when `out` is full, at the end of `input`, or before the first varint which doesn't fit in a
+/// `u32`: one which is longer than 5 bytes, or whose fifth byte has more than the 4 bits which are left. A varint
+/// which is cut off by the end of `input` isn't decoded, so a stream can be decoded in pieces by starting the next
+/// piece at [`Decoded::bytes`]. When decoding stops with both `out` and `input` left over, the next varint either
+/// doesn't fit in a `u32` or is cut off.
+#[inline(always)]
+pub fn decode_u32<S: Simd>(simd: S, input: &[u8], out: &mut [u32]) -> Decoded {
+    let mut decoded = Decoded {
+        bytes: 0,
+        values: 0,
+    };
+    while decoded.values < out.len() {
+        let rest = &input[decoded.bytes..];
+        let block = match rest.get(..BLOCK) {
+            Some(block) => u8x16::from_slice(simd, block),
+            None => load_tail(simd, rest, 0x80),
+        };
+        let room = out.len() - decoded.values;
+        let (values, count, bytes) = decode_block(simd, block, room);
+        let out = &mut out[decoded.values..][..count];
+        if count == BLOCK {
+            values.store_slice(out);
+        } else {
+            out.copy_from_slice(&values.as_slice()[..count]);
+        }
+        decoded.values += count;
+        decoded.bytes += bytes;
+        // The next block starts with the varint which stopped this one, so it can only decode nothing if that varint
+        // doesn't fit in a `u32` or is cut off.
+        if count == 0 {
+            break;
+        }
+    }
+    decoded
+}
+
+/// Decode up to `room` varints from a block which starts with a varint, returning the values, the number of values
+/// and the number of bytes which they took.
+///
+/// The bytes of each varint are gathered into five vectors, one for each of its possible bytes, with a lane for
+/// each varint. These are widened and combined at once.
+#[inline(always)]
+fn decode_block<S: Simd>(
+    simd: S,
+    block: u8x16<S>,
+    room: usize,
+) -> (crate::u32x16<S>, usize, usize) {
+    let continues = block.simd_ge(0x80);
+    let ends = !continues.to_bitmask() & 0xffff;
+    // Each varint starts after the end of the previous one, and only those with an end in the block are complete.
+    let starts = mask8x16::from_bitmask(simd, (ends << 1 | 1) & 0xffff);
+    let complete = usize::try_from(ends.count_ones()).unwrap();
+
+    let data = block & 0x7f;
+    let continues = continues.select(u8x16::splat(simd, 0xff), u8x16::splat(simd, 0));
+    // `runK` is set in the lanes where the varint continues for at least `K` more bytes.
+    let run1 = continues;
+    let run2 = run1 & continues.slide::<1>(0);
+    let run3 = run2 & continues.slide::<2>(0);
+    let run4 = run3 & continues.slide::<3>(0);
+    let run5 = run4 & continues.slide::<4>(0);
+    let gather = |bytes: u8x16<S>| simd.compress_bytes_u8x16(bytes, starts);
+    let byte0 = gather(data);
+    let byte1 = gather(data.slide::<1>(0) & run1);
+    let byte2 = gather(data.slide::<2>(0) & run2);
+    let byte3 = gather(data.slide::<3>(0) & run3);
+    let byte4 = gather(data.slide::<4>(0) & run4);
+
+    // A varint is too long for a `u32` if it continues past its fifth byte, or if that byte has more than 4 bits. The
+    // lanes of `run5` are 0 or `0xff`, so both are found with one comparison.
+    let too_long = (gather(run5) | byte4).simd_gt(0x0f);
+    let valid = usize::try_from(too_long.to_bitmask().trailing_zeros())
+        .unwrap()
+        .min(BLOCK);
+    let count = complete.min(room).min(valid);
+
+    let widen = |bytes: u8x16<S>| simd.widen_u16x16(simd.widen_u8x16(bytes));
+    let values = widen(byte0)
+        | widen(byte1) << 7
+        | widen(byte2) << 14
+        | widen(byte3) << 21
+        | widen(byte4) << 28;
+
+    // The end of the last decoded varint is the `count`th end.
+    let mut remaining = ends;
+    for _ in 1..count {
+        remaining &= remaining - 1;
+    }
+    let bytes = if count == 0 {
+        0
+    } else {
+        usize::try_from(remaining.trailing_zeros()).unwrap() + 1
+    };
+    (values, count, bytes)
+}
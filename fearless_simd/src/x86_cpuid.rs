@@ -0,0 +1,186 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Detection of x86 features with the `cpuid` and `xgetbv` instructions, for when the standard library isn't
+//! available.
+//!
+//! This follows the same rules as `is_x86_feature_detected!`, so that [`Level::try_detect`](crate::Level::try_detect)
+//! selects the same level with or without `std`. In particular, features which use the AVX or AVX-512 registers are
+//! only reported if the operating system (or whatever has set up the CPU) has enabled saving those registers, which
+//! is checked with `xgetbv`. The instructions are used directly, so this doesn't depend on the C runtime or the
+//! operating system.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{__cpuid_count, _xgetbv, CpuidResult};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{__cpuid_count, _xgetbv, CpuidResult};
+
+/// The registers of the `cpuid` leaves which report the features that [`Level`](crate::Level) uses.
+///
+/// The features which need support from the operating system are already cleared if it's missing.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Cpuid {
+    /// Leaf 1, `ecx`.
+    leaf1_ecx: u32,
+    /// Leaf 1, `edx`.
+    leaf1_edx: u32,
+    /// Leaf 7, sub-leaf 0, `ebx`.
+    leaf7_ebx: u32,
+    /// Leaf 7, sub-leaf 0, `ecx`.
+    leaf7_ecx: u32,
+    /// Leaf `0xD`, sub-leaf 1, `eax`.
+    leaf_d1_eax: u32,
+    /// Leaf `0x8000_0001`, `ecx`.
+    extended1_ecx: u32,
+}
+
+/// The bits of XCR0 which show that the SSE and AVX registers are saved on context switches.
+const XCR0_AVX: u64 = 0b110;
+
+/// The bits of XCR0 which show that the AVX-512 mask registers and the upper halves of the ZMM registers are saved on
+/// context switches.
+const XCR0_AVX512: u64 = 0b1110_0000;
+
+// Bits of leaf 1, `ecx`.
+const OSXSAVE: u32 = 1 << 27;
+const AVX: u32 = 1 << 28;
+const FMA: u32 = 1 << 12;
+const XSAVE: u32 = 1 << 26;
+const F16C: u32 = 1 << 29;
+
+// Bits of leaf 7, `ebx`.
+const AVX2: u32 = 1 << 5;
+
+// Bits of leaf 7, `ecx`.
+const VAES: u32 = 1 << 9;
+const VPCLMULQDQ: u32 = 1 << 10;
+
+/// The bits of leaf 7, `ebx`, which use the AVX-512 registers.
+const LEAF7_EBX_AVX512: u32 = (1 << 16) | (1 << 17) | (1 << 21) | (1 << 28) | (1 << 30) | (1 << 31);
+
+/// The bits of leaf 7, `ecx`, which use the AVX-512 registers.
+const LEAF7_ECX_AVX512: u32 = (1 << 1) | (1 << 6) | (1 << 11) | (1 << 12) | (1 << 14);
+
+impl Cpuid {
+    /// Reads the features of the current CPU.
+    pub(crate) fn read() -> Self {
+        // The `cpuid` instruction was added during the life of the 486, so 32-bit x86 has to check that it exists.
+        #[cfg(target_arch = "x86")]
+        if !core::arch::x86::has_cpuid() {
+            return Self::default();
+        }
+
+        let max_leaf = cpuid(0, 0).eax;
+        let leaf1 = cpuid(1, 0);
+        let mut features = Self {
+            leaf1_ecx: leaf1.ecx,
+            leaf1_edx: leaf1.edx,
+            ..Self::default()
+        };
+        if max_leaf >= 7 {
+            let leaf7 = cpuid(7, 0);
+            features.leaf7_ebx = leaf7.ebx;
+            features.leaf7_ecx = leaf7.ecx;
+        }
+        if max_leaf >= 0xD {
+            features.leaf_d1_eax = cpuid(0xD, 1).eax;
+        }
+        if cpuid(0x8000_0000, 0).eax >= 0x8000_0001 {
+            features.extended1_ecx = cpuid(0x8000_0001, 0).ecx;
+        }
+
+        let xcr0 = if features.leaf1_ecx & OSXSAVE != 0 {
+            // Safety: OSXSAVE is only set if the CPU supports `xsave`, and the operating system has enabled `xgetbv`.
+            unsafe { xcr0() }
+        } else {
+            0
+        };
+        if xcr0 & XCR0_AVX != XCR0_AVX {
+            // The standard library also treats the `xsave` family as unusable without AVX support.
+            features.leaf1_ecx &= !(AVX | FMA | XSAVE | F16C);
+            features.leaf7_ebx &= !AVX2;
+            features.leaf7_ecx &= !(VAES | VPCLMULQDQ);
+            features.leaf_d1_eax = 0;
+        }
+        if xcr0 & (XCR0_AVX | XCR0_AVX512) != XCR0_AVX | XCR0_AVX512 {
+            features.leaf7_ebx &= !LEAF7_EBX_AVX512;
+            features.leaf7_ecx &= !LEAF7_ECX_AVX512;
+        }
+        features
+    }
+
+    /// Whether the CPU has `feature`, which is named as in `is_x86_feature_detected!`.
+    ///
+    /// This only knows the features which [`Level`](crate::Level) uses, and returns `false` for any others.
+    pub(crate) fn has_feature(&self, feature: &str) -> bool {
+        let (register, bit) = match feature {
+            "fxsr" => (self.leaf1_edx, 24),
+            "sse" => (self.leaf1_edx, 25),
+            "sse2" => (self.leaf1_edx, 26),
+            "sse3" => (self.leaf1_ecx, 0),
+            "pclmulqdq" => (self.leaf1_ecx, 1),
+            "ssse3" => (self.leaf1_ecx, 9),
+            "fma" => (self.leaf1_ecx, 12),
+            "cmpxchg16b" => (self.leaf1_ecx, 13),
+            "sse4.1" => (self.leaf1_ecx, 19),
+            "sse4.2" => (self.leaf1_ecx, 20),
+            "movbe" => (self.leaf1_ecx, 22),
+            "popcnt" => (self.leaf1_ecx, 23),
+            "aes" => (self.leaf1_ecx, 25),
+            "xsave" => (self.leaf1_ecx, 26),
+            "avx" => (self.leaf1_ecx, 28),
+            "f16c" => (self.leaf1_ecx, 29),
+            "rdrand" => (self.leaf1_ecx, 30),
+            "bmi1" => (self.leaf7_ebx, 3),
+            "avx2" => (self.leaf7_ebx, 5),
+            "bmi2" => (self.leaf7_ebx, 8),
+            "avx512f" => (self.leaf7_ebx, 16),
+            "avx512dq" => (self.leaf7_ebx, 17),
+            "rdseed" => (self.leaf7_ebx, 18),
+            "adx" => (self.leaf7_ebx, 19),
+            "avx512ifma" => (self.leaf7_ebx, 21),
+            "avx512cd" => (self.leaf7_ebx, 28),
+            "sha" => (self.leaf7_ebx, 29),
+            "avx512bw" => (self.leaf7_ebx, 30),
+            "avx512vl" => (self.leaf7_ebx, 31),
+            "avx512vbmi" => (self.leaf7_ecx, 1),
+            "avx512vbmi2" => (self.leaf7_ecx, 6),
+            "gfni" => (self.leaf7_ecx, 8),
+            "vaes" => (self.leaf7_ecx, 9),
+            "vpclmulqdq" => (self.leaf7_ecx, 10),
+            "avx512vnni" => (self.leaf7_ecx, 11),
+            "avx512bitalg" => (self.leaf7_ecx, 12),
+            "avx512vpopcntdq" => (self.leaf7_ecx, 14),
+            "xsaveopt" => (self.leaf_d1_eax, 0),
+            "xsavec" => (self.leaf_d1_eax, 1),
+            "xsaves" => (self.leaf_d1_eax, 3),
+            "lzcnt" => (self.extended1_ecx, 5),
+            _ => return false,
+        };
+        register & (1 << bit) != 0
+    }
+}
+
+#[allow(
+    clippy::allow_attributes,
+    reason = "Only needed in some Rust versions."
+)]
+#[allow(
+    unused_unsafe,
+    reason = "`__cpuid_count` is unsafe in older Rust versions, even though `cpuid` always exists when this is called."
+)]
+fn cpuid(leaf: u32, sub_leaf: u32) -> CpuidResult {
+    // Safety: `cpuid` is available on every x86-64 CPU, and on 32-bit x86 this is only called after `has_cpuid`.
+    unsafe { __cpuid_count(leaf, sub_leaf) }
+}
+
+/// Reads the XCR0 register, which shows which registers the operating system saves on context switches.
+///
+/// # Safety
+///
+/// The CPU must report OSXSAVE.
+#[target_feature(enable = "xsave")]
+unsafe fn xcr0() -> u64 {
+    // Safety: The caller guarantees that `xgetbv` is available and enabled.
+    unsafe { _xgetbv(0) }
+}
@@ -0,0 +1,225 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conversion between 8-bit YUV (`Y'CbCr`) and RGBA, with the matrices of BT.601 and BT.709.
+//!
+//! Decoded video frames are YUV, with a brightness plane and two color-difference planes, and displays and image
+//! processing want RGB. The functions here convert between 4:4:4 planar YUV, where each plane has one sample for
+//! each pixel, and interleaved RGBA with 4 bytes per pixel. Frames with subsampled chroma (4:2:0 or 4:2:2) need
+//! their chroma planes to be upsampled to the size of the brightness plane first.
+//!
+//! YUV is in the limited ("video" or "studio") range of broadcast and most compressed video: Y' is from 16 to 235 and
+//! the color differences are from 16 to 240, centered on 128. Values outside the range are converted like any other,
+//! and the RGB results are clamped.
+//!
+//! The arithmetic is fixed point, with coefficients scaled by 2<sup>14</sup>. The samples are widened to 32 bits, the
+//! products are summed and rounded, and the results are narrowed with saturation, 16 pixels at a time. The results
+//! are the same on every level, and within 1 of the exact conversion.
+//!
+//! ```rust
+//! use fearless_simd::yuv::{YuvMatrix, rgba_to_yuv, yuv_to_rgba};
+//! use fearless_simd::{Level, dispatch};
+//!
+//! // Black, white and a mid gray, which have no color.
+//! let (y, u, v) = ([16, 235, 126], [128; 3], [128; 3]);
+//! let mut rgba = [0; 3 * 4];
+//! dispatch!(Level::new(), simd => yuv_to_rgba(simd, YuvMatrix::Bt601, &y, &u, &v, &mut rgba));
+//! assert_eq!(rgba, [0, 0, 0, 255, 255, 255, 255, 255, 128, 128, 128, 255]);
+//!
+//! // Pure red has the largest V.
+//! let (mut y, mut u, mut v) = ([0; 1], [0; 1], [0; 1]);
+//! let red = [255, 0, 0, 255];
+//! dispatch!(Level::new(), simd => rgba_to_yuv(simd, YuvMatrix::Bt601, &red, &mut y, &mut u, &mut v));
+//! assert_eq!((y, u, v), ([81], [90], [240]));
+//! ```
+
+use crate::{Bytes, Simd, SimdBase, SimdCombine, SimdInt, SimdSplit, i16x32, i32x16, u8x16, u8x64};
+
+/// The number of fractional bits of the coefficients.
+const SHIFT: u32 = 14;
+
+/// Half of the last place of the coefficients, which is added to round to the nearest integer.
+const ROUND: i32 = 1 << (SHIFT - 1);
+
+/// The number of pixels which are converted at once.
+const BLOCK: usize = 16;
+
+/// The matrix which relates RGB to YUV.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YuvMatrix {
+    /// ITU-R BT.601, for standard-definition video and JPEG-style content, with `Kr = 0.299` and `Kb = 0.114`.
+    Bt601,
+    /// ITU-R BT.709, for high-definition video, with `Kr = 0.2126` and `Kb = 0.0722`.
+    Bt709,
+}
+
+impl YuvMatrix {
+    /// The scale of Y', and the weights of the color differences in R, G and B: `[y, r_v, g_u, g_v, b_u]`.
+    fn to_rgb(self) -> [i32; 5] {
+        match self {
+            Self::Bt601 => [19077, 26149, 6419, 13320, 33050],
+            Self::Bt709 => [19077, 29372, 3494, 8731, 34610],
+        }
+    }
+
+    /// The weights of R, G and B in each of Y', U and V.
+    ///
+    /// The weights of U and V sum to zero, so that grays have no color.
+    fn to_yuv(self) -> [[i32; 3]; 3] {
+        match self {
+            Self::Bt601 => [
+                [4207, 8260, 1604],
+                [-2428, -4768, 7196],
+                [7196, -6026, -1170],
+            ],
+            Self::Bt709 => [
+                [2991, 10064, 1016],
+                [-1649, -5547, 7196],
+                [7196, -6536, -660],
+            ],
+        }
+    }
+}
+
+/// Convert planes of Y', U and V to interleaved RGBA, with an alpha of 255.
+///
+/// # Panics
+///
+/// Panics if the planes don't have the same length, or if `rgba` doesn't have 4 bytes for each pixel.
+#[inline(always)]
+pub fn yuv_to_rgba<S: Simd>(
+    simd: S,
+    matrix: YuvMatrix,
+    y: &[u8],
+    u: &[u8],
+    v: &[u8],
+    rgba: &mut [u8],
+) {
+    assert!(
+        u.len() == y.len() && v.len() == y.len(),
+        "the Y', U and V planes should have the same length"
+    );
+    assert_eq!(
+        rgba.len(),
+        4 * y.len(),
+        "`rgba` should have 4 bytes for each pixel"
+    );
+    let coeffs = matrix.to_rgb();
+    let blocks = y.chunks(BLOCK).zip(u.chunks(BLOCK)).zip(v.chunks(BLOCK));
+    for (((y, u), v), rgba) in blocks.zip(rgba.chunks_mut(4 * BLOCK)) {
+        if let Ok(rgba) = <&mut [u8; 4 * BLOCK]>::try_from(&mut *rgba) {
+            let [y, u, v] = [y, u, v].map(|plane| u8x16::from_slice(simd, plane));
+            simd.store_interleaved_128_u8x64(yuv_to_rgba_block(simd, coeffs, y, u, v), rgba);
+        } else {
+            // The last block is converted through a buffer, with its extra pixels left as zeros.
+            let [y, u, v] =
+                [y, u, v].map(|plane| u8x16::from_fn(simd, |i| plane.get(i).copied().unwrap_or(0)));
+            let mut buffer = [0; 4 * BLOCK];
+            simd.store_interleaved_128_u8x64(yuv_to_rgba_block(simd, coeffs, y, u, v), &mut buffer);
+            rgba.copy_from_slice(&buffer[..rgba.len()]);
+        }
+    }
+}
+
+/// Convert interleaved RGBA to planes of Y', U and V, ignoring the alpha.
+///
+/// # Panics
+///
+/// Panics if the planes don't have the same length, or if `rgba` doesn't have 4 bytes for each pixel.
+#[inline(always)]
+pub fn rgba_to_yuv<S: Simd>(
+    simd: S,
+    matrix: YuvMatrix,
+    rgba: &[u8],
+    y: &mut [u8],
+    u: &mut [u8],
+    v: &mut [u8],
+) {
+    assert!(
+        u.len() == y.len() && v.len() == y.len(),
+        "the Y', U and V planes should have the same length"
+    );
+    assert_eq!(
+        rgba.len(),
+        4 * y.len(),
+        "`rgba` should have 4 bytes for each pixel"
+    );
+    let coeffs = matrix.to_yuv();
+    let blocks = y
+        .chunks_mut(BLOCK)
+        .zip(u.chunks_mut(BLOCK))
+        .zip(v.chunks_mut(BLOCK));
+    for (((y, u), v), rgba) in blocks.zip(rgba.chunks(4 * BLOCK)) {
+        let pixels = if let Ok(rgba) = <&[u8; 4 * BLOCK]>::try_from(rgba) {
+            simd.load_interleaved_128_u8x64(rgba)
+        } else {
+            let mut buffer = [0; 4 * BLOCK];
+            buffer[..rgba.len()].copy_from_slice(rgba);
+            simd.load_interleaved_128_u8x64(&buffer)
+        };
+        let planes = rgba_to_yuv_block(simd, coeffs, pixels);
+        for (plane, out) in planes.into_iter().zip([y, u, v]) {
+            if out.len() == BLOCK {
+                plane.store_slice(out);
+            } else {
+                out.copy_from_slice(&plane.as_slice()[..out.len()]);
+            }
+        }
+    }
+}
+
+/// Convert 16 pixels of YUV to RGBA, with the channels of each pixel in one of the four 16-byte quarters.
+#[inline(always)]
+fn yuv_to_rgba_block<S: Simd>(
+    simd: S,
+    [cy, crv, cgu, cgv, cbu]: [i32; 5],
+    y: u8x16<S>,
+    u: u8x16<S>,
+    v: u8x16<S>,
+) -> u8x64<S> {
+    let y = (widen(simd, y) - 16) * cy + ROUND;
+    let u = widen(simd, u) - 128;
+    let v = widen(simd, v) - 128;
+    let r = narrow_saturate(simd, y + v * crv);
+    let g = narrow_saturate(simd, y - u * cgu - v * cgv);
+    let b = narrow_saturate(simd, y + u * cbu);
+    let a = u8x16::splat(simd, u8::MAX);
+    r.combine(g).combine(b.combine(a))
+}
+
+/// Convert 16 pixels of RGBA, with the channels in the four 16-byte quarters, to Y', U and V.
+#[inline(always)]
+fn rgba_to_yuv_block<S: Simd>(simd: S, coeffs: [[i32; 3]; 3], pixels: u8x64<S>) -> [u8x16<S>; 3] {
+    let (rg, ba) = pixels.split();
+    let (r, g) = rg.split();
+    let (b, _) = ba.split();
+    let [r, g, b] = [r, g, b].map(|channel| widen(simd, channel));
+    let offsets = [16, 128, 128];
+    core::array::from_fn(|i| {
+        let [cr, cg, cb] = coeffs[i];
+        narrow_saturate(
+            simd,
+            r * cr + g * cg + b * cb + ((offsets[i] << SHIFT) + ROUND),
+        )
+    })
+}
+
+/// Widen each sample to a signed 32-bit integer.
+#[inline(always)]
+fn widen<S: Simd>(simd: S, x: u8x16<S>) -> i32x16<S> {
+    simd.widen_u16x16(simd.widen_u8x16(x)).as_signed()
+}
+
+/// Remove the fractional bits of each sum, and clamp it to a `u8`.
+#[inline(always)]
+fn narrow_saturate<S: Simd>(simd: S, x: i32x16<S>) -> u8x16<S> {
+    // The integer parts are small enough for an `i16`, which is the lower half of each `i32`: the first on
+    // little-endian targets.
+    let (lo, hi) = (x >> SHIFT).bitcast::<i16x32<S>>().split();
+    let x = if cfg!(target_endian = "big") {
+        lo.unzip_high(hi)
+    } else {
+        lo.unzip_low(hi)
+    };
+    simd.pack_saturate_u8_i16x16(x)
+}
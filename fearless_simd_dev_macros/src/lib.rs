@@ -4,16 +4,103 @@
 //! Development macros for `fearless_simd`.
 
 use proc_macro::TokenStream;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{ItemFn, parse_macro_input};
+use syn::{Expr, ItemFn, parse_macro_input};
+
+/// The names of the levels which tests are generated for, used as the suffixes of the test names.
+const LEVELS: [&str; 7] = ["fallback", "neon", "wasm", "sse2", "sse4", "avx2", "avx512"];
 
 /// Create test for checking consistency between different SIMD backends.
+///
+/// By default, the test function returns nothing and makes its own assertions. Results which
+/// legitimately differ between levels can instead be returned from the test function, and checked
+/// against the lanes given with `expect`:
+///
+/// ```ignore
+/// #[simd_test(expect(
+///     default = [-1, i32::MAX, _],
+///     x86 = [-1, i32::MIN, i32::MIN],
+/// ))]
+/// fn cvt_out_of_range<S: Simd>(simd: S) -> i32x4<S> { ... }
+/// ```
+///
+/// Each level uses the expectation with its own name (one of `fallback`, `neon`, `wasm`, `sse2`,
+/// `sse4`, `avx2` and `avx512`) if there is one, then `x86` for the x86 levels, then `default`.
+/// A lane written as `_` is implementation-defined, and isn't checked. Floating point lanes which
+/// are NaN match any expected NaN.
 #[proc_macro_attribute]
-pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
+pub fn simd_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut expectations = Vec::new();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("expect") {
+            meta.parse_nested_meta(|level| {
+                let name = level
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .filter(|name| {
+                        name == "default" || name == "x86" || LEVELS.contains(&name.as_str())
+                    })
+                    .ok_or_else(|| level.error("expected `default`, `x86` or a level name"))?;
+                let Expr::Array(lanes) = level.value()?.parse()? else {
+                    return Err(level.error("expected an array of lanes"));
+                };
+                expectations.push((name, lanes));
+                Ok(())
+            })
+        } else {
+            Err(meta.error("unsupported `simd_test` argument"))
+        }
+    });
+    parse_macro_input!(attr with parser);
+
     let input_fn = parse_macro_input!(item as ItemFn);
     let input_fn_name = input_fn.sig.ident.clone();
 
+    // Wrap the call of the test function for a level in a check of its result, if expectations
+    // were given.
+    let run = |level: &str, call: TokenStream2| {
+        if expectations.is_empty() {
+            return quote! { #call; };
+        }
+        let is_x86 = matches!(level, "sse2" | "sse4" | "avx2" | "avx512");
+        let find = |name: &str| expectations.iter().find(|(n, _)| n == name);
+        let Some((_, lanes)) = find(level)
+            .or_else(|| find("x86").filter(|_| is_x86))
+            .or_else(|| find("default"))
+        else {
+            let message = format!("no expected result for the `{level}` level");
+            return quote! { compile_error!(#message); };
+        };
+        let lanes = lanes.elems.iter().map(|lane| match lane {
+            Expr::Infer(_) => quote! { None },
+            lane => quote! { Some(#lane) },
+        });
+        quote! {
+            let result = #call;
+            let actual = fearless_simd::SimdBase::as_slice(&result);
+            let expected = [#(#lanes),*];
+            assert_eq!(
+                actual.len(),
+                expected.len(),
+                "wrong number of expected lanes for the `{}` level",
+                #level
+            );
+            for (lane, (&actual, expected)) in actual.iter().zip(expected).enumerate() {
+                if let Some(expected) = expected {
+                    #[allow(clippy::eq_op, reason = "NaN is the only value which isn't equal to itself")]
+                    let matches = actual == expected || (actual != actual && expected != expected);
+                    assert!(
+                        matches,
+                        "lane {lane} on the `{}` level: expected {expected:?}, got {actual:?}",
+                        #level
+                    );
+                }
+            }
+        }
+    };
+
     let get_ident =
         |name: &str| Ident::new(&format!("{input_fn_name}_{name}"), input_fn_name.span());
 
@@ -46,12 +133,32 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
     let ignore_avx512 = ignore_attr(exclude_avx512);
     let ignore_wasm = ignore_attr(exclude_wasm);
 
+    let run_fallback = run("fallback", quote! { #input_fn_name(fallback) });
+    let run_neon = run("neon", quote! { #input_fn_name(neon) });
+    let run_wasm = run("wasm", quote! { #input_fn_name(wasm) });
+    let vectorized = |token: &str| {
+        let token = Ident::new(token, input_fn_name.span());
+        run(
+            &token.to_string(),
+            quote! {
+                #token.vectorize(
+                    #[inline(always)]
+                    || #input_fn_name(#token)
+                )
+            },
+        )
+    };
+    let run_sse2 = vectorized("sse2");
+    let run_sse4 = vectorized("sse4");
+    let run_avx2 = vectorized("avx2");
+    let run_avx512 = vectorized("avx512");
+
     let fallback_snippet = quote! {
         #[test]
         #ignore_fallback
         fn #fallback_name() {
             let fallback = fearless_simd::Fallback::new();
-            #input_fn_name(fallback);
+            #run_fallback
         }
     };
 
@@ -66,13 +173,13 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
     // as a result of that.
 
     let neon_snippet = quote! {
-        #[cfg(target_arch = "aarch64")]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
         #[test]
         #ignore_neon
         fn #neon_name() {
             if std::arch::is_aarch64_feature_detected!("neon") {
                 let neon = unsafe { fearless_simd::aarch64::Neon::new_unchecked() };
-                #input_fn_name(neon);
+                #run_neon
             }
         }
     };
@@ -88,10 +195,7 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
                 && std::arch::is_x86_feature_detected!("popcnt")
             {
                 let sse4 = unsafe { fearless_simd::x86::Sse4_2::new_unchecked() };
-                sse4.vectorize(
-                    #[inline(always)]
-                    || #input_fn_name(sse4)
-                );
+                #run_sse4
             }
         }
     };
@@ -106,10 +210,7 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
                 && std::arch::is_x86_feature_detected!("sse2")
             {
                 let sse2 = unsafe { fearless_simd::x86::Sse2::new_unchecked() };
-                sse2.vectorize(
-                    #[inline(always)]
-                    || #input_fn_name(sse2)
-                );
+                #run_sse2
             }
         }
     };
@@ -132,10 +233,7 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
                 && std::arch::is_x86_feature_detected!("xsave")
             {
                 let avx2 = unsafe { fearless_simd::x86::Avx2::new_unchecked() };
-                avx2.vectorize(
-                    #[inline(always)]
-                    || #input_fn_name(avx2)
-                );
+                #run_avx2
             }
         }
     };
@@ -179,10 +277,7 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
                 && std::arch::is_x86_feature_detected!("xsaves")
             {
                 let avx512 = unsafe { fearless_simd::x86::Avx512::new_unchecked() };
-                avx512.vectorize(
-                    #[inline(always)]
-                    || #input_fn_name(avx512)
-                );
+                #run_avx512
             }
         }
     };
@@ -193,7 +288,7 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
         #ignore_wasm
         fn #wasm_name() {
             let wasm = unsafe { fearless_simd::wasm32::WasmSimd128::new_unchecked() };
-            #input_fn_name(wasm);
+            #run_wasm
         }
     };
 
@@ -213,7 +308,8 @@ pub fn simd_test(_: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 // You can update below functions if you want to exclude certain tests from different architectures
-// (for example because they haven't been implemented yet).
+// (for example because they haven't been implemented yet). Results which legitimately differ between
+// levels should use `expect` in `#[simd_test]` instead, so that the rest of the test still runs.
 
 fn exclude_neon(_test_name: &str) -> bool {
     false
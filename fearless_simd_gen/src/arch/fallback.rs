@@ -53,18 +53,48 @@ pub(crate) fn translate_op(op: &str, is_float: bool) -> Option<&'static str> {
         "and" => "bitand",
         "or" => "bitor",
         "xor" => "bitxor",
-        "shr" => "shr",
-        "shl" => "shl",
-        "shrv" => "shr",
-        "shlv" => "shl",
+        // The wrapping shifts take the shift amount modulo the bit width, rather than panicking
+        // on overflow in debug builds.
+        "shr" | "shrv" => "wrapping_shr",
+        "shl" | "shlv" => "wrapping_shl",
         "max" => "max",
         "min" => "min",
         "max_precise" => "max",
         "min_precise" => "min",
+        "saturating_add" => "saturating_add",
+        "saturating_sub" => "saturating_sub",
+        "count_ones" => "count_ones",
+        "leading_zeros" => "leading_zeros",
+        "trailing_zeros" => "trailing_zeros",
         _ => return None,
     })
 }
 
+/// Whether the float version of `op` is computed by the `FallbackFloat` trait, rather than by an operator or
+/// method of `f32` or `f64`.
+///
+/// These are the operations whose results can differ between hosts, which the `soft_float` feature makes exact.
+pub(crate) fn uses_fallback_float(op: &str, ty: &VecType) -> bool {
+    ty.scalar == ScalarType::Float
+        && matches!(
+            op,
+            "add"
+                | "sub"
+                | "mul"
+                | "div"
+                | "sqrt"
+                | "floor"
+                | "ceil"
+                | "round_ties_even"
+                | "trunc"
+                | "fract"
+                | "max"
+                | "min"
+                | "max_precise"
+                | "min_precise"
+        )
+}
+
 pub(crate) fn simple_intrinsic(name: &str, ty: &VecType) -> TokenStream {
     let ty_prefix = ty.scalar.rust(ty.scalar_bits);
     let ident = Ident::new(name, Span::call_site());
@@ -77,6 +107,22 @@ pub(crate) fn expr(op: &str, ty: &VecType, args: &[TokenStream]) -> TokenStream
         unimplemented!("missing {op}");
     };
 
+    if uses_fallback_float(op, ty) {
+        let ident = Ident::new(translated, Span::call_site());
+        return quote! { FallbackFloat::#ident ( #( #args ),* ) };
+    }
     let intrinsic = simple_intrinsic(translated, ty);
+    if matches!(op, "count_ones" | "leading_zeros" | "trailing_zeros") {
+        // These return a `u32`, whose value fits in every element type.
+        let count = quote! { #intrinsic ( #( #args ),* ) };
+        return match (ty.scalar, ty.scalar_bits) {
+            (ScalarType::Unsigned, 32) => count,
+            (ScalarType::Int, 32) => quote! { #count.cast_signed() },
+            _ => {
+                let scalar = ty.scalar.rust(ty.scalar_bits);
+                quote! { #count as #scalar }
+            }
+        };
+    }
     quote! { #intrinsic ( #( #args ),* ) }
 }
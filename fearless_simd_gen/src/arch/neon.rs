@@ -29,14 +29,19 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "xor" => "veor",
         "max" => "vmax",
         "min" => "vmin",
+        "saturating_add" => "vqadd",
+        "saturating_sub" => "vqsub",
+        "avg_round" => "vrhadd",
         "shr" => "vshl",
         "shrv" => "vshl",
         "shl" => "vshl",
         "shlv" => "vshl",
         "max_precise" => "vmaxnm",
         "min_precise" => "vminnm",
+        // These must be the fused `vfma` and `vfms`, not `vmla` and `vmls`, which round the product before adding it.
         "mul_add" => "vfma",
         "mul_sub" => "vfms",
+        "nmul_add" | "nmul_sub" => "vfms",
         _ => return None,
     })
 }
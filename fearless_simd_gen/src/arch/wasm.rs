@@ -31,6 +31,9 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "shrv" => "shr",
         "max" => "max",
         "min" => "min",
+        "saturating_add" => "add_sat",
+        "saturating_sub" => "sub_sat",
+        "avg_round" => "avgr",
         "splat" => "splat",
         _ => return None,
     })
@@ -23,6 +23,9 @@ pub(crate) fn translate_op(op: &str) -> Option<&'static str> {
         "shr" => "shr",
         "max" => "max",
         "min" => "min",
+        "saturating_add" => "adds",
+        "saturating_sub" => "subs",
+        "avg_round" => "avg",
         "select" => "blendv",
         _ => return None,
     })
@@ -30,7 +33,10 @@ pub(crate) fn translate_op(op: &str) -> Option<&'static str> {
 
 pub(crate) fn expr(op: &str, ty: &VecType, args: &[TokenStream]) -> TokenStream {
     if let Some(op_name) = translate_op(op) {
-        let sign_aware = matches!(op, "max" | "min");
+        let sign_aware = matches!(
+            op,
+            "max" | "min" | "saturating_add" | "saturating_sub" | "avg_round"
+        );
 
         let suffix = match op_name {
             "and" | "or" | "xor" => coarse_type(ty),
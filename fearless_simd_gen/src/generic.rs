@@ -18,6 +18,21 @@ pub(crate) fn fallback_method(op: Op, vec_ty: &VecType) -> TokenStream {
     crate::mk_fallback::Fallback.make_method(op, vec_ty)
 }
 
+/// The method of a `_relaxed` op, like `mul_add_relaxed`, on a level without relaxed instructions, which calls the op
+/// it relaxes. Returns `None` for other ops.
+pub(crate) fn generic_relaxed(op: Op, vec_ty: &VecType) -> Option<TokenStream> {
+    let relaxed = op.method.strip_suffix("_relaxed")?;
+    let method_sig = op.simd_trait_method_sig(vec_ty);
+    let relaxed = generic_op_name(relaxed, vec_ty);
+    let args = op.sig.simd_trait_arg_names();
+    let args = args.iter().map(|name| Ident::new(name, Span::call_site()));
+    Some(quote! {
+        #method_sig {
+            self.#relaxed(#( #args ),*)
+        }
+    })
+}
+
 /// For backends that store masks as all-zero/all-one integer lanes, convert the public
 /// `bool` mask splat argument into the backend's lane representation.
 pub(crate) fn integer_lane_mask_splat_arg(vec_ty: &VecType) -> TokenStream {
@@ -31,6 +46,85 @@ pub(crate) fn integer_lane_mask_splat_arg(vec_ty: &VecType) -> TokenStream {
     }
 }
 
+/// The body of a table-based `compress_bytes` or `expand_bytes` kernel for a 16-byte vector, given the level's byte
+/// shuffle intrinsic.
+///
+/// The intrinsic must zero lanes whose index has the high bit set, as `pshufb`, `tbl` and `i8x16.swizzle` all do.
+pub(crate) fn table_compress_bytes(
+    token: &Ident,
+    vec_ty: &VecType,
+    expand: bool,
+    shuffle: impl Fn(TokenStream, TokenStream) -> TokenStream,
+) -> TokenStream {
+    assert_eq!(
+        vec_ty.n_bits(),
+        128,
+        "table-based compression only supports 16-byte vectors"
+    );
+    let to_bitmask = generic_op_name("to_bitmask", &vec_ty.mask_ty());
+    let load_array = generic_op_name("load_array", vec_ty);
+    if expand {
+        let expanded = shuffle(
+            quote! { a.into() },
+            quote! { #token.#load_array(indices).into() },
+        );
+        quote! {
+            let indices = crate::support::expand_bytes_indices(#token.#to_bitmask(mask));
+            #expanded.simd_into(#token)
+        }
+    } else {
+        let packed = shuffle(
+            quote! { a.into() },
+            quote! { #token.#load_array(shuffle).into() },
+        );
+        let merged = shuffle(
+            quote! { packed },
+            quote! { #token.#load_array(merge).into() },
+        );
+        quote! {
+            let (shuffle, merge) = crate::support::compress_bytes_indices(#token.#to_bitmask(mask));
+            let packed = #packed;
+            #merged.simd_into(#token)
+        }
+    }
+}
+
+/// The body of a `leading_zeros` kernel built from `count_ones`, for levels without a leading zero count
+/// instruction.
+///
+/// Shifting right and combining with `or` copies the highest set bit into every bit below it, so that the zero bits
+/// which are left are the leading zeros. For signed elements, the shifts replicate the sign bit instead of shifting
+/// in zeros, but that only sets more bits of negative elements, which have no leading zeros either way.
+pub(crate) fn leading_zeros_from_count_ones(vec_ty: &VecType) -> TokenStream {
+    let or = generic_op_name("or", vec_ty);
+    let shr = generic_op_name("shr", vec_ty);
+    let not = generic_op_name("not", vec_ty);
+    let count_ones = generic_op_name("count_ones", vec_ty);
+    let smears = core::iter::successors(Some(1_u32), |shift| Some(shift * 2))
+        .take_while(|&shift| (shift as usize) < vec_ty.scalar_bits)
+        .map(|shift| quote! { let a = self.#or(a, self.#shr(a, #shift)); });
+    quote! {
+        #( #smears )*
+        self.#count_ones(self.#not(a))
+    }
+}
+
+/// The body of a `trailing_zeros` kernel built from `count_ones`, for levels without a trailing zero count
+/// instruction.
+///
+/// The bits below the lowest set bit are the ones which are set in both `!a` and `a - 1`. If `a` is zero, that's
+/// all of them.
+pub(crate) fn trailing_zeros_from_count_ones(vec_ty: &VecType) -> TokenStream {
+    let and = generic_op_name("and", vec_ty);
+    let not = generic_op_name("not", vec_ty);
+    let sub = generic_op_name("sub", vec_ty);
+    let splat = generic_op_name("splat", vec_ty);
+    let count_ones = generic_op_name("count_ones", vec_ty);
+    quote! {
+        self.#count_ones(self.#and(self.#not(a), self.#sub(a, self.#splat(1))))
+    }
+}
+
 /// Implementation based on split/combine
 ///
 /// Only suitable for lane-wise and block-wise operations
@@ -66,6 +160,16 @@ pub(crate) fn generic_op(op: &Op, ty: &VecType) -> TokenStream {
                 }
             }
         }
+        OpSig::ShiftSigned => {
+            let split_amounts = generic_op_name("split", &ty.cast(ScalarType::Int));
+            quote! {
+                #method_sig {
+                    let (a0, a1) = self.#split(a);
+                    let (b0, b1) = self.#split_amounts(b);
+                    self.#combine(self.#do_half(a0, b0), self.#do_half(a1, b1))
+                }
+            }
+        }
         OpSig::Shift => {
             quote! {
                 #method_sig {
@@ -88,6 +192,65 @@ pub(crate) fn generic_op(op: &Op, ty: &VecType) -> TokenStream {
                 }
             }
         }
+        OpSig::Compress { expand } => {
+            let mask_ty = ty.mask_ty();
+            let split_mask = generic_op_name("split", &mask_ty);
+            let half_mask = VecType::new(ScalarType::Mask, half.scalar_bits, half.len);
+            let to_bitmask = generic_op_name("to_bitmask", &half_mask);
+            let as_array = generic_op_name("as_array", ty);
+            let as_array_half = generic_op_name("as_array", &half);
+            let load_array = generic_op_name("load_array", ty);
+            let load_array_half = generic_op_name("load_array", &half);
+            let n = Literal::usize_unsuffixed(ty.len);
+            let half_len = Literal::usize_unsuffixed(half.len);
+            // The upper half's lanes are packed after, or taken from after, the lanes used by the lower half.
+            if expand {
+                quote! {
+                    #method_sig {
+                        let (mask0, mask1) = self.#split_mask(mask);
+                        let count = self.#to_bitmask(mask0).count_ones() as usize;
+                        let lanes = self.#as_array(a);
+                        let (a0, _) = self.#split(a);
+                        let a1 = self.#load_array_half(core::array::from_fn(|i| lanes[count + i]));
+                        self.#combine(self.#do_half(a0, mask0), self.#do_half(a1, mask1))
+                    }
+                }
+            } else {
+                quote! {
+                    #method_sig {
+                        let (a0, a1) = self.#split(a);
+                        let (mask0, mask1) = self.#split_mask(mask);
+                        let count = self.#to_bitmask(mask0).count_ones() as usize;
+                        let mut result = [0; #n];
+                        result[..#half_len].copy_from_slice(&self.#as_array_half(self.#do_half(a0, mask0)));
+                        result[count..count + #half_len]
+                            .copy_from_slice(&self.#as_array_half(self.#do_half(a1, mask1)));
+                        self.#load_array(result)
+                    }
+                }
+            }
+        }
+        OpSig::WidenAccumulate { acc_ty } => {
+            let split_acc = generic_op_name("split", &acc_ty);
+            let half_acc = VecType::new(acc_ty.scalar, acc_ty.scalar_bits, half.len);
+            let combine_acc = generic_op_name("combine", &half_acc);
+            quote! {
+                #method_sig {
+                    let (acc0, acc1) = self.#split_acc(acc);
+                    let (a0, a1) = self.#split(a);
+                    let (b0, b1) = self.#split(b);
+                    self.#combine_acc(self.#do_half(acc0, a0, b0), self.#do_half(acc1, a1, b1))
+                }
+            }
+        }
+        OpSig::ReduceWidened => {
+            quote! {
+                #method_sig {
+                    let (a0, a1) = self.#split(a);
+                    self.#do_half(a0) + self.#do_half(a1)
+                }
+            }
+        }
         OpSig::Ternary => {
             quote! {
                 #method_sig {
@@ -227,9 +390,67 @@ pub(crate) fn generic_op(op: &Op, ty: &VecType) -> TokenStream {
                 }
             }
         }
+        OpSig::MaskToInt => {
+            let mut half_int = ty.cast(ScalarType::Int);
+            half_int.len /= 2;
+            let combine = generic_op_name("combine", &half_int);
+            quote! {
+                #method_sig {
+                    let (a0, a1) = self.#split(a);
+                    self.#combine(self.#do_half(a0), self.#do_half(a1))
+                }
+            }
+        }
         OpSig::MaskSet => {
             panic!("Mask set must operate on the full mask vector")
         }
+        OpSig::ExtractLane { dynamic: false } => {
+            let check = const_lane_check(ty);
+            let arms = modulo_offset_arms(ty, |lane| {
+                let (a, lane) = if lane < half.len {
+                    (quote! { a0 }, lane)
+                } else {
+                    (quote! { a1 }, lane - half.len)
+                };
+                let lane = Literal::usize_unsuffixed(lane);
+                quote! { self.#do_half::<#lane>(#a) }
+            });
+            quote! {
+                #method_sig {
+                    #check
+                    let (a0, a1) = self.#split(a);
+                    match LANE {
+                        #(#arms,)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        }
+        OpSig::ExtractLane { dynamic: true } => {
+            panic!("Dynamic lane extraction must operate on the full vector")
+        }
+        OpSig::InsertLane => {
+            let check = const_lane_check(ty);
+            let arms = modulo_offset_arms(ty, |lane| {
+                if lane < half.len {
+                    let lane = Literal::usize_unsuffixed(lane);
+                    quote! { self.#combine(self.#do_half::<#lane>(a0, value), a1) }
+                } else {
+                    let lane = Literal::usize_unsuffixed(lane - half.len);
+                    quote! { self.#combine(a0, self.#do_half::<#lane>(a1, value)) }
+                }
+            });
+            quote! {
+                #method_sig {
+                    #check
+                    let (a0, a1) = self.#split(a);
+                    match LANE {
+                        #(#arms,)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        }
         OpSig::LoadInterleaved {
             block_size,
             block_count,
@@ -370,6 +591,75 @@ pub(crate) fn generic_op(op: &Op, ty: &VecType) -> TokenStream {
                 }
             }
         }
+        OpSig::BitTranspose => match op.method {
+            "transpose_bits_8x8" => {
+                // Swap the off-diagonal 1x1, then 2x2, then 4x4 blocks within each 8x8 matrix, from
+                // Hacker's Delight (7-3).
+                let splat = generic_op_name("splat", ty);
+                let and = generic_op_name("and", ty);
+                let xor = generic_op_name("xor", ty);
+                let shl = generic_op_name("shl", ty);
+                let shr = generic_op_name("shr", ty);
+                quote! {
+                    #method_sig {
+                        let t = self.#and(self.#xor(a, self.#shr(a, 7)), self.#splat(0x00AA_00AA_00AA_00AA));
+                        let a = self.#xor(self.#xor(a, t), self.#shl(t, 7));
+                        let t = self.#and(self.#xor(a, self.#shr(a, 14)), self.#splat(0x0000_CCCC_0000_CCCC));
+                        let a = self.#xor(self.#xor(a, t), self.#shl(t, 14));
+                        let t = self.#and(self.#xor(a, self.#shr(a, 28)), self.#splat(0x0000_0000_F0F0_F0F0));
+                        self.#xor(self.#xor(a, t), self.#shl(t, 28))
+                    }
+                }
+            }
+            "transpose_bits_16x16" => {
+                // Split the matrix into the 8x8 blocks [A B; C D], with A in the low bytes of rows 0 to 7, and
+                // transpose them into [A^T C^T; B^T D^T]. The bytes are taken in little-endian order, so the rows
+                // of each block are the same on every target.
+                assert_eq!(ty.len, 16, "16x16 bit transposes work on one matrix");
+                let bytes_ty = VecType::new(ScalarType::Unsigned, 8, 32);
+                let bytes_half = VecType::new(ScalarType::Unsigned, 8, 16);
+                let blocks_ty = VecType::new(ScalarType::Unsigned, 64, 2);
+                let split_bytes = generic_op_name("split", &bytes_ty);
+                let combine_bytes = generic_op_name("combine", &bytes_half);
+                let unzip_low = generic_op_name("unzip_low", &bytes_half);
+                let unzip_high = generic_op_name("unzip_high", &bytes_half);
+                let zip_low = generic_op_name("zip_low", &bytes_half);
+                let zip_high = generic_op_name("zip_high", &bytes_half);
+                let zip_low_blocks = generic_op_name("zip_low", &blocks_ty);
+                let zip_high_blocks = generic_op_name("zip_high", &blocks_ty);
+                let transpose_blocks = generic_op_name("transpose_bits_8x8", &blocks_ty);
+                quote! {
+                    #method_sig {
+                        let (top, bottom) = self.#split_bytes(a.to_le_bytes());
+                        let ac = self.#transpose_blocks(Bytes::from_le_bytes(self.#unzip_low(top, bottom)));
+                        let bd = self.#transpose_blocks(Bytes::from_le_bytes(self.#unzip_high(top, bottom)));
+                        let ab = self.#zip_low_blocks(ac, bd).to_le_bytes();
+                        let cd = self.#zip_high_blocks(ac, bd).to_le_bytes();
+                        Bytes::from_le_bytes(self.#combine_bytes(self.#zip_low(ab, cd), self.#zip_high(ab, cd)))
+                    }
+                }
+            }
+            _ => unreachable!("unknown bit transpose {}", op.method),
+        },
+        OpSig::GfMulConst => {
+            // Multiplication by a constant distributes over the two nibbles of each byte, so the product is the XOR of
+            // two 16-entry table lookups, which are computed at compile time.
+            let n = Literal::usize_unsuffixed(ty.len);
+            let splat = generic_op_name("splat", ty);
+            let load_array = generic_op_name("load_array", ty);
+            let swizzle = generic_op_name("swizzle_dyn_within_blocks", ty);
+            let and = generic_op_name("and", ty);
+            let shr = generic_op_name("shr", ty);
+            let xor = generic_op_name("xor", ty);
+            quote! {
+                #method_sig {
+                    let (low, high) = const { crate::support::gf_mul_nibble_tables::<#n>(FACTOR) };
+                    let low = self.#swizzle(self.#load_array(low), self.#and(a, self.#splat(0x0F)));
+                    let high = self.#swizzle(self.#load_array(high), self.#shr(a, 4));
+                    self.#xor(low, high)
+                }
+            }
+        }
         OpSig::Slide { granularity, .. } => {
             match (granularity, ty.n_bits()) {
                 (SlideGranularity::WithinBlocks, 128) => {
@@ -616,6 +906,21 @@ pub(crate) fn generic_mask_to_bitmask(method_sig: TokenStream, vec_ty: &VecType)
     }
 }
 
+/// For backends that store masks with the same representation as the signed integer vectors, convert a mask to its
+/// integer vector by reusing its storage.
+pub(crate) fn generic_mask_to_int(method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+    let int_ty = vec_ty.cast(ScalarType::Int).rust();
+
+    quote! {
+        #method_sig {
+            #int_ty {
+                val: a.val,
+                simd: self,
+            }
+        }
+    }
+}
+
 pub(crate) fn generic_mask_set(method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
     let from_array = generic_op_name("load_array", vec_ty);
     let as_array = generic_op_name("as_array", vec_ty);
@@ -634,3 +939,29 @@ pub(crate) fn generic_mask_set(method_sig: TokenStream, vec_ty: &VecType) -> Tok
         }
     }
 }
+
+/// A compile-time check that the const-generic `LANE` of a lane extraction or insertion is in bounds.
+pub(crate) fn const_lane_check(vec_ty: &VecType) -> TokenStream {
+    let len = vec_ty.len;
+    quote! {
+        const {
+            assert!(LANE < #len, "the lane index should be less than the number of lanes");
+        }
+    }
+}
+
+pub(crate) fn generic_extract_dyn(method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+    let as_array_ref = generic_op_name("as_array_ref", vec_ty);
+    let len = vec_ty.len;
+
+    quote! {
+        #method_sig {
+            assert!(
+                index < #len,
+                "lane index {index} is out of bounds for {} lanes",
+                #len
+            );
+            self.#as_array_ref(&a)[index]
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2025 the Fearless_SIMD Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::{
@@ -23,6 +23,14 @@ pub(crate) trait Level {
     /// instance, NEON provides tuples of vectors like `int32x4x4_t` up to 512 bits, and the fallback implementation
     /// stores everything as arrays but only operates on 128-bit chunks.
     fn max_block_size(&self) -> usize;
+    /// Whether `mul_add`, `mul_sub`, `nmul_add` and `nmul_sub` are always computed with a single rounding on this level. This is exposed as
+    /// `Simd::FUSED_MUL_ADD`, and the code generator must only emit fused instructions for these ops if it's `true`.
+    fn fused_mul_add(&self) -> bool;
+    /// Whether this level computes floats with integer arithmetic, rather than with the float unit. This is exposed
+    /// as `Simd::SOFT_FLOAT`, and is an expression, as it can depend on a Cargo feature.
+    fn soft_float(&self) -> TokenStream {
+        quote! { false }
+    }
     /// The names of the target features to enable within vectorized code. This goes in the
     /// `#[target_feature(enable = "...")]` attribute.
     ///
@@ -46,6 +54,19 @@ pub(crate) trait Level {
     /// Any additional imports or supporting code necessary for the module (for instance, importing
     /// implementation-specific functions from `core::arch`).
     fn make_module_prelude(&self) -> TokenStream;
+    /// Imports of the architecture's vector types, which are needed by the token's `ArchTypes` implementation.
+    fn make_arch_imports(&self) -> TokenStream {
+        TokenStream::new()
+    }
+    /// The Cargo feature of `fearless_simd` which enables this level's `Simd` implementation, for levels which can be
+    /// left out to reduce compile times.
+    ///
+    /// The token type is generated regardless, so that `Level` and `kernel!` don't depend on the feature. Everything
+    /// else is generated by [`Level::make_simd_impl_module`] into a child module, which is only compiled when the
+    /// feature is enabled.
+    fn cargo_feature(&self) -> Option<&'static str> {
+        None
+    }
     /// Inner attributes to place at the top of the generated module.
     fn make_module_attrs(&self) -> TokenStream {
         TokenStream::new()
@@ -165,36 +186,22 @@ pub(crate) trait Level {
         };
 
         let level_body = self.make_level_body();
+        let native_bits = Literal::usize_unsuffixed(native_width);
+        let fused_mul_add = self.fused_mul_add();
+        let soft_float = self.soft_float();
 
-        let mut assoc_types = vec![];
-        for (scalar, scalar_bits) in [
-            (ScalarType::Float, 32),
-            (ScalarType::Float, 64),
-            (ScalarType::Unsigned, 8),
-            (ScalarType::Int, 8),
-            (ScalarType::Unsigned, 16),
-            (ScalarType::Int, 16),
-            (ScalarType::Unsigned, 32),
-            (ScalarType::Int, 32),
-            (ScalarType::Unsigned, 64),
-            (ScalarType::Int, 64),
-            (ScalarType::Mask, 8),
-            (ScalarType::Mask, 16),
-            (ScalarType::Mask, 32),
-            (ScalarType::Mask, 64),
-        ] {
-            let native_width_ty = VecType::new(scalar, scalar_bits, native_width / scalar_bits);
-            let name = native_width_ty.rust();
-            let native_width_name = scalar.native_width_name(scalar_bits);
-            assoc_types.push(quote! {
-                type #native_width_name = #name<Self>;
-            });
-        }
+        let assoc_types = native_assoc_types(native_width);
 
         quote! {
             impl Simd for #level_tok {
                 #( #assoc_types )*
 
+                const NATIVE_BITS: usize = #native_bits;
+
+                const FUSED_MUL_ADD: bool = #fused_mul_add;
+
+                const SOFT_FLOAT: bool = #soft_float;
+
                 #[inline(always)]
                 fn level(self) -> Level {
                     #level_body
@@ -272,27 +279,13 @@ pub(crate) trait Level {
         }
     }
 
-    fn make_module(&self) -> TokenStream {
+    fn make_token(&self) -> TokenStream {
         let level_tok = self.token();
         let token_doc = self.token_doc();
-        let imports = type_imports();
-        let module_attrs = self.make_module_attrs();
-        let module_prelude = self.make_module_prelude();
         let impl_body = self.make_impl_body();
         let arch_types_impl = self.impl_arch_types();
-        let simd_impl = self.make_simd_impl();
-        let ty_impl = self.make_type_impl();
-        let footer = self.make_module_footer();
 
         quote! {
-            #module_attrs
-
-            use crate::{prelude::*, seal::Seal, arch_types::ArchTypes, Level};
-
-            #imports
-
-            #module_prelude
-
             #[doc = #token_doc]
             #[derive(Clone, Copy, Debug)]
             pub struct #level_tok {
@@ -306,6 +299,67 @@ pub(crate) trait Level {
             impl Seal for #level_tok {}
 
             #arch_types_impl
+        }
+    }
+
+    fn make_module(&self) -> TokenStream {
+        let imports = type_imports();
+        let arch_imports = self.make_arch_imports();
+        let token = self.make_token();
+
+        let module_attrs = self.make_module_attrs();
+        let ty_impl = self.make_type_impl();
+
+        // The conversions to and from the architecture's vector types are kept with the token, as they're needed by
+        // `kernel!` users even without the `Simd` implementation.
+        if let Some(feature) = self.cargo_feature() {
+            let converted_tys = SIMD_TYPES
+                .iter()
+                .filter(|ty| self.should_impl_arch_type_conversion(ty))
+                .map(VecType::rust)
+                .collect::<Vec<_>>();
+            let conversion_imports = if converted_tys.is_empty() {
+                TokenStream::new()
+            } else {
+                quote! {
+                    use crate::{prelude::*, #( #converted_tys ),*};
+                }
+            };
+
+            return quote! {
+                #module_attrs
+
+                use crate::{seal::Seal, arch_types::ArchTypes};
+
+                #conversion_imports
+
+                #arch_imports
+
+                #token
+
+                #ty_impl
+
+                #[cfg(feature = #feature)]
+                mod simd_impl;
+            };
+        }
+
+        let module_prelude = self.make_module_prelude();
+        let simd_impl = self.make_simd_impl();
+        let footer = self.make_module_footer();
+
+        quote! {
+            #module_attrs
+
+            use crate::{prelude::*, seal::Seal, arch_types::ArchTypes, Level};
+
+            #imports
+
+            #arch_imports
+
+            #module_prelude
+
+            #token
 
             #simd_impl
 
@@ -314,4 +368,62 @@ pub(crate) trait Level {
             #footer
         }
     }
+
+    /// The `simd_impl` child module of a level with a [`Level::cargo_feature`], containing the `Simd` implementation.
+    fn make_simd_impl_module(&self) -> TokenStream {
+        let level_tok = self.token();
+        let imports = type_imports();
+        let arch_imports = self.make_arch_imports();
+        let module_attrs = self.make_module_attrs();
+        let module_prelude = self.make_module_prelude();
+        let simd_impl = self.make_simd_impl();
+        let footer = self.make_module_footer();
+
+        quote! {
+            #module_attrs
+
+            use super::#level_tok;
+            use crate::{prelude::*, Level};
+
+            #imports
+
+            #arch_imports
+
+            #module_prelude
+
+            #simd_impl
+
+            #footer
+        }
+    }
+}
+
+/// The `Simd` trait's associated types for the native-width vectors, like `type f32s = f32x4<Self>;`, for a level whose
+/// native width is `native_width` bits.
+pub(crate) fn native_assoc_types(native_width: usize) -> Vec<TokenStream> {
+    let mut assoc_types = vec![];
+    for (scalar, scalar_bits) in [
+        (ScalarType::Float, 32),
+        (ScalarType::Float, 64),
+        (ScalarType::Unsigned, 8),
+        (ScalarType::Int, 8),
+        (ScalarType::Unsigned, 16),
+        (ScalarType::Int, 16),
+        (ScalarType::Unsigned, 32),
+        (ScalarType::Int, 32),
+        (ScalarType::Unsigned, 64),
+        (ScalarType::Int, 64),
+        (ScalarType::Mask, 8),
+        (ScalarType::Mask, 16),
+        (ScalarType::Mask, 32),
+        (ScalarType::Mask, 64),
+    ] {
+        let native_width_ty = VecType::new(scalar, scalar_bits, native_width / scalar_bits);
+        let name = native_width_ty.rust();
+        let native_width_name = scalar.native_width_name(scalar_bits);
+        assoc_types.push(quote! {
+            type #native_width_name = #name<Self>;
+        });
+    }
+    assoc_types
 }
@@ -0,0 +1,315 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Which instructions each level lowers each operation to.
+//!
+//! This is worked out from the code the generator emits for each level, rather than being written down separately,
+//! so it can't drift from the implementations. A method's intrinsics are the intrinsic calls in its body, plus those of
+//! any other `Simd` methods and module helpers it calls, so a split op lists the intrinsics of its halves, and an op
+//! composed from others (like `mul_add` as `a * b + c`) lists those of its parts.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::sync::LazyLock;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+
+use crate::generic::{fallback_method, generic_op, generic_op_name};
+use crate::level::Level;
+use crate::mk_fallback::Fallback;
+use crate::mk_neon::Neon;
+use crate::mk_wasm::WasmSimd128;
+use crate::mk_x86::X86;
+use crate::ops::{Op, ops_for_type};
+use crate::types::{SIMD_TYPES, VecType};
+
+/// The names of the levels, in the order their lowerings are listed in.
+pub(crate) const LEVELS: [&str; 7] = [
+    "Fallback",
+    "Neon",
+    "WasmSimd128",
+    "Sse2",
+    "Sse4_2",
+    "Avx2",
+    "Avx512",
+];
+
+/// How a level implements an op for a vector type.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum LoweringKind {
+    /// The level shares the fallback level's implementation, although that may call other methods which the level
+    /// implements natively.
+    Portable,
+    /// The level implements the op with its own instructions.
+    Native,
+    /// The op is split into halves which the level implements, then recombined.
+    Split,
+}
+
+impl LoweringKind {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Portable => "portable",
+            Self::Native => "native",
+            Self::Split => "split",
+        }
+    }
+}
+
+/// How one level implements an op for a vector type.
+#[derive(Clone, Debug)]
+pub(crate) struct Lowering {
+    pub(crate) kind: LoweringKind,
+    /// The intrinsics used, in the order they first appear.
+    pub(crate) intrinsics: Vec<String>,
+}
+
+/// The lowerings of every op, for every vector type and level.
+pub(crate) struct Lowerings {
+    by_method: HashMap<String, [Lowering; LEVELS.len()]>,
+}
+
+/// The lowerings of every op, which are worked out the first time they're needed.
+pub(crate) fn lowerings() -> &'static Lowerings {
+    static LOWERINGS: LazyLock<Lowerings> = LazyLock::new(Lowerings::new);
+    &LOWERINGS
+}
+
+impl Lowerings {
+    fn new() -> Self {
+        let levels = [
+            analyze(&Fallback),
+            analyze(&Neon),
+            analyze(&WasmSimd128),
+            analyze(&X86::Sse2),
+            analyze(&X86::Sse4_2),
+            analyze(&X86::Avx2),
+            analyze(&X86::Avx512),
+        ];
+        let mut by_method = HashMap::new();
+        for vec_ty in SIMD_TYPES {
+            for op in ops_for_type(vec_ty) {
+                let name = generic_op_name(op.method, vec_ty).to_string();
+                let lowerings = levels.each_ref().map(|level| level[&name].clone());
+                by_method.insert(name, lowerings);
+            }
+        }
+        Self { by_method }
+    }
+
+    /// The lowering of `op` for `vec_ty` on each level, in the order of [`LEVELS`].
+    pub(crate) fn get(&self, op: Op, vec_ty: &VecType) -> &[Lowering; LEVELS.len()] {
+        &self.by_method[&generic_op_name(op.method, vec_ty).to_string()]
+    }
+}
+
+/// The `# Lowering` section of the documentation of `op`'s `Simd` method for `vec_ty`, as a table of the intrinsics
+/// each level uses.
+pub(crate) fn doc_section(op: Op, vec_ty: &VecType) -> String {
+    let mut doc = String::from("\n\n# Lowering\n\n| Level | Intrinsics |\n| --- | --- |\n");
+    for (level, lowering) in LEVELS.iter().zip(lowerings().get(op, vec_ty)) {
+        let intrinsics = lowering
+            .intrinsics
+            .iter()
+            .map(|intrinsic| format!("`{intrinsic}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let description = match lowering.kind {
+            LoweringKind::Portable if *level == Fallback.name() => "Portable code".to_string(),
+            LoweringKind::Portable if intrinsics.is_empty() => {
+                format!("Same as `{}`", Fallback.name())
+            }
+            LoweringKind::Portable => {
+                format!("Same code as `{}`, using {intrinsics}", Fallback.name())
+            }
+            LoweringKind::Native if intrinsics.is_empty() => "No SIMD intrinsics".to_string(),
+            LoweringKind::Native => intrinsics,
+            LoweringKind::Split => {
+                let half = VecType::new(vec_ty.scalar, vec_ty.scalar_bits, vec_ty.len / 2);
+                if intrinsics.is_empty() {
+                    format!(
+                        "Split into `{}` halves, with no SIMD intrinsics",
+                        half.rust_name()
+                    )
+                } else {
+                    format!("Split into `{}` halves: {intrinsics}", half.rust_name())
+                }
+            }
+        };
+        writeln!(doc, "| `{level}` | {description} |").unwrap();
+    }
+    doc
+}
+
+/// Work out the lowering of every `Simd` method on `level`, keyed by method name.
+fn analyze(level: &impl Level) -> HashMap<String, Lowering> {
+    let mut methods = HashMap::new();
+    let mut kinds = HashMap::new();
+    for vec_ty in SIMD_TYPES {
+        for op in ops_for_type(vec_ty) {
+            let name = generic_op_name(op.method, vec_ty).to_string();
+            let (kind, body) = if level.name() == Fallback.name() {
+                (LoweringKind::Portable, TokenStream::new())
+            } else if level.should_use_generic_op(&op, vec_ty) {
+                (LoweringKind::Split, generic_op(&op, vec_ty))
+            } else {
+                let body = level.make_method(op, vec_ty);
+                (kind(op, vec_ty, &body), body)
+            };
+            kinds.insert(name.clone(), kind);
+            methods.insert(name, body);
+        }
+    }
+    let helpers = helper_fns(&level.make_module_footer());
+
+    let mut scanner = Scanner {
+        level: level.name(),
+        methods: &methods,
+        helpers: &helpers,
+        done: HashMap::new(),
+        in_progress: HashSet::new(),
+    };
+    kinds
+        .into_iter()
+        .map(|(name, kind)| {
+            let intrinsics = scanner.method(&name);
+            (name, Lowering { kind, intrinsics })
+        })
+        .collect()
+}
+
+/// Classify how a level implements `op` for `vec_ty`, given the method it generates when it isn't split.
+fn kind(op: Op, vec_ty: &VecType, method: &TokenStream) -> LoweringKind {
+    // Levels without a native lowering delegate to the fallback implementation verbatim.
+    if !Fallback.should_use_generic_op(&op, vec_ty)
+        && method.to_string() == fallback_method(op, vec_ty).to_string()
+    {
+        return LoweringKind::Portable;
+    }
+    LoweringKind::Native
+}
+
+/// The free functions defined in a module footer, keyed by name.
+fn helper_fns(footer: &TokenStream) -> HashMap<String, TokenStream> {
+    let mut helpers = HashMap::new();
+    collect_fns(footer.clone(), &mut helpers);
+    helpers
+}
+
+fn collect_fns(tokens: TokenStream, fns: &mut HashMap<String, TokenStream>) {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    for (i, token) in tokens.iter().enumerate() {
+        // Helpers may be wrapped in a macro call, like `crate::kernel!(fn helper(...) {...})`.
+        if let TokenTree::Group(group) = token {
+            collect_fns(group.stream(), fns);
+            continue;
+        }
+        let (TokenTree::Ident(keyword), Some(TokenTree::Ident(name))) = (token, tokens.get(i + 1))
+        else {
+            continue;
+        };
+        if keyword != "fn" {
+            continue;
+        }
+        // The body is the first brace-delimited group after the name.
+        let body = tokens[i + 2..].iter().find_map(|token| match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                Some(group.stream())
+            }
+            _ => None,
+        });
+        if let Some(body) = body {
+            fns.insert(name.to_string(), body);
+        }
+    }
+}
+
+/// Whether `name` is one of `level`'s intrinsics, as opposed to a function or constructor of this crate.
+fn is_intrinsic(level: &str, name: &str) -> bool {
+    match level {
+        "Neon" => name.starts_with('v') && name.contains('_'),
+        "WasmSimd128" => {
+            const PREFIXES: [&str; 11] = [
+                "v128_", "i8x16_", "u8x16_", "i16x8_", "u16x8_", "i32x4_", "u32x4_", "i64x2_",
+                "u64x2_", "f32x4_", "f64x2_",
+            ];
+            PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+        }
+        "Sse2" | "Sse4_2" | "Avx2" | "Avx512" => {
+            name.starts_with("_mm") || name.starts_with("_cvt") || name.starts_with("_kand")
+        }
+        _ => false,
+    }
+}
+
+/// Collects the intrinsics reachable from each method of one level, memoizing the results.
+struct Scanner<'a> {
+    level: &'static str,
+    methods: &'a HashMap<String, TokenStream>,
+    helpers: &'a HashMap<String, TokenStream>,
+    done: HashMap<String, Vec<String>>,
+    in_progress: HashSet<String>,
+}
+
+impl Scanner<'_> {
+    /// The intrinsics used by the `Simd` method or helper function `name`.
+    fn method(&mut self, name: &str) -> Vec<String> {
+        if let Some(intrinsics) = self.done.get(name) {
+            return intrinsics.clone();
+        }
+        let Some(body) = self.methods.get(name).or_else(|| self.helpers.get(name)) else {
+            return vec![];
+        };
+        if !self.in_progress.insert(name.to_string()) {
+            return vec![];
+        }
+        let mut intrinsics = vec![];
+        self.scan(body.clone(), &mut intrinsics);
+        self.in_progress.remove(name);
+        self.done.insert(name.to_string(), intrinsics.clone());
+        intrinsics
+    }
+
+    fn scan(&mut self, tokens: TokenStream, intrinsics: &mut Vec<String>) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, token) in tokens.iter().enumerate() {
+            let name = match token {
+                TokenTree::Group(group) => {
+                    self.scan(group.stream(), intrinsics);
+                    continue;
+                }
+                TokenTree::Ident(name) => name.to_string(),
+                _ => continue,
+            };
+            let is_call = match tokens.get(i + 1) {
+                Some(TokenTree::Group(group)) => group.delimiter() == Delimiter::Parenthesis,
+                // A call with explicit generic arguments, like `_mm_srli_epi32::<3>(a)`.
+                Some(TokenTree::Punct(punct)) => punct.as_char() == ':',
+                _ => false,
+            };
+            if !is_call {
+                continue;
+            }
+            let previous = i.checked_sub(1).map(|i| &tokens[i]);
+            let callee = match previous {
+                Some(TokenTree::Ident(keyword)) if keyword == "fn" => continue,
+                // A method call, which only matters if it's on the `Simd` implementation itself.
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '.' => {
+                    match i.checked_sub(2).map(|i| &tokens[i]) {
+                        Some(TokenTree::Ident(receiver)) if receiver == "self" => {
+                            self.method(&name)
+                        }
+                        _ => continue,
+                    }
+                }
+                _ if is_intrinsic(self.level, &name) => vec![name],
+                _ => self.method(&name),
+            };
+            for intrinsic in callee {
+                if !intrinsics.contains(&intrinsic) {
+                    intrinsics.push(intrinsic);
+                }
+            }
+        }
+    }
+}
@@ -16,9 +16,15 @@ use crate::level::Level as _;
 mod arch;
 mod generic;
 mod level;
+mod lowering;
+mod mk_custom;
 mod mk_fallback;
+mod mk_lowering;
+mod mk_manifest;
 mod mk_neon;
 mod mk_ops;
+mod mk_profile;
+mod mk_reference;
 mod mk_simd_trait;
 mod mk_simd_types;
 mod mk_wasm;
@@ -31,6 +37,9 @@ enum Module {
     SimdTypes,
     SimdTrait,
     Ops,
+    Reference,
+    Lowering,
+    Custom,
     Neon,
     Wasm,
     Fallback,
@@ -55,6 +64,18 @@ enum Module {
 struct Cli {
     #[arg(short, long, help = "Generate a specific module and print to stdout")]
     module: Option<Module>,
+    #[arg(
+        long,
+        conflicts_with = "module",
+        help = "Print the machine-readable op manifest to stdout"
+    )]
+    manifest: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["module", "manifest"],
+        help = "Print the ops measured by fearless_simd_profile to stdout"
+    )]
+    profile: bool,
 }
 
 impl Module {
@@ -63,6 +84,9 @@ impl Module {
             Self::SimdTypes => mk_simd_types::mk_simd_types(),
             Self::SimdTrait => mk_simd_trait::mk_simd_trait(),
             Self::Ops => mk_ops::mk_ops(),
+            Self::Reference => mk_reference::mk_reference(),
+            Self::Lowering => mk_lowering::mk_lowering(),
+            Self::Custom => mk_custom::mk_custom(),
             Self::Neon => mk_neon::Neon.make_module(),
             Self::Wasm => mk_wasm::WasmSimd128.make_module(),
             Self::Fallback => mk_fallback::Fallback.make_module(),
@@ -73,30 +97,21 @@ impl Module {
         }
     }
 
-    fn generate(self, out: impl Into<std::process::Stdio>) {
-        let code = self.generate_code();
-        let mut child = std::process::Command::new("rustfmt")
-            .stdin(std::process::Stdio::piped())
-            .stdout(out)
-            .spawn()
-            .expect("`rustfmt` should spawn");
-        let mut stdin = child.stdin.take().unwrap();
-        stdin
-            .write_all(
-                format!(
-                    r#"
-// Copyright 2025 the Fearless_SIMD Authors
-// SPDX-License-Identifier: Apache-2.0 OR MIT
-
-// This file is autogenerated by fearless_simd_gen
+    /// The code of the `simd_impl` child module, for levels whose `Simd` implementation is behind a feature.
+    fn generate_simd_impl_code(self) -> Option<TokenStream> {
+        let level = match self {
+            Self::Sse2 => mk_x86::X86::Sse2,
+            Self::Sse4_2 => mk_x86::X86::Sse4_2,
+            Self::Avx2 => mk_x86::X86::Avx2,
+            Self::Avx512 => mk_x86::X86::Avx512,
+            _ => return None,
+        };
+        level.cargo_feature()?;
+        Some(level.make_simd_impl_module())
+    }
 
-{code}"#
-                )
-                .as_bytes(),
-            )
-            .unwrap();
-        drop(stdin);
-        child.wait().expect("`rustfmt` should succeed");
+    fn generate(self, out: impl Into<std::process::Stdio>) {
+        write_formatted(self.generate_code(), out);
     }
 
     fn file_base(self) -> &'static str {
@@ -104,6 +119,9 @@ impl Module {
             Self::SimdTypes => "simd_types",
             Self::SimdTrait => "simd_trait",
             Self::Ops => "ops",
+            Self::Reference => "reference",
+            Self::Lowering => "lowering",
+            Self::Custom => "custom_level",
             Self::Neon => "neon",
             Self::Fallback => "fallback",
             Self::Wasm => "wasm",
@@ -115,10 +133,38 @@ impl Module {
     }
 }
 
+fn write_formatted(code: TokenStream, out: impl Into<std::process::Stdio>) {
+    let mut child = std::process::Command::new("rustfmt")
+        .stdin(std::process::Stdio::piped())
+        .stdout(out)
+        .spawn()
+        .expect("`rustfmt` should spawn");
+    let mut stdin = child.stdin.take().unwrap();
+    stdin
+        .write_all(
+            format!(
+                r#"
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This file is autogenerated by fearless_simd_gen
+
+{code}"#
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    drop(stdin);
+    child.wait().expect("`rustfmt` should succeed");
+}
+
 const MODULES: &[Module] = &[
     Module::SimdTypes,
     Module::SimdTrait,
     Module::Ops,
+    Module::Reference,
+    Module::Lowering,
+    Module::Custom,
     Module::Neon,
     Module::Fallback,
     Module::Wasm,
@@ -130,10 +176,23 @@ const MODULES: &[Module] = &[
 
 const FILE_BASE: &str = "./fearless_simd/src/generated";
 
+/// The file name of the op manifest, relative to [`FILE_BASE`].
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// The ops measured by `fearless_simd_profile`, which is generated along with the modules.
+const PROFILE_FILE: &str = "./fearless_simd_profile/src/generated.rs";
+
 fn main() {
     let cli = Cli::parse();
     if let Some(module) = cli.module {
         module.generate(std::process::Stdio::inherit());
+        if let Some(code) = module.generate_simd_impl_code() {
+            write_formatted(code, std::process::Stdio::inherit());
+        }
+    } else if cli.manifest {
+        print!("{}", mk_manifest::mk_manifest());
+    } else if cli.profile {
+        write_formatted(mk_profile::mk_profile(), std::process::Stdio::inherit());
     } else {
         // generate all modules
         let base_dir = Path::new(FILE_BASE);
@@ -145,6 +204,19 @@ fn main() {
             let path = base_dir.join(format!("{name}.rs"));
             let file = File::create(&path).expect("error creating {path:?}");
             module.generate(file);
+            if let Some(code) = module.generate_simd_impl_code() {
+                let dir = base_dir.join(name);
+                std::fs::create_dir_all(&dir)
+                    .unwrap_or_else(|e| panic!("error creating {dir:?}: {e}"));
+                let path = dir.join("simd_impl.rs");
+                let file = File::create(&path).expect("error creating {path:?}");
+                write_formatted(code, file);
+            }
         }
+        let path = base_dir.join(MANIFEST_FILE);
+        std::fs::write(&path, mk_manifest::mk_manifest())
+            .unwrap_or_else(|e| panic!("error writing {path:?}: {e}"));
+        let file = File::create(PROFILE_FILE).expect("error creating {PROFILE_FILE:?}");
+        write_formatted(mk_profile::mk_profile(), file);
     }
 }
@@ -0,0 +1,406 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The `CustomLevel` trait, which lets other crates implement a SIMD level on top of one of ours, and the blanket
+//! implementation of `Simd` for it.
+//!
+//! Every method of `CustomLevel` defaults to moving its vectors to the host level, calling the host's method, and
+//! moving the result back. This is free, as a custom level stores its vectors in the same way as its host.
+
+use proc_macro2::{Delimiter, Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+
+use crate::generic::generic_op_name;
+use crate::level::native_assoc_types;
+use crate::ops::{Op, OpSig, RefKind, ops_for_type};
+use crate::types::{SIMD_TYPES, ScalarType, VecType, type_imports};
+
+/// The native width of a custom level, which is the only width every host supports.
+const NATIVE_WIDTH: usize = 128;
+
+pub(crate) fn mk_custom() -> TokenStream {
+    let imports = type_imports();
+    let mut trait_methods = vec![];
+    let mut impl_methods = vec![];
+    let mut arch_types = vec![];
+    let mut checks = vec![];
+    for vec_ty in SIMD_TYPES {
+        let ty = vec_ty.rust();
+        arch_types.push(quote! {
+            type #ty = <L::Host as ArchTypes>::#ty;
+        });
+        for op in ops_for_type(vec_ty) {
+            trait_methods.push(trait_method(op, vec_ty));
+            impl_methods.push(impl_method(op, vec_ty));
+        }
+        checks.extend(check_type(vec_ty));
+    }
+    let assoc_types = native_assoc_types(NATIVE_WIDTH);
+    let native_bits = Literal::usize_unsuffixed(NATIVE_WIDTH);
+
+    quote! {
+        use crate::{prelude::*, seal::Seal, arch_types::ArchTypes, Level};
+        use crate::custom::{Conformance, lanes, shift_amounts};
+
+        #imports
+
+        /// A SIMD level implemented outside of this crate, on top of one of its levels.
+        ///
+        /// This is the extension point for experimenting with other backends, such as an emulator which
+        /// counts the operations a kernel performs, or a shim which records them for offloading, without
+        /// forking this crate. Everything implementing this trait implements [`Simd`], so it can be passed
+        /// to any code written against `Simd`.
+        ///
+        /// A custom level has a [host](Self::Host), which is one of the levels of this crate (or another
+        /// custom level). Its vectors are stored in the same way as the host's, and every method of this
+        /// trait forwards to the host's method of the same name by default, so an implementation only
+        /// needs to override the operations it's interested in. The native vectors of a custom level, like
+        /// [`Simd::f32s`], are always 128 bits wide.
+        ///
+        /// The methods of this trait have the same names as those of [`Simd`], so they should be called
+        /// through `Simd`, like `Simd::add_f32x4(self, a, b)`, if both traits are in scope.
+        ///
+        /// Use [`custom::assert_conforms`](crate::custom::assert_conforms) to check that a custom level
+        /// agrees with its host, and [`Level::custom`] to carry one in a [`Level`].
+        ///
+        /// This is only available with the `custom_level` feature.
+        pub trait CustomLevel: Copy + Send + Sync + 'static {
+            /// The level which this level's vectors are stored as, and which its methods forward to.
+            type Host: Simd;
+
+            /// Whether `mul_add`, `mul_sub`, `nmul_add` and `nmul_sub` are computed with a single rounding,
+            /// as in [`Simd::FUSED_MUL_ADD`].
+            ///
+            /// This defaults to the host's value, and must be overridden if any of these ops is overridden
+            /// to behave differently.
+            const FUSED_MUL_ADD: bool = <Self::Host as Simd>::FUSED_MUL_ADD;
+
+            /// The host level, which the methods forward to.
+            fn host(self) -> Self::Host;
+
+            /// The [`Level`] of this level, as in [`Simd::level`].
+            ///
+            /// This defaults to the host's level, so that [`dispatch`](crate::dispatch) selects the
+            /// host. Implementations which want to be recovered from the level can return
+            /// [`Level::custom`] instead.
+            #[inline(always)]
+            fn level(self) -> Level {
+                Simd::level(self.host())
+            }
+
+            /// Run `f` with the target features of this level enabled, as in [`Simd::vectorize`].
+            ///
+            /// This defaults to the host's implementation.
+            #[inline]
+            fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
+                Simd::vectorize(self.host(), f)
+            }
+
+            #( #trait_methods )*
+        }
+
+        impl<L: CustomLevel> Seal for L {}
+
+        impl<L: CustomLevel> ArchTypes for L {
+            #( #arch_types )*
+        }
+
+        impl<L: CustomLevel> Simd for L {
+            #( #assoc_types )*
+
+            const NATIVE_BITS: usize = #native_bits;
+
+            const FUSED_MUL_ADD: bool = <L as CustomLevel>::FUSED_MUL_ADD;
+
+            const SOFT_FLOAT: bool = <L::Host as Simd>::SOFT_FLOAT;
+
+            #[inline(always)]
+            fn level(self) -> Level {
+                <L as CustomLevel>::level(self)
+            }
+
+            #[inline]
+            fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
+                <L as CustomLevel>::vectorize(self, f)
+            }
+
+            #( #impl_methods )*
+        }
+
+        /// Check every lane-wise operation of `level` against its host, for
+        /// [`custom::assert_conforms`](crate::custom::assert_conforms).
+        pub(crate) fn check_ops<L: CustomLevel>(level: L, conformance: &Conformance<'_>) {
+            let host = level.host();
+            #( #checks )*
+        }
+    }
+}
+
+/// The const generic parameter of `op`, if it has one.
+fn const_param(op: Op) -> Option<Ident> {
+    let name = match op.sig {
+        OpSig::Slide { .. } => "SHIFT",
+        OpSig::ElementRotate { .. } | OpSig::ElementShift { .. } => "OFFSET",
+        OpSig::GfMulConst => "FACTOR",
+        OpSig::ExtractLane { dynamic: false } | OpSig::InsertLane => "LANE",
+        _ => return None,
+    };
+    Some(Ident::new(name, Span::call_site()))
+}
+
+/// How a type in a `Simd` method's signature refers to a vector.
+enum Shape {
+    /// A vector, like `f32x4<Self>`.
+    Vector(Ident),
+    /// A pair of vectors, like `(f32x4<Self>, f32x4<Self>)`.
+    Pair(Ident, Ident),
+    /// A reference to a vector, like `&mut mask32x4<Self>`.
+    Reference(Ident),
+    /// Anything else, such as a scalar or an array, which is the same for a level and its host.
+    Other,
+}
+
+fn shape(ty: &TokenStream) -> Shape {
+    let tokens: Vec<TokenTree> = ty.clone().into_iter().collect();
+    let vector = |tokens: &[TokenTree]| match tokens {
+        [
+            TokenTree::Ident(name),
+            TokenTree::Punct(open),
+            TokenTree::Ident(simd),
+            TokenTree::Punct(close),
+        ] if open.as_char() == '<' && simd == "Self" && close.as_char() == '>' => {
+            Some(name.clone())
+        }
+        _ => None,
+    };
+    match tokens.as_slice() {
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+            let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+            let mut halves =
+                inner.split(|token| matches!(token, TokenTree::Punct(p) if p.as_char() == ','));
+            match (
+                halves.next().and_then(vector),
+                halves.next().and_then(vector),
+            ) {
+                (Some(lo), Some(hi)) => Shape::Pair(lo, hi),
+                _ => Shape::Other,
+            }
+        }
+        [TokenTree::Punct(amp), rest @ ..] if amp.as_char() == '&' => {
+            let rest = match rest {
+                [TokenTree::Ident(keyword), rest @ ..] if keyword == "mut" => rest,
+                rest => rest,
+            };
+            vector(rest).map_or(Shape::Other, Shape::Reference)
+        }
+        tokens => vector(tokens).map_or(Shape::Other, Shape::Vector),
+    }
+}
+
+/// The method of `CustomLevel` for `op` on `vec_ty`, with a default implementation which forwards to the host.
+fn trait_method(op: Op, vec_ty: &VecType) -> TokenStream {
+    let name = generic_op_name(op.method, vec_ty);
+    let method_sig = op.simd_trait_method_sig(vec_ty);
+    let sig = op.simd_trait_sig_parts(vec_ty, quote! { Self });
+    let doc = format!("Forwards [`Simd::{name}`] to the host by default.");
+
+    let body = match op.sig {
+        // These borrow from the vector, so can't go through a host vector. The storage is the host's, which
+        // has the same layout as the array.
+        OpSig::AsArray { kind: RefKind::Ref } => {
+            quote! { crate::transmute::checked_cast_ref(&a.val) }
+        }
+        OpSig::AsArray { kind: RefKind::Mut } => {
+            quote! { crate::transmute::checked_cast_mut(&mut a.val) }
+        }
+        _ => {
+            let mut setup = vec![];
+            let mut write_back = vec![];
+            let args = sig
+                .arg_names
+                .iter()
+                .zip(&sig.arg_tys)
+                .map(|(arg, ty)| match shape(ty) {
+                    Shape::Vector(vec) => quote! { #vec { val: #arg.val, simd: host } },
+                    Shape::Reference(vec) => {
+                        let host_arg = format_ident!("host_{arg}");
+                        setup.push(
+                            quote! { let mut #host_arg = #vec { val: #arg.val, simd: host }; },
+                        );
+                        write_back.push(quote! { #arg.val = #host_arg.val; });
+                        quote! { &mut #host_arg }
+                    }
+                    Shape::Pair(..) | Shape::Other => quote! { #arg },
+                })
+                .collect::<Vec<_>>();
+            let turbofish = const_param(op).map(|param| quote! { ::<#param> });
+            let call = quote! { Simd::#name #turbofish(host #(, #args)*) };
+            match shape(&sig.ret) {
+                Shape::Vector(vec) => quote! {
+                    let host = self.host();
+                    let result = #call;
+                    #vec { val: result.val, simd: self }
+                },
+                Shape::Pair(lo, hi) => quote! {
+                    let host = self.host();
+                    let (lo, hi) = #call;
+                    (#lo { val: lo.val, simd: self }, #hi { val: hi.val, simd: self })
+                },
+                Shape::Reference(_) | Shape::Other if sig.ret.to_string() == "()" => quote! {
+                    let host = self.host();
+                    #( #setup )*
+                    #call;
+                    #( #write_back )*
+                },
+                Shape::Reference(_) | Shape::Other => quote! {
+                    let host = self.host();
+                    #call
+                },
+            }
+        }
+    };
+
+    quote! {
+        #[doc = #doc]
+        #[inline(always)]
+        #method_sig {
+            #body
+        }
+    }
+}
+
+/// The method of the blanket `Simd` implementation for `op` on `vec_ty`, which calls the `CustomLevel` method.
+fn impl_method(op: Op, vec_ty: &VecType) -> TokenStream {
+    let name = generic_op_name(op.method, vec_ty);
+    let method_sig = op.simd_trait_method_sig(vec_ty);
+    let sig = op.simd_trait_sig_parts(vec_ty, quote! { Self });
+    let args = &sig.arg_names;
+    let turbofish = const_param(op).map(|param| quote! { ::<#param> });
+    let call = quote! { <L as CustomLevel>::#name #turbofish(self #(, #args)*) };
+    let body = if sig.ret.to_string() == "()" {
+        quote! { #call; }
+    } else {
+        call
+    };
+    quote! {
+        #[inline(always)]
+        #method_sig {
+            #body
+        }
+    }
+}
+
+/// The arguments of the lane-wise ops which the conformance check covers, or `None` for other ops.
+///
+/// These are the same ops as those with a function in the `reference` module.
+fn check_args(op: Op) -> Option<&'static [&'static str]> {
+    Some(match op.sig {
+        OpSig::Unary
+        | OpSig::Cvt { .. }
+        | OpSig::Reinterpret { .. }
+        | OpSig::WidenNarrow { .. } => &["a"],
+        OpSig::Binary
+        | OpSig::Compare
+        | OpSig::Zip { .. }
+        | OpSig::Unzip { .. }
+        | OpSig::Interleave
+        | OpSig::Deinterleave => &["a", "b"],
+        OpSig::ShiftSigned => &["a", "shifts"],
+        OpSig::Ternary => &["a", "b", "c"],
+        OpSig::Shift => &["a", "shift"],
+        _ => return None,
+    })
+}
+
+/// The type of vector `op` returns for `vec_ty`, of the ops covered by [`check_args`].
+fn check_result_ty(op: Op, vec_ty: &VecType) -> VecType {
+    match op.sig {
+        OpSig::Compare => vec_ty.mask_ty(),
+        OpSig::Cvt {
+            target_ty,
+            scalar_bits,
+            ..
+        }
+        | OpSig::Reinterpret {
+            target_ty,
+            scalar_bits,
+        } => vec_ty.reinterpret(target_ty, scalar_bits),
+        OpSig::WidenNarrow { target_ty } => target_ty,
+        _ => *vec_ty,
+    }
+}
+
+/// The checks of the lane-wise ops on `vec_ty`, run for a number of rounds of different inputs.
+fn check_type(vec_ty: &VecType) -> Option<TokenStream> {
+    if vec_ty.scalar == ScalarType::Mask {
+        return None;
+    }
+    let ops = ops_for_type(vec_ty)
+        .into_iter()
+        .filter_map(|op| Some((op, check_args(op)?)))
+        .collect::<Vec<_>>();
+    if ops.is_empty() {
+        return None;
+    }
+
+    let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+    let signed = ScalarType::Int.rust(vec_ty.scalar_bits);
+    let len = Literal::usize_unsuffixed(vec_ty.len);
+    let uses = |arg: &str| ops.iter().any(|(_, args)| args.contains(&arg));
+    let mut inputs = vec![];
+    for (arg, offset) in [
+        ("a", quote! { round }),
+        ("b", quote! { 3 * round + 1 }),
+        ("c", quote! { 5 * round + 2 }),
+    ] {
+        if uses(arg) {
+            let arg = Ident::new(arg, Span::call_site());
+            inputs.push(quote! { let #arg: [#scalar; #len] = lanes(#offset); });
+        }
+    }
+    if uses("shifts") {
+        inputs.push(quote! { let shifts: [#signed; #len] = shift_amounts(round); });
+    }
+    if uses("shift") {
+        inputs.push(quote! { let shift = (round % 8) as u32; });
+    }
+
+    let checks = ops.iter().map(|(op, args)| {
+        let name = generic_op_name(op.method, vec_ty);
+        let method = name.to_string();
+        let result = |simd: TokenStream| {
+            let args = args.iter().map(|&name| {
+                let arg = Ident::new(name, Span::call_site());
+                if name == "shift" {
+                    quote! { #arg }
+                } else {
+                    quote! { #arg.simd_into(#simd) }
+                }
+            });
+            let as_array = generic_op_name("as_array", &check_result_ty(*op, vec_ty));
+            let call = quote! { Simd::#name(#simd #(, #args)*) };
+            if matches!(op.sig, OpSig::Interleave | OpSig::Deinterleave) {
+                quote! {
+                    || {
+                        let (lo, hi) = #call;
+                        (Simd::#as_array(#simd, lo), Simd::#as_array(#simd, hi))
+                    }
+                }
+            } else {
+                quote! { || Simd::#as_array(#simd, #call) }
+            }
+        };
+        let actual = result(quote! { level });
+        let expected = result(quote! { host });
+        quote! {
+            conformance.check(#method, #actual, #expected);
+        }
+    });
+
+    Some(quote! {
+        for round in 0..crate::custom::ROUNDS {
+            #( #inputs )*
+            #( #checks )*
+        }
+    })
+}
@@ -3,85 +3,24 @@
 
 use crate::arch::fallback;
 use crate::generic::{
-    generic_from_bytes, generic_mask_from_bitmask, generic_mask_set, generic_mask_to_bitmask,
-    generic_op_name, generic_to_bytes, integer_lane_mask_splat_arg,
+    const_lane_check, generic_extract_dyn, generic_from_bytes, generic_mask_from_bitmask,
+    generic_mask_set, generic_mask_to_bitmask, generic_mask_to_int, generic_op_name,
+    generic_relaxed, generic_to_bytes, integer_lane_mask_splat_arg,
 };
 use crate::level::Level;
 use crate::ops::{Op, OpSig, RefKind, valid_reinterpret};
 use crate::types::{ScalarType, VecType};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 #[derive(Clone, Copy)]
 pub(crate) struct Fallback;
 
-pub(crate) fn float_ext_prelude() -> TokenStream {
+/// The import of the scalar float operations which [`fallback::expr`] uses, for every module which includes fallback
+/// implementations.
+pub(crate) fn fallback_float_prelude() -> TokenStream {
     quote! {
-        #[cfg(all(feature = "libm", not(feature = "std")))]
-        #[allow(dead_code, reason = "Generated backends use different subsets of these helpers")]
-        trait FloatExt {
-            fn floor(self) -> Self;
-            fn ceil(self) -> Self;
-            fn round_ties_even(self) -> Self;
-            fn fract(self) -> Self;
-            fn sqrt(self) -> Self;
-            fn trunc(self) -> Self;
-        }
-        #[cfg(all(feature = "libm", not(feature = "std")))]
-        impl FloatExt for f32 {
-            #[inline(always)]
-            fn floor(self) -> f32 {
-                libm::floorf(self)
-            }
-            #[inline(always)]
-            fn ceil(self) -> f32 {
-                libm::ceilf(self)
-            }
-            #[inline(always)]
-            fn round_ties_even(self) -> f32 {
-                libm::rintf(self)
-            }
-            #[inline(always)]
-            fn sqrt(self) -> f32 {
-                libm::sqrtf(self)
-            }
-            #[inline(always)]
-            fn fract(self) -> f32 {
-                self - self.trunc()
-            }
-            #[inline(always)]
-            fn trunc(self) -> f32 {
-                libm::truncf(self)
-            }
-        }
-
-        #[cfg(all(feature = "libm", not(feature = "std")))]
-        impl FloatExt for f64 {
-            #[inline(always)]
-            fn floor(self) -> f64 {
-                libm::floor(self)
-            }
-            #[inline(always)]
-            fn ceil(self) -> f64 {
-                libm::ceil(self)
-            }
-            #[inline(always)]
-            fn round_ties_even(self) -> f64 {
-                libm::rint(self)
-            }
-            #[inline(always)]
-            fn sqrt(self) -> f64 {
-                libm::sqrt(self)
-            }
-            #[inline(always)]
-            fn fract(self) -> f64 {
-                self - self.trunc()
-            }
-            #[inline(always)]
-            fn trunc(self) -> f64 {
-                libm::trunc(self)
-            }
-        }
+        use crate::fallback_float::FallbackFloat;
     }
 }
 
@@ -98,6 +37,14 @@ impl Level for Fallback {
         512
     }
 
+    fn fused_mul_add(&self) -> bool {
+        false
+    }
+
+    fn soft_float(&self) -> TokenStream {
+        quote! { cfg!(feature = "soft_float") }
+    }
+
     fn enabled_target_features(&self) -> Option<&'static str> {
         None
     }
@@ -113,12 +60,12 @@ impl Level for Fallback {
     }
 
     fn make_module_prelude(&self) -> TokenStream {
-        let float_ext = float_ext_prelude();
+        let fallback_float = fallback_float_prelude();
 
         quote! {
             use core::ops::*;
 
-            #float_ext
+            #fallback_float
         }
     }
 
@@ -142,6 +89,9 @@ impl Level for Fallback {
     }
 
     fn make_method(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        if let Some(method) = generic_relaxed(op, vec_ty) {
+            return method;
+        }
         let Op { sig, method, .. } = op;
         let method_sig = op.simd_trait_method_sig(vec_ty);
 
@@ -187,7 +137,103 @@ impl Level for Fallback {
                         .map(|idx| {
                             let scalar_ty = target_ty.scalar.rust(target_ty.scalar_bits);
                             let a = lane(quote! { a }, vec_ty, idx);
-                            quote! { #a as #scalar_ty }
+                            if method == "pack_clamped_u8" {
+                                // Float-to-int `as` casts saturate, and map NaN to 0.
+                                quote! { FallbackFloat::round_ties_even(#a) as #scalar_ty }
+                            } else if method.starts_with("pack_saturate") {
+                                let (min, max) = match target_ty.scalar {
+                                    ScalarType::Int => (i64::from(i8::MIN), i64::from(i8::MAX)),
+                                    _ => (0, i64::from(u8::MAX)),
+                                };
+                                let max = Literal::i64_unsuffixed(max);
+                                if vec_ty.scalar == ScalarType::Unsigned {
+                                    quote! { #a.min(#max) as #scalar_ty }
+                                } else {
+                                    let min = Literal::i64_unsuffixed(min);
+                                    quote! { #a.clamp(#min, #max) as #scalar_ty }
+                                }
+                            } else {
+                                quote! { #a as #scalar_ty }
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                );
+
+                quote! {
+                    #method_sig {
+                        #items.simd_into(self)
+                    }
+                }
+            }
+            OpSig::Binary if matches!(method, "dot2" | "dot3" | "dot4") => {
+                let products = make_list(
+                    (0..vec_ty.len)
+                        .map(|idx| {
+                            let a = lane(quote! { a }, vec_ty, idx);
+                            let b = lane(quote! { b }, vec_ty, idx);
+                            fallback::expr("mul", vec_ty, &[a, b])
+                        })
+                        .collect::<Vec<_>>(),
+                );
+                let group_len = if method == "dot2" { 2 } else { 4 };
+                let items = make_list(
+                    (0..vec_ty.len)
+                        .map(|idx| {
+                            let [p0, p1, p2, p3] =
+                                core::array::from_fn(|i| idx / group_len * group_len + i);
+                            let add = |a: TokenStream, b: TokenStream| {
+                                fallback::expr("add", vec_ty, &[a, b])
+                            };
+                            // Sum in the same order as `dpps`, which replaces ignored products with zero.
+                            match method {
+                                "dot2" => add(quote! { p[#p0] }, quote! { p[#p1] }),
+                                "dot3" => add(
+                                    add(quote! { p[#p0] }, quote! { p[#p1] }),
+                                    add(quote! { p[#p2] }, quote! { 0.0 }),
+                                ),
+                                _ => add(
+                                    add(quote! { p[#p0] }, quote! { p[#p1] }),
+                                    add(quote! { p[#p2] }, quote! { p[#p3] }),
+                                ),
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                );
+
+                quote! {
+                    #method_sig {
+                        let p = #products;
+                        #items.simd_into(self)
+                    }
+                }
+            }
+            OpSig::Binary if method == "avg_round" => {
+                let items = make_list(
+                    (0..vec_ty.len)
+                        .map(|idx| {
+                            // The bits which are set in both are kept, and half of those set in one are added, so
+                            // that this can't overflow.
+                            quote! { (a[#idx] | b[#idx]) - ((a[#idx] ^ b[#idx]) >> 1) }
+                        })
+                        .collect::<Vec<_>>(),
+                );
+
+                quote! {
+                    #method_sig {
+                        #items.simd_into(self)
+                    }
+                }
+            }
+            OpSig::Binary if method == "table_lookup" => {
+                let items = make_list(
+                    (0..vec_ty.len)
+                        .map(|idx| {
+                            quote! {
+                                {
+                                    let index = b[#idx] as usize;
+                                    if index < 16 { a[index] } else { 0 }
+                                }
+                            }
                         })
                         .collect::<Vec<_>>(),
                 );
@@ -203,12 +249,21 @@ impl Level for Fallback {
                     (0..vec_ty.len)
                         .map(|idx| {
                             let b_lane = lane(quote! { b }, vec_ty, idx);
-                            let b = if fallback::translate_op(
-                                method,
-                                vec_ty.scalar == ScalarType::Float,
-                            )
-                            .map(rhs_reference)
-                            .unwrap_or(true)
+                            let b = if matches!(method, "shlv" | "shrv") {
+                                // The wrapping shifts take the amount as a `u32`.
+                                if vec_ty.scalar == ScalarType::Unsigned && vec_ty.scalar_bits == 32
+                                {
+                                    b_lane
+                                } else {
+                                    quote! { #b_lane as u32 }
+                                }
+                            } else if !fallback::uses_fallback_float(method, vec_ty)
+                                && fallback::translate_op(
+                                    method,
+                                    vec_ty.scalar == ScalarType::Float,
+                                )
+                                .map(rhs_reference)
+                                .unwrap_or(true)
                             {
                                 quote! { &#b_lane }
                             } else {
@@ -228,6 +283,30 @@ impl Level for Fallback {
                     }
                 }
             }
+            OpSig::ShiftSigned => {
+                let items = make_list(
+                    (0..vec_ty.len)
+                        .map(|idx| {
+                            let a = lane(quote! { a }, vec_ty, idx);
+                            let b = lane(quote! { b }, vec_ty, idx);
+                            // Negating the amount as a `u32` gives the magnitude of a negative amount.
+                            quote! {
+                                if #b >= 0 {
+                                    #a.wrapping_shl(#b as u32)
+                                } else {
+                                    #a.wrapping_shr((#b as u32).wrapping_neg())
+                                }
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                );
+
+                quote! {
+                    #method_sig {
+                        #items.simd_into(self)
+                    }
+                }
+            }
             OpSig::Shift => {
                 let items = make_list(
                     (0..vec_ty.len)
@@ -258,6 +337,18 @@ impl Level for Fallback {
                             a.mul(b).sub(c)
                         }
                     }
+                } else if method == "nmul_add" {
+                    quote! {
+                        #method_sig {
+                            c.sub(a.mul(b))
+                        }
+                    }
+                } else if method == "nmul_sub" {
+                    quote! {
+                        #method_sig {
+                            a.mul(b).neg().sub(c)
+                        }
+                    }
                 } else {
                     let args = [
                         quote! { a.into() },
@@ -394,6 +485,9 @@ impl Level for Fallback {
                 let n = vec_ty.len;
                 quote! {
                     #method_sig {
+                        if SHIFT >= #n {
+                            return b;
+                        }
                         let mut dest = [Default::default(); #n];
                         dest[..#n - SHIFT].copy_from_slice(&a.val.0[SHIFT..]);
                         dest[#n - SHIFT..].copy_from_slice(&b.val.0[..SHIFT]);
@@ -404,6 +498,56 @@ impl Level for Fallback {
             OpSig::ElementRotate { .. } | OpSig::ElementShift { .. } => {
                 unreachable!("element moves use generic lowering")
             }
+            OpSig::BitTranspose => unreachable!("bit transposes use generic lowering"),
+            OpSig::GfMulConst => unreachable!("GF(2^8) multiplication uses generic lowering"),
+            OpSig::Compress { expand } => {
+                let n = vec_ty.len;
+                let (dest, src) = if expand {
+                    (quote! { i }, quote! { count })
+                } else {
+                    (quote! { count }, quote! { i })
+                };
+                let mask_as_array = generic_op_name("as_array", &vec_ty.mask_ty());
+                quote! {
+                    #method_sig {
+                        let mask = self.#mask_as_array(mask);
+                        let mut result = [0; #n];
+                        let mut count = 0;
+                        for i in 0..#n {
+                            if mask[i] != 0 {
+                                result[#dest] = a[#src];
+                                count += 1;
+                            }
+                        }
+                        result.simd_into(self)
+                    }
+                }
+            }
+            OpSig::WidenAccumulate { acc_ty } => {
+                let acc_scalar = acc_ty.scalar.rust(acc_ty.scalar_bits);
+                let items = make_list(
+                    (0..vec_ty.len)
+                        .map(|idx| {
+                            quote! { acc[#idx].wrapping_add(a[#idx].abs_diff(b[#idx]) as #acc_scalar) }
+                        })
+                        .collect::<Vec<_>>(),
+                );
+
+                quote! {
+                    #method_sig {
+                        #items.simd_into(self)
+                    }
+                }
+            }
+            OpSig::ReduceWidened => {
+                let result = vec_ty.scalar.rust(32);
+                let lanes = (0..vec_ty.len).map(|idx| quote! { #result::from(a[#idx]) });
+                quote! {
+                    #method_sig {
+                        #(#lanes)+*
+                    }
+                }
+            }
             OpSig::SwizzleDynWithinBlocks => {
                 assert_eq!(
                     vec_ty.n_bits(),
@@ -500,7 +644,29 @@ impl Level for Fallback {
             }
             OpSig::MaskFromBitmask => generic_mask_from_bitmask(method_sig, vec_ty),
             OpSig::MaskToBitmask => generic_mask_to_bitmask(method_sig, vec_ty),
+            OpSig::MaskToInt => generic_mask_to_int(method_sig, vec_ty),
             OpSig::MaskSet => generic_mask_set(method_sig, vec_ty),
+            OpSig::ExtractLane { dynamic: false } => {
+                let check = const_lane_check(vec_ty);
+                quote! {
+                    #method_sig {
+                        #check
+                        a.val.0[LANE]
+                    }
+                }
+            }
+            OpSig::ExtractLane { dynamic: true } => generic_extract_dyn(method_sig, vec_ty),
+            OpSig::InsertLane => {
+                let check = const_lane_check(vec_ty);
+                quote! {
+                    #method_sig {
+                        #check
+                        let mut lanes = a.val.0;
+                        lanes[LANE] = value;
+                        lanes.simd_into(self)
+                    }
+                }
+            }
             OpSig::LoadInterleaved {
                 block_size,
                 block_count,
@@ -610,7 +776,16 @@ fn lane(value: TokenStream, vec_ty: &VecType, idx: usize) -> TokenStream {
 fn rhs_reference(method: &str) -> bool {
     !matches!(
         method,
-        "copysign" | "min" | "max" | "wrapping_sub" | "wrapping_mul" | "wrapping_add"
+        "copysign"
+            | "min"
+            | "max"
+            | "wrapping_sub"
+            | "wrapping_mul"
+            | "wrapping_add"
+            | "wrapping_shl"
+            | "wrapping_shr"
+            | "saturating_add"
+            | "saturating_sub"
     )
 }
 
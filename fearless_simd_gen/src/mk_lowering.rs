@@ -0,0 +1,129 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A const table of the intrinsics each level lowers each op to, for tools which would otherwise have to read the
+//! `Simd` documentation.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::generic::generic_op_name;
+use crate::lowering::{LEVELS, Lowering, LoweringKind, lowerings};
+use crate::ops::ops_for_type;
+use crate::types::SIMD_TYPES;
+
+pub(crate) fn mk_lowering() -> TokenStream {
+    let mut entries = vec![];
+    for vec_ty in SIMD_TYPES {
+        for op in ops_for_type(vec_ty) {
+            let method = generic_op_name(op.method, vec_ty).to_string();
+            entries.push((
+                method,
+                op.method,
+                vec_ty.rust_name(),
+                lowerings().get(op, vec_ty),
+            ));
+        }
+    }
+    // Sorted, so that `find` can binary search.
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let level_names = LEVELS;
+    let n_levels = Literal::usize_unsuffixed(LEVELS.len());
+    let entries = entries.iter().map(|(method, op, vec_ty, levels)| {
+        let levels = levels.iter().map(lowering_expr);
+        quote! { op(#method, #op, #vec_ty, [#( #levels ),*]) }
+    });
+
+    quote! {
+        /// The names of the levels, in the order of [`OpLowering::levels`].
+        pub const LEVELS: [&str; #n_levels] = [#( #level_names ),*];
+
+        /// How a level implements an op for a vector type.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum LoweringKind {
+            /// The level shares the implementation of the `Fallback` level.
+            ///
+            /// This is either scalar code, or composed from other methods, which the level may implement with
+            /// intrinsics.
+            Portable,
+            /// The level has its own implementation.
+            Native,
+            /// The vector is split into halves, which the level implements separately, and the results are combined.
+            Split,
+        }
+
+        /// How one level implements an op for a vector type.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct Lowering {
+            /// How the op is implemented.
+            pub kind: LoweringKind,
+            /// The intrinsics used, in the order they first appear, including those of any other `Simd` methods
+            /// which the implementation calls.
+            ///
+            /// This is empty if the implementation doesn't use any SIMD intrinsics, for example because it only
+            /// reinterprets the vector, or works on scalars.
+            pub intrinsics: &'static [&'static str],
+        }
+
+        /// How every level implements an op for a vector type.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct OpLowering {
+            /// The name of the [`Simd`](crate::Simd) method, like `"mul_add_f32x4"`.
+            pub method: &'static str,
+            /// The name of the op, like `"mul_add"`.
+            pub op: &'static str,
+            /// The name of the vector type, like `"f32x4"`.
+            pub vector: &'static str,
+            /// The lowering on each level, in the order of [`LEVELS`].
+            pub levels: [Lowering; #n_levels],
+        }
+
+        impl OpLowering {
+            /// The lowering on the level named `level`, which is one of [`LEVELS`].
+            pub fn level(&self, level: &str) -> Option<&Lowering> {
+                let index = LEVELS.iter().position(|name| *name == level)?;
+                self.levels.get(index)
+            }
+        }
+
+        /// Finds the lowering of the [`Simd`](crate::Simd) method named `method`, like `"mul_add_f32x4"`.
+        pub fn find(method: &str) -> Option<&'static OpLowering> {
+            let index = LOWERINGS
+                .binary_search_by(|lowering| lowering.method.cmp(method))
+                .ok()?;
+            LOWERINGS.get(index)
+        }
+
+        /// The lowering of every [`Simd`](crate::Simd) method, sorted by method name.
+        pub const LOWERINGS: &[OpLowering] = &[#( #entries ),*];
+
+        const fn op(
+            method: &'static str,
+            op: &'static str,
+            vector: &'static str,
+            levels: [Lowering; #n_levels],
+        ) -> OpLowering {
+            OpLowering { method, op, vector, levels }
+        }
+
+        const PORTABLE: Lowering = Lowering { kind: LoweringKind::Portable, intrinsics: &[] };
+
+        const fn native(intrinsics: &'static [&'static str]) -> Lowering {
+            Lowering { kind: LoweringKind::Native, intrinsics }
+        }
+
+        const fn split(intrinsics: &'static [&'static str]) -> Lowering {
+            Lowering { kind: LoweringKind::Split, intrinsics }
+        }
+    }
+}
+
+fn lowering_expr(lowering: &Lowering) -> TokenStream {
+    let intrinsics = &lowering.intrinsics;
+    match lowering.kind {
+        LoweringKind::Portable => quote! { PORTABLE },
+        LoweringKind::Native => quote! { native(&[#( #intrinsics ),*]) },
+        LoweringKind::Split => quote! { split(&[#( #intrinsics ),*]) },
+    }
+}
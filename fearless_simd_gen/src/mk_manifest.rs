@@ -0,0 +1,179 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A machine-readable manifest of every generated operation, for external tooling.
+//!
+//! The manifest is written as JSON next to the generated modules. It lists each op once, with its
+//! documentation and the trait it's exposed through, then each vector type it's implemented for
+//! with the `Simd` trait method signature and how each level lowers it, in the same order as the
+//! top-level `levels` list:
+//!
+//! - `native`: the level implements the op with its own instructions.
+//! - `split`: the op is split into halves which the level implements natively, then recombined.
+//! - `portable`: the level shares the fallback level's implementation. This covers scalar loops,
+//!   ops which are just a reinterpretation of the vector's bytes, and ops composed from other
+//!   ops, which the level may implement natively.
+//!
+//! Alongside, `intrinsics` lists the intrinsics each level uses, as worked out by the `lowering`
+//! module.
+
+use std::fmt::Write as _;
+
+use proc_macro2::TokenStream;
+
+use crate::lowering::{LEVELS, Lowering, lowerings};
+use crate::ops::{Op, OpKind, TyFlavor, ops_for_type};
+use crate::types::{SIMD_TYPES, ScalarType, VecType};
+
+struct OpEntry {
+    op: Op,
+    impls: Vec<(VecType, &'static [Lowering; LEVELS.len()])>,
+}
+
+pub(crate) fn mk_manifest() -> String {
+    let mut entries: Vec<OpEntry> = vec![];
+    for vec_ty in SIMD_TYPES {
+        for op in ops_for_type(vec_ty) {
+            let lowerings = lowerings().get(op, vec_ty);
+            match entries
+                .iter_mut()
+                .find(|entry| entry.op.method == op.method)
+            {
+                Some(entry) => entry.impls.push((*vec_ty, lowerings)),
+                None => entries.push(OpEntry {
+                    op,
+                    impls: vec![(*vec_ty, lowerings)],
+                }),
+            }
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("{\n");
+    out.push_str("  \"generator\": \"fearless_simd_gen\",\n");
+    let levels = LEVELS.map(json_string).join(", ");
+    writeln!(out, "  \"levels\": [{levels}],").unwrap();
+
+    out.push_str("  \"types\": [\n");
+    for (i, vec_ty) in SIMD_TYPES.iter().enumerate() {
+        let sep = if i + 1 == SIMD_TYPES.len() { "" } else { "," };
+        writeln!(
+            out,
+            "    {{\"name\": {}, \"scalar\": {}, \"lanes\": {}, \"bits\": {}}}{sep}",
+            json_string(&vec_ty.rust_name()),
+            json_string(scalar_name(vec_ty)),
+            vec_ty.len,
+            vec_ty.n_bits(),
+        )
+        .unwrap();
+    }
+    out.push_str("  ],\n");
+
+    out.push_str("  \"ops\": [\n");
+    for (i, entry) in entries.iter().enumerate() {
+        let op = &entry.op;
+        out.push_str("    {\n");
+        writeln!(out, "      \"method\": {},", json_string(op.method)).unwrap();
+        writeln!(out, "      \"kind\": {},", json_string(kind_name(op.kind))).unwrap();
+        writeln!(
+            out,
+            "      \"doc\": {},",
+            json_string(&op.format_docstring(TyFlavor::SimdTrait))
+        )
+        .unwrap();
+        out.push_str("      \"impls\": [\n");
+        for (j, (vec_ty, lowerings)) in entry.impls.iter().enumerate() {
+            let sep = if j + 1 == entry.impls.len() { "" } else { "," };
+            let kinds = lowerings
+                .iter()
+                .map(|lowering| json_string(lowering.kind.name()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let intrinsics = lowerings
+                .iter()
+                .map(|lowering| {
+                    let intrinsics = lowering
+                        .intrinsics
+                        .iter()
+                        .map(|intrinsic| json_string(intrinsic));
+                    format!("[{}]", intrinsics.collect::<Vec<_>>().join(", "))
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                out,
+                "        {{\"type\": {}, \"signature\": {}, \"lowering\": [{kinds}], \"intrinsics\": [{intrinsics}]}}{sep}",
+                json_string(&vec_ty.rust_name()),
+                json_string(&tidy_tokens(&op.simd_trait_method_sig(vec_ty))),
+            )
+            .unwrap();
+        }
+        out.push_str("      ]\n");
+        let sep = if i + 1 == entries.len() { "" } else { "," };
+        writeln!(out, "    }}{sep}").unwrap();
+    }
+    out.push_str("  ]\n");
+    out.push_str("}\n");
+    out
+}
+
+fn kind_name(kind: OpKind) -> &'static str {
+    match kind {
+        OpKind::Overloaded(_) => "overloaded",
+        OpKind::BaseTraitMethod => "base_trait_method",
+        OpKind::VecTraitMethod => "vec_trait_method",
+        OpKind::OwnTrait => "own_trait",
+        OpKind::AssociatedOnly => "associated_only",
+    }
+}
+
+fn scalar_name(vec_ty: &VecType) -> &'static str {
+    match vec_ty.scalar {
+        ScalarType::Float => "float",
+        ScalarType::Unsigned => "unsigned",
+        ScalarType::Int => "int",
+        ScalarType::Mask => "mask",
+    }
+}
+
+/// Render a signature the way rustfmt would, rather than with a space between every token.
+fn tidy_tokens(tokens: &TokenStream) -> String {
+    let mut s = tokens.to_string();
+    for (from, to) in [
+        (" ,", ","),
+        (" :", ":"),
+        (" < ", "<"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        ("( ", "("),
+        (" (", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+        ("& ", "&"),
+        ("- >", "->"),
+        ("> (", ">("),
+    ] {
+        s = s.replace(from, to);
+    }
+    s.replace("->", " -> ").replace("  ", " ")
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => write!(out, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
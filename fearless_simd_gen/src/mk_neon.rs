@@ -5,8 +5,10 @@ use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{ToTokens as _, format_ident, quote};
 
 use crate::generic::{
-    fallback_method, generic_as_array, generic_from_array, generic_from_bytes, generic_mask_set,
-    generic_op_name, generic_store_array, generic_to_bytes, integer_lane_mask_splat_arg,
+    const_lane_check, fallback_method, generic_as_array, generic_extract_dyn, generic_from_array,
+    generic_from_bytes, generic_mask_set, generic_mask_to_int, generic_op_name, generic_relaxed,
+    generic_store_array, generic_to_bytes, integer_lane_mask_splat_arg,
+    leading_zeros_from_count_ones, table_compress_bytes, trailing_zeros_from_count_ones,
 };
 use crate::level::Level;
 use crate::ops::{Op, SlideGranularity, valid_reinterpret};
@@ -32,6 +34,10 @@ impl Level for Neon {
         512
     }
 
+    fn fused_mul_add(&self) -> bool {
+        true
+    }
+
     fn enabled_target_features(&self) -> Option<&'static str> {
         Some("neon")
     }
@@ -76,6 +82,9 @@ impl Level for Neon {
     }
 
     fn make_method(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        if let Some(method) = generic_relaxed(op, vec_ty) {
+            return method;
+        }
         let Op { sig, method, .. } = op;
         let method_sig = op.simd_trait_method_sig(vec_ty);
 
@@ -111,6 +120,9 @@ impl Level for Neon {
                     quote! { #expr.simd_into(#token) }
                 })
             }
+            OpSig::Unary if matches!(method, "count_ones" | "leading_zeros" | "trailing_zeros") => {
+                self.handle_bit_count(op, method_sig, method, vec_ty)
+            }
             OpSig::Unary => {
                 let args = [quote! { a.into() }];
 
@@ -170,7 +182,50 @@ impl Level for Neon {
                 let vec_scalar_ty = vec_ty.scalar.rust(vec_ty.scalar_bits);
                 let target_scalar_ty = target_ty.scalar.rust(target_ty.scalar_bits);
 
-                if method == "narrow" {
+                if method == "pack_clamped_u8" {
+                    let arch = self.arch_ty(vec_ty);
+                    self.kernel_method(op, vec_ty, |token| {
+                        quote! {
+                            let a: #arch = a.into();
+                            // `vcvtnq` rounds to nearest with ties to even, saturates, and maps NaN to 0.
+                            let lo = vcombine_s16(
+                                vqmovn_s32(vcvtnq_s32_f32(a.0)),
+                                vqmovn_s32(vcvtnq_s32_f32(a.1)),
+                            );
+                            let hi = vcombine_s16(
+                                vqmovn_s32(vcvtnq_s32_f32(a.2)),
+                                vqmovn_s32(vcvtnq_s32_f32(a.3)),
+                            );
+                            vcombine_u8(vqmovun_s16(lo), vqmovun_s16(hi)).simd_into(#token)
+                        }
+                    })
+                } else if method.starts_with("pack_saturate") {
+                    let arch = self.arch_ty(vec_ty);
+                    let pack = |half: TokenStream| match (vec_ty.scalar, target_ty.scalar) {
+                        (ScalarType::Int, ScalarType::Int) => quote! { vqmovn_s16(#half) },
+                        (ScalarType::Int, _) => quote! { vqmovun_s16(#half) },
+                        // Clamp to `i8::MAX` first, so that the unsigned saturation gives the right result.
+                        (_, ScalarType::Int) => {
+                            quote! { vqmovn_u16(vminq_u16(#half, vdupq_n_u16(127))) }
+                        }
+                        _ => quote! { vqmovn_u16(#half) },
+                    };
+                    let lo = pack(quote! { a.0 });
+                    let hi = pack(quote! { a.1 });
+                    let result = match (vec_ty.scalar, target_ty.scalar) {
+                        (ScalarType::Int, ScalarType::Int) => quote! { vcombine_s8(#lo, #hi) },
+                        (_, ScalarType::Int) => {
+                            quote! { vreinterpretq_s8_u8(vcombine_u8(#lo, #hi)) }
+                        }
+                        _ => quote! { vcombine_u8(#lo, #hi) },
+                    };
+                    self.kernel_method(op, vec_ty, |token| {
+                        quote! {
+                            let a: #arch = a.into();
+                            #result.simd_into(#token)
+                        }
+                    })
+                } else if method == "narrow" {
                     let arch = self.arch_ty(vec_ty);
 
                     let id1 = Ident::new(&format!("vmovn_{}", vec_scalar_ty), Span::call_site());
@@ -188,10 +243,10 @@ impl Level for Neon {
                     })
                 } else {
                     let arch = self.arch_ty(&target_ty);
-                    let id1 = Ident::new(&format!("vmovl_{}", vec_scalar_ty), Span::call_site());
-                    let id2 = Ident::new(&format!("vget_low_{}", vec_scalar_ty), Span::call_site());
-                    let id3 =
-                        Ident::new(&format!("vget_high_{}", vec_scalar_ty), Span::call_site());
+                    let half_ty = VecType::new(vec_ty.scalar, vec_ty.scalar_bits, vec_ty.len / 2);
+                    let id1 = simple_intrinsic("vmovl", &half_ty);
+                    let id2 = split_intrinsic("vget", "low", &half_ty);
+                    let id3 = split_intrinsic("vget", "high", &half_ty);
 
                     self.kernel_method(op, vec_ty, |token| {
                         quote! {
@@ -203,6 +258,37 @@ impl Level for Neon {
                     })
                 }
             }
+            OpSig::ShiftSigned => {
+                // `vshl` shifts by signed amounts, right for negative ones, for both signed and unsigned vectors.
+                let expr = neon::expr("shlv", vec_ty, &[quote! { a.into() }, quote! { b.into() }]);
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! {
+                        #expr.simd_into(#token)
+                    }
+                })
+            }
+            OpSig::Binary if matches!(method, "dot2" | "dot3" | "dot4") => {
+                self.kernel_method(op, vec_ty, |token| {
+                    if method == "dot2" {
+                        return quote! {
+                            let p = vmulq_f32(a.into(), b.into());
+                            vaddq_f32(p, vrev64q_f32(p)).simd_into(#token)
+                        };
+                    }
+                    // Replace the ignored product with zero, like `dpps` does.
+                    let mask_product = (method == "dot3").then(|| {
+                        quote! {
+                            let p = vsetq_lane_f32::<3>(0.0, p);
+                        }
+                    });
+                    quote! {
+                        let p = vmulq_f32(a.into(), b.into());
+                        #mask_product
+                        let sums = vpaddq_f32(p, p);
+                        vpaddq_f32(sums, sums).simd_into(#token)
+                    }
+                })
+            }
             OpSig::Binary => {
                 if vec_ty.scalar_bits == 64
                     && matches!(vec_ty.scalar, ScalarType::Int | ScalarType::Unsigned)
@@ -235,6 +321,10 @@ impl Level for Neon {
                             #expr.simd_into(#token)
                         }
                     }
+                    "table_lookup" => {
+                        // `tbl` gives zero for indices which are out of range.
+                        quote! { vqtbl1q_u8(a.into(), b.into()).simd_into(#token) }
+                    }
                     "copysign" => {
                         let shift_amt = Literal::usize_unsuffixed(vec_ty.scalar_bits - 1);
                         let unsigned_ty = vec_ty.cast(ScalarType::Unsigned);
@@ -263,6 +353,21 @@ impl Level for Neon {
                         quote! { b.into() },
                         quote! { a.into() },
                     ],
+                    // -(a * b) + c = c - a * b
+                    "nmul_add" => [
+                        quote! { c.into() },
+                        quote! { a.into() },
+                        quote! { b.into() },
+                    ],
+                    // -(a * b) - c = (-c) - a * b, which also gives the same sign of zero.
+                    "nmul_sub" => {
+                        let neg = simple_intrinsic("vneg", vec_ty);
+                        [
+                            quote! { #neg(c.into()) },
+                            quote! { a.into() },
+                            quote! { b.into() },
+                        ]
+                    }
                     _ => [
                         quote! { a.into() },
                         quote! { b.into() },
@@ -436,6 +541,43 @@ impl Level for Neon {
             OpSig::ElementRotate { .. } | OpSig::ElementShift { .. } => {
                 unreachable!("element moves use generic lowering")
             }
+            OpSig::BitTranspose => unreachable!("bit transposes use generic lowering"),
+            OpSig::GfMulConst => unreachable!("GF(2^8) multiplication uses generic lowering"),
+            OpSig::Compress { expand } => self.kernel_method(op, vec_ty, |token| {
+                table_compress_bytes(token, vec_ty, expand, |a, indices| {
+                    quote! { vqtbl1q_u8(#a, #indices) }
+                })
+            }),
+            OpSig::WidenAccumulate { acc_ty } => {
+                let arch = self.arch_ty(&acc_ty);
+                let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+                let get_low = format_ident!("vget_low_{scalar}");
+                let vabal = format_ident!("vabal_{scalar}");
+                let vabal_high = format_ident!("vabal_high_{scalar}");
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! {
+                        let acc: #arch = acc.into();
+                        let (a, b) = (a.into(), b.into());
+                        #arch(
+                            #vabal(acc.0, #get_low(a), #get_low(b)),
+                            #vabal_high(acc.1, a, b),
+                        )
+                        .simd_into(#token)
+                    }
+                })
+            }
+            OpSig::ReduceWidened => {
+                let vaddlv = simple_intrinsic("vaddlv", vec_ty);
+                let result = vec_ty.scalar.rust(32);
+                self.kernel_method(op, vec_ty, |_| {
+                    // `vaddlv` widens to twice the element width, which is already 32 bits for 16-bit elements.
+                    if vec_ty.scalar_bits == 8 {
+                        quote! { #result::from(#vaddlv(a.into())) }
+                    } else {
+                        quote! { #vaddlv(a.into()) }
+                    }
+                })
+            }
             OpSig::SwizzleDynWithinBlocks => {
                 assert_eq!(
                     vec_ty.n_bits(),
@@ -518,7 +660,11 @@ impl Level for Neon {
             }
             OpSig::MaskFromBitmask => self.handle_mask_from_bitmask(op, vec_ty),
             OpSig::MaskToBitmask => self.handle_mask_to_bitmask(op, vec_ty),
+            OpSig::MaskToInt => generic_mask_to_int(method_sig, vec_ty),
             OpSig::MaskSet => generic_mask_set(method_sig, vec_ty),
+            OpSig::ExtractLane { dynamic: false } => self.handle_extract_lane(method_sig, vec_ty),
+            OpSig::ExtractLane { dynamic: true } => generic_extract_dyn(method_sig, vec_ty),
+            OpSig::InsertLane => self.handle_insert_lane(method_sig, vec_ty),
             OpSig::FromArray { kind } => generic_from_array(method_sig, vec_ty, kind),
             OpSig::AsArray { kind } => {
                 generic_as_array(method_sig, vec_ty, kind, self.max_block_size(), |vec_ty| {
@@ -551,6 +697,143 @@ impl Level for Neon {
 }
 
 impl Neon {
+    fn handle_extract_lane(&self, method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+        assert_eq!(
+            vec_ty.n_bits(),
+            128,
+            "wide lane extractions should use the generic split implementation"
+        );
+        let level = self.token();
+        let ty = vec_ty.rust();
+        let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+        let check = const_lane_check(vec_ty);
+        let vget_lane = split_intrinsic("vget", "lane", vec_ty);
+        let arms = (0..vec_ty.len).map(|lane| {
+            let lane = Literal::usize_unsuffixed(lane);
+            quote! { #lane => #vget_lane::<#lane>(a) }
+        });
+        // `kernel!` functions can't be const-generic, so the lane is passed in as an argument, and matched on to
+        // pick the intrinsic's immediate.
+        quote! {
+            #method_sig {
+                #check
+                crate::kernel!(
+                    #[inline(always)]
+                    fn kernel(token: #level, a: #ty<#level>, lane: usize) -> #scalar {
+                        let a = a.into();
+                        match lane {
+                            #(#arms,)*
+                            _ => unreachable!(),
+                        }
+                    }
+                );
+
+                kernel(self, a, LANE)
+            }
+        }
+    }
+
+    fn handle_insert_lane(&self, method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+        assert_eq!(
+            vec_ty.n_bits(),
+            128,
+            "wide lane insertions should use the generic split implementation"
+        );
+        let level = self.token();
+        let ty = vec_ty.rust();
+        let vec = quote! { #ty<#level> };
+        let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+        let check = const_lane_check(vec_ty);
+        let vset_lane = split_intrinsic("vset", "lane", vec_ty);
+        let arms = (0..vec_ty.len).map(|lane| {
+            let lane = Literal::usize_unsuffixed(lane);
+            quote! { #lane => #vset_lane::<#lane>(value, a) }
+        });
+        quote! {
+            #method_sig {
+                #check
+                crate::kernel!(
+                    #[inline(always)]
+                    fn kernel(token: #level, a: #vec, value: #scalar, lane: usize) -> #vec {
+                        let a = a.into();
+                        match lane {
+                            #(#arms,)*
+                            _ => unreachable!(),
+                        }
+                        .simd_into(token)
+                    }
+                );
+
+                kernel(self, a, value, LANE)
+            }
+        }
+    }
+
+    fn handle_bit_count(
+        &self,
+        op: Op,
+        method_sig: TokenStream,
+        method: &str,
+        vec_ty: &VecType,
+    ) -> TokenStream {
+        match method {
+            "count_ones" => {
+                // `vcnt` counts the bits of each byte, and `vpaddl` adds up adjacent pairs of counts into wider
+                // elements, until they're as wide as the vector's.
+                let bytes_ty = vec_ty.reinterpret(ScalarType::Unsigned, 8);
+                let count = if vec_ty.scalar_bits == 8 {
+                    let vcnt = simple_intrinsic("vcnt", vec_ty);
+                    quote! { #vcnt(a.into()) }
+                } else {
+                    let to_bytes = cvt_intrinsic("vreinterpret", &bytes_ty, vec_ty);
+                    let mut count = quote! { vcntq_u8(#to_bytes(a.into())) };
+                    let mut bits = 8;
+                    while bits < vec_ty.scalar_bits {
+                        let vpaddl = simple_intrinsic(
+                            "vpaddl",
+                            &vec_ty.reinterpret(ScalarType::Unsigned, bits),
+                        );
+                        count = quote! { #vpaddl(#count) };
+                        bits *= 2;
+                    }
+                    if vec_ty.scalar == ScalarType::Int {
+                        let unsigned_ty = vec_ty.cast(ScalarType::Unsigned);
+                        let from_unsigned = cvt_intrinsic("vreinterpret", vec_ty, &unsigned_ty);
+                        count = quote! { #from_unsigned(#count) };
+                    }
+                    count
+                };
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! { #count.simd_into(#token) }
+                })
+            }
+            // There's no leading zero count of 64-bit elements.
+            "leading_zeros" if vec_ty.scalar_bits < 64 => {
+                let vclz = simple_intrinsic("vclz", vec_ty);
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! { #vclz(a.into()).simd_into(#token) }
+                })
+            }
+            "leading_zeros" => {
+                let body = leading_zeros_from_count_ones(vec_ty);
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
+            "trailing_zeros" => {
+                let body = trailing_zeros_from_count_ones(vec_ty);
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn handle_mask_from_bitmask(&self, op: Op, vec_ty: &VecType) -> TokenStream {
         assert_eq!(
             vec_ty.scalar,
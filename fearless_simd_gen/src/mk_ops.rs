@@ -134,6 +134,25 @@ pub(crate) fn mk_ops() -> TokenStream {
                 }
             }
         }
+
+        // Masks aren't integer vectors, but negating one gives the common idiom of counting true
+        // lanes by accumulating `-mask`.
+        if ty.scalar == ScalarType::Mask {
+            let int_ty = ty.cast(ScalarType::Int);
+            let int_name = int_ty.rust();
+            let to_int = generic_op_name("to_int", ty);
+            let neg = generic_op_name("neg", &int_ty);
+            impls.push(quote! {
+                impl<S: Simd> core::ops::Neg for #simd<S> {
+                    type Output = #int_name<S>;
+                    #[doc = "Negate the integer lanes of this mask, giving `1` for true lanes and `0` for false lanes."]
+                    #[inline(always)]
+                    fn neg(self) -> Self::Output {
+                        self.simd.#neg(self.simd.#to_int(self))
+                    }
+                }
+            });
+        }
     }
 
     quote! {
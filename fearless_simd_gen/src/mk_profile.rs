@@ -0,0 +1,112 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The list of ops which `fearless_simd_profile` measures, with the code to call each one.
+//!
+//! This covers the lane-wise ops, which are the same as those with a function in the `reference` module. Ops which
+//! return a value of the type of their first argument are measured both for latency, by feeding each result back
+//! in, and for throughput. The others, such as comparisons and conversions, are only measured for throughput.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::generic::generic_op_name;
+use crate::ops::{Op, OpSig, ops_for_type};
+use crate::types::{SIMD_TYPES, ScalarType, VecType};
+
+pub(crate) fn mk_profile() -> TokenStream {
+    let mut types = vec![];
+    for vec_ty in SIMD_TYPES {
+        if vec_ty.scalar == ScalarType::Mask {
+            continue;
+        }
+        let measurements = ops_for_type(vec_ty)
+            .into_iter()
+            .filter_map(|op| measure_op(op, vec_ty))
+            .collect::<Vec<_>>();
+        if measurements.is_empty() {
+            continue;
+        }
+        let ty = vec_ty.rust();
+        let one = match vec_ty.scalar {
+            ScalarType::Float => quote! { 1.0 },
+            _ => quote! { 1 },
+        };
+        // Only the arguments which are used, as the others would be unused variables.
+        let uses = |arg: &str| measurements.iter().any(|(_, args)| args.contains(&arg));
+        let mut inputs = vec![quote! { let a = #ty::splat(simd, black_box(#one)); }];
+        for arg in ["b", "c"] {
+            if uses(arg) {
+                let arg = Ident::new(arg, Span::call_site());
+                inputs.push(quote! { let #arg = #ty::splat(simd, black_box(#one)); });
+            }
+        }
+        if uses("shifts") {
+            let signed = VecType::new(ScalarType::Int, vec_ty.scalar_bits, vec_ty.len).rust();
+            inputs.push(quote! { let shifts = #signed::splat(simd, black_box(1)); });
+        }
+        if uses("shift") {
+            inputs.push(quote! { let shift = black_box(1); });
+        }
+        let measurements = measurements.iter().map(|(measurement, _)| measurement);
+        types.push(quote! {
+            {
+                #( #inputs )*
+                #( #measurements )*
+            }
+        });
+    }
+
+    quote! {
+        use core::hint::black_box;
+
+        use fearless_simd::*;
+
+        use crate::Bench;
+
+        /// Measure every lane-wise op on `simd`.
+        ///
+        /// The arguments are all one, which keeps the values of chains of ops away from subnormals, which are slow on
+        /// some CPUs. They're passed through `black_box`, so that the ops can't be evaluated at compile time.
+        pub(crate) fn profile_ops<S: Simd>(simd: S, bench: &mut Bench) {
+            #( #types )*
+        }
+    }
+}
+
+/// The measurement of `op` on `vec_ty` and the arguments it uses besides `a`, or `None` if it isn't measured.
+fn measure_op(op: Op, vec_ty: &VecType) -> Option<(TokenStream, &'static [&'static str])> {
+    let name = generic_op_name(op.method, vec_ty);
+    let method = name.to_string();
+    let (args, chained): (&[&str], bool) = match op.sig {
+        OpSig::Unary | OpSig::BitTranspose => (&[], true),
+        OpSig::Binary | OpSig::Zip { .. } | OpSig::Unzip { .. } => (&["b"], true),
+        OpSig::Ternary => (&["b", "c"], true),
+        OpSig::ShiftSigned => (&["shifts"], true),
+        OpSig::Shift => (&["shift"], true),
+        OpSig::Compare => (&["b"], false),
+        OpSig::Cvt { .. } | OpSig::Reinterpret { .. } | OpSig::WidenNarrow { .. } => (&[], false),
+        // The second argument of these is chained too.
+        OpSig::Interleave | OpSig::Deinterleave => {
+            return Some((
+                quote! {
+                    bench.chain(simd, #method, (a, b), #[inline(always)] |(a, b)| Simd::#name(simd, a, b));
+                },
+                &["b"],
+            ));
+        }
+        _ => return None,
+    };
+    let arg_idents = args.iter().map(|arg| Ident::new(arg, Span::call_site()));
+    let measure = if chained {
+        Ident::new("chain", Span::call_site())
+    } else {
+        Ident::new("map", Span::call_site())
+    };
+    Some((
+        quote! {
+            bench.#measure(simd, #method, a, #[inline(always)] |a| Simd::#name(simd, a #(, #arg_idents)*));
+        },
+        args,
+    ))
+}
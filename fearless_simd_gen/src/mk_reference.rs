@@ -0,0 +1,128 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Array-based reference functions for the lane-wise operations, backed by the fallback level.
+//!
+//! Each function takes arrays by reference and returns arrays, so downstream tests can compare
+//! their own SIMD code against the exact semantics of the fallback implementation without
+//! constructing vectors themselves.
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+
+use crate::generic::generic_op_name;
+use crate::ops::{Op, OpSig, ops_for_type};
+use crate::types::{SIMD_TYPES, ScalarType, VecType};
+
+pub(crate) fn mk_reference() -> TokenStream {
+    let mut result = quote! {
+        use crate::{Fallback, Simd, SimdInto, SimdMask};
+    };
+    for vec_ty in SIMD_TYPES {
+        if vec_ty.scalar == ScalarType::Mask {
+            continue;
+        }
+        for op in ops_for_type(vec_ty) {
+            if let Some(function) = reference_fn(op, vec_ty) {
+                result.extend(function);
+            }
+        }
+    }
+    result
+}
+
+fn array_ty(vec_ty: &VecType) -> TokenStream {
+    let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+    let len = Literal::usize_unsuffixed(vec_ty.len);
+    quote! { [#scalar; #len] }
+}
+
+/// Generate the reference function for `op` on `vec_ty`, or `None` if the op isn't a plain
+/// computation on lane values (for example, loads and stores, or ops with const generics).
+fn reference_fn(op: Op, vec_ty: &VecType) -> Option<TokenStream> {
+    let name = generic_op_name(op.method, vec_ty);
+    let array = array_ty(vec_ty);
+    let arg_names: &[&str] = match op.sig {
+        OpSig::Unary
+        | OpSig::Cvt { .. }
+        | OpSig::Reinterpret { .. }
+        | OpSig::WidenNarrow { .. } => &["a"],
+        OpSig::Binary
+        | OpSig::ShiftSigned
+        | OpSig::Compare
+        | OpSig::Zip { .. }
+        | OpSig::Unzip { .. }
+        | OpSig::Interleave
+        | OpSig::Deinterleave => &["a", "b"],
+        OpSig::Ternary => &["a", "b", "c"],
+        OpSig::Shift => &["a"],
+        _ => return None,
+    };
+    let args = arg_names
+        .iter()
+        .map(|name| Ident::new(name, Span::call_site()))
+        .collect::<Vec<_>>();
+    let mut params = args
+        .iter()
+        .map(|arg| quote! { #arg: &#array })
+        .collect::<Vec<_>>();
+    if matches!(op.sig, OpSig::ShiftSigned) {
+        let amounts = array_ty(&vec_ty.cast(ScalarType::Int));
+        params[1] = quote! { b: &#amounts };
+    }
+    let mut call_args = args
+        .iter()
+        .map(|arg| quote! { (*#arg).simd_into(simd) })
+        .collect::<Vec<_>>();
+    if matches!(op.sig, OpSig::Shift) {
+        params.push(quote! { shift: u32 });
+        call_args.push(quote! { shift });
+    }
+
+    let call = quote! { simd.#name(#(#call_args),*) };
+    let (ret, body) = match op.sig {
+        OpSig::Compare => {
+            let len = Literal::usize_unsuffixed(vec_ty.len);
+            (
+                quote! { [bool; #len] },
+                quote! {
+                    let mask = #call;
+                    core::array::from_fn(|i| mask.test(i))
+                },
+            )
+        }
+        OpSig::Interleave | OpSig::Deinterleave => (
+            quote! { (#array, #array) },
+            quote! {
+                let (lo, hi) = #call;
+                (lo.into(), hi.into())
+            },
+        ),
+        OpSig::Cvt {
+            target_ty,
+            scalar_bits,
+            ..
+        }
+        | OpSig::Reinterpret {
+            target_ty,
+            scalar_bits,
+        } => (
+            array_ty(&vec_ty.reinterpret(target_ty, scalar_bits)),
+            quote! { #call.into() },
+        ),
+        OpSig::WidenNarrow { target_ty } => (array_ty(&target_ty), quote! { #call.into() }),
+        _ => (array.clone(), quote! { #call.into() }),
+    };
+
+    let doc = format!(
+        "Reference implementation of [`Simd::{name}`], computed by the [`Fallback`] level."
+    );
+    Some(quote! {
+        #[doc = #doc]
+        #[inline]
+        pub fn #name(#(#params),*) -> #ret {
+            let simd = Fallback::new();
+            #body
+        }
+    })
+}
@@ -5,6 +5,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::{
+    lowering,
     ops::{
         CoreOpTrait, OpKind, OpSig, TyFlavor, base_trait_ops, ops_for_type, overloaded_ops_for,
         vec_trait_ops_for,
@@ -19,7 +20,7 @@ pub(crate) fn mk_simd_trait() -> TokenStream {
     for vec_ty in SIMD_TYPES {
         for op in ops_for_type(vec_ty) {
             let method_sig = op.simd_trait_method_sig(vec_ty);
-            let doc = op.format_docstring(TyFlavor::SimdTrait);
+            let doc = op.format_docstring(TyFlavor::SimdTrait) + &lowering::doc_section(op, vec_ty);
             methods.extend(quote! {
                 #[doc = #doc]
                 #method_sig;
@@ -27,7 +28,7 @@ pub(crate) fn mk_simd_trait() -> TokenStream {
         }
     }
     let mut code = quote! {
-        use crate::{seal::Seal, Level, SimdElement, SimdFrom, SimdInto, SimdCvtTruncate, SimdCvtFloat, Select, Bytes};
+        use crate::{seal::Seal, Level, SimdElement, SimdFrom, SimdInto, SimdCvtTruncate, SimdCvtFloat, SimdTruncateLanes, SimdZeroExtend, Select, Bytes};
         #imports
         /// The main SIMD trait, implemented by all SIMD token types.
         ///
@@ -45,6 +46,23 @@ pub(crate) fn mk_simd_trait() -> TokenStream {
         /// The trait defines associated types for the highest "native" vector width of each scalar type (e.g. `f32s`,
         /// `u32s`). These are always at least 128 bits, but may be larger. Currently, they are 128 bits on the
         /// fallback, NEON, WASM, SSE2, and SSE4.2 backends, 256 bits on AVX2, and 512 bits on AVX-512.
+        /// This width is available as [`Simd::NATIVE_BITS`].
+        ///
+        /// Every vector type (e.g. `f32x4`, `f32x8` and `f32x16`) is available on every backend. Vectors wider
+        /// than the native width are emulated, by splitting them into native-width halves and operating on
+        /// each half separately. This is correct, but usually no faster than using native-width vectors
+        /// directly, so generic code should prefer the native-width types, and use [`SimdBase::N`] or
+        /// [`SimdBase::BITS`] to decide how much data to process at once.
+        ///
+        /// All of the native-width vector types have the same size, and their [`Bytes`] type is
+        /// [`Simd::u8s`]. This means that any of them can be reinterpreted as any other with
+        /// [`Bytes::bitcast`], even in generic code, for example to operate on the bits of `f32s`
+        /// as `u32s`, or on the bytes of `u16s` as `u8s`.
+        ///
+        /// # Lowering
+        ///
+        /// The documentation of each method has a table of the intrinsics which each level implements it with. The
+        /// same information is available to tools in the [`lowering`](crate::lowering) module.
         ///
         /// # Example
         ///
@@ -66,35 +84,59 @@ pub(crate) fn mk_simd_trait() -> TokenStream {
         /// ```
         pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + arch_types::ArchTypes + 'static {
             /// A native-width SIMD vector of [`f32`]s.
-            type f32s: SimdFloat<Self, Element = f32, Block = f32x4<Self>, Mask = Self::mask32s, Bytes = <Self::u32s as Bytes>::Bytes> + SimdCvtFloat<Self::u32s> + SimdCvtFloat<Self::i32s>;
+            type f32s: SimdFloat<Self, Element = f32, Block = f32x4<Self>, Mask = Self::mask32s, Bytes = Self::u8s, Int = Self::i32s, Uint = Self::u32s> + SimdVector<Simd = Self> + SimdCvtFloat<Self::u32s> + SimdCvtFloat<Self::i32s> + crate::SimdDot<Self>;
             /// A native-width SIMD vector of [`f64`]s.
-            type f64s: SimdFloat<Self, Element = f64, Block = f64x2<Self>, Mask = Self::mask64s, Bytes = <Self::u64s as Bytes>::Bytes>;
+            type f64s: SimdFloat<Self, Element = f64, Block = f64x2<Self>, Mask = Self::mask64s, Bytes = Self::u8s, Int = Self::i64s, Uint = Self::u64s> + SimdVector<Simd = Self>;
             /// A native-width SIMD vector of [`u8`]s.
-            type u8s: SimdInt<Self, Element = u8, Block = u8x16<Self>, Mask = Self::mask8s>;
+            type u8s: SimdInt<Self, Element = u8, Block = u8x16<Self>, Mask = Self::mask8s, Bytes = Self::u8s, Signed = Self::i8s, Unsigned = Self::u8s> + SimdVector<Simd = Self>;
             /// A native-width SIMD vector of [`i8`]s.
-            type i8s: SimdInt<Self, Element = i8, Block = i8x16<Self>, Mask = Self::mask8s, Bytes = <Self::u8s as Bytes>::Bytes> + core::ops::Neg<Output = Self::i8s>;
+            type i8s: SimdInt<Self, Element = i8, Block = i8x16<Self>, Mask = Self::mask8s, Bytes = Self::u8s, Signed = Self::i8s, Unsigned = Self::u8s> + SimdVector<Simd = Self> + core::ops::Neg<Output = Self::i8s>;
             /// A native-width SIMD vector of [`u16`]s.
-            type u16s: SimdInt<Self, Element = u16, Block = u16x8<Self>, Mask = Self::mask16s>;
+            type u16s: SimdInt<Self, Element = u16, Block = u16x8<Self>, Mask = Self::mask16s, Bytes = Self::u8s, Signed = Self::i16s, Unsigned = Self::u16s> + SimdVector<Simd = Self>;
             /// A native-width SIMD vector of [`i16`]s.
-            type i16s: SimdInt<Self, Element = i16, Block = i16x8<Self>, Mask = Self::mask16s, Bytes = <Self::u16s as Bytes>::Bytes> + core::ops::Neg<Output = Self::i16s>;
+            type i16s: SimdInt<Self, Element = i16, Block = i16x8<Self>, Mask = Self::mask16s, Bytes = Self::u8s, Signed = Self::i16s, Unsigned = Self::u16s> + SimdVector<Simd = Self> + core::ops::Neg<Output = Self::i16s>;
             /// A native-width SIMD vector of [`u32`]s.
-            type u32s: SimdInt<Self, Element = u32, Block = u32x4<Self>, Mask = Self::mask32s> + SimdCvtTruncate<Self::f32s>;
+            type u32s: SimdInt<Self, Element = u32, Block = u32x4<Self>, Mask = Self::mask32s, Bytes = Self::u8s, Signed = Self::i32s, Unsigned = Self::u32s> + SimdVector<Simd = Self> + crate::SimdHashLanes<Self> + SimdCvtTruncate<Self::f32s>;
             /// A native-width SIMD vector of [`i32`]s.
-            type i32s: SimdInt<Self, Element = i32, Block = i32x4<Self>, Mask = Self::mask32s, Bytes = <Self::u32s as Bytes>::Bytes> + SimdCvtTruncate<Self::f32s>
+            type i32s: SimdInt<Self, Element = i32, Block = i32x4<Self>, Mask = Self::mask32s, Bytes = Self::u8s, Signed = Self::i32s, Unsigned = Self::u32s> + SimdVector<Simd = Self> + SimdCvtTruncate<Self::f32s>
                 + core::ops::Neg<Output = Self::i32s>;
             /// A native-width SIMD vector of [`u64`]s.
-            type u64s: SimdInt<Self, Element = u64, Block = u64x2<Self>, Mask = Self::mask64s>;
+            type u64s: SimdInt<Self, Element = u64, Block = u64x2<Self>, Mask = Self::mask64s, Bytes = Self::u8s, Signed = Self::i64s, Unsigned = Self::u64s> + SimdVector<Simd = Self> + crate::SimdHashLanes<Self>;
             /// A native-width SIMD vector of [`i64`]s.
-            type i64s: SimdInt<Self, Element = i64, Block = i64x2<Self>, Mask = Self::mask64s, Bytes = <Self::u64s as Bytes>::Bytes>
+            type i64s: SimdInt<Self, Element = i64, Block = i64x2<Self>, Mask = Self::mask64s, Bytes = Self::u8s, Signed = Self::i64s, Unsigned = Self::u64s> + SimdVector<Simd = Self>
                 + core::ops::Neg<Output = Self::i64s>;
             /// A native-width SIMD mask with 8-bit lanes.
-            type mask8s: SimdMask<Self, Element = i8> + Select<Self::u8s> + Select<Self::i8s> + Select<Self::mask8s>;
+            type mask8s: SimdMask<Self, Element = i8, Int = Self::i8s> + core::ops::Neg<Output = Self::i8s> + Select<Self::u8s> + Select<Self::i8s> + Select<Self::mask8s>;
             /// A native-width SIMD mask with 16-bit lanes.
-            type mask16s: SimdMask<Self, Element = i16> + Select<Self::u16s> + Select<Self::i16s> + Select<Self::mask16s>;
+            type mask16s: SimdMask<Self, Element = i16, Int = Self::i16s> + core::ops::Neg<Output = Self::i16s> + Select<Self::u16s> + Select<Self::i16s> + Select<Self::mask16s>;
             /// A native-width SIMD mask with 32-bit lanes.
-            type mask32s: SimdMask<Self, Element = i32> + Select<Self::f32s> + Select<Self::u32s> + Select<Self::i32s> + Select<Self::mask32s>;
+            type mask32s: SimdMask<Self, Element = i32, Int = Self::i32s> + core::ops::Neg<Output = Self::i32s> + Select<Self::f32s> + Select<Self::u32s> + Select<Self::i32s> + Select<Self::mask32s>;
             /// A native-width SIMD mask with 64-bit lanes.
-            type mask64s: SimdMask<Self, Element = i64> + Select<Self::f64s> + Select<Self::u64s> + Select<Self::i64s> + Select<Self::mask64s>;
+            type mask64s: SimdMask<Self, Element = i64, Int = Self::i64s> + core::ops::Neg<Output = Self::i64s> + Select<Self::f64s> + Select<Self::u64s> + Select<Self::i64s> + Select<Self::mask64s>;
+
+            /// The width of the native vector types (such as [`Simd::f32s`]) in bits.
+            ///
+            /// This is the widest vector which this level operates on without emulation.
+            /// It is at least 128, and a multiple of 128.
+            const NATIVE_BITS: usize;
+
+            /// Whether [`mul_add`](SimdFloat::mul_add), [`mul_sub`](SimdFloat::mul_sub),
+            /// [`nmul_add`](SimdFloat::nmul_add) and [`nmul_sub`](SimdFloat::nmul_sub) are computed with a single
+            /// rounding on this level.
+            ///
+            /// This is `true` for Neon, AVX2 and AVX-512, which have fused multiply-add instructions.
+            /// If it's `false`, these ops may be computed as a multiplication followed by an addition or
+            /// subtraction, which rounds twice. On WebAssembly, they always round twice, even with the
+            /// `relaxed-simd` feature, whose multiply-add is only used by
+            /// [`mul_add_relaxed`](SimdFloat::mul_add_relaxed).
+            const FUSED_MUL_ADD: bool;
+
+            /// Whether this level computes floats with integer arithmetic, rather than with the CPU's float unit.
+            ///
+            /// This is only `true` for [`Fallback`](crate::Fallback) with the `soft_float` feature. The float
+            /// unit's modes, such as [flushing subnormals to zero](crate::SimdFlushToZero), don't affect such
+            /// levels.
+            const SOFT_FLOAT: bool;
 
             /// This SIMD token's feature level.
             fn level(self) -> Level;
@@ -103,11 +145,65 @@ pub(crate) fn mk_simd_trait() -> TokenStream {
             ///
             /// For performance, the provided function should be `#[inline(always)]`.
             fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R;
+
+            /// Load the element of `values` at each index in `indices`.
+            ///
+            /// This is [`slice::gather_u32`](crate::slice::gather_u32), which uses a gather instruction with AVX2
+            /// and AVX-512, and loads the elements one at a time on other levels.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is out of bounds for `values`.
+            #[inline(always)]
+            fn gather_u32(self, values: &[u32], indices: u32x8<Self>) -> u32x8<Self> {
+                crate::slice::gather_u32(self, values, indices)
+            }
+
+            /// Load the element of `values` at each index in `indices`.
+            ///
+            /// This is [`slice::gather_f32`](crate::slice::gather_f32), the floating-point version of
+            /// [`gather_u32`](Simd::gather_u32).
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is out of bounds for `values`.
+            #[inline(always)]
+            fn gather_f32(self, values: &[f32], indices: u32x8<Self>) -> f32x8<Self> {
+                crate::slice::gather_f32(self, values, indices)
+            }
+
+            /// Store each lane of `data` to the element of `values` at the index in the same lane of `indices`.
+            ///
+            /// This is [`slice::scatter_u32`](crate::slice::scatter_u32), which uses a scatter instruction with
+            /// AVX-512, and stores the lanes one at a time on other levels. If several lanes have the same index,
+            /// the element is set to the last of them.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is out of bounds for `values`, in which case nothing is stored.
+            #[inline(always)]
+            fn scatter_u32(self, values: &mut [u32], indices: u32x8<Self>, data: u32x8<Self>) {
+                crate::slice::scatter_u32(self, values, indices, data);
+            }
+
+            /// Store each lane of `data` to the element of `values` at the index in the same lane of `indices`.
+            ///
+            /// This is [`slice::scatter_f32`](crate::slice::scatter_f32), the floating-point version of
+            /// [`scatter_u32`](Simd::scatter_u32).
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is out of bounds for `values`, in which case nothing is stored.
+            #[inline(always)]
+            fn scatter_f32(self, values: &mut [f32], indices: u32x8<Self>, data: f32x8<Self>) {
+                crate::slice::scatter_f32(self, values, indices, data);
+            }
             #( #methods )*
         }
     };
     code.extend(mk_arch_types());
     code.extend(mk_simd_base());
+    code.extend(mk_simd_vector());
     code.extend(mk_simd_float());
     code.extend(mk_simd_int());
     code.extend(mk_simd_mask());
@@ -141,7 +237,7 @@ pub(crate) fn mk_arch_types() -> TokenStream {
 fn mk_simd_base() -> TokenStream {
     let mut methods = vec![];
     for op in base_trait_ops() {
-        let doc = op.format_docstring(TyFlavor::VecImpl);
+        let doc = op.format_docstring_with_example(TyFlavor::VecImpl);
         if let Some(method_sig) = op.vec_trait_method_sig() {
             methods.push(quote! {
                 #[doc = #doc]
@@ -164,7 +260,40 @@ fn mk_simd_base() -> TokenStream {
             /// This vector type's lane count. This is useful when you're
             /// working with a native-width vector (e.g. [`Simd::f32s`]) and
             /// want to process data in native-width chunks.
+            ///
+            /// This is always [`Self::BITS`] divided by the size of [`Self::Element`] in bits.
             const N: usize;
+            /// This vector type's total width in bits.
+            ///
+            /// This is the same for every [`Simd`] implementation. For the native-width types,
+            /// such as [`Simd::f32s`], it is [`Simd::NATIVE_BITS`].
+            const BITS: usize;
+            /// The identity element of addition, which leaves any value unchanged when added to it.
+            ///
+            /// This is `0` for integers, and `-0.0` for floating-point types, because adding `0.0` to
+            /// `-0.0` gives `0.0`, losing the sign.
+            ///
+            /// Splatting this gives the starting value of a sum, without matching on the element type:
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, Fallback, f32x4};
+            /// fn sum<S: Simd, V: SimdBase<S> + core::ops::Add<Output = V>>(simd: S, chunks: &[V]) -> V {
+            ///     chunks.iter().fold(V::splat(simd, V::ADD_IDENTITY), |acc, &x| acc + x)
+            /// }
+            ///
+            /// let simd = Fallback::new();
+            /// let chunks = [f32x4::splat(simd, -0.0); 2];
+            /// assert!(sum(simd, &chunks)[0].is_sign_negative());
+            /// ```
+            const ADD_IDENTITY: Self::Element;
+            /// The identity element of `min`, which is never smaller than any other value.
+            ///
+            /// This is positive infinity for floating-point types, and the largest value for integers.
+            const MIN_IDENTITY: Self::Element;
+            /// The identity element of `max`, which is never larger than any other value.
+            ///
+            /// This is negative infinity for floating-point types, and the smallest value for integers.
+            const MAX_IDENTITY: Self::Element;
             /// A SIMD vector mask with the same number of logical lanes.
             ///
             /// Masks intentionally do not implement [`SimdBase`]. SSE, NEON, WASM, and the
@@ -183,12 +312,52 @@ fn mk_simd_base() -> TokenStream {
             fn as_mut_slice(&mut self) -> &mut [Self::Element];
             /// Create a SIMD vector from a slice.
             ///
-            /// The slice must be exactly the size of the SIMD vector.
+            /// # Panics
+            ///
+            /// Panics if the slice isn't exactly the size of the SIMD vector. Use
+            /// [`Self::try_from_slice`] to handle this without panicking.
             fn from_slice(simd: S, slice: &[Self::Element]) -> Self;
+            /// Create a SIMD vector from a slice, or return `None` if the slice isn't exactly the
+            /// size of the SIMD vector.
+            fn try_from_slice(simd: S, slice: &[Self::Element]) -> Option<Self>;
             /// Store a SIMD vector into a slice.
             ///
-            /// The slice must be exactly the size of the SIMD vector.
+            /// # Panics
+            ///
+            /// Panics if the slice isn't exactly the size of the SIMD vector.
             fn store_slice(&self, slice: &mut [Self::Element]);
+            /// Store a SIMD vector into a slice of possibly uninitialized elements, and return the
+            /// same slice, now initialized.
+            ///
+            /// This fills buffers which are handed out uninitialized, such as
+            /// [`Vec::spare_capacity_mut`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.spare_capacity_mut),
+            /// without zeroing them first.
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, Fallback, f32x4};
+            /// # let simd = Fallback::new();
+            /// let mut out = Vec::<f32>::with_capacity(4);
+            /// let v = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+            /// v.write_to_uninit(&mut out.spare_capacity_mut()[..4]);
+            /// // SAFETY: `write_to_uninit` initialized the first 4 elements.
+            /// unsafe { out.set_len(4) };
+            /// assert_eq!(out, [1.0, 2.0, 3.0, 4.0]);
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slice isn't exactly the size of the SIMD vector.
+            fn write_to_uninit<'a>(
+                &self,
+                slice: &'a mut [core::mem::MaybeUninit<Self::Element>],
+            ) -> &'a mut [Self::Element];
+            /// Create a SIMD vector with all elements set to zero.
+            ///
+            /// This is the cheapest way to initialize an accumulator: the constant zero is
+            /// materialized with the register-zeroing idiom of each level (such as `xorps` or
+            /// `pxor` on x86, `movi` on NEON, and `v128.const` on WASM), rather than by
+            /// broadcasting a scalar.
+            fn zeroed(simd: S) -> Self;
             /// Create a SIMD vector from a 128-bit vector of the same scalar
             /// type, repeated.
             fn block_splat(block: Self::Block) -> Self;
@@ -196,12 +365,62 @@ fn mk_simd_base() -> TokenStream {
             /// calling `f` with that element's lane index (from 0 to
             /// [`SimdBase::N`] - 1).
             fn from_fn(simd: S, f: impl FnMut(usize) -> Self::Element) -> Self;
+            /// Widen this vector to a longer vector with the same element type, filling the new upper
+            /// lanes with zeroes. This is a convenience method that delegates to
+            /// [`SimdZeroExtend::zero_extend_from`].
+            ///
+            /// For example, `v.zero_extend_to::<f32x8<S>>()` on an `f32x4` `[a, b, c, d]` produces
+            /// `[a, b, c, d, 0, 0, 0, 0]`.
+            #[inline(always)]
+            fn zero_extend_to<T: SimdZeroExtend<Self>>(self) -> T { T::zero_extend_from(self) }
+            /// Shorten this vector to a vector with the same element type and fewer lanes, keeping the
+            /// lowest lanes and discarding the rest. This is a convenience method that delegates to
+            /// [`SimdTruncateLanes::truncate_lanes_from`].
+            ///
+            /// This is the inverse of [`SimdBase::zero_extend_to`].
+            #[inline(always)]
+            fn truncate_to<T: SimdTruncateLanes<Self>>(self) -> T { T::truncate_lanes_from(self) }
 
             #( #methods )*
         }
     }
 }
 
+fn mk_simd_vector() -> TokenStream {
+    quote! {
+        /// A SIMD vector, with its [`Simd`] implementation as an associated type rather than a parameter.
+        ///
+        /// This is implemented by every vector type which implements [`SimdBase`]. It allows code which is generic
+        /// over vectors to write bounds such as `V: SimdVector<Element = f32>`, without also being generic over the
+        /// [`Simd`] implementation. The element, mask and byte array types are available as `V::Element`, `V::Mask`
+        /// and `V::Bytes` through the supertraits, and the other vector traits can be added using [`Self::Simd`]:
+        ///
+        /// ```rust
+        /// # use fearless_simd::{prelude::*, Fallback, f32x4, f32x8};
+        /// fn sum_lanes<V: SimdVector<Element = f32>>(v: V) -> f32 {
+        ///     v.as_slice().iter().sum()
+        /// }
+        ///
+        /// fn positive<V: SimdVector<Element = f32> + SimdFloat<V::Simd>>(v: V) -> V::Mask {
+        ///     v.simd_gt(0.0)
+        /// }
+        ///
+        /// let simd = Fallback::new();
+        /// assert_eq!(f32x4::<Fallback>::LANES, 4);
+        /// assert_eq!(sum_lanes(f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0])), 10.0);
+        /// assert_eq!(sum_lanes(f32x8::splat(simd, 0.5)), 4.0);
+        /// let mask = positive(f32x4::from_slice(simd, &[1.0, -2.0, 0.0, 4.0]));
+        /// assert_eq!(mask.to_bitmask(), 0b1001);
+        /// ```
+        pub trait SimdVector: SimdBase<Self::Simd> {
+            /// The [`Simd`] implementation which this vector uses.
+            type Simd: Simd;
+            /// This vector type's lane count, which is the same as [`SimdBase::N`].
+            const LANES: usize;
+        }
+    }
+}
+
 fn mk_simd_float() -> TokenStream {
     let methods = methods_for_vec_trait(ScalarType::Float);
     let overloaded_ops = overloaded_ops_for(ScalarType::Float);
@@ -217,13 +436,29 @@ fn mk_simd_float() -> TokenStream {
         pub trait SimdFloat<S: Simd>: SimdBase<S> + Seal
             #(+ #op_traits)*
         {
+            /// The signed integer vector with the same number and width of lanes.
+            type Int: SimdInt<S, Element = <Self::Element as SimdElement>::Mask>;
+            /// The unsigned integer vector with the same number and width of lanes.
+            type Uint: SimdInt<S>;
+
             /// Convert this floating-point type to an integer. This is a convenience method that
             /// delegates to [`SimdCvtTruncate::truncate_from`], and can only be called if there
             /// actually exists a target type of the same bit width (currently, only `u32` and
             /// `i32`).
             ///
+            /// The target can be either [`Self::Int`] or [`Self::Uint`], so it usually needs to be named, unless it's
+            /// inferred from how the result is used. [`to_uint`](Self::to_uint) doesn't need this for unsigned
+            /// integers.
+            ///
             /// For more information about the semantics of this specific conversion, see the
             /// concrete `SimdCvtTruncate` implementations for integer types.
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, Fallback, f32x4, i32x4};
+            /// # let simd = Fallback::new();
+            /// let a = f32x4::from_slice(simd, &[-1.5, 0.5, 2.5, 7.0]);
+            /// assert_eq!(*a.to_int::<i32x4<_>>(), [-1, 0, 2, 7]);
+            /// ```
             #[inline(always)]
             fn to_int<T: SimdCvtTruncate<Self>>(self) -> T { T::truncate_from(self) }
 
@@ -232,11 +467,42 @@ fn mk_simd_float() -> TokenStream {
             /// [`SimdCvtTruncate::truncate_from_precise`], and can only be called if there actually
             /// exists a target type of the same bit width (currently, only `u32` and `i32`).
             ///
+            /// As with [`to_int`](Self::to_int), the target can be either [`Self::Int`] or [`Self::Uint`].
+            ///
             /// For more information about the semantics of this specific conversion, see the
             /// concrete `SimdCvtTruncate` implementations for integer types.
             #[inline(always)]
             fn to_int_precise<T: SimdCvtTruncate<Self>>(self) -> T { T::truncate_from_precise(self) }
 
+            /// Convert this floating-point type to the unsigned integer vector with the same lanes, rounding towards
+            /// zero. This is [`to_int`](Self::to_int) with [`Self::Uint`] as the target, so it can only be called
+            /// if that conversion exists (currently, only for `f32` vectors).
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, Fallback, f32x4};
+            /// # let simd = Fallback::new();
+            /// let a = f32x4::from_slice(simd, &[0.5, 1.5, 2.5, 300.0]);
+            /// assert_eq!(*a.to_uint(), [0, 1, 2, 300]);
+            /// ```
+            #[inline(always)]
+            fn to_uint(self) -> Self::Uint
+            where
+                Self::Uint: SimdCvtTruncate<Self>,
+            {
+                SimdCvtTruncate::truncate_from(self)
+            }
+
+            /// Convert this floating-point type to the unsigned integer vector with the same lanes, saturating on
+            /// overflow and returning 0 for NaN. This is [`to_int_precise`](Self::to_int_precise) with
+            /// [`Self::Uint`] as the target.
+            #[inline(always)]
+            fn to_uint_precise(self) -> Self::Uint
+            where
+                Self::Uint: SimdCvtTruncate<Self>,
+            {
+                SimdCvtTruncate::truncate_from_precise(self)
+            }
+
             #( #methods )*
         }
     }
@@ -257,12 +523,77 @@ fn mk_simd_int() -> TokenStream {
         pub trait SimdInt<S: Simd>: SimdBase<S> + Seal
             #(+ #op_traits)*
         {
+            /// The signed integer vector with the same number and width of lanes. This is `Self` for signed
+            /// vectors.
+            type Signed: SimdInt<S, Element = <Self::Element as SimdElement>::Mask, Mask = Self::Mask, Bytes = Self::Bytes>;
+            /// The unsigned integer vector with the same number and width of lanes. This is `Self` for unsigned
+            /// vectors.
+            type Unsigned: SimdInt<S, Mask = Self::Mask, Bytes = Self::Bytes>;
+
+            /// Reinterpret the bits of each lane as a signed integer, like [`u32::cast_signed`].
+            ///
+            /// This is free: the register is unchanged, and only its type differs. It makes it possible to mix signed
+            /// and unsigned vectors in one expression, in the same way as intrinsics which ignore the sign, without
+            /// naming the type of the result:
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, Fallback, i8x16, u8x16};
+            /// # let simd = Fallback::new();
+            /// let pixels = u8x16::splat(simd, 200);
+            /// let offsets = i8x16::splat(simd, -3);
+            /// // Flip the top bit to map `u8` to `i8` while keeping the order, then add a signed offset.
+            /// let shifted = (pixels ^ 0x80).as_signed() + offsets;
+            /// assert_eq!(shifted[0], 69);
+            /// assert_eq!((shifted.as_unsigned() ^ 0x80)[0], 197);
+            /// ```
+            fn as_signed(self) -> Self::Signed;
+
+            /// Reinterpret the bits of each lane as an unsigned integer, like [`i32::cast_unsigned`].
+            ///
+            /// As with [`as_signed`](Self::as_signed), this is free.
+            fn as_unsigned(self) -> Self::Unsigned;
+
             /// Convert this integer type to a floating-point type. This is a convenience method
             /// that delegates to [`SimdCvtFloat::float_from`], and can only be called if there
             /// actually exists a target type of the same bit width (currently, only `f32`).
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, Fallback, f32x4, u32x4};
+            /// # let simd = Fallback::new();
+            /// let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);
+            /// assert_eq!(*a.to_float::<f32x4<_>>(), [0.0, 1.0, 2.0, 3.0]);
+            /// ```
             #[inline(always)]
             fn to_float<T: SimdCvtFloat<Self>>(self) -> T { T::float_from(self) }
 
+            /// The identity element of bitwise AND, with every bit set.
+            ///
+            /// This is the starting value for reducing with `&`, in the same way as
+            /// [`SimdBase::ADD_IDENTITY`] is for sums. Reductions with `|` and `^` start from `0`.
+            const AND_IDENTITY: Self::Element;
+
+            /// Create a SIMD vector where each element is its own lane index, that is,
+            /// `[0, 1, 2, ...]`.
+            ///
+            /// Comparing this with a scalar gives a mask of the lanes before or after a position,
+            /// for example to handle the tail of a slice which doesn't fill a whole vector:
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, *};
+            /// # #[inline(always)]
+            /// # fn example<S: Simd>(simd: S) {
+            /// let tail = [1, 2, 3];
+            /// let mut padded = [0; 8];
+            /// padded[..tail.len()].copy_from_slice(&tail);
+            /// let in_bounds = u32x8::lane_indices(simd).simd_lt(tail.len() as u32);
+            /// let values = in_bounds.select(u32x8::from_slice(simd, &padded), u32x8::splat(simd, 9));
+            /// assert_eq!(*values, [1, 2, 3, 9, 9, 9, 9, 9]);
+            /// # }
+            /// # example(Fallback::new());
+            /// # dispatch!(Level::new(), simd => example(simd));
+            /// ```
+            fn lane_indices(simd: S) -> Self;
+
             #( #methods )*
         }
     }
@@ -306,6 +637,9 @@ fn mk_simd_mask() -> TokenStream {
             /// (integer value -1).
             type Element: SimdElement;
 
+            /// The signed integer vector type with the same lanes as this mask.
+            type Int: SimdInt<S, Element = Self::Element, Mask = Self> + core::ops::Neg<Output = Self::Int>;
+
             /// This mask type's lane count.
             const N: usize;
 
@@ -324,9 +658,73 @@ fn mk_simd_mask() -> TokenStream {
             /// Convert this mask to a compact bitmask.
             ///
             /// Bit `i` maps to lane `i`, with lane 0 in the least significant bit. Bits above
-            /// [`Self::N`] are cleared.
+            /// [`Self::N`] are cleared. Every mask has at most 64 lanes, so this includes all
+            /// lanes of the 256-bit and 512-bit masks, which are packed with native instructions
+            /// where the level has them.
+            ///
+            /// [`first_true`](Self::first_true) and [`last_true`](Self::last_true) find which lane
+            /// of a search matched from this.
             fn to_bitmask(self) -> u64;
 
+            /// Count the lanes which are set.
+            ///
+            /// This is a single population count of [`Self::to_bitmask`], which uses the `popcnt`
+            /// instruction on x86 levels from SSE4.2, and `cnt` on NEON.
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, *};
+            /// # #[inline(always)]
+            /// # fn example<S: Simd>(simd: S) {
+            /// let values = f32x16::from_fn(simd, |i| i as f32);
+            /// assert_eq!(values.simd_gt(10.0).count_true(), 5);
+            /// # }
+            /// # example(Fallback::new());
+            /// # dispatch!(Level::new(), simd => example(simd));
+            /// ```
+            #[inline(always)]
+            fn count_true(self) -> usize {
+                self.to_bitmask().count_ones() as usize
+            }
+
+            /// The index of the first lane which is set, or `None` if none are.
+            ///
+            /// This is the number of trailing zeros of [`Self::to_bitmask`], which finds the first match of
+            /// a search, where [`any_true`](Self::any_true) only says whether there is one.
+            ///
+            /// ```rust
+            /// # use fearless_simd::{prelude::*, *};
+            /// # #[inline(always)]
+            /// # fn example<S: Simd>(simd: S) {
+            /// let bytes = u8x16::from_slice(simd, b"key=value;rest..");
+            /// assert_eq!(bytes.simd_eq(b'=').first_true(), Some(3));
+            /// assert_eq!(bytes.simd_eq(b'#').first_true(), None);
+            /// # }
+            /// # example(Fallback::new());
+            /// # dispatch!(Level::new(), simd => example(simd));
+            /// ```
+            #[inline(always)]
+            fn first_true(self) -> Option<usize> {
+                let bits = self.to_bitmask();
+                (bits != 0).then(|| bits.trailing_zeros() as usize)
+            }
+
+            /// The index of the last lane which is set, or `None` if none are.
+            ///
+            /// This is found from the number of leading zeros of [`Self::to_bitmask`], like
+            /// [`first_true`](Self::first_true).
+            #[inline(always)]
+            fn last_true(self) -> Option<usize> {
+                let bits = self.to_bitmask();
+                (bits != 0).then(|| (u64::BITS - 1 - bits.leading_zeros()) as usize)
+            }
+
+            /// Convert this mask to the signed integer vector with the same lanes.
+            ///
+            /// True lanes become `-1` and false lanes become `0`, so that, for example, matching
+            /// lanes can be counted by subtracting the converted mask from an accumulator. On most
+            /// levels, this is free.
+            fn to_int(self) -> Self::Int;
+
             /// Test whether one logical lane is set.
             ///
             /// Panics if `index` is greater than or equal to the number of lanes in the mask.
@@ -345,14 +743,25 @@ fn mk_simd_mask() -> TokenStream {
             /// Panics if `index` is greater than or equal to the number of lanes in the mask.
             fn set(&mut self, index: usize, value: bool);
 
+            /// Create a SIMD mask where each lane is set if `f` returns `true` for that lane's
+            /// index (from 0 to [`Self::N`] - 1).
+            fn from_fn(simd: S, f: impl FnMut(usize) -> bool) -> Self;
+
             /// Create a SIMD mask from signed integer mask lanes.
             ///
-            /// The slice must be exactly the size of the SIMD mask.
+            /// Each lane must be `-1` (true) or `0` (false); to build a mask from conditions,
+            /// [`Self::from_fn`] is less error-prone.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slice isn't exactly the size of the SIMD mask.
             fn from_slice(simd: S, slice: &[Self::Element]) -> Self;
 
             /// Store this SIMD mask as signed integer mask lanes.
             ///
-            /// The slice must be exactly the size of the SIMD mask.
+            /// # Panics
+            ///
+            /// Panics if the slice isn't exactly the size of the SIMD mask.
             fn store_slice(&self, slice: &mut [Self::Element]);
 
             #( #methods )*
@@ -363,7 +772,7 @@ fn mk_simd_mask() -> TokenStream {
 fn methods_for_vec_trait(scalar: ScalarType) -> Vec<TokenStream> {
     let mut methods = vec![];
     for op in vec_trait_ops_for(scalar) {
-        let doc = op.format_docstring(TyFlavor::VecImpl);
+        let doc = op.format_docstring_with_example(TyFlavor::VecImpl);
         let method_sig = if scalar == ScalarType::Mask && matches!(op.sig, OpSig::Compare) {
             Some(quote! { fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self })
         } else {
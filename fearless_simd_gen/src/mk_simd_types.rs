@@ -15,7 +15,7 @@ use crate::{
 
 pub(crate) fn mk_simd_types() -> TokenStream {
     let mut result = quote! {
-        use crate::{Bytes, Select, Simd, SimdBase, SimdFrom, SimdInto, SimdMask, SimdCvtFloat, SimdCvtTruncate, seal::Seal};
+        use crate::{Bytes, Select, Simd, SimdBase, SimdFrom, SimdInto, SimdMask, SimdVector, SimdCvtFloat, SimdCvtTruncate, seal::Seal};
     };
     for ty in SIMD_TYPES {
         let name = ty.rust();
@@ -26,6 +26,7 @@ pub(crate) fn mk_simd_types() -> TokenStream {
         let len = Literal::usize_unsuffixed(ty.len);
         let rust_scalar = ty.scalar.rust(ty.scalar_bits);
         let select = generic_op_name("select", ty);
+        let select_relaxed = generic_op_name("select_relaxed", ty);
         let from_array_op = generic_op_name("load_array", ty);
         let as_array_op = generic_op_name("as_array", ty);
         let as_array_ref_op = generic_op_name("as_array_ref", ty);
@@ -34,6 +35,7 @@ pub(crate) fn mk_simd_types() -> TokenStream {
         let to_bytes_op = generic_op_name("cvt_to_bytes", ty);
         let bytes = ty.bytes_ty().rust();
         let mask = ty.mask_ty().rust();
+        let le_bytes = le_bytes_impl(ty);
 
         if ty.scalar == ScalarType::Mask {
             let splat = Ident::new(&format!("splat_{}", ty.rust_name()), Span::call_site());
@@ -69,6 +71,14 @@ pub(crate) fn mk_simd_types() -> TokenStream {
                     }
                 }
 
+                impl<S: Simd> #name<S> {
+                    /// Create a SIMD mask with each lane set to the corresponding boolean.
+                    #[inline(always)]
+                    pub fn from_bools(simd: S, bools: &[bool; #len]) -> Self {
+                        Self::from_fn(simd, |i| bools[i])
+                    }
+                }
+
                 impl<S: Simd> SimdFrom<bool, S> for #name<S> {
                     #[inline(always)]
                     fn simd_from(simd: S, value: bool) -> Self {
@@ -81,6 +91,11 @@ pub(crate) fn mk_simd_types() -> TokenStream {
                     fn select(self, if_true: #name<S>, if_false: #name<S>) -> #name<S> {
                         self.simd.#select(self, if_true, if_false)
                     }
+
+                    #[inline(always)]
+                    fn select_relaxed(self, if_true: #name<S>, if_false: #name<S>) -> #name<S> {
+                        self.simd.#select_relaxed(self, if_true, if_false)
+                    }
                 }
 
                 #impl_block
@@ -143,6 +158,26 @@ pub(crate) fn mk_simd_types() -> TokenStream {
                         }
                     });
                 }
+                let [dot2, dot3, dot4] =
+                    ["dot2", "dot3", "dot4"].map(|method| generic_op_name(method, ty));
+                conditional_impls.push(quote! {
+                    impl<S: Simd> crate::SimdDot<S> for #name<S> {
+                        #[inline(always)]
+                        fn dot2(self, rhs: impl SimdInto<Self, S>) -> Self {
+                            self.simd.#dot2(self, rhs.simd_into(self.simd))
+                        }
+
+                        #[inline(always)]
+                        fn dot3(self, rhs: impl SimdInto<Self, S>) -> Self {
+                            self.simd.#dot3(self, rhs.simd_into(self.simd))
+                        }
+
+                        #[inline(always)]
+                        fn dot4(self, rhs: impl SimdInto<Self, S>) -> Self {
+                            self.simd.#dot4(self, rhs.simd_into(self.simd))
+                        }
+                    }
+                });
             }
             ScalarType::Int | ScalarType::Unsigned if ty.scalar_bits == 32 => {
                 let src_ty = ty.cast(ScalarType::Float);
@@ -214,6 +249,7 @@ pub(crate) fn mk_simd_types() -> TokenStream {
                 }
             });
         }
+        conditional_impls.extend(resize_impls(ty));
         result.extend(quote! {
             #[doc = #doc]
             #[derive(Clone, Copy)]
@@ -267,6 +303,11 @@ pub(crate) fn mk_simd_types() -> TokenStream {
                 fn select(self, if_true: #name<S>, if_false: #name<S>) -> #name<S> {
                     self.simd.#select(self, if_true, if_false)
                 }
+
+                #[inline(always)]
+                fn select_relaxed(self, if_true: #name<S>, if_false: #name<S>) -> #name<S> {
+                    self.simd.#select_relaxed(self, if_true, if_false)
+                }
             }
 
             impl<S: Simd> Bytes for #name<S> {
@@ -281,6 +322,8 @@ pub(crate) fn mk_simd_types() -> TokenStream {
                 fn from_bytes(value: Self::Bytes) -> Self {
                     value.simd.#from_bytes_op(value)
                 }
+
+                #le_bytes
             }
 
             #impl_block
@@ -291,6 +334,52 @@ pub(crate) fn mk_simd_types() -> TokenStream {
     result
 }
 
+/// The `to_le_bytes` and `from_le_bytes` methods of `Bytes`, which only need to swap the bytes of
+/// each lane on big-endian targets.
+fn le_bytes_impl(ty: &VecType) -> TokenStream {
+    if ty.scalar_bits == 8 {
+        return quote! {
+            #[inline(always)]
+            fn to_le_bytes(self) -> Self::Bytes {
+                self.to_bytes()
+            }
+
+            #[inline(always)]
+            fn from_le_bytes(value: Self::Bytes) -> Self {
+                Self::from_bytes(value)
+            }
+        };
+    }
+    let rust_scalar = ty.scalar.rust(ty.scalar_bits);
+    let swap_lane = if ty.scalar == ScalarType::Float {
+        quote! { #rust_scalar::from_bits(lane.to_bits().swap_bytes()) }
+    } else {
+        quote! { lane.swap_bytes() }
+    };
+    quote! {
+        #[inline(always)]
+        fn to_le_bytes(mut self) -> Self::Bytes {
+            if cfg!(target_endian = "big") {
+                for lane in self.as_mut_slice() {
+                    *lane = #swap_lane;
+                }
+            }
+            self.to_bytes()
+        }
+
+        #[inline(always)]
+        fn from_le_bytes(value: Self::Bytes) -> Self {
+            let mut result = Self::from_bytes(value);
+            if cfg!(target_endian = "big") {
+                for lane in result.as_mut_slice() {
+                    *lane = #swap_lane;
+                }
+            }
+            result
+        }
+    }
+}
+
 fn simd_mask_impl(ty: &VecType) -> TokenStream {
     let name = ty.rust();
     let scalar = ty.scalar.rust(ty.scalar_bits);
@@ -298,9 +387,12 @@ fn simd_mask_impl(ty: &VecType) -> TokenStream {
     let splat = generic_op_name("splat", ty);
     let from_bitmask_op = generic_op_name("from_bitmask", ty);
     let to_bitmask_op = generic_op_name("to_bitmask", ty);
+    let to_int_op = generic_op_name("to_int", ty);
+    let int_ty = ty.cast(ScalarType::Int).rust();
     let set_op = generic_op_name("set", ty);
     let from_array_op = generic_op_name("load_array", ty);
     let as_array_op = generic_op_name("as_array", ty);
+    let from_fn_items = unrolled_array(ty.len, |idx| quote! { if f(#idx) { -1 } else { 0 } });
     let mut methods = vec![];
     for op in vec_trait_ops_for(ty.scalar) {
         let Op { sig, method, .. } = op;
@@ -326,6 +418,7 @@ fn simd_mask_impl(ty: &VecType) -> TokenStream {
     quote! {
         impl<S: Simd> SimdMask<S> for #name<S> {
             type Element = #scalar;
+            type Int = #int_ty<S>;
             const N: usize = #len;
 
             #[inline(always)]
@@ -348,11 +441,21 @@ fn simd_mask_impl(ty: &VecType) -> TokenStream {
                 self.simd.#to_bitmask_op(self)
             }
 
+            #[inline(always)]
+            fn to_int(self) -> #int_ty<S> {
+                self.simd.#to_int_op(self)
+            }
+
             #[inline(always)]
             fn set(&mut self, index: usize, value: bool) {
                 self.simd.#set_op(self, index, value);
             }
 
+            #[inline(always)]
+            fn from_fn(simd: S, mut f: impl FnMut(usize) -> bool) -> Self {
+                simd.#from_array_op(#from_fn_items)
+            }
+
             #[inline(always)]
             fn from_slice(simd: S, slice: &[#scalar]) -> Self {
                 let slice: &[#scalar; #len] = slice.try_into().unwrap();
@@ -374,6 +477,7 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
     let name = ty.rust();
     let scalar = ty.scalar.rust(ty.scalar_bits);
     let len = Literal::usize_unsuffixed(ty.len);
+    let bits = Literal::usize_unsuffixed(ty.n_bits());
     let from_fn_items = unrolled_array(ty.len, |idx| quote! { f(#idx) });
     let vec_trait = match ty.scalar {
         ScalarType::Float => "SimdFloat",
@@ -382,6 +486,23 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
     };
     let vec_trait_id = Ident::new(vec_trait, Span::call_site());
     let splat = generic_op_name("splat", ty);
+    let as_array_op = generic_op_name("as_array", ty);
+    let zero = match ty.scalar {
+        ScalarType::Float => quote! { 0.0 },
+        _ => quote! { 0 },
+    };
+    let (add_identity, min_identity, max_identity) = match ty.scalar {
+        ScalarType::Float => (
+            quote! { -0.0 },
+            quote! { #scalar::INFINITY },
+            quote! { #scalar::NEG_INFINITY },
+        ),
+        _ => (
+            quote! { 0 },
+            quote! { #scalar::MAX },
+            quote! { #scalar::MIN },
+        ),
+    };
     let mut methods = vec![];
     for op in vec_trait_ops_for(ty.scalar) {
         let Op { sig, method, .. } = op;
@@ -398,6 +519,44 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
             });
         }
     }
+    if vec_trait == "SimdFloat" {
+        let int_ty = ty.cast(ScalarType::Int).rust();
+        let uint_ty = ty.cast(ScalarType::Unsigned).rust();
+        methods.push(quote! {
+            type Int = #int_ty<S>;
+            type Uint = #uint_ty<S>;
+        });
+    }
+    if vec_trait == "SimdInt" {
+        let from_array_op = generic_op_name("load_array", ty);
+        let indices = unrolled_array(ty.len, |idx| {
+            let idx = Literal::usize_unsuffixed(idx);
+            quote! { #idx }
+        });
+        let signed_ty = ty.cast(ScalarType::Int).rust();
+        let unsigned_ty = ty.cast(ScalarType::Unsigned).rust();
+        methods.push(quote! {
+            type Signed = #signed_ty<S>;
+            type Unsigned = #unsigned_ty<S>;
+
+            #[inline(always)]
+            fn as_signed(self) -> Self::Signed {
+                self.bitcast()
+            }
+
+            #[inline(always)]
+            fn as_unsigned(self) -> Self::Unsigned {
+                self.bitcast()
+            }
+
+            const AND_IDENTITY: #scalar = !0;
+
+            #[inline(always)]
+            fn lane_indices(simd: S) -> Self {
+                simd.#from_array_op(#indices)
+            }
+        });
+    }
     let mask_ty = ty.mask_ty().rust();
     let block_ty = ty.block_ty().rust();
     let block_splat_body = match ty.n_bits() {
@@ -434,11 +593,18 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
     let rotate_elements_right_op = generic_op_name("rotate_elements_right", ty);
     let shift_elements_left_op = generic_op_name("shift_elements_left", ty);
     let shift_elements_right_op = generic_op_name("shift_elements_right", ty);
+    let extract_op = generic_op_name("extract", ty);
+    let insert_op = generic_op_name("insert", ty);
+    let extract_dyn_op = generic_op_name("extract_dyn", ty);
     let swizzle_dyn_within_blocks_op = generic_op_name("swizzle_dyn_within_blocks", ty);
     quote! {
         impl<S: Simd> SimdBase<S> for #name<S> {
             type Element = #scalar;
             const N: usize = #len;
+            const BITS: usize = #bits;
+            const ADD_IDENTITY: #scalar = #add_identity;
+            const MIN_IDENTITY: #scalar = #min_identity;
+            const MAX_IDENTITY: #scalar = #max_identity;
             type Mask = #mask_ty<S>;
             type Block = #block_ty<S>;
             type Array = [#scalar; #len];
@@ -463,11 +629,33 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
                 simd.#from_array_ref_op(slice.try_into().unwrap())
             }
 
+            #[inline(always)]
+            fn try_from_slice(simd: S, slice: &[#scalar]) -> Option<Self> {
+                let array = slice.try_into().ok()?;
+                Some(simd.#from_array_ref_op(array))
+            }
+
             #[inline(always)]
             fn store_slice(&self, slice: &mut [#scalar]) {
                 self.simd.#store_array_op(*self, slice.try_into().unwrap());
             }
 
+            #[inline(always)]
+            fn write_to_uninit<'a>(
+                &self,
+                slice: &'a mut [core::mem::MaybeUninit<#scalar>],
+            ) -> &'a mut [#scalar] {
+                crate::support::write_uninit_array(
+                    slice.try_into().unwrap(),
+                    self.simd.#as_array_op(*self),
+                )
+            }
+
+            #[inline(always)]
+            fn zeroed(simd: S) -> Self {
+                simd.#splat(#zero)
+            }
+
             #[inline(always)]
             fn splat(simd: S, val: #scalar) -> Self {
                 simd.#splat(val)
@@ -513,6 +701,21 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
                 self.simd.#shift_elements_right_op::<OFFSET>(self, padding)
             }
 
+            #[inline(always)]
+            fn extract<const LANE: usize>(self) -> #scalar {
+                self.simd.#extract_op::<LANE>(self)
+            }
+
+            #[inline(always)]
+            fn insert<const LANE: usize>(self, value: #scalar) -> Self {
+                self.simd.#insert_op::<LANE>(self, value)
+            }
+
+            #[inline(always)]
+            fn extract_dyn(self, index: usize) -> #scalar {
+                self.simd.#extract_dyn_op(self, index)
+            }
+
             #[inline(always)]
             fn swizzle_dyn_within_blocks(self, indices: impl SimdInto<Self::Bytes, S>) -> Self {
                 self.simd.#swizzle_dyn_within_blocks_op(self, indices.simd_into(self.simd))
@@ -521,5 +724,57 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
         impl<S: Simd> crate::#vec_trait_id<S> for #name<S> {
             #( #methods )*
         }
+        impl<S: Simd> SimdVector for #name<S> {
+            type Simd = S;
+            const LANES: usize = #len;
+        }
+    }
+}
+
+/// Implement `SimdZeroExtend` from this vector type into every wider vector type with the same element type, and
+/// `SimdTruncateLanes` from every wider vector type into this one.
+///
+/// These are chains of `combine` and `split`, which are free or a single register move on most levels.
+fn resize_impls(ty: &VecType) -> Vec<TokenStream> {
+    let name = ty.rust();
+    let zero = match ty.scalar {
+        ScalarType::Float => quote! { 0.0 },
+        _ => quote! { 0 },
+    };
+    let mut impls = Vec::new();
+    let mut extended = quote! { x };
+    let mut splits = Vec::new();
+    let mut wide_ty = *ty;
+    while let Some(next_ty) = wide_ty.combine_operand() {
+        let combine = generic_op_name("combine", &wide_ty);
+        let splat = generic_op_name("splat", &wide_ty);
+        let split = generic_op_name("split", &next_ty);
+        extended = quote! { simd.#combine(#extended, simd.#splat(#zero)) };
+        // Splitting starts from the widest vector, so the newest split is applied first.
+        splits.push(split);
+        let truncated = splits.iter().rev().fold(
+            quote! { x },
+            |inner, split| quote! { simd.#split(#inner).0 },
+        );
+        let wide = next_ty.rust();
+        impls.push(quote! {
+            impl<S: Simd> crate::SimdZeroExtend<#name<S>> for #wide<S> {
+                #[inline(always)]
+                fn zero_extend_from(x: #name<S>) -> Self {
+                    let simd = x.simd;
+                    #extended
+                }
+            }
+
+            impl<S: Simd> crate::SimdTruncateLanes<#wide<S>> for #name<S> {
+                #[inline(always)]
+                fn truncate_lanes_from(x: #wide<S>) -> Self {
+                    let simd = x.simd;
+                    #truncated
+                }
+            }
+        });
+        wide_ty = next_ty;
     }
+    impls
 }
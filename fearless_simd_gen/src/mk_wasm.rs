@@ -6,9 +6,11 @@ use quote::{format_ident, quote};
 
 use crate::arch::wasm::{arch_prefix, v128_intrinsic};
 use crate::generic::{
-    fallback_method, generic_as_array, generic_block_combine, generic_block_split,
-    generic_from_array, generic_from_bytes, generic_mask_set, generic_op_name, generic_store_array,
-    generic_to_bytes, integer_lane_mask_splat_arg,
+    const_lane_check, fallback_method, generic_as_array, generic_block_combine,
+    generic_block_split, generic_extract_dyn, generic_from_array, generic_from_bytes,
+    generic_mask_set, generic_mask_to_int, generic_op_name, generic_store_array, generic_to_bytes,
+    integer_lane_mask_splat_arg, leading_zeros_from_count_ones, table_compress_bytes,
+    trailing_zeros_from_count_ones,
 };
 use crate::level::Level;
 use crate::ops::{Op, Quantifier, SlideGranularity, valid_reinterpret};
@@ -105,6 +107,12 @@ impl Level for WasmSimd128 {
         128
     }
 
+    fn fused_mul_add(&self) -> bool {
+        // `relaxed_madd` is allowed to be either fused or unfused, depending on the host, so it's only used by
+        // `mul_add_relaxed`.
+        false
+    }
+
     fn enabled_target_features(&self) -> Option<&'static str> {
         None
     }
@@ -152,6 +160,51 @@ impl Level for WasmSimd128 {
                     }
                 }
             }
+            OpSig::Unary if method == "count_ones" => {
+                // `popcnt` counts the bits of each byte, and `extadd_pairwise` adds up adjacent pairs of counts into
+                // wider elements. There's no pairwise addition into 64-bit elements, so the halves of those are
+                // added with a shift.
+                let count = match vec_ty.scalar_bits {
+                    8 => quote! { i8x16_popcnt(a.into()) },
+                    16 => quote! { i16x8_extadd_pairwise_u8x16(i8x16_popcnt(a.into())) },
+                    32 | 64 => quote! {
+                        i32x4_extadd_pairwise_u16x8(i16x8_extadd_pairwise_u8x16(i8x16_popcnt(a.into())))
+                    },
+                    _ => unreachable!(),
+                };
+                let count = if vec_ty.scalar_bits == 64 {
+                    quote! {
+                        {
+                            let halves = #count;
+                            i64x2_add(v128_and(halves, u64x2_splat(0xffff_ffff)), u64x2_shr(halves, 32))
+                        }
+                    }
+                } else {
+                    count
+                };
+                quote! {
+                    #method_sig {
+                        #count.simd_into(self)
+                    }
+                }
+            }
+            // WebAssembly has no leading or trailing zero counts.
+            OpSig::Unary if method == "leading_zeros" => {
+                let body = leading_zeros_from_count_ones(vec_ty);
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
+            OpSig::Unary if method == "trailing_zeros" => {
+                let body = trailing_zeros_from_count_ones(vec_ty);
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
             OpSig::Unary => {
                 let args = [quote! { a.into() }];
                 let expr = if matches!(method, "fract") {
@@ -186,17 +239,47 @@ impl Level for WasmSimd128 {
                     }
                 }
             }
+            // WebAssembly only has shifts by a single amount for the whole vector.
+            OpSig::ShiftSigned => fallback_method(op, vec_ty),
+            OpSig::Binary if matches!(method, "dot2" | "dot3" | "dot4") => {
+                // Replace the ignored product with zero, like `dpps` does.
+                let mask_product = (method == "dot3").then(|| {
+                    quote! {
+                        let p = f32x4_replace_lane::<3>(p, 0.0);
+                    }
+                });
+                let sum = if method == "dot2" {
+                    quote! { pairs }
+                } else {
+                    quote! { f32x4_add(pairs, i32x4_shuffle::<2, 3, 0, 1>(pairs, pairs)) }
+                };
+                quote! {
+                    #method_sig {
+                        let p = f32x4_mul(a.into(), b.into());
+                        #mask_product
+                        let pairs = f32x4_add(p, i32x4_shuffle::<1, 0, 3, 2>(p, p));
+                        #sum.simd_into(self)
+                    }
+                }
+            }
             OpSig::Binary => {
                 if matches!(method, "shlv" | "shrv")
                     || (matches!(method, "min" | "max")
                         && vec_ty.scalar_bits == 64
                         && matches!(vec_ty.scalar, ScalarType::Int | ScalarType::Unsigned))
+                    // WASM SIMD only has saturating arithmetic for 8-bit and 16-bit lanes.
+                    || (matches!(method, "saturating_add" | "saturating_sub")
+                        && vec_ty.scalar_bits >= 32)
                 {
                     return fallback_method(op, vec_ty);
                 }
 
                 let args = [quote! { a.into() }, quote! { b.into() }];
                 let expr = match method {
+                    "table_lookup" => {
+                        // `swizzle` gives zero for indices which are out of range.
+                        quote! { u8x16_swizzle(a.into(), b.into()).simd_into(self) }
+                    }
                     "mul" if vec_ty.scalar_bits == 8 && vec_ty.len == 16 => {
                         let (extmul_low, extmul_high) = match vec_ty.scalar {
                             ScalarType::Unsigned => (
@@ -282,34 +365,40 @@ impl Level for WasmSimd128 {
                 }
             }
             OpSig::Ternary => {
-                if matches!(method, "mul_add" | "mul_sub") {
-                    let add_sub =
-                        generic_op_name(if method == "mul_add" { "add" } else { "sub" }, vec_ty);
-                    let mul = generic_op_name("mul", vec_ty);
-
-                    let c = if method == "mul_sub" {
-                        // WebAssembly just... forgot fused multiply-subtract? It seems the
-                        // initial proposal
-                        // (https://github.com/WebAssembly/relaxed-simd/issues/27) confused it
-                        // with negate multiply-add, and nobody ever resolved the confusion.
-                        let negate = simple_intrinsic("neg", vec_ty);
-                        quote! { #negate(c.into()) }
-                    } else {
-                        quote! { c.into() }
-                    };
-                    let relaxed_madd = simple_intrinsic("relaxed_madd", vec_ty);
-
-                    quote! {
-                        #method_sig {
-                            #[cfg(target_feature = "relaxed-simd")]
-                            { #relaxed_madd(a.into(), b.into(), #c).simd_into(self) }
+                let mul = generic_op_name("mul", vec_ty);
+                let sub = generic_op_name("sub", vec_ty);
+                // The relaxed instructions may be fused or not, depending on the host, so they're only used by
+                // `mul_add_relaxed`, and the other ops always multiply and then add, like the fallback level.
+                let unfused = match method {
+                    "mul_add" => {
+                        let add = generic_op_name("add", vec_ty);
+                        quote! { self.#add(self.#mul(a, b), c) }
+                    }
+                    "mul_sub" => quote! { self.#sub(self.#mul(a, b), c) },
+                    "nmul_add" => quote! { self.#sub(c, self.#mul(a, b)) },
+                    "nmul_sub" => {
+                        let neg = generic_op_name("neg", vec_ty);
+                        quote! { self.#sub(self.#neg(self.#mul(a, b)), c) }
+                    }
+                    "mul_add_relaxed" => {
+                        let mul_add = generic_op_name("mul_add", vec_ty);
+                        let relaxed_madd = simple_intrinsic("relaxed_madd", vec_ty);
+                        return quote! {
+                            #method_sig {
+                                #[cfg(target_feature = "relaxed-simd")]
+                                { #relaxed_madd(a.into(), b.into(), c.into()).simd_into(self) }
 
-                            #[cfg(not(target_feature = "relaxed-simd"))]
-                            { self.#add_sub(self.#mul(a, b), c) }
-                        }
+                                #[cfg(not(target_feature = "relaxed-simd"))]
+                                { self.#mul_add(a, b, c) }
+                            }
+                        };
+                    }
+                    _ => unimplemented!(),
+                };
+                quote! {
+                    #method_sig {
+                        #unfused
                     }
-                } else {
-                    unimplemented!()
                 }
             }
             OpSig::Compare => {
@@ -326,18 +415,26 @@ impl Level for WasmSimd128 {
                 }
             }
             OpSig::Select => {
-                // Rust includes unsigned versions of the lane select intrinsics, but they're
-                // just aliases for the signed ones
-                let lane_ty = vec_ty.cast(ScalarType::Int);
-                let lane_select = simple_intrinsic("relaxed_laneselect", &lane_ty);
-
-                quote! {
-                    #method_sig {
-                        #[cfg(target_feature = "relaxed-simd")]
-                        { #lane_select(b.into(), c.into(), a.into()).simd_into(self) }
+                if method == "select_relaxed" {
+                    // Rust includes unsigned versions of the lane select intrinsics, but they're
+                    // just aliases for the signed ones
+                    let lane_ty = vec_ty.cast(ScalarType::Int);
+                    let lane_select = simple_intrinsic("relaxed_laneselect", &lane_ty);
+                    let select = generic_op_name("select", vec_ty);
+                    quote! {
+                        #method_sig {
+                            #[cfg(target_feature = "relaxed-simd")]
+                            { #lane_select(b.into(), c.into(), a.into()).simd_into(self) }
 
-                        #[cfg(not(target_feature = "relaxed-simd"))]
-                        { v128_bitselect(b.into(), c.into(), a.into()).simd_into(self) }
+                            #[cfg(not(target_feature = "relaxed-simd"))]
+                            { self.#select(a, b, c) }
+                        }
+                    }
+                } else {
+                    quote! {
+                        #method_sig {
+                            v128_bitselect(b.into(), c.into(), a.into()).simd_into(self)
+                        }
                     }
                 }
             }
@@ -497,6 +594,74 @@ impl Level for WasmSimd128 {
             OpSig::ElementRotate { .. } | OpSig::ElementShift { .. } => {
                 unreachable!("element moves use generic lowering")
             }
+            OpSig::BitTranspose => unreachable!("bit transposes use generic lowering"),
+            OpSig::GfMulConst => unreachable!("GF(2^8) multiplication uses generic lowering"),
+            OpSig::Compress { expand } => {
+                let token = Ident::new("self", Span::call_site());
+                let body = table_compress_bytes(&token, vec_ty, expand, |a, indices| {
+                    quote! { u8x16_swizzle(#a, #indices) }
+                });
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
+            OpSig::WidenAccumulate { acc_ty } => {
+                let bits = vec_ty.scalar_bits;
+                let acc_bits = acc_ty.scalar_bits;
+                let sub_sat = format_ident!("u{bits}x{}_sub_sat", vec_ty.len);
+                let extend_low = format_ident!(
+                    "u{acc_bits}x{}_extend_low_u{bits}x{}",
+                    vec_ty.len / 2,
+                    vec_ty.len
+                );
+                let extend_high = format_ident!(
+                    "u{acc_bits}x{}_extend_high_u{bits}x{}",
+                    vec_ty.len / 2,
+                    vec_ty.len
+                );
+                let add = format_ident!("i{acc_bits}x{}_add", vec_ty.len / 2);
+                let split_acc = generic_op_name("split", &acc_ty);
+                let combine_acc = generic_op_name(
+                    "combine",
+                    &VecType::new(acc_ty.scalar, acc_bits, vec_ty.len / 2),
+                );
+                quote! {
+                    #method_sig {
+                        let (a, b) = (a.into(), b.into());
+                        // One of the saturating differences is always zero.
+                        let abd = v128_or(#sub_sat(a, b), #sub_sat(b, a));
+                        let (acc0, acc1) = self.#split_acc(acc);
+                        let lo = #add(acc0.into(), #extend_low(abd));
+                        let hi = #add(acc1.into(), #extend_high(abd));
+                        self.#combine_acc(lo.simd_into(self), hi.simd_into(self))
+                    }
+                }
+            }
+            OpSig::ReduceWidened => {
+                let sign = if vec_ty.scalar == ScalarType::Int {
+                    "i"
+                } else {
+                    "u"
+                };
+                let extadd_32 = format_ident!("{sign}32x4_extadd_pairwise_{sign}16x8");
+                let pairs = if vec_ty.scalar_bits == 8 {
+                    let extadd_16 = format_ident!("{sign}16x8_extadd_pairwise_{sign}8x16");
+                    quote! { #extadd_32(#extadd_16(a.into())) }
+                } else {
+                    quote! { #extadd_32(a.into()) }
+                };
+                let extract = format_ident!("{sign}32x4_extract_lane");
+                quote! {
+                    #method_sig {
+                        let sums = #pairs;
+                        let sums = i32x4_add(sums, i32x4_shuffle::<2, 3, 0, 1>(sums, sums));
+                        let sums = i32x4_add(sums, i32x4_shuffle::<1, 0, 3, 2>(sums, sums));
+                        #extract::<0>(sums)
+                    }
+                }
+            }
             OpSig::SwizzleDynWithinBlocks => {
                 assert_eq!(
                     vec_ty.n_bits(),
@@ -560,21 +725,17 @@ impl Level for WasmSimd128 {
             OpSig::WidenNarrow { target_ty } => {
                 match method {
                     "widen" => {
-                        assert_eq!(
-                            vec_ty.rust_name(),
-                            "u8x16",
-                            "Currently only u8x16 -> u16x16 widening is supported"
-                        );
-                        assert_eq!(
-                            target_ty.rust_name(),
-                            "u16x16",
-                            "Currently only u8x16 -> u16x16 widening is supported"
-                        );
+                        let half_ty = target_ty.block_ty();
+                        let half_name = half_ty.rust_name();
+                        let src_name = vec_ty.rust_name();
+                        let extend_low = format_ident!("{half_name}_extend_low_{src_name}");
+                        let extend_high = format_ident!("{half_name}_extend_high_{src_name}");
+                        let combine = generic_op_name("combine", &half_ty);
                         quote! {
                             #method_sig {
-                                let low = u16x8_extend_low_u8x16(a.into());
-                                let high = u16x8_extend_high_u8x16(a.into());
-                                self.combine_u16x8(low.simd_into(self), high.simd_into(self))
+                                let low = #extend_low(a.into());
+                                let high = #extend_high(a.into());
+                                self.#combine(low.simd_into(self), high.simd_into(self))
                             }
                         }
                     }
@@ -602,6 +763,59 @@ impl Level for WasmSimd128 {
                             }
                         }
                     }
+                    "pack_saturate_i8" | "pack_saturate_u8" => {
+                        let split = generic_op_name("split", vec_ty);
+                        let narrow = if target_ty.scalar == ScalarType::Int {
+                            quote! { i8x16_narrow_i16x8 }
+                        } else {
+                            quote! { u8x16_narrow_i16x8 }
+                        };
+                        // The narrowing instructions treat their inputs as signed, so unsigned inputs are first
+                        // clamped to the target's maximum.
+                        let clamp = (vec_ty.scalar == ScalarType::Unsigned).then(|| {
+                            let max = if target_ty.scalar == ScalarType::Int {
+                                quote! { 127 }
+                            } else {
+                                quote! { 255 }
+                            };
+                            quote! {
+                                let max = u16x8_splat(#max);
+                                let low = u16x8_min(low, max);
+                                let high = u16x8_min(high, max);
+                            }
+                        });
+                        quote! {
+                            #method_sig {
+                                let (low, high) = self.#split(a);
+                                let (low, high) = (low.into(), high.into());
+                                #clamp
+                                #narrow(low, high).simd_into(self)
+                            }
+                        }
+                    }
+                    "pack_clamped_u8" => {
+                        assert_eq!(
+                            vec_ty.rust_name(),
+                            "f32x16",
+                            "Currently only f32x16 -> u8x16 packing is supported"
+                        );
+                        // The saturating float-to-int conversion truncates and maps NaN to 0, so round first.
+                        // The narrowing instructions then saturate the integers down to bytes.
+                        quote! {
+                            #method_sig {
+                                let (a, b) = self.split_f32x16(a);
+                                let (a0, a1) = self.split_f32x8(a);
+                                let (b0, b1) = self.split_f32x8(b);
+                                let a0 = i32x4_trunc_sat_f32x4(f32x4_nearest(a0.into()));
+                                let a1 = i32x4_trunc_sat_f32x4(f32x4_nearest(a1.into()));
+                                let b0 = i32x4_trunc_sat_f32x4(f32x4_nearest(b0.into()));
+                                let b1 = i32x4_trunc_sat_f32x4(f32x4_nearest(b1.into()));
+                                let lo = i16x8_narrow_i32x4(a0, a1);
+                                let hi = i16x8_narrow_i32x4(b0, b1);
+                                u8x16_narrow_i16x8(lo, hi).simd_into(self)
+                            }
+                        }
+                    }
                     _ => unimplemented!(),
                 }
             }
@@ -626,7 +840,30 @@ impl Level for WasmSimd128 {
             }
             OpSig::MaskFromBitmask => mask_from_bitmask(method_sig, vec_ty),
             OpSig::MaskToBitmask => mask_to_bitmask(method_sig, vec_ty),
+            OpSig::MaskToInt => generic_mask_to_int(method_sig, vec_ty),
             OpSig::MaskSet => generic_mask_set(method_sig, vec_ty),
+            // The lane intrinsics take the lane as a const generic, so `LANE` can be passed through.
+            OpSig::ExtractLane { dynamic: false } => {
+                let check = const_lane_check(vec_ty);
+                let extract_lane = simple_intrinsic("extract_lane", vec_ty);
+                quote! {
+                    #method_sig {
+                        #check
+                        #extract_lane::<LANE>(a.into())
+                    }
+                }
+            }
+            OpSig::ExtractLane { dynamic: true } => generic_extract_dyn(method_sig, vec_ty),
+            OpSig::InsertLane => {
+                let check = const_lane_check(vec_ty);
+                let replace_lane = simple_intrinsic("replace_lane", vec_ty);
+                quote! {
+                    #method_sig {
+                        #check
+                        #replace_lane::<LANE>(a.into(), value).simd_into(self)
+                    }
+                }
+            }
             OpSig::LoadInterleaved {
                 block_size,
                 block_count,
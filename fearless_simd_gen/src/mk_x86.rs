@@ -7,9 +7,11 @@ use crate::arch::x86::{
     unpack_intrinsic,
 };
 use crate::generic::{
-    fallback_method, generic_as_array, generic_block_combine, generic_block_split,
-    generic_from_array, generic_from_bytes, generic_mask_from_bitmask, generic_mask_set,
-    generic_op_name, generic_store_array, generic_to_bytes, integer_lane_mask_splat_arg,
+    const_lane_check, fallback_method, generic_as_array, generic_block_combine,
+    generic_block_split, generic_extract_dyn, generic_from_array, generic_from_bytes,
+    generic_mask_from_bitmask, generic_mask_set, generic_mask_to_int, generic_op_name,
+    generic_relaxed, generic_store_array, generic_to_bytes, integer_lane_mask_splat_arg,
+    leading_zeros_from_count_ones, table_compress_bytes, trailing_zeros_from_count_ones,
 };
 use crate::level::Level;
 use crate::ops::{Op, OpSig, Quantifier, SlideGranularity, valid_reinterpret};
@@ -54,6 +56,10 @@ impl Level for X86 {
         self.native_width()
     }
 
+    fn fused_mul_add(&self) -> bool {
+        matches!(self, Self::Avx2 | Self::Avx512)
+    }
+
     fn enabled_target_features(&self) -> Option<&'static str> {
         Some(match self {
             Self::Sse2 => SSE2_FEATURES,
@@ -106,20 +112,34 @@ impl Level for X86 {
         }
     }
 
+    fn make_arch_imports(&self) -> TokenStream {
+        quote! {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::*;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::*;
+        }
+    }
+
+    fn cargo_feature(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Sse2 => "sse2",
+            Self::Sse4_2 => "sse4_2",
+            Self::Avx2 => "avx2",
+            Self::Avx512 => "avx512",
+        })
+    }
+
     fn make_module_prelude(&self) -> TokenStream {
-        let float_ext = if *self == Self::Sse2 {
-            crate::mk_fallback::float_ext_prelude()
+        let fallback_float = if *self == Self::Sse2 {
+            crate::mk_fallback::fallback_float_prelude()
         } else {
             TokenStream::new()
         };
 
         quote! {
-            #[cfg(target_arch = "x86")]
-            use core::arch::x86::*;
-            #[cfg(target_arch = "x86_64")]
-            use core::arch::x86_64::*;
             use core::ops::*;
-            #float_ext
+            #fallback_float
         }
     }
 
@@ -277,6 +297,22 @@ impl Level for X86 {
             return true;
         }
 
+        // Only AVX-512 can compress or expand more than 16 bytes at once.
+        if *self != Self::Avx512
+            && matches!(op.sig, OpSig::Compress { .. })
+            && vec_ty.n_bits() > 128
+        {
+            return true;
+        }
+
+        // GFNI transposes each 8x8 bit matrix with affine transforms, and multiplies bytes in GF(2^8). The 16x16
+        // transpose is built from the 8x8 one.
+        if *self == Self::Avx512
+            && (op.method == "transpose_bits_8x8" || matches!(op.sig, OpSig::GfMulConst))
+        {
+            return false;
+        }
+
         let should_use_generic = op.sig.should_use_generic_op(vec_ty, self.native_width());
         if !should_use_generic {
             return false;
@@ -290,6 +326,9 @@ impl Level for X86 {
     }
 
     fn make_method(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        if let Some(method) = generic_relaxed(op, vec_ty) {
+            return method;
+        }
         let Op { sig, method, .. } = op;
         let method_sig = op.simd_trait_method_sig(vec_ty);
 
@@ -300,8 +339,12 @@ impl Level for X86 {
             OpSig::WidenNarrow { target_ty } => {
                 self.handle_widen_narrow(op, method, vec_ty, target_ty)
             }
+            OpSig::Binary if matches!(method, "dot2" | "dot3" | "dot4") => {
+                self.handle_dot(op, method, vec_ty)
+            }
             OpSig::Binary => self.handle_binary(op, method, vec_ty),
             OpSig::Shift => self.handle_shift(op, method, vec_ty),
+            OpSig::ShiftSigned => self.handle_shift_signed(op, vec_ty),
             OpSig::Ternary => self.handle_ternary(op, method_sig, method, vec_ty),
             OpSig::Select => self.handle_select(op, vec_ty),
             OpSig::Combine { combined_ty } => self.handle_combine(op, vec_ty, &combined_ty),
@@ -312,7 +355,12 @@ impl Level for X86 {
             OpSig::ElementRotate { .. } | OpSig::ElementShift { .. } => {
                 unreachable!("element moves use generic lowering")
             }
+            OpSig::BitTranspose => self.handle_bit_transpose(op, vec_ty),
+            OpSig::GfMulConst => self.handle_gf_mul_const(method_sig, vec_ty),
             OpSig::SwizzleDynWithinBlocks => self.handle_swizzle_dyn_within_blocks(op, vec_ty),
+            OpSig::Compress { expand } => self.handle_compress(op, vec_ty, expand),
+            OpSig::WidenAccumulate { acc_ty } => self.handle_widen_accumulate(op, vec_ty, acc_ty),
+            OpSig::ReduceWidened => self.handle_reduce_widened(op, vec_ty),
             OpSig::Cvt {
                 target_ty,
                 scalar_bits,
@@ -328,10 +376,17 @@ impl Level for X86 {
             } => self.handle_mask_reduce(op, vec_ty, quantifier, condition),
             OpSig::MaskFromBitmask => self.handle_mask_from_bitmask(op, vec_ty),
             OpSig::MaskToBitmask => self.handle_mask_to_bitmask(op, vec_ty),
+            OpSig::MaskToInt if *self == Self::Avx512 && vec_ty.scalar == ScalarType::Mask => {
+                self.handle_avx512_mask_to_int(op, vec_ty)
+            }
+            OpSig::MaskToInt => generic_mask_to_int(method_sig, vec_ty),
             OpSig::MaskSet if *self == Self::Avx512 && vec_ty.scalar == ScalarType::Mask => {
                 self.handle_avx512_mask_set(method_sig, vec_ty)
             }
             OpSig::MaskSet => generic_mask_set(method_sig, vec_ty),
+            OpSig::ExtractLane { dynamic: false } => self.handle_extract_lane(method_sig, vec_ty),
+            OpSig::ExtractLane { dynamic: true } => generic_extract_dyn(method_sig, vec_ty),
+            OpSig::InsertLane => self.handle_insert_lane(method_sig, vec_ty),
             OpSig::LoadInterleaved {
                 block_size,
                 block_count,
@@ -853,6 +908,54 @@ fn sse2_min_max_expr(method: &str, vec_ty: &VecType) -> TokenStream {
     }
 }
 
+/// Count the set bits of each element of `a` with `pshufb`, by looking up the count of each nibble in a table, and
+/// adding up the counts of the bytes of wider elements.
+fn nibble_count_ones(vec_ty: &VecType) -> TokenStream {
+    let bits = vec_ty.n_bits();
+    let coarse = coarse_type(vec_ty);
+    let and = intrinsic_ident("and", coarse, bits);
+    let setzero = intrinsic_ident("setzero", coarse, bits);
+    let set1_epi8 = intrinsic_ident("set1", "epi8", bits);
+    let set1_epi16 = intrinsic_ident("set1", "epi16", bits);
+    let setr_epi8 = intrinsic_ident("setr", "epi8", bits);
+    let shuffle = intrinsic_ident("shuffle", "epi8", bits);
+    let add_epi8 = intrinsic_ident("add", "epi8", bits);
+    let add_epi16 = intrinsic_ident("add", "epi16", bits);
+    let srli_epi16 = intrinsic_ident("srli", "epi16", bits);
+    let madd = intrinsic_ident("madd", "epi16", bits);
+    let sad = intrinsic_ident("sad", "epu8", bits);
+    // `pshufb` looks up each 128-bit block in its own block of the table.
+    let table = (0..bits / 8).map(|i| Literal::u32_unsuffixed((i % 16).count_ones()));
+    let bytes = quote! { #add_epi8(#shuffle(table, low_nibbles), #shuffle(table, high_nibbles)) };
+    let halfwords = quote! {
+        #add_epi16(#and(bytes, #set1_epi16(0xff)), #srli_epi16::<8>(bytes))
+    };
+    let count = match vec_ty.scalar_bits {
+        8 => bytes,
+        16 => quote! {
+            let bytes = #bytes;
+            #halfwords
+        },
+        32 => quote! {
+            let bytes = #bytes;
+            #madd(#halfwords, #set1_epi16(1))
+        },
+        // `psadbw` adds up the eight bytes of each 64-bit element.
+        64 => quote! {
+            let bytes = #bytes;
+            #sad(bytes, #setzero())
+        },
+        _ => unreachable!(),
+    };
+    quote! {
+        let a = a.into();
+        let table = #setr_epi8(#( #table ),*);
+        let low_nibbles = #and(a, #set1_epi8(0x0f));
+        let high_nibbles = #and(#srli_epi16::<4>(a), #set1_epi8(0x0f));
+        #count
+    }
+}
+
 fn avx512_mask_register_bits(vec_ty: &VecType) -> usize {
     match vec_ty.len {
         0..=8 => 8,
@@ -1177,6 +1280,17 @@ impl X86 {
         })
     }
 
+    pub(crate) fn handle_avx512_mask_to_int(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        let movm = intrinsic_ident(
+            "movm",
+            op_suffix(vec_ty.scalar, vec_ty.scalar_bits, true),
+            vec_ty.n_bits(),
+        );
+        self.kernel_method(op, vec_ty, |token| {
+            quote! { #movm(a.val).simd_into(#token) }
+        })
+    }
+
     pub(crate) fn handle_avx512_mask_set(
         &self,
         method_sig: TokenStream,
@@ -1468,19 +1582,26 @@ impl X86 {
             return fallback_method(op, vec_ty);
         }
 
+        if matches!(method, "count_ones" | "leading_zeros" | "trailing_zeros") {
+            return self.handle_bit_count(op, method_sig, method, vec_ty);
+        }
+
         match method {
             "fract" => {
+                let sub_op = generic_op_name("sub", vec_ty);
                 let trunc_op = generic_op_name("trunc", vec_ty);
                 quote! {
                     #method_sig {
-                        a - self.#trunc_op(a)
+                        self.#sub_op(a, self.#trunc_op(a))
                     }
                 }
             }
             "approximate_recip" if vec_ty.scalar_bits == 64 => {
+                let div_op = generic_op_name("div", vec_ty);
+                let splat_op = generic_op_name("splat", vec_ty);
                 quote! {
                     #method_sig {
-                        1.0 / a
+                        self.#div_op(self.#splat_op(1.0), a)
                     }
                 }
             }
@@ -1494,9 +1615,11 @@ impl X86 {
                 }
             }
             "not" => {
+                let xor_op = generic_op_name("xor", vec_ty);
+                let splat_op = generic_op_name("splat", vec_ty);
                 quote! {
                     #method_sig {
-                        a ^ !0
+                        self.#xor_op(a, self.#splat_op(!0))
                     }
                 }
             }
@@ -1510,6 +1633,62 @@ impl X86 {
         }
     }
 
+    pub(crate) fn handle_bit_count(
+        &self,
+        op: Op,
+        method_sig: TokenStream,
+        method: &str,
+        vec_ty: &VecType,
+    ) -> TokenStream {
+        // SSE2 has no byte shuffle to look up the counts of nibbles with.
+        if *self == Self::Sse2 {
+            return fallback_method(op, vec_ty);
+        }
+
+        let suffix = op_suffix(vec_ty.scalar, vec_ty.scalar_bits, false);
+        match method {
+            "count_ones" if *self == Self::Avx512 => {
+                let popcnt = intrinsic_ident("popcnt", suffix, vec_ty.n_bits());
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! { #popcnt(a.into()).simd_into(#token) }
+                })
+            }
+            "count_ones" => {
+                let count = nibble_count_ones(vec_ty);
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! {
+                        let count = { #count };
+                        count.simd_into(#token)
+                    }
+                })
+            }
+            // AVX-512 only counts the leading zeros of 32-bit and 64-bit elements.
+            "leading_zeros" if *self == Self::Avx512 && vec_ty.scalar_bits >= 32 => {
+                let lzcnt = intrinsic_ident("lzcnt", suffix, vec_ty.n_bits());
+                self.kernel_method(op, vec_ty, |token| {
+                    quote! { #lzcnt(a.into()).simd_into(#token) }
+                })
+            }
+            "leading_zeros" => {
+                let body = leading_zeros_from_count_ones(vec_ty);
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
+            "trailing_zeros" => {
+                let body = trailing_zeros_from_count_ones(vec_ty);
+                quote! {
+                    #method_sig {
+                        #body
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
     pub(crate) fn handle_widen_narrow(
         &self,
         op: Op,
@@ -1563,21 +1742,30 @@ impl X86 {
                         }
                     }
                     (Self::Sse2, 256, 128) => {
-                        assert_eq!(
-                            vec_ty.scalar,
-                            ScalarType::Unsigned,
-                            "only unsigned widen operations are currently generated"
-                        );
-                        assert_eq!(
-                            vec_ty.scalar_bits, 8,
-                            "SSE2 widen only handles u8 to u16"
-                        );
+                        // SSE2 has no extension instructions, so interleave each element with
+                        // its high half: zero for unsigned elements, or copies of the sign bit
+                        // for signed ones.
+                        let high_half = match vec_ty.scalar {
+                            ScalarType::Int => {
+                                let cmpgt = intrinsic_ident(
+                                    "cmpgt",
+                                    op_suffix(ScalarType::Int, vec_ty.scalar_bits, false),
+                                    128,
+                                );
+                                quote! { #cmpgt(_mm_setzero_si128(), raw) }
+                            }
+                            _ => quote! { _mm_setzero_si128() },
+                        };
+                        let unpacklo =
+                            unpack_intrinsic(vec_ty.scalar, vec_ty.scalar_bits, true, 128);
+                        let unpackhi =
+                            unpack_intrinsic(vec_ty.scalar, vec_ty.scalar_bits, false, 128);
                         let combine = generic_op_name("combine", &target_ty.block_ty());
                         quote! {
                             let raw = a.into();
-                            let zero = _mm_setzero_si128();
-                            let lo = _mm_unpacklo_epi8(raw, zero).simd_into(#token);
-                            let hi = _mm_unpackhi_epi8(raw, zero).simd_into(#token);
+                            let high_half = #high_half;
+                            let lo = #unpacklo(raw, high_half).simd_into(#token);
+                            let hi = #unpackhi(raw, high_half).simd_into(#token);
                             #token.#combine(lo, hi)
                         }
                     }
@@ -1678,10 +1866,144 @@ impl X86 {
                     _ => unimplemented!(),
                 }
             }
+            "pack_saturate_i8" | "pack_saturate_u8" => {
+                self.pack_saturate_body(token, vec_ty, &target_ty)
+            }
+            "pack_clamped_u8" => match self {
+                Self::Avx512 => quote! {
+                    // `_mm512_min_ps` returns its second operand if either is NaN, and `_mm512_max_ps` then turns
+                    // NaN into 0, so the clamped values always fit and can be truncated to bytes.
+                    let clamped = _mm512_max_ps(
+                        _mm512_min_ps(_mm512_set1_ps(255.0), a.into()),
+                        _mm512_setzero_ps(),
+                    );
+                    _mm512_cvtepi32_epi8(_mm512_cvtps_epi32(clamped)).simd_into(#token)
+                },
+                Self::Avx2 => quote! {
+                    let (a, b) = #token.split_f32x16(a);
+                    // Only the upper bound needs clamping. `_mm256_min_ps` returns its second operand if either is
+                    // NaN, and NaN converts to `i32::MIN`, which the packs saturate to 0 like any negative value.
+                    let max = _mm256_set1_ps(255.0);
+                    let a = _mm256_cvtps_epi32(_mm256_min_ps(max, a.into()));
+                    let b = _mm256_cvtps_epi32(_mm256_min_ps(max, b.into()));
+                    // The 256-bit pack operates lane-wise, so put the halves of `a` and `b` back together first.
+                    let packed = _mm256_permute4x64_epi64::<0b_11_01_10_00>(_mm256_packs_epi32(a, b));
+                    _mm_packus_epi16(
+                        _mm256_castsi256_si128(packed),
+                        _mm256_extracti128_si256::<1>(packed),
+                    )
+                    .simd_into(#token)
+                },
+                Self::Sse2 | Self::Sse4_2 => quote! {
+                    let (a, b) = #token.split_f32x16(a);
+                    let (a0, a1) = #token.split_f32x8(a);
+                    let (b0, b1) = #token.split_f32x8(b);
+                    // Only the upper bound needs clamping. `_mm_min_ps` returns its second operand if either is NaN,
+                    // and NaN converts to `i32::MIN`, which the packs saturate to 0 like any negative value.
+                    let max = _mm_set1_ps(255.0);
+                    let a0 = _mm_cvtps_epi32(_mm_min_ps(max, a0.into()));
+                    let a1 = _mm_cvtps_epi32(_mm_min_ps(max, a1.into()));
+                    let b0 = _mm_cvtps_epi32(_mm_min_ps(max, b0.into()));
+                    let b1 = _mm_cvtps_epi32(_mm_min_ps(max, b1.into()));
+                    _mm_packus_epi16(_mm_packs_epi32(a0, a1), _mm_packs_epi32(b0, b1)).simd_into(#token)
+                },
+            },
             _ => unreachable!(),
         })
     }
 
+    /// The body of a saturating conversion from 16-bit to 8-bit lanes, with any combination of signedness.
+    fn pack_saturate_body(
+        &self,
+        token: &Ident,
+        vec_ty: &VecType,
+        target_ty: &VecType,
+    ) -> TokenStream {
+        let signed_src = vec_ty.scalar == ScalarType::Int;
+        let signed_dst = target_ty.scalar == ScalarType::Int;
+        let src_bits = vec_ty.n_bits();
+
+        if *self == Self::Avx512 {
+            let zero = intrinsic_ident("setzero", &format!("si{src_bits}"), src_bits);
+            let set1 = intrinsic_ident("set1", "epi16", src_bits);
+            let max_epi16 = intrinsic_ident("max", "epi16", src_bits);
+            let min_epu16 = intrinsic_ident("min", "epu16", src_bits);
+            let cvt = |name: &str| intrinsic_ident(name, "epi8", src_bits);
+            let body = match (signed_src, signed_dst) {
+                (true, true) => {
+                    let cvt = cvt("cvtsepi16");
+                    quote! { #cvt(a.into()) }
+                }
+                // The unsigned saturating conversion treats its input as unsigned, so clamp negative values first.
+                (true, false) => {
+                    let cvt = cvt("cvtusepi16");
+                    quote! { #cvt(#max_epi16(a.into(), #zero())) }
+                }
+                (false, true) => {
+                    let cvt = cvt("cvtepi16");
+                    quote! { #cvt(#min_epu16(a.into(), #set1(127))) }
+                }
+                (false, false) => {
+                    let cvt = cvt("cvtusepi16");
+                    quote! { #cvt(a.into()) }
+                }
+            };
+            return quote! { #body.simd_into(#token) };
+        }
+
+        // Below AVX-512, pack the two halves of the input together, with each half being one native vector.
+        let dst_bits = target_ty.n_bits();
+        let halves = if *self == Self::Avx2 && src_bits == 256 {
+            quote! {
+                let a: __m256i = a.into();
+                let lo = _mm256_castsi256_si128(a);
+                let hi = _mm256_extracti128_si256::<1>(a);
+            }
+        } else {
+            let split = generic_op_name("split", vec_ty);
+            quote! {
+                let (lo, hi) = #token.#split(a);
+                let (lo, hi) = (lo.into(), hi.into());
+            }
+        };
+        // The pack instructions treat their inputs as signed, so unsigned inputs are first clamped to the target's
+        // maximum.
+        let clamp = (!signed_src).then(|| {
+            let max = Literal::i16_unsuffixed(if signed_dst { 127 } else { 255 });
+            let set1 = intrinsic_ident("set1", "epi16", dst_bits);
+            let subs = intrinsic_ident("subs", "epu16", dst_bits);
+            let sub = intrinsic_ident("sub", "epi16", dst_bits);
+            let min_epu16 = intrinsic_ident("min", "epu16", dst_bits);
+            let min = |x: TokenStream| {
+                if *self == Self::Sse2 {
+                    // SSE2 has no unsigned 16-bit minimum, but `x - (x -| max)` computes the same thing.
+                    quote! { #sub(#x, #subs(#x, max)) }
+                } else {
+                    quote! { #min_epu16(#x, max) }
+                }
+            };
+            let lo = min(quote! { lo });
+            let hi = min(quote! { hi });
+            quote! {
+                let max = #set1(#max);
+                let lo = #lo;
+                let hi = #hi;
+            }
+        });
+        let pack = pack_intrinsic(16, signed_dst, dst_bits);
+        let packed = if dst_bits == 256 {
+            // The 256-bit pack operates lane-wise, so put the halves back in order afterwards.
+            quote! { _mm256_permute4x64_epi64::<0b_11_01_10_00>(#pack(lo, hi)) }
+        } else {
+            quote! { #pack(lo, hi) }
+        };
+        quote! {
+            #halves
+            #clamp
+            #packed.simd_into(#token)
+        }
+    }
+
     pub(crate) fn handle_binary(&self, op: Op, method: &str, vec_ty: &VecType) -> TokenStream {
         let method_sig = op.simd_trait_method_sig(vec_ty);
 
@@ -1777,6 +2099,29 @@ impl X86 {
             return fallback_method(op, vec_ty);
         }
 
+        if method == "table_lookup" {
+            // SSE2 has no byte shuffle.
+            if *self == Self::Sse2 {
+                return fallback_method(op, vec_ty);
+            }
+            let shuffle = intrinsic_ident("shuffle", "epi8", vec_ty.n_bits());
+            let adds = intrinsic_ident("adds", "epu8", vec_ty.n_bits());
+            let set1 = intrinsic_ident("set1", "epi8", vec_ty.n_bits());
+            return self.kernel_method(op, vec_ty, |token| {
+                quote! {
+                    // `pshufb` only gives zero for indices with the high bit set, so those from 16 upwards are
+                    // saturated into that range.
+                    let indices = #adds(b.into(), #set1(0x70));
+                    #shuffle(a.into(), indices).simd_into(#token)
+                }
+            });
+        }
+
+        if matches!(method, "saturating_add" | "saturating_sub") && vec_ty.scalar_bits >= 32 {
+            // x86 only has saturating arithmetic for 8-bit and 16-bit lanes.
+            return fallback_method(op, vec_ty);
+        }
+
         match method {
             "shrv"
                 if *self != Self::Avx512
@@ -1840,6 +2185,327 @@ impl X86 {
         }
     }
 
+    fn handle_shift_signed(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        // Without lane-wise variable shifts in both directions, two scalar fallbacks and a select would be slower than
+        // one scalar fallback.
+        let has_variable_shifts = match self {
+            Self::Avx512 => true,
+            Self::Avx2 => {
+                vec_ty.scalar_bits == 32
+                    || (vec_ty.scalar_bits == 64 && vec_ty.scalar == ScalarType::Unsigned)
+            }
+            _ => false,
+        };
+        if !has_variable_shifts {
+            return fallback_method(op, vec_ty);
+        }
+        let amounts_ty = vec_ty.cast(ScalarType::Int);
+        let shlv = generic_op_name("shlv", vec_ty);
+        let shrv = generic_op_name("shrv", vec_ty);
+        let select = generic_op_name("select", vec_ty);
+        let neg = generic_op_name("neg", &amounts_ty);
+        let simd_lt = generic_op_name("simd_lt", &amounts_ty);
+        let splat = generic_op_name("splat", &amounts_ty);
+        let method_sig = op.simd_trait_method_sig(vec_ty);
+        quote! {
+            #method_sig {
+                let left = self.#shlv(a, b.bitcast());
+                let right = self.#shrv(a, self.#neg(b).bitcast());
+                self.#select(self.#simd_lt(b, self.#splat(0)), right, left)
+            }
+        }
+    }
+
+    fn handle_dot(&self, op: Op, method: &str, vec_ty: &VecType) -> TokenStream {
+        let ty_bits = vec_ty.n_bits();
+        // `dpps` sums in the same order as the shuffles below, so both give the same result. There's no 512-bit
+        // version, and pairs are cheaper to sum with a single shuffle.
+        if *self != Self::Sse2 && ty_bits <= 256 && method != "dot2" {
+            let dp = simple_intrinsic("dp", vec_ty);
+            // The high bits select the products to sum, and the low bits the lanes to write the sum to.
+            let imm = if method == "dot3" {
+                quote! { 0x7f }
+            } else {
+                quote! { 0xff }
+            };
+            return self.kernel_method(op, vec_ty, |token| {
+                quote! {
+                    #dp::<#imm>(a.into(), b.into()).simd_into(#token)
+                }
+            });
+        }
+
+        let mul = simple_intrinsic("mul", vec_ty);
+        let add = simple_intrinsic("add", vec_ty);
+        let shuffle = simple_intrinsic("shuffle", vec_ty);
+        // Replace the ignored product with zero, like `dpps` does.
+        let mask_product = (method == "dot3").then(|| {
+            if ty_bits == 512 {
+                quote! { let p = _mm512_maskz_mov_ps(0x7777, p); }
+            } else {
+                let and = simple_intrinsic("and", vec_ty);
+                let set = intrinsic_ident("set", "epi32", ty_bits);
+                let cast = cast_ident(ScalarType::Int, ScalarType::Float, 32, 32, ty_bits);
+                quote! { let p = #and(p, #cast(#set(0, -1, -1, -1))); }
+            }
+        });
+        let sum = if method == "dot2" {
+            quote! { pairs }
+        } else {
+            quote! { #add(pairs, #shuffle::<0b01_00_11_10>(pairs, pairs)) }
+        };
+        self.kernel_method(op, vec_ty, |token| {
+            quote! {
+                let p = #mul(a.into(), b.into());
+                #mask_product
+                let pairs = #add(p, #shuffle::<0b10_11_00_01>(p, p));
+                #sum.simd_into(#token)
+            }
+        })
+    }
+
+    fn handle_bit_transpose(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        assert_eq!(
+            op.method, "transpose_bits_8x8",
+            "only 8x8 bit transposes are specialized"
+        );
+        let affine = intrinsic_ident("gf2p8affine", "epi64_epi8", vec_ty.n_bits());
+        let set1 = set1_intrinsic(vec_ty);
+        // Each output byte `j` of `gf2p8affine(x, m)` has bit `i` set to the parity of `x.byte[j] & m.byte[7 - i]`.
+        // With `x.byte[j] = 1 << j`, that's bit `j` of row `7 - i`: the transpose, but with each byte bit-reversed.
+        // The same constant as the matrix operand reverses them back.
+        self.kernel_method(op, vec_ty, |token| {
+            quote! {
+                let diagonal = #set1(0x8040_2010_0804_0201_u64.cast_signed());
+                let reversed = #affine::<0>(diagonal, a.into());
+                #affine::<0>(reversed, diagonal).simd_into(#token)
+            }
+        })
+    }
+
+    fn handle_extract_lane(&self, method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+        assert_eq!(
+            vec_ty.n_bits(),
+            128,
+            "wide lane extractions should use the generic split implementation"
+        );
+        let level = self.token();
+        let ty = vec_ty.rust();
+        let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+        let check = const_lane_check(vec_ty);
+        let arms = (0..vec_ty.len).map(|lane| {
+            let lane_lit = Literal::usize_unsuffixed(lane);
+            let expr = match (vec_ty.scalar, vec_ty.scalar_bits) {
+                (ScalarType::Float, 32) if lane == 0 => quote! { _mm_cvtss_f32(a) },
+                (ScalarType::Float, 32) => quote! { _mm_cvtss_f32(_mm_shuffle_ps::<#lane_lit>(a, a)) },
+                (ScalarType::Float, 64) if lane == 0 => quote! { _mm_cvtsd_f64(a) },
+                (ScalarType::Float, 64) => quote! { _mm_cvtsd_f64(_mm_unpackhi_pd(a, a)) },
+                // SSE2 can only extract 16-bit lanes, so a byte is taken from the word holding it.
+                (_, 8) if *self == Self::Sse2 => {
+                    let word = Literal::usize_unsuffixed(lane / 2);
+                    if lane % 2 == 0 {
+                        quote! { _mm_extract_epi16::<#word>(a) as #scalar }
+                    } else {
+                        quote! { (_mm_extract_epi16::<#word>(a) >> 8) as #scalar }
+                    }
+                }
+                (_, 8) => quote! { _mm_extract_epi8::<#lane_lit>(a) as #scalar },
+                (_, 16) => quote! { _mm_extract_epi16::<#lane_lit>(a) as #scalar },
+                (_, 32) => {
+                    let lane = if *self == Self::Sse2 {
+                        if lane == 0 {
+                            quote! { _mm_cvtsi128_si32(a) }
+                        } else {
+                            quote! { _mm_cvtsi128_si32(_mm_shuffle_epi32::<#lane_lit>(a)) }
+                        }
+                    } else {
+                        quote! { _mm_extract_epi32::<#lane_lit>(a) }
+                    };
+                    if vec_ty.scalar == ScalarType::Unsigned {
+                        quote! { #lane.cast_unsigned() }
+                    } else {
+                        lane
+                    }
+                }
+                // The 64-bit integer moves are only available on `x86_64`, so the lane is moved out as the bits of
+                // an `f64`.
+                (_, 64) => {
+                    let lane = if lane == 0 {
+                        quote! { _mm_cvtsd_f64(_mm_castsi128_pd(a)).to_bits() }
+                    } else {
+                        quote! { _mm_cvtsd_f64(_mm_unpackhi_pd(_mm_castsi128_pd(a), _mm_castsi128_pd(a))).to_bits() }
+                    };
+                    if vec_ty.scalar == ScalarType::Int {
+                        quote! { #lane.cast_signed() }
+                    } else {
+                        lane
+                    }
+                }
+                _ => unreachable!(),
+            };
+            quote! { #lane_lit => #expr }
+        });
+        let truncates = if vec_ty.scalar_bits < 32 {
+            quote! {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "the intrinsics zero-extend the lane to an `i32`"
+                )]
+            }
+        } else {
+            TokenStream::new()
+        };
+        // `kernel!` functions can't be const-generic, so the lane is passed in as an argument, and matched on to
+        // pick the intrinsic's immediate.
+        quote! {
+            #method_sig {
+                #check
+                crate::kernel!(
+                    #[inline(always)]
+                    #truncates
+                    fn kernel(token: #level, a: #ty<#level>, lane: usize) -> #scalar {
+                        let a = a.into();
+                        match lane {
+                            #(#arms,)*
+                            _ => unreachable!(),
+                        }
+                    }
+                );
+
+                kernel(self, a, LANE)
+            }
+        }
+    }
+
+    fn handle_insert_lane(&self, method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+        assert_eq!(
+            vec_ty.n_bits(),
+            128,
+            "wide lane insertions should use the generic split implementation"
+        );
+        let level = self.token();
+        let ty = vec_ty.rust();
+        let vec = quote! { #ty<#level> };
+        let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+        let check = const_lane_check(vec_ty);
+        // SSE2 can only insert 16-bit lanes, so a 32-bit lane is inserted as its two halves.
+        let insert_epi32 = |a: TokenStream, value: TokenStream, lane: usize| {
+            if *self == Self::Sse2 {
+                let low = Literal::usize_unsuffixed(2 * lane);
+                let high = Literal::usize_unsuffixed(2 * lane + 1);
+                quote! { _mm_insert_epi16::<#high>(_mm_insert_epi16::<#low>(#a, #value), #value >> 16) }
+            } else {
+                let lane = Literal::usize_unsuffixed(lane);
+                quote! { _mm_insert_epi32::<#lane>(#a, #value) }
+            }
+        };
+        let arms = (0..vec_ty.len).map(|lane| {
+            let lane_lit = Literal::usize_unsuffixed(lane);
+            let expr = match (vec_ty.scalar, vec_ty.scalar_bits) {
+                (ScalarType::Float, 32) if lane == 0 => {
+                    quote! { _mm_move_ss(a, _mm_set_ss(value)) }
+                }
+                (ScalarType::Float, 32) if *self == Self::Sse2 => {
+                    let inserted = insert_epi32(
+                        quote! { _mm_castps_si128(a) },
+                        quote! { value.to_bits().cast_signed() },
+                        lane,
+                    );
+                    quote! { _mm_castsi128_ps(#inserted) }
+                }
+                (ScalarType::Float, 32) => {
+                    let imm = Literal::usize_unsuffixed(lane << 4);
+                    quote! { _mm_insert_ps::<#imm>(a, _mm_set_ss(value)) }
+                }
+                (ScalarType::Float, 64) if lane == 0 => {
+                    quote! { _mm_move_sd(a, _mm_set_sd(value)) }
+                }
+                (ScalarType::Float, 64) => quote! { _mm_unpacklo_pd(a, _mm_set_sd(value)) },
+                (_, 8) if *self == Self::Sse2 => {
+                    let word = Literal::usize_unsuffixed(lane / 2);
+                    let byte = if vec_ty.scalar == ScalarType::Int {
+                        quote! { i32::from(value.cast_unsigned()) }
+                    } else {
+                        quote! { i32::from(value) }
+                    };
+                    let word_value = if lane % 2 == 0 {
+                        quote! { (_mm_extract_epi16::<#word>(a) & 0xff00) | #byte }
+                    } else {
+                        quote! { (_mm_extract_epi16::<#word>(a) & 0xff) | (#byte << 8) }
+                    };
+                    quote! { _mm_insert_epi16::<#word>(a, #word_value) }
+                }
+                (_, 8) => quote! { _mm_insert_epi8::<#lane_lit>(a, i32::from(value)) },
+                (_, 16) => quote! { _mm_insert_epi16::<#lane_lit>(a, i32::from(value)) },
+                (_, 32) => {
+                    let value = if vec_ty.scalar == ScalarType::Unsigned {
+                        quote! { value.cast_signed() }
+                    } else {
+                        quote! { value }
+                    };
+                    insert_epi32(quote! { a }, value, lane)
+                }
+                (_, 64) => {
+                    let value = if vec_ty.scalar == ScalarType::Unsigned {
+                        quote! { _mm_set1_epi64x(value.cast_signed()) }
+                    } else {
+                        quote! { _mm_set1_epi64x(value) }
+                    };
+                    if lane == 0 {
+                        quote! { _mm_unpackhi_epi64(#value, a) }
+                    } else {
+                        quote! { _mm_unpacklo_epi64(a, #value) }
+                    }
+                }
+                _ => unreachable!(),
+            };
+            quote! { #lane_lit => #expr }
+        });
+        quote! {
+            #method_sig {
+                #check
+                crate::kernel!(
+                    #[inline(always)]
+                    fn kernel(token: #level, a: #vec, value: #scalar, lane: usize) -> #vec {
+                        let a = a.into();
+                        match lane {
+                            #(#arms,)*
+                            _ => unreachable!(),
+                        }
+                        .simd_into(token)
+                    }
+                );
+
+                kernel(self, a, value, LANE)
+            }
+        }
+    }
+
+    fn handle_gf_mul_const(&self, method_sig: TokenStream, vec_ty: &VecType) -> TokenStream {
+        assert!(
+            *self == Self::Avx512,
+            "GF(2^8) multiplication is only specialized for GFNI"
+        );
+        let level = self.token();
+        let ty = vec_ty.rust();
+        let vec = quote! { #ty<#level> };
+        let mul = intrinsic_ident("gf2p8mul", "epi8", vec_ty.n_bits());
+        let set1 = set1_intrinsic(vec_ty);
+        // `kernel!` functions can't be const-generic, so the factor is passed in as an argument.
+        quote! {
+            #method_sig {
+                crate::kernel!(
+                    #[inline(always)]
+                    fn kernel(token: #level, a: #vec, factor: u8) -> #vec {
+                        #mul(a.into(), #set1(factor.cast_signed())).simd_into(token)
+                    }
+                );
+
+                kernel(self, a, FACTOR)
+            }
+        }
+    }
+
     fn handle_avx512_narrow_variable_shift(
         &self,
         method: &str,
@@ -1986,7 +2652,7 @@ impl X86 {
         vec_ty: &VecType,
     ) -> TokenStream {
         match method {
-            "mul_add" if matches!(self, Self::Avx2 | Self::Avx512) => {
+            "mul_add" if self.fused_mul_add() => {
                 let intrinsic = simple_intrinsic("fmadd", vec_ty);
                 self.kernel_method(
                     op,
@@ -1994,7 +2660,7 @@ impl X86 {
                     |token| quote! { #intrinsic(a.into(), b.into(), c.into()).simd_into(#token) },
                 )
             }
-            "mul_sub" if matches!(self, Self::Avx2 | Self::Avx512) => {
+            "mul_sub" if self.fused_mul_add() => {
                 let intrinsic = simple_intrinsic("fmsub", vec_ty);
                 self.kernel_method(
                     op,
@@ -2002,17 +2668,47 @@ impl X86 {
                     |token| quote! { #intrinsic(a.into(), b.into(), c.into()).simd_into(#token) },
                 )
             }
-            "mul_add" => {
+            "nmul_add" | "nmul_sub" if self.fused_mul_add() => {
+                let intrinsic = simple_intrinsic(
+                    if method == "nmul_add" {
+                        "fnmadd"
+                    } else {
+                        "fnmsub"
+                    },
+                    vec_ty,
+                );
+                self.kernel_method(
+                    op,
+                    vec_ty,
+                    |token| quote! { #intrinsic(a.into(), b.into(), c.into()).simd_into(#token) },
+                )
+            }
+            "mul_add" | "mul_sub" => {
+                let add_sub =
+                    generic_op_name(if method == "mul_add" { "add" } else { "sub" }, vec_ty);
+                let mul = generic_op_name("mul", vec_ty);
+                quote! {
+                    #method_sig {
+                        self.#add_sub(self.#mul(a, b), c)
+                    }
+                }
+            }
+            "nmul_add" => {
+                let sub = generic_op_name("sub", vec_ty);
+                let mul = generic_op_name("mul", vec_ty);
                 quote! {
                     #method_sig {
-                        a * b + c
+                        self.#sub(c, self.#mul(a, b))
                     }
                 }
             }
-            "mul_sub" => {
+            "nmul_sub" => {
+                let sub = generic_op_name("sub", vec_ty);
+                let neg = generic_op_name("neg", vec_ty);
+                let mul = generic_op_name("mul", vec_ty);
                 quote! {
                     #method_sig {
-                        a * b - c
+                        self.#sub(self.#neg(self.#mul(a, b)), c)
                     }
                 }
             }
@@ -2758,6 +3454,158 @@ impl X86 {
         })
     }
 
+    pub(crate) fn handle_compress(&self, op: Op, vec_ty: &VecType, expand: bool) -> TokenStream {
+        if *self == Self::Sse2 {
+            return fallback_method(op, vec_ty);
+        }
+
+        self.kernel_method(op, vec_ty, |token| {
+            if *self == Self::Avx512 {
+                let name = if expand { "expand" } else { "compress" };
+                let intrinsic = intrinsic_ident(&format!("maskz_{name}"), "epi8", vec_ty.n_bits());
+                quote! { #intrinsic(mask.val, a.into()).simd_into(#token) }
+            } else {
+                table_compress_bytes(token, vec_ty, expand, |a, indices| {
+                    quote! { _mm_shuffle_epi8(#a, #indices) }
+                })
+            }
+        })
+    }
+
+    pub(crate) fn handle_widen_accumulate(
+        &self,
+        op: Op,
+        vec_ty: &VecType,
+        acc_ty: VecType,
+    ) -> TokenStream {
+        let src_bits = vec_ty.n_bits();
+        let bits = vec_ty.scalar_bits;
+        let acc_bits = acc_ty.scalar_bits;
+        let subs = intrinsic_ident("subs", &format!("epu{bits}"), src_bits);
+        let or = intrinsic_ident("or", &format!("si{src_bits}"), src_bits);
+        self.kernel_method(op, vec_ty, |token| {
+            let widen_add = if acc_ty.n_bits() <= self.native_width() {
+                let extend = intrinsic_ident(
+                    &format!("cvtepu{bits}"),
+                    &format!("epi{acc_bits}"),
+                    acc_ty.n_bits(),
+                );
+                let add = intrinsic_ident("add", &format!("epi{acc_bits}"), acc_ty.n_bits());
+                quote! { #add(acc.into(), #extend(abd)).simd_into(#token) }
+            } else {
+                // The accumulator takes two registers, so widen the low and high halves of the difference separately.
+                let (lo, hi) = if src_bits == 256 {
+                    let extend =
+                        intrinsic_ident(&format!("cvtepu{bits}"), &format!("epi{acc_bits}"), 256);
+                    (
+                        quote! { #extend(_mm256_castsi256_si128(abd)) },
+                        quote! { #extend(_mm256_extracti128_si256::<1>(abd)) },
+                    )
+                } else {
+                    let unpacklo = format_ident!("_mm_unpacklo_epi{bits}");
+                    let unpackhi = format_ident!("_mm_unpackhi_epi{bits}");
+                    (
+                        quote! { #unpacklo(abd, _mm_setzero_si128()) },
+                        quote! { #unpackhi(abd, _mm_setzero_si128()) },
+                    )
+                };
+                let add = intrinsic_ident("add", &format!("epi{acc_bits}"), src_bits);
+                let split_acc = generic_op_name("split", &acc_ty);
+                let combine_acc = generic_op_name(
+                    "combine",
+                    &VecType::new(acc_ty.scalar, acc_bits, acc_ty.len / 2),
+                );
+                quote! {
+                    let (acc0, acc1) = #token.#split_acc(acc);
+                    let lo = #add(acc0.into(), #lo).simd_into(#token);
+                    let hi = #add(acc1.into(), #hi).simd_into(#token);
+                    #token.#combine_acc(lo, hi)
+                }
+            };
+            quote! {
+                let (a, b) = (a.into(), b.into());
+                // One of the saturating differences is always zero.
+                let abd = #or(#subs(a, b), #subs(b, a));
+                #widen_add
+            }
+        })
+    }
+
+    pub(crate) fn handle_reduce_widened(&self, op: Op, vec_ty: &VecType) -> TokenStream {
+        let bits = vec_ty.n_bits();
+        let len = vec_ty.len;
+        let xor = intrinsic_ident("xor", &format!("si{bits}"), bits);
+        // Both `psadbw` and `pmaddwd` produce lanes that can be summed as `i32`s. `psadbw` only works on unsigned bytes
+        // and `pmaddwd` on signed words, so flip the sign bit of the other kind first, then remove the bias from the
+        // sum.
+        let (sums, result) = match (vec_ty.scalar, vec_ty.scalar_bits) {
+            (ScalarType::Unsigned, 8) => {
+                let sad = intrinsic_ident("sad", "epu8", bits);
+                let zero = intrinsic_ident("setzero", &format!("si{bits}"), bits);
+                (
+                    quote! { #sad(a.into(), #zero()) },
+                    Some(quote! { sum.cast_unsigned() }),
+                )
+            }
+            (ScalarType::Int, 8) => {
+                let sad = intrinsic_ident("sad", "epu8", bits);
+                let zero = intrinsic_ident("setzero", &format!("si{bits}"), bits);
+                let set1 = intrinsic_ident("set1", "epi8", bits);
+                let bias = Literal::usize_unsuffixed(128 * len);
+                (
+                    quote! { #sad(#xor(a.into(), #set1(i8::MIN)), #zero()) },
+                    Some(quote! { sum - #bias }),
+                )
+            }
+            (ScalarType::Unsigned, 16) => {
+                let madd = intrinsic_ident("madd", "epi16", bits);
+                let set1 = intrinsic_ident("set1", "epi16", bits);
+                let bias = Literal::usize_unsuffixed(32768 * len);
+                (
+                    quote! { #madd(#xor(a.into(), #set1(i16::MIN)), #set1(1)) },
+                    Some(quote! { (sum + #bias).cast_unsigned() }),
+                )
+            }
+            (ScalarType::Int, 16) => {
+                let madd = intrinsic_ident("madd", "epi16", bits);
+                let set1 = intrinsic_ident("set1", "epi16", bits);
+                (quote! { #madd(a.into(), #set1(1)) }, None)
+            }
+            _ => unreachable!("widened reductions are only defined for 8- and 16-bit integers"),
+        };
+        // Add the lanes down to a single `i32`; AVX-512 has a helper for this.
+        let (fold, sum) = match bits {
+            512 => (None, quote! { _mm512_reduce_add_epi32(sums) }),
+            _ => {
+                let narrow = (bits == 256).then(|| {
+                    quote! {
+                        let sums = _mm_add_epi32(_mm256_castsi256_si128(sums), _mm256_extracti128_si256::<1>(sums));
+                    }
+                });
+                let fold = quote! {
+                    #narrow
+                    let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b01_00_11_10>(sums));
+                    let sums = _mm_add_epi32(sums, _mm_shuffle_epi32::<0b10_11_00_01>(sums));
+                };
+                (Some(fold), quote! { _mm_cvtsi128_si32(sums) })
+            }
+        };
+        let result = match result {
+            Some(result) => quote! {
+                let sum = #sum;
+                #result
+            },
+            None => sum,
+        };
+        self.kernel_method(op, vec_ty, |_| {
+            quote! {
+                let sums = #sums;
+                #fold
+                #result
+            }
+        })
+    }
+
     pub(crate) fn handle_cvt(
         &self,
         op: Op,
@@ -64,6 +64,9 @@ pub(crate) enum OpSig {
     Unary,
     /// Takes two argument of the vector type, and returns that same vector type.
     Binary,
+    /// Takes a vector and a signed integer vector of the same shape, and returns that same vector type with each
+    /// element shifted left by a positive amount, or right by a negative one.
+    ShiftSigned,
     /// Takes three argument of the vector type, and returns that same vector type.
     Ternary,
     /// Takes two argument of the vector type, and returns the corresponding mask type.
@@ -91,11 +94,27 @@ pub(crate) enum OpSig {
     /// Takes a vector, a scalar padding value, plus a const-generic offset, and returns that same vector type with
     /// elements shifted.
     ElementShift { direction: ElementDirection },
+    /// Takes a single argument of the vector type, which holds one or more square bit matrices, and returns that same
+    /// vector type with each matrix transposed.
+    BitTranspose,
     /// Takes two arguments of a vector type, plus a const generic shift amount, and returns that same vector type.
     Slide { granularity: SlideGranularity },
     /// Takes a vector and a same-width byte-index vector, and returns the original vector type with its bytes
     /// dynamically swizzled within each 128-bit block.
     SwizzleDynWithinBlocks,
+    /// Takes a vector and a mask of the same length, and returns that same vector type. Compressing packs the selected
+    /// lanes into the start of the result; expanding is the inverse, spreading the leading lanes to the selected
+    /// positions. All other lanes are zero.
+    Compress { expand: bool },
+    /// Takes a byte vector plus a const-generic factor, and returns that same vector type with each element multiplied
+    /// by the factor in GF(2^8).
+    GfMulConst,
+    /// Takes an accumulator of the target vector type, and two arguments of the source vector type, which has elements
+    /// half as wide and the same length. Returns the target vector type.
+    WidenAccumulate { acc_ty: VecType },
+    /// Takes a single argument of an 8- or 16-bit integer vector type, and returns the sum of its elements as a 32-bit
+    /// scalar of the same signedness.
+    ReduceWidened,
     /// Takes a single argument of the source vector type, and returns a vector type of the target scalar type and the
     /// same length.
     Cvt {
@@ -124,8 +143,16 @@ pub(crate) enum OpSig {
     MaskFromBitmask,
     /// Takes a mask vector type and returns its compact bitmask representation.
     MaskToBitmask,
+    /// Takes a mask vector type and returns the signed integer vector type with the same lanes.
+    MaskToInt,
     /// Takes a mutable mask vector, a lane index, and a boolean, and updates the lane in place.
     MaskSet,
+    /// Takes a vector and a lane index, and returns the element in that lane. The index is a const generic, unless
+    /// `dynamic` is set, in which case it's a `usize` argument.
+    ExtractLane { dynamic: bool },
+    /// Takes a vector and a value of its element type, plus a const-generic lane index, and returns that same vector
+    /// type with the lane replaced by the value.
+    InsertLane,
     /// Takes an argument of an array of a certain scalar type, with the length (`block_size` * `block_count`) / [scalar
     /// type's byte size]. Returns a vector type of that scalar type and length.
     ///
@@ -179,17 +206,23 @@ pub(crate) struct Op {
     /// `{arg1}`, etc. correspond to the argument names, which are different between the `Simd` trait methods and the
     /// ones defined on the vector types themselves (for instance, the first argument is always `self` in the latter).
     pub(crate) doc: &'static str,
+    /// A runnable example, which is added to the documentation as a doctest. This isn't templated, since the example is
+    /// code. It's the body of a function generic over `simd: S`, which is run once with the fallback level and once
+    /// with the best available level, so it should assert the exact resulting lanes.
+    ///
+    /// Examples are only emitted on the vector trait methods, which are documented once rather than for every type.
+    pub(crate) example: Option<&'static str>,
 }
 
-struct SimdTraitSigParts {
-    const_params: TokenStream,
-    arg_names: Vec<Ident>,
-    arg_tys: Vec<TokenStream>,
-    ret: TokenStream,
+pub(crate) struct SimdTraitSigParts {
+    pub(crate) const_params: TokenStream,
+    pub(crate) arg_names: Vec<Ident>,
+    pub(crate) arg_tys: Vec<TokenStream>,
+    pub(crate) ret: TokenStream,
 }
 
 impl SimdTraitSigParts {
-    fn arg_decls(&self) -> Vec<TokenStream> {
+    pub(crate) fn arg_decls(&self) -> Vec<TokenStream> {
         self.arg_names
             .iter()
             .zip(&self.arg_tys)
@@ -205,6 +238,14 @@ impl Op {
             kind,
             sig,
             doc,
+            example: None,
+        }
+    }
+
+    const fn with_example(self, example: &'static str) -> Self {
+        Self {
+            example: Some(example),
+            ..self
         }
     }
 
@@ -264,7 +305,11 @@ impl Op {
         }
     }
 
-    fn simd_trait_sig_parts(&self, vec_ty: &VecType, simd_ty: TokenStream) -> SimdTraitSigParts {
+    pub(crate) fn simd_trait_sig_parts(
+        &self,
+        vec_ty: &VecType,
+        simd_ty: TokenStream,
+    ) -> SimdTraitSigParts {
         let ty = vec_ty.rust();
         let arg_names = self
             .sig
@@ -278,6 +323,10 @@ impl Op {
             OpSig::ElementRotate { .. } | OpSig::ElementShift { .. } => {
                 quote! { <const OFFSET: usize> }
             }
+            OpSig::GfMulConst => quote! { <const FACTOR: u8> },
+            OpSig::ExtractLane { dynamic: false } | OpSig::InsertLane => {
+                quote! { <const LANE: usize> }
+            }
             _ => TokenStream::new(),
         };
 
@@ -312,7 +361,7 @@ impl Op {
                 let result = combined_ty.rust();
                 (vec![vec.clone(), vec], quote! { #result<#simd_ty> })
             }
-            OpSig::Unary => (vec![vec.clone()], vec),
+            OpSig::Unary | OpSig::BitTranspose | OpSig::GfMulConst => (vec![vec.clone()], vec),
             OpSig::Binary | OpSig::Zip { .. } | OpSig::Unzip { .. } => {
                 (vec![vec.clone(), vec.clone()], vec)
             }
@@ -326,6 +375,23 @@ impl Op {
                 let bytes_ty = vec_ty.bytes_ty().rust();
                 (vec![vec.clone(), quote! { #bytes_ty<#simd_ty> }], vec)
             }
+            OpSig::ShiftSigned => {
+                let amounts_ty = vec_ty.cast(ScalarType::Int).rust();
+                (vec![vec.clone(), quote! { #amounts_ty<#simd_ty> }], vec)
+            }
+            OpSig::Compress { .. } => {
+                let mask_ty = vec_ty.mask_ty().rust();
+                (vec![vec.clone(), quote! { #mask_ty<#simd_ty> }], vec)
+            }
+            OpSig::WidenAccumulate { acc_ty } => {
+                let acc = acc_ty.rust();
+                let acc = quote! { #acc<#simd_ty> };
+                (vec![acc.clone(), vec.clone(), vec], acc)
+            }
+            OpSig::ReduceWidened => {
+                let result = vec_ty.scalar.rust(32);
+                (vec![vec], quote! { #result })
+            }
             OpSig::Cvt {
                 target_ty,
                 scalar_bits,
@@ -345,10 +411,26 @@ impl Op {
             OpSig::MaskReduce { .. } => (vec![vec], quote! { bool }),
             OpSig::MaskFromBitmask => (vec![quote! { u64 }], vec),
             OpSig::MaskToBitmask => (vec![vec], quote! { u64 }),
+            OpSig::MaskToInt => {
+                let int_ty = vec_ty.cast(ScalarType::Int).rust();
+                (vec![vec], quote! { #int_ty<#simd_ty> })
+            }
             OpSig::MaskSet => (
                 vec![quote! { &mut #vec }, quote! { usize }, quote! { bool }],
                 quote! { () },
             ),
+            OpSig::ExtractLane { dynamic } => {
+                let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+                if *dynamic {
+                    (vec![vec, quote! { usize }], quote! { #scalar })
+                } else {
+                    (vec![vec], quote! { #scalar })
+                }
+            }
+            OpSig::InsertLane => {
+                let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+                (vec![vec.clone(), quote! { #scalar }], vec)
+            }
             OpSig::Shift => (vec![vec.clone(), quote! { u32 }], vec),
             OpSig::Ternary => (vec![vec.clone(), vec.clone(), vec.clone()], vec),
             OpSig::Select => {
@@ -416,8 +498,17 @@ impl Op {
             OpSig::LoadInterleaved { .. } | OpSig::StoreInterleaved { .. } | OpSig::StoreArray => {
                 return None;
             }
-            OpSig::MaskFromBitmask | OpSig::MaskToBitmask | OpSig::MaskSet => return None,
+            OpSig::MaskFromBitmask | OpSig::MaskToBitmask | OpSig::MaskToInt | OpSig::MaskSet => {
+                return None;
+            }
+            OpSig::Compress { .. }
+            | OpSig::WidenAccumulate { .. }
+            | OpSig::ReduceWidened
+            | OpSig::GfMulConst => {
+                return None;
+            }
             OpSig::Unary
+            | OpSig::BitTranspose
             | OpSig::Cvt { .. }
             | OpSig::Reinterpret { .. }
             | OpSig::WidenNarrow { .. } => {
@@ -452,11 +543,30 @@ impl Op {
                 let arg1 = &arg_names[1];
                 quote! { <const SHIFT: usize>(#arg0, #arg1: impl SimdInto<Self, S>) -> Self }
             }
+            OpSig::ExtractLane { dynamic: false } => {
+                let arg0 = &arg_names[0];
+                quote! { <const LANE: usize>(#arg0) -> Self::Element }
+            }
+            OpSig::ExtractLane { dynamic: true } => {
+                let arg0 = &arg_names[0];
+                let arg1 = &arg_names[1];
+                quote! { (#arg0, #arg1: usize) -> Self::Element }
+            }
+            OpSig::InsertLane => {
+                let arg0 = &arg_names[0];
+                let arg1 = &arg_names[1];
+                quote! { <const LANE: usize>(#arg0, #arg1: Self::Element) -> Self }
+            }
             OpSig::SwizzleDynWithinBlocks => {
                 let arg0 = &arg_names[0];
                 let arg1 = &arg_names[1];
                 quote! { (#arg0, #arg1: impl SimdInto<Self::Bytes, S>) -> Self }
             }
+            OpSig::ShiftSigned => {
+                let arg0 = &arg_names[0];
+                let arg1 = &arg_names[1];
+                quote! { (#arg0, #arg1: impl SimdInto<<Self::Mask as SimdMask<S>>::Int, S>) -> Self }
+            }
             OpSig::Compare => {
                 let arg0 = &arg_names[0];
                 let arg1 = &arg_names[1];
@@ -532,6 +642,28 @@ impl Op {
 
         dest
     }
+
+    /// Like [`Op::format_docstring`], followed by the example as a doctest if there is one.
+    pub(crate) fn format_docstring_with_example(&self, flavor: TyFlavor) -> String {
+        let mut doc = self.format_docstring(flavor);
+        if let Some(example) = self.example {
+            doc.push_str(
+                "\n\n# Example\n\n\
+                ```rust\n\
+                # use fearless_simd::{prelude::*, *};\n\
+                # #[inline(always)]\n\
+                # fn example<S: Simd>(simd: S) {\n",
+            );
+            doc.push_str(example);
+            doc.push_str(
+                "\n# }\n\
+                # example(Fallback::new());\n\
+                # dispatch!(Level::new(), simd => example(simd));\n\
+                ```",
+            );
+        }
+        doc
+    }
 }
 
 fn splat_arg_ty(vec_ty: &VecType) -> TokenStream {
@@ -548,6 +680,10 @@ const BASE_OPS: &[Op] = &[
         OpKind::BaseTraitMethod,
         OpSig::Splat,
         "Create a SIMD vector with all elements set to the given value.",
+    )
+    .with_example(
+        "let a = f32x4::splat(simd, 1.5);\n\
+        assert_eq!(*a, [1.5; 4]);",
     ),
     Op::new(
         "load_array",
@@ -593,13 +729,13 @@ const BASE_OPS: &[Op] = &[
         "cvt_from_bytes",
         OpKind::OwnTrait,
         OpSig::FromBytes,
-        "Reinterpret a vector of bytes as a SIMD vector of a given type, with the equivalent byte length.",
+        "Reinterpret a vector of bytes as a SIMD vector of a given type, with the equivalent byte length.\n\nThe bytes of each lane are in the target's native byte order.",
     ),
     Op::new(
         "cvt_to_bytes",
         OpKind::OwnTrait,
         OpSig::ToBytes,
-        "Reinterpret a SIMD vector as a vector of bytes, with the equivalent byte length.",
+        "Reinterpret a SIMD vector as a vector of bytes, with the equivalent byte length.\n\nThe bytes of each lane are in the target's native byte order.",
     ),
     Op::new(
         "slide",
@@ -614,6 +750,11 @@ const BASE_OPS: &[Op] = &[
          ```text\n\n\
          slide::<1>([a b c d], [e f g h]) == [b c d e]\n\n\
          ```",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = u32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        assert_eq!(*a.slide::<1>(b), [1, 2, 3, 4]);",
     ),
     Op::new(
         "slide_within_blocks",
@@ -622,6 +763,11 @@ const BASE_OPS: &[Op] = &[
             granularity: SlideGranularity::WithinBlocks,
         },
         "Like `slide`, but operates independently on each 128-bit block.",
+    )
+    .with_example(
+        "let a = u32x8::from_slice(simd, &[0, 1, 2, 3, 4, 5, 6, 7]);\n\
+        let b = u32x8::from_slice(simd, &[8, 9, 10, 11, 12, 13, 14, 15]);\n\
+        assert_eq!(*a.slide_within_blocks::<1>(b), [1, 2, 3, 8, 5, 6, 7, 12]);",
     ),
     Op::new(
         "rotate_elements_left",
@@ -631,6 +777,10 @@ const BASE_OPS: &[Op] = &[
         },
         "Rotate the vector elements to the left by `OFFSET`.\n\n\
         If `OFFSET` is greater than or equal to `Self::N`, it wraps modulo `Self::N`.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        assert_eq!(*a.rotate_elements_left::<1>(), [1, 2, 3, 0]);",
     ),
     Op::new(
         "rotate_elements_right",
@@ -640,6 +790,10 @@ const BASE_OPS: &[Op] = &[
         },
         "Rotate the vector elements to the right by `OFFSET`.\n\n\
         If `OFFSET` is greater than or equal to `Self::N`, it wraps modulo `Self::N`.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        assert_eq!(*a.rotate_elements_right::<1>(), [3, 0, 1, 2]);",
     ),
     Op::new(
         "shift_elements_left",
@@ -649,6 +803,10 @@ const BASE_OPS: &[Op] = &[
         },
         "Shift the vector elements to the left by `OFFSET`, filling in with `padding` from the right.\n\n\
         If `OFFSET` is greater than or equal to `Self::N`, all lanes are filled with `padding`.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        assert_eq!(*a.shift_elements_left::<1>(9), [1, 2, 3, 9]);",
     ),
     Op::new(
         "shift_elements_right",
@@ -658,6 +816,49 @@ const BASE_OPS: &[Op] = &[
         },
         "Shift the vector elements to the right by `OFFSET`, filling in with `padding` from the left.\n\n\
         If `OFFSET` is greater than or equal to `Self::N`, all lanes are filled with `padding`.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        assert_eq!(*a.shift_elements_right::<1>(9), [9, 0, 1, 2]);",
+    ),
+    Op::new(
+        "extract",
+        OpKind::BaseTraitMethod,
+        OpSig::ExtractLane { dynamic: false },
+        "Return the element in lane `LANE`.\n\n\
+        The lane is moved directly out of the vector, rather than through its array representation as with indexing, \
+        which would usually store the vector to memory. `LANE` must be less than `Self::N`, which is checked at \
+        compile time.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        assert_eq!(a.extract::<2>(), 2);",
+    ),
+    Op::new(
+        "insert",
+        OpKind::BaseTraitMethod,
+        OpSig::InsertLane,
+        "Replace the element in lane `LANE` with `{arg1}`.\n\n\
+        `LANE` must be less than `Self::N`, which is checked at compile time.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        assert_eq!(*a.insert::<2>(9), [0, 1, 9, 3]);",
+    ),
+    Op::new(
+        "extract_dyn",
+        OpKind::BaseTraitMethod,
+        OpSig::ExtractLane { dynamic: true },
+        "Return the element in lane `{arg1}`, which is only known at runtime.\n\n\
+        This reads the lane through the vector's array representation, like indexing does, so `extract` should be \
+        preferred when the lane is a constant.\n\n\
+        # Panics\n\n\
+        Panics if `{arg1}` is not less than `Self::N`.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let lane = a.extract::<0>() as usize + 1;\n\
+        assert_eq!(a.extract_dyn(lane), 1);",
     ),
     Op::new(
         "swizzle_dyn_within_blocks",
@@ -666,6 +867,13 @@ const BASE_OPS: &[Op] = &[
         "Dynamically swizzle this vector's bytes independently within each 128-bit block.\n\n\
         The `indices` operand is a same-width byte vector. For each output byte, index values `0..=15` select the corresponding byte from the same 128-bit input block.\n\n\
         Out-of-range index behavior varies by platform.",
+    )
+    .with_example(
+        "let a = u8x16::from_fn(simd, |i| 10 * i as u8);\n\
+        // Reverse the bytes.\n\
+        let indices = u8x16::from_fn(simd, |i| 15 - i as u8);\n\
+        let expected: [u8; 16] = core::array::from_fn(|i| 150 - 10 * i as u8);\n\
+        assert_eq!(*a.swizzle_dyn_within_blocks(indices), expected);",
     ),
 ];
 
@@ -692,6 +900,12 @@ const MASK_REPRESENTATION_OPS: &[Op] = &[
         },
         "Convert a SIMD mask to signed integer mask lanes.",
     ),
+    Op::new(
+        "to_int",
+        OpKind::AssociatedOnly,
+        OpSig::MaskToInt,
+        "Convert a SIMD mask to a signed integer vector with the same lanes.\n\nTrue lanes become `-1` and false lanes become `0`. On most levels, masks are stored as integer lanes, so this is free.",
+    ),
     Op::new(
         "from_bitmask",
         OpKind::AssociatedOnly,
@@ -718,6 +932,10 @@ const FLOAT_OPS: &[Op] = &[
         OpKind::VecTraitMethod,
         OpSig::Unary,
         "Compute the absolute value of each element.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[-1.0, 2.0, -0.5, 0.0]);\n\
+        assert_eq!(*a.abs(), [1.0, 2.0, 0.5, 0.0]);",
     ),
     Op::new(
         "neg",
@@ -731,6 +949,10 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Unary,
         "Compute the square root of each element.\n\n\
         Negative elements other than `-0.0` will become NaN.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[4.0, 9.0, 0.25, 0.0]);\n\
+        assert_eq!(*a.sqrt(), [2.0, 3.0, 0.5, 0.0]);",
     ),
     Op::new(
         "approximate_recip",
@@ -741,6 +963,11 @@ const FLOAT_OPS: &[Op] = &[
          On x86 for `f32`, this has a relative error less than `1.5 × 2^-12`. \
          On `AArch64` (`f32` and `f64`), this has a relative error less than `2^-8`. \
          The precision of this operation may change as new platform support is added.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[2.0, -4.0, 0.5, 10.0]);\n\
+        let relative_error = (a.approximate_recip() * a - 1.0).abs();\n\
+        assert!(relative_error.simd_le(1.0 / 256.0).all_true());",
     ),
     Op::new(
         "add",
@@ -772,6 +999,11 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Binary,
         "Return a vector with the magnitude of `{arg0}` and the sign of `{arg1}` for each element.\n\n\
         This operation copies the sign bit, so if an input element is NaN, the output element will be a NaN with the same payload and a copied sign bit.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, -2.0, 3.0, -4.0]);\n\
+        let signs = f32x4::from_slice(simd, &[-1.0, 1.0, -0.0, 0.0]);\n\
+        assert_eq!(*a.copysign(signs), [-1.0, 2.0, -3.0, 4.0]);",
     ),
     Op::new(
         "simd_eq",
@@ -779,6 +1011,12 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for equality.\n\n\
         Returns a mask where each logical lane is true if the corresponding elements are equal, and false if not.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, f32::NAN]);\n\
+        let b = f32x4::from_slice(simd, &[1.0, 0.0, 3.0, f32::NAN]);\n\
+        // NaN isn't equal to anything, including itself.\n\
+        assert_eq!(a.simd_eq(b).to_bitmask(), 0b0101);",
     ),
     Op::new(
         "simd_lt",
@@ -786,6 +1024,10 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for less than.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is less than `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(a.simd_lt(2.0).to_bitmask(), 0b0001);",
     ),
     Op::new(
         "simd_le",
@@ -793,6 +1035,10 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for less than or equal.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is less than or equal to `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(a.simd_le(2.0).to_bitmask(), 0b0011);",
     ),
     Op::new(
         "simd_ge",
@@ -800,6 +1046,10 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for greater than or equal.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is greater than or equal to `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(a.simd_ge(2.0).to_bitmask(), 0b1110);",
     ),
     Op::new(
         "simd_gt",
@@ -807,6 +1057,10 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for greater than.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is greater than `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(a.simd_gt(2.0).to_bitmask(), 0b1100);",
     ),
     Op::new(
         "zip_low",
@@ -817,6 +1071,11 @@ const FLOAT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a2, a3, b2, b3`.
         For fully interleaving two vectors prefer `interleave`,
         which is faster than `zip_low` followed by `zip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);\n\
+        let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);\n\
+        assert_eq!(*a.zip_low(b), [0.0, 4.0, 1.0, 5.0]);",
     ),
     Op::new(
         "zip_high",
@@ -827,6 +1086,11 @@ const FLOAT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a0, a1, b0, b1`.\
         For fully interleaving two vectors prefer `interleave`,
         which is faster than `zip_low` followed by `zip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);\n\
+        let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);\n\
+        assert_eq!(*a.zip_high(b), [2.0, 6.0, 3.0, 7.0]);",
     ),
     Op::new(
         "unzip_low",
@@ -837,6 +1101,11 @@ const FLOAT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a1, a3, b1, b3`.\
         For fully deinterleaving two vectors prefer `deinterleave`,
         which is faster than `unzip_low` followed by `unzip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);\n\
+        let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);\n\
+        assert_eq!(*a.unzip_low(b), [0.0, 2.0, 4.0, 6.0]);",
     ),
     Op::new(
         "unzip_high",
@@ -847,6 +1116,11 @@ const FLOAT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a0, a2, b0, b2`.\
         For fully deinterleaving two vectors prefer `deinterleave`,
         which is faster than `unzip_low` followed by `unzip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);\n\
+        let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);\n\
+        assert_eq!(*a.unzip_high(b), [1.0, 3.0, 5.0, 7.0]);",
     ),
     Op::new(
         "interleave",
@@ -857,6 +1131,13 @@ const FLOAT_OPS: &[Op] = &[
         first filling the first result, and then the second.\n\n\
         The reverse of this operation is `deinterleave`.\n\n\
         For vectors `[a0, a1, a2, a3]` and `[b0, b1, b2, b3]`, returns `([a0, b0, a1, b1], [a2, b2, a3, b3])`.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);\n\
+        let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);\n\
+        let (lo, hi) = a.interleave(b);\n\
+        assert_eq!(*lo, [0.0, 4.0, 1.0, 5.0]);\n\
+        assert_eq!(*hi, [2.0, 6.0, 3.0, 7.0]);",
     ),
     Op::new(
         "deinterleave",
@@ -867,6 +1148,13 @@ const FLOAT_OPS: &[Op] = &[
         The second result contains all odd-indexed elements from `{arg0}` followed by all odd-indexed elements from `{arg1}`.\n\n\
         The reverse of this operation is `interleave`.\n\n\
         For vectors `[a0, b0, a1, b1]` and `[a2, b2, a3, b3]`, returns `([a0, a1, a2, a3], [b0, b1, b2, b3])`.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);\n\
+        let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);\n\
+        let (even, odd) = a.deinterleave(b);\n\
+        assert_eq!(*even, [0.0, 2.0, 4.0, 6.0]);\n\
+        assert_eq!(*odd, [1.0, 3.0, 5.0, 7.0]);",
     ),
     Op::new(
         "max",
@@ -875,6 +1163,10 @@ const FLOAT_OPS: &[Op] = &[
         "Return the element-wise maximum of two vectors.\n\n\
         If either operand is NaN, the result for that lane is implementation-defined-- it could be either the first or second operand. See `max_precise` for a version that returns the non-NaN operand if only one is NaN.\n\n\
         If one operand is positive zero and the other is negative zero, the result is also implementation-defined, and it could be either one.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, -2.0, 3.0, -4.0]);\n\
+        assert_eq!(*a.max(0.5), [1.0, 0.5, 3.0, 0.5]);",
     ),
     Op::new(
         "min",
@@ -883,6 +1175,10 @@ const FLOAT_OPS: &[Op] = &[
         "Return the element-wise minimum of two vectors.\n\n\
         If either operand is NaN, the result for that lane is implementation-defined-- it could be either the first or second operand. See `min_precise` for a version that returns the non-NaN operand if only one is NaN.\n\n\
         If one operand is positive zero and the other is negative zero, the result is also implementation-defined, and it could be either one.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, -2.0, 3.0, -4.0]);\n\
+        assert_eq!(*a.min(0.5), [0.5, -2.0, 0.5, -4.0]);",
     ),
     Op::new(
         "max_precise",
@@ -893,6 +1189,11 @@ const FLOAT_OPS: &[Op] = &[
         If one operand is positive zero and the other is negative zero, the result is implementation-defined, and it could be either one.\n\n\
         If an operand is a *signaling* NaN, the result is not just implementation-defined, but fully non-deterministic: it may be either NaN or the non-NaN operand.\n\
         Signaling NaN values are not produced by floating-point math operations, only from manual initialization with specific bit patterns. You probably don't need to worry about them.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, f32::NAN, 3.0, -4.0]);\n\
+        let b = f32x4::from_slice(simd, &[2.0, 5.0, f32::NAN, -5.0]);\n\
+        assert_eq!(*a.max_precise(b), [2.0, 5.0, 3.0, -4.0]);",
     ),
     Op::new(
         "min_precise",
@@ -903,32 +1204,95 @@ const FLOAT_OPS: &[Op] = &[
         If one operand is positive zero and the other is negative zero, the result is implementation-defined, and it could be either one.\n\n\
         If an operand is a *signaling* NaN, the result is not just implementation-defined, but fully non-deterministic: it may be either NaN or the non-NaN operand.\n\
         Signaling NaN values are not produced by floating-point math operations, only from manual initialization with specific bit patterns. You probably don't need to worry about them.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, f32::NAN, 3.0, -4.0]);\n\
+        let b = f32x4::from_slice(simd, &[2.0, 5.0, f32::NAN, -5.0]);\n\
+        assert_eq!(*a.min_precise(b), [1.0, 5.0, 3.0, -5.0]);",
     ),
     Op::new(
         "mul_add",
         OpKind::VecTraitMethod,
         OpSig::Ternary,
         "Compute `({arg0} * {arg1}) + {arg2}` (fused multiply-add) for each element.\n\n\
-        Depending on hardware support, the result may be computed with only one rounding error, or may be implemented as a regular multiply followed by an add, which will result in two rounding errors.",
+        Depending on hardware support, the result may be computed with only one rounding error, or may be implemented as a regular multiply followed by an add, which will result in two rounding errors. [`Simd::FUSED_MUL_ADD`] reports which one the level uses.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(*a.mul_add(2.0, 0.5), [2.5, 4.5, 6.5, 8.5]);",
     ),
     Op::new(
         "mul_sub",
         OpKind::VecTraitMethod,
         OpSig::Ternary,
         "Compute `({arg0} * {arg1}) - {arg2}` (fused multiply-subtract) for each element.\n\n\
-        Depending on hardware support, the result may be computed with only one rounding error, or may be implemented as a regular multiply followed by a subtract, which will result in two rounding errors.",
+        Depending on hardware support, the result may be computed with only one rounding error, or may be implemented as a regular multiply followed by a subtract, which will result in two rounding errors. [`Simd::FUSED_MUL_ADD`] reports which one the level uses.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(*a.mul_sub(2.0, 0.5), [1.5, 3.5, 5.5, 7.5]);",
+    ),
+    Op::new(
+        "nmul_add",
+        OpKind::VecTraitMethod,
+        OpSig::Ternary,
+        "Compute `-({arg0} * {arg1}) + {arg2}` (fused negated multiply-add) for each element.\n\n\
+        This is `{arg2} - {arg0} * {arg1}`, which comes up in polynomial evaluation and in Newton-Raphson steps. It's \
+        a single instruction wherever `mul_add` is, rather than a `mul_add` and a negation. As with `mul_add`, the \
+        result may be computed with one rounding error or two, and [`Simd::FUSED_MUL_ADD`] reports which one the level \
+        uses.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(*a.nmul_add(2.0, 0.5), [-1.5, -3.5, -5.5, -7.5]);",
+    ),
+    Op::new(
+        "nmul_sub",
+        OpKind::VecTraitMethod,
+        OpSig::Ternary,
+        "Compute `-({arg0} * {arg1}) - {arg2}` (fused negated multiply-subtract) for each element.\n\n\
+        It's a single instruction wherever `mul_add` is, rather than a `mul_add` and a negation. As with `mul_add`, \
+        the result may be computed with one rounding error or two, and [`Simd::FUSED_MUL_ADD`] reports which one the \
+        level uses.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(*a.nmul_sub(2.0, 0.5), [-2.5, -4.5, -6.5, -8.5]);",
+    ),
+    Op::new(
+        "mul_add_relaxed",
+        OpKind::VecTraitMethod,
+        OpSig::Ternary,
+        "Compute `({arg0} * {arg1}) + {arg2}` for each element, with whichever of one or two rounding errors is \
+        faster.\n\n\
+        This is the same as `mul_add`, except on WebAssembly with the `relaxed-simd` feature, where it's a relaxed \
+        multiply-add, whose rounding depends on the host rather than the level. `mul_add` always rounds twice there, \
+        so that a level gives the same results on every host. This is what the \
+        [`Fast`](crate::determinism::Fast) tier uses.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);\n\
+        assert_eq!(*a.mul_add_relaxed(2.0, 0.5), [2.5, 4.5, 6.5, 8.5]);",
     ),
     Op::new(
         "floor",
         OpKind::VecTraitMethod,
         OpSig::Unary,
         "Return the largest integer less than or equal to each element, that is, round towards negative infinity.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[-1.5, -0.5, 0.5, 2.0]);\n\
+        assert_eq!(*a.floor(), [-2.0, -1.0, 0.0, 2.0]);",
     ),
     Op::new(
         "ceil",
         OpKind::VecTraitMethod,
         OpSig::Unary,
         "Return the smallest integer greater than or equal to each element, that is, round towards positive infinity.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[-1.5, -0.5, 0.5, 2.0]);\n\
+        assert_eq!(*a.ceil(), [-1.0, -0.0, 1.0, 2.0]);",
     ),
     Op::new(
         "round_ties_even",
@@ -936,18 +1300,30 @@ const FLOAT_OPS: &[Op] = &[
         OpSig::Unary,
         "Round each element to the nearest integer, with ties rounding to the nearest even integer.\n\n\
         There is no corresponding `round` operation. Rust's `round` operation rounds ties away from zero, a behavior it inherited from C. That behavior is not implemented across all platforms, whereas round-ties-even is.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[-1.5, -0.5, 0.5, 2.5]);\n\
+        assert_eq!(*a.round_ties_even(), [-2.0, -0.0, 0.0, 2.0]);",
     ),
     Op::new(
         "fract",
         OpKind::VecTraitMethod,
         OpSig::Unary,
         "Return the fractional part of each element.\n\nThis is equivalent to `{arg0} - {arg0}.trunc()`.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.25, -1.25, 3.0, 0.5]);\n\
+        assert_eq!(*a.fract(), [0.25, -0.25, 0.0, 0.5]);",
     ),
     Op::new(
         "trunc",
         OpKind::VecTraitMethod,
         OpSig::Unary,
         "Return the integer part of each element, rounding towards zero.",
+    )
+    .with_example(
+        "let a = f32x4::from_slice(simd, &[1.75, -1.75, 0.5, -0.5]);\n\
+        assert_eq!(*a.trunc(), [1.0, -1.0, 0.0, -0.0]);",
     ),
     Op::new(
         "select",
@@ -956,6 +1332,13 @@ const FLOAT_OPS: &[Op] = &[
         "Select elements from {arg1} and {arg2} based on the mask operand {arg0}.\n\n\
     This operation's behavior is unspecified if {arg0} was constructed from signed integer lanes that are neither all-zeroes (integer value 0) nor all-ones (integer value -1). See the [`Select`] trait's documentation for more information.",
     ),
+    Op::new(
+        "select_relaxed",
+        OpKind::OwnTrait,
+        OpSig::Select,
+        "Select elements from `{arg1}` and `{arg2}` based on the mask operand `{arg0}`, with the relaxed lane select on WebAssembly with the `relaxed-simd` feature.\n\n\
+    This is the same as `select` for masks whose lanes are all-zeroes or all-ones, and otherwise, its result depends on the host. See [`Select::select_relaxed`] for more information.",
+    ),
 ];
 
 const INT_OPS: &[Op] = &[
@@ -1031,12 +1414,33 @@ const INT_OPS: &[Op] = &[
         For unsigned integers, zeros are shifted in on the left. For signed integers, the sign bit is replicated.\n\n\
         This operation is not implemented in hardware on all platforms. On WebAssembly, and on x86 platforms without AVX2, this will use a fallback scalar implementation.",
     ),
+    Op::new(
+        "shift_signed",
+        OpKind::VecTraitMethod,
+        OpSig::ShiftSigned,
+        "Shift each element by the corresponding element of a signed integer vector: left if it's positive, and right by \
+        its magnitude if it's negative.\n\n\
+        This is useful when the direction of each shift is computed at runtime. A right shift shifts in zeros for \
+        unsigned integers, and replicates the sign bit for signed integers, like `shrv`. The result is unspecified if \
+        the magnitude of an amount is at least the width of the elements.\n\n\
+        This is a single instruction on NEON, which shifts by signed amounts natively. On x86 platforms with AVX2, it's \
+        a left and a right shift and a select, and elsewhere it uses a fallback scalar implementation.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0b1100, 0b1100, 0b1100, 0b1100]);\n\
+        let amounts = i32x4::from_slice(simd, &[2, -2, 0, -3]);\n\
+        assert_eq!(*a.shift_signed(amounts), [0b11_0000, 0b11, 0b1100, 0b1]);",
+    ),
     Op::new(
         "simd_eq",
         OpKind::VecTraitMethod,
         OpSig::Compare,
         "Compare two vectors element-wise for equality.\n\n\
         Returns a mask where each logical lane is true if the corresponding elements are equal, and false if not.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        assert_eq!(a.simd_eq(3).to_bitmask(), 0b0100);",
     ),
     Op::new(
         "simd_lt",
@@ -1044,6 +1448,10 @@ const INT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for less than.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is less than `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        assert_eq!(a.simd_lt(0).to_bitmask(), 0b1010);",
     ),
     Op::new(
         "simd_le",
@@ -1051,6 +1459,10 @@ const INT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for less than or equal.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is less than or equal to `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        assert_eq!(a.simd_le(1).to_bitmask(), 0b1011);",
     ),
     Op::new(
         "simd_ge",
@@ -1058,6 +1470,10 @@ const INT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for greater than or equal.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is greater than or equal to `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        assert_eq!(a.simd_ge(1).to_bitmask(), 0b0101);",
     ),
     Op::new(
         "simd_gt",
@@ -1065,6 +1481,10 @@ const INT_OPS: &[Op] = &[
         OpSig::Compare,
         "Compare two vectors element-wise for greater than.\n\n\
         Returns a mask where each logical lane is true if `{arg0}` is greater than `{arg1}`, and false if not.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        assert_eq!(a.simd_gt(1).to_bitmask(), 0b0100);",
     ),
     Op::new(
         "zip_low",
@@ -1075,6 +1495,11 @@ const INT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a2, a3, b2, b3`.
         For fully interleaving two vectors prefer `interleave`,
         which is faster than `zip_low` followed by `zip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = i32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        assert_eq!(*a.zip_low(b), [0, 4, 1, 5]);",
     ),
     Op::new(
         "zip_high",
@@ -1085,6 +1510,11 @@ const INT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a0, a1, b0, b1`.\
         For fully interleaving two vectors prefer `interleave`,
         which is faster than `zip_low` followed by `zip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = i32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        assert_eq!(*a.zip_high(b), [2, 6, 3, 7]);",
     ),
     Op::new(
         "unzip_low",
@@ -1095,6 +1525,11 @@ const INT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a1, a3, b1, b3`.\
         For fully deinterleaving two vectors prefer `deinterleave`,
         which is faster than `unzip_low` followed by `unzip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = i32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        assert_eq!(*a.unzip_low(b), [0, 2, 4, 6]);",
     ),
     Op::new(
         "unzip_high",
@@ -1105,6 +1540,11 @@ const INT_OPS: &[Op] = &[
         **Note:** This operation is only useful if you need to discard elements `a0, a2, b0, b2`.\
         For fully deinterleaving two vectors prefer `deinterleave`,
         which is faster than `unzip_low` followed by `unzip_high` on some platforms.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = i32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        assert_eq!(*a.unzip_high(b), [1, 3, 5, 7]);",
     ),
     Op::new(
         "interleave",
@@ -1115,6 +1555,13 @@ const INT_OPS: &[Op] = &[
         first filling the first result, and then the second.\n\n\
         The reverse of this operation is `deinterleave`.\n\n\
         For vectors `[a0, a1, a2, a3]` and `[b0, b1, b2, b3]`, returns `([a0, b0, a1, b1], [a2, b2, a3, b3])`.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = i32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        let (lo, hi) = a.interleave(b);\n\
+        assert_eq!(*lo, [0, 4, 1, 5]);\n\
+        assert_eq!(*hi, [2, 6, 3, 7]);",
     ),
     Op::new(
         "deinterleave",
@@ -1125,6 +1572,13 @@ const INT_OPS: &[Op] = &[
         The second result contains all odd-indexed elements from `{arg0}` followed by all odd-indexed elements from `{arg1}`.\n\n\
         The reverse of this operation is `interleave`.\n\n\
         For vectors `[a0, b0, a1, b1]` and `[a2, b2, a3, b3]`, returns `([a0, a1, a2, a3], [b0, b1, b2, b3])`.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 2, 3]);\n\
+        let b = i32x4::from_slice(simd, &[4, 5, 6, 7]);\n\
+        let (even, odd) = a.deinterleave(b);\n\
+        assert_eq!(*even, [0, 2, 4, 6]);\n\
+        assert_eq!(*odd, [1, 3, 5, 7]);",
     ),
     Op::new(
         "select",
@@ -1133,17 +1587,100 @@ const INT_OPS: &[Op] = &[
         "Select elements from {arg1} and {arg2} based on the mask operand {arg0}.\n\n\
     This operation's behavior is unspecified if {arg0} was constructed from signed integer lanes that are neither all-zeroes (integer value 0) nor all-ones (integer value -1). See the [`Select`] trait's documentation for more information.",
     ),
+    Op::new(
+        "select_relaxed",
+        OpKind::OwnTrait,
+        OpSig::Select,
+        "Select elements from `{arg1}` and `{arg2}` based on the mask operand `{arg0}`, with the relaxed lane select on WebAssembly with the `relaxed-simd` feature.\n\n\
+    This is the same as `select` for masks whose lanes are all-zeroes or all-ones, and otherwise, its result depends on the host. See [`Select::select_relaxed`] for more information.",
+    ),
     Op::new(
         "min",
         OpKind::VecTraitMethod,
         OpSig::Binary,
         "Return the element-wise minimum of two vectors.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        let b = i32x4::from_slice(simd, &[0, 0, 5, -5]);\n\
+        assert_eq!(*a.min(b), [0, -2, 3, -5]);",
     ),
     Op::new(
         "max",
         OpKind::VecTraitMethod,
         OpSig::Binary,
         "Return the element-wise maximum of two vectors.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[1, -2, 3, -4]);\n\
+        let b = i32x4::from_slice(simd, &[0, 0, 5, -5]);\n\
+        assert_eq!(*a.max(b), [1, 0, 5, -4]);",
+    ),
+    Op::new(
+        "saturating_add",
+        OpKind::VecTraitMethod,
+        OpSig::Binary,
+        "Add two vectors element-wise, saturating at the bounds of the element type instead of wrapping on overflow.\n\n\
+        This is a single instruction on all platforms for 8-bit and 16-bit elements, and on NEON for all elements. \
+        Elsewhere, it uses a fallback scalar implementation.",
+    )
+    .with_example(
+        "let a = i16x8::from_slice(simd, &[1, i16::MAX, -2, i16::MIN, 0, 100, -100, 7]);\n\
+        let b = i16x8::from_slice(simd, &[2, 1, -3, -1, 0, i16::MAX, i16::MIN, -7]);\n\
+        assert_eq!(*a.saturating_add(b), [3, i16::MAX, -5, i16::MIN, 0, i16::MAX, i16::MIN, 0]);",
+    ),
+    Op::new(
+        "saturating_sub",
+        OpKind::VecTraitMethod,
+        OpSig::Binary,
+        "Subtract two vectors element-wise, saturating at the bounds of the element type instead of wrapping on \
+        overflow.\n\n\
+        This is a single instruction on all platforms for 8-bit and 16-bit elements, and on NEON for all elements. \
+        Elsewhere, it uses a fallback scalar implementation.",
+    )
+    .with_example(
+        "let a = u16x8::from_slice(simd, &[5, 0, 1, u16::MAX, 0, 100, 7, 3]);\n\
+        let b = u16x8::from_slice(simd, &[2, 1, 1, 0, u16::MAX, 101, 7, 4]);\n\
+        assert_eq!(*a.saturating_sub(b), [3, 0, 0, u16::MAX, 0, 0, 0, 0]);",
+    ),
+    Op::new(
+        "count_ones",
+        OpKind::VecTraitMethod,
+        OpSig::Unary,
+        "Count the number of set bits in each element.\n\n\
+        This is a single instruction on NEON for 8-bit elements, on WebAssembly for 8-bit elements, and on x86 \
+        platforms with AVX-512. Wider elements add up the counts of their bytes. On x86 platforms without AVX-512, \
+        the bytes are counted with a lookup table of nibbles, and SSE2 uses a fallback scalar implementation.",
+    )
+    .with_example(
+        "let a = u32x4::from_slice(simd, &[0, 1, 0b1011, u32::MAX]);\n\
+        assert_eq!(*a.count_ones(), [0, 1, 3, 32]);",
+    ),
+    Op::new(
+        "leading_zeros",
+        OpKind::VecTraitMethod,
+        OpSig::Unary,
+        "Count the number of leading zero bits, starting from the most significant bit, in each element.\n\n\
+        Elements which are zero have as many leading zeros as they have bits, and negative elements have none.\n\n\
+        This is a single instruction on NEON for elements of up to 32 bits, and on x86 platforms with AVX-512 for \
+        32-bit and 64-bit elements. Elsewhere, the highest set bit is copied into every lower bit, and the zeros are \
+        counted with `count_ones`.",
+    )
+    .with_example(
+        "let a = u16x8::from_slice(simd, &[0, 1, 0x00f0, 0x8000, 2, 3, 4, 5]);\n\
+        assert_eq!(*a.leading_zeros(), [16, 15, 8, 0, 14, 14, 13, 13]);",
+    ),
+    Op::new(
+        "trailing_zeros",
+        OpKind::VecTraitMethod,
+        OpSig::Unary,
+        "Count the number of trailing zero bits, starting from the least significant bit, in each element.\n\n\
+        Elements which are zero have as many trailing zeros as they have bits.\n\n\
+        This counts the bits below the lowest set bit with `count_ones`.",
+    )
+    .with_example(
+        "let a = i32x4::from_slice(simd, &[0, 1, 12, i32::MIN]);\n\
+        assert_eq!(*a.trailing_zeros(), [32, 0, 2, 31]);",
     ),
 ];
 
@@ -1190,12 +1727,24 @@ const MASK_OPS: &[Op] = &[
         "Select elements from `{arg1}` and `{arg2}` based on the mask operand `{arg0}`.\n\n\
     This operation's behavior is unspecified if {arg0} was constructed from signed integer lanes that are neither all-zeroes (integer value 0) nor all-ones (integer value -1). See the [`Select`] trait's documentation for more information.",
     ),
+    Op::new(
+        "select_relaxed",
+        OpKind::OwnTrait,
+        OpSig::Select,
+        "Select elements from `{arg1}` and `{arg2}` based on the mask operand `{arg0}`, with the relaxed lane select on WebAssembly with the `relaxed-simd` feature.\n\n\
+    This is the same as `select` for masks whose lanes are all-zeroes or all-ones, and otherwise, its result depends on the host. See [`Select::select_relaxed`] for more information.",
+    ),
     Op::new(
         "simd_eq",
         OpKind::VecTraitMethod,
         OpSig::Compare,
         "Compare two vectors element-wise for equality.\n\n\
         Returns a mask where each logical lane is true if the corresponding elements are equal, and false if not.",
+    )
+    .with_example(
+        "let a = mask32x4::from_bitmask(simd, 0b0011);\n\
+        let b = mask32x4::from_bitmask(simd, 0b0101);\n\
+        assert_eq!(a.simd_eq(b).to_bitmask(), 0b1001);",
     ),
     Op::new(
         "any_true",
@@ -1208,6 +1757,10 @@ const MASK_OPS: &[Op] = &[
             "Returns true if any logical lanes in this mask are true.\n\n",
             mask_reduce_blurb!()
         ),
+    )
+    .with_example(
+        "assert!(mask32x4::from_bitmask(simd, 0b0100).any_true());\n\
+        assert!(!mask32x4::splat(simd, false).any_true());",
     ),
     Op::new(
         "all_true",
@@ -1220,6 +1773,10 @@ const MASK_OPS: &[Op] = &[
             "Returns true if all logical lanes in this mask are true.\n\n",
             mask_reduce_blurb!()
         ),
+    )
+    .with_example(
+        "assert!(mask32x4::splat(simd, true).all_true());\n\
+        assert!(!mask32x4::from_bitmask(simd, 0b1011).all_true());",
     ),
     Op::new(
         "any_false",
@@ -1233,6 +1790,10 @@ const MASK_OPS: &[Op] = &[
             This is logically equivalent to `!all_true`, but may be faster.\n\n",
             mask_reduce_blurb!()
         ),
+    )
+    .with_example(
+        "assert!(mask32x4::from_bitmask(simd, 0b1011).any_false());\n\
+        assert!(!mask32x4::splat(simd, true).any_false());",
     ),
     Op::new(
         "all_false",
@@ -1246,6 +1807,10 @@ const MASK_OPS: &[Op] = &[
             This is logically equivalent to `!any_true`, but may be faster.\n\n",
             mask_reduce_blurb!()
         ),
+    )
+    .with_example(
+        "assert!(mask32x4::splat(simd, false).all_false());\n\
+        assert!(!mask32x4::from_bitmask(simd, 0b0100).all_false());",
     ),
 ];
 
@@ -1362,6 +1927,131 @@ pub(crate) const I32_TO_F32: Op = Op::new(
     Values that cannot be exactly represented are rounded to the nearest representable value.",
 );
 
+pub(crate) const F32_PACK_CLAMPED_U8: Op = Op::new(
+    "pack_clamped_u8",
+    OpKind::AssociatedOnly,
+    OpSig::WidenNarrow {
+        target_ty: VecType::new(ScalarType::Unsigned, 8, 16),
+    },
+    "Convert each floating-point element to an unsigned 8-bit integer, rounding to the nearest integer with ties \
+    to even.\n\n\
+    Out-of-range values are saturated to the closest in-range value. NaN becomes 0.\n\n\
+    This is a single conversion and pack sequence on each level, and is useful for storing pixel values that have \
+    already been scaled to the `0.0..=255.0` range.",
+);
+
+const COMPRESS_BYTES: Op = Op::new(
+    "compress_bytes",
+    OpKind::AssociatedOnly,
+    OpSig::Compress { expand: false },
+    "Pack the bytes of `{arg0}` whose lanes are set in `{arg1}` into the start of the result, keeping their order.\n\n\
+    The remaining lanes of the result are zero. The number of packed bytes is the number of lanes set in the mask, \
+    which can be found with [`SimdMask::count_true`](crate::SimdMask::count_true).\n\n\
+    This is a single instruction with AVX-512 (which requires VBMI2), and uses small lookup tables of byte shuffles \
+    on other levels.",
+);
+
+const EXPAND_BYTES: Op = Op::new(
+    "expand_bytes",
+    OpKind::AssociatedOnly,
+    OpSig::Compress { expand: true },
+    "Spread the leading bytes of `{arg0}` out to the lanes which are set in `{arg1}`, keeping their order.\n\n\
+    Lanes which aren't set in the mask are zero. This is the inverse of `compress_bytes`.\n\n\
+    This is a single instruction with AVX-512 (which requires VBMI2), and uses small lookup tables of byte shuffles \
+    on other levels.",
+);
+
+const AVG_ROUND: Op = Op::new(
+    "avg_round",
+    OpKind::AssociatedOnly,
+    OpSig::Binary,
+    "Compute the average of each pair of elements of `{arg0}` and `{arg1}`, rounding halves up.\n\n\
+    Each element of the result is `(a[i] + b[i] + 1) >> 1`, computed without overflow. This is the bilinear \
+    interpolation of two pixels halfway between them, as used for chroma upsampling and for half-pixel motion \
+    compensation in video codecs.\n\n\
+    This is a single `pavgb` or `pavgw` instruction on x86, a `urhadd` on NEON and an `avgr_u` on WebAssembly.",
+);
+
+const TABLE_LOOKUP: Op = Op::new(
+    "table_lookup",
+    OpKind::AssociatedOnly,
+    OpSig::Binary,
+    "Look up each element of `{arg1}` in the 16-byte table formed by the same 128-bit block of `{arg0}`.\n\n\
+    Each element of the result is `{arg0}[block + {arg1}[i]]`, where `block` is the index of the first byte of the \
+    block, or zero if `{arg1}[i]` is 16 or more. Unlike `swizzle_dyn_within_blocks`, the result is the same on every \
+    level for any index, so this is suitable for nibble lookups such as in base64 decoding and UTF-8 validation, \
+    and for reordering color channels. To look up the same table in every block of a wider vector, use \
+    [`SimdBase::block_splat`](crate::SimdBase::block_splat).\n\n\
+    This is a `tbl` instruction on NEON and an `i8x16.swizzle` on WebAssembly. On x86, it's a `pshufb`, after a \
+    saturating add which sets the high bit of indices which are out of range, so that they give zero. SSE2 has no \
+    byte shuffle, and uses a fallback scalar implementation.",
+);
+
+const GF_MUL_CONST: Op = Op::new(
+    "gf_mul_const",
+    OpKind::AssociatedOnly,
+    OpSig::GfMulConst,
+    "Multiply each element of `{arg0}` by `FACTOR` in the Galois field GF(2^8), as used by Reed-Solomon erasure \
+    codes.\n\n\
+    The field is defined by the polynomial x^8 + x^4 + x^3 + x + 1 (`0x11B`), which is the one used by AES and by \
+    the GFNI instructions. Addition in this field is XOR, so a parity byte is the XOR of the products of the data \
+    bytes and their coefficients.\n\n\
+    This is a single `vgf2p8mulb` instruction with AVX-512, and two 16-entry table lookups, one for each half of \
+    every byte, on other levels. The tables are computed at compile time.",
+);
+
+const TRANSPOSE_BITS_8X8: Op = Op::new(
+    "transpose_bits_8x8",
+    OpKind::AssociatedOnly,
+    OpSig::BitTranspose,
+    "Transpose the 8x8 bit matrix in each element of `{arg0}`.\n\n\
+    Byte `r` of each element is row `r` of its matrix, and bit `c` of that byte is column `c`, so bit `8 * r + c` \
+    of the input moves to bit `8 * c + r` of the output. This suits bitboards and bit-plane image formats.\n\n\
+    This uses two GFNI affine transforms with AVX-512, and three rounds of shifts and masks on other levels.",
+);
+
+const TRANSPOSE_BITS_16X16: Op = Op::new(
+    "transpose_bits_16x16",
+    OpKind::AssociatedOnly,
+    OpSig::BitTranspose,
+    "Transpose the 16x16 bit matrix in `{arg0}`.\n\n\
+    Element `r` is row `r` of the matrix, and bit `c` of that element is column `c`, so bit `c` of element `r` \
+    moves to bit `r` of element `c`.\n\n\
+    This splits the matrix into four 8x8 blocks, which are transposed with `transpose_bits_8x8` and interleaved \
+    back together with byte shuffles.",
+);
+
+const DOT_OPS: &[Op] = &[
+    Op::new(
+        "dot2",
+        OpKind::AssociatedOnly,
+        OpSig::Binary,
+        "Compute the dot product of each pair of adjacent elements of `{arg0}` and `{arg1}`, such as packed 2D \
+        vectors.\n\n\
+        Both lanes of each pair in the result hold `a[0] * b[0] + a[1] * b[1]` for that pair. This is a multiply, a \
+        shuffle and an add on each level.",
+    ),
+    Op::new(
+        "dot3",
+        OpKind::AssociatedOnly,
+        OpSig::Binary,
+        "Compute the dot product of the first three elements of each group of four elements of `{arg0}` and \
+        `{arg1}`, such as 3D vectors padded to four lanes.\n\n\
+        The fourth element of each group is ignored, even if it's NaN or infinite, and every lane of the group in the \
+        result holds the dot product. It is summed like `dot4`, with the fourth product replaced by `0.0`.",
+    ),
+    Op::new(
+        "dot4",
+        OpKind::AssociatedOnly,
+        OpSig::Binary,
+        "Compute the dot product of each group of four elements of `{arg0}` and `{arg1}`, such as 4D vectors or \
+        quaternions.\n\n\
+        Every lane of the group in the result holds `(a[0] * b[0] + a[1] * b[1]) + (a[2] * b[2] + a[3] * b[3])` for \
+        that group, rounding after each step, so the result is the same on every level. This is a `dpps` \
+        instruction with SSE4.1 and AVX2, and a multiply followed by two rounds of shuffles and adds elsewhere.",
+    ),
+];
+
 pub(crate) fn ops_for_type(ty: &VecType) -> Vec<Op> {
     let base = match ty.scalar {
         ScalarType::Float => FLOAT_OPS,
@@ -1417,7 +2107,7 @@ pub(crate) fn ops_for_type(ty: &VecType) -> Vec<Op> {
                     target_ty: ScalarType::Float,
                     scalar_bits: 64,
                 },
-                "Reinterpret the bits of this vector as a vector of `f64` elements.\n\nThe number of elements in the result is half that of the input.",
+                "Reinterpret the bits of this vector as a vector of `f64` elements.\n\nThe number of elements in the result is half that of the input, and the result depends on the target's byte order.",
             ));
 
             ops.push(Op::new(
@@ -1473,24 +2163,95 @@ pub(crate) fn ops_for_type(ty: &VecType) -> Vec<Op> {
         ));
     }
 
-    if matches!(ty.scalar, ScalarType::Unsigned) {
-        if let Some(target_ty) = ty.widened() {
-            ops.push(Op::new(
-                "widen",
-                OpKind::AssociatedOnly,
-                OpSig::WidenNarrow { target_ty },
-                "Zero-extend each element to a wider integer type.\n\nThe number of elements in the result is half that of the input.",
-            ));
-        }
+    if let Some(target_ty) = ty.widened() {
+        let doc = match ty.scalar {
+            ScalarType::Int => {
+                "Sign-extend each element to an integer type twice as wide.\n\nThe result has the same number of elements as the input, so is twice as wide."
+            }
+            _ => {
+                "Zero-extend each element to an integer type twice as wide.\n\nThe result has the same number of elements as the input, so is twice as wide."
+            }
+        };
+        ops.push(Op::new(
+            "widen",
+            OpKind::AssociatedOnly,
+            OpSig::WidenNarrow { target_ty },
+            doc,
+        ));
+    }
 
-        if let Some(target_ty) = ty.narrowed() {
-            ops.push(Op::new(
-                "narrow",
-                OpKind::AssociatedOnly,
-                OpSig::WidenNarrow { target_ty },
-                "Truncate each element to a narrower integer type.\n\nThe number of elements in the result is twice that of the input.",
-            ));
-        }
+    if matches!(ty.scalar, ScalarType::Unsigned)
+        && let Some(target_ty) = ty.narrowed()
+    {
+        ops.push(Op::new(
+            "narrow",
+            OpKind::AssociatedOnly,
+            OpSig::WidenNarrow { target_ty },
+            "Truncate each element to a narrower integer type.\n\nThe number of elements in the result is twice that of the input.",
+        ));
+    }
+
+    if let Some(narrowed) = ty.narrowed() {
+        ops.push(Op::new(
+            "pack_saturate_i8",
+            OpKind::AssociatedOnly,
+            OpSig::WidenNarrow {
+                target_ty: narrowed.reinterpret(ScalarType::Int, 8),
+            },
+            "Convert each element to an `i8`, saturating values outside of `i8::MIN..=i8::MAX`.\n\n\
+            Unlike `narrow`, which keeps the low byte of each element, this clamps each element to the closest \
+            representable value. This is suitable for signed data such as audio samples.",
+        ));
+        ops.push(Op::new(
+            "pack_saturate_u8",
+            OpKind::AssociatedOnly,
+            OpSig::WidenNarrow {
+                target_ty: narrowed.reinterpret(ScalarType::Unsigned, 8),
+            },
+            "Convert each element to a `u8`, saturating values outside of `0..=u8::MAX`.\n\n\
+            Unlike `narrow`, which keeps the low byte of each element, this clamps each element to the closest \
+            representable value. This is suitable for unsigned data such as pixel values.",
+        ));
+    }
+
+    if ty.scalar == ScalarType::Unsigned && matches!(ty.scalar_bits, 8 | 16) && ty.n_bits() <= 256 {
+        ops.push(Op::new(
+            "abd_accumulate",
+            OpKind::AssociatedOnly,
+            OpSig::WidenAccumulate {
+                acc_ty: VecType::new(ty.scalar, ty.scalar_bits * 2, ty.len),
+            },
+            "Add the absolute difference of each pair of elements of `{arg1}` and `{arg2}` to the accumulator \
+            `{arg0}`, whose elements are twice as wide.\n\n\
+            Each element of the result is `acc[i] + a[i].abs_diff(b[i])`, wrapping on overflow. The accumulator has \
+            one element per input element, in the same order, so the result is the same on every level. Starting \
+            from zero, 257 accumulations of 8-bit inputs (or 65,537 of 16-bit inputs) can never overflow. Summing the \
+            elements of the accumulator then gives the sum of absolute differences (SAD) of all of the inputs.\n\n\
+            On Arm, this is a `vabal` instruction for each half of the inputs. x86 has no widening absolute \
+            difference, and `psadbw` sums groups of eight elements, which doesn't match this layout. There, it uses \
+            saturating subtractions followed by a zero extension and an add instead.",
+        ));
+    }
+
+    if ty.scalar == ScalarType::Unsigned && matches!(ty.scalar_bits, 8 | 16) {
+        ops.push(AVG_ROUND);
+    }
+
+    if matches!(ty.scalar, ScalarType::Unsigned | ScalarType::Int)
+        && matches!(ty.scalar_bits, 8 | 16)
+    {
+        ops.push(Op::new(
+            "reduce_add_widened",
+            OpKind::AssociatedOnly,
+            OpSig::ReduceWidened,
+            "Sum the elements of `{arg0}`, returning the total as a 32-bit integer of the same signedness.\n\n\
+            Unlike adding up the elements in their own type, this can't overflow: even 64 elements at the limits of \
+            their range fit in the result.\n\n\
+            On Arm, this is a single `vaddlv` for each 128-bit block. On x86, 8-bit elements are summed in groups of \
+            eight with `psadbw` against zero, and 16-bit elements in pairs with `pmaddwd` against ones, before adding \
+            the partial sums together. Signed bytes and unsigned words have their sign bit flipped first, and the bias \
+            is removed from the result. WebAssembly uses pairwise widening additions.",
+        ));
     }
 
     if valid_reinterpret(ty, ScalarType::Unsigned, 8) {
@@ -1501,7 +2262,7 @@ pub(crate) fn ops_for_type(ty: &VecType) -> Vec<Op> {
                 target_ty: ScalarType::Unsigned,
                 scalar_bits: 8,
             },
-            "Reinterpret the bits of this vector as a vector of `u8` elements.\n\nThe total bit width is preserved; the number of elements changes accordingly.",
+            "Reinterpret the bits of this vector as a vector of `u8` elements.\n\nThe total bit width is preserved; the number of elements changes accordingly. If the element width changes, the result depends on the target's byte order.",
         ));
     }
 
@@ -1513,7 +2274,7 @@ pub(crate) fn ops_for_type(ty: &VecType) -> Vec<Op> {
                 target_ty: ScalarType::Unsigned,
                 scalar_bits: 32,
             },
-            "Reinterpret the bits of this vector as a vector of `u32` elements.\n\nThe total bit width is preserved; the number of elements changes accordingly.",
+            "Reinterpret the bits of this vector as a vector of `u32` elements.\n\nThe total bit width is preserved; the number of elements changes accordingly. If the element width changes, the result depends on the target's byte order.",
         ));
     }
 
@@ -1523,8 +2284,20 @@ pub(crate) fn ops_for_type(ty: &VecType) -> Vec<Op> {
             ops.push(F32_TO_U32_PRECISE);
             ops.push(F32_TO_I32);
             ops.push(F32_TO_I32_PRECISE);
+            if ty.len == 16 {
+                ops.push(F32_PACK_CLAMPED_U8);
+            }
+            ops.extend_from_slice(DOT_OPS);
         }
+        (ScalarType::Unsigned, 8) => {
+            ops.push(COMPRESS_BYTES);
+            ops.push(EXPAND_BYTES);
+            ops.push(TABLE_LOOKUP);
+            ops.push(GF_MUL_CONST);
+        }
+        (ScalarType::Unsigned, 16) if ty.len == 16 => ops.push(TRANSPOSE_BITS_16X16),
         (ScalarType::Unsigned, 32) => ops.push(U32_TO_F32),
+        (ScalarType::Unsigned, 64) => ops.push(TRANSPOSE_BITS_8X8),
         (ScalarType::Int, 32) => ops.push(I32_TO_F32),
         _ => (),
     }
@@ -1640,7 +2413,13 @@ impl OpSig {
             return false;
         }
 
-        if matches!(self, Self::ElementRotate { .. } | Self::ElementShift { .. }) {
+        if matches!(
+            self,
+            Self::ElementRotate { .. }
+                | Self::ElementShift { .. }
+                | Self::BitTranspose
+                | Self::GfMulConst
+        ) {
             return true;
         }
 
@@ -1655,6 +2434,8 @@ impl OpSig {
                 | Self::AsArray { .. }
                 | Self::StoreArray
                 | Self::MaskSet
+                | Self::MaskToInt
+                | Self::ExtractLane { dynamic: true }
                 | Self::Slide {
                     granularity: SlideGranularity::AcrossBlocks,
                     ..
@@ -1663,6 +2444,15 @@ impl OpSig {
             return false;
         }
 
+        // Lanes are moved in and out of single 128-bit blocks, so wider vectors are split down to the block holding
+        // the lane.
+        if matches!(
+            self,
+            Self::ExtractLane { dynamic: false } | Self::InsertLane
+        ) {
+            return vec_ty.n_bits() > 128;
+        }
+
         // For a block-wise item slide/shift, defer to the non-block-wise version if the operand is 1 block wide anyway
         if let Self::Slide {
             granularity: SlideGranularity::WithinBlocks,
@@ -1680,22 +2470,32 @@ impl OpSig {
         true
     }
 
-    fn simd_trait_arg_names(&self) -> &'static [&'static str] {
+    pub(crate) fn simd_trait_arg_names(&self) -> &'static [&'static str] {
         match self {
             Self::Splat | Self::FromArray { .. } => &["val"],
             Self::MaskFromBitmask => &["bits"],
             Self::MaskSet => &["a", "index", "value"],
+            Self::ExtractLane { dynamic: true } => &["a", "index"],
+            Self::InsertLane => &["a", "value"],
             Self::Unary
+            | Self::BitTranspose
+            | Self::GfMulConst
             | Self::Split { .. }
             | Self::Cvt { .. }
             | Self::Reinterpret { .. }
             | Self::WidenNarrow { .. }
             | Self::MaskReduce { .. }
+            | Self::ReduceWidened
             | Self::MaskToBitmask
+            | Self::MaskToInt
+            | Self::ExtractLane { dynamic: false }
             | Self::AsArray { .. }
             | Self::FromBytes
             | Self::ToBytes => &["a"],
             Self::SwizzleDynWithinBlocks => &["a", "indices"],
+            Self::ShiftSigned => &["a", "b"],
+            Self::Compress { .. } => &["a", "mask"],
+            Self::WidenAccumulate { .. } => &["acc", "a", "b"],
             Self::Binary
             | Self::Compare
             | Self::Combine { .. }
@@ -1720,17 +2520,27 @@ impl OpSig {
             | Self::FromArray { .. }
             | Self::MaskFromBitmask
             | Self::MaskToBitmask
+            | Self::MaskToInt
             | Self::MaskSet
             | Self::FromBytes { .. }
-            | Self::StoreArray => &[],
+            | Self::StoreArray
+            | Self::Compress { .. }
+            | Self::WidenAccumulate { .. }
+            | Self::ReduceWidened
+            | Self::GfMulConst => &[],
             Self::Unary
+            | Self::BitTranspose
             | Self::Cvt { .. }
             | Self::Reinterpret { .. }
             | Self::WidenNarrow { .. }
             | Self::MaskReduce { .. }
             | Self::AsArray { .. }
+            | Self::ExtractLane { dynamic: false }
             | Self::ToBytes => &["self"],
+            Self::ExtractLane { dynamic: true } => &["self", "index"],
+            Self::InsertLane => &["self", "value"],
             Self::SwizzleDynWithinBlocks => &["self", "indices"],
+            Self::ShiftSigned => &["self", "amounts"],
             Self::Binary
             | Self::Compare
             | Self::Zip { .. }
@@ -1762,6 +2572,7 @@ impl OpSig {
                 quote! { #arg0 }
             }
             Self::Binary
+            | Self::ShiftSigned
             | Self::Compare
             | Self::Combine { .. }
             | Self::Zip { .. }
@@ -1786,8 +2597,11 @@ impl OpSig {
             | Self::Shift
             | Self::ElementRotate { .. }
             | Self::ElementShift { .. }
+            | Self::BitTranspose
+            | Self::GfMulConst
             | Self::MaskFromBitmask
             | Self::MaskToBitmask
+            | Self::MaskToInt
             | Self::MaskSet
             | Self::LoadInterleaved { .. }
             | Self::StoreInterleaved { .. }
@@ -1797,6 +2611,11 @@ impl OpSig {
             | Self::FromBytes
             | Self::ToBytes
             | Self::SwizzleDynWithinBlocks
+            | Self::Compress { .. }
+            | Self::WidenAccumulate { .. }
+            | Self::ReduceWidened
+            | Self::ExtractLane { .. }
+            | Self::InsertLane
             | Self::Slide { .. } => return None,
         };
         Some(args)
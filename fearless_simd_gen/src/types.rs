@@ -128,7 +128,7 @@ impl VecType {
     pub(crate) fn widened(&self) -> Option<Self> {
         if matches!(self.scalar, ScalarType::Mask | ScalarType::Float)
             || self.n_bits() > 256
-            || self.scalar_bits != 8
+            || self.scalar_bits > 32
         {
             return None;
         }
@@ -186,10 +186,14 @@ impl VecType {
                 .map(|i| if i == 0 { "-1" } else { "0" })
                 .collect::<Vec<_>>()
                 .join(", ");
+            let bool_example = (0..self.len)
+                .map(|i| if i == 0 { "true" } else { "false" })
+                .collect::<Vec<_>>()
+                .join(", ");
             format!(
                 "A SIMD mask of {len} logical lanes corresponding to {scalar_bits}-bit vector elements.\n\n\
                 The storage representation of this type is intentionally opaque and may vary depending on the SIMD level.\n\n\
-                You can construct this mask type using the [`Self::splat`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n\
+                You can construct this mask type using the [`Self::splat`], [`Self::from_bools`], [`Self::from_fn`], [`Self::from_bitmask`], [`Self::from_slice`], and [`Self::simd_from`] methods.\n\n\
                 ```rust\n\
 # use fearless_simd::{{prelude::*, {rust_name}}};
 fn construct_mask<S: Simd>(simd: S) {{
@@ -197,6 +201,10 @@ fn construct_mask<S: Simd>(simd: S) {{
     let a = {rust_name}::splat(simd, true);
     let b = {rust_name}::simd_from(simd, true);
 
+    // From a boolean for each lane:
+    let g = {rust_name}::from_bools(simd, &[{bool_example}]);
+    let h = {rust_name}::from_fn(simd, |i| i == 0);
+
     // From signed integer mask lanes:
     let c = {rust_name}::from_slice(simd, &[{lane_example}]);
     let d = {rust_name}::simd_from(simd, [{lane_example}]);
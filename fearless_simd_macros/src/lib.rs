@@ -0,0 +1,229 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Procedural macros for `fearless_simd`.
+//!
+//! These are re-exported by `fearless_simd` when its `macros` feature is enabled,
+//! and should be used through that crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    Error, Expr, FnArg, GenericParam, ImplItem, ImplItemFn, ItemImpl, Pat, Type, TypeParamBound,
+    parse_macro_input, parse_quote,
+};
+
+/// Dispatch the SIMD methods of an `impl` block from a stored [`Level`].
+///
+/// Stateful processors, such as filters holding their coefficients, usually want to detect the
+/// SIMD level once when they are created, and then use it for every call.
+/// Applying `#[simd_impl]` to an `impl` block turns every method which is generic over
+/// `S: Simd` and takes an argument of type `S` into a dispatching method, so callers
+/// don't need to pass a token or call [`dispatch`] themselves.
+///
+/// For each such method, for example:
+///
+/// ```rust,ignore
+/// pub fn process<S: Simd>(&mut self, simd: S, samples: &mut [f32]) { /* ... */ }
+/// ```
+///
+/// the macro generates:
+///
+/// - `process_simd`, the original method with the same visibility, marked `#[inline(always)]`.
+///   This can be called directly from other SIMD code which already has a token, without
+///   dispatching again.
+/// - `process`, with the `S` parameter and the token argument removed, which dispatches on the
+///   stored level and calls `process_simd`.
+///
+/// Methods which aren't generic over a `Simd` type are left unchanged.
+///
+/// By default, the level is read from `self.level`. A different expression which evaluates to a
+/// [`Level`] can be passed as an argument, e.g. `#[simd_impl(self.config.level)]`.
+/// Because the level is read from `self`, every SIMD method must take `self` by reference or by value.
+/// Arguments other than `self` must be simple identifiers, so that they can be forwarded.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fearless_simd::{Level, Simd, prelude::*, simd_impl};
+///
+/// struct Gain {
+///     level: Level,
+///     gain: f32,
+/// }
+///
+/// #[simd_impl]
+/// impl Gain {
+///     pub fn new(gain: f32) -> Self {
+///         Self { level: Level::new(), gain }
+///     }
+///
+///     pub fn apply<S: Simd>(&self, simd: S, samples: &mut [f32]) {
+///         let mut chunks = samples.chunks_exact_mut(S::f32s::N);
+///         for chunk in &mut chunks {
+///             let v = S::f32s::from_slice(simd, chunk) * self.gain;
+///             v.store_slice(chunk);
+///         }
+///         for sample in chunks.into_remainder() {
+///             *sample *= self.gain;
+///         }
+///     }
+/// }
+///
+/// let mut samples = [1.0, 2.0, 3.0];
+/// Gain::new(0.5).apply(&mut samples);
+/// assert_eq!(samples, [0.5, 1.0, 1.5]);
+/// ```
+///
+/// [`Level`]: https://docs.rs/fearless_simd/latest/fearless_simd/enum.Level.html
+/// [`dispatch`]: https://docs.rs/fearless_simd/latest/fearless_simd/macro.dispatch.html
+#[proc_macro_attribute]
+pub fn simd_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let level: Expr = if attr.is_empty() {
+        parse_quote!(self.level)
+    } else {
+        parse_macro_input!(attr as Expr)
+    };
+    let mut item_impl = parse_macro_input!(item as ItemImpl);
+
+    let mut items = Vec::with_capacity(item_impl.items.len());
+    for item in core::mem::take(&mut item_impl.items) {
+        let ImplItem::Fn(method) = item else {
+            items.push(item);
+            continue;
+        };
+        match simd_method(method, &level) {
+            Ok(generated) => items.extend(generated),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    }
+    item_impl.items = items;
+
+    quote! { #item_impl }.into()
+}
+
+/// Split a method generic over `S: Simd` into the `_simd` method and its dispatching wrapper.
+///
+/// Methods which aren't generic over a `Simd` type are returned unchanged.
+fn simd_method(method: ImplItemFn, level: &Expr) -> syn::Result<Vec<ImplItem>> {
+    let Some((param_idx, simd_ty)) = simd_type_param(&method) else {
+        return Ok(vec![ImplItem::Fn(method)]);
+    };
+
+    let sig = &method.sig;
+    if sig.receiver().is_none() {
+        return Err(Error::new_spanned(
+            sig,
+            "`#[simd_impl]` methods must take `self`, so that the level can be read from it",
+        ));
+    }
+
+    let simd = syn::Ident::new("__fearless_simd_token", Span::mixed_site());
+    let mut token_arg = None;
+    let mut call_args = vec![];
+    for (idx, input) in sig.inputs.iter().enumerate() {
+        let FnArg::Typed(arg) = input else {
+            continue;
+        };
+        let Pat::Ident(pat) = &*arg.pat else {
+            return Err(Error::new_spanned(
+                &arg.pat,
+                "`#[simd_impl]` method arguments must be identifiers, so that they can be forwarded",
+            ));
+        };
+        if token_arg.is_none() && is_type(&arg.ty, &simd_ty) {
+            token_arg = Some(idx);
+            call_args.push(simd.clone());
+        } else {
+            call_args.push(pat.ident.clone());
+        }
+    }
+    let Some(token_arg) = token_arg else {
+        return Err(Error::new_spanned(
+            sig,
+            format!("`#[simd_impl]` methods must take the `{simd_ty}` token as an argument"),
+        ));
+    };
+
+    let name = &sig.ident;
+    let simd_name = format_ident!("{name}_simd");
+
+    let mut simd_fn = method.clone();
+    simd_fn.sig.ident = simd_name.clone();
+    simd_fn.attrs.retain(|attr| !attr.path().is_ident("inline"));
+    simd_fn.attrs.push(parse_quote!(#[inline(always)]));
+
+    let mut dispatch_sig = sig.clone();
+    dispatch_sig.generics.params = dispatch_sig
+        .generics
+        .params
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, param)| (idx != param_idx).then_some(param))
+        .collect();
+    dispatch_sig.inputs = dispatch_sig
+        .inputs
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, input)| (idx != token_arg).then_some(input))
+        .collect();
+    if let Some(where_clause) = &dispatch_sig.generics.where_clause
+        && quote!(#where_clause)
+            .into_iter()
+            .any(|token| matches!(token, TokenTree::Ident(ident) if ident == simd_ty))
+    {
+        return Err(Error::new_spanned(
+            where_clause,
+            format!("`#[simd_impl]` doesn't support `where` clauses mentioning `{simd_ty}`"),
+        ));
+    }
+
+    let attrs = method
+        .attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("inline"));
+    let vis = &method.vis;
+    let defaultness = &method.defaultness;
+    let dispatch_fn: ImplItemFn = parse_quote! {
+        #(#attrs)*
+        #vis #defaultness #dispatch_sig {
+            ::fearless_simd::dispatch!(#level, #simd => self.#simd_name(#(#call_args),*))
+        }
+    };
+
+    Ok(vec![ImplItem::Fn(dispatch_fn), ImplItem::Fn(simd_fn)])
+}
+
+/// Find the generic type parameter bounded by `Simd`, returning its index and name.
+fn simd_type_param(method: &ImplItemFn) -> Option<(usize, syn::Ident)> {
+    method
+        .sig
+        .generics
+        .params
+        .iter()
+        .enumerate()
+        .find_map(|(idx, param)| {
+            let GenericParam::Type(ty) = param else {
+                return None;
+            };
+            ty.bounds
+                .iter()
+                .any(|bound| match bound {
+                    TypeParamBound::Trait(bound) => bound
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "Simd"),
+                    _ => false,
+                })
+                .then(|| (idx, ty.ident.clone()))
+        })
+}
+
+fn is_type(ty: &Type, ident: &syn::Ident) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident(ident),
+        _ => false,
+    }
+}
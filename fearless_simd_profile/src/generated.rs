@@ -0,0 +1,7580 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This file is autogenerated by fearless_simd_gen
+
+use crate::Bench;
+use core::hint::black_box;
+use fearless_simd::*;
+#[doc = r" Measure every lane-wise op on `simd`."]
+#[doc = r""]
+#[doc = r" The arguments are all one, which keeps the values of chains of ops away from subnormals, which are slow on"]
+#[doc = r" some CPUs. They're passed through `black_box`, so that the ops can't be evaluated at compile time."]
+pub(crate) fn profile_ops<S: Simd>(simd: S, bench: &mut Bench) {
+    {
+        let a = f32x4::splat(simd, black_box(1.0));
+        let b = f32x4::splat(simd, black_box(1.0));
+        let c = f32x4::splat(simd, black_box(1.0));
+        bench.chain(
+            simd,
+            "abs_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::abs_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "sqrt_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sqrt_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "approximate_recip_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::approximate_recip_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "add_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::add_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "div_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::div_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "copysign_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::copysign_f32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_eq_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_f32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_f32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_f32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_f32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_f32x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_f32x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_precise_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_precise_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_precise_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_precise_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_add_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_f32x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_sub_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_sub_f32x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_add_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_add_f32x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_sub_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_sub_f32x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_add_relaxed_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_relaxed_f32x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "floor_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::floor_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "ceil_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::ceil_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "round_ties_even_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::round_ties_even_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "fract_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::fract_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trunc_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::trunc_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_f64_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_f64_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_i32_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_i32_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_u32_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_u32_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_u32_precise_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_u32_precise_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_i32_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_i32_f32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_i32_precise_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_i32_precise_f32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "dot2_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::dot2_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "dot3_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::dot3_f32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "dot4_f32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::dot4_f32x4(simd, a, b),
+        );
+    }
+    {
+        let a = i8x16::splat(simd, black_box(1));
+        let b = i8x16::splat(simd, black_box(1));
+        let shifts = i8x16::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i8x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i8x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i8x16(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i8x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i8x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i8x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_i8x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i8x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i8x16(simd, a),
+        );
+    }
+    {
+        let a = u8x16::splat(simd, black_box(1));
+        let b = u8x16::splat(simd, black_box(1));
+        let shifts = i8x16::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u8x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u8x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u8x16(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u8x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u8x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u8x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u8x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_u8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "avg_round_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::avg_round_u8x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u8x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "table_lookup_u8x16",
+            a,
+            #[inline(always)]
+            |a| Simd::table_lookup_u8x16(simd, a, b),
+        );
+    }
+    {
+        let a = i16x8::splat(simd, black_box(1));
+        let b = i16x8::splat(simd, black_box(1));
+        let shifts = i16x8::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i16x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i16x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i16x8(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i16x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i16x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i16x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_i16x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i16x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i16x8(simd, a),
+        );
+    }
+    {
+        let a = u16x8::splat(simd, black_box(1));
+        let b = u16x8::splat(simd, black_box(1));
+        let shifts = i16x8::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u16x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u16x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u16x8(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u16x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u16x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u16x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u16x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_u16x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "avg_round_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::avg_round_u16x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u16x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u16x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u16x8(simd, a),
+        );
+    }
+    {
+        let a = i32x4::splat(simd, black_box(1));
+        let b = i32x4::splat(simd, black_box(1));
+        let shifts = i32x4::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i32x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i32x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i32x4(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i32x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i32x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_i32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_f32_i32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_f32_i32x4(simd, a),
+        );
+    }
+    {
+        let a = u32x4::splat(simd, black_box(1));
+        let b = u32x4::splat(simd, black_box(1));
+        let shifts = i32x4::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u32x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u32x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u32x4(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u32x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u32x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u32x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u32x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u32x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_u32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u32x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_f32_u32x4",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_f32_u32x4(simd, a),
+        );
+    }
+    {
+        let a = f64x2::splat(simd, black_box(1.0));
+        let b = f64x2::splat(simd, black_box(1.0));
+        let c = f64x2::splat(simd, black_box(1.0));
+        bench.chain(
+            simd,
+            "abs_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::abs_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "sqrt_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::sqrt_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "approximate_recip_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::approximate_recip_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "add_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::add_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "div_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::div_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "copysign_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::copysign_f64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_eq_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_f64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_f64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_f64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_f64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_f64x2",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_f64x2",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::max_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::min_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_precise_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::max_precise_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_precise_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::min_precise_f64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_add_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_f64x2(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_sub_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_sub_f64x2(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_add_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_add_f64x2(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_sub_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_sub_f64x2(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_add_relaxed_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_relaxed_f64x2(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "floor_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::floor_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "ceil_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::ceil_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "round_ties_even_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::round_ties_even_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "fract_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::fract_f64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trunc_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::trunc_f64x2(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_f32_f64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_f32_f64x2(simd, a),
+        );
+    }
+    {
+        let a = i64x2::splat(simd, black_box(1));
+        let b = i64x2::splat(simd, black_box(1));
+        let shifts = i64x2::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i64x2(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i64x2(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i64x2(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i64x2",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i64x2",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i64x2(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i64x2(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i64x2(simd, a),
+        );
+    }
+    {
+        let a = u64x2::splat(simd, black_box(1));
+        let b = u64x2::splat(simd, black_box(1));
+        let shifts = i64x2::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u64x2(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u64x2(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u64x2(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u64x2(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u64x2",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u64x2",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u64x2(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u64x2(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u64x2(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u64x2(simd, a),
+        );
+        bench.chain(
+            simd,
+            "transpose_bits_8x8_u64x2",
+            a,
+            #[inline(always)]
+            |a| Simd::transpose_bits_8x8_u64x2(simd, a),
+        );
+    }
+    {
+        let a = f32x8::splat(simd, black_box(1.0));
+        let b = f32x8::splat(simd, black_box(1.0));
+        let c = f32x8::splat(simd, black_box(1.0));
+        bench.chain(
+            simd,
+            "abs_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::abs_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "sqrt_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sqrt_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "approximate_recip_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::approximate_recip_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "add_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "div_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::div_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "copysign_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::copysign_f32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_eq_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_f32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_f32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_f32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_f32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_f32x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_f32x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_precise_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_precise_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_precise_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_precise_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_add_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_f32x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_sub_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_sub_f32x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_add_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_add_f32x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_sub_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_sub_f32x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_add_relaxed_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_relaxed_f32x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "floor_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::floor_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "ceil_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::ceil_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "round_ties_even_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::round_ties_even_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "fract_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::fract_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trunc_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trunc_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_f64_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_f64_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_i32_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_i32_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_u32_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_u32_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_u32_precise_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_u32_precise_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_i32_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_i32_f32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_i32_precise_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_i32_precise_f32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "dot2_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::dot2_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "dot3_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::dot3_f32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "dot4_f32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::dot4_f32x8(simd, a, b),
+        );
+    }
+    {
+        let a = i8x32::splat(simd, black_box(1));
+        let b = i8x32::splat(simd, black_box(1));
+        let shifts = i8x32::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i8x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i8x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i8x32(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i8x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i8x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i8x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_i8x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i8x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i8x32(simd, a),
+        );
+    }
+    {
+        let a = u8x32::splat(simd, black_box(1));
+        let b = u8x32::splat(simd, black_box(1));
+        let shifts = i8x32::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u8x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u8x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u8x32(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u8x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u8x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u8x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u8x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_u8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "avg_round_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::avg_round_u8x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u8x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "table_lookup_u8x32",
+            a,
+            #[inline(always)]
+            |a| Simd::table_lookup_u8x32(simd, a, b),
+        );
+    }
+    {
+        let a = i16x16::splat(simd, black_box(1));
+        let b = i16x16::splat(simd, black_box(1));
+        let shifts = i16x16::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i16x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i16x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i16x16(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i16x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i16x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_i16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_i8_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_i8_i16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_u8_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_u8_i16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i16x16(simd, a),
+        );
+    }
+    {
+        let a = u16x16::splat(simd, black_box(1));
+        let b = u16x16::splat(simd, black_box(1));
+        let shifts = i16x16::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u16x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u16x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u16x16(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u16x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u16x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u16x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_u16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "narrow_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::narrow_u16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_i8_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_i8_u16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_u8_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_u8_u16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "avg_round_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::avg_round_u16x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u16x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u16x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "transpose_bits_16x16_u16x16",
+            a,
+            #[inline(always)]
+            |a| Simd::transpose_bits_16x16_u16x16(simd, a),
+        );
+    }
+    {
+        let a = i32x8::splat(simd, black_box(1));
+        let b = i32x8::splat(simd, black_box(1));
+        let shifts = i32x8::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i32x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i32x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i32x8(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i32x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i32x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_i32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_f32_i32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_f32_i32x8(simd, a),
+        );
+    }
+    {
+        let a = u32x8::splat(simd, black_box(1));
+        let b = u32x8::splat(simd, black_box(1));
+        let shifts = i32x8::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u32x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u32x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u32x8(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u32x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u32x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u32x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u32x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u32x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "widen_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::widen_u32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u32x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_f32_u32x8",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_f32_u32x8(simd, a),
+        );
+    }
+    {
+        let a = f64x4::splat(simd, black_box(1.0));
+        let b = f64x4::splat(simd, black_box(1.0));
+        let c = f64x4::splat(simd, black_box(1.0));
+        bench.chain(
+            simd,
+            "abs_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::abs_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "sqrt_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sqrt_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "approximate_recip_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::approximate_recip_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "add_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::add_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "div_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::div_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "copysign_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::copysign_f64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_eq_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_f64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_f64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_f64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_f64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_f64x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_f64x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_precise_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_precise_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_precise_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_precise_f64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_add_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_f64x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_sub_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_sub_f64x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_add_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_add_f64x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_sub_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_sub_f64x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_add_relaxed_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_relaxed_f64x4(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "floor_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::floor_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "ceil_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::ceil_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "round_ties_even_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::round_ties_even_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "fract_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::fract_f64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trunc_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::trunc_f64x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_f32_f64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_f32_f64x4(simd, a),
+        );
+    }
+    {
+        let a = i64x4::splat(simd, black_box(1));
+        let b = i64x4::splat(simd, black_box(1));
+        let shifts = i64x4::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i64x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i64x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i64x4(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i64x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i64x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i64x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i64x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i64x4(simd, a),
+        );
+    }
+    {
+        let a = u64x4::splat(simd, black_box(1));
+        let b = u64x4::splat(simd, black_box(1));
+        let shifts = i64x4::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u64x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u64x4(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u64x4(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u64x4(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u64x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u64x4",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u64x4(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u64x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u64x4(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u64x4(simd, a),
+        );
+        bench.chain(
+            simd,
+            "transpose_bits_8x8_u64x4",
+            a,
+            #[inline(always)]
+            |a| Simd::transpose_bits_8x8_u64x4(simd, a),
+        );
+    }
+    {
+        let a = f32x16::splat(simd, black_box(1.0));
+        let b = f32x16::splat(simd, black_box(1.0));
+        let c = f32x16::splat(simd, black_box(1.0));
+        bench.chain(
+            simd,
+            "abs_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::abs_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "sqrt_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sqrt_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "approximate_recip_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::approximate_recip_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "add_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "div_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::div_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "copysign_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::copysign_f32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_eq_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_f32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_f32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_f32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_f32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_f32x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_f32x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_precise_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_precise_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_precise_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_precise_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_add_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_f32x16(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_sub_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_sub_f32x16(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_add_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_add_f32x16(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_sub_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_sub_f32x16(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_add_relaxed_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_relaxed_f32x16(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "floor_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::floor_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "ceil_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::ceil_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "round_ties_even_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::round_ties_even_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "fract_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::fract_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trunc_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trunc_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_f64_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_f64_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_i32_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_i32_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_u32_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_u32_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_u32_precise_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_u32_precise_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_i32_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_i32_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_i32_precise_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_i32_precise_f32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_clamped_u8_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_clamped_u8_f32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "dot2_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::dot2_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "dot3_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::dot3_f32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "dot4_f32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::dot4_f32x16(simd, a, b),
+        );
+    }
+    {
+        let a = i8x64::splat(simd, black_box(1));
+        let b = i8x64::splat(simd, black_box(1));
+        let shifts = i8x64::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i8x64(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i8x64(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i8x64(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i8x64",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i8x64",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i8x64(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i8x64(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i8x64(simd, a),
+        );
+    }
+    {
+        let a = u8x64::splat(simd, black_box(1));
+        let b = u8x64::splat(simd, black_box(1));
+        let shifts = i8x64::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u8x64(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u8x64(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u8x64(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u8x64",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u8x64",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u8x64(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "avg_round_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::avg_round_u8x64(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u8x64(simd, a),
+        );
+        bench.chain(
+            simd,
+            "table_lookup_u8x64",
+            a,
+            #[inline(always)]
+            |a| Simd::table_lookup_u8x64(simd, a, b),
+        );
+    }
+    {
+        let a = i16x32::splat(simd, black_box(1));
+        let b = i16x32::splat(simd, black_box(1));
+        let shifts = i16x32::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i16x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i16x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i16x32(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i16x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i16x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_i8_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_i8_i16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_u8_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_u8_i16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i16x32(simd, a),
+        );
+    }
+    {
+        let a = u16x32::splat(simd, black_box(1));
+        let b = u16x32::splat(simd, black_box(1));
+        let shifts = i16x32::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u16x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u16x32(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u16x32(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u16x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u16x32",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u16x32(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "narrow_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::narrow_u16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_i8_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_i8_u16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "pack_saturate_u8_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::pack_saturate_u8_u16x32(simd, a),
+        );
+        bench.chain(
+            simd,
+            "avg_round_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::avg_round_u16x32(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u16x32(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u16x32",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u16x32(simd, a),
+        );
+    }
+    {
+        let a = i32x16::splat(simd, black_box(1));
+        let b = i32x16::splat(simd, black_box(1));
+        let shifts = i32x16::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i32x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i32x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i32x16(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i32x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i32x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_f32_i32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_f32_i32x16(simd, a),
+        );
+    }
+    {
+        let a = u32x16::splat(simd, black_box(1));
+        let b = u32x16::splat(simd, black_box(1));
+        let shifts = i32x16::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u32x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u32x16(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u32x16(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u32x16(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u32x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u32x16",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u32x16(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u32x16(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u32x16(simd, a),
+        );
+        bench.map(
+            simd,
+            "cvt_f32_u32x16",
+            a,
+            #[inline(always)]
+            |a| Simd::cvt_f32_u32x16(simd, a),
+        );
+    }
+    {
+        let a = f64x8::splat(simd, black_box(1.0));
+        let b = f64x8::splat(simd, black_box(1.0));
+        let c = f64x8::splat(simd, black_box(1.0));
+        bench.chain(
+            simd,
+            "abs_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::abs_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "sqrt_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sqrt_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "approximate_recip_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::approximate_recip_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "add_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "div_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::div_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "copysign_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::copysign_f64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_eq_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_f64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_f64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_f64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_f64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_f64x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_f64x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_precise_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_precise_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_precise_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_precise_f64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_add_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_f64x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_sub_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_sub_f64x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_add_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_add_f64x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "nmul_sub_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::nmul_sub_f64x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "mul_add_relaxed_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_add_relaxed_f64x8(simd, a, b, c),
+        );
+        bench.chain(
+            simd,
+            "floor_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::floor_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "ceil_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::ceil_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "round_ties_even_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::round_ties_even_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "fract_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::fract_f64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trunc_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trunc_f64x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_f32_f64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_f32_f64x8(simd, a),
+        );
+    }
+    {
+        let a = i64x8::splat(simd, black_box(1));
+        let b = i64x8::splat(simd, black_box(1));
+        let shifts = i64x8::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::and_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::or_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::not_i64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_i64x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_i64x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_i64x8(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_i64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_i64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_i64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_i64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_i64x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_i64x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_i64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_i64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_i64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_i64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "neg_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::neg_i64x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_i64x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_i64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_i64x8(simd, a),
+        );
+    }
+    {
+        let a = u64x8::splat(simd, black_box(1));
+        let b = u64x8::splat(simd, black_box(1));
+        let shifts = i64x8::splat(simd, black_box(1));
+        let shift = black_box(1);
+        bench.chain(
+            simd,
+            "add_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::add_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "sub_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::sub_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "mul_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::mul_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "and_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::and_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "or_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::or_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "xor_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::xor_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "not_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::not_u64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "shl_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shl_u64x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shlv_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shlv_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shr_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shr_u64x8(simd, a, shift),
+        );
+        bench.chain(
+            simd,
+            "shrv_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shrv_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "shift_signed_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::shift_signed_u64x8(simd, a, shifts),
+        );
+        bench.map(
+            simd,
+            "simd_eq_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_eq_u64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_lt_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_lt_u64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_le_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_le_u64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_ge_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_ge_u64x8(simd, a, b),
+        );
+        bench.map(
+            simd,
+            "simd_gt_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::simd_gt_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_low_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_low_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "zip_high_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::zip_high_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_low_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_low_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "unzip_high_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::unzip_high_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "interleave_u64x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::interleave_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "deinterleave_u64x8",
+            (a, b),
+            #[inline(always)]
+            |(a, b)| Simd::deinterleave_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "min_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::min_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "max_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::max_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_add_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_add_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "saturating_sub_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::saturating_sub_u64x8(simd, a, b),
+        );
+        bench.chain(
+            simd,
+            "count_ones_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::count_ones_u64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "leading_zeros_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::leading_zeros_u64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "trailing_zeros_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::trailing_zeros_u64x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u8_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u8_u64x8(simd, a),
+        );
+        bench.map(
+            simd,
+            "reinterpret_u32_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::reinterpret_u32_u64x8(simd, a),
+        );
+        bench.chain(
+            simd,
+            "transpose_bits_8x8_u64x8",
+            a,
+            #[inline(always)]
+            |a| Simd::transpose_bits_8x8_u64x8(simd, a),
+        );
+    }
+}
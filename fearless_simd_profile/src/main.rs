@@ -0,0 +1,329 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measure the latency and throughput of each lane-wise op of Fearless SIMD, on every level which the current
+//! machine supports, and print them as a table.
+//!
+//! This is meant for choosing between ops on your own hardware, such as `cvt_u32` and `cvt_u32_precise`, rather
+//! than as an exact measurement: the times include some loop overhead, and vary between runs.
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::time::Instant;
+
+use clap::Parser;
+use fearless_simd::{Fallback, Level, Simd};
+
+mod generated;
+
+#[derive(Parser)]
+#[command(
+    name = "fearless_simd_profile",
+    about = "Measure the latency and throughput of each Fearless SIMD op on this machine",
+    long_about = "Measure the latency and throughput of each lane-wise Fearless SIMD op, on every level which \
+                  this machine supports, and print them as a table.\n\
+                  \n\
+                  Each cell is the latency, then the throughput, in nanoseconds per op. The latency is the time \
+                  from an op's inputs to its result, measured by feeding each result into the next op. The \
+                  throughput is the time per op when many independent ops are in flight. Ops which return a \
+                  different type than their input, such as comparisons and conversions, only have a throughput, \
+                  which includes moving the result to memory. Ops which the compiler can see through when \
+                  they're repeated, such as taking the absolute value of an absolute value, show as zero."
+)]
+struct Cli {
+    #[arg(
+        short,
+        long,
+        help = "Only measure the ops whose method name contains this, like `f32x8`"
+    )]
+    filter: Option<String>,
+    #[arg(
+        short,
+        long,
+        default_value_t = 20_000,
+        help = "The number of times each op is run in each measurement"
+    )]
+    iterations: u32,
+}
+
+/// The number of independent chains of ops which are run for the throughput.
+const CHAINS: usize = 8;
+
+/// The number of times each measurement is repeated, of which the fastest is reported.
+const REPEATS: usize = 3;
+
+/// The latency and throughput of an op on a level, in nanoseconds per op.
+#[derive(Clone, Copy, Debug)]
+struct Timing {
+    latency: Option<f64>,
+    throughput: f64,
+}
+
+/// Runs the measurements, and collects their results for each level.
+#[derive(Debug)]
+pub(crate) struct Bench {
+    filter: Option<String>,
+    iterations: u32,
+    /// The index of the level being measured, in `levels`.
+    level: usize,
+    levels: Vec<&'static str>,
+    timings: BTreeMap<&'static str, Vec<Option<Timing>>>,
+}
+
+impl Bench {
+    /// Measure the latency and throughput of `op`, by feeding its result back in, starting from `init`.
+    // Each op is measured by a separate function, so that the compiler doesn't optimize all of them as one.
+    #[inline(never)]
+    pub(crate) fn chain<S: Simd, V: Copy>(
+        &mut self,
+        simd: S,
+        method: &'static str,
+        init: V,
+        op: impl Fn(V) -> V,
+    ) {
+        if !self.includes(method) {
+            return;
+        }
+        let iterations = self.iterations;
+        let (latency, throughput) = simd.vectorize(
+            #[inline(always)]
+            || {
+                (
+                    latency(iterations, init, &op),
+                    throughput(iterations, init, &op),
+                )
+            },
+        );
+        self.record(
+            method,
+            Timing {
+                latency: Some(latency),
+                throughput,
+            },
+        );
+    }
+
+    /// Measure the throughput of `op` on `input`, for ops whose result can't be fed back in.
+    #[inline(never)]
+    pub(crate) fn map<S: Simd, V: Copy, R>(
+        &mut self,
+        simd: S,
+        method: &'static str,
+        input: V,
+        op: impl Fn(V) -> R,
+    ) {
+        if !self.includes(method) {
+            return;
+        }
+        let iterations = self.iterations;
+        let throughput = simd.vectorize(
+            #[inline(always)]
+            || map_throughput(iterations, input, &op),
+        );
+        self.record(
+            method,
+            Timing {
+                latency: None,
+                throughput,
+            },
+        );
+    }
+
+    fn includes(&self, method: &str) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| method.contains(filter.as_str()))
+    }
+
+    fn record(&mut self, method: &'static str, timing: Timing) {
+        let timings = self
+            .timings
+            .entry(method)
+            .or_insert_with(|| vec![None; self.levels.len()]);
+        timings[self.level] = Some(timing);
+    }
+}
+
+// The measurement loops and the closures which call them are `#[inline(always)]`, as in `dispatch!`, so that they
+// and `op` are inlined into `vectorize`, where the target features of the level are enabled. Otherwise, each op
+// would be a function call.
+
+/// The latency of `op` in nanoseconds, as the fastest of [`REPEATS`] runs of a chain of `iterations` ops.
+#[inline(always)]
+fn latency<V: Copy>(iterations: u32, init: V, op: &impl Fn(V) -> V) -> f64 {
+    let mut fastest = f64::INFINITY;
+    for _ in 0..REPEATS {
+        let start = Instant::now();
+        let mut value = black_box(init);
+        for _ in 0..iterations {
+            value = op(value);
+        }
+        black_box(value);
+        fastest = fastest.min(start.elapsed().as_secs_f64());
+    }
+    fastest * 1e9 / f64::from(iterations)
+}
+
+/// The throughput of `op` in nanoseconds, as the fastest of [`REPEATS`] runs of [`CHAINS`] independent chains of
+/// `iterations` ops.
+#[inline(always)]
+fn throughput<V: Copy>(iterations: u32, init: V, op: &impl Fn(V) -> V) -> f64 {
+    let mut fastest = f64::INFINITY;
+    for _ in 0..REPEATS {
+        let start = Instant::now();
+        // Each chain starts from a value the compiler doesn't know is the same, so that it can't compute them once.
+        let mut values = [init; CHAINS];
+        for value in &mut values {
+            *value = black_box(*value);
+        }
+        for _ in 0..iterations {
+            for value in &mut values {
+                *value = op(*value);
+            }
+        }
+        black_box(values);
+        fastest = fastest.min(start.elapsed().as_secs_f64());
+    }
+    fastest * 1e9 / (f64::from(iterations) * CHAINS as f64)
+}
+
+/// The throughput of `op` in nanoseconds, like [`throughput`], for an op whose result can't be fed back in.
+#[inline(always)]
+fn map_throughput<V: Copy, R>(iterations: u32, input: V, op: &impl Fn(V) -> R) -> f64 {
+    let mut fastest = f64::INFINITY;
+    for _ in 0..REPEATS {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            for _ in 0..CHAINS {
+                // Hiding the input stops the op from being moved out of the loop.
+                black_box(op(black_box(input)));
+            }
+        }
+        fastest = fastest.min(start.elapsed().as_secs_f64());
+    }
+    fastest * 1e9 / (f64::from(iterations) * CHAINS as f64)
+}
+
+/// Call `f` with every level this machine supports, from the fallback level to the best one.
+fn for_each_level(best: Level, mut f: impl FnMut(&'static str, Level)) {
+    f("Fallback", Level::Fallback(Fallback::new()));
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    if let Some(neon) = best.as_neon() {
+        f("Neon", Level::Neon(neon));
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if let Some(wasm) = best.as_wasm_simd128() {
+        f("WasmSimd128", Level::WasmSimd128(wasm));
+    }
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if let Some(sse2) = best.as_sse2() {
+            f("Sse2", Level::Sse2(sse2));
+        }
+        if let Some(sse4_2) = best.as_sse4_2() {
+            f("Sse4_2", Level::Sse4_2(sse4_2));
+        }
+        if let Some(avx2) = best.as_avx2() {
+            f("Avx2", Level::Avx2(avx2));
+        }
+        if let Some(avx512) = best.as_avx512() {
+            f("Avx512", Level::Avx512(avx512));
+        }
+    }
+    // Silence the unused variable warning on targets without SIMD levels.
+    let _ = best;
+}
+
+/// Measure every op on `level`.
+fn profile_level(level: Level, bench: &mut Bench) {
+    // The tokens are passed directly rather than through `dispatch`, which would normalize the weaker levels to the
+    // best one which is compiled in.
+    #[allow(
+        unreachable_patterns,
+        reason = "`Level` is non-exhaustive, and some targets only have the fallback level"
+    )]
+    match level {
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        Level::Neon(neon) => generated::profile_ops(neon, bench),
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        Level::WasmSimd128(wasm) => generated::profile_ops(wasm, bench),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Level::Sse2(sse2) => generated::profile_ops(sse2, bench),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Level::Sse4_2(sse4_2) => generated::profile_ops(sse4_2, bench),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Level::Avx2(avx2) => generated::profile_ops(avx2, bench),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Level::Avx512(avx512) => generated::profile_ops(avx512, bench),
+        _ => generated::profile_ops(Fallback::new(), bench),
+    }
+}
+
+fn format_timing(timing: Option<Timing>) -> String {
+    let Some(timing) = timing else {
+        return String::new();
+    };
+    let latency = timing
+        .latency
+        .map_or_else(|| "-".to_string(), |latency| format!("{latency:.2}"));
+    format!("{latency} / {:.2}", timing.throughput)
+}
+
+#[expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "a command line tool"
+)]
+fn main() {
+    let cli = Cli::parse();
+    let mut levels = vec![];
+    for_each_level(Level::new(), |name, level| levels.push((name, level)));
+
+    let mut bench = Bench {
+        filter: cli.filter,
+        iterations: cli.iterations.max(1),
+        level: 0,
+        levels: levels.iter().map(|(name, _)| *name).collect(),
+        timings: BTreeMap::new(),
+    };
+    for (i, (name, level)) in levels.iter().enumerate() {
+        eprintln!("Measuring {name}");
+        bench.level = i;
+        profile_level(*level, &mut bench);
+    }
+
+    let method_width = bench.timings.keys().map(|method| method.len()).max();
+    let Some(method_width) = method_width else {
+        eprintln!("No op matches the filter");
+        return;
+    };
+    let rows = bench
+        .timings
+        .iter()
+        .map(|(method, timings)| {
+            let cells = timings.iter().map(|timing| format_timing(*timing));
+            (*method, cells.collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    let cell_width = rows
+        .iter()
+        .flat_map(|(_, cells)| cells.iter().map(String::len))
+        .chain(bench.levels.iter().map(|name| name.len()))
+        .max()
+        .unwrap_or_default();
+
+    println!("Latency / throughput, in nanoseconds per op");
+    println!();
+    print!("{:method_width$}", "op");
+    for name in &bench.levels {
+        print!("  {name:>cell_width$}");
+    }
+    println!();
+    for (method, cells) in rows {
+        print!("{method:method_width$}");
+        for cell in cells {
+            print!("  {cell:>cell_width$}");
+        }
+        println!();
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of the alignment, padding and growth of `AlignedVec`.
+
+use fearless_simd::aligned::{AlignedVec, NativeAlignedVec};
+use fearless_simd::{Simd, SimdBase, SimdMask, f32x16, u8x16};
+use fearless_simd_dev_macros::simd_test;
+
+#[test]
+fn aligned_and_padded_while_growing() {
+    let mut vec = NativeAlignedVec::<u16>::new();
+    assert_eq!(vec.padded().len(), 0, "empty");
+    for i in 0..100 {
+        vec.push(i);
+        assert_eq!(vec.as_ptr().addr() % 64, 0, "aligned at length {}", i + 1);
+        assert_eq!(vec.padded().len(), (i as usize + 1).next_multiple_of(32));
+        assert!(
+            vec.padded()[vec.len()..].iter().all(|&x| x == 0),
+            "zero padding at length {}",
+            i + 1
+        );
+    }
+    assert!(vec.iter().copied().eq(0..100), "elements are kept");
+}
+
+#[test]
+fn removed_elements_are_zeroed() {
+    let mut vec = AlignedVec::<f32, 16>::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    vec.padded_mut()[6] = 9.0;
+    assert_eq!(vec.pop(), Some(5.0), "last element");
+    vec.truncate(2);
+    assert_eq!(*vec, [1.0, 2.0], "truncated");
+    assert_eq!(vec.padded(), [1.0, 2.0, 0.0, 0.0], "padding is zeroed");
+    vec.resize(7, 8.0);
+    assert_eq!(
+        vec.padded(),
+        [1.0, 2.0, 8.0, 8.0, 8.0, 8.0, 8.0, 0.0],
+        "resized"
+    );
+    vec.clear();
+    assert!(vec.is_empty(), "cleared");
+    assert_eq!(vec.pop(), None, "empty");
+}
+
+#[test]
+fn collects_and_clones() {
+    let vec: AlignedVec<i32, 32> = (0..10).collect();
+    let copy = vec.clone();
+    assert_eq!(copy, vec, "clone");
+    assert_eq!(
+        format!("{copy:?}"),
+        format!("{:?}", (0..10).collect::<Vec<_>>()),
+        "formatted like a slice"
+    );
+    assert_eq!(AlignedVec::<u8, 8>::zeroed(3).padded(), [0; 8], "zeroed");
+}
+
+#[simd_test]
+fn for_each_vector_masks_padding<S: Simd>(simd: S) {
+    let vec = NativeAlignedVec::from_slice(&[1_u8; 20]);
+    let mut counts = vec![];
+    vec.for_each_vector(simd, |v: u8x16<S>, valid| {
+        let ones = v.as_slice().iter().filter(|&&x| x == 1).count();
+        counts.push((ones, valid.count_true()));
+    });
+    assert_eq!(counts, [(16, 16), (4, 4)], "valid lanes");
+
+    // Vectors which are wider than the alignment are padded with zeros past the allocation.
+    let vec = AlignedVec::<f32, 16>::from_slice(&[1.0; 20]);
+    let mut sums = vec![];
+    vec.for_each_vector(simd, |v: f32x16<S>, valid| {
+        sums.push((v.as_slice().iter().sum::<f32>(), valid.count_true()));
+    });
+    assert_eq!(sums, [(16.0, 16), (4.0, 4)], "wide vectors");
+}
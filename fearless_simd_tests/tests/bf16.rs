@@ -0,0 +1,73 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that `bf16x8` conversions round correctly, and give the same result on every level.
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// The value of a finite `bfloat16`, or of the next power of two past the largest one for an infinity, so that
+/// rounding to it can be compared against the finite candidates.
+fn bf16_value(bits: u16) -> f64 {
+    let value = f32::from_bits(u32::from(bits) << 16);
+    if value.is_infinite() {
+        2_f64.powi(128).copysign(f64::from(value))
+    } else {
+        f64::from(value)
+    }
+}
+
+/// Round an `f32` to the nearest `bfloat16` by comparing it with the values on either side.
+fn round_to_bf16(x: f32) -> u16 {
+    let bits = x.to_bits();
+    if x.is_nan() {
+        return ((bits >> 16) as u16) | 0x0040;
+    }
+    let below = (bits >> 16) as u16;
+    if bits & 0xFFFF == 0 {
+        return below;
+    }
+    let above = below + 1;
+    let (x, low, high) = (f64::from(x), bf16_value(below), bf16_value(above));
+    let (to_low, to_high) = ((x - low).abs(), (high - x).abs());
+    if to_low < to_high || (to_low == to_high && below.is_multiple_of(2)) {
+        below
+    } else {
+        above
+    }
+}
+
+#[simd_test]
+fn bf16_from_f32_rounds_to_nearest<S: Simd>(simd: S) {
+    // Walk through the `f32` bit patterns with a stride which visits every exponent, with a variety of discarded bits.
+    for start in (0..u32::MAX - 8 * 0x0001_2345).step_by(8 * 0x0001_2345) {
+        let values: [f32; 8] = core::array::from_fn(|i| {
+            f32::from_bits(start + u32::try_from(i).unwrap() * 0x0001_2345)
+        });
+        let result = bf16x8::from_f32(f32x8::from_slice(simd, &values));
+        let expected = values.map(round_to_bf16);
+        assert_eq!(*result.to_bits(), expected, "rounding {values:?}");
+    }
+}
+
+#[simd_test]
+fn bf16_special_values<S: Simd>(simd: S) {
+    let values = [
+        f32::from_bits(0x0000_8000),
+        f32::from_bits(0x0001_8000),
+        f32::MIN_POSITIVE,
+        -f32::MAX,
+        f32::INFINITY,
+        f32::NAN,
+        f32::from_bits(0xFF80_0001),
+        1.0 + f32::EPSILON,
+    ];
+    let result = bf16x8::from_f32(f32x8::from_slice(simd, &values));
+    assert_eq!(
+        *result.to_bits(),
+        [
+            0x0000, 0x0002, 0x0080, 0xFF80, 0x7F80, 0x7FC0, 0xFFC0, 0x3F80
+        ],
+        "subnormals, overflow and NaNs should be rounded on every level"
+    );
+}
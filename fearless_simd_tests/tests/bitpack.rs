@@ -0,0 +1,103 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that bit packing matches a bit-at-a-time reference for every width.
+
+use fearless_simd::bitpack::{pack_bits, pack_bits_slice, unpack_bits, unpack_bits_slice};
+use fearless_simd::{Simd, SimdBase, u16x8};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::bytes;
+
+/// Pseudo-random values, including bits above the width.
+fn values(len: usize, seed: u32) -> Vec<u16> {
+    bytes(2 * len, seed)
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+fn reference_unpack(bytes: &[u8], width: usize, len: usize) -> Vec<u16> {
+    (0..len)
+        .map(|i| {
+            (0..width).fold(0, |value, bit| {
+                let n = i * width + bit;
+                value | u16::from(bytes[n / 8] >> (n % 8) & 1) << bit
+            })
+        })
+        .collect()
+}
+
+fn reference_pack(values: &[u16], width: usize) -> Vec<u8> {
+    let mut bytes = vec![0; (values.len() * width).div_ceil(8)];
+    for (i, &value) in values.iter().enumerate() {
+        for bit in 0..width {
+            let n = i * width + bit;
+            bytes[n / 8] |= u8::from(value >> bit & 1 == 1) << (n % 8);
+        }
+    }
+    bytes
+}
+
+fn check_width<S: Simd, const WIDTH: usize>(simd: S) {
+    for seed in 0..4 {
+        let packed: [u8; WIDTH] = bytes(WIDTH, seed).try_into().unwrap();
+        let unpacked = unpack_bits::<S, WIDTH>(simd, &packed);
+        assert_eq!(
+            unpacked.as_slice(),
+            reference_unpack(&packed, WIDTH, 8),
+            "unpacking {WIDTH} bits should match the reference"
+        );
+        assert_eq!(
+            pack_bits::<S, WIDTH>(unpacked),
+            packed,
+            "{WIDTH} bits should round trip"
+        );
+
+        let values = values(8, seed);
+        assert_eq!(
+            pack_bits::<S, WIDTH>(u16x8::from_slice(simd, &values)).as_slice(),
+            reference_pack(&values, WIDTH),
+            "packing {WIDTH} bits should match the reference and ignore the higher bits"
+        );
+    }
+
+    // Whole groups of 8 values, and partial groups at the end.
+    for len in 0..=27 {
+        let values = values(len, 5);
+        let mut packed = vec![0xaa; (len * WIDTH).div_ceil(8)];
+        pack_bits_slice::<S, WIDTH>(simd, &values, &mut packed);
+        assert_eq!(
+            packed,
+            reference_pack(&values, WIDTH),
+            "packing {len} values of {WIDTH} bits should match the reference"
+        );
+        let mut unpacked = vec![0; len];
+        unpack_bits_slice::<S, WIDTH>(simd, &packed, &mut unpacked);
+        assert_eq!(
+            unpacked,
+            reference_unpack(&packed, WIDTH, len),
+            "unpacking {len} values of {WIDTH} bits should match the reference"
+        );
+    }
+}
+
+#[simd_test]
+fn bitpack_matches_reference<S: Simd>(simd: S) {
+    check_width::<S, 1>(simd);
+    check_width::<S, 2>(simd);
+    check_width::<S, 3>(simd);
+    check_width::<S, 4>(simd);
+    check_width::<S, 5>(simd);
+    check_width::<S, 6>(simd);
+    check_width::<S, 7>(simd);
+    check_width::<S, 8>(simd);
+    check_width::<S, 9>(simd);
+    check_width::<S, 10>(simd);
+    check_width::<S, 11>(simd);
+    check_width::<S, 12>(simd);
+    check_width::<S, 13>(simd);
+    check_width::<S, 14>(simd);
+    check_width::<S, 15>(simd);
+    check_width::<S, 16>(simd);
+}
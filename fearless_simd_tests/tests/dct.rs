@@ -0,0 +1,248 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that the JPEG DCTs give exactly the results of libjpeg's `islow` transforms on every level.
+
+use fearless_simd::Simd;
+use fearless_simd::dct::{forward_dct_8x8, inverse_dct_8x8};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::bytes;
+
+/// Blocks of pseudo-random samples, and flat, striped and checkered blocks at the extremes.
+fn sample_blocks() -> Vec<[u8; 64]> {
+    let mut blocks: Vec<[u8; 64]> = bytes(64 * 32, 1)
+        .chunks_exact(64)
+        .map(|block| block.try_into().unwrap())
+        .collect();
+    blocks.push([0; 64]);
+    blocks.push([255; 64]);
+    blocks.push(core::array::from_fn(|i| if i % 2 == 0 { 0 } else { 255 }));
+    blocks.push(core::array::from_fn(|i| {
+        if (i / 8 + i % 8) % 2 == 0 { 255 } else { 0 }
+    }));
+    blocks
+}
+
+/// Blocks of coefficients of the size which a JPEG decoder sees after dequantization, and single coefficients.
+fn coefficient_blocks() -> Vec<[i16; 64]> {
+    let mut blocks: Vec<[i16; 64]> = bytes(64 * 64, 2)
+        .chunks_exact(128)
+        .enumerate()
+        .map(|(n, block)| {
+            // Small coefficients for the first blocks, and the largest which 8-bit JPEG can encode for the rest.
+            let range = if n < 16 { 64 } else { 1024 };
+            core::array::from_fn(|i| {
+                let value = i16::from_le_bytes([block[2 * i], block[2 * i + 1]]);
+                value % range
+            })
+        })
+        .collect();
+    for position in 0..64 {
+        for value in [-1023, 1023] {
+            let mut block = [0; 64];
+            block[position] = value;
+            blocks.push(block);
+        }
+    }
+    blocks
+}
+
+const CONST_BITS: u32 = 13;
+const PASS1_BITS: u32 = 2;
+
+fn descale(x: i32, n: u32) -> i32 {
+    x.wrapping_add(1 << (n - 1)) >> n
+}
+
+/// A scalar port of libjpeg's `jpeg_fdct_islow`, including the level shift of `jcdctmgr.c`.
+fn reference_fdct(samples: &[u8; 64]) -> [i16; 64] {
+    let mut data: [i32; 64] = core::array::from_fn(|i| i32::from(samples[i]) - 128);
+    for pass in 0..2 {
+        let first = pass == 0;
+        let shift = if first {
+            CONST_BITS - PASS1_BITS
+        } else {
+            CONST_BITS + PASS1_BITS
+        };
+        for line in 0..8 {
+            // Rows in the first pass, and columns in the second.
+            let index = |k: usize| if first { line * 8 + k } else { k * 8 + line };
+            let d: [i32; 8] = core::array::from_fn(|k| data[index(k)]);
+            let tmp0 = d[0] + d[7];
+            let tmp7 = d[0] - d[7];
+            let tmp1 = d[1] + d[6];
+            let tmp6 = d[1] - d[6];
+            let tmp2 = d[2] + d[5];
+            let tmp5 = d[2] - d[5];
+            let tmp3 = d[3] + d[4];
+            let tmp4 = d[3] - d[4];
+
+            let tmp10 = tmp0 + tmp3;
+            let tmp13 = tmp0 - tmp3;
+            let tmp11 = tmp1 + tmp2;
+            let tmp12 = tmp1 - tmp2;
+            let mut out = [0; 8];
+            if first {
+                out[0] = (tmp10 + tmp11) << PASS1_BITS;
+                out[4] = (tmp10 - tmp11) << PASS1_BITS;
+            } else {
+                out[0] = descale(tmp10 + tmp11, PASS1_BITS);
+                out[4] = descale(tmp10 - tmp11, PASS1_BITS);
+            }
+            let z1 = (tmp12 + tmp13) * 4433;
+            out[2] = descale(z1 + tmp13 * 6270, shift);
+            out[6] = descale(z1 + tmp12 * -15137, shift);
+
+            let z1 = tmp4 + tmp7;
+            let z2 = tmp5 + tmp6;
+            let z3 = tmp4 + tmp6;
+            let z4 = tmp5 + tmp7;
+            let z5 = (z3 + z4) * 9633;
+            let z1 = z1 * -7373;
+            let z2 = z2 * -20995;
+            let z3 = z3 * -16069 + z5;
+            let z4 = z4 * -3196 + z5;
+            out[7] = descale(tmp4 * 2446 + z1 + z3, shift);
+            out[5] = descale(tmp5 * 16819 + z2 + z4, shift);
+            out[3] = descale(tmp6 * 25172 + z2 + z3, shift);
+            out[1] = descale(tmp7 * 12299 + z1 + z4, shift);
+            for (k, out) in out.into_iter().enumerate() {
+                data[index(k)] = out;
+            }
+        }
+    }
+    data.map(|x| i16::try_from(x).unwrap())
+}
+
+/// A scalar port of libjpeg's `jpeg_idct_islow`, with the results clamped to a `u8` and wrapping arithmetic.
+fn reference_idct(coeffs: &[i16; 64]) -> [u8; 64] {
+    let mut data: [i32; 64] = coeffs.map(i32::from);
+    for pass in 0..2 {
+        let first = pass == 0;
+        let shift = if first {
+            CONST_BITS - PASS1_BITS
+        } else {
+            CONST_BITS + PASS1_BITS + 3
+        };
+        for line in 0..8 {
+            // Columns in the first pass, and rows in the second.
+            let index = |k: usize| if first { k * 8 + line } else { line * 8 + k };
+            let d: [i32; 8] = core::array::from_fn(|k| data[index(k)]);
+            let z1 = (d[2].wrapping_add(d[6])).wrapping_mul(4433);
+            let tmp2 = z1.wrapping_add(d[6].wrapping_mul(-15137));
+            let tmp3 = z1.wrapping_add(d[2].wrapping_mul(6270));
+            let tmp0 = d[0].wrapping_add(d[4]).wrapping_shl(CONST_BITS);
+            let tmp1 = d[0].wrapping_sub(d[4]).wrapping_shl(CONST_BITS);
+            let tmp10 = tmp0.wrapping_add(tmp3);
+            let tmp13 = tmp0.wrapping_sub(tmp3);
+            let tmp11 = tmp1.wrapping_add(tmp2);
+            let tmp12 = tmp1.wrapping_sub(tmp2);
+
+            let (tmp0, tmp1, tmp2, tmp3) = (d[7], d[5], d[3], d[1]);
+            let z5 = (tmp0
+                .wrapping_add(tmp2)
+                .wrapping_add(tmp1)
+                .wrapping_add(tmp3))
+            .wrapping_mul(9633);
+            let z1 = tmp0.wrapping_add(tmp3).wrapping_mul(-7373);
+            let z2 = tmp1.wrapping_add(tmp2).wrapping_mul(-20995);
+            let z3 = tmp0
+                .wrapping_add(tmp2)
+                .wrapping_mul(-16069)
+                .wrapping_add(z5);
+            let z4 = tmp1.wrapping_add(tmp3).wrapping_mul(-3196).wrapping_add(z5);
+            let tmp0 = tmp0.wrapping_mul(2446).wrapping_add(z1).wrapping_add(z3);
+            let tmp1 = tmp1.wrapping_mul(16819).wrapping_add(z2).wrapping_add(z4);
+            let tmp2 = tmp2.wrapping_mul(25172).wrapping_add(z2).wrapping_add(z3);
+            let tmp3 = tmp3.wrapping_mul(12299).wrapping_add(z1).wrapping_add(z4);
+
+            let out = [
+                tmp10.wrapping_add(tmp3),
+                tmp11.wrapping_add(tmp2),
+                tmp12.wrapping_add(tmp1),
+                tmp13.wrapping_add(tmp0),
+                tmp13.wrapping_sub(tmp0),
+                tmp12.wrapping_sub(tmp1),
+                tmp11.wrapping_sub(tmp2),
+                tmp10.wrapping_sub(tmp3),
+            ];
+            for (k, out) in out.into_iter().enumerate() {
+                data[index(k)] = descale(out, shift);
+            }
+        }
+    }
+    data.map(|x| u8::try_from(x.wrapping_add(128).clamp(0, 255)).unwrap())
+}
+
+#[simd_test]
+fn forward_dct_matches_libjpeg<S: Simd>(simd: S) {
+    for (n, block) in sample_blocks().iter().enumerate() {
+        assert_eq!(
+            forward_dct_8x8(simd, block),
+            reference_fdct(block),
+            "block {n} should match libjpeg"
+        );
+    }
+}
+
+#[simd_test]
+fn inverse_dct_matches_libjpeg<S: Simd>(simd: S) {
+    for (n, block) in coefficient_blocks().iter().enumerate() {
+        assert_eq!(
+            inverse_dct_8x8(simd, block),
+            reference_idct(block),
+            "block {n} should match libjpeg"
+        );
+    }
+}
+
+#[simd_test]
+fn inverse_dct_wraps_like_libjpeg<S: Simd>(simd: S) {
+    // Far outside the range of JPEG, where the intermediate sums overflow.
+    let blocks: [[i16; 64]; 2] = [
+        [i16::MAX; 64],
+        core::array::from_fn(|i| if i % 3 == 0 { i16::MIN } else { i16::MAX }),
+    ];
+    for (n, block) in blocks.iter().enumerate() {
+        assert_eq!(
+            inverse_dct_8x8(simd, block),
+            reference_idct(block),
+            "block {n} should match libjpeg"
+        );
+    }
+}
+
+#[simd_test]
+fn dct_round_trips<S: Simd>(simd: S) {
+    for (n, block) in sample_blocks().iter().enumerate() {
+        // Remove the extra factor of 8 of the forward transform, rounding to the nearest.
+        let coeffs = forward_dct_8x8(simd, block).map(|c| (c + 4).div_euclid(8));
+        let samples = inverse_dct_8x8(simd, &coeffs);
+        for (i, (&before, &after)) in block.iter().zip(&samples).enumerate() {
+            assert!(
+                before.abs_diff(after) <= 1,
+                "sample {i} of block {n} should round trip within 1, not {before} to {after}"
+            );
+        }
+    }
+}
+
+#[simd_test]
+fn dct_of_single_frequency<S: Simd>(simd: S) {
+    // The lowest horizontal frequency, as a JPEG coefficient of 100 would give it.
+    let mut coeffs = [0; 64];
+    coeffs[1] = 100;
+    let samples = inverse_dct_8x8(simd, &coeffs);
+    // Within a half of 128 + 100 / (4 * sqrt(2)) * cos((2x + 1)π / 16).
+    let expected = [145, 143, 138, 131, 125, 118, 113, 111];
+    for row in samples.chunks_exact(8) {
+        assert_eq!(row, expected, "every row should be the same cosine");
+    }
+    let back = forward_dct_8x8(simd, &samples);
+    assert!(
+        (back[1] - 800).abs() <= 8,
+        "the coefficient should come back as 8 * 100, not {}",
+        back[1]
+    );
+}
@@ -0,0 +1,95 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of the scaling of fixed-point decimals by powers of ten.
+
+use fearless_simd::decimal::{
+    div_pow10, div_pow10_round, mul_pow10, mul_pow10_overflows, mul_pow10_saturating,
+};
+use fearless_simd::{Simd, SimdBase, SimdMask, i64x8};
+use fearless_simd_dev_macros::simd_test;
+
+/// Values at the limits of the range, at the edges of overflow for small powers, and halfway between multiples.
+const VALUES: [[i64; 8]; 3] = [
+    [0, 1, -1, 5, -5, 15, -25, 149],
+    [
+        i64::MAX,
+        i64::MIN,
+        i64::MAX / 10,
+        i64::MIN / 10,
+        i64::MAX / 10 + 1,
+        i64::MIN / 10 - 1,
+        922,
+        -9_223,
+    ],
+    [
+        123_456_789_012_345_678,
+        -987_654_321_098_765_432,
+        5_000_000_000,
+        -5_000_000_000,
+        4_999_999_999,
+        -15_000_000_000,
+        i64::MAX / 100,
+        i64::MIN / 100 - 1,
+    ],
+];
+
+/// Divides by `divisor`, rounding halves away from zero, with 128-bit arithmetic which can't overflow.
+fn div_round_reference(a: i64, divisor: i64) -> i64 {
+    let (a, divisor) = (i128::from(a), i128::from(divisor));
+    let rounded = (a.abs() * 2 + divisor) / (divisor * 2);
+    i64::try_from(rounded * a.signum()).unwrap()
+}
+
+fn check_pow10<const K: u32, S: Simd>(simd: S) {
+    let divisor = 10_i64.pow(K);
+    for values in VALUES {
+        let a = i64x8::from_slice(simd, &values);
+        let overflows = values.map(|x| x.checked_mul(divisor).is_none());
+        assert_eq!(
+            *mul_pow10::<K, _, _>(a),
+            values.map(|x| x.wrapping_mul(divisor)),
+            "wrapping product by 10^{K}"
+        );
+        assert_eq!(
+            *mul_pow10_saturating::<K, _, _>(a),
+            values.map(|x| x.saturating_mul(divisor)),
+            "saturating product by 10^{K}"
+        );
+        let mask = mul_pow10_overflows::<K, _, _>(a).to_bitmask();
+        assert_eq!(
+            core::array::from_fn(|i| mask & (1 << i) != 0),
+            overflows,
+            "overflowing products by 10^{K}"
+        );
+        assert_eq!(
+            *div_pow10::<K, _, _>(a),
+            values.map(|x| x / divisor),
+            "truncated quotient by 10^{K}"
+        );
+        assert_eq!(
+            *div_pow10_round::<K, _, _>(a),
+            values.map(|x| div_round_reference(x, divisor)),
+            "rounded quotient by 10^{K}"
+        );
+    }
+}
+
+#[simd_test]
+fn scaling_matches_scalar<S: Simd>(simd: S) {
+    check_pow10::<0, S>(simd);
+    check_pow10::<1, S>(simd);
+    check_pow10::<2, S>(simd);
+    check_pow10::<9, S>(simd);
+    check_pow10::<18, S>(simd);
+}
+
+#[simd_test]
+fn rounds_halves_away_from_zero<S: Simd>(simd: S) {
+    let cents = i64x8::from_slice(simd, &[50, 150, 249, 250, -50, -150, -249, -250]);
+    assert_eq!(
+        *div_pow10_round::<2, _, _>(cents),
+        [1, 2, 2, 3, -1, -2, -2, -3],
+        "halves of a unit"
+    );
+}
@@ -0,0 +1,166 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that delta and delta-of-delta encoding match a sequential loop, and that decoding reverses them.
+
+use fearless_simd::Simd;
+use fearless_simd::delta::{
+    Previous, decode_delta_of_delta_i32, decode_delta_of_delta_i64, decode_delta_of_delta_u32,
+    decode_delta_of_delta_u64, decode_i32, decode_i64, decode_u32, decode_u64,
+    encode_delta_of_delta_i32, encode_delta_of_delta_i64, encode_delta_of_delta_u32,
+    encode_delta_of_delta_u64, encode_i32, encode_i64, encode_u32, encode_u64,
+};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::bytes;
+
+/// Checks the functions for one element type against sequential loops, for every length of a random series, whose
+/// differences often overflow, and of a slowly changing one.
+macro_rules! check_element {
+    ($simd:expr, $t:ty, $encode:ident, $decode:ident, $encode_dod:ident, $decode_dod:ident) => {{
+        let simd = $simd;
+        let size = size_of::<$t>();
+        let random: Vec<$t> = bytes(70 * size, 1)
+            .chunks_exact(size)
+            .map(|bytes| <$t>::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        let slow: Vec<$t> = (0..70).map(|i: $t| i * 1_000 + i % 3).collect();
+        let previous = random[0];
+        let state = Previous {
+            value: random[1],
+            delta: random[2],
+        };
+        for series in [&random, &slow] {
+            for len in 0..=series.len() {
+                let values = &series[..len];
+
+                let mut expected = values.to_vec();
+                let mut last = previous;
+                for value in &mut expected {
+                    (*value, last) = (value.wrapping_sub(last), *value);
+                }
+                let mut actual = values.to_vec();
+                let returned = $encode(simd, &mut actual, previous);
+                assert_eq!(
+                    actual,
+                    expected,
+                    "{} of {len} elements",
+                    stringify!($encode)
+                );
+                assert_eq!(
+                    returned,
+                    last,
+                    "{} should return the last element",
+                    stringify!($encode)
+                );
+                let returned = $decode(simd, &mut actual, previous);
+                assert_eq!(actual, values, "{} of {len} elements", stringify!($decode));
+                assert_eq!(
+                    returned,
+                    last,
+                    "{} should return the last element",
+                    stringify!($decode)
+                );
+
+                let mut expected = values.to_vec();
+                let mut last = state;
+                for value in &mut expected {
+                    let delta = value.wrapping_sub(last.value);
+                    let delta_of_delta = delta.wrapping_sub(last.delta);
+                    last = Previous {
+                        value: *value,
+                        delta,
+                    };
+                    *value = delta_of_delta;
+                }
+                let mut actual = values.to_vec();
+                let returned = $encode_dod(simd, &mut actual, state);
+                assert_eq!(
+                    actual,
+                    expected,
+                    "{} of {len} elements",
+                    stringify!($encode_dod)
+                );
+                assert_eq!(
+                    returned,
+                    last,
+                    "{} should return the last state",
+                    stringify!($encode_dod)
+                );
+                let returned = $decode_dod(simd, &mut actual, state);
+                assert_eq!(
+                    actual,
+                    values,
+                    "{} of {len} elements",
+                    stringify!($decode_dod)
+                );
+                assert_eq!(
+                    returned,
+                    last,
+                    "{} should return the last state",
+                    stringify!($decode_dod)
+                );
+            }
+        }
+    }};
+}
+
+#[simd_test]
+fn delta_matches_reference<S: Simd>(simd: S) {
+    check_element!(
+        simd,
+        u32,
+        encode_u32,
+        decode_u32,
+        encode_delta_of_delta_u32,
+        decode_delta_of_delta_u32
+    );
+    check_element!(
+        simd,
+        i32,
+        encode_i32,
+        decode_i32,
+        encode_delta_of_delta_i32,
+        decode_delta_of_delta_i32
+    );
+    check_element!(
+        simd,
+        u64,
+        encode_u64,
+        decode_u64,
+        encode_delta_of_delta_u64,
+        decode_delta_of_delta_u64
+    );
+    check_element!(
+        simd,
+        i64,
+        encode_i64,
+        decode_i64,
+        encode_delta_of_delta_i64,
+        decode_delta_of_delta_i64
+    );
+}
+
+#[simd_test]
+fn delta_in_parts<S: Simd>(simd: S) {
+    let timestamps: Vec<i64> = (0..100).map(|i| 1_700_000_000 + 15 * i + i % 7).collect();
+    let mut whole = timestamps.clone();
+    encode_delta_of_delta_i64(simd, &mut whole, Previous::default());
+
+    // Each part starts from the state which the part before it returned.
+    let mut parts = timestamps.clone();
+    let mut state = Previous::default();
+    for part in parts.chunks_mut(13) {
+        state = encode_delta_of_delta_i64(simd, part, state);
+    }
+    assert_eq!(parts, whole, "encoding in parts should match");
+    let mut state = Previous::default();
+    for part in parts.chunks_mut(9) {
+        state = decode_delta_of_delta_i64(simd, part, state);
+    }
+    assert_eq!(parts, timestamps, "decoding in parts should round trip");
+    assert!(
+        whole[2..].iter().all(|dod| dod.abs() <= 7),
+        "the deltas of deltas of nearly regular timestamps should be small"
+    );
+}
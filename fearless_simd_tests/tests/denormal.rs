@@ -0,0 +1,93 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that subnormals are flushed to zero inside `with_flush_to_zero`, and only there.
+
+use core::hint::black_box;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use fearless_simd::{Fallback, Simd, SimdBase, SimdFlushToZero, f32x4};
+use fearless_simd_dev_macros::simd_test;
+
+/// Halve the smallest normal float, which gives a subnormal unless it's flushed.
+fn halve_min_positive<S: Simd>(simd: S) -> f32x4<S> {
+    f32x4::splat(simd, black_box(f32::MIN_POSITIVE)) * black_box(0.5)
+}
+
+#[simd_test]
+fn flushes_inside_closure<S: Simd>(simd: S) {
+    let subnormal = f32::MIN_POSITIVE / 2.0;
+    assert_eq!(
+        *halve_min_positive(simd),
+        [subnormal; 4],
+        "not flushed before"
+    );
+    let inside = simd.with_flush_to_zero(|| *halve_min_positive(simd));
+    let expected = if simd.supports_flush_to_zero() {
+        0.0
+    } else {
+        subnormal
+    };
+    assert_eq!(inside, [expected; 4], "flushed inside when supported");
+    assert_eq!(
+        *halve_min_positive(simd),
+        [subnormal; 4],
+        "not flushed after"
+    );
+}
+
+#[simd_test]
+fn nested_calls_restore<S: Simd>(simd: S) {
+    let inner = simd.with_flush_to_zero(|| {
+        simd.with_flush_to_zero(|| ());
+        // The inner call restores the mode of the outer call, rather than the default.
+        *halve_min_positive(simd)
+    });
+    assert_eq!(
+        inner[0] == 0.0,
+        simd.supports_flush_to_zero(),
+        "the outer call's mode should still apply"
+    );
+}
+
+#[simd_test]
+fn panics_restore<S: Simd>(simd: S) {
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        simd.with_flush_to_zero(|| panic!("expected panic"));
+    }));
+    assert!(result.is_err(), "the closure should panic");
+    assert_eq!(
+        *halve_min_positive(simd),
+        [f32::MIN_POSITIVE / 2.0; 4],
+        "the mode should be restored by the panic"
+    );
+}
+
+#[test]
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn supported_on_every_cpu() {
+    let level = fearless_simd::Level::new();
+    assert!(
+        fearless_simd::dispatch!(level, simd => simd.supports_flush_to_zero()),
+        "every CPU of these targets can flush subnormals"
+    );
+}
+
+/// The fallback token's `Level` is the baseline level, so this checks that its support depends on its type.
+#[test]
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn fallback_supported_unless_soft_float() {
+    let simd = Fallback::new();
+    assert_eq!(
+        simd.supports_flush_to_zero(),
+        !Fallback::SOFT_FLOAT,
+        "supported unless floats are computed with integers"
+    );
+    // Scalar float operations use the float unit, so they show whether the mode was changed.
+    let inside = simd.with_flush_to_zero(|| black_box(f32::MIN_POSITIVE) * black_box(0.5));
+    assert_eq!(
+        inside == 0.0,
+        !Fallback::SOFT_FLOAT,
+        "the mode is only changed if supported"
+    );
+}
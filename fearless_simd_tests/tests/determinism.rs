@@ -0,0 +1,129 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that the `BitExact` tier gives the same results on every level, and that the other tiers agree with it
+//! away from edge cases.
+
+use fearless_simd::determinism::{BitExact, DeterminismLevel, Fast, UlpBounded};
+use fearless_simd::{
+    Bytes, Fallback, Select, Simd, SimdBase, SimdFloat, SimdMask, f32x8, i32x8, mask32x8, u32x8,
+};
+use fearless_simd_dev_macros::simd_test;
+
+const A: [f32; 8] = [
+    1.0,
+    -0.0,
+    f32::NAN,
+    3.5,
+    f32::INFINITY,
+    -7.25,
+    1e10,
+    f32::NAN,
+];
+const B: [f32; 8] = [2.0, 0.0, 1.0, f32::NAN, 4.0, -7.25, -1e10, f32::NAN];
+const C: [f32; 8] = [0.5, -1.0, 2.0, 0.1, -3.0, 1e-3, 1.0, 0.0];
+
+/// The results of every method of `D` on `A`, `B` and `C`, as bits.
+fn results<D: DeterminismLevel, S: Simd>(simd: S) -> Vec<[u32; 8]> {
+    simd.vectorize(
+        #[inline(always)]
+        || {
+            let [a, b, c] = [A, B, C].map(|v| f32x8::from_slice(simd, &v));
+            let ints: i32x8<S> = D::to_int(a);
+            let uints: u32x8<S> = D::to_uint(a);
+            let mut results = vec![*ints.bitcast::<u32x8<S>>(), *uints];
+            for float in [D::mul_add(a, b, c), D::min(a, b), D::max(a, b), D::recip(c)] {
+                results.push(*float.bitcast::<u32x8<S>>());
+            }
+            results
+        },
+    )
+}
+
+#[simd_test]
+fn bit_exact_matches_fallback<S: Simd>(simd: S) {
+    assert_eq!(
+        results::<BitExact, _>(simd),
+        results::<BitExact, _>(Fallback::new()),
+        "results differ from the fallback level"
+    );
+}
+
+#[simd_test]
+fn bit_exact_edge_cases<S: Simd>(simd: S) {
+    let [a, b] = [A, B].map(|v| f32x8::from_slice(simd, &v));
+    let min = BitExact::min(a, b);
+    let max = BitExact::max(a, b);
+    assert_eq!([min[0], max[0]], [1.0, 2.0], "ordered operands");
+    assert!(
+        min[1].is_sign_positive() && max[1].is_sign_positive(),
+        "ties go to `b`"
+    );
+    assert_eq!([min[2], min[3]], [1.0, 3.5], "NaN is ignored");
+    assert_eq!([max[2], max[3]], [1.0, 3.5], "NaN is ignored");
+    assert!(min[7].is_nan() && max[7].is_nan(), "both NaN");
+    let ints: i32x8<S> = BitExact::to_int(a);
+    assert_eq!(ints[2], 0, "NaN converts to zero");
+    assert_eq!(ints[4], i32::MAX, "saturating");
+    assert_eq!(ints[6], i32::MAX, "saturating");
+    let uints = BitExact::to_uint(a);
+    assert_eq!(uints[5], 0, "saturating");
+}
+
+#[simd_test]
+fn tiers_agree_away_from_edge_cases<S: Simd>(simd: S) {
+    let a = f32x8::from_slice(simd, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let b = f32x8::from_slice(simd, &[0.5, 4.0, 2.0, 8.0, 0.25, 6.0, 1.0, 2.0]);
+    let exact = BitExact::mul_add(a, b, a);
+    assert_eq!(*UlpBounded::mul_add(a, b, a), *exact, "mul_add");
+    assert_eq!(*Fast::mul_add(a, b, a), *exact, "mul_add");
+    assert_eq!(*UlpBounded::min(a, b), *BitExact::min(a, b), "min");
+    assert_eq!(*Fast::max(a, b), *BitExact::max(a, b), "max");
+    let ints: i32x8<S> = Fast::to_int(a);
+    let exact_ints: i32x8<S> = BitExact::to_int(a);
+    assert_eq!(*ints, *exact_ints, "to_int");
+    assert_eq!(*UlpBounded::recip(b), *BitExact::recip(b), "recip");
+    let relative_error = (Fast::recip(b) * b - 1.0).abs();
+    assert!(
+        relative_error.simd_le(1.0 / 256.0).all_true(),
+        "approximate recip"
+    );
+}
+
+/// Tests that only `Fast` uses the relaxed WebAssembly instructions, so that `BitExact`, `UlpBounded` and the default
+/// ops give the same bits whether or not the `relaxed-simd` feature is enabled.
+///
+/// As in the tests of `mul_add`, `a * a + c` is `e * e` if it's fused, and `0` otherwise. The expected results only
+/// depend on [`Simd::FUSED_MUL_ADD`], which is `false` for WebAssembly with and without the feature.
+#[simd_test]
+fn only_fast_uses_relaxed_ops<S: Simd>(simd: S) {
+    let e = 1.0 / 8192.0;
+    let a = f32x8::splat(simd, 1.0 + e);
+    let c = f32x8::splat(simd, -(1.0 + 2.0 * e));
+    let (fused, unfused) = ([e * e; 8], [0.0; 8]);
+    let expected = if S::FUSED_MUL_ADD { fused } else { unfused };
+    assert_eq!(*BitExact::mul_add(a, a, c), unfused, "BitExact");
+    assert_eq!(*UlpBounded::mul_add(a, a, c), expected, "UlpBounded");
+    assert_eq!(*a.mul_add(a, c), expected, "mul_add");
+    let fast = Fast::mul_add(a, a, c);
+    assert!(*fast == fused || *fast == unfused, "Fast: {:?}", *fast);
+
+    let mask = mask32x8::from_bitmask(simd, 0b1001_0110);
+    let (if_true, if_false) = (a, c);
+    let selected = *mask.select(if_true, if_false);
+    assert_eq!(
+        *BitExact::select(mask, if_true, if_false),
+        selected,
+        "BitExact select"
+    );
+    assert_eq!(
+        *UlpBounded::select(mask, if_true, if_false),
+        selected,
+        "UlpBounded select"
+    );
+    assert_eq!(
+        *Fast::select(mask, if_true, if_false),
+        selected,
+        "Fast select"
+    );
+}
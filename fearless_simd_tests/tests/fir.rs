@@ -0,0 +1,82 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that `FirFilter` matches a direct convolution, however the input is split into blocks.
+
+use fearless_simd::Simd;
+use fearless_simd::fir::FirFilter;
+use fearless_simd_dev_macros::simd_test;
+
+/// Small integers, so that every sum of products is exact whatever order it's computed in.
+fn signal(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| f32::from(u8::try_from((i * 37 + 11) % 23).unwrap()) - 11.0)
+        .collect()
+}
+
+fn convolve(coeffs: &[f32], input: &[f32]) -> Vec<f32> {
+    (0..input.len())
+        .map(|n| {
+            coeffs
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| k <= n)
+                .map(|(k, c)| c * input[n - k])
+                .sum()
+        })
+        .collect()
+}
+
+fn check_taps<S: Simd, const TAPS: usize>(simd: S) {
+    let coeffs: [f32; TAPS] = core::array::from_fn(|k| [1.0, -2.0, 0.5, 3.0, -0.25][k % 5]);
+    let input = signal(100);
+    let expected = convolve(&coeffs, &input);
+    // Blocks which are shorter than, equal to, and longer than both the vectors and the filter.
+    for sizes in [
+        &[100][..],
+        &[1, 2, 3, 94],
+        &[0, 8, 16, 7, 0, 69],
+        &[13, 25, 30, 32],
+    ] {
+        let mut filter = FirFilter::new(simd, &coeffs);
+        let mut output = vec![0.0; input.len()];
+        let mut start = 0;
+        for &size in sizes {
+            let range = start..start + size;
+            filter.process(&input[range.clone()], &mut output[range]);
+            start += size;
+        }
+        assert_eq!(output, expected, "{TAPS} taps in blocks of {sizes:?}");
+    }
+}
+
+#[simd_test]
+fn fir_filter_matches_convolution<S: Simd>(simd: S) {
+    check_taps::<S, 1>(simd);
+    check_taps::<S, 3>(simd);
+    check_taps::<S, 8>(simd);
+    check_taps::<S, 13>(simd);
+    check_taps::<S, 24>(simd);
+    check_taps::<S, 41>(simd);
+}
+
+#[simd_test]
+fn fir_filter_reset<S: Simd>(simd: S) {
+    let mut filter = FirFilter::new(simd, &[1.0, 1.0, 1.0]);
+    let mut output = [0.0; 4];
+    filter.process(&[1.0, 2.0, 3.0, 4.0], &mut output);
+    assert_eq!(
+        output,
+        [1.0, 3.0, 6.0, 9.0],
+        "sums of the last three samples"
+    );
+    filter.process(&[0.0, 0.0], &mut output[..2]);
+    assert_eq!(output[..2], [7.0, 4.0], "the history should carry over");
+    filter.reset();
+    filter.process(&[1.0, 2.0, 3.0, 4.0], &mut output);
+    assert_eq!(
+        output,
+        [1.0, 3.0, 6.0, 9.0],
+        "reset should clear the history"
+    );
+}
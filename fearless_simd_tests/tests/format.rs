@@ -0,0 +1,96 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that formatting slices of `f32` matches formatting each value with `Display`.
+
+use fearless_simd::Simd;
+use fearless_simd::format::{FormatF32, Shortest, format_f32_slice, format_f32_slice_with};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::random;
+
+/// Values around the edges of the vectorized integer formatting, and others which the backend formats.
+fn values() -> Vec<f32> {
+    let mut values = vec![
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        9.0,
+        10.0,
+        99.0,
+        100.0,
+        1000.0,
+        12_345_678.0,
+        -10_000_000.0,
+        16_777_215.0,
+        16_777_216.0,
+        -16_777_216.0,
+        1e10,
+        0.1,
+        -2.5,
+        1e-7,
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    values.extend(random(100, 7, |state| {
+        let value = f32::from_bits(state >> 8) * 1e38;
+        if state.is_multiple_of(3) {
+            value.trunc()
+        } else {
+            value
+        }
+    }));
+    values
+}
+
+fn reference(values: &[f32], separator: &str) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[simd_test]
+fn format_f32_slice_matches_display<S: Simd>(simd: S) {
+    let values = values();
+    // Every length, so that the value at each edge case is in every lane and in a partial block.
+    for start in 0..9 {
+        for len in 0..values.len() - start {
+            let values = &values[start..][..len];
+            let mut out = String::from("prefix:");
+            format_f32_slice(simd, values, &mut out);
+            assert_eq!(
+                out,
+                format!("prefix:{}", reference(values, ",")),
+                "{len} values should be appended"
+            );
+        }
+    }
+}
+
+#[simd_test]
+fn format_f32_slice_with_backend<S: Simd>(simd: S) {
+    let values = values();
+    let mut calls = 0;
+    let mut backend = |value: f32, out: &mut String| {
+        calls += 1;
+        Shortest.format_f32(value, out);
+    };
+    let mut out = String::new();
+    format_f32_slice_with(simd, &values, "; ", &mut backend, &mut out);
+    assert_eq!(
+        out,
+        reference(&values, "; "),
+        "the backend should format every value"
+    );
+    assert_eq!(
+        calls,
+        values.len(),
+        "a closure shouldn't have integers formatted for it"
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that the int8 matrix multiplication micro-kernel matches a scalar product on every level.
+
+use fearless_simd::Simd;
+use fearless_simd::gemm_ukernel::{KR, MR, NR, gemm_u8i8_4x16};
+use fearless_simd_dev_macros::simd_test;
+
+/// Multiply packed blocks one element at a time, adding to `c`.
+fn reference(a: &[[u8; MR * KR]], b: &[[i8; NR * KR]], c: &mut [[i32; NR]; MR]) {
+    for (a, b) in a.iter().zip(b) {
+        for (m, row) in c.iter_mut().enumerate() {
+            for (n, c) in row.iter_mut().enumerate() {
+                for k in 0..KR {
+                    *c = c.wrapping_add(i32::from(a[m * KR + k]) * i32::from(b[n * KR + k]));
+                }
+            }
+        }
+    }
+}
+
+#[simd_test]
+fn gemm_u8i8_4x16_matches_reference<S: Simd>(simd: S) {
+    // A simple linear congruential generator, to cover every position with a variety of values.
+    let mut state = 0x1234_5678_u32;
+    let mut next = move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 24) as u8
+    };
+    let a: [[u8; MR * KR]; 5] = core::array::from_fn(|_| core::array::from_fn(|_| next()));
+    let b: [[i8; NR * KR]; 5] =
+        core::array::from_fn(|_| core::array::from_fn(|_| next().cast_signed()));
+    let mut c: [[i32; NR]; MR] =
+        core::array::from_fn(|m| core::array::from_fn(|n| i32::try_from(m * NR + n).unwrap() - 32));
+
+    let mut expected = c;
+    reference(&a, &b, &mut expected);
+    gemm_u8i8_4x16(simd, &a, &b, &mut c);
+    assert_eq!(
+        c, expected,
+        "the product should be added to the existing tile"
+    );
+}
+
+#[simd_test]
+fn gemm_u8i8_4x16_extremes<S: Simd>(simd: S) {
+    // Every product is `255 * -128`, the largest in magnitude, which would overflow an `i16`
+    // partial sum.
+    let a = [[u8::MAX; MR * KR]; 3];
+    let b = [[i8::MIN; NR * KR]; 3];
+    let mut c = [[i32::MIN; NR]; MR];
+    gemm_u8i8_4x16(simd, &a, &b, &mut c);
+    let expected = i32::MIN.wrapping_add(255 * -128 * 12);
+    assert_eq!(c, [[expected; NR]; MR], "sums should wrap");
+
+    let mut c = [[7; NR]; MR];
+    gemm_u8i8_4x16(simd, &[], &[], &mut c);
+    assert_eq!(
+        c, [[7; NR]; MR],
+        "an empty product should leave the tile unchanged"
+    );
+}
@@ -0,0 +1,119 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+fn bytes<const N: usize>(seed: u8) -> [u8; N] {
+    core::array::from_fn(|i| {
+        let i = u8::try_from(i).unwrap();
+        i.wrapping_mul(73).wrapping_add(seed).rotate_left(3)
+    })
+}
+
+fn halfwords<const N: usize>(seed: u16) -> [u16; N] {
+    core::array::from_fn(|i| {
+        let i = u16::try_from(i).unwrap();
+        i.wrapping_mul(40_503).wrapping_add(seed).rotate_left(5)
+    })
+}
+
+#[simd_test]
+fn abd_accumulate_u8x16<S: Simd>(simd: S) {
+    let a = bytes::<16>(1);
+    let b = bytes::<16>(200);
+    // Start close to the top of the range, so that some lanes wrap.
+    let acc: [u16; 16] = core::array::from_fn(|i| 65_400 - 1000 * u16::try_from(i).unwrap());
+    let result = simd.abd_accumulate_u8x16(
+        u16x16::from_slice(simd, &acc),
+        u8x16::from_slice(simd, &a),
+        u8x16::from_slice(simd, &b),
+    );
+    let expected: [u16; 16] =
+        core::array::from_fn(|i| acc[i].wrapping_add(u16::from(a[i].abs_diff(b[i]))));
+    assert_eq!(*result, expected, "u8x16 accumulation");
+}
+
+#[simd_test]
+fn abd_accumulate_u8x32<S: Simd>(simd: S) {
+    let a = bytes::<32>(17);
+    let b = bytes::<32>(90);
+    let acc: [u16; 32] = core::array::from_fn(|i| 65_500 - 300 * u16::try_from(i).unwrap());
+    let result = simd.abd_accumulate_u8x32(
+        u16x32::from_slice(simd, &acc),
+        u8x32::from_slice(simd, &a),
+        u8x32::from_slice(simd, &b),
+    );
+    let expected: [u16; 32] =
+        core::array::from_fn(|i| acc[i].wrapping_add(u16::from(a[i].abs_diff(b[i]))));
+    assert_eq!(*result, expected, "u8x32 accumulation");
+}
+
+#[simd_test]
+fn abd_accumulate_u16x8<S: Simd>(simd: S) {
+    let a = halfwords::<8>(3);
+    let b = halfwords::<8>(60_000);
+    let acc: [u32; 8] = core::array::from_fn(|i| u32::MAX - 20_000 * u32::try_from(i).unwrap());
+    let result = simd.abd_accumulate_u16x8(
+        u32x8::from_slice(simd, &acc),
+        u16x8::from_slice(simd, &a),
+        u16x8::from_slice(simd, &b),
+    );
+    let expected: [u32; 8] =
+        core::array::from_fn(|i| acc[i].wrapping_add(u32::from(a[i].abs_diff(b[i]))));
+    assert_eq!(*result, expected, "u16x8 accumulation");
+}
+
+#[simd_test]
+fn abd_accumulate_u16x16<S: Simd>(simd: S) {
+    let a = halfwords::<16>(12_345);
+    let b = halfwords::<16>(7);
+    let acc: [u32; 16] = core::array::from_fn(|i| u32::MAX - 9_000 * u32::try_from(i).unwrap());
+    let result = simd.abd_accumulate_u16x16(
+        u32x16::from_slice(simd, &acc),
+        u16x16::from_slice(simd, &a),
+        u16x16::from_slice(simd, &b),
+    );
+    let expected: [u32; 16] =
+        core::array::from_fn(|i| acc[i].wrapping_add(u32::from(a[i].abs_diff(b[i]))));
+    assert_eq!(*result, expected, "u16x16 accumulation");
+}
+
+#[simd_test]
+fn abd_accumulate_extremes<S: Simd>(simd: S) {
+    let a: [u8; 16] = [
+        0, 255, 0, 255, 1, 254, 128, 127, 0, 0, 255, 255, 10, 20, 30, 40,
+    ];
+    let b: [u8; 16] = [
+        255, 0, 0, 255, 254, 1, 127, 128, 1, 255, 0, 254, 40, 30, 20, 10,
+    ];
+    let mut acc = u16x16::splat(simd, 0);
+    // The largest number of accumulations which can't overflow.
+    for _ in 0..257 {
+        acc = simd.abd_accumulate_u8x16(
+            acc,
+            u8x16::from_slice(simd, &a),
+            u8x16::from_slice(simd, &b),
+        );
+    }
+    let expected: [u16; 16] = core::array::from_fn(|i| 257 * u16::from(a[i].abs_diff(b[i])));
+    assert_eq!(*acc, expected, "repeated accumulation");
+}
+
+#[simd_test]
+fn abd_accumulate_sum_of_absolute_differences<S: Simd>(simd: S) {
+    let a = bytes::<160>(5);
+    let b = bytes::<160>(77);
+    let mut acc = u16x16::splat(simd, 0);
+    for (a, b) in a.chunks_exact(16).zip(b.chunks_exact(16)) {
+        acc =
+            simd.abd_accumulate_u8x16(acc, u8x16::from_slice(simd, a), u8x16::from_slice(simd, b));
+    }
+    let sad: u32 = acc.as_slice().iter().copied().map(u32::from).sum();
+    let expected: u32 = a
+        .iter()
+        .zip(&b)
+        .map(|(a, b)| u32::from(a.abs_diff(*b)))
+        .sum();
+    assert_eq!(sad, expected, "summing the accumulator should give the SAD");
+}
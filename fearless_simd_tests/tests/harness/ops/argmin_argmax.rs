@@ -0,0 +1,58 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn argmin_argmax_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[3.0, -1.0, 4.0, -1.0]);
+    assert_eq!(a.argmin(), 1);
+    assert_eq!(a.argmax(), 2);
+}
+
+#[simd_test]
+fn argmin_argmax_f32x4_ties_and_nan<S: Simd>(simd: S) {
+    let zeros = f32x4::from_slice(simd, &[f32::NAN, 0.0, -0.0, f32::NAN]);
+    assert_eq!(
+        zeros.argmin(),
+        1,
+        "signed zeros are equal, so the first wins"
+    );
+    assert_eq!(
+        zeros.argmax(),
+        1,
+        "signed zeros are equal, so the first wins"
+    );
+    let nan = f32x4::splat(simd, f32::NAN);
+    assert_eq!(nan.argmin(), 0, "all NaN returns lane 0");
+    assert_eq!(nan.argmax(), 0, "all NaN returns lane 0");
+}
+
+#[simd_test]
+fn argmin_argmax_i8x16<S: Simd>(simd: S) {
+    let a = i8x16::from_fn(simd, |i| [5, -128, 127, 0][i % 4]);
+    assert_eq!(a.argmin(), 1);
+    assert_eq!(a.argmax(), 2);
+}
+
+#[simd_test]
+fn argmin_argmax_u32x8<S: Simd>(simd: S) {
+    let a = u32x8::from_slice(simd, &[7, 7, 9, 0, 9, 0, 3, 1]);
+    assert_eq!(a.argmin(), 3);
+    assert_eq!(a.argmax(), 2);
+}
+
+#[simd_test]
+fn argmin_argmax_f64x8<S: Simd>(simd: S) {
+    let a = f64x8::from_fn(simd, |i| (i as f64 - 5.5).abs());
+    assert_eq!(a.argmin(), 5);
+    assert_eq!(a.argmax(), 0);
+}
+
+#[simd_test]
+fn argmin_argmax_native<S: Simd>(simd: S) {
+    let a = S::f32s::from_fn(simd, |i| i as f32);
+    assert_eq!(a.argmin(), 0);
+    assert_eq!(a.argmax(), S::f32s::N - 1);
+}
@@ -0,0 +1,66 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn as_signed_u8x16<S: Simd>(simd: S) {
+    let a = u8x16::from_slice(
+        simd,
+        &[0, 1, 127, 128, 129, 255, 2, 3, 4, 5, 6, 7, 8, 9, 10, 200],
+    );
+    let signed: i8x16<S> = a.as_signed();
+    assert_eq!(*signed, a.map(u8::cast_signed));
+    assert_eq!(*signed.as_unsigned(), *a);
+}
+
+#[simd_test]
+fn as_unsigned_i16x16<S: Simd>(simd: S) {
+    let a = i16x16::from_slice(
+        simd,
+        &[
+            i16::MIN,
+            -1,
+            0,
+            1,
+            i16::MAX,
+            -300,
+            300,
+            -2,
+            2,
+            -4097,
+            4097,
+            12,
+            -12,
+            0x55,
+            -0x56,
+            7,
+        ],
+    );
+    let unsigned: u16x16<S> = a.as_unsigned();
+    assert_eq!(*unsigned, a.map(i16::cast_unsigned));
+    assert_eq!(*unsigned.as_signed(), *a);
+}
+
+#[simd_test]
+fn as_signed_is_identity_for_signed<S: Simd>(simd: S) {
+    let a = i32x8::from_slice(simd, &[-1, 0, 1, i32::MIN, i32::MAX, -5, 6, -7]);
+    assert_eq!(*a.as_signed(), *a);
+    let b = u64x8::from_slice(simd, &[0, 1, u64::MAX, 3, 4, 5, 6, 1 << 63]);
+    assert_eq!(*b.as_unsigned(), *b);
+}
+
+#[simd_test]
+fn as_signed_native<S: Simd>(simd: S) {
+    let a = S::u32s::from_fn(simd, |i| u32::MAX - u32::try_from(i).unwrap());
+    // Signed and unsigned vectors can be mixed without naming the other type.
+    let sum = a.as_signed() + S::i32s::splat(simd, 2);
+    let expected: Vec<i32> = (0..S::u32s::N)
+        .map(|i| 1 - i32::try_from(i).unwrap())
+        .collect();
+    assert_eq!(sum.as_slice(), expected.as_slice());
+    let mask = sum.simd_gt(0);
+    let picked = mask.select(a, sum.as_unsigned());
+    assert_eq!(picked[0], u32::MAX);
+}
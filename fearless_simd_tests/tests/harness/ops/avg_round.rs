@@ -0,0 +1,81 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Pairs of values which include the extremes, and sums which are odd and even.
+fn operands<const N: usize>(max: u32) -> ([u32; N], [u32; N]) {
+    let a = core::array::from_fn(|i| match i {
+        0 | 1 => max,
+        2 => 0,
+        _ => (i as u32).wrapping_mul(0x9e37_79b9) % (max + 1),
+    });
+    let b = core::array::from_fn(|i| match i {
+        0 | 2 => max,
+        1 => max - 1,
+        _ => (i as u32).wrapping_mul(0x85eb_ca6b) % (max + 1),
+    });
+    (a, b)
+}
+
+#[simd_test]
+fn avg_round_u8x16<S: Simd>(simd: S) {
+    let a = [
+        0, 0, 1, 1, 2, 254, 255, 255, 100, 101, 7, 8, 128, 127, 0, 255,
+    ];
+    let b = [
+        0, 1, 1, 2, 3, 255, 254, 255, 200, 200, 9, 9, 127, 128, 255, 0,
+    ];
+    let result = simd.avg_round_u8x16(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(
+        *result,
+        [
+            0, 1, 1, 2, 3, 255, 255, 255, 150, 151, 8, 9, 128, 128, 128, 128
+        ]
+    );
+}
+
+#[simd_test]
+fn avg_round_u16x8<S: Simd>(simd: S) {
+    let a = [0, 1, 65535, 65535, 65534, 1000, 32768, 0];
+    let b = [1, 1, 65535, 65534, 65535, 2001, 32767, 65535];
+    let result = simd.avg_round_u16x8(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*result, [1, 1, 65535, 65535, 65535, 1501, 32768, 32768]);
+}
+
+#[simd_test]
+fn avg_round_u8x32<S: Simd>(simd: S) {
+    let (a, b) = operands::<32>(u8::MAX.into());
+    let expected = core::array::from_fn(|i| u8::try_from((a[i] + b[i] + 1) >> 1).unwrap());
+    let [a, b]: [[u8; 32]; 2] = [a, b].map(|v| v.map(|x| u8::try_from(x).unwrap()));
+    let result = simd.avg_round_u8x32(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*result, expected);
+}
+
+#[simd_test]
+fn avg_round_u8x64<S: Simd>(simd: S) {
+    let (a, b) = operands::<64>(u8::MAX.into());
+    let expected = core::array::from_fn(|i| u8::try_from((a[i] + b[i] + 1) >> 1).unwrap());
+    let [a, b]: [[u8; 64]; 2] = [a, b].map(|v| v.map(|x| u8::try_from(x).unwrap()));
+    let result = simd.avg_round_u8x64(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*result, expected);
+}
+
+#[simd_test]
+fn avg_round_u16x16<S: Simd>(simd: S) {
+    let (a, b) = operands::<16>(u16::MAX.into());
+    let expected = core::array::from_fn(|i| u16::try_from((a[i] + b[i] + 1) >> 1).unwrap());
+    let [a, b]: [[u16; 16]; 2] = [a, b].map(|v| v.map(|x| u16::try_from(x).unwrap()));
+    let result = simd.avg_round_u16x16(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*result, expected);
+}
+
+#[simd_test]
+fn avg_round_u16x32<S: Simd>(simd: S) {
+    let (a, b) = operands::<32>(u16::MAX.into());
+    let expected = core::array::from_fn(|i| u16::try_from((a[i] + b[i] + 1) >> 1).unwrap());
+    let [a, b]: [[u16; 32]; 2] = [a, b].map(|v| v.map(|x| u16::try_from(x).unwrap()));
+    let result = simd.avg_round_u16x32(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*result, expected);
+}
@@ -15,6 +15,52 @@ fn bitcast_native<S: Simd>(simd: S) {
     );
 }
 
+#[simd_test]
+fn bitcast_native_across_lane_widths<S: Simd>(simd: S) {
+    let a = S::f32s::from_slice(simd, &vec![-1.5; S::f32s::N]);
+    let bytes = a.bitcast::<S::u8s>();
+    let expected: Vec<u8> = (-1.5_f32)
+        .to_ne_bytes()
+        .into_iter()
+        .cycle()
+        .take(S::u8s::N)
+        .collect();
+    assert_eq!(
+        bytes.as_slice(),
+        expected.as_slice(),
+        "native f32s should bitcast to u8s"
+    );
+
+    let halves = bytes.bitcast::<S::i16s>();
+    let words = halves.bitcast::<S::u64s>();
+    let back = words.bitcast::<S::f64s>().bitcast::<S::f32s>();
+    assert_eq!(
+        back.as_slice(),
+        a.as_slice(),
+        "a chain of native bitcasts should round-trip"
+    );
+}
+
+#[simd_test]
+fn bitcast_f32x8_across_lane_widths<S: Simd>(simd: S) {
+    let values: [f32; 8] = core::array::from_fn(|i| i as f32 - 3.5);
+    let a = f32x8::from_slice(simd, &values);
+    let lanes = a.bitcast::<i64x4<S>>().bitcast::<u16x16<S>>();
+    let expected: Vec<u16> = values
+        .iter()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect::<Vec<u8>>()
+        .chunks_exact(2)
+        .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+        .collect();
+    assert_eq!(lanes.as_slice(), expected.as_slice());
+    assert_eq!(
+        lanes.bitcast::<f32x8<S>>().as_slice(),
+        values.as_slice(),
+        "bitcasting back should restore the floats"
+    );
+}
+
 // Generated gap-fill coverage rows.
 
 #[simd_test]
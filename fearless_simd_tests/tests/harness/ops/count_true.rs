@@ -0,0 +1,37 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn count_true_mask8x64<S: Simd>(simd: S) {
+    let bits = 0x8000_0001_f00f_0ff1_u64;
+    let mask = mask8x64::from_bitmask(simd, bits);
+    assert_eq!(mask.count_true(), 19);
+    assert_eq!(mask8x64::splat(simd, true).count_true(), 64);
+}
+
+#[simd_test]
+fn count_true_mask16x32<S: Simd>(simd: S) {
+    let mask = mask16x32::from_fn(simd, |i| i % 4 == 1);
+    assert_eq!(mask.count_true(), 8);
+}
+
+#[simd_test]
+fn count_true_mask32x8<S: Simd>(simd: S) {
+    let values = f32x8::from_slice(simd, &[0.5, -1.0, 2.0, f32::NAN, 3.0, -0.0, 7.0, 1.0]);
+    assert_eq!(values.simd_gt(0.75).count_true(), 4);
+    assert_eq!(mask32x8::splat(simd, false).count_true(), 0);
+}
+
+#[simd_test]
+fn count_true_mask64x2<S: Simd>(simd: S) {
+    assert_eq!(mask64x2::from_bools(simd, &[false, true]).count_true(), 1);
+}
+
+#[simd_test]
+fn count_true_native<S: Simd>(simd: S) {
+    let mask = S::i32s::lane_indices(simd).simd_lt(3);
+    assert_eq!(mask.count_true(), 3);
+}
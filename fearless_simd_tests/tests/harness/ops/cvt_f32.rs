@@ -85,3 +85,10 @@ fn cvt_f32_i32x8<S: Simd>(simd: S) {
     let result = simd.cvt_f32_i32x8(a);
     assert_eq!(result.as_slice(), expected.as_slice());
 }
+
+#[simd_test]
+fn cvt_f32_native<S: Simd>(simd: S) {
+    let a = S::u32s::from_fn(simd, |i| i as u32 * 2);
+    let expected: Vec<f32> = (0..S::u32s::N).map(|i| i as f32 * 2.0).collect();
+    assert_eq!(a.to_float::<S::f32s>().as_slice(), expected);
+}
@@ -25,6 +25,15 @@ fn cvt_i32_f32x4<S: Simd>(simd: S) {
     assert_eq!(*a.to_int::<i32x4<_>>(), [-10, 0, 13, 234234]);
 }
 
+// Out-of-range values and NaN saturate on most levels, but x86 returns `i32::MIN` for all of them.
+#[simd_test(expect(
+    default = [-1, i32::MAX, i32::MIN, 0],
+    x86 = [-1, i32::MIN, i32::MIN, i32::MIN],
+))]
+fn cvt_i32_f32x4_out_of_range<S: Simd>(simd: S) -> i32x4<S> {
+    f32x4::from_slice(simd, &[-1.5, 3e9, -3e9, f32::NAN]).to_int()
+}
+
 #[simd_test]
 fn cvt_i32_f32x8_rounding<S: Simd>(simd: S) {
     let a = f32x8::from_slice(simd, &[0.0, 0.49, 0.51, 0.99, 1.01, 1.99, 2.5, 3.75]);
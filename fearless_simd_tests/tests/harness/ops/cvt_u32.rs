@@ -65,3 +65,14 @@ fn cvt_u32_f32x16<S: Simd>(simd: S) {
         [1, 2, 3, 4, 5, 6, 7, 8, 10, 11, 12, 13, 14, 15, 0, 100]
     );
 }
+
+#[simd_test]
+fn cvt_u32_to_uint<S: Simd>(simd: S) {
+    let a = f32x16::from_fn(simd, |i| i as f32 * 1.5);
+    let expected: [u32; 16] = core::array::from_fn(|i| (i * 3 / 2) as u32);
+    assert_eq!(*a.to_uint(), expected);
+
+    let a = S::f32s::from_fn(simd, |i| i as f32 + 0.75);
+    let expected: Vec<u32> = (0..S::f32s::N).map(|i| i as u32).collect();
+    assert_eq!(a.to_uint().as_slice(), expected);
+}
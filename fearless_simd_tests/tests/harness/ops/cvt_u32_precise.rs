@@ -97,3 +97,15 @@ fn cvt_u32_precise_f32x16<S: Simd>(simd: S) {
     assert_eq!(result[5], 0); // 0.5 truncates to 0
     assert_eq!(result[6], 0); // -1.0 clamps to 0
 }
+
+#[simd_test]
+fn cvt_u32_precise_to_uint_precise<S: Simd>(simd: S) {
+    let a = f32x8::from_slice(
+        simd,
+        &[-1.0, 0.5, 3e9, 5e9, f32::NAN, f32::INFINITY, 7.9, -0.0],
+    );
+    assert_eq!(
+        *a.to_uint_precise(),
+        [0, 0, 3_000_000_000, u32::MAX, 0, u32::MAX, 7, 0]
+    );
+}
@@ -0,0 +1,84 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn dot2_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, 2.0, -3.0, 0.5]);
+    let b = f32x4::from_slice(simd, &[3.0, 4.0, 2.0, 8.0]);
+    assert_eq!(*a.dot2(b), [11.0, 11.0, -2.0, -2.0]);
+}
+
+#[simd_test]
+fn dot3_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, f32::NAN]);
+    let b = f32x4::from_slice(simd, &[4.0, -5.0, 6.0, f32::INFINITY]);
+    assert_eq!(
+        *a.dot3(b),
+        [12.0; 4],
+        "the fourth lane should be ignored, even if it's NaN"
+    );
+}
+
+#[simd_test]
+fn dot3_zero_sign_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[-0.0, -0.0, -0.0, -0.0]);
+    let b = f32x4::splat(simd, 1.0);
+    assert_eq!(
+        a.dot3(b).map(f32::to_bits),
+        [0.0_f32.to_bits(); 4],
+        "the ignored product should be replaced by positive zero"
+    );
+}
+
+#[simd_test]
+fn dot4_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+    let b = f32x4::from_slice(simd, &[5.0, 6.0, -7.0, 8.0]);
+    assert_eq!(*a.dot4(b), [28.0; 4]);
+}
+
+#[simd_test]
+fn dot4_sum_order_f32x4<S: Simd>(simd: S) {
+    // Summing left to right would give 1.0, but the two pairs are summed first.
+    let a = f32x4::from_slice(simd, &[1e8, 1.0, -1e8, 1.0]);
+    let b = f32x4::splat(simd, 1.0);
+    assert_eq!(*a.dot4(b), [0.0; 4]);
+}
+
+#[simd_test]
+fn dot_f32x8<S: Simd>(simd: S) {
+    let a = f32x8::from_slice(simd, &[1.0, 2.0, 3.0, 4.0, -1.0, 0.5, 2.0, 10.0]);
+    let b = f32x8::from_slice(simd, &[2.0, 2.0, 2.0, 2.0, 4.0, 4.0, 4.0, 4.0]);
+    assert_eq!(*a.dot2(b), [6.0, 6.0, 14.0, 14.0, -2.0, -2.0, 48.0, 48.0]);
+    assert_eq!(*a.dot3(b), [12.0, 12.0, 12.0, 12.0, 6.0, 6.0, 6.0, 6.0]);
+    assert_eq!(*a.dot4(b), [20.0, 20.0, 20.0, 20.0, 46.0, 46.0, 46.0, 46.0]);
+}
+
+#[simd_test]
+fn dot_f32x16<S: Simd>(simd: S) {
+    let values: [f32; 16] = core::array::from_fn(|i| i as f32 - 6.0);
+    let a = f32x16::from_slice(simd, &values);
+    let b = f32x16::from_fn(simd, |i| if i % 4 == 3 { f32::NAN } else { 0.5 });
+    let expected: [f32; 16] = core::array::from_fn(|i| {
+        let group = &values[i / 4 * 4..][..3];
+        (group[0] * 0.5 + group[1] * 0.5) + group[2] * 0.5
+    });
+    assert_eq!(*a.dot3(b), expected);
+    let squares = [
+        86.0, 86.0, 86.0, 86.0, 6.0, 6.0, 6.0, 6.0, 54.0, 54.0, 54.0, 54.0, 230.0, 230.0, 230.0,
+        230.0,
+    ];
+    assert_eq!(*a.dot4(a), squares);
+}
+
+#[simd_test]
+fn dot4_native<S: Simd>(simd: S) {
+    let a = S::f32s::from_fn(simd, |i| (i % 4) as f32);
+    assert!(
+        a.dot4(a).as_slice().iter().all(|&x| x == 14.0),
+        "every group should hold the dot product"
+    );
+}
@@ -0,0 +1,86 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+const BITMASKS: [u64; 8] = [
+    0,
+    u64::MAX,
+    0x5555_5555_5555_5555,
+    0xaaaa_aaaa_aaaa_aaaa,
+    0x0000_0000_ffff_0000,
+    0x8000_0000_0000_0001,
+    0x0123_4567_89ab_cdef,
+    0xf0e1_d2c3_b4a5_9687,
+];
+
+fn expand_reference<const N: usize>(a: [u8; N], bits: u64) -> [u8; N] {
+    let mut result = [0; N];
+    let mut next = a.iter();
+    for (i, dest) in result.iter_mut().enumerate() {
+        if bits & (1 << i) != 0 {
+            *dest = *next.next().unwrap();
+        }
+    }
+    result
+}
+
+#[simd_test]
+fn expand_bytes_u8x16<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| u8::try_from(i + 1).unwrap());
+    for bits in BITMASKS {
+        let mask = mask8x16::from_bitmask(simd, bits);
+        assert_eq!(
+            *simd.expand_bytes_u8x16(a, mask),
+            expand_reference(*a, bits),
+            "expanding with mask {bits:#x}"
+        );
+    }
+}
+
+#[simd_test]
+fn expand_bytes_u8x32<S: Simd>(simd: S) {
+    let a = u8x32::from_fn(simd, |i| u8::try_from(i + 1).unwrap());
+    for bits in BITMASKS {
+        let mask = mask8x32::from_bitmask(simd, bits);
+        assert_eq!(
+            *simd.expand_bytes_u8x32(a, mask),
+            expand_reference(*a, bits),
+            "expanding with mask {bits:#x}"
+        );
+    }
+}
+
+#[simd_test]
+fn expand_bytes_u8x64<S: Simd>(simd: S) {
+    let a = u8x64::from_fn(simd, |i| u8::try_from(i + 1).unwrap());
+    for bits in BITMASKS {
+        let mask = mask8x64::from_bitmask(simd, bits);
+        assert_eq!(
+            *simd.expand_bytes_u8x64(a, mask),
+            expand_reference(*a, bits),
+            "expanding with mask {bits:#x}"
+        );
+    }
+}
+
+#[simd_test]
+fn expand_bytes_every_u8x16_mask<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| u8::try_from(i * 7 + 3).unwrap());
+    for bits in 0..=u64::from(u16::MAX) {
+        let mask = mask8x16::from_bitmask(simd, bits);
+        assert_eq!(
+            *simd.expand_bytes_u8x16(a, mask),
+            expand_reference(*a, bits),
+            "expanding with mask {bits:#x}"
+        );
+        let compressed = simd.compress_bytes_u8x16(simd.expand_bytes_u8x16(a, mask), mask);
+        let kept = usize::try_from(bits.count_ones()).unwrap();
+        assert_eq!(
+            compressed.as_slice()[..kept],
+            a.as_slice()[..kept],
+            "compressing undoes expanding with mask {bits:#x}"
+        );
+    }
+}
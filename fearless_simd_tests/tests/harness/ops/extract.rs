@@ -0,0 +1,263 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+macro_rules! check_lanes {
+    ($a:ident, [$($lane:literal),*]) => {
+        $(
+            assert_eq!($a.extract::<$lane>(), $a[$lane], "lane {}", $lane);
+        )*
+    };
+}
+
+// Every lane of the 128-bit types, which are each lowered separately, and the lanes either side of each split of
+// the wider types. The elements spread their bits, so that the sign bits and high halves are set in some lanes.
+
+#[simd_test]
+fn extract_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn extract_i8x16<S: Simd>(simd: S) {
+    let a = i8x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+#[simd_test]
+fn extract_u8x16<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+#[simd_test]
+fn extract_i16x8<S: Simd>(simd: S) {
+    let a = i16x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[simd_test]
+fn extract_u16x8<S: Simd>(simd: S) {
+    let a = u16x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[simd_test]
+fn extract_i32x4<S: Simd>(simd: S) {
+    let a = i32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn extract_u32x4<S: Simd>(simd: S) {
+    let a = u32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn extract_f64x2<S: Simd>(simd: S) {
+    let a = f64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1]);
+}
+
+#[simd_test]
+fn extract_i64x2<S: Simd>(simd: S) {
+    let a = i64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1]);
+}
+
+#[simd_test]
+fn extract_u64x2<S: Simd>(simd: S) {
+    let a = u64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    });
+    check_lanes!(a, [0, 1]);
+}
+
+#[simd_test]
+fn extract_f32x8<S: Simd>(simd: S) {
+    let a = f32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 3, 4, 7]);
+}
+
+#[simd_test]
+fn extract_i8x32<S: Simd>(simd: S) {
+    let a = i8x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 15, 16, 17, 31]);
+}
+
+#[simd_test]
+fn extract_u8x32<S: Simd>(simd: S) {
+    let a = u8x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 15, 16, 17, 31]);
+}
+
+#[simd_test]
+fn extract_i16x16<S: Simd>(simd: S) {
+    let a = i16x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 7, 8, 15]);
+}
+
+#[simd_test]
+fn extract_u16x16<S: Simd>(simd: S) {
+    let a = u16x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 7, 8, 15]);
+}
+
+#[simd_test]
+fn extract_i32x8<S: Simd>(simd: S) {
+    let a = i32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 3, 4, 7]);
+}
+
+#[simd_test]
+fn extract_u32x8<S: Simd>(simd: S) {
+    let a = u32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 3, 4, 7]);
+}
+
+#[simd_test]
+fn extract_f64x4<S: Simd>(simd: S) {
+    let a = f64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn extract_i64x4<S: Simd>(simd: S) {
+    let a = i64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn extract_u64x4<S: Simd>(simd: S) {
+    let a = u64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    });
+    check_lanes!(a, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn extract_f32x16<S: Simd>(simd: S) {
+    let a = f32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 3, 4, 7, 8, 15]);
+}
+
+#[simd_test]
+fn extract_i8x64<S: Simd>(simd: S) {
+    let a = i8x64::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 15, 16, 31, 32, 33, 63]);
+}
+
+#[simd_test]
+fn extract_u8x64<S: Simd>(simd: S) {
+    let a = u8x64::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 15, 16, 31, 32, 33, 63]);
+}
+
+#[simd_test]
+fn extract_i16x32<S: Simd>(simd: S) {
+    let a = i16x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 7, 8, 15, 16, 31]);
+}
+
+#[simd_test]
+fn extract_u16x32<S: Simd>(simd: S) {
+    let a = u16x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 7, 8, 15, 16, 31]);
+}
+
+#[simd_test]
+fn extract_i32x16<S: Simd>(simd: S) {
+    let a = i32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 3, 4, 7, 8, 15]);
+}
+
+#[simd_test]
+fn extract_u32x16<S: Simd>(simd: S) {
+    let a = u32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 3, 4, 7, 8, 15]);
+}
+
+#[simd_test]
+fn extract_f64x8<S: Simd>(simd: S) {
+    let a = f64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 7]);
+}
+
+#[simd_test]
+fn extract_i64x8<S: Simd>(simd: S) {
+    let a = i64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 7]);
+}
+
+#[simd_test]
+fn extract_u64x8<S: Simd>(simd: S) {
+    let a = u64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    });
+    check_lanes!(a, [0, 1, 2, 3, 4, 7]);
+}
+
+#[simd_test]
+fn extract_f32x4_special_values<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, -0.0, 3.5, f32::INFINITY]);
+    assert_eq!(simd.extract_f32x4::<3>(a), f32::INFINITY);
+    assert!(a.extract::<1>().is_sign_negative());
+}
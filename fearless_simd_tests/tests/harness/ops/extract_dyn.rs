@@ -0,0 +1,231 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+fn check_every_lane<S: Simd, V: SimdBase<S>>(a: V)
+where
+    V::Element: PartialEq + core::fmt::Debug,
+{
+    for i in 0..V::N {
+        assert_eq!(a.extract_dyn(i), a[i], "lane {i}");
+    }
+}
+
+#[simd_test]
+fn extract_dyn_f32x4<S: Simd>(simd: S) {
+    check_every_lane(f32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i8x16<S: Simd>(simd: S) {
+    check_every_lane(i8x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u8x16<S: Simd>(simd: S) {
+    check_every_lane(u8x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i16x8<S: Simd>(simd: S) {
+    check_every_lane(i16x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u16x8<S: Simd>(simd: S) {
+    check_every_lane(u16x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i32x4<S: Simd>(simd: S) {
+    check_every_lane(i32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u32x4<S: Simd>(simd: S) {
+    check_every_lane(u32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_f64x2<S: Simd>(simd: S) {
+    check_every_lane(f64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i64x2<S: Simd>(simd: S) {
+    check_every_lane(i64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u64x2<S: Simd>(simd: S) {
+    check_every_lane(u64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_f32x8<S: Simd>(simd: S) {
+    check_every_lane(f32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i8x32<S: Simd>(simd: S) {
+    check_every_lane(i8x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u8x32<S: Simd>(simd: S) {
+    check_every_lane(u8x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i16x16<S: Simd>(simd: S) {
+    check_every_lane(i16x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u16x16<S: Simd>(simd: S) {
+    check_every_lane(u16x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i32x8<S: Simd>(simd: S) {
+    check_every_lane(i32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u32x8<S: Simd>(simd: S) {
+    check_every_lane(u32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_f64x4<S: Simd>(simd: S) {
+    check_every_lane(f64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i64x4<S: Simd>(simd: S) {
+    check_every_lane(i64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u64x4<S: Simd>(simd: S) {
+    check_every_lane(u64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_f32x16<S: Simd>(simd: S) {
+    check_every_lane(f32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i8x64<S: Simd>(simd: S) {
+    check_every_lane(i8x64::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u8x64<S: Simd>(simd: S) {
+    check_every_lane(u8x64::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i16x32<S: Simd>(simd: S) {
+    check_every_lane(i16x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u16x32<S: Simd>(simd: S) {
+    check_every_lane(u16x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i32x16<S: Simd>(simd: S) {
+    check_every_lane(i32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u32x16<S: Simd>(simd: S) {
+    check_every_lane(u32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_f64x8<S: Simd>(simd: S) {
+    check_every_lane(f64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_i64x8<S: Simd>(simd: S) {
+    check_every_lane(i64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    }));
+}
+
+#[simd_test]
+fn extract_dyn_u64x8<S: Simd>(simd: S) {
+    check_every_lane(u64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }));
+}
+
+#[test]
+#[should_panic(expected = "lane index 4 is out of bounds for 4 lanes")]
+fn extract_dyn_out_of_bounds() {
+    let a = u32x4::splat(Fallback::new(), 1);
+    a.extract_dyn(4);
+}
@@ -0,0 +1,54 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn first_true_mask8x16<S: Simd>(simd: S) {
+    let bytes = u8x16::from_slice(simd, b"a,b,c;d,e,f;g,h;");
+    assert_eq!(bytes.simd_eq(b';').first_true(), Some(5));
+    assert_eq!(bytes.simd_eq(b';').last_true(), Some(15));
+    assert_eq!(bytes.simd_eq(b'#').first_true(), None);
+    assert_eq!(bytes.simd_eq(b'#').last_true(), None);
+}
+
+#[simd_test]
+fn first_true_every_lane_mask8x64<S: Simd>(simd: S) {
+    for lane in 0..64 {
+        let mask = mask8x64::from_fn(simd, |i| i == lane);
+        assert_eq!(mask.first_true(), Some(lane));
+        assert_eq!(mask.last_true(), Some(lane));
+    }
+    let mask = mask8x64::splat(simd, true);
+    assert_eq!((mask.first_true(), mask.last_true()), (Some(0), Some(63)));
+}
+
+#[simd_test]
+fn first_true_mask16x32<S: Simd>(simd: S) {
+    let mask = mask16x32::from_fn(simd, |i| i % 7 == 3);
+    assert_eq!(mask.first_true(), Some(3));
+    assert_eq!(mask.last_true(), Some(31));
+}
+
+#[simd_test]
+fn first_true_mask32x8<S: Simd>(simd: S) {
+    let values = f32x8::from_slice(simd, &[0.5, -1.0, f32::NAN, 2.0, 3.0, -0.0, 7.0, 0.25]);
+    let mask = values.simd_gt(1.0);
+    assert_eq!((mask.first_true(), mask.last_true()), (Some(3), Some(6)));
+    assert_eq!(mask32x8::splat(simd, false).first_true(), None);
+}
+
+#[simd_test]
+fn first_true_mask64x2<S: Simd>(simd: S) {
+    let mask = mask64x2::from_bools(simd, &[false, true]);
+    assert_eq!((mask.first_true(), mask.last_true()), (Some(1), Some(1)));
+}
+
+#[simd_test]
+fn first_true_native<S: Simd>(simd: S) {
+    let lanes = S::i32s::lane_indices(simd);
+    let mask = lanes.simd_ge(1);
+    assert_eq!(mask.first_true(), Some(1));
+    assert_eq!(mask.last_true(), Some(S::i32s::N - 1));
+}
@@ -0,0 +1,37 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn from_bools_mask8x32<S: Simd>(simd: S) {
+    let bools: [bool; 32] = core::array::from_fn(|i| i.is_power_of_two());
+    let a = mask8x32::from_bools(simd, &bools);
+    let expected: [i8; 32] = bools.map(|b| if b { -1 } else { 0 });
+    assert_eq!(<[i8; 32]>::from(a), expected);
+}
+
+#[simd_test]
+fn from_bools_mask16x8<S: Simd>(simd: S) {
+    let a = mask16x8::from_bools(simd, &[true, false, false, true, true, false, true, false]);
+    assert_eq!(a.to_bitmask(), 0b0101_1001);
+}
+
+#[simd_test]
+fn from_bools_mask32x4<S: Simd>(simd: S) {
+    let a = mask32x4::from_bools(simd, &[false, true, false, true]);
+    assert_eq!(<[i32; 4]>::from(a), [0, -1, 0, -1]);
+    assert!(
+        a.simd_eq(mask32x4::from_bitmask(simd, 0b1010)).all_true(),
+        "masks from bools should be canonical"
+    );
+}
+
+#[simd_test]
+fn from_bools_mask64x2<S: Simd>(simd: S) {
+    let a = mask64x2::from_bools(simd, &[true, false]);
+    let b = f64x2::from_slice(simd, &[1.0, 2.0]);
+    let c = f64x2::splat(simd, 0.0);
+    assert_eq!(a.select(b, c).as_slice(), [1.0, 0.0]);
+}
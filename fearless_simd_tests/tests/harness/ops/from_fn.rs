@@ -260,3 +260,23 @@ fn from_fn_f64x8<S: Simd>(simd: S) {
     let result = f64x8::from_fn(simd, |i| i as f64 + 1.25_f64);
     assert_eq!(result.as_slice(), expected.as_slice());
 }
+
+#[simd_test]
+fn from_fn_mask8x16<S: Simd>(simd: S) {
+    let a = mask8x16::from_fn(simd, |i| i % 3 == 0);
+    let expected: [i8; 16] = core::array::from_fn(|i| if i % 3 == 0 { -1 } else { 0 });
+    assert_eq!(<[i8; 16]>::from(a), expected);
+    assert_eq!(a.to_bitmask(), 0b1001_0010_0100_1001);
+}
+
+#[simd_test]
+fn from_fn_mask32x8<S: Simd>(simd: S) {
+    let a = mask32x8::from_fn(simd, |i| i >= 5);
+    assert_eq!(<[i32; 8]>::from(a), [0, 0, 0, 0, 0, -1, -1, -1]);
+}
+
+#[simd_test]
+fn from_fn_mask64x8<S: Simd>(simd: S) {
+    let a = mask64x8::from_fn(simd, |i| i == 7);
+    assert_eq!(<[i64; 8]>::from(a), [0, 0, 0, 0, 0, 0, 0, -1]);
+}
@@ -0,0 +1,39 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+// These hold on every target, so they also check the byte order on big-endian ones.
+
+#[simd_test]
+fn from_le_bytes_u32x4<S: Simd>(simd: S) {
+    let bytes = u8x16::from_fn(simd, |i| i as u8 + 1);
+    assert_eq!(
+        *u32x4::from_le_bytes(bytes),
+        [0x0403_0201, 0x0807_0605, 0x0c0b_0a09, 0x100f_0e0d]
+    );
+}
+
+#[simd_test]
+fn from_le_bytes_f32x8<S: Simd>(simd: S) {
+    let values = [
+        1.0_f32,
+        -2.5,
+        0.1,
+        f32::MAX,
+        -0.0,
+        1e-40,
+        7.0,
+        f32::INFINITY,
+    ];
+    let bytes: Vec<u8> = values.iter().flat_map(|x| x.to_le_bytes()).collect();
+    let a = f32x8::from_le_bytes(u8x32::from_slice(simd, &bytes));
+    assert_eq!((*a).map(f32::to_bits), values.map(f32::to_bits));
+}
+
+#[simd_test]
+fn from_le_bytes_roundtrip_i64x8<S: Simd>(simd: S) {
+    let a = i64x8::from_fn(simd, |i| (i as i64 - 4) * 0x0102_0304_0506);
+    assert_eq!(*i64x8::from_le_bytes(a.to_le_bytes()), *a);
+}
@@ -0,0 +1,48 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Multiply in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1, one bit of `b` at a time.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1B } else { 0 };
+        b >>= 1;
+    }
+    product
+}
+
+#[simd_test]
+fn gf_mul_const_u8x16<S: Simd>(simd: S) {
+    // The examples from section 4.2 of FIPS 197.
+    let a = u8x16::splat(simd, 0x57);
+    assert_eq!(*simd.gf_mul_const_u8x16::<0x83>(a), [0xC1; 16]);
+    assert_eq!(*simd.gf_mul_const_u8x16::<0x13>(a), [0xFE; 16]);
+
+    let a = u8x16::from_fn(simd, |i| i as u8 * 17);
+    assert_eq!(*simd.gf_mul_const_u8x16::<0>(a), [0; 16]);
+    assert_eq!(*simd.gf_mul_const_u8x16::<1>(a), *a);
+}
+
+#[simd_test]
+fn gf_mul_const_u8x32<S: Simd>(simd: S) {
+    let a = u8x32::from_fn(simd, |i| (i * 8 + 3) as u8);
+    let expected: [u8; 32] = core::array::from_fn(|i| gf_mul(a[i], 0x1D));
+    assert_eq!(*simd.gf_mul_const_u8x32::<0x1D>(a), expected);
+}
+
+#[simd_test]
+fn gf_mul_const_u8x64_all_bytes<S: Simd>(simd: S) {
+    for chunk in 0..4 {
+        let a = u8x64::from_fn(simd, |i| (chunk * 64 + i) as u8);
+        let expected: [u8; 64] = core::array::from_fn(|i| gf_mul(a[i], 0xCA));
+        assert_eq!(*simd.gf_mul_const_u8x64::<0xCA>(a), expected);
+        let expected: [u8; 64] = core::array::from_fn(|i| gf_mul(a[i], 0xFF));
+        assert_eq!(*simd.gf_mul_const_u8x64::<0xFF>(a), expected);
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn identity_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[-0.0, 0.0, -3.5, f32::MAX]);
+    let sum = f32x4::splat(simd, f32x4::<S>::ADD_IDENTITY) + a;
+    assert_eq!(
+        sum.map(f32::to_bits),
+        a.map(f32::to_bits),
+        "adding the identity should keep the sign of zero"
+    );
+    assert_eq!(*a.min(f32x4::<S>::MIN_IDENTITY), *a);
+    assert_eq!(*a.max(f32x4::<S>::MAX_IDENTITY), *a);
+}
+
+#[simd_test]
+fn identity_i16x8<S: Simd>(simd: S) {
+    let a = i16x8::from_slice(simd, &[i16::MIN, -1, 0, 1, i16::MAX, 7, -300, 42]);
+    assert_eq!(*(a + i16x8::<S>::ADD_IDENTITY), *a);
+    assert_eq!(*a.min(i16x8::<S>::MIN_IDENTITY), *a);
+    assert_eq!(*a.max(i16x8::<S>::MAX_IDENTITY), *a);
+    assert_eq!(*(a & i16x8::<S>::AND_IDENTITY), *a);
+}
+
+#[simd_test]
+fn identity_native_u32<S: Simd>(simd: S) {
+    let a = S::u32s::from_fn(simd, |i| u32::MAX - 1000 * u32::try_from(i).unwrap());
+    let mut min = S::u32s::splat(simd, S::u32s::MIN_IDENTITY);
+    let mut max = S::u32s::splat(simd, S::u32s::MAX_IDENTITY);
+    let mut and = S::u32s::splat(simd, S::u32s::AND_IDENTITY);
+    for _ in 0..2 {
+        min = min.min(a);
+        max = max.max(a);
+        and &= a;
+    }
+    assert_eq!(min.as_slice(), a.as_slice());
+    assert_eq!(max.as_slice(), a.as_slice());
+    assert_eq!(and.as_slice(), a.as_slice());
+}
@@ -0,0 +1,364 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+macro_rules! check_lanes {
+    ($a:ident, $values:ident, [$($lane:literal),*]) => {
+        $(
+            let mut expected = *$a;
+            expected[$lane] = $values[$lane];
+            assert_eq!(*$a.insert::<$lane>($values[$lane]), expected, "lane {}", $lane);
+        )*
+    };
+}
+
+// Every lane of the 128-bit types, which are each lowered separately, and the lanes either side of each split of
+// the wider types. The elements spread their bits, so that the sign bits and high halves are set in some lanes.
+
+#[simd_test]
+fn insert_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = f32x4::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn insert_i8x16<S: Simd>(simd: S) {
+    let a = i8x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i8x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(
+        a,
+        values,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+    );
+}
+
+#[simd_test]
+fn insert_u8x16<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u8x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(
+        a,
+        values,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+    );
+}
+
+#[simd_test]
+fn insert_i16x8<S: Simd>(simd: S) {
+    let a = i16x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i16x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[simd_test]
+fn insert_u16x8<S: Simd>(simd: S) {
+    let a = u16x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u16x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[simd_test]
+fn insert_i32x4<S: Simd>(simd: S) {
+    let a = i32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i32x4::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn insert_u32x4<S: Simd>(simd: S) {
+    let a = u32x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u32x4::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn insert_f64x2<S: Simd>(simd: S) {
+    let a = f64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = f64x2::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1]);
+}
+
+#[simd_test]
+fn insert_i64x2<S: Simd>(simd: S) {
+    let a = i64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i64x2::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1]);
+}
+
+#[simd_test]
+fn insert_u64x2<S: Simd>(simd: S) {
+    let a = u64x2::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    });
+    let values = u64x2::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93)
+    });
+    check_lanes!(a, values, [0, 1]);
+}
+
+#[simd_test]
+fn insert_f32x8<S: Simd>(simd: S) {
+    let a = f32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = f32x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 3, 4, 7]);
+}
+
+#[simd_test]
+fn insert_i8x32<S: Simd>(simd: S) {
+    let a = i8x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i8x32::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 15, 16, 17, 31]);
+}
+
+#[simd_test]
+fn insert_u8x32<S: Simd>(simd: S) {
+    let a = u8x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u8x32::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 15, 16, 17, 31]);
+}
+
+#[simd_test]
+fn insert_i16x16<S: Simd>(simd: S) {
+    let a = i16x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i16x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 7, 8, 15]);
+}
+
+#[simd_test]
+fn insert_u16x16<S: Simd>(simd: S) {
+    let a = u16x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u16x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 7, 8, 15]);
+}
+
+#[simd_test]
+fn insert_i32x8<S: Simd>(simd: S) {
+    let a = i32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i32x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 3, 4, 7]);
+}
+
+#[simd_test]
+fn insert_u32x8<S: Simd>(simd: S) {
+    let a = u32x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u32x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 3, 4, 7]);
+}
+
+#[simd_test]
+fn insert_f64x4<S: Simd>(simd: S) {
+    let a = f64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = f64x4::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn insert_i64x4<S: Simd>(simd: S) {
+    let a = i64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i64x4::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn insert_u64x4<S: Simd>(simd: S) {
+    let a = u64x4::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    });
+    let values = u64x4::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93)
+    });
+    check_lanes!(a, values, [0, 1, 2, 3]);
+}
+
+#[simd_test]
+fn insert_f32x16<S: Simd>(simd: S) {
+    let a = f32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = f32x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 3, 4, 7, 8, 15]);
+}
+
+#[simd_test]
+fn insert_i8x64<S: Simd>(simd: S) {
+    let a = i8x64::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i8x64::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 15, 16, 31, 32, 33, 63]);
+}
+
+#[simd_test]
+fn insert_u8x64<S: Simd>(simd: S) {
+    let a = u8x64::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u8x64::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 15, 16, 31, 32, 33, 63]);
+}
+
+#[simd_test]
+fn insert_i16x32<S: Simd>(simd: S) {
+    let a = i16x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i16x32::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 7, 8, 15, 16, 31]);
+}
+
+#[simd_test]
+fn insert_u16x32<S: Simd>(simd: S) {
+    let a = u16x32::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u16x32::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 7, 8, 15, 16, 31]);
+}
+
+#[simd_test]
+fn insert_i32x16<S: Simd>(simd: S) {
+    let a = i32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i32x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 3, 4, 7, 8, 15]);
+}
+
+#[simd_test]
+fn insert_u32x16<S: Simd>(simd: S) {
+    let a = u32x16::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = u32x16::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 3, 4, 7, 8, 15]);
+}
+
+#[simd_test]
+fn insert_f64x8<S: Simd>(simd: S) {
+    let a = f64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = f64x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3, 4, 7]);
+}
+
+#[simd_test]
+fn insert_i64x8<S: Simd>(simd: S) {
+    let a = i64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) as _
+    });
+    let values = i64x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93) as _
+    });
+    check_lanes!(a, values, [0, 1, 2, 3, 4, 7]);
+}
+
+#[simd_test]
+fn insert_u64x8<S: Simd>(simd: S) {
+    let a = u64x8::from_fn(simd, |i| {
+        ((i * 37 + 3) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    });
+    let values = u64x8::from_fn(simd, |i| {
+        ((i * 11 + 200) as u64).wrapping_mul(0xD6E8_FEB8_6659_FD93)
+    });
+    check_lanes!(a, values, [0, 1, 2, 3, 4, 7]);
+}
+
+#[simd_test]
+fn insert_then_extract<S: Simd>(simd: S) {
+    let a = i8x16::splat(simd, 0).insert::<5>(-7).insert::<6>(i8::MIN);
+    assert_eq!(a.extract::<5>(), -7);
+    assert_eq!(a.extract::<6>(), i8::MIN);
+    assert_eq!(a.extract::<7>(), 0);
+}
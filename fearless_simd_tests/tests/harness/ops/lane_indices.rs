@@ -0,0 +1,45 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn lane_indices_native<S: Simd>(simd: S) {
+    let expected: Vec<u32> = (0..S::u32s::N).map(|i| i.try_into().unwrap()).collect();
+    assert_eq!(S::u32s::lane_indices(simd).as_slice(), expected.as_slice());
+}
+
+#[simd_test]
+fn lane_indices_i8x64<S: Simd>(simd: S) {
+    let expected: [i8; 64] = core::array::from_fn(|i| i.try_into().unwrap());
+    assert_eq!(*i8x64::lane_indices(simd), expected);
+}
+
+#[simd_test]
+fn lane_indices_u8x16<S: Simd>(simd: S) {
+    let expected: [u8; 16] = core::array::from_fn(|i| i.try_into().unwrap());
+    assert_eq!(*u8x16::lane_indices(simd), expected);
+}
+
+#[simd_test]
+fn lane_indices_i16x16<S: Simd>(simd: S) {
+    let expected: [i16; 16] = core::array::from_fn(|i| i.try_into().unwrap());
+    assert_eq!(*i16x16::lane_indices(simd), expected);
+}
+
+#[simd_test]
+fn lane_indices_i32x8<S: Simd>(simd: S) {
+    assert_eq!(*i32x8::lane_indices(simd), [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[simd_test]
+fn lane_indices_u64x8<S: Simd>(simd: S) {
+    assert_eq!(*u64x8::lane_indices(simd), [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[simd_test]
+fn lane_indices_compare_gives_tail_mask<S: Simd>(simd: S) {
+    let mask = u16x8::lane_indices(simd).simd_lt(5);
+    assert_eq!(mask.to_bitmask(), 0b0001_1111);
+}
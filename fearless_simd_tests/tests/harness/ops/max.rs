@@ -13,6 +13,13 @@ fn max_f32x4<S: Simd>(simd: S) {
     assert_eq!(*a.max(b), [2.0, -2.0, 7.0, 3.0]);
 }
 
+#[simd_test(expect(default = [2.0, _, _, _]))]
+fn max_f32x4_nan_and_zero_are_implementation_defined<S: Simd>(simd: S) -> f32x4<S> {
+    let a = f32x4::from_slice(simd, &[1.0, f32::NAN, 3.0, -0.0]);
+    let b = f32x4::from_slice(simd, &[2.0, 1.0, f32::NAN, 0.0]);
+    a.max(b)
+}
+
 #[simd_test]
 fn max_i8x16<S: Simd>(simd: S) {
     let a = i8x16::from_slice(
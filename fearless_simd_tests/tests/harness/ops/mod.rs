@@ -3,6 +3,7 @@
 
 // One module per tested operation.
 
+mod abd_accumulate;
 mod abs;
 mod add;
 mod all_false;
@@ -11,14 +12,20 @@ mod and;
 mod any_false;
 mod any_true;
 mod approximate_recip;
+mod argmin_argmax;
 mod as_array;
 mod as_array_mut;
 mod as_array_ref;
+mod as_signed;
+mod avg_round;
 mod bitcast;
 mod block_splat;
 mod ceil;
 mod combine;
+mod compress_bytes;
 mod copysign;
+mod count_ones;
+mod count_true;
 mod cvt_f32;
 mod cvt_i32;
 mod cvt_i32_precise;
@@ -26,14 +33,26 @@ mod cvt_u32;
 mod cvt_u32_precise;
 mod deinterleave;
 mod div;
+mod dot;
+mod expand_bytes;
+mod extract;
+mod extract_dyn;
+mod first_true;
 mod floor;
 mod fract;
 mod from_bitmask;
+mod from_bools;
 mod from_bytes;
 mod from_fn;
+mod from_le_bytes;
 mod from_slice;
+mod gf_mul_const;
+mod identity;
 mod index;
+mod insert;
 mod interleave;
+mod lane_indices;
+mod leading_zeros;
 mod load_array;
 mod load_array_ref;
 mod load_interleaved_128;
@@ -43,12 +62,21 @@ mod min;
 mod min_precise;
 mod mul;
 mod mul_add;
+mod mul_add_relaxed;
 mod mul_sub;
 mod narrow;
 mod native_width;
 mod neg;
+mod nmul_add;
+mod nmul_sub;
 mod not;
 mod or;
+mod pack_clamped_u8;
+mod pack_saturate;
+mod poly_eval;
+mod pow_fast;
+mod reduce_add_widened;
+mod reduce_lanes;
 mod reinterpret_f32;
 mod reinterpret_f64;
 mod reinterpret_i32;
@@ -57,20 +85,27 @@ mod reinterpret_u8;
 mod rotate_elements_left;
 mod rotate_elements_right;
 mod round_ties_even;
+mod saturating_add;
+mod saturating_sub;
 mod select;
 mod set;
 mod shift_elements_left;
 mod shift_elements_right;
+mod shift_signed;
 mod shl;
 mod shlv;
 mod shr;
 mod shrv;
+mod shuffle;
+mod shuffle2;
 mod simd_eq;
 mod simd_from;
 mod simd_ge;
 mod simd_gt;
 mod simd_le;
 mod simd_lt;
+mod simd_vector;
+mod sincos;
 mod slide;
 mod slide_within_blocks;
 mod splat;
@@ -81,12 +116,49 @@ mod store_interleaved_128;
 mod store_slice;
 mod sub;
 mod swizzle_dyn_within_blocks;
+mod table_lookup;
 mod to_bitmask;
 mod to_bytes;
+mod to_int;
+mod to_le_bytes;
+mod trailing_zeros;
+mod transpose_bits;
 mod trunc;
+mod truncate_to;
+mod try_from_slice;
 mod unzip_high;
 mod unzip_low;
 mod widen;
+mod write_to_uninit;
 mod xor;
+mod zero_extend_to;
+mod zeroed;
 mod zip_high;
 mod zip_low;
+
+/// Lanes of a `bits`-bit integer with every number of set bits from 0 to `bits`, every single set bit, and every
+/// number of leading and trailing zeros, as vectors of `N` lanes.
+///
+/// The lanes are `u64`s, to be truncated to the lane type. The last vector is filled up with the first patterns.
+fn bit_patterns<const N: usize>(bits: u32) -> Vec<[u64; N]> {
+    // The lowest `k` bits.
+    let low = |k: u32| u64::MAX.checked_shr(64 - k).unwrap_or(0);
+    let mut patterns = vec![];
+    for k in 0..=bits {
+        // `k` set bits, with `bits - k` leading or trailing zeros.
+        patterns.push(low(k));
+        patterns.push(low(bits) & !low(bits - k));
+    }
+    for i in 0..bits {
+        // A single set bit, and bits at either end with gaps of every length.
+        patterns.push(1 << i);
+        patterns.push(1 << i | 1);
+        patterns.push(1 << i | 1 << (bits - 1));
+    }
+    patterns.push(0x5555_5555_5555_5555 & low(bits));
+    patterns.push(0xaaaa_aaaa_aaaa_aaaa & low(bits));
+    (0..patterns.len())
+        .step_by(N)
+        .map(|start| core::array::from_fn(|i| patterns[(start + i) % patterns.len()]))
+        .collect()
+}
@@ -87,3 +87,33 @@ fn mul_add_f64x4<S: Simd>(simd: S) {
     let result = simd.mul_add_f64x4(a, b, c);
     assert_eq!(result.as_slice(), expected.as_slice());
 }
+
+/// Check that `mul_add` rounds once if and only if the level reports it to be fused.
+///
+/// For `a = 1 + e`, the exact `a * a` is `1 + 2e + e * e`, which rounds to `1 + 2e` when `e * e` is less than half
+/// an ULP of 1. Removing `1 + 2e` from the product then leaves `e * e` if it's fused, and `0` otherwise.
+#[simd_test]
+fn mul_add_fused_f32x4<S: Simd>(simd: S) {
+    let e = 1.0 / 8192.0;
+    let a = f32x4::splat(simd, 1.0 + e);
+    let c = f32x4::splat(simd, -(1.0 + 2.0 * e));
+    let result = a.mul_add(a, c);
+    if S::FUSED_MUL_ADD {
+        assert_eq!(*result, [e * e; 4]);
+    } else {
+        assert_eq!(*result, [0.0; 4]);
+    }
+}
+
+#[simd_test]
+fn mul_add_fused_f64x2<S: Simd>(simd: S) {
+    let e = 1.0 / 134_217_728.0;
+    let a = f64x2::splat(simd, 1.0 + e);
+    let c = f64x2::splat(simd, -(1.0 + 2.0 * e));
+    let result = a.mul_add(a, c);
+    if S::FUSED_MUL_ADD {
+        assert_eq!(*result, [e * e; 2]);
+    } else {
+        assert_eq!(*result, [0.0; 2]);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn mul_add_relaxed_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, -2.0, 7.0, 3.0]);
+    let b = f32x4::from_slice(simd, &[5.0, 4.0, 100.0, 8.0]);
+    let c = f32x4::from_slice(simd, &[2.0, -3.0, 0.0, 0.5]);
+    assert_eq!(*a.mul_add_relaxed(b, c), [7.0, -11.0, 700.0, 24.5]);
+}
+
+#[simd_test]
+fn mul_add_relaxed_f64x4<S: Simd>(simd: S) {
+    let a = f64x4::from_slice(simd, &[1.0, -2.0, 7.0, 3.0]);
+    let b = f64x4::from_slice(simd, &[5.0, 4.0, 100.0, 8.0]);
+    let c = f64x4::from_slice(simd, &[2.0, -3.0, 0.0, 0.5]);
+    assert_eq!(*a.mul_add_relaxed(b, c), [7.0, -11.0, 700.0, 24.5]);
+}
+
+/// Check that `mul_add_relaxed` rounds once or twice, and is the same as `mul_add` where it isn't relaxed.
+///
+/// As in the test of `mul_add`, this leaves `e * e` if it's fused, and `0` otherwise.
+#[simd_test]
+fn mul_add_relaxed_rounding_f32x8<S: Simd>(simd: S) {
+    let e = 1.0 / 8192.0;
+    let a = f32x8::splat(simd, 1.0 + e);
+    let c = f32x8::splat(simd, -(1.0 + 2.0 * e));
+    let result = a.mul_add_relaxed(a, c);
+    assert!(
+        *result == [e * e; 8] || *result == [0.0; 8],
+        "rounded once or twice: {:?}",
+        *result
+    );
+    if !cfg!(target_feature = "relaxed-simd") {
+        assert_eq!(*result, *a.mul_add(a, c));
+    }
+}
@@ -53,3 +53,30 @@ fn native_width_i64_u64<S: Simd>(simd: S) {
         [13, 14].repeat(S::u64s::N / 2)
     );
 }
+
+fn bits_and_lanes<S: Simd, V: SimdBase<S>>() -> (usize, usize) {
+    (V::BITS, V::N * 8 * size_of::<V::Element>())
+}
+
+#[simd_test]
+fn native_width_constants<S: Simd>(_simd: S) {
+    assert!(
+        S::NATIVE_BITS >= 128 && S::NATIVE_BITS % 128 == 0,
+        "the native width should be a whole number of 128-bit blocks"
+    );
+    for (bits, lane_bits) in [
+        bits_and_lanes::<S, S::f32s>(),
+        bits_and_lanes::<S, S::f64s>(),
+        bits_and_lanes::<S, S::u8s>(),
+        bits_and_lanes::<S, S::i16s>(),
+        bits_and_lanes::<S, S::u32s>(),
+        bits_and_lanes::<S, S::i64s>(),
+    ] {
+        assert_eq!(bits, S::NATIVE_BITS, "native types should be native width");
+        assert_eq!(bits, lane_bits, "BITS should be N times the element size");
+    }
+    // Emulated types keep their width.
+    assert_eq!(bits_and_lanes::<S, f32x4<S>>(), (128, 128));
+    assert_eq!(bits_and_lanes::<S, u16x16<S>>(), (256, 256));
+    assert_eq!(bits_and_lanes::<S, i8x64<S>>(), (512, 512));
+}
@@ -0,0 +1,52 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn nmul_add_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[2.0, 3.0, -4.0, 5.0]);
+    let b = f32x4::from_slice(simd, &[10.0, 10.0, 10.0, 0.5]);
+    let c = f32x4::from_slice(simd, &[1.0, 40.0, 3.0, 2.5]);
+    assert_eq!(*a.nmul_add(b, c), [-19.0, 10.0, 43.0, 0.0]);
+}
+
+#[simd_test]
+fn nmul_add_f64x2<S: Simd>(simd: S) {
+    let a = f64x2::from_slice(simd, &[2.0, 3.0]);
+    let b = f64x2::from_slice(simd, &[4.0, 5.0]);
+    let c = f64x2::from_slice(simd, &[1.0, 20.0]);
+    assert_eq!(*a.nmul_add(b, c), [-7.0, 5.0]);
+}
+
+#[simd_test]
+fn nmul_add_f32x8<S: Simd>(simd: S) {
+    let a = f32x8::from_slice(simd, &[2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    let c = f32x8::from_slice(simd, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(
+        *a.nmul_add(10.0, c),
+        [-19.0, -28.0, -37.0, -46.0, -55.0, -64.0, -73.0, -82.0]
+    );
+}
+
+#[simd_test]
+fn nmul_add_f64x8<S: Simd>(simd: S) {
+    let a = f64x8::from_slice(simd, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(
+        *a.nmul_add(a, 10.0),
+        [9.0, 6.0, 1.0, -6.0, -15.0, -26.0, -39.0, -54.0]
+    );
+}
+
+#[simd_test]
+fn nmul_add_matches_mul_add_of_negation<S: Simd>(simd: S) {
+    // When fused, the product isn't rounded, so `1 - x * x` keeps the low bits of `x * x`.
+    let x = f32x4::splat(simd, 1.0 + f32::EPSILON);
+    let fused = x.nmul_add(x, 1.0);
+    assert_eq!(*fused, *(-x).mul_add(x, 1.0));
+    let rounded = 1.0 - (1.0 + f32::EPSILON) * (1.0 + f32::EPSILON);
+    let exact = -2.0 * f32::EPSILON - f32::EPSILON * f32::EPSILON;
+    let expected = if S::FUSED_MUL_ADD { exact } else { rounded };
+    assert_eq!(fused[0], expected);
+}
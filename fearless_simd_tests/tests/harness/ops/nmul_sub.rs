@@ -0,0 +1,35 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn nmul_sub_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[2.0, 3.0, -4.0, 5.0]);
+    let b = f32x4::from_slice(simd, &[10.0, 10.0, 10.0, 0.5]);
+    let c = f32x4::from_slice(simd, &[1.0, -40.0, 3.0, -2.5]);
+    assert_eq!(*a.nmul_sub(b, c), [-21.0, 10.0, 37.0, 0.0]);
+}
+
+#[simd_test]
+fn nmul_sub_f64x4<S: Simd>(simd: S) {
+    let a = f64x4::from_slice(simd, &[2.0, 3.0, 4.0, 5.0]);
+    let c = f64x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(*a.nmul_sub(4.0, c), [-9.0, -14.0, -19.0, -24.0]);
+}
+
+#[simd_test]
+fn nmul_sub_f32x16<S: Simd>(simd: S) {
+    let a = f32x16::from_fn(simd, |i| i as f32);
+    let expected: [f32; 16] = core::array::from_fn(|i| -(i as f32 * 2.0) - 1.0);
+    assert_eq!(*a.nmul_sub(2.0, 1.0), expected);
+}
+
+#[simd_test]
+fn nmul_sub_sign_of_zero<S: Simd>(simd: S) {
+    // `-(a * b) - c` is `-0.0 - -0.0`, which is positive zero, unlike `-(a * b + c)`.
+    let zero = f32x4::splat(simd, 0.0);
+    let result = zero.nmul_sub(zero, -0.0);
+    assert!(result.iter().all(|x| *x == 0.0 && x.is_sign_positive()));
+}
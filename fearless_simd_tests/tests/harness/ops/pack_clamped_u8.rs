@@ -0,0 +1,51 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn pack_clamped_u8_f32x16<S: Simd>(simd: S) {
+    let a = f32x16::from_slice(
+        simd,
+        &[
+            0.0, 1.0, 127.4, 127.6, 254.9, 255.0, 255.5, 1000.0, -0.4, -1.0, 0.5, 1.5, 2.5,
+            -1000.0, 100.0, 42.0,
+        ],
+    );
+    assert_eq!(
+        *simd.pack_clamped_u8_f32x16(a),
+        [
+            0, 1, 127, 128, 255, 255, 255, 255, 0, 0, 0, 2, 2, 0, 100, 42
+        ]
+    );
+}
+
+#[simd_test]
+fn pack_clamped_u8_f32x16_special_values<S: Simd>(simd: S) {
+    let a = f32x16::from_slice(
+        simd,
+        &[
+            f32::NAN,
+            -f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::MAX,
+            f32::MIN,
+            3e9,
+            -3e9,
+            f32::MIN_POSITIVE,
+            -0.0,
+            0.49999997,
+            254.5,
+            253.5,
+            128.0,
+            64.0,
+            32.0,
+        ],
+    );
+    assert_eq!(
+        *simd.pack_clamped_u8_f32x16(a),
+        [0, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 254, 254, 128, 64, 32]
+    );
+}
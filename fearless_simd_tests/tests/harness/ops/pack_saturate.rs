@@ -0,0 +1,137 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Signed values around each boundary of `i8` and `u8`, repeated to fill a vector.
+const SIGNED: [i16; 16] = [
+    i16::MIN,
+    -1000,
+    -129,
+    -128,
+    -127,
+    -1,
+    0,
+    1,
+    126,
+    127,
+    128,
+    254,
+    255,
+    256,
+    1000,
+    i16::MAX,
+];
+
+/// Unsigned values around each boundary of `i8` and `u8`, including ones which are negative when treated as signed.
+const UNSIGNED: [u16; 16] = [
+    0, 1, 126, 127, 128, 129, 254, 255, 256, 1000, 32767, 32768, 32769, 40000, 65534, 65535,
+];
+
+fn signed<const N: usize>() -> [i16; N] {
+    core::array::from_fn(|i| SIGNED[(i * 7) % SIGNED.len()])
+}
+
+fn unsigned<const N: usize>() -> [u16; N] {
+    core::array::from_fn(|i| UNSIGNED[(i * 7) % UNSIGNED.len()])
+}
+
+fn saturate<T: TryFrom<i32> + Copy, const N: usize>(
+    values: [impl Into<i32> + Copy; N],
+    min: T,
+    max: T,
+) -> [T; N] {
+    values.map(|x| {
+        let x = x.into();
+        T::try_from(x).unwrap_or(if x < 0 { min } else { max })
+    })
+}
+
+#[simd_test]
+fn pack_saturate_i8_i16x16<S: Simd>(simd: S) {
+    let values = signed::<16>();
+    let a = i16x16::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_i8_i16x16(a),
+        saturate(values, i8::MIN, i8::MAX),
+        "i16 to i8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_u8_i16x16<S: Simd>(simd: S) {
+    let values = signed::<16>();
+    let a = i16x16::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_u8_i16x16(a),
+        saturate(values, u8::MIN, u8::MAX),
+        "i16 to u8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_i8_u16x16<S: Simd>(simd: S) {
+    let values = unsigned::<16>();
+    let a = u16x16::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_i8_u16x16(a),
+        saturate(values, i8::MIN, i8::MAX),
+        "u16 to i8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_u8_u16x16<S: Simd>(simd: S) {
+    let values = unsigned::<16>();
+    let a = u16x16::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_u8_u16x16(a),
+        saturate(values, u8::MIN, u8::MAX),
+        "u16 to u8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_i8_i16x32<S: Simd>(simd: S) {
+    let values = signed::<32>();
+    let a = i16x32::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_i8_i16x32(a),
+        saturate(values, i8::MIN, i8::MAX),
+        "i16 to i8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_u8_i16x32<S: Simd>(simd: S) {
+    let values = signed::<32>();
+    let a = i16x32::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_u8_i16x32(a),
+        saturate(values, u8::MIN, u8::MAX),
+        "i16 to u8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_i8_u16x32<S: Simd>(simd: S) {
+    let values = unsigned::<32>();
+    let a = u16x32::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_i8_u16x32(a),
+        saturate(values, i8::MIN, i8::MAX),
+        "u16 to i8"
+    );
+}
+
+#[simd_test]
+fn pack_saturate_u8_u16x32<S: Simd>(simd: S) {
+    let values = unsigned::<32>();
+    let a = u16x32::from_slice(simd, &values);
+    assert_eq!(
+        *simd.pack_saturate_u8_u16x32(a),
+        saturate(values, u8::MIN, u8::MAX),
+        "u16 to u8"
+    );
+}
@@ -0,0 +1,43 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Horner's scheme in `f64`, as the reference.
+fn horner(x: f32, coeffs: &[f32]) -> f64 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(0.0, |acc, &c| acc * f64::from(x) + f64::from(c))
+}
+
+#[simd_test]
+fn poly_eval_f32x8<S: Simd>(simd: S) {
+    let x = f32x8::from_slice(simd, &[0.0, 1.0, -1.0, 0.5, -0.25, 2.0, 0.1, -1.5]);
+    let coeffs = [1.5, -2.0, 0.25, 3.0, -0.75, 0.125, 1.0];
+    let y = poly_eval(x, coeffs);
+    for i in 0..8 {
+        let expected = horner(x[i], &coeffs);
+        assert!((f64::from(y[i]) - expected).abs() <= 1e-5 * expected.abs().max(1.0));
+    }
+}
+
+#[simd_test]
+fn poly_eval_lengths<S: Simd>(simd: S) {
+    let x = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, -3.0]);
+    assert_eq!(*poly_eval(x, []), [0.0; 4]);
+    assert_eq!(*poly_eval(x, [5.0]), [5.0; 4]);
+    assert_eq!(*poly_eval(x, [1.0, 2.0]), [1.0, 3.0, 5.0, -5.0]);
+    assert_eq!(*poly_eval(x, [1.0, 2.0, 3.0]), [1.0, 6.0, 17.0, 22.0]);
+    // Every power of two up to `x⁸` is exact for these inputs.
+    let powers = poly_eval(x, [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(*powers, [0.0, 1.0, 256.0, 6561.0]);
+}
+
+#[simd_test]
+fn poly_eval_f64x4<S: Simd>(simd: S) {
+    let x = f64x4::from_slice(simd, &[0.5, -2.0, 3.0, 10.0]);
+    let y = poly_eval(x, [1.0, -1.0, 1.0, -1.0, 1.0]);
+    assert_eq!(*y, [0.6875, 31.0, 61.0, 9091.0]);
+}
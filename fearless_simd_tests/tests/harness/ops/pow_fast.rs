@@ -0,0 +1,90 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+fn relative_error(actual: f32, expected: f64) -> f64 {
+    ((f64::from(actual) - expected) / expected).abs()
+}
+
+#[simd_test]
+fn exp2_fast_accuracy<S: Simd>(simd: S) {
+    for start in -125..125 {
+        let x = f32x16::from_fn(simd, |i| start as f32 + i as f32 / 16.0 + 0.01);
+        let y = x.exp2_fast();
+        for i in 0..16 {
+            let expected = 2_f64.powf(f64::from(x[i]));
+            assert!(relative_error(y[i], expected) <= 2.0 * f64::from(f32::EPSILON));
+        }
+    }
+}
+
+#[simd_test]
+fn log2_fast_accuracy<S: Simd>(simd: S) {
+    for start in -40..40 {
+        let x = f32x16::from_fn(simd, |i| 2_f32.powf(start as f32 * 3.0 + i as f32 * 0.19));
+        let y = x.log2_fast();
+        for i in 0..16 {
+            let expected = f64::from(x[i]).log2();
+            let error = (f64::from(y[i]) - expected).abs() / expected.abs().max(1.0);
+            assert!(error <= 2.0 * f64::from(f32::EPSILON));
+        }
+    }
+    // Around 1, where the result is small.
+    let x = f32x4::from_slice(simd, &[1.0, 1.0 + f32::EPSILON, 0.999, 1.001]);
+    let y = x.log2_fast();
+    assert_eq!(y[0], 0.0);
+    for i in 1..4 {
+        assert!(relative_error(y[i], f64::from(x[i]).log2()) <= 1e-6);
+    }
+}
+
+#[simd_test]
+fn pow_fast_accuracy<S: Simd>(simd: S) {
+    for x_step in 0..64 {
+        let x = 2_f32.powf(x_step as f32 / 8.0 - 4.0) + 0.003;
+        let y = f32x16::from_fn(simd, |i| i as f32 * 0.37 - 2.9);
+        let pow = f32x16::splat(simd, x).pow_fast(y);
+        for i in 0..16 {
+            let expected = f64::from(x).powf(f64::from(y[i]));
+            assert!(relative_error(pow[i], expected) <= 1.0 / f64::from(1 << 20));
+        }
+    }
+    // Close to the range of `f32`.
+    let x = f32x4::from_slice(simd, &[2.5, 0.5, 10.0, 3.0]);
+    let y = f32x4::from_slice(simd, &[94.0, 125.5, -37.0, 79.0]);
+    let pow = x.pow_fast(y);
+    for i in 0..4 {
+        let expected = f64::from(x[i]).powf(f64::from(y[i]));
+        assert!(relative_error(pow[i], expected) <= 1.0 / f64::from(1 << 17));
+    }
+}
+
+#[simd_test]
+fn pow_fast_special_values<S: Simd>(simd: S) {
+    let x = f32x8::from_slice(
+        simd,
+        &[0.0, 0.0, 0.0, -2.0, f32::NAN, 5.0, 1e-40, f32::INFINITY],
+    );
+    let y = f32x8::from_slice(simd, &[2.0, -2.0, 0.0, 2.0, 1.0, 0.0, 0.5, 2.0]);
+    let pow = x.pow_fast(y);
+    assert_eq!(pow[0], 0.0);
+    assert_eq!(pow[1], f32::INFINITY);
+    assert_eq!(pow[2], 1.0);
+    assert!(pow[3].is_nan());
+    assert!(pow[4].is_nan());
+    assert_eq!(pow[5], 1.0);
+    assert!(relative_error(pow[6], f64::from(x[6]).sqrt()) <= 1.0 / f64::from(1 << 17));
+    assert_eq!(pow[7], f32::INFINITY);
+
+    let log2 = f32x4::from_slice(simd, &[0.0, -1.0, f32::INFINITY, f32::NAN]).log2_fast();
+    assert_eq!(log2[0], f32::NEG_INFINITY);
+    assert!(log2[1].is_nan());
+    assert_eq!(log2[2], f32::INFINITY);
+    assert!(log2[3].is_nan());
+
+    let exp2 = f32x4::from_slice(simd, &[-200.0, 200.0, f32::NEG_INFINITY, f32::NAN]).exp2_fast();
+    assert_eq!([exp2[0], exp2[1], exp2[2]], [0.0, f32::INFINITY, 0.0]);
+    assert!(exp2[3].is_nan());
+}
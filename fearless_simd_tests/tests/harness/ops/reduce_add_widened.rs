@@ -0,0 +1,145 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn reduce_add_widened_u8x16<S: Simd>(simd: S) {
+    let a: [u8; 16] = core::array::from_fn(|i| (i * 37 + 11) as u8);
+    let expected: u32 = a.iter().map(|&x| u32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_u8x16(u8x16::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_u8x16(u8x16::splat(simd, u8::MAX)),
+        16 * 255
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_u8x64<S: Simd>(simd: S) {
+    let a: [u8; 64] = core::array::from_fn(|i| (i * 73 + 5) as u8);
+    let expected: u32 = a.iter().map(|&x| u32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_u8x64(u8x64::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_u8x64(u8x64::splat(simd, u8::MAX)),
+        64 * 255
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_i8x16<S: Simd>(simd: S) {
+    let a: [i8; 16] = core::array::from_fn(|i| (i * 37 + 11) as u8 as i8);
+    let expected: i32 = a.iter().map(|&x| i32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_i8x16(i8x16::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i8x16(i8x16::splat(simd, i8::MIN)),
+        16 * -128
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i8x16(i8x16::splat(simd, i8::MAX)),
+        16 * 127
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_i8x32<S: Simd>(simd: S) {
+    let a: [i8; 32] = core::array::from_fn(|i| (i * 91 + 3) as u8 as i8);
+    let expected: i32 = a.iter().map(|&x| i32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_i8x32(i8x32::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i8x32(i8x32::splat(simd, i8::MIN)),
+        32 * -128
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_i8x64<S: Simd>(simd: S) {
+    assert_eq!(
+        simd.reduce_add_widened_i8x64(i8x64::splat(simd, i8::MIN)),
+        64 * -128
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i8x64(i8x64::splat(simd, i8::MAX)),
+        64 * 127
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_u16x8<S: Simd>(simd: S) {
+    let a: [u16; 8] = core::array::from_fn(|i| (i as u16).wrapping_mul(40_503));
+    let expected: u32 = a.iter().map(|&x| u32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_u16x8(u16x8::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_u16x8(u16x8::splat(simd, u16::MAX)),
+        8 * 65_535
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_u16x32<S: Simd>(simd: S) {
+    let a: [u16; 32] = core::array::from_fn(|i| (i as u16).wrapping_mul(40_503));
+    let expected: u32 = a.iter().map(|&x| u32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_u16x32(u16x32::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_u16x32(u16x32::splat(simd, u16::MAX)),
+        32 * 65_535
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_i16x8<S: Simd>(simd: S) {
+    let a: [i16; 8] = core::array::from_fn(|i| (i as u16).wrapping_mul(40_503) as i16);
+    let expected: i32 = a.iter().map(|&x| i32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_i16x8(i16x8::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i16x8(i16x8::splat(simd, i16::MIN)),
+        8 * -32_768
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_i16x16<S: Simd>(simd: S) {
+    let a: [i16; 16] = core::array::from_fn(|i| (i as u16).wrapping_mul(40_503) as i16);
+    let expected: i32 = a.iter().map(|&x| i32::from(x)).sum();
+    assert_eq!(
+        simd.reduce_add_widened_i16x16(i16x16::from_slice(simd, &a)),
+        expected
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i16x16(i16x16::splat(simd, i16::MAX)),
+        16 * 32_767
+    );
+}
+
+#[simd_test]
+fn reduce_add_widened_i16x32<S: Simd>(simd: S) {
+    assert_eq!(
+        simd.reduce_add_widened_i16x32(i16x32::splat(simd, i16::MIN)),
+        32 * -32_768
+    );
+    assert_eq!(
+        simd.reduce_add_widened_i16x32(i16x32::splat(simd, i16::MAX)),
+        32 * 32_767
+    );
+}
@@ -0,0 +1,72 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn reduce_lanes_sum_u8x64<S: Simd>(simd: S) {
+    let a = u8x64::from_fn(simd, |i| u8::try_from(i * 7 % 256).unwrap());
+    let expected = a.iter().fold(0_u8, |sum, &x| sum.wrapping_add(x));
+    assert_eq!(
+        reduce_lanes(a, |a, b| a + b),
+        expected,
+        "the sum should wrap"
+    );
+}
+
+#[simd_test]
+fn reduce_lanes_in_order<S: Simd>(simd: S) {
+    // Keeping one side of each pair isn't commutative, so these find the ends only if the lanes stay in order.
+    let a = i16x32::from_fn(simd, |i| i16::try_from(i).unwrap() - 3);
+    assert_eq!(reduce_lanes(a, |a, _| a), -3, "the first lane");
+    assert_eq!(reduce_lanes(a, |_, b| b), 28, "the last lane");
+
+    let last_nonzero = |a: u32x8<S>, b: u32x8<S>| b.simd_eq(0).select(a, b);
+    for nonzero in 0..8 {
+        let v = u32x8::from_fn(simd, |i| {
+            if i <= nonzero && i % 3 != 1 {
+                10 + u32::try_from(i).unwrap()
+            } else {
+                0
+            }
+        });
+        let expected = v.iter().rev().copied().find(|&x| x != 0).unwrap_or(0);
+        assert_eq!(
+            reduce_lanes(v, last_nonzero),
+            expected,
+            "the last non-zero lane of {:?}",
+            *v
+        );
+    }
+}
+
+#[simd_test]
+fn reduce_lanes_min_with_index<S: Simd>(simd: S) {
+    let values = [5_u32, 2, 9, 2, 7, 1, 1, 8];
+    let packed = u64x8::from_fn(simd, |i| {
+        u64::from(values[i]) << 32 | u64::try_from(i).unwrap()
+    });
+    assert_eq!(
+        reduce_lanes(packed, |a, b| a.min(b)),
+        1 << 32 | 5,
+        "the first of the smallest values"
+    );
+}
+
+#[simd_test]
+fn reduce_lanes_native<S: Simd>(simd: S) {
+    let a = S::f32s::from_fn(simd, |i| f32::from(u8::try_from(i).unwrap()));
+    let n = S::f32s::N;
+    assert_eq!(
+        reduce_lanes(a, |a, b| a.max(b)),
+        f32::from(u8::try_from(n - 1).unwrap()),
+        "the largest lane"
+    );
+    let ones = S::u32s::splat(simd, 1);
+    assert_eq!(
+        reduce_lanes(ones, |a, b| a + b),
+        u32::try_from(n).unwrap(),
+        "the number of lanes"
+    );
+}
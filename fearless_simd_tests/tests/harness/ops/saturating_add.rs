@@ -0,0 +1,65 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn saturating_add_i8x16<S: Simd>(simd: S) {
+    let values: [i8; 16] = [
+        0, 1, -1, 100, -100, 127, -128, 64, -64, 27, -27, 126, -127, 5, -5, 0,
+    ];
+    let others: [i8; 16] = [
+        0, 127, -128, 100, -100, 1, -1, 64, -65, -27, 27, 2, -2, -128, 127, -128,
+    ];
+    let expected: [i8; 16] = core::array::from_fn(|i| values[i].saturating_add(others[i]));
+    let a = i8x16::from_slice(simd, &values);
+    assert_eq!(
+        *a.saturating_add(i8x16::from_slice(simd, &others)),
+        expected
+    );
+}
+
+#[simd_test]
+fn saturating_add_u8x32<S: Simd>(simd: S) {
+    let values: [u8; 32] = core::array::from_fn(|i| (i * 17) as u8);
+    let others: [u8; 32] = core::array::from_fn(|i| (i * 29 + 100) as u8);
+    let expected: [u8; 32] = core::array::from_fn(|i| values[i].saturating_add(others[i]));
+    let a = u8x32::from_slice(simd, &values);
+    assert_eq!(
+        *a.saturating_add(u8x32::from_slice(simd, &others)),
+        expected
+    );
+}
+
+#[simd_test]
+fn saturating_add_i16x8<S: Simd>(simd: S) {
+    let a = i16x8::from_slice(simd, &[1, i16::MAX, -2, i16::MIN, 0, 100, -100, 7]);
+    let b = i16x8::from_slice(simd, &[2, 1, -3, -1, 0, i16::MAX, i16::MIN, -7]);
+    assert_eq!(
+        *a.saturating_add(b),
+        [3, i16::MAX, -5, i16::MIN, 0, i16::MAX, i16::MIN, 0]
+    );
+}
+
+#[simd_test]
+fn saturating_add_u16x32<S: Simd>(simd: S) {
+    let values: [u16; 32] = core::array::from_fn(|i| (i as u16).wrapping_mul(4099));
+    let expected: [u16; 32] = core::array::from_fn(|i| values[i].saturating_add(40_000));
+    let a = u16x32::from_slice(simd, &values);
+    assert_eq!(*a.saturating_add(40_000), expected);
+}
+
+#[simd_test]
+fn saturating_add_u32x4<S: Simd>(simd: S) {
+    let a = u32x4::from_slice(simd, &[1, u32::MAX, u32::MAX - 1, 0]);
+    let b = u32x4::from_slice(simd, &[2, 1, 1, u32::MAX]);
+    assert_eq!(*a.saturating_add(b), [3, u32::MAX, u32::MAX, u32::MAX]);
+}
+
+#[simd_test]
+fn saturating_add_i64x4<S: Simd>(simd: S) {
+    let a = i64x4::from_slice(simd, &[i64::MAX, i64::MIN, -1, 5]);
+    let b = i64x4::from_slice(simd, &[1, -1, i64::MIN, -6]);
+    assert_eq!(*a.saturating_add(b), [i64::MAX, i64::MIN, i64::MIN, -1]);
+}
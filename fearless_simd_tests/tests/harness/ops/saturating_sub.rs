@@ -0,0 +1,61 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn saturating_sub_u8x16<S: Simd>(simd: S) {
+    let values: [u8; 16] = core::array::from_fn(|i| (i * 19) as u8);
+    let others: [u8; 16] = core::array::from_fn(|i| (255 - i * 13) as u8);
+    let expected: [u8; 16] = core::array::from_fn(|i| values[i].saturating_sub(others[i]));
+    let a = u8x16::from_slice(simd, &values);
+    assert_eq!(
+        *a.saturating_sub(u8x16::from_slice(simd, &others)),
+        expected
+    );
+}
+
+#[simd_test]
+fn saturating_sub_i8x64<S: Simd>(simd: S) {
+    let values: [i8; 64] = core::array::from_fn(|i| (i as i8).wrapping_mul(9));
+    let others: [i8; 64] = core::array::from_fn(|i| (i as i8).wrapping_mul(-23));
+    let expected: [i8; 64] = core::array::from_fn(|i| values[i].saturating_sub(others[i]));
+    let a = i8x64::from_slice(simd, &values);
+    assert_eq!(
+        *a.saturating_sub(i8x64::from_slice(simd, &others)),
+        expected
+    );
+}
+
+#[simd_test]
+fn saturating_sub_u16x8<S: Simd>(simd: S) {
+    let a = u16x8::from_slice(simd, &[5, 0, 1, u16::MAX, 0, 100, 7, 3]);
+    let b = u16x8::from_slice(simd, &[2, 1, 1, 0, u16::MAX, 101, 7, 4]);
+    assert_eq!(*a.saturating_sub(b), [3, 0, 0, u16::MAX, 0, 0, 0, 0]);
+}
+
+#[simd_test]
+fn saturating_sub_i16x16<S: Simd>(simd: S) {
+    let values: [i16; 16] = core::array::from_fn(|i| (i as i16 - 8) * 4096);
+    let expected: [i16; 16] = core::array::from_fn(|i| values[i].saturating_sub(-20_000));
+    let a = i16x16::from_slice(simd, &values);
+    assert_eq!(*a.saturating_sub(-20_000), expected);
+}
+
+#[simd_test]
+fn saturating_sub_i32x8<S: Simd>(simd: S) {
+    let a = i32x8::from_slice(simd, &[i32::MIN, i32::MAX, 0, -1, 5, 0, i32::MIN, 1]);
+    let b = i32x8::from_slice(simd, &[1, -1, i32::MIN, i32::MAX, 6, 0, i32::MIN, -1]);
+    assert_eq!(
+        *a.saturating_sub(b),
+        [i32::MIN, i32::MAX, i32::MAX, i32::MIN, -1, 0, 0, 2]
+    );
+}
+
+#[simd_test]
+fn saturating_sub_u64x2<S: Simd>(simd: S) {
+    let a = u64x2::from_slice(simd, &[1, u64::MAX]);
+    let b = u64x2::from_slice(simd, &[2, 1]);
+    assert_eq!(*a.saturating_sub(b), [0, u64::MAX - 1]);
+}
@@ -722,3 +722,20 @@ fn select_mask32x16<S: Simd>(simd: S) {
     let result = simd.select_mask32x16(mask, if_true, if_false);
     assert_eq!(<[i32; 16]>::from(result), expected);
 }
+
+#[simd_test]
+fn select_relaxed_matches_select<S: Simd>(simd: S) {
+    let values = f32x8::from_fn(simd, |i| i as f32);
+    let mask = values.simd_lt(f32x8::splat(simd, 3.0)) | values.simd_gt(f32x8::splat(simd, 6.0));
+    let other = f32x8::splat(simd, -1.0);
+    assert_eq!(
+        *mask.select_relaxed(values, other),
+        [0.0, 1.0, 2.0, -1.0, -1.0, -1.0, -1.0, 7.0]
+    );
+    let bytes = u8x16::from_fn(simd, |i| i as u8);
+    let mask = bytes.simd_eq(u8x16::splat(simd, 5));
+    assert_eq!(
+        *mask.select_relaxed(bytes, u8x16::splat(simd, 0)),
+        *mask.select(bytes, u8x16::splat(simd, 0))
+    );
+}
@@ -0,0 +1,98 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// The expected result of shifting `a` by a signed `amount`, which is less than the width of the lanes.
+macro_rules! shift_by {
+    ($a:expr, $amount:expr) => {
+        if $amount >= 0 {
+            $a << $amount
+        } else {
+            $a >> -$amount
+        }
+    };
+}
+
+#[simd_test]
+fn shift_signed_u32x4<S: Simd>(simd: S) {
+    let a = u32x4::from_slice(simd, &[0xF00F, 0xF00F, 0xF00F, 0x8000_0001]);
+    let amounts = i32x4::from_slice(simd, &[4, -4, 0, -31]);
+    assert_eq!(*a.shift_signed(amounts), [0xF_00F0, 0xF00, 0xF00F, 1]);
+    assert_eq!(*a.shift_signed(-1), [0x7807, 0x7807, 0x7807, 0x4000_0000]);
+}
+
+#[simd_test]
+fn shift_signed_i32x8<S: Simd>(simd: S) {
+    let a = i32x8::from_slice(simd, &[-64, -64, 64, 64, -1, i32::MIN, 3, -3]);
+    let amounts = i32x8::from_slice(simd, &[2, -2, 2, -2, -31, -31, 30, 1]);
+    assert_eq!(
+        *a.shift_signed(amounts),
+        [-256, -16, 256, 16, -1, -1, i32::MIN + (1 << 30), -6],
+        "right shifts of signed lanes should replicate the sign bit"
+    );
+}
+
+#[simd_test]
+fn shift_signed_i8x16<S: Simd>(simd: S) {
+    let values: [i8; 16] = [
+        1, -1, 127, -128, 5, -5, 64, -64, 3, -3, 17, -17, 0, 100, -100, 9,
+    ];
+    let shifts: [i8; 16] = [7, 7, -7, -7, -1, -1, 1, 1, 2, -2, -4, -4, 3, -3, 3, 0];
+    let a = i8x16::from_slice(simd, &values);
+    let expected: [i8; 16] = core::array::from_fn(|i| shift_by!(values[i], shifts[i]));
+    assert_eq!(*a.shift_signed(i8x16::from_slice(simd, &shifts)), expected);
+}
+
+#[simd_test]
+fn shift_signed_u16x16<S: Simd>(simd: S) {
+    let values: [u16; 16] = core::array::from_fn(|i| 0x8421_u16.rotate_left(i as u32));
+    let shifts: [i16; 16] = core::array::from_fn(|i| i as i16 - 8);
+    let a = u16x16::from_slice(simd, &values);
+    let expected: [u16; 16] = core::array::from_fn(|i| shift_by!(values[i], shifts[i]));
+    assert_eq!(
+        *a.shift_signed(i16x16::from_slice(simd, &shifts)),
+        expected,
+        "right shifts of unsigned lanes should shift in zeros"
+    );
+}
+
+#[simd_test]
+fn shift_signed_i64x4<S: Simd>(simd: S) {
+    let a = i64x4::from_slice(simd, &[-1 << 40, -1 << 40, 1 << 40, 7]);
+    let amounts = i64x4::from_slice(simd, &[-40, 20, -63, 60]);
+    assert_eq!(*a.shift_signed(amounts), [-1, -1 << 60, 0, 7 << 60]);
+}
+
+#[simd_test]
+fn shift_signed_u64x8<S: Simd>(simd: S) {
+    let a = u64x8::splat(simd, 0x8000_0000_0000_0001);
+    let amounts = i64x8::from_slice(simd, &[-63, 63, -1, 1, 0, -32, 32, -62]);
+    assert_eq!(
+        *a.shift_signed(amounts),
+        [
+            1,
+            0x8000_0000_0000_0000,
+            0x4000_0000_0000_0000,
+            2,
+            0x8000_0000_0000_0001,
+            0x8000_0000,
+            0x1_0000_0000,
+            2
+        ]
+    );
+}
+
+#[simd_test]
+fn shift_signed_native_matches_reference<S: Simd>(simd: S) {
+    let values: Vec<u32> = (0..S::u32s::N as u32)
+        .map(|i| 0x9e37_79b9_u32.rotate_left(i))
+        .collect();
+    let shifts: Vec<i32> = (0..S::u32s::N as i32).map(|i| i * 5 % 63 - 31).collect();
+    let a = S::u32s::from_slice(simd, &values);
+    let shifted = a.shift_signed(S::i32s::from_slice(simd, &shifts));
+    for (i, (&value, &shift)) in values.iter().zip(&shifts).enumerate() {
+        assert_eq!(shifted[i], shift_by!(value, shift), "lane {i}");
+    }
+}
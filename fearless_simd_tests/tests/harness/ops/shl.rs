@@ -269,3 +269,14 @@ fn shl_u32x8<S: Simd>(simd: S) {
     let result = simd.shl_u32x8(a, 1);
     assert_eq!(result.as_slice(), expected.as_slice());
 }
+
+#[simd_test]
+fn shl_out_of_range_does_not_panic<S: Simd>(simd: S) {
+    // The result is unspecified, but this must not panic, even in debug builds.
+    let a = u8x16::splat(simd, 1);
+    let _ = a << 8;
+    let _ = a >> 200;
+    let b = i32x8::splat(simd, -1);
+    let _ = b << 32;
+    let _ = b >> u32::MAX;
+}
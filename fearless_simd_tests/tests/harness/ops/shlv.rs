@@ -326,3 +326,14 @@ fn shlv_i32x16<S: Simd>(simd: S) {
     let result = simd.shlv_i32x16(a, shifts);
     assert_eq!(result.as_slice(), expected.as_slice());
 }
+
+#[simd_test]
+fn shlv_out_of_range_does_not_panic<S: Simd>(simd: S) {
+    // The result is unspecified, but this must not panic, even in debug builds.
+    let a = i16x8::splat(simd, 1);
+    let _ = a << i16x8::from_slice(simd, &[15, 16, 17, 100, -1, -16, i16::MIN, i16::MAX]);
+    let _ = a >> i16x8::splat(simd, 16);
+    let b = u64x4::splat(simd, 1);
+    let _ = b << u64x4::splat(simd, 64);
+    let _ = b >> u64x4::splat(simd, u64::MAX);
+}
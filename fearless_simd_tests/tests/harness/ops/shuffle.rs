@@ -0,0 +1,57 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn shuffle_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(*shuffle!(a, [3, 2, 1, 0]), [3.0, 2.0, 1.0, 0.0], "reversal");
+    assert_eq!(*shuffle!(a, [1, 2, 3, 0]), [1.0, 2.0, 3.0, 0.0], "rotation");
+    assert_eq!(
+        *shuffle!(a, [2, 2, 2, 2]),
+        [2.0; 4],
+        "broadcast of one lane"
+    );
+    assert_eq!(
+        *shuffle!(a, [0, 0, 3, 3]),
+        [0.0, 0.0, 3.0, 3.0],
+        "repeated lanes"
+    );
+}
+
+#[simd_test]
+fn shuffle_u8x16<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| u8::try_from(i).unwrap() * 10);
+    let shuffled = shuffle!(a, [2, 1, 0, 3, 6, 5, 4, 7, 10, 9, 8, 11, 14, 13, 12, 15]);
+    assert_eq!(
+        *shuffled,
+        [
+            20, 10, 0, 30, 60, 50, 40, 70, 100, 90, 80, 110, 140, 130, 120, 150
+        ],
+        "swap of the first and third channel of each pixel"
+    );
+}
+
+#[simd_test]
+fn shuffle_i16x16<S: Simd>(simd: S) {
+    let a = i16x16::from_fn(simd, |i| i16::try_from(i).unwrap());
+    // A transpose of a 4x4 matrix of lanes, which crosses the 128-bit blocks.
+    let transposed = shuffle!(a, [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15]);
+    assert_eq!(
+        *transposed,
+        [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15],
+        "lanes should be picked from across the whole vector"
+    );
+}
+
+#[simd_test]
+fn shuffle_f64x8<S: Simd>(simd: S) {
+    let a = f64x8::from_fn(simd, |i| f64::from(u8::try_from(i).unwrap()));
+    assert_eq!(
+        *shuffle!(a, [4, 5, 6, 7, 0, 1, 2, 3]),
+        [4.0, 5.0, 6.0, 7.0, 0.0, 1.0, 2.0, 3.0],
+        "swap of the halves"
+    );
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn shuffle2_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[0.0, 1.0, 2.0, 3.0]);
+    let b = f32x4::from_slice(simd, &[4.0, 5.0, 6.0, 7.0]);
+    assert_eq!(
+        *shuffle2!(a, b, [1, 2, 3, 4]),
+        [1.0, 2.0, 3.0, 4.0],
+        "sliding window across both inputs"
+    );
+    assert_eq!(
+        *shuffle2!(a, b, [0, 4, 1, 5]),
+        [0.0, 4.0, 1.0, 5.0],
+        "interleave of the low halves"
+    );
+    assert_eq!(
+        *shuffle2!(a, b, [7, 6, 5, 4]),
+        [7.0, 6.0, 5.0, 4.0],
+        "reversed second input"
+    );
+    assert_eq!(
+        *shuffle2!(a, b, [3, 3, 0, 0]),
+        [3.0, 3.0, 0.0, 0.0],
+        "repeated indices from the first input"
+    );
+}
+
+#[simd_test]
+fn shuffle2_u8x16<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| u8::try_from(i).unwrap());
+    let b = u8x16::from_fn(simd, |i| u8::try_from(i + 16).unwrap());
+    let shuffled = shuffle2!(
+        a,
+        b,
+        [31, 0, 30, 1, 29, 2, 28, 3, 27, 4, 26, 5, 25, 6, 24, 7]
+    );
+    assert_eq!(
+        *shuffled,
+        [31, 0, 30, 1, 29, 2, 28, 3, 27, 4, 26, 5, 25, 6, 24, 7],
+        "lanes should be picked from the concatenation of both inputs"
+    );
+}
+
+#[simd_test]
+fn shuffle2_f32x8<S: Simd>(simd: S) {
+    let a = f32x8::from_fn(simd, |i| f32::from(u8::try_from(i).unwrap()));
+    let b = f32x8::from_fn(simd, |i| f32::from(u8::try_from(i + 8).unwrap()));
+    assert_eq!(
+        *shuffle2!(a, b, [4, 5, 6, 7, 8, 9, 10, 11]),
+        [4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0],
+        "upper half of the first input and lower half of the second"
+    );
+    assert_eq!(
+        *shuffle2!(a, b, [0, 8, 2, 10, 4, 12, 6, 14]),
+        [0.0, 8.0, 2.0, 10.0, 4.0, 12.0, 6.0, 14.0],
+        "even lanes of both inputs, interleaved"
+    );
+}
+
+#[simd_test]
+fn shuffle2_i32x16<S: Simd>(simd: S) {
+    let a = i32x16::from_fn(simd, |i| i32::try_from(i).unwrap());
+    let b = i32x16::from_fn(simd, |i| -i32::try_from(i).unwrap() - 1);
+    let shuffled = shuffle2!(
+        a,
+        b,
+        [
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+        ]
+    );
+    assert_eq!(*shuffled, *b, "all lanes from the second input");
+}
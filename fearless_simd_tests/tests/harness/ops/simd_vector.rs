@@ -0,0 +1,37 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Counts the lanes of `v` which are greater than `threshold`, without naming the `Simd` implementation.
+fn count_greater<V>(v: V, threshold: V::Element) -> usize
+where
+    V: SimdVector,
+    V::Element: PartialOrd,
+{
+    assert_eq!(V::LANES, V::N);
+    (0..V::LANES).filter(|&i| v[i] > threshold).count()
+}
+
+fn double_all<V: SimdVector<Element = f32> + SimdFloat<V::Simd>>(v: V) -> V {
+    v * 2.0
+}
+
+#[simd_test]
+fn simd_vector_f32<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, -2.0, 3.0, 0.0]);
+    assert_eq!(count_greater(a, 0.5), 2);
+    assert_eq!(*double_all(a), [2.0, -4.0, 6.0, 0.0]);
+
+    let b = S::f32s::from_fn(simd, |i| i as f32);
+    assert_eq!(count_greater(b, 0.5), S::f32s::LANES - 1);
+    assert_eq!(double_all(b)[1], 2.0);
+}
+
+#[simd_test]
+fn simd_vector_int<S: Simd>(simd: S) {
+    assert_eq!(count_greater(u8x64::from_fn(simd, |i| i as u8), 31), 32);
+    assert_eq!(count_greater(i64x2::from_slice(simd, &[-1, 1]), 0), 1);
+    assert_eq!(<S::u16s as SimdVector>::LANES, S::NATIVE_BITS / 16);
+}
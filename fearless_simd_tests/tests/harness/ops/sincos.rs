@@ -0,0 +1,78 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+fn assert_close(actual: f32, expected: f32, x: f32, what: &str) {
+    assert!(
+        (actual - expected).abs() <= 4.0 * f32::EPSILON * expected.abs().max(0.125),
+        "{what}({x}) = {actual}, expected {expected}"
+    );
+}
+
+#[simd_test]
+fn sincos_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[0.0, core::f32::consts::FRAC_PI_2, -1.0, 3.0]);
+    let (sin, cos) = a.sincos();
+    for i in 0..4 {
+        assert_close(sin[i], a[i].sin(), a[i], "sin");
+        assert_close(cos[i], a[i].cos(), a[i], "cos");
+    }
+}
+
+#[simd_test]
+fn sincos_f32x16_range<S: Simd>(simd: S) {
+    for start in -400..400 {
+        let a = f32x16::from_fn(simd, |i| {
+            (start * 16 + i32::try_from(i).unwrap()) as f32 * 0.0371
+        });
+        let (sin, cos) = a.sincos();
+        assert_eq!(*sin, *a.sin(), "sincos and sin should agree");
+        assert_eq!(*cos, *a.cos(), "sincos and cos should agree");
+        for i in 0..16 {
+            assert_close(sin[i], a[i].sin(), a[i], "sin");
+            assert_close(cos[i], a[i].cos(), a[i], "cos");
+        }
+    }
+}
+
+#[simd_test]
+fn sincos_large_inputs<S: Simd>(simd: S) {
+    let a = f32x8::from_slice(
+        simd,
+        &[
+            1000.0, -1234.5, 5000.25, 8192.0, -9999.0, 10000.0, 100.0, 12345.0,
+        ],
+    );
+    let (sin, cos) = a.sincos();
+    for i in 0..8 {
+        let x = f64::from(a[i]);
+        assert!(
+            (f64::from(sin[i]) - x.sin()).abs() < 1e-6,
+            "sin({x}) = {}",
+            sin[i]
+        );
+        assert!(
+            (f64::from(cos[i]) - x.cos()).abs() < 1e-6,
+            "cos({x}) = {}",
+            cos[i]
+        );
+    }
+}
+
+#[simd_test]
+fn sincos_special_values<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0]);
+    let (sin, cos) = a.sincos();
+    assert!(
+        sin.as_slice()[..3].iter().all(|x| x.is_nan()),
+        "sin of NaN and infinities is NaN"
+    );
+    assert!(
+        cos.as_slice()[..3].iter().all(|x| x.is_nan()),
+        "cos of NaN and infinities is NaN"
+    );
+    assert_eq!(sin[3], 0.0, "sin(-0.0) is zero");
+    assert_eq!(cos[3], 1.0, "cos(-0.0) is one");
+}
@@ -0,0 +1,58 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Look up each index in the 16-byte block of `table` which it's in, giving zero if it's out of range.
+fn lookup_reference<const N: usize>(table: [u8; N], indices: [u8; N]) -> [u8; N] {
+    core::array::from_fn(|i| {
+        let block = i / 16 * 16;
+        table
+            .get(block + indices[i] as usize)
+            .filter(|_| indices[i] < 16)
+            .copied()
+            .unwrap_or(0)
+    })
+}
+
+#[simd_test]
+fn table_lookup_u8x16<S: Simd>(simd: S) {
+    let table: [u8; 16] = core::array::from_fn(|i| 100 + i as u8);
+    let indices: [u8; 16] = [
+        0, 15, 3, 16, 17, 31, 32, 127, 128, 143, 255, 8, 8, 1, 0x70, 0x8f,
+    ];
+    let result = simd.table_lookup_u8x16(table.simd_into(simd), indices.simd_into(simd));
+    assert_eq!(
+        *result,
+        [100, 115, 103, 0, 0, 0, 0, 0, 0, 0, 0, 108, 108, 101, 0, 0],
+        "indices of 16 or more should give zero"
+    );
+}
+
+#[simd_test]
+fn table_lookup_u8x32<S: Simd>(simd: S) {
+    let table: [u8; 32] = core::array::from_fn(|i| i as u8 * 3);
+    let indices: [u8; 32] = core::array::from_fn(|i| (i as u8).wrapping_mul(37) % 24);
+    let result = simd.table_lookup_u8x32(table.simd_into(simd), indices.simd_into(simd));
+    assert_eq!(*result, lookup_reference(table, indices));
+}
+
+#[simd_test]
+fn table_lookup_u8x64<S: Simd>(simd: S) {
+    // Map each nibble to its hexadecimal digit.
+    let digits: [u8; 16] = *b"0123456789abcdef";
+    let table = u8x64::block_splat(digits.simd_into(simd));
+    let bytes: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(73));
+    let nibbles = u8x64::from_slice(simd, &bytes) & 0x0f;
+    let result = simd.table_lookup_u8x64(table, nibbles);
+    let expected: [u8; 64] = core::array::from_fn(|i| digits[(bytes[i] & 0x0f) as usize]);
+    assert_eq!(*result, expected);
+    for indices in [0_u8, 15, 16, 200] {
+        let indices = u8x64::splat(simd, indices);
+        assert_eq!(
+            *simd.table_lookup_u8x64(table, indices),
+            lookup_reference(*table, *indices)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn to_int_mask8x16<S: Simd>(simd: S) {
+    let values: [i8; 16] = core::array::from_fn(|i| if i % 3 == 0 { -1 } else { 0 });
+    let a = mask8x16::from_slice(simd, &values);
+    assert_eq!(*a.to_int(), values);
+}
+
+#[simd_test]
+fn to_int_mask16x16<S: Simd>(simd: S) {
+    let values: [i16; 16] = core::array::from_fn(|i| if i % 5 == 1 { -1 } else { 0 });
+    let a = mask16x16::from_slice(simd, &values);
+    assert_eq!(*a.to_int(), values);
+}
+
+#[simd_test]
+fn to_int_mask32x4<S: Simd>(simd: S) {
+    let a = mask32x4::from_slice(simd, &[-1, 0, 0, -1]);
+    assert_eq!(*a.to_int(), [-1, 0, 0, -1]);
+}
+
+#[simd_test]
+fn to_int_mask64x8<S: Simd>(simd: S) {
+    let a = mask64x8::from_bitmask(simd, 0b1000_0110);
+    assert_eq!(*a.to_int(), [0, -1, -1, 0, 0, 0, 0, -1]);
+}
+
+#[simd_test]
+fn to_int_mask8x64<S: Simd>(simd: S) {
+    let a = mask8x64::from_bitmask(simd, (1 << 63) | 1);
+    let expected: [i8; 64] = core::array::from_fn(|i| if i == 0 || i == 63 { -1 } else { 0 });
+    assert_eq!(*a.to_int(), expected);
+}
+
+#[simd_test]
+fn neg_mask32x8<S: Simd>(simd: S) {
+    let a = mask32x8::from_bitmask(simd, 0b1010_0011);
+    assert_eq!(*(-a), [1, 1, 0, 0, 0, 1, 0, 1]);
+}
+
+#[simd_test]
+fn neg_mask16x8<S: Simd>(simd: S) {
+    let a = mask16x8::from_bitmask(simd, 0b0110_0001);
+    assert_eq!(*(-a), [1, 0, 0, 0, 0, 1, 1, 0]);
+}
+
+#[simd_test]
+fn count_matches_by_subtracting_masks<S: Simd>(simd: S) {
+    let haystack: [u8; 64] = core::array::from_fn(|i| (i * 7 % 11) as u8);
+    let needle = u8x16::splat(simd, 3);
+    let mut counts = i8x16::splat(simd, 0);
+    for chunk in haystack.chunks_exact(16) {
+        counts -= u8x16::from_slice(simd, chunk).simd_eq(needle).to_int();
+    }
+    let total: i32 = counts.as_slice().iter().map(|&c| i32::from(c)).sum();
+    let expected = haystack.iter().filter(|&&b| b == 3).count();
+    assert_eq!(usize::try_from(total).unwrap(), expected);
+}
+
+#[simd_test]
+fn count_matches_generic<S: Simd>(simd: S) {
+    let data: [i32; 64] = core::array::from_fn(|i| i32::try_from(i).unwrap() % 5);
+    let mut counts = S::i32s::splat(simd, 0);
+    for chunk in data.chunks_exact(S::i32s::N) {
+        let is_zero = S::i32s::from_slice(simd, chunk).simd_eq(0);
+        counts += -is_zero;
+    }
+    let total: i32 = counts.as_slice().iter().sum();
+    assert_eq!(total, 13);
+}
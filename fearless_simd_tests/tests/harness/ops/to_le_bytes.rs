@@ -0,0 +1,44 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+// These hold on every target, so they also check the byte order on big-endian ones.
+
+#[simd_test]
+fn to_le_bytes_u32x4<S: Simd>(simd: S) {
+    let a = u32x4::from_slice(simd, &[0x0403_0201, 0x0807_0605, 0x0c0b_0a09, 0x100f_0e0d]);
+    let expected: [u8; 16] = core::array::from_fn(|i| i as u8 + 1);
+    assert_eq!(*a.to_le_bytes(), expected);
+}
+
+#[simd_test]
+fn to_le_bytes_i16x16<S: Simd>(simd: S) {
+    let a = i16x16::from_fn(simd, |i| -(i as i16) * 0x0101 - 0x0102);
+    let expected: Vec<u8> = a.iter().flat_map(|lane| lane.to_le_bytes()).collect();
+    assert_eq!(a.to_le_bytes().as_slice(), expected);
+}
+
+#[simd_test]
+fn to_le_bytes_f64x2<S: Simd>(simd: S) {
+    let a = f64x2::from_slice(simd, &[1.0, -0.1]);
+    let expected: Vec<u8> = [1.0_f64, -0.1]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    assert_eq!(a.to_le_bytes().as_slice(), expected);
+}
+
+#[simd_test]
+fn to_le_bytes_u8x16_is_unchanged<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| i as u8 * 3);
+    assert_eq!(*a.to_le_bytes(), *a);
+}
+
+#[simd_test]
+fn to_bytes_uses_native_byte_order<S: Simd>(simd: S) {
+    let a = u64x4::from_slice(simd, &[0x0102_0304_0506_0708, 1, u64::MAX - 1, 0xdead_beef]);
+    let expected: Vec<u8> = a.iter().flat_map(|lane| lane.to_ne_bytes()).collect();
+    assert_eq!(a.to_bytes().as_slice(), expected);
+}
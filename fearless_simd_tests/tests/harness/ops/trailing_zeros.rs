@@ -0,0 +1,81 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+use super::bit_patterns;
+
+#[simd_test]
+fn trailing_zeros_u8x16<S: Simd>(simd: S) {
+    for values in bit_patterns::<16>(8) {
+        let values: [u8; 16] = values.map(|x| x as u8);
+        let expected: [u8; 16] = values.map(|x| x.trailing_zeros() as u8);
+        assert_eq!(*u8x16::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_i8x32<S: Simd>(simd: S) {
+    for values in bit_patterns::<32>(8) {
+        let values: [i8; 32] = values.map(|x| x as i8);
+        let expected: [i8; 32] = values.map(|x| x.trailing_zeros() as i8);
+        assert_eq!(*i8x32::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_i16x8<S: Simd>(simd: S) {
+    for values in bit_patterns::<8>(16) {
+        let values: [i16; 8] = values.map(|x| x as i16);
+        let expected: [i16; 8] = values.map(|x| x.trailing_zeros() as i16);
+        assert_eq!(*i16x8::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_u16x16<S: Simd>(simd: S) {
+    for values in bit_patterns::<16>(16) {
+        let values: [u16; 16] = values.map(|x| x as u16);
+        let expected: [u16; 16] = values.map(|x| x.trailing_zeros() as u16);
+        assert_eq!(
+            *u16x16::from_slice(simd, &values).trailing_zeros(),
+            expected
+        );
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_u32x4<S: Simd>(simd: S) {
+    for values in bit_patterns::<4>(32) {
+        let values: [u32; 4] = values.map(|x| x as u32);
+        let expected: [u32; 4] = values.map(u32::trailing_zeros);
+        assert_eq!(*u32x4::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_i32x8<S: Simd>(simd: S) {
+    for values in bit_patterns::<8>(32) {
+        let values: [i32; 8] = values.map(|x| x as i32);
+        let expected: [i32; 8] = values.map(|x| x.trailing_zeros().cast_signed());
+        assert_eq!(*i32x8::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_u64x2<S: Simd>(simd: S) {
+    for values in bit_patterns::<2>(64) {
+        let expected: [u64; 2] = values.map(|x| x.trailing_zeros() as u64);
+        assert_eq!(*u64x2::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
+
+#[simd_test]
+fn trailing_zeros_i64x8<S: Simd>(simd: S) {
+    for values in bit_patterns::<8>(64) {
+        let values: [i64; 8] = values.map(u64::cast_signed);
+        let expected: [i64; 8] = values.map(|x| x.trailing_zeros() as i64);
+        assert_eq!(*i64x8::from_slice(simd, &values).trailing_zeros(), expected);
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+fn transpose_8x8(x: u64) -> u64 {
+    let mut result = 0;
+    for r in 0..8 {
+        for c in 0..8 {
+            result |= ((x >> (8 * r + c)) & 1) << (8 * c + r);
+        }
+    }
+    result
+}
+
+fn transpose_16x16(rows: [u16; 16]) -> [u16; 16] {
+    core::array::from_fn(|c| (0..16).fold(0, |row, r| row | (((rows[r] >> c) & 1) << r)))
+}
+
+/// Pseudo-random matrices, so that every bit is exercised.
+fn matrices<const N: usize>(seed: u64) -> [u64; N] {
+    let mut state = seed;
+    core::array::from_fn(|_| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        state ^ (state >> 29)
+    })
+}
+
+#[simd_test]
+fn transpose_bits_8x8_u64x2<S: Simd>(simd: S) {
+    // The main diagonal is fixed, and the first row becomes the first column.
+    let a = u64x2::from_slice(simd, &[0x8040_2010_0804_0201, 0xFF]);
+    assert_eq!(
+        *simd.transpose_bits_8x8_u64x2(a),
+        [0x8040_2010_0804_0201, 0x0101_0101_0101_0101]
+    );
+}
+
+#[simd_test]
+fn transpose_bits_8x8_u64x4<S: Simd>(simd: S) {
+    let values = matrices::<4>(1);
+    let a = u64x4::from_slice(simd, &values);
+    assert_eq!(*simd.transpose_bits_8x8_u64x4(a), values.map(transpose_8x8));
+}
+
+#[simd_test]
+fn transpose_bits_8x8_u64x8<S: Simd>(simd: S) {
+    let values = matrices::<8>(2);
+    let a = u64x8::from_slice(simd, &values);
+    let transposed = simd.transpose_bits_8x8_u64x8(a);
+    assert_eq!(*transposed, values.map(transpose_8x8));
+    assert_eq!(*simd.transpose_bits_8x8_u64x8(transposed), values);
+}
+
+#[simd_test]
+fn transpose_bits_16x16_u16x16<S: Simd>(simd: S) {
+    let identity: [u16; 16] = core::array::from_fn(|i| 1 << i);
+    let a = u16x16::from_slice(simd, &identity);
+    assert_eq!(*simd.transpose_bits_16x16_u16x16(a), identity);
+
+    let mut first_row = [0_u16; 16];
+    first_row[0] = 0xFFFF;
+    let a = u16x16::from_slice(simd, &first_row);
+    assert_eq!(*simd.transpose_bits_16x16_u16x16(a), [1; 16]);
+
+    for seed in 0..4 {
+        let words = matrices::<4>(seed);
+        let rows: [u16; 16] = core::array::from_fn(|i| (words[i / 4] >> (16 * (i % 4))) as u16);
+        let a = u16x16::from_slice(simd, &rows);
+        assert_eq!(*simd.transpose_bits_16x16_u16x16(a), transpose_16x16(rows));
+    }
+}
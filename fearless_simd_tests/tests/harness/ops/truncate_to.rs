@@ -0,0 +1,34 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn truncate_to_f32x8_f32x4<S: Simd>(simd: S) {
+    let a = f32x8::from_slice(simd, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let b: f32x4<S> = a.truncate_to();
+    assert_eq!(*b, [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[simd_test]
+fn truncate_to_u32x16_u32x4<S: Simd>(simd: S) {
+    let a = u32x16::from_fn(simd, |i| u32::try_from(i).unwrap() * 10);
+    let b = a.truncate_to::<u32x4<S>>();
+    assert_eq!(*b, [0, 10, 20, 30]);
+}
+
+#[simd_test]
+fn truncate_to_i8x64_i8x32<S: Simd>(simd: S) {
+    let a = i8x64::from_fn(simd, |i| i8::try_from(i).unwrap() - 32);
+    let b = a.truncate_to::<i8x32<S>>();
+    let expected: [i8; 32] = core::array::from_fn(|i| i8::try_from(i).unwrap() - 32);
+    assert_eq!(*b, expected);
+}
+
+#[simd_test]
+fn truncate_to_inverts_zero_extend_to<S: Simd>(simd: S) {
+    let a = u64x2::from_slice(simd, &[u64::MAX, 7]);
+    let b = a.zero_extend_to::<u64x8<S>>().truncate_to::<u64x2<S>>();
+    assert_eq!(*b, *a);
+}
@@ -0,0 +1,31 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn try_from_slice_f32x8<S: Simd>(simd: S) {
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    let a = f32x8::try_from_slice(simd, &values[..8]).expect("the slice has 8 elements");
+    assert_eq!(*a, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert!(
+        f32x8::try_from_slice(simd, &values).is_none(),
+        "a longer slice should be rejected"
+    );
+    assert!(
+        f32x8::try_from_slice(simd, &values[..7]).is_none(),
+        "a shorter slice should be rejected"
+    );
+}
+
+#[simd_test]
+fn try_from_slice_native<S: Simd>(simd: S) {
+    let values: Vec<u16> = (0..=u16::try_from(S::u16s::N).unwrap()).collect();
+    let a = S::u16s::try_from_slice(simd, &values[1..]).expect("the slice fills a vector");
+    assert_eq!(a.as_slice(), &values[1..]);
+    assert!(
+        S::u16s::try_from_slice(simd, &[]).is_none(),
+        "an empty slice should be rejected"
+    );
+}
@@ -35,3 +35,95 @@ fn widen_u8x32<S: Simd>(simd: S) {
         ]
     );
 }
+
+// Values at the edges of each element type, which must be sign- or zero-extended.
+
+#[simd_test]
+fn widen_i8x16<S: Simd>(simd: S) {
+    let corners = [i8::MIN, -1, 0, 1, i8::MAX];
+    let values: [i8; 16] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = i8x16::from_slice(simd, &values);
+    let expected: [i16; 16] = core::array::from_fn(|i| i16::from(values[i]));
+    assert_eq!(*simd.widen_i8x16(a), expected);
+}
+
+#[simd_test]
+fn widen_i16x8<S: Simd>(simd: S) {
+    let corners = [i16::MIN, -1, 0, 1, i16::MAX];
+    let values: [i16; 8] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = i16x8::from_slice(simd, &values);
+    let expected: [i32; 8] = core::array::from_fn(|i| i32::from(values[i]));
+    assert_eq!(*simd.widen_i16x8(a), expected);
+}
+
+#[simd_test]
+fn widen_i32x4<S: Simd>(simd: S) {
+    let corners = [i32::MIN, -1, 0, 1, i32::MAX];
+    let values: [i32; 4] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = i32x4::from_slice(simd, &values);
+    let expected: [i64; 4] = core::array::from_fn(|i| i64::from(values[i]));
+    assert_eq!(*simd.widen_i32x4(a), expected);
+}
+
+#[simd_test]
+fn widen_u16x8<S: Simd>(simd: S) {
+    let corners = [0, 1, u16::MAX / 2 + 1, u16::MAX];
+    let values: [u16; 8] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = u16x8::from_slice(simd, &values);
+    let expected: [u32; 8] = core::array::from_fn(|i| u32::from(values[i]));
+    assert_eq!(*simd.widen_u16x8(a), expected);
+}
+
+#[simd_test]
+fn widen_u32x4<S: Simd>(simd: S) {
+    let corners = [0, 1, u32::MAX / 2 + 1, u32::MAX];
+    let values: [u32; 4] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = u32x4::from_slice(simd, &values);
+    let expected: [u64; 4] = core::array::from_fn(|i| u64::from(values[i]));
+    assert_eq!(*simd.widen_u32x4(a), expected);
+}
+
+#[simd_test]
+fn widen_i8x32<S: Simd>(simd: S) {
+    let corners = [i8::MIN, -1, 0, 1, i8::MAX];
+    let values: [i8; 32] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = i8x32::from_slice(simd, &values);
+    let expected: [i16; 32] = core::array::from_fn(|i| i16::from(values[i]));
+    assert_eq!(*simd.widen_i8x32(a), expected);
+}
+
+#[simd_test]
+fn widen_i16x16<S: Simd>(simd: S) {
+    let corners = [i16::MIN, -1, 0, 1, i16::MAX];
+    let values: [i16; 16] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = i16x16::from_slice(simd, &values);
+    let expected: [i32; 16] = core::array::from_fn(|i| i32::from(values[i]));
+    assert_eq!(*simd.widen_i16x16(a), expected);
+}
+
+#[simd_test]
+fn widen_i32x8<S: Simd>(simd: S) {
+    let corners = [i32::MIN, -1, 0, 1, i32::MAX];
+    let values: [i32; 8] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = i32x8::from_slice(simd, &values);
+    let expected: [i64; 8] = core::array::from_fn(|i| i64::from(values[i]));
+    assert_eq!(*simd.widen_i32x8(a), expected);
+}
+
+#[simd_test]
+fn widen_u16x16<S: Simd>(simd: S) {
+    let corners = [0, 1, u16::MAX / 2 + 1, u16::MAX];
+    let values: [u16; 16] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = u16x16::from_slice(simd, &values);
+    let expected: [u32; 16] = core::array::from_fn(|i| u32::from(values[i]));
+    assert_eq!(*simd.widen_u16x16(a), expected);
+}
+
+#[simd_test]
+fn widen_u32x8<S: Simd>(simd: S) {
+    let corners = [0, 1, u32::MAX / 2 + 1, u32::MAX];
+    let values: [u32; 8] = core::array::from_fn(|i| corners[i % corners.len()]);
+    let a = u32x8::from_slice(simd, &values);
+    let expected: [u64; 8] = core::array::from_fn(|i| u64::from(values[i]));
+    assert_eq!(*simd.widen_u32x8(a), expected);
+}
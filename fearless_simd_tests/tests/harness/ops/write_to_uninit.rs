@@ -0,0 +1,31 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn write_to_uninit_i16x16<S: Simd>(simd: S) {
+    let a = i16x16::from_fn(simd, |i| i16::try_from(i).unwrap() * -3);
+    let mut buf = [MaybeUninit::<i16>::uninit(); 16];
+    let written = a.write_to_uninit(&mut buf);
+    assert_eq!(written, a.as_slice());
+}
+
+#[simd_test]
+fn write_to_uninit_spare_capacity<S: Simd>(simd: S) {
+    let n = S::f32s::N;
+    let mut out = Vec::<f32>::with_capacity(2 * n);
+    let a = S::f32s::from_fn(simd, |i| i as f32 + 0.5);
+    a.write_to_uninit(&mut out.spare_capacity_mut()[..n]);
+    (a * 2.0).write_to_uninit(&mut out.spare_capacity_mut()[n..2 * n]);
+    // SAFETY: both stores above initialized `n` elements each.
+    unsafe { out.set_len(2 * n) };
+    let expected: Vec<f32> = (0..n)
+        .map(|i| i as f32 + 0.5)
+        .chain((0..n).map(|i| 2.0 * i as f32 + 1.0))
+        .collect();
+    assert_eq!(out, expected);
+}
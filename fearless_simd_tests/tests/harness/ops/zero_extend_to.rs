@@ -0,0 +1,47 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn zero_extend_to_f32x4_f32x8<S: Simd>(simd: S) {
+    let a = f32x4::from_slice(simd, &[1.0, -2.0, 3.0, -4.0]);
+    let b: f32x8<S> = a.zero_extend_to();
+    assert_eq!(*b, [1.0, -2.0, 3.0, -4.0, 0.0, 0.0, 0.0, 0.0]);
+}
+
+#[simd_test]
+fn zero_extend_to_u8x16_u8x64<S: Simd>(simd: S) {
+    let a = u8x16::from_fn(simd, |i| u8::try_from(i + 1).unwrap());
+    let b = a.zero_extend_to::<u8x64<S>>();
+    let expected: [u8; 64] = core::array::from_fn(|i| {
+        if i < 16 {
+            u8::try_from(i).unwrap() + 1
+        } else {
+            0
+        }
+    });
+    assert_eq!(*b, expected);
+}
+
+#[simd_test]
+fn zero_extend_to_i16x16_i16x32<S: Simd>(simd: S) {
+    let a = i16x16::from_fn(simd, |i| -i16::try_from(i).unwrap());
+    let b = a.zero_extend_to::<i16x32<S>>();
+    let expected: [i16; 32] = core::array::from_fn(|i| {
+        if i < 16 {
+            -i16::try_from(i).unwrap()
+        } else {
+            0
+        }
+    });
+    assert_eq!(*b, expected);
+}
+
+#[simd_test]
+fn zero_extend_to_f64x2_f64x8<S: Simd>(simd: S) {
+    let a = f64x2::from_slice(simd, &[1.5, -0.5]);
+    let b = a.zero_extend_to::<f64x8<S>>();
+    assert_eq!(*b, [1.5, -0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+}
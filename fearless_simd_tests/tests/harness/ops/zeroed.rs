@@ -0,0 +1,32 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn zeroed_f32x4<S: Simd>(simd: S) {
+    let a = f32x4::zeroed(simd);
+    assert_eq!(*a, [0.0; 4]);
+    assert!(
+        a.as_slice().iter().all(|x| x.is_sign_positive()),
+        "zero should be positive"
+    );
+}
+
+#[simd_test]
+fn zeroed_u64x8<S: Simd>(simd: S) {
+    assert_eq!(*u64x8::zeroed(simd), [0; 8]);
+}
+
+#[simd_test]
+fn zeroed_accumulator<S: Simd>(simd: S) {
+    let mut acc = S::i32s::zeroed(simd);
+    for _ in 0..3 {
+        acc += S::i32s::lane_indices(simd);
+    }
+    let expected: Vec<i32> = (0..S::i32s::N)
+        .map(|i| 3 * i32::try_from(i).unwrap())
+        .collect();
+    assert_eq!(acc.as_slice(), expected);
+}
@@ -0,0 +1,123 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that the biquad filters match a scalar implementation in every lane.
+
+use fearless_simd::iir::{Biquad, BiquadCascade, BiquadCoeffs};
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// Different stable coefficients for each lane: low-pass, high-pass and resonant sections.
+fn lane_coeffs(lane: usize) -> BiquadCoeffs {
+    let r = 0.5 + 0.03 * lane as f32;
+    let theta = 0.2 + 0.15 * lane as f32;
+    BiquadCoeffs {
+        b0: 0.25 + 0.05 * lane as f32,
+        b1: if lane.is_multiple_of(2) { 0.5 } else { -0.5 },
+        b2: 0.125,
+        a1: -2.0 * r * theta.cos(),
+        a2: r * r,
+    }
+}
+
+/// A direct-form I biquad in `f64`, which is a different structure with the same transfer function.
+fn reference(coeffs: &[BiquadCoeffs], input: &[f32]) -> Vec<f64> {
+    let mut output: Vec<f64> = input.iter().map(|&x| f64::from(x)).collect();
+    for c in coeffs {
+        let (b0, b1, b2, a1, a2) = (
+            f64::from(c.b0),
+            f64::from(c.b1),
+            f64::from(c.b2),
+            f64::from(c.a1),
+            f64::from(c.a2),
+        );
+        let (mut x1, mut x2, mut y1, mut y2) = (0.0, 0.0, 0.0, 0.0);
+        for sample in &mut output {
+            let x = *sample;
+            let y = b0 * x + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2;
+            (x2, x1, y2, y1) = (x1, x, y1, y);
+            *sample = y;
+        }
+    }
+    output
+}
+
+fn signal(lane: usize, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| ((i * (lane + 3)) % 17) as f32 / 8.0 - 1.0)
+        .collect()
+}
+
+fn assert_close(actual: &[f32], expected: &[f64], label: &str) {
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        assert!(
+            (f64::from(*a) - e).abs() <= 1e-4 * (1.0 + e.abs()),
+            "{label}: sample {i} is {a}, expected {e}"
+        );
+    }
+}
+
+#[simd_test]
+fn biquad_matches_reference_per_lane<S: Simd>(simd: S) {
+    let n = S::f32s::N;
+    let len = 200;
+    let mut frames: Vec<S::f32s> = (0..len)
+        .map(|i| S::f32s::from_fn(simd, |lane| signal(lane, len)[i]))
+        .collect();
+    let mut filter = Biquad::from_fn(simd, lane_coeffs);
+    // Split the input to check that the state carries over between calls.
+    let (first, second) = frames.split_at_mut(37);
+    filter.process(first);
+    filter.process(second);
+    for lane in 0..n {
+        let actual: Vec<f32> = frames.iter().map(|frame| frame[lane]).collect();
+        let expected = reference(&[lane_coeffs(lane)], &signal(lane, len));
+        assert_close(&actual, &expected, &format!("lane {lane}"));
+    }
+}
+
+#[simd_test]
+fn biquad_cascade_matches_reference<S: Simd>(simd: S) {
+    let n = S::f32s::N;
+    let len = 150;
+    let stage_coeffs = |stage: usize, lane: usize| lane_coeffs((lane + 3 * stage) % 7);
+    let mut cascade = BiquadCascade::new(
+        [0, 1, 2].map(|stage| Biquad::from_fn(simd, |lane| stage_coeffs(stage, lane))),
+    );
+    let mut samples: Vec<f32> = (0..len)
+        .flat_map(|i| (0..n).map(move |lane| signal(lane, len)[i]))
+        .collect();
+    cascade.process_interleaved(&mut samples);
+    for lane in 0..n {
+        let actual: Vec<f32> = samples.iter().skip(lane).step_by(n).copied().collect();
+        let coeffs = [0, 1, 2].map(|stage| stage_coeffs(stage, lane));
+        let expected = reference(&coeffs, &signal(lane, len));
+        assert_close(&actual, &expected, &format!("lane {lane}"));
+    }
+}
+
+#[simd_test]
+fn biquad_reset_and_identity<S: Simd>(simd: S) {
+    let mut filter = Biquad::splat(simd, lane_coeffs(2));
+    let impulse = [
+        S::f32s::splat(simd, 1.0),
+        S::f32s::splat(simd, 0.0),
+        S::f32s::splat(simd, 0.0),
+    ];
+    let mut first = impulse;
+    filter.process(&mut first);
+    filter.reset();
+    let mut second = impulse;
+    filter.process(&mut second);
+    for (a, b) in first.iter().zip(&second) {
+        assert_eq!(a.as_slice(), b.as_slice(), "reset should clear the state");
+    }
+
+    let mut identity = Biquad::splat(simd, BiquadCoeffs::IDENTITY);
+    let frame = S::f32s::from_fn(simd, |i| i as f32 - 2.5);
+    assert_eq!(
+        identity.process_frame(frame).as_slice(),
+        frame.as_slice(),
+        "the identity section should pass its input through"
+    );
+}
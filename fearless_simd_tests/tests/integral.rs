@@ -0,0 +1,50 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that integral images match a scalar summed-area table on every level.
+
+use fearless_simd::Simd;
+use fearless_simd::integral::integral_image;
+use fearless_simd_dev_macros::simd_test;
+
+/// The table of `integral_image`, built one pixel at a time.
+fn reference(pixels: &[u8], width: usize) -> Vec<u32> {
+    let stride = width + 1;
+    let height = pixels.len() / width;
+    let mut table = vec![0_u32; stride * (height + 1)];
+    for y in 0..height {
+        for x in 0..width {
+            table[(y + 1) * stride + x + 1] = u32::from(pixels[y * width + x])
+                .wrapping_add(table[y * stride + x + 1])
+                .wrapping_add(table[(y + 1) * stride + x])
+                .wrapping_sub(table[y * stride + x]);
+        }
+    }
+    table
+}
+
+#[simd_test]
+fn integral_image_matches_reference<S: Simd>(simd: S) {
+    let mut state = 0x2545_f491_u32;
+    let pixels: Vec<u8> = (0..70 * 9)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()[0]
+        })
+        .collect();
+    // Widths around the 16 pixels of each prefix sum, and around every vector width for the second pass.
+    for width in [1, 3, 15, 16, 17, 31, 32, 33, 63, 64, 70] {
+        for height in [0, 1, 2, 9] {
+            let pixels = &pixels[..width * height];
+            let mut table = vec![u32::MAX; (width + 1) * (height + 1)];
+            integral_image(simd, pixels, width, &mut table);
+            assert_eq!(
+                table,
+                reference(pixels, width),
+                "an image of {width}x{height} pixels"
+            );
+        }
+    }
+}
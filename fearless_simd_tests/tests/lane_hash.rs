@@ -0,0 +1,99 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that `LaneHash` and `SimdHashLanes` give the same result on every level.
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn lane_hash_matches_fallback<S: Simd>(simd: S) {
+    let fallback = Fallback::new();
+    let values: [f32; 8] = [1.5, -0.0, f32::INFINITY, 3.25, -7.0, 0.0, 1e-3, f32::MIN];
+
+    let a = f32x8::from_slice(simd, &values);
+    let b = f32x8::from_slice(fallback, &values);
+    assert_eq!(
+        (a * 2.0).lane_hash(),
+        (b * 2.0).lane_hash(),
+        "vector hashes should match the fallback level"
+    );
+    assert_eq!(
+        a.simd_gt(0.0).lane_hash(),
+        b.simd_gt(0.0).lane_hash(),
+        "comparison masks should be canonical on every level"
+    );
+
+    let bytes = u8x64::from_fn(simd, |i| u8::try_from(i * 3).unwrap());
+    let fallback_bytes = u8x64::from_fn(fallback, |i| u8::try_from(i * 3).unwrap());
+    assert_eq!(
+        bytes.simd_lt(u8x64::splat(simd, 90)).lane_hash(),
+        fallback_bytes
+            .simd_lt(u8x64::splat(fallback, 90))
+            .lane_hash(),
+        "wide masks should be canonical on every level"
+    );
+}
+
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+fn fmix64(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
+
+#[simd_test]
+fn hash_lanes_matches_scalar<S: Simd>(simd: S) {
+    let keys: [u32; 16] = core::array::from_fn(|i| {
+        u32::try_from(i).unwrap().wrapping_mul(0x9e37_79b9) ^ (1 << (i + 15))
+    });
+    assert_eq!(
+        *u32x16::from_slice(simd, &keys).hash_lanes(),
+        keys.map(fmix32),
+        "each lane should be hashed with `fmix32`"
+    );
+    assert_eq!(
+        *u32x4::from_slice(simd, &keys[..4]).hash_lanes(),
+        keys[..4].iter().map(|&k| fmix32(k)).collect::<Vec<_>>()[..],
+        "the hash shouldn't depend on the width of the vector"
+    );
+
+    let keys: [u64; 8] = core::array::from_fn(|i| {
+        u64::try_from(i)
+            .unwrap()
+            .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+            | (1 << (63 - i))
+    });
+    assert_eq!(
+        *u64x8::from_slice(simd, &keys).hash_lanes(),
+        keys.map(fmix64),
+        "each lane should be hashed with `fmix64`"
+    );
+
+    // The native-width types can be hashed in generic code.
+    let native = S::u32s::from_fn(simd, |i| u32::try_from(i).unwrap());
+    for (i, &hash) in native.hash_lanes().as_slice().iter().enumerate() {
+        assert_eq!(
+            hash,
+            fmix32(u32::try_from(i).unwrap()),
+            "lane {i} of the native vector"
+        );
+    }
+    let native = S::u64s::splat(simd, u64::MAX).hash_lanes();
+    assert!(
+        native
+            .as_slice()
+            .iter()
+            .all(|&hash| hash == fmix64(u64::MAX)),
+        "every lane of the native vector should have the same hash"
+    );
+}
@@ -0,0 +1,53 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the `lowering` table which tools use to find the intrinsics of each op.
+
+use fearless_simd::lowering::{self, LEVELS, LOWERINGS, LoweringKind};
+
+#[test]
+fn lowering_table_is_sorted_and_searchable() {
+    for pair in LOWERINGS.windows(2) {
+        assert!(
+            pair[0].method < pair[1].method,
+            "{} should sort before {}",
+            pair[0].method,
+            pair[1].method
+        );
+    }
+    for entry in LOWERINGS {
+        assert_eq!(entry.method, format!("{}_{}", entry.op, entry.vector));
+        assert_eq!(lowering::find(entry.method), Some(entry));
+    }
+    assert_eq!(lowering::find("not_a_method_f32x4"), None);
+}
+
+#[test]
+fn fallback_is_portable() {
+    for entry in LOWERINGS {
+        let fallback = entry.level("Fallback").unwrap();
+        assert_eq!(fallback.kind, LoweringKind::Portable, "{}", entry.method);
+        assert!(fallback.intrinsics.is_empty(), "{}", entry.method);
+    }
+    assert_eq!(LEVELS[0], "Fallback");
+    assert!(LOWERINGS[0].level("Rvv").is_none());
+}
+
+#[test]
+fn mul_add_lowering() {
+    let mul_add = lowering::find("mul_add_f32x4").unwrap();
+    assert_eq!(mul_add.level("Neon").unwrap().intrinsics, ["vfmaq_f32"]);
+    assert_eq!(mul_add.level("Avx2").unwrap().intrinsics, ["_mm_fmadd_ps"]);
+    // Without FMA, this is a multiplication followed by an addition.
+    let sse4_2 = mul_add.level("Sse4_2").unwrap();
+    assert_eq!(sse4_2.kind, LoweringKind::Native);
+    assert_eq!(sse4_2.intrinsics, ["_mm_add_ps", "_mm_mul_ps"]);
+
+    let wide = lowering::find("mul_add_f32x16").unwrap();
+    let avx2 = wide.level("Avx2").unwrap();
+    assert_eq!(avx2.kind, LoweringKind::Split);
+    assert!(avx2.intrinsics.contains(&"_mm256_fmadd_ps"));
+    let avx512 = wide.level("Avx512").unwrap();
+    assert_eq!(avx512.kind, LoweringKind::Native);
+    assert_eq!(avx512.intrinsics, ["_mm512_fmadd_ps"]);
+}
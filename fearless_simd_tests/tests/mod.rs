@@ -13,9 +13,40 @@
 use fearless_simd::*;
 use fearless_simd_dev_macros::simd_test;
 
+mod aligned;
+mod bf16;
+mod bitpack;
+mod dct;
+mod decimal;
+mod delta;
+#[cfg(not(miri))] // uses inline assembly
+mod denormal;
+mod determinism;
+mod fir;
+mod format;
+mod gemm_ukernel;
 mod harness;
+mod iir;
+mod integral;
+mod lane_hash;
+mod lowering;
+mod morphology;
+mod panic_free;
+mod partial;
+mod png;
+mod random;
+mod reference;
+mod rng;
+mod simd_impl;
+mod simd_op;
+mod slice;
+mod soa;
 #[cfg(not(miri))] // too slow
 mod soundness;
+mod testing;
+mod topk;
+mod varint;
+mod yuv;
 
 // Ensure that we can cast between generic native-width vectors
 #[expect(dead_code, reason = "Compile only test")]
@@ -23,6 +54,19 @@ fn generic_cast<S: Simd>(x: S::f32s) -> S::u32s {
     x.to_int()
 }
 
+// The associated integer types of native-width floats are the native-width integers
+#[expect(dead_code, reason = "Compile only test")]
+fn generic_cast_associated<S: Simd>(x: S::f32s) -> (S::i32s, S::u32s, S::f32s) {
+    let int: <S::f32s as SimdFloat<S>>::Int = x.to_int();
+    (int, x.to_uint_precise(), int.to_float::<S::f32s>())
+}
+
+// The signed and unsigned counterparts of native-width integers are also native-width
+#[expect(dead_code, reason = "Compile only test")]
+fn generic_as_signed<S: Simd>(x: S::u16s, y: S::i64s) -> (S::i16s, S::u64s) {
+    (x.as_signed(), y.as_unsigned())
+}
+
 #[allow(clippy::allow_attributes, reason = "Only needed in some cfgs.")]
 #[allow(
     unused_variables,
@@ -53,7 +97,7 @@ fn supports_highest_level() {
             "This machine does not support every routinely local-tested x86 `Level` supported by Fearless SIMD (currently AVX2 and below; AVX-512 is covered by the SDE CI job).\n{UNSUPPORTED_LEVEL_MESSAGE}",
         );
 
-        #[cfg(target_arch = "aarch64")]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
         assert!(
             level.as_neon().is_some(),
             "This machine does not support every `Level` supported by Fearless SIMD (currently NEON and below).\n{UNSUPPORTED_LEVEL_MESSAGE}",
@@ -0,0 +1,69 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that erosion and dilation match a scalar sliding window on every level.
+
+use fearless_simd::{Simd, morphology};
+use fearless_simd_dev_macros::simd_test;
+
+/// Pseudo-random pixels, so that the minimum and maximum come from each position of the window.
+fn pixels(len: usize, seed: u8) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(73).wrapping_add(41);
+            state
+        })
+        .collect()
+}
+
+/// The minimum or maximum of each pixel and its neighbors, ignoring those outside the row.
+fn reference_3x1(row: &[u8], op: fn(u8, u8) -> u8) -> Vec<u8> {
+    (0..row.len())
+        .map(|i| {
+            row[i.saturating_sub(1)..(i + 2).min(row.len())]
+                .iter()
+                .copied()
+                .reduce(op)
+                .unwrap()
+        })
+        .collect()
+}
+
+// Lengths around every vector width, including empty and single-pixel rows.
+const LENGTHS: [usize; 12] = [0, 1, 2, 15, 16, 17, 32, 33, 63, 64, 65, 200];
+
+#[simd_test]
+fn horizontal_matches_reference<S: Simd>(simd: S) {
+    for len in LENGTHS {
+        let row = pixels(len, 3);
+        let mut out = vec![0; len];
+        morphology::erode_3x1(simd, &row, &mut out);
+        assert_eq!(out, reference_3x1(&row, u8::min), "erosion of {len} pixels");
+        morphology::dilate_3x1(simd, &row, &mut out);
+        assert_eq!(
+            out,
+            reference_3x1(&row, u8::max),
+            "dilation of {len} pixels"
+        );
+    }
+}
+
+#[simd_test]
+fn vertical_matches_reference<S: Simd>(simd: S) {
+    for len in LENGTHS {
+        let rows = [pixels(len, 1), pixels(len, 2), pixels(len, 5)];
+        let rows = [&rows[0][..], &rows[1], &rows[2]];
+        let mut out = vec![0; len];
+        morphology::erode_1x3(simd, rows, &mut out);
+        let expected: Vec<u8> = (0..len)
+            .map(|i| rows[0][i].min(rows[1][i]).min(rows[2][i]))
+            .collect();
+        assert_eq!(out, expected, "erosion of {len} pixels");
+        morphology::dilate_1x3(simd, rows, &mut out);
+        let expected: Vec<u8> = (0..len)
+            .map(|i| rows[0][i].max(rows[1][i]).max(rows[2][i]))
+            .collect();
+        assert_eq!(out, expected, "dilation of {len} pixels");
+    }
+}
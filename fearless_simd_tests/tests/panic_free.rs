@@ -0,0 +1,113 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the generated operations only panic where it's documented.
+//!
+//! Real-time users rely on the operations not panicking, so this scans the generated sources for
+//! panicking macros and methods, and only allows them in the functions which take a slice or a
+//! lane index. Assertions in `const` blocks fail to compile rather than panicking, so they're
+//! allowed anywhere.
+
+const GENERATED: &[(&str, &str)] = &[
+    (
+        "fallback.rs",
+        include_str!("../../fearless_simd/src/generated/fallback.rs"),
+    ),
+    (
+        "neon.rs",
+        include_str!("../../fearless_simd/src/generated/neon.rs"),
+    ),
+    (
+        "wasm.rs",
+        include_str!("../../fearless_simd/src/generated/wasm.rs"),
+    ),
+    (
+        "sse2/simd_impl.rs",
+        include_str!("../../fearless_simd/src/generated/sse2/simd_impl.rs"),
+    ),
+    (
+        "sse4_2/simd_impl.rs",
+        include_str!("../../fearless_simd/src/generated/sse4_2/simd_impl.rs"),
+    ),
+    (
+        "avx2/simd_impl.rs",
+        include_str!("../../fearless_simd/src/generated/avx2/simd_impl.rs"),
+    ),
+    (
+        "avx512/simd_impl.rs",
+        include_str!("../../fearless_simd/src/generated/avx512/simd_impl.rs"),
+    ),
+    (
+        "simd_trait.rs",
+        include_str!("../../fearless_simd/src/generated/simd_trait.rs"),
+    ),
+    (
+        "simd_types.rs",
+        include_str!("../../fearless_simd/src/generated/simd_types.rs"),
+    ),
+];
+
+/// Code which can panic. `unreachable!` isn't included, as the generated code only uses it after
+/// matching on constants or on lengths which are known at compile time.
+const PANICKING: &[&str] = &[
+    "panic!(",
+    "assert!(",
+    "assert_eq!(",
+    "assert_ne!(",
+    "todo!(",
+    "unimplemented!(",
+    ".unwrap()",
+    ".expect(",
+];
+
+/// Whether the function with this name is documented to panic on an out-of-bounds slice or index.
+fn may_panic(function: &str) -> bool {
+    matches!(
+        function,
+        "from_slice" | "store_slice" | "write_to_uninit" | "set" | "test"
+    ) || function.starts_with("set_mask")
+        || function.starts_with("extract_dyn")
+}
+
+#[test]
+fn generated_ops_only_panic_where_documented() {
+    let mut violations = Vec::new();
+    for (file, source) in GENERATED {
+        let mut function = "";
+        let mut in_const = false;
+        for (line_number, line) in source.lines().enumerate() {
+            let line = line.trim_start();
+            // Examples in the documentation are allowed to assert.
+            if line.starts_with("#[doc") || line.starts_with("///") {
+                continue;
+            }
+            if line == "const {" {
+                in_const = true;
+            } else if line == "}" {
+                in_const = false;
+            }
+            if in_const {
+                continue;
+            }
+            if let Some((_, rest)) = line.split_once("fn ") {
+                let end = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                if end > 0 {
+                    function = &rest[..end];
+                }
+            }
+            if PANICKING.iter().any(|pattern| line.contains(pattern)) && !may_panic(function) {
+                violations.push(format!(
+                    "{file}:{}: in `{function}`: {line}",
+                    line_number + 1
+                ));
+            }
+        }
+    }
+    assert!(
+        violations.is_empty(),
+        "generated code can panic outside of the documented operations:\n{}",
+        violations.join("\n")
+    );
+}
@@ -0,0 +1,66 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of the lane validity tracking of `PartialVec`.
+
+use fearless_simd::{PartialVec, Simd, SimdBase, SimdFloat, SimdMask, f32x4, u32x8};
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn chunks_and_commit_round_trip<S: Simd>(simd: S) {
+    let values: Vec<u32> = (1..=37).collect();
+    for len in [0, 3, 8, 16, 37] {
+        let values = &values[..len];
+        let mut out = vec![0; len];
+        let mut lens = vec![];
+        let chunks = PartialVec::<S, u32x8<S>>::chunks(simd, values);
+        for (chunk, out) in chunks.zip(out.chunks_mut(8)) {
+            assert_eq!(
+                chunk.mask().to_bitmask(),
+                (1 << chunk.len()) - 1,
+                "mask of {len} elements"
+            );
+            lens.push(chunk.len());
+            (chunk * u32x8::splat(simd, 3)).commit(out);
+        }
+        let expected_lens: Vec<usize> = values.chunks(8).map(<[u32]>::len).collect();
+        assert_eq!(lens, expected_lens, "lengths of {len} elements");
+        let expected: Vec<u32> = values.iter().map(|x| x * 3).collect();
+        assert_eq!(out, expected, "stored elements of {len} elements");
+    }
+}
+
+#[simd_test]
+fn operators_keep_lanes_valid_in_both<S: Simd>(simd: S) {
+    let a = PartialVec::load(simd, &[1.0, 2.0, 3.0]);
+    let b: PartialVec<S, f32x4<S>> = PartialVec::load(simd, &[10.0, 20.0, 30.0, 40.0, 50.0]);
+    assert!(b.is_full(), "a whole vector");
+    let sum = a + b;
+    assert_eq!(sum.len(), 3, "the shorter of the operands");
+    assert_eq!(sum.valid(), [11.0, 22.0, 33.0], "the sums");
+    let scaled = -(sum * f32x4::splat(simd, 2.0));
+    assert_eq!(scaled.valid(), [-22.0, -44.0, -66.0], "with a plain vector");
+
+    // The padding is zero, which would be the minimum if it weren't replaced.
+    let min = scaled.map(|v| v.abs()).fill_padding(f32::INFINITY);
+    let min = min
+        .assume_init()
+        .as_slice()
+        .iter()
+        .copied()
+        .fold(f32::INFINITY, f32::min);
+    assert_eq!(min, 22.0, "the padding is replaced");
+
+    let empty = PartialVec::<S, f32x4<S>>::load(simd, &[]);
+    assert!(empty.is_empty() && !empty.mask().any_true(), "no lanes");
+    let mut out = [0.0; 0];
+    (empty + b).commit(&mut out);
+}
+
+#[test]
+#[should_panic(expected = "one element per valid lane")]
+fn commit_checks_the_length() {
+    let simd = fearless_simd::Fallback::new();
+    let tail = PartialVec::<_, u32x8<_>>::load(simd, &[1, 2, 3]);
+    tail.commit(&mut [0; 8]);
+}
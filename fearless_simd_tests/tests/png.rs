@@ -0,0 +1,114 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that reversing PNG filters matches the definitions in the PNG specification.
+
+use fearless_simd::Simd;
+use fearless_simd::png::{FilterType, unfilter_row};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::bytes;
+
+const FILTERS: [FilterType; 5] = [
+    FilterType::None,
+    FilterType::Sub,
+    FilterType::Up,
+    FilterType::Average,
+    FilterType::Paeth,
+];
+
+/// The Paeth predictor, as written in the PNG specification.
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = i16::from(a) + i16::from(b) - i16::from(c);
+    let pa = (p - i16::from(a)).abs();
+    let pb = (p - i16::from(b)).abs();
+    let pc = (p - i16::from(c)).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Reverse `filter` one byte at a time.
+fn reference_unfilter(filter: FilterType, bpp: usize, previous: &[u8], current: &mut [u8]) {
+    for i in 0..current.len() {
+        let a = if i >= bpp { current[i - bpp] } else { 0 };
+        let b = previous[i];
+        let c = if i >= bpp { previous[i - bpp] } else { 0 };
+        let prediction = match filter {
+            FilterType::None => 0,
+            FilterType::Sub => a,
+            FilterType::Up => b,
+            FilterType::Average => u8::try_from((u16::from(a) + u16::from(b)) / 2).unwrap(),
+            FilterType::Paeth => paeth(a, b, c),
+        };
+        current[i] = current[i].wrapping_add(prediction);
+    }
+}
+
+#[simd_test]
+fn unfilter_row_matches_reference<S: Simd>(simd: S) {
+    for filter in FILTERS {
+        for bpp in 1..=8 {
+            // Rows shorter than a vector, around the 16 bytes of each step, and long rows.
+            for pixels in [0, 1, 2, 5, 15, 16, 17, 33, 100] {
+                let len = pixels * bpp;
+                let seed = u32::try_from(len).unwrap();
+                let previous = bytes(len, seed);
+                let filtered = bytes(len, seed + 1);
+                let mut actual = filtered.clone();
+                unfilter_row(simd, filter, bpp, &previous, &mut actual);
+                let mut expected = filtered;
+                reference_unfilter(filter, bpp, &previous, &mut expected);
+                assert_eq!(
+                    actual, expected,
+                    "{filter:?} with {bpp} bytes per pixel and {pixels} pixels should match the reference"
+                );
+            }
+        }
+    }
+}
+
+#[simd_test]
+fn unfilter_row_paeth_ties<S: Simd>(simd: S) {
+    // Each combination of `a`, `b` and `c` is a pair of 1-byte pixels: `a`, with `c` above it, and then a pixel with
+    // `b` above it, which is predicted from exactly those values. This includes the ties and the largest distances.
+    let values: [u8; 6] = [0, 1, 127, 128, 254, 255];
+    let mut previous = Vec::new();
+    let mut expected = Vec::new();
+    for a in values {
+        for b in values {
+            for c in values {
+                previous.extend([c, b]);
+                expected.extend([a, 0x5a]);
+            }
+        }
+    }
+    let mut filtered = expected.clone();
+    for i in 0..filtered.len() {
+        let (a, c) = if i > 0 {
+            (expected[i - 1], previous[i - 1])
+        } else {
+            (0, 0)
+        };
+        filtered[i] = expected[i].wrapping_sub(paeth(a, previous[i], c));
+    }
+    let mut actual = filtered;
+    unfilter_row(simd, FilterType::Paeth, 1, &previous, &mut actual);
+    assert_eq!(actual, expected, "Paeth should match the specification");
+}
+
+#[test]
+fn filter_type_from_byte() {
+    for (byte, filter) in (0..).zip(FILTERS) {
+        assert_eq!(
+            FilterType::from_byte(byte),
+            Some(filter),
+            "{byte} should be {filter:?}"
+        );
+    }
+    assert_eq!(FilterType::from_byte(5), None, "5 isn't a filter type");
+}
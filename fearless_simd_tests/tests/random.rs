@@ -0,0 +1,22 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Seeded pseudo-random test data, which is the same on every target.
+
+/// `len` pseudo-random values from `seed`, each made from the generator's next state by `value`.
+///
+/// The generator is a 32 bit linear congruential generator, whose high bits are the most random.
+pub(crate) fn random<T>(len: usize, seed: u32, mut value: impl FnMut(u32) -> T) -> Vec<T> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(747_796_405).wrapping_add(2_891_336_453);
+            value(state)
+        })
+        .collect()
+}
+
+/// Pseudo-random bytes.
+pub(crate) fn bytes(len: usize, seed: u32) -> Vec<u8> {
+    random(len, seed, |state| state.to_le_bytes()[3])
+}
@@ -0,0 +1,70 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the `reference` functions agree with every level for ops with guaranteed semantics.
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn reference_matches_min_precise<S: Simd>(simd: S) {
+    let a = [1.0, f32::NAN, -0.5, f32::INFINITY];
+    let b = [f32::NAN, 2.0, -1.5, 3.0];
+    let expected = reference::min_precise_f32x4(&a, &b);
+    let actual = simd.min_precise_f32x4(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*actual, expected, "min_precise should match the reference");
+}
+
+#[simd_test]
+fn reference_matches_cvt_u32_precise<S: Simd>(simd: S) {
+    let a = [1.5, -1.0, 3e10, f32::NAN, 0.0, 4294967040.0, -0.0, 7.9];
+    let expected = reference::cvt_u32_precise_f32x8(&a);
+    assert_eq!(
+        expected,
+        [1, 0, u32::MAX, 0, 0, 4294967040, 0, 7],
+        "reference should saturate and map NaN to 0"
+    );
+    let actual = simd.cvt_u32_precise_f32x8(a.simd_into(simd));
+    assert_eq!(
+        *actual, expected,
+        "cvt_u32_precise should match the reference"
+    );
+}
+
+#[simd_test]
+fn reference_matches_shr<S: Simd>(simd: S) {
+    let a = [-128, -1, 0, 1, 2, 64, 127, -64, 5, 6, 7, 8, 9, 10, 11, -12];
+    let expected = reference::shr_i8x16(&a, 3);
+    let actual = simd.shr_i8x16(a.simd_into(simd), 3);
+    assert_eq!(*actual, expected, "shr should match the reference");
+}
+
+#[simd_test]
+fn reference_matches_compare<S: Simd>(simd: S) {
+    let a = [1.0, f32::NAN, 3.0, 4.0];
+    let b = [1.0, f32::NAN, 2.0, 5.0];
+    let expected = reference::simd_eq_f32x4(&a, &b);
+    assert_eq!(
+        expected,
+        [true, false, false, false],
+        "NaN should compare unequal to itself"
+    );
+    let actual = simd.simd_eq_f32x4(a.simd_into(simd), b.simd_into(simd));
+    for (i, expected) in expected.into_iter().enumerate() {
+        assert_eq!(
+            actual.test(i),
+            expected,
+            "lane {i} should match the reference"
+        );
+    }
+}
+
+#[simd_test]
+fn reference_matches_interleave<S: Simd>(simd: S) {
+    let a = [0, 1, 2, 3, 4, 5, 6, 7];
+    let b = [10, 11, 12, 13, 14, 15, 16, 17];
+    let (lo, hi) = reference::interleave_u32x8(&a, &b);
+    let (actual_lo, actual_hi) = simd.interleave_u32x8(a.simd_into(simd), b.simd_into(simd));
+    assert_eq!(*actual_lo, lo, "low half should match the reference");
+    assert_eq!(*actual_hi, hi, "high half should match the reference");
+}
@@ -0,0 +1,137 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that every lane of the vector random number generator is the scalar xoshiro128++ reference.
+
+use fearless_simd::rng::Xoshiro128PlusPlus;
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// The scalar xoshiro128++ generator, as in its authors' reference implementation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Reference([u32; 4]);
+
+impl Reference {
+    fn next(&mut self) -> u32 {
+        let s = &mut self.0;
+        let result = s[0].wrapping_add(s[3]).rotate_left(7).wrapping_add(s[0]);
+        let t = s[1] << 9;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(11);
+        result
+    }
+
+    fn apply_jump(&mut self, poly: [u32; 4]) {
+        let mut acc = [0; 4];
+        for word in poly {
+            for bit in 0..32 {
+                if word & (1 << bit) != 0 {
+                    for (acc, s) in acc.iter_mut().zip(self.0) {
+                        *acc ^= s;
+                    }
+                }
+                self.next();
+            }
+        }
+        self.0 = acc;
+    }
+
+    fn jump(&mut self) {
+        self.apply_jump([0x8764_000b, 0xf542_d2d3, 0x6fa0_35c3, 0x77f2_db5b]);
+    }
+
+    fn long_jump(&mut self) {
+        self.apply_jump([0xb523_952e, 0x0b6f_099f, 0xccf5_a0ef, 0x1c58_0662]);
+    }
+}
+
+/// The scalar generators for each lane of `Xoshiro128PlusPlus::from_state(simd, state)`.
+fn reference_lanes(state: [u32; 4]) -> [Reference; 8] {
+    let mut lane = Reference(state);
+    core::array::from_fn(|_| {
+        let start = lane;
+        lane.jump();
+        start
+    })
+}
+
+fn assert_matches_reference<S: Simd>(
+    rng: &mut Xoshiro128PlusPlus<S>,
+    lanes: &mut [Reference; 8],
+    what: &str,
+) {
+    for (i, lane) in lanes.iter().enumerate() {
+        assert_eq!(rng.lane_state(i), lane.0, "state of lane {i} {what}");
+    }
+    for _ in 0..20 {
+        let expected = lanes.each_mut().map(Reference::next);
+        assert_eq!(*rng.next_u32(), expected, "outputs {what}");
+    }
+}
+
+#[simd_test]
+fn lanes_are_jumped_reference_streams<S: Simd>(simd: S) {
+    let state = [1, 2, 3, 4];
+    assert_eq!(
+        Reference(state).next(),
+        (5 << 7) + 1,
+        "the reference should give the known first output"
+    );
+    let mut rng = Xoshiro128PlusPlus::from_state(simd, state);
+    assert_matches_reference(&mut rng, &mut reference_lanes(state), "after seeding");
+}
+
+#[simd_test]
+fn jumps_match_reference<S: Simd>(simd: S) {
+    let state = [0x0123_4567, 0x89ab_cdef, 0xdead_beef, 0x0bad_f00d];
+    let mut rng = Xoshiro128PlusPlus::from_state(simd, state);
+    let mut lanes = reference_lanes(state);
+
+    // Lane `i` moves to the start of lane `i + 8`.
+    rng.jump();
+    for lane in &mut lanes {
+        for _ in 0..8 {
+            lane.jump();
+        }
+    }
+    assert_matches_reference(&mut rng, &mut lanes, "after a jump");
+
+    rng.long_jump();
+    for lane in &mut lanes {
+        lane.long_jump();
+    }
+    assert_matches_reference(&mut rng, &mut lanes, "after a long jump");
+}
+
+#[simd_test]
+fn seed_from_u64_uses_splitmix64<S: Simd>(simd: S) {
+    // The first two outputs of SplitMix64 with a state of zero are 0xe220a8397b1dcdaf and 0x6e789e6aa1b965f4.
+    let rng = Xoshiro128PlusPlus::seed_from_u64(simd, 0);
+    assert_eq!(
+        rng.lane_state(0),
+        [0x7b1d_cdaf, 0xe220_a839, 0xa1b9_65f4, 0x6e78_9e6a],
+        "the seed state should be the halves of the SplitMix64 outputs"
+    );
+}
+
+#[simd_test]
+fn next_f32_uses_upper_bits<S: Simd>(simd: S) {
+    let mut rng = Xoshiro128PlusPlus::seed_from_u64(simd, 7);
+    for _ in 0..100 {
+        let mut copy = rng.clone();
+        let floats = rng.next_f32();
+        let bits = copy.next_u32();
+        for (x, bits) in floats.iter().zip(bits.iter()) {
+            assert!((0.0..1.0).contains(x), "{x} should be in [0, 1)");
+            assert_eq!(
+                f64::from(*x),
+                f64::from(bits >> 8) / f64::from(1 << 24),
+                "the float should be the upper 24 bits of {bits:#x}"
+            );
+        }
+    }
+}
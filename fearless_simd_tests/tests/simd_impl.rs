@@ -0,0 +1,104 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the `#[simd_impl]` attribute macro.
+
+use fearless_simd::{Level, Simd, SimdBase, simd_impl};
+
+struct Gain {
+    level: Level,
+    gain: f32,
+    calls: usize,
+}
+
+#[simd_impl]
+impl Gain {
+    fn new(gain: f32) -> Self {
+        Self {
+            level: Level::new(),
+            gain,
+            calls: 0,
+        }
+    }
+
+    /// Multiply every sample by the gain.
+    fn apply<S: Simd>(&mut self, simd: S, samples: &mut [f32]) {
+        self.calls += 1;
+        let mut chunks = samples.chunks_exact_mut(S::f32s::N);
+        for chunk in &mut chunks {
+            let v = S::f32s::from_slice(simd, chunk) * self.gain;
+            v.store_slice(chunk);
+        }
+        for sample in chunks.into_remainder() {
+            *sample *= self.gain;
+        }
+    }
+
+    fn sum<S: Simd>(&self, simd: S, samples: &[f32]) -> f32 {
+        let mut acc = S::f32s::splat(simd, 0.0);
+        let mut chunks = samples.chunks_exact(S::f32s::N);
+        for chunk in &mut chunks {
+            acc += S::f32s::from_slice(simd, chunk);
+        }
+        acc.as_slice().iter().sum::<f32>() + chunks.remainder().iter().sum::<f32>()
+    }
+
+    fn gain_applied_sum<S: Simd>(&mut self, simd: S, samples: &mut [f32]) -> f32 {
+        // The `_simd` methods can be called without dispatching again.
+        self.apply_simd(simd, samples);
+        self.sum_simd(simd, samples)
+    }
+}
+
+struct Nested {
+    config: Config,
+}
+
+struct Config {
+    level: Level,
+}
+
+#[simd_impl(self.config.level)]
+impl Nested {
+    fn lanes<S: Simd>(self, extra: usize, _simd: S) -> usize {
+        S::f32s::N + extra
+    }
+}
+
+#[test]
+fn simd_impl_dispatches_methods() {
+    let mut gain = Gain::new(0.5);
+    let mut samples: Vec<f32> = (0..19).map(|i| i as f32).collect();
+    gain.apply(&mut samples);
+    let expected: Vec<f32> = (0..19).map(|i| i as f32 * 0.5).collect();
+    assert_eq!(samples, expected, "apply should scale every sample");
+    assert_eq!(gain.calls, 1, "apply should run exactly once");
+    assert_eq!(gain.sum(&samples), 85.5, "sum should add every sample");
+    assert_eq!(
+        gain.gain_applied_sum(&mut samples),
+        42.75,
+        "methods can call each other's `_simd` variants"
+    );
+    assert_eq!(
+        gain.calls, 2,
+        "apply_simd should have been called once more"
+    );
+}
+
+#[test]
+fn simd_impl_custom_level_expression() {
+    let level = Level::new();
+    let nested = Nested {
+        config: Config { level },
+    };
+    let expected = fearless_simd::dispatch!(level, simd => simd_lanes(simd));
+    assert_eq!(
+        nested.lanes(1),
+        expected + 1,
+        "the level should be read from the given expression, and arguments forwarded in order"
+    );
+}
+
+fn simd_lanes<S: Simd>(_: S) -> usize {
+    S::f32s::N
+}
@@ -0,0 +1,95 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the `simd_op!` macro.
+
+use fearless_simd::{Fallback, Level, Simd, SimdBase, dispatch, i32x4, simd_op};
+use fearless_simd_dev_macros::simd_test;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use fearless_simd::SimdFrom;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{__m128i, _mm_add_epi32};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{__m128i, _mm_add_epi32};
+
+simd_op! {
+    /// Which specialization of the op is used.
+    fn specialization<S: Simd>(simd: S) -> &'static str {
+        Avx2(_avx2) -> &'static str { "avx2" }
+        Sse2(_sse2) -> &'static str { "sse2" }
+        Neon(_neon) -> &'static str { "neon" }
+        WasmSimd128(_wasm) -> &'static str { "wasm" }
+        _ => {
+            let _ = simd;
+            "portable"
+        }
+    }
+}
+
+simd_op! {
+    /// Compute `a + b * scale`, with a specialization which mixes vector and scalar arguments.
+    fn add_scaled<S: Simd>(simd: S, a: i32x4<S>, b: i32x4<S>, scale: i32) -> i32x4<S> {
+        Sse2(sse2, a: __m128i, b: __m128i, scale: i32) -> __m128i {
+            let b = i32x4::simd_from(sse2, b) * scale;
+            _mm_add_epi32(a, b.into())
+        }
+        _ => {
+            a + b * scale
+        }
+    }
+}
+
+/// The specialization which `specialization` should use for `level`.
+fn expected_specialization(level: Level) -> &'static str {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if level.as_avx2().is_some() {
+        return "avx2";
+    } else if level.as_sse2().is_some() {
+        return "sse2";
+    }
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    if level.as_neon().is_some() {
+        return "neon";
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if level.as_wasm_simd128().is_some() {
+        return "wasm";
+    }
+    let _ = level;
+    "portable"
+}
+
+#[test]
+fn simd_op_uses_strongest_specialization() {
+    let level = Level::new();
+    assert_eq!(
+        dispatch!(level, simd => specialization(simd)),
+        expected_specialization(level),
+        "`simd_op!` should pick the first specialization which the level supports"
+    );
+}
+
+#[test]
+fn simd_op_follows_level_of_fallback() {
+    // Unless `force_support_fallback` is enabled, `Fallback` reports the target's baseline level,
+    // so it can use a specialization.
+    let simd = Fallback::new();
+    assert_eq!(
+        specialization(simd),
+        expected_specialization(simd.level()),
+        "`simd_op!` should dispatch on the level of the `Simd` it's given"
+    );
+}
+
+#[simd_test]
+fn simd_op_converts_arguments_and_result<S: Simd>(simd: S) {
+    let a = i32x4::from_slice(simd, &[1, 2, 3, 4]);
+    let b = i32x4::splat(simd, 10);
+    assert_eq!(
+        <[i32; 4]>::from(add_scaled(simd, a, b, -2)),
+        [-19, -18, -17, -16],
+        "every specialization should compute the same result"
+    );
+}
@@ -0,0 +1,632 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the slice-level operations in `fearless_simd::slice`.
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn any_nan_finds_nan_in_body_and_tail<S: Simd>(simd: S) {
+    let mut values = [1.0_f32; 37];
+    assert!(!slice::any_nan(simd, &values), "no NaN yet");
+    values[2] = f32::NAN;
+    assert!(slice::any_nan(simd, &values), "NaN in a full vector");
+    values[2] = 0.0;
+    values[36] = -f32::NAN;
+    assert!(slice::any_nan(simd, &values), "NaN in the tail");
+    assert!(!slice::any_nan(simd, &[]), "empty slice");
+    assert!(
+        !slice::any_nan(simd, &[f32::INFINITY, f32::NEG_INFINITY]),
+        "infinities aren't NaN"
+    );
+}
+
+#[simd_test]
+fn max_abs_ignores_nan<S: Simd>(simd: S) {
+    let mut values = [0.5_f32; 35];
+    values[3] = -7.5;
+    values[10] = f32::NAN;
+    values[20] = 3.0;
+    values[34] = -8.0;
+    assert_eq!(
+        slice::max_abs(simd, &values),
+        8.0,
+        "largest magnitude is in the tail"
+    );
+    assert_eq!(slice::max_abs(simd, &values[..32]), 7.5, "no tail");
+    assert_eq!(slice::max_abs(simd, &values[..3]), 0.5, "only a tail");
+    assert_eq!(slice::max_abs(simd, &[]), 0.0, "empty slice");
+    assert_eq!(
+        slice::max_abs(simd, &[f32::NAN, -0.0]),
+        0.0,
+        "only NaN and zero"
+    );
+    assert_eq!(
+        slice::max_abs(simd, &[f32::NEG_INFINITY, 1.0]),
+        f32::INFINITY,
+        "infinities are the largest magnitude"
+    );
+}
+
+#[simd_test]
+fn argmin_argmax_find_first_extreme<S: Simd>(simd: S) {
+    let mut values: Vec<f32> = (0..37).map(|i| ((i * 7) % 11) as f32).collect();
+    values[13] = -2.0;
+    values[29] = -2.0;
+    values[17] = 20.0;
+    assert_eq!(
+        slice::argmin(simd, &values),
+        Some(13),
+        "first of two minima"
+    );
+    assert_eq!(
+        slice::argmax(simd, &values),
+        Some(17),
+        "maximum in the body"
+    );
+    values[36] = 30.0;
+    assert_eq!(
+        slice::argmax(simd, &values),
+        Some(36),
+        "maximum in the tail"
+    );
+    assert_eq!(slice::argmin(simd, &values[..32]), Some(13), "no tail");
+    assert_eq!(slice::argmax(simd, &values[30..33]), Some(1), "only a tail");
+    assert_eq!(
+        slice::argmin(simd, &[1.0, 0.0, -0.0]),
+        Some(1),
+        "signed zeros are equal"
+    );
+}
+
+#[simd_test]
+fn argmin_argmax_ignore_nan<S: Simd>(simd: S) {
+    let mut values = [f32::NAN; 35];
+    assert_eq!(slice::argmin(simd, &values), None, "only NaN");
+    assert_eq!(slice::argmax(simd, &values), None, "only NaN");
+    assert_eq!(slice::argmin(simd, &[]), None, "empty slice");
+    values[21] = 1.0;
+    values[34] = f32::NEG_INFINITY;
+    assert_eq!(
+        slice::argmin(simd, &values),
+        Some(34),
+        "minimum in the tail"
+    );
+    assert_eq!(
+        slice::argmax(simd, &values),
+        Some(21),
+        "NaN isn't the maximum"
+    );
+}
+
+#[simd_test]
+fn accumulate_u8_bins_matches_scalar_histogram<S: Simd>(simd: S) {
+    // Longer than a batch of 255 vectors at every width, with a tail.
+    let values: Vec<u8> = (0..40_000_u32).map(|i| (i * 37 % 23) as u8).collect();
+    let mut expected = [0_u32; 16];
+    for &v in &values {
+        if let Some(bin) = expected.get_mut(usize::from(v)) {
+            *bin += 1;
+        }
+    }
+    let mut bins = [0_u32; 16];
+    slice::accumulate_u8_bins(simd, &values, &mut bins);
+    assert_eq!(bins, expected, "values of 16 or more shouldn't be counted");
+    slice::accumulate_u8_bins(simd, &values, &mut bins);
+    assert_eq!(
+        bins,
+        expected.map(|c| c * 2),
+        "counts should be added to the existing bins"
+    );
+}
+
+#[simd_test]
+fn accumulate_u8_bins_small_bin_counts<S: Simd>(simd: S) {
+    let values = [0, 1, 2, 3, 2, 1, 0, 255, 2];
+    let mut bins = [0_u32; 3];
+    slice::accumulate_u8_bins(simd, &values, &mut bins);
+    assert_eq!(bins, [2, 2, 3], "only a tail");
+    let mut none = [0_u32; 0];
+    slice::accumulate_u8_bins(simd, &values, &mut none);
+    slice::accumulate_u8_bins(simd, &[], &mut bins);
+    assert_eq!(bins, [2, 2, 3], "empty slice");
+}
+
+#[simd_test]
+fn nibble_histogram_matches_scalar<S: Simd>(simd: S) {
+    // Longer than a batch of 127 vectors at every width, with a tail.
+    let bytes: Vec<u8> = (0..20_000_u32)
+        .map(|i| (i.wrapping_mul(0x9e37_79b9) >> 24) as u8)
+        .collect();
+    for len in [0, 1, 37, 64, bytes.len()] {
+        let bytes = &bytes[..len];
+        let mut expected = [0_u64; 16];
+        for &b in bytes {
+            expected[usize::from(b & 0x0f)] += 1;
+            expected[usize::from(b >> 4)] += 1;
+        }
+        assert_eq!(
+            slice::nibble_histogram(simd, bytes),
+            expected,
+            "{len} bytes"
+        );
+    }
+}
+
+#[simd_test]
+fn count_set_bits_matches_scalar<S: Simd>(simd: S) {
+    // Longer than a batch of 31 vectors of all ones, with a tail.
+    let ones = [0xff_u8; 1000];
+    assert_eq!(slice::count_set_bits(simd, &ones), 8000, "all ones");
+    let bytes: Vec<u8> = (0..5000_u32)
+        .map(|i| (i.wrapping_mul(0x9e37_79b9) >> 24) as u8)
+        .collect();
+    for len in [0, 1, 31, 32, 100, bytes.len()] {
+        let bytes = &bytes[..len];
+        let expected: u64 = bytes.iter().map(|b| u64::from(b.count_ones())).sum();
+        assert_eq!(slice::count_set_bits(simd, bytes), expected, "{len} bytes");
+    }
+}
+
+#[simd_test]
+fn exclusive_scan_u32_matches_scalar<S: Simd>(simd: S) {
+    let values: Vec<u32> = (0..70_u32).map(|i| i.wrapping_mul(0x9e37_79b9)).collect();
+    for len in [0, 1, 3, 4, 15, 16, 17, 64, 70] {
+        let mut scanned = values[..len].to_vec();
+        let total = slice::exclusive_scan_u32(simd, &mut scanned, 5);
+        let mut expected = Vec::with_capacity(len);
+        let mut sum = 5_u32;
+        for &v in &values[..len] {
+            expected.push(sum);
+            sum = sum.wrapping_add(v);
+        }
+        assert_eq!(scanned, expected, "prefix sums of {len} elements");
+        assert_eq!(total, sum, "total of {len} elements");
+    }
+}
+
+#[simd_test]
+fn exclusive_scan_u32_in_parts<S: Simd>(simd: S) {
+    let values: Vec<u32> = (0..50).map(|i| i % 7).collect();
+    let mut whole = values.clone();
+    let whole_total = slice::exclusive_scan_u32(simd, &mut whole, 0);
+    let mut parts = values;
+    let (first, second) = parts.split_at_mut(21);
+    let carry = slice::exclusive_scan_u32(simd, first, 0);
+    let parts_total = slice::exclusive_scan_u32(simd, second, carry);
+    assert_eq!(parts, whole, "the carry should continue the scan");
+    assert_eq!(parts_total, whole_total, "the totals should match");
+}
+
+#[simd_test]
+fn exclusive_scan_f32_is_the_same_on_every_level<S: Simd>(simd: S) {
+    let values: Vec<f32> = (0..37).map(|i| 1.0 / (i as f32 + 1.0)).collect();
+    let mut scanned = values.clone();
+    let total = slice::exclusive_scan_f32(simd, &mut scanned, 0.25);
+    let mut expected = values.clone();
+    let expected_total = slice::exclusive_scan_f32(Fallback::new(), &mut expected, 0.25);
+    assert_eq!(
+        scanned.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+        expected.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+        "every level should round in the same way"
+    );
+    assert_eq!(
+        total.to_bits(),
+        expected_total.to_bits(),
+        "the total should be the same on every level"
+    );
+    fearless_simd::assert_ulps_eq!(
+        scanned[36],
+        0.25 + values[..36].iter().sum::<f32>(),
+        4,
+        "the scan should be close to a sequential sum"
+    );
+
+    let mut integers: Vec<f32> = (0..11).map(|i| i as f32).collect();
+    assert_eq!(
+        slice::exclusive_scan_f32(simd, &mut integers, -0.0),
+        55.0,
+        "small integers should be summed exactly"
+    );
+    assert_eq!(
+        integers,
+        [0.0, 0.0, 1.0, 3.0, 6.0, 10.0, 15.0, 21.0, 28.0, 36.0, 45.0],
+        "small integers should be summed exactly"
+    );
+    assert!(
+        integers[0].is_sign_negative(),
+        "the first element should be `init`"
+    );
+}
+
+#[simd_test]
+fn first_mismatch_finds_first_difference<S: Simd>(simd: S) {
+    // Longer than the unrolled loop at every width, with a tail.
+    let a: Vec<u8> = (0..300_u32).map(|i| (i * 31 % 251) as u8).collect();
+    assert_eq!(slice::first_mismatch(simd, &a, &a), None, "equal slices");
+    assert_eq!(slice::first_mismatch(simd, &[], &[]), None, "empty slices");
+    for position in [0, 1, 15, 16, 63, 64, 200, 255, 256, 257, 299] {
+        let mut b = a.clone();
+        b[position] ^= 0x80;
+        b[299] ^= 1;
+        assert_eq!(
+            slice::first_mismatch(simd, &a, &b),
+            Some(position),
+            "the first of two differences should be found"
+        );
+        assert!(
+            !slice::eq_slices(simd, &a, &b),
+            "slices which differ at {position} aren't equal"
+        );
+    }
+}
+
+#[simd_test]
+fn first_mismatch_with_different_lengths<S: Simd>(simd: S) {
+    let a: Vec<u8> = (0..100).collect();
+    assert_eq!(
+        slice::first_mismatch(simd, &a, &a[..70]),
+        Some(70),
+        "a prefix differs at its end"
+    );
+    assert_eq!(
+        slice::first_mismatch(simd, &a[..3], &a),
+        Some(3),
+        "a short prefix differs at its end"
+    );
+    assert_eq!(
+        slice::first_mismatch(simd, &[1, 0], &[2]),
+        Some(0),
+        "a difference comes before the end of the shorter slice"
+    );
+    assert_eq!(
+        slice::first_mismatch(simd, &[0, 0], &[0, 0, 0]),
+        Some(2),
+        "zero padding isn't mistaken for data"
+    );
+    assert!(
+        slice::eq_slices(simd, &a[10..90], &a.clone()[10..90]),
+        "equal slices"
+    );
+    assert!(
+        !slice::eq_slices(simd, &a, &a[..99]),
+        "slices of different lengths aren't equal"
+    );
+    assert!(!slice::eq_slices(simd, &[], &[0]), "an empty slice");
+}
+
+/// Multiply in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1, one bit of `b` at a time.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+        b >>= 1;
+    }
+    product
+}
+
+/// Distinct pseudo-random blocks of 147 bytes, which is a whole number of vectors plus a tail at every width.
+fn blocks(count: u8) -> Vec<Vec<u8>> {
+    (0..count)
+        .map(|block| {
+            (0..147_u8)
+                .map(|i| i.wrapping_mul(73) ^ block.wrapping_mul(151).wrapping_add(5))
+                .collect()
+        })
+        .collect()
+}
+
+#[simd_test]
+fn xor_accumulate_matches_scalar<S: Simd>(simd: S) {
+    let sources = blocks(5);
+    let sources: Vec<&[u8]> = sources.iter().map(Vec::as_slice).collect();
+    let mut dst: Vec<u8> = (0..147).collect();
+    let mut expected = dst.clone();
+    for source in &sources {
+        for (e, s) in expected.iter_mut().zip(*source) {
+            *e ^= s;
+        }
+    }
+    slice::xor_accumulate(simd, &mut dst, &sources);
+    assert_eq!(dst, expected, "every source should be XORed in");
+
+    slice::xor_accumulate(simd, &mut dst, &[]);
+    assert_eq!(dst, expected, "no sources shouldn't change the destination");
+    slice::xor_accumulate(simd, &mut [], &[&[]]);
+}
+
+#[simd_test]
+fn xor_scale_accumulate_matches_scalar<S: Simd>(simd: S) {
+    // More sources than are handled in one pass over the destination.
+    let sources = blocks(11);
+    let sources: Vec<&[u8]> = sources.iter().map(Vec::as_slice).collect();
+    let coeffs = [
+        0x00, 0x01, 0x02, 0x03, 0x1d, 0x53, 0x80, 0x8d, 0xca, 0xfe, 0xff,
+    ];
+    let mut dst = vec![0x5a; 147];
+    let mut expected = dst.clone();
+    for (source, &coeff) in sources.iter().zip(&coeffs) {
+        for (e, &s) in expected.iter_mut().zip(*source) {
+            *e ^= gf_mul(s, coeff);
+        }
+    }
+    slice::xor_scale_accumulate(simd, &mut dst, &sources, &coeffs);
+    assert_eq!(dst, expected, "every product should be XORed in");
+}
+
+#[simd_test]
+fn xor_scale_accumulate_recovers_lost_blocks<S: Simd>(simd: S) {
+    // A Reed-Solomon code with two parity blocks, using the Vandermonde rows [1, 1, 1] and [1, 2, 4].
+    let data = blocks(3);
+    let data: Vec<&[u8]> = data.iter().map(Vec::as_slice).collect();
+    let mut p = vec![0; 147];
+    let mut q = vec![0; 147];
+    slice::xor_accumulate(simd, &mut p, &data);
+    slice::xor_scale_accumulate(simd, &mut q, &data, &[1, 2, 4]);
+
+    // Lose the first two blocks. Removing the last one leaves p' = d0 + d1 and q' = d0 + 2 d1, so
+    // d1 = (p' + q') / 3 and d0 = p' + d1.
+    slice::xor_accumulate(simd, &mut p, &[data[2]]);
+    slice::xor_scale_accumulate(simd, &mut q, &[data[2]], &[4]);
+    let mut d1 = vec![0; 147];
+    // 0xf6 is the inverse of 3 in this field.
+    slice::xor_scale_accumulate(simd, &mut d1, &[&p, &q], &[0xf6, 0xf6]);
+    assert_eq!(d1, data[1], "the second block should be recovered");
+    slice::xor_accumulate(simd, &mut p, &[&d1]);
+    assert_eq!(p, data[0], "the first block should be recovered");
+}
+
+#[simd_test]
+fn for_each_vector_with_tail_masks_the_tail<S: Simd>(simd: S) {
+    let values: Vec<u32> = (1..=37).collect();
+    for len in [0, 3, 16, 37] {
+        let values = &values[..len];
+        let mut visited = Vec::new();
+        slice::for_each_vector_with_tail(simd, values, |v: u32x16<S>, valid| {
+            let bits = valid.to_bitmask();
+            for (i, &x) in v.as_slice().iter().enumerate() {
+                if bits & (1 << i) != 0 {
+                    visited.push(x);
+                } else {
+                    assert_eq!(x, 0, "padding lanes should be zero");
+                }
+            }
+        });
+        assert_eq!(
+            visited, values,
+            "every element should be passed once, in order"
+        );
+    }
+}
+
+#[simd_test]
+fn for_each_vector_with_tail_native_width<S: Simd>(simd: S) {
+    let values = [1.5_f32, -2.0, 4.0, 0.25, -8.0, 3.0, 2.0];
+    let mut sum = S::f32s::splat(simd, 0.0);
+    let mut lanes = 0;
+    slice::for_each_vector_with_tail(simd, &values, |v: S::f32s, valid| {
+        sum += valid.select(v * v, S::f32s::splat(simd, 0.0));
+        lanes += valid.count_true();
+    });
+    assert_eq!(lanes, 7, "only the elements of the slice should be valid");
+    assert_eq!(
+        sum.as_slice().iter().sum::<f32>(),
+        values.iter().map(|x| x * x).sum::<f32>(),
+        "the sum of squares should only include the elements of the slice"
+    );
+}
+
+#[simd_test]
+fn process_until_finds_first_match<S: Simd>(simd: S) {
+    let text = b"key=value;other=a longer value, with a comma;last";
+    for len in 0..=text.len() {
+        let text = &text[..len];
+        let mut vectors = 0;
+        let found = slice::process_until(simd, text, |v: u8x16<S>| {
+            vectors += 1;
+            v.simd_eq(b';') | v.simd_eq(b',')
+        });
+        let expected = text.iter().position(|&c| c == b';' || c == b',');
+        assert_eq!(found, expected, "the first delimiter of {len} bytes");
+        assert_eq!(
+            vectors,
+            expected.map_or(len.div_ceil(16), |i| i / 16 + 1),
+            "the scan should stop at the vector with the first delimiter"
+        );
+    }
+}
+
+#[simd_test]
+fn process_until_ignores_the_padding<S: Simd>(simd: S) {
+    // The padding is zero, which matches this predicate, but isn't part of the slice.
+    let values = [3_u32; 37];
+    for len in [0, 5, 32, 37] {
+        assert_eq!(
+            slice::process_until(simd, &values[..len], |v: S::u32s| v.simd_eq(0)),
+            None,
+            "the padding of {len} elements shouldn't match"
+        );
+    }
+    let samples: Vec<f32> = (0..70)
+        .map(|i| f32::from(u8::try_from(i).unwrap()) * 0.5)
+        .collect();
+    assert_eq!(
+        slice::process_until(simd, &samples, |v: S::f32s| v.simd_gt(33.0)),
+        Some(67),
+        "the first sample which is out of range, in the tail"
+    );
+}
+
+#[simd_test]
+fn zip_map_matches_scalar<S: Simd>(simd: S) {
+    let a: Vec<u32> = (0..100).map(|i| i * 3 + 1).collect();
+    let b: Vec<u32> = (0..100).map(|i| i * i).collect();
+    // Lengths with and without a whole number of pairs of vectors, a single vector, and a tail.
+    for len in [0, 5, 16, 32, 40, 63, 64, 100] {
+        let mut out = vec![0; len];
+        slice::zip_map(simd, &a[..len], &b[..len], &mut out, |a: u32x16<S>, b| {
+            a * b + 7
+        });
+        let expected: Vec<u32> = a.iter().zip(&b).map(|(a, b)| a * b + 7).take(len).collect();
+        assert_eq!(out, expected, "{len} elements");
+    }
+}
+
+#[simd_test]
+fn zip3_map_matches_scalar<S: Simd>(simd: S) {
+    let a: Vec<f32> = (0..70_u8).map(|i| f32::from(i) * 0.5).collect();
+    let b: Vec<f32> = (0..70_u8).map(|i| f32::from(i) - 20.0).collect();
+    let c: Vec<f32> = (0..70_u8).map(|i| f32::from(i % 7)).collect();
+    for len in [0, 3, 8, 33, 70] {
+        let mut out = vec![f32::NAN; len];
+        slice::zip3_map(
+            simd,
+            &a[..len],
+            &b[..len],
+            &c[..len],
+            &mut out,
+            |a: S::f32s, b, c| a.max(b) - c,
+        );
+        let expected: Vec<f32> = (0..len).map(|i| a[i].max(b[i]) - c[i]).collect();
+        assert_eq!(out, expected, "{len} elements");
+    }
+}
+
+#[simd_test]
+fn gather_matches_indexing<S: Simd>(simd: S) {
+    let values: Vec<u32> = (0..300).map(|i| i * 7 + 1).collect();
+    let floats: Vec<f32> = values.iter().map(|&v| v as f32 * 0.5).collect();
+    for indices in [
+        [0, 1, 2, 3, 4, 5, 6, 7],
+        [299, 0, 299, 150, 7, 7, 7, 7],
+        [42; 8],
+        [8, 16, 32, 64, 128, 256, 3, 5],
+    ] {
+        let gathered = slice::gather_u32(simd, &values, u32x8::from_slice(simd, &indices));
+        assert_eq!(
+            *gathered,
+            indices.map(|i| values[i as usize]),
+            "gathering {indices:?}"
+        );
+        let gathered = slice::gather_f32(simd, &floats, u32x8::from_slice(simd, &indices));
+        assert_eq!(
+            *gathered,
+            indices.map(|i| floats[i as usize]),
+            "gathering {indices:?}"
+        );
+    }
+}
+
+#[simd_test]
+fn scatter_matches_indexing<S: Simd>(simd: S) {
+    for indices in [
+        [0, 1, 2, 3, 4, 5, 6, 7],
+        [299, 0, 299, 150, 7, 7, 7, 7],
+        [42; 8],
+        [8, 16, 32, 64, 128, 256, 3, 5],
+    ] {
+        let data = [11, 22, 33, 44, 55, 66, 77, 88];
+        let mut expected = vec![0_u32; 300];
+        for (i, value) in indices.into_iter().zip(data) {
+            expected[i as usize] = value;
+        }
+        let mut values = vec![0_u32; 300];
+        let indices = u32x8::from_slice(simd, &indices);
+        simd.scatter_u32(&mut values, indices, u32x8::from_slice(simd, &data));
+        assert_eq!(values, expected, "scattering to {:?}", *indices);
+        assert_eq!(
+            *simd.gather_u32(&values, indices),
+            *simd.gather_u32(&expected, indices),
+            "gathering what was scattered"
+        );
+
+        let floats = data.map(|v| v as f32 * 0.5);
+        let mut values = vec![0.0_f32; 300];
+        simd.scatter_f32(&mut values, indices, f32x8::from_slice(simd, &floats));
+        let expected: Vec<f32> = expected.iter().map(|&v| v as f32 * 0.5).collect();
+        assert_eq!(values, expected, "scattering to {:?}", *indices);
+        assert_eq!(
+            *simd.gather_f32(&values, indices),
+            *slice::gather_f32(simd, &expected, indices),
+            "gathering what was scattered"
+        );
+    }
+}
+
+/// The bits which the documentation of `slice::bloom_insert` says that `key` sets, in a filter of `len` words.
+fn bloom_bits(key: u32, hashes: u32, len: usize) -> impl Iterator<Item = usize> {
+    fn fmix32(mut h: u32) -> u32 {
+        h ^= h >> 16;
+        h = h.wrapping_mul(0x85eb_ca6b);
+        h ^= h >> 13;
+        h = h.wrapping_mul(0xc2b2_ae35);
+        h ^ (h >> 16)
+    }
+    let h1 = fmix32(key);
+    let h2 = fmix32(key ^ 0x9e37_79b9) | 1;
+    (0..hashes)
+        .map(move |i| usize::try_from(h1.wrapping_add(i.wrapping_mul(h2))).unwrap() % (len * 32))
+}
+
+#[simd_test]
+fn bloom_filter_matches_documented_layout<S: Simd>(simd: S) {
+    let keys: Vec<u32> = (0..45)
+        .map(|i: u32| i.wrapping_mul(0x0100_0193) ^ 0xabcd)
+        .collect();
+    for (len, hashes) in [(1, 1), (16, 3), (64, 7), (64, 0)] {
+        let mut filter = vec![0_u32; len];
+        slice::bloom_insert(simd, &mut filter, &keys, hashes);
+        let mut expected = vec![0_u32; len];
+        for &key in &keys {
+            for bit in bloom_bits(key, hashes, len) {
+                expected[bit / 32] |= 1 << (bit % 32);
+            }
+        }
+        assert_eq!(
+            filter, expected,
+            "inserting into {len} words with {hashes} hashes"
+        );
+
+        // Every inserted key is found, along with any other key whose bits happen to be set.
+        for queries in keys
+            .chunks_exact(8)
+            .chain([&[7, 8, 9, 10, 11, 12, 13, 14][..]])
+        {
+            let found = slice::bloom_probe(simd, &filter, u32x8::from_slice(simd, queries), hashes);
+            for (i, &key) in queries.iter().enumerate() {
+                let present = bloom_bits(key, hashes, len)
+                    .all(|bit| expected[bit / 32] & (1 << (bit % 32)) != 0);
+                assert_eq!(
+                    found.test(i),
+                    present,
+                    "probing {key} in {len} words with {hashes} hashes"
+                );
+            }
+        }
+    }
+}
+
+#[simd_test]
+fn threshold_to_bitmap_packs_one_bit_per_pixel<S: Simd>(simd: S) {
+    let pixels: Vec<u8> = (0..203_u8).map(|i| i.wrapping_mul(37)).collect();
+    // Whole vectors at every width, a partial vector with whole bytes, and partial bytes.
+    for len in [0, 1, 7, 8, 64, 128, 136, 150, 203] {
+        let pixels = &pixels[..len];
+        for threshold in [0, 127, 128, 254, 255] {
+            // Start with set bits, to check that the unused bits of the last byte are cleared.
+            let mut bitmap = vec![0xff; len.div_ceil(8)];
+            slice::threshold_to_bitmap(simd, pixels, threshold, &mut bitmap);
+            let mut expected = vec![0; len.div_ceil(8)];
+            for (i, &pixel) in pixels.iter().enumerate() {
+                if pixel > threshold {
+                    expected[i / 8] |= 1 << (i % 8);
+                }
+            }
+            assert_eq!(bitmap, expected, "{len} pixels above {threshold}");
+        }
+    }
+}
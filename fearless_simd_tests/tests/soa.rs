@@ -0,0 +1,247 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests for the structure-of-arrays vectors in `fearless_simd::soa`.
+
+use fearless_simd::soa::{QuatxN, Rot2xN, Vec2xN, Vec3xN, Vec4xN};
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+/// One distinct vector per lane of `S::f32s`.
+fn points<S: Simd>() -> Vec<[f32; 3]> {
+    (0..S::f32s::N)
+        .map(|i| {
+            let i = i as f32;
+            [i, 2.0 - i, 0.5 * i]
+        })
+        .collect()
+}
+
+/// Assert that each component of the vectors differs by at most `1e-5`.
+#[track_caller]
+fn assert_close<const D: usize>(actual: &[[f32; D]], expected: &[[f32; D]], message: &str) {
+    assert_eq!(actual.len(), expected.len(), "{message}: lengths differ");
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        assert!(
+            a.iter().zip(e).all(|(a, e)| (a - e).abs() <= 1e-5),
+            "{message}: lane {i} is {a:?}, expected {e:?}"
+        );
+    }
+}
+
+#[simd_test]
+fn vec3_round_trips_through_slices<S: Simd>(simd: S) {
+    let points = points::<S>();
+    let v = Vec3xN::from_slice(simd, &points);
+    assert_eq!(v.y[1], 1.0, "components should be split into fields");
+    let mut stored = vec![[0.0; 3]; S::f32s::N];
+    v.store_slice(&mut stored);
+    assert_eq!(stored, points, "storing should restore the input");
+}
+
+#[simd_test]
+fn vec3_arithmetic<S: Simd>(simd: S) {
+    let points = points::<S>();
+    let a = Vec3xN::from_slice(simd, &points);
+    let b = Vec3xN::splat(simd, [1.0, -1.0, 2.0]);
+    let scale = S::f32s::from_fn(simd, |i| i as f32);
+    let mut result = vec![[0.0; 3]; S::f32s::N];
+    ((a + b) * scale - -b).store_slice(&mut result);
+    for (i, (r, p)) in result.iter().zip(&points).enumerate() {
+        let s = i as f32;
+        assert_eq!(
+            *r,
+            [
+                (p[0] + 1.0) * s + 1.0,
+                (p[1] - 1.0) * s - 1.0,
+                (p[2] + 2.0) * s + 2.0,
+            ],
+            "lane {i}"
+        );
+    }
+
+    let mut acc = a;
+    acc += b;
+    acc -= a;
+    acc *= S::f32s::splat(simd, 2.0);
+    a.component_mul(b).store_slice(&mut result);
+    assert_eq!(result[3], [3.0, 1.0, 3.0], "component-wise product");
+    acc.store_slice(&mut result);
+    assert_eq!(result[0], [2.0, -2.0, 4.0], "compound assignment");
+}
+
+#[simd_test]
+fn vec3_dot_and_cross<S: Simd>(simd: S) {
+    let x = Vec3xN::splat(simd, [1.0, 0.0, 0.0]);
+    let y = Vec3xN::splat(simd, [0.0, 1.0, 0.0]);
+    let mut result = vec![[0.0; 3]; S::f32s::N];
+    x.cross(y).store_slice(&mut result);
+    assert!(
+        result.iter().all(|&v| v == [0.0, 0.0, 1.0]),
+        "x cross y should be z"
+    );
+
+    let a = Vec3xN::from_slice(simd, &points::<S>());
+    let b = Vec3xN::splat(simd, [3.0, -2.0, 4.0]);
+    let normal = a.cross(b);
+    assert!(
+        normal.dot(a).as_slice().iter().all(|&d| d == 0.0),
+        "the cross product should be orthogonal to its inputs"
+    );
+    assert_eq!(
+        b.dot(b).as_slice(),
+        S::f32s::splat(simd, 29.0).as_slice(),
+        "dot product"
+    );
+}
+
+#[simd_test]
+fn length_and_normalize<S: Simd>(simd: S) {
+    let v = Vec2xN::<S>::new(
+        S::f32s::from_fn(simd, |i| 3.0 * i as f32),
+        S::f32s::from_fn(simd, |i| 4.0 * i as f32),
+    );
+    let expected: Vec<f32> = (0..S::f32s::N).map(|i| 5.0 * i as f32).collect();
+    assert_eq!(v.length().as_slice(), expected, "length");
+    let unit = v.normalize();
+    assert!(unit.x[0].is_nan(), "a zero vector can't be normalized");
+    assert_eq!(
+        [unit.x[1], unit.y[1]],
+        [0.6, 0.8],
+        "normalized vectors should have unit length"
+    );
+
+    let w = Vec4xN::splat(simd, [1.0, 1.0, 1.0, 1.0]);
+    assert!(
+        w.length_squared().as_slice().iter().all(|&l| l == 4.0),
+        "every component should count towards the length"
+    );
+    assert!(
+        w.normalize().w.as_slice().iter().all(|&c| c == 0.5),
+        "the last component should be normalized"
+    );
+}
+
+/// The Hamilton product of quaternions stored as `[x, y, z, w]`.
+fn hamilton([ax, ay, az, aw]: [f32; 4], [bx, by, bz, bw]: [f32; 4]) -> [f32; 4] {
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+#[simd_test]
+fn quat_mul_is_hamilton_product<S: Simd>(simd: S) {
+    // Small integers keep every product and sum exact, with or without FMA.
+    let a: Vec<[f32; 4]> = (0..S::f32s::N)
+        .map(|i| {
+            let i = i as f32;
+            [i, 1.0 - i, 2.0, -3.0 + i]
+        })
+        .collect();
+    let b: Vec<[f32; 4]> = (0..S::f32s::N)
+        .map(|i| {
+            let i = i as f32;
+            [-1.0, i, 2.0 * i, 5.0]
+        })
+        .collect();
+    let mut product = QuatxN::from_slice(simd, &a);
+    product *= QuatxN::from_slice(simd, &b);
+    let mut result = vec![[0.0; 4]; S::f32s::N];
+    product.store_slice(&mut result);
+    let expected: Vec<_> = a.iter().zip(&b).map(|(&a, &b)| hamilton(a, b)).collect();
+    assert_eq!(result, expected, "product of each lane");
+
+    let identity = QuatxN::identity(simd);
+    (identity * QuatxN::from_slice(simd, &a)).store_slice(&mut result);
+    assert_eq!(result, a, "the identity shouldn't change the quaternion");
+}
+
+#[simd_test]
+fn quat_rotations_compose<S: Simd>(simd: S) {
+    let axis = Vec3xN::splat(simd, [2.0, -1.0, 2.0]).normalize();
+    let angles = S::f32s::from_fn(simd, |i| 0.3 * i as f32 - 1.0);
+    let half = QuatxN::from_axis_angle(axis, angles * 0.5);
+    let full = QuatxN::from_axis_angle(axis, angles);
+    let mut composed = vec![[0.0; 4]; S::f32s::N];
+    let mut expected = composed.clone();
+    (half * half).store_slice(&mut composed);
+    full.store_slice(&mut expected);
+    assert_close(
+        &composed,
+        &expected,
+        "two half turns should make a full turn",
+    );
+
+    let points: Vec<[f32; 3]> = points::<S>();
+    let v = Vec3xN::from_slice(simd, &points);
+    let mut result = vec![[0.0; 3]; S::f32s::N];
+    full.conjugate()
+        .rotate(full.rotate(v))
+        .store_slice(&mut result);
+    assert_close(&result, &points, "the conjugate should undo the rotation");
+
+    full.rotate(axis).store_slice(&mut result);
+    let mut axis_lanes = vec![[0.0; 3]; S::f32s::N];
+    axis.store_slice(&mut axis_lanes);
+    assert_close(&result, &axis_lanes, "the axis shouldn't be rotated");
+}
+
+#[simd_test]
+fn quat_normalize<S: Simd>(simd: S) {
+    let q = QuatxN::splat(simd, [1.0, -1.0, 1.0, 1.0]).normalize();
+    let mut result = vec![[0.0; 4]; S::f32s::N];
+    q.store_slice(&mut result);
+    assert!(
+        result.iter().all(|&q| q == [0.5, -0.5, 0.5, 0.5]),
+        "every component should be scaled"
+    );
+}
+
+#[simd_test]
+fn rot2_rotates_and_composes<S: Simd>(simd: S) {
+    let quarter = Rot2xN::<S> {
+        cos: S::f32s::splat(simd, 0.0),
+        sin: S::f32s::splat(simd, 1.0),
+    };
+    let v = Vec2xN::<S>::new(
+        S::f32s::from_fn(simd, |i| i as f32),
+        S::f32s::splat(simd, 2.0),
+    );
+    let mut result = vec![[0.0; 2]; S::f32s::N];
+    quarter.rotate(v).store_slice(&mut result);
+    for (i, r) in result.iter().enumerate() {
+        assert_eq!(*r, [-2.0, i as f32], "a quarter turn should be exact");
+    }
+    (quarter.inverse() * quarter)
+        .rotate(v)
+        .store_slice(&mut result);
+    let mut original = vec![[0.0; 2]; S::f32s::N];
+    v.store_slice(&mut original);
+    assert_eq!(result, original, "the inverse should undo the rotation");
+
+    let angles = S::f32s::from_fn(simd, |i| 0.7 * i as f32 - 2.0);
+    let twice = Rot2xN::<S>::from_angle(angles) * Rot2xN::<S>::from_angle(angles);
+    let expected = Rot2xN::<S>::from_angle(angles + angles);
+    Vec2xN::<S>::new(twice.cos, twice.sin).store_slice(&mut result);
+    Vec2xN::<S>::new(expected.cos, expected.sin).store_slice(&mut original);
+    assert_close(&result, &original, "composition should add the angles");
+
+    let drifted = Rot2xN::<S> {
+        cos: twice.cos * 1.01,
+        sin: twice.sin * 1.01,
+    }
+    .normalize();
+    Vec2xN::<S>::new(drifted.cos, drifted.sin).store_slice(&mut result);
+    Vec2xN::<S>::new(twice.cos, twice.sin).store_slice(&mut original);
+    assert_close(&result, &original, "normalizing should restore unit length");
+    assert_eq!(
+        format!("{:?}", Rot2xN::identity(simd))
+            .matches("1.0")
+            .count(),
+        S::f32s::N,
+        "debug output should show each lane"
+    );
+}
@@ -138,3 +138,150 @@ fn mask_test_rejects_out_of_bounds<S: Simd>(simd: S) {
 fn mask_set_rejects_out_of_bounds<S: Simd>(simd: S) {
     for_each_mask_type!(check_mask_set_oob, simd);
 }
+
+#[simd_test]
+fn gather_rejects_out_of_bounds<S: Simd>(simd: S) {
+    let values = [1_u32; 10];
+    let floats = [1.0_f32; 10];
+    // Past the end, at the end, and an index which would be a negative offset for a gather instruction.
+    for bad in [10, 11, 1 << 31, u32::MAX] {
+        let indices = u32x8::from_slice(simd, &[0, 1, 2, 3, bad, 5, 6, 7]);
+        assert_panics("slice::gather_u32", || {
+            slice::gather_u32(simd, &values, indices);
+        });
+        assert_panics("slice::gather_f32", || {
+            slice::gather_f32(simd, &floats, indices);
+        });
+    }
+    assert_panics("slice::gather_u32 from an empty slice", || {
+        slice::gather_u32(simd, &[], u32x8::splat(simd, 0));
+    });
+}
+
+#[simd_test]
+fn scatter_rejects_out_of_bounds<S: Simd>(simd: S) {
+    // As for gathers, including an index which would be a negative offset for a scatter instruction.
+    for bad in [10, 11, 1 << 31, u32::MAX] {
+        let indices = u32x8::from_slice(simd, &[0, 1, 2, 3, bad, 5, 6, 7]);
+        let mut values = [0_u32; 10];
+        assert_panics("slice::scatter_u32", || {
+            slice::scatter_u32(simd, &mut values, indices, u32x8::splat(simd, 1));
+        });
+        assert_eq!(values, [0; 10], "nothing is stored");
+        let mut floats = [0.0_f32; 10];
+        assert_panics("slice::scatter_f32", || {
+            slice::scatter_f32(simd, &mut floats, indices, f32x8::splat(simd, 1.0));
+        });
+        assert_eq!(floats, [0.0; 10], "nothing is stored");
+    }
+}
+
+#[simd_test]
+fn bloom_filter_rejects_bad_lengths<S: Simd>(simd: S) {
+    for len in [0, 3, 24] {
+        let mut filter = vec![0_u32; len];
+        assert_panics("slice::bloom_insert", || {
+            slice::bloom_insert(simd, &mut filter, &[1], 2);
+        });
+        assert_panics("slice::bloom_probe", || {
+            slice::bloom_probe(simd, &filter, u32x8::splat(simd, 1), 2);
+        });
+    }
+}
+
+#[simd_test]
+fn threshold_to_bitmap_rejects_wrong_bitmap_length<S: Simd>(simd: S) {
+    let pixels = [0_u8; 17];
+    for len in [2, 4] {
+        let mut bitmap = vec![0; len];
+        assert_panics("slice::threshold_to_bitmap", || {
+            slice::threshold_to_bitmap(simd, &pixels, 0, &mut bitmap);
+        });
+    }
+}
+
+#[simd_test]
+fn morphology_rejects_mismatched_lengths<S: Simd>(simd: S) {
+    let row = [1_u8; 20];
+    let short = [1_u8; 19];
+    let mut out = [0_u8; 21];
+    assert_panics("morphology::erode_3x1", || {
+        morphology::erode_3x1(simd, &row, &mut out);
+    });
+    let mut out = [0_u8; 20];
+    assert_panics("morphology::dilate_1x3", || {
+        morphology::dilate_1x3(simd, [&row, &short, &row], &mut out);
+    });
+}
+
+#[simd_test]
+fn integral_image_rejects_bad_dimensions<S: Simd>(simd: S) {
+    let pixels = [1_u8; 12];
+    let mut table = vec![0_u32; 5 * 4];
+    assert_panics("integral::integral_image with a width of zero", || {
+        integral::integral_image(simd, &pixels, 0, &mut table);
+    });
+    assert_panics("integral::integral_image with a partial row", || {
+        integral::integral_image(simd, &pixels, 5, &mut table);
+    });
+    let mut short = vec![0_u32; 5 * 3];
+    assert_panics("integral::integral_image with a short table", || {
+        integral::integral_image(simd, &pixels, 4, &mut short);
+    });
+}
+
+#[simd_test]
+fn yuv_rejects_mismatched_planes<S: Simd>(simd: S) {
+    let planes = [[0_u8; 5]; 3];
+    let mut rgba = [0_u8; 4 * 4];
+    assert_panics("yuv::yuv_to_rgba with a short `rgba`", || {
+        yuv::yuv_to_rgba(
+            simd,
+            yuv::YuvMatrix::Bt601,
+            &planes[0],
+            &planes[1],
+            &planes[2],
+            &mut rgba,
+        );
+    });
+    let (mut y, mut u, mut v) = ([0_u8; 4], [0_u8; 4], [0_u8; 3]);
+    assert_panics("yuv::rgba_to_yuv with a short plane", || {
+        yuv::rgba_to_yuv(simd, yuv::YuvMatrix::Bt709, &rgba, &mut y, &mut u, &mut v);
+    });
+}
+
+#[simd_test]
+fn png_unfilter_row_rejects_bad_rows<S: Simd>(simd: S) {
+    let previous = [0_u8; 6];
+    let mut current = [0_u8; 6];
+    assert_panics("png::unfilter_row with 9 bytes per pixel", || {
+        png::unfilter_row(simd, png::FilterType::Sub, 9, &previous, &mut current);
+    });
+    assert_panics("png::unfilter_row with a short previous row", || {
+        png::unfilter_row(simd, png::FilterType::Up, 1, &previous[..5], &mut current);
+    });
+    assert_panics("png::unfilter_row with a partial pixel", || {
+        png::unfilter_row(simd, png::FilterType::Paeth, 4, &previous, &mut current);
+    });
+}
+
+#[simd_test]
+fn varint_end_mask_rejects_long_input<S: Simd>(simd: S) {
+    let bytes = [0_u8; 65];
+    assert_panics("varint::end_mask with 65 bytes", || {
+        varint::end_mask(simd, &bytes);
+    });
+}
+
+#[simd_test]
+fn bitpack_rejects_mismatched_lengths<S: Simd>(simd: S) {
+    let values = [0_u16; 9];
+    let mut bytes = [0_u8; 13];
+    assert_panics("bitpack::pack_bits_slice with too many bytes", || {
+        bitpack::pack_bits_slice::<S, 10>(simd, &values, &mut bytes);
+    });
+    let mut values = [0_u16; 9];
+    assert_panics("bitpack::unpack_bits_slice with too many bytes", || {
+        bitpack::unpack_bits_slice::<S, 10>(simd, &bytes, &mut values);
+    });
+}
@@ -0,0 +1,37 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that floating point results which may be rounded differently are close on every level.
+
+use fearless_simd::*;
+use fearless_simd_dev_macros::simd_test;
+
+#[simd_test]
+fn mul_add_is_close_to_fallback<S: Simd>(simd: S) {
+    let fallback = Fallback::new();
+    let values = |i: usize| (i as f32 + 0.5) * 0.37;
+    let (a, b, c) = (
+        f32x16::from_fn(simd, values),
+        f32x16::from_fn(simd, |i| values(i + 3)),
+        f32x16::from_fn(simd, |i| values(i * 2)),
+    );
+    let (fa, fb, fc) = (
+        f32x16::from_fn(fallback, values),
+        f32x16::from_fn(fallback, |i| values(i + 3)),
+        f32x16::from_fn(fallback, |i| values(i * 2)),
+    );
+    // Whether the multiply and add are fused depends on the level, which changes the rounding,
+    // but with positive operands that's at most one ULP.
+    assert_ulps_eq!(
+        a.mul_add(b, c),
+        fa.mul_add(fb, fc),
+        1,
+        "mul_add should be close to the fallback level"
+    );
+    assert_ulps_eq!(
+        a.mul_add(b, c),
+        *(a * b + c),
+        1,
+        "mul_add should be close to a separate multiply and add"
+    );
+}
@@ -0,0 +1,101 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that partial selection matches sorting, including ties and NaN.
+
+use fearless_simd::{Simd, SimdBase, topk};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::random;
+
+/// Pseudo-random values with many ties, both zeros and some NaN.
+fn values(len: usize, seed: u32) -> Vec<f32> {
+    random(len, seed, |state| match state >> 27 {
+        0 => f32::NAN,
+        1 => -0.0,
+        bits => f32::from(u8::try_from(bits % 12).unwrap()) - 4.0,
+    })
+}
+
+/// The indices of `values` from the best, with ties going to the lower index and NaN last.
+fn ranked(values: &[f32], largest: bool) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    indices.sort_by(|&a, &b| {
+        let (x, y) = (values[a], values[b]);
+        let order = match (x.is_nan(), y.is_nan()) {
+            (false, false) if largest => y.partial_cmp(&x).unwrap(),
+            (false, false) => x.partial_cmp(&y).unwrap(),
+            (nan_x, nan_y) => nan_x.cmp(&nan_y),
+        };
+        order.then(a.cmp(&b))
+    });
+    indices
+}
+
+fn reference_select<const K: usize>(values: &[f32], largest: bool) -> [Option<usize>; K] {
+    let ranked: Vec<usize> = ranked(values, largest)
+        .into_iter()
+        .filter(|&i| !values[i].is_nan())
+        .collect();
+    core::array::from_fn(|i| ranked.get(i).copied())
+}
+
+#[simd_test]
+fn top_lanes_match_sorting<S: Simd>(simd: S) {
+    for seed in 0..50 {
+        let lanes = values(S::f32s::N, seed);
+        let v = S::f32s::from_slice(simd, &lanes);
+        let expected = ranked(&lanes, true);
+        assert_eq!(
+            topk::top2(simd, v),
+            expected[..2],
+            "top2 of {lanes:?} should match sorting"
+        );
+        assert_eq!(
+            topk::top4(simd, v),
+            expected[..4],
+            "top4 of {lanes:?} should match sorting"
+        );
+    }
+}
+
+fn check_select<S: Simd, const K: usize>(simd: S) {
+    for len in 0..=70 {
+        let values = values(len, u32::try_from(len).unwrap());
+        assert_eq!(
+            topk::largest::<S, K>(simd, &values),
+            reference_select::<K>(&values, true),
+            "the {K} largest of {values:?} should match sorting"
+        );
+        assert_eq!(
+            topk::smallest::<S, K>(simd, &values),
+            reference_select::<K>(&values, false),
+            "the {K} smallest of {values:?} should match sorting"
+        );
+    }
+}
+
+#[simd_test]
+fn select_matches_sorting<S: Simd>(simd: S) {
+    check_select::<S, 1>(simd);
+    check_select::<S, 2>(simd);
+    check_select::<S, 3>(simd);
+    check_select::<S, 4>(simd);
+    check_select::<S, 7>(simd);
+    check_select::<S, 16>(simd);
+}
+
+#[simd_test]
+fn select_ignores_nan<S: Simd>(simd: S) {
+    let values = [f32::NAN, 2.0, f32::NAN, f32::NEG_INFINITY, f32::NAN];
+    assert_eq!(
+        topk::largest::<S, 3>(simd, &values),
+        [Some(1), Some(3), None],
+        "NaN shouldn't be selected"
+    );
+    assert_eq!(
+        topk::smallest::<S, 3>(simd, &values),
+        [Some(3), Some(1), None],
+        "NaN shouldn't be selected"
+    );
+}
@@ -0,0 +1,183 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that varint decoding matches a byte-at-a-time decoder, including where it stops.
+
+use fearless_simd::Simd;
+use fearless_simd::varint::{Decoded, decode_u32, end_mask};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::random;
+
+/// Pseudo-random values, whose number of bits is spread evenly so that every length of varint is common.
+fn values(len: usize, seed: u32) -> Vec<u32> {
+    random(len, seed, |state| state >> (state % 32))
+}
+
+fn encode(values: &[u32]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for &value in values {
+        let mut value = value;
+        while value >= 0x80 {
+            bytes.push(u8::try_from(value & 0x7f).unwrap() | 0x80);
+            value >>= 7;
+        }
+        bytes.push(u8::try_from(value).unwrap());
+    }
+    bytes
+}
+
+/// Decode one byte at a time, stopping at the same places as `decode_u32`.
+fn reference_decode(input: &[u8], out: &mut [u32]) -> Decoded {
+    let mut decoded = Decoded {
+        bytes: 0,
+        values: 0,
+    };
+    'values: while decoded.values < out.len() {
+        let mut value = 0_u64;
+        for (i, &byte) in input[decoded.bytes..].iter().enumerate() {
+            if i == 5 {
+                break 'values;
+            }
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte < 0x80 {
+                let Ok(value) = u32::try_from(value) else {
+                    break 'values;
+                };
+                out[decoded.values] = value;
+                decoded.values += 1;
+                decoded.bytes += i + 1;
+                continue 'values;
+            }
+        }
+        // The varint is cut off.
+        break;
+    }
+    decoded
+}
+
+fn assert_decodes_like_reference<S: Simd>(simd: S, input: &[u8], out_len: usize, what: &str) {
+    let mut actual = vec![0; out_len];
+    let mut expected = vec![0; out_len];
+    let decoded = decode_u32(simd, input, &mut actual);
+    assert_eq!(
+        decoded,
+        reference_decode(input, &mut expected),
+        "{what} should stop at the same place"
+    );
+    assert_eq!(
+        actual[..decoded.values],
+        expected[..decoded.values],
+        "{what} should decode the same values"
+    );
+}
+
+#[simd_test]
+fn decode_u32_matches_reference<S: Simd>(simd: S) {
+    for seed in 0..8 {
+        let values = values(200, seed);
+        let input = encode(&values);
+        let mut out = vec![0; values.len()];
+        let decoded = decode_u32(simd, &input, &mut out);
+        assert_eq!(
+            (decoded.bytes, decoded.values),
+            (input.len(), values.len()),
+            "seed {seed} should decode the whole input"
+        );
+        assert_eq!(out, values, "seed {seed} should round trip");
+
+        // A short output, and inputs cut off at every length, including in the middle of varints.
+        assert_decodes_like_reference(simd, &input, 37, "a short output");
+        for len in 0..40 {
+            assert_decodes_like_reference(simd, &input[..len], values.len(), "a cut off input");
+        }
+    }
+}
+
+#[simd_test]
+fn decode_u32_single_bytes<S: Simd>(simd: S) {
+    let input: Vec<u8> = (0..100).collect();
+    let mut out = [0; 100];
+    let decoded = decode_u32(simd, &input, &mut out);
+    assert_eq!(
+        decoded,
+        Decoded {
+            bytes: 100,
+            values: 100
+        },
+        "every byte should be a value"
+    );
+    assert!(
+        out.iter().zip(0..).all(|(&value, i)| value == i),
+        "the values should be the bytes"
+    );
+}
+
+#[simd_test]
+fn decode_u32_stops_before_invalid_varints<S: Simd>(simd: S) {
+    let longest = [0xff, 0xff, 0xff, 0xff, 0x0f];
+    let cases: [(&[u8], &str); 5] = [
+        (
+            &[0xff, 0xff, 0xff, 0xff, 0x1f],
+            "a fifth byte with too many bits",
+        ),
+        (&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00], "six bytes"),
+        (&[0x80; 20], "a block without an end"),
+        (&[0x80, 0x80, 0x80, 0x80, 0x00], "a padded zero"),
+        (&longest, "the largest value"),
+    ];
+    for (varint, what) in cases {
+        // Valid varints before and after, at every offset in a block.
+        for before in 0..20 {
+            let mut input = encode(&values(before, 1));
+            input.extend_from_slice(varint);
+            input.extend(encode(&[1, 2, 3]));
+            assert_decodes_like_reference(simd, &input, 64, what);
+        }
+    }
+    let mut out = [0; 1];
+    decode_u32(simd, &longest, &mut out);
+    assert_eq!(
+        out,
+        [u32::MAX],
+        "the longest varint should be the largest value"
+    );
+}
+
+#[simd_test]
+fn decode_u32_in_pieces<S: Simd>(simd: S) {
+    let values = values(300, 9);
+    let input = encode(&values);
+    let mut out = vec![0; values.len()];
+    let (mut bytes, mut count) = (0, 0);
+    // Each piece ends at an arbitrary byte, and the next starts where decoding stopped.
+    for end in (7..input.len()).step_by(23).chain([input.len()]) {
+        let decoded = decode_u32(simd, &input[bytes..end], &mut out[count..]);
+        bytes += decoded.bytes;
+        count += decoded.values;
+    }
+    assert_eq!(
+        (bytes, count),
+        (input.len(), values.len()),
+        "the pieces should cover the input"
+    );
+    assert_eq!(out, values, "the pieces should round trip");
+}
+
+#[simd_test]
+fn end_mask_matches_reference<S: Simd>(simd: S) {
+    let input = encode(&values(40, 3));
+    for len in [0, 1, 15, 16, 17, 33, 64] {
+        let bytes = &input[..len];
+        let expected = bytes
+            .iter()
+            .enumerate()
+            .filter(|&(_, &byte)| byte < 0x80)
+            .fold(0_u64, |mask, (i, _)| mask | 1 << i);
+        assert_eq!(
+            end_mask(simd, bytes),
+            expected,
+            "the mask of {len} bytes should match"
+        );
+    }
+}
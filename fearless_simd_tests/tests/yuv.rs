@@ -0,0 +1,109 @@
+// Copyright 2026 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests that YUV conversions are within 1 of the exact conversion, and the same on every level.
+
+use fearless_simd::yuv::{YuvMatrix, rgba_to_yuv, yuv_to_rgba};
+use fearless_simd::{Fallback, Simd};
+use fearless_simd_dev_macros::simd_test;
+
+use crate::random::bytes;
+
+const MATRICES: [(YuvMatrix, f64, f64); 2] = [
+    (YuvMatrix::Bt601, 0.299, 0.114),
+    (YuvMatrix::Bt709, 0.2126, 0.0722),
+];
+
+fn assert_close(actual: u8, exact: f64, what: &str) {
+    let exact = exact.clamp(0.0, 255.0);
+    assert!(
+        (f64::from(actual) - exact).abs() <= 1.0,
+        "{what}: {actual} should be within 1 of {exact}"
+    );
+}
+
+#[simd_test]
+fn yuv_to_rgba_is_close_to_exact<S: Simd>(simd: S) {
+    for (matrix, kr, kb) in MATRICES {
+        let kg = 1.0 - kr - kb;
+        // Not a multiple of the 16 pixels of each block.
+        let len = 16 * 20 + 5;
+        let (y, u, v) = (bytes(len, 1), bytes(len, 2), bytes(len, 3));
+        let mut rgba = vec![0; 4 * len];
+        yuv_to_rgba(simd, matrix, &y, &u, &v, &mut rgba);
+        let mut fallback = vec![0; 4 * len];
+        yuv_to_rgba(Fallback::new(), matrix, &y, &u, &v, &mut fallback);
+        assert_eq!(rgba, fallback, "{matrix:?} should match the fallback level");
+
+        for i in 0..len {
+            let luma = (f64::from(y[i]) - 16.0) * 255.0 / 219.0;
+            let pb = (f64::from(u[i]) - 128.0) * 255.0 / 224.0;
+            let pr = (f64::from(v[i]) - 128.0) * 255.0 / 224.0;
+            let r = luma + 2.0 * (1.0 - kr) * pr;
+            let b = luma + 2.0 * (1.0 - kb) * pb;
+            let g = (luma - kr * r - kb * b) / kg;
+            let what = format!("{matrix:?} pixel {i}");
+            assert_close(rgba[4 * i], r, &what);
+            assert_close(rgba[4 * i + 1], g, &what);
+            assert_close(rgba[4 * i + 2], b, &what);
+            assert_eq!(rgba[4 * i + 3], 255, "{what} should be opaque");
+        }
+    }
+}
+
+#[simd_test]
+fn rgba_to_yuv_is_close_to_exact<S: Simd>(simd: S) {
+    for (matrix, kr, kb) in MATRICES {
+        let kg = 1.0 - kr - kb;
+        for len in [0, 1, 15, 16, 17, 100] {
+            let rgba = bytes(4 * len, 4);
+            let (mut y, mut u, mut v) = (vec![0; len], vec![0; len], vec![0; len]);
+            rgba_to_yuv(simd, matrix, &rgba, &mut y, &mut u, &mut v);
+            let mut fallback = (vec![0; len], vec![0; len], vec![0; len]);
+            rgba_to_yuv(
+                Fallback::new(),
+                matrix,
+                &rgba,
+                &mut fallback.0,
+                &mut fallback.1,
+                &mut fallback.2,
+            );
+            assert_eq!(
+                (&y, &u, &v),
+                (&fallback.0, &fallback.1, &fallback.2),
+                "{matrix:?} should match the fallback level"
+            );
+
+            for i in 0..len {
+                let [r, g, b] = [0, 1, 2].map(|c| f64::from(rgba[4 * i + c]) / 255.0);
+                let luma = kr * r + kg * g + kb * b;
+                let what = format!("{matrix:?} pixel {i} of {len}");
+                assert_close(y[i], 16.0 + 219.0 * luma, &what);
+                assert_close(u[i], 128.0 + 224.0 * (b - luma) / (2.0 * (1.0 - kb)), &what);
+                assert_close(v[i], 128.0 + 224.0 * (r - luma) / (2.0 * (1.0 - kr)), &what);
+            }
+        }
+    }
+}
+
+#[simd_test]
+fn grays_round_trip<S: Simd>(simd: S) {
+    let grays: Vec<u8> = (0..=255).collect();
+    let rgba: Vec<u8> = grays.iter().flat_map(|&g| [g, g, g, 255]).collect();
+    for (matrix, _, _) in MATRICES {
+        let (mut y, mut u, mut v) = (vec![0; 256], vec![0; 256], vec![0; 256]);
+        rgba_to_yuv(simd, matrix, &rgba, &mut y, &mut u, &mut v);
+        assert!(
+            u.iter().chain(&v).all(|&c| c == 128),
+            "{matrix:?} grays should have no color"
+        );
+        let mut back = vec![0; 4 * 256];
+        yuv_to_rgba(simd, matrix, &y, &u, &v, &mut back);
+        for (gray, pixel) in grays.iter().zip(back.chunks_exact(4)) {
+            assert!(
+                pixel[..3].iter().all(|&c| c.abs_diff(*gray) <= 1),
+                "{matrix:?} gray {gray} came back as {pixel:?}"
+            );
+        }
+    }
+}